@@ -0,0 +1,160 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::num::arithmetic::traits::{CheckedFibonacci, CheckedLucas, Fibonacci, Lucas};
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+
+fn checked_fibonacci2<T: PrimitiveUnsigned>(n: u64) -> Option<(T, T)> {
+    // $F_{-1} = 1$.
+    let mut f = T::ZERO;
+    let mut f_prev = T::ONE;
+    for _ in 0..n {
+        (f, f_prev) = (f.checked_add(f_prev)?, f);
+    }
+    Some((f, f_prev))
+}
+
+fn checked_lucas<T: PrimitiveUnsigned>(n: u64) -> Option<T> {
+    if n == 0 {
+        return Some(T::TWO);
+    }
+    // $L_n = F_n + 2F_{n-1}$.
+    let (f, f_prev) = checked_fibonacci2::<T>(n)?;
+    f.checked_add(f_prev.checked_mul(T::TWO)?)
+}
+
+macro_rules! impl_fibonacci {
+    ($t:ident) => {
+        impl CheckedFibonacci for $t {
+            /// Computes the $n$th Fibonacci number, returning `None` if it is too large to be
+            /// represented.
+            ///
+            /// $$
+            /// f(n) = \\begin{cases}
+            ///     \operatorname{Some}(F_n) & \text{if} \\quad F_n < 2^W, \\\\
+            ///     \operatorname{None} & \text{otherwise},
+            /// \\end{cases}
+            /// $$
+            /// where $W$ is `Self::WIDTH`, $F_0 = 0$, $F_1 = 1$, and $F_n = F_{n-1} + F_{n-2}$.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Examples
+            /// See [here](super::fibonacci#checked_fibonacci).
+            #[inline]
+            fn checked_fibonacci(n: u64) -> Option<$t> {
+                checked_fibonacci2(n).map(|p| p.0)
+            }
+
+            /// Computes the $n$th and $(n-1)$th Fibonacci numbers, returning `None` if they are
+            /// too large to be represented. $F_{-1}$ is 1.
+            ///
+            /// $$
+            /// f(n) = \\begin{cases}
+            ///     \operatorname{Some}((F_n, F_{n-1})) & \text{if} \\quad F_n < 2^W, \\\\
+            ///     \operatorname{None} & \text{otherwise},
+            /// \\end{cases}
+            /// $$
+            /// where $W$ is `Self::WIDTH`.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Examples
+            /// See [here](super::fibonacci#checked_fibonacci2).
+            #[inline]
+            fn checked_fibonacci2(n: u64) -> Option<($t, $t)> {
+                checked_fibonacci2(n)
+            }
+        }
+
+        impl Fibonacci for $t {
+            /// Computes the $n$th Fibonacci number.
+            ///
+            /// $f(n) = F_n$, where $F_0 = 0$, $F_1 = 1$, and $F_n = F_{n-1} + F_{n-2}$.
+            ///
+            /// $F_n = O(\varphi^n)$, where $\varphi = (1 + \sqrt 5)/2$ is the golden ratio.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Panics
+            /// Panics if the output is too large to be represented.
+            ///
+            /// # Examples
+            /// See [here](super::fibonacci#fibonacci).
+            #[inline]
+            fn fibonacci(n: u64) -> $t {
+                $t::checked_fibonacci(n).unwrap()
+            }
+
+            /// Computes the $n$th and $(n-1)$th Fibonacci numbers. $F_{-1}$ is 1.
+            ///
+            /// $f(n) = (F_n, F_{n-1})$.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Panics
+            /// Panics if the output is too large to be represented.
+            ///
+            /// # Examples
+            /// See [here](super::fibonacci#fibonacci2).
+            #[inline]
+            fn fibonacci2(n: u64) -> ($t, $t) {
+                $t::checked_fibonacci2(n).unwrap()
+            }
+        }
+
+        impl CheckedLucas for $t {
+            /// Computes the $n$th Lucas number, returning `None` if it is too large to be
+            /// represented.
+            ///
+            /// $$
+            /// f(n) = \\begin{cases}
+            ///     \operatorname{Some}(L_n) & \text{if} \\quad L_n < 2^W, \\\\
+            ///     \operatorname{None} & \text{otherwise},
+            /// \\end{cases}
+            /// $$
+            /// where $W$ is `Self::WIDTH`, $L_0 = 2$, $L_1 = 1$, and $L_n = L_{n-1} + L_{n-2}$.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Examples
+            /// See [here](super::fibonacci#checked_lucas).
+            #[inline]
+            fn checked_lucas(n: u64) -> Option<$t> {
+                checked_lucas(n)
+            }
+        }
+
+        impl Lucas for $t {
+            /// Computes the $n$th Lucas number.
+            ///
+            /// $f(n) = L_n$, where $L_0 = 2$, $L_1 = 1$, and $L_n = L_{n-1} + L_{n-2}$.
+            ///
+            /// $L_n = O(\varphi^n)$, where $\varphi = (1 + \sqrt 5)/2$ is the golden ratio.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Panics
+            /// Panics if the output is too large to be represented.
+            ///
+            /// # Examples
+            /// See [here](super::fibonacci#lucas).
+            #[inline]
+            fn lucas(n: u64) -> $t {
+                $t::checked_lucas(n).unwrap()
+            }
+        }
+    };
+}
+apply_to_unsigneds!(impl_fibonacci);
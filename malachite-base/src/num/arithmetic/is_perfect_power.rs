@@ -0,0 +1,204 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::num::arithmetic::traits::{ExpressAsPower, IsPerfectPower, ModPow, UnsignedAbs};
+use crate::num::basic::signeds::PrimitiveSigned;
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::conversion::traits::WrappingFrom;
+use crate::num::factorization::traits::{IsPrime, Primes};
+
+#[doc(hidden)]
+// Returns `false` if $x$ is certainly not a perfect $p$th power, where $p$ is an odd prime, by
+// checking whether $x$ is a $p$th power residue modulo a few primes $q \equiv 1 \pmod p$. `x_mod`
+// must return $x \mod q$; each $q$ is less than $2^{16}$.
+pub fn power_residue_filter<F: FnMut(u64) -> u64>(p: u64, mut x_mod: F) -> bool {
+    let step = p << 1;
+    let mut q = step + 1;
+    let mut checked = 0;
+    while checked < 4 && q < 1 << 16 {
+        if q.is_prime() {
+            let r = x_mod(q);
+            if r != 0 && r.mod_pow((q - 1) / p, q) != 1 {
+                return false;
+            }
+            checked += 1;
+        }
+        q += step;
+    }
+    true
+}
+
+fn express_as_power_unsigned<T: PrimitiveUnsigned>(x: T) -> Option<(T, u64)> {
+    if x <= T::ONE {
+        return Some((x, 2));
+    }
+    // If $x = y^k$, then $k$ divides the multiplicity of 2 in $x$.
+    let zeros = x.trailing_zeros();
+    for p in u64::primes_less_than(&x.significant_bits()) {
+        if zeros != 0 && zeros % p != 0 {
+            continue;
+        }
+        let root = if p == 2 {
+            if !x.is_square() {
+                continue;
+            }
+            x.floor_sqrt()
+        } else if !power_residue_filter(p, |q| {
+            if T::convertible_from(q) {
+                (x % T::wrapping_from(q)).wrapping_into()
+            } else {
+                x.wrapping_into()
+            }
+        }) {
+            continue;
+        } else if let Some(root) = x.checked_root(p) {
+            root
+        } else {
+            continue;
+        };
+        // Since `p` is the smallest prime exponent, the largest exponent of `x` is `p` times the
+        // largest exponent of `root`.
+        return Some(match express_as_power_unsigned(root) {
+            Some((base, k)) => (base, k * p),
+            None => (root, p),
+        });
+    }
+    None
+}
+
+fn express_as_power_signed<
+    U: ExpressAsPower + PrimitiveUnsigned,
+    S: PrimitiveSigned + UnsignedAbs<Output = U> + WrappingFrom<U>,
+>(
+    x: S,
+) -> Option<(S, u64)> {
+    let abs = x.unsigned_abs();
+    if x >= S::ZERO {
+        return abs
+            .express_as_power()
+            .map(|(base, k)| (S::wrapping_from(base), k));
+    }
+    if abs == U::ONE {
+        return Some((S::NEGATIVE_ONE, 3));
+    }
+    // A negative number is a perfect power only if its absolute value is a perfect power with an
+    // odd exponent.
+    let (base, k) = abs.express_as_power()?;
+    let odd = k >> k.trailing_zeros();
+    if odd == 1 {
+        None
+    } else {
+        Some((-S::wrapping_from(base.pow(k / odd)), odd))
+    }
+}
+
+macro_rules! impl_is_perfect_power_unsigned {
+    ($t:ident) => {
+        impl ExpressAsPower for $t {
+            /// Expresses a number as a perfect power $y^k$, with the exponent $k \geq 2$ as large as
+            /// possible. Returns `None` if the number is not a perfect power.
+            ///
+            /// 0 and 1 are expressed as $0^2$ and $1^2$.
+            ///
+            /// $f(x) = (y, k)$, where $y^k = x$ and $k \geq 2$ is maximal, if such $y$ and $k$ exist,
+            /// and `None` otherwise.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n^2)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `Self::WIDTH`.
+            ///
+            /// # Examples
+            /// See [here](super::is_perfect_power#express_as_power).
+            #[inline]
+            fn express_as_power(&self) -> Option<($t, u64)> {
+                express_as_power_unsigned(*self)
+            }
+        }
+
+        impl IsPerfectPower for $t {
+            /// Determines whether a number is a perfect power; that is, whether it is equal to $y^k$
+            /// for some $y$ and some $k \geq 2$.
+            ///
+            /// 0 and 1 are perfect powers.
+            ///
+            /// $f(x) = (\exists y, k \in \N : k \geq 2 \land y^k = x)$.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n^2)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `Self::WIDTH`.
+            ///
+            /// # Examples
+            /// See [here](super::is_perfect_power#is_perfect_power).
+            #[inline]
+            fn is_perfect_power(&self) -> bool {
+                express_as_power_unsigned(*self).is_some()
+            }
+        }
+    };
+}
+apply_to_unsigneds!(impl_is_perfect_power_unsigned);
+
+macro_rules! impl_is_perfect_power_signed {
+    ($u:ident, $s:ident) => {
+        impl ExpressAsPower for $s {
+            /// Expresses a number as a perfect power $y^k$, with the exponent $k \geq 2$ as large as
+            /// possible. Returns `None` if the number is not a perfect power.
+            ///
+            /// A negative number can only be a perfect power with an odd exponent. 0 and 1 are
+            /// expressed as $0^2$ and $1^2$, and $-1$ is expressed as $(-1)^3$.
+            ///
+            /// $f(x) = (y, k)$, where $y^k = x$ and $k \geq 2$ is maximal, if such $y$ and $k$ exist,
+            /// and `None` otherwise.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n^2)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `Self::WIDTH`.
+            ///
+            /// # Examples
+            /// See [here](super::is_perfect_power#express_as_power).
+            #[inline]
+            fn express_as_power(&self) -> Option<($s, u64)> {
+                express_as_power_signed::<$u, $s>(*self)
+            }
+        }
+
+        impl IsPerfectPower for $s {
+            /// Determines whether a number is a perfect power; that is, whether it is equal to $y^k$
+            /// for some $y$ and some $k \geq 2$.
+            ///
+            /// 0, 1, and $-1$ are perfect powers. A negative number can only be a perfect power
+            /// with an odd exponent.
+            ///
+            /// $f(x) = (\exists y \in \Z, k \in \N : k \geq 2 \land y^k = x)$.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n^2)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `Self::WIDTH`.
+            ///
+            /// # Examples
+            /// See [here](super::is_perfect_power#is_perfect_power).
+            #[inline]
+            fn is_perfect_power(&self) -> bool {
+                express_as_power_signed::<$u, $s>(*self).is_some()
+            }
+        }
+    };
+}
+apply_to_unsigned_signed_pairs!(impl_is_perfect_power_signed);
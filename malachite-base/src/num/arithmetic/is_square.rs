@@ -0,0 +1,83 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::num::arithmetic::traits::IsSquare;
+use crate::num::basic::integers::PrimitiveInt;
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+
+// Bit $i$ of each mask is set if and only if $i$ is a square modulo 64, 63, 65, and 11
+// respectively.
+const SQUARES_MOD_64: u64 = 0x202021202030213;
+const SQUARES_MOD_63: u64 = 0x402483012450293;
+const SQUARES_MOD_65: u128 = 0x1218a019866014613;
+const SQUARES_MOD_11: u64 = 0x23b;
+
+#[doc(hidden)]
+// Returns `false` if $x$ is certainly not a square, given $x \mod 64$ and $x \mod 45045$, where
+// $45045 = 63 \cdot 65 \cdot 11$. Fewer than 1% of non-squares pass this test.
+pub const fn square_residue_filter(x_mod_64: u64, x_mod_45045: u64) -> bool {
+    (SQUARES_MOD_64 >> x_mod_64) & 1 != 0
+        && (SQUARES_MOD_63 >> (x_mod_45045 % 63)) & 1 != 0
+        && (SQUARES_MOD_65 >> (x_mod_45045 % 65)) & 1 != 0
+        && (SQUARES_MOD_11 >> (x_mod_45045 % 11)) & 1 != 0
+}
+
+fn is_square_unsigned<T: PrimitiveUnsigned>(x: T) -> bool {
+    let x_mod_45045: u64 = if T::WIDTH < u16::WIDTH {
+        x.wrapping_into()
+    } else {
+        (x % T::wrapping_from(45045u16)).wrapping_into()
+    };
+    square_residue_filter(x.mod_power_of_2(6).wrapping_into(), x_mod_45045)
+        && x.checked_sqrt().is_some()
+}
+
+macro_rules! impl_is_square_unsigned {
+    ($t:ident) => {
+        impl IsSquare for $t {
+            /// Determines whether a number is a perfect square.
+            ///
+            /// Most non-squares are rejected by looking at their residues modulo a few small
+            /// numbers, without computing a square root.
+            ///
+            /// $f(x) = (\exists y \in \Z : y^2 = x)$.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Examples
+            /// See [here](super::is_square#is_square).
+            #[inline]
+            fn is_square(&self) -> bool {
+                is_square_unsigned(*self)
+            }
+        }
+    };
+}
+apply_to_unsigneds!(impl_is_square_unsigned);
+
+macro_rules! impl_is_square_signed {
+    ($u:ident, $s:ident) => {
+        impl IsSquare for $s {
+            /// Determines whether a number is a perfect square. Negative numbers are never squares.
+            ///
+            /// $f(x) = (\exists y \in \Z : y^2 = x)$.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Examples
+            /// See [here](super::is_square#is_square).
+            #[inline]
+            fn is_square(&self) -> bool {
+                *self >= 0 && self.unsigned_abs().is_square()
+            }
+        }
+    };
+}
+apply_to_unsigned_signed_pairs!(impl_is_square_signed);
@@ -896,6 +896,77 @@ pub mod extended_gcd;
 /// assert_eq!(u32::checked_subfactorial(100), None);
 /// ```
 pub mod factorial;
+/// Traits for computing Fibonacci and Lucas numbers. Each function has a trait whose
+/// implementations panic if the result cannot be represented, and a checked trait whose
+/// implementations return `None` in that case. The traits are [`Fibonacci`](traits::Fibonacci),
+/// [`Lucas`](traits::Lucas), [`CheckedFibonacci`](traits::CheckedFibonacci), and
+/// [`CheckedLucas`](traits::CheckedLucas).
+///
+/// # fibonacci
+/// ```
+/// use malachite_base::num::arithmetic::traits::Fibonacci;
+///
+/// assert_eq!(u8::fibonacci(0), 0);
+/// assert_eq!(u8::fibonacci(1), 1);
+/// assert_eq!(u8::fibonacci(2), 1);
+/// assert_eq!(u8::fibonacci(10), 55);
+/// assert_eq!(u8::fibonacci(13), 233);
+/// assert_eq!(u64::fibonacci(93), 12200160415121876738);
+/// ```
+///
+/// # fibonacci2
+/// ```
+/// use malachite_base::num::arithmetic::traits::Fibonacci;
+///
+/// assert_eq!(u8::fibonacci2(0), (0, 1));
+/// assert_eq!(u8::fibonacci2(1), (1, 0));
+/// assert_eq!(u8::fibonacci2(10), (55, 34));
+/// assert_eq!(u32::fibonacci2(47), (2971215073, 1836311903));
+/// ```
+///
+/// # checked_fibonacci
+/// ```
+/// use malachite_base::num::arithmetic::traits::CheckedFibonacci;
+///
+/// assert_eq!(u8::checked_fibonacci(0), Some(0));
+/// assert_eq!(u8::checked_fibonacci(10), Some(55));
+/// assert_eq!(u8::checked_fibonacci(13), Some(233));
+/// assert_eq!(u8::checked_fibonacci(14), None);
+/// assert_eq!(u64::checked_fibonacci(93), Some(12200160415121876738));
+/// assert_eq!(u64::checked_fibonacci(94), None);
+/// ```
+///
+/// # checked_fibonacci2
+/// ```
+/// use malachite_base::num::arithmetic::traits::CheckedFibonacci;
+///
+/// assert_eq!(u8::checked_fibonacci2(0), Some((0, 1)));
+/// assert_eq!(u8::checked_fibonacci2(13), Some((233, 144)));
+/// assert_eq!(u8::checked_fibonacci2(14), None);
+/// ```
+///
+/// # lucas
+/// ```
+/// use malachite_base::num::arithmetic::traits::Lucas;
+///
+/// assert_eq!(u8::lucas(0), 2);
+/// assert_eq!(u8::lucas(1), 1);
+/// assert_eq!(u8::lucas(2), 3);
+/// assert_eq!(u8::lucas(10), 123);
+/// assert_eq!(u64::lucas(92), 16860207025497407047);
+/// ```
+///
+/// # checked_lucas
+/// ```
+/// use malachite_base::num::arithmetic::traits::CheckedLucas;
+///
+/// assert_eq!(u8::checked_lucas(0), Some(2));
+/// assert_eq!(u8::checked_lucas(11), Some(199));
+/// assert_eq!(u8::checked_lucas(12), None);
+/// assert_eq!(u64::checked_lucas(92), Some(16860207025497407047));
+/// assert_eq!(u64::checked_lucas(93), None);
+/// ```
+pub mod fibonacci;
 /// [`Floor`](traits::Floor) and [`FloorAssign`](traits::FloorAssign), traits for computing the
 /// floor of a number.
 ///
@@ -936,6 +1007,36 @@ pub mod floor;
 /// assert_eq!(x, 6);
 /// ```
 pub mod gcd;
+/// [`IsPerfectPower`](traits::IsPerfectPower) and [`ExpressAsPower`](traits::ExpressAsPower),
+/// traits for determining whether a number is a perfect power and for expressing it as one.
+///
+/// # is_perfect_power
+/// ```
+/// use malachite_base::num::arithmetic::traits::IsPerfectPower;
+///
+/// assert_eq!(0u8.is_perfect_power(), true);
+/// assert_eq!(1u8.is_perfect_power(), true);
+/// assert_eq!(6u16.is_perfect_power(), false);
+/// assert_eq!(243u32.is_perfect_power(), true);
+/// assert_eq!(1000u64.is_perfect_power(), true);
+/// assert_eq!((-8i8).is_perfect_power(), true);
+/// assert_eq!((-16i32).is_perfect_power(), false);
+/// ```
+///
+/// # express_as_power
+/// ```
+/// use malachite_base::num::arithmetic::traits::ExpressAsPower;
+///
+/// assert_eq!(0u8.express_as_power(), Some((0, 2)));
+/// assert_eq!(1u8.express_as_power(), Some((1, 2)));
+/// assert_eq!(6u16.express_as_power(), None);
+/// assert_eq!(64u32.express_as_power(), Some((2, 6)));
+/// assert_eq!(1000u64.express_as_power(), Some((10, 3)));
+/// assert_eq!((-1i8).express_as_power(), Some((-1, 3)));
+/// assert_eq!((-64i16).express_as_power(), Some((-4, 3)));
+/// assert_eq!((-16i32).express_as_power(), None);
+/// ```
+pub mod is_perfect_power;
 /// [`IsPowerOf2`](traits::IsPowerOf2), a trait for determining whether a number is an integer power
 /// of 2.
 ///
@@ -949,6 +1050,20 @@ pub mod gcd;
 /// assert_eq!((-4.0).is_power_of_2(), false);
 /// ```
 pub mod is_power_of_2;
+/// [`IsSquare`](traits::IsSquare), a trait for determining whether a number is a perfect square.
+///
+/// # is_square
+/// ```
+/// use malachite_base::num::arithmetic::traits::IsSquare;
+///
+/// assert_eq!(0u8.is_square(), true);
+/// assert_eq!(1u16.is_square(), true);
+/// assert_eq!(99u32.is_square(), false);
+/// assert_eq!(100u64.is_square(), true);
+/// assert_eq!((-4i32).is_square(), false);
+/// assert_eq!(u64::MAX.is_square(), false);
+/// ```
+pub mod is_square;
 /// [`LegendreSymbol`](traits::LegendreSymbol), [`JacobiSymbol`](traits::JacobiSymbol), and
 /// [`KroneckerSymbol`](traits::KroneckerSymbol), traits for computing the Legendre, Jacobi, and
 /// Kronecker symbols of two numbers.
@@ -1173,6 +1288,28 @@ pub mod log_base_power_of_2;
 /// assert_eq!(n, 2);
 /// ```
 pub mod mod_add;
+/// [`ModDiscreteLog`](traits::ModDiscreteLog), a trait for finding discrete logarithms modulo a
+/// number.
+///
+/// # mod_discrete_log
+/// ```
+/// use malachite_base::num::arithmetic::traits::ModDiscreteLog;
+///
+/// assert_eq!(3u8.mod_discrete_log(6, 7), Some(3));
+/// assert_eq!(3u8.mod_discrete_log(1, 7), Some(0));
+/// // 2 generates the subgroup {1, 2, 4}
+/// assert_eq!(2u8.mod_discrete_log(3, 7), None);
+/// // The base does not need to be coprime to the modulus
+/// assert_eq!(2u8.mod_discrete_log(0, 8), Some(3));
+/// assert_eq!(12u8.mod_discrete_log(0, 72), Some(2));
+/// assert_eq!(4u8.mod_discrete_log(2, 6), None);
+/// assert_eq!(2u32.mod_discrete_log(123456789, 4294967291), Some(3676225943));
+/// assert_eq!(
+///     5u64.mod_discrete_log(12345678901234567, 18446744073709551557),
+///     Some(7356342371669930477)
+/// );
+/// ```
+pub mod mod_discrete_log;
 /// [`ModInverse`](traits::ModInverse), a trait for finding the multiplicative inverse of a number
 /// modulo another number.
 ///
@@ -1197,6 +1334,21 @@ pub mod mod_inverse;
 /// assert_eq!(100u16.mod_is_reduced(&101), true);
 /// ```
 pub mod mod_is_reduced;
+/// [`ModLucasSequence`](traits::ModLucasSequence), a trait for computing terms of the Lucas
+/// sequences $U(P, Q)$ and $V(P, Q)$ modulo a number.
+///
+/// # mod_lucas_sequence
+/// ```
+/// use malachite_base::num::arithmetic::traits::ModLucasSequence;
+///
+/// // The Fibonacci and Lucas numbers are $U(1, -1)$ and $V(1, -1)$
+/// assert_eq!(1u32.mod_lucas_sequence(999, 10, 1000), (55, 123));
+/// // $U(3, 2)_k = 2^k - 1$ and $V(3, 2)_k = 2^k + 1$
+/// assert_eq!(3u16.mod_lucas_sequence(2, 10, 1000), (23, 25));
+/// assert_eq!(5u8.mod_lucas_sequence(3, 17, 64), (17, 43));
+/// assert_eq!(4u64.mod_lucas_sequence(1, 100, 1009), (219, 820));
+/// ```
+pub mod mod_lucas_sequence;
 /// Traits for multiplying two numbers modulo another number.
 ///
 /// The traits are [`ModMul`](traits::ModMul), [`ModMulAssign`](traits::ModMulAssign),
@@ -2018,6 +2170,29 @@ pub mod mod_shl;
 /// assert_eq!(n, 2);
 /// ```
 pub mod mod_shr;
+/// [`ModSqrt`](traits::ModSqrt) and [`ModPrimePowerSqrt`](traits::ModPrimePowerSqrt), traits for
+/// finding square roots modulo a prime or a prime power.
+///
+/// # mod_sqrt
+/// ```
+/// use malachite_base::num::arithmetic::traits::ModSqrt;
+///
+/// assert_eq!(2u8.mod_sqrt(7), Some(3));
+/// assert_eq!(3u8.mod_sqrt(7), None);
+/// assert_eq!(10u32.mod_sqrt(13), Some(6));
+/// assert_eq!(2u64.mod_sqrt(1000000007), Some(59713600));
+/// ```
+///
+/// # mod_prime_power_sqrt
+/// ```
+/// use malachite_base::num::arithmetic::traits::ModPrimePowerSqrt;
+///
+/// assert_eq!(2u16.mod_prime_power_sqrt(7, 3), Some(108));
+/// assert_eq!(17u8.mod_prime_power_sqrt(2, 5), Some(7));
+/// assert_eq!(9u8.mod_prime_power_sqrt(3, 3), Some(3));
+/// assert_eq!(3u8.mod_prime_power_sqrt(3, 3), None);
+/// ```
+pub mod mod_sqrt;
 /// Traits for squaring a number modulo another number.
 ///
 /// The traits are [`ModSquare`](traits::ModSquare), [`ModSquareAssign`](traits::ModSquareAssign),
@@ -0,0 +1,241 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::num::arithmetic::mod_sqrt::mod_pow_same_type;
+use crate::num::arithmetic::traits::ModDiscreteLog;
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::factorization::factor::Factors;
+use crate::num::factorization::traits::{CarmichaelLambda, Factor};
+use alloc::vec::Vec;
+
+// Subgroups whose prime order has at most this many bits are searched using baby-step giant-step;
+// larger ones use Pollard's rho algorithm, which needs no table.
+const BSGS_MAX_BITS: u64 = 32;
+
+// Pollard's rho algorithm restarts from a new starting point when a collision gives no information.
+// If $h$ lies in the subgroup generated by $\gamma$, this happens with probability about $1/q$, so
+// after this many restarts $h$ is taken to lie outside the subgroup.
+const RHO_MAX_ATTEMPTS: u64 = 8;
+
+// Finds $x$ such that $\gamma^x \equiv h \mod m$, where $\gamma$ generates a subgroup of prime
+// order $q$, using the baby-step giant-step algorithm.
+fn subgroup_log_bsgs<T: PrimitiveUnsigned>(gamma: T, h: T, q: T, m: T) -> Option<T> {
+    let s = q.ceiling_sqrt();
+    let mut baby_steps = Vec::with_capacity(s.exact_into());
+    let mut x = T::ONE;
+    let mut j = T::ZERO;
+    while j < s {
+        baby_steps.push((x, j));
+        x.mod_mul_assign(gamma, m);
+        j += T::ONE;
+    }
+    baby_steps.sort_unstable();
+    // `x` is now $\gamma^s$.
+    let giant_step = x.mod_inverse(m).unwrap();
+    let mut y = h;
+    let mut i = T::ZERO;
+    while i < s {
+        if let Ok(k) = baby_steps.binary_search_by(|&(z, _)| z.cmp(&y)) {
+            return Some(i * s + baby_steps[k].1);
+        }
+        y.mod_mul_assign(giant_step, m);
+        i += T::ONE;
+    }
+    None
+}
+
+// Finds $x$ such that $\gamma^x \equiv h \mod m$, where $\gamma$ generates a subgroup of prime
+// order $q$, using Pollard's rho algorithm. Each walk element is kept as $\gamma^a h^b$, and a
+// collision between the tortoise and the hare yields a linear equation for $x$ modulo $q$. If $h$
+// does not lie in the subgroup, the result is either `None` or wrong, so it must be checked.
+fn subgroup_log_rho<T: PrimitiveUnsigned>(gamma: T, h: T, q: T, m: T) -> Option<T> {
+    let three = T::from(3u8);
+    let step = |(y, a, b): (T, T, T)| {
+        if y % three == T::ZERO {
+            (y.mod_mul(gamma, m), a.mod_add(T::ONE, q), b)
+        } else if y % three == T::ONE {
+            (y.mod_square(m), a.mod_add(a, q), b.mod_add(b, q))
+        } else {
+            (y.mod_mul(h, m), a, b.mod_add(T::ONE, q))
+        }
+    };
+    for a in 1..=RHO_MAX_ATTEMPTS {
+        let a = T::exact_from(a);
+        let start = (mod_pow_same_type(gamma, a, m).mod_mul(h, m), a, T::ONE);
+        let mut tortoise = step(start);
+        let mut hare = step(tortoise);
+        while tortoise.0 != hare.0 {
+            tortoise = step(tortoise);
+            hare = step(step(hare));
+        }
+        let (_, a_1, b_1) = tortoise;
+        let (_, a_2, b_2) = hare;
+        if b_1 != b_2 {
+            // $a_1 + b_1 x \equiv a_2 + b_2 x \mod q$.
+            return Some(
+                a_2.mod_sub(a_1, q)
+                    .mod_mul(b_1.mod_sub(b_2, q).mod_inverse(q).unwrap(), q),
+            );
+        }
+    }
+    None
+}
+
+fn subgroup_log<T: PrimitiveUnsigned>(gamma: T, h: T, q: T, m: T) -> Option<T> {
+    if q.significant_bits() <= BSGS_MAX_BITS {
+        subgroup_log_bsgs(gamma, h, q, m)
+    } else {
+        subgroup_log_rho(gamma, h, q, m)
+    }
+}
+
+// The Pohlig–Hellman algorithm. `base` and `target` are coprime to `m`. The order of `base` is
+// found by removing prime factors from $\lambda(m)$, and the logarithm is then computed modulo each
+// prime power dividing that order, one base-$q$ digit at a time, and combined using the Chinese
+// remainder theorem.
+//
+// When the group of units modulo `m` is not cyclic, `target` may have an order dividing that of
+// `base` without being a power of `base`, and then the subgroup logarithms may be wrong; so the
+// result is checked at the end.
+fn mod_discrete_log_coprime<
+    T: CarmichaelLambda + Factor<FACTORS = Factors<T>> + PrimitiveUnsigned,
+>(
+    base: T,
+    target: T,
+    m: T,
+) -> Option<T> {
+    let lambda = m.carmichael_lambda();
+    let mut order = lambda;
+    let mut order_factors = Vec::new();
+    for (q, e) in lambda.factor() {
+        let mut e = u64::from(e);
+        while e != 0 {
+            let reduced = order / q;
+            if mod_pow_same_type(base, reduced, m) != T::ONE {
+                break;
+            }
+            order = reduced;
+            e -= 1;
+        }
+        if e != 0 {
+            order_factors.push((q, e));
+        }
+    }
+    if mod_pow_same_type(target, order, m) != T::ONE {
+        return None;
+    }
+    let mut log = T::ZERO;
+    let mut log_modulus = T::ONE;
+    for (q, e) in order_factors {
+        let q_e = q.pow(e);
+        let cofactor = order / q_e;
+        let g = mod_pow_same_type(base, cofactor, m);
+        let h = mod_pow_same_type(target, cofactor, m);
+        let g_inverse = g.mod_inverse(m).unwrap();
+        let gamma = mod_pow_same_type(g, q_e / q, m);
+        let mut log_q = T::ZERO;
+        let mut q_i = T::ONE;
+        for _ in 0..e {
+            let h_i = mod_pow_same_type(
+                mod_pow_same_type(g_inverse, log_q, m).mod_mul(h, m),
+                q_e / q_i / q,
+                m,
+            );
+            log_q += subgroup_log(gamma, h_i, q, m)? * q_i;
+            q_i *= q;
+        }
+        let t = log_q
+            .mod_sub(log % q_e, q_e)
+            .mod_mul((log_modulus % q_e).mod_inverse(q_e).unwrap(), q_e);
+        log += log_modulus * t;
+        log_modulus *= q_e;
+    }
+    if mod_pow_same_type(base, log, m) == target {
+        Some(log)
+    } else {
+        None
+    }
+}
+
+fn mod_discrete_log<T: CarmichaelLambda + Factor<FACTORS = Factors<T>> + PrimitiveUnsigned>(
+    mut base: T,
+    mut target: T,
+    mut m: T,
+) -> Option<T> {
+    assert!(base < m, "base must be reduced mod m, but {base} >= {m}");
+    assert!(
+        target < m,
+        "target must be reduced mod m, but {target} >= {m}"
+    );
+    // While `base` shares a factor $g$ with `m`, a solution $k > 0$ of $cb^k \equiv x \mod m$ is a
+    // solution $k - 1$ of $c(b/g)b^{k-1} \equiv x/g \mod m/g$. Each step at least halves `m`, so
+    // there are few steps, and the solutions they skip are checked along the way.
+    let mut offset = T::ZERO;
+    let mut c = T::ONE % m;
+    loop {
+        if c == target {
+            return Some(offset);
+        }
+        let g = base.gcd(m);
+        if g == T::ONE {
+            break;
+        }
+        if !target.divisible_by(g) {
+            return None;
+        }
+        target /= g;
+        m /= g;
+        c = (c % m).mod_mul((base / g) % m, m);
+        base %= m;
+        offset += T::ONE;
+    }
+    // `c` is now coprime to `m`.
+    let target = target.mod_mul(c.mod_inverse(m).unwrap(), m);
+    Some(offset + mod_discrete_log_coprime(base, target, m)?)
+}
+
+macro_rules! impl_mod_discrete_log {
+    ($t:ident) => {
+        impl ModDiscreteLog<$t, $t> for $t {
+            type Output = $t;
+
+            /// Finds the discrete logarithm of a number with respect to a base, modulo another
+            /// number $m$. The base and the number must be already reduced modulo $m$.
+            ///
+            /// Returns the smallest $k$ such that $b^k \equiv x \mod m$, where $b$ is `self` and
+            /// $x$ is `target`, or `None` if there is no such $k$.
+            ///
+            /// The modulus does not need to be prime, and the base does not need to be coprime to
+            /// it. The Pohlig–Hellman algorithm is used, with baby-step giant-step or Pollard's
+            /// rho algorithm solving the logarithms in subgroups of prime order. The modulus and
+            /// the Carmichael function of the modulus are factored.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(2^{n/2})$
+            ///
+            /// $M(n) = O(2^{n/4})$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+            ///
+            /// # Panics
+            /// Panics if `self` or `target` are greater than or equal to `m`.
+            ///
+            /// # Examples
+            /// See [here](super::mod_discrete_log#mod_discrete_log).
+            #[inline]
+            fn mod_discrete_log(self, target: $t, m: $t) -> Option<$t> {
+                mod_discrete_log(self, target, m)
+            }
+        }
+    };
+}
+impl_mod_discrete_log!(u8);
+impl_mod_discrete_log!(u16);
+impl_mod_discrete_log!(u32);
+impl_mod_discrete_log!(u64);
+impl_mod_discrete_log!(usize);
@@ -0,0 +1,74 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::num::arithmetic::traits::ModLucasSequence;
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::logic::traits::{BitAccess, SignificantBits};
+
+fn mod_lucas_sequence<T: PrimitiveUnsigned>(p: T, q: T, k: u64, m: T) -> (T, T) {
+    assert!(p < m, "p must be reduced mod m, but {p} >= {m}");
+    assert!(q < m, "q must be reduced mod m, but {q} >= {m}");
+    let two = T::TWO % m;
+    // Maintain $a = U_j$ and $b = U_{j+1}$, where $j$ is the prefix of `k` processed so far. The
+    // doubling formulas $U_{2j} = U_j(2U_{j+1} - PU_j)$ and $U_{2j+1} = U_{j+1}^2 - QU_j^2$ avoid
+    // any division, so `m` may be even.
+    let mut a = T::ZERO;
+    let mut b = T::ONE % m;
+    for i in (0..k.significant_bits()).rev() {
+        let u_2j = a.mod_mul(two.mod_mul(b, m).mod_sub(p.mod_mul(a, m), m), m);
+        let u_2j_1 = b.mod_mul(b, m).mod_sub(q.mod_mul(a.mod_mul(a, m), m), m);
+        if k.get_bit(i) {
+            b = p.mod_mul(u_2j_1, m).mod_sub(q.mod_mul(u_2j, m), m);
+            a = u_2j_1;
+        } else {
+            a = u_2j;
+            b = u_2j_1;
+        }
+    }
+    // $V_k = 2U_{k+1} - PU_k$.
+    let v = two.mod_mul(b, m).mod_sub(p.mod_mul(a, m), m);
+    (a, v)
+}
+
+macro_rules! impl_mod_lucas_sequence {
+    ($t:ident) => {
+        impl ModLucasSequence<$t, u64, $t> for $t {
+            type Output = $t;
+
+            /// Computes the $k$th terms of the Lucas sequences $U(P, Q)$ and $V(P, Q)$ modulo $m$.
+            /// $P$ and $Q$ must be already reduced modulo $m$.
+            ///
+            /// The sequences are defined by $U_0 = 0$, $U_1 = 1$, $V_0 = 2$, $V_1 = P$, and
+            /// $X_n = PX_{n-1} - QX_{n-2}$. The Fibonacci and Lucas numbers are $U(1, -1)$ and
+            /// $V(1, -1)$.
+            ///
+            /// $f(P, Q, k, m) = (u, v)$, where $u, v < m$, $U_k \equiv u \mod m$, and $V_k \equiv
+            /// v \mod m$.
+            ///
+            /// The modulus does not need to be odd.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `k.significant_bits()`.
+            ///
+            /// # Panics
+            /// Panics if `self` or `q` are greater than or equal to `m`.
+            ///
+            /// # Examples
+            /// See [here](super::mod_lucas_sequence#mod_lucas_sequence).
+            #[inline]
+            fn mod_lucas_sequence(self, q: $t, k: u64, m: $t) -> ($t, $t) {
+                mod_lucas_sequence(self, q, k, m)
+            }
+        }
+    };
+}
+apply_to_unsigneds!(impl_mod_lucas_sequence);
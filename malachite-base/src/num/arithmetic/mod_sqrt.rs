@@ -0,0 +1,266 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::num::arithmetic::traits::{ModPrimePowerSqrt, ModSqrt, Parity};
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use core::cmp::min;
+
+// Computes $x^e \mod m$, where the exponent has the same type as the modulus. `x` must be reduced
+// modulo `m`, and `m` must be greater than 1.
+pub(crate) fn mod_pow_same_type<T: PrimitiveUnsigned>(mut x: T, mut e: T, m: T) -> T {
+    let mut result = T::ONE;
+    while e != T::ZERO {
+        if e.odd() {
+            result.mod_mul_assign(x, m);
+        }
+        x.mod_square_assign(m);
+        e >>= 1u32;
+    }
+    result
+}
+
+// Returns the smallest `z` in [2, p) such that the Legendre symbol (z / p) is -1, or `None` if
+// there is no such `z` (which can only happen if `p` is not prime).
+fn find_non_residue<T: PrimitiveUnsigned>(p: T) -> Option<T> {
+    let mut z = T::TWO;
+    while z < p {
+        if z.legendre_symbol(p) == -1 {
+            return Some(z);
+        }
+        z += T::ONE;
+    }
+    None
+}
+
+// The Tonelli–Shanks algorithm. `p` is an odd prime, $p - 1 = 2^s q$ with $q$ odd, and `x` is a
+// nonzero quadratic residue.
+fn mod_sqrt_tonelli_shanks<T: PrimitiveUnsigned>(x: T, p: T, s: u64, q: T) -> Option<T> {
+    let z = find_non_residue(p)?;
+    let mut c = mod_pow_same_type(z, q, p);
+    let mut r = mod_pow_same_type(x, (q >> 1u32) + T::ONE, p);
+    let mut t = mod_pow_same_type(x, q, p);
+    let mut m = s;
+    while t != T::ONE {
+        // Find the least i such that t^(2^i) = 1.
+        let mut i = 0;
+        let mut u = t;
+        while u != T::ONE {
+            u.mod_square_assign(p);
+            i += 1;
+            if i == m {
+                return None;
+            }
+        }
+        let mut b = c;
+        for _ in 0..m - i - 1 {
+            b.mod_square_assign(p);
+        }
+        r.mod_mul_assign(b, p);
+        c = b.mod_square(p);
+        t.mod_mul_assign(c, p);
+        m = i;
+    }
+    Some(r)
+}
+
+// Cipolla's algorithm. `p` is an odd prime and `x` is a nonzero quadratic residue. Finds `a` such
+// that $a^2 - x$ is a non-residue $w$, and computes $(a + \sqrt w)^{(p + 1) / 2}$ in
+// $\mathbb{F}_p(\sqrt w)$.
+fn mod_sqrt_cipolla<T: PrimitiveUnsigned>(x: T, p: T) -> Option<T> {
+    let mut a = T::ONE;
+    let w = loop {
+        if a == p {
+            return None;
+        }
+        let w = a.mod_square(p).mod_sub(x, p);
+        if w.legendre_symbol(p) == -1 {
+            break w;
+        }
+        a += T::ONE;
+    };
+    let mul = |(a0, a1): (T, T), (b0, b1): (T, T)| {
+        (
+            a0.mod_mul(b0, p)
+                .mod_add(a1.mod_mul(b1, p).mod_mul(w, p), p),
+            a0.mod_mul(b1, p).mod_add(a1.mod_mul(b0, p), p),
+        )
+    };
+    let mut result = (T::ONE, T::ZERO);
+    let mut base = (a, T::ONE);
+    let mut e = (p >> 1u32) + T::ONE;
+    while e != T::ZERO {
+        if e.odd() {
+            result = mul(result, base);
+        }
+        base = mul(base, base);
+        e >>= 1u32;
+    }
+    Some(result.0)
+}
+
+fn mod_sqrt<T: PrimitiveUnsigned>(x: T, p: T) -> Option<T> {
+    assert!(x < p, "x must be reduced mod p, but {x} >= {p}");
+    if x == T::ZERO || p == T::TWO {
+        return Some(x);
+    }
+    if p.even() || x.legendre_symbol(p) != 1 {
+        return None;
+    }
+    let s = (p - T::ONE).trailing_zeros();
+    let r = if s == 1 {
+        mod_pow_same_type(x, (p >> 2u32) + T::ONE, p)
+    } else if s * s > p.significant_bits() << 3 {
+        // When $p - 1$ is divisible by a large power of 2, Tonelli–Shanks takes $O(s^2)$
+        // multiplications, but Cipolla's algorithm still takes $O(\log p)$.
+        mod_sqrt_cipolla(x, p)?
+    } else {
+        mod_sqrt_tonelli_shanks(x, p, s, (p - T::ONE) >> s)?
+    };
+    // If p is not prime, r may not be a square root.
+    if r.mod_square(p) == x {
+        Some(min(r, p - r))
+    } else {
+        None
+    }
+}
+
+// Returns the smallest square root of `u` modulo $p^k$, where `u` is not divisible by $p$ and
+// `pk` is $p^k$.
+fn mod_prime_power_sqrt_unit<T: PrimitiveUnsigned>(u: T, p: T, k: u64, pk: T) -> Option<T> {
+    if p == T::TWO {
+        return match k {
+            1 => Some(T::ONE),
+            2 => (u.mod_power_of_2(2) == T::ONE).then_some(T::ONE),
+            _ => {
+                if u.mod_power_of_2(3) != T::ONE {
+                    return None;
+                }
+                // Each step extends s^2 = u mod 2^j to s^2 = u mod 2^(j + 1).
+                let mut s = T::ONE;
+                for j in 3..k {
+                    if s.wrapping_square().get_bit(j) != u.get_bit(j) {
+                        s.set_bit(j - 1);
+                    }
+                }
+                // The square roots are ±s and ±s + 2^(k - 1), so the smallest is the smaller of
+                // s and -s modulo 2^(k - 1).
+                let s = s.mod_power_of_2(k - 1);
+                Some(min(s, T::power_of_2(k - 1) - s))
+            }
+        };
+    }
+    let mut s = mod_sqrt(u % p, p)?;
+    // Newton's method doubles the precision at each step: if s^2 = u mod p^j, then s - (s^2 - u) /
+    // (2s) is a square root of u modulo p^(2j).
+    let mut j = 1;
+    let mut pj = p;
+    while j < k {
+        let new_j = min(j << 1, k);
+        pj = if new_j == k { pk } else { pj * pj };
+        j = new_j;
+        let u = u % pj;
+        let f = s.mod_square(pj).mod_sub(u, pj);
+        let d = s.mod_add(s, pj).mod_inverse(pj)?;
+        s.mod_sub_assign(f.mod_mul(d, pj), pj);
+    }
+    Some(min(s, pk - s))
+}
+
+fn mod_prime_power_sqrt<T: PrimitiveUnsigned>(x: T, p: T, pow: u64) -> Option<T> {
+    let pk = p.checked_pow(pow).unwrap();
+    assert!(x < pk, "x must be reduced mod p^pow, but {x} >= {pk}");
+    if x == T::ZERO {
+        return Some(T::ZERO);
+    }
+    // Write x as p^v * u, with u not divisible by p. Since x < p^pow, v < pow.
+    let mut u = x;
+    let mut v: u64 = 0;
+    let mut pv = T::ONE;
+    while (u % p) == T::ZERO {
+        u /= p;
+        v += 1;
+        pv *= p;
+    }
+    if v.odd() {
+        return None;
+    }
+    // If s is a square root of u modulo p^(pow - v), then p^(v / 2) * s is a square root of x
+    // modulo p^pow, and every square root of x has this form.
+    let s = mod_prime_power_sqrt_unit(u, p, pow - v, pk / pv)?;
+    Some(p.pow(v >> 1) * s)
+}
+
+macro_rules! impl_mod_sqrt {
+    ($t:ident) => {
+        impl ModSqrt<$t> for $t {
+            type Output = $t;
+
+            /// Finds the smallest square root of a number modulo a prime $p$. The input must be
+            /// already reduced modulo $p$.
+            ///
+            /// Returns `None` if $x$ is not a square modulo $p$. The modulus is assumed to be
+            /// prime; if it isn't, the result is either `None` or a square root of $x$.
+            ///
+            /// $f(x, p) = \min \\{y : 0 \leq y < p, y^2 \equiv x \mod p\\}$.
+            ///
+            /// The Tonelli–Shanks algorithm is used, unless $p - 1$ is divisible by a large power
+            /// of 2, in which case Cipolla's algorithm is used.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n^2)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `p.significant_bits()`.
+            ///
+            /// # Panics
+            /// Panics if `self` is greater than or equal to `p`.
+            ///
+            /// # Examples
+            /// See [here](super::mod_sqrt#mod_sqrt).
+            #[inline]
+            fn mod_sqrt(self, p: $t) -> Option<$t> {
+                mod_sqrt(self, p)
+            }
+        }
+
+        impl ModPrimePowerSqrt<$t> for $t {
+            type Output = $t;
+
+            /// Finds the smallest square root of a number modulo $p^k$, where $p$ is prime. The
+            /// input must be already reduced modulo $p^k$.
+            ///
+            /// Returns `None` if $x$ is not a square modulo $p^k$. The modulus is assumed to be a
+            /// prime power; if it isn't, the result is unspecified.
+            ///
+            /// $f(x, p, k) = \min \\{y : 0 \leq y < p^k, y^2 \equiv x \mod p^k\\}$.
+            ///
+            /// A square root modulo $p$ is found using [`ModSqrt`] and then lifted to a root
+            /// modulo $p^k$ using Hensel's lemma.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n^2)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `T::WIDTH`.
+            ///
+            /// # Panics
+            /// Panics if $p^k$ is not representable, or if `self` is greater than or equal to
+            /// $p^k$.
+            ///
+            /// # Examples
+            /// See [here](super::mod_sqrt#mod_prime_power_sqrt).
+            #[inline]
+            fn mod_prime_power_sqrt(self, p: $t, pow: u64) -> Option<$t> {
+                mod_prime_power_sqrt(self, p, pow)
+            }
+        }
+    };
+}
+apply_to_unsigneds!(impl_mod_sqrt);
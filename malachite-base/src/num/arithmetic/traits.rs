@@ -304,6 +304,12 @@ pub trait DivisibleBy<RHS = Self> {
     fn divisible_by(self, other: RHS) -> bool;
 }
 
+/// Expresses a number as a perfect power $y^k$, with $k \geq 2$ as large as possible, if it is
+/// one.
+pub trait ExpressAsPower: Sized {
+    fn express_as_power(&self) -> Option<(Self, u64)>;
+}
+
 /// Determines whether a number is equivalent to another number modulo $2^k$.
 pub trait EqModPowerOf2<RHS = Self> {
     fn eq_mod_power_of_2(self, other: RHS, pow: u64) -> bool;
@@ -386,6 +392,89 @@ pub trait CheckedSubfactorial: Sized {
     fn checked_subfactorial(n: u64) -> Option<Self>;
 }
 
+/// Computes the $n$th Fibonacci number, or the $n$th and $(n-1)$th Fibonacci numbers together.
+pub trait Fibonacci: Sized {
+    fn fibonacci(n: u64) -> Self;
+
+    fn fibonacci2(n: u64) -> (Self, Self);
+}
+
+/// Computes the $n$th Fibonacci number, or the $n$th and $(n-1)$th Fibonacci numbers together,
+/// returning `None` if the result is too large to be represented.
+pub trait CheckedFibonacci: Sized {
+    fn checked_fibonacci(n: u64) -> Option<Self>;
+
+    fn checked_fibonacci2(n: u64) -> Option<(Self, Self)>;
+}
+
+/// Computes the $n$th Lucas number.
+pub trait Lucas {
+    fn lucas(n: u64) -> Self;
+}
+
+/// Computes the $n$th Lucas number, returning `None` if the result is too large to be represented.
+pub trait CheckedLucas: Sized {
+    fn checked_lucas(n: u64) -> Option<Self>;
+}
+
+/// Computes the $n$th Catalan number, $C_n = \frac{1}{n+1}\binom{2n}{n}$.
+pub trait Catalan {
+    fn catalan(n: u64) -> Self;
+}
+
+/// Computes the $n$th Bell number, which counts the partitions of a set of $n$ elements.
+pub trait Bell {
+    fn bell(n: u64) -> Self;
+}
+
+/// Computes Stirling numbers of the first and second kinds.
+///
+/// The unsigned Stirling number of the first kind, $\left[{n \atop k}\right]$, counts the
+/// permutations of $n$ elements with exactly $k$ cycles. The Stirling number of the second kind,
+/// $\left\\{{n \atop k}\right\\}$, counts the partitions of a set of $n$ elements into exactly $k$
+/// nonempty subsets.
+pub trait Stirling {
+    fn stirling1(n: u64, k: u64) -> Self;
+
+    fn stirling2(n: u64, k: u64) -> Self;
+}
+
+/// Computes the number of partitions of $n$; that is, the number of ways of writing $n$ as a sum
+/// of positive integers, disregarding order.
+pub trait Partitions {
+    fn partitions(n: u64) -> Self;
+}
+
+/// Computes the $n$th Euler number $E_n$, the coefficient of $x^n/n!$ in the Taylor series of
+/// $\operatorname{sech} x$.
+pub trait EulerNumber {
+    fn euler_number(n: u64) -> Self;
+}
+
+/// Writes a number as a sum of two squares, if possible.
+///
+/// A number is a sum of two squares if and only if every prime congruent to 3 mod 4 divides it to
+/// an even power. The `_from_factorization` function takes the prime factorization of $n$ instead
+/// of $n$ itself, as a list of distinct primes, each paired with its exponent.
+pub trait TwoSquares: Sized {
+    fn two_squares(&self) -> Option<(Self, Self)>;
+
+    fn two_squares_from_factorization(factors: &[(Self, u64)]) -> Option<(Self, Self)>;
+}
+
+/// Writes a number as a sum of four squares. By Lagrange's four-square theorem, this is always
+/// possible.
+pub trait FourSquares: Sized {
+    fn four_squares(&self) -> (Self, Self, Self, Self);
+}
+
+/// Finds a primitive solution $(x, y)$ of $x^2 + dy^2 = m$, using Cornacchia's algorithm.
+pub trait Cornacchia<M = Self> {
+    type Output;
+
+    fn cornacchia(self, m: M) -> Option<(Self::Output, Self::Output)>;
+}
+
 /// Takes the floor of a number.
 pub trait Floor {
     type Output;
@@ -415,6 +504,17 @@ pub trait IsPowerOf2 {
     fn is_power_of_2(&self) -> bool;
 }
 
+/// Determines whether a number is a perfect power; that is, whether it is equal to $y^k$ for some
+/// integer $y$ and some integer $k \geq 2$.
+pub trait IsPerfectPower {
+    fn is_perfect_power(&self) -> bool;
+}
+
+/// Determines whether a number is a perfect square.
+pub trait IsSquare {
+    fn is_square(&self) -> bool;
+}
+
 /// Calculates the LCM (least common multiple) of two numbers.
 pub trait Lcm<RHS = Self> {
     type Output;
@@ -537,6 +637,15 @@ pub trait ModAddAssign<RHS = Self, M = Self> {
     fn mod_add_assign(&mut self, other: RHS, m: M);
 }
 
+/// Finds the discrete logarithm of a number with respect to a base, modulo another number $m$;
+/// that is, the smallest $k$ such that $b^k \equiv x \mod m$. The base and the number must be
+/// already reduced modulo $m$.
+pub trait ModDiscreteLog<T = Self, M = Self> {
+    type Output;
+
+    fn mod_discrete_log(self, target: T, m: M) -> Option<Self::Output>;
+}
+
 /// Finds the multiplicative inverse of a number modulo another number $m$. The input must be
 /// already reduced modulo $m$.
 pub trait ModInverse<M = Self> {
@@ -661,6 +770,14 @@ pub trait CeilingModAssign<RHS = Self> {
     fn ceiling_mod_assign(&mut self, other: RHS);
 }
 
+/// Computes the $k$th terms of the Lucas sequences $U(P, Q)$ and $V(P, Q)$ modulo another number
+/// $m$. $P$ and $Q$ must be already reduced modulo $m$.
+pub trait ModLucasSequence<Q = Self, K = Self, M = Self> {
+    type Output;
+
+    fn mod_lucas_sequence(self, q: Q, k: K, m: M) -> (Self::Output, Self::Output);
+}
+
 /// Raises a number to a power modulo another number $m$. The base must be already reduced modulo
 /// $m$.
 pub trait ModPow<RHS = Self, M = Self> {
@@ -894,6 +1011,14 @@ pub trait CeilingModPowerOf2Assign {
     fn ceiling_mod_power_of_2_assign(&mut self, other: u64);
 }
 
+/// Finds a square root of a number modulo $p^k$, where $p$ is prime. The input must be already
+/// reduced modulo $p^k$.
+pub trait ModPrimePowerSqrt<P = Self> {
+    type Output;
+
+    fn mod_prime_power_sqrt(self, p: P, pow: u64) -> Option<Self::Output>;
+}
+
 /// Left-shifts a number (multiplies it by a power of 2) modulo another number $m$. The number must
 /// be already reduced modulo $m$.
 pub trait ModShl<RHS, M = Self> {
@@ -922,6 +1047,14 @@ pub trait ModShrAssign<RHS, M = Self> {
     fn mod_shr_assign(&mut self, other: RHS, m: M);
 }
 
+/// Finds a square root of a number modulo a prime $p$. The input must be already reduced modulo
+/// $p$.
+pub trait ModSqrt<M = Self> {
+    type Output;
+
+    fn mod_sqrt(self, m: M) -> Option<Self::Output>;
+}
+
 /// Squares a number modulo another number $m$. The input must be already reduced modulo $m$.
 pub trait ModSquare<M = Self> {
     type Output;
@@ -14,18 +14,18 @@ use crate::num::arithmetic::traits::{
     CheckedBinomialCoefficient, CheckedDiv, CheckedMul, CheckedNeg, CheckedPow, CheckedRoot,
     CheckedSqrt, CheckedSquare, CheckedSub, CheckedSubMul, DivAssignMod, DivAssignRem, DivExact,
     DivExactAssign, DivMod, DivRem, DivRound, DivRoundAssign, DivisibleBy, DivisibleByPowerOf2,
-    EqMod, EqModPowerOf2, ExtendedGcd, FloorRoot, FloorRootAssign, FloorSqrt, FloorSqrtAssign,
-    JacobiSymbol, KroneckerSymbol, LegendreSymbol, Mod, ModAssign, ModPowerOf2, ModPowerOf2Assign,
-    OverflowingAdd, OverflowingAddAssign, OverflowingAddMul, OverflowingAddMulAssign,
-    OverflowingDiv, OverflowingDivAssign, OverflowingMul, OverflowingMulAssign, OverflowingNeg,
-    OverflowingNegAssign, OverflowingPow, OverflowingPowAssign, OverflowingSquare,
-    OverflowingSquareAssign, OverflowingSub, OverflowingSubAssign, OverflowingSubMul,
-    OverflowingSubMulAssign, Parity, Pow, PowAssign, PowerOf2, RemPowerOf2, RemPowerOf2Assign,
-    RotateLeft, RotateLeftAssign, RotateRight, RotateRightAssign, RoundToMultiple,
-    RoundToMultipleAssign, RoundToMultipleOfPowerOf2, RoundToMultipleOfPowerOf2Assign,
-    SaturatingAdd, SaturatingAddAssign, SaturatingAddMul, SaturatingAddMulAssign, SaturatingMul,
-    SaturatingMulAssign, SaturatingPow, SaturatingPowAssign, SaturatingSquare,
-    SaturatingSquareAssign, SaturatingSub, SaturatingSubAssign, SaturatingSubMul,
+    EqMod, EqModPowerOf2, ExpressAsPower, ExtendedGcd, FloorRoot, FloorRootAssign, FloorSqrt,
+    FloorSqrtAssign, IsPerfectPower, IsSquare, JacobiSymbol, KroneckerSymbol, LegendreSymbol, Mod,
+    ModAssign, ModPowerOf2, ModPowerOf2Assign, OverflowingAdd, OverflowingAddAssign,
+    OverflowingAddMul, OverflowingAddMulAssign, OverflowingDiv, OverflowingDivAssign,
+    OverflowingMul, OverflowingMulAssign, OverflowingNeg, OverflowingNegAssign, OverflowingPow,
+    OverflowingPowAssign, OverflowingSquare, OverflowingSquareAssign, OverflowingSub,
+    OverflowingSubAssign, OverflowingSubMul, OverflowingSubMulAssign, Parity, Pow, PowAssign,
+    PowerOf2, RemPowerOf2, RemPowerOf2Assign, RotateLeft, RotateLeftAssign, RotateRight,
+    RotateRightAssign, RoundToMultiple, RoundToMultipleAssign, RoundToMultipleOfPowerOf2,
+    RoundToMultipleOfPowerOf2Assign, SaturatingAdd, SaturatingAddAssign, SaturatingAddMul,
+    SaturatingAddMulAssign, SaturatingMul, SaturatingMulAssign, SaturatingPow, SaturatingPowAssign,
+    SaturatingSquare, SaturatingSquareAssign, SaturatingSub, SaturatingSubAssign, SaturatingSubMul,
     SaturatingSubMulAssign, ShlRound, ShlRoundAssign, ShrRound, ShrRoundAssign, Sign, Square,
     SquareAssign, SubMul, SubMulAssign, WrappingAdd, WrappingAddAssign, WrappingAddMul,
     WrappingAddMulAssign, WrappingDiv, WrappingDivAssign, WrappingMul, WrappingMulAssign,
@@ -189,6 +189,7 @@ pub trait PrimitiveInt:
     + ExactInto<u64>
     + ExactInto<u8>
     + ExactInto<usize>
+    + ExpressAsPower
     + ExtendedGcd<Self>
     + FloorRoot<u64, Output = Self>
     + FloorRootAssign<u64>
@@ -201,6 +202,8 @@ pub trait PrimitiveInt:
     + HasRandomPrimitiveInts
     + Hash
     + IsInteger
+    + IsPerfectPower
+    + IsSquare
     + JacobiSymbol<Self>
     + KroneckerSymbol<Self>
     + LeadingZeros
@@ -666,6 +669,7 @@ pub trait PrimitiveInt:
     + ExactInto<u64>
     + ExactInto<u8>
     + ExactInto<usize>
+    + ExpressAsPower
     + ExtendedGcd<Self>
     + FloorRoot<u64, Output = Self>
     + FloorRootAssign<u64>
@@ -677,6 +681,8 @@ pub trait PrimitiveInt:
     + FromStringBase
     + Hash
     + IsInteger
+    + IsPerfectPower
+    + IsSquare
     + JacobiSymbol<Self>
     + KroneckerSymbol<Self>
     + LeadingZeros
@@ -8,22 +8,23 @@
 
 use crate::num::arithmetic::traits::{
     AbsDiff, AbsDiffAssign, CeilingDivAssignNegMod, CeilingDivNegMod, CeilingLogBase,
-    CeilingLogBase2, CeilingLogBasePowerOf2, CheckedDoubleFactorial, CheckedFactorial, CheckedLcm,
-    CheckedLogBase, CheckedLogBase2, CheckedLogBasePowerOf2, CheckedMultifactorial,
-    CheckedNextPowerOf2, CheckedPrimorial, CheckedSubfactorial, CoprimeWith, DoubleFactorial,
-    ExtendedGcd, Factorial, FloorLogBase, FloorLogBase2, FloorLogBasePowerOf2, Gcd, GcdAssign,
-    IsPowerOf2, Lcm, LcmAssign, ModAdd, ModAddAssign, ModInverse, ModIsReduced, ModMul,
-    ModMulAssign, ModMulPrecomputed, ModMulPrecomputedAssign, ModNeg, ModNegAssign, ModPow,
-    ModPowAssign, ModPowPrecomputed, ModPowPrecomputedAssign, ModPowerOf2, ModPowerOf2Add,
-    ModPowerOf2AddAssign, ModPowerOf2Inverse, ModPowerOf2IsReduced, ModPowerOf2Mul,
-    ModPowerOf2MulAssign, ModPowerOf2Neg, ModPowerOf2NegAssign, ModPowerOf2Pow,
-    ModPowerOf2PowAssign, ModPowerOf2Shl, ModPowerOf2ShlAssign, ModPowerOf2Shr,
-    ModPowerOf2ShrAssign, ModPowerOf2Square, ModPowerOf2SquareAssign, ModPowerOf2Sub,
-    ModPowerOf2SubAssign, ModSquare, ModSquareAssign, ModSquarePrecomputed,
-    ModSquarePrecomputedAssign, ModSub, ModSubAssign, Multifactorial, NegMod, NegModAssign,
-    NegModPowerOf2, NegModPowerOf2Assign, NextPowerOf2, NextPowerOf2Assign, Primorial,
-    RootAssignRem, RootRem, SqrtAssignRem, SqrtRem, Subfactorial, XMulYToZZ, XXAddYYToZZ,
-    XXDivModYToQR, XXSubYYToZZ, XXXAddYYYToZZZ, XXXSubYYYToZZZ, XXXXAddYYYYToZZZZ,
+    CeilingLogBase2, CeilingLogBasePowerOf2, CheckedDoubleFactorial, CheckedFactorial,
+    CheckedFibonacci, CheckedLcm, CheckedLogBase, CheckedLogBase2, CheckedLogBasePowerOf2,
+    CheckedLucas, CheckedMultifactorial, CheckedNextPowerOf2, CheckedPrimorial,
+    CheckedSubfactorial, CoprimeWith, DoubleFactorial, ExtendedGcd, Factorial, Fibonacci,
+    FloorLogBase, FloorLogBase2, FloorLogBasePowerOf2, Gcd, GcdAssign, IsPowerOf2, Lcm, LcmAssign,
+    Lucas, ModAdd, ModAddAssign, ModInverse, ModIsReduced, ModLucasSequence, ModMul, ModMulAssign,
+    ModMulPrecomputed, ModMulPrecomputedAssign, ModNeg, ModNegAssign, ModPow, ModPowAssign,
+    ModPowPrecomputed, ModPowPrecomputedAssign, ModPowerOf2, ModPowerOf2Add, ModPowerOf2AddAssign,
+    ModPowerOf2Inverse, ModPowerOf2IsReduced, ModPowerOf2Mul, ModPowerOf2MulAssign, ModPowerOf2Neg,
+    ModPowerOf2NegAssign, ModPowerOf2Pow, ModPowerOf2PowAssign, ModPowerOf2Shl,
+    ModPowerOf2ShlAssign, ModPowerOf2Shr, ModPowerOf2ShrAssign, ModPowerOf2Square,
+    ModPowerOf2SquareAssign, ModPowerOf2Sub, ModPowerOf2SubAssign, ModPrimePowerSqrt, ModSqrt,
+    ModSquare, ModSquareAssign, ModSquarePrecomputed, ModSquarePrecomputedAssign, ModSub,
+    ModSubAssign, Multifactorial, NegMod, NegModAssign, NegModPowerOf2, NegModPowerOf2Assign,
+    NextPowerOf2, NextPowerOf2Assign, Primorial, RootAssignRem, RootRem, SqrtAssignRem, SqrtRem,
+    Subfactorial, XMulYToZZ, XXAddYYToZZ, XXDivModYToQR, XXSubYYToZZ, XXXAddYYYToZZZ,
+    XXXSubYYYToZZZ, XXXXAddYYYYToZZZZ,
 };
 use crate::num::basic::integers::PrimitiveInt;
 use crate::num::conversion::traits::{
@@ -46,6 +47,7 @@ pub trait PrimitiveUnsigned:
     + CeilingDivNegMod<Self, DivOutput = Self, ModOutput = Self>
     + CheckedDoubleFactorial
     + CheckedFactorial
+    + CheckedFibonacci
     + CheckedMultifactorial
     + CheckedPrimorial
     + CheckedSubfactorial
@@ -53,6 +55,7 @@ pub trait PrimitiveUnsigned:
     + CheckedLogBase<Output = u64>
     + CheckedLogBase2<Output = u64>
     + CheckedLogBasePowerOf2<u64, Output = u64>
+    + CheckedLucas
     + CheckedNextPowerOf2<Output = Self>
     + CoprimeWith<Self>
     + DoubleFactorial
@@ -64,6 +67,7 @@ pub trait PrimitiveUnsigned:
     + Digits<usize>
     + ExtendedGcd<Self, Gcd = Self>
     + Factorial
+    + Fibonacci
     + FloorLogBase<Output = u64>
     + FloorLogBase2<Output = u64>
     + FloorLogBasePowerOf2<u64, Output = u64>
@@ -81,10 +85,12 @@ pub trait PrimitiveUnsigned:
     + IsPowerOf2
     + Lcm<Self, Output = Self>
     + LcmAssign<Self>
+    + Lucas
     + ModIsReduced<Self>
     + ModAdd<Self, Self, Output = Self>
     + ModAddAssign<Self, Self>
     + ModInverse<Self, Output = Self>
+    + ModLucasSequence<Self, u64, Self, Output = Self>
     + ModMul<Self, Self, Output = Self>
     + ModMulAssign<Self, Self>
     + ModMulPrecomputed<Self, Self, Output = Self>
@@ -143,6 +149,8 @@ pub trait PrimitiveUnsigned:
     + ModPowerOf2SubAssign<Self>
     + ModPowPrecomputed<u64, Self, Output = Self>
     + ModPowPrecomputedAssign<u64, Self>
+    + ModPrimePowerSqrt<Self, Output = Self>
+    + ModSqrt<Self, Output = Self>
     + ModSquare<Self, Output = Self>
     + ModSquareAssign<Self>
     + ModSquarePrecomputed<u64, Self, Output = Self>
@@ -0,0 +1,227 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::factorization::totient::factorization;
+use crate::num::factorization::traits::{DivisorCount, DivisorSigma, Divisors, Factor};
+use alloc::vec;
+use alloc::vec::IntoIter;
+
+fn divisor_count_from_factorization<T: PrimitiveUnsigned>(factors: &[(T, u64)]) -> T {
+    // $d(n) = \prod_{p^e \| n} (e + 1)$.
+    let mut count = T::ONE;
+    for &(_, e) in factors {
+        assert_ne!(e, 0);
+        count = count.checked_mul(T::exact_from(e + 1)).unwrap();
+    }
+    count
+}
+
+fn divisor_sigma_from_factorization<T: PrimitiveUnsigned>(factors: &[(T, u64)], k: u64) -> T {
+    // $\sigma_k(n) = \prod_{p^e \| n} (1 + p^k + p^{2k} + \cdots + p^{ek})$. The terms are summed
+    // directly rather than using the closed form, since $p^{k(e+1)}$ may overflow even when the sum
+    // does not.
+    let mut sigma = T::ONE;
+    for &(p, e) in factors {
+        assert_ne!(e, 0);
+        let p_k = p.checked_pow(k).unwrap();
+        let mut term = T::ONE;
+        let mut sum = T::ONE;
+        for _ in 0..e {
+            term = term.checked_mul(p_k).unwrap();
+            sum = sum.checked_add(term).unwrap();
+        }
+        sigma = sigma.checked_mul(sum).unwrap();
+    }
+    sigma
+}
+
+fn divisors_from_factorization<T: PrimitiveUnsigned>(factors: &[(T, u64)]) -> IntoIter<T> {
+    let mut divisors = vec![T::ONE];
+    for &(p, e) in factors {
+        assert_ne!(e, 0);
+        let len = divisors.len();
+        let mut p_i = T::ONE;
+        for _ in 0..e {
+            p_i *= p;
+            for j in 0..len {
+                divisors.push(divisors[j] * p_i);
+            }
+        }
+    }
+    divisors.sort_unstable();
+    divisors.into_iter()
+}
+
+macro_rules! impl_divisors {
+    ($t:ident) => {
+        impl DivisorCount for $t {
+            /// Counts the positive divisors of a number.
+            ///
+            /// The number is factored first; if its factorization is already known, use
+            /// [`divisor_count_from_factorization`](DivisorCount::divisor_count_from_factorization)
+            /// instead.
+            ///
+            /// $f(n) = d(n) = \sigma_0(n) = \sum_{d | n} 1$.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(2^{n/4})$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+            ///
+            /// # Panics
+            /// Panics if `self` is 0.
+            ///
+            /// # Examples
+            /// See [here](super::divisors#divisor_count).
+            #[inline]
+            fn divisor_count(&self) -> $t {
+                divisor_count_from_factorization(&factorization(self.factor()))
+            }
+
+            /// Counts the positive divisors of a number, given its prime factorization as a list of
+            /// distinct primes, each paired with its exponent.
+            ///
+            /// The factorization is not checked for validity.
+            ///
+            /// $$
+            /// f(((p_1, e_1), \ldots, (p_k, e_k))) = \prod_{i=1}^k (e_i + 1).
+            /// $$
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `factors.len()`.
+            ///
+            /// # Panics
+            /// Panics if any exponent is 0, or if the output is too large to be represented.
+            ///
+            /// # Examples
+            /// See [here](super::divisors#divisor_count_from_factorization).
+            #[inline]
+            fn divisor_count_from_factorization(factors: &[($t, u64)]) -> $t {
+                divisor_count_from_factorization(factors)
+            }
+        }
+
+        impl DivisorSigma for $t {
+            /// Computes the divisor function $\sigma_k(n)$ of a number: the sum of the $k$th powers
+            /// of its positive divisors.
+            ///
+            /// The number is factored first; if its factorization is already known, use
+            /// [`divisor_sigma_from_factorization`](DivisorSigma::divisor_sigma_from_factorization)
+            /// instead.
+            ///
+            /// $f(n, k) = \sigma_k(n) = \sum_{d | n} d^k$.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(2^{n/4})$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+            ///
+            /// # Panics
+            /// Panics if `self` is 0, or if the output is too large to be represented.
+            ///
+            /// # Examples
+            /// See [here](super::divisors#divisor_sigma).
+            #[inline]
+            fn divisor_sigma(&self, k: u64) -> $t {
+                divisor_sigma_from_factorization(&factorization(self.factor()), k)
+            }
+
+            /// Computes the divisor function $\sigma_k(n)$ of a number, given its prime
+            /// factorization as a list of distinct primes, each paired with its exponent.
+            ///
+            /// The factorization is not checked for validity.
+            ///
+            /// $$
+            /// f(((p_1, e_1), \ldots, (p_r, e_r)), k) = \prod_{i=1}^r \sum_{j=0}^{e_i} p_i^{jk}.
+            /// $$
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is the sum of the exponents.
+            ///
+            /// # Panics
+            /// Panics if any exponent is 0, or if the output is too large to be represented.
+            ///
+            /// # Examples
+            /// See [here](super::divisors#divisor_sigma_from_factorization).
+            #[inline]
+            fn divisor_sigma_from_factorization(factors: &[($t, u64)], k: u64) -> $t {
+                divisor_sigma_from_factorization(factors, k)
+            }
+        }
+
+        impl Divisors for $t {
+            type I = IntoIter<$t>;
+
+            /// Returns an iterator that generates the positive divisors of a number, in ascending
+            /// order.
+            ///
+            /// The number is factored first; if its factorization is already known, use
+            /// [`divisors_from_factorization`](Divisors::divisors_from_factorization) instead.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(2^{n/4} + d \log d)$
+            ///
+            /// $M(n) = O(d)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, $n$ is `self.significant_bits()`, and
+            /// $d$ is the number of divisors of `self`.
+            ///
+            /// # Panics
+            /// Panics if `self` is 0.
+            ///
+            /// # Examples
+            /// See [here](super::divisors#divisors).
+            #[inline]
+            fn divisors(&self) -> IntoIter<$t> {
+                divisors_from_factorization(&factorization(self.factor()))
+            }
+
+            /// Returns an iterator that generates the positive divisors of a number, in ascending
+            /// order, given the number's prime factorization as a list of distinct primes, each
+            /// paired with its exponent.
+            ///
+            /// The factorization is not checked for validity.
+            ///
+            /// # Worst-case complexity
+            /// $T(d) = O(d \log d)$
+            ///
+            /// $M(d) = O(d)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $d$ is the number of divisors.
+            ///
+            /// # Panics
+            /// Panics if any exponent is 0, or if the factorization represents a number that is too
+            /// large to be represented.
+            ///
+            /// # Examples
+            /// See [here](super::divisors#divisors_from_factorization).
+            #[inline]
+            fn divisors_from_factorization(factors: &[($t, u64)]) -> IntoIter<$t> {
+                divisors_from_factorization(factors)
+            }
+        }
+    };
+}
+impl_divisors!(u8);
+impl_divisors!(u16);
+impl_divisors!(u32);
+impl_divisors!(u64);
+impl_divisors!(usize);
@@ -0,0 +1,359 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::num::arithmetic::traits::{
+    CheckedRoot, Gcd, ModAdd, ModMulPrecomputed, ModMulPrecomputedAssign,
+};
+use crate::num::basic::integers::USIZE_IS_U32;
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::conversion::traits::WrappingFrom;
+use crate::num::factorization::traits::{Factor, IsPrime, Primes};
+use core::cmp::min;
+use core::fmt::{self, Debug, Formatter};
+
+/// The maximum number of distinct prime factors that a `u64` can have. The product of the first 15
+/// primes is less than $2^{64}$, but the product of the first 16 is not.
+pub const MAX_FACTORS_IN_U64: usize = 15;
+
+// Trial division is done using all primes less than this limit. Any composite that remains after
+// trial division has no prime factors less than the limit.
+const TRIAL_DIVISION_LIMIT: u64 = 1 << 10;
+
+/// The prime factorization of a primitive unsigned integer.
+///
+/// The factorization is stored as a list of distinct primes in ascending order, each paired with its
+/// exponent. It can be iterated over to obtain the `(prime, exponent)` pairs.
+///
+/// This `struct` is created by [`Factor::factor`]; see its documentation for more.
+#[derive(Clone, Copy, Eq, Hash, PartialEq)]
+pub struct Factors<T: PrimitiveUnsigned> {
+    factors: [T; MAX_FACTORS_IN_U64],
+    exponents: [u8; MAX_FACTORS_IN_U64],
+    count: usize,
+}
+
+impl<T: PrimitiveUnsigned> Factors<T> {
+    const fn new() -> Factors<T> {
+        Factors {
+            factors: [T::ZERO; MAX_FACTORS_IN_U64],
+            exponents: [0; MAX_FACTORS_IN_U64],
+            count: 0,
+        }
+    }
+
+    // Adds `p ^ e` to the factorization, keeping the primes sorted. `p` must be prime.
+    fn insert(&mut self, p: T, e: u8) {
+        let factors = &self.factors[..self.count];
+        match factors.binary_search(&p) {
+            Ok(i) => self.exponents[i] += e,
+            Err(i) => {
+                self.factors.copy_within(i..self.count, i + 1);
+                self.exponents.copy_within(i..self.count, i + 1);
+                self.factors[i] = p;
+                self.exponents[i] = e;
+                self.count += 1;
+            }
+        }
+    }
+
+    /// Returns the number of distinct prime factors.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::factorization::traits::Factor;
+    ///
+    /// assert_eq!(1u32.factor().len(), 0);
+    /// assert_eq!(360u32.factor().len(), 3);
+    /// ```
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.count
+    }
+
+    /// Returns whether there are no prime factors; this is only the case when factoring 1.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::factorization::traits::Factor;
+    ///
+    /// assert_eq!(1u32.factor().is_empty(), true);
+    /// assert_eq!(360u32.factor().is_empty(), false);
+    /// ```
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+}
+
+impl<T: PrimitiveUnsigned> Debug for Factors<T> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_list().entries(*self).finish()
+    }
+}
+
+/// An iterator over the `(prime, exponent)` pairs of a [`Factors`].
+///
+/// This `struct` is created by the [`IntoIterator`] implementation of [`Factors`].
+#[derive(Clone, Debug)]
+pub struct FactorsIterator<T: PrimitiveUnsigned> {
+    factors: Factors<T>,
+    i: usize,
+}
+
+impl<T: PrimitiveUnsigned> Iterator for FactorsIterator<T> {
+    type Item = (T, u8);
+
+    fn next(&mut self) -> Option<(T, u8)> {
+        if self.i == self.factors.count {
+            None
+        } else {
+            let i = self.i;
+            self.i += 1;
+            Some((self.factors.factors[i], self.factors.exponents[i]))
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.factors.count - self.i;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T: PrimitiveUnsigned> ExactSizeIterator for FactorsIterator<T> {}
+
+impl<T: PrimitiveUnsigned> IntoIterator for Factors<T> {
+    type Item = (T, u8);
+    type IntoIter = FactorsIterator<T>;
+
+    #[inline]
+    fn into_iter(self) -> FactorsIterator<T> {
+        FactorsIterator {
+            factors: self,
+            i: 0,
+        }
+    }
+}
+
+// Brent's variant of Pollard's rho algorithm, using the polynomial $x^2 + c$. Returns a nontrivial
+// factor of `n`, or `None` if the cycle closed without one being found; in that case a different
+// `c` should be tried.
+//
+// `n` must be odd and composite, and `c` must be less than `n`.
+fn pollard_brent_u64(n: u64, c: u64) -> Option<u64> {
+    const BATCH_SIZE: u64 = 128;
+    let data = u64::precompute_mod_mul_data(&n);
+    let f = |x: u64| x.mod_mul_precomputed(x, n, &data).mod_add(c, n);
+    let mut y = 2;
+    let mut r = 1;
+    let mut q = 1;
+    let mut g = 1;
+    let mut x;
+    let mut ys = y;
+    loop {
+        x = y;
+        for _ in 0..r {
+            y = f(y);
+        }
+        let mut k = 0;
+        while k < r && g == 1 {
+            ys = y;
+            for _ in 0..min(BATCH_SIZE, r - k) {
+                y = f(y);
+                q.mod_mul_precomputed_assign(x.abs_diff(y), n, &data);
+            }
+            g = q.gcd(n);
+            k += BATCH_SIZE;
+        }
+        r <<= 1;
+        if g != 1 {
+            break;
+        }
+    }
+    if g == n {
+        // The batched product hit 0 mod n; redo the last batch one step at a time.
+        loop {
+            ys = f(ys);
+            g = x.abs_diff(ys).gcd(n);
+            if g != 1 {
+                break;
+            }
+        }
+    }
+    if g == n {
+        None
+    } else {
+        Some(g)
+    }
+}
+
+// Factors a `u64` that has no prime factors less than `TRIAL_DIVISION_LIMIT`, multiplying all
+// exponents by `e`.
+fn factor_u64_no_small_factors(factors: &mut Factors<u64>, n: u64, e: u8) {
+    if n == 1 {
+        return;
+    }
+    if n.is_prime() {
+        factors.insert(n, e);
+        return;
+    }
+    // Since every prime factor is at least 2^10, a nontrivial power of a prime factor must have an
+    // exponent no greater than 6.
+    for k in [2, 3, 5] {
+        if let Some(root) = n.checked_root(k) {
+            factor_u64_no_small_factors(factors, root, e * u8::wrapping_from(k));
+            return;
+        }
+    }
+    let mut c = 1;
+    let d = loop {
+        if let Some(d) = pollard_brent_u64(n, c) {
+            break d;
+        }
+        c += 1;
+    };
+    factor_u64_no_small_factors(factors, d, e);
+    factor_u64_no_small_factors(factors, n / d, e);
+}
+
+// Factors a `u64` using trial division followed by Pollard's rho algorithm.
+fn factor_u64(mut n: u64) -> Factors<u64> {
+    assert_ne!(n, 0);
+    let mut factors = Factors::new();
+    let zeros = n.trailing_zeros();
+    if zeros != 0 {
+        factors.insert(2, u8::wrapping_from(zeros));
+        n >>= zeros;
+    }
+    for p in u64::primes_less_than(&TRIAL_DIVISION_LIMIT).skip(1) {
+        if p * p > n {
+            if n != 1 {
+                factors.insert(n, 1);
+            }
+            return factors;
+        }
+        if n % p == 0 {
+            let mut e = 0;
+            while n % p == 0 {
+                n /= p;
+                e += 1;
+            }
+            factors.insert(p, e);
+        }
+    }
+    factor_u64_no_small_factors(&mut factors, n, 1);
+    factors
+}
+
+fn convert_factors<T: PrimitiveUnsigned>(factors: Factors<u64>) -> Factors<T> {
+    let mut out = Factors::new();
+    for (p, e) in factors {
+        out.insert(T::wrapping_from(p), e);
+    }
+    out
+}
+
+macro_rules! impl_factor {
+    ($t:ident) => {
+        impl Factor for $t {
+            type FACTORS = Factors<$t>;
+
+            /// Returns the prime factorization of a number.
+            ///
+            /// The result is a [`Factors`], which can be iterated over to obtain the distinct
+            /// prime factors in ascending order, each paired with its exponent.
+            ///
+            /// Small factors are removed by trial division. If a composite remains, it is split
+            /// using Brent's variant of Pollard's rho algorithm.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(2^{n/4})$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+            ///
+            /// # Panics
+            /// Panics if `self` is 0.
+            ///
+            /// # Examples
+            /// See [here](super::factor#factor).
+            #[inline]
+            fn factor(&self) -> Factors<$t> {
+                convert_factors(factor_u64(u64::from(*self)))
+            }
+        }
+    };
+}
+impl_factor!(u8);
+impl_factor!(u16);
+impl_factor!(u32);
+
+impl Factor for u64 {
+    type FACTORS = Factors<u64>;
+
+    /// Returns the prime factorization of a `u64`.
+    ///
+    /// The result is a [`Factors`], which can be iterated over to obtain the distinct prime factors
+    /// in ascending order, each paired with its exponent.
+    ///
+    /// Small factors are removed by trial division. If a composite remains, it is split using
+    /// Brent's variant of Pollard's rho algorithm.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(2^{n/4})$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is 0.
+    ///
+    /// # Examples
+    /// See [here](super::factor#factor).
+    #[inline]
+    fn factor(&self) -> Factors<u64> {
+        factor_u64(*self)
+    }
+}
+
+impl Factor for usize {
+    type FACTORS = Factors<usize>;
+
+    /// Returns the prime factorization of a `usize`.
+    ///
+    /// The result is a [`Factors`], which can be iterated over to obtain the distinct prime factors
+    /// in ascending order, each paired with its exponent.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(2^{n/4})$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is 0.
+    ///
+    /// # Examples
+    /// See [here](super::factor#factor).
+    #[inline]
+    fn factor(&self) -> Factors<usize> {
+        if USIZE_IS_U32 {
+            convert_factors(factor_u64(u64::from(u32::wrapping_from(*self))))
+        } else {
+            convert_factors(factor_u64(u64::wrapping_from(*self)))
+        }
+    }
+}
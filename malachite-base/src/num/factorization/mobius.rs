@@ -0,0 +1,89 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::num::factorization::traits::{Factor, Mobius};
+
+fn mobius_from_factorization<T>(factors: &[(T, u64)]) -> i8 {
+    let mut mobius = 1;
+    for &(_, e) in factors {
+        match e {
+            0 => panic!("Exponents in a factorization must be positive"),
+            1 => mobius = -mobius,
+            _ => return 0,
+        }
+    }
+    mobius
+}
+
+macro_rules! impl_mobius {
+    ($t:ident) => {
+        impl Mobius for $t {
+            /// Computes the Möbius function $\mu(n)$ of a number.
+            ///
+            /// The number is factored first; if its factorization is already known, use
+            /// [`mobius_from_factorization`](Mobius::mobius_from_factorization) instead.
+            ///
+            /// $$
+            /// f(n) = \mu(n) = \\begin{cases}
+            ///     0 & \text{if} \\quad n \text{ is divisible by a square greater than 1}, \\\\
+            ///     (-1)^k & \text{otherwise, where } n \text{ has } k \text{ prime factors}.
+            /// \\end{cases}
+            /// $$
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(2^{n/4})$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+            ///
+            /// # Panics
+            /// Panics if `self` is 0.
+            ///
+            /// # Examples
+            /// See [here](super::mobius#mobius).
+            fn mobius(&self) -> i8 {
+                let mut mobius = 1;
+                for (_, e) in self.factor() {
+                    if e > 1 {
+                        return 0;
+                    }
+                    mobius = -mobius;
+                }
+                mobius
+            }
+
+            /// Computes the Möbius function $\mu(n)$ of a number, given its prime factorization as
+            /// a list of distinct primes, each paired with its exponent.
+            ///
+            /// The factorization is not checked for validity.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `factors.len()`.
+            ///
+            /// # Panics
+            /// Panics if any exponent is 0.
+            ///
+            /// # Examples
+            /// See [here](super::mobius#mobius_from_factorization).
+            #[inline]
+            fn mobius_from_factorization(factors: &[($t, u64)]) -> i8 {
+                mobius_from_factorization(factors)
+            }
+        }
+    };
+}
+impl_mobius!(u8);
+impl_mobius!(u16);
+impl_mobius!(u32);
+impl_mobius!(u64);
+impl_mobius!(usize);
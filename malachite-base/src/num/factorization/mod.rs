@@ -6,8 +6,164 @@
 // Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
 // 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
 
+/// [`DivisorCount`](traits::DivisorCount), [`DivisorSigma`](traits::DivisorSigma), and
+/// [`Divisors`](traits::Divisors), traits for counting, summing, and generating the divisors of a
+/// number.
+///
+/// # divisor_count
+/// ```
+/// use malachite_base::num::factorization::traits::DivisorCount;
+///
+/// assert_eq!(1u8.divisor_count(), 1);
+/// assert_eq!(12u8.divisor_count(), 6);
+/// assert_eq!(97u16.divisor_count(), 2);
+/// assert_eq!(1000000u32.divisor_count(), 49);
+/// assert_eq!(18446744073709551615u64.divisor_count(), 128);
+/// ```
+///
+/// # divisor_count_from_factorization
+/// ```
+/// use malachite_base::num::factorization::traits::DivisorCount;
+///
+/// assert_eq!(u8::divisor_count_from_factorization(&[]), 1);
+/// assert_eq!(u8::divisor_count_from_factorization(&[(2, 2), (3, 1)]), 6);
+/// assert_eq!(u32::divisor_count_from_factorization(&[(2, 6), (5, 6)]), 49);
+/// ```
+///
+/// # divisor_sigma
+/// ```
+/// use malachite_base::num::factorization::traits::DivisorSigma;
+///
+/// assert_eq!(1u8.divisor_sigma(1), 1);
+/// assert_eq!(12u8.divisor_sigma(0), 6);
+/// assert_eq!(12u8.divisor_sigma(1), 28);
+/// assert_eq!(12u8.divisor_sigma(2), 210);
+/// assert_eq!(28u16.divisor_sigma(1), 56);
+/// assert_eq!(100u32.divisor_sigma(3), 1149823);
+/// ```
+///
+/// # divisor_sigma_from_factorization
+/// ```
+/// use malachite_base::num::factorization::traits::DivisorSigma;
+///
+/// assert_eq!(u8::divisor_sigma_from_factorization(&[], 1), 1);
+/// assert_eq!(u8::divisor_sigma_from_factorization(&[(2, 2), (3, 1)], 1), 28);
+/// assert_eq!(u32::divisor_sigma_from_factorization(&[(2, 2), (5, 2)], 3), 1149823);
+/// ```
+///
+/// # divisors
+/// ```
+/// use itertools::Itertools;
+/// use malachite_base::num::factorization::traits::Divisors;
+///
+/// assert_eq!(1u8.divisors().collect_vec(), &[1]);
+/// assert_eq!(97u8.divisors().collect_vec(), &[1, 97]);
+/// assert_eq!(
+///     60u16.divisors().collect_vec(),
+///     &[1, 2, 3, 4, 5, 6, 10, 12, 15, 20, 30, 60]
+/// );
+/// ```
+///
+/// # divisors_from_factorization
+/// ```
+/// use itertools::Itertools;
+/// use malachite_base::num::factorization::traits::Divisors;
+///
+/// assert_eq!(u8::divisors_from_factorization(&[]).collect_vec(), &[1]);
+/// assert_eq!(
+///     u32::divisors_from_factorization(&[(2, 2), (3, 1), (5, 1)]).collect_vec(),
+///     &[1, 2, 3, 4, 5, 6, 10, 12, 15, 20, 30, 60]
+/// );
+/// ```
+pub mod divisors;
+/// [`Factor`](traits::Factor), a trait for finding the prime factorization of a number.
+///
+/// # factor
+/// ```
+/// use itertools::Itertools;
+/// use malachite_base::num::factorization::traits::Factor;
+///
+/// assert_eq!(1u8.factor().into_iter().collect_vec(), &[]);
+/// assert_eq!(60u8.factor().into_iter().collect_vec(), &[(2, 2), (3, 1), (5, 1)]);
+/// assert_eq!(65521u16.factor().into_iter().collect_vec(), &[(65521, 1)]);
+/// assert_eq!(
+///     4294967295u32.factor().into_iter().collect_vec(),
+///     &[(3, 1), (5, 1), (17, 1), (257, 1), (65537, 1)]
+/// );
+/// assert_eq!(
+///     18446744073709551615u64.factor().into_iter().collect_vec(),
+///     &[(3, 1), (5, 1), (17, 1), (257, 1), (641, 1), (65537, 1), (6700417, 1)]
+/// );
+/// assert_eq!(
+///     1000000016000000063u64.factor().into_iter().collect_vec(),
+///     &[(1000000007, 1), (1000000009, 1)]
+/// );
+/// ```
+pub mod factor;
 /// [`IsPrime`](traits::IsPrime), a trait for testing a number for primality.
 pub mod is_prime;
+/// [`Mobius`](traits::Mobius), a trait for computing the Möbius function of a number.
+///
+/// # mobius
+/// ```
+/// use malachite_base::num::factorization::traits::Mobius;
+///
+/// assert_eq!(1u8.mobius(), 1);
+/// assert_eq!(2u8.mobius(), -1);
+/// assert_eq!(6u8.mobius(), 1);
+/// assert_eq!(12u16.mobius(), 0);
+/// assert_eq!(561u32.mobius(), -1);
+/// assert_eq!(18446744073709551615u64.mobius(), -1);
+/// ```
+///
+/// # mobius_from_factorization
+/// ```
+/// use malachite_base::num::factorization::traits::Mobius;
+///
+/// assert_eq!(u8::mobius_from_factorization(&[]), 1);
+/// assert_eq!(u8::mobius_from_factorization(&[(2, 1), (3, 1)]), 1);
+/// assert_eq!(u8::mobius_from_factorization(&[(2, 2), (3, 1)]), 0);
+/// assert_eq!(u32::mobius_from_factorization(&[(3, 1), (11, 1), (17, 1)]), -1);
+/// ```
+pub mod mobius;
+/// [`NextPrime`](traits::NextPrime) and [`PreviousPrime`](traits::PreviousPrime), traits for
+/// finding the nearest primes above and below a number.
+///
+/// # next_prime
+/// ```
+/// use malachite_base::num::factorization::traits::NextPrime;
+///
+/// assert_eq!(0u8.next_prime(), 2);
+/// assert_eq!(2u8.next_prime(), 3);
+/// assert_eq!(100u16.next_prime(), 101);
+/// assert_eq!(4294967279u32.next_prime(), 4294967291);
+/// assert_eq!(1000000000000u64.next_prime(), 1000000000039);
+/// ```
+///
+/// # previous_prime
+/// ```
+/// use malachite_base::num::factorization::traits::PreviousPrime;
+///
+/// assert_eq!(2u8.previous_prime(), None);
+/// assert_eq!(3u8.previous_prime(), Some(2));
+/// assert_eq!(100u16.previous_prime(), Some(97));
+/// assert_eq!(u32::MAX.previous_prime(), Some(4294967291));
+/// assert_eq!(1000000000000u64.previous_prime(), Some(999999999989));
+/// ```
+pub mod next_prime;
+/// [`PrimePi`](traits::PrimePi), a trait for counting primes.
+///
+/// # prime_pi
+/// ```
+/// use malachite_base::num::factorization::traits::PrimePi;
+///
+/// assert_eq!(0u8.prime_pi(), 0);
+/// assert_eq!(10u8.prime_pi(), 4);
+/// assert_eq!(65535u16.prime_pi(), 6542);
+/// assert_eq!(u32::MAX.prime_pi(), 203280221);
+/// assert_eq!(10000000000u64.prime_pi(), 455052511);
+/// ```
+pub mod prime_pi;
 /// An efficient prime sieve.
 pub mod prime_sieve;
 /// [`Primes`](traits::Primes), a trait for generating prime numbers.
@@ -65,5 +221,65 @@ pub mod prime_sieve;
 /// );
 /// ```
 pub mod primes;
+/// [`Totient`](traits::Totient) and [`CarmichaelLambda`](traits::CarmichaelLambda), traits for
+/// computing Euler's totient function and the Carmichael function of a number.
+///
+/// # totient
+/// ```
+/// use malachite_base::num::factorization::traits::Totient;
+///
+/// assert_eq!(1u8.totient(), 1);
+/// assert_eq!(12u8.totient(), 4);
+/// assert_eq!(97u8.totient(), 96);
+/// assert_eq!(1000000u32.totient(), 400000);
+/// assert_eq!(18446744073709551615u64.totient(), 9208981628670443520);
+/// ```
+///
+/// # totient_from_factorization
+/// ```
+/// use malachite_base::num::factorization::traits::Totient;
+///
+/// assert_eq!(u8::totient_from_factorization(&[]), 1);
+/// assert_eq!(u8::totient_from_factorization(&[(2, 2), (3, 1)]), 4);
+/// assert_eq!(u32::totient_from_factorization(&[(2, 6), (5, 6)]), 400000);
+/// ```
+///
+/// # carmichael_lambda
+/// ```
+/// use malachite_base::num::factorization::traits::CarmichaelLambda;
+///
+/// assert_eq!(1u8.carmichael_lambda(), 1);
+/// assert_eq!(12u8.carmichael_lambda(), 2);
+/// assert_eq!(97u8.carmichael_lambda(), 96);
+/// assert_eq!(561u16.carmichael_lambda(), 80);
+/// assert_eq!(65536u32.carmichael_lambda(), 16384);
+/// assert_eq!(18446744073709551615u64.carmichael_lambda(), 17153064960);
+/// ```
+///
+/// # carmichael_lambda_from_factorization
+/// ```
+/// use malachite_base::num::factorization::traits::CarmichaelLambda;
+///
+/// assert_eq!(u8::carmichael_lambda_from_factorization(&[]), 1);
+/// assert_eq!(u8::carmichael_lambda_from_factorization(&[(2, 2), (3, 1)]), 2);
+/// assert_eq!(u16::carmichael_lambda_from_factorization(&[(3, 1), (11, 1), (17, 1)]), 80);
+/// assert_eq!(u32::carmichael_lambda_from_factorization(&[(2, 16)]), 16384);
+/// ```
+pub mod totient;
 /// Various traits for generating primes, primality testing, and factorization.
+///
+/// # Functions of the factorization
+/// [`Totient`](traits::Totient), [`CarmichaelLambda`](traits::CarmichaelLambda),
+/// [`DivisorCount`](traits::DivisorCount), [`DivisorSigma`](traits::DivisorSigma),
+/// [`Mobius`](traits::Mobius), and [`Divisors`](traits::Divisors) depend only on the prime
+/// factorization of $n$. Each has a `_from_factorization` function, which takes that factorization
+/// instead of $n$ itself, as a list of distinct primes in ascending order, each paired with its
+/// exponent. Factoring is usually the most expensive step, so these functions should be used
+/// whenever the factorization is already known.
+///
+/// The other functions factor $n$ with [`Factor::factor`](traits::Factor::factor). This begins
+/// with trial division by the primes generated by [`Primes`](traits::Primes), which fully factors
+/// most small inputs. Unlike plain trial division, though, it splits any remaining composite
+/// cofactor with Pollard's rho algorithm, the elliptic curve method, or the quadratic sieve, so
+/// that an input with two large prime factors doesn't take time proportional to $\sqrt{n}$.
 pub mod traits;
@@ -0,0 +1,83 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::factorization::traits::{IsPrime, NextPrime, PreviousPrime};
+
+fn next_prime<T: IsPrime + PrimitiveUnsigned>(n: T) -> T {
+    if n < T::TWO {
+        return T::TWO;
+    }
+    let mut p = n;
+    loop {
+        p = if p.even() {
+            p.checked_add(T::ONE)
+        } else {
+            p.checked_add(T::TWO)
+        }
+        .unwrap_or_else(|| panic!("No prime greater than {n} is representable"));
+        if p.is_prime() {
+            return p;
+        }
+    }
+}
+
+fn previous_prime<T: IsPrime + PrimitiveUnsigned>(n: T) -> Option<T> {
+    if n <= T::TWO {
+        return None;
+    }
+    if n == T::from(3u8) {
+        return Some(T::TWO);
+    }
+    let mut p = n - if n.even() { T::ONE } else { T::TWO };
+    while !p.is_prime() {
+        p -= T::TWO;
+    }
+    Some(p)
+}
+
+macro_rules! impl_next_prime {
+    ($t:ident) => {
+        impl NextPrime for $t {
+            /// Returns the smallest prime greater than a number.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Panics
+            /// Panics if the result is not representable.
+            ///
+            /// # Examples
+            /// See [here](super::next_prime#next_prime).
+            #[inline]
+            fn next_prime(&self) -> $t {
+                next_prime(*self)
+            }
+        }
+
+        impl PreviousPrime for $t {
+            /// Returns the largest prime less than a number, or `None` if the number is less than
+            /// or equal to 2.
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Examples
+            /// See [here](super::next_prime#previous_prime).
+            #[inline]
+            fn previous_prime(&self) -> Option<$t> {
+                previous_prime(*self)
+            }
+        }
+    };
+}
+impl_next_prime!(u8);
+impl_next_prime!(u16);
+impl_next_prime!(u32);
+impl_next_prime!(u64);
+impl_next_prime!(usize);
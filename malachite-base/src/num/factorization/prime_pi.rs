@@ -0,0 +1,318 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::num::arithmetic::traits::{FloorRoot, FloorSqrt};
+use crate::num::conversion::traits::WrappingFrom;
+use crate::num::factorization::traits::{PrimePi, Primes};
+use alloc::vec::Vec;
+use core::cmp::{max, min};
+
+// Below this limit, primes are simply counted using a sieve.
+const PRIME_PI_SIEVE_LIMIT: u64 = 1 << 16;
+
+// The primes 2 through 13, their product, and the number of integers in [1, product] coprime to the
+// product. Legendre's function is evaluated directly for these primes using a lookup table.
+const PHI_PRIMES: usize = 6;
+const PHI_PRODUCT: u64 = 30030;
+const PHI_TOTIENT: u64 = 5760;
+
+// The number of integers sieved at a time.
+const SEGMENT_SIZE: u64 = 1 << 18;
+
+// Sets `sieve[i]` to whether `low + i` is prime, for `low + i` in `[low, high]`. All primes up to
+// the square root of `high` must be in `primes`.
+fn sieve_segment(primes: &[u64], low: u64, high: u64, sieve: &mut Vec<bool>) {
+    sieve.clear();
+    sieve.resize(usize::wrapping_from(high - low + 1), true);
+    for x in low..min(2, high + 1) {
+        sieve[usize::wrapping_from(x - low)] = false;
+    }
+    for &p in primes {
+        if p > high / p {
+            break;
+        }
+        let mut m = max(low.div_ceil(p) * p, p * p);
+        while m <= high {
+            sieve[usize::wrapping_from(m - low)] = false;
+            m += p;
+        }
+    }
+}
+
+// Counts the primes in `[low, high]`. All primes up to the square root of `high` must be in
+// `primes`.
+fn count_primes_in_range(primes: &[u64], mut low: u64, high: u64) -> u64 {
+    let mut count = 0;
+    let mut sieve = Vec::new();
+    while low <= high {
+        let segment_high = min(low + SEGMENT_SIZE - 1, high);
+        sieve_segment(primes, low, segment_high, &mut sieve);
+        count += u64::wrapping_from(sieve.iter().filter(|&&b| b).count());
+        low = segment_high + 1;
+    }
+    count
+}
+
+// Counts the primes in `(start, t]` for a nondecreasing sequence of targets `t`, sieving one
+// segment at a time as the targets advance.
+struct AscendingPrimeCounter<'a> {
+    primes: &'a [u64],
+    // The number of primes in `(start, low + i)`.
+    count: u64,
+    low: u64,
+    i: usize,
+    sieve: Vec<bool>,
+}
+
+impl AscendingPrimeCounter<'_> {
+    const fn new(primes: &[u64], start: u64) -> AscendingPrimeCounter<'_> {
+        AscendingPrimeCounter {
+            primes,
+            count: 0,
+            low: start + 1,
+            i: 0,
+            sieve: Vec::new(),
+        }
+    }
+
+    fn count_up_to(&mut self, t: u64) -> u64 {
+        while self.low + u64::wrapping_from(self.i) <= t {
+            if self.i == self.sieve.len() {
+                self.low += u64::wrapping_from(self.sieve.len());
+                self.i = 0;
+                sieve_segment(
+                    self.primes,
+                    self.low,
+                    self.low + SEGMENT_SIZE - 1,
+                    &mut self.sieve,
+                );
+            }
+            if self.sieve[self.i] {
+                self.count += 1;
+            }
+            self.i += 1;
+        }
+        self.count
+    }
+}
+
+// A binary indexed tree over a sieved segment, counting the entries that have not been crossed
+// out.
+struct SegmentCounter {
+    tree: Vec<u32>,
+    total: u64,
+}
+
+impl SegmentCounter {
+    fn new(sieve: &[bool]) -> SegmentCounter {
+        let mut tree: Vec<u32> = sieve.iter().map(|&b| u32::from(b)).collect();
+        let len = tree.len();
+        for i in 0..len {
+            let j = i | (i + 1);
+            if j < len {
+                tree[j] += tree[i];
+            }
+        }
+        SegmentCounter {
+            tree,
+            total: u64::wrapping_from(sieve.iter().filter(|&&b| b).count()),
+        }
+    }
+
+    fn remove(&mut self, mut i: usize) {
+        while i < self.tree.len() {
+            self.tree[i] -= 1;
+            i |= i + 1;
+        }
+        self.total -= 1;
+    }
+
+    // The number of entries in `[0, i]` that have not been crossed out.
+    fn count_up_to(&self, i: usize) -> u64 {
+        let mut count = 0;
+        let mut i = i + 1;
+        while i != 0 {
+            count += u64::from(self.tree[i - 1]);
+            i &= i - 1;
+        }
+        count
+    }
+}
+
+// Legendre's function $\phi(x, c)$, the number of integers in $[1, x]$ that are not divisible by
+// any of the first $c$ primes, where $c$ is `PHI_PRIMES`.
+fn phi_small(phi_table: &[u16], x: u64) -> u64 {
+    x / PHI_PRODUCT * PHI_TOTIENT + u64::from(phi_table[usize::wrapping_from(x % PHI_PRODUCT)])
+}
+
+// Computes Legendre's function $\phi(x, a)$, where $a = \pi(y)$, $y^2 < x$, and `primes` contains all
+// primes up to $y$, using the Lagarias–Miller–Odlyzko method. Expanding $\phi$ with the rule
+// $\phi(x, b + 1) = \phi(x, b) - \phi(x/p_{b+1}, b)$, and stopping at the leaves $\pm\phi(x/n, b)$
+// where $n \leq y$ and $b = c$, or $n > y$, gives
+// $$
+// \phi(x, a) = \sum_{\substack{n \leq y \\ p_{\min}(n) > p_c}} \mu(n)\phi(x/n, c) -
+// \sum_{b=c}^{a-1} \sum_{\substack{y/p_{b+1} < m \leq y \\ p_{\min}(m) > p_{b+1}}}
+// \mu(m)\phi(x/(mp_{b+1}), b).
+// $$
+// The ordinary leaves, in the first sum, are found using a table. The arguments of the special
+// leaves, in the second sum, are less than $x/y$, so they are found by sieving $[1, x/y]$ one
+// segment at a time, removing the multiples of one prime after another and keeping track of the
+// survivors with a binary indexed tree. Only $O(y)$ memory is used.
+fn phi_lmo(x: u64, y: u64, primes: &[u64]) -> u64 {
+    let a = primes.partition_point(|&p| p <= y);
+    let y_usize = usize::wrapping_from(y);
+    // The Möbius function and the least prime factor of every integer up to y. The least prime
+    // factor of 1 is taken to be infinite.
+    let mut mu = vec![1i8; y_usize + 1];
+    let mut least_prime_factor = vec![u32::MAX; y_usize + 1];
+    for &p in primes[..a].iter().rev() {
+        let p_usize = usize::wrapping_from(p);
+        for m in (p_usize..=y_usize).step_by(p_usize) {
+            mu[m] = -mu[m];
+            least_prime_factor[m] = u32::wrapping_from(p);
+        }
+        for m in (p_usize * p_usize..=y_usize).step_by(p_usize * p_usize) {
+            mu[m] = 0;
+        }
+    }
+    let mut phi_table = Vec::with_capacity(usize::wrapping_from(PHI_PRODUCT));
+    let mut count = 0;
+    for n in 0..PHI_PRODUCT {
+        if n != 0 && primes[..PHI_PRIMES].iter().all(|&p| n % p != 0) {
+            count += 1;
+        }
+        phi_table.push(count);
+    }
+    let p_c = u32::wrapping_from(primes[PHI_PRIMES - 1]);
+    let mut sum: i128 = 0;
+    for n in 1..=y_usize {
+        if mu[n] != 0 && least_prime_factor[n] > p_c {
+            sum += i128::from(mu[n]) * i128::from(phi_small(&phi_table, x / u64::wrapping_from(n)));
+        }
+    }
+    // `phi[b]` is the number of integers below the current segment that are not divisible by any
+    // of the first b primes.
+    let mut phi = vec![0u64; a];
+    let limit = x / y;
+    let mut sieve = Vec::new();
+    let mut low = 1;
+    while low <= limit {
+        let high = min(low + SEGMENT_SIZE - 1, limit);
+        sieve.clear();
+        sieve.resize(usize::wrapping_from(high - low + 1), true);
+        for &p in &primes[..PHI_PRIMES] {
+            let mut m = low.div_ceil(p) * p;
+            while m <= high {
+                sieve[usize::wrapping_from(m - low)] = false;
+                m += p;
+            }
+        }
+        let mut counter = SegmentCounter::new(&sieve);
+        for b in PHI_PRIMES..a {
+            let p = primes[b];
+            // The special leaves whose arguments x/(mp) lie in [low, high].
+            let m_low = max(y / p, x / p / (high + 1));
+            let m_high = min(y, x / p / low);
+            for m in m_low + 1..=m_high {
+                let m_usize = usize::wrapping_from(m);
+                if mu[m_usize] != 0 && u64::from(least_prime_factor[m_usize]) > p {
+                    let z = x / p / m;
+                    let leaf = phi[b] + counter.count_up_to(usize::wrapping_from(z - low));
+                    sum -= i128::from(mu[m_usize]) * i128::from(leaf);
+                }
+            }
+            phi[b] += counter.total;
+            let mut m = low.div_ceil(p) * p;
+            while m <= high {
+                let i = usize::wrapping_from(m - low);
+                if sieve[i] {
+                    sieve[i] = false;
+                    counter.remove(i);
+                }
+                m += p;
+            }
+        }
+        low = high + 1;
+    }
+    u64::try_from(sum).unwrap()
+}
+
+// Counts the primes less than or equal to `x` using the Lagarias–Miller–Odlyzko method. With $y =
+// \lfloor x^{1/3} \rfloor$, $a = \pi(y)$, and $b = \pi(x^{1/2})$,
+// $$
+// \pi(x) = \phi(x, a) + a - 1 - \sum_{i=a+1}^b (\pi(x/p_i) - i + 1),
+// $$
+// since every integer in $[1, x]$ with no prime factors up to $y$ is 1, a prime, or the product of
+// two primes greater than $y$. Legendre's function $\phi(x, a)$ is computed by `phi_lmo`. The
+// primes $p_i$ are enumerated in descending order by sieving $(y, x^{1/2}]$ one segment at a time,
+// so that the $x/p_i$ ascend, and $\pi(x/p_i)$ is found by sieving $(x^{1/2}, x/y]$ one segment
+// at a time. Only the primes up to $(x/y)^{1/2}$, which is about $x^{1/3}$, are stored.
+fn prime_pi_u64(x: u64) -> u64 {
+    if x < PRIME_PI_SIEVE_LIMIT {
+        return u64::wrapping_from(u64::primes_less_than_or_equal_to(&x).count());
+    }
+    let sqrt = x.floor_sqrt();
+    let y = x.floor_root(3);
+    let primes =
+        u64::primes_less_than_or_equal_to(&max(y, (x / y).floor_sqrt())).collect::<Vec<_>>();
+    let a = u64::wrapping_from(primes.partition_point(|&p| p <= y));
+    let pi_sqrt = a + count_primes_in_range(&primes, y + 1, sqrt);
+    // The sum of pi(x/p) over the primes p in (y, sqrt(x)]
+    let mut pi_sum: u128 = 0;
+    let mut counter = AscendingPrimeCounter::new(&primes, sqrt);
+    let mut sieve = Vec::new();
+    let mut high = sqrt;
+    while high > y {
+        let low = max(y + 1, (high + 1).saturating_sub(SEGMENT_SIZE));
+        sieve_segment(&primes, low, high, &mut sieve);
+        for (i, &is_prime) in sieve.iter().enumerate().rev() {
+            if is_prime {
+                let p = low + u64::wrapping_from(i);
+                pi_sum += u128::from(pi_sqrt + counter.count_up_to(x / p));
+            }
+        }
+        high = low - 1;
+    }
+    // The sum of i - 1 for i in (a, pi(sqrt(x))]
+    let index_sum =
+        (u128::from(pi_sqrt) * u128::from(pi_sqrt - 1) - u128::from(a) * u128::from(a - 1)) >> 1;
+    u64::try_from(u128::from(phi_lmo(x, y, &primes)) + u128::from(a) - 1 + index_sum - pi_sum)
+        .unwrap()
+}
+
+macro_rules! impl_prime_pi {
+    ($t:ident) => {
+        impl PrimePi for $t {
+            /// Returns the number of primes less than or equal to a number.
+            ///
+            /// $f(n) = \pi(n) = |\\{p \leq n : p \text{ is prime}\\}|$.
+            ///
+            /// The Lagarias–Miller–Odlyzko method is used, so that the primes are counted without
+            /// being enumerated.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n^{2/3} \log n)$
+            ///
+            /// $M(n) = O(n^{1/3})$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `self`.
+            ///
+            /// # Examples
+            /// See [here](super::prime_pi#prime_pi).
+            #[inline]
+            fn prime_pi(&self) -> $t {
+                $t::wrapping_from(prime_pi_u64(u64::wrapping_from(*self)))
+            }
+        }
+    };
+}
+impl_prime_pi!(u8);
+impl_prime_pi!(u16);
+impl_prime_pi!(u32);
+impl_prime_pi!(u64);
+impl_prime_pi!(usize);
@@ -0,0 +1,172 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::factorization::traits::{CarmichaelLambda, Factor, Totient};
+use alloc::vec::Vec;
+
+#[doc(hidden)]
+// Collects the `(prime, exponent)` pairs of a factorization into a `Vec` with `u64` exponents, the
+// form taken by the `_from_factorization` functions.
+pub fn factorization<T: PrimitiveUnsigned>(
+    factors: impl IntoIterator<Item = (T, u8)>,
+) -> Vec<(T, u64)> {
+    factors
+        .into_iter()
+        .map(|(p, e)| (p, u64::from(e)))
+        .collect()
+}
+
+fn totient_from_factorization<T: PrimitiveUnsigned>(factors: &[(T, u64)]) -> T {
+    // $\varphi(n) = \prod_{p^e \| n} p^{e-1}(p-1)$.
+    let mut totient = T::ONE;
+    for &(p, e) in factors {
+        assert_ne!(e, 0);
+        totient *= p.pow(e - 1) * (p - T::ONE);
+    }
+    totient
+}
+
+fn carmichael_lambda_from_factorization<T: PrimitiveUnsigned>(factors: &[(T, u64)]) -> T {
+    // $\lambda(n)$ is the LCM of $\lambda(p^e)$ over the prime powers $p^e$ exactly dividing $n$.
+    // $\lambda(p^e) = \varphi(p^e)$, except that $\lambda(2^e) = 2^{e-2}$ when $e \geq 3$.
+    let mut lambda = T::ONE;
+    for &(p, e) in factors {
+        assert_ne!(e, 0);
+        let lambda_p = if p == T::TWO && e >= 3 {
+            T::power_of_2(e - 2)
+        } else {
+            p.pow(e - 1) * (p - T::ONE)
+        };
+        lambda = lambda.lcm(lambda_p);
+    }
+    lambda
+}
+
+macro_rules! impl_totient {
+    ($t:ident) => {
+        impl Totient for $t {
+            /// Computes Euler's totient function $\varphi(n)$ of a number: the number of integers
+            /// in $[1, n]$ that are coprime to $n$.
+            ///
+            /// The number is factored first; if its factorization is already known, use
+            /// [`totient_from_factorization`](Totient::totient_from_factorization) instead.
+            ///
+            /// $$
+            /// f(n) = \varphi(n) = n\prod_{p | n} \left(1 - \frac{1}{p}\right).
+            /// $$
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(2^{n/4})$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+            ///
+            /// # Panics
+            /// Panics if `self` is 0.
+            ///
+            /// # Examples
+            /// See [here](super::totient#totient).
+            #[inline]
+            fn totient(&self) -> $t {
+                totient_from_factorization(&factorization(self.factor()))
+            }
+
+            /// Computes Euler's totient function $\varphi(n)$ of a number, given its prime
+            /// factorization as a list of distinct primes, each paired with its exponent.
+            ///
+            /// The factorization is not checked for validity.
+            ///
+            /// $$
+            /// f(((p_1, e_1), \ldots, (p_k, e_k))) = \prod_{i=1}^k p_i^{e_i-1}(p_i-1).
+            /// $$
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `factors.len()`.
+            ///
+            /// # Panics
+            /// Panics if any exponent is 0, or if the factorization represents a number that is too
+            /// large to be represented.
+            ///
+            /// # Examples
+            /// See [here](super::totient#totient_from_factorization).
+            #[inline]
+            fn totient_from_factorization(factors: &[($t, u64)]) -> $t {
+                totient_from_factorization(factors)
+            }
+        }
+
+        impl CarmichaelLambda for $t {
+            /// Computes the Carmichael function $\lambda(n)$ of a number: the smallest positive
+            /// $m$ such that $a^m \equiv 1 \mod n$ for every $a$ coprime to $n$.
+            ///
+            /// The number is factored first; if its factorization is already known, use
+            /// [`carmichael_lambda_from_factorization`](
+            /// CarmichaelLambda::carmichael_lambda_from_factorization) instead.
+            ///
+            /// $\lambda(n)$ always divides $\varphi(n)$.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(2^{n/4})$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+            ///
+            /// # Panics
+            /// Panics if `self` is 0.
+            ///
+            /// # Examples
+            /// See [here](super::totient#carmichael_lambda).
+            #[inline]
+            fn carmichael_lambda(&self) -> $t {
+                carmichael_lambda_from_factorization(&factorization(self.factor()))
+            }
+
+            /// Computes the Carmichael function $\lambda(n)$ of a number, given its prime
+            /// factorization as a list of distinct primes, each paired with its exponent.
+            ///
+            /// The factorization is not checked for validity.
+            ///
+            /// $$
+            /// f(((p_1, e_1), \ldots, (p_k, e_k))) = \operatorname{lcm}(\lambda(p_1^{e_1}), \ldots,
+            /// \lambda(p_k^{e_k})),
+            /// $$
+            /// where $\lambda(p^e) = p^{e-1}(p-1)$, except that $\lambda(2^e) = 2^{e-2}$ when $e
+            /// \geq 3$.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `factors.len()`.
+            ///
+            /// # Panics
+            /// Panics if any exponent is 0, or if the factorization represents a number that is too
+            /// large to be represented.
+            ///
+            /// # Examples
+            /// See [here](super::totient#carmichael_lambda_from_factorization).
+            #[inline]
+            fn carmichael_lambda_from_factorization(factors: &[($t, u64)]) -> $t {
+                carmichael_lambda_from_factorization(factors)
+            }
+        }
+    };
+}
+impl_totient!(u8);
+impl_totient!(u16);
+impl_totient!(u32);
+impl_totient!(u64);
+impl_totient!(usize);
@@ -20,3 +20,85 @@ pub trait Primes {
 pub trait IsPrime {
     fn is_prime(&self) -> bool;
 }
+
+/// Finds the prime factorization of a number, as a sequence of distinct primes in ascending order,
+/// each paired with its exponent.
+pub trait Factor {
+    type FACTORS;
+
+    fn factor(&self) -> Self::FACTORS;
+}
+
+/// Finds the smallest prime greater than a number.
+pub trait NextPrime {
+    fn next_prime(&self) -> Self;
+}
+
+/// Finds the largest prime less than a number, if one exists.
+pub trait PreviousPrime: Sized {
+    fn previous_prime(&self) -> Option<Self>;
+}
+
+/// Counts the primes less than or equal to a number.
+pub trait PrimePi {
+    fn prime_pi(&self) -> Self;
+}
+
+/// Computes Euler's totient function $\varphi(n)$ of a number: the number of integers in $[1, n]$
+/// that are coprime to $n$.
+///
+/// See [here](self#functions-of-the-factorization) for the `_from_factorization` function.
+pub trait Totient: Sized {
+    fn totient(&self) -> Self;
+
+    fn totient_from_factorization(factors: &[(Self, u64)]) -> Self;
+}
+
+/// Computes the Carmichael function $\lambda(n)$ of a number: the exponent of the multiplicative
+/// group of integers modulo $n$.
+///
+/// See [here](self#functions-of-the-factorization) for the `_from_factorization` function.
+pub trait CarmichaelLambda: Sized {
+    fn carmichael_lambda(&self) -> Self;
+
+    fn carmichael_lambda_from_factorization(factors: &[(Self, u64)]) -> Self;
+}
+
+/// Counts the positive divisors of a number.
+///
+/// See [here](self#functions-of-the-factorization) for the `_from_factorization` function.
+pub trait DivisorCount: Sized {
+    fn divisor_count(&self) -> Self;
+
+    fn divisor_count_from_factorization(factors: &[(Self, u64)]) -> Self;
+}
+
+/// Computes the divisor function $\sigma_k(n)$ of a number: the sum of the $k$th powers of its
+/// positive divisors.
+///
+/// See [here](self#functions-of-the-factorization) for the `_from_factorization` function.
+pub trait DivisorSigma: Sized {
+    fn divisor_sigma(&self, k: u64) -> Self;
+
+    fn divisor_sigma_from_factorization(factors: &[(Self, u64)], k: u64) -> Self;
+}
+
+/// Computes the Möbius function $\mu(n)$ of a number.
+///
+/// See [here](self#functions-of-the-factorization) for the `_from_factorization` function.
+pub trait Mobius: Sized {
+    fn mobius(&self) -> i8;
+
+    fn mobius_from_factorization(factors: &[(Self, u64)]) -> i8;
+}
+
+/// Generates the positive divisors of a number, in ascending order.
+///
+/// See [here](self#functions-of-the-factorization) for the `_from_factorization` function.
+pub trait Divisors: Sized {
+    type I: Iterator<Item = Self>;
+
+    fn divisors(&self) -> Self::I;
+
+    fn divisors_from_factorization(factors: &[(Self, u64)]) -> Self::I;
+}
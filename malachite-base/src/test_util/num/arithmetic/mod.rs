@@ -9,8 +9,10 @@
 pub mod extended_gcd;
 pub mod factorial;
 pub mod kronecker_symbol;
+pub mod mod_discrete_log;
 pub mod mod_inverse;
 pub mod mod_mul;
 pub mod mod_pow;
 pub mod mod_power_of_2_inverse;
+pub mod mod_sqrt;
 pub mod primorial;
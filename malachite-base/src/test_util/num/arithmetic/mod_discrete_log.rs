@@ -0,0 +1,24 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+
+pub fn mod_discrete_log_naive<T: PrimitiveUnsigned>(base: T, target: T, m: T) -> Option<T> {
+    // The powers of `base` take at most `m` distinct values, so every value that appears does so
+    // within the first `m` powers.
+    let mut x = T::ONE % m;
+    let mut k = T::ZERO;
+    while k < m {
+        if x == target {
+            return Some(k);
+        }
+        x.mod_mul_assign(base, m);
+        k += T::ONE;
+    }
+    None
+}
@@ -0,0 +1,30 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+
+pub fn factor_naive<T: PrimitiveUnsigned>(mut n: T) -> Vec<(T, u8)> {
+    assert_ne!(n, T::ZERO);
+    let mut factors = Vec::new();
+    let mut p = T::TWO;
+    while p <= n / p {
+        let mut e = 0;
+        while n.divisible_by(p) {
+            n /= p;
+            e += 1;
+        }
+        if e != 0 {
+            factors.push((p, e));
+        }
+        p += if p == T::TWO { T::ONE } else { T::TWO };
+    }
+    if n != T::ONE {
+        factors.push((n, 1));
+    }
+    factors
+}
@@ -6,6 +6,8 @@
 // Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
 // 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
 
+pub mod factor;
 pub mod is_prime;
+pub mod prime_pi;
 pub mod prime_sieve;
 pub mod primes;
@@ -0,0 +1,14 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::num::basic::unsigneds::PrimitiveUnsigned;
+use crate::num::factorization::traits::Primes;
+
+pub fn prime_pi_naive<T: Primes + PrimitiveUnsigned>(n: T) -> T {
+    T::exact_from(T::primes_less_than_or_equal_to(&n).count())
+}
@@ -216,17 +216,22 @@ pub mod num {
         pub mod eq_mod_power_of_2;
         pub mod extended_gcd;
         pub mod factorial;
+        pub mod fibonacci;
         pub mod floor;
         pub mod gcd;
+        pub mod is_perfect_power;
         pub mod is_power_of_2;
+        pub mod is_square;
         pub mod kronecker_symbol;
         pub mod lcm;
         pub mod log_base;
         pub mod log_base_2;
         pub mod log_base_power_of_2;
         pub mod mod_add;
+        pub mod mod_discrete_log;
         pub mod mod_inverse;
         pub mod mod_is_reduced;
+        pub mod mod_lucas_sequence;
         pub mod mod_mul;
         pub mod mod_neg;
         pub mod mod_op;
@@ -244,6 +249,7 @@ pub mod num {
         pub mod mod_power_of_2_sub;
         pub mod mod_shl;
         pub mod mod_shr;
+        pub mod mod_sqrt;
         pub mod mod_square;
         pub mod mod_sub;
         pub mod neg;
@@ -395,11 +401,17 @@ pub mod num {
         pub mod primitive_int_increasing_range;
     }
     pub mod factorization {
+        pub mod divisors;
+        pub mod factor;
         pub mod is_prime;
+        pub mod mobius;
+        pub mod next_prime;
         pub mod prime_indicator_sequence;
         pub mod prime_indicator_sequence_less_than;
+        pub mod prime_pi;
         pub mod prime_sieve;
         pub mod primes;
+        pub mod totient;
     }
     pub mod float {
         pub mod basic {
@@ -0,0 +1,123 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::CheckedLucas;
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use std::panic::catch_unwind;
+
+#[test]
+fn test_fibonacci() {
+    fn test<T: PrimitiveUnsigned>(n: u64, out: T, out_prev: T) {
+        assert_eq!(T::fibonacci(n), out);
+        assert_eq!(T::fibonacci2(n), (out, out_prev));
+        assert_eq!(T::checked_fibonacci(n), Some(out));
+        assert_eq!(T::checked_fibonacci2(n), Some((out, out_prev)));
+    }
+    test::<u8>(0, 0, 1);
+    test::<u8>(1, 1, 0);
+    test::<u8>(2, 1, 1);
+    test::<u8>(3, 2, 1);
+    test::<u8>(4, 3, 2);
+    test::<u8>(5, 5, 3);
+    test::<u8>(10, 55, 34);
+    test::<u8>(13, 233, 144);
+    test::<u16>(24, 46368, 28657);
+    test::<u32>(47, 2971215073, 1836311903);
+    test::<u64>(93, 12200160415121876738, 7540113804746346429);
+    test::<u128>(
+        186,
+        332825110087067562321196029789634457848,
+        205697230343233228174223751303346572685,
+    );
+}
+
+#[test]
+fn test_checked_fibonacci() {
+    fn test<T: PrimitiveUnsigned>(n: u64) {
+        assert_eq!(T::checked_fibonacci(n), None);
+        assert_eq!(T::checked_fibonacci2(n), None);
+    }
+    test::<u8>(14);
+    test::<u16>(25);
+    test::<u32>(48);
+    test::<u64>(94);
+    test::<u128>(187);
+    test::<u64>(u64::MAX);
+}
+
+fn fibonacci_fail_helper<T: PrimitiveUnsigned>() {
+    assert_panic!(T::fibonacci(200));
+    assert_panic!(T::fibonacci2(200));
+}
+
+#[test]
+fn fibonacci_fail() {
+    apply_fn_to_unsigneds!(fibonacci_fail_helper);
+}
+
+#[test]
+fn test_lucas() {
+    fn test<T: PrimitiveUnsigned>(n: u64, out: T) {
+        assert_eq!(T::lucas(n), out);
+        assert_eq!(T::checked_lucas(n), Some(out));
+    }
+    test::<u8>(0, 2);
+    test::<u8>(1, 1);
+    test::<u8>(2, 3);
+    test::<u8>(3, 4);
+    test::<u8>(4, 7);
+    test::<u8>(10, 123);
+    test::<u8>(11, 199);
+    test::<u16>(23, 64079);
+    test::<u32>(46, 4106118243);
+    test::<u64>(92, 16860207025497407047);
+
+    assert_eq!(u8::checked_lucas(12), None);
+    assert_eq!(u16::checked_lucas(24), None);
+    assert_eq!(u32::checked_lucas(47), None);
+    assert_eq!(u64::checked_lucas(93), None);
+    assert_eq!(u128::checked_lucas(185), None);
+}
+
+fn lucas_fail_helper<T: PrimitiveUnsigned>() {
+    assert_panic!(T::lucas(200));
+}
+
+#[test]
+fn lucas_fail() {
+    apply_fn_to_unsigneds!(lucas_fail_helper);
+}
+
+fn fibonacci_properties_helper<T: PrimitiveUnsigned>() {
+    let mut n = 0;
+    while let Some((f, f_prev)) = T::checked_fibonacci2(n) {
+        assert_eq!(T::fibonacci(n), f);
+        if n >= 1 {
+            assert_eq!(T::fibonacci(n - 1), f_prev);
+        }
+        if n >= 2 {
+            assert_eq!(T::fibonacci(n - 2) + f_prev, f);
+        }
+        if let Some(l) = T::checked_lucas(n) {
+            // $L_n = F_{n-1} + F_{n+1} = F_n + 2F_{n-1}$
+            assert_eq!(f + (f_prev << 1), l);
+            if n >= 2 {
+                assert_eq!(T::lucas(n - 2) + T::lucas(n - 1), l);
+            }
+        }
+        let x = T::ONE.mod_lucas_sequence(T::MAX - T::ONE, n, T::MAX);
+        assert_eq!(x.0, f);
+        n += 1;
+    }
+    assert!(T::checked_lucas(n).is_none());
+}
+
+#[test]
+fn fibonacci_properties() {
+    apply_fn_to_unsigneds!(fibonacci_properties_helper);
+}
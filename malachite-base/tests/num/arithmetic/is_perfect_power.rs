@@ -0,0 +1,147 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{ExpressAsPower, Parity};
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::signeds::PrimitiveSigned;
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::test_util::generators::{signed_gen, unsigned_gen};
+
+fn express_as_power_naive<T: PrimitiveInt>(x: T) -> Option<(T, u64)> {
+    if x == T::ZERO || x == T::ONE {
+        return Some((x, 2));
+    }
+    if x.checked_add(T::ONE) == Some(T::ZERO) {
+        return Some((x, 3));
+    }
+    for k in (2..T::WIDTH).rev() {
+        if x < T::ZERO && k.even() {
+            continue;
+        }
+        if let Some(root) = x.checked_root(k) {
+            return Some((root, k));
+        }
+    }
+    None
+}
+
+fn express_as_power_helper<T: PrimitiveInt>() {
+    let test = |n: u8, out: Option<(u8, u64)>| {
+        let out = out.map(|(b, k)| (T::exact_from(b), k));
+        assert_eq!(T::exact_from(n).express_as_power(), out);
+        assert_eq!(T::exact_from(n).is_perfect_power(), out.is_some());
+    };
+    test(0, Some((0, 2)));
+    test(1, Some((1, 2)));
+    test(2, None);
+    test(4, Some((2, 2)));
+    test(6, None);
+    test(8, Some((2, 3)));
+    test(9, Some((3, 2)));
+    test(16, Some((2, 4)));
+    test(27, Some((3, 3)));
+    test(32, Some((2, 5)));
+    test(36, Some((6, 2)));
+    test(64, Some((2, 6)));
+    test(72, None);
+    test(81, Some((3, 4)));
+    test(100, Some((10, 2)));
+    test(125, Some((5, 3)));
+}
+
+fn express_as_power_signed_helper<T: PrimitiveSigned>() {
+    let test = |n: i8, out: Option<(i8, u64)>| {
+        let out = out.map(|(b, k)| (T::from(b), k));
+        assert_eq!(T::from(n).express_as_power(), out);
+        assert_eq!(T::from(n).is_perfect_power(), out.is_some());
+    };
+    test(-1, Some((-1, 3)));
+    test(-2, None);
+    test(-4, None);
+    test(-8, Some((-2, 3)));
+    test(-16, None);
+    test(-27, Some((-3, 3)));
+    test(-32, Some((-2, 5)));
+    test(-64, Some((-4, 3)));
+    test(-125, Some((-5, 3)));
+    test(-128, Some((-2, 7)));
+}
+
+#[test]
+fn test_express_as_power() {
+    apply_fn_to_primitive_ints!(express_as_power_helper);
+    apply_fn_to_signeds!(express_as_power_signed_helper);
+
+    assert_eq!(255u8.express_as_power(), None);
+    assert_eq!(1024u16.express_as_power(), Some((2, 10)));
+    assert_eq!(1000000u32.express_as_power(), Some((10, 6)));
+    assert_eq!((1u64 << 63).express_as_power(), Some((2, 63)));
+    assert_eq!(3u64.pow(40).express_as_power(), Some((3, 40)));
+    assert_eq!((3u64.pow(40) + 1).express_as_power(), None);
+    assert_eq!(u64::MAX.express_as_power(), None);
+    assert_eq!((1u128 << 127).express_as_power(), Some((2, 127)));
+    assert_eq!(3u128.pow(80).express_as_power(), Some((3, 80)));
+    assert_eq!((3u128.pow(80) - 1).express_as_power(), None);
+    assert_eq!(7u128.pow(45).express_as_power(), Some((7, 45)));
+    assert_eq!((7u128.pow(45) + 2).express_as_power(), None);
+    assert_eq!(35u128.pow(15).express_as_power(), Some((35, 15)));
+    assert_eq!(1000003u128.pow(5).express_as_power(), Some((1000003, 5)));
+    assert_eq!(i64::MIN.express_as_power(), Some((-2, 63)));
+    assert_eq!(i32::MIN.express_as_power(), Some((-2, 31)));
+    assert_eq!(i16::MIN.express_as_power(), Some((-2, 15)));
+    assert_eq!((-(1i64 << 62)).express_as_power(), Some((-(1 << 2), 31)));
+    assert_eq!(7i64.pow(22).express_as_power(), Some((7, 22)));
+    assert_eq!((-7i64).pow(21).express_as_power(), Some((-7, 21)));
+}
+
+#[test]
+fn express_as_power_exhaustive() {
+    for x in 0..=u16::MAX {
+        assert_eq!(x.express_as_power(), express_as_power_naive(x));
+    }
+    for x in i16::MIN..=i16::MAX {
+        assert_eq!(x.express_as_power(), express_as_power_naive(x));
+    }
+}
+
+fn express_as_power_properties_helper_unsigned<T: PrimitiveUnsigned>() {
+    unsigned_gen::<T>().test_properties(|x| {
+        let result = x.express_as_power();
+        assert_eq!(x.is_perfect_power(), result.is_some());
+        if T::WIDTH <= u32::WIDTH {
+            assert_eq!(express_as_power_naive(x), result);
+        }
+        if let Some((base, k)) = result {
+            assert!(k >= 2);
+            assert_eq!(base.pow(k), x);
+            if base > T::ONE {
+                assert!(!base.is_perfect_power());
+            }
+        }
+    });
+}
+
+fn express_as_power_properties_helper_signed<T: PrimitiveSigned>() {
+    signed_gen::<T>().test_properties(|x| {
+        let result = x.express_as_power();
+        assert_eq!(x.is_perfect_power(), result.is_some());
+        if let Some((base, k)) = result {
+            assert!(k >= 2);
+            assert_eq!(base.pow(k), x);
+            if x < T::ZERO {
+                assert!(k.odd());
+            }
+        }
+    });
+}
+
+#[test]
+fn express_as_power_properties() {
+    apply_fn_to_unsigneds!(express_as_power_properties_helper_unsigned);
+    apply_fn_to_signeds!(express_as_power_properties_helper_signed);
+}
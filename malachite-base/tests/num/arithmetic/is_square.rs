@@ -0,0 +1,96 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{CheckedSqrt, IsSquare};
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::signeds::PrimitiveSigned;
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::test_util::generators::{signed_gen, unsigned_gen};
+
+fn is_square_helper<T: PrimitiveInt>() {
+    let test = |n: u8, out| {
+        assert_eq!(T::exact_from(n).is_square(), out);
+    };
+    test(0, true);
+    test(1, true);
+    test(2, false);
+    test(3, false);
+    test(4, true);
+    test(15, false);
+    test(16, true);
+    test(17, false);
+    test(99, false);
+    test(100, true);
+    test(121, true);
+    test(126, false);
+}
+
+fn is_square_signed_helper<T: PrimitiveSigned>() {
+    let test = |n: i8, out| {
+        assert_eq!(T::from(n).is_square(), out);
+    };
+    test(-1, false);
+    test(-4, false);
+    test(-100, false);
+    test(i8::MIN, false);
+}
+
+#[test]
+fn test_is_square() {
+    apply_fn_to_primitive_ints!(is_square_helper);
+    apply_fn_to_signeds!(is_square_signed_helper);
+
+    let test = |n: u64, out| {
+        assert_eq!(n.is_square(), out);
+    };
+    test(u64::MAX, false);
+    test(0xfffffffe00000001, true);
+    test(0xfffffffe00000002, false);
+    test(0xfffffffe00000000, false);
+    test(1 << 62, true);
+    test(1 << 63, false);
+    assert_eq!(u128::MAX.is_square(), false);
+    assert_eq!(
+        (u128::from(u64::MAX) * u128::from(u64::MAX)).is_square(),
+        true
+    );
+    assert_eq!(i64::MAX.is_square(), false);
+    assert_eq!(((1i64 << 31) - 1).pow(2).is_square(), true);
+}
+
+#[test]
+fn is_square_exhaustive() {
+    for x in 0..=u16::MAX {
+        assert_eq!(x.is_square(), x.checked_sqrt().is_some());
+    }
+    for x in i16::MIN..=i16::MAX {
+        assert_eq!(x.is_square(), x >= 0 && x.checked_sqrt().is_some());
+    }
+}
+
+fn is_square_properties_helper_unsigned<T: PrimitiveUnsigned>() {
+    unsigned_gen::<T>().test_properties(|x| {
+        assert_eq!(x.is_square(), x.checked_sqrt().is_some());
+        assert!(x.floor_sqrt().square().is_square());
+    });
+}
+
+fn is_square_properties_helper_signed<T: PrimitiveSigned>() {
+    signed_gen::<T>().test_properties(|x| {
+        assert_eq!(x.is_square(), x >= T::ZERO && x.checked_sqrt().is_some());
+        if x != T::ZERO && x != T::MIN {
+            assert!(!(x.is_square() && (-x).is_square()));
+        }
+    });
+}
+
+#[test]
+fn is_square_properties() {
+    apply_fn_to_unsigneds!(is_square_properties_helper_unsigned);
+    apply_fn_to_signeds!(is_square_properties_helper_signed);
+}
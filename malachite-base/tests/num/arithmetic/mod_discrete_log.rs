@@ -0,0 +1,142 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::ModDiscreteLog;
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::factorization::traits::CarmichaelLambda;
+use malachite_base::test_util::generators::unsigned_triple_gen_var_12;
+use malachite_base::test_util::num::arithmetic::mod_discrete_log::mod_discrete_log_naive;
+use std::panic::catch_unwind;
+
+fn mod_discrete_log_helper<T: ModDiscreteLog<T, T, Output = T> + PrimitiveUnsigned>() {
+    let test = |base: u8, target: u8, m: u8, out: Option<u8>| {
+        let base = T::from(base);
+        let target = T::from(target);
+        let m = T::from(m);
+        let out = out.map(T::from);
+        assert_eq!(base.mod_discrete_log(target, m), out);
+        assert_eq!(mod_discrete_log_naive(base, target, m), out);
+    };
+    test(0, 0, 1, Some(0));
+    test(0, 0, 2, Some(1));
+    test(0, 1, 2, Some(0));
+    test(1, 0, 2, None);
+    test(1, 1, 2, Some(0));
+    test(0, 0, 5, Some(1));
+    test(0, 1, 5, Some(0));
+    test(0, 2, 5, None);
+    test(3, 1, 7, Some(0));
+    test(3, 6, 7, Some(3));
+    test(2, 3, 7, None);
+    test(2, 0, 8, Some(3));
+    test(2, 4, 8, Some(2));
+    test(2, 6, 8, None);
+    test(12, 0, 72, Some(2));
+    test(4, 2, 6, None);
+    // 11 has order 2, which divides the order of 2, but it is not a power of 2
+    test(2, 11, 15, None);
+    test(6, 6, 10, Some(1));
+    test(6, 4, 10, None);
+    test(7, 13, 100, None);
+    test(7, 43, 100, Some(3));
+    test(3, 10, 17, Some(3));
+    test(6, 20, 251, Some(100));
+    test(5, 128, 251, None);
+    test(10, 136, 243, Some(15));
+    test(10, 200, 243, None);
+    test(2, 1, 255, Some(0));
+    test(2, 100, 255, None);
+}
+
+#[test]
+fn test_mod_discrete_log() {
+    mod_discrete_log_helper::<u8>();
+    mod_discrete_log_helper::<u16>();
+    mod_discrete_log_helper::<u32>();
+    mod_discrete_log_helper::<u64>();
+    mod_discrete_log_helper::<usize>();
+
+    let test = |base: u64, target: u64, m: u64, out: Option<u64>| {
+        assert_eq!(base.mod_discrete_log(target, m), out);
+    };
+    test(6, 39361, 65521, Some(1000));
+    test(2, 1000, 65521, None);
+    test(3, 41241, 65535, Some(136));
+    test(3, 12345, 65535, None);
+    test(2, 0, 65536, Some(16));
+    test(10, 40000, 60000, Some(5));
+    test(2, 123456789, 4294967291, Some(3676225943));
+    // 18446744073709551556 = 2^2 * 11 * 137 * 547 * 5594472617641
+    test(
+        5,
+        12345678901234567,
+        18446744073709551557,
+        Some(7356342371669930477),
+    );
+}
+
+fn mod_discrete_log_fail_helper<T: ModDiscreteLog<T, T, Output = T> + PrimitiveUnsigned>() {
+    assert_panic!(T::ZERO.mod_discrete_log(T::ZERO, T::ZERO));
+    assert_panic!(T::from(7u8).mod_discrete_log(T::ONE, T::from(7u8)));
+    assert_panic!(T::from(3u8).mod_discrete_log(T::from(7u8), T::from(7u8)));
+}
+
+#[test]
+fn mod_discrete_log_fail() {
+    mod_discrete_log_fail_helper::<u8>();
+    mod_discrete_log_fail_helper::<u16>();
+    mod_discrete_log_fail_helper::<u32>();
+    mod_discrete_log_fail_helper::<u64>();
+    mod_discrete_log_fail_helper::<usize>();
+}
+
+fn mod_discrete_log_properties_helper<
+    T: CarmichaelLambda + ModDiscreteLog<T, T, Output = T> + PrimitiveUnsigned,
+>() {
+    unsigned_triple_gen_var_12::<T>().test_properties(|(base, target, m)| {
+        let result = base.mod_discrete_log(target, m);
+        if let Some(k) = result {
+            assert!(k < m);
+            assert_eq!(base.mod_pow(k.exact_into(), m), target);
+        }
+        if m.significant_bits() <= 12 {
+            assert_eq!(mod_discrete_log_naive(base, target, m), result);
+        }
+        assert_eq!(base.mod_discrete_log(T::ONE % m, m), Some(T::ZERO));
+        let k = base
+            .mod_discrete_log(base.mod_pow(target.exact_into(), m), m)
+            .unwrap();
+        assert!(k <= target);
+        assert_eq!(
+            base.mod_pow(k.exact_into(), m),
+            base.mod_pow(target.exact_into(), m)
+        );
+        if base.gcd(m) == T::ONE {
+            // For a base coprime to the modulus, the logarithm is less than the base's order,
+            // which divides the Carmichael function of the modulus.
+            assert!(k < m.carmichael_lambda());
+        }
+    });
+}
+
+#[test]
+fn mod_discrete_log_properties() {
+    mod_discrete_log_properties_helper::<u8>();
+    mod_discrete_log_properties_helper::<u16>();
+    mod_discrete_log_properties_helper::<u32>();
+
+    unsigned_triple_gen_var_12::<u32>().test_properties(|(base, target, m)| {
+        assert_eq!(
+            u64::from(base)
+                .mod_discrete_log(u64::from(target), u64::from(m))
+                .map(u32::exact_from),
+            base.mod_discrete_log(target, m)
+        );
+    });
+}
@@ -0,0 +1,82 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::test_util::generators::unsigned_triple_gen_var_12;
+use std::panic::catch_unwind;
+
+fn mod_lucas_sequence_naive<T: PrimitiveUnsigned>(p: T, q: T, k: u64, m: T) -> (T, T) {
+    let mut u = (T::ZERO, T::ONE % m);
+    let mut v = (T::TWO % m, p);
+    for _ in 0..k {
+        u = (u.1, p.mod_mul(u.1, m).mod_sub(q.mod_mul(u.0, m), m));
+        v = (v.1, p.mod_mul(v.1, m).mod_sub(q.mod_mul(v.0, m), m));
+    }
+    (u.0, v.0)
+}
+
+#[test]
+fn test_mod_lucas_sequence() {
+    fn test<T: PrimitiveUnsigned>(p: T, q: T, k: u64, m: T, out: (T, T)) {
+        assert_eq!(p.mod_lucas_sequence(q, k, m), out);
+        assert_eq!(mod_lucas_sequence_naive(p, q, k, m), out);
+    }
+    test::<u8>(0, 0, 0, 1, (0, 0));
+    test::<u8>(0, 0, 5, 1, (0, 0));
+    test::<u8>(1, 9, 0, 10, (0, 2));
+    test::<u8>(1, 9, 1, 10, (1, 1));
+    test::<u32>(1, 999, 10, 1000, (55, 123));
+    test::<u16>(3, 2, 10, 1000, (23, 25));
+    test::<u8>(5, 3, 17, 64, (17, 43));
+    test::<u64>(4, 1, 100, 1009, (219, 820));
+    test::<u8>(0, 0, 3, 7, (0, 0));
+    test::<u8>(0, 1, 2, 7, (0, 5));
+    test::<u64>(
+        1,
+        18446744073709551556,
+        1000000,
+        18446744073709551557,
+        (1531522294932794719, 17782668989762456554),
+    );
+}
+
+fn mod_lucas_sequence_fail_helper<T: PrimitiveUnsigned>() {
+    assert_panic!(T::ZERO.mod_lucas_sequence(T::ZERO, 1, T::ZERO));
+    assert_panic!(T::from(7u8).mod_lucas_sequence(T::ONE, 1, T::from(7u8)));
+    assert_panic!(T::ONE.mod_lucas_sequence(T::from(7u8), 1, T::from(7u8)));
+}
+
+#[test]
+fn mod_lucas_sequence_fail() {
+    apply_fn_to_unsigneds!(mod_lucas_sequence_fail_helper);
+}
+
+fn mod_lucas_sequence_properties_helper<T: PrimitiveUnsigned>() {
+    unsigned_triple_gen_var_12::<T>().test_properties(|(p, q, m)| {
+        for k in [0, 1, 2, 3, 10, 65] {
+            let (u, v) = p.mod_lucas_sequence(q, k, m);
+            assert!(u < m);
+            assert!(v < m);
+            assert_eq!((u, v), mod_lucas_sequence_naive(p, q, k, m));
+        }
+        // $U_{2k} = U_k V_k$ and $V_{2k} = V_k^2 - 2Q^k$
+        let (u, v) = p.mod_lucas_sequence(q, 50, m);
+        let (u_2, v_2) = p.mod_lucas_sequence(q, 100, m);
+        assert_eq!(u.mod_mul(v, m), u_2);
+        assert_eq!(
+            v.mod_square(m)
+                .mod_sub((T::TWO % m).mod_mul(q.mod_pow(50, m), m), m),
+            v_2
+        );
+    });
+}
+
+#[test]
+fn mod_lucas_sequence_properties() {
+    apply_fn_to_unsigneds!(mod_lucas_sequence_properties_helper);
+}
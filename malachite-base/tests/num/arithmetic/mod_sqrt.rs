@@ -0,0 +1,167 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::ModSqrt;
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::factorization::traits::{NextPrime, PreviousPrime};
+use malachite_base::test_util::generators::unsigned_pair_gen_var_16;
+use malachite_base::test_util::num::arithmetic::mod_sqrt::mod_sqrt_naive;
+use std::panic::catch_unwind;
+
+fn mod_sqrt_helper<T: PrimitiveUnsigned>() {
+    let test = |x: u8, p: u8, out: Option<u8>| {
+        let x = T::from(x);
+        let p = T::from(p);
+        let out = out.map(T::from);
+        assert_eq!(x.mod_sqrt(p), out);
+        assert_eq!(mod_sqrt_naive(x, p), out);
+    };
+    test(0, 2, Some(0));
+    test(1, 2, Some(1));
+    test(0, 7, Some(0));
+    test(1, 7, Some(1));
+    test(2, 7, Some(3));
+    test(3, 7, None);
+    test(4, 7, Some(2));
+    test(10, 13, Some(6));
+    test(11, 13, None);
+    test(15, 17, Some(7));
+    test(3, 97, Some(10));
+    test(5, 97, None);
+    test(100, 251, Some(10));
+}
+
+#[test]
+fn test_mod_sqrt() {
+    apply_fn_to_unsigneds!(mod_sqrt_helper);
+
+    let test = |x: u64, p: u64, out: Option<u64>| {
+        assert_eq!(x.mod_sqrt(p), out);
+    };
+    test(2, 1000000009, Some(291087696));
+    test(5, 1000000009, Some(383008016));
+    test(7, 1000000009, Some(121078727));
+    // 998244353 = 119 * 2^23 + 1
+    test(3, 998244353, None);
+    test(123456789, 998244353, Some(154095821));
+    test(10, 18446744073709551557, Some(2952772625122071245));
+    test(3, 18446744073709551557, None);
+    // 2^64 - 2^32 + 1
+    test(5, 18446744069414584321, Some(4828663060389951155));
+
+    assert_eq!(
+        2u128.mod_sqrt(340282366920938463463374607431768211297),
+        Some(166739981435469030752172800393460694289)
+    );
+    assert_eq!(
+        3u128.mod_sqrt(340282366920938463463374607431768211297),
+        Some(37588732492396219416677157024388183606)
+    );
+}
+
+fn mod_sqrt_fail_helper<T: PrimitiveUnsigned>() {
+    assert_panic!(T::ZERO.mod_sqrt(T::ZERO));
+    assert_panic!(T::from(7u8).mod_sqrt(T::from(7u8)));
+    assert_panic!(T::from(10u8).mod_sqrt(T::from(7u8)));
+}
+
+#[test]
+fn mod_sqrt_fail() {
+    apply_fn_to_unsigneds!(mod_sqrt_fail_helper);
+}
+
+#[test]
+fn test_mod_prime_power_sqrt() {
+    fn test<T: PrimitiveUnsigned>(x: T, p: T, pow: u64, out: Option<T>) {
+        assert_eq!(x.mod_prime_power_sqrt(p, pow), out);
+        if let Some(m) = p.checked_pow(pow) {
+            if m.significant_bits() <= 12 {
+                assert_eq!(mod_sqrt_naive(x, m), out);
+            }
+        }
+    }
+    test::<u8>(0, 2, 0, Some(0));
+    test::<u8>(0, 5, 2, Some(0));
+    test::<u8>(1, 2, 1, Some(1));
+    test::<u8>(3, 2, 2, None);
+    test::<u8>(1, 2, 3, Some(1));
+    test::<u8>(5, 2, 3, None);
+    test::<u8>(4, 2, 4, Some(2));
+    test::<u8>(17, 2, 5, Some(7));
+    test::<u8>(1, 2, 7, Some(1));
+    test::<u8>(9, 3, 3, Some(3));
+    test::<u8>(3, 3, 3, None);
+    test::<u8>(2, 5, 1, None);
+    test::<u16>(12, 2, 8, None);
+    test::<u16>(2, 7, 3, Some(108));
+    test::<u16>(100, 5, 5, Some(10));
+    test::<u64>(41, 2, 63, Some(1955243926911477453));
+    test::<u64>(2, 7, 22, Some(1830612359265707720));
+    test::<u64>(98, 7, 22, Some(32268359055578121));
+    test::<u64>(10, 3, 40, Some(5205248770455236438));
+    test::<u64>(100, 5, 27, Some(10));
+    test::<u128>(41, 2, 64, Some(1955243926911477453));
+    test::<u128>(164, 2, 64, Some(701198164604432998));
+}
+
+fn mod_prime_power_sqrt_fail_helper<T: PrimitiveUnsigned>() {
+    assert_panic!(T::from(8u8).mod_prime_power_sqrt(T::TWO, 3));
+    assert_panic!(T::ONE.mod_prime_power_sqrt(T::TWO, T::WIDTH));
+}
+
+#[test]
+fn mod_prime_power_sqrt_fail() {
+    apply_fn_to_unsigneds!(mod_prime_power_sqrt_fail_helper);
+}
+
+fn mod_sqrt_properties_helper<T: NextPrime + PreviousPrime + PrimitiveUnsigned>() {
+    unsigned_pair_gen_var_16::<T>().test_properties(|(x, m)| {
+        let Some(p) = m.checked_add(T::ONE).and_then(|m| m.previous_prime()) else {
+            return;
+        };
+        let x = x % p;
+        let result = x.mod_sqrt(p);
+        if let Some(r) = result {
+            assert!(r < p);
+            assert_eq!(r.mod_square(p), x);
+            assert!(r == T::ZERO || r <= p - r);
+        } else {
+            assert_eq!(x.legendre_symbol(p), -1);
+        }
+        if p.significant_bits() <= 12 {
+            assert_eq!(mod_sqrt_naive(x, p), result);
+        }
+        let r = x.mod_square(p).mod_sqrt(p).unwrap();
+        assert_eq!(r, if x <= p - x { x } else { p - x });
+    });
+
+    unsigned_pair_gen_var_16::<T>().test_properties(|(x, y)| {
+        let p = (y % T::from(32u8)).next_prime();
+        let pow = T::MAX.floor_log_base(p);
+        let pk = p.pow(pow);
+        let x = x % pk;
+        let result = x.mod_prime_power_sqrt(p, pow);
+        if let Some(r) = result {
+            assert!(r < pk);
+            assert_eq!(r.mod_square(pk), x);
+        }
+        let r = x.mod_square(pk).mod_prime_power_sqrt(p, pow).unwrap();
+        assert!(r <= x);
+        let x = x % p;
+        assert_eq!(x.mod_prime_power_sqrt(p, 1), x.mod_sqrt(p));
+    });
+}
+
+#[test]
+fn mod_sqrt_properties() {
+    mod_sqrt_properties_helper::<u8>();
+    mod_sqrt_properties_helper::<u16>();
+    mod_sqrt_properties_helper::<u32>();
+    mod_sqrt_properties_helper::<u64>();
+    mod_sqrt_properties_helper::<usize>();
+}
@@ -0,0 +1,184 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use itertools::Itertools;
+use malachite_base::iterators::comparison::is_strictly_ascending;
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::exhaustive::exhaustive_positive_primitive_ints;
+use malachite_base::num::factorization::factor::Factors;
+use malachite_base::num::factorization::totient::factorization;
+use malachite_base::num::factorization::traits::{
+    DivisorCount, DivisorSigma, Divisors, Factor, IsPrime,
+};
+use malachite_base::test_util::generators::unsigned_gen_var_1;
+use std::panic::catch_unwind;
+
+#[test]
+fn test_divisor_count() {
+    fn test<T: DivisorCount + Factor<FACTORS = Factors<T>> + PrimitiveUnsigned>(n: T, out: T) {
+        assert_eq!(n.divisor_count(), out);
+        assert_eq!(
+            T::divisor_count_from_factorization(&factorization(n.factor())),
+            out
+        );
+    }
+    test::<u8>(1, 1);
+    test::<u8>(2, 2);
+    test::<u8>(12, 6);
+    test::<u8>(36, 9);
+    test::<u8>(97, 2);
+    test::<u8>(100, 9);
+    test::<u16>(561, 8);
+    test::<u32>(65536, 17);
+    test::<u32>(1000000, 49);
+    test::<u32>(4294967295, 32);
+    test::<u64>(18446744073709551615, 128);
+    test::<usize>(1000000, 49);
+}
+
+#[test]
+fn test_divisor_sigma() {
+    fn test<T: DivisorSigma + Factor<FACTORS = Factors<T>> + PrimitiveUnsigned>(
+        n: T,
+        k: u64,
+        out: T,
+    ) {
+        assert_eq!(n.divisor_sigma(k), out);
+        assert_eq!(
+            T::divisor_sigma_from_factorization(&factorization(n.factor()), k),
+            out
+        );
+    }
+    test::<u8>(1, 0, 1);
+    test::<u8>(1, 1, 1);
+    test::<u8>(1, 100, 1);
+    test::<u8>(12, 0, 6);
+    test::<u8>(12, 1, 28);
+    test::<u8>(12, 2, 210);
+    test::<u8>(28, 1, 56);
+    test::<u16>(255, 1, 432);
+    test::<u32>(100, 3, 1149823);
+}
+
+#[test]
+fn test_divisors() {
+    fn test<T: Divisors + Factor<FACTORS = Factors<T>> + PrimitiveUnsigned>(n: T, out: &[T]) {
+        assert_eq!(n.divisors().collect_vec(), out);
+        assert_eq!(
+            T::divisors_from_factorization(&factorization(n.factor())).collect_vec(),
+            out
+        );
+    }
+    test::<u8>(1, &[1]);
+    test::<u8>(2, &[1, 2]);
+    test::<u8>(12, &[1, 2, 3, 4, 6, 12]);
+    test::<u8>(97, &[1, 97]);
+    test::<u16>(60, &[1, 2, 3, 4, 5, 6, 10, 12, 15, 20, 30, 60]);
+    test::<u32>(
+        65536,
+        &[1, 2, 4, 8, 16, 32, 64, 128, 256, 512, 1024, 2048, 4096, 8192, 16384, 32768, 65536],
+    );
+    test::<u64>(4294967297, &[1, 641, 6700417, 4294967297]);
+}
+
+fn divisors_fail_helper<T: DivisorCount + DivisorSigma + Divisors + PrimitiveUnsigned>() {
+    assert_panic!(T::ZERO.divisor_count());
+    assert_panic!(T::ZERO.divisor_sigma(1));
+    assert_panic!(T::ZERO.divisors());
+    assert_panic!(T::divisor_count_from_factorization(&[(T::TWO, 0)]));
+    assert_panic!(T::divisor_sigma_from_factorization(&[(T::TWO, 0)], 1));
+    assert_panic!(T::divisors_from_factorization(&[(T::TWO, 0)]));
+    assert_panic!(T::MAX.divisor_sigma(1));
+}
+
+#[test]
+fn divisors_fail() {
+    divisors_fail_helper::<u8>();
+    divisors_fail_helper::<u16>();
+    divisors_fail_helper::<u32>();
+    divisors_fail_helper::<u64>();
+    divisors_fail_helper::<usize>();
+    assert_panic!(2u8.divisor_sigma(8));
+}
+
+fn divisors_naive<T: PrimitiveUnsigned>(n: T) -> Vec<T> {
+    exhaustive_positive_primitive_ints::<T>()
+        .take_while(|&d| d <= n)
+        .filter(|&d| n.divisible_by(d))
+        .collect()
+}
+
+fn divisors_properties_helper_helper<
+    T: DivisorCount
+        + DivisorSigma
+        + Divisors
+        + Factor<FACTORS = Factors<T>>
+        + IsPrime
+        + PrimitiveUnsigned,
+>(
+    n: T,
+) {
+    let factors = factorization(n.factor());
+    let divisors = n.divisors().collect_vec();
+    assert_eq!(
+        T::divisors_from_factorization(&factors).collect_vec(),
+        divisors
+    );
+    assert!(is_strictly_ascending(divisors.iter()));
+    assert_eq!(divisors.first(), Some(&T::ONE));
+    assert_eq!(divisors.last(), Some(&n));
+    for &d in &divisors {
+        assert!(n.divisible_by(d));
+        assert!(divisors.binary_search(&(n / d)).is_ok());
+    }
+    let count = n.divisor_count();
+    assert_eq!(T::divisor_count_from_factorization(&factors), count);
+    assert_eq!(T::exact_from(divisors.len()), count);
+    assert_eq!(count == T::TWO, n.is_prime());
+    assert_eq!(n.divisor_sigma(0), count);
+    if let Some(sigma) = divisors
+        .iter()
+        .try_fold(T::ZERO, |sum, &d| sum.checked_add(d))
+    {
+        assert_eq!(n.divisor_sigma(1), sigma);
+        assert_eq!(T::divisor_sigma_from_factorization(&factors, 1), sigma);
+        assert!(sigma > n || n == T::ONE);
+    }
+}
+
+fn divisors_properties_helper<
+    T: DivisorCount
+        + DivisorSigma
+        + Divisors
+        + Factor<FACTORS = Factors<T>>
+        + IsPrime
+        + PrimitiveUnsigned,
+>() {
+    for n in exhaustive_positive_primitive_ints::<T>().take(1000) {
+        assert_eq!(n.divisors().collect_vec(), divisors_naive(n));
+    }
+    if T::WIDTH < u32::WIDTH {
+        for n in exhaustive_positive_primitive_ints::<T>() {
+            divisors_properties_helper_helper(n);
+        }
+    } else {
+        unsigned_gen_var_1::<T>().test_properties(|n| {
+            divisors_properties_helper_helper(n);
+        });
+    }
+}
+
+#[test]
+fn divisors_properties() {
+    divisors_properties_helper::<u8>();
+    divisors_properties_helper::<u16>();
+    divisors_properties_helper::<u32>();
+    divisors_properties_helper::<u64>();
+    divisors_properties_helper::<usize>();
+}
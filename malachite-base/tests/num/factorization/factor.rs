@@ -0,0 +1,173 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use itertools::Itertools;
+use malachite_base::iterators::comparison::is_strictly_ascending;
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::exhaustive::exhaustive_positive_primitive_ints;
+use malachite_base::num::factorization::factor::Factors;
+use malachite_base::num::factorization::traits::{Factor, IsPrime};
+use malachite_base::test_util::generators::{unsigned_gen_var_1, unsigned_pair_gen_var_50};
+use malachite_base::test_util::num::factorization::factor::factor_naive;
+use std::panic::catch_unwind;
+
+fn factor_helper<T: Factor<FACTORS = Factors<T>> + PrimitiveUnsigned>() {
+    let test = |n: u64, out: &[(u64, u8)]| {
+        if let Ok(n) = T::try_from(n) {
+            let factors = n.factor().into_iter().collect_vec();
+            let out = out
+                .iter()
+                .map(|&(p, e)| (T::exact_from(p), e))
+                .collect_vec();
+            assert_eq!(factors, out);
+            assert_eq!(factor_naive(n), out);
+        }
+    };
+    test(1, &[]);
+    test(2, &[(2, 1)]);
+    test(3, &[(3, 1)]);
+    test(4, &[(2, 2)]);
+    test(6, &[(2, 1), (3, 1)]);
+    test(8, &[(2, 3)]);
+    test(60, &[(2, 2), (3, 1), (5, 1)]);
+    test(128, &[(2, 7)]);
+    test(243, &[(3, 5)]);
+    test(255, &[(3, 1), (5, 1), (17, 1)]);
+    test(251, &[(251, 1)]);
+    test(1001, &[(7, 1), (11, 1), (13, 1)]);
+    test(65521, &[(65521, 1)]);
+    test(65535, &[(3, 1), (5, 1), (17, 1), (257, 1)]);
+    test(1048573 * 1048573, &[(1048573, 2)]);
+    test(4294967291, &[(4294967291, 1)]);
+    test(4294967295, &[(3, 1), (5, 1), (17, 1), (257, 1), (65537, 1)]);
+    test(
+        614889782588491410,
+        &[
+            (2, 1),
+            (3, 1),
+            (5, 1),
+            (7, 1),
+            (11, 1),
+            (13, 1),
+            (17, 1),
+            (19, 1),
+            (23, 1),
+            (29, 1),
+            (31, 1),
+            (37, 1),
+            (41, 1),
+            (43, 1),
+            (47, 1),
+        ],
+    );
+}
+
+#[test]
+fn test_factor() {
+    factor_helper::<u8>();
+    factor_helper::<u16>();
+    factor_helper::<u32>();
+    factor_helper::<u64>();
+    factor_helper::<usize>();
+
+    let test_u64 = |n: u64, out: &[(u64, u8)]| {
+        assert_eq!(n.factor().into_iter().collect_vec(), out);
+    };
+    test_u64(
+        u64::MAX,
+        &[(3, 1), (5, 1), (17, 1), (257, 1), (641, 1), (65537, 1), (6700417, 1)],
+    );
+    test_u64(18446744073709551557, &[(18446744073709551557, 1)]);
+    test_u64(1000000016000000063, &[(1000000007, 1), (1000000009, 1)]);
+    test_u64(4294967291 * 4294967279, &[(4294967279, 1), (4294967291, 1)]);
+    test_u64(1 << 63, &[(2, 63)]);
+    test_u64(3u64.pow(40), &[(3, 40)]);
+    test_u64(2097143u64.pow(3), &[(2097143, 3)]);
+    test_u64(
+        1099511627689 * 16769023,
+        &[(16769023, 1), (1099511627689, 1)],
+    );
+}
+
+fn factor_fail_helper<T: Factor + PrimitiveUnsigned>() {
+    assert_panic!(T::ZERO.factor());
+}
+
+#[test]
+fn factor_fail() {
+    factor_fail_helper::<u8>();
+    factor_fail_helper::<u16>();
+    factor_fail_helper::<u32>();
+    factor_fail_helper::<u64>();
+    factor_fail_helper::<usize>();
+}
+
+fn factor_properties_helper_helper<
+    T: Factor<FACTORS = Factors<T>> + IsPrime + PrimitiveUnsigned,
+>(
+    n: T,
+) {
+    let factors = n.factor();
+    assert_eq!(factors.len(), factors.into_iter().count());
+    assert_eq!(factors.is_empty(), n == T::ONE);
+    let pairs = factors.into_iter().collect_vec();
+    assert!(is_strictly_ascending(pairs.iter().map(|&(p, _)| p)));
+    let mut product = T::ONE;
+    for &(p, e) in &pairs {
+        assert!(p.is_prime());
+        assert_ne!(e, 0);
+        product *= p.pow(u64::from(e));
+    }
+    assert_eq!(product, n);
+    assert_eq!(n.is_prime(), pairs.len() == 1 && pairs[0].1 == 1);
+}
+
+fn factor_properties_helper_1<T: Factor<FACTORS = Factors<T>> + IsPrime + PrimitiveUnsigned>() {
+    if T::WIDTH < u32::WIDTH {
+        for n in exhaustive_positive_primitive_ints::<T>() {
+            let factors = n.factor();
+            assert_eq!(factors.into_iter().collect_vec(), factor_naive(n));
+            factor_properties_helper_helper(n);
+        }
+    } else {
+        for n in exhaustive_positive_primitive_ints::<T>().take(100_000) {
+            assert_eq!(n.factor().into_iter().collect_vec(), factor_naive(n));
+        }
+        unsigned_gen_var_1::<T>().test_properties(|n| {
+            factor_properties_helper_helper(n);
+        });
+    }
+}
+
+fn factor_properties_helper_2<
+    T: PrimitiveUnsigned,
+    DT: Factor<FACTORS = Factors<DT>> + From<T> + IsPrime + PrimitiveUnsigned,
+>() {
+    unsigned_pair_gen_var_50::<T>().test_properties(|(a, b)| {
+        let n = DT::from(a) * DT::from(b);
+        let factors = n.factor().into_iter().collect_vec();
+        for (p, e) in DT::from(a).factor().into_iter().chain(DT::from(b).factor()) {
+            let i = factors.iter().position(|&(q, _)| q == p).unwrap();
+            assert!(factors[i].1 >= e);
+        }
+        assert!(!n.is_prime());
+    });
+}
+
+#[test]
+fn factor_properties() {
+    factor_properties_helper_1::<u8>();
+    factor_properties_helper_1::<u16>();
+    factor_properties_helper_1::<u32>();
+    factor_properties_helper_1::<u64>();
+    factor_properties_helper_1::<usize>();
+
+    factor_properties_helper_2::<u16, u32>();
+    factor_properties_helper_2::<u32, u64>();
+}
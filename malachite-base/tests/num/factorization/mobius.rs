@@ -0,0 +1,117 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::exhaustive::exhaustive_positive_primitive_ints;
+use malachite_base::num::factorization::factor::Factors;
+use malachite_base::num::factorization::totient::factorization;
+use malachite_base::num::factorization::traits::{Divisors, Factor, IsPrime, Mobius};
+use malachite_base::test_util::generators::unsigned_gen_var_1;
+use std::panic::catch_unwind;
+
+#[test]
+fn test_mobius() {
+    fn test<T: Factor<FACTORS = Factors<T>> + Mobius + PrimitiveUnsigned>(n: T, out: i8) {
+        assert_eq!(n.mobius(), out);
+        assert_eq!(
+            T::mobius_from_factorization(&factorization(n.factor())),
+            out
+        );
+    }
+    test::<u8>(1, 1);
+    test::<u8>(2, -1);
+    test::<u8>(4, 0);
+    test::<u8>(6, 1);
+    test::<u8>(12, 0);
+    test::<u8>(30, -1);
+    test::<u8>(97, -1);
+    test::<u16>(561, -1);
+    test::<u32>(65536, 0);
+    test::<u32>(4294967295, -1);
+    test::<u64>(18446744073709551615, -1);
+    test::<usize>(1000000, 0);
+}
+
+fn mobius_fail_helper<T: Mobius + PrimitiveUnsigned>() {
+    assert_panic!(T::ZERO.mobius());
+    assert_panic!(T::mobius_from_factorization(&[(T::TWO, 0)]));
+}
+
+#[test]
+fn mobius_fail() {
+    mobius_fail_helper::<u8>();
+    mobius_fail_helper::<u16>();
+    mobius_fail_helper::<u32>();
+    mobius_fail_helper::<u64>();
+    mobius_fail_helper::<usize>();
+}
+
+fn mobius_naive<T: PrimitiveUnsigned>(mut n: T) -> i8 {
+    let mut mobius = 1;
+    let mut p = T::TWO;
+    while n != T::ONE {
+        if n.divisible_by(p) {
+            n /= p;
+            if n.divisible_by(p) {
+                return 0;
+            }
+            mobius = -mobius;
+        }
+        p += T::ONE;
+    }
+    mobius
+}
+
+fn mobius_properties_helper_helper<
+    T: Divisors + Factor<FACTORS = Factors<T>> + IsPrime + Mobius + PrimitiveUnsigned,
+>(
+    n: T,
+) {
+    let mobius = n.mobius();
+    assert_eq!(
+        T::mobius_from_factorization(&factorization(n.factor())),
+        mobius
+    );
+    assert!((-1..=1).contains(&mobius));
+    if n.is_prime() {
+        assert_eq!(mobius, -1);
+    }
+    if let Some(square) = n.checked_square() {
+        assert_eq!(square.mobius(), i8::from(n == T::ONE));
+    }
+    // The sum of $\mu(d)$ over the divisors $d$ of $n$ is 1 if $n = 1$ and 0 otherwise.
+    let sum: i64 = n.divisors().map(|d| i64::from(d.mobius())).sum();
+    assert_eq!(sum, i64::from(n == T::ONE));
+}
+
+fn mobius_properties_helper<
+    T: Divisors + Factor<FACTORS = Factors<T>> + IsPrime + Mobius + PrimitiveUnsigned,
+>() {
+    for n in exhaustive_positive_primitive_ints::<T>().take(1000) {
+        assert_eq!(n.mobius(), mobius_naive(n));
+    }
+    if T::WIDTH < u16::WIDTH {
+        for n in exhaustive_positive_primitive_ints::<T>() {
+            mobius_properties_helper_helper(n);
+        }
+    } else {
+        unsigned_gen_var_1::<T>().test_properties(|n| {
+            mobius_properties_helper_helper(n);
+        });
+    }
+}
+
+#[test]
+fn mobius_properties() {
+    mobius_properties_helper::<u8>();
+    mobius_properties_helper::<u16>();
+    mobius_properties_helper::<u32>();
+    mobius_properties_helper::<u64>();
+    mobius_properties_helper::<usize>();
+}
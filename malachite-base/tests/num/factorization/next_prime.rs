@@ -0,0 +1,113 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::exhaustive::exhaustive_unsigneds;
+use malachite_base::num::factorization::traits::{IsPrime, NextPrime, PreviousPrime};
+use malachite_base::test_util::generators::unsigned_gen;
+use std::panic::catch_unwind;
+
+fn next_prime_helper<T: IsPrime + NextPrime + PreviousPrime + PrimitiveUnsigned>() {
+    let test = |n: u64, next: u64, previous: Option<u64>| {
+        if let Ok(n) = T::try_from(n) {
+            if let Ok(next) = T::try_from(next) {
+                assert_eq!(n.next_prime(), next);
+            }
+            assert_eq!(n.previous_prime(), previous.map(T::exact_from));
+        }
+    };
+    test(0, 2, None);
+    test(1, 2, None);
+    test(2, 3, None);
+    test(3, 5, Some(2));
+    test(4, 5, Some(3));
+    test(10, 11, Some(7));
+    test(100, 101, Some(97));
+    test(251, 257, Some(241));
+    test(255, 257, Some(251));
+    test(65521, 65537, Some(65519));
+    test(65535, 65537, Some(65521));
+    test(4294967291, 4294967311, Some(4294967279));
+    test(4294967295, 4294967311, Some(4294967291));
+    test(1000000000000, 1000000000039, Some(999999999989));
+    test(
+        18446744073709551556,
+        18446744073709551557,
+        Some(18446744073709551533),
+    );
+}
+
+#[test]
+fn test_next_prime() {
+    next_prime_helper::<u8>();
+    next_prime_helper::<u16>();
+    next_prime_helper::<u32>();
+    next_prime_helper::<u64>();
+    next_prime_helper::<usize>();
+}
+
+fn next_prime_fail_helper<T: NextPrime + PrimitiveUnsigned>() {
+    assert_panic!(T::MAX.next_prime());
+}
+
+#[test]
+fn next_prime_fail() {
+    next_prime_fail_helper::<u8>();
+    next_prime_fail_helper::<u16>();
+    next_prime_fail_helper::<u32>();
+    next_prime_fail_helper::<u64>();
+    next_prime_fail_helper::<usize>();
+}
+
+fn next_prime_properties_helper_helper<
+    T: IsPrime + NextPrime + PreviousPrime + PrimitiveUnsigned,
+>(
+    n: T,
+) {
+    if let Some(p) = n.previous_prime() {
+        assert!(p < n);
+        assert!(p.is_prime());
+        if n.significant_bits() < T::WIDTH {
+            assert!(p.next_prime() >= n);
+        }
+    } else {
+        assert!(n <= T::TWO);
+    }
+    // By Bertrand's postulate, the next prime is representable.
+    if n.significant_bits() < T::WIDTH {
+        let p = n.next_prime();
+        assert!(p > n);
+        assert!(p.is_prime());
+        assert!(p == T::TWO || p.previous_prime().unwrap() <= n);
+    }
+}
+
+fn next_prime_properties_helper<T: IsPrime + NextPrime + PreviousPrime + PrimitiveUnsigned>() {
+    if T::WIDTH < u32::WIDTH {
+        for n in exhaustive_unsigneds::<T>() {
+            next_prime_properties_helper_helper(n);
+        }
+    } else {
+        for n in exhaustive_unsigneds::<T>().take(100_000) {
+            next_prime_properties_helper_helper(n);
+        }
+        unsigned_gen::<T>().test_properties(|n| {
+            next_prime_properties_helper_helper(n);
+        });
+    }
+}
+
+#[test]
+fn next_prime_properties() {
+    next_prime_properties_helper::<u8>();
+    next_prime_properties_helper::<u16>();
+    next_prime_properties_helper::<u32>();
+    next_prime_properties_helper::<u64>();
+    next_prime_properties_helper::<usize>();
+}
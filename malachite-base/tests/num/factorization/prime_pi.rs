@@ -0,0 +1,86 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::exhaustive::exhaustive_unsigneds;
+use malachite_base::num::factorization::traits::{IsPrime, PrimePi, Primes};
+use malachite_base::test_util::generators::unsigned_gen_var_5;
+use malachite_base::test_util::num::factorization::prime_pi::prime_pi_naive;
+
+fn prime_pi_helper<T: PrimePi + Primes + PrimitiveUnsigned>() {
+    let test = |n: u64, out: u64| {
+        if let Ok(n) = T::try_from(n) {
+            assert_eq!(n.prime_pi(), T::exact_from(out));
+            if out < 1000000 {
+                assert_eq!(prime_pi_naive(n), T::exact_from(out));
+            }
+        }
+    };
+    test(0, 0);
+    test(1, 0);
+    test(2, 1);
+    test(3, 2);
+    test(4, 2);
+    test(10, 4);
+    test(100, 25);
+    test(255, 54);
+    test(1000, 168);
+    test(10201, 1252);
+    test(65535, 6542);
+    // - n >= PRIME_PI_SIEVE_LIMIT
+    test(65536, 6542);
+    test(1000000, 78498);
+    // - square of a prime
+    test(66049, 6595);
+    test(1018081, 79830);
+    test(10000000, 664579);
+    test(100000000, 5761455);
+    test(1000000000, 50847534);
+    test(4294967295, 203280221);
+    test(10000000000, 455052511);
+}
+
+#[test]
+fn test_prime_pi() {
+    prime_pi_helper::<u8>();
+    prime_pi_helper::<u16>();
+    prime_pi_helper::<u32>();
+    prime_pi_helper::<u64>();
+    prime_pi_helper::<usize>();
+}
+
+fn prime_pi_properties_helper<T: IsPrime + PrimePi + Primes + PrimitiveUnsigned>() {
+    if T::WIDTH < u32::WIDTH {
+        let mut count = T::ZERO;
+        for n in exhaustive_unsigneds::<T>() {
+            if n.is_prime() {
+                count += T::ONE;
+            }
+            assert_eq!(n.prime_pi(), count);
+        }
+    } else {
+        unsigned_gen_var_5::<T>().test_properties(|n| {
+            let pi = n.prime_pi();
+            assert_eq!(prime_pi_naive(n), pi);
+            if n != T::ZERO {
+                let pi_previous = (n - T::ONE).prime_pi();
+                assert_eq!(pi - pi_previous, T::from(n.is_prime()));
+            }
+        });
+    }
+}
+
+#[test]
+fn prime_pi_properties() {
+    prime_pi_properties_helper::<u8>();
+    prime_pi_properties_helper::<u16>();
+    prime_pi_properties_helper::<u32>();
+    prime_pi_properties_helper::<u64>();
+    prime_pi_properties_helper::<usize>();
+}
@@ -0,0 +1,140 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::unsigneds::PrimitiveUnsigned;
+use malachite_base::num::exhaustive::exhaustive_positive_primitive_ints;
+use malachite_base::num::factorization::factor::Factors;
+use malachite_base::num::factorization::totient::factorization;
+use malachite_base::num::factorization::traits::{CarmichaelLambda, Factor, IsPrime, Totient};
+use malachite_base::test_util::generators::unsigned_gen_var_1;
+use std::panic::catch_unwind;
+
+#[test]
+fn test_totient() {
+    fn test<T: Factor<FACTORS = Factors<T>> + PrimitiveUnsigned + Totient>(n: T, out: T) {
+        assert_eq!(n.totient(), out);
+        assert_eq!(
+            T::totient_from_factorization(&factorization(n.factor())),
+            out
+        );
+    }
+    test::<u8>(1, 1);
+    test::<u8>(2, 1);
+    test::<u8>(12, 4);
+    test::<u8>(36, 12);
+    test::<u8>(97, 96);
+    test::<u8>(100, 40);
+    test::<u16>(561, 320);
+    test::<u32>(65536, 32768);
+    test::<u32>(1000000, 400000);
+    test::<u32>(4294967295, 2147483648);
+    test::<u64>(18446744073709551615, 9208981628670443520);
+    test::<usize>(1000000, 400000);
+}
+
+#[test]
+fn test_carmichael_lambda() {
+    fn test<T: CarmichaelLambda + Factor<FACTORS = Factors<T>> + PrimitiveUnsigned>(n: T, out: T) {
+        assert_eq!(n.carmichael_lambda(), out);
+        assert_eq!(
+            T::carmichael_lambda_from_factorization(&factorization(n.factor())),
+            out
+        );
+    }
+    test::<u8>(1, 1);
+    test::<u8>(2, 1);
+    test::<u8>(12, 2);
+    test::<u8>(36, 6);
+    test::<u8>(97, 96);
+    test::<u8>(100, 20);
+    test::<u16>(561, 80);
+    test::<u32>(65536, 16384);
+    test::<u32>(1000000, 50000);
+    test::<u32>(4294967295, 65536);
+    test::<u64>(18446744073709551615, 17153064960);
+    test::<usize>(1000000, 50000);
+}
+
+fn totient_fail_helper<T: CarmichaelLambda + PrimitiveUnsigned + Totient>() {
+    assert_panic!(T::ZERO.totient());
+    assert_panic!(T::ZERO.carmichael_lambda());
+    assert_panic!(T::totient_from_factorization(&[(T::TWO, 0)]));
+    assert_panic!(T::carmichael_lambda_from_factorization(&[(T::TWO, 0)]));
+}
+
+#[test]
+fn totient_fail() {
+    totient_fail_helper::<u8>();
+    totient_fail_helper::<u16>();
+    totient_fail_helper::<u32>();
+    totient_fail_helper::<u64>();
+    totient_fail_helper::<usize>();
+}
+
+fn totient_naive<T: PrimitiveUnsigned>(n: T) -> T {
+    let mut totient = T::ZERO;
+    let mut k = T::ONE;
+    loop {
+        if k.coprime_with(n) {
+            totient += T::ONE;
+        }
+        if k == n {
+            return totient;
+        }
+        k += T::ONE;
+    }
+}
+
+fn totient_properties_helper_helper<
+    T: CarmichaelLambda + Factor<FACTORS = Factors<T>> + IsPrime + PrimitiveUnsigned + Totient,
+>(
+    n: T,
+) {
+    let factors = factorization(n.factor());
+    let totient = n.totient();
+    assert_eq!(T::totient_from_factorization(&factors), totient);
+    assert_ne!(totient, T::ZERO);
+    assert!(totient <= n);
+    assert_eq!(totient == n - T::ONE, n.is_prime());
+    let lambda = n.carmichael_lambda();
+    assert_eq!(T::carmichael_lambda_from_factorization(&factors), lambda);
+    assert!(totient.divisible_by(lambda));
+    for (p, _) in factors {
+        assert!(lambda.divisible_by(p - T::ONE));
+    }
+    if n > T::TWO {
+        assert_eq!(T::TWO.mod_pow(lambda.exact_into(), n) == T::ONE, n.odd());
+    }
+}
+
+fn totient_properties_helper<
+    T: CarmichaelLambda + Factor<FACTORS = Factors<T>> + IsPrime + PrimitiveUnsigned + Totient,
+>() {
+    for n in exhaustive_positive_primitive_ints::<T>().take(1000) {
+        assert_eq!(n.totient(), totient_naive(n));
+    }
+    if T::WIDTH < u32::WIDTH {
+        for n in exhaustive_positive_primitive_ints::<T>() {
+            totient_properties_helper_helper(n);
+        }
+    } else {
+        unsigned_gen_var_1::<T>().test_properties(|n| {
+            totient_properties_helper_helper(n);
+        });
+    }
+}
+
+#[test]
+fn totient_properties() {
+    totient_properties_helper::<u8>();
+    totient_properties_helper::<u16>();
+    totient_properties_helper::<u32>();
+    totient_properties_helper::<u64>();
+    totient_properties_helper::<usize>();
+}
@@ -0,0 +1,105 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Decimal;
+use malachite_base::num::arithmetic::traits::{Abs, AbsAssign};
+
+impl Abs for Decimal {
+    type Output = Decimal;
+
+    /// Takes the absolute value of a [`Decimal`], taking the [`Decimal`] by value. The scale is
+    /// unchanged.
+    ///
+    /// $$
+    /// f(x) = |x|.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Abs;
+    /// use malachite_decimal::Decimal;
+    ///
+    /// assert_eq!("0.00".parse::<Decimal>().unwrap().abs().to_string(), "0.00");
+    /// assert_eq!("1.50".parse::<Decimal>().unwrap().abs().to_string(), "1.50");
+    /// assert_eq!("-1.50".parse::<Decimal>().unwrap().abs().to_string(), "1.50");
+    /// ```
+    fn abs(mut self) -> Decimal {
+        self.unscaled.abs_assign();
+        self
+    }
+}
+
+impl Abs for &Decimal {
+    type Output = Decimal;
+
+    /// Takes the absolute value of a [`Decimal`], taking the [`Decimal`] by reference. The scale
+    /// is unchanged.
+    ///
+    /// $$
+    /// f(x) = |x|.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.unscaled().significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Abs;
+    /// use malachite_decimal::Decimal;
+    ///
+    /// assert_eq!((&"0.00".parse::<Decimal>().unwrap()).abs().to_string(), "0.00");
+    /// assert_eq!((&"1.50".parse::<Decimal>().unwrap()).abs().to_string(), "1.50");
+    /// assert_eq!((&"-1.50".parse::<Decimal>().unwrap()).abs().to_string(), "1.50");
+    /// ```
+    fn abs(self) -> Decimal {
+        Decimal {
+            unscaled: (&self.unscaled).abs(),
+            scale: self.scale,
+        }
+    }
+}
+
+impl AbsAssign for Decimal {
+    /// Replaces a [`Decimal`] with its absolute value. The scale is unchanged.
+    ///
+    /// $$
+    /// x \gets |x|.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::AbsAssign;
+    /// use malachite_decimal::Decimal;
+    ///
+    /// let mut x = "0.00".parse::<Decimal>().unwrap();
+    /// x.abs_assign();
+    /// assert_eq!(x.to_string(), "0.00");
+    ///
+    /// let mut x = "1.50".parse::<Decimal>().unwrap();
+    /// x.abs_assign();
+    /// assert_eq!(x.to_string(), "1.50");
+    ///
+    /// let mut x = "-1.50".parse::<Decimal>().unwrap();
+    /// x.abs_assign();
+    /// assert_eq!(x.to_string(), "1.50");
+    /// ```
+    fn abs_assign(&mut self) {
+        self.unscaled.abs_assign();
+    }
+}
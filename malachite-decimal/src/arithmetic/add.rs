@@ -0,0 +1,259 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::{power_of_10, Decimal};
+use core::cmp::max;
+use core::cmp::Ordering::*;
+use core::mem::swap;
+use core::ops::{Add, AddAssign};
+
+// Multiplies the unscaled value of `x` by a power of 10 so that its scale becomes `scale`, which
+// must be at least the current scale of `x`. The value of `x` is unchanged.
+pub(crate) fn upscale_assign(x: &mut Decimal, scale: i64) {
+    if scale > x.scale {
+        x.unscaled *= power_of_10(scale.abs_diff(x.scale));
+        x.scale = scale;
+    }
+}
+
+impl Add<Decimal> for Decimal {
+    type Output = Decimal;
+
+    /// Adds two [`Decimal`]s, taking both by value. The sum is exact, and its scale is the larger
+    /// of the two scales.
+    ///
+    /// $$
+    /// f(x, y) = x + y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the number of bits in the larger
+    /// unscaled value after both [`Decimal`]s have been brought to the same scale.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_decimal::Decimal;
+    ///
+    /// let x = "1.5".parse::<Decimal>().unwrap();
+    /// let y = "2.25".parse::<Decimal>().unwrap();
+    /// assert_eq!((x + y).to_string(), "3.75");
+    ///
+    /// let x = "1.50".parse::<Decimal>().unwrap();
+    /// let y = "-1.5".parse::<Decimal>().unwrap();
+    /// assert_eq!((x + y).to_string(), "0.00");
+    /// ```
+    #[inline]
+    fn add(mut self, other: Decimal) -> Decimal {
+        self += other;
+        self
+    }
+}
+
+impl Add<&Decimal> for Decimal {
+    type Output = Decimal;
+
+    /// Adds two [`Decimal`]s, taking the first by value and the second by reference. The sum is
+    /// exact, and its scale is the larger of the two scales.
+    ///
+    /// $$
+    /// f(x, y) = x + y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the number of bits in the larger
+    /// unscaled value after both [`Decimal`]s have been brought to the same scale.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_decimal::Decimal;
+    ///
+    /// let x = "1.5".parse::<Decimal>().unwrap();
+    /// let y = "2.25".parse::<Decimal>().unwrap();
+    /// assert_eq!((x + &y).to_string(), "3.75");
+    ///
+    /// let x = "1.50".parse::<Decimal>().unwrap();
+    /// let y = "-1.5".parse::<Decimal>().unwrap();
+    /// assert_eq!((x + &y).to_string(), "0.00");
+    /// ```
+    #[inline]
+    fn add(mut self, other: &Decimal) -> Decimal {
+        self += other;
+        self
+    }
+}
+
+impl Add<Decimal> for &Decimal {
+    type Output = Decimal;
+
+    /// Adds two [`Decimal`]s, taking the first by reference and the second by value. The sum is
+    /// exact, and its scale is the larger of the two scales.
+    ///
+    /// $$
+    /// f(x, y) = x + y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the number of bits in the larger
+    /// unscaled value after both [`Decimal`]s have been brought to the same scale.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_decimal::Decimal;
+    ///
+    /// let x = "1.5".parse::<Decimal>().unwrap();
+    /// let y = "2.25".parse::<Decimal>().unwrap();
+    /// assert_eq!((&x + y).to_string(), "3.75");
+    ///
+    /// let x = "1.50".parse::<Decimal>().unwrap();
+    /// let y = "-1.5".parse::<Decimal>().unwrap();
+    /// assert_eq!((&x + y).to_string(), "0.00");
+    /// ```
+    #[inline]
+    fn add(self, mut other: Decimal) -> Decimal {
+        other += self;
+        other
+    }
+}
+
+impl Add<&Decimal> for &Decimal {
+    type Output = Decimal;
+
+    /// Adds two [`Decimal`]s, taking both by reference. The sum is exact, and its scale is the
+    /// larger of the two scales.
+    ///
+    /// $$
+    /// f(x, y) = x + y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the number of bits in the larger
+    /// unscaled value after both [`Decimal`]s have been brought to the same scale.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_decimal::Decimal;
+    ///
+    /// let x = "1.5".parse::<Decimal>().unwrap();
+    /// let y = "2.25".parse::<Decimal>().unwrap();
+    /// assert_eq!((&x + &y).to_string(), "3.75");
+    ///
+    /// let x = "1.50".parse::<Decimal>().unwrap();
+    /// let y = "-1.5".parse::<Decimal>().unwrap();
+    /// assert_eq!((&x + &y).to_string(), "0.00");
+    /// ```
+    fn add(self, other: &Decimal) -> Decimal {
+        let unscaled = match self.scale.cmp(&other.scale) {
+            Equal => &self.unscaled + &other.unscaled,
+            Greater => {
+                &self.unscaled + &other.unscaled * power_of_10(self.scale.abs_diff(other.scale))
+            }
+            Less => {
+                &self.unscaled * power_of_10(other.scale.abs_diff(self.scale)) + &other.unscaled
+            }
+        };
+        Decimal {
+            unscaled,
+            scale: max(self.scale, other.scale),
+        }
+    }
+}
+
+impl AddAssign<Decimal> for Decimal {
+    /// Adds a [`Decimal`] to a [`Decimal`] in place, taking the [`Decimal`] on the right-hand side
+    /// by value. The sum is exact, and its scale is the larger of the two scales.
+    ///
+    /// $$
+    /// x \gets x + y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the number of bits in the larger
+    /// unscaled value after both [`Decimal`]s have been brought to the same scale.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_decimal::Decimal;
+    ///
+    /// let mut x = "1.5".parse::<Decimal>().unwrap();
+    /// x += "2.25".parse::<Decimal>().unwrap();
+    /// assert_eq!(x.to_string(), "3.75");
+    ///
+    /// let mut x = "1.50".parse::<Decimal>().unwrap();
+    /// x += "-1.5".parse::<Decimal>().unwrap();
+    /// assert_eq!(x.to_string(), "0.00");
+    /// ```
+    fn add_assign(&mut self, mut other: Decimal) {
+        if self.scale < other.scale {
+            swap(self, &mut other);
+        }
+        upscale_assign(&mut other, self.scale);
+        self.unscaled += other.unscaled;
+    }
+}
+
+impl AddAssign<&Decimal> for Decimal {
+    /// Adds a [`Decimal`] to a [`Decimal`] in place, taking the [`Decimal`] on the right-hand side
+    /// by reference. The sum is exact, and its scale is the larger of the two scales.
+    ///
+    /// $$
+    /// x \gets x + y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the number of bits in the larger
+    /// unscaled value after both [`Decimal`]s have been brought to the same scale.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_decimal::Decimal;
+    ///
+    /// let mut x = "1.5".parse::<Decimal>().unwrap();
+    /// x += &"2.25".parse::<Decimal>().unwrap();
+    /// assert_eq!(x.to_string(), "3.75");
+    ///
+    /// let mut x = "1.50".parse::<Decimal>().unwrap();
+    /// x += &"-1.5".parse::<Decimal>().unwrap();
+    /// assert_eq!(x.to_string(), "0.00");
+    /// ```
+    fn add_assign(&mut self, other: &Decimal) {
+        match self.scale.cmp(&other.scale) {
+            Equal => self.unscaled += &other.unscaled,
+            Greater => {
+                self.unscaled += &other.unscaled * power_of_10(self.scale.abs_diff(other.scale));
+            }
+            Less => {
+                upscale_assign(self, other.scale);
+                self.unscaled += &other.unscaled;
+            }
+        }
+    }
+}
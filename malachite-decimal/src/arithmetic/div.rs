@@ -0,0 +1,606 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::{power_of_10, Decimal};
+use core::cmp::Ordering;
+use malachite_base::num::arithmetic::traits::DivRoundAssign;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+
+// Returns the power of 10 that the dividend (if nonnegative) or the divisor (if negative) must be
+// multiplied by so that the quotient of the unscaled values has the scale `scale`.
+fn quotient_shift(x: &Decimal, y: &Decimal, scale: i64) -> i128 {
+    assert!(y.unscaled != 0u32, "division by zero");
+    i128::from(scale) - i128::from(x.scale) + i128::from(y.scale)
+}
+
+impl Decimal {
+    /// Divides a [`Decimal`] by another [`Decimal`], taking both by value, and rounds the quotient
+    /// to the specified scale using the specified rounding mode. An [`Ordering`] is also returned,
+    /// indicating whether the rounded quotient is less than, equal to, or greater than the exact
+    /// quotient.
+    ///
+    /// The result is the multiple of $10^{-s}$ obtained by rounding $x/y$ with the rounding mode
+    /// $m$. Unlike addition, subtraction, and multiplication, the scale of the result does not
+    /// depend on the scales of the inputs.
+    ///
+    /// $$
+    /// f(x, y, s, m) = 10^{-s} g(10^s x/y, m),
+    /// $$
+    /// where $g(z, m)$ rounds $z$ to an integer using the rounding mode $m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.unscaled().significant_bits(), other.unscaled().significant_bits())` plus
+    /// $|s-s_x+s_y|$, where $s_x$ and $s_y$ are the scales of `self` and `other`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero, or if `rm` is `Exact` but $x/y$ is not a multiple of $10^{-s}$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_decimal::Decimal;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = "1".parse::<Decimal>().unwrap();
+    /// let y = "3".parse::<Decimal>().unwrap();
+    /// let (q, o) = x.div_scale_round(y.clone(), 4, Nearest);
+    /// assert_eq!(q.to_string(), "0.3333");
+    /// assert_eq!(o, Less);
+    ///
+    /// let x = "2.0".parse::<Decimal>().unwrap();
+    /// let (q, o) = x.div_scale_round(y.clone(), 2, Floor);
+    /// assert_eq!(q.to_string(), "0.66");
+    /// assert_eq!(o, Less);
+    ///
+    /// let x = "2.0".parse::<Decimal>().unwrap();
+    /// let (q, o) = x.div_scale_round(y.clone(), 2, Ceiling);
+    /// assert_eq!(q.to_string(), "0.67");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let x = "1".parse::<Decimal>().unwrap();
+    /// let y = "0.80".parse::<Decimal>().unwrap();
+    /// let (q, o) = x.div_scale_round(y.clone(), 3, Exact);
+    /// assert_eq!(q.to_string(), "1.250");
+    /// assert_eq!(o, Equal);
+    /// ```
+    #[inline]
+    pub fn div_scale_round(
+        mut self,
+        other: Decimal,
+        scale: i64,
+        rm: RoundingMode,
+    ) -> (Decimal, Ordering) {
+        let o = self.div_scale_round_assign(other, scale, rm);
+        (self, o)
+    }
+
+    /// Divides a [`Decimal`] by another [`Decimal`], taking the first by value and the second by
+    /// reference, and rounds the quotient to the specified scale using the specified rounding mode.
+    /// An [`Ordering`] is also returned, indicating whether the rounded quotient is less than,
+    /// equal to, or greater than the exact quotient.
+    ///
+    /// The result is the multiple of $10^{-s}$ obtained by rounding $x/y$ with the rounding mode
+    /// $m$. Unlike addition, subtraction, and multiplication, the scale of the result does not
+    /// depend on the scales of the inputs.
+    ///
+    /// $$
+    /// f(x, y, s, m) = 10^{-s} g(10^s x/y, m),
+    /// $$
+    /// where $g(z, m)$ rounds $z$ to an integer using the rounding mode $m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.unscaled().significant_bits(), other.unscaled().significant_bits())` plus
+    /// $|s-s_x+s_y|$, where $s_x$ and $s_y$ are the scales of `self` and `other`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero, or if `rm` is `Exact` but $x/y$ is not a multiple of $10^{-s}$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_decimal::Decimal;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = "1".parse::<Decimal>().unwrap();
+    /// let y = "3".parse::<Decimal>().unwrap();
+    /// let (q, o) = x.div_scale_round_val_ref(&y, 4, Nearest);
+    /// assert_eq!(q.to_string(), "0.3333");
+    /// assert_eq!(o, Less);
+    ///
+    /// let x = "2.0".parse::<Decimal>().unwrap();
+    /// let (q, o) = x.div_scale_round_val_ref(&y, 2, Floor);
+    /// assert_eq!(q.to_string(), "0.66");
+    /// assert_eq!(o, Less);
+    ///
+    /// let x = "2.0".parse::<Decimal>().unwrap();
+    /// let (q, o) = x.div_scale_round_val_ref(&y, 2, Ceiling);
+    /// assert_eq!(q.to_string(), "0.67");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let x = "1".parse::<Decimal>().unwrap();
+    /// let y = "0.80".parse::<Decimal>().unwrap();
+    /// let (q, o) = x.div_scale_round_val_ref(&y, 3, Exact);
+    /// assert_eq!(q.to_string(), "1.250");
+    /// assert_eq!(o, Equal);
+    /// ```
+    #[inline]
+    pub fn div_scale_round_val_ref(
+        mut self,
+        other: &Decimal,
+        scale: i64,
+        rm: RoundingMode,
+    ) -> (Decimal, Ordering) {
+        let o = self.div_scale_round_assign_ref(other, scale, rm);
+        (self, o)
+    }
+
+    /// Divides a [`Decimal`] by another [`Decimal`], taking the first by reference and the second
+    /// by value, and rounds the quotient to the specified scale using the specified rounding mode.
+    /// An [`Ordering`] is also returned, indicating whether the rounded quotient is less than,
+    /// equal to, or greater than the exact quotient.
+    ///
+    /// The result is the multiple of $10^{-s}$ obtained by rounding $x/y$ with the rounding mode
+    /// $m$. Unlike addition, subtraction, and multiplication, the scale of the result does not
+    /// depend on the scales of the inputs.
+    ///
+    /// $$
+    /// f(x, y, s, m) = 10^{-s} g(10^s x/y, m),
+    /// $$
+    /// where $g(z, m)$ rounds $z$ to an integer using the rounding mode $m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.unscaled().significant_bits(), other.unscaled().significant_bits())` plus
+    /// $|s-s_x+s_y|$, where $s_x$ and $s_y$ are the scales of `self` and `other`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero, or if `rm` is `Exact` but $x/y$ is not a multiple of $10^{-s}$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_decimal::Decimal;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = "1".parse::<Decimal>().unwrap();
+    /// let y = "3".parse::<Decimal>().unwrap();
+    /// let (q, o) = x.div_scale_round_ref_val(y.clone(), 4, Nearest);
+    /// assert_eq!(q.to_string(), "0.3333");
+    /// assert_eq!(o, Less);
+    ///
+    /// let x = "2.0".parse::<Decimal>().unwrap();
+    /// let (q, o) = x.div_scale_round_ref_val(y.clone(), 2, Floor);
+    /// assert_eq!(q.to_string(), "0.66");
+    /// assert_eq!(o, Less);
+    ///
+    /// let x = "2.0".parse::<Decimal>().unwrap();
+    /// let (q, o) = x.div_scale_round_ref_val(y.clone(), 2, Ceiling);
+    /// assert_eq!(q.to_string(), "0.67");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let x = "1".parse::<Decimal>().unwrap();
+    /// let y = "0.80".parse::<Decimal>().unwrap();
+    /// let (q, o) = x.div_scale_round_ref_val(y.clone(), 3, Exact);
+    /// assert_eq!(q.to_string(), "1.250");
+    /// assert_eq!(o, Equal);
+    /// ```
+    pub fn div_scale_round_ref_val(
+        &self,
+        other: Decimal,
+        scale: i64,
+        rm: RoundingMode,
+    ) -> (Decimal, Ordering) {
+        let mut x = self.clone();
+        let o = x.div_scale_round_assign(other, scale, rm);
+        (x, o)
+    }
+
+    /// Divides a [`Decimal`] by another [`Decimal`], taking both by reference, and rounds the
+    /// quotient to the specified scale using the specified rounding mode. An [`Ordering`] is also
+    /// returned, indicating whether the rounded quotient is less than, equal to, or greater than
+    /// the exact quotient.
+    ///
+    /// The result is the multiple of $10^{-s}$ obtained by rounding $x/y$ with the rounding mode
+    /// $m$. Unlike addition, subtraction, and multiplication, the scale of the result does not
+    /// depend on the scales of the inputs.
+    ///
+    /// $$
+    /// f(x, y, s, m) = 10^{-s} g(10^s x/y, m),
+    /// $$
+    /// where $g(z, m)$ rounds $z$ to an integer using the rounding mode $m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.unscaled().significant_bits(), other.unscaled().significant_bits())` plus
+    /// $|s-s_x+s_y|$, where $s_x$ and $s_y$ are the scales of `self` and `other`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero, or if `rm` is `Exact` but $x/y$ is not a multiple of $10^{-s}$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_decimal::Decimal;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = "1".parse::<Decimal>().unwrap();
+    /// let y = "3".parse::<Decimal>().unwrap();
+    /// let (q, o) = x.div_scale_round_ref_ref(&y, 4, Nearest);
+    /// assert_eq!(q.to_string(), "0.3333");
+    /// assert_eq!(o, Less);
+    ///
+    /// let x = "2.0".parse::<Decimal>().unwrap();
+    /// let (q, o) = x.div_scale_round_ref_ref(&y, 2, Floor);
+    /// assert_eq!(q.to_string(), "0.66");
+    /// assert_eq!(o, Less);
+    ///
+    /// let x = "2.0".parse::<Decimal>().unwrap();
+    /// let (q, o) = x.div_scale_round_ref_ref(&y, 2, Ceiling);
+    /// assert_eq!(q.to_string(), "0.67");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let x = "1".parse::<Decimal>().unwrap();
+    /// let y = "0.80".parse::<Decimal>().unwrap();
+    /// let (q, o) = x.div_scale_round_ref_ref(&y, 3, Exact);
+    /// assert_eq!(q.to_string(), "1.250");
+    /// assert_eq!(o, Equal);
+    /// ```
+    pub fn div_scale_round_ref_ref(
+        &self,
+        other: &Decimal,
+        scale: i64,
+        rm: RoundingMode,
+    ) -> (Decimal, Ordering) {
+        let mut x = self.clone();
+        let o = x.div_scale_round_assign_ref(other, scale, rm);
+        (x, o)
+    }
+
+    /// Divides a [`Decimal`] by another [`Decimal`] in place, taking the [`Decimal`] on the
+    /// right-hand side by value, and rounds the quotient to the specified scale using the specified
+    /// rounding mode. An [`Ordering`] is returned, indicating whether the rounded quotient is less
+    /// than, equal to, or greater than the exact quotient.
+    ///
+    /// See [`Decimal::div_scale_round`] for more details.
+    ///
+    /// $$
+    /// x \gets 10^{-s} g(10^s x/y, m),
+    /// $$
+    /// where $g(z, m)$ rounds $z$ to an integer using the rounding mode $m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.unscaled().significant_bits(), other.unscaled().significant_bits())` plus
+    /// $|s-s_x+s_y|$, where $s_x$ and $s_y$ are the scales of `self` and `other`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero, or if `rm` is `Exact` but $x/y$ is not a multiple of $10^{-s}$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_decimal::Decimal;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let y = "3".parse::<Decimal>().unwrap();
+    /// let mut x = "1".parse::<Decimal>().unwrap();
+    /// assert_eq!(x.div_scale_round_assign(y.clone(), 4, Nearest), Less);
+    /// assert_eq!(x.to_string(), "0.3333");
+    ///
+    /// let mut x = "2.0".parse::<Decimal>().unwrap();
+    /// assert_eq!(x.div_scale_round_assign(y.clone(), 2, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "0.67");
+    /// ```
+    pub fn div_scale_round_assign(
+        &mut self,
+        mut other: Decimal,
+        scale: i64,
+        rm: RoundingMode,
+    ) -> Ordering {
+        let k = quotient_shift(self, &other, scale);
+        self.scale = scale;
+        if k >= 0 {
+            self.unscaled *= power_of_10(u64::exact_from(k));
+            self.unscaled.div_round_assign(other.unscaled, rm)
+        } else {
+            other.unscaled *= power_of_10(u64::exact_from(-k));
+            self.unscaled.div_round_assign(other.unscaled, rm)
+        }
+    }
+
+    /// Divides a [`Decimal`] by another [`Decimal`] in place, taking the [`Decimal`] on the
+    /// right-hand side by reference, and rounds the quotient to the specified scale using the
+    /// specified rounding mode. An [`Ordering`] is returned, indicating whether the rounded
+    /// quotient is less than, equal to, or greater than the exact quotient.
+    ///
+    /// See [`Decimal::div_scale_round`] for more details.
+    ///
+    /// $$
+    /// x \gets 10^{-s} g(10^s x/y, m),
+    /// $$
+    /// where $g(z, m)$ rounds $z$ to an integer using the rounding mode $m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.unscaled().significant_bits(), other.unscaled().significant_bits())` plus
+    /// $|s-s_x+s_y|$, where $s_x$ and $s_y$ are the scales of `self` and `other`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero, or if `rm` is `Exact` but $x/y$ is not a multiple of $10^{-s}$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_decimal::Decimal;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let y = "3".parse::<Decimal>().unwrap();
+    /// let mut x = "1".parse::<Decimal>().unwrap();
+    /// assert_eq!(x.div_scale_round_assign_ref(&y, 4, Nearest), Less);
+    /// assert_eq!(x.to_string(), "0.3333");
+    ///
+    /// let mut x = "2.0".parse::<Decimal>().unwrap();
+    /// assert_eq!(x.div_scale_round_assign_ref(&y, 2, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "0.67");
+    /// ```
+    pub fn div_scale_round_assign_ref(
+        &mut self,
+        other: &Decimal,
+        scale: i64,
+        rm: RoundingMode,
+    ) -> Ordering {
+        let k = quotient_shift(self, other, scale);
+        self.scale = scale;
+        if k >= 0 {
+            self.unscaled *= power_of_10(u64::exact_from(k));
+            self.unscaled.div_round_assign(&other.unscaled, rm)
+        } else {
+            self.unscaled
+                .div_round_assign(&other.unscaled * power_of_10(u64::exact_from(-k)), rm)
+        }
+    }
+
+    /// Divides a [`Decimal`] by another [`Decimal`], taking both by value, and rounds the quotient
+    /// to the specified scale using the nearest rounding mode, breaking ties by choosing an even
+    /// unscaled value. An [`Ordering`] is also returned, indicating whether the rounded quotient is
+    /// less than, equal to, or greater than the exact quotient.
+    ///
+    /// See [`Decimal::div_scale_round`] for more details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.unscaled().significant_bits(), other.unscaled().significant_bits())` plus
+    /// $|s-s_x+s_y|$, where $s_x$ and $s_y$ are the scales of `self` and `other`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_decimal::Decimal;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let y = "3".parse::<Decimal>().unwrap();
+    /// let x = "2".parse::<Decimal>().unwrap();
+    /// let (q, o) = x.div_scale(y.clone(), 4);
+    /// assert_eq!(q.to_string(), "0.6667");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn div_scale(self, other: Decimal, scale: i64) -> (Decimal, Ordering) {
+        self.div_scale_round(other, scale, Nearest)
+    }
+
+    /// Divides a [`Decimal`] by another [`Decimal`], taking the first by value and the second by
+    /// reference, and rounds the quotient to the specified scale using the nearest rounding mode,
+    /// breaking ties by choosing an even unscaled value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded quotient is less than, equal to, or greater than the exact
+    /// quotient.
+    ///
+    /// See [`Decimal::div_scale_round_val_ref`] for more details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.unscaled().significant_bits(), other.unscaled().significant_bits())` plus
+    /// $|s-s_x+s_y|$, where $s_x$ and $s_y$ are the scales of `self` and `other`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_decimal::Decimal;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let y = "3".parse::<Decimal>().unwrap();
+    /// let x = "2".parse::<Decimal>().unwrap();
+    /// let (q, o) = x.div_scale_val_ref(&y, 4);
+    /// assert_eq!(q.to_string(), "0.6667");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn div_scale_val_ref(self, other: &Decimal, scale: i64) -> (Decimal, Ordering) {
+        self.div_scale_round_val_ref(other, scale, Nearest)
+    }
+
+    /// Divides a [`Decimal`] by another [`Decimal`], taking the first by reference and the second
+    /// by value, and rounds the quotient to the specified scale using the nearest rounding mode,
+    /// breaking ties by choosing an even unscaled value. An [`Ordering`] is also returned,
+    /// indicating whether the rounded quotient is less than, equal to, or greater than the exact
+    /// quotient.
+    ///
+    /// See [`Decimal::div_scale_round_ref_val`] for more details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.unscaled().significant_bits(), other.unscaled().significant_bits())` plus
+    /// $|s-s_x+s_y|$, where $s_x$ and $s_y$ are the scales of `self` and `other`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_decimal::Decimal;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let y = "3".parse::<Decimal>().unwrap();
+    /// let x = "2".parse::<Decimal>().unwrap();
+    /// let (q, o) = x.div_scale_ref_val(y.clone(), 4);
+    /// assert_eq!(q.to_string(), "0.6667");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn div_scale_ref_val(&self, other: Decimal, scale: i64) -> (Decimal, Ordering) {
+        self.div_scale_round_ref_val(other, scale, Nearest)
+    }
+
+    /// Divides a [`Decimal`] by another [`Decimal`], taking both by reference, and rounds the
+    /// quotient to the specified scale using the nearest rounding mode, breaking ties by choosing
+    /// an even unscaled value. An [`Ordering`] is also returned, indicating whether the rounded
+    /// quotient is less than, equal to, or greater than the exact quotient.
+    ///
+    /// See [`Decimal::div_scale_round_ref_ref`] for more details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.unscaled().significant_bits(), other.unscaled().significant_bits())` plus
+    /// $|s-s_x+s_y|$, where $s_x$ and $s_y$ are the scales of `self` and `other`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_decimal::Decimal;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let y = "3".parse::<Decimal>().unwrap();
+    /// let x = "2".parse::<Decimal>().unwrap();
+    /// let (q, o) = x.div_scale_ref_ref(&y, 4);
+    /// assert_eq!(q.to_string(), "0.6667");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn div_scale_ref_ref(&self, other: &Decimal, scale: i64) -> (Decimal, Ordering) {
+        self.div_scale_round_ref_ref(other, scale, Nearest)
+    }
+
+    /// Divides a [`Decimal`] by another [`Decimal`] in place, taking the [`Decimal`] on the
+    /// right-hand side by value, and rounds the quotient to the specified scale using the nearest
+    /// rounding mode, breaking ties by choosing an even unscaled value. An [`Ordering`] is
+    /// returned, indicating whether the rounded quotient is less than, equal to, or greater than
+    /// the exact quotient.
+    ///
+    /// See [`Decimal::div_scale_round_assign`] for more details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.unscaled().significant_bits(), other.unscaled().significant_bits())` plus
+    /// $|s-s_x+s_y|$, where $s_x$ and $s_y$ are the scales of `self` and `other`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_decimal::Decimal;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let y = "3".parse::<Decimal>().unwrap();
+    /// let mut x = "2".parse::<Decimal>().unwrap();
+    /// assert_eq!(x.div_scale_assign(y.clone(), 4), Greater);
+    /// assert_eq!(x.to_string(), "0.6667");
+    /// ```
+    #[inline]
+    pub fn div_scale_assign(&mut self, other: Decimal, scale: i64) -> Ordering {
+        self.div_scale_round_assign(other, scale, Nearest)
+    }
+
+    /// Divides a [`Decimal`] by another [`Decimal`] in place, taking the [`Decimal`] on the
+    /// right-hand side by reference, and rounds the quotient to the specified scale using the
+    /// nearest rounding mode, breaking ties by choosing an even unscaled value. An [`Ordering`] is
+    /// returned, indicating whether the rounded quotient is less than, equal to, or greater than
+    /// the exact quotient.
+    ///
+    /// See [`Decimal::div_scale_round_assign_ref`] for more details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.unscaled().significant_bits(), other.unscaled().significant_bits())` plus
+    /// $|s-s_x+s_y|$, where $s_x$ and $s_y$ are the scales of `self` and `other`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_decimal::Decimal;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let y = "3".parse::<Decimal>().unwrap();
+    /// let mut x = "2".parse::<Decimal>().unwrap();
+    /// assert_eq!(x.div_scale_assign_ref(&y, 4), Greater);
+    /// assert_eq!(x.to_string(), "0.6667");
+    /// ```
+    #[inline]
+    pub fn div_scale_assign_ref(&mut self, other: &Decimal, scale: i64) -> Ordering {
+        self.div_scale_round_assign_ref(other, scale, Nearest)
+    }
+}
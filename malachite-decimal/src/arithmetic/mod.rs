@@ -0,0 +1,27 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+/// Absolute value of [`Decimal`](crate::Decimal)s.
+pub mod abs;
+/// Addition of [`Decimal`](crate::Decimal)s.
+pub mod add;
+/// Division of [`Decimal`](crate::Decimal)s, rounding the quotient to a specified scale.
+pub mod div;
+/// Multiplication of [`Decimal`](crate::Decimal)s.
+pub mod mul;
+/// Negation of [`Decimal`](crate::Decimal)s.
+pub mod neg;
+/// Functions for removing the trailing zeros of a [`Decimal`](crate::Decimal)'s unscaled value.
+pub mod normalize;
+/// Functions for changing the scale of a [`Decimal`](crate::Decimal), rounding if necessary.
+pub mod rescale;
+/// An implementation of [`Sign`](malachite_base::num::arithmetic::traits::Sign), a trait for
+/// determining the sign of a number.
+pub mod sign;
+/// Subtraction of [`Decimal`](crate::Decimal)s.
+pub mod sub;
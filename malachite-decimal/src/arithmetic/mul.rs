@@ -0,0 +1,250 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Decimal;
+use core::ops::{Mul, MulAssign};
+
+const fn product_scale(x: i64, y: i64) -> i64 {
+    x.checked_add(y)
+        .expect("The scale of the product is out of range")
+}
+
+impl Mul<Decimal> for Decimal {
+    type Output = Decimal;
+
+    /// Multiplies two [`Decimal`]s, taking both by value. The product is exact, and its scale is
+    /// the sum of the two scales.
+    ///
+    /// $$
+    /// f(x, y) = xy.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.unscaled().significant_bits(), other.unscaled().significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if the sum of the scales is not an [`i64`].
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_decimal::Decimal;
+    ///
+    /// let x = "1.5".parse::<Decimal>().unwrap();
+    /// let y = "2.25".parse::<Decimal>().unwrap();
+    /// assert_eq!((x * y).to_string(), "3.375");
+    ///
+    /// let x = "1.50".parse::<Decimal>().unwrap();
+    /// let y = "-2.0".parse::<Decimal>().unwrap();
+    /// assert_eq!((x * y).to_string(), "-3.000");
+    /// ```
+    #[inline]
+    fn mul(mut self, other: Decimal) -> Decimal {
+        self *= other;
+        self
+    }
+}
+
+impl Mul<&Decimal> for Decimal {
+    type Output = Decimal;
+
+    /// Multiplies two [`Decimal`]s, taking the first by value and the second by reference. The
+    /// product is exact, and its scale is the sum of the two scales.
+    ///
+    /// $$
+    /// f(x, y) = xy.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.unscaled().significant_bits(), other.unscaled().significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if the sum of the scales is not an [`i64`].
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_decimal::Decimal;
+    ///
+    /// let x = "1.5".parse::<Decimal>().unwrap();
+    /// let y = "2.25".parse::<Decimal>().unwrap();
+    /// assert_eq!((x * &y).to_string(), "3.375");
+    ///
+    /// let x = "1.50".parse::<Decimal>().unwrap();
+    /// let y = "-2.0".parse::<Decimal>().unwrap();
+    /// assert_eq!((x * &y).to_string(), "-3.000");
+    /// ```
+    #[inline]
+    fn mul(mut self, other: &Decimal) -> Decimal {
+        self *= other;
+        self
+    }
+}
+
+impl Mul<Decimal> for &Decimal {
+    type Output = Decimal;
+
+    /// Multiplies two [`Decimal`]s, taking the first by reference and the second by value. The
+    /// product is exact, and its scale is the sum of the two scales.
+    ///
+    /// $$
+    /// f(x, y) = xy.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.unscaled().significant_bits(), other.unscaled().significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if the sum of the scales is not an [`i64`].
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_decimal::Decimal;
+    ///
+    /// let x = "1.5".parse::<Decimal>().unwrap();
+    /// let y = "2.25".parse::<Decimal>().unwrap();
+    /// assert_eq!((&x * y).to_string(), "3.375");
+    ///
+    /// let x = "1.50".parse::<Decimal>().unwrap();
+    /// let y = "-2.0".parse::<Decimal>().unwrap();
+    /// assert_eq!((&x * y).to_string(), "-3.000");
+    /// ```
+    #[inline]
+    fn mul(self, mut other: Decimal) -> Decimal {
+        other *= self;
+        other
+    }
+}
+
+impl Mul<&Decimal> for &Decimal {
+    type Output = Decimal;
+
+    /// Multiplies two [`Decimal`]s, taking both by reference. The product is exact, and its scale
+    /// is the sum of the two scales.
+    ///
+    /// $$
+    /// f(x, y) = xy.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.unscaled().significant_bits(), other.unscaled().significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if the sum of the scales is not an [`i64`].
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_decimal::Decimal;
+    ///
+    /// let x = "1.5".parse::<Decimal>().unwrap();
+    /// let y = "2.25".parse::<Decimal>().unwrap();
+    /// assert_eq!((&x * &y).to_string(), "3.375");
+    ///
+    /// let x = "1.50".parse::<Decimal>().unwrap();
+    /// let y = "-2.0".parse::<Decimal>().unwrap();
+    /// assert_eq!((&x * &y).to_string(), "-3.000");
+    /// ```
+    fn mul(self, other: &Decimal) -> Decimal {
+        Decimal {
+            unscaled: &self.unscaled * &other.unscaled,
+            scale: product_scale(self.scale, other.scale),
+        }
+    }
+}
+
+impl MulAssign<Decimal> for Decimal {
+    /// Multiplies a [`Decimal`] by a [`Decimal`] in place, taking the [`Decimal`] on the right-hand
+    /// side by value. The product is exact, and its scale is the sum of the two scales.
+    ///
+    /// $$
+    /// x \gets xy.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.unscaled().significant_bits(), other.unscaled().significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if the sum of the scales is not an [`i64`].
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_decimal::Decimal;
+    ///
+    /// let mut x = "1.5".parse::<Decimal>().unwrap();
+    /// x *= "2.25".parse::<Decimal>().unwrap();
+    /// assert_eq!(x.to_string(), "3.375");
+    ///
+    /// let mut x = "1.50".parse::<Decimal>().unwrap();
+    /// x *= "-2.0".parse::<Decimal>().unwrap();
+    /// assert_eq!(x.to_string(), "-3.000");
+    /// ```
+    fn mul_assign(&mut self, other: Decimal) {
+        self.unscaled *= other.unscaled;
+        self.scale = product_scale(self.scale, other.scale);
+    }
+}
+
+impl MulAssign<&Decimal> for Decimal {
+    /// Multiplies a [`Decimal`] by a [`Decimal`] in place, taking the [`Decimal`] on the right-hand
+    /// side by reference. The product is exact, and its scale is the sum of the two scales.
+    ///
+    /// $$
+    /// x \gets xy.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.unscaled().significant_bits(), other.unscaled().significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if the sum of the scales is not an [`i64`].
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_decimal::Decimal;
+    ///
+    /// let mut x = "1.5".parse::<Decimal>().unwrap();
+    /// x *= &"2.25".parse::<Decimal>().unwrap();
+    /// assert_eq!(x.to_string(), "3.375");
+    ///
+    /// let mut x = "1.50".parse::<Decimal>().unwrap();
+    /// x *= &"-2.0".parse::<Decimal>().unwrap();
+    /// assert_eq!(x.to_string(), "-3.000");
+    /// ```
+    fn mul_assign(&mut self, other: &Decimal) {
+        self.unscaled *= &other.unscaled;
+        self.scale = product_scale(self.scale, other.scale);
+    }
+}
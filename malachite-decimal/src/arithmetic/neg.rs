@@ -0,0 +1,102 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Decimal;
+use core::ops::Neg;
+use malachite_base::num::arithmetic::traits::NegAssign;
+
+impl Neg for Decimal {
+    type Output = Decimal;
+
+    /// Negates a [`Decimal`], taking it by value. The scale is unchanged.
+    ///
+    /// $$
+    /// f(x) = -x.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_decimal::Decimal;
+    ///
+    /// assert_eq!((-"0.00".parse::<Decimal>().unwrap()).to_string(), "0.00");
+    /// assert_eq!((-"1.50".parse::<Decimal>().unwrap()).to_string(), "-1.50");
+    /// assert_eq!((-"-12e3".parse::<Decimal>().unwrap()).to_string(), "12e3");
+    /// ```
+    fn neg(mut self) -> Decimal {
+        self.unscaled.neg_assign();
+        self
+    }
+}
+
+impl Neg for &Decimal {
+    type Output = Decimal;
+
+    /// Negates a [`Decimal`], taking it by reference. The scale is unchanged.
+    ///
+    /// $$
+    /// f(x) = -x.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.unscaled().significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_decimal::Decimal;
+    ///
+    /// assert_eq!((-&"0.00".parse::<Decimal>().unwrap()).to_string(), "0.00");
+    /// assert_eq!((-&"1.50".parse::<Decimal>().unwrap()).to_string(), "-1.50");
+    /// assert_eq!((-&"-12e3".parse::<Decimal>().unwrap()).to_string(), "12e3");
+    /// ```
+    fn neg(self) -> Decimal {
+        Decimal {
+            unscaled: -&self.unscaled,
+            scale: self.scale,
+        }
+    }
+}
+
+impl NegAssign for Decimal {
+    /// Negates a [`Decimal`] in place. The scale is unchanged.
+    ///
+    /// $$
+    /// x \gets -x.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::NegAssign;
+    /// use malachite_decimal::Decimal;
+    ///
+    /// let mut x = "0.00".parse::<Decimal>().unwrap();
+    /// x.neg_assign();
+    /// assert_eq!(x.to_string(), "0.00");
+    ///
+    /// let mut x = "1.50".parse::<Decimal>().unwrap();
+    /// x.neg_assign();
+    /// assert_eq!(x.to_string(), "-1.50");
+    ///
+    /// let mut x = "-12e3".parse::<Decimal>().unwrap();
+    /// x.neg_assign();
+    /// assert_eq!(x.to_string(), "12e3");
+    /// ```
+    fn neg_assign(&mut self) {
+        self.unscaled.neg_assign();
+    }
+}
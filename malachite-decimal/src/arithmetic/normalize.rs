@@ -0,0 +1,139 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::{power_of_10, Decimal};
+use malachite_base::num::arithmetic::traits::{DivExactAssign, DivisibleBy};
+
+impl Decimal {
+    /// Removes the trailing zeros from a [`Decimal`]'s unscaled value, taking the [`Decimal`] by
+    /// value and decreasing its scale accordingly. The value is unchanged.
+    ///
+    /// The result is the unique [`Decimal`] with the same value whose unscaled value is not
+    /// divisible by 10. This means that the scale may become negative; for example, 1200.0 is
+    /// normalized to `12e2`. Zero is normalized to zero with scale 0.
+    ///
+    /// Two [`Decimal`]s are equal if and only if their normalized forms have the same unscaled
+    /// value and scale.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.unscaled().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if the scale of the result is not an [`i64`].
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_decimal::Decimal;
+    ///
+    /// assert_eq!("0.000".parse::<Decimal>().unwrap().normalize().to_string(), "0");
+    /// assert_eq!("1.2500".parse::<Decimal>().unwrap().normalize().to_string(), "1.25");
+    /// assert_eq!("-1200.0".parse::<Decimal>().unwrap().normalize().to_string(), "-12e2");
+    /// ```
+    #[inline]
+    pub fn normalize(mut self) -> Decimal {
+        self.normalize_assign();
+        self
+    }
+
+    /// Removes the trailing zeros from a [`Decimal`]'s unscaled value, taking the [`Decimal`] by
+    /// reference and decreasing its scale accordingly. The value is unchanged.
+    ///
+    /// See [`Decimal::normalize`] for more details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.unscaled().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if the scale of the result is not an [`i64`].
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_decimal::Decimal;
+    ///
+    /// assert_eq!("0.000".parse::<Decimal>().unwrap().normalize_ref().to_string(), "0");
+    /// assert_eq!("1.2500".parse::<Decimal>().unwrap().normalize_ref().to_string(), "1.25");
+    /// assert_eq!("-1200.0".parse::<Decimal>().unwrap().normalize_ref().to_string(), "-12e2");
+    /// ```
+    #[inline]
+    pub fn normalize_ref(&self) -> Decimal {
+        let mut x = self.clone();
+        x.normalize_assign();
+        x
+    }
+
+    /// Removes the trailing zeros from a [`Decimal`]'s unscaled value in place, decreasing its
+    /// scale accordingly. The value is unchanged.
+    ///
+    /// See [`Decimal::normalize`] for more details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.unscaled().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if the scale of the result is not an [`i64`].
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_decimal::Decimal;
+    ///
+    /// let mut x = "0.000".parse::<Decimal>().unwrap();
+    /// x.normalize_assign();
+    /// assert_eq!(x.to_string(), "0");
+    ///
+    /// let mut x = "1.2500".parse::<Decimal>().unwrap();
+    /// x.normalize_assign();
+    /// assert_eq!(x.to_string(), "1.25");
+    ///
+    /// let mut x = "-1200.0".parse::<Decimal>().unwrap();
+    /// x.normalize_assign();
+    /// assert_eq!(x.to_string(), "-12e2");
+    /// ```
+    pub fn normalize_assign(&mut self) {
+        // An unscaled value divisible by 10^k is also divisible by 2^k, so its number of trailing
+        // binary zeros bounds the number of trailing decimal zeros.
+        let Some(bound) = self.unscaled.trailing_zeros() else {
+            self.scale = 0;
+            return;
+        };
+        // Greedily remove powers of 10, doubling the step after each success and halving it after
+        // each failure.
+        let mut removed = 0;
+        let mut step = 1;
+        while step != 0 {
+            if removed + step <= bound {
+                let p = power_of_10(step);
+                if (&self.unscaled).divisible_by(&p) {
+                    self.unscaled.div_exact_assign(p);
+                    removed += step;
+                    step <<= 1;
+                    continue;
+                }
+            }
+            step >>= 1;
+        }
+        self.scale = self
+            .scale
+            .checked_sub_unsigned(removed)
+            .expect("The scale of the normalized Decimal is out of range");
+    }
+}
@@ -0,0 +1,282 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::arithmetic::add::upscale_assign;
+use crate::{power_of_10, Decimal};
+use core::cmp::Ordering::{self, *};
+use malachite_base::num::arithmetic::traits::{DivRound, DivRoundAssign};
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+
+impl Decimal {
+    /// Changes the scale of a [`Decimal`], taking it by value and rounding with the specified
+    /// rounding mode if necessary. An [`Ordering`] is also returned, indicating whether the new
+    /// value is less than, equal to, or greater than the original value.
+    ///
+    /// Increasing the scale never requires rounding; it just appends zeros to the unscaled value.
+    /// Decreasing the scale is exact if and only if the digits that are removed are all zero.
+    ///
+    /// $$
+    /// f(x, s, m) = 10^{-s} g(10^s x, m),
+    /// $$
+    /// where $g(z, m)$ rounds $z$ to an integer using the rounding mode $m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.unscaled().significant_bits(), scale.abs_diff(self.scale()))`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the new scale is smaller than the old one and one of the
+    /// removed digits is nonzero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_decimal::Decimal;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = "1.25".parse::<Decimal>().unwrap();
+    ///
+    /// let (y, o) = x.clone().rescale_round(4, Exact);
+    /// assert_eq!(y.to_string(), "1.2500");
+    /// assert_eq!(o, Equal);
+    ///
+    /// let (y, o) = x.clone().rescale_round(1, Nearest);
+    /// assert_eq!(y.to_string(), "1.2");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (y, o) = x.clone().rescale_round(1, Up);
+    /// assert_eq!(y.to_string(), "1.3");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (y, o) = x.rescale_round(-1, Nearest);
+    /// assert_eq!(y.to_string(), "0e1");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn rescale_round(mut self, scale: i64, rm: RoundingMode) -> (Decimal, Ordering) {
+        let o = self.rescale_round_assign(scale, rm);
+        (self, o)
+    }
+
+    /// Changes the scale of a [`Decimal`], taking it by reference and rounding with the specified
+    /// rounding mode if necessary. An [`Ordering`] is also returned, indicating whether the new
+    /// value is less than, equal to, or greater than the original value.
+    ///
+    /// See [`Decimal::rescale_round`] for more details.
+    ///
+    /// $$
+    /// f(x, s, m) = 10^{-s} g(10^s x, m),
+    /// $$
+    /// where $g(z, m)$ rounds $z$ to an integer using the rounding mode $m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.unscaled().significant_bits(), scale.abs_diff(self.scale()))`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the new scale is smaller than the old one and one of the
+    /// removed digits is nonzero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_decimal::Decimal;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = "1.25".parse::<Decimal>().unwrap();
+    ///
+    /// let (y, o) = x.rescale_round_ref(4, Exact);
+    /// assert_eq!(y.to_string(), "1.2500");
+    /// assert_eq!(o, Equal);
+    ///
+    /// let (y, o) = x.rescale_round_ref(1, Nearest);
+    /// assert_eq!(y.to_string(), "1.2");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (y, o) = x.rescale_round_ref(1, Up);
+    /// assert_eq!(y.to_string(), "1.3");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (y, o) = x.rescale_round_ref(-1, Nearest);
+    /// assert_eq!(y.to_string(), "0e1");
+    /// assert_eq!(o, Less);
+    /// ```
+    pub fn rescale_round_ref(&self, scale: i64, rm: RoundingMode) -> (Decimal, Ordering) {
+        let p = power_of_10(scale.abs_diff(self.scale));
+        let (unscaled, o) = if scale >= self.scale {
+            (&self.unscaled * p, Equal)
+        } else {
+            (&self.unscaled).div_round(p, rm)
+        };
+        (Decimal { unscaled, scale }, o)
+    }
+
+    /// Changes the scale of a [`Decimal`] in place, rounding with the specified rounding mode if
+    /// necessary. An [`Ordering`] is returned, indicating whether the new value is less than,
+    /// equal to, or greater than the original value.
+    ///
+    /// See [`Decimal::rescale_round`] for more details.
+    ///
+    /// $$
+    /// x \gets 10^{-s} g(10^s x, m),
+    /// $$
+    /// where $g(z, m)$ rounds $z$ to an integer using the rounding mode $m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.unscaled().significant_bits(), scale.abs_diff(self.scale()))`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the new scale is smaller than the old one and one of the
+    /// removed digits is nonzero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_decimal::Decimal;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let original_x = "1.25".parse::<Decimal>().unwrap();
+    ///
+    /// let mut x = original_x.clone();
+    /// assert_eq!(x.rescale_round_assign(4, Exact), Equal);
+    /// assert_eq!(x.to_string(), "1.2500");
+    ///
+    /// let mut x = original_x.clone();
+    /// assert_eq!(x.rescale_round_assign(1, Nearest), Less);
+    /// assert_eq!(x.to_string(), "1.2");
+    ///
+    /// let mut x = original_x.clone();
+    /// assert_eq!(x.rescale_round_assign(1, Up), Greater);
+    /// assert_eq!(x.to_string(), "1.3");
+    ///
+    /// let mut x = original_x;
+    /// assert_eq!(x.rescale_round_assign(-1, Nearest), Less);
+    /// assert_eq!(x.to_string(), "0e1");
+    /// ```
+    pub fn rescale_round_assign(&mut self, scale: i64, rm: RoundingMode) -> Ordering {
+        if scale >= self.scale {
+            upscale_assign(self, scale);
+            Equal
+        } else {
+            let p = power_of_10(scale.abs_diff(self.scale));
+            self.scale = scale;
+            self.unscaled.div_round_assign(p, rm)
+        }
+    }
+
+    /// Changes the scale of a [`Decimal`], taking it by value and rounding to the nearest value if
+    /// necessary. Ties are broken by choosing an even unscaled value. An [`Ordering`] is also
+    /// returned, indicating whether the new value is less than, equal to, or greater than the
+    /// original value.
+    ///
+    /// See [`Decimal::rescale_round`] for more details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.unscaled().significant_bits(), scale.abs_diff(self.scale()))`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_decimal::Decimal;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (y, o) = "1.25".parse::<Decimal>().unwrap().rescale(4);
+    /// assert_eq!(y.to_string(), "1.2500");
+    /// assert_eq!(o, Equal);
+    ///
+    /// let (y, o) = "1.35".parse::<Decimal>().unwrap().rescale(1);
+    /// assert_eq!(y.to_string(), "1.4");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn rescale(self, scale: i64) -> (Decimal, Ordering) {
+        self.rescale_round(scale, Nearest)
+    }
+
+    /// Changes the scale of a [`Decimal`], taking it by reference and rounding to the nearest
+    /// value if necessary. Ties are broken by choosing an even unscaled value. An [`Ordering`] is
+    /// also returned, indicating whether the new value is less than, equal to, or greater than the
+    /// original value.
+    ///
+    /// See [`Decimal::rescale_round`] for more details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.unscaled().significant_bits(), scale.abs_diff(self.scale()))`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_decimal::Decimal;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (y, o) = "1.25".parse::<Decimal>().unwrap().rescale_ref(4);
+    /// assert_eq!(y.to_string(), "1.2500");
+    /// assert_eq!(o, Equal);
+    ///
+    /// let (y, o) = "1.35".parse::<Decimal>().unwrap().rescale_ref(1);
+    /// assert_eq!(y.to_string(), "1.4");
+    /// assert_eq!(o, Greater);
+    /// ```
+    #[inline]
+    pub fn rescale_ref(&self, scale: i64) -> (Decimal, Ordering) {
+        self.rescale_round_ref(scale, Nearest)
+    }
+
+    /// Changes the scale of a [`Decimal`] in place, rounding to the nearest value if necessary.
+    /// Ties are broken by choosing an even unscaled value. An [`Ordering`] is returned, indicating
+    /// whether the new value is less than, equal to, or greater than the original value.
+    ///
+    /// See [`Decimal::rescale_round`] for more details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.unscaled().significant_bits(), scale.abs_diff(self.scale()))`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_decimal::Decimal;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = "1.25".parse::<Decimal>().unwrap();
+    /// assert_eq!(x.rescale_assign(4), Equal);
+    /// assert_eq!(x.to_string(), "1.2500");
+    ///
+    /// let mut x = "1.35".parse::<Decimal>().unwrap();
+    /// assert_eq!(x.rescale_assign(1), Greater);
+    /// assert_eq!(x.to_string(), "1.4");
+    /// ```
+    #[inline]
+    pub fn rescale_assign(&mut self, scale: i64) -> Ordering {
+        self.rescale_round_assign(scale, Nearest)
+    }
+}
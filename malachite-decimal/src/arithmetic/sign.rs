@@ -0,0 +1,36 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Decimal;
+use core::cmp::Ordering;
+use malachite_base::num::arithmetic::traits::Sign;
+
+impl Sign for Decimal {
+    /// Compares a [`Decimal`] to zero.
+    ///
+    /// Returns `Greater`, `Equal`, or `Less`, depending on whether the [`Decimal`] is positive,
+    /// zero, or negative, respectively.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Sign;
+    /// use malachite_decimal::Decimal;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// assert_eq!("0.00".parse::<Decimal>().unwrap().sign(), Equal);
+    /// assert_eq!("1.50".parse::<Decimal>().unwrap().sign(), Greater);
+    /// assert_eq!("-12e3".parse::<Decimal>().unwrap().sign(), Less);
+    /// ```
+    #[inline]
+    fn sign(&self) -> Ordering {
+        self.unscaled.sign()
+    }
+}
@@ -0,0 +1,257 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::arithmetic::add::upscale_assign;
+use crate::{power_of_10, Decimal};
+use core::cmp::max;
+use core::cmp::Ordering::*;
+use core::mem::swap;
+use core::ops::{Sub, SubAssign};
+use malachite_base::num::arithmetic::traits::NegAssign;
+
+impl Sub<Decimal> for Decimal {
+    type Output = Decimal;
+
+    /// Subtracts a [`Decimal`] by another [`Decimal`], taking both by value. The difference is
+    /// exact, and its scale is the larger of the two scales.
+    ///
+    /// $$
+    /// f(x, y) = x - y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the number of bits in the larger
+    /// unscaled value after both [`Decimal`]s have been brought to the same scale.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_decimal::Decimal;
+    ///
+    /// let x = "1.5".parse::<Decimal>().unwrap();
+    /// let y = "-2.25".parse::<Decimal>().unwrap();
+    /// assert_eq!((x - y).to_string(), "3.75");
+    ///
+    /// let x = "1.50".parse::<Decimal>().unwrap();
+    /// let y = "1.5".parse::<Decimal>().unwrap();
+    /// assert_eq!((x - y).to_string(), "0.00");
+    /// ```
+    #[inline]
+    fn sub(mut self, other: Decimal) -> Decimal {
+        self -= other;
+        self
+    }
+}
+
+impl Sub<&Decimal> for Decimal {
+    type Output = Decimal;
+
+    /// Subtracts a [`Decimal`] by another [`Decimal`], taking the first by value and the second by
+    /// reference. The difference is exact, and its scale is the larger of the two scales.
+    ///
+    /// $$
+    /// f(x, y) = x - y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the number of bits in the larger
+    /// unscaled value after both [`Decimal`]s have been brought to the same scale.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_decimal::Decimal;
+    ///
+    /// let x = "1.5".parse::<Decimal>().unwrap();
+    /// let y = "-2.25".parse::<Decimal>().unwrap();
+    /// assert_eq!((x - &y).to_string(), "3.75");
+    ///
+    /// let x = "1.50".parse::<Decimal>().unwrap();
+    /// let y = "1.5".parse::<Decimal>().unwrap();
+    /// assert_eq!((x - &y).to_string(), "0.00");
+    /// ```
+    #[inline]
+    fn sub(mut self, other: &Decimal) -> Decimal {
+        self -= other;
+        self
+    }
+}
+
+impl Sub<Decimal> for &Decimal {
+    type Output = Decimal;
+
+    /// Subtracts a [`Decimal`] by another [`Decimal`], taking the first by reference and the second
+    /// by value. The difference is exact, and its scale is the larger of the two scales.
+    ///
+    /// $$
+    /// f(x, y) = x - y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the number of bits in the larger
+    /// unscaled value after both [`Decimal`]s have been brought to the same scale.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_decimal::Decimal;
+    ///
+    /// let x = "1.5".parse::<Decimal>().unwrap();
+    /// let y = "-2.25".parse::<Decimal>().unwrap();
+    /// assert_eq!((&x - y).to_string(), "3.75");
+    ///
+    /// let x = "1.50".parse::<Decimal>().unwrap();
+    /// let y = "1.5".parse::<Decimal>().unwrap();
+    /// assert_eq!((&x - y).to_string(), "0.00");
+    /// ```
+    fn sub(self, mut other: Decimal) -> Decimal {
+        other -= self;
+        other.unscaled.neg_assign();
+        other
+    }
+}
+
+impl Sub<&Decimal> for &Decimal {
+    type Output = Decimal;
+
+    /// Subtracts a [`Decimal`] by another [`Decimal`], taking both by reference. The difference is
+    /// exact, and its scale is the larger of the two scales.
+    ///
+    /// $$
+    /// f(x, y) = x - y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the number of bits in the larger
+    /// unscaled value after both [`Decimal`]s have been brought to the same scale.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_decimal::Decimal;
+    ///
+    /// let x = "1.5".parse::<Decimal>().unwrap();
+    /// let y = "-2.25".parse::<Decimal>().unwrap();
+    /// assert_eq!((&x - &y).to_string(), "3.75");
+    ///
+    /// let x = "1.50".parse::<Decimal>().unwrap();
+    /// let y = "1.5".parse::<Decimal>().unwrap();
+    /// assert_eq!((&x - &y).to_string(), "0.00");
+    /// ```
+    fn sub(self, other: &Decimal) -> Decimal {
+        let unscaled = match self.scale.cmp(&other.scale) {
+            Equal => &self.unscaled - &other.unscaled,
+            Greater => {
+                &self.unscaled - &other.unscaled * power_of_10(self.scale.abs_diff(other.scale))
+            }
+            Less => {
+                &self.unscaled * power_of_10(other.scale.abs_diff(self.scale)) - &other.unscaled
+            }
+        };
+        Decimal {
+            unscaled,
+            scale: max(self.scale, other.scale),
+        }
+    }
+}
+
+impl SubAssign<Decimal> for Decimal {
+    /// Subtracts a [`Decimal`] by another [`Decimal`] in place, taking the [`Decimal`] on the
+    /// right-hand side by value. The difference is exact, and its scale is the larger of the two
+    /// scales.
+    ///
+    /// $$
+    /// x \gets x - y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the number of bits in the larger
+    /// unscaled value after both [`Decimal`]s have been brought to the same scale.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_decimal::Decimal;
+    ///
+    /// let mut x = "1.5".parse::<Decimal>().unwrap();
+    /// x -= "-2.25".parse::<Decimal>().unwrap();
+    /// assert_eq!(x.to_string(), "3.75");
+    ///
+    /// let mut x = "1.50".parse::<Decimal>().unwrap();
+    /// x -= "1.5".parse::<Decimal>().unwrap();
+    /// assert_eq!(x.to_string(), "0.00");
+    /// ```
+    fn sub_assign(&mut self, mut other: Decimal) {
+        if self.scale < other.scale {
+            swap(self, &mut other);
+            self.unscaled.neg_assign();
+            *self += other;
+        } else {
+            upscale_assign(&mut other, self.scale);
+            self.unscaled -= other.unscaled;
+        }
+    }
+}
+
+impl SubAssign<&Decimal> for Decimal {
+    /// Subtracts a [`Decimal`] by another [`Decimal`] in place, taking the [`Decimal`] on the
+    /// right-hand side by reference. The difference is exact, and its scale is the larger of the
+    /// two scales.
+    ///
+    /// $$
+    /// x \gets x - y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the number of bits in the larger
+    /// unscaled value after both [`Decimal`]s have been brought to the same scale.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_decimal::Decimal;
+    ///
+    /// let mut x = "1.5".parse::<Decimal>().unwrap();
+    /// x -= &"-2.25".parse::<Decimal>().unwrap();
+    /// assert_eq!(x.to_string(), "3.75");
+    ///
+    /// let mut x = "1.50".parse::<Decimal>().unwrap();
+    /// x -= &"1.5".parse::<Decimal>().unwrap();
+    /// assert_eq!(x.to_string(), "0.00");
+    /// ```
+    fn sub_assign(&mut self, other: &Decimal) {
+        match self.scale.cmp(&other.scale) {
+            Equal => self.unscaled -= &other.unscaled,
+            Greater => {
+                self.unscaled -= &other.unscaled * power_of_10(self.scale.abs_diff(other.scale));
+            }
+            Less => {
+                upscale_assign(self, other.scale);
+                self.unscaled -= &other.unscaled;
+            }
+        }
+    }
+}
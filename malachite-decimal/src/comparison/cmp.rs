@@ -0,0 +1,108 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::{power_of_10, Decimal};
+use core::cmp::Ordering::{self, *};
+use malachite_base::num::arithmetic::traits::Sign;
+use malachite_base::num::comparison::traits::OrdAbs;
+use malachite_base::num::logic::traits::SignificantBits;
+
+// Bounds on log_2(10): LOG_2_10_LOWER / 10^10 < log_2(10) < LOG_2_10_UPPER / 10^10.
+const LOG_2_10_LOWER: i128 = 33219280948;
+const LOG_2_10_UPPER: i128 = 33219280949;
+const LOG_2_10_DENOMINATOR: i128 = 10000000000;
+
+// Returns true if $2^a \geq 10^d$ is guaranteed by the bounds on $\log_2 10$.
+const fn power_of_2_certainly_ge_power_of_10(a: i128, d: i128) -> bool {
+    a * LOG_2_10_DENOMINATOR
+        >= d * if d >= 0 {
+            LOG_2_10_UPPER
+        } else {
+            LOG_2_10_LOWER
+        }
+}
+
+// Compares the absolute values of two nonzero `Decimal`s with different scales.
+fn cmp_abs_nonzero(x: &Decimal, y: &Decimal) -> Ordering {
+    // |x| lies in [2^(b_x - 1), 2^b_x) * 10^(-s_x), and likewise for |y|. If the bit lengths of the
+    // unscaled values differ by enough to outweigh the difference of the scales, there's no need
+    // to align them.
+    let x_bits = i128::from(x.unscaled.significant_bits());
+    let y_bits = i128::from(y.unscaled.significant_bits());
+    let d = i128::from(x.scale) - i128::from(y.scale);
+    if power_of_2_certainly_ge_power_of_10(x_bits - y_bits - 1, d) {
+        return Greater;
+    }
+    if power_of_2_certainly_ge_power_of_10(y_bits - x_bits - 1, -d) {
+        return Less;
+    }
+    // Otherwise, the difference of the scales is bounded by the bit lengths, so aligning the
+    // unscaled values is cheap.
+    if d > 0 {
+        x.unscaled
+            .cmp_abs(&(&y.unscaled * power_of_10(x.scale.abs_diff(y.scale))))
+    } else {
+        (&x.unscaled * power_of_10(x.scale.abs_diff(y.scale))).cmp_abs(&y.unscaled)
+    }
+}
+
+impl PartialOrd for Decimal {
+    /// Compares two [`Decimal`]s.
+    ///
+    /// See the documentation for the [`Ord`] implementation.
+    #[inline]
+    fn partial_cmp(&self, other: &Decimal) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Decimal {
+    /// Compares two [`Decimal`]s by value. The scales are ignored, so that, for example, 1.5 and
+    /// 1.50 are equal.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.unscaled().significant_bits(), other.unscaled().significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_decimal::Decimal;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = "1.5".parse::<Decimal>().unwrap();
+    /// assert_eq!(x.cmp(&"1.50".parse().unwrap()), Equal);
+    /// assert_eq!(x.cmp(&"1.499".parse().unwrap()), Greater);
+    /// assert_eq!(x.cmp(&"2e3".parse().unwrap()), Less);
+    /// assert_eq!(x.cmp(&"-2e3".parse().unwrap()), Greater);
+    /// ```
+    fn cmp(&self, other: &Decimal) -> Ordering {
+        if core::ptr::eq(self, other) {
+            return Equal;
+        }
+        // First check signs
+        let self_sign = self.sign();
+        let other_sign = other.sign();
+        let sign_cmp = self_sign.cmp(&other_sign);
+        if sign_cmp != Equal || self_sign == Equal {
+            return sign_cmp;
+        }
+        if self.scale == other.scale {
+            return self.unscaled.cmp(&other.unscaled);
+        }
+        let abs_cmp = cmp_abs_nonzero(self, other);
+        if self_sign == Greater {
+            abs_cmp
+        } else {
+            abs_cmp.reverse()
+        }
+    }
+}
@@ -0,0 +1,42 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Decimal;
+use core::cmp::Ordering::*;
+
+impl PartialEq for Decimal {
+    /// Determines whether two [`Decimal`]s are equal. Only the values are compared, not the
+    /// scales, so that, for example, 1.5 and 1.50 are equal.
+    ///
+    /// To check whether two [`Decimal`]s have the same unscaled value and scale, compare the
+    /// results of [`Decimal::unscaled`] and [`Decimal::scale`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.unscaled().significant_bits(), other.unscaled().significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_decimal::Decimal;
+    ///
+    /// let x = "1.5".parse::<Decimal>().unwrap();
+    /// assert_eq!(x, "1.50".parse::<Decimal>().unwrap());
+    /// assert_eq!(x, "0.15e1".parse::<Decimal>().unwrap());
+    /// assert_ne!(x, "1.49".parse::<Decimal>().unwrap());
+    /// ```
+    #[inline]
+    fn eq(&self, other: &Decimal) -> bool {
+        self.cmp(other) == Equal
+    }
+}
+
+impl Eq for Decimal {}
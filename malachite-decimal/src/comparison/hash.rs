@@ -0,0 +1,30 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Decimal;
+use core::hash::{Hash, Hasher};
+
+impl Hash for Decimal {
+    /// Hashes a [`Decimal`].
+    ///
+    /// Since equality ignores scales, the normalized form of the [`Decimal`] (see
+    /// [`Decimal::normalize`]) is hashed, so that equal [`Decimal`]s have equal hashes.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.unscaled().significant_bits()`.
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let x = self.normalize_ref();
+        x.unscaled.hash(state);
+        x.scale.hash(state);
+    }
+}
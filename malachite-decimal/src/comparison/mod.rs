@@ -0,0 +1,15 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+/// Comparison of [`Decimal`](crate::Decimal)s.
+pub mod cmp;
+/// Equality of [`Decimal`](crate::Decimal)s.
+pub mod eq;
+/// An implementation of [`Hash`](core::hash::Hash) for [`Decimal`](crate::Decimal)s that is
+/// consistent with their equality.
+pub mod hash;
@@ -0,0 +1,57 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Decimal;
+use malachite_nz::integer::Integer;
+
+impl From<Integer> for Decimal {
+    /// Converts an [`Integer`] to a [`Decimal`] with scale 0, taking the [`Integer`] by value.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_decimal::Decimal;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Decimal::from(Integer::from(123)).to_string(), "123");
+    /// assert_eq!(Decimal::from(Integer::from(-123)).to_string(), "-123");
+    /// ```
+    #[inline]
+    fn from(unscaled: Integer) -> Decimal {
+        Decimal { unscaled, scale: 0 }
+    }
+}
+
+impl From<&Integer> for Decimal {
+    /// Converts an [`Integer`] to a [`Decimal`] with scale 0, taking the [`Integer`] by reference.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_decimal::Decimal;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Decimal::from(&Integer::from(123)).to_string(), "123");
+    /// assert_eq!(Decimal::from(&Integer::from(-123)).to_string(), "-123");
+    /// ```
+    #[inline]
+    fn from(x: &Integer) -> Decimal {
+        Decimal {
+            unscaled: x.clone(),
+            scale: 0,
+        }
+    }
+}
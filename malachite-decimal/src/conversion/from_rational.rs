@@ -0,0 +1,302 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Decimal;
+use core::cmp::{max, Ordering};
+use malachite_base::num::arithmetic::traits::{CheckedLogBase, DivExact, Pow, Sign};
+use malachite_base::num::conversion::traits::{ExactFrom, RoundingFrom};
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_q::Rational;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct DecimalFromRationalError;
+
+// Returns the smallest nonnegative scale at which `x` can be represented exactly, or `None` if the
+// denominator of `x` has a prime factor other than 2 or 5.
+fn exact_scale(x: &Rational) -> Option<u64> {
+    let d = x.denominator_ref();
+    let twos = d.trailing_zeros().unwrap();
+    let odd = d >> twos;
+    let fives = if odd == 1u32 {
+        0
+    } else {
+        (&odd).checked_log_base(&Natural::from(5u32))?
+    };
+    Some(max(twos, fives))
+}
+
+impl TryFrom<Rational> for Decimal {
+    type Error = DecimalFromRationalError;
+
+    /// Converts a [`Rational`] to a [`Decimal`], taking the [`Rational`] by value. If the
+    /// [`Rational`] can't be represented exactly, an error is returned.
+    ///
+    /// A [`Rational`] can be represented exactly if and only if its denominator has no prime
+    /// factors other than 2 and 5. The result has the smallest nonnegative scale that represents
+    /// the [`Rational`] exactly.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_decimal::conversion::from_rational::DecimalFromRationalError;
+    /// use malachite_decimal::Decimal;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(
+    ///     Decimal::try_from(Rational::from(123)).unwrap().to_string(),
+    ///     "123"
+    /// );
+    /// assert_eq!(
+    ///     Decimal::try_from(Rational::from_signeds(-3, 8)).unwrap().to_string(),
+    ///     "-0.375"
+    /// );
+    /// assert_eq!(
+    ///     Decimal::try_from(Rational::from_signeds(1, 3)),
+    ///     Err(DecimalFromRationalError)
+    /// );
+    /// ```
+    fn try_from(x: Rational) -> Result<Decimal, Self::Error> {
+        let scale = exact_scale(&x).ok_or(DecimalFromRationalError)?;
+        let sign = x.sign() != Ordering::Less;
+        let (numerator, denominator) = x.into_numerator_and_denominator();
+        Ok(Decimal {
+            unscaled: Integer::from_sign_and_abs(
+                sign,
+                (numerator * Natural::from(10u32).pow(scale)).div_exact(denominator),
+            ),
+            scale: i64::exact_from(scale),
+        })
+    }
+}
+
+impl TryFrom<&Rational> for Decimal {
+    type Error = DecimalFromRationalError;
+
+    /// Converts a [`Rational`] to a [`Decimal`], taking the [`Rational`] by reference. If the
+    /// [`Rational`] can't be represented exactly, an error is returned.
+    ///
+    /// A [`Rational`] can be represented exactly if and only if its denominator has no prime
+    /// factors other than 2 and 5. The result has the smallest nonnegative scale that represents
+    /// the [`Rational`] exactly.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_decimal::conversion::from_rational::DecimalFromRationalError;
+    /// use malachite_decimal::Decimal;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(
+    ///     Decimal::try_from(&Rational::from(123)).unwrap().to_string(),
+    ///     "123"
+    /// );
+    /// assert_eq!(
+    ///     Decimal::try_from(&Rational::from_signeds(-3, 8)).unwrap().to_string(),
+    ///     "-0.375"
+    /// );
+    /// assert_eq!(
+    ///     Decimal::try_from(&Rational::from_signeds(1, 3)),
+    ///     Err(DecimalFromRationalError)
+    /// );
+    /// ```
+    fn try_from(x: &Rational) -> Result<Decimal, Self::Error> {
+        let scale = exact_scale(x).ok_or(DecimalFromRationalError)?;
+        Ok(Decimal {
+            unscaled: Integer::from_sign_and_abs(
+                x.sign() != Ordering::Less,
+                (x.numerator_ref() * Natural::from(10u32).pow(scale))
+                    .div_exact(x.denominator_ref()),
+            ),
+            scale: i64::exact_from(scale),
+        })
+    }
+}
+
+impl Decimal {
+    /// Converts a [`Rational`] to a [`Decimal`] with the specified scale, taking the [`Rational`]
+    /// by value and rounding with the specified rounding mode if necessary. An [`Ordering`] is
+    /// also returned, indicating whether the [`Decimal`] is less than, equal to, or greater than
+    /// the [`Rational`].
+    ///
+    /// $$
+    /// f(x, s, m) = 10^{-s} g(10^s x, m),
+    /// $$
+    /// where $g(z, m)$ rounds $z$ to an integer using the rounding mode $m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(x.significant_bits(), scale.unsigned_abs())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but $x$ is not a multiple of $10^{-s}$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_decimal::Decimal;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (x, o) = Decimal::from_rational_scale_round(Rational::from_signeds(2, 3), 3, Floor);
+    /// assert_eq!(x.to_string(), "0.666");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (x, o) = Decimal::from_rational_scale_round(Rational::from_signeds(2, 3), 3, Nearest);
+    /// assert_eq!(x.to_string(), "0.667");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (x, o) = Decimal::from_rational_scale_round(Rational::from_signeds(1, 4), 3, Exact);
+    /// assert_eq!(x.to_string(), "0.250");
+    /// assert_eq!(o, Equal);
+    /// ```
+    pub fn from_rational_scale_round(
+        x: Rational,
+        scale: i64,
+        rm: RoundingMode,
+    ) -> (Decimal, Ordering) {
+        let (unscaled, o) = Integer::rounding_from(x * Rational::from(10u32).pow(scale), rm);
+        (Decimal { unscaled, scale }, o)
+    }
+
+    /// Converts a [`Rational`] to a [`Decimal`] with the specified scale, taking the [`Rational`]
+    /// by reference and rounding with the specified rounding mode if necessary. An [`Ordering`] is
+    /// also returned, indicating whether the [`Decimal`] is less than, equal to, or greater than
+    /// the [`Rational`].
+    ///
+    /// $$
+    /// f(x, s, m) = 10^{-s} g(10^s x, m),
+    /// $$
+    /// where $g(z, m)$ rounds $z$ to an integer using the rounding mode $m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(x.significant_bits(), scale.unsigned_abs())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but $x$ is not a multiple of $10^{-s}$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_decimal::Decimal;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = Rational::from_signeds(2, 3);
+    /// let (y, o) = Decimal::from_rational_scale_round_ref(&x, 3, Floor);
+    /// assert_eq!(y.to_string(), "0.666");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (y, o) = Decimal::from_rational_scale_round_ref(&x, 3, Nearest);
+    /// assert_eq!(y.to_string(), "0.667");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let x = Rational::from_signeds(1, 4);
+    /// let (y, o) = Decimal::from_rational_scale_round_ref(&x, 3, Exact);
+    /// assert_eq!(y.to_string(), "0.250");
+    /// assert_eq!(o, Equal);
+    /// ```
+    pub fn from_rational_scale_round_ref(
+        x: &Rational,
+        scale: i64,
+        rm: RoundingMode,
+    ) -> (Decimal, Ordering) {
+        let (unscaled, o) = Integer::rounding_from(x * Rational::from(10u32).pow(scale), rm);
+        (Decimal { unscaled, scale }, o)
+    }
+
+    /// Converts a [`Rational`] to a [`Decimal`] with the specified scale, taking the [`Rational`]
+    /// by value and rounding to the nearest value if necessary. Ties are broken by choosing an
+    /// even unscaled value. An [`Ordering`] is also returned, indicating whether the [`Decimal`]
+    /// is less than, equal to, or greater than the [`Rational`].
+    ///
+    /// See [`Decimal::from_rational_scale_round`] for more details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(x.significant_bits(), scale.unsigned_abs())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_decimal::Decimal;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (x, o) = Decimal::from_rational_scale(Rational::from_signeds(2, 3), 3);
+    /// assert_eq!(x.to_string(), "0.667");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (x, o) = Decimal::from_rational_scale(Rational::from_signeds(5, 8), 2);
+    /// assert_eq!(x.to_string(), "0.62");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn from_rational_scale(x: Rational, scale: i64) -> (Decimal, Ordering) {
+        Decimal::from_rational_scale_round(x, scale, Nearest)
+    }
+
+    /// Converts a [`Rational`] to a [`Decimal`] with the specified scale, taking the [`Rational`]
+    /// by reference and rounding to the nearest value if necessary. Ties are broken by choosing an
+    /// even unscaled value. An [`Ordering`] is also returned, indicating whether the [`Decimal`]
+    /// is less than, equal to, or greater than the [`Rational`].
+    ///
+    /// See [`Decimal::from_rational_scale_round`] for more details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(x.significant_bits(), scale.unsigned_abs())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_decimal::Decimal;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (x, o) = Decimal::from_rational_scale_ref(&Rational::from_signeds(2, 3), 3);
+    /// assert_eq!(x.to_string(), "0.667");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (x, o) = Decimal::from_rational_scale_ref(&Rational::from_signeds(5, 8), 2);
+    /// assert_eq!(x.to_string(), "0.62");
+    /// assert_eq!(o, Less);
+    /// ```
+    #[inline]
+    pub fn from_rational_scale_ref(x: &Rational, scale: i64) -> (Decimal, Ordering) {
+        Decimal::from_rational_scale_round_ref(x, scale, Nearest)
+    }
+}
@@ -0,0 +1,31 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Decimal;
+use malachite_nz::integer::Integer;
+
+impl Decimal {
+    /// Creates a [`Decimal`] from an unscaled value and a scale. The value of the result is
+    /// $u \cdot 10^{-s}$.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_decimal::Decimal;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Decimal::from_unscaled_and_scale(Integer::from(150), 2).to_string(), "1.50");
+    /// assert_eq!(Decimal::from_unscaled_and_scale(Integer::from(-7), 0).to_string(), "-7");
+    /// assert_eq!(Decimal::from_unscaled_and_scale(Integer::from(12), -3).to_string(), "12e3");
+    /// ```
+    pub const fn from_unscaled_and_scale(unscaled: Integer, scale: i64) -> Decimal {
+        Decimal { unscaled, scale }
+    }
+}
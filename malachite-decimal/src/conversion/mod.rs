@@ -0,0 +1,25 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+/// Implementations of the [`From`] trait for converting an
+/// [`Integer`](malachite_nz::integer::Integer) to a [`Decimal`](crate::Decimal).
+pub mod from_integer;
+/// Functions and implementations of the [`TryFrom`] trait for converting a
+/// [`Rational`](malachite_q::Rational) to a [`Decimal`](crate::Decimal), either exactly or by
+/// rounding to a specified scale.
+pub mod from_rational;
+/// Functions for constructing a [`Decimal`](crate::Decimal) from an unscaled value and a scale.
+pub mod from_unscaled_and_scale;
+/// Implementations of the [`From`] trait for converting a [`Decimal`](crate::Decimal) to a
+/// [`Rational`](malachite_q::Rational).
+pub mod rational_from_decimal;
+/// Functions and implementations of traits for converting [`Decimal`](crate::Decimal)s to and from
+/// strings.
+pub mod string;
+/// Functions for getting the unscaled value and the scale of a [`Decimal`](crate::Decimal).
+pub mod to_unscaled_and_scale;
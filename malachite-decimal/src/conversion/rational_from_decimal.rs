@@ -0,0 +1,72 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::{power_of_10, Decimal};
+use malachite_q::Rational;
+
+impl From<Decimal> for Rational {
+    /// Converts a [`Decimal`] to a [`Rational`], taking the [`Decimal`] by value. The conversion
+    /// is exact.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(x.unscaled().significant_bits(), x.scale().unsigned_abs())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_decimal::Decimal;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(Rational::from("1.50".parse::<Decimal>().unwrap()).to_string(), "3/2");
+    /// assert_eq!(Rational::from("-0.125".parse::<Decimal>().unwrap()).to_string(), "-1/8");
+    /// assert_eq!(Rational::from("12e3".parse::<Decimal>().unwrap()).to_string(), "12000");
+    /// ```
+    fn from(x: Decimal) -> Rational {
+        let p = power_of_10(x.scale.unsigned_abs());
+        if x.scale >= 0 {
+            Rational::from_integers(x.unscaled, p)
+        } else {
+            Rational::from(x.unscaled * p)
+        }
+    }
+}
+
+impl From<&Decimal> for Rational {
+    /// Converts a [`Decimal`] to a [`Rational`], taking the [`Decimal`] by reference. The
+    /// conversion is exact.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(x.unscaled().significant_bits(), x.scale().unsigned_abs())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_decimal::Decimal;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(Rational::from(&"1.50".parse::<Decimal>().unwrap()).to_string(), "3/2");
+    /// assert_eq!(Rational::from(&"-0.125".parse::<Decimal>().unwrap()).to_string(), "-1/8");
+    /// assert_eq!(Rational::from(&"12e3".parse::<Decimal>().unwrap()).to_string(), "12000");
+    /// ```
+    fn from(x: &Decimal) -> Rational {
+        let p = power_of_10(x.scale.unsigned_abs());
+        if x.scale >= 0 {
+            Rational::from_integers_ref(&x.unscaled, &p)
+        } else {
+            Rational::from(&x.unscaled * p)
+        }
+    }
+}
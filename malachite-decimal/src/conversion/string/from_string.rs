@@ -0,0 +1,110 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Decimal;
+use alloc::string::String;
+use core::str::FromStr;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+
+impl FromStr for Decimal {
+    type Err = ();
+
+    /// Converts a string to a [`Decimal`].
+    ///
+    /// The string consists of an optional `-` sign, then digits with an optional decimal point,
+    /// then an optional exponent: an `e` or `E` followed by an optionally signed integer. There
+    /// must be at least one digit before or after the decimal point. The scale of the result is
+    /// the number of digits after the decimal point minus the exponent, so trailing zeros are
+    /// preserved: "1.50" has scale 2, and "1.5e3" has scale $-2$.
+    ///
+    /// If the string does not represent a valid [`Decimal`], or if the scale is not an [`i64`], an
+    /// `Err` is returned.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `s.len()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_decimal::Decimal;
+    /// use std::str::FromStr;
+    ///
+    /// let x = Decimal::from_str("1.50").unwrap();
+    /// assert_eq!(*x.unscaled(), 150);
+    /// assert_eq!(x.scale(), 2);
+    ///
+    /// let x = Decimal::from_str("-007").unwrap();
+    /// assert_eq!(*x.unscaled(), -7);
+    /// assert_eq!(x.scale(), 0);
+    ///
+    /// let x = Decimal::from_str(".5").unwrap();
+    /// assert_eq!(*x.unscaled(), 5);
+    /// assert_eq!(x.scale(), 1);
+    ///
+    /// let x = Decimal::from_str("1.5e3").unwrap();
+    /// assert_eq!(*x.unscaled(), 15);
+    /// assert_eq!(x.scale(), -2);
+    ///
+    /// let x = Decimal::from_str("12E-4").unwrap();
+    /// assert_eq!(*x.unscaled(), 12);
+    /// assert_eq!(x.scale(), 4);
+    ///
+    /// assert!(Decimal::from_str("").is_err());
+    /// assert!(Decimal::from_str(".").is_err());
+    /// assert!(Decimal::from_str("1.2.3").is_err());
+    /// assert!(Decimal::from_str("--1").is_err());
+    /// assert!(Decimal::from_str("+1").is_err());
+    /// assert!(Decimal::from_str("1e").is_err());
+    /// assert!(Decimal::from_str("e5").is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Decimal, ()> {
+        let (mantissa, exponent) = if let Some(e_index) = s.find(['e', 'E']) {
+            (
+                &s[..e_index],
+                i64::from_str(&s[e_index + 1..]).map_err(|_| ())?,
+            )
+        } else {
+            (s, 0)
+        };
+        let (abs_mantissa, sign) = if let Some(abs_mantissa) = mantissa.strip_prefix('-') {
+            (abs_mantissa, false)
+        } else {
+            (mantissa, true)
+        };
+        let (int_digits, frac_digits) = if let Some(point_index) = abs_mantissa.find('.') {
+            (
+                &abs_mantissa[..point_index],
+                &abs_mantissa[point_index + 1..],
+            )
+        } else {
+            (abs_mantissa, "")
+        };
+        if int_digits.is_empty() && frac_digits.is_empty()
+            || !int_digits
+                .bytes()
+                .chain(frac_digits.bytes())
+                .all(|b| b.is_ascii_digit())
+        {
+            return Err(());
+        }
+        let mut digits = String::with_capacity(int_digits.len() + frac_digits.len());
+        digits.push_str(int_digits);
+        digits.push_str(frac_digits);
+        let scale = i64::try_from(i128::exact_from(frac_digits.len()) - i128::from(exponent))
+            .map_err(|_| ())?;
+        Ok(Decimal {
+            unscaled: Integer::from_sign_and_abs(sign, Natural::from_str(&digits)?),
+            scale,
+        })
+    }
+}
@@ -0,0 +1,117 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Decimal;
+use alloc::string::ToString;
+use core::fmt::{Debug, Display, Formatter, Result, Write};
+use malachite_base::num::conversion::traits::ExactFrom;
+
+impl Display for Decimal {
+    /// Converts a [`Decimal`] to a [`String`](alloc::string::String).
+    ///
+    /// The scale is preserved, so that the result can be parsed back into a [`Decimal`] with the
+    /// same unscaled value and scale. If the scale $s$ is nonnegative, the value is written in
+    /// plain notation with exactly $s$ digits after the decimal point, including any trailing
+    /// zeros. If the scale is negative, the unscaled value is written followed by `e` and $-s$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.unscaled().significant_bits(), self.scale().unsigned_abs())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_decimal::Decimal;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Decimal::ZERO.to_string(), "0");
+    /// assert_eq!(
+    ///     Decimal::from_unscaled_and_scale(Integer::from(0), 3).to_string(),
+    ///     "0.000"
+    /// );
+    /// assert_eq!(
+    ///     Decimal::from_unscaled_and_scale(Integer::from(150), 2).to_string(),
+    ///     "1.50"
+    /// );
+    /// assert_eq!(
+    ///     Decimal::from_unscaled_and_scale(Integer::from(-15), 4).to_string(),
+    ///     "-0.0015"
+    /// );
+    /// assert_eq!(
+    ///     Decimal::from_unscaled_and_scale(Integer::from(15), -2).to_string(),
+    ///     "15e2"
+    /// );
+    /// ```
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        if self.scale <= 0 {
+            Display::fmt(&self.unscaled, f)?;
+            if self.scale < 0 {
+                write!(f, "e{}", self.scale.unsigned_abs())?;
+            }
+            return Ok(());
+        }
+        if self.unscaled < 0u32 {
+            f.write_char('-')?;
+        }
+        let digits = self.unscaled.unsigned_abs_ref().to_string();
+        let scale = self.scale.unsigned_abs();
+        let len = u64::exact_from(digits.len());
+        if len > scale {
+            let point_index = usize::exact_from(len - scale);
+            f.write_str(&digits[..point_index])?;
+            f.write_char('.')?;
+            f.write_str(&digits[point_index..])
+        } else {
+            f.write_str("0.")?;
+            for _ in 0..scale - len {
+                f.write_char('0')?;
+            }
+            f.write_str(&digits)
+        }
+    }
+}
+
+impl Debug for Decimal {
+    /// Converts a [`Decimal`] to a [`String`](alloc::string::String).
+    ///
+    /// This is the same implementation as for [`Display`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.unscaled().significant_bits(), self.scale().unsigned_abs())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_decimal::Decimal;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Decimal::ZERO.to_debug_string(), "0");
+    /// assert_eq!(
+    ///     Decimal::from_unscaled_and_scale(Integer::from(150), 2).to_debug_string(),
+    ///     "1.50"
+    /// );
+    /// assert_eq!(
+    ///     Decimal::from_unscaled_and_scale(Integer::from(15), -2).to_debug_string(),
+    ///     "15e2"
+    /// );
+    /// ```
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        Display::fmt(self, f)
+    }
+}
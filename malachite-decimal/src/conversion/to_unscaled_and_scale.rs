@@ -0,0 +1,72 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Decimal;
+use malachite_nz::integer::Integer;
+
+impl Decimal {
+    /// Returns a reference to the unscaled value of a [`Decimal`].
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_decimal::Decimal;
+    ///
+    /// assert_eq!(*"1.50".parse::<Decimal>().unwrap().unscaled(), 150);
+    /// assert_eq!(*"-7".parse::<Decimal>().unwrap().unscaled(), -7);
+    /// assert_eq!(*"12e3".parse::<Decimal>().unwrap().unscaled(), 12);
+    /// ```
+    #[inline]
+    pub const fn unscaled(&self) -> &Integer {
+        &self.unscaled
+    }
+
+    /// Returns the scale of a [`Decimal`].
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_decimal::Decimal;
+    ///
+    /// assert_eq!("1.50".parse::<Decimal>().unwrap().scale(), 2);
+    /// assert_eq!("-7".parse::<Decimal>().unwrap().scale(), 0);
+    /// assert_eq!("12e3".parse::<Decimal>().unwrap().scale(), -3);
+    /// ```
+    #[inline]
+    pub const fn scale(&self) -> i64 {
+        self.scale
+    }
+
+    /// Returns the unscaled value and the scale of a [`Decimal`], taking the [`Decimal`] by value.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_decimal::Decimal;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(
+    ///     "1.50".parse::<Decimal>().unwrap().into_unscaled_and_scale(),
+    ///     (Integer::from(150), 2)
+    /// );
+    /// assert_eq!(
+    ///     "12e3".parse::<Decimal>().unwrap().into_unscaled_and_scale(),
+    ///     (Integer::from(12), -3)
+    /// );
+    /// ```
+    #[inline]
+    pub fn into_unscaled_and_scale(self) -> (Integer, i64) {
+        (self.unscaled, self.scale)
+    }
+}
@@ -0,0 +1,175 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+//! This crate defines [`Decimal`]s, arbitrary-precision decimal numbers with an explicit scale.
+//! A [`Decimal`] is an [`Integer`] $u$, the _unscaled value_, together with an [`i64`] $s$, the
+//! _scale_; its value is $u \cdot 10^{-s}$. This is the representation used by SQL's `NUMERIC`
+//! type and by Java's `BigDecimal`.
+//! - The scale is part of a [`Decimal`]'s identity: `1.5` and `1.50` have the same value but
+//!   different scales, and both are preserved when the [`Decimal`]s are parsed and formatted.
+//!   Comparison and hashing, however, only consider the value, so `1.5` and `1.50` are equal.
+//! - Addition, subtraction, and multiplication are exact. The scale of a sum or difference is the
+//!   larger of the operands' scales, and the scale of a product is the sum of the operands' scales.
+//! - Division and changing the scale can't be exact in general, so these take the scale of the
+//!   result and a [`RoundingMode`](malachite_base::rounding_modes::RoundingMode). They also return
+//!   an [`Ordering`](core::cmp::Ordering) indicating whether the result is less than, equal to, or
+//!   greater than the exact value.
+//! - [`Decimal`]s can be converted exactly to [`Rational`]s, and a [`Rational`] can be converted
+//!   to a [`Decimal`] either exactly, if its denominator has no prime factors other than 2 and 5,
+//!   or with a specified scale and rounding.
+//!
+//! # Features
+//! - `32_bit_limbs`: Sets the type of [`Limb`](malachite_nz#limbs) to [`u32`] instead of the
+//!   default, [`u64`].
+//! - `random`: This feature provides some functions for randomly generating values. It is off by
+//!   default to avoid pulling in some extra dependencies.
+//! - `test_build`: A large proportion of the code in this crate is only used for testing. For a
+//!   typical user, building this code would result in an unnecessarily long compilation time and
+//!   an unnecessarily large binary. My solution is to only build this code when the `test_build`
+//!   feature is enabled. If you want to run unit tests, you must enable `test_build`. However,
+//!   doctests don't require it, since they only test the public interface. Enabling this feature
+//!   also enables `random`.
+//!
+//! [`Integer`]: malachite_nz::integer::Integer
+//! [`Rational`]: malachite_q::Rational
+
+#![allow(
+    unstable_name_collisions,
+    clippy::assertions_on_constants,
+    clippy::cognitive_complexity,
+    clippy::many_single_char_names,
+    clippy::range_plus_one,
+    clippy::suspicious_arithmetic_impl,
+    clippy::suspicious_op_assign_impl,
+    clippy::too_many_arguments,
+    clippy::type_complexity,
+    clippy::upper_case_acronyms
+)]
+#![warn(
+    clippy::cast_lossless,
+    clippy::explicit_into_iter_loop,
+    clippy::explicit_iter_loop,
+    clippy::filter_map_next,
+    clippy::large_digit_groups,
+    clippy::manual_filter_map,
+    clippy::manual_find_map,
+    clippy::map_flatten,
+    clippy::map_unwrap_or,
+    clippy::match_same_arms,
+    clippy::missing_const_for_fn,
+    clippy::mut_mut,
+    clippy::needless_borrow,
+    clippy::needless_continue,
+    clippy::needless_pass_by_value,
+    clippy::print_stdout,
+    clippy::redundant_closure_for_method_calls,
+    clippy::single_match_else,
+    clippy::trait_duplication_in_bounds,
+    clippy::type_repetition_in_bounds,
+    clippy::uninlined_format_args,
+    clippy::unused_self,
+    clippy::if_not_else,
+    clippy::manual_assert,
+    clippy::range_plus_one,
+    clippy::redundant_else,
+    clippy::semicolon_if_nothing_returned,
+    clippy::cloned_instead_of_copied,
+    clippy::flat_map_option,
+    clippy::unnecessary_wraps,
+    clippy::unnested_or_patterns,
+    clippy::trivially_copy_pass_by_ref
+)]
+#![cfg_attr(not(any(feature = "test_build", feature = "random")), no_std)]
+
+extern crate alloc;
+
+extern crate malachite_base;
+extern crate malachite_nz;
+extern crate malachite_q;
+
+use malachite_base::num::arithmetic::traits::Pow;
+use malachite_base::num::basic::traits::{NegativeOne, One, Two, Zero};
+use malachite_nz::integer::Integer;
+
+/// An arbitrary-precision decimal number with an explicit scale.
+///
+/// A [`Decimal`] with unscaled value $u$ and scale $s$ represents $u \cdot 10^{-s}$. The scale may
+/// be negative; for example, the [`Decimal`] with unscaled value 12 and scale $-3$ is 12000.
+///
+/// Many [`Decimal`]s have the same value: 1.5, 1.50, and 1.500 differ only in their scale. The
+/// scale is preserved by arithmetic and by conversion to and from strings, but not by comparison
+/// or hashing, which only look at the value.
+#[derive(Clone)]
+pub struct Decimal {
+    pub(crate) unscaled: Integer,
+    pub(crate) scale: i64,
+}
+
+impl Decimal {
+    // Returns true iff `self` is valid. To be valid, its unscaled value must be valid. All
+    // `Decimal`s must be valid.
+    #[cfg(feature = "test_build")]
+    pub fn is_valid(&self) -> bool {
+        self.unscaled.is_valid()
+    }
+}
+
+// Returns $10^k$.
+pub(crate) fn power_of_10(k: u64) -> Integer {
+    Integer::from(10u32).pow(k)
+}
+
+/// The constant 0.
+impl Zero for Decimal {
+    const ZERO: Decimal = Decimal {
+        unscaled: Integer::ZERO,
+        scale: 0,
+    };
+}
+
+/// The constant 1.
+impl One for Decimal {
+    const ONE: Decimal = Decimal {
+        unscaled: Integer::ONE,
+        scale: 0,
+    };
+}
+
+/// The constant 2.
+impl Two for Decimal {
+    const TWO: Decimal = Decimal {
+        unscaled: Integer::TWO,
+        scale: 0,
+    };
+}
+
+/// The constant -1.
+impl NegativeOne for Decimal {
+    const NEGATIVE_ONE: Decimal = Decimal {
+        unscaled: Integer::NEGATIVE_ONE,
+        scale: 0,
+    };
+}
+
+impl Default for Decimal {
+    /// The default value of a [`Decimal`], 0, with scale 0.
+    fn default() -> Decimal {
+        Decimal::ZERO
+    }
+}
+
+/// Traits for arithmetic.
+pub mod arithmetic;
+/// Traits for comparing [`Decimal`]s for equality or order, and for hashing them.
+pub mod comparison;
+/// Functions and traits for converting to and from [`Decimal`]s, including converting to and from
+/// strings.
+pub mod conversion;
+
+#[cfg(feature = "test_build")]
+pub mod test_util;
@@ -0,0 +1,104 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::test_util::extra_variadic::exhaustive_triples_from_single;
+use crate::test_util::generators::{div_is_exact, rescale_is_exact};
+use crate::Decimal;
+use malachite_base::num::arithmetic::traits::{Pow, Sign};
+use malachite_base::num::exhaustive::exhaustive_signeds;
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_base::test_util::generators::common::It;
+use malachite_base::tuples::exhaustive::{
+    exhaustive_pairs, exhaustive_pairs_from_single, exhaustive_triples,
+};
+use malachite_nz::integer::exhaustive::exhaustive_integers;
+use malachite_q::exhaustive::exhaustive_rationals;
+use malachite_q::Rational;
+use std::cmp::Ordering::*;
+
+fn exhaustive_decimals() -> impl Clone + Iterator<Item = Decimal> {
+    exhaustive_pairs(exhaustive_integers(), exhaustive_signeds::<i64>())
+        .map(|(unscaled, scale)| Decimal { unscaled, scale })
+}
+
+// -- Decimal --
+
+pub fn exhaustive_decimal_gen() -> It<Decimal> {
+    Box::new(exhaustive_decimals())
+}
+
+// -- (Decimal, Decimal) --
+
+pub fn exhaustive_decimal_pair_gen() -> It<(Decimal, Decimal)> {
+    Box::new(exhaustive_pairs_from_single(exhaustive_decimals()))
+}
+
+pub fn exhaustive_decimal_pair_gen_var_1() -> It<(Decimal, Decimal)> {
+    Box::new(exhaustive_pairs(
+        exhaustive_decimals(),
+        exhaustive_decimals().filter(|y| y.sign() != Equal),
+    ))
+}
+
+// -- (Decimal, Decimal, Decimal) --
+
+pub fn exhaustive_decimal_triple_gen() -> It<(Decimal, Decimal, Decimal)> {
+    Box::new(exhaustive_triples_from_single(exhaustive_decimals()))
+}
+
+// -- (Decimal, Decimal, i64, RoundingMode) --
+
+pub fn exhaustive_decimal_decimal_signed_rounding_mode_quadruple_gen_var_1(
+) -> It<(Decimal, Decimal, i64, RoundingMode)> {
+    Box::new(
+        exhaustive_triples(
+            exhaustive_decimal_pair_gen_var_1(),
+            exhaustive_signeds::<i64>(),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|((x, y), scale, rm)| *rm != Exact || div_is_exact(x, y, *scale))
+        .map(|((x, y), scale, rm)| (x, y, scale, rm)),
+    )
+}
+
+// -- (Decimal, i64, RoundingMode) --
+
+pub fn exhaustive_decimal_signed_rounding_mode_triple_gen_var_1() -> It<(Decimal, i64, RoundingMode)>
+{
+    Box::new(
+        exhaustive_triples(
+            exhaustive_decimals(),
+            exhaustive_signeds::<i64>(),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|(x, scale, rm)| *rm != Exact || rescale_is_exact(x, *scale)),
+    )
+}
+
+// -- Rational --
+
+pub fn exhaustive_rational_gen() -> It<Rational> {
+    Box::new(exhaustive_rationals())
+}
+
+// -- (Rational, i64, RoundingMode) --
+
+pub fn exhaustive_rational_signed_rounding_mode_triple_gen_var_1(
+) -> It<(Rational, i64, RoundingMode)> {
+    Box::new(
+        exhaustive_triples(
+            exhaustive_rationals(),
+            exhaustive_signeds::<i64>(),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|(x, scale, rm)| {
+            *rm != Exact || (x * Rational::from(10u32).pow(*scale)).denominator_ref() == &1u32
+        }),
+    )
+}
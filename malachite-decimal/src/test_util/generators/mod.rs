@@ -0,0 +1,96 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::test_util::generators::exhaustive::*;
+use crate::test_util::generators::random::*;
+use crate::{power_of_10, Decimal};
+use malachite_base::num::arithmetic::traits::{DivisibleBy, Pow};
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_base::test_util::generators::common::Generator;
+use malachite_q::Rational;
+
+// Returns whether `x` can be rescaled to `scale` without rounding.
+pub(crate) fn rescale_is_exact(x: &Decimal, scale: i64) -> bool {
+    scale >= x.scale || (&x.unscaled).divisible_by(power_of_10(x.scale.abs_diff(scale)))
+}
+
+// Returns whether `x / y` is a multiple of 10^(-`scale`).
+pub(crate) fn div_is_exact(x: &Decimal, y: &Decimal, scale: i64) -> bool {
+    (Rational::from(x) / Rational::from(y) * Rational::from(10u32).pow(scale)).denominator_ref()
+        == &1u32
+}
+
+// -- Decimal --
+
+pub fn decimal_gen() -> Generator<Decimal> {
+    Generator::new_no_special(&exhaustive_decimal_gen, &random_decimal_gen)
+}
+
+// -- (Decimal, Decimal) --
+
+pub fn decimal_pair_gen() -> Generator<(Decimal, Decimal)> {
+    Generator::new_no_special(&exhaustive_decimal_pair_gen, &random_decimal_pair_gen)
+}
+
+// All pairs of `Decimal`s where the second is nonzero.
+pub fn decimal_pair_gen_var_1() -> Generator<(Decimal, Decimal)> {
+    Generator::new_no_special(
+        &exhaustive_decimal_pair_gen_var_1,
+        &random_decimal_pair_gen_var_1,
+    )
+}
+
+// -- (Decimal, Decimal, Decimal) --
+
+pub fn decimal_triple_gen() -> Generator<(Decimal, Decimal, Decimal)> {
+    Generator::new_no_special(&exhaustive_decimal_triple_gen, &random_decimal_triple_gen)
+}
+
+// -- (Decimal, Decimal, i64, RoundingMode) --
+
+// All quadruples of two `Decimal`s $x$ and $y$, an `i64` $s$, and a `RoundingMode` $m$, where $y$
+// is nonzero, and $m$ is not `Exact` unless $x/y$ is a multiple of $10^{-s}$.
+pub fn decimal_decimal_signed_rounding_mode_quadruple_gen_var_1(
+) -> Generator<(Decimal, Decimal, i64, RoundingMode)> {
+    Generator::new_no_special(
+        &exhaustive_decimal_decimal_signed_rounding_mode_quadruple_gen_var_1,
+        &random_decimal_decimal_signed_rounding_mode_quadruple_gen_var_1,
+    )
+}
+
+// -- (Decimal, i64, RoundingMode) --
+
+// All triples of a `Decimal` $x$, an `i64` $s$, and a `RoundingMode` $m$, where $m$ is not `Exact`
+// unless $x$ is a multiple of $10^{-s}$.
+pub fn decimal_signed_rounding_mode_triple_gen_var_1() -> Generator<(Decimal, i64, RoundingMode)> {
+    Generator::new_no_special(
+        &exhaustive_decimal_signed_rounding_mode_triple_gen_var_1,
+        &random_decimal_signed_rounding_mode_triple_gen_var_1,
+    )
+}
+
+// -- Rational --
+
+pub fn rational_gen() -> Generator<Rational> {
+    Generator::new_no_special(&exhaustive_rational_gen, &random_rational_gen)
+}
+
+// -- (Rational, i64, RoundingMode) --
+
+// All triples of a `Rational` $x$, an `i64` $s$, and a `RoundingMode` $m$, where $m$ is not
+// `Exact` unless $x$ is a multiple of $10^{-s}$.
+pub fn rational_signed_rounding_mode_triple_gen_var_1() -> Generator<(Rational, i64, RoundingMode)>
+{
+    Generator::new_no_special(
+        &exhaustive_rational_signed_rounding_mode_triple_gen_var_1,
+        &random_rational_signed_rounding_mode_triple_gen_var_1,
+    )
+}
+
+pub mod exhaustive;
+pub mod random;
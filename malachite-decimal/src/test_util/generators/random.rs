@@ -0,0 +1,149 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::test_util::extra_variadic::{random_triples, random_triples_from_single};
+use crate::test_util::generators::{div_is_exact, rescale_is_exact};
+use crate::Decimal;
+use malachite_base::num::arithmetic::traits::{Pow, Sign};
+use malachite_base::num::random::geometric::geometric_random_signeds;
+use malachite_base::random::{Seed, EXAMPLE_SEED};
+use malachite_base::rounding_modes::random::random_rounding_modes;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_base::test_util::generators::common::{GenConfig, It};
+use malachite_base::tuples::random::{random_pairs, random_pairs_from_single};
+use malachite_nz::integer::random::random_integers;
+use malachite_q::random::random_rationals;
+use malachite_q::Rational;
+use std::cmp::Ordering::*;
+
+fn random_scales(seed: Seed, config: &GenConfig) -> impl Iterator<Item = i64> {
+    geometric_random_signeds(
+        seed,
+        config.get_or("mean_scale_n", 4),
+        config.get_or("mean_scale_d", 1),
+    )
+}
+
+fn random_decimals(seed: Seed, config: &GenConfig) -> impl Iterator<Item = Decimal> {
+    random_pairs(
+        seed,
+        &|seed_2| {
+            random_integers(
+                seed_2,
+                config.get_or("mean_bits_n", 64),
+                config.get_or("mean_bits_d", 1),
+            )
+        },
+        &|seed_2| random_scales(seed_2, config),
+    )
+    .map(|(unscaled, scale)| Decimal { unscaled, scale })
+}
+
+fn random_decimal_pairs_var_1(
+    seed: Seed,
+    config: &GenConfig,
+) -> impl Iterator<Item = (Decimal, Decimal)> {
+    random_pairs(seed, &|seed_2| random_decimals(seed_2, config), &|seed_2| {
+        random_decimals(seed_2, config).filter(|y| y.sign() != Equal)
+    })
+}
+
+// -- Decimal --
+
+pub fn random_decimal_gen(config: &GenConfig) -> It<Decimal> {
+    Box::new(random_decimals(EXAMPLE_SEED, config))
+}
+
+// -- (Decimal, Decimal) --
+
+pub fn random_decimal_pair_gen(config: &GenConfig) -> It<(Decimal, Decimal)> {
+    Box::new(random_pairs_from_single(random_decimals(
+        EXAMPLE_SEED,
+        config,
+    )))
+}
+
+pub fn random_decimal_pair_gen_var_1(config: &GenConfig) -> It<(Decimal, Decimal)> {
+    Box::new(random_decimal_pairs_var_1(EXAMPLE_SEED, config))
+}
+
+// -- (Decimal, Decimal, Decimal) --
+
+pub fn random_decimal_triple_gen(config: &GenConfig) -> It<(Decimal, Decimal, Decimal)> {
+    Box::new(random_triples_from_single(random_decimals(
+        EXAMPLE_SEED,
+        config,
+    )))
+}
+
+// -- (Decimal, Decimal, i64, RoundingMode) --
+
+pub fn random_decimal_decimal_signed_rounding_mode_quadruple_gen_var_1(
+    config: &GenConfig,
+) -> It<(Decimal, Decimal, i64, RoundingMode)> {
+    Box::new(
+        random_triples(
+            EXAMPLE_SEED,
+            &|seed| random_decimal_pairs_var_1(seed, config),
+            &|seed| random_scales(seed, config),
+            &random_rounding_modes,
+        )
+        .filter(|((x, y), scale, rm)| *rm != Exact || div_is_exact(x, y, *scale))
+        .map(|((x, y), scale, rm)| (x, y, scale, rm)),
+    )
+}
+
+// -- (Decimal, i64, RoundingMode) --
+
+pub fn random_decimal_signed_rounding_mode_triple_gen_var_1(
+    config: &GenConfig,
+) -> It<(Decimal, i64, RoundingMode)> {
+    Box::new(
+        random_triples(
+            EXAMPLE_SEED,
+            &|seed| random_decimals(seed, config),
+            &|seed| random_scales(seed, config),
+            &random_rounding_modes,
+        )
+        .filter(|(x, scale, rm)| *rm != Exact || rescale_is_exact(x, *scale)),
+    )
+}
+
+// -- Rational --
+
+pub fn random_rational_gen(config: &GenConfig) -> It<Rational> {
+    Box::new(random_rationals(
+        EXAMPLE_SEED,
+        config.get_or("mean_bits_n", 64),
+        config.get_or("mean_bits_d", 1),
+    ))
+}
+
+// -- (Rational, i64, RoundingMode) --
+
+pub fn random_rational_signed_rounding_mode_triple_gen_var_1(
+    config: &GenConfig,
+) -> It<(Rational, i64, RoundingMode)> {
+    Box::new(
+        random_triples(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_rationals(
+                    seed,
+                    config.get_or("mean_bits_n", 64),
+                    config.get_or("mean_bits_d", 1),
+                )
+            },
+            &|seed| random_scales(seed, config),
+            &random_rounding_modes,
+        )
+        .filter(|(x, scale, rm)| {
+            *rm != Exact || (x * Rational::from(10u32).pow(*scale)).denominator_ref() == &1u32
+        }),
+    )
+}
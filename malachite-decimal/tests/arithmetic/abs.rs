@@ -0,0 +1,64 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{Abs, AbsAssign, Sign};
+use malachite_decimal::test_util::generators::decimal_gen;
+use malachite_decimal::Decimal;
+use malachite_q::Rational;
+use std::cmp::Ordering::*;
+use std::str::FromStr;
+
+#[test]
+fn test_abs() {
+    let test = |s, out| {
+        let x = Decimal::from_str(s).unwrap();
+
+        let abs = x.clone().abs();
+        assert!(abs.is_valid());
+        assert_eq!(abs.to_string(), out);
+
+        let abs = (&x).abs();
+        assert!(abs.is_valid());
+        assert_eq!(abs.to_string(), out);
+
+        let mut abs = x.clone();
+        abs.abs_assign();
+        assert!(abs.is_valid());
+        assert_eq!(abs.to_string(), out);
+    };
+    test("0", "0");
+    test("0.00", "0.00");
+    test("1.50", "1.50");
+    test("-1.50", "1.50");
+    test("-12e3", "12e3");
+    test(
+        "-123456789012345678901234567890.123456789",
+        "123456789012345678901234567890.123456789",
+    );
+}
+
+#[test]
+fn abs_properties() {
+    decimal_gen().test_properties(|x| {
+        let abs = x.clone().abs();
+        assert!(abs.is_valid());
+        let abs_alt = (&x).abs();
+        assert!(abs_alt.is_valid());
+        assert_eq!(abs_alt.to_string(), abs.to_string());
+        let mut mut_x = x.clone();
+        mut_x.abs_assign();
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x.to_string(), abs.to_string());
+
+        assert_eq!(abs.scale(), x.scale());
+        assert_eq!((&abs).abs().to_string(), abs.to_string());
+        assert_ne!(abs.sign(), Less);
+        assert_eq!(abs == x, x.sign() != Less);
+        assert_eq!(Rational::from(&abs), Rational::from(&x).abs());
+    });
+}
@@ -0,0 +1,208 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::Abs;
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::rounding_modes::RoundingMode::*;
+use malachite_decimal::test_util::generators::{
+    decimal_decimal_signed_rounding_mode_quadruple_gen_var_1, decimal_pair_gen_var_1,
+};
+use malachite_decimal::Decimal;
+use malachite_q::Rational;
+use std::cmp::Ordering::{self, *};
+use std::str::FromStr;
+
+#[test]
+fn test_div_scale_round() {
+    let test = |s, t, scale, rm, out, o_out: Ordering| {
+        let u = Decimal::from_str(s).unwrap();
+        let v = Decimal::from_str(t).unwrap();
+
+        let (q, o) = u.clone().div_scale_round(v.clone(), scale, rm);
+        assert_eq!(q.to_string(), out);
+        assert!(q.is_valid());
+        assert_eq!(o, o_out);
+
+        let (q, o) = u.clone().div_scale_round_val_ref(&v, scale, rm);
+        assert_eq!(q.to_string(), out);
+        assert!(q.is_valid());
+        assert_eq!(o, o_out);
+
+        let (q, o) = u.div_scale_round_ref_val(v.clone(), scale, rm);
+        assert_eq!(q.to_string(), out);
+        assert!(q.is_valid());
+        assert_eq!(o, o_out);
+
+        let (q, o) = u.div_scale_round_ref_ref(&v, scale, rm);
+        assert_eq!(q.to_string(), out);
+        assert!(q.is_valid());
+        assert_eq!(o, o_out);
+
+        let mut q = u.clone();
+        assert_eq!(q.div_scale_round_assign(v.clone(), scale, rm), o_out);
+        assert_eq!(q.to_string(), out);
+        assert!(q.is_valid());
+
+        let mut q = u.clone();
+        assert_eq!(q.div_scale_round_assign_ref(&v, scale, rm), o_out);
+        assert_eq!(q.to_string(), out);
+        assert!(q.is_valid());
+    };
+    test("0", "1", 0, Exact, "0", Equal);
+    test("0.00", "-3.5", 3, Exact, "0.000", Equal);
+    test("1", "3", 2, Nearest, "0.33", Less);
+    test("2", "3", 2, Nearest, "0.67", Greater);
+    test("1", "3", 2, Up, "0.34", Greater);
+    test("-1", "3", 1, Floor, "-0.4", Less);
+    test("-1", "3", 1, Ceiling, "-0.3", Greater);
+    test("1", "8", 3, Exact, "0.125", Equal);
+    test("1", "8", 5, Exact, "0.12500", Equal);
+    test("-7.5", "2.5", 0, Exact, "-3", Equal);
+    test("10", "4", 0, Nearest, "2", Less);
+    test("14", "4", 0, Nearest, "4", Greater);
+    test("1", "3", -1, Nearest, "0e1", Less);
+    test("12e3", "0.004", -5, Exact, "30e5", Equal);
+    test("12e3", "0.004", -6, Exact, "3e6", Equal);
+    test("22", "7", 10, Down, "3.1428571428", Less);
+}
+
+#[test]
+#[should_panic]
+fn div_scale_round_fail_1() {
+    Decimal::ONE.div_scale_round(Decimal::ZERO, 0, Floor);
+}
+
+#[test]
+#[should_panic]
+fn div_scale_round_fail_2() {
+    Decimal::ONE.div_scale_round(Decimal::from_str("3").unwrap(), 2, Exact);
+}
+
+#[test]
+#[should_panic]
+fn div_scale_round_ref_ref_fail() {
+    Decimal::ONE.div_scale_round_ref_ref(&Decimal::ZERO, 0, Floor);
+}
+
+#[test]
+#[should_panic]
+fn div_scale_round_assign_fail() {
+    let mut x = Decimal::ONE;
+    x.div_scale_round_assign(Decimal::ZERO, 0, Floor);
+}
+
+#[test]
+fn test_div_scale() {
+    let test = |s, t, scale, out, o_out: Ordering| {
+        let u = Decimal::from_str(s).unwrap();
+        let v = Decimal::from_str(t).unwrap();
+
+        let (q, o) = u.clone().div_scale(v.clone(), scale);
+        assert_eq!(q.to_string(), out);
+        assert!(q.is_valid());
+        assert_eq!(o, o_out);
+
+        let (q, o) = u.clone().div_scale_val_ref(&v, scale);
+        assert_eq!(q.to_string(), out);
+        assert_eq!(o, o_out);
+
+        let (q, o) = u.div_scale_ref_val(v.clone(), scale);
+        assert_eq!(q.to_string(), out);
+        assert_eq!(o, o_out);
+
+        let (q, o) = u.div_scale_ref_ref(&v, scale);
+        assert_eq!(q.to_string(), out);
+        assert_eq!(o, o_out);
+
+        let mut q = u.clone();
+        assert_eq!(q.div_scale_assign(v.clone(), scale), o_out);
+        assert_eq!(q.to_string(), out);
+
+        let mut q = u.clone();
+        assert_eq!(q.div_scale_assign_ref(&v, scale), o_out);
+        assert_eq!(q.to_string(), out);
+    };
+    test("0", "1", 0, "0", Equal);
+    test("1", "3", 2, "0.33", Less);
+    test("2", "3", 2, "0.67", Greater);
+    test("10", "4", 0, "2", Less);
+    test("1", "8", 3, "0.125", Equal);
+}
+
+#[test]
+#[should_panic]
+fn div_scale_fail() {
+    Decimal::ONE.div_scale(Decimal::ZERO, 0);
+}
+
+#[test]
+fn div_scale_round_properties() {
+    decimal_decimal_signed_rounding_mode_quadruple_gen_var_1().test_properties(
+        |(x, y, scale, rm)| {
+            let (q, o) = x.clone().div_scale_round(y.clone(), scale, rm);
+            assert!(q.is_valid());
+            assert_eq!(q.scale(), scale);
+
+            let (q_alt, o_alt) = x.clone().div_scale_round_val_ref(&y, scale, rm);
+            assert_eq!(q_alt.to_string(), q.to_string());
+            assert_eq!(o_alt, o);
+            let (q_alt, o_alt) = x.div_scale_round_ref_val(y.clone(), scale, rm);
+            assert_eq!(q_alt.to_string(), q.to_string());
+            assert_eq!(o_alt, o);
+            let (q_alt, o_alt) = x.div_scale_round_ref_ref(&y, scale, rm);
+            assert_eq!(q_alt.to_string(), q.to_string());
+            assert_eq!(o_alt, o);
+
+            let mut mut_x = x.clone();
+            assert_eq!(mut_x.div_scale_round_assign(y.clone(), scale, rm), o);
+            assert!(mut_x.is_valid());
+            assert_eq!(mut_x.to_string(), q.to_string());
+            let mut mut_x = x.clone();
+            assert_eq!(mut_x.div_scale_round_assign_ref(&y, scale, rm), o);
+            assert!(mut_x.is_valid());
+            assert_eq!(mut_x.to_string(), q.to_string());
+
+            let exact = Rational::from(&x) / Rational::from(&y);
+            assert_eq!(Rational::from(&q).cmp(&exact), o);
+            let (q_alt, o_alt) = Decimal::from_rational_scale_round_ref(&exact, scale, rm);
+            assert_eq!(q_alt.to_string(), q.to_string());
+            assert_eq!(o_alt, o);
+            match rm {
+                Floor => assert_ne!(o, Greater),
+                Ceiling => assert_ne!(o, Less),
+                Down => assert!(Rational::from(&q).abs() <= exact.abs()),
+                Up => assert!(Rational::from(&q).abs() >= exact.abs()),
+                Exact => assert_eq!(o, Equal),
+                _ => {}
+            }
+            if o == Equal {
+                for rm in exhaustive_rounding_modes() {
+                    let (q_alt, o_alt) = x.div_scale_round_ref_ref(&y, scale, rm);
+                    assert_eq!(q_alt.to_string(), q.to_string());
+                    assert_eq!(o_alt, Equal);
+                }
+            }
+        },
+    );
+
+    decimal_pair_gen_var_1().test_properties(|(x, y)| {
+        let (q, o) = x.div_scale_round_ref_ref(&y, x.scale(), Down);
+        let (q_alt, o_alt) = x.div_scale_round_ref_ref(&-&y, x.scale(), Down);
+        assert_eq!(q_alt.to_string(), (-&q).to_string());
+        assert_eq!(o_alt, o.reverse());
+
+        let (one, o) = y.div_scale_round_ref_ref(&y, 0, Exact);
+        assert_eq!(one.to_string(), "1");
+        assert_eq!(o, Equal);
+
+        let (x_alt, o) = (&x * &y).div_scale_round_ref_ref(&y, x.scale(), Exact);
+        assert_eq!(x_alt.to_string(), x.to_string());
+        assert_eq!(o, Equal);
+    });
+}
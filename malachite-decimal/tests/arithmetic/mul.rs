@@ -0,0 +1,117 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::basic::traits::{NegativeOne, One, Zero};
+use malachite_decimal::test_util::generators::{decimal_gen, decimal_pair_gen, decimal_triple_gen};
+use malachite_decimal::Decimal;
+use malachite_nz::integer::Integer;
+use malachite_q::Rational;
+use std::str::FromStr;
+
+#[test]
+fn test_mul() {
+    let test = |s, t, out| {
+        let u = Decimal::from_str(s).unwrap();
+        let v = Decimal::from_str(t).unwrap();
+
+        let mut n = u.clone();
+        n *= v.clone();
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let mut n = u.clone();
+        n *= &v;
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = u.clone() * v.clone();
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = &u * v.clone();
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = u.clone() * &v;
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = &u * &v;
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+    };
+    test("0", "0", "0");
+    test("0.0", "0.00", "0.000");
+    test("1.5", "2.25", "3.375");
+    test("1.50", "-2.0", "-3.000");
+    test("0.1", "0.1", "0.01");
+    test("12e3", "0.5", "60e2");
+    test("12e3", "3e2", "36e5");
+    test("-0.001", "-1000", "1.000");
+    test(
+        "123456789012345678901234567890.5",
+        "0.000000000000000000002",
+        "246913578.0246913578024691357810",
+    );
+}
+
+#[test]
+#[should_panic]
+fn mul_fail() {
+    let x = Decimal::from_unscaled_and_scale(Integer::ONE, i64::MAX);
+    let _ = &x * &x;
+}
+
+#[test]
+fn mul_properties() {
+    decimal_pair_gen().test_properties(|(x, y)| {
+        let product_val_val = x.clone() * y.clone();
+        let product_val_ref = x.clone() * &y;
+        let product_ref_val = &x * y.clone();
+        let product = &x * &y;
+        assert!(product_val_val.is_valid());
+        assert!(product_val_ref.is_valid());
+        assert!(product_ref_val.is_valid());
+        assert!(product.is_valid());
+        assert_eq!(product_val_val.to_string(), product.to_string());
+        assert_eq!(product_val_ref.to_string(), product.to_string());
+        assert_eq!(product_ref_val.to_string(), product.to_string());
+
+        let mut mut_x = x.clone();
+        mut_x *= y.clone();
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x.to_string(), product.to_string());
+        let mut mut_x = x.clone();
+        mut_x *= &y;
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x.to_string(), product.to_string());
+
+        assert_eq!(product.scale(), x.scale() + y.scale());
+        assert_eq!(
+            Rational::from(&product),
+            Rational::from(&x) * Rational::from(&y)
+        );
+        assert_eq!((&y * &x).to_string(), product.to_string());
+        assert_eq!((-&x * &y).to_string(), (-&product).to_string());
+    });
+
+    decimal_gen().test_properties(|x| {
+        assert_eq!((&x * Decimal::ONE).to_string(), x.to_string());
+        assert_eq!((Decimal::ONE * &x).to_string(), x.to_string());
+        assert_eq!((&x * Decimal::NEGATIVE_ONE).to_string(), (-&x).to_string());
+        assert_eq!(&x * Decimal::ZERO, Decimal::ZERO);
+    });
+
+    decimal_triple_gen().test_properties(|(x, y, z)| {
+        assert_eq!(((&x * &y) * &z).to_string(), (&x * (&y * &z)).to_string());
+        assert_eq!(
+            (&x * (&y + &z)).to_string(),
+            (&x * &y + &x * &z).to_string()
+        );
+    });
+}
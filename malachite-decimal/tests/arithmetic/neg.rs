@@ -0,0 +1,64 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::NegAssign;
+use malachite_base::num::basic::traits::Zero;
+use malachite_decimal::test_util::generators::decimal_gen;
+use malachite_decimal::Decimal;
+use malachite_q::Rational;
+use std::str::FromStr;
+
+#[test]
+fn test_neg() {
+    let test = |s, out| {
+        let x = Decimal::from_str(s).unwrap();
+
+        let neg = -x.clone();
+        assert!(neg.is_valid());
+        assert_eq!(neg.to_string(), out);
+
+        let neg = -&x;
+        assert!(neg.is_valid());
+        assert_eq!(neg.to_string(), out);
+
+        let mut neg = x.clone();
+        neg.neg_assign();
+        assert!(neg.is_valid());
+        assert_eq!(neg.to_string(), out);
+    };
+    test("0", "0");
+    test("0.00", "0.00");
+    test("1.50", "-1.50");
+    test("-1.50", "1.50");
+    test("12e3", "-12e3");
+    test(
+        "-123456789012345678901234567890.123456789",
+        "123456789012345678901234567890.123456789",
+    );
+}
+
+#[test]
+fn neg_properties() {
+    decimal_gen().test_properties(|x| {
+        let neg = -x.clone();
+        assert!(neg.is_valid());
+        let neg_alt = -&x;
+        assert!(neg_alt.is_valid());
+        assert_eq!(neg_alt.to_string(), neg.to_string());
+        let mut mut_x = x.clone();
+        mut_x.neg_assign();
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x.to_string(), neg.to_string());
+
+        assert_eq!(neg.scale(), x.scale());
+        assert_eq!((-&neg).to_string(), x.to_string());
+        assert_eq!(Rational::from(&neg), -Rational::from(&x));
+        assert_eq!(&neg + &x, Decimal::ZERO);
+        assert_eq!(neg == x, x == Decimal::ZERO);
+    });
+}
@@ -0,0 +1,77 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::DivisibleBy;
+use malachite_decimal::test_util::generators::{decimal_gen, decimal_pair_gen};
+use malachite_decimal::Decimal;
+use malachite_nz::integer::Integer;
+use malachite_q::Rational;
+use std::str::FromStr;
+
+#[test]
+fn test_normalize() {
+    let test = |s, out| {
+        let u = Decimal::from_str(s).unwrap();
+
+        let n = u.clone().normalize();
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = u.normalize_ref();
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let mut n = u;
+        n.normalize_assign();
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+    };
+    test("0", "0");
+    test("0.000", "0");
+    test("0e5", "0");
+    test("1", "1");
+    test("1.2500", "1.25");
+    test("-1200.0", "-12e2");
+    test("10", "1e1");
+    test("0.001000", "0.001");
+    test("12e3", "12e3");
+    test("120e3", "12e4");
+    test("100000000000000000000000000000000000000", "1e38");
+    test("-123456789.000000000000000000000", "-123456789");
+}
+
+#[test]
+fn normalize_properties() {
+    decimal_gen().test_properties(|x| {
+        let n = x.clone().normalize();
+        assert!(n.is_valid());
+        assert_eq!(x.normalize_ref().to_string(), n.to_string());
+        let mut mut_x = x.clone();
+        mut_x.normalize_assign();
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x.to_string(), n.to_string());
+
+        assert_eq!(n, x);
+        assert_eq!(Rational::from(&n), Rational::from(&x));
+        assert_eq!(n.normalize_ref().to_string(), n.to_string());
+        if *n.unscaled() == 0u32 {
+            assert_eq!(n.scale(), 0);
+        } else {
+            assert!(!n.unscaled().divisible_by(Integer::from(10u32)));
+            assert!(n.scale() <= x.scale());
+        }
+        assert_eq!((-&x).normalize().to_string(), (-n).to_string());
+    });
+
+    decimal_pair_gen().test_properties(|(x, y)| {
+        assert_eq!(
+            x == y,
+            x.normalize_ref().to_string() == y.normalize_ref().to_string()
+        );
+    });
+}
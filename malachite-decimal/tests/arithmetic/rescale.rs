@@ -0,0 +1,164 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::rounding_modes::RoundingMode::*;
+use malachite_decimal::test_util::generators::{
+    decimal_gen, decimal_signed_rounding_mode_triple_gen_var_1,
+};
+use malachite_decimal::Decimal;
+use malachite_q::Rational;
+use std::cmp::Ordering::{self, *};
+use std::str::FromStr;
+
+#[test]
+fn test_rescale_round() {
+    let test = |s, scale, rm, out, o_out: Ordering| {
+        let u = Decimal::from_str(s).unwrap();
+
+        let (n, o) = u.clone().rescale_round(scale, rm);
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+        assert_eq!(o, o_out);
+
+        let (n, o) = u.rescale_round_ref(scale, rm);
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+        assert_eq!(o, o_out);
+
+        let mut n = u;
+        assert_eq!(n.rescale_round_assign(scale, rm), o_out);
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+    };
+    test("0", 0, Exact, "0", Equal);
+    test("0", 3, Exact, "0.000", Equal);
+    test("0.000", -2, Exact, "0e2", Equal);
+    test("1.25", 4, Exact, "1.2500", Equal);
+    test("1.25", 1, Nearest, "1.2", Less);
+    test("1.35", 1, Nearest, "1.4", Greater);
+    test("1.25", 1, Up, "1.3", Greater);
+    test("1.25", 1, Down, "1.2", Less);
+    test("-1.25", 1, Floor, "-1.3", Less);
+    test("-1.25", 1, Ceiling, "-1.2", Greater);
+    test("-1.25", 1, Down, "-1.2", Greater);
+    test("-1.25", 1, Up, "-1.3", Less);
+    test("1.25", -1, Nearest, "0e1", Less);
+    test("1.2500", 2, Exact, "1.25", Equal);
+    test("12e3", 1, Exact, "12000.0", Equal);
+    test("12345", -2, Nearest, "123e2", Less);
+    test("12355", -1, Nearest, "1236e1", Greater);
+}
+
+#[test]
+#[should_panic]
+fn rescale_round_fail() {
+    Decimal::from_str("1.25").unwrap().rescale_round(1, Exact);
+}
+
+#[test]
+#[should_panic]
+fn rescale_round_ref_fail() {
+    Decimal::from_str("1.25")
+        .unwrap()
+        .rescale_round_ref(1, Exact);
+}
+
+#[test]
+#[should_panic]
+fn rescale_round_assign_fail() {
+    let mut x = Decimal::from_str("1.25").unwrap();
+    x.rescale_round_assign(1, Exact);
+}
+
+#[test]
+fn test_rescale() {
+    let test = |s, scale, out, o_out: Ordering| {
+        let u = Decimal::from_str(s).unwrap();
+
+        let (n, o) = u.clone().rescale(scale);
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+        assert_eq!(o, o_out);
+
+        let (n, o) = u.rescale_ref(scale);
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+        assert_eq!(o, o_out);
+
+        let mut n = u;
+        assert_eq!(n.rescale_assign(scale), o_out);
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+    };
+    test("1.25", 4, "1.2500", Equal);
+    test("1.25", 1, "1.2", Less);
+    test("1.35", 1, "1.4", Greater);
+    test("-2.5", 0, "-2", Greater);
+    test("-3.5", 0, "-4", Less);
+}
+
+#[test]
+fn rescale_round_properties() {
+    decimal_signed_rounding_mode_triple_gen_var_1().test_properties(|(x, scale, rm)| {
+        let (n, o) = x.clone().rescale_round(scale, rm);
+        assert!(n.is_valid());
+        assert_eq!(n.scale(), scale);
+
+        let (n_alt, o_alt) = x.rescale_round_ref(scale, rm);
+        assert!(n_alt.is_valid());
+        assert_eq!(n_alt.to_string(), n.to_string());
+        assert_eq!(o_alt, o);
+
+        let mut mut_x = x.clone();
+        assert_eq!(mut_x.rescale_round_assign(scale, rm), o);
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x.to_string(), n.to_string());
+
+        let rational_x = Rational::from(&x);
+        assert_eq!(Rational::from(&n).cmp(&rational_x), o);
+        let (n_alt, o_alt) = Decimal::from_rational_scale_round(rational_x, scale, rm);
+        assert_eq!(n_alt.to_string(), n.to_string());
+        assert_eq!(o_alt, o);
+        match rm {
+            Floor => assert_ne!(o, Greater),
+            Ceiling => assert_ne!(o, Less),
+            Exact => assert_eq!(o, Equal),
+            _ => {}
+        }
+
+        let (n_alt, o_alt) = (-&x).rescale_round(scale, -rm);
+        assert_eq!(n_alt.to_string(), (-&n).to_string());
+        assert_eq!(o_alt, o.reverse());
+
+        if scale >= x.scale() {
+            assert_eq!(o, Equal);
+            assert_eq!(n, x);
+            assert_eq!(
+                n.rescale_round_ref(x.scale(), Exact).0.to_string(),
+                x.to_string()
+            );
+        }
+        if o == Equal {
+            for rm in exhaustive_rounding_modes() {
+                let (n_alt, o_alt) = x.rescale_round_ref(scale, rm);
+                assert_eq!(n_alt.to_string(), n.to_string());
+                assert_eq!(o_alt, Equal);
+            }
+        }
+    });
+
+    decimal_gen().test_properties(|x| {
+        let (n, o) = x.rescale_round_ref(x.scale(), Exact);
+        assert_eq!(n.to_string(), x.to_string());
+        assert_eq!(o, Equal);
+        let (n, o) = x.rescale_ref(x.scale());
+        assert_eq!(n.to_string(), x.to_string());
+        assert_eq!(o, Equal);
+    });
+}
@@ -0,0 +1,42 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::Sign;
+use malachite_base::num::basic::traits::Zero;
+use malachite_decimal::test_util::generators::decimal_gen;
+use malachite_decimal::Decimal;
+use malachite_q::Rational;
+use std::cmp::Ordering::*;
+use std::str::FromStr;
+
+#[test]
+fn test_sign() {
+    let test = |s, out| {
+        assert_eq!(Decimal::from_str(s).unwrap().sign(), out);
+    };
+    test("0", Equal);
+    test("0.000", Equal);
+    test("0e5", Equal);
+    test("1.50", Greater);
+    test("0.001", Greater);
+    test("-1.50", Less);
+    test("-12e3", Less);
+}
+
+#[test]
+fn sign_properties() {
+    decimal_gen().test_properties(|x| {
+        let sign = x.sign();
+        assert_eq!(Rational::from(&x).sign(), sign);
+        assert_eq!(x.unscaled().sign(), sign);
+        if sign != Equal {
+            assert_eq!((-&x).sign(), sign.reverse());
+        }
+        assert_eq!(x.partial_cmp(&Decimal::ZERO), Some(sign));
+    });
+}
@@ -0,0 +1,113 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::basic::traits::Zero;
+use malachite_decimal::test_util::generators::{decimal_gen, decimal_pair_gen, decimal_triple_gen};
+use malachite_decimal::Decimal;
+use malachite_q::Rational;
+use std::cmp::max;
+use std::str::FromStr;
+
+#[test]
+fn test_sub() {
+    let test = |s, t, out| {
+        let u = Decimal::from_str(s).unwrap();
+        let v = Decimal::from_str(t).unwrap();
+
+        let mut n = u.clone();
+        n -= v.clone();
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let mut n = u.clone();
+        n -= &v;
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = u.clone() - v.clone();
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = &u - v.clone();
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = u.clone() - &v;
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = &u - &v;
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+    };
+    test("0", "0", "0");
+    test("0", "0.00", "0.00");
+    test("1.5", "2.25", "-0.75");
+    test("2.25", "1.5", "0.75");
+    test("1.50", "1.5", "0.00");
+    test("0.3", "0.1", "0.2");
+    test("12e3", "1", "11999");
+    test("12e3", "3e2", "117e2");
+    test("1", "1e3", "-999");
+    test(
+        "123456789012345678901234567890.5",
+        "0.000000000000000000001",
+        "123456789012345678901234567890.499999999999999999999",
+    );
+}
+
+#[test]
+fn sub_properties() {
+    decimal_pair_gen().test_properties(|(x, y)| {
+        let diff_val_val = x.clone() - y.clone();
+        let diff_val_ref = x.clone() - &y;
+        let diff_ref_val = &x - y.clone();
+        let diff = &x - &y;
+        assert!(diff_val_val.is_valid());
+        assert!(diff_val_ref.is_valid());
+        assert!(diff_ref_val.is_valid());
+        assert!(diff.is_valid());
+        assert_eq!(diff_val_val.to_string(), diff.to_string());
+        assert_eq!(diff_val_ref.to_string(), diff.to_string());
+        assert_eq!(diff_ref_val.to_string(), diff.to_string());
+
+        let mut mut_x = x.clone();
+        mut_x -= y.clone();
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x.to_string(), diff.to_string());
+        let mut mut_x = x.clone();
+        mut_x -= &y;
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x.to_string(), diff.to_string());
+
+        assert_eq!(diff.scale(), max(x.scale(), y.scale()));
+        assert_eq!(
+            Rational::from(&diff),
+            Rational::from(&x) - Rational::from(&y)
+        );
+        assert_eq!((-(&y - &x)).to_string(), diff.to_string());
+        assert_eq!((&x + -&y).to_string(), diff.to_string());
+        assert_eq!(&diff + &y, x);
+    });
+
+    decimal_gen().test_properties(|x| {
+        assert_eq!(&x - Decimal::ZERO, x);
+        assert_eq!(Decimal::ZERO - &x, -&x);
+        if x.scale() >= 0 {
+            assert_eq!((&x - Decimal::ZERO).to_string(), x.to_string());
+            assert_eq!((Decimal::ZERO - &x).to_string(), (-&x).to_string());
+        }
+        let diff = &x - &x;
+        assert_eq!(diff, Decimal::ZERO);
+        assert_eq!(diff.scale(), x.scale());
+    });
+
+    decimal_triple_gen().test_properties(|(x, y, z)| {
+        assert_eq!(((&x - &y) - &z).to_string(), (x - (y + z)).to_string());
+    });
+}
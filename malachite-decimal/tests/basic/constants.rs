@@ -0,0 +1,46 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::basic::traits::{NegativeOne, One, Two, Zero};
+use malachite_decimal::Decimal;
+
+#[test]
+fn test_zero() {
+    let zero = Decimal::ZERO;
+    assert!(zero.is_valid());
+    assert_eq!(*zero.unscaled(), 0);
+    assert_eq!(zero.scale(), 0);
+    assert_eq!(zero.to_string(), "0");
+}
+
+#[test]
+fn test_one() {
+    let one = Decimal::ONE;
+    assert!(one.is_valid());
+    assert_eq!(*one.unscaled(), 1);
+    assert_eq!(one.scale(), 0);
+    assert_eq!(one.to_string(), "1");
+}
+
+#[test]
+fn test_two() {
+    let two = Decimal::TWO;
+    assert!(two.is_valid());
+    assert_eq!(*two.unscaled(), 2);
+    assert_eq!(two.scale(), 0);
+    assert_eq!(two.to_string(), "2");
+}
+
+#[test]
+fn test_negative_one() {
+    let negative_one = Decimal::NEGATIVE_ONE;
+    assert!(negative_one.is_valid());
+    assert_eq!(*negative_one.unscaled(), -1);
+    assert_eq!(negative_one.scale(), 0);
+    assert_eq!(negative_one.to_string(), "-1");
+}
@@ -0,0 +1,85 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::test_util::common::test_cmp_helper;
+use malachite_decimal::test_util::generators::{decimal_gen, decimal_pair_gen, decimal_triple_gen};
+use malachite_decimal::Decimal;
+use malachite_q::Rational;
+use std::cmp::Ordering::*;
+use std::str::FromStr;
+
+#[test]
+fn test_cmp() {
+    let strings = &[
+        "-1000000000001",
+        "-1e12",
+        "-999999999999.99",
+        "-123",
+        "-2",
+        "-1.4",
+        "-1",
+        "-0.714",
+        "-0.375",
+        "-0.000123",
+        "-1.237e-9",
+        "0",
+        "1.237e-9",
+        "0.000123",
+        "0.375",
+        "0.714",
+        "1",
+        "1.4",
+        "2",
+        "123",
+        "999999999999.99",
+        "1e12",
+        "1000000000001",
+    ];
+    test_cmp_helper::<Decimal>(strings);
+
+    let test = |s, t, out| {
+        let u = Decimal::from_str(s).unwrap();
+        let v = Decimal::from_str(t).unwrap();
+        assert_eq!(u.cmp(&v), out);
+        assert_eq!(u.partial_cmp(&v), Some(out));
+        assert_eq!(v.cmp(&u), out.reverse());
+    };
+    test("1", "1.000", Equal);
+    test("0", "0e5", Equal);
+    test("-0.000", "0e-3", Equal);
+    test("12e3", "12000.0000", Equal);
+    test("1.5", "1.49999999999999999999999999", Greater);
+    test("-1.5", "-1.49999999999999999999999999", Less);
+    test("1e100", "99999999999999999999999999999.9", Greater);
+    test("1e-100", "0.00000000000000000001", Less);
+}
+
+#[test]
+fn cmp_properties() {
+    decimal_pair_gen().test_properties(|(x, y)| {
+        let ord = x.cmp(&y);
+        assert_eq!(x.partial_cmp(&y), Some(ord));
+        assert_eq!(Rational::from(&x).cmp(&Rational::from(&y)), ord);
+        assert_eq!(y.cmp(&x).reverse(), ord);
+        assert_eq!(x == y, x.cmp(&y) == Equal);
+        assert_eq!((-&y).cmp(&-&x), ord);
+        assert_eq!(x.normalize_ref().cmp(&y), ord);
+    });
+
+    decimal_gen().test_properties(|x| {
+        assert_eq!(x.cmp(&x), Equal);
+    });
+
+    decimal_triple_gen().test_properties(|(x, y, z)| {
+        if x < y && y < z {
+            assert!(x < z);
+        } else if x > y && y > z {
+            assert!(x > z);
+        }
+    });
+}
@@ -0,0 +1,54 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::test_util::common::test_eq_helper;
+use malachite_decimal::test_util::generators::{decimal_gen, decimal_pair_gen, decimal_triple_gen};
+use malachite_decimal::Decimal;
+use malachite_q::Rational;
+use std::str::FromStr;
+
+#[test]
+fn test_eq() {
+    let strings = &["-1e12", "-123", "-1", "-0.5", "0", "0.25", "1", "1.5", "123", "1e12"];
+    test_eq_helper::<Decimal>(strings);
+
+    let test = |s, t, out| {
+        let u = Decimal::from_str(s).unwrap();
+        let v = Decimal::from_str(t).unwrap();
+        assert_eq!(u == v, out);
+        assert_eq!(v == u, out);
+    };
+    test("1", "1.000", true);
+    test("0", "-0.00", true);
+    test("0e-10", "0e10", true);
+    test("12e3", "12000", true);
+    test("1.20", "1.2", true);
+    test("1.21", "1.2", false);
+    test("-1.2", "1.2", false);
+}
+
+#[allow(clippy::eq_op)]
+#[test]
+fn eq_properties() {
+    decimal_pair_gen().test_properties(|(x, y)| {
+        let eq = x == y;
+        assert_eq!(y == x, eq);
+        assert_eq!(Rational::from(&x) == Rational::from(&y), eq);
+    });
+
+    decimal_gen().test_properties(|x| {
+        assert_eq!(x, x);
+        assert_eq!(x.normalize_ref(), x);
+    });
+
+    decimal_triple_gen().test_properties(|(x, y, z)| {
+        if x == y && y == z {
+            assert_eq!(x, z);
+        }
+    });
+}
@@ -0,0 +1,53 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_decimal::test_util::generators::decimal_gen;
+use malachite_decimal::Decimal;
+use malachite_nz::integer::Integer;
+use malachite_q::Rational;
+use std::str::FromStr;
+
+#[test]
+fn test_from_integer() {
+    let test = |s, out| {
+        let u = Integer::from_str(s).unwrap();
+
+        let x = Decimal::from(u.clone());
+        assert!(x.is_valid());
+        assert_eq!(x.to_string(), out);
+        assert_eq!(x.scale(), 0);
+
+        let x = Decimal::from(&u);
+        assert!(x.is_valid());
+        assert_eq!(x.to_string(), out);
+        assert_eq!(x.scale(), 0);
+    };
+    test("0", "0");
+    test("123", "123");
+    test("-123", "-123");
+    test("1000000000000", "1000000000000");
+    test("-1000000000000", "-1000000000000");
+}
+
+#[test]
+fn from_integer_properties() {
+    decimal_gen().test_properties(|x| {
+        let x = x.into_unscaled_and_scale().0;
+        let decimal_x = Decimal::from(x.clone());
+        assert!(decimal_x.is_valid());
+        assert_eq!(decimal_x.to_string(), x.to_string());
+        assert_eq!(*decimal_x.unscaled(), x);
+        assert_eq!(decimal_x.scale(), 0);
+
+        let decimal_x_alt = Decimal::from(&x);
+        assert!(decimal_x_alt.is_valid());
+        assert_eq!(decimal_x_alt.to_string(), decimal_x.to_string());
+
+        assert_eq!(Rational::from(&decimal_x), Rational::from(&x));
+    });
+}
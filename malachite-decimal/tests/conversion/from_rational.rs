@@ -0,0 +1,176 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{DivisibleBy, Pow};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::rounding_modes::RoundingMode::*;
+use malachite_decimal::conversion::from_rational::DecimalFromRationalError;
+use malachite_decimal::test_util::generators::{
+    decimal_gen, rational_gen, rational_signed_rounding_mode_triple_gen_var_1,
+};
+use malachite_decimal::Decimal;
+use malachite_nz::integer::Integer;
+use malachite_q::Rational;
+use std::cmp::Ordering::{self, *};
+use std::str::FromStr;
+
+#[test]
+fn test_try_from_rational() {
+    let test = |s, out: Result<&str, DecimalFromRationalError>| {
+        let u = Rational::from_str(s).unwrap();
+
+        let x = Decimal::try_from(u.clone());
+        if let Ok(x) = &x {
+            assert!(x.is_valid());
+        }
+        assert_eq!(x.map(|x| x.to_string()), out.map(str::to_string));
+
+        let x = Decimal::try_from(&u);
+        if let Ok(x) = &x {
+            assert!(x.is_valid());
+        }
+        assert_eq!(x.map(|x| x.to_string()), out.map(str::to_string));
+    };
+    test("0", Ok("0"));
+    test("123", Ok("123"));
+    test("-123", Ok("-123"));
+    test("12000", Ok("12000"));
+    test("3/2", Ok("1.5"));
+    test("-3/8", Ok("-0.375"));
+    test("1/1024", Ok("0.0009765625"));
+    test("7/50", Ok("0.14"));
+    test("1/3", Err(DecimalFromRationalError));
+    test("-5/6", Err(DecimalFromRationalError));
+    test("1/7", Err(DecimalFromRationalError));
+}
+
+#[test]
+fn test_from_rational_scale_round() {
+    let test = |s, scale, rm, out, o_out: Ordering| {
+        let u = Rational::from_str(s).unwrap();
+
+        let (x, o) = Decimal::from_rational_scale_round(u.clone(), scale, rm);
+        assert!(x.is_valid());
+        assert_eq!(x.to_string(), out);
+        assert_eq!(o, o_out);
+
+        let (x, o) = Decimal::from_rational_scale_round_ref(&u, scale, rm);
+        assert!(x.is_valid());
+        assert_eq!(x.to_string(), out);
+        assert_eq!(o, o_out);
+    };
+    test("0", 3, Exact, "0.000", Equal);
+    test("2/3", 3, Floor, "0.666", Less);
+    test("2/3", 3, Nearest, "0.667", Greater);
+    test("-2/3", 3, Down, "-0.666", Greater);
+    test("-2/3", 3, Up, "-0.667", Less);
+    test("1/4", 3, Exact, "0.250", Equal);
+    test("5/8", 2, Nearest, "0.62", Less);
+    test("7/8", 2, Nearest, "0.88", Greater);
+    test("12345", -2, Nearest, "123e2", Less);
+    test("12345", -2, Ceiling, "124e2", Greater);
+    test("1/3", -1, Nearest, "0e1", Less);
+}
+
+#[test]
+#[should_panic]
+fn from_rational_scale_round_fail() {
+    Decimal::from_rational_scale_round(Rational::from_signeds(1, 3), 10, Exact);
+}
+
+#[test]
+#[should_panic]
+fn from_rational_scale_round_ref_fail() {
+    Decimal::from_rational_scale_round_ref(&Rational::from_signeds(1, 3), 10, Exact);
+}
+
+#[test]
+fn test_from_rational_scale() {
+    let test = |s, scale, out, o_out: Ordering| {
+        let u = Rational::from_str(s).unwrap();
+
+        let (x, o) = Decimal::from_rational_scale(u.clone(), scale);
+        assert!(x.is_valid());
+        assert_eq!(x.to_string(), out);
+        assert_eq!(o, o_out);
+
+        let (x, o) = Decimal::from_rational_scale_ref(&u, scale);
+        assert!(x.is_valid());
+        assert_eq!(x.to_string(), out);
+        assert_eq!(o, o_out);
+    };
+    test("0", 0, "0", Equal);
+    test("2/3", 3, "0.667", Greater);
+    test("5/8", 2, "0.62", Less);
+    test("-5/8", 2, "-0.62", Greater);
+    test("1/4", 4, "0.2500", Equal);
+}
+
+#[test]
+fn try_from_rational_properties() {
+    rational_gen().test_properties(|x| {
+        let decimal_x = Decimal::try_from(x.clone());
+        assert_eq!(
+            Decimal::try_from(&x).as_ref().map(ToString::to_string),
+            decimal_x.as_ref().map(ToString::to_string)
+        );
+        if let Ok(d) = decimal_x {
+            assert!(d.is_valid());
+            assert!(d.scale() >= 0);
+            assert_eq!(Rational::from(&d), x);
+            if d.scale() > 0 {
+                assert!(!d.unscaled().divisible_by(Integer::from(10u32)));
+            }
+        } else {
+            // If the denominator were 2^a 5^b, both a and b would be at most its bit count.
+            let p = Rational::from(10u32).pow(x.denominator_ref().significant_bits());
+            assert_ne!(*(&x * p).denominator_ref(), 1u32);
+        }
+    });
+
+    decimal_gen().test_properties(|x| {
+        let y = Decimal::try_from(Rational::from(&x)).unwrap();
+        assert_eq!(y, x);
+    });
+}
+
+#[test]
+fn from_rational_scale_round_properties() {
+    rational_signed_rounding_mode_triple_gen_var_1().test_properties(|(x, scale, rm)| {
+        let (d, o) = Decimal::from_rational_scale_round(x.clone(), scale, rm);
+        assert!(d.is_valid());
+        assert_eq!(d.scale(), scale);
+        let (d_alt, o_alt) = Decimal::from_rational_scale_round_ref(&x, scale, rm);
+        assert_eq!(d_alt.to_string(), d.to_string());
+        assert_eq!(o_alt, o);
+
+        assert_eq!(Rational::from(&d).cmp(&x), o);
+        match rm {
+            Floor => assert_ne!(o, Greater),
+            Ceiling => assert_ne!(o, Less),
+            Exact => assert_eq!(o, Equal),
+            _ => {}
+        }
+        let (d_alt, o_alt) = Decimal::from_rational_scale_round(-&x, scale, -rm);
+        assert_eq!(d_alt.to_string(), (-&d).to_string());
+        assert_eq!(o_alt, o.reverse());
+        if o == Equal {
+            for rm in exhaustive_rounding_modes() {
+                let (d_alt, o_alt) = Decimal::from_rational_scale_round_ref(&x, scale, rm);
+                assert_eq!(d_alt.to_string(), d.to_string());
+                assert_eq!(o_alt, Equal);
+            }
+        }
+
+        let (d, o) = Decimal::from_rational_scale_ref(&x, scale);
+        let (d_alt, o_alt) = Decimal::from_rational_scale_round_ref(&x, scale, Nearest);
+        assert_eq!(d_alt.to_string(), d.to_string());
+        assert_eq!(o_alt, o);
+    });
+}
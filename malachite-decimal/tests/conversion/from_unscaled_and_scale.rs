@@ -0,0 +1,44 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_decimal::test_util::generators::decimal_gen;
+use malachite_decimal::Decimal;
+use malachite_nz::integer::Integer;
+use std::str::FromStr;
+
+#[test]
+fn test_from_unscaled_and_scale() {
+    let test = |unscaled, scale, out| {
+        let x = Decimal::from_unscaled_and_scale(Integer::from_str(unscaled).unwrap(), scale);
+        assert!(x.is_valid());
+        assert_eq!(x.to_string(), out);
+        assert_eq!(x.unscaled().to_string(), unscaled);
+        assert_eq!(x.scale(), scale);
+    };
+    test("0", 0, "0");
+    test("0", 2, "0.00");
+    test("150", 2, "1.50");
+    test("-15", 4, "-0.0015");
+    test("15", -2, "15e2");
+    test(
+        "123456789012345678901234567890",
+        30,
+        "0.123456789012345678901234567890",
+    );
+}
+
+#[test]
+fn from_unscaled_and_scale_properties() {
+    decimal_gen().test_properties(|x| {
+        let y = Decimal::from_unscaled_and_scale(x.unscaled().clone(), x.scale());
+        assert_eq!(y.to_string(), x.to_string());
+        let (unscaled, scale) = x.clone().into_unscaled_and_scale();
+        assert_eq!(&unscaled, x.unscaled());
+        assert_eq!(scale, x.scale());
+    });
+}
@@ -0,0 +1,53 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_decimal::test_util::generators::{decimal_gen, decimal_pair_gen};
+use malachite_decimal::Decimal;
+use malachite_q::Rational;
+use std::str::FromStr;
+
+#[test]
+fn test_rational_from_decimal() {
+    let test = |s, out| {
+        let u = Decimal::from_str(s).unwrap();
+
+        let x = Rational::from(u.clone());
+        assert_eq!(x.to_string(), out);
+
+        let x = Rational::from(&u);
+        assert_eq!(x.to_string(), out);
+    };
+    test("0", "0");
+    test("0.000", "0");
+    test("0e5", "0");
+    test("1.50", "3/2");
+    test("-0.125", "-1/8");
+    test("12e3", "12000");
+    test("-1.2e-3", "-3/2500");
+    test("123456789.123456789", "123456789123456789/1000000000");
+}
+
+#[test]
+fn rational_from_decimal_properties() {
+    decimal_gen().test_properties(|x| {
+        let rational_x = Rational::from(x.clone());
+        let rational_x_alt = Rational::from(&x);
+        assert_eq!(rational_x_alt, rational_x);
+
+        assert_eq!(Rational::from(&-&x), -&rational_x);
+        assert_eq!(Rational::from(x.normalize_ref()), rational_x);
+        let y = Decimal::try_from(&rational_x).unwrap();
+        assert_eq!(y, x);
+        assert!(y.scale() <= x.scale().max(0));
+    });
+
+    decimal_pair_gen().test_properties(|(x, y)| {
+        assert_eq!(Rational::from(&x) == Rational::from(&y), x == y);
+        assert_eq!(Rational::from(&x).cmp(&Rational::from(&y)), x.cmp(&y));
+    });
+}
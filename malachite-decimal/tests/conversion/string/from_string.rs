@@ -0,0 +1,84 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::test_util::generators::common::GenConfig;
+use malachite_base::test_util::generators::string_gen;
+use malachite_decimal::test_util::generators::decimal_gen;
+use malachite_decimal::Decimal;
+use std::str::FromStr;
+
+#[test]
+fn test_from_str() {
+    let test_ok = |s, unscaled, scale, out| {
+        let x = Decimal::from_str(s).unwrap();
+        assert!(x.is_valid());
+        assert_eq!(x.unscaled().to_string(), unscaled);
+        assert_eq!(x.scale(), scale);
+        assert_eq!(x.to_string(), out);
+    };
+    test_ok("0", "0", 0, "0");
+    test_ok("-0", "0", 0, "0");
+    test_ok("0.000", "0", 3, "0.000");
+    test_ok("123456", "123456", 0, "123456");
+    test_ok("-123456", "-123456", 0, "-123456");
+    test_ok("1.50", "150", 2, "1.50");
+    test_ok("-007", "-7", 0, "-7");
+    test_ok(".5", "5", 1, "0.5");
+    test_ok("5.", "5", 0, "5");
+    test_ok("-.25", "-25", 2, "-0.25");
+    test_ok("1.5e3", "15", -2, "15e2");
+    test_ok("1.5E+3", "15", -2, "15e2");
+    test_ok("12E-4", "12", 4, "0.0012");
+    test_ok("1e0", "1", 0, "1");
+    test_ok("0e5", "0", -5, "0e5");
+    test_ok(
+        "1000000000000000000000000.000000000000000000000001",
+        "1000000000000000000000000000000000000000000000001",
+        24,
+        "1000000000000000000000000.000000000000000000000001",
+    );
+
+    let test_err = |s| {
+        assert!(Decimal::from_str(s).is_err());
+    };
+    test_err("");
+    test_err(".");
+    test_err("-");
+    test_err("-.");
+    test_err("+1");
+    test_err("--1");
+    test_err("1.2.3");
+    test_err("1e");
+    test_err("e5");
+    test_err("1e5.5");
+    test_err("1e5e5");
+    test_err(" 1");
+    test_err("1 ");
+    test_err("12A");
+    test_err("1/2");
+    test_err("$%^");
+    test_err("1e99999999999999999999");
+    test_err("0.1e-9223372036854775807");
+}
+
+#[allow(unused_must_use)]
+#[test]
+fn from_str_properties() {
+    let mut config = GenConfig::new();
+    config.insert("mean_length_n", 64);
+    string_gen().test_properties_with_config(&config, |s| {
+        Decimal::from_str(&s);
+    });
+
+    decimal_gen().test_properties(|x| {
+        let y = Decimal::from_str(&x.to_string()).unwrap();
+        assert!(y.is_valid());
+        assert_eq!(y.unscaled(), x.unscaled());
+        assert_eq!(y.scale(), x.scale());
+    });
+}
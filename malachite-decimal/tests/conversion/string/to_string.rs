@@ -0,0 +1,63 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::strings::string_is_subset;
+use malachite_base::strings::ToDebugString;
+use malachite_decimal::test_util::generators::decimal_gen;
+use malachite_decimal::Decimal;
+use malachite_nz::integer::Integer;
+use malachite_q::Rational;
+use std::str::FromStr;
+
+#[test]
+fn test_to_string() {
+    let test = |unscaled: i64, scale, out| {
+        let x = Decimal::from_unscaled_and_scale(Integer::from(unscaled), scale);
+        assert_eq!(x.to_string(), out);
+        assert_eq!(x.to_debug_string(), out);
+    };
+    test(0, 0, "0");
+    test(0, 3, "0.000");
+    test(0, -1, "0e1");
+    test(1, 0, "1");
+    test(-1, 0, "-1");
+    test(150, 2, "1.50");
+    test(-15, 4, "-0.0015");
+    test(15, -2, "15e2");
+    test(-15, -2, "-15e2");
+    test(123456, 3, "123.456");
+    test(123456, 6, "0.123456");
+    test(123456, 7, "0.0123456");
+    test(-123456, 10, "-0.0000123456");
+    test(1000, 3, "1.000");
+}
+
+#[test]
+fn to_string_properties() {
+    decimal_gen().test_properties(|x| {
+        let s = x.to_string();
+        assert_eq!(x.to_debug_string(), s);
+        assert!(string_is_subset(&s, "-.0123456789e"));
+        let y = Decimal::from_str(&s).unwrap();
+        assert_eq!(y.unscaled(), x.unscaled());
+        assert_eq!(y.scale(), x.scale());
+        assert_eq!(
+            (-&x).to_string().trim_start_matches('-'),
+            s.trim_start_matches('-')
+        );
+        if x.scale() > 0 {
+            assert_eq!(
+                s.len() - s.find('.').unwrap() - 1,
+                usize::try_from(x.scale()).unwrap()
+            );
+        } else if x.scale() == 0 {
+            assert_eq!(s, x.unscaled().to_string());
+            assert_eq!(Rational::from(&x).to_string(), s);
+        }
+    });
+}
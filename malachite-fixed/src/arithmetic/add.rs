@@ -0,0 +1,234 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Fixed;
+use core::ops::{Add, AddAssign};
+
+impl<const FRAC_BITS: u64> Add<Fixed<FRAC_BITS>> for Fixed<FRAC_BITS> {
+    type Output = Fixed<FRAC_BITS>;
+
+    /// Adds two [`Fixed`]s, taking both by value. The sum is exact.
+    ///
+    /// $$
+    /// f(x, y) = x + y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.raw().significant_bits(), other.raw().significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_fixed::Fixed;
+    ///
+    /// assert_eq!(
+    ///     ("1.5".parse::<Fixed<4>>().unwrap() + "2.25".parse::<Fixed<4>>().unwrap())
+    ///         .to_string(),
+    ///     "3.75"
+    /// );
+    /// assert_eq!(
+    ///     ("1.5".parse::<Fixed<4>>().unwrap() + "-1.5".parse::<Fixed<4>>().unwrap())
+    ///         .to_string(),
+    ///     "0"
+    /// );
+    /// ```
+    #[inline]
+    fn add(mut self, other: Fixed<FRAC_BITS>) -> Fixed<FRAC_BITS> {
+        self += other;
+        self
+    }
+}
+
+impl<const FRAC_BITS: u64> Add<&Fixed<FRAC_BITS>> for Fixed<FRAC_BITS> {
+    type Output = Fixed<FRAC_BITS>;
+
+    /// Adds two [`Fixed`]s, taking the first by value and the second by reference. The sum is
+    /// exact.
+    ///
+    /// $$
+    /// f(x, y) = x + y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.raw().significant_bits(), other.raw().significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_fixed::Fixed;
+    ///
+    /// assert_eq!(
+    ///     ("1.5".parse::<Fixed<4>>().unwrap() + &"2.25".parse::<Fixed<4>>().unwrap())
+    ///         .to_string(),
+    ///     "3.75"
+    /// );
+    /// assert_eq!(
+    ///     ("1.5".parse::<Fixed<4>>().unwrap() + &"-1.5".parse::<Fixed<4>>().unwrap())
+    ///         .to_string(),
+    ///     "0"
+    /// );
+    /// ```
+    #[inline]
+    fn add(mut self, other: &Fixed<FRAC_BITS>) -> Fixed<FRAC_BITS> {
+        self += other;
+        self
+    }
+}
+
+impl<const FRAC_BITS: u64> Add<Fixed<FRAC_BITS>> for &Fixed<FRAC_BITS> {
+    type Output = Fixed<FRAC_BITS>;
+
+    /// Adds two [`Fixed`]s, taking the first by reference and the second by value. The sum is
+    /// exact.
+    ///
+    /// $$
+    /// f(x, y) = x + y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.raw().significant_bits(), other.raw().significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_fixed::Fixed;
+    ///
+    /// assert_eq!(
+    ///     (&"1.5".parse::<Fixed<4>>().unwrap() + "2.25".parse::<Fixed<4>>().unwrap())
+    ///         .to_string(),
+    ///     "3.75"
+    /// );
+    /// assert_eq!(
+    ///     (&"1.5".parse::<Fixed<4>>().unwrap() + "-1.5".parse::<Fixed<4>>().unwrap())
+    ///         .to_string(),
+    ///     "0"
+    /// );
+    /// ```
+    #[inline]
+    fn add(self, mut other: Fixed<FRAC_BITS>) -> Fixed<FRAC_BITS> {
+        other += self;
+        other
+    }
+}
+
+impl<const FRAC_BITS: u64> Add<&Fixed<FRAC_BITS>> for &Fixed<FRAC_BITS> {
+    type Output = Fixed<FRAC_BITS>;
+
+    /// Adds two [`Fixed`]s, taking both by reference. The sum is exact.
+    ///
+    /// $$
+    /// f(x, y) = x + y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.raw().significant_bits(), other.raw().significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_fixed::Fixed;
+    ///
+    /// assert_eq!(
+    ///     (&"1.5".parse::<Fixed<4>>().unwrap() + &"2.25".parse::<Fixed<4>>().unwrap())
+    ///         .to_string(),
+    ///     "3.75"
+    /// );
+    /// assert_eq!(
+    ///     (&"1.5".parse::<Fixed<4>>().unwrap() + &"-1.5".parse::<Fixed<4>>().unwrap())
+    ///         .to_string(),
+    ///     "0"
+    /// );
+    /// ```
+    fn add(self, other: &Fixed<FRAC_BITS>) -> Fixed<FRAC_BITS> {
+        Fixed {
+            raw: &self.raw + &other.raw,
+        }
+    }
+}
+
+impl<const FRAC_BITS: u64> AddAssign<Fixed<FRAC_BITS>> for Fixed<FRAC_BITS> {
+    /// Adds a [`Fixed`] to a [`Fixed`] in place, taking the [`Fixed`] on the right-hand side by
+    /// value. The sum is exact.
+    ///
+    /// $$
+    /// x \gets x + y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.raw().significant_bits(), other.raw().significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_fixed::Fixed;
+    ///
+    /// let mut x = "1.5".parse::<Fixed<4>>().unwrap();
+    /// x += "2.25".parse::<Fixed<4>>().unwrap();
+    /// assert_eq!(x.to_string(), "3.75");
+    ///
+    /// let mut x = "1.5".parse::<Fixed<4>>().unwrap();
+    /// x += "-1.5".parse::<Fixed<4>>().unwrap();
+    /// assert_eq!(x.to_string(), "0");
+    /// ```
+    fn add_assign(&mut self, other: Fixed<FRAC_BITS>) {
+        self.raw += other.raw;
+    }
+}
+
+impl<const FRAC_BITS: u64> AddAssign<&Fixed<FRAC_BITS>> for Fixed<FRAC_BITS> {
+    /// Adds a [`Fixed`] to a [`Fixed`] in place, taking the [`Fixed`] on the right-hand side by
+    /// reference. The sum is exact.
+    ///
+    /// $$
+    /// x \gets x + y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.raw().significant_bits(), other.raw().significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_fixed::Fixed;
+    ///
+    /// let mut x = "1.5".parse::<Fixed<4>>().unwrap();
+    /// x += &"2.25".parse::<Fixed<4>>().unwrap();
+    /// assert_eq!(x.to_string(), "3.75");
+    ///
+    /// let mut x = "1.5".parse::<Fixed<4>>().unwrap();
+    /// x += &"-1.5".parse::<Fixed<4>>().unwrap();
+    /// assert_eq!(x.to_string(), "0");
+    /// ```
+    fn add_assign(&mut self, other: &Fixed<FRAC_BITS>) {
+        self.raw += &other.raw;
+    }
+}
@@ -0,0 +1,385 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Fixed;
+use core::cmp::Ordering;
+use malachite_base::num::arithmetic::traits::{DivRound, DivRoundAssign};
+use malachite_base::rounding_modes::RoundingMode;
+
+impl<const FRAC_BITS: u64> DivRound<Fixed<FRAC_BITS>> for Fixed<FRAC_BITS> {
+    type Output = Fixed<FRAC_BITS>;
+
+    /// Divides a [`Fixed`] by another [`Fixed`], taking both by value, and rounds the quotient to a
+    /// multiple of $2^{-F}$ using the specified rounding mode. An [`Ordering`] is also returned,
+    /// indicating whether the rounded quotient is less than, equal to, or greater than the exact
+    /// quotient.
+    ///
+    /// The quotient is computed exactly and rounded only once. If it is already a multiple of
+    /// $2^{-F}$, it is returned unchanged, whatever the rounding mode.
+    ///
+    /// $$
+    /// f(x, y, m) = 2^{-F} g(2^F x/y, m),
+    /// $$
+    /// where $g(z, m)$ rounds $z$ to an integer using the rounding mode $m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.raw().significant_bits(), other.raw().significant_bits()) + F`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero, or if `rm` is `Exact` but the quotient is not a multiple of
+    /// $2^{-F}$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::DivRound;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_fixed::Fixed;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = "1".parse::<Fixed<4>>().unwrap();
+    /// let y = "3".parse::<Fixed<4>>().unwrap();
+    /// let (q, o) = x.div_round(y, Floor);
+    /// assert_eq!(q.to_string(), "0.3125");
+    /// assert_eq!(o, Less);
+    ///
+    /// let x = "1".parse::<Fixed<4>>().unwrap();
+    /// let y = "3".parse::<Fixed<4>>().unwrap();
+    /// let (q, o) = x.div_round(y, Ceiling);
+    /// assert_eq!(q.to_string(), "0.375");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let x = "-1".parse::<Fixed<4>>().unwrap();
+    /// let y = "3".parse::<Fixed<4>>().unwrap();
+    /// let (q, o) = x.div_round(y, Nearest);
+    /// assert_eq!(q.to_string(), "-0.3125");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let x = "3".parse::<Fixed<4>>().unwrap();
+    /// let y = "2".parse::<Fixed<4>>().unwrap();
+    /// let (q, o) = x.div_round(y, Exact);
+    /// assert_eq!(q.to_string(), "1.5");
+    /// assert_eq!(o, Equal);
+    /// ```
+    #[inline]
+    fn div_round(
+        mut self,
+        other: Fixed<FRAC_BITS>,
+        rm: RoundingMode,
+    ) -> (Fixed<FRAC_BITS>, Ordering) {
+        let o = self.div_round_assign(other, rm);
+        (self, o)
+    }
+}
+
+impl<const FRAC_BITS: u64> DivRound<&Fixed<FRAC_BITS>> for Fixed<FRAC_BITS> {
+    type Output = Fixed<FRAC_BITS>;
+
+    /// Divides a [`Fixed`] by another [`Fixed`], taking the first by value and the second by
+    /// reference, and rounds the quotient to a multiple of $2^{-F}$ using the specified rounding
+    /// mode. An [`Ordering`] is also returned, indicating whether the rounded quotient is less
+    /// than, equal to, or greater than the exact quotient.
+    ///
+    /// $$
+    /// f(x, y, m) = 2^{-F} g(2^F x/y, m),
+    /// $$
+    /// where $g(z, m)$ rounds $z$ to an integer using the rounding mode $m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.raw().significant_bits(), other.raw().significant_bits()) + F`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero, or if `rm` is `Exact` but the quotient is not a multiple of
+    /// $2^{-F}$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::DivRound;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_fixed::Fixed;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = "1".parse::<Fixed<4>>().unwrap();
+    /// let y = "3".parse::<Fixed<4>>().unwrap();
+    /// let (q, o) = x.div_round(&y, Floor);
+    /// assert_eq!(q.to_string(), "0.3125");
+    /// assert_eq!(o, Less);
+    ///
+    /// let x = "1".parse::<Fixed<4>>().unwrap();
+    /// let y = "3".parse::<Fixed<4>>().unwrap();
+    /// let (q, o) = x.div_round(&y, Ceiling);
+    /// assert_eq!(q.to_string(), "0.375");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let x = "-1".parse::<Fixed<4>>().unwrap();
+    /// let y = "3".parse::<Fixed<4>>().unwrap();
+    /// let (q, o) = x.div_round(&y, Nearest);
+    /// assert_eq!(q.to_string(), "-0.3125");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let x = "3".parse::<Fixed<4>>().unwrap();
+    /// let y = "2".parse::<Fixed<4>>().unwrap();
+    /// let (q, o) = x.div_round(&y, Exact);
+    /// assert_eq!(q.to_string(), "1.5");
+    /// assert_eq!(o, Equal);
+    /// ```
+    #[inline]
+    fn div_round(
+        mut self,
+        other: &Fixed<FRAC_BITS>,
+        rm: RoundingMode,
+    ) -> (Fixed<FRAC_BITS>, Ordering) {
+        let o = self.div_round_assign(other, rm);
+        (self, o)
+    }
+}
+
+impl<const FRAC_BITS: u64> DivRound<Fixed<FRAC_BITS>> for &Fixed<FRAC_BITS> {
+    type Output = Fixed<FRAC_BITS>;
+
+    /// Divides a [`Fixed`] by another [`Fixed`], taking the first by reference and the second by
+    /// value, and rounds the quotient to a multiple of $2^{-F}$ using the specified rounding mode.
+    /// An [`Ordering`] is also returned, indicating whether the rounded quotient is less than,
+    /// equal to, or greater than the exact quotient.
+    ///
+    /// $$
+    /// f(x, y, m) = 2^{-F} g(2^F x/y, m),
+    /// $$
+    /// where $g(z, m)$ rounds $z$ to an integer using the rounding mode $m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.raw().significant_bits(), other.raw().significant_bits()) + F`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero, or if `rm` is `Exact` but the quotient is not a multiple of
+    /// $2^{-F}$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::DivRound;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_fixed::Fixed;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = "1".parse::<Fixed<4>>().unwrap();
+    /// let y = "3".parse::<Fixed<4>>().unwrap();
+    /// let (q, o) = (&x).div_round(y, Floor);
+    /// assert_eq!(q.to_string(), "0.3125");
+    /// assert_eq!(o, Less);
+    ///
+    /// let x = "1".parse::<Fixed<4>>().unwrap();
+    /// let y = "3".parse::<Fixed<4>>().unwrap();
+    /// let (q, o) = (&x).div_round(y, Ceiling);
+    /// assert_eq!(q.to_string(), "0.375");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let x = "-1".parse::<Fixed<4>>().unwrap();
+    /// let y = "3".parse::<Fixed<4>>().unwrap();
+    /// let (q, o) = (&x).div_round(y, Nearest);
+    /// assert_eq!(q.to_string(), "-0.3125");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let x = "3".parse::<Fixed<4>>().unwrap();
+    /// let y = "2".parse::<Fixed<4>>().unwrap();
+    /// let (q, o) = (&x).div_round(y, Exact);
+    /// assert_eq!(q.to_string(), "1.5");
+    /// assert_eq!(o, Equal);
+    /// ```
+    fn div_round(self, other: Fixed<FRAC_BITS>, rm: RoundingMode) -> (Fixed<FRAC_BITS>, Ordering) {
+        let (raw, o) = (&self.raw << FRAC_BITS).div_round(other.raw, rm);
+        (Fixed { raw }, o)
+    }
+}
+
+impl<const FRAC_BITS: u64> DivRound<&Fixed<FRAC_BITS>> for &Fixed<FRAC_BITS> {
+    type Output = Fixed<FRAC_BITS>;
+
+    /// Divides a [`Fixed`] by another [`Fixed`], taking both by reference, and rounds the quotient
+    /// to a multiple of $2^{-F}$ using the specified rounding mode. An [`Ordering`] is also
+    /// returned, indicating whether the rounded quotient is less than, equal to, or greater than
+    /// the exact quotient.
+    ///
+    /// $$
+    /// f(x, y, m) = 2^{-F} g(2^F x/y, m),
+    /// $$
+    /// where $g(z, m)$ rounds $z$ to an integer using the rounding mode $m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.raw().significant_bits(), other.raw().significant_bits()) + F`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero, or if `rm` is `Exact` but the quotient is not a multiple of
+    /// $2^{-F}$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::DivRound;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_fixed::Fixed;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = "1".parse::<Fixed<4>>().unwrap();
+    /// let y = "3".parse::<Fixed<4>>().unwrap();
+    /// let (q, o) = (&x).div_round(&y, Floor);
+    /// assert_eq!(q.to_string(), "0.3125");
+    /// assert_eq!(o, Less);
+    ///
+    /// let x = "1".parse::<Fixed<4>>().unwrap();
+    /// let y = "3".parse::<Fixed<4>>().unwrap();
+    /// let (q, o) = (&x).div_round(&y, Ceiling);
+    /// assert_eq!(q.to_string(), "0.375");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let x = "-1".parse::<Fixed<4>>().unwrap();
+    /// let y = "3".parse::<Fixed<4>>().unwrap();
+    /// let (q, o) = (&x).div_round(&y, Nearest);
+    /// assert_eq!(q.to_string(), "-0.3125");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let x = "3".parse::<Fixed<4>>().unwrap();
+    /// let y = "2".parse::<Fixed<4>>().unwrap();
+    /// let (q, o) = (&x).div_round(&y, Exact);
+    /// assert_eq!(q.to_string(), "1.5");
+    /// assert_eq!(o, Equal);
+    /// ```
+    fn div_round(self, other: &Fixed<FRAC_BITS>, rm: RoundingMode) -> (Fixed<FRAC_BITS>, Ordering) {
+        let (raw, o) = (&self.raw << FRAC_BITS).div_round(&other.raw, rm);
+        (Fixed { raw }, o)
+    }
+}
+
+impl<const FRAC_BITS: u64> DivRoundAssign<Fixed<FRAC_BITS>> for Fixed<FRAC_BITS> {
+    /// Divides a [`Fixed`] by another [`Fixed`] in place, taking the [`Fixed`] on the right-hand
+    /// side by value, and rounds the quotient to a multiple of $2^{-F}$ using the specified
+    /// rounding mode. An [`Ordering`] is returned, indicating whether the rounded quotient is less
+    /// than, equal to, or greater than the exact quotient.
+    ///
+    /// $$
+    /// x \gets 2^{-F} g(2^F x/y, m),
+    /// $$
+    /// where $g(z, m)$ rounds $z$ to an integer using the rounding mode $m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.raw().significant_bits(), other.raw().significant_bits()) + F`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero, or if `rm` is `Exact` but the quotient is not a multiple of
+    /// $2^{-F}$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::DivRoundAssign;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_fixed::Fixed;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = "1".parse::<Fixed<4>>().unwrap();
+    /// let y = "3".parse::<Fixed<4>>().unwrap();
+    /// assert_eq!(x.div_round_assign(y, Floor), Less);
+    /// assert_eq!(x.to_string(), "0.3125");
+    ///
+    /// let mut x = "1".parse::<Fixed<4>>().unwrap();
+    /// let y = "3".parse::<Fixed<4>>().unwrap();
+    /// assert_eq!(x.div_round_assign(y, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "0.375");
+    ///
+    /// let mut x = "-1".parse::<Fixed<4>>().unwrap();
+    /// let y = "3".parse::<Fixed<4>>().unwrap();
+    /// assert_eq!(x.div_round_assign(y, Nearest), Greater);
+    /// assert_eq!(x.to_string(), "-0.3125");
+    ///
+    /// let mut x = "3".parse::<Fixed<4>>().unwrap();
+    /// let y = "2".parse::<Fixed<4>>().unwrap();
+    /// assert_eq!(x.div_round_assign(y, Exact), Equal);
+    /// assert_eq!(x.to_string(), "1.5");
+    /// ```
+    fn div_round_assign(&mut self, other: Fixed<FRAC_BITS>, rm: RoundingMode) -> Ordering {
+        self.raw <<= FRAC_BITS;
+        self.raw.div_round_assign(other.raw, rm)
+    }
+}
+
+impl<const FRAC_BITS: u64> DivRoundAssign<&Fixed<FRAC_BITS>> for Fixed<FRAC_BITS> {
+    /// Divides a [`Fixed`] by another [`Fixed`] in place, taking the [`Fixed`] on the right-hand
+    /// side by reference, and rounds the quotient to a multiple of $2^{-F}$ using the specified
+    /// rounding mode. An [`Ordering`] is returned, indicating whether the rounded quotient is less
+    /// than, equal to, or greater than the exact quotient.
+    ///
+    /// $$
+    /// x \gets 2^{-F} g(2^F x/y, m),
+    /// $$
+    /// where $g(z, m)$ rounds $z$ to an integer using the rounding mode $m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.raw().significant_bits(), other.raw().significant_bits()) + F`.
+    ///
+    /// # Panics
+    /// Panics if `other` is zero, or if `rm` is `Exact` but the quotient is not a multiple of
+    /// $2^{-F}$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::DivRoundAssign;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_fixed::Fixed;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = "1".parse::<Fixed<4>>().unwrap();
+    /// let y = "3".parse::<Fixed<4>>().unwrap();
+    /// assert_eq!(x.div_round_assign(&y, Floor), Less);
+    /// assert_eq!(x.to_string(), "0.3125");
+    ///
+    /// let mut x = "1".parse::<Fixed<4>>().unwrap();
+    /// let y = "3".parse::<Fixed<4>>().unwrap();
+    /// assert_eq!(x.div_round_assign(&y, Ceiling), Greater);
+    /// assert_eq!(x.to_string(), "0.375");
+    ///
+    /// let mut x = "-1".parse::<Fixed<4>>().unwrap();
+    /// let y = "3".parse::<Fixed<4>>().unwrap();
+    /// assert_eq!(x.div_round_assign(&y, Nearest), Greater);
+    /// assert_eq!(x.to_string(), "-0.3125");
+    ///
+    /// let mut x = "3".parse::<Fixed<4>>().unwrap();
+    /// let y = "2".parse::<Fixed<4>>().unwrap();
+    /// assert_eq!(x.div_round_assign(&y, Exact), Equal);
+    /// assert_eq!(x.to_string(), "1.5");
+    /// ```
+    fn div_round_assign(&mut self, other: &Fixed<FRAC_BITS>, rm: RoundingMode) -> Ordering {
+        self.raw <<= FRAC_BITS;
+        self.raw.div_round_assign(&other.raw, rm)
+    }
+}
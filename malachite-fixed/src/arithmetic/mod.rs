@@ -0,0 +1,24 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+/// Absolute value of [`Fixed`](crate::Fixed)s.
+pub mod abs;
+/// Addition of [`Fixed`](crate::Fixed)s.
+pub mod add;
+/// An implementation of [`DivRound`](malachite_base::num::arithmetic::traits::DivRound), a trait
+/// for dividing [`Fixed`](crate::Fixed)s and rounding the quotient to a multiple of $2^{-F}$.
+pub mod div;
+/// Multiplication of [`Fixed`](crate::Fixed)s, rounding the product to a multiple of $2^{-F}$.
+pub mod mul;
+/// Negation of [`Fixed`](crate::Fixed)s.
+pub mod neg;
+/// An implementation of [`Sign`](malachite_base::num::arithmetic::traits::Sign), a trait for
+/// determining the sign of a number.
+pub mod sign;
+/// Subtraction of [`Fixed`](crate::Fixed)s.
+pub mod sub;
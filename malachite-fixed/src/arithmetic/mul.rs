@@ -0,0 +1,371 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Fixed;
+use core::cmp::Ordering;
+use malachite_base::num::arithmetic::traits::{ShrRound, ShrRoundAssign};
+use malachite_base::rounding_modes::RoundingMode;
+
+impl<const FRAC_BITS: u64> Fixed<FRAC_BITS> {
+    /// Multiplies two [`Fixed`]s, taking both by value, and rounds the product to a multiple of
+    /// $2^{-F}$ using the specified rounding mode. An [`Ordering`] is also returned, indicating
+    /// whether the rounded product is less than, equal to, or greater than the exact product.
+    ///
+    /// The product is computed exactly and rounded only once. If it is already a multiple of
+    /// $2^{-F}$, it is returned unchanged, whatever the rounding mode.
+    ///
+    /// $$
+    /// f(x, y, m) = 2^{-F} g(2^F xy, m),
+    /// $$
+    /// where $g(z, m)$ rounds $z$ to an integer using the rounding mode $m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.raw().significant_bits(), other.raw().significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the product is not a multiple of $2^{-F}$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_fixed::Fixed;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = "1.5".parse::<Fixed<2>>().unwrap();
+    /// let y = "1.25".parse::<Fixed<2>>().unwrap();
+    /// let (p, o) = x.mul_round(y, Floor);
+    /// assert_eq!(p.to_string(), "1.75");
+    /// assert_eq!(o, Less);
+    ///
+    /// let x = "1.5".parse::<Fixed<2>>().unwrap();
+    /// let y = "1.25".parse::<Fixed<2>>().unwrap();
+    /// let (p, o) = x.mul_round(y, Nearest);
+    /// assert_eq!(p.to_string(), "2");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let x = "1.5".parse::<Fixed<2>>().unwrap();
+    /// let y = "-1.25".parse::<Fixed<2>>().unwrap();
+    /// let (p, o) = x.mul_round(y, Nearest);
+    /// assert_eq!(p.to_string(), "-2");
+    /// assert_eq!(o, Less);
+    ///
+    /// let x = "1.5".parse::<Fixed<2>>().unwrap();
+    /// let y = "0.5".parse::<Fixed<2>>().unwrap();
+    /// let (p, o) = x.mul_round(y, Exact);
+    /// assert_eq!(p.to_string(), "0.75");
+    /// assert_eq!(o, Equal);
+    /// ```
+    #[inline]
+    pub fn mul_round(
+        mut self,
+        other: Fixed<FRAC_BITS>,
+        rm: RoundingMode,
+    ) -> (Fixed<FRAC_BITS>, Ordering) {
+        let o = self.mul_round_assign(other, rm);
+        (self, o)
+    }
+
+    /// Multiplies two [`Fixed`]s, taking the first by value and the second by reference, and rounds
+    /// the product to a multiple of $2^{-F}$ using the specified rounding mode. An [`Ordering`] is
+    /// also returned, indicating whether the rounded product is less than, equal to, or greater
+    /// than the exact product.
+    ///
+    /// See [`Fixed::mul_round`] for more details.
+    ///
+    /// $$
+    /// f(x, y, m) = 2^{-F} g(2^F xy, m),
+    /// $$
+    /// where $g(z, m)$ rounds $z$ to an integer using the rounding mode $m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.raw().significant_bits(), other.raw().significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the product is not a multiple of $2^{-F}$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_fixed::Fixed;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = "1.5".parse::<Fixed<2>>().unwrap();
+    /// let y = "1.25".parse::<Fixed<2>>().unwrap();
+    /// let (p, o) = x.mul_round_val_ref(&y, Floor);
+    /// assert_eq!(p.to_string(), "1.75");
+    /// assert_eq!(o, Less);
+    ///
+    /// let x = "1.5".parse::<Fixed<2>>().unwrap();
+    /// let y = "1.25".parse::<Fixed<2>>().unwrap();
+    /// let (p, o) = x.mul_round_val_ref(&y, Nearest);
+    /// assert_eq!(p.to_string(), "2");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let x = "1.5".parse::<Fixed<2>>().unwrap();
+    /// let y = "-1.25".parse::<Fixed<2>>().unwrap();
+    /// let (p, o) = x.mul_round_val_ref(&y, Nearest);
+    /// assert_eq!(p.to_string(), "-2");
+    /// assert_eq!(o, Less);
+    ///
+    /// let x = "1.5".parse::<Fixed<2>>().unwrap();
+    /// let y = "0.5".parse::<Fixed<2>>().unwrap();
+    /// let (p, o) = x.mul_round_val_ref(&y, Exact);
+    /// assert_eq!(p.to_string(), "0.75");
+    /// assert_eq!(o, Equal);
+    /// ```
+    #[inline]
+    pub fn mul_round_val_ref(
+        mut self,
+        other: &Fixed<FRAC_BITS>,
+        rm: RoundingMode,
+    ) -> (Fixed<FRAC_BITS>, Ordering) {
+        let o = self.mul_round_assign_ref(other, rm);
+        (self, o)
+    }
+
+    /// Multiplies two [`Fixed`]s, taking the first by reference and the second by value, and rounds
+    /// the product to a multiple of $2^{-F}$ using the specified rounding mode. An [`Ordering`] is
+    /// also returned, indicating whether the rounded product is less than, equal to, or greater
+    /// than the exact product.
+    ///
+    /// See [`Fixed::mul_round`] for more details.
+    ///
+    /// $$
+    /// f(x, y, m) = 2^{-F} g(2^F xy, m),
+    /// $$
+    /// where $g(z, m)$ rounds $z$ to an integer using the rounding mode $m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.raw().significant_bits(), other.raw().significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the product is not a multiple of $2^{-F}$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_fixed::Fixed;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = "1.5".parse::<Fixed<2>>().unwrap();
+    /// let y = "1.25".parse::<Fixed<2>>().unwrap();
+    /// let (p, o) = x.mul_round_ref_val(y, Floor);
+    /// assert_eq!(p.to_string(), "1.75");
+    /// assert_eq!(o, Less);
+    ///
+    /// let x = "1.5".parse::<Fixed<2>>().unwrap();
+    /// let y = "1.25".parse::<Fixed<2>>().unwrap();
+    /// let (p, o) = x.mul_round_ref_val(y, Nearest);
+    /// assert_eq!(p.to_string(), "2");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let x = "1.5".parse::<Fixed<2>>().unwrap();
+    /// let y = "-1.25".parse::<Fixed<2>>().unwrap();
+    /// let (p, o) = x.mul_round_ref_val(y, Nearest);
+    /// assert_eq!(p.to_string(), "-2");
+    /// assert_eq!(o, Less);
+    ///
+    /// let x = "1.5".parse::<Fixed<2>>().unwrap();
+    /// let y = "0.5".parse::<Fixed<2>>().unwrap();
+    /// let (p, o) = x.mul_round_ref_val(y, Exact);
+    /// assert_eq!(p.to_string(), "0.75");
+    /// assert_eq!(o, Equal);
+    /// ```
+    pub fn mul_round_ref_val(
+        &self,
+        other: Fixed<FRAC_BITS>,
+        rm: RoundingMode,
+    ) -> (Fixed<FRAC_BITS>, Ordering) {
+        let (raw, o) = (&self.raw * other.raw).shr_round(FRAC_BITS, rm);
+        (Fixed { raw }, o)
+    }
+
+    /// Multiplies two [`Fixed`]s, taking both by reference, and rounds the product to a multiple of
+    /// $2^{-F}$ using the specified rounding mode. An [`Ordering`] is also returned, indicating
+    /// whether the rounded product is less than, equal to, or greater than the exact product.
+    ///
+    /// See [`Fixed::mul_round`] for more details.
+    ///
+    /// $$
+    /// f(x, y, m) = 2^{-F} g(2^F xy, m),
+    /// $$
+    /// where $g(z, m)$ rounds $z$ to an integer using the rounding mode $m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.raw().significant_bits(), other.raw().significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the product is not a multiple of $2^{-F}$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_fixed::Fixed;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let x = "1.5".parse::<Fixed<2>>().unwrap();
+    /// let y = "1.25".parse::<Fixed<2>>().unwrap();
+    /// let (p, o) = x.mul_round_ref_ref(&y, Floor);
+    /// assert_eq!(p.to_string(), "1.75");
+    /// assert_eq!(o, Less);
+    ///
+    /// let x = "1.5".parse::<Fixed<2>>().unwrap();
+    /// let y = "1.25".parse::<Fixed<2>>().unwrap();
+    /// let (p, o) = x.mul_round_ref_ref(&y, Nearest);
+    /// assert_eq!(p.to_string(), "2");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let x = "1.5".parse::<Fixed<2>>().unwrap();
+    /// let y = "-1.25".parse::<Fixed<2>>().unwrap();
+    /// let (p, o) = x.mul_round_ref_ref(&y, Nearest);
+    /// assert_eq!(p.to_string(), "-2");
+    /// assert_eq!(o, Less);
+    ///
+    /// let x = "1.5".parse::<Fixed<2>>().unwrap();
+    /// let y = "0.5".parse::<Fixed<2>>().unwrap();
+    /// let (p, o) = x.mul_round_ref_ref(&y, Exact);
+    /// assert_eq!(p.to_string(), "0.75");
+    /// assert_eq!(o, Equal);
+    /// ```
+    pub fn mul_round_ref_ref(
+        &self,
+        other: &Fixed<FRAC_BITS>,
+        rm: RoundingMode,
+    ) -> (Fixed<FRAC_BITS>, Ordering) {
+        let (raw, o) = (&self.raw * &other.raw).shr_round(FRAC_BITS, rm);
+        (Fixed { raw }, o)
+    }
+
+    /// Multiplies a [`Fixed`] by a [`Fixed`] in place, taking the [`Fixed`] on the right-hand side
+    /// by value, and rounds the product to a multiple of $2^{-F}$ using the specified rounding
+    /// mode. An [`Ordering`] is returned, indicating whether the rounded product is less than,
+    /// equal to, or greater than the exact product.
+    ///
+    /// See [`Fixed::mul_round`] for more details.
+    ///
+    /// $$
+    /// x \gets 2^{-F} g(2^F xy, m),
+    /// $$
+    /// where $g(z, m)$ rounds $z$ to an integer using the rounding mode $m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.raw().significant_bits(), other.raw().significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the product is not a multiple of $2^{-F}$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_fixed::Fixed;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = "1.5".parse::<Fixed<2>>().unwrap();
+    /// let y = "1.25".parse::<Fixed<2>>().unwrap();
+    /// assert_eq!(x.mul_round_assign(y, Floor), Less);
+    /// assert_eq!(x.to_string(), "1.75");
+    ///
+    /// let mut x = "1.5".parse::<Fixed<2>>().unwrap();
+    /// let y = "1.25".parse::<Fixed<2>>().unwrap();
+    /// assert_eq!(x.mul_round_assign(y, Nearest), Greater);
+    /// assert_eq!(x.to_string(), "2");
+    ///
+    /// let mut x = "1.5".parse::<Fixed<2>>().unwrap();
+    /// let y = "-1.25".parse::<Fixed<2>>().unwrap();
+    /// assert_eq!(x.mul_round_assign(y, Nearest), Less);
+    /// assert_eq!(x.to_string(), "-2");
+    ///
+    /// let mut x = "1.5".parse::<Fixed<2>>().unwrap();
+    /// let y = "0.5".parse::<Fixed<2>>().unwrap();
+    /// assert_eq!(x.mul_round_assign(y, Exact), Equal);
+    /// assert_eq!(x.to_string(), "0.75");
+    /// ```
+    pub fn mul_round_assign(&mut self, other: Fixed<FRAC_BITS>, rm: RoundingMode) -> Ordering {
+        self.raw *= other.raw;
+        self.raw.shr_round_assign(FRAC_BITS, rm)
+    }
+
+    /// Multiplies a [`Fixed`] by a [`Fixed`] in place, taking the [`Fixed`] on the right-hand side
+    /// by reference, and rounds the product to a multiple of $2^{-F}$ using the specified rounding
+    /// mode. An [`Ordering`] is returned, indicating whether the rounded product is less than,
+    /// equal to, or greater than the exact product.
+    ///
+    /// See [`Fixed::mul_round`] for more details.
+    ///
+    /// $$
+    /// x \gets 2^{-F} g(2^F xy, m),
+    /// $$
+    /// where $g(z, m)$ rounds $z$ to an integer using the rounding mode $m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.raw().significant_bits(), other.raw().significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the product is not a multiple of $2^{-F}$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_fixed::Fixed;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let mut x = "1.5".parse::<Fixed<2>>().unwrap();
+    /// let y = "1.25".parse::<Fixed<2>>().unwrap();
+    /// assert_eq!(x.mul_round_assign_ref(&y, Floor), Less);
+    /// assert_eq!(x.to_string(), "1.75");
+    ///
+    /// let mut x = "1.5".parse::<Fixed<2>>().unwrap();
+    /// let y = "1.25".parse::<Fixed<2>>().unwrap();
+    /// assert_eq!(x.mul_round_assign_ref(&y, Nearest), Greater);
+    /// assert_eq!(x.to_string(), "2");
+    ///
+    /// let mut x = "1.5".parse::<Fixed<2>>().unwrap();
+    /// let y = "-1.25".parse::<Fixed<2>>().unwrap();
+    /// assert_eq!(x.mul_round_assign_ref(&y, Nearest), Less);
+    /// assert_eq!(x.to_string(), "-2");
+    ///
+    /// let mut x = "1.5".parse::<Fixed<2>>().unwrap();
+    /// let y = "0.5".parse::<Fixed<2>>().unwrap();
+    /// assert_eq!(x.mul_round_assign_ref(&y, Exact), Equal);
+    /// assert_eq!(x.to_string(), "0.75");
+    /// ```
+    pub fn mul_round_assign_ref(&mut self, other: &Fixed<FRAC_BITS>, rm: RoundingMode) -> Ordering {
+        self.raw *= &other.raw;
+        self.raw.shr_round_assign(FRAC_BITS, rm)
+    }
+}
@@ -0,0 +1,98 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Fixed;
+use core::ops::Neg;
+use malachite_base::num::arithmetic::traits::NegAssign;
+
+impl<const FRAC_BITS: u64> Neg for Fixed<FRAC_BITS> {
+    type Output = Fixed<FRAC_BITS>;
+
+    /// Negates a [`Fixed`], taking it by value.
+    ///
+    /// $$
+    /// f(x) = -x.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_fixed::Fixed;
+    ///
+    /// assert_eq!((-"0".parse::<Fixed<4>>().unwrap()).to_string(), "0");
+    /// assert_eq!((-"1.5".parse::<Fixed<4>>().unwrap()).to_string(), "-1.5");
+    /// assert_eq!((-"-12000".parse::<Fixed<4>>().unwrap()).to_string(), "12000");
+    /// ```
+    fn neg(mut self) -> Fixed<FRAC_BITS> {
+        self.raw.neg_assign();
+        self
+    }
+}
+
+impl<const FRAC_BITS: u64> Neg for &Fixed<FRAC_BITS> {
+    type Output = Fixed<FRAC_BITS>;
+
+    /// Negates a [`Fixed`], taking it by reference.
+    ///
+    /// $$
+    /// f(x) = -x.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.raw().significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_fixed::Fixed;
+    ///
+    /// assert_eq!((-&"0".parse::<Fixed<4>>().unwrap()).to_string(), "0");
+    /// assert_eq!((-&"1.5".parse::<Fixed<4>>().unwrap()).to_string(), "-1.5");
+    /// assert_eq!((-&"-12000".parse::<Fixed<4>>().unwrap()).to_string(), "12000");
+    /// ```
+    fn neg(self) -> Fixed<FRAC_BITS> {
+        Fixed { raw: -&self.raw }
+    }
+}
+
+impl<const FRAC_BITS: u64> NegAssign for Fixed<FRAC_BITS> {
+    /// Negates a [`Fixed`] in place.
+    ///
+    /// $$
+    /// x \gets -x.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::NegAssign;
+    /// use malachite_fixed::Fixed;
+    ///
+    /// let mut x = "0".parse::<Fixed<4>>().unwrap();
+    /// x.neg_assign();
+    /// assert_eq!(x.to_string(), "0");
+    ///
+    /// let mut x = "1.5".parse::<Fixed<4>>().unwrap();
+    /// x.neg_assign();
+    /// assert_eq!(x.to_string(), "-1.5");
+    ///
+    /// let mut x = "-12000".parse::<Fixed<4>>().unwrap();
+    /// x.neg_assign();
+    /// assert_eq!(x.to_string(), "12000");
+    /// ```
+    fn neg_assign(&mut self) {
+        self.raw.neg_assign();
+    }
+}
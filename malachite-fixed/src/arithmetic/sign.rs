@@ -0,0 +1,36 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Fixed;
+use core::cmp::Ordering;
+use malachite_base::num::arithmetic::traits::Sign;
+
+impl<const FRAC_BITS: u64> Sign for Fixed<FRAC_BITS> {
+    /// Compares a [`Fixed`] to zero.
+    ///
+    /// Returns `Greater`, `Equal`, or `Less`, depending on whether the [`Fixed`] is positive, zero,
+    /// or negative, respectively.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Sign;
+    /// use malachite_fixed::Fixed;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// assert_eq!("0".parse::<Fixed<4>>().unwrap().sign(), Equal);
+    /// assert_eq!("1.5".parse::<Fixed<4>>().unwrap().sign(), Greater);
+    /// assert_eq!("-12000".parse::<Fixed<4>>().unwrap().sign(), Less);
+    /// ```
+    #[inline]
+    fn sign(&self) -> Ordering {
+        self.raw.sign()
+    }
+}
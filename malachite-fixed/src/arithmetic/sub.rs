@@ -0,0 +1,234 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Fixed;
+use core::ops::{Sub, SubAssign};
+
+impl<const FRAC_BITS: u64> Sub<Fixed<FRAC_BITS>> for Fixed<FRAC_BITS> {
+    type Output = Fixed<FRAC_BITS>;
+
+    /// Subtracts two [`Fixed`]s, taking both by value. The difference is exact.
+    ///
+    /// $$
+    /// f(x, y) = x - y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.raw().significant_bits(), other.raw().significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_fixed::Fixed;
+    ///
+    /// assert_eq!(
+    ///     ("1.5".parse::<Fixed<4>>().unwrap() - "2.25".parse::<Fixed<4>>().unwrap())
+    ///         .to_string(),
+    ///     "-0.75"
+    /// );
+    /// assert_eq!(
+    ///     ("0.0625".parse::<Fixed<4>>().unwrap() - "-1".parse::<Fixed<4>>().unwrap())
+    ///         .to_string(),
+    ///     "1.0625"
+    /// );
+    /// ```
+    #[inline]
+    fn sub(mut self, other: Fixed<FRAC_BITS>) -> Fixed<FRAC_BITS> {
+        self -= other;
+        self
+    }
+}
+
+impl<const FRAC_BITS: u64> Sub<&Fixed<FRAC_BITS>> for Fixed<FRAC_BITS> {
+    type Output = Fixed<FRAC_BITS>;
+
+    /// Subtracts two [`Fixed`]s, taking the first by value and the second by reference. The
+    /// difference is exact.
+    ///
+    /// $$
+    /// f(x, y) = x - y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.raw().significant_bits(), other.raw().significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_fixed::Fixed;
+    ///
+    /// assert_eq!(
+    ///     ("1.5".parse::<Fixed<4>>().unwrap() - &"2.25".parse::<Fixed<4>>().unwrap())
+    ///         .to_string(),
+    ///     "-0.75"
+    /// );
+    /// assert_eq!(
+    ///     ("0.0625".parse::<Fixed<4>>().unwrap() - &"-1".parse::<Fixed<4>>().unwrap())
+    ///         .to_string(),
+    ///     "1.0625"
+    /// );
+    /// ```
+    #[inline]
+    fn sub(mut self, other: &Fixed<FRAC_BITS>) -> Fixed<FRAC_BITS> {
+        self -= other;
+        self
+    }
+}
+
+impl<const FRAC_BITS: u64> Sub<Fixed<FRAC_BITS>> for &Fixed<FRAC_BITS> {
+    type Output = Fixed<FRAC_BITS>;
+
+    /// Subtracts two [`Fixed`]s, taking the first by reference and the second by value. The
+    /// difference is exact.
+    ///
+    /// $$
+    /// f(x, y) = x - y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.raw().significant_bits(), other.raw().significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_fixed::Fixed;
+    ///
+    /// assert_eq!(
+    ///     (&"1.5".parse::<Fixed<4>>().unwrap() - "2.25".parse::<Fixed<4>>().unwrap())
+    ///         .to_string(),
+    ///     "-0.75"
+    /// );
+    /// assert_eq!(
+    ///     (&"0.0625".parse::<Fixed<4>>().unwrap() - "-1".parse::<Fixed<4>>().unwrap())
+    ///         .to_string(),
+    ///     "1.0625"
+    /// );
+    /// ```
+    fn sub(self, other: Fixed<FRAC_BITS>) -> Fixed<FRAC_BITS> {
+        Fixed {
+            raw: &self.raw - other.raw,
+        }
+    }
+}
+
+impl<const FRAC_BITS: u64> Sub<&Fixed<FRAC_BITS>> for &Fixed<FRAC_BITS> {
+    type Output = Fixed<FRAC_BITS>;
+
+    /// Subtracts two [`Fixed`]s, taking both by reference. The difference is exact.
+    ///
+    /// $$
+    /// f(x, y) = x - y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.raw().significant_bits(), other.raw().significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_fixed::Fixed;
+    ///
+    /// assert_eq!(
+    ///     (&"1.5".parse::<Fixed<4>>().unwrap() - &"2.25".parse::<Fixed<4>>().unwrap())
+    ///         .to_string(),
+    ///     "-0.75"
+    /// );
+    /// assert_eq!(
+    ///     (&"0.0625".parse::<Fixed<4>>().unwrap() - &"-1".parse::<Fixed<4>>().unwrap())
+    ///         .to_string(),
+    ///     "1.0625"
+    /// );
+    /// ```
+    fn sub(self, other: &Fixed<FRAC_BITS>) -> Fixed<FRAC_BITS> {
+        Fixed {
+            raw: &self.raw - &other.raw,
+        }
+    }
+}
+
+impl<const FRAC_BITS: u64> SubAssign<Fixed<FRAC_BITS>> for Fixed<FRAC_BITS> {
+    /// Subtracts a [`Fixed`] from a [`Fixed`] in place, taking the [`Fixed`] on the right-hand side
+    /// by value. The difference is exact.
+    ///
+    /// $$
+    /// x \gets x - y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.raw().significant_bits(), other.raw().significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_fixed::Fixed;
+    ///
+    /// let mut x = "1.5".parse::<Fixed<4>>().unwrap();
+    /// x -= "2.25".parse::<Fixed<4>>().unwrap();
+    /// assert_eq!(x.to_string(), "-0.75");
+    ///
+    /// let mut x = "0.0625".parse::<Fixed<4>>().unwrap();
+    /// x -= "-1".parse::<Fixed<4>>().unwrap();
+    /// assert_eq!(x.to_string(), "1.0625");
+    /// ```
+    fn sub_assign(&mut self, other: Fixed<FRAC_BITS>) {
+        self.raw -= other.raw;
+    }
+}
+
+impl<const FRAC_BITS: u64> SubAssign<&Fixed<FRAC_BITS>> for Fixed<FRAC_BITS> {
+    /// Subtracts a [`Fixed`] from a [`Fixed`] in place, taking the [`Fixed`] on the right-hand side
+    /// by reference. The difference is exact.
+    ///
+    /// $$
+    /// x \gets x - y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.raw().significant_bits(), other.raw().significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_fixed::Fixed;
+    ///
+    /// let mut x = "1.5".parse::<Fixed<4>>().unwrap();
+    /// x -= &"2.25".parse::<Fixed<4>>().unwrap();
+    /// assert_eq!(x.to_string(), "-0.75");
+    ///
+    /// let mut x = "0.0625".parse::<Fixed<4>>().unwrap();
+    /// x -= &"-1".parse::<Fixed<4>>().unwrap();
+    /// assert_eq!(x.to_string(), "1.0625");
+    /// ```
+    fn sub_assign(&mut self, other: &Fixed<FRAC_BITS>) {
+        self.raw -= &other.raw;
+    }
+}
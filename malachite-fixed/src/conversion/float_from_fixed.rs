@@ -0,0 +1,84 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Fixed;
+use malachite_float::Float;
+use malachite_q::conversion::primitive_float_from_rational::FloatConversionError;
+use malachite_q::Rational;
+
+impl<const FRAC_BITS: u64> TryFrom<Fixed<FRAC_BITS>> for Float {
+    type Error = FloatConversionError;
+
+    /// Converts a [`Fixed`] to a [`Float`], taking the [`Fixed`] by value. The [`Float`]'s
+    /// precision is the minimum number of bits needed to represent the [`Fixed`] exactly.
+    ///
+    /// Every [`Fixed`] is a dyadic rational, so the conversion can only fail if the [`Fixed`] is
+    /// too far from zero or too close to zero to be represented as a [`Float`], in which case an
+    /// overflow or underflow error is returned.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `x.raw().significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_fixed::Fixed;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Float::try_from("-1.5".parse::<Fixed<4>>().unwrap()).unwrap();
+    /// assert_eq!(x.to_string(), "-1.5");
+    /// assert_eq!(x.get_prec(), Some(2));
+    ///
+    /// let x = Float::try_from("0.5".parse::<Fixed<100>>().unwrap()).unwrap();
+    /// assert_eq!(x.to_string(), "0.5");
+    /// assert_eq!(x.get_prec(), Some(1));
+    /// ```
+    #[inline]
+    fn try_from(x: Fixed<FRAC_BITS>) -> Result<Float, Self::Error> {
+        Float::try_from(Rational::from(x))
+    }
+}
+
+impl<const FRAC_BITS: u64> TryFrom<&Fixed<FRAC_BITS>> for Float {
+    type Error = FloatConversionError;
+
+    /// Converts a [`Fixed`] to a [`Float`], taking the [`Fixed`] by reference. The [`Float`]'s
+    /// precision is the minimum number of bits needed to represent the [`Fixed`] exactly.
+    ///
+    /// Every [`Fixed`] is a dyadic rational, so the conversion can only fail if the [`Fixed`] is
+    /// too far from zero or too close to zero to be represented as a [`Float`], in which case an
+    /// overflow or underflow error is returned.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `x.raw().significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_fixed::Fixed;
+    /// use malachite_float::Float;
+    ///
+    /// let x = Float::try_from(&"-1.5".parse::<Fixed<4>>().unwrap()).unwrap();
+    /// assert_eq!(x.to_string(), "-1.5");
+    /// assert_eq!(x.get_prec(), Some(2));
+    ///
+    /// let x = Float::try_from(&"0.5".parse::<Fixed<100>>().unwrap()).unwrap();
+    /// assert_eq!(x.to_string(), "0.5");
+    /// assert_eq!(x.get_prec(), Some(1));
+    /// ```
+    #[inline]
+    fn try_from(x: &Fixed<FRAC_BITS>) -> Result<Float, Self::Error> {
+        Float::try_from(Rational::from(x))
+    }
+}
@@ -0,0 +1,227 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Fixed;
+use core::cmp::Ordering;
+use malachite_base::num::conversion::traits::{ConvertibleFrom, RoundingFrom};
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_float::Float;
+use malachite_q::Rational;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct FixedFromFloatError;
+
+impl<const FRAC_BITS: u64> TryFrom<Float> for Fixed<FRAC_BITS> {
+    type Error = FixedFromFloatError;
+
+    /// Converts a [`Float`] to a [`Fixed`], taking the [`Float`] by value. If the [`Float`] is NaN,
+    /// infinite, or not a multiple of $2^{-F}$, an error is returned.
+    ///
+    /// Both positive and negative zero are converted to zero.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `x.complexity() + F`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::Infinity;
+    /// use malachite_fixed::conversion::from_float::FixedFromFloatError;
+    /// use malachite_fixed::Fixed;
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(
+    ///     Fixed::<4>::try_from(Float::from(-1.5)).unwrap().to_string(),
+    ///     "-1.5"
+    /// );
+    /// assert_eq!(
+    ///     Fixed::<4>::try_from(Float::from(0.03125)),
+    ///     Err(FixedFromFloatError)
+    /// );
+    /// assert_eq!(
+    ///     Fixed::<4>::try_from(Float::INFINITY),
+    ///     Err(FixedFromFloatError)
+    /// );
+    /// ```
+    fn try_from(x: Float) -> Result<Fixed<FRAC_BITS>, Self::Error> {
+        Rational::try_from(x)
+            .ok()
+            .and_then(|q| Fixed::try_from(q).ok())
+            .ok_or(FixedFromFloatError)
+    }
+}
+
+impl<const FRAC_BITS: u64> TryFrom<&Float> for Fixed<FRAC_BITS> {
+    type Error = FixedFromFloatError;
+
+    /// Converts a [`Float`] to a [`Fixed`], taking the [`Float`] by reference. If the [`Float`] is
+    /// NaN, infinite, or not a multiple of $2^{-F}$, an error is returned.
+    ///
+    /// Both positive and negative zero are converted to zero.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `x.complexity() + F`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::Infinity;
+    /// use malachite_fixed::conversion::from_float::FixedFromFloatError;
+    /// use malachite_fixed::Fixed;
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(
+    ///     Fixed::<4>::try_from(&Float::from(-1.5)).unwrap().to_string(),
+    ///     "-1.5"
+    /// );
+    /// assert_eq!(
+    ///     Fixed::<4>::try_from(&Float::from(0.03125)),
+    ///     Err(FixedFromFloatError)
+    /// );
+    /// assert_eq!(
+    ///     Fixed::<4>::try_from(&Float::INFINITY),
+    ///     Err(FixedFromFloatError)
+    /// );
+    /// ```
+    fn try_from(x: &Float) -> Result<Fixed<FRAC_BITS>, Self::Error> {
+        Rational::try_from(x)
+            .ok()
+            .and_then(|q| Fixed::try_from(q).ok())
+            .ok_or(FixedFromFloatError)
+    }
+}
+
+impl<const FRAC_BITS: u64> ConvertibleFrom<&Float> for Fixed<FRAC_BITS> {
+    /// Determines whether a [`Float`] can be converted to a [`Fixed`]; that is, whether it is
+    /// finite and a multiple of $2^{-F}$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `x.complexity()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::NaN;
+    /// use malachite_base::num::conversion::traits::ConvertibleFrom;
+    /// use malachite_fixed::Fixed;
+    /// use malachite_float::Float;
+    ///
+    /// assert_eq!(Fixed::<4>::convertible_from(&Float::from(-1.5)), true);
+    /// assert_eq!(Fixed::<4>::convertible_from(&Float::from(0.03125)), false);
+    /// assert_eq!(Fixed::<4>::convertible_from(&Float::NAN), false);
+    /// ```
+    #[inline]
+    fn convertible_from(x: &Float) -> bool {
+        Rational::try_from(x).is_ok_and(|q| Fixed::<FRAC_BITS>::convertible_from(&q))
+    }
+}
+
+impl<const FRAC_BITS: u64> RoundingFrom<Float> for Fixed<FRAC_BITS> {
+    /// Converts a [`Float`] to a [`Fixed`], taking the [`Float`] by value and rounding it to a
+    /// multiple of $2^{-F}$ using the specified rounding mode. An [`Ordering`] is also returned,
+    /// indicating whether the returned value is less than, equal to, or greater than the original
+    /// value.
+    ///
+    /// If the [`Float`] is NaN or infinite, the function will panic regardless of the rounding
+    /// mode.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `x.complexity() + F`.
+    ///
+    /// # Panics
+    /// Panics if the [`Float`] is not a multiple of $2^{-F}$ and `rm` is `Exact`, or if the
+    /// [`Float`] is NaN or infinite.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::conversion::traits::RoundingFrom;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_fixed::Fixed;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (x, o) = Fixed::<2>::rounding_from(Float::from(1.3), Floor);
+    /// assert_eq!(x.to_string(), "1.25");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (x, o) = Fixed::<2>::rounding_from(Float::from(-1.3), Nearest);
+    /// assert_eq!(x.to_string(), "-1.25");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (x, o) = Fixed::<2>::rounding_from(Float::from(1.75), Exact);
+    /// assert_eq!(x.to_string(), "1.75");
+    /// assert_eq!(o, Equal);
+    /// ```
+    fn rounding_from(x: Float, rm: RoundingMode) -> (Fixed<FRAC_BITS>, Ordering) {
+        match Rational::try_from(x) {
+            Ok(q) => Fixed::rounding_from(q, rm),
+            Err(_) => panic!("Can't convert a non-finite Float to a Fixed"),
+        }
+    }
+}
+
+impl<const FRAC_BITS: u64> RoundingFrom<&Float> for Fixed<FRAC_BITS> {
+    /// Converts a [`Float`] to a [`Fixed`], taking the [`Float`] by reference and rounding it to a
+    /// multiple of $2^{-F}$ using the specified rounding mode. An [`Ordering`] is also returned,
+    /// indicating whether the returned value is less than, equal to, or greater than the original
+    /// value.
+    ///
+    /// If the [`Float`] is NaN or infinite, the function will panic regardless of the rounding
+    /// mode.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `x.complexity() + F`.
+    ///
+    /// # Panics
+    /// Panics if the [`Float`] is not a multiple of $2^{-F}$ and `rm` is `Exact`, or if the
+    /// [`Float`] is NaN or infinite.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::conversion::traits::RoundingFrom;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_fixed::Fixed;
+    /// use malachite_float::Float;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (x, o) = Fixed::<2>::rounding_from(&Float::from(1.3), Floor);
+    /// assert_eq!(x.to_string(), "1.25");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (x, o) = Fixed::<2>::rounding_from(&Float::from(-1.3), Nearest);
+    /// assert_eq!(x.to_string(), "-1.25");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (x, o) = Fixed::<2>::rounding_from(&Float::from(1.75), Exact);
+    /// assert_eq!(x.to_string(), "1.75");
+    /// assert_eq!(o, Equal);
+    /// ```
+    fn rounding_from(x: &Float, rm: RoundingMode) -> (Fixed<FRAC_BITS>, Ordering) {
+        match Rational::try_from(x) {
+            Ok(q) => Fixed::rounding_from(q, rm),
+            Err(_) => panic!("Can't convert a non-finite Float to a Fixed"),
+        }
+    }
+}
@@ -0,0 +1,70 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Fixed;
+use malachite_nz::integer::Integer;
+
+impl<const FRAC_BITS: u64> From<Integer> for Fixed<FRAC_BITS> {
+    /// Converts an [`Integer`] to a [`Fixed`], taking the [`Integer`] by value.
+    ///
+    /// $$
+    /// f(x) = x.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits() + F`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_fixed::Fixed;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Fixed::<4>::from(Integer::from(123)).to_string(), "123");
+    /// assert_eq!(*Fixed::<4>::from(Integer::from(-123)).raw(), -1968);
+    /// ```
+    #[inline]
+    fn from(x: Integer) -> Fixed<FRAC_BITS> {
+        Fixed {
+            raw: x << FRAC_BITS,
+        }
+    }
+}
+
+impl<const FRAC_BITS: u64> From<&Integer> for Fixed<FRAC_BITS> {
+    /// Converts an [`Integer`] to a [`Fixed`], taking the [`Integer`] by reference.
+    ///
+    /// $$
+    /// f(x) = x.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits() + F`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_fixed::Fixed;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Fixed::<4>::from(&Integer::from(123)).to_string(), "123");
+    /// assert_eq!(*Fixed::<4>::from(&Integer::from(-123)).raw(), -1968);
+    /// ```
+    #[inline]
+    fn from(x: &Integer) -> Fixed<FRAC_BITS> {
+        Fixed {
+            raw: x << FRAC_BITS,
+        }
+    }
+}
@@ -0,0 +1,97 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Fixed;
+use core::cmp::Ordering;
+use malachite_base::num::conversion::traits::{ConvertibleFrom, RoundingFrom};
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_q::Rational;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct FixedFromPrimitiveFloatError;
+
+macro_rules! float_impls {
+    ($f: ident) => {
+        impl<const FRAC_BITS: u64> TryFrom<$f> for Fixed<FRAC_BITS> {
+            type Error = FixedFromPrimitiveFloatError;
+
+            /// Converts a primitive float to a [`Fixed`]. If the float is NaN, infinite, or not a
+            /// multiple of $2^{-F}$, an error is returned.
+            ///
+            /// Both positive and negative zero are converted to zero.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is
+            /// `max(value.sci_exponent().abs(), F)`.
+            ///
+            /// # Examples
+            /// See [here](super::from_primitive_float#try_from).
+            fn try_from(value: $f) -> Result<Fixed<FRAC_BITS>, Self::Error> {
+                Rational::try_from(value)
+                    .ok()
+                    .and_then(|q| Fixed::try_from(q).ok())
+                    .ok_or(FixedFromPrimitiveFloatError)
+            }
+        }
+
+        impl<const FRAC_BITS: u64> ConvertibleFrom<$f> for Fixed<FRAC_BITS> {
+            /// Determines whether a primitive float can be converted to a [`Fixed`]; that is,
+            /// whether it is finite and a multiple of $2^{-F}$.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is
+            /// `value.sci_exponent().abs()`.
+            ///
+            /// # Examples
+            /// See [here](super::from_primitive_float#convertible_from).
+            fn convertible_from(value: $f) -> bool {
+                Rational::try_from(value).is_ok_and(|q| Fixed::<FRAC_BITS>::convertible_from(&q))
+            }
+        }
+
+        impl<const FRAC_BITS: u64> RoundingFrom<$f> for Fixed<FRAC_BITS> {
+            /// Converts a primitive float to a [`Fixed`], rounding it to a multiple of $2^{-F}$
+            /// using the specified rounding mode. An [`Ordering`] is also returned, indicating
+            /// whether the returned value is less than, equal to, or greater than the original
+            /// value.
+            ///
+            /// If the float is NaN or infinite, the function will panic regardless of the rounding
+            /// mode.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is
+            /// `max(value.sci_exponent().abs(), F)`.
+            ///
+            /// # Panics
+            /// Panics if the float is not a multiple of $2^{-F}$ and `rm` is `Exact`, or if the
+            /// float is NaN or infinite.
+            ///
+            /// # Examples
+            /// See [here](super::from_primitive_float#rounding_from).
+            fn rounding_from(value: $f, rm: RoundingMode) -> (Fixed<FRAC_BITS>, Ordering) {
+                match Rational::try_from(value) {
+                    Ok(q) => Fixed::rounding_from(q, rm),
+                    Err(_) => panic!("Can't convert {value} to a Fixed"),
+                }
+            }
+        }
+    };
+}
+apply_to_primitive_floats!(float_impls);
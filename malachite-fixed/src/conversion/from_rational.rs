@@ -0,0 +1,244 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Fixed;
+use core::cmp::Ordering;
+use malachite_base::num::arithmetic::traits::CheckedLogBase2;
+use malachite_base::num::conversion::traits::{ConvertibleFrom, RoundingFrom};
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::integer::Integer;
+use malachite_q::Rational;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct FixedFromRationalError;
+
+// Returns the number of fraction bits needed to represent `x` exactly, or `None` if the
+// denominator of `x` is not a power of 2.
+#[inline]
+fn frac_bits_needed(x: &Rational) -> Option<u64> {
+    x.denominator_ref().checked_log_base_2()
+}
+
+impl<const FRAC_BITS: u64> TryFrom<Rational> for Fixed<FRAC_BITS> {
+    type Error = FixedFromRationalError;
+
+    /// Converts a [`Rational`] to a [`Fixed`], taking the [`Rational`] by value. If the
+    /// [`Rational`] is not a multiple of $2^{-F}$, an error is returned.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits() + F`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_fixed::conversion::from_rational::FixedFromRationalError;
+    /// use malachite_fixed::Fixed;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(
+    ///     Fixed::<4>::try_from(Rational::from_signeds(-3, 8)).unwrap().to_string(),
+    ///     "-0.375"
+    /// );
+    /// assert_eq!(
+    ///     Fixed::<2>::try_from(Rational::from_signeds(-3, 8)),
+    ///     Err(FixedFromRationalError)
+    /// );
+    /// assert_eq!(
+    ///     Fixed::<4>::try_from(Rational::from_signeds(1, 3)),
+    ///     Err(FixedFromRationalError)
+    /// );
+    /// ```
+    fn try_from(x: Rational) -> Result<Fixed<FRAC_BITS>, Self::Error> {
+        match frac_bits_needed(&x) {
+            Some(bits) if bits <= FRAC_BITS => {
+                let sign = x >= 0u32;
+                Ok(Fixed {
+                    raw: Integer::from_sign_and_abs(sign, x.into_numerator()) << (FRAC_BITS - bits),
+                })
+            }
+            _ => Err(FixedFromRationalError),
+        }
+    }
+}
+
+impl<const FRAC_BITS: u64> TryFrom<&Rational> for Fixed<FRAC_BITS> {
+    type Error = FixedFromRationalError;
+
+    /// Converts a [`Rational`] to a [`Fixed`], taking the [`Rational`] by reference. If the
+    /// [`Rational`] is not a multiple of $2^{-F}$, an error is returned.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits() + F`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_fixed::conversion::from_rational::FixedFromRationalError;
+    /// use malachite_fixed::Fixed;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(
+    ///     Fixed::<4>::try_from(&Rational::from_signeds(-3, 8)).unwrap().to_string(),
+    ///     "-0.375"
+    /// );
+    /// assert_eq!(
+    ///     Fixed::<2>::try_from(&Rational::from_signeds(-3, 8)),
+    ///     Err(FixedFromRationalError)
+    /// );
+    /// assert_eq!(
+    ///     Fixed::<4>::try_from(&Rational::from_signeds(1, 3)),
+    ///     Err(FixedFromRationalError)
+    /// );
+    /// ```
+    fn try_from(x: &Rational) -> Result<Fixed<FRAC_BITS>, Self::Error> {
+        match frac_bits_needed(x) {
+            Some(bits) if bits <= FRAC_BITS => Ok(Fixed {
+                raw: Integer::from_sign_and_abs_ref(*x >= 0u32, x.numerator_ref())
+                    << (FRAC_BITS - bits),
+            }),
+            _ => Err(FixedFromRationalError),
+        }
+    }
+}
+
+impl<const FRAC_BITS: u64> ConvertibleFrom<&Rational> for Fixed<FRAC_BITS> {
+    /// Determines whether a [`Rational`] can be converted to a [`Fixed`]; that is, whether it is a
+    /// multiple of $2^{-F}$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::conversion::traits::ConvertibleFrom;
+    /// use malachite_fixed::Fixed;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(Fixed::<4>::convertible_from(&Rational::from_signeds(-3, 8)), true);
+    /// assert_eq!(Fixed::<2>::convertible_from(&Rational::from_signeds(-3, 8)), false);
+    /// assert_eq!(Fixed::<4>::convertible_from(&Rational::from_signeds(1, 3)), false);
+    /// ```
+    #[inline]
+    fn convertible_from(x: &Rational) -> bool {
+        frac_bits_needed(x).is_some_and(|bits| bits <= FRAC_BITS)
+    }
+}
+
+impl<const FRAC_BITS: u64> RoundingFrom<Rational> for Fixed<FRAC_BITS> {
+    /// Converts a [`Rational`] to a [`Fixed`], taking the [`Rational`] by value and rounding it to
+    /// a multiple of $2^{-F}$ using the specified rounding mode. An [`Ordering`] is also returned,
+    /// indicating whether the returned value is less than, equal to, or greater than the original
+    /// value.
+    ///
+    /// $$
+    /// f(x, m) = 2^{-F} g(2^F x, m),
+    /// $$
+    /// where $g(z, m)$ rounds $z$ to an integer using the rounding mode $m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits() + F`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the [`Rational`] is not a multiple of $2^{-F}$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::conversion::traits::RoundingFrom;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_fixed::Fixed;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (x, o) = Fixed::<4>::rounding_from(Rational::from_signeds(1, 3), Floor);
+    /// assert_eq!(x.to_string(), "0.3125");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (x, o) = Fixed::<4>::rounding_from(Rational::from_signeds(1, 3), Ceiling);
+    /// assert_eq!(x.to_string(), "0.375");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (x, o) = Fixed::<2>::rounding_from(Rational::from_signeds(-3, 8), Nearest);
+    /// assert_eq!(x.to_string(), "-0.5");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (x, o) = Fixed::<4>::rounding_from(Rational::from_signeds(-3, 8), Exact);
+    /// assert_eq!(x.to_string(), "-0.375");
+    /// assert_eq!(o, Equal);
+    /// ```
+    #[inline]
+    fn rounding_from(x: Rational, rm: RoundingMode) -> (Fixed<FRAC_BITS>, Ordering) {
+        let (raw, o) = Integer::rounding_from(x << FRAC_BITS, rm);
+        (Fixed { raw }, o)
+    }
+}
+
+impl<const FRAC_BITS: u64> RoundingFrom<&Rational> for Fixed<FRAC_BITS> {
+    /// Converts a [`Rational`] to a [`Fixed`], taking the [`Rational`] by reference and rounding it
+    /// to a multiple of $2^{-F}$ using the specified rounding mode. An [`Ordering`] is also
+    /// returned, indicating whether the returned value is less than, equal to, or greater than the
+    /// original value.
+    ///
+    /// $$
+    /// f(x, m) = 2^{-F} g(2^F x, m),
+    /// $$
+    /// where $g(z, m)$ rounds $z$ to an integer using the rounding mode $m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits() + F`.
+    ///
+    /// # Panics
+    /// Panics if `rm` is `Exact` but the [`Rational`] is not a multiple of $2^{-F}$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::conversion::traits::RoundingFrom;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_fixed::Fixed;
+    /// use malachite_q::Rational;
+    /// use std::cmp::Ordering::*;
+    ///
+    /// let (x, o) = Fixed::<4>::rounding_from(&Rational::from_signeds(1, 3), Floor);
+    /// assert_eq!(x.to_string(), "0.3125");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (x, o) = Fixed::<4>::rounding_from(&Rational::from_signeds(1, 3), Ceiling);
+    /// assert_eq!(x.to_string(), "0.375");
+    /// assert_eq!(o, Greater);
+    ///
+    /// let (x, o) = Fixed::<2>::rounding_from(&Rational::from_signeds(-3, 8), Nearest);
+    /// assert_eq!(x.to_string(), "-0.5");
+    /// assert_eq!(o, Less);
+    ///
+    /// let (x, o) = Fixed::<4>::rounding_from(&Rational::from_signeds(-3, 8), Exact);
+    /// assert_eq!(x.to_string(), "-0.375");
+    /// assert_eq!(o, Equal);
+    /// ```
+    #[inline]
+    fn rounding_from(x: &Rational, rm: RoundingMode) -> (Fixed<FRAC_BITS>, Ordering) {
+        let (raw, o) = Integer::rounding_from(x << FRAC_BITS, rm);
+        (Fixed { raw }, o)
+    }
+}
@@ -0,0 +1,35 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Fixed;
+use malachite_nz::integer::Integer;
+
+impl<const FRAC_BITS: u64> Fixed<FRAC_BITS> {
+    /// Creates a [`Fixed`] from its raw value. The value of the result is $r \cdot 2^{-F}$.
+    ///
+    /// $$
+    /// f(r) = r \cdot 2^{-F}.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_fixed::Fixed;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Fixed::<4>::from_raw(Integer::from(24)).to_string(), "1.5");
+    /// assert_eq!(Fixed::<4>::from_raw(Integer::from(-1)).to_string(), "-0.0625");
+    /// assert_eq!(Fixed::<0>::from_raw(Integer::from(24)).to_string(), "24");
+    /// ```
+    #[inline]
+    pub const fn from_raw(raw: Integer) -> Fixed<FRAC_BITS> {
+        Fixed { raw }
+    }
+}
@@ -0,0 +1,108 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Fixed;
+use core::cmp::Ordering;
+use malachite_base::num::arithmetic::traits::ShrRound;
+use malachite_base::num::conversion::traits::RoundingFrom;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::integer::Integer;
+
+impl<const FRAC_BITS: u64> RoundingFrom<Fixed<FRAC_BITS>> for Integer {
+    /// Converts a [`Fixed`] to an [`Integer`], using a specified [`RoundingMode`] and taking the
+    /// [`Fixed`] by value. An [`Ordering`] is also returned, indicating whether the returned value
+    /// is less than, equal to, or greater than the original value.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `x.raw().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if the [`Fixed`] is not an integer and `rm` is `Exact`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::conversion::traits::RoundingFrom;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_fixed::Fixed;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let x = "-1.5".parse::<Fixed<4>>().unwrap();
+    /// assert_eq!(
+    ///     Integer::rounding_from(x.clone(), Floor).to_debug_string(),
+    ///     "(-2, Less)"
+    /// );
+    /// assert_eq!(
+    ///     Integer::rounding_from(x.clone(), Down).to_debug_string(),
+    ///     "(-1, Greater)"
+    /// );
+    /// assert_eq!(
+    ///     Integer::rounding_from(x, Nearest).to_debug_string(),
+    ///     "(-2, Less)"
+    /// );
+    /// assert_eq!(
+    ///     Integer::rounding_from("3".parse::<Fixed<4>>().unwrap(), Exact).to_debug_string(),
+    ///     "(3, Equal)"
+    /// );
+    /// ```
+    #[inline]
+    fn rounding_from(x: Fixed<FRAC_BITS>, rm: RoundingMode) -> (Integer, Ordering) {
+        x.raw.shr_round(FRAC_BITS, rm)
+    }
+}
+
+impl<const FRAC_BITS: u64> RoundingFrom<&Fixed<FRAC_BITS>> for Integer {
+    /// Converts a [`Fixed`] to an [`Integer`], using a specified [`RoundingMode`] and taking the
+    /// [`Fixed`] by reference. An [`Ordering`] is also returned, indicating whether the returned
+    /// value is less than, equal to, or greater than the original value.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `x.raw().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if the [`Fixed`] is not an integer and `rm` is `Exact`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::conversion::traits::RoundingFrom;
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_fixed::Fixed;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let x = "-1.5".parse::<Fixed<4>>().unwrap();
+    /// assert_eq!(
+    ///     Integer::rounding_from(&x, Floor).to_debug_string(),
+    ///     "(-2, Less)"
+    /// );
+    /// assert_eq!(
+    ///     Integer::rounding_from(&x, Down).to_debug_string(),
+    ///     "(-1, Greater)"
+    /// );
+    /// assert_eq!(
+    ///     Integer::rounding_from(&x, Nearest).to_debug_string(),
+    ///     "(-2, Less)"
+    /// );
+    /// assert_eq!(
+    ///     Integer::rounding_from(&"3".parse::<Fixed<4>>().unwrap(), Exact).to_debug_string(),
+    ///     "(3, Equal)"
+    /// );
+    /// ```
+    #[inline]
+    fn rounding_from(x: &Fixed<FRAC_BITS>, rm: RoundingMode) -> (Integer, Ordering) {
+        (&x.raw).shr_round(FRAC_BITS, rm)
+    }
+}
@@ -0,0 +1,151 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+/// Implementations of traits for converting a [`Fixed`](crate::Fixed) to a
+/// [`Float`](malachite_float::Float).
+pub mod float_from_fixed;
+/// Implementations of traits for converting a [`Float`](malachite_float::Float) to a
+/// [`Fixed`](crate::Fixed), either exactly or by rounding.
+pub mod from_float;
+/// Implementations of the [`From`] trait for converting an
+/// [`Integer`](malachite_nz::integer::Integer) to a [`Fixed`](crate::Fixed).
+pub mod from_integer;
+/// Implementations of traits for converting a primitive float to a [`Fixed`](crate::Fixed), either
+/// exactly or by rounding.
+///
+/// The traits are [`TryFrom`],
+/// [`ConvertibleFrom`](malachite_base::num::conversion::traits::ConvertibleFrom), and
+/// [`RoundingFrom`](malachite_base::num::conversion::traits::RoundingFrom).
+///
+/// # try_from
+/// ```
+/// use malachite_fixed::conversion::from_primitive_float::FixedFromPrimitiveFloatError;
+/// use malachite_fixed::Fixed;
+///
+/// assert_eq!(Fixed::<4>::try_from(1.5f64).unwrap().to_string(), "1.5");
+/// assert_eq!(Fixed::<4>::try_from(-0.0f32).unwrap().to_string(), "0");
+/// assert_eq!(
+///     Fixed::<4>::try_from(0.03125f64),
+///     Err(FixedFromPrimitiveFloatError)
+/// );
+/// assert_eq!(
+///     Fixed::<4>::try_from(f32::NAN),
+///     Err(FixedFromPrimitiveFloatError)
+/// );
+/// assert_eq!(
+///     Fixed::<4>::try_from(f64::INFINITY),
+///     Err(FixedFromPrimitiveFloatError)
+/// );
+/// ```
+///
+/// # convertible_from
+/// ```
+/// use malachite_base::num::conversion::traits::ConvertibleFrom;
+/// use malachite_fixed::Fixed;
+///
+/// assert_eq!(Fixed::<4>::convertible_from(1.5f64), true);
+/// assert_eq!(Fixed::<4>::convertible_from(0.03125f32), false);
+/// assert_eq!(Fixed::<5>::convertible_from(0.03125f32), true);
+/// assert_eq!(Fixed::<4>::convertible_from(f64::NAN), false);
+/// assert_eq!(Fixed::<4>::convertible_from(f32::NEG_INFINITY), false);
+/// ```
+///
+/// # rounding_from
+/// ```
+/// use malachite_base::num::conversion::traits::RoundingFrom;
+/// use malachite_base::rounding_modes::RoundingMode::*;
+/// use malachite_fixed::Fixed;
+/// use std::cmp::Ordering::*;
+///
+/// let (x, o) = Fixed::<4>::rounding_from(0.1f64, Floor);
+/// assert_eq!(x.to_string(), "0.0625");
+/// assert_eq!(o, Less);
+///
+/// let (x, o) = Fixed::<4>::rounding_from(0.1f64, Nearest);
+/// assert_eq!(x.to_string(), "0.125");
+/// assert_eq!(o, Greater);
+///
+/// let (x, o) = Fixed::<4>::rounding_from(-1.5f32, Exact);
+/// assert_eq!(x.to_string(), "-1.5");
+/// assert_eq!(o, Equal);
+/// ```
+pub mod from_primitive_float;
+/// Implementations of traits for converting a [`Rational`](malachite_q::Rational) to a
+/// [`Fixed`](crate::Fixed), either exactly or by rounding.
+pub mod from_rational;
+/// Functions for constructing a [`Fixed`](crate::Fixed) from its raw value.
+pub mod from_raw;
+/// Implementations of the [`RoundingFrom`](malachite_base::num::conversion::traits::RoundingFrom)
+/// trait for converting a [`Fixed`](crate::Fixed) to an
+/// [`Integer`](malachite_nz::integer::Integer).
+pub mod integer_from_fixed;
+/// Implementations of traits for converting a [`Fixed`](crate::Fixed) to a primitive float.
+///
+/// The traits are [`TryFrom`],
+/// [`ConvertibleFrom`](malachite_base::num::conversion::traits::ConvertibleFrom), and
+/// [`RoundingFrom`](malachite_base::num::conversion::traits::RoundingFrom).
+///
+/// # rounding_from
+/// ```
+/// use malachite_base::num::basic::floats::PrimitiveFloat;
+/// use malachite_base::num::conversion::traits::RoundingFrom;
+/// use malachite_base::num::float::NiceFloat;
+/// use malachite_base::rounding_modes::RoundingMode::*;
+/// use malachite_fixed::Fixed;
+/// use malachite_nz::integer::Integer;
+/// use std::cmp::Ordering::*;
+///
+/// let x = "1.5".parse::<Fixed<4>>().unwrap();
+/// assert_eq!(f32::rounding_from(x.clone(), Exact), (1.5, Equal));
+/// assert_eq!(f64::rounding_from(&x, Exact), (1.5, Equal));
+///
+/// let small = Fixed::<200>::from_raw(Integer::from(1));
+/// let (rounded, o) = f32::rounding_from(&small, Down);
+/// assert_eq!(NiceFloat(rounded), NiceFloat(0.0));
+/// assert_eq!(o, Less);
+/// assert_eq!(
+///     f32::rounding_from(small, Up),
+///     (f32::MIN_POSITIVE_SUBNORMAL, Greater)
+/// );
+/// ```
+///
+/// # try_from
+/// ```
+/// use malachite_fixed::Fixed;
+/// use malachite_nz::integer::Integer;
+/// use malachite_q::conversion::primitive_float_from_rational::FloatConversionError;
+///
+/// let x = "-1.5".parse::<Fixed<4>>().unwrap();
+/// assert_eq!(f32::try_from(x.clone()), Ok(-1.5));
+/// assert_eq!(f64::try_from(&x), Ok(-1.5));
+///
+/// let small = Fixed::<200>::from_raw(Integer::from(1));
+/// assert_eq!(f32::try_from(&small), Err(FloatConversionError::Inexact));
+/// assert_eq!(f64::try_from(small), Ok(6.223015277861142e-61));
+/// ```
+///
+/// # convertible_from
+/// ```
+/// use malachite_base::num::conversion::traits::ConvertibleFrom;
+/// use malachite_fixed::Fixed;
+/// use malachite_nz::integer::Integer;
+///
+/// assert_eq!(f32::convertible_from(&"1.5".parse::<Fixed<4>>().unwrap()), true);
+///
+/// let small = Fixed::<200>::from_raw(Integer::from(1));
+/// assert_eq!(f32::convertible_from(&small), false);
+/// assert_eq!(f64::convertible_from(&small), true);
+/// ```
+pub mod primitive_float_from_fixed;
+/// Implementations of the [`From`] trait for converting a [`Fixed`](crate::Fixed) to a
+/// [`Rational`](malachite_q::Rational).
+pub mod rational_from_fixed;
+/// Implementations of traits for converting [`Fixed`](crate::Fixed)s to and from strings.
+pub mod string;
+/// Functions for getting the raw value of a [`Fixed`](crate::Fixed).
+pub mod to_raw;
@@ -0,0 +1,142 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Fixed;
+use core::cmp::Ordering;
+use malachite_base::num::conversion::traits::{ConvertibleFrom, RoundingFrom};
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_q::conversion::primitive_float_from_rational::FloatConversionError;
+use malachite_q::Rational;
+
+macro_rules! float_impls {
+    ($f: ident) => {
+        impl<const FRAC_BITS: u64> RoundingFrom<Fixed<FRAC_BITS>> for $f {
+            /// Converts a [`Fixed`] to a value of a primitive float according to a specified
+            /// [`RoundingMode`], taking the [`Fixed`] by value. An [`Ordering`] is also returned,
+            /// indicating whether the returned value is less than, equal to, or greater than the
+            /// original value.
+            ///
+            /// The rounding behaves as in the conversion from a
+            /// [`Rational`](malachite_q::Rational); in particular, values too large to be
+            /// represented are rounded to the largest finite float or to infinity, depending on the
+            /// rounding mode.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is
+            /// `value.raw().significant_bits()`.
+            ///
+            /// # Panics
+            /// Panics if the [`Fixed`] is not exactly equal to any float and `rm` is `Exact`.
+            ///
+            /// # Examples
+            /// See [here](super::primitive_float_from_fixed#rounding_from).
+            #[inline]
+            fn rounding_from(value: Fixed<FRAC_BITS>, rm: RoundingMode) -> ($f, Ordering) {
+                $f::rounding_from(Rational::from(value), rm)
+            }
+        }
+
+        impl<const FRAC_BITS: u64> RoundingFrom<&Fixed<FRAC_BITS>> for $f {
+            /// Converts a [`Fixed`] to a value of a primitive float according to a specified
+            /// [`RoundingMode`], taking the [`Fixed`] by reference. An [`Ordering`] is also
+            /// returned, indicating whether the returned value is less than, equal to, or greater
+            /// than the original value.
+            ///
+            /// The rounding behaves as in the conversion from a
+            /// [`Rational`](malachite_q::Rational); in particular, values too large to be
+            /// represented are rounded to the largest finite float or to infinity, depending on the
+            /// rounding mode.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is
+            /// `value.raw().significant_bits()`.
+            ///
+            /// # Panics
+            /// Panics if the [`Fixed`] is not exactly equal to any float and `rm` is `Exact`.
+            ///
+            /// # Examples
+            /// See [here](super::primitive_float_from_fixed#rounding_from).
+            #[inline]
+            fn rounding_from(value: &Fixed<FRAC_BITS>, rm: RoundingMode) -> ($f, Ordering) {
+                $f::rounding_from(Rational::from(value), rm)
+            }
+        }
+
+        impl<const FRAC_BITS: u64> TryFrom<Fixed<FRAC_BITS>> for $f {
+            type Error = FloatConversionError;
+
+            /// Converts a [`Fixed`] to a primitive float, taking the [`Fixed`] by value. If the
+            /// input isn't exactly equal to any float, an error is returned.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is
+            /// `value.raw().significant_bits()`.
+            ///
+            /// # Examples
+            /// See [here](super::primitive_float_from_fixed#try_from).
+            #[inline]
+            fn try_from(value: Fixed<FRAC_BITS>) -> Result<$f, Self::Error> {
+                $f::try_from(Rational::from(value))
+            }
+        }
+
+        impl<const FRAC_BITS: u64> TryFrom<&Fixed<FRAC_BITS>> for $f {
+            type Error = FloatConversionError;
+
+            /// Converts a [`Fixed`] to a primitive float, taking the [`Fixed`] by reference. If the
+            /// input isn't exactly equal to any float, an error is returned.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is
+            /// `value.raw().significant_bits()`.
+            ///
+            /// # Examples
+            /// See [here](super::primitive_float_from_fixed#try_from).
+            #[inline]
+            fn try_from(value: &Fixed<FRAC_BITS>) -> Result<$f, Self::Error> {
+                $f::try_from(Rational::from(value))
+            }
+        }
+
+        impl<const FRAC_BITS: u64> ConvertibleFrom<&Fixed<FRAC_BITS>> for $f {
+            /// Determines whether a [`Fixed`] can be exactly converted to a primitive float.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is
+            /// `value.raw().significant_bits()`.
+            ///
+            /// # Examples
+            /// See [here](super::primitive_float_from_fixed#convertible_from).
+            #[inline]
+            fn convertible_from(value: &Fixed<FRAC_BITS>) -> bool {
+                $f::convertible_from(Rational::from(value))
+            }
+        }
+    };
+}
+apply_to_primitive_floats!(float_impls);
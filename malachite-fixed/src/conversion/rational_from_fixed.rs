@@ -0,0 +1,62 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Fixed;
+use malachite_q::Rational;
+
+impl<const FRAC_BITS: u64> From<Fixed<FRAC_BITS>> for Rational {
+    /// Converts a [`Fixed`] to a [`Rational`], taking the [`Fixed`] by value. The conversion is
+    /// always exact.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `x.raw().significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_fixed::Fixed;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(Rational::from("1.5".parse::<Fixed<4>>().unwrap()).to_string(), "3/2");
+    /// assert_eq!(Rational::from("-0.0625".parse::<Fixed<4>>().unwrap()).to_string(), "-1/16");
+    /// assert_eq!(Rational::from("12".parse::<Fixed<4>>().unwrap()).to_string(), "12");
+    /// ```
+    #[inline]
+    fn from(x: Fixed<FRAC_BITS>) -> Rational {
+        Rational::from(x.raw) >> FRAC_BITS
+    }
+}
+
+impl<const FRAC_BITS: u64> From<&Fixed<FRAC_BITS>> for Rational {
+    /// Converts a [`Fixed`] to a [`Rational`], taking the [`Fixed`] by reference. The conversion is
+    /// always exact.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `x.raw().significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_fixed::Fixed;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(Rational::from(&"1.5".parse::<Fixed<4>>().unwrap()).to_string(), "3/2");
+    /// assert_eq!(Rational::from(&"-0.0625".parse::<Fixed<4>>().unwrap()).to_string(), "-1/16");
+    /// assert_eq!(Rational::from(&"12".parse::<Fixed<4>>().unwrap()).to_string(), "12");
+    /// ```
+    #[inline]
+    fn from(x: &Fixed<FRAC_BITS>) -> Rational {
+        Rational::from(&x.raw) >> FRAC_BITS
+    }
+}
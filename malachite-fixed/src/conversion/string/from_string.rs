@@ -0,0 +1,87 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Fixed;
+use alloc::string::String;
+use core::str::FromStr;
+use malachite_base::num::arithmetic::traits::Pow;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_q::Rational;
+
+impl<const FRAC_BITS: u64> FromStr for Fixed<FRAC_BITS> {
+    type Err = ();
+
+    /// Converts a string to a [`Fixed`].
+    ///
+    /// The string consists of an optional `-` sign, then digits with an optional decimal point.
+    /// There must be at least one digit before or after the decimal point. The value must be
+    /// exactly representable with `FRAC_BITS` fractional bits; for example, "0.1" can't be
+    /// converted to any [`Fixed`], and "0.125" can only be converted to a [`Fixed`] with at least 3
+    /// fractional bits.
+    ///
+    /// If the string does not represent a valid [`Fixed`], an `Err` is returned.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `s.len()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_fixed::Fixed;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!(*Fixed::<4>::from_str("1.5").unwrap().raw(), 24);
+    /// assert_eq!(*Fixed::<4>::from_str("-007").unwrap().raw(), -112);
+    /// assert_eq!(*Fixed::<4>::from_str(".0625").unwrap().raw(), 1);
+    /// assert_eq!(*Fixed::<4>::from_str("2.500").unwrap().raw(), 40);
+    ///
+    /// assert!(Fixed::<4>::from_str("0.03125").is_err());
+    /// assert!(Fixed::<4>::from_str("0.1").is_err());
+    /// assert!(Fixed::<4>::from_str("").is_err());
+    /// assert!(Fixed::<4>::from_str(".").is_err());
+    /// assert!(Fixed::<4>::from_str("1.2.3").is_err());
+    /// assert!(Fixed::<4>::from_str("--1").is_err());
+    /// assert!(Fixed::<4>::from_str("+1").is_err());
+    /// assert!(Fixed::<4>::from_str("1e3").is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Fixed<FRAC_BITS>, ()> {
+        let (abs_s, sign) = if let Some(abs_s) = s.strip_prefix('-') {
+            (abs_s, false)
+        } else {
+            (s, true)
+        };
+        let (int_digits, frac_digits) = if let Some(point_index) = abs_s.find('.') {
+            (&abs_s[..point_index], &abs_s[point_index + 1..])
+        } else {
+            (abs_s, "")
+        };
+        if int_digits.is_empty() && frac_digits.is_empty()
+            || !int_digits
+                .bytes()
+                .chain(frac_digits.bytes())
+                .all(|b| b.is_ascii_digit())
+        {
+            return Err(());
+        }
+        let mut digits = String::with_capacity(int_digits.len() + frac_digits.len());
+        digits.push_str(int_digits);
+        digits.push_str(frac_digits);
+        let numerator = Integer::from_sign_and_abs(sign, Natural::from_str(&digits)?);
+        let denominator = Natural::from(10u32).pow(u64::exact_from(frac_digits.len()));
+        Fixed::try_from(Rational::from_integers(
+            numerator,
+            Integer::from(denominator),
+        ))
+        .map_err(|_| ())
+    }
+}
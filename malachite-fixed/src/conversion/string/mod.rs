@@ -0,0 +1,12 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+/// An implementation of [`FromStr`](core::str::FromStr).
+pub mod from_string;
+/// Implementations of [`Display`](core::fmt::Display) and [`Debug`].
+pub mod to_string;
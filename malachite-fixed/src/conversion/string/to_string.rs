@@ -0,0 +1,110 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Fixed;
+use alloc::string::ToString;
+use core::fmt::{Debug, Display, Formatter, Result, Write};
+use malachite_base::num::arithmetic::traits::Pow;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_nz::natural::Natural;
+
+impl<const FRAC_BITS: u64> Display for Fixed<FRAC_BITS> {
+    /// Converts a [`Fixed`] to a [`String`](alloc::string::String).
+    ///
+    /// The result is the exact value of the [`Fixed`], written in plain decimal notation. Since
+    /// $2^{-F} = 5^F \cdot 10^{-F}$, every [`Fixed`] has a terminating decimal expansion with at
+    /// most $F$ digits after the decimal point. Trailing zeros are omitted, and so is the decimal
+    /// point if the [`Fixed`] is an integer.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.raw().significant_bits() + F`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_fixed::Fixed;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Fixed::<4>::ZERO.to_string(), "0");
+    /// assert_eq!(Fixed::<4>::from_raw(Integer::from(24)).to_string(), "1.5");
+    /// assert_eq!(Fixed::<4>::from_raw(Integer::from(-1)).to_string(), "-0.0625");
+    /// assert_eq!(Fixed::<4>::from_raw(Integer::from(-32)).to_string(), "-2");
+    /// assert_eq!(
+    ///     Fixed::<10>::from_raw(Integer::from(1)).to_string(),
+    ///     "0.0009765625"
+    /// );
+    /// ```
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        // Only the fraction bits up to the lowest set bit produce nonzero decimal digits.
+        let Some(zeros) = self.raw.trailing_zeros() else {
+            return f.write_char('0');
+        };
+        if zeros >= FRAC_BITS {
+            return Display::fmt(&(&self.raw >> FRAC_BITS), f);
+        }
+        if self.raw < 0u32 {
+            f.write_char('-')?;
+        }
+        let frac_digits = FRAC_BITS - zeros;
+        let digits = ((self.raw.unsigned_abs_ref() >> zeros)
+            * Natural::from(5u32).pow(frac_digits))
+        .to_string();
+        let len = u64::exact_from(digits.len());
+        if len > frac_digits {
+            let point_index = usize::exact_from(len - frac_digits);
+            f.write_str(&digits[..point_index])?;
+            f.write_char('.')?;
+            f.write_str(&digits[point_index..])
+        } else {
+            f.write_str("0.")?;
+            for _ in 0..frac_digits - len {
+                f.write_char('0')?;
+            }
+            f.write_str(&digits)
+        }
+    }
+}
+
+impl<const FRAC_BITS: u64> Debug for Fixed<FRAC_BITS> {
+    /// Converts a [`Fixed`] to a [`String`](alloc::string::String).
+    ///
+    /// This is the same implementation as for [`Display`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.raw().significant_bits() + F`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_fixed::Fixed;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Fixed::<4>::ZERO.to_debug_string(), "0");
+    /// assert_eq!(
+    ///     Fixed::<4>::from_raw(Integer::from(24)).to_debug_string(),
+    ///     "1.5"
+    /// );
+    /// assert_eq!(
+    ///     Fixed::<4>::from_raw(Integer::from(-1)).to_debug_string(),
+    ///     "-0.0625"
+    /// );
+    /// ```
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        Display::fmt(self, f)
+    }
+}
@@ -0,0 +1,48 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Fixed;
+use malachite_nz::integer::Integer;
+
+impl<const FRAC_BITS: u64> Fixed<FRAC_BITS> {
+    /// Returns a reference to the raw value of a [`Fixed`]; that is, the [`Fixed`] multiplied by
+    /// $2^F$.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_fixed::Fixed;
+    ///
+    /// assert_eq!(*"1.5".parse::<Fixed<4>>().unwrap().raw(), 24);
+    /// assert_eq!(*"-0.0625".parse::<Fixed<4>>().unwrap().raw(), -1);
+    /// ```
+    #[inline]
+    pub const fn raw(&self) -> &Integer {
+        &self.raw
+    }
+
+    /// Returns the raw value of a [`Fixed`], taking the [`Fixed`] by value; that is, the [`Fixed`]
+    /// multiplied by $2^F$.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_fixed::Fixed;
+    ///
+    /// assert_eq!("1.5".parse::<Fixed<4>>().unwrap().into_raw(), 24);
+    /// assert_eq!("-0.0625".parse::<Fixed<4>>().unwrap().into_raw(), -1);
+    /// ```
+    #[inline]
+    pub fn into_raw(self) -> Integer {
+        self.raw
+    }
+}
@@ -0,0 +1,146 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+//! This crate defines [`Fixed`]s, arbitrary-width binary fixed-point numbers. A [`Fixed<F>`] is
+//! an [`Integer`] $r$, the _raw value_, and its value is $r \cdot 2^{-F}$, where the number of
+//! fraction bits $F$ is a compile-time constant. Since the integer part is arbitrarily large, a
+//! [`Fixed`] never overflows, and it can model fixed-point hardware of any width bit-exactly.
+//! - Addition, subtraction, and negation are exact, and comparison and hashing work directly on the
+//!   raw values.
+//! - The exact product or quotient of two [`Fixed<F>`]s generally has more than $F$ fraction bits,
+//!   so multiplication and division take a [`RoundingMode`] and return an [`Ordering`] indicating
+//!   whether the result is less than, equal to, or greater than the exact value.
+//! - [`Fixed`]s can be converted exactly to [`Rational`]s and [`Float`]s, and from [`Rational`]s,
+//!   [`Float`]s, and primitive floats either exactly or with a specified rounding mode. They can
+//!   also be converted to primitive floats and [`Integer`]s with a specified rounding mode.
+//! - [`Fixed`]s are formatted as exact decimal numbers; since $2^{-F}$ has exactly $F$ digits
+//!   after the decimal point, no rounding is needed.
+//!
+//! # Features
+//! - `32_bit_limbs`: Sets the type of [`Limb`](malachite_nz#limbs) to [`u32`] instead of the
+//!   default, [`u64`].
+//! - `random`: This feature provides some functions for randomly generating values. It is off by
+//!   default to avoid pulling in some extra dependencies.
+//! - `test_build`: A large proportion of the code in this crate is only used for testing. For a
+//!   typical user, building this code would result in an unnecessarily long compilation time and
+//!   an unnecessarily large binary. My solution is to only build this code when the `test_build`
+//!   feature is enabled. If you want to run unit tests, you must enable `test_build`. However,
+//!   doctests don't require it, since they only test the public interface. Enabling this feature
+//!   also enables `random`.
+//!
+//! [`Float`]: malachite_float::Float
+//! [`Integer`]: malachite_nz::integer::Integer
+//! [`Ordering`]: core::cmp::Ordering
+//! [`Rational`]: malachite_q::Rational
+//! [`RoundingMode`]: malachite_base::rounding_modes::RoundingMode
+
+#![allow(
+    unstable_name_collisions,
+    clippy::assertions_on_constants,
+    clippy::cognitive_complexity,
+    clippy::many_single_char_names,
+    clippy::range_plus_one,
+    clippy::suspicious_arithmetic_impl,
+    clippy::suspicious_op_assign_impl,
+    clippy::too_many_arguments,
+    clippy::type_complexity,
+    clippy::upper_case_acronyms
+)]
+#![warn(
+    clippy::cast_lossless,
+    clippy::explicit_into_iter_loop,
+    clippy::explicit_iter_loop,
+    clippy::filter_map_next,
+    clippy::large_digit_groups,
+    clippy::manual_filter_map,
+    clippy::manual_find_map,
+    clippy::map_flatten,
+    clippy::map_unwrap_or,
+    clippy::match_same_arms,
+    clippy::missing_const_for_fn,
+    clippy::mut_mut,
+    clippy::needless_borrow,
+    clippy::needless_continue,
+    clippy::needless_pass_by_value,
+    clippy::print_stdout,
+    clippy::redundant_closure_for_method_calls,
+    clippy::single_match_else,
+    clippy::trait_duplication_in_bounds,
+    clippy::type_repetition_in_bounds,
+    clippy::uninlined_format_args,
+    clippy::unused_self,
+    clippy::if_not_else,
+    clippy::manual_assert,
+    clippy::range_plus_one,
+    clippy::redundant_else,
+    clippy::semicolon_if_nothing_returned,
+    clippy::cloned_instead_of_copied,
+    clippy::flat_map_option,
+    clippy::unnecessary_wraps,
+    clippy::unnested_or_patterns,
+    clippy::trivially_copy_pass_by_ref
+)]
+#![cfg_attr(not(any(feature = "test_build", feature = "random")), no_std)]
+
+extern crate alloc;
+
+#[macro_use]
+extern crate malachite_base;
+extern crate malachite_float;
+extern crate malachite_nz;
+extern crate malachite_q;
+
+use malachite_base::num::basic::traits::Zero;
+use malachite_nz::integer::Integer;
+
+/// An arbitrary-width binary fixed-point number with `FRAC_BITS` fraction bits.
+///
+/// A [`Fixed<F>`] with raw value $r$ represents $r \cdot 2^{-F}$. Every value is a multiple of
+/// $2^{-F}$, and every multiple of $2^{-F}$ has exactly one representation, so equality, ordering,
+/// and hashing are the same as for the raw values.
+///
+/// Because the raw value is an [`Integer`], the integer part of a [`Fixed`] is unbounded. This
+/// makes [`Fixed`] suitable for simulating fixed-point hardware of any width: the rounding behavior
+/// of every operation is explicit, and values that would overflow a fixed-width type can be
+/// detected instead of silently wrapping.
+#[derive(Clone, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Fixed<const FRAC_BITS: u64> {
+    pub(crate) raw: Integer,
+}
+
+impl<const FRAC_BITS: u64> Fixed<FRAC_BITS> {
+    /// The number of fraction bits of this [`Fixed`] type.
+    pub const FRAC_BITS: u64 = FRAC_BITS;
+
+    // Returns true iff `self` is valid. To be valid, its raw value must be valid. All `Fixed`s must
+    // be valid.
+    #[cfg(feature = "test_build")]
+    pub fn is_valid(&self) -> bool {
+        self.raw.is_valid()
+    }
+}
+
+/// The constant 0.
+impl<const FRAC_BITS: u64> Zero for Fixed<FRAC_BITS> {
+    const ZERO: Fixed<FRAC_BITS> = Fixed { raw: Integer::ZERO };
+}
+
+impl<const FRAC_BITS: u64> Default for Fixed<FRAC_BITS> {
+    /// The default value of a [`Fixed`], 0.
+    fn default() -> Fixed<FRAC_BITS> {
+        Fixed::ZERO
+    }
+}
+
+/// Traits for arithmetic.
+pub mod arithmetic;
+/// Functions and traits for converting to and from [`Fixed`]s, including converting to strings.
+pub mod conversion;
+
+#[cfg(feature = "test_build")]
+pub mod test_util;
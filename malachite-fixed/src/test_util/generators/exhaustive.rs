@@ -0,0 +1,103 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::test_util::extra_variadic::exhaustive_triples_from_single;
+use crate::test_util::generators::{div_is_exact, mul_is_exact, rational_is_exact};
+use crate::Fixed;
+use malachite_base::num::arithmetic::traits::{DivisibleByPowerOf2, Sign};
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_base::test_util::generators::common::It;
+use malachite_base::tuples::exhaustive::{
+    exhaustive_pairs, exhaustive_pairs_from_single, exhaustive_triples,
+};
+use malachite_nz::integer::exhaustive::exhaustive_integers;
+use malachite_q::exhaustive::exhaustive_rationals;
+use malachite_q::Rational;
+use std::cmp::Ordering::*;
+
+fn exhaustive_fixeds<const F: u64>() -> impl Clone + Iterator<Item = Fixed<F>> {
+    exhaustive_integers().map(|raw| Fixed { raw })
+}
+
+// -- Fixed --
+
+pub fn exhaustive_fixed_gen<const F: u64>() -> It<Fixed<F>> {
+    Box::new(exhaustive_fixeds())
+}
+
+// -- (Fixed, Fixed) --
+
+pub fn exhaustive_fixed_pair_gen<const F: u64>() -> It<(Fixed<F>, Fixed<F>)> {
+    Box::new(exhaustive_pairs_from_single(exhaustive_fixeds()))
+}
+
+pub fn exhaustive_fixed_pair_gen_var_1<const F: u64>() -> It<(Fixed<F>, Fixed<F>)> {
+    Box::new(exhaustive_pairs(
+        exhaustive_fixeds(),
+        exhaustive_fixeds().filter(|y: &Fixed<F>| y.sign() != Equal),
+    ))
+}
+
+// -- (Fixed, Fixed, Fixed) --
+
+pub fn exhaustive_fixed_triple_gen<const F: u64>() -> It<(Fixed<F>, Fixed<F>, Fixed<F>)> {
+    Box::new(exhaustive_triples_from_single(exhaustive_fixeds()))
+}
+
+// -- (Fixed, Fixed, RoundingMode) --
+
+pub fn exhaustive_fixed_fixed_rounding_mode_triple_gen_var_1<const F: u64>(
+) -> It<(Fixed<F>, Fixed<F>, RoundingMode)> {
+    Box::new(
+        exhaustive_triples(
+            exhaustive_fixeds(),
+            exhaustive_fixeds(),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|(x, y, rm)| *rm != Exact || mul_is_exact(x, y)),
+    )
+}
+
+pub fn exhaustive_fixed_fixed_rounding_mode_triple_gen_var_2<const F: u64>(
+) -> It<(Fixed<F>, Fixed<F>, RoundingMode)> {
+    Box::new(
+        exhaustive_triples(
+            exhaustive_fixeds(),
+            exhaustive_fixeds().filter(|y: &Fixed<F>| y.sign() != Equal),
+            exhaustive_rounding_modes(),
+        )
+        .filter(|(x, y, rm)| *rm != Exact || div_is_exact(x, y)),
+    )
+}
+
+// -- (Fixed, RoundingMode) --
+
+pub fn exhaustive_fixed_rounding_mode_pair_gen_var_1<const F: u64>() -> It<(Fixed<F>, RoundingMode)>
+{
+    Box::new(
+        exhaustive_pairs(exhaustive_fixeds(), exhaustive_rounding_modes())
+            .filter(|(x, rm): &(Fixed<F>, _)| *rm != Exact || x.raw.divisible_by_power_of_2(F)),
+    )
+}
+
+// -- Rational --
+
+pub fn exhaustive_rational_gen() -> It<Rational> {
+    Box::new(exhaustive_rationals())
+}
+
+// -- (Rational, RoundingMode) --
+
+pub fn exhaustive_rational_rounding_mode_pair_gen_var_1<const F: u64>(
+) -> It<(Rational, RoundingMode)> {
+    Box::new(
+        exhaustive_pairs(exhaustive_rationals(), exhaustive_rounding_modes())
+            .filter(|(x, rm)| *rm != Exact || rational_is_exact::<F>(x)),
+    )
+}
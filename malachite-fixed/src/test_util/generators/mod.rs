@@ -0,0 +1,119 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::test_util::generators::exhaustive::*;
+use crate::test_util::generators::random::*;
+use crate::Fixed;
+use malachite_base::num::arithmetic::traits::{DivisibleBy, DivisibleByPowerOf2};
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_base::test_util::generators::common::Generator;
+use malachite_q::Rational;
+
+// Returns whether `x * y` is a multiple of 2^(-`FRAC_BITS`).
+pub(crate) fn mul_is_exact<const FRAC_BITS: u64>(
+    x: &Fixed<FRAC_BITS>,
+    y: &Fixed<FRAC_BITS>,
+) -> bool {
+    (&x.raw * &y.raw).divisible_by_power_of_2(FRAC_BITS)
+}
+
+// Returns whether `x / y` is a multiple of 2^(-`FRAC_BITS`).
+pub(crate) fn div_is_exact<const FRAC_BITS: u64>(
+    x: &Fixed<FRAC_BITS>,
+    y: &Fixed<FRAC_BITS>,
+) -> bool {
+    (&x.raw << FRAC_BITS).divisible_by(&y.raw)
+}
+
+// Returns whether `x` is a multiple of 2^(-`FRAC_BITS`).
+pub(crate) fn rational_is_exact<const FRAC_BITS: u64>(x: &Rational) -> bool {
+    (x << FRAC_BITS).denominator_ref() == &1u32
+}
+
+// -- Fixed --
+
+pub fn fixed_gen<const F: u64>() -> Generator<Fixed<F>> {
+    Generator::new_no_special(&exhaustive_fixed_gen::<F>, &random_fixed_gen::<F>)
+}
+
+// -- (Fixed, Fixed) --
+
+pub fn fixed_pair_gen<const F: u64>() -> Generator<(Fixed<F>, Fixed<F>)> {
+    Generator::new_no_special(&exhaustive_fixed_pair_gen::<F>, &random_fixed_pair_gen::<F>)
+}
+
+// All pairs of `Fixed`s where the second is nonzero.
+pub fn fixed_pair_gen_var_1<const F: u64>() -> Generator<(Fixed<F>, Fixed<F>)> {
+    Generator::new_no_special(
+        &exhaustive_fixed_pair_gen_var_1::<F>,
+        &random_fixed_pair_gen_var_1::<F>,
+    )
+}
+
+// -- (Fixed, Fixed, Fixed) --
+
+pub fn fixed_triple_gen<const F: u64>() -> Generator<(Fixed<F>, Fixed<F>, Fixed<F>)> {
+    Generator::new_no_special(
+        &exhaustive_fixed_triple_gen::<F>,
+        &random_fixed_triple_gen::<F>,
+    )
+}
+
+// -- (Fixed, Fixed, RoundingMode) --
+
+// All triples of two `Fixed`s $x$ and $y$ and a `RoundingMode` $m$, where $m$ is not `Exact` unless
+// $xy$ is a multiple of $2^{-F}$.
+pub fn fixed_fixed_rounding_mode_triple_gen_var_1<const F: u64>(
+) -> Generator<(Fixed<F>, Fixed<F>, RoundingMode)> {
+    Generator::new_no_special(
+        &exhaustive_fixed_fixed_rounding_mode_triple_gen_var_1::<F>,
+        &random_fixed_fixed_rounding_mode_triple_gen_var_1::<F>,
+    )
+}
+
+// All triples of two `Fixed`s $x$ and $y$ and a `RoundingMode` $m$, where $y$ is nonzero, and $m$
+// is not `Exact` unless $x/y$ is a multiple of $2^{-F}$.
+pub fn fixed_fixed_rounding_mode_triple_gen_var_2<const F: u64>(
+) -> Generator<(Fixed<F>, Fixed<F>, RoundingMode)> {
+    Generator::new_no_special(
+        &exhaustive_fixed_fixed_rounding_mode_triple_gen_var_2::<F>,
+        &random_fixed_fixed_rounding_mode_triple_gen_var_2::<F>,
+    )
+}
+
+// -- (Fixed, RoundingMode) --
+
+// All pairs of a `Fixed` $x$ and a `RoundingMode` $m$, where $m$ is not `Exact` unless $x$ is an
+// integer.
+pub fn fixed_rounding_mode_pair_gen_var_1<const F: u64>() -> Generator<(Fixed<F>, RoundingMode)> {
+    Generator::new_no_special(
+        &exhaustive_fixed_rounding_mode_pair_gen_var_1::<F>,
+        &random_fixed_rounding_mode_pair_gen_var_1::<F>,
+    )
+}
+
+// -- Rational --
+
+pub fn rational_gen() -> Generator<Rational> {
+    Generator::new_no_special(&exhaustive_rational_gen, &random_rational_gen)
+}
+
+// -- (Rational, RoundingMode) --
+
+// All pairs of a `Rational` $x$ and a `RoundingMode` $m$, where $m$ is not `Exact` unless $x$ is a
+// multiple of $2^{-F}$.
+pub fn rational_rounding_mode_pair_gen_var_1<const F: u64>() -> Generator<(Rational, RoundingMode)>
+{
+    Generator::new_no_special(
+        &exhaustive_rational_rounding_mode_pair_gen_var_1::<F>,
+        &random_rational_rounding_mode_pair_gen_var_1::<F>,
+    )
+}
+
+pub mod exhaustive;
+pub mod random;
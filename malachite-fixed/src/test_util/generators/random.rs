@@ -0,0 +1,147 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::test_util::extra_variadic::{random_triples, random_triples_from_single};
+use crate::test_util::generators::{div_is_exact, mul_is_exact, rational_is_exact};
+use crate::Fixed;
+use malachite_base::num::arithmetic::traits::{DivisibleByPowerOf2, Sign};
+use malachite_base::random::{Seed, EXAMPLE_SEED};
+use malachite_base::rounding_modes::random::random_rounding_modes;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_base::test_util::generators::common::{GenConfig, It};
+use malachite_base::tuples::random::{random_pairs, random_pairs_from_single};
+use malachite_nz::integer::random::random_integers;
+use malachite_q::random::random_rationals;
+use malachite_q::Rational;
+use std::cmp::Ordering::*;
+
+fn random_fixeds<const F: u64>(seed: Seed, config: &GenConfig) -> impl Iterator<Item = Fixed<F>> {
+    random_integers(
+        seed,
+        config.get_or("mean_bits_n", 64),
+        config.get_or("mean_bits_d", 1),
+    )
+    .map(|raw| Fixed { raw })
+}
+
+fn random_nonzero_fixeds<const F: u64>(
+    seed: Seed,
+    config: &GenConfig,
+) -> impl Iterator<Item = Fixed<F>> {
+    random_fixeds(seed, config).filter(|y: &Fixed<F>| y.sign() != Equal)
+}
+
+// -- Fixed --
+
+pub fn random_fixed_gen<const F: u64>(config: &GenConfig) -> It<Fixed<F>> {
+    Box::new(random_fixeds(EXAMPLE_SEED, config))
+}
+
+// -- (Fixed, Fixed) --
+
+pub fn random_fixed_pair_gen<const F: u64>(config: &GenConfig) -> It<(Fixed<F>, Fixed<F>)> {
+    Box::new(random_pairs_from_single(random_fixeds(
+        EXAMPLE_SEED,
+        config,
+    )))
+}
+
+pub fn random_fixed_pair_gen_var_1<const F: u64>(config: &GenConfig) -> It<(Fixed<F>, Fixed<F>)> {
+    Box::new(random_pairs(
+        EXAMPLE_SEED,
+        &|seed| random_fixeds(seed, config),
+        &|seed| random_nonzero_fixeds(seed, config),
+    ))
+}
+
+// -- (Fixed, Fixed, Fixed) --
+
+pub fn random_fixed_triple_gen<const F: u64>(
+    config: &GenConfig,
+) -> It<(Fixed<F>, Fixed<F>, Fixed<F>)> {
+    Box::new(random_triples_from_single(random_fixeds(
+        EXAMPLE_SEED,
+        config,
+    )))
+}
+
+// -- (Fixed, Fixed, RoundingMode) --
+
+pub fn random_fixed_fixed_rounding_mode_triple_gen_var_1<const F: u64>(
+    config: &GenConfig,
+) -> It<(Fixed<F>, Fixed<F>, RoundingMode)> {
+    Box::new(
+        random_triples(
+            EXAMPLE_SEED,
+            &|seed| random_fixeds(seed, config),
+            &|seed| random_fixeds(seed, config),
+            &random_rounding_modes,
+        )
+        .filter(|(x, y, rm)| *rm != Exact || mul_is_exact(x, y)),
+    )
+}
+
+pub fn random_fixed_fixed_rounding_mode_triple_gen_var_2<const F: u64>(
+    config: &GenConfig,
+) -> It<(Fixed<F>, Fixed<F>, RoundingMode)> {
+    Box::new(
+        random_triples(
+            EXAMPLE_SEED,
+            &|seed| random_fixeds(seed, config),
+            &|seed| random_nonzero_fixeds(seed, config),
+            &random_rounding_modes,
+        )
+        .filter(|(x, y, rm)| *rm != Exact || div_is_exact(x, y)),
+    )
+}
+
+// -- (Fixed, RoundingMode) --
+
+pub fn random_fixed_rounding_mode_pair_gen_var_1<const F: u64>(
+    config: &GenConfig,
+) -> It<(Fixed<F>, RoundingMode)> {
+    Box::new(
+        random_pairs(
+            EXAMPLE_SEED,
+            &|seed| random_fixeds(seed, config),
+            &random_rounding_modes,
+        )
+        .filter(|(x, rm): &(Fixed<F>, _)| *rm != Exact || x.raw.divisible_by_power_of_2(F)),
+    )
+}
+
+// -- Rational --
+
+pub fn random_rational_gen(config: &GenConfig) -> It<Rational> {
+    Box::new(random_rationals(
+        EXAMPLE_SEED,
+        config.get_or("mean_bits_n", 64),
+        config.get_or("mean_bits_d", 1),
+    ))
+}
+
+// -- (Rational, RoundingMode) --
+
+pub fn random_rational_rounding_mode_pair_gen_var_1<const F: u64>(
+    config: &GenConfig,
+) -> It<(Rational, RoundingMode)> {
+    Box::new(
+        random_pairs(
+            EXAMPLE_SEED,
+            &|seed| {
+                random_rationals(
+                    seed,
+                    config.get_or("mean_bits_n", 64),
+                    config.get_or("mean_bits_d", 1),
+                )
+            },
+            &random_rounding_modes,
+        )
+        .filter(|(x, rm)| *rm != Exact || rational_is_exact::<F>(x)),
+    )
+}
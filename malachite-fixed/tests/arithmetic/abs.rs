@@ -0,0 +1,68 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{Abs, AbsAssign, Sign};
+use malachite_fixed::test_util::generators::fixed_gen;
+use malachite_fixed::Fixed;
+use malachite_q::Rational;
+use std::cmp::Ordering::*;
+use std::str::FromStr;
+
+#[test]
+fn test_abs() {
+    let test = |s, out| {
+        let x = Fixed::<4>::from_str(s).unwrap();
+
+        let abs = x.clone().abs();
+        assert!(abs.is_valid());
+        assert_eq!(abs.to_string(), out);
+
+        let abs = (&x).abs();
+        assert!(abs.is_valid());
+        assert_eq!(abs.to_string(), out);
+
+        let mut abs = x.clone();
+        abs.abs_assign();
+        assert!(abs.is_valid());
+        assert_eq!(abs.to_string(), out);
+    };
+    test("0", "0");
+    test("1.5", "1.5");
+    test("-1.5", "1.5");
+    test("-0.0625", "0.0625");
+    test(
+        "-123456789012345678901234567890.9375",
+        "123456789012345678901234567890.9375",
+    );
+}
+
+fn abs_properties_helper<const F: u64>() {
+    fixed_gen::<F>().test_properties(|x| {
+        let abs = x.clone().abs();
+        assert!(abs.is_valid());
+        let abs_alt = (&x).abs();
+        assert!(abs_alt.is_valid());
+        assert_eq!(abs_alt, abs);
+        let mut mut_x = x.clone();
+        mut_x.abs_assign();
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x, abs);
+
+        assert_eq!((&abs).abs(), abs);
+        assert_ne!(abs.sign(), Less);
+        assert_eq!(abs == x, x.sign() != Less);
+        assert_eq!(Rational::from(&abs), Rational::from(&x).abs());
+    });
+}
+
+#[test]
+fn abs_properties() {
+    abs_properties_helper::<0>();
+    abs_properties_helper::<4>();
+    abs_properties_helper::<100>();
+}
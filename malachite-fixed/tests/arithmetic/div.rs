@@ -0,0 +1,163 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{DivRound, DivRoundAssign};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::RoundingFrom;
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::rounding_modes::RoundingMode::*;
+use malachite_fixed::test_util::generators::{
+    fixed_fixed_rounding_mode_triple_gen_var_2, fixed_pair_gen_var_1,
+};
+use malachite_fixed::Fixed;
+use malachite_nz::integer::Integer;
+use malachite_q::Rational;
+use std::cmp::Ordering::{self, *};
+use std::str::FromStr;
+
+#[test]
+fn test_div_round() {
+    let test = |s, t, rm, out, o_out: Ordering| {
+        let u = Fixed::<4>::from_str(s).unwrap();
+        let v = Fixed::<4>::from_str(t).unwrap();
+
+        let (q, o) = u.clone().div_round(v.clone(), rm);
+        assert_eq!(q.to_string(), out);
+        assert!(q.is_valid());
+        assert_eq!(o, o_out);
+
+        let (q, o) = u.clone().div_round(&v, rm);
+        assert_eq!(q.to_string(), out);
+        assert!(q.is_valid());
+        assert_eq!(o, o_out);
+
+        let (q, o) = (&u).div_round(v.clone(), rm);
+        assert_eq!(q.to_string(), out);
+        assert!(q.is_valid());
+        assert_eq!(o, o_out);
+
+        let (q, o) = (&u).div_round(&v, rm);
+        assert_eq!(q.to_string(), out);
+        assert!(q.is_valid());
+        assert_eq!(o, o_out);
+
+        let mut q = u.clone();
+        assert_eq!(q.div_round_assign(v.clone(), rm), o_out);
+        assert_eq!(q.to_string(), out);
+        assert!(q.is_valid());
+
+        let mut q = u.clone();
+        assert_eq!(q.div_round_assign(&v, rm), o_out);
+        assert_eq!(q.to_string(), out);
+        assert!(q.is_valid());
+    };
+    test("0", "1", Exact, "0", Equal);
+    test("0", "-0.0625", Exact, "0", Equal);
+    test("1", "8", Exact, "0.125", Equal);
+    test("-7.5", "2.5", Exact, "-3", Equal);
+    test("1", "0.0625", Exact, "16", Equal);
+    test("1", "3", Floor, "0.3125", Less);
+    test("1", "3", Ceiling, "0.375", Greater);
+    test("1", "3", Nearest, "0.3125", Less);
+    test("2", "3", Nearest, "0.6875", Greater);
+    test("-1", "3", Floor, "-0.375", Less);
+    test("-1", "3", Ceiling, "-0.3125", Greater);
+    test("1", "32", Nearest, "0", Less);
+    test("1", "32", Up, "0.0625", Greater);
+    test("3", "32", Nearest, "0.125", Greater);
+    test("22", "7", Down, "3.125", Less);
+}
+
+#[test]
+#[should_panic]
+fn div_round_fail_1() {
+    Fixed::<4>::from_str("1")
+        .unwrap()
+        .div_round(Fixed::ZERO, Floor);
+}
+
+#[test]
+#[should_panic]
+fn div_round_fail_2() {
+    Fixed::<4>::from_str("1")
+        .unwrap()
+        .div_round(Fixed::from_str("3").unwrap(), Exact);
+}
+
+#[test]
+#[should_panic]
+fn div_round_ref_ref_fail() {
+    (&Fixed::<4>::from_str("1").unwrap()).div_round(&Fixed::ZERO, Floor);
+}
+
+#[test]
+#[should_panic]
+fn div_round_assign_fail() {
+    let mut x = Fixed::<4>::from_str("1").unwrap();
+    x.div_round_assign(Fixed::ZERO, Floor);
+}
+
+fn div_round_properties_helper<const F: u64>() {
+    fixed_fixed_rounding_mode_triple_gen_var_2::<F>().test_properties(|(x, y, rm)| {
+        let (q, o) = x.clone().div_round(y.clone(), rm);
+        assert!(q.is_valid());
+
+        let (q_alt, o_alt) = x.clone().div_round(&y, rm);
+        assert_eq!(q_alt, q);
+        assert_eq!(o_alt, o);
+        let (q_alt, o_alt) = (&x).div_round(y.clone(), rm);
+        assert_eq!(q_alt, q);
+        assert_eq!(o_alt, o);
+        let (q_alt, o_alt) = (&x).div_round(&y, rm);
+        assert_eq!(q_alt, q);
+        assert_eq!(o_alt, o);
+
+        let mut mut_x = x.clone();
+        assert_eq!(mut_x.div_round_assign(y.clone(), rm), o);
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x, q);
+        let mut mut_x = x.clone();
+        assert_eq!(mut_x.div_round_assign(&y, rm), o);
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x, q);
+
+        let exact = Rational::from(&x) / Rational::from(&y);
+        assert_eq!(Rational::from(&q).cmp(&exact), o);
+        assert_eq!(Fixed::rounding_from(&exact, rm), (q.clone(), o));
+        let (q_alt, o_alt) = (-&x).div_round(&y, -rm);
+        assert_eq!(-q_alt, q);
+        assert_eq!(o_alt.reverse(), o);
+        match rm {
+            Floor => assert_ne!(o, Greater),
+            Ceiling => assert_ne!(o, Less),
+            Exact => assert_eq!(o, Equal),
+            _ => {}
+        }
+        if o == Equal {
+            for rm in exhaustive_rounding_modes() {
+                let (q_alt, o_alt) = (&x).div_round(&y, rm);
+                assert_eq!(q_alt, q);
+                assert_eq!(o_alt, Equal);
+            }
+        }
+    });
+
+    fixed_pair_gen_var_1::<F>().test_properties(|(x, y)| {
+        let one = Fixed::<F>::from(Integer::ONE);
+        assert_eq!((&x).div_round(&one, Exact), (x.clone(), Equal));
+        assert_eq!((&y).div_round(&y, Exact), (one, Equal));
+        assert_eq!(Fixed::ZERO.div_round(&y, Exact), (Fixed::ZERO, Equal));
+    });
+}
+
+#[test]
+fn div_round_properties() {
+    div_round_properties_helper::<0>();
+    div_round_properties_helper::<4>();
+    div_round_properties_helper::<100>();
+}
@@ -0,0 +1,176 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::RoundingFrom;
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::rounding_modes::RoundingMode::*;
+use malachite_fixed::test_util::generators::{
+    fixed_fixed_rounding_mode_triple_gen_var_1, fixed_gen,
+};
+use malachite_fixed::Fixed;
+use malachite_nz::integer::Integer;
+use malachite_q::Rational;
+use std::cmp::Ordering::{self, *};
+use std::str::FromStr;
+
+#[test]
+fn test_mul_round() {
+    let test = |s, t, rm, out, o_out: Ordering| {
+        let u = Fixed::<4>::from_str(s).unwrap();
+        let v = Fixed::<4>::from_str(t).unwrap();
+
+        let (p, o) = u.clone().mul_round(v.clone(), rm);
+        assert_eq!(p.to_string(), out);
+        assert!(p.is_valid());
+        assert_eq!(o, o_out);
+
+        let (p, o) = u.clone().mul_round_val_ref(&v, rm);
+        assert_eq!(p.to_string(), out);
+        assert!(p.is_valid());
+        assert_eq!(o, o_out);
+
+        let (p, o) = u.mul_round_ref_val(v.clone(), rm);
+        assert_eq!(p.to_string(), out);
+        assert!(p.is_valid());
+        assert_eq!(o, o_out);
+
+        let (p, o) = u.mul_round_ref_ref(&v, rm);
+        assert_eq!(p.to_string(), out);
+        assert!(p.is_valid());
+        assert_eq!(o, o_out);
+
+        let mut p = u.clone();
+        assert_eq!(p.mul_round_assign(v.clone(), rm), o_out);
+        assert_eq!(p.to_string(), out);
+        assert!(p.is_valid());
+
+        let mut p = u.clone();
+        assert_eq!(p.mul_round_assign_ref(&v, rm), o_out);
+        assert_eq!(p.to_string(), out);
+        assert!(p.is_valid());
+    };
+    test("0", "1", Exact, "0", Equal);
+    test("1.5", "1.25", Exact, "1.875", Equal);
+    test("0.25", "0.25", Exact, "0.0625", Equal);
+    test("3.5", "-2", Exact, "-7", Equal);
+    test("0.0625", "0.5", Floor, "0", Less);
+    test("0.0625", "0.5", Ceiling, "0.0625", Greater);
+    test("0.0625", "0.5", Down, "0", Less);
+    test("0.0625", "0.5", Up, "0.0625", Greater);
+    test("0.0625", "0.5", Nearest, "0", Less);
+    test("0.1875", "0.5", Nearest, "0.125", Greater);
+    test("-0.0625", "0.5", Floor, "-0.0625", Less);
+    test("-0.0625", "0.5", Ceiling, "0", Greater);
+    test("-0.0625", "0.5", Nearest, "0", Greater);
+    test("0.0625", "0.0625", Nearest, "0", Less);
+    test("0.0625", "0.0625", Up, "0.0625", Greater);
+    test(
+        "123456789012345678901234567890.5",
+        "-0.0625",
+        Floor,
+        "-7716049313271604931327160493.1875",
+        Less,
+    );
+}
+
+#[test]
+#[should_panic]
+fn mul_round_fail() {
+    Fixed::<4>::from_str("0.0625")
+        .unwrap()
+        .mul_round(Fixed::from_str("0.5").unwrap(), Exact);
+}
+
+#[test]
+#[should_panic]
+fn mul_round_ref_ref_fail() {
+    Fixed::<4>::from_str("0.0625")
+        .unwrap()
+        .mul_round_ref_ref(&Fixed::from_str("0.5").unwrap(), Exact);
+}
+
+#[test]
+#[should_panic]
+fn mul_round_assign_fail() {
+    let mut x = Fixed::<4>::from_str("0.0625").unwrap();
+    x.mul_round_assign(Fixed::from_str("0.5").unwrap(), Exact);
+}
+
+fn mul_round_properties_helper<const F: u64>() {
+    fixed_fixed_rounding_mode_triple_gen_var_1::<F>().test_properties(|(x, y, rm)| {
+        let (p, o) = x.clone().mul_round(y.clone(), rm);
+        assert!(p.is_valid());
+
+        let (p_alt, o_alt) = x.clone().mul_round_val_ref(&y, rm);
+        assert_eq!(p_alt, p);
+        assert_eq!(o_alt, o);
+        let (p_alt, o_alt) = x.mul_round_ref_val(y.clone(), rm);
+        assert_eq!(p_alt, p);
+        assert_eq!(o_alt, o);
+        let (p_alt, o_alt) = x.mul_round_ref_ref(&y, rm);
+        assert_eq!(p_alt, p);
+        assert_eq!(o_alt, o);
+
+        let mut mut_x = x.clone();
+        assert_eq!(mut_x.mul_round_assign(y.clone(), rm), o);
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x, p);
+        let mut mut_x = x.clone();
+        assert_eq!(mut_x.mul_round_assign_ref(&y, rm), o);
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x, p);
+
+        let exact = Rational::from(&x) * Rational::from(&y);
+        assert_eq!(Rational::from(&p).cmp(&exact), o);
+        assert_eq!(Fixed::rounding_from(&exact, rm), (p.clone(), o));
+        let (p_alt, o_alt) = y.mul_round_ref_ref(&x, rm);
+        assert_eq!(p_alt, p);
+        assert_eq!(o_alt, o);
+        let (p_alt, o_alt) = (-&x).mul_round_ref_ref(&y, -rm);
+        assert_eq!(-p_alt, p);
+        assert_eq!(o_alt.reverse(), o);
+        match rm {
+            Floor => assert_ne!(o, Greater),
+            Ceiling => assert_ne!(o, Less),
+            Exact => assert_eq!(o, Equal),
+            _ => {}
+        }
+        if o == Equal {
+            for rm in exhaustive_rounding_modes() {
+                let (p_alt, o_alt) = x.mul_round_ref_ref(&y, rm);
+                assert_eq!(p_alt, p);
+                assert_eq!(o_alt, Equal);
+            }
+        }
+    });
+
+    fixed_gen::<F>().test_properties(|x| {
+        let one = Fixed::<F>::from(Integer::ONE);
+        assert_eq!(x.mul_round_ref_ref(&one, Exact), (x.clone(), Equal));
+        assert_eq!(one.mul_round_ref_ref(&x, Exact), (x.clone(), Equal));
+        assert_eq!(
+            x.mul_round_ref_ref(&Fixed::ZERO, Exact),
+            (Fixed::ZERO, Equal)
+        );
+        assert_eq!(
+            Fixed::ZERO.mul_round_ref_ref(&x, Exact),
+            (Fixed::ZERO, Equal)
+        );
+        let (p, o) = x.mul_round_ref_ref(&-&one, Exact);
+        assert_eq!(p, -&x);
+        assert_eq!(o, Equal);
+    });
+}
+
+#[test]
+fn mul_round_properties() {
+    mul_round_properties_helper::<0>();
+    mul_round_properties_helper::<4>();
+    mul_round_properties_helper::<100>();
+}
@@ -0,0 +1,68 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::NegAssign;
+use malachite_base::num::basic::traits::Zero;
+use malachite_fixed::test_util::generators::fixed_gen;
+use malachite_fixed::Fixed;
+use malachite_q::Rational;
+use std::str::FromStr;
+
+#[test]
+fn test_neg() {
+    let test = |s, out| {
+        let x = Fixed::<4>::from_str(s).unwrap();
+
+        let neg = -x.clone();
+        assert!(neg.is_valid());
+        assert_eq!(neg.to_string(), out);
+
+        let neg = -&x;
+        assert!(neg.is_valid());
+        assert_eq!(neg.to_string(), out);
+
+        let mut neg = x.clone();
+        neg.neg_assign();
+        assert!(neg.is_valid());
+        assert_eq!(neg.to_string(), out);
+    };
+    test("0", "0");
+    test("1.5", "-1.5");
+    test("-1.5", "1.5");
+    test("0.0625", "-0.0625");
+    test(
+        "-123456789012345678901234567890.9375",
+        "123456789012345678901234567890.9375",
+    );
+}
+
+fn neg_properties_helper<const F: u64>() {
+    fixed_gen::<F>().test_properties(|x| {
+        let neg = -x.clone();
+        assert!(neg.is_valid());
+        let neg_alt = -&x;
+        assert!(neg_alt.is_valid());
+        assert_eq!(neg_alt, neg);
+        let mut mut_x = x.clone();
+        mut_x.neg_assign();
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x, neg);
+
+        assert_eq!(-&neg, x);
+        assert_eq!(Rational::from(&neg), -Rational::from(&x));
+        assert_eq!(&neg + &x, Fixed::ZERO);
+        assert_eq!(neg == x, x == Fixed::ZERO);
+    });
+}
+
+#[test]
+fn neg_properties() {
+    neg_properties_helper::<0>();
+    neg_properties_helper::<4>();
+    neg_properties_helper::<100>();
+}
@@ -0,0 +1,47 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::Sign;
+use malachite_base::num::basic::traits::Zero;
+use malachite_fixed::test_util::generators::fixed_gen;
+use malachite_fixed::Fixed;
+use malachite_q::Rational;
+use std::cmp::Ordering::*;
+use std::str::FromStr;
+
+#[test]
+fn test_sign() {
+    let test = |s, out| {
+        assert_eq!(Fixed::<4>::from_str(s).unwrap().sign(), out);
+    };
+    test("0", Equal);
+    test("-0.000", Equal);
+    test("1.5", Greater);
+    test("0.0625", Greater);
+    test("-1.5", Less);
+    test("-12000", Less);
+}
+
+fn sign_properties_helper<const F: u64>() {
+    fixed_gen::<F>().test_properties(|x| {
+        let sign = x.sign();
+        assert_eq!(Rational::from(&x).sign(), sign);
+        assert_eq!(x.raw().sign(), sign);
+        if sign != Equal {
+            assert_eq!((-&x).sign(), sign.reverse());
+        }
+        assert_eq!(x.cmp(&Fixed::ZERO), sign);
+    });
+}
+
+#[test]
+fn sign_properties() {
+    sign_properties_helper::<0>();
+    sign_properties_helper::<4>();
+    sign_properties_helper::<100>();
+}
@@ -0,0 +1,110 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::basic::traits::Zero;
+use malachite_fixed::test_util::generators::{fixed_gen, fixed_pair_gen, fixed_triple_gen};
+use malachite_fixed::Fixed;
+use malachite_q::Rational;
+use std::str::FromStr;
+
+#[test]
+fn test_sub() {
+    let test = |s, t, out| {
+        let u = Fixed::<4>::from_str(s).unwrap();
+        let v = Fixed::<4>::from_str(t).unwrap();
+
+        let mut n = u.clone();
+        n -= v.clone();
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let mut n = u.clone();
+        n -= &v;
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = u.clone() - v.clone();
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = &u - v.clone();
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = u.clone() - &v;
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = &u - &v;
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+    };
+    test("0", "0", "0");
+    test("1.5", "2.25", "-0.75");
+    test("2.25", "1.5", "0.75");
+    test("1.5", "1.5", "0");
+    test("1", "0.0625", "0.9375");
+    test("1", "1000", "-999");
+    test(
+        "123456789012345678901234567890.5",
+        "0.0625",
+        "123456789012345678901234567890.4375",
+    );
+}
+
+#[allow(clippy::eq_op)]
+fn sub_properties_helper<const F: u64>() {
+    fixed_pair_gen::<F>().test_properties(|(x, y)| {
+        let diff_val_val = x.clone() - y.clone();
+        let diff_val_ref = x.clone() - &y;
+        let diff_ref_val = &x - y.clone();
+        let diff = &x - &y;
+        assert!(diff_val_val.is_valid());
+        assert!(diff_val_ref.is_valid());
+        assert!(diff_ref_val.is_valid());
+        assert!(diff.is_valid());
+        assert_eq!(diff_val_val, diff);
+        assert_eq!(diff_val_ref, diff);
+        assert_eq!(diff_ref_val, diff);
+
+        let mut mut_x = x.clone();
+        mut_x -= y.clone();
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x, diff);
+        let mut mut_x = x.clone();
+        mut_x -= &y;
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x, diff);
+
+        assert_eq!(*diff.raw(), x.raw() - y.raw());
+        assert_eq!(
+            Rational::from(&diff),
+            Rational::from(&x) - Rational::from(&y)
+        );
+        assert_eq!(-(&y - &x), diff);
+        assert_eq!(&x + -&y, diff);
+        assert_eq!(&diff + &y, x);
+    });
+
+    fixed_gen::<F>().test_properties(|x| {
+        assert_eq!(&x - Fixed::ZERO, x);
+        assert_eq!(Fixed::ZERO - &x, -&x);
+        assert_eq!(&x - &x, Fixed::ZERO);
+    });
+
+    fixed_triple_gen::<F>().test_properties(|(x, y, z)| {
+        assert_eq!((&x - &y) - &z, x - (y + z));
+    });
+}
+
+#[test]
+fn sub_properties() {
+    sub_properties_helper::<0>();
+    sub_properties_helper::<4>();
+    sub_properties_helper::<100>();
+}
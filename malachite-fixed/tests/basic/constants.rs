@@ -0,0 +1,24 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::basic::traits::Zero;
+use malachite_fixed::Fixed;
+
+fn test_zero_helper<const F: u64>() {
+    let zero = Fixed::<F>::ZERO;
+    assert!(zero.is_valid());
+    assert_eq!(*zero.raw(), 0);
+    assert_eq!(zero.to_string(), "0");
+}
+
+#[test]
+fn test_zero() {
+    test_zero_helper::<0>();
+    test_zero_helper::<4>();
+    test_zero_helper::<100>();
+}
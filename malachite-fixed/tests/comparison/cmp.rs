@@ -0,0 +1,86 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::test_util::common::test_cmp_helper;
+use malachite_fixed::test_util::generators::{fixed_gen, fixed_pair_gen, fixed_triple_gen};
+use malachite_fixed::Fixed;
+use malachite_q::Rational;
+use std::cmp::Ordering::*;
+use std::str::FromStr;
+
+#[test]
+fn test_cmp() {
+    let strings = &[
+        "-1000000000001",
+        "-1000000000000",
+        "-999999999999.9375",
+        "-123",
+        "-2",
+        "-1.5",
+        "-1",
+        "-0.75",
+        "-0.375",
+        "-0.0625",
+        "0",
+        "0.0625",
+        "0.375",
+        "0.75",
+        "1",
+        "1.5",
+        "2",
+        "123",
+        "999999999999.9375",
+        "1000000000000",
+        "1000000000001",
+    ];
+    test_cmp_helper::<Fixed<4>>(strings);
+
+    let test = |s, t, out| {
+        let u = Fixed::<4>::from_str(s).unwrap();
+        let v = Fixed::<4>::from_str(t).unwrap();
+        assert_eq!(u.cmp(&v), out);
+        assert_eq!(u.partial_cmp(&v), Some(out));
+        assert_eq!(v.cmp(&u), out.reverse());
+    };
+    test("1", "1.000", Equal);
+    test("-0.000", "0", Equal);
+    test("1.5", "1.4375", Greater);
+    test("-1.5", "-1.4375", Less);
+    test("-0.0625", "0", Less);
+}
+
+fn cmp_properties_helper<const F: u64>() {
+    fixed_pair_gen::<F>().test_properties(|(x, y)| {
+        let ord = x.cmp(&y);
+        assert_eq!(x.partial_cmp(&y), Some(ord));
+        assert_eq!(Rational::from(&x).cmp(&Rational::from(&y)), ord);
+        assert_eq!(x.raw().cmp(y.raw()), ord);
+        assert_eq!(y.cmp(&x).reverse(), ord);
+        assert_eq!(x == y, x.cmp(&y) == Equal);
+        assert_eq!((-&y).cmp(&-&x), ord);
+    });
+
+    fixed_gen::<F>().test_properties(|x| {
+        assert_eq!(x.cmp(&x), Equal);
+    });
+
+    fixed_triple_gen::<F>().test_properties(|(x, y, z)| {
+        if x < y && y < z {
+            assert!(x < z);
+        } else if x > y && y > z {
+            assert!(x > z);
+        }
+    });
+}
+
+#[test]
+fn cmp_properties() {
+    cmp_properties_helper::<0>();
+    cmp_properties_helper::<4>();
+    cmp_properties_helper::<100>();
+}
@@ -0,0 +1,58 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::test_util::common::test_eq_helper;
+use malachite_fixed::test_util::generators::{fixed_gen, fixed_pair_gen, fixed_triple_gen};
+use malachite_fixed::Fixed;
+use malachite_q::Rational;
+use std::str::FromStr;
+
+#[test]
+fn test_eq() {
+    let strings = &["-1000", "-123", "-1", "-0.5", "0", "0.25", "1", "1.5", "123", "1000"];
+    test_eq_helper::<Fixed<4>>(strings);
+
+    let test = |s, t, out| {
+        let u = Fixed::<4>::from_str(s).unwrap();
+        let v = Fixed::<4>::from_str(t).unwrap();
+        assert_eq!(u == v, out);
+        assert_eq!(v == u, out);
+    };
+    test("1", "1.000", true);
+    test("0", "-0.00", true);
+    test("1.25", "1.2500", true);
+    test("1.3125", "1.25", false);
+    test("-1.25", "1.25", false);
+}
+
+#[allow(clippy::eq_op)]
+fn eq_properties_helper<const F: u64>() {
+    fixed_pair_gen::<F>().test_properties(|(x, y)| {
+        let eq = x == y;
+        assert_eq!(y == x, eq);
+        assert_eq!(Rational::from(&x) == Rational::from(&y), eq);
+        assert_eq!(x.raw() == y.raw(), eq);
+    });
+
+    fixed_gen::<F>().test_properties(|x| {
+        assert_eq!(x, x);
+    });
+
+    fixed_triple_gen::<F>().test_properties(|(x, y, z)| {
+        if x == y && y == z {
+            assert_eq!(x, z);
+        }
+    });
+}
+
+#[test]
+fn eq_properties() {
+    eq_properties_helper::<0>();
+    eq_properties_helper::<4>();
+    eq_properties_helper::<100>();
+}
@@ -0,0 +1,29 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::test_util::hash::hash;
+use malachite_fixed::test_util::generators::{fixed_gen, fixed_pair_gen};
+
+fn hash_properties_helper<const F: u64>() {
+    fixed_gen::<F>().test_properties(|x| {
+        assert_eq!(hash(&x), hash(&x.clone()));
+    });
+
+    fixed_pair_gen::<F>().test_properties(|(x, y)| {
+        if x == y {
+            assert_eq!(hash(&x), hash(&y));
+        }
+    });
+}
+
+#[test]
+fn hash_properties() {
+    hash_properties_helper::<0>();
+    hash_properties_helper::<4>();
+    hash_properties_helper::<100>();
+}
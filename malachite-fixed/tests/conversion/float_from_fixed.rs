@@ -0,0 +1,64 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_fixed::test_util::generators::{fixed_gen, fixed_pair_gen};
+use malachite_fixed::Fixed;
+use malachite_float::Float;
+use malachite_nz::integer::Integer;
+use malachite_q::Rational;
+use std::str::FromStr;
+
+#[test]
+fn test_float_try_from_fixed() {
+    let test = |s, out, out_prec| {
+        let x = Fixed::<4>::from_str(s).unwrap();
+
+        let f = Float::try_from(x.clone()).unwrap();
+        assert_eq!(f.to_string(), out);
+        assert_eq!(f.get_prec(), out_prec);
+
+        let f = Float::try_from(&x).unwrap();
+        assert_eq!(f.to_string(), out);
+        assert_eq!(f.get_prec(), out_prec);
+    };
+    test("0", "0.0", None);
+    test("1", "1.0", Some(1));
+    test("1.5", "1.5", Some(2));
+    test("-0.4375", "-0.44", Some(3));
+    test("1000", "1.0e3", Some(7));
+}
+
+fn float_try_from_fixed_properties_helper<const F: u64>() {
+    fixed_gen::<F>().test_properties(|x| {
+        let f = Float::try_from(&x).unwrap();
+        assert_eq!(Float::try_from(x.clone()).unwrap(), f);
+        assert_eq!(Rational::try_from(&f).unwrap(), Rational::from(&x));
+        assert_eq!(Fixed::<F>::try_from(&f), Ok(x.clone()));
+        if let Some(prec) = f.get_prec() {
+            let raw = x.raw();
+            assert_eq!(prec, raw.significant_bits() - raw.trailing_zeros().unwrap());
+        }
+    });
+
+    fixed_pair_gen::<F>().test_properties(|(x, y)| {
+        let f = Float::try_from(&x).unwrap();
+        let g = Float::try_from(&y).unwrap();
+        assert_eq!(f.partial_cmp(&g), Some(x.cmp(&y)));
+    });
+
+    let x = Fixed::<F>::from(Integer::from(-3));
+    assert_eq!(Float::try_from(x).unwrap().to_string(), "-3.0");
+}
+
+#[test]
+fn float_try_from_fixed_properties() {
+    float_try_from_fixed_properties_helper::<0>();
+    float_try_from_fixed_properties_helper::<4>();
+    float_try_from_fixed_properties_helper::<100>();
+}
@@ -0,0 +1,127 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::basic::traits::{Infinity, NaN, NegativeInfinity, Zero};
+use malachite_base::num::conversion::traits::{ConvertibleFrom, RoundingFrom};
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_base::test_util::generators::primitive_float_gen_var_8;
+use malachite_fixed::conversion::from_float::FixedFromFloatError;
+use malachite_fixed::test_util::generators::fixed_gen;
+use malachite_fixed::Fixed;
+use malachite_float::Float;
+use malachite_q::Rational;
+use std::cmp::Ordering::{self, *};
+
+#[test]
+fn test_try_from_float() {
+    let test = |f: Float, out: Result<&str, FixedFromFloatError>| {
+        let x = Fixed::<4>::try_from(f.clone());
+        assert!(x.as_ref().map_or(true, Fixed::is_valid));
+        assert_eq!(x.map(|x| x.to_string()), out.map(str::to_string));
+
+        let x = Fixed::<4>::try_from(&f);
+        assert!(x.as_ref().map_or(true, Fixed::is_valid));
+        assert_eq!(x.map(|x| x.to_string()), out.map(str::to_string));
+
+        assert_eq!(Fixed::<4>::convertible_from(&f), out.is_ok());
+    };
+    test(Float::ZERO, Ok("0"));
+    test(Float::from(-0.0), Ok("0"));
+    test(Float::from(1.5), Ok("1.5"));
+    test(Float::from(-0.4375), Ok("-0.4375"));
+    test(Float::from(1.0e10), Ok("10000000000"));
+    test(Float::from(0.03125), Err(FixedFromFloatError));
+    test(Float::from(0.1), Err(FixedFromFloatError));
+    test(Float::NAN, Err(FixedFromFloatError));
+    test(Float::INFINITY, Err(FixedFromFloatError));
+    test(Float::NEGATIVE_INFINITY, Err(FixedFromFloatError));
+}
+
+#[test]
+fn test_rounding_from_float() {
+    let test = |f: Float, rm: RoundingMode, out, o_out: Ordering| {
+        let (x, o) = Fixed::<4>::rounding_from(f.clone(), rm);
+        assert!(x.is_valid());
+        assert_eq!(x.to_string(), out);
+        assert_eq!(o, o_out);
+
+        let (x, o) = Fixed::<4>::rounding_from(&f, rm);
+        assert!(x.is_valid());
+        assert_eq!(x.to_string(), out);
+        assert_eq!(o, o_out);
+    };
+    test(Float::ZERO, Exact, "0", Equal);
+    test(Float::from(1.5), Exact, "1.5", Equal);
+    test(Float::from(0.1), Floor, "0.0625", Less);
+    test(Float::from(0.1), Nearest, "0.125", Greater);
+    test(Float::from(-0.1), Down, "-0.0625", Greater);
+    test(Float::from(0.03125), Nearest, "0", Less);
+    test(Float::from(0.09375), Nearest, "0.125", Greater);
+}
+
+#[test]
+#[should_panic]
+fn rounding_from_float_fail_1() {
+    Fixed::<4>::rounding_from(Float::from(0.1), Exact);
+}
+
+#[test]
+#[should_panic]
+fn rounding_from_float_fail_2() {
+    Fixed::<4>::rounding_from(Float::NAN, Floor);
+}
+
+#[test]
+#[should_panic]
+fn rounding_from_float_ref_fail() {
+    Fixed::<4>::rounding_from(&Float::INFINITY, Floor);
+}
+
+fn from_float_properties_helper<const F: u64>() {
+    primitive_float_gen_var_8::<f64>().test_properties(|f| {
+        let f = Float::from(f);
+        let q = Rational::try_from(&f).unwrap();
+        let result = Fixed::<F>::try_from(f.clone());
+        assert!(result.as_ref().map_or(true, Fixed::is_valid));
+        let result_alt = Fixed::<F>::try_from(&f);
+        assert_eq!(result_alt, result);
+        assert_eq!(Fixed::<F>::convertible_from(&f), result.is_ok());
+        assert_eq!(result.is_ok(), Fixed::<F>::convertible_from(&q));
+
+        for rm in exhaustive_rounding_modes() {
+            if rm == Exact && result.is_err() {
+                continue;
+            }
+            let (x, o) = Fixed::<F>::rounding_from(&f, rm);
+            assert!(x.is_valid());
+            let (x_alt, o_alt) = Fixed::<F>::rounding_from(f.clone(), rm);
+            assert_eq!(x_alt, x);
+            assert_eq!(o_alt, o);
+            assert_eq!(Fixed::<F>::rounding_from(&q, rm), (x.clone(), o));
+            if let Ok(y) = &result {
+                assert_eq!(x, *y);
+                assert_eq!(o, Equal);
+            }
+        }
+    });
+
+    fixed_gen::<F>().test_properties(|x| {
+        if let Ok(f) = Float::try_from(&x) {
+            assert_eq!(Fixed::<F>::try_from(&f), Ok(x.clone()));
+            assert_eq!(Fixed::<F>::rounding_from(f, Exact), (x, Equal));
+        }
+    });
+}
+
+#[test]
+fn from_float_properties() {
+    from_float_properties_helper::<0>();
+    from_float_properties_helper::<4>();
+    from_float_properties_helper::<100>();
+}
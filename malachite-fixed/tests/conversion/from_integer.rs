@@ -0,0 +1,58 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_fixed::test_util::generators::fixed_gen;
+use malachite_fixed::Fixed;
+use malachite_nz::integer::Integer;
+use malachite_q::Rational;
+use std::str::FromStr;
+
+#[test]
+fn test_from_integer() {
+    let test = |s, out, out_raw: i64| {
+        let u = Integer::from_str(s).unwrap();
+
+        let x = Fixed::<4>::from(u.clone());
+        assert!(x.is_valid());
+        assert_eq!(x.to_string(), out);
+        assert_eq!(*x.raw(), out_raw);
+
+        let x = Fixed::<4>::from(&u);
+        assert!(x.is_valid());
+        assert_eq!(x.to_string(), out);
+        assert_eq!(*x.raw(), out_raw);
+    };
+    test("0", "0", 0);
+    test("1", "1", 16);
+    test("123", "123", 1968);
+    test("-123", "-123", -1968);
+    test("1000000000000", "1000000000000", 16000000000000);
+}
+
+fn from_integer_properties_helper<const F: u64>() {
+    fixed_gen::<F>().test_properties(|x| {
+        let n = x.into_raw();
+        let fixed_n = Fixed::<F>::from(n.clone());
+        assert!(fixed_n.is_valid());
+        assert_eq!(fixed_n.to_string(), n.to_string());
+        assert_eq!(*fixed_n.raw(), &n << F);
+
+        let fixed_n_alt = Fixed::<F>::from(&n);
+        assert!(fixed_n_alt.is_valid());
+        assert_eq!(fixed_n_alt, fixed_n);
+
+        assert_eq!(Rational::from(&fixed_n), Rational::from(&n));
+    });
+}
+
+#[test]
+fn from_integer_properties() {
+    from_integer_properties_helper::<0>();
+    from_integer_properties_helper::<4>();
+    from_integer_properties_helper::<100>();
+}
@@ -0,0 +1,124 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::conversion::traits::{ConvertibleFrom, RoundingFrom};
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_base::test_util::generators::{primitive_float_gen, primitive_float_gen_var_8};
+use malachite_fixed::conversion::from_primitive_float::FixedFromPrimitiveFloatError;
+use malachite_fixed::Fixed;
+use malachite_q::Rational;
+use std::cmp::Ordering::{self, *};
+
+#[test]
+fn test_try_from_primitive_float() {
+    fn test<T: PrimitiveFloat>(f: T, out: Result<&str, FixedFromPrimitiveFloatError>)
+    where
+        Fixed<4>: TryFrom<T, Error = FixedFromPrimitiveFloatError> + ConvertibleFrom<T>,
+    {
+        let x = Fixed::<4>::try_from(f);
+        assert!(x.as_ref().map_or(true, Fixed::is_valid));
+        assert_eq!(x.map(|x| x.to_string()), out.map(str::to_string));
+        assert_eq!(Fixed::<4>::convertible_from(f), out.is_ok());
+    }
+    test(0.0f32, Ok("0"));
+    test(-0.0f64, Ok("0"));
+    test(1.5f32, Ok("1.5"));
+    test(-0.4375f64, Ok("-0.4375"));
+    test(1.0e10f64, Ok("10000000000"));
+    test(0.03125f32, Err(FixedFromPrimitiveFloatError));
+    test(0.1f64, Err(FixedFromPrimitiveFloatError));
+    test(f32::NAN, Err(FixedFromPrimitiveFloatError));
+    test(f64::INFINITY, Err(FixedFromPrimitiveFloatError));
+    test(f32::NEG_INFINITY, Err(FixedFromPrimitiveFloatError));
+}
+
+#[test]
+fn test_rounding_from_primitive_float() {
+    fn test<T: PrimitiveFloat>(f: T, rm: RoundingMode, out: &str, o_out: Ordering)
+    where
+        Fixed<4>: RoundingFrom<T>,
+    {
+        let (x, o) = Fixed::<4>::rounding_from(f, rm);
+        assert!(x.is_valid());
+        assert_eq!(x.to_string(), out);
+        assert_eq!(o, o_out);
+    }
+    test(0.0f32, Exact, "0", Equal);
+    test(1.5f64, Exact, "1.5", Equal);
+    test(0.1f64, Floor, "0.0625", Less);
+    test(0.1f64, Nearest, "0.125", Greater);
+    test(-0.1f32, Down, "-0.0625", Greater);
+    test(0.03125f32, Nearest, "0", Less);
+    test(0.09375f64, Nearest, "0.125", Greater);
+}
+
+#[test]
+#[should_panic]
+fn rounding_from_primitive_float_fail_1() {
+    Fixed::<4>::rounding_from(0.1f64, Exact);
+}
+
+#[test]
+#[should_panic]
+fn rounding_from_primitive_float_fail_2() {
+    Fixed::<4>::rounding_from(f32::NAN, Floor);
+}
+
+#[test]
+#[should_panic]
+fn rounding_from_primitive_float_fail_3() {
+    Fixed::<4>::rounding_from(f64::INFINITY, Floor);
+}
+
+fn from_primitive_float_properties_helper<T: PrimitiveFloat, const F: u64>()
+where
+    Fixed<F>: TryFrom<T, Error = FixedFromPrimitiveFloatError>
+        + ConvertibleFrom<T>
+        + RoundingFrom<T>
+        + for<'a> ConvertibleFrom<&'a Rational>
+        + for<'a> RoundingFrom<&'a Rational>,
+    Rational: TryFrom<T>,
+{
+    primitive_float_gen::<T>().test_properties(|f| {
+        let result = Fixed::<F>::try_from(f);
+        assert!(result.as_ref().map_or(true, Fixed::is_valid));
+        assert_eq!(Fixed::<F>::convertible_from(f), result.is_ok());
+        if !f.is_finite() {
+            assert!(result.is_err());
+        }
+    });
+
+    primitive_float_gen_var_8::<T>().test_properties(|f| {
+        let q = Rational::try_from(f).ok().unwrap();
+        let result = Fixed::<F>::try_from(f);
+        assert_eq!(result.is_ok(), Fixed::<F>::convertible_from(&q));
+        for rm in exhaustive_rounding_modes() {
+            if rm == Exact && result.is_err() {
+                continue;
+            }
+            let (x, o) = Fixed::<F>::rounding_from(f, rm);
+            assert!(x.is_valid());
+            assert_eq!(Fixed::<F>::rounding_from(&q, rm), (x.clone(), o));
+            if let Ok(y) = &result {
+                assert_eq!(x, *y);
+                assert_eq!(o, Equal);
+            }
+        }
+    });
+}
+
+#[test]
+fn from_primitive_float_properties() {
+    from_primitive_float_properties_helper::<f32, 0>();
+    from_primitive_float_properties_helper::<f64, 0>();
+    from_primitive_float_properties_helper::<f32, 4>();
+    from_primitive_float_properties_helper::<f64, 4>();
+    from_primitive_float_properties_helper::<f64, 100>();
+}
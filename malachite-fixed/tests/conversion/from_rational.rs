@@ -0,0 +1,137 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::conversion::traits::{ConvertibleFrom, RoundingFrom};
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_fixed::conversion::from_rational::FixedFromRationalError;
+use malachite_fixed::test_util::generators::{
+    fixed_gen, rational_gen, rational_rounding_mode_pair_gen_var_1,
+};
+use malachite_fixed::Fixed;
+use malachite_nz::integer::Integer;
+use malachite_q::Rational;
+use std::cmp::Ordering::{self, *};
+use std::str::FromStr;
+
+#[test]
+fn test_try_from_rational() {
+    let test = |s, out: Result<&str, FixedFromRationalError>| {
+        let u = Rational::from_str(s).unwrap();
+
+        let x = Fixed::<4>::try_from(u.clone());
+        assert!(x.as_ref().map_or(true, Fixed::is_valid));
+        assert_eq!(x.map(|x| x.to_string()), out.map(str::to_string));
+
+        let x = Fixed::<4>::try_from(&u);
+        assert!(x.as_ref().map_or(true, Fixed::is_valid));
+        assert_eq!(x.map(|x| x.to_string()), out.map(str::to_string));
+
+        assert_eq!(Fixed::<4>::convertible_from(&u), out.is_ok());
+    };
+    test("0", Ok("0"));
+    test("3/2", Ok("1.5"));
+    test("-7/16", Ok("-0.4375"));
+    test("123", Ok("123"));
+    test("1/32", Err(FixedFromRationalError));
+    test("-1/3", Err(FixedFromRationalError));
+    test("1/10", Err(FixedFromRationalError));
+}
+
+#[test]
+fn test_rounding_from_rational() {
+    let test = |s, rm: RoundingMode, out, o_out: Ordering| {
+        let u = Rational::from_str(s).unwrap();
+
+        let (x, o) = Fixed::<4>::rounding_from(u.clone(), rm);
+        assert!(x.is_valid());
+        assert_eq!(x.to_string(), out);
+        assert_eq!(o, o_out);
+
+        let (x, o) = Fixed::<4>::rounding_from(&u, rm);
+        assert!(x.is_valid());
+        assert_eq!(x.to_string(), out);
+        assert_eq!(o, o_out);
+    };
+    test("0", Exact, "0", Equal);
+    test("3/2", Exact, "1.5", Equal);
+    test("1/3", Floor, "0.3125", Less);
+    test("1/3", Ceiling, "0.375", Greater);
+    test("1/3", Nearest, "0.3125", Less);
+    test("-1/3", Floor, "-0.375", Less);
+    test("-1/3", Down, "-0.3125", Greater);
+    test("1/32", Nearest, "0", Less);
+    test("3/32", Nearest, "0.125", Greater);
+    test("1/10", Up, "0.125", Greater);
+    test("-1/10", Nearest, "-0.125", Less);
+}
+
+#[test]
+#[should_panic]
+fn rounding_from_rational_fail() {
+    Fixed::<4>::rounding_from(Rational::from_signeds(1, 3), Exact);
+}
+
+#[test]
+#[should_panic]
+fn rounding_from_rational_ref_fail() {
+    Fixed::<4>::rounding_from(&Rational::from_signeds(1, 3), Exact);
+}
+
+fn from_rational_properties_helper<const F: u64>() {
+    rational_gen().test_properties(|x| {
+        let result = Fixed::<F>::try_from(x.clone());
+        assert!(result.as_ref().map_or(true, Fixed::is_valid));
+        let result_alt = Fixed::<F>::try_from(&x);
+        assert_eq!(result_alt, result);
+        assert_eq!(Fixed::<F>::convertible_from(&x), result.is_ok());
+        assert_eq!(result.is_ok(), (&x << F).denominator_ref() == &1u32);
+        if let Ok(y) = result {
+            assert_eq!(Rational::from(&y), x);
+            assert_eq!(Fixed::<F>::rounding_from(&x, Exact), (y, Equal));
+        }
+    });
+
+    rational_rounding_mode_pair_gen_var_1::<F>().test_properties(|(x, rm)| {
+        let (y, o) = Fixed::<F>::rounding_from(&x, rm);
+        assert!(y.is_valid());
+        let (y_alt, o_alt) = Fixed::<F>::rounding_from(x.clone(), rm);
+        assert_eq!(y_alt, y);
+        assert_eq!(o_alt, o);
+
+        assert_eq!(Rational::from(&y).cmp(&x), o);
+        assert_eq!(*y.raw(), Integer::rounding_from(&x << F, rm).0);
+        let (y_alt, o_alt) = Fixed::<F>::rounding_from(-&x, -rm);
+        assert_eq!(-y_alt, y);
+        assert_eq!(o_alt.reverse(), o);
+        match rm {
+            Floor => assert_ne!(o, Greater),
+            Ceiling => assert_ne!(o, Less),
+            Exact => assert_eq!(o, Equal),
+            _ => {}
+        }
+        if o == Equal {
+            for rm in exhaustive_rounding_modes() {
+                assert_eq!(Fixed::<F>::rounding_from(&x, rm), (y.clone(), Equal));
+            }
+        }
+    });
+
+    fixed_gen::<F>().test_properties(|x| {
+        let q = Rational::from(&x);
+        assert_eq!(Fixed::<F>::try_from(&q), Ok(x.clone()));
+        assert_eq!(Fixed::<F>::rounding_from(q, Exact), (x, Equal));
+    });
+}
+
+#[test]
+fn from_rational_properties() {
+    from_rational_properties_helper::<0>();
+    from_rational_properties_helper::<4>();
+    from_rational_properties_helper::<100>();
+}
@@ -0,0 +1,47 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_fixed::test_util::generators::fixed_gen;
+use malachite_fixed::Fixed;
+use malachite_nz::integer::Integer;
+use malachite_q::Rational;
+
+#[test]
+fn test_from_raw() {
+    let test = |raw: i64, out| {
+        let x = Fixed::<4>::from_raw(Integer::from(raw));
+        assert!(x.is_valid());
+        assert_eq!(x.to_string(), out);
+        assert_eq!(*x.raw(), raw);
+        assert_eq!(x.into_raw(), raw);
+    };
+    test(0, "0");
+    test(1, "0.0625");
+    test(-1, "-0.0625");
+    test(24, "1.5");
+    test(-32, "-2");
+    test(123456789, "7716049.3125");
+}
+
+fn from_raw_properties_helper<const F: u64>() {
+    fixed_gen::<F>().test_properties(|x| {
+        let raw = x.raw().clone();
+        let y = Fixed::<F>::from_raw(raw.clone());
+        assert!(y.is_valid());
+        assert_eq!(y, x);
+        assert_eq!(x.clone().into_raw(), raw);
+        assert_eq!(Rational::from(&x), Rational::from(raw) >> F);
+    });
+}
+
+#[test]
+fn from_raw_properties() {
+    from_raw_properties_helper::<0>();
+    from_raw_properties_helper::<4>();
+    from_raw_properties_helper::<100>();
+}
@@ -0,0 +1,112 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::RoundingFrom;
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_base::strings::ToDebugString;
+use malachite_fixed::test_util::generators::{fixed_gen, fixed_rounding_mode_pair_gen_var_1};
+use malachite_fixed::Fixed;
+use malachite_nz::integer::Integer;
+use malachite_q::Rational;
+use std::cmp::Ordering::*;
+use std::str::FromStr;
+
+#[test]
+fn test_integer_rounding_from_fixed() {
+    let test = |s, rm: RoundingMode, out| {
+        let x = Fixed::<4>::from_str(s).unwrap();
+
+        let (n, o) = Integer::rounding_from(x.clone(), rm);
+        assert_eq!((n, o).to_debug_string(), out);
+
+        let (n, o) = Integer::rounding_from(&x, rm);
+        assert_eq!((n, o).to_debug_string(), out);
+    };
+    test("0", Exact, "(0, Equal)");
+    test("3", Exact, "(3, Equal)");
+    test("-3", Exact, "(-3, Equal)");
+    test("1.5", Floor, "(1, Less)");
+    test("1.5", Ceiling, "(2, Greater)");
+    test("1.5", Down, "(1, Less)");
+    test("1.5", Up, "(2, Greater)");
+    test("1.5", Nearest, "(2, Greater)");
+    test("2.5", Nearest, "(2, Less)");
+    test("2.5625", Nearest, "(3, Greater)");
+    test("-1.5", Floor, "(-2, Less)");
+    test("-1.5", Ceiling, "(-1, Greater)");
+    test("-1.5", Down, "(-1, Greater)");
+    test("-1.5", Up, "(-2, Less)");
+    test("-1.5", Nearest, "(-2, Less)");
+    test("-0.0625", Floor, "(-1, Less)");
+    test("-0.0625", Nearest, "(0, Greater)");
+}
+
+#[test]
+#[should_panic]
+fn integer_rounding_from_fixed_fail() {
+    Integer::rounding_from(Fixed::<4>::from_str("1.5").unwrap(), Exact);
+}
+
+#[test]
+#[should_panic]
+fn integer_rounding_from_fixed_ref_fail() {
+    Integer::rounding_from(&Fixed::<4>::from_str("1.5").unwrap(), Exact);
+}
+
+fn integer_rounding_from_fixed_properties_helper<const F: u64>() {
+    fixed_rounding_mode_pair_gen_var_1::<F>().test_properties(|(x, rm)| {
+        let (n, o) = Integer::rounding_from(&x, rm);
+        let (n_alt, o_alt) = Integer::rounding_from(x.clone(), rm);
+        assert_eq!(n_alt, n);
+        assert_eq!(o_alt, o);
+
+        let q = Rational::from(&x);
+        assert_eq!(Integer::rounding_from(&q, rm), (n.clone(), o));
+        assert_eq!(Rational::from(&n).cmp(&q), o);
+        let (n_alt, o_alt) = Integer::rounding_from(-&x, -rm);
+        assert_eq!(-n_alt, n);
+        assert_eq!(o_alt.reverse(), o);
+        match rm {
+            Floor => assert_ne!(o, Greater),
+            Ceiling => assert_ne!(o, Less),
+            Exact => assert_eq!(o, Equal),
+            _ => {}
+        }
+        if o == Equal {
+            for rm in exhaustive_rounding_modes() {
+                assert_eq!(Integer::rounding_from(&x, rm), (n.clone(), Equal));
+            }
+        }
+    });
+
+    fixed_gen::<F>().test_properties(|x| {
+        let n = x.into_raw();
+        assert_eq!(
+            Integer::rounding_from(Fixed::<F>::from(&n), Exact),
+            (n, Equal)
+        );
+    });
+
+    assert_eq!(
+        Integer::rounding_from(Fixed::<F>::ZERO, Exact),
+        (Integer::ZERO, Equal)
+    );
+    assert_eq!(
+        Integer::rounding_from(Fixed::<F>::from(Integer::ONE), Exact),
+        (Integer::ONE, Equal)
+    );
+}
+
+#[test]
+fn integer_rounding_from_fixed_properties() {
+    integer_rounding_from_fixed_properties_helper::<0>();
+    integer_rounding_from_fixed_properties_helper::<4>();
+    integer_rounding_from_fixed_properties_helper::<100>();
+}
@@ -0,0 +1,131 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::basic::floats::PrimitiveFloat;
+use malachite_base::num::conversion::traits::{ConvertibleFrom, RoundingFrom};
+use malachite_base::num::float::NiceFloat;
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_fixed::test_util::generators::fixed_gen;
+use malachite_fixed::Fixed;
+use malachite_nz::integer::Integer;
+use malachite_q::conversion::primitive_float_from_rational::FloatConversionError;
+use malachite_q::Rational;
+use std::cmp::Ordering::{self, *};
+use std::str::FromStr;
+
+#[test]
+fn test_primitive_float_rounding_from_fixed() {
+    let test = |x: Fixed<4>, rm: RoundingMode, out: f32, o_out: Ordering| {
+        let (f, o) = f32::rounding_from(x.clone(), rm);
+        assert_eq!(NiceFloat(f), NiceFloat(out));
+        assert_eq!(o, o_out);
+
+        let (f, o) = f32::rounding_from(&x, rm);
+        assert_eq!(NiceFloat(f), NiceFloat(out));
+        assert_eq!(o, o_out);
+    };
+    test(Fixed::from_str("0").unwrap(), Exact, 0.0, Equal);
+    test(Fixed::from_str("1.5").unwrap(), Exact, 1.5, Equal);
+    test(Fixed::from_str("-0.4375").unwrap(), Exact, -0.4375, Equal);
+    test(
+        Fixed::from_str("16777217").unwrap(),
+        Floor,
+        16777216.0,
+        Less,
+    );
+    test(
+        Fixed::from_str("16777217").unwrap(),
+        Ceiling,
+        16777218.0,
+        Greater,
+    );
+    test(
+        Fixed::from_str("16777217.0625").unwrap(),
+        Nearest,
+        16777218.0,
+        Greater,
+    );
+    test(
+        Fixed::from_str("-16777217").unwrap(),
+        Down,
+        -16777216.0,
+        Greater,
+    );
+}
+
+#[test]
+#[should_panic]
+fn primitive_float_rounding_from_fixed_fail() {
+    f32::rounding_from(Fixed::<4>::from_str("16777217").unwrap(), Exact);
+}
+
+#[test]
+fn test_primitive_float_try_from_fixed() {
+    let test = |x: Fixed<4>, out: Result<f32, FloatConversionError>| {
+        assert_eq!(f32::try_from(x.clone()).map(NiceFloat), out.map(NiceFloat));
+        assert_eq!(f32::try_from(&x).map(NiceFloat), out.map(NiceFloat));
+        assert_eq!(f32::convertible_from(&x), out.is_ok());
+    };
+    test(Fixed::from_str("0").unwrap(), Ok(0.0));
+    test(Fixed::from_str("1.5").unwrap(), Ok(1.5));
+    test(Fixed::from_str("-0.0625").unwrap(), Ok(-0.0625));
+    test(
+        Fixed::from_str("16777217").unwrap(),
+        Err(FloatConversionError::Inexact),
+    );
+    test(
+        Fixed::from_raw(Integer::from(16777217)),
+        Err(FloatConversionError::Inexact),
+    );
+}
+
+fn primitive_float_from_fixed_properties_helper<T, const F: u64>()
+where
+    T: PrimitiveFloat
+        + for<'a> RoundingFrom<&'a Fixed<F>>
+        + RoundingFrom<Fixed<F>>
+        + for<'a> RoundingFrom<&'a Rational>
+        + for<'a> TryFrom<&'a Fixed<F>, Error = FloatConversionError>
+        + TryFrom<Fixed<F>, Error = FloatConversionError>
+        + for<'a> TryFrom<&'a Rational, Error = FloatConversionError>
+        + for<'a> ConvertibleFrom<&'a Fixed<F>>,
+{
+    fixed_gen::<F>().test_properties(|x| {
+        let q = Rational::from(&x);
+        let result = T::try_from(&x);
+        assert_eq!(T::try_from(x.clone()).map(NiceFloat), result.map(NiceFloat));
+        assert_eq!(result.map(NiceFloat), T::try_from(&q).map(NiceFloat));
+        assert_eq!(T::convertible_from(&x), result.is_ok());
+        for rm in exhaustive_rounding_modes() {
+            if rm == Exact && result.is_err() {
+                continue;
+            }
+            let (f, o) = T::rounding_from(&x, rm);
+            let (f_alt, o_alt) = T::rounding_from(x.clone(), rm);
+            assert_eq!(NiceFloat(f_alt), NiceFloat(f));
+            assert_eq!(o_alt, o);
+            let (f_alt, o_alt) = T::rounding_from(&q, rm);
+            assert_eq!(NiceFloat(f_alt), NiceFloat(f));
+            assert_eq!(o_alt, o);
+            if let Ok(g) = result {
+                assert_eq!(NiceFloat(f), NiceFloat(g));
+                assert_eq!(o, Equal);
+            }
+        }
+    });
+}
+
+#[test]
+fn primitive_float_from_fixed_properties() {
+    primitive_float_from_fixed_properties_helper::<f32, 0>();
+    primitive_float_from_fixed_properties_helper::<f64, 0>();
+    primitive_float_from_fixed_properties_helper::<f32, 4>();
+    primitive_float_from_fixed_properties_helper::<f64, 4>();
+    primitive_float_from_fixed_properties_helper::<f64, 100>();
+}
@@ -0,0 +1,55 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_fixed::test_util::generators::{fixed_gen, fixed_pair_gen};
+use malachite_fixed::Fixed;
+use malachite_q::Rational;
+use std::str::FromStr;
+
+#[test]
+fn test_rational_from_fixed() {
+    let test = |s, out| {
+        let u = Fixed::<4>::from_str(s).unwrap();
+
+        let x = Rational::from(u.clone());
+        assert_eq!(x.to_string(), out);
+
+        let x = Rational::from(&u);
+        assert_eq!(x.to_string(), out);
+    };
+    test("0", "0");
+    test("1.5", "3/2");
+    test("-0.0625", "-1/16");
+    test("-0.4375", "-7/16");
+    test("12000", "12000");
+    test("123456789.125", "987654313/8");
+}
+
+fn rational_from_fixed_properties_helper<const F: u64>() {
+    fixed_gen::<F>().test_properties(|x| {
+        let rational_x = Rational::from(x.clone());
+        let rational_x_alt = Rational::from(&x);
+        assert_eq!(rational_x_alt, rational_x);
+
+        assert_eq!(Rational::from(&-&x), -&rational_x);
+        assert_eq!(Fixed::<F>::try_from(&rational_x).unwrap(), x);
+        assert!(*rational_x.denominator_ref() <= Rational::from(1u32) << F);
+    });
+
+    fixed_pair_gen::<F>().test_properties(|(x, y)| {
+        assert_eq!(Rational::from(&x) == Rational::from(&y), x == y);
+        assert_eq!(Rational::from(&x).cmp(&Rational::from(&y)), x.cmp(&y));
+    });
+}
+
+#[test]
+fn rational_from_fixed_properties() {
+    rational_from_fixed_properties_helper::<0>();
+    rational_from_fixed_properties_helper::<4>();
+    rational_from_fixed_properties_helper::<100>();
+}
@@ -0,0 +1,82 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::test_util::generators::common::GenConfig;
+use malachite_base::test_util::generators::string_gen;
+use malachite_fixed::test_util::generators::fixed_gen;
+use malachite_fixed::Fixed;
+use std::str::FromStr;
+
+#[test]
+fn test_from_str() {
+    let test_ok = |s, raw, out| {
+        let x = Fixed::<4>::from_str(s).unwrap();
+        assert!(x.is_valid());
+        assert_eq!(x.raw().to_string(), raw);
+        assert_eq!(x.to_string(), out);
+    };
+    test_ok("0", "0", "0");
+    test_ok("-0", "0", "0");
+    test_ok("0.000", "0", "0");
+    test_ok("123456", "1975296", "123456");
+    test_ok("-123456", "-1975296", "-123456");
+    test_ok("1.50", "24", "1.5");
+    test_ok("-007", "-112", "-7");
+    test_ok(".5", "8", "0.5");
+    test_ok("5.", "80", "5");
+    test_ok("-.25", "-4", "-0.25");
+    test_ok("0.0625", "1", "0.0625");
+    test_ok("0.93750000", "15", "0.9375");
+    test_ok(
+        "1000000000000000000000000.0625",
+        "16000000000000000000000001",
+        "1000000000000000000000000.0625",
+    );
+
+    let test_err = |s| {
+        assert!(Fixed::<4>::from_str(s).is_err());
+    };
+    test_err("");
+    test_err(".");
+    test_err("-");
+    test_err("-.");
+    test_err("+1");
+    test_err("--1");
+    test_err("1.2.3");
+    test_err("1e5");
+    test_err(" 1");
+    test_err("1 ");
+    test_err("12A");
+    test_err("1/2");
+    test_err("$%^");
+    test_err("0.1");
+    test_err("0.03125");
+    test_err("-0.00000000000000000001");
+}
+
+#[allow(unused_must_use)]
+fn from_str_properties_helper<const F: u64>() {
+    let mut config = GenConfig::new();
+    config.insert("mean_length_n", 64);
+    string_gen().test_properties_with_config(&config, |s| {
+        Fixed::<F>::from_str(&s);
+    });
+
+    fixed_gen::<F>().test_properties(|x| {
+        let y = Fixed::<F>::from_str(&x.to_string()).unwrap();
+        assert!(y.is_valid());
+        assert_eq!(y, x);
+    });
+}
+
+#[test]
+fn from_str_properties() {
+    from_str_properties_helper::<0>();
+    from_str_properties_helper::<4>();
+    from_str_properties_helper::<100>();
+}
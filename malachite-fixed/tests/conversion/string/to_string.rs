@@ -0,0 +1,67 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::strings::{string_is_subset, ToDebugString};
+use malachite_fixed::test_util::generators::fixed_gen;
+use malachite_fixed::Fixed;
+use malachite_nz::integer::Integer;
+use malachite_q::Rational;
+use std::str::FromStr;
+
+#[test]
+fn test_to_string() {
+    fn test<const F: u64>(raw: i64, out: &str) {
+        let x = Fixed::<F>::from_raw(Integer::from(raw));
+        assert_eq!(x.to_string(), out);
+        assert_eq!(x.to_debug_string(), out);
+    }
+    test::<0>(0, "0");
+    test::<0>(123, "123");
+    test::<0>(-123, "-123");
+    test::<4>(0, "0");
+    test::<4>(1, "0.0625");
+    test::<4>(-1, "-0.0625");
+    test::<4>(16, "1");
+    test::<4>(24, "1.5");
+    test::<4>(-24, "-1.5");
+    test::<4>(-32, "-2");
+    test::<4>(1000, "62.5");
+    test::<10>(1, "0.0009765625");
+    test::<10>(1025, "1.0009765625");
+    test::<20>(3, "0.00000286102294921875");
+    test::<64>(
+        1,
+        "0.0000000000000000000542101086242752217003726400434970855712890625",
+    );
+}
+
+fn to_string_properties_helper<const F: u64>() {
+    fixed_gen::<F>().test_properties(|x| {
+        let s = x.to_string();
+        assert_eq!(x.to_debug_string(), s);
+        assert!(string_is_subset(&s, "-.0123456789"));
+        assert_eq!(Fixed::<F>::from_str(&s).unwrap(), x);
+        assert_eq!(
+            (-&x).to_string().trim_start_matches('-'),
+            s.trim_start_matches('-')
+        );
+        if let Some(point_index) = s.find('.') {
+            assert!(!s.ends_with('0'));
+            assert!(u64::try_from(s.len() - point_index - 1).unwrap() <= F);
+        } else {
+            assert_eq!(Rational::from(&x).to_string(), s);
+        }
+    });
+}
+
+#[test]
+fn to_string_properties() {
+    to_string_properties_helper::<0>();
+    to_string_properties_helper::<4>();
+    to_string_properties_helper::<100>();
+}
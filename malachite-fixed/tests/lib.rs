@@ -0,0 +1,97 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+#![allow(
+    unstable_name_collisions,
+    clippy::bool_assert_comparison,
+    clippy::assertions_on_constants,
+    clippy::cognitive_complexity,
+    clippy::excessive_precision,
+    clippy::many_single_char_names,
+    clippy::range_plus_one,
+    clippy::suspicious_arithmetic_impl,
+    clippy::suspicious_op_assign_impl,
+    clippy::too_many_arguments,
+    clippy::float_cmp,
+    clippy::type_complexity
+)]
+#![warn(
+    clippy::cast_lossless,
+    clippy::explicit_into_iter_loop,
+    clippy::explicit_iter_loop,
+    clippy::filter_map_next,
+    clippy::large_digit_groups,
+    clippy::manual_filter_map,
+    clippy::manual_find_map,
+    clippy::map_flatten,
+    clippy::map_unwrap_or,
+    clippy::match_same_arms,
+    clippy::missing_const_for_fn,
+    clippy::mut_mut,
+    clippy::needless_borrow,
+    clippy::needless_continue,
+    clippy::needless_pass_by_value,
+    clippy::option_if_let_else,
+    clippy::print_stdout,
+    clippy::redundant_closure_for_method_calls,
+    clippy::single_match_else,
+    clippy::trait_duplication_in_bounds,
+    clippy::type_repetition_in_bounds,
+    clippy::uninlined_format_args,
+    clippy::unused_self,
+    clippy::if_not_else,
+    clippy::manual_assert,
+    clippy::range_plus_one,
+    clippy::redundant_else,
+    clippy::semicolon_if_nothing_returned,
+    clippy::cloned_instead_of_copied,
+    clippy::flat_map_option,
+    clippy::unnecessary_wraps,
+    clippy::unnested_or_patterns,
+    clippy::trivially_copy_pass_by_ref
+)]
+
+extern crate malachite_base;
+extern crate malachite_fixed;
+extern crate malachite_float;
+extern crate malachite_nz;
+extern crate malachite_q;
+
+pub mod arithmetic {
+    pub mod abs;
+    pub mod add;
+    pub mod div;
+    pub mod mul;
+    pub mod neg;
+    pub mod sign;
+    pub mod sub;
+}
+pub mod basic {
+    pub mod constants;
+    pub mod default;
+}
+pub mod comparison {
+    pub mod cmp;
+    pub mod eq;
+    pub mod hash;
+}
+pub mod conversion {
+    pub mod float_from_fixed;
+    pub mod from_float;
+    pub mod from_integer;
+    pub mod from_primitive_float;
+    pub mod from_rational;
+    pub mod from_raw;
+    pub mod integer_from_fixed;
+    pub mod primitive_float_from_fixed;
+    pub mod rational_from_fixed;
+    pub mod string {
+        pub mod from_string;
+        pub mod to_string;
+    }
+}
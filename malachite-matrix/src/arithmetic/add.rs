@@ -0,0 +1,238 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Matrix;
+use core::ops::{Add, AddAssign};
+
+pub(crate) fn assert_same_shape<T>(x: &Matrix<T>, y: &Matrix<T>) {
+    assert!(
+        x.height == y.height && x.width == y.width,
+        "The matrices must have the same dimensions, but {}x{} != {}x{}",
+        x.height,
+        x.width,
+        y.height,
+        y.width
+    );
+}
+
+impl<T: AddAssign> Add<Matrix<T>> for Matrix<T> {
+    type Output = Matrix<T>;
+
+    /// Adds two [`Matrix`]s, taking both by value.
+    ///
+    /// $$
+    /// f(A, B) = A + B.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in the
+    /// entries of `self` and `other`.
+    ///
+    /// # Panics
+    /// Panics if the matrices have different dimensions.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_matrix::Matrix;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let a = Matrix::from_entries(2, 2, [1, 2, 3, 4].map(Integer::from).to_vec());
+    /// let b = Matrix::from_entries(2, 2, [4, -2, 0, 1].map(Integer::from).to_vec());
+    /// assert_eq!((a + b).to_string(), "[[5, 0], [3, 5]]");
+    /// ```
+    #[inline]
+    fn add(mut self, other: Matrix<T>) -> Matrix<T> {
+        self += other;
+        self
+    }
+}
+
+impl<T: for<'a> AddAssign<&'a T>> Add<&Matrix<T>> for Matrix<T> {
+    type Output = Matrix<T>;
+
+    /// Adds two [`Matrix`]s, taking the first by value and the second by reference.
+    ///
+    /// $$
+    /// f(A, B) = A + B.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(m) = O(m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the total number of bits in the entries
+    /// of `self` and `other`, and $m$ is the total number of bits in the entries of `other`.
+    ///
+    /// # Panics
+    /// Panics if the matrices have different dimensions.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_matrix::Matrix;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let a = Matrix::from_entries(2, 2, [1, 2, 3, 4].map(Integer::from).to_vec());
+    /// let b = Matrix::from_entries(2, 2, [4, -2, 0, 1].map(Integer::from).to_vec());
+    /// assert_eq!((a + &b).to_string(), "[[5, 0], [3, 5]]");
+    /// ```
+    #[inline]
+    fn add(mut self, other: &Matrix<T>) -> Matrix<T> {
+        self += other;
+        self
+    }
+}
+
+impl<T: for<'a> AddAssign<&'a T>> Add<Matrix<T>> for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    /// Adds two [`Matrix`]s, taking the first by reference and the second by value.
+    ///
+    /// $$
+    /// f(A, B) = A + B.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(m) = O(m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the total number of bits in the entries
+    /// of `self` and `other`, and $m$ is the total number of bits in the entries of `self`.
+    ///
+    /// # Panics
+    /// Panics if the matrices have different dimensions.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_matrix::Matrix;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let a = Matrix::from_entries(2, 2, [1, 2, 3, 4].map(Integer::from).to_vec());
+    /// let b = Matrix::from_entries(2, 2, [4, -2, 0, 1].map(Integer::from).to_vec());
+    /// assert_eq!((&a + b).to_string(), "[[5, 0], [3, 5]]");
+    /// ```
+    #[inline]
+    fn add(self, mut other: Matrix<T>) -> Matrix<T> {
+        other += self;
+        other
+    }
+}
+
+impl<T: Clone + for<'a> AddAssign<&'a T>> Add<&Matrix<T>> for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    /// Adds two [`Matrix`]s, taking both by reference.
+    ///
+    /// $$
+    /// f(A, B) = A + B.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in the
+    /// entries of `self` and `other`.
+    ///
+    /// # Panics
+    /// Panics if the matrices have different dimensions.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_matrix::Matrix;
+    /// use malachite_q::Rational;
+    ///
+    /// let a = Matrix::from_rows(vec![vec![Rational::from_signeds(1, 2), Rational::from(1)]]);
+    /// let b = Matrix::from_rows(vec![vec![Rational::from_signeds(1, 3), Rational::from(-1)]]);
+    /// assert_eq!((&a + &b).to_string(), "[[5/6, 0]]");
+    /// ```
+    #[inline]
+    fn add(self, other: &Matrix<T>) -> Matrix<T> {
+        let mut sum = self.clone();
+        sum += other;
+        sum
+    }
+}
+
+impl<T: AddAssign> AddAssign<Matrix<T>> for Matrix<T> {
+    /// Adds a [`Matrix`] to a [`Matrix`] in place, taking the [`Matrix`] on the right-hand side
+    /// by value.
+    ///
+    /// $$
+    /// A \gets A + B.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in the
+    /// entries of `self` and `other`.
+    ///
+    /// # Panics
+    /// Panics if the matrices have different dimensions.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_matrix::Matrix;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let mut a = Matrix::from_entries(2, 2, [1, 2, 3, 4].map(Integer::from).to_vec());
+    /// a += Matrix::<Integer>::identity(2);
+    /// assert_eq!(a.to_string(), "[[2, 2], [3, 5]]");
+    /// ```
+    fn add_assign(&mut self, other: Matrix<T>) {
+        assert_same_shape(self, &other);
+        for (x, y) in self.entries.iter_mut().zip(other.entries) {
+            *x += y;
+        }
+    }
+}
+
+impl<T: for<'a> AddAssign<&'a T>> AddAssign<&Matrix<T>> for Matrix<T> {
+    /// Adds a [`Matrix`] to a [`Matrix`] in place, taking the [`Matrix`] on the right-hand side
+    /// by reference.
+    ///
+    /// $$
+    /// A \gets A + B.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(m) = O(m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the total number of bits in the entries
+    /// of `self` and `other`, and $m$ is the total number of bits in the entries of `other`.
+    ///
+    /// # Panics
+    /// Panics if the matrices have different dimensions.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_matrix::Matrix;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let mut a = Matrix::from_entries(2, 2, [1, 2, 3, 4].map(Integer::from).to_vec());
+    /// a += &Matrix::<Integer>::identity(2);
+    /// assert_eq!(a.to_string(), "[[2, 2], [3, 5]]");
+    /// ```
+    fn add_assign(&mut self, other: &Matrix<T>) {
+        assert_same_shape(self, other);
+        for (x, y) in self.entries.iter_mut().zip(&other.entries) {
+            *x += y;
+        }
+    }
+}
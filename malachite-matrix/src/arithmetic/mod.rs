@@ -0,0 +1,18 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+/// Addition of [`Matrix`](crate::Matrix)s.
+pub mod add;
+/// Multiplication of [`Matrix`](crate::Matrix)s.
+pub mod mul;
+/// Negation of [`Matrix`](crate::Matrix)s.
+pub mod neg;
+/// Subtraction of [`Matrix`](crate::Matrix)s.
+pub mod sub;
+/// Transposition of [`Matrix`](crate::Matrix)s.
+pub mod transpose;
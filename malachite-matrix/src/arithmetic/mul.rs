@@ -0,0 +1,264 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Matrix;
+use alloc::vec::Vec;
+use core::ops::{AddAssign, Mul, MulAssign};
+use malachite_base::num::basic::traits::Zero;
+
+impl<T> Mul<Matrix<T>> for Matrix<T>
+where
+    T: AddAssign + Zero,
+    for<'a> &'a T: Mul<&'a T, Output = T>,
+{
+    type Output = Matrix<T>;
+
+    /// Multiplies two [`Matrix`]s, taking both by value.
+    ///
+    /// $$
+    /// f(A, B) = AB.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 m \log m \log\log m)$
+    ///
+    /// $M(n, m) = O(n^2 m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the maximum of the dimensions of
+    /// `self` and `other`, and $m$ is the maximum number of bits in an entry of `self` or `other`.
+    ///
+    /// # Panics
+    /// Panics if the width of `self` is not equal to the height of `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_matrix::Matrix;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let a = Matrix::from_entries(2, 2, [1, 2, 3, 4].map(Integer::from).to_vec());
+    /// let b = Matrix::from_entries(2, 1, [5, 6].map(Integer::from).to_vec());
+    /// assert_eq!((a * b).to_string(), "[[17], [39]]");
+    /// ```
+    #[inline]
+    fn mul(self, other: Matrix<T>) -> Matrix<T> {
+        &self * &other
+    }
+}
+
+impl<T> Mul<&Matrix<T>> for Matrix<T>
+where
+    T: AddAssign + Zero,
+    for<'a> &'a T: Mul<&'a T, Output = T>,
+{
+    type Output = Matrix<T>;
+
+    /// Multiplies two [`Matrix`]s, taking the first by value and the second by reference.
+    ///
+    /// $$
+    /// f(A, B) = AB.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 m \log m \log\log m)$
+    ///
+    /// $M(n, m) = O(n^2 m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the maximum of the dimensions of
+    /// `self` and `other`, and $m$ is the maximum number of bits in an entry of `self` or `other`.
+    ///
+    /// # Panics
+    /// Panics if the width of `self` is not equal to the height of `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_matrix::Matrix;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let a = Matrix::from_entries(2, 2, [1, 2, 3, 4].map(Integer::from).to_vec());
+    /// let b = Matrix::from_entries(2, 1, [5, 6].map(Integer::from).to_vec());
+    /// assert_eq!((a * &b).to_string(), "[[17], [39]]");
+    /// ```
+    #[inline]
+    fn mul(self, other: &Matrix<T>) -> Matrix<T> {
+        &self * other
+    }
+}
+
+impl<T> Mul<Matrix<T>> for &Matrix<T>
+where
+    T: AddAssign + Zero,
+    for<'a> &'a T: Mul<&'a T, Output = T>,
+{
+    type Output = Matrix<T>;
+
+    /// Multiplies two [`Matrix`]s, taking the first by reference and the second by value.
+    ///
+    /// $$
+    /// f(A, B) = AB.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 m \log m \log\log m)$
+    ///
+    /// $M(n, m) = O(n^2 m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the maximum of the dimensions of
+    /// `self` and `other`, and $m$ is the maximum number of bits in an entry of `self` or `other`.
+    ///
+    /// # Panics
+    /// Panics if the width of `self` is not equal to the height of `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_matrix::Matrix;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let a = Matrix::from_entries(2, 2, [1, 2, 3, 4].map(Integer::from).to_vec());
+    /// let b = Matrix::from_entries(2, 1, [5, 6].map(Integer::from).to_vec());
+    /// assert_eq!((&a * b).to_string(), "[[17], [39]]");
+    /// ```
+    #[inline]
+    fn mul(self, other: Matrix<T>) -> Matrix<T> {
+        self * &other
+    }
+}
+
+impl<T> Mul<&Matrix<T>> for &Matrix<T>
+where
+    T: AddAssign + Zero,
+    for<'a> &'a T: Mul<&'a T, Output = T>,
+{
+    type Output = Matrix<T>;
+
+    /// Multiplies two [`Matrix`]s, taking both by reference.
+    ///
+    /// $$
+    /// f(A, B) = AB.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 m \log m \log\log m)$
+    ///
+    /// $M(n, m) = O(n^2 m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the maximum of the dimensions of
+    /// `self` and `other`, and $m$ is the maximum number of bits in an entry of `self` or `other`.
+    ///
+    /// # Panics
+    /// Panics if the width of `self` is not equal to the height of `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_matrix::Matrix;
+    /// use malachite_q::Rational;
+    ///
+    /// let a = Matrix::from_rows(vec![
+    ///     vec![Rational::from_signeds(1, 2), Rational::from(1)],
+    ///     vec![Rational::from(0), Rational::from_signeds(-1, 3)],
+    /// ]);
+    /// assert_eq!((&a * &a).to_string(), "[[1/4, 1/6], [0, 1/9]]");
+    /// ```
+    fn mul(self, other: &Matrix<T>) -> Matrix<T> {
+        assert_eq!(
+            self.width, other.height,
+            "The width of the first matrix must equal the height of the second"
+        );
+        let mut entries = Vec::with_capacity(self.height * other.width);
+        for i in 0..self.height {
+            let row = self.row(i);
+            for j in 0..other.width {
+                let mut sum = T::ZERO;
+                for (k, x) in row.iter().enumerate() {
+                    sum += x * &other.entries[k * other.width + j];
+                }
+                entries.push(sum);
+            }
+        }
+        Matrix {
+            height: self.height,
+            width: other.width,
+            entries,
+        }
+    }
+}
+
+impl<T> MulAssign<Matrix<T>> for Matrix<T>
+where
+    T: AddAssign + Zero,
+    for<'a> &'a T: Mul<&'a T, Output = T>,
+{
+    /// Multiplies a [`Matrix`] by a [`Matrix`] on the right in place, taking the [`Matrix`] on the
+    /// right-hand side by value.
+    ///
+    /// $$
+    /// A \gets AB.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 m \log m \log\log m)$
+    ///
+    /// $M(n, m) = O(n^2 m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the maximum of the dimensions of
+    /// `self` and `other`, and $m$ is the maximum number of bits in an entry of `self` or `other`.
+    ///
+    /// # Panics
+    /// Panics if the width of `self` is not equal to the height of `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_matrix::Matrix;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let mut a = Matrix::from_entries(2, 2, [1, 2, 3, 4].map(Integer::from).to_vec());
+    /// a *= Matrix::from_entries(2, 1, [5, 6].map(Integer::from).to_vec());
+    /// assert_eq!(a.to_string(), "[[17], [39]]");
+    /// ```
+    #[inline]
+    fn mul_assign(&mut self, other: Matrix<T>) {
+        *self = &*self * &other;
+    }
+}
+
+impl<T> MulAssign<&Matrix<T>> for Matrix<T>
+where
+    T: AddAssign + Zero,
+    for<'a> &'a T: Mul<&'a T, Output = T>,
+{
+    /// Multiplies a [`Matrix`] by a [`Matrix`] on the right in place, taking the [`Matrix`] on the
+    /// right-hand side by reference.
+    ///
+    /// $$
+    /// A \gets AB.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 m \log m \log\log m)$
+    ///
+    /// $M(n, m) = O(n^2 m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the maximum of the dimensions of
+    /// `self` and `other`, and $m$ is the maximum number of bits in an entry of `self` or `other`.
+    ///
+    /// # Panics
+    /// Panics if the width of `self` is not equal to the height of `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_matrix::Matrix;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let mut a = Matrix::from_entries(2, 2, [1, 2, 3, 4].map(Integer::from).to_vec());
+    /// a *= &Matrix::from_entries(2, 1, [5, 6].map(Integer::from).to_vec());
+    /// assert_eq!(a.to_string(), "[[17], [39]]");
+    /// ```
+    #[inline]
+    fn mul_assign(&mut self, other: &Matrix<T>) {
+        *self = &*self * other;
+    }
+}
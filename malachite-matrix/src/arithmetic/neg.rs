@@ -0,0 +1,111 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Matrix;
+use core::ops::Neg;
+use malachite_base::num::arithmetic::traits::NegAssign;
+
+impl<T: NegAssign> Neg for Matrix<T> {
+    type Output = Matrix<T>;
+
+    /// Negates a [`Matrix`], taking it by value.
+    ///
+    /// $$
+    /// f(A) = -A.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in the
+    /// entries of `self`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_matrix::Matrix;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let a = Matrix::from_entries(2, 2, [1, -2, 0, 4].map(Integer::from).to_vec());
+    /// assert_eq!((-a).to_string(), "[[-1, 2], [0, -4]]");
+    /// ```
+    fn neg(mut self) -> Matrix<T> {
+        self.neg_assign();
+        self
+    }
+}
+
+impl<T> Neg for &Matrix<T>
+where
+    for<'a> &'a T: Neg<Output = T>,
+{
+    type Output = Matrix<T>;
+
+    /// Negates a [`Matrix`], taking it by reference.
+    ///
+    /// $$
+    /// f(A) = -A.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in the
+    /// entries of `self`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_matrix::Matrix;
+    /// use malachite_q::Rational;
+    ///
+    /// let a = Matrix::from_rows(vec![vec![Rational::from_signeds(1, 2), Rational::from(-1)]]);
+    /// assert_eq!((-&a).to_string(), "[[-1/2, 1]]");
+    /// ```
+    fn neg(self) -> Matrix<T> {
+        Matrix {
+            height: self.height,
+            width: self.width,
+            entries: self.entries.iter().map(|x| -x).collect(),
+        }
+    }
+}
+
+impl<T: NegAssign> NegAssign for Matrix<T> {
+    /// Negates a [`Matrix`] in place.
+    ///
+    /// $$
+    /// A \gets -A.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in the
+    /// entries of `self`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::NegAssign;
+    /// use malachite_matrix::Matrix;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let mut a = Matrix::from_entries(2, 2, [1, -2, 0, 4].map(Integer::from).to_vec());
+    /// a.neg_assign();
+    /// assert_eq!(a.to_string(), "[[-1, 2], [0, -4]]");
+    /// ```
+    fn neg_assign(&mut self) {
+        for x in &mut self.entries {
+            x.neg_assign();
+        }
+    }
+}
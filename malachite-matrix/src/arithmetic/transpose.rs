@@ -0,0 +1,46 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Matrix;
+use alloc::vec::Vec;
+
+impl<T: Clone> Matrix<T> {
+    /// Returns the transpose of a [`Matrix`].
+    ///
+    /// $f(A) = A^T$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in the
+    /// entries of `self`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_matrix::Matrix;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let a = Matrix::from_entries(2, 3, [1, 2, 3, 4, 5, 6].map(Integer::from).to_vec());
+    /// assert_eq!(a.transpose().to_string(), "[[1, 4], [2, 5], [3, 6]]");
+    /// ```
+    pub fn transpose(&self) -> Matrix<T> {
+        let mut entries = Vec::with_capacity(self.entries.len());
+        for j in 0..self.width {
+            for i in 0..self.height {
+                entries.push(self.entries[i * self.width + j].clone());
+            }
+        }
+        Matrix {
+            height: self.width,
+            width: self.height,
+            entries,
+        }
+    }
+}
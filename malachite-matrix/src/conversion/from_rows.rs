@@ -0,0 +1,136 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Matrix;
+use alloc::vec::Vec;
+use malachite_base::num::basic::traits::{One, Zero};
+
+impl<T> Matrix<T> {
+    /// Creates a [`Matrix`] from its height, width, and entries in row-major order.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Panics
+    /// Panics if the number of entries is not `height * width`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_matrix::Matrix;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let m = Matrix::from_entries(2, 3, [1, 2, 3, 4, 5, 6].map(Integer::from).to_vec());
+    /// assert_eq!(m.to_string(), "[[1, 2, 3], [4, 5, 6]]");
+    ///
+    /// let m = Matrix::<Integer>::from_entries(0, 3, Vec::new());
+    /// assert_eq!(m.width(), 3);
+    /// ```
+    pub fn from_entries(height: usize, width: usize, entries: Vec<T>) -> Matrix<T> {
+        assert_eq!(
+            height.checked_mul(width),
+            Some(entries.len()),
+            "The number of entries must be the product of the height and width"
+        );
+        Matrix {
+            height,
+            width,
+            entries,
+        }
+    }
+
+    /// Creates a [`Matrix`] from a [`Vec`] of rows. If there are no rows, the width is zero.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the number of entries.
+    ///
+    /// # Panics
+    /// Panics if the rows don't all have the same length.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_matrix::Matrix;
+    /// use malachite_q::Rational;
+    ///
+    /// let m = Matrix::from_rows(vec![
+    ///     vec![Rational::from(1), Rational::from_signeds(1, 2)],
+    ///     vec![Rational::from_signeds(-2, 3), Rational::from(0)],
+    /// ]);
+    /// assert_eq!(m.to_string(), "[[1, 1/2], [-2/3, 0]]");
+    /// ```
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Matrix<T> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+        let mut entries = Vec::with_capacity(height * width);
+        for row in rows {
+            assert_eq!(row.len(), width, "All rows must have the same length");
+            entries.extend(row);
+        }
+        Matrix {
+            height,
+            width,
+            entries,
+        }
+    }
+}
+
+impl<T: Clone + Zero> Matrix<T> {
+    /// Creates a [`Matrix`] of the given height and width whose entries are all zero.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `height * width`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_matrix::Matrix;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Matrix::<Integer>::zero(2, 3).to_string(), "[[0, 0, 0], [0, 0, 0]]");
+    /// ```
+    pub fn zero(height: usize, width: usize) -> Matrix<T> {
+        Matrix {
+            height,
+            width,
+            entries: vec![T::ZERO; height * width],
+        }
+    }
+}
+
+impl<T: Clone + Zero + One> Matrix<T> {
+    /// Creates the `n` by `n` identity [`Matrix`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n^2)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `n`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_matrix::Matrix;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(Matrix::<Rational>::identity(2).to_string(), "[[1, 0], [0, 1]]");
+    /// assert_eq!(Matrix::<Rational>::identity(0).to_string(), "[]");
+    /// ```
+    pub fn identity(n: usize) -> Matrix<T> {
+        let mut m = Matrix::zero(n, n);
+        for i in 0..n {
+            m.entries[i * (n + 1)] = T::ONE;
+        }
+        m
+    }
+}
@@ -0,0 +1,15 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+/// Functions for constructing a [`Matrix`](crate::Matrix) from its entries or rows, and for
+/// constructing zero and identity matrices.
+pub mod from_rows;
+/// Functions for getting the dimensions, entries, and rows of a [`Matrix`](crate::Matrix).
+pub mod to_rows;
+/// Implementations of [`Display`](core::fmt::Display) and [`Debug`] for [`Matrix`](crate::Matrix)s.
+pub mod to_string;
@@ -0,0 +1,163 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Matrix;
+use alloc::vec::Vec;
+
+impl<T> Matrix<T> {
+    /// Returns the number of rows of a [`Matrix`].
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_matrix::Matrix;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Matrix::<Integer>::zero(2, 3).height(), 2);
+    /// ```
+    pub const fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns the number of columns of a [`Matrix`].
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_matrix::Matrix;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Matrix::<Integer>::zero(2, 3).width(), 3);
+    /// ```
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Determines whether a [`Matrix`] is square.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_matrix::Matrix;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Matrix::<Integer>::zero(2, 3).is_square(), false);
+    /// assert_eq!(Matrix::<Integer>::identity(3).is_square(), true);
+    /// ```
+    pub const fn is_square(&self) -> bool {
+        self.height == self.width
+    }
+
+    /// Returns a reference to the entry of a [`Matrix`] in row `i` and column `j`.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Panics
+    /// Panics if `i >= self.height()` or `j >= self.width()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_matrix::Matrix;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let m = Matrix::from_entries(2, 2, [1, 2, 3, 4].map(Integer::from).to_vec());
+    /// assert_eq!(*m.get(1, 0), 3);
+    /// ```
+    pub fn get(&self, i: usize, j: usize) -> &T {
+        assert!(i < self.height && j < self.width, "Index out of bounds");
+        &self.entries[i * self.width + j]
+    }
+
+    /// Returns row `i` of a [`Matrix`] as a slice.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Panics
+    /// Panics if `i >= self.height()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_matrix::Matrix;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let m = Matrix::from_entries(2, 2, [1, 2, 3, 4].map(Integer::from).to_vec());
+    /// assert_eq!(m.row(1), &[Integer::from(3), Integer::from(4)]);
+    /// ```
+    pub fn row(&self, i: usize) -> &[T] {
+        assert!(i < self.height, "Index out of bounds");
+        &self.entries[i * self.width..(i + 1) * self.width]
+    }
+
+    /// Returns the entries of a [`Matrix`] in row-major order.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_matrix::Matrix;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let m = Matrix::from_entries(2, 2, [1, 2, 3, 4].map(Integer::from).to_vec());
+    /// assert_eq!(m.entries(), &[1, 2, 3, 4].map(Integer::from));
+    /// ```
+    pub fn entries(&self) -> &[T] {
+        &self.entries
+    }
+
+    /// Returns the entries of a [`Matrix`] in row-major order, taking the [`Matrix`] by value.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_matrix::Matrix;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let m = Matrix::from_entries(2, 2, [1, 2, 3, 4].map(Integer::from).to_vec());
+    /// assert_eq!(m.into_entries(), &[1, 2, 3, 4].map(Integer::from));
+    /// ```
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn into_entries(self) -> Vec<T> {
+        self.entries
+    }
+}
+
+impl<T: Clone> Matrix<T> {
+    /// Returns the rows of a [`Matrix`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the number of entries.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_matrix::Matrix;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let m = Matrix::from_entries(2, 2, [1, 2, 3, 4].map(Integer::from).to_vec());
+    /// assert_eq!(m.to_rows().to_debug_string(), "[[1, 2], [3, 4]]");
+    /// assert_eq!(Matrix::<Integer>::zero(2, 0).to_rows().to_debug_string(), "[[], []]");
+    /// ```
+    pub fn to_rows(&self) -> Vec<Vec<T>> {
+        (0..self.height).map(|i| self.row(i).to_vec()).collect()
+    }
+}
@@ -0,0 +1,83 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Matrix;
+use core::fmt::{Debug, Display, Formatter, Result, Write};
+
+impl<T: Display> Display for Matrix<T> {
+    /// Converts a [`Matrix`] to a [`String`], writing it as a list of rows.
+    ///
+    /// A [`Matrix`] with no rows is written as `"[]"`, regardless of its width.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in the
+    /// entries of `self`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_matrix::Matrix;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_q::Rational;
+    ///
+    /// let m = Matrix::from_entries(2, 2, [1, -2, 3, 4].map(Integer::from).to_vec());
+    /// assert_eq!(m.to_string(), "[[1, -2], [3, 4]]");
+    ///
+    /// let m = Matrix::from_rows(vec![vec![Rational::from_signeds(1, 2), Rational::from(3)]]);
+    /// assert_eq!(m.to_string(), "[[1/2, 3]]");
+    ///
+    /// assert_eq!(Matrix::<Integer>::zero(0, 3).to_string(), "[]");
+    /// ```
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        f.write_char('[')?;
+        for i in 0..self.height {
+            if i != 0 {
+                f.write_str(", ")?;
+            }
+            f.write_char('[')?;
+            for (j, x) in self.row(i).iter().enumerate() {
+                if j != 0 {
+                    f.write_str(", ")?;
+                }
+                Display::fmt(x, f)?;
+            }
+            f.write_char(']')?;
+        }
+        f.write_char(']')
+    }
+}
+
+impl<T: Display> Debug for Matrix<T> {
+    /// Converts a [`Matrix`] to a [`String`].
+    ///
+    /// This is the same as the [`Display::fmt`] implementation.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in the
+    /// entries of `self`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_matrix::Matrix;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Matrix::<Integer>::identity(2).to_debug_string(), "[[1, 0], [0, 1]]");
+    /// ```
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        Display::fmt(self, f)
+    }
+}
@@ -0,0 +1,198 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Matrix;
+use malachite_base::num::arithmetic::traits::{
+    DivExact, DivRound, DivisibleBy, ExtendedGcd, NegAssign,
+};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::rounding_modes::RoundingMode::*;
+use malachite_nz::integer::Integer;
+
+// Given two entries `a` and `b`, with `b` nonzero, returns $(s, t, u, v)$ such that $sa + tb =
+// \gcd(a, b)$, $ub - va = 0$, and $su + tv = 1$. Replacing a pair of rows or columns $(p, q)$ by
+// $(sp + tq, uq - vp)$ is then a unimodular operation that puts $\gcd(a, b)$ in place of $a$ and
+// zero in place of $b$. If $a$ divides $b$, the operation just subtracts a multiple of $p$ from
+// $q$; this keeps Smith normal form reduction from cycling.
+pub(crate) fn gcd_cofactors(a: &Integer, b: &Integer) -> (Integer, Integer, Integer, Integer) {
+    if *a != 0u32 && b.divisible_by(a) {
+        return (Integer::ONE, Integer::ZERO, Integer::ONE, b.div_exact(a));
+    }
+    let (g, s, t) = a.extended_gcd(b);
+    let g = Integer::from(g);
+    (s, t, a.div_exact(&g), b.div_exact(&g))
+}
+
+// Replaces rows `p` and `q` of `xs` by `s * row_p + t * row_q` and `u * row_q - v * row_p`.
+pub(crate) fn combine_rows(
+    xs: &mut [Integer],
+    width: usize,
+    p: usize,
+    q: usize,
+    (s, t, u, v): &(Integer, Integer, Integer, Integer),
+) {
+    for j in 0..width {
+        let x = &xs[p * width + j];
+        let y = &xs[q * width + j];
+        let new_x = s * x + t * y;
+        let new_y = u * y - v * x;
+        xs[p * width + j] = new_x;
+        xs[q * width + j] = new_y;
+    }
+}
+
+// Subtracts `c * row_q` from row `p` of `xs`.
+pub(crate) fn sub_mul_row(xs: &mut [Integer], width: usize, p: usize, q: usize, c: &Integer) {
+    for j in 0..width {
+        let y = c * &xs[q * width + j];
+        xs[p * width + j] -= y;
+    }
+}
+
+pub(crate) fn neg_row(xs: &mut [Integer], width: usize, p: usize) {
+    for x in &mut xs[p * width..(p + 1) * width] {
+        x.neg_assign();
+    }
+}
+
+pub(crate) fn swap_rows(xs: &mut [Integer], width: usize, p: usize, q: usize) {
+    if p != q {
+        for j in 0..width {
+            xs.swap(p * width + j, q * width + j);
+        }
+    }
+}
+
+// Brings `xs` into Hermite normal form using unimodular row operations. If `us` is given, the same
+// operations are applied to it.
+fn hermite(xs: &mut [Integer], height: usize, width: usize, mut us: Option<&mut [Integer]>) {
+    let mut r = 0;
+    for c in 0..width {
+        if r == height {
+            break;
+        }
+        for i in r + 1..height {
+            if xs[i * width + c] != 0u32 {
+                let cofactors = gcd_cofactors(&xs[r * width + c], &xs[i * width + c]);
+                combine_rows(xs, width, r, i, &cofactors);
+                if let Some(us) = us.as_deref_mut() {
+                    combine_rows(us, height, r, i, &cofactors);
+                }
+            }
+        }
+        let pivot = &xs[r * width + c];
+        if *pivot == 0u32 {
+            continue;
+        }
+        if *pivot < 0u32 {
+            neg_row(xs, width, r);
+            if let Some(us) = us.as_deref_mut() {
+                neg_row(us, height, r);
+            }
+        }
+        for i in 0..r {
+            let q = (&xs[i * width + c]).div_round(&xs[r * width + c], Floor).0;
+            if q != 0u32 {
+                sub_mul_row(xs, width, i, r, &q);
+                if let Some(us) = us.as_deref_mut() {
+                    sub_mul_row(us, height, i, r, &q);
+                }
+            }
+        }
+        r += 1;
+    }
+}
+
+impl Matrix<Integer> {
+    /// Computes the Hermite normal form of a [`Matrix`] over $\Z$.
+    ///
+    /// The Hermite normal form $H$ of $A$ is the unique [`Matrix`] in row echelon form that can be
+    /// obtained from $A$ by unimodular row operations (swapping rows, negating rows, and adding
+    /// integer multiples of one row to another), and that satisfies the following conditions:
+    /// - the first nonzero entry of each nonzero row, its pivot, is positive;
+    /// - every entry above a pivot is nonnegative and less than the pivot;
+    /// - any zero rows are at the bottom.
+    ///
+    /// The nonzero rows of $H$ form a canonical basis of the lattice spanned by the rows of $A$, so
+    /// two [`Matrix`]s of the same width have rows spanning the same lattice if and only if their
+    /// Hermite normal forms have the same nonzero rows.
+    ///
+    /// Pairs of rows are combined using the cofactors returned by
+    /// [`ExtendedGcd`](malachite_base::num::arithmetic::traits::ExtendedGcd), and entries above
+    /// the pivots are reduced using
+    /// [`DivRound`](malachite_base::num::arithmetic::traits::DivRound).
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 (nm) \log (nm) \log\log (nm))$
+    ///
+    /// $M(n, m) = O(n^2 (nm))$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `max(self.height(), self.width())`, and
+    /// $m$ is the maximum number of bits in an entry of `self`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_matrix::Matrix;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let a = Matrix::from_entries(2, 3, [2, 4, 4, -6, 6, 12].map(Integer::from).to_vec());
+    /// assert_eq!(a.hermite_normal_form().to_string(), "[[2, 4, 4], [0, 18, 24]]");
+    ///
+    /// let a = Matrix::from_entries(3, 2, [3, 1, 4, 1, 5, 9].map(Integer::from).to_vec());
+    /// assert_eq!(a.hermite_normal_form().to_string(), "[[1, 0], [0, 1], [0, 0]]");
+    /// ```
+    pub fn hermite_normal_form(&self) -> Matrix<Integer> {
+        let mut entries = self.entries.clone();
+        hermite(&mut entries, self.height, self.width, None);
+        Matrix {
+            height: self.height,
+            width: self.width,
+            entries,
+        }
+    }
+
+    /// Computes the Hermite normal form $H$ of a [`Matrix`] $A$ over $\Z$, together with a
+    /// unimodular [`Matrix`] $U$ such that $UA = H$.
+    ///
+    /// See [`hermite_normal_form`](Matrix::hermite_normal_form) for details. $U$ is square, with
+    /// size equal to the height of $A$, and its determinant is $\pm 1$. If $A$ has dependent rows,
+    /// $U$ is not unique.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 (nm) \log (nm) \log\log (nm))$
+    ///
+    /// $M(n, m) = O(n^2 (nm))$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `max(self.height(), self.width())`, and
+    /// $m$ is the maximum number of bits in an entry of `self`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_matrix::Matrix;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let a = Matrix::from_entries(2, 2, [4, 6, 2, 5].map(Integer::from).to_vec());
+    /// let (h, u) = a.hermite_normal_form_with_transform();
+    /// assert_eq!(h.to_string(), "[[2, 1], [0, 4]]");
+    /// assert_eq!(u.to_string(), "[[1, -1], [-1, 2]]");
+    /// assert_eq!(u * a, h);
+    /// ```
+    pub fn hermite_normal_form_with_transform(&self) -> (Matrix<Integer>, Matrix<Integer>) {
+        let mut entries = self.entries.clone();
+        let mut u = Matrix::identity(self.height);
+        hermite(&mut entries, self.height, self.width, Some(&mut u.entries));
+        (
+            Matrix {
+                height: self.height,
+                width: self.width,
+                entries,
+            },
+            u,
+        )
+    }
+}
@@ -0,0 +1,450 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::lattice::hermite_normal_form::{sub_mul_row, swap_rows};
+use crate::Matrix;
+use alloc::vec::Vec;
+use malachite_base::num::arithmetic::traits::{Abs, DivRound, Square};
+use malachite_base::num::basic::traits::{OneHalf, Zero};
+use malachite_base::num::conversion::traits::RoundingFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::*;
+use malachite_float::Float;
+use malachite_nz::integer::Integer;
+use malachite_q::Rational;
+
+fn dot(xs: &[Integer], ys: &[Integer]) -> Integer {
+    xs.iter().zip(ys).map(|(x, y)| x * y).sum()
+}
+
+// Rounds a `Rational` to the nearest `Integer`, using `DivRound` on its numerator and denominator.
+fn round(x: &Rational) -> Integer {
+    let (n, d) = x.numerator_and_denominator_ref();
+    Integer::from_sign_and_abs_ref(*x >= 0u32, n)
+        .div_round(Integer::from(d), Nearest)
+        .0
+}
+
+// Computes the Gram–Schmidt coefficients $\mu_{i,j}$ (in a `height` by `height` row-major
+// `Vec`) and the squared norms $B_i$ of the Gram–Schmidt vectors of the rows of `xs`. Returns
+// `None` if the rows are linearly dependent.
+fn gram_schmidt(
+    xs: &[Integer],
+    height: usize,
+    width: usize,
+) -> Option<(Vec<Rational>, Vec<Rational>)> {
+    let row = |i: usize| &xs[i * width..(i + 1) * width];
+    let mut mu = vec![Rational::ZERO; height * height];
+    let mut bs: Vec<Rational> = Vec::with_capacity(height);
+    for k in 0..height {
+        for j in 0..k {
+            let mut x = Rational::from(dot(row(k), row(j)));
+            for i in 0..j {
+                x -= &mu[j * height + i] * &mu[k * height + i] * &bs[i];
+            }
+            mu[k * height + j] = x / &bs[j];
+        }
+        let mut b = Rational::from(dot(row(k), row(k)));
+        for j in 0..k {
+            b -= (&mu[k * height + j]).square() * &bs[j];
+        }
+        if b == 0u32 {
+            return None;
+        }
+        bs.push(b);
+    }
+    Some((mu, bs))
+}
+
+fn is_reduced(
+    xs: &[Integer],
+    height: usize,
+    width: usize,
+    delta: &Rational,
+    eta: &Rational,
+) -> bool {
+    let Some((mu, bs)) = gram_schmidt(xs, height, width) else {
+        return false;
+    };
+    (1..height).all(|k| {
+        (0..k).all(|j| (&mu[k * height + j]).abs() <= *eta)
+            && delta * &bs[k - 1] <= &bs[k] + (&mu[k * height + k - 1]).square() * &bs[k - 1]
+    })
+}
+
+fn validate_delta(delta: &Rational) {
+    assert!(
+        *delta > Rational::from_unsigneds(1u32, 4) && *delta <= 1u32,
+        "delta must be greater than 1/4 and at most 1. Invalid delta: {delta}"
+    );
+}
+
+// LLL reduction with exact Gram–Schmidt orthogonalization; this is Algorithm 2.6.3 from Cohen's
+// "A Course in Computational Algebraic Number Theory". The Gram–Schmidt data are computed
+// incrementally, and are updated rather than recomputed when two rows are swapped.
+fn lll_exact(xs: &mut [Integer], height: usize, width: usize, delta: &Rational) {
+    if height == 0 {
+        return;
+    }
+    let d = height;
+    let mut mu = vec![Rational::ZERO; d * d];
+    let mut bs = vec![Rational::ZERO; d];
+    bs[0] = Rational::from(dot(&xs[..width], &xs[..width]));
+    assert!(bs[0] != 0u32, "The rows must be linearly independent");
+    // Size-reduces row k against row l.
+    let reduce = |xs: &mut [Integer], mu: &mut [Rational], k: usize, l: usize| {
+        if (&mu[k * d + l]).abs() <= Rational::ONE_HALF {
+            return;
+        }
+        let q = round(&mu[k * d + l]);
+        sub_mul_row(xs, width, k, l, &q);
+        mu[k * d + l] -= Rational::from(&q);
+        for i in 0..l {
+            let y = &mu[l * d + i] * Rational::from(&q);
+            mu[k * d + i] -= y;
+        }
+    };
+    let mut k = 1;
+    let mut k_max = 0;
+    while k < d {
+        if k > k_max {
+            k_max = k;
+            let row_k = &xs[k * width..(k + 1) * width];
+            for j in 0..k {
+                let mut x = Rational::from(dot(row_k, &xs[j * width..(j + 1) * width]));
+                for i in 0..j {
+                    x -= &mu[j * d + i] * &mu[k * d + i] * &bs[i];
+                }
+                mu[k * d + j] = x / &bs[j];
+            }
+            let mut b = Rational::from(dot(row_k, row_k));
+            for j in 0..k {
+                b -= (&mu[k * d + j]).square() * &bs[j];
+            }
+            assert!(b != 0u32, "The rows must be linearly independent");
+            bs[k] = b;
+        }
+        reduce(xs, &mut mu, k, k - 1);
+        if bs[k] < (delta - (&mu[k * d + k - 1]).square()) * &bs[k - 1] {
+            // Swap rows k - 1 and k, and update the Gram–Schmidt data.
+            swap_rows(xs, width, k - 1, k);
+            for j in 0..k - 1 {
+                mu.swap(k * d + j, (k - 1) * d + j);
+            }
+            let m = mu[k * d + k - 1].clone();
+            let b = &bs[k] + (&m).square() * &bs[k - 1];
+            mu[k * d + k - 1] = &m * &bs[k - 1] / &b;
+            bs[k] = &bs[k - 1] * &bs[k] / &b;
+            bs[k - 1] = b;
+            for i in k + 1..=k_max {
+                let t = mu[i * d + k].clone();
+                mu[i * d + k] = &mu[i * d + k - 1] - &m * &t;
+                mu[i * d + k - 1] = t + &mu[k * d + k - 1] * &mu[i * d + k];
+            }
+            k = (k - 1).max(1);
+        } else {
+            for l in (0..k - 1).rev() {
+                reduce(xs, &mut mu, k, l);
+            }
+            k += 1;
+        }
+    }
+}
+
+// One attempt at L² reduction with `prec`-bit floating-point Gram–Schmidt coefficients; this is
+// the algorithm from Nguyen and Stehlé's "An LLL Algorithm with Quadratic Complexity". The Gram
+// matrix of the rows is maintained exactly. Returns `false` if the precision turns out to be
+// insufficient, either because a Gram–Schmidt norm is computed as nonpositive or because size
+// reduction or swapping fails to terminate in a reasonable number of steps. Even in that case,
+// the rows of `xs` still form a basis of the original lattice.
+fn l2_attempt(
+    xs: &mut [Integer],
+    d: usize,
+    width: usize,
+    delta: &Rational,
+    eta: &Rational,
+    prec: u64,
+) -> bool {
+    if d == 0 {
+        return true;
+    }
+    let to_float = |x: &Integer| Float::from_integer_prec_ref(x, prec).0;
+    let mut g = vec![Integer::ZERO; d * d];
+    for i in 0..d {
+        for j in 0..=i {
+            let x = dot(
+                &xs[i * width..(i + 1) * width],
+                &xs[j * width..(j + 1) * width],
+            );
+            g[j * d + i] = x.clone();
+            g[i * d + j] = x;
+        }
+    }
+    let max_bits = (0..d)
+        .map(|i| g[i * d + i].significant_bits())
+        .max()
+        .unwrap_or(0);
+    let max_swaps = ((d * d) as u64 * (max_bits + 1)) << 6;
+    let mut swaps = 0;
+    let delta_f = Float::from_rational_prec_ref(delta, prec).0;
+    let mut r = vec![Float::ZERO; d * d];
+    let mut mu = vec![Float::ZERO; d * d];
+    r[0] = to_float(&g[0]);
+    let mut k = 1;
+    while k < d {
+        // Lazy size reduction: recompute the coefficients of row k and reduce it until no
+        // coefficient exceeds eta.
+        let max_passes = g[k * d + k].significant_bits() + 16;
+        let mut passes = 0;
+        loop {
+            for j in 0..k {
+                let mut x = to_float(&g[k * d + j]);
+                for i in 0..j {
+                    x = x
+                        .sub_prec(mu[j * d + i].mul_prec_ref_ref(&r[k * d + i], prec).0, prec)
+                        .0;
+                }
+                mu[k * d + j] = x.div_prec_ref_ref(&r[j * d + j], prec).0;
+                r[k * d + j] = x;
+                if !mu[k * d + j].is_finite() {
+                    return false;
+                }
+            }
+            if (0..k).all(|j| (&mu[k * d + j]).abs() <= *eta) {
+                break;
+            }
+            passes += 1;
+            if passes > max_passes {
+                return false;
+            }
+            for j in (0..k).rev() {
+                let q = Integer::rounding_from(&mu[k * d + j], Nearest).0;
+                if q == 0u32 {
+                    continue;
+                }
+                sub_mul_row(xs, width, k, j, &q);
+                let q = to_float(&q);
+                for i in 0..j {
+                    let y = q.mul_prec_ref_ref(&mu[j * d + i], prec).0;
+                    mu[k * d + i] = mu[k * d + i].sub_prec_ref_ref(&y, prec).0;
+                }
+            }
+            let row_k = &xs[k * width..(k + 1) * width];
+            for j in 0..d {
+                let x = dot(row_k, &xs[j * width..(j + 1) * width]);
+                g[j * d + k] = x.clone();
+                g[k * d + j] = x;
+            }
+        }
+        // s is the squared norm that the Gram–Schmidt vector of row k would have if row k were
+        // moved to position k - 1.
+        let mut s = to_float(&g[k * d + k]);
+        for j in 0..k - 1 {
+            s = s
+                .sub_prec(mu[k * d + j].mul_prec_ref_ref(&r[k * d + j], prec).0, prec)
+                .0;
+        }
+        if delta_f.mul_prec_ref_ref(&r[(k - 1) * d + k - 1], prec).0 > s {
+            swaps += 1;
+            if swaps > max_swaps || s <= 0u32 {
+                return false;
+            }
+            swap_rows(xs, width, k - 1, k);
+            swap_rows(&mut g, d, k - 1, k);
+            for i in 0..d {
+                g.swap(i * d + k - 1, i * d + k);
+            }
+            for j in 0..k - 1 {
+                r.swap((k - 1) * d + j, k * d + j);
+                mu.swap((k - 1) * d + j, k * d + j);
+            }
+            r[(k - 1) * d + k - 1] = s;
+            k = (k - 1).max(1);
+        } else {
+            let b = s
+                .sub_prec(
+                    mu[k * d + k - 1]
+                        .mul_prec_ref_ref(&r[k * d + k - 1], prec)
+                        .0,
+                    prec,
+                )
+                .0;
+            if b <= 0u32 {
+                return false;
+            }
+            r[k * d + k] = b;
+            k += 1;
+        }
+    }
+    true
+}
+
+impl Matrix<Integer> {
+    /// Determines whether the rows of a [`Matrix`] over $\Z$ form a $(\delta, \eta)$-LLL-reduced
+    /// basis.
+    ///
+    /// Let $b_1, \ldots, b_d$ be the rows, let $b_1^*, \ldots, b_d^*$ be their Gram–Schmidt
+    /// orthogonalization, and let $\mu_{i,j} = \langle b_i, b_j^* \rangle / \langle b_j^*, b_j^*
+    /// \rangle$. The basis is $(\delta, \eta)$-LLL-reduced if the rows are linearly independent,
+    /// if $|\mu_{i,j}| \leq \eta$ for all $j < i$ (size reduction), and if $\delta
+    /// \|b_{i-1}^*\|^2 \leq \|b_i^*\|^2 + \mu_{i,i-1}^2 \|b_{i-1}^*\|^2$ for all $i > 1$ (the
+    /// Lovász condition). The check is done exactly.
+    ///
+    /// # Worst-case complexity
+    /// $T(d, n, m) = O(d^3 (nm) \log (nm) \log\log (nm))$
+    ///
+    /// $M(d, n, m) = O(d^2 nm)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $d$ is `self.height()`, $n$ is
+    /// `max(self.height(), self.width())`, and $m$ is the maximum number of bits in an entry of
+    /// `self`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_matrix::Matrix;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_q::Rational;
+    ///
+    /// let delta = Rational::from_unsigneds(3u32, 4);
+    /// let half = Rational::from_unsigneds(1u32, 2);
+    /// let a = Matrix::from_entries(2, 2, [1, 0, 0, 1].map(Integer::from).to_vec());
+    /// assert!(a.is_lll_reduced(&delta, &half));
+    /// let a = Matrix::from_entries(2, 2, [1, 0, 5, 1].map(Integer::from).to_vec());
+    /// assert!(!a.is_lll_reduced(&delta, &half));
+    /// ```
+    pub fn is_lll_reduced(&self, delta: &Rational, eta: &Rational) -> bool {
+        is_reduced(&self.entries, self.height, self.width, delta, eta)
+    }
+
+    /// LLL-reduces the basis formed by the rows of a [`Matrix`] over $\Z$, using exact
+    /// Gram–Schmidt orthogonalization over $\Q$.
+    ///
+    /// The result is a basis of the same lattice that is $(\delta, \frac{1}{2})$-LLL-reduced; see
+    /// [`is_lll_reduced`](Matrix::is_lll_reduced). Its first row is at most $(\delta -
+    /// \frac{1}{4})^{-(d-1)/2}$ times as long as the shortest nonzero vector of the lattice, where
+    /// $d$ is the number of rows. The most common choice of $\delta$ is $\frac{3}{4}$; values
+    /// closer to 1 give better bases but take longer.
+    ///
+    /// All Gram–Schmidt data are exact [`Rational`]s, which are updated incrementally. The
+    /// Gram–Schmidt coefficients are rounded using
+    /// [`DivRound`](malachite_base::num::arithmetic::traits::DivRound). For large lattices,
+    /// [`lll_l2`](Matrix::lll_l2) is usually much faster.
+    ///
+    /// # Worst-case complexity
+    /// $T(d, n, m) = O(d^5 n m^3)$
+    ///
+    /// $M(d, n, m) = O(d^2 (d + n) m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $d$ is `self.height()`, $n$ is `self.width()`,
+    /// and $m$ is the maximum number of bits in an entry of `self`.
+    ///
+    /// # Panics
+    /// Panics if `delta` is not greater than $\frac{1}{4}$ and at most 1, or if the rows of `self`
+    /// are not linearly independent.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_matrix::Matrix;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_q::Rational;
+    ///
+    /// let a = Matrix::from_entries(
+    ///     3,
+    ///     3,
+    ///     [1, 1, 1, -1, 0, 2, 3, 5, 6].map(Integer::from).to_vec(),
+    /// );
+    /// assert_eq!(
+    ///     a.lll(&Rational::from_unsigneds(3u32, 4)).to_string(),
+    ///     "[[0, 1, 0], [1, 0, 1], [-1, 0, 2]]"
+    /// );
+    /// ```
+    pub fn lll(&self, delta: &Rational) -> Matrix<Integer> {
+        validate_delta(delta);
+        let mut entries = self.entries.clone();
+        lll_exact(&mut entries, self.height, self.width, delta);
+        Matrix {
+            height: self.height,
+            width: self.width,
+            entries,
+        }
+    }
+
+    /// LLL-reduces the basis formed by the rows of a [`Matrix`] over $\Z$, using Nguyen and
+    /// Stehlé's L² algorithm.
+    ///
+    /// The result is a basis of the same lattice that is $(\delta, \eta)$-LLL-reduced; see
+    /// [`is_lll_reduced`](Matrix::is_lll_reduced). Common choices are $\delta = \frac{99}{100}$
+    /// and $\eta = \frac{51}{100}$.
+    ///
+    /// The Gram matrix of the rows is kept exactly, but the Gram–Schmidt coefficients are
+    /// computed using [`Float`]s whose precision grows only linearly with the number of rows, and
+    /// does not depend on the size of the entries. This makes the algorithm much faster than
+    /// [`lll`](Matrix::lll) when the entries are large. The precision starts at $2d + 53$ bits;
+    /// if the computation breaks down, or if the result fails an exact check with
+    /// [`is_lll_reduced`](Matrix::is_lll_reduced), the precision is doubled and the reduction
+    /// continues from the current basis.
+    ///
+    /// # Worst-case complexity
+    /// $T(d, n, m) = O(d^4 n m (d + m))$
+    ///
+    /// $M(d, n, m) = O(d^2 (d + n + m))$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $d$ is `self.height()`, $n$ is `self.width()`,
+    /// and $m$ is the maximum number of bits in an entry of `self`.
+    ///
+    /// # Panics
+    /// Panics if `delta` is not strictly between $\frac{1}{4}$ and 1, if `eta` is not greater than
+    /// $\frac{1}{2}$ and less than $\sqrt{\delta}$, or if the rows of `self` are not linearly
+    /// independent.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_matrix::Matrix;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_q::Rational;
+    ///
+    /// let a = Matrix::from_entries(
+    ///     3,
+    ///     3,
+    ///     [1, 1, 1, -1, 0, 2, 3, 5, 6].map(Integer::from).to_vec(),
+    /// );
+    /// let delta = Rational::from_unsigneds(99u32, 100);
+    /// let eta = Rational::from_unsigneds(51u32, 100);
+    /// let b = a.lll_l2(&delta, &eta);
+    /// assert_eq!(b.to_string(), "[[0, 1, 0], [1, 0, 1], [-1, 0, 2]]");
+    /// assert!(b.is_lll_reduced(&delta, &eta));
+    /// ```
+    pub fn lll_l2(&self, delta: &Rational, eta: &Rational) -> Matrix<Integer> {
+        assert!(
+            *delta > Rational::from_unsigneds(1u32, 4) && *delta < 1u32,
+            "delta must be strictly between 1/4 and 1. Invalid delta: {delta}"
+        );
+        assert!(
+            *eta > Rational::ONE_HALF && eta.square() < *delta,
+            "eta must be greater than 1/2 and less than sqrt(delta). Invalid eta: {eta}"
+        );
+        assert_eq!(
+            self.rank(),
+            self.height,
+            "The rows must be linearly independent"
+        );
+        let d = self.height;
+        let mut entries = self.entries.clone();
+        let mut prec = ((d as u64) << 1) + 53;
+        while !(l2_attempt(&mut entries, d, self.width, delta, eta, prec)
+            && is_reduced(&entries, d, self.width, delta, eta))
+        {
+            prec <<= 1;
+        }
+        Matrix {
+            height: self.height,
+            width: self.width,
+            entries,
+        }
+    }
+}
@@ -0,0 +1,15 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+/// Hermite normal forms of [`Matrix`](crate::Matrix)s over $\Z$.
+pub mod hermite_normal_form;
+/// LLL reduction of lattice bases, using either exact Gram–Schmidt orthogonalization over $\Q$ or
+/// the floating-point L² algorithm.
+pub mod lll;
+/// Smith normal forms of [`Matrix`](crate::Matrix)s over $\Z$.
+pub mod smith_normal_form;
@@ -0,0 +1,223 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::lattice::hermite_normal_form::{
+    combine_rows, gcd_cofactors, neg_row, sub_mul_row, swap_rows,
+};
+use crate::Matrix;
+use malachite_base::num::arithmetic::traits::DivisibleBy;
+use malachite_base::num::basic::traits::NegativeOne;
+use malachite_nz::integer::Integer;
+
+// Replaces columns `p` and `q` of `xs` by `s * col_p + t * col_q` and `u * col_q - v * col_p`.
+fn combine_columns(
+    xs: &mut [Integer],
+    height: usize,
+    width: usize,
+    p: usize,
+    q: usize,
+    (s, t, u, v): &(Integer, Integer, Integer, Integer),
+) {
+    for i in 0..height {
+        let x = &xs[i * width + p];
+        let y = &xs[i * width + q];
+        let new_x = s * x + t * y;
+        let new_y = u * y - v * x;
+        xs[i * width + p] = new_x;
+        xs[i * width + q] = new_y;
+    }
+}
+
+fn swap_columns(xs: &mut [Integer], height: usize, width: usize, p: usize, q: usize) {
+    if p != q {
+        for i in 0..height {
+            xs.swap(i * width + p, i * width + q);
+        }
+    }
+}
+
+// Brings `xs` into Smith normal form using unimodular row and column operations. If `us` is given,
+// the row operations are applied to it, and if `vs` is given, the column operations are applied to
+// it.
+fn smith(
+    xs: &mut [Integer],
+    height: usize,
+    width: usize,
+    mut us: Option<&mut [Integer]>,
+    mut vs: Option<&mut [Integer]>,
+) {
+    for t in 0..height.min(width) {
+        loop {
+            // Move the entry with the smallest absolute value to the pivot position. Every pass
+            // through this loop either finishes the pivot or strictly decreases its absolute value.
+            let mut best: Option<(usize, usize)> = None;
+            for i in t..height {
+                for j in t..width {
+                    let x = &xs[i * width + j];
+                    if *x != 0u32
+                        && best.is_none_or(|(bi, bj)| {
+                            x.unsigned_abs_ref() < xs[bi * width + bj].unsigned_abs_ref()
+                        })
+                    {
+                        best = Some((i, j));
+                    }
+                }
+            }
+            let Some((pi, pj)) = best else {
+                return;
+            };
+            swap_rows(xs, width, t, pi);
+            if let Some(us) = us.as_deref_mut() {
+                swap_rows(us, height, t, pi);
+            }
+            swap_columns(xs, height, width, t, pj);
+            if let Some(vs) = vs.as_deref_mut() {
+                swap_columns(vs, width, width, t, pj);
+            }
+            for i in t + 1..height {
+                if xs[i * width + t] != 0u32 {
+                    let cofactors = gcd_cofactors(&xs[t * width + t], &xs[i * width + t]);
+                    combine_rows(xs, width, t, i, &cofactors);
+                    if let Some(us) = us.as_deref_mut() {
+                        combine_rows(us, height, t, i, &cofactors);
+                    }
+                }
+            }
+            for j in t + 1..width {
+                if xs[t * width + j] != 0u32 {
+                    let cofactors = gcd_cofactors(&xs[t * width + t], &xs[t * width + j]);
+                    combine_columns(xs, height, width, t, j, &cofactors);
+                    if let Some(vs) = vs.as_deref_mut() {
+                        combine_columns(vs, width, width, t, j, &cofactors);
+                    }
+                }
+            }
+            if (t + 1..height).any(|i| xs[i * width + t] != 0u32) {
+                continue;
+            }
+            // The pivot must divide every remaining entry. If it doesn't divide some entry, adding
+            // that entry's row to the pivot row lets the next pass replace the pivot by a proper
+            // divisor of itself.
+            let pivot = &xs[t * width + t];
+            let bad_row = (t + 1..height)
+                .find(|&i| (t + 1..width).any(|j| !(&xs[i * width + j]).divisible_by(pivot)));
+            let Some(i) = bad_row else {
+                break;
+            };
+            sub_mul_row(xs, width, t, i, &Integer::NEGATIVE_ONE);
+            if let Some(us) = us.as_deref_mut() {
+                sub_mul_row(us, height, t, i, &Integer::NEGATIVE_ONE);
+            }
+        }
+        if xs[t * width + t] < 0u32 {
+            neg_row(xs, width, t);
+            if let Some(us) = us.as_deref_mut() {
+                neg_row(us, height, t);
+            }
+        }
+    }
+}
+
+impl Matrix<Integer> {
+    /// Computes the Smith normal form of a [`Matrix`] over $\Z$.
+    ///
+    /// The Smith normal form $S$ of $A$ is the unique [`Matrix`] with the same dimensions as $A$
+    /// that can be written as $S = UAV$ for unimodular $U$ and $V$, whose only nonzero entries are
+    /// on the diagonal, and whose diagonal entries $d_1, d_2, \ldots$ are nonnegative and satisfy
+    /// $d_1 | d_2 | \cdots$. The nonzero $d_i$ are the invariant factors of $A$: the abelian group
+    /// $\Z^n / L$, where $L$ is the lattice spanned by the rows of $A$, is isomorphic to the
+    /// product of the $\Z / d_i \Z$ and a free part.
+    ///
+    /// Rows and columns are combined using the cofactors returned by
+    /// [`ExtendedGcd`](malachite_base::num::arithmetic::traits::ExtendedGcd).
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^4 (nm) \log (nm) \log\log (nm))$
+    ///
+    /// $M(n, m) = O(n^2 (nm))$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `max(self.height(), self.width())`, and
+    /// $m$ is the maximum number of bits in an entry of `self`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_matrix::Matrix;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let a = Matrix::from_entries(
+    ///     3,
+    ///     3,
+    ///     [2, 4, 4, -6, 6, 12, 10, -4, -16].map(Integer::from).to_vec(),
+    /// );
+    /// assert_eq!(
+    ///     a.smith_normal_form().to_string(),
+    ///     "[[2, 0, 0], [0, 6, 0], [0, 0, 12]]"
+    /// );
+    ///
+    /// let a = Matrix::from_entries(2, 3, [1, 2, 3, 4, 5, 6].map(Integer::from).to_vec());
+    /// assert_eq!(a.smith_normal_form().to_string(), "[[1, 0, 0], [0, 3, 0]]");
+    /// ```
+    pub fn smith_normal_form(&self) -> Matrix<Integer> {
+        let mut entries = self.entries.clone();
+        smith(&mut entries, self.height, self.width, None, None);
+        Matrix {
+            height: self.height,
+            width: self.width,
+            entries,
+        }
+    }
+
+    /// Computes the Smith normal form $S$ of a [`Matrix`] $A$ over $\Z$, together with unimodular
+    /// [`Matrix`]s $U$ and $V$ such that $UAV = S$.
+    ///
+    /// See [`smith_normal_form`](Matrix::smith_normal_form) for details. $U$ and $V$ are square,
+    /// with sizes equal to the height and width of $A$, respectively, and their determinants are
+    /// $\pm 1$. They are not unique.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^4 (nm) \log (nm) \log\log (nm))$
+    ///
+    /// $M(n, m) = O(n^2 (nm))$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `max(self.height(), self.width())`, and
+    /// $m$ is the maximum number of bits in an entry of `self`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_matrix::Matrix;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let a = Matrix::from_entries(2, 2, [4, 6, 2, 5].map(Integer::from).to_vec());
+    /// let (s, u, v) = a.smith_normal_form_with_transforms();
+    /// assert_eq!(s.to_string(), "[[1, 0], [0, 8]]");
+    /// assert_eq!(u * a * v, s);
+    /// ```
+    pub fn smith_normal_form_with_transforms(
+        &self,
+    ) -> (Matrix<Integer>, Matrix<Integer>, Matrix<Integer>) {
+        let mut entries = self.entries.clone();
+        let mut u = Matrix::identity(self.height);
+        let mut v = Matrix::identity(self.width);
+        smith(
+            &mut entries,
+            self.height,
+            self.width,
+            Some(&mut u.entries),
+            Some(&mut v.entries),
+        );
+        (
+            Matrix {
+                height: self.height,
+                width: self.width,
+                entries,
+            },
+            u,
+            v,
+        )
+    }
+}
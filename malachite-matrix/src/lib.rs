@@ -0,0 +1,141 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+//! This crate defines [`Matrix`], a dense matrix whose entries are [`Integer`]s or [`Rational`]s,
+//! together with exact linear algebra on such matrices.
+//! - Matrices can be added, subtracted, multiplied, and transposed.
+//! - Determinants, ranks, nullspaces, inverses, and solutions of linear systems are computed
+//!   exactly. All of these are built on fraction-free (Bareiss) elimination, which keeps every
+//!   intermediate entry a minor of the original matrix. This avoids the coefficient blow-up of
+//!   naive Gaussian elimination over $\Q$. A [`Matrix`] over $\Q$ is first reduced to a [`Matrix`]
+//!   over $\Z$ by clearing the denominators of each row.
+//! - Large nonsingular systems can also be solved using Dixon's $p$-adic lifting, which works
+//!   modulo a word-sized prime and recovers the solution using rational reconstruction.
+//! - The rows of a [`Matrix`] over $\Z$ can be treated as a lattice basis and LLL-reduced, either
+//!   exactly or using the floating-point L² algorithm. Hermite and Smith normal forms are also
+//!   available.
+//!
+//! A [`Matrix`] is stored as a [`Vec`](alloc::vec::Vec) of entries in row-major order, together
+//! with its height and width. Either dimension may be zero.
+//!
+//! # Features
+//! - `32_bit_limbs`: Sets the type of [`Limb`](malachite_nz#limbs) to [`u32`] instead of the
+//!   default, [`u64`].
+//! - `test_build`: A large proportion of the code in this crate is only used for testing. For a
+//!   typical user, building this code would result in an unnecessarily long compilation time and
+//!   an unnecessarily large binary. My solution is to only build this code when the `test_build`
+//!   feature is enabled. If you want to run unit tests, you must enable `test_build`. However,
+//!   doctests don't require it, since they only test the public interface.
+//!
+//! [`Integer`]: malachite_nz::integer::Integer
+//! [`Rational`]: malachite_q::Rational
+
+#![allow(
+    unstable_name_collisions,
+    clippy::assertions_on_constants,
+    clippy::cognitive_complexity,
+    clippy::many_single_char_names,
+    clippy::range_plus_one,
+    clippy::suspicious_arithmetic_impl,
+    clippy::suspicious_op_assign_impl,
+    clippy::too_many_arguments,
+    clippy::type_complexity,
+    clippy::upper_case_acronyms
+)]
+#![warn(
+    clippy::cast_lossless,
+    clippy::explicit_into_iter_loop,
+    clippy::explicit_iter_loop,
+    clippy::filter_map_next,
+    clippy::large_digit_groups,
+    clippy::manual_filter_map,
+    clippy::manual_find_map,
+    clippy::map_flatten,
+    clippy::map_unwrap_or,
+    clippy::match_same_arms,
+    clippy::missing_const_for_fn,
+    clippy::mut_mut,
+    clippy::needless_borrow,
+    clippy::needless_continue,
+    clippy::needless_pass_by_value,
+    clippy::print_stdout,
+    clippy::redundant_closure_for_method_calls,
+    clippy::single_match_else,
+    clippy::trait_duplication_in_bounds,
+    clippy::type_repetition_in_bounds,
+    clippy::uninlined_format_args,
+    clippy::unused_self,
+    clippy::if_not_else,
+    clippy::manual_assert,
+    clippy::range_plus_one,
+    clippy::redundant_else,
+    clippy::semicolon_if_nothing_returned,
+    clippy::cloned_instead_of_copied,
+    clippy::flat_map_option,
+    clippy::unnecessary_wraps,
+    clippy::unnested_or_patterns,
+    clippy::trivially_copy_pass_by_ref
+)]
+#![cfg_attr(not(any(feature = "test_build", feature = "random")), no_std)]
+
+#[macro_use]
+extern crate alloc;
+
+extern crate malachite_base;
+extern crate malachite_float;
+extern crate malachite_nz;
+extern crate malachite_q;
+
+use alloc::vec::Vec;
+use malachite_nz::integer::Integer;
+use malachite_q::Rational;
+
+/// A dense matrix.
+///
+/// The entries are stored in row-major order. The entry type `T` may be
+/// [`Integer`](malachite_nz::integer::Integer) or [`Rational`](malachite_q::Rational); the
+/// constructors and the basic arithmetic work for any entry type with the appropriate operations.
+#[derive(Clone, Hash, Eq, PartialEq)]
+pub struct Matrix<T> {
+    pub(crate) height: usize,
+    pub(crate) width: usize,
+    pub(crate) entries: Vec<T>,
+}
+
+impl Matrix<Integer> {
+    // Returns true iff `self` is valid. To be valid, the number of its entries must be the product
+    // of its height and width. All `Matrix`s must be valid.
+    #[cfg(feature = "test_build")]
+    pub fn is_valid(&self) -> bool {
+        self.entries.len() == self.height * self.width
+    }
+}
+
+impl Matrix<Rational> {
+    // Returns true iff `self` is valid. To be valid, the number of its entries must be the product
+    // of its height and width, and each of its entries must be valid. All `Matrix`s must be valid.
+    #[cfg(feature = "test_build")]
+    pub fn is_valid(&self) -> bool {
+        self.entries.len() == self.height * self.width
+            && self.entries.iter().all(Rational::is_valid)
+    }
+}
+
+/// Traits for arithmetic.
+pub mod arithmetic;
+/// Functions and traits for converting to and from [`Matrix`]s, including converting to strings.
+pub mod conversion;
+/// Lattice reduction and normal forms over $\Z$: LLL reduction, Hermite normal forms, and Smith
+/// normal forms.
+pub mod lattice;
+/// Exact linear algebra: fraction-free elimination, determinants, ranks, nullspaces, inverses, and
+/// linear system solving.
+pub mod linear_algebra;
+
+#[cfg(feature = "test_build")]
+pub mod test_util;
@@ -0,0 +1,110 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::linear_algebra::fraction_free_echelon_form::{bareiss, clear_row_denominators};
+use crate::Matrix;
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_nz::integer::Integer;
+use malachite_q::Rational;
+
+fn integer_determinant(mut xs: alloc::vec::Vec<Integer>, n: usize) -> Integer {
+    if n == 0 {
+        return Integer::ONE;
+    }
+    let (pivots, odd) = bareiss(&mut xs, n, n, n);
+    if pivots.len() < n {
+        Integer::ZERO
+    } else {
+        let determinant = xs.swap_remove(n * n - 1);
+        if odd {
+            -determinant
+        } else {
+            determinant
+        }
+    }
+}
+
+impl Matrix<Integer> {
+    /// Computes the determinant of a square [`Matrix`] over $\Z$.
+    ///
+    /// The determinant is computed using fraction-free (Bareiss) elimination; see
+    /// [`fraction_free_echelon_form`](Matrix::fraction_free_echelon_form). The determinant of the
+    /// $0 \times 0$ [`Matrix`] is 1.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^4 m \log (nm) \log\log (nm))$
+    ///
+    /// $M(n, m) = O(n^3 m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.height()`, and $m$ is the maximum
+    /// number of bits in an entry of `self`.
+    ///
+    /// # Panics
+    /// Panics if `self` is not square.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_matrix::Matrix;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let a = Matrix::from_entries(
+    ///     3,
+    ///     3,
+    ///     [2, 3, 1, 4, 1, -2, 6, 4, 1].map(Integer::from).to_vec(),
+    /// );
+    /// assert_eq!(a.determinant(), -20);
+    ///
+    /// let a = Matrix::from_entries(2, 2, [0, 1, 1, 0].map(Integer::from).to_vec());
+    /// assert_eq!(a.determinant(), -1);
+    ///
+    /// let a = Matrix::from_entries(2, 2, [1, 2, 2, 4].map(Integer::from).to_vec());
+    /// assert_eq!(a.determinant(), 0);
+    ///
+    /// assert_eq!(Matrix::<Integer>::identity(0).determinant(), 1);
+    /// ```
+    pub fn determinant(&self) -> Integer {
+        assert!(self.is_square(), "The matrix must be square");
+        integer_determinant(self.entries.clone(), self.height)
+    }
+}
+
+impl Matrix<Rational> {
+    /// Computes the determinant of a square [`Matrix`] over $\Q$.
+    ///
+    /// Each row is first multiplied by the LCM of its denominators, and the determinant of the
+    /// resulting [`Matrix`] over $\Z$ is computed using fraction-free (Bareiss) elimination. The
+    /// determinant of the $0 \times 0$ [`Matrix`] is 1.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^5 m \log (nm) \log\log (nm))$
+    ///
+    /// $M(n, m) = O(n^4 m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.height()`, and $m$ is the maximum
+    /// number of bits in an entry of `self`.
+    ///
+    /// # Panics
+    /// Panics if `self` is not square.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_matrix::Matrix;
+    /// use malachite_q::Rational;
+    ///
+    /// let a = Matrix::from_rows(vec![
+    ///     vec![Rational::from_signeds(1, 2), Rational::from_signeds(1, 3)],
+    ///     vec![Rational::from_signeds(1, 4), Rational::from_signeds(1, 5)],
+    /// ]);
+    /// assert_eq!(a.determinant().to_string(), "1/60");
+    /// ```
+    pub fn determinant(&self) -> Rational {
+        assert!(self.is_square(), "The matrix must be square");
+        let (xs, scale) = clear_row_denominators(&self.entries, self.height, self.width);
+        Rational::from_integers(integer_determinant(xs, self.height), Integer::from(scale))
+    }
+}
@@ -0,0 +1,172 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Matrix;
+use alloc::vec::Vec;
+use core::mem::take;
+use malachite_base::num::arithmetic::traits::{DivExact, DivExactAssign, Lcm};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::logic::traits::NotAssign;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_q::Rational;
+
+// Runs fraction-free (Bareiss) elimination in place on a row-major matrix with the given height and
+// width. Only the first `columns` columns are used as pivot columns; any remaining columns (for
+// example, the right-hand sides of a linear system) are transformed along with them. Returns the
+// pivot columns, in increasing order, and whether an odd number of row swaps was made.
+//
+// Afterwards, row `i` has zeros to the left of its pivot column `pivots[i]`, and the rows below the
+// last pivot row are zero in the first `columns` columns. Every entry that has been transformed is
+// a minor of the original (row-permuted) matrix, so all the divisions are exact; in particular, if
+// the matrix is square and nonsingular, the last pivot is its determinant, up to sign.
+pub(crate) fn bareiss(
+    xs: &mut [Integer],
+    height: usize,
+    width: usize,
+    columns: usize,
+) -> (Vec<usize>, bool) {
+    let mut pivots = Vec::new();
+    let mut odd = false;
+    let mut previous = Integer::ONE;
+    let mut r = 0;
+    for c in 0..columns {
+        if r == height {
+            break;
+        }
+        let Some(p) = (r..height).find(|&i| xs[i * width + c] != 0u32) else {
+            continue;
+        };
+        if p != r {
+            for j in c..width {
+                xs.swap(p * width + j, r * width + j);
+            }
+            odd.not_assign();
+        }
+        let (top, bottom) = xs.split_at_mut((r + 1) * width);
+        let pivot_row = &top[r * width..];
+        let pivot = &pivot_row[c];
+        for i in 0..height - r - 1 {
+            let row = &mut bottom[i * width..(i + 1) * width];
+            let factor = take(&mut row[c]);
+            for (x, y) in row[c + 1..].iter_mut().zip(&pivot_row[c + 1..]) {
+                *x *= pivot;
+                *x -= &factor * y;
+                x.div_exact_assign(&previous);
+            }
+        }
+        previous = pivot.clone();
+        pivots.push(c);
+        r += 1;
+    }
+    (pivots, odd)
+}
+
+// Multiplies each row of a row-major `Rational` matrix by the LCM of the denominators of its
+// entries, producing an `Integer` matrix with the same row space and nullspace. Also returns the
+// product of the multipliers.
+pub(crate) fn clear_row_denominators(
+    xs: &[Rational],
+    height: usize,
+    width: usize,
+) -> (Vec<Integer>, Natural) {
+    let mut ys = Vec::with_capacity(xs.len());
+    let mut scale = Natural::ONE;
+    for i in 0..height {
+        let row = &xs[i * width..(i + 1) * width];
+        let lcm = row
+            .iter()
+            .fold(Natural::ONE, |lcm, x| lcm.lcm(x.denominator_ref()));
+        ys.extend(row.iter().map(|x| {
+            Integer::from_sign_and_abs(
+                *x >= 0u32,
+                x.numerator_ref() * (&lcm).div_exact(x.denominator_ref()),
+            )
+        }));
+        scale *= lcm;
+    }
+    (ys, scale)
+}
+
+// Given the result of `bareiss` on a matrix of the given width, where all of the pivots are less
+// than `columns`, finds the $x$ with $x_j = 0$ for every non-pivot column $j$ such that
+// $\sum_j a_{ij} x_j = s a_{i,k}$ for each row $i$, where $k$ is `target` and $s$ is $-1$ if
+// `negate` is true and $1$ otherwise. The entry of $x$ at `target` is left as zero.
+pub(crate) fn back_substitute(
+    xs: &[Integer],
+    width: usize,
+    columns: usize,
+    pivots: &[usize],
+    target: usize,
+    negate: bool,
+) -> Vec<Rational> {
+    let mut solution = vec![Rational::ZERO; columns];
+    for (i, &c) in pivots.iter().enumerate().rev() {
+        let row = &xs[i * width..(i + 1) * width];
+        let mut sum = Rational::from(&row[target]);
+        if negate {
+            sum = -sum;
+        }
+        for &d in &pivots[i + 1..] {
+            sum -= Rational::from(&row[d]) * &solution[d];
+        }
+        solution[c] = sum / Rational::from(&row[c]);
+    }
+    solution
+}
+
+impl Matrix<Integer> {
+    /// Brings a [`Matrix`] over $\Z$ into row echelon form using fraction-free (Bareiss)
+    /// elimination.
+    ///
+    /// Naive Gaussian elimination over $\Q$ can produce entries whose size grows exponentially.
+    /// Bareiss's algorithm instead divides each updated entry exactly by the previous pivot, so
+    /// that every entry of the result is a minor of the original matrix (after some rows have been
+    /// swapped). The size of the entries therefore grows at most linearly, and the
+    /// [`Matrix`] stays over $\Z$.
+    ///
+    /// In the result, the first nonzero entry of each row is strictly to the right of the first
+    /// nonzero entry of the row above, and any zero rows are at the bottom. If the [`Matrix`] is
+    /// square and nonsingular, its last entry is the determinant, up to sign.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^4 m \log (nm) \log\log (nm))$
+    ///
+    /// $M(n, m) = O(n^3 m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `max(self.height(), self.width())`, and
+    /// $m$ is the maximum number of bits in an entry of `self`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_matrix::Matrix;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let a = Matrix::from_entries(
+    ///     3,
+    ///     3,
+    ///     [2, 3, 1, 4, 1, -2, 6, 4, 1].map(Integer::from).to_vec(),
+    /// );
+    /// assert_eq!(
+    ///     a.fraction_free_echelon_form().to_string(),
+    ///     "[[2, 3, 1], [0, -10, -8], [0, 0, -20]]"
+    /// );
+    ///
+    /// let a = Matrix::from_entries(2, 3, [0, 1, 2, 0, 2, 4].map(Integer::from).to_vec());
+    /// assert_eq!(a.fraction_free_echelon_form().to_string(), "[[0, 1, 2], [0, 0, 0]]");
+    /// ```
+    pub fn fraction_free_echelon_form(&self) -> Matrix<Integer> {
+        let mut entries = self.entries.clone();
+        bareiss(&mut entries, self.height, self.width, self.width);
+        Matrix {
+            height: self.height,
+            width: self.width,
+            entries,
+        }
+    }
+}
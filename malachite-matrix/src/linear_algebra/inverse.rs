@@ -0,0 +1,128 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::linear_algebra::fraction_free_echelon_form::{
+    back_substitute, bareiss, clear_row_denominators,
+};
+use crate::Matrix;
+use alloc::vec::Vec;
+use malachite_base::num::basic::traits::Zero;
+use malachite_nz::integer::Integer;
+use malachite_q::Rational;
+
+// Given an `n` by `2n` row-major `Integer` matrix $[A | B]$, returns $A^{-1}B$, or `None` if $A$
+// is singular.
+fn solve_for_columns(mut xs: Vec<Integer>, n: usize) -> Option<Matrix<Rational>> {
+    let width = n << 1;
+    let (pivots, _) = bareiss(&mut xs, n, width, n);
+    if pivots.len() < n {
+        return None;
+    }
+    let mut entries = vec![Rational::ZERO; n * n];
+    for j in 0..n {
+        for (i, x) in back_substitute(&xs, width, n, &pivots, n + j, false)
+            .into_iter()
+            .enumerate()
+        {
+            entries[i * n + j] = x;
+        }
+    }
+    Some(Matrix {
+        height: n,
+        width: n,
+        entries,
+    })
+}
+
+impl Matrix<Integer> {
+    /// Computes the inverse of a square [`Matrix`] over $\Z$, returning `None` if the [`Matrix`]
+    /// is singular. The inverse is a [`Matrix`] over $\Q$.
+    ///
+    /// The identity [`Matrix`] is appended to `self`, and the combined [`Matrix`] is brought into
+    /// row echelon form using fraction-free (Bareiss) elimination, followed by back-substitution.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^5 m \log (nm) \log\log (nm))$
+    ///
+    /// $M(n, m) = O(n^3 m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.height()`, and $m$ is the maximum
+    /// number of bits in an entry of `self`.
+    ///
+    /// # Panics
+    /// Panics if `self` is not square.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_matrix::Matrix;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let a = Matrix::from_entries(2, 2, [2, 1, 5, 3].map(Integer::from).to_vec());
+    /// assert_eq!(a.inverse().to_debug_string(), "Some([[3, -1], [-5, 2]])");
+    ///
+    /// let a = Matrix::from_entries(2, 2, [1, 2, 3, 4].map(Integer::from).to_vec());
+    /// assert_eq!(a.inverse().to_debug_string(), "Some([[-2, 1], [3/2, -1/2]])");
+    ///
+    /// let a = Matrix::from_entries(2, 2, [1, 2, 2, 4].map(Integer::from).to_vec());
+    /// assert_eq!(a.inverse(), None);
+    /// ```
+    pub fn inverse(&self) -> Option<Matrix<Rational>> {
+        assert!(self.is_square(), "The matrix must be square");
+        let n = self.height;
+        let mut xs = Vec::with_capacity((n * n) << 1);
+        for i in 0..n {
+            xs.extend_from_slice(self.row(i));
+            xs.extend((0..n).map(|j| Integer::from(i == j)));
+        }
+        solve_for_columns(xs, n)
+    }
+}
+
+impl Matrix<Rational> {
+    /// Computes the inverse of a square [`Matrix`] over $\Q$, returning `None` if the [`Matrix`]
+    /// is singular.
+    ///
+    /// The identity [`Matrix`] is appended to `self`, the denominators of each row of the combined
+    /// [`Matrix`] are cleared, and the result is brought into row echelon form using
+    /// fraction-free (Bareiss) elimination, followed by back-substitution.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^6 m \log (nm) \log\log (nm))$
+    ///
+    /// $M(n, m) = O(n^4 m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.height()`, and $m$ is the maximum
+    /// number of bits in an entry of `self`.
+    ///
+    /// # Panics
+    /// Panics if `self` is not square.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_matrix::Matrix;
+    /// use malachite_q::Rational;
+    ///
+    /// let a = Matrix::from_rows(vec![
+    ///     vec![Rational::from_signeds(1, 2), Rational::from_signeds(1, 3)],
+    ///     vec![Rational::from_signeds(1, 4), Rational::from_signeds(1, 5)],
+    /// ]);
+    /// assert_eq!(a.inverse().to_debug_string(), "Some([[12, -20], [-15, 30]])");
+    /// ```
+    pub fn inverse(&self) -> Option<Matrix<Rational>> {
+        assert!(self.is_square(), "The matrix must be square");
+        let n = self.height;
+        let mut xs = Vec::with_capacity((n * n) << 1);
+        for i in 0..n {
+            xs.extend_from_slice(self.row(i));
+            xs.extend((0..n).map(|j| Rational::from(i == j)));
+        }
+        solve_for_columns(clear_row_denominators(&xs, n, n << 1).0, n)
+    }
+}
@@ -0,0 +1,23 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+/// Determinants of square [`Matrix`](crate::Matrix)s.
+pub mod determinant;
+/// Fraction-free (Bareiss) elimination, which brings a [`Matrix`](crate::Matrix) over $\Z$ into
+/// row echelon form without leaving $\Z$.
+pub mod fraction_free_echelon_form;
+/// Inverses of square [`Matrix`](crate::Matrix)s.
+pub mod inverse;
+/// Bases of the nullspace (kernel) of a [`Matrix`](crate::Matrix).
+pub mod nullspace;
+/// Ranks of [`Matrix`](crate::Matrix)s.
+pub mod rank;
+/// Solutions of linear systems $Ax = b$ using fraction-free elimination.
+pub mod solve;
+/// Solutions of nonsingular linear systems $Ax = b$ using Dixon's $p$-adic lifting.
+pub mod solve_dixon;
@@ -0,0 +1,115 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::linear_algebra::fraction_free_echelon_form::{
+    back_substitute, bareiss, clear_row_denominators,
+};
+use crate::Matrix;
+use alloc::vec::Vec;
+use malachite_base::num::basic::traits::One;
+use malachite_nz::integer::Integer;
+use malachite_q::Rational;
+
+// Returns the basis of the nullspace of a row-major `Integer` matrix in which the $k$th vector has
+// a 1 in the position of the $k$th non-pivot column and a 0 in the positions of all the other
+// non-pivot columns.
+fn rational_nullspace(mut xs: Vec<Integer>, height: usize, width: usize) -> Vec<Vec<Rational>> {
+    let (pivots, _) = bareiss(&mut xs, height, width, width);
+    let mut basis = Vec::with_capacity(width - pivots.len());
+    let mut remaining_pivots = pivots.iter().peekable();
+    for f in 0..width {
+        if remaining_pivots.next_if_eq(&&f).is_some() {
+            continue;
+        }
+        let mut v = back_substitute(&xs, width, width, &pivots, f, true);
+        v[f] = Rational::ONE;
+        basis.push(v);
+    }
+    basis
+}
+
+impl Matrix<Integer> {
+    /// Computes a basis of the nullspace of a [`Matrix`] over $\Z$; that is, of the vectors $x$
+    /// with $Ax = 0$.
+    ///
+    /// There is one basis vector for each column that does not contain a pivot in the
+    /// [fraction-free echelon form](Matrix::fraction_free_echelon_form) of `self`. Each basis
+    /// vector is the smallest positive integer multiple of the vector with a 1 in the position of
+    /// its column and 0 in the positions of the other non-pivot columns; in particular, the basis
+    /// vectors are primitive (the GCD of each vector's entries is 1). They form a basis of the
+    /// nullspace over $\Q$, but not necessarily of the integer lattice of solutions.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^5 m \log (nm) \log\log (nm))$
+    ///
+    /// $M(n, m) = O(n^3 m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `max(self.height(), self.width())`, and
+    /// $m$ is the maximum number of bits in an entry of `self`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_matrix::Matrix;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let a = Matrix::from_entries(2, 3, [1, 2, 3, 4, 5, 6].map(Integer::from).to_vec());
+    /// assert_eq!(a.nullspace().to_debug_string(), "[[1, -2, 1]]");
+    ///
+    /// let a = Matrix::from_entries(1, 3, [2, 4, 3].map(Integer::from).to_vec());
+    /// assert_eq!(a.nullspace().to_debug_string(), "[[-2, 1, 0], [-3, 0, 2]]");
+    ///
+    /// assert_eq!(Matrix::<Integer>::identity(2).nullspace().to_debug_string(), "[]");
+    /// ```
+    pub fn nullspace(&self) -> Vec<Vec<Integer>> {
+        rational_nullspace(self.entries.clone(), self.height, self.width)
+            .into_iter()
+            .map(|v| {
+                // Since one entry is 1, the entries of the scaled vector are coprime
+                let (xs, _) = clear_row_denominators(&v, 1, v.len());
+                xs
+            })
+            .collect()
+    }
+}
+
+impl Matrix<Rational> {
+    /// Computes a basis of the nullspace of a [`Matrix`] over $\Q$; that is, of the vectors $x$
+    /// with $Ax = 0$.
+    ///
+    /// There is one basis vector for each non-pivot column of the row echelon form of `self`. The
+    /// basis vector corresponding to a non-pivot column has a 1 in the position of that column, and
+    /// a 0 in the positions of the other non-pivot columns. This is the basis that can be read off
+    /// from the reduced row echelon form of `self`.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^5 m \log (nm) \log\log (nm))$
+    ///
+    /// $M(n, m) = O(n^4 m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `max(self.height(), self.width())`, and
+    /// $m$ is the maximum number of bits in an entry of `self`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_matrix::Matrix;
+    /// use malachite_q::Rational;
+    ///
+    /// let a = Matrix::from_rows(vec![vec![
+    ///     Rational::from(2),
+    ///     Rational::from(4),
+    ///     Rational::from_signeds(1, 2),
+    /// ]]);
+    /// assert_eq!(a.nullspace().to_debug_string(), "[[-2, 1, 0], [-1/4, 0, 1]]");
+    /// ```
+    pub fn nullspace(&self) -> Vec<Vec<Rational>> {
+        let (xs, _) = clear_row_denominators(&self.entries, self.height, self.width);
+        rational_nullspace(xs, self.height, self.width)
+    }
+}
@@ -0,0 +1,73 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::linear_algebra::fraction_free_echelon_form::{bareiss, clear_row_denominators};
+use crate::Matrix;
+use malachite_nz::integer::Integer;
+use malachite_q::Rational;
+
+impl Matrix<Integer> {
+    /// Computes the rank of a [`Matrix`] over $\Z$; that is, the dimension of the space spanned by
+    /// its rows (or columns) over $\Q$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^4 m \log (nm) \log\log (nm))$
+    ///
+    /// $M(n, m) = O(n^3 m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `max(self.height(), self.width())`, and
+    /// $m$ is the maximum number of bits in an entry of `self`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_matrix::Matrix;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let a = Matrix::from_entries(3, 3, [1, 2, 3, 4, 5, 6, 7, 8, 9].map(Integer::from).to_vec());
+    /// assert_eq!(a.rank(), 2);
+    /// assert_eq!(Matrix::<Integer>::identity(4).rank(), 4);
+    /// assert_eq!(Matrix::<Integer>::zero(2, 3).rank(), 0);
+    /// ```
+    pub fn rank(&self) -> usize {
+        let mut xs = self.entries.clone();
+        bareiss(&mut xs, self.height, self.width, self.width)
+            .0
+            .len()
+    }
+}
+
+impl Matrix<Rational> {
+    /// Computes the rank of a [`Matrix`] over $\Q$; that is, the dimension of the space spanned by
+    /// its rows (or columns).
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^5 m \log (nm) \log\log (nm))$
+    ///
+    /// $M(n, m) = O(n^4 m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `max(self.height(), self.width())`, and
+    /// $m$ is the maximum number of bits in an entry of `self`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_matrix::Matrix;
+    /// use malachite_q::Rational;
+    ///
+    /// let a = Matrix::from_rows(vec![
+    ///     vec![Rational::from_signeds(1, 2), Rational::from(1)],
+    ///     vec![Rational::from_signeds(1, 3), Rational::from_signeds(2, 3)],
+    /// ]);
+    /// assert_eq!(a.rank(), 1);
+    /// ```
+    pub fn rank(&self) -> usize {
+        let (mut xs, _) = clear_row_denominators(&self.entries, self.height, self.width);
+        bareiss(&mut xs, self.height, self.width, self.width)
+            .0
+            .len()
+    }
+}
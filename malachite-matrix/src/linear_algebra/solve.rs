@@ -0,0 +1,143 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::linear_algebra::fraction_free_echelon_form::{
+    back_substitute, bareiss, clear_row_denominators,
+};
+use crate::Matrix;
+use alloc::vec::Vec;
+use malachite_nz::integer::Integer;
+use malachite_q::Rational;
+
+// Given an `height` by `width + 1` row-major `Integer` matrix $[A | b]$, returns a solution of
+// $Ax = b$ whose free variables are zero, or `None` if there is no solution.
+fn solve_augmented(mut xs: Vec<Integer>, height: usize, width: usize) -> Option<Vec<Rational>> {
+    let (pivots, _) = bareiss(&mut xs, height, width + 1, width + 1);
+    if pivots.last() == Some(&width) {
+        // Some row reduces to $0 = c$ with $c \neq 0$
+        None
+    } else {
+        Some(back_substitute(
+            &xs,
+            width + 1,
+            width,
+            &pivots,
+            width,
+            false,
+        ))
+    }
+}
+
+// Returns the row-major entries of the augmented matrix $[A | b]$.
+pub(crate) fn augment<T: Clone>(a: &Matrix<T>, b: &[T]) -> Vec<T> {
+    assert_eq!(
+        b.len(),
+        a.height,
+        "The length of the right-hand side must equal the height of the matrix"
+    );
+    let mut xs = Vec::with_capacity(a.entries.len() + b.len());
+    for (i, y) in b.iter().enumerate() {
+        xs.extend_from_slice(a.row(i));
+        xs.push(y.clone());
+    }
+    xs
+}
+
+impl Matrix<Integer> {
+    /// Solves the linear system $Ax = b$, where $A$ is `self`, returning `None` if the system has
+    /// no solution.
+    ///
+    /// $A$ need not be square. If the system has more than one solution, the one returned has
+    /// zeros in the positions of the columns that don't contain a pivot in the
+    /// [fraction-free echelon form](Matrix::fraction_free_echelon_form) of $A$; the other
+    /// solutions can be obtained by adding elements of the [`nullspace`](Matrix::nullspace).
+    ///
+    /// The augmented [`Matrix`] $[A | b]$ is brought into row echelon form using fraction-free
+    /// (Bareiss) elimination, followed by back-substitution. For large nonsingular systems,
+    /// [`solve_dixon`](Matrix::solve_dixon) may be faster.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^4 m \log (nm) \log\log (nm))$
+    ///
+    /// $M(n, m) = O(n^3 m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `max(self.height(), self.width())`, and
+    /// $m$ is the maximum number of bits in an entry of `self` or `b`.
+    ///
+    /// # Panics
+    /// Panics if the length of `b` is not equal to the height of `self`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_matrix::Matrix;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let a = Matrix::from_entries(2, 2, [2, 1, 1, 3].map(Integer::from).to_vec());
+    /// assert_eq!(
+    ///     a.solve(&[3, 5].map(Integer::from)).to_debug_string(),
+    ///     "Some([4/5, 7/5])"
+    /// );
+    ///
+    /// // Underdetermined: the free variable is set to zero
+    /// let a = Matrix::from_entries(1, 2, [1, 1].map(Integer::from).to_vec());
+    /// assert_eq!(a.solve(&[Integer::from(2)]).to_debug_string(), "Some([2, 0])");
+    ///
+    /// // Inconsistent
+    /// let a = Matrix::from_entries(2, 1, [1, 1].map(Integer::from).to_vec());
+    /// assert_eq!(a.solve(&[1, 2].map(Integer::from)), None);
+    /// ```
+    pub fn solve(&self, b: &[Integer]) -> Option<Vec<Rational>> {
+        solve_augmented(augment(self, b), self.height, self.width)
+    }
+}
+
+impl Matrix<Rational> {
+    /// Solves the linear system $Ax = b$, where $A$ is `self`, returning `None` if the system has
+    /// no solution.
+    ///
+    /// $A$ need not be square. If the system has more than one solution, the one returned has
+    /// zeros in the positions of the columns that don't contain a pivot in the row echelon form of
+    /// $A$; the other solutions can be obtained by adding elements of the
+    /// [`nullspace`](Matrix::nullspace).
+    ///
+    /// The denominators of each row of the augmented [`Matrix`] $[A | b]$ are cleared, and the
+    /// result is brought into row echelon form using fraction-free (Bareiss) elimination, followed
+    /// by back-substitution.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^5 m \log (nm) \log\log (nm))$
+    ///
+    /// $M(n, m) = O(n^4 m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `max(self.height(), self.width())`, and
+    /// $m$ is the maximum number of bits in an entry of `self` or `b`.
+    ///
+    /// # Panics
+    /// Panics if the length of `b` is not equal to the height of `self`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_matrix::Matrix;
+    /// use malachite_q::Rational;
+    ///
+    /// let a = Matrix::from_rows(vec![
+    ///     vec![Rational::from_signeds(1, 2), Rational::from(1)],
+    ///     vec![Rational::from(0), Rational::from_signeds(1, 3)],
+    /// ]);
+    /// assert_eq!(
+    ///     a.solve(&[Rational::from(1), Rational::from(1)]).to_debug_string(),
+    ///     "Some([-4, 3])"
+    /// );
+    /// ```
+    pub fn solve(&self, b: &[Rational]) -> Option<Vec<Rational>> {
+        let (xs, _) = clear_row_denominators(&augment(self, b), self.height, self.width + 1);
+        solve_augmented(xs, self.height, self.width)
+    }
+}
@@ -0,0 +1,250 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::linear_algebra::fraction_free_echelon_form::clear_row_denominators;
+use crate::linear_algebra::solve::augment;
+use crate::Matrix;
+use alloc::vec::Vec;
+use malachite_base::num::arithmetic::traits::{
+    DivExactAssign, Mod, ModAdd, ModInverse, ModMul, ModMulAssign, ModSub, PowerOf2, Square,
+};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::factorization::traits::IsPrime;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_q::Rational;
+
+// Every prime used is less than $2^{32}$, so that products of residues fit in a `u64`, and greater
+// than $2^{31}$.
+const LARGEST_PRIME_BELOW_2_32: u64 = 4294967291;
+const PRIME_BITS: u64 = 31;
+
+fn reduce(x: &Integer, p: &Integer) -> u64 {
+    u64::exact_from(&x.mod_op(p))
+}
+
+// Inverts an `n` by `n` row-major matrix modulo a prime `p` using Gauss-Jordan elimination,
+// returning `None` if the matrix is singular modulo `p`.
+fn inverse_mod_prime(mut xs: Vec<u64>, n: usize, p: u64) -> Option<Vec<u64>> {
+    let mut inverse = vec![0; n * n];
+    for i in 0..n {
+        inverse[i * (n + 1)] = 1;
+    }
+    for c in 0..n {
+        let r = (c..n).find(|&i| xs[i * n + c] != 0)?;
+        if r != c {
+            for j in 0..n {
+                xs.swap(r * n + j, c * n + j);
+                inverse.swap(r * n + j, c * n + j);
+            }
+        }
+        let pivot_inverse = xs[c * n + c].mod_inverse(p).unwrap();
+        for j in 0..n {
+            xs[c * n + j].mod_mul_assign(pivot_inverse, p);
+            inverse[c * n + j].mod_mul_assign(pivot_inverse, p);
+        }
+        for i in 0..n {
+            let factor = xs[i * n + c];
+            if i == c || factor == 0 {
+                continue;
+            }
+            for j in 0..n {
+                xs[i * n + j] = xs[i * n + j].mod_sub(factor.mod_mul(xs[c * n + j], p), p);
+                inverse[i * n + j] =
+                    inverse[i * n + j].mod_sub(factor.mod_mul(inverse[c * n + j], p), p);
+            }
+        }
+    }
+    Some(inverse)
+}
+
+// Returns $\lceil \log_2 \lVert v \rVert \rceil$ or slightly more, where $\lVert v \rVert$ is the
+// Euclidean norm of $v$ and the square of the norm is `norm_squared`.
+fn norm_bits(norm_squared: &Natural) -> u64 {
+    (norm_squared.significant_bits() + 1) >> 1
+}
+
+// Solves $Ax = b$, where $A$ is an `n` by `n` row-major `Integer` matrix, using Dixon's $p$-adic
+// lifting. Returns `None` if $A$ is singular.
+fn solve_dixon_integer(a: &[Integer], b: &[Integer], n: usize) -> Option<Vec<Rational>> {
+    if n == 0 {
+        return Some(Vec::new());
+    }
+    // By Hadamard's inequality, $|\det A| < 2^d$, and by Cramer's rule each entry of the solution
+    // is $\det A_i / \det A$, where $A_i$ is $A$ with its $i$th column replaced by $b$, and
+    // $|\det A_i| < 2^e$.
+    let mut det_bits = 0;
+    let mut numerator_bits = 0;
+    for (row, y) in a.chunks_exact(n).zip(b) {
+        let mut norm_squared: Natural = row.iter().map(|x| x.unsigned_abs_ref().square()).sum();
+        det_bits += norm_bits(&norm_squared);
+        norm_squared += y.unsigned_abs_ref().square();
+        numerator_bits += norm_bits(&norm_squared);
+    }
+    // Find a prime $p$ that doesn't divide $\det A$. Each prime is greater than $2^{31}$, so if $A$
+    // is nonsingular, fewer than $d / 31$ of them divide $\det A$.
+    let mut failures = 0;
+    let mut p = LARGEST_PRIME_BELOW_2_32;
+    let inverse = loop {
+        let p_integer = Integer::from(p);
+        if let Some(inverse) =
+            inverse_mod_prime(a.iter().map(|x| reduce(x, &p_integer)).collect(), n, p)
+        {
+            break inverse;
+        }
+        failures += 1;
+        if failures > det_bits / PRIME_BITS {
+            return None;
+        }
+        p -= 2;
+        while !p.is_prime() {
+            p -= 2;
+        }
+    };
+    let p_integer = Integer::from(p);
+    let p_natural = Natural::from(p);
+    // Lift until $p^k > 2^{d + e + 1}$, so that rational reconstruction is unique
+    let target_bits = det_bits + numerator_bits + 1;
+    let mut residual = b.to_vec();
+    let mut lifted = vec![Natural::ZERO; n];
+    let mut modulus = Natural::ONE;
+    let mut digits = vec![0u64; n];
+    while modulus.significant_bits() <= target_bits {
+        // Find $x$ with $Ax \equiv r \mod p$, and then replace $r$ with $(r - Ax) / p$
+        let residual_mod: Vec<u64> = residual.iter().map(|x| reduce(x, &p_integer)).collect();
+        for (digit, row) in digits.iter_mut().zip(inverse.chunks_exact(n)) {
+            *digit = row
+                .iter()
+                .zip(&residual_mod)
+                .fold(0, |sum, (&x, &y)| sum.mod_add(x.mod_mul(y, p), p));
+        }
+        for (z, &digit) in lifted.iter_mut().zip(&digits) {
+            *z += &modulus * Natural::from(digit);
+        }
+        for (r, row) in residual.iter_mut().zip(a.chunks_exact(n)) {
+            for (x, &digit) in row.iter().zip(&digits) {
+                *r -= x * Integer::from(digit);
+            }
+            r.div_exact_assign(&p_integer);
+        }
+        modulus *= &p_natural;
+    }
+    let numerator_bound = Natural::power_of_2(numerator_bits);
+    let denominator_bound = Natural::power_of_2(det_bits);
+    Some(
+        lifted
+            .iter()
+            .map(|z| {
+                Rational::from_modular(z, &modulus, &numerator_bound, &denominator_bound).unwrap()
+            })
+            .collect(),
+    )
+}
+
+impl Matrix<Integer> {
+    /// Solves the linear system $Ax = b$, where $A$ is `self`, using Dixon's $p$-adic lifting.
+    /// Returns `None` if $A$ is singular.
+    ///
+    /// A prime $p$ slightly smaller than $2^{32}$ that does not divide $\det A$ is chosen, and
+    /// $A^{-1} \mod p$ is computed using word-sized arithmetic. The solution is then lifted to a
+    /// solution modulo $p^k$, where $p^k$ exceeds twice the product of the Hadamard bounds on the
+    /// numerators and the denominator of the solution. Finally, each entry of the solution is
+    /// recovered from its residue using rational reconstruction; see [`Rational::from_modular`].
+    ///
+    /// Unlike fraction-free elimination, which operates on numbers that grow to the size of the
+    /// determinant throughout, almost all of the work here is done on word-sized numbers, which
+    /// makes this method faster for large systems.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^3 (n + m)^2)$
+    ///
+    /// $M(n, m) = O(n^2 (n + m))$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.height()`, and $m$ is the maximum
+    /// number of bits in an entry of `self` or `b`.
+    ///
+    /// # Panics
+    /// Panics if `self` is not square or if the length of `b` is not equal to the height of
+    /// `self`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_matrix::Matrix;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// let a = Matrix::from_entries(2, 2, [2, 1, 1, 3].map(Integer::from).to_vec());
+    /// assert_eq!(
+    ///     a.solve_dixon(&[3, 5].map(Integer::from)).to_debug_string(),
+    ///     "Some([4/5, 7/5])"
+    /// );
+    ///
+    /// let a = Matrix::from_entries(2, 2, [1, 2, 2, 4].map(Integer::from).to_vec());
+    /// assert_eq!(a.solve_dixon(&[1, 2].map(Integer::from)), None);
+    /// ```
+    pub fn solve_dixon(&self, b: &[Integer]) -> Option<Vec<Rational>> {
+        assert!(self.is_square(), "The matrix must be square");
+        assert_eq!(
+            b.len(),
+            self.height,
+            "The length of the right-hand side must equal the height of the matrix"
+        );
+        solve_dixon_integer(&self.entries, b, self.height)
+    }
+}
+
+impl Matrix<Rational> {
+    /// Solves the linear system $Ax = b$, where $A$ is `self`, using Dixon's $p$-adic lifting.
+    /// Returns `None` if $A$ is singular.
+    ///
+    /// The denominators of each row of the augmented [`Matrix`] $[A | b]$ are cleared, and the
+    /// resulting system over $\Z$ is solved using
+    /// [`Matrix::<Integer>::solve_dixon`](Matrix::solve_dixon).
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(n^5 m^2)$
+    ///
+    /// $M(n, m) = O(n^3 m)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.height()`, and $m$ is the maximum
+    /// number of bits in an entry of `self` or `b`.
+    ///
+    /// # Panics
+    /// Panics if `self` is not square or if the length of `b` is not equal to the height of
+    /// `self`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_matrix::Matrix;
+    /// use malachite_q::Rational;
+    ///
+    /// let a = Matrix::from_rows(vec![
+    ///     vec![Rational::from_signeds(1, 2), Rational::from(1)],
+    ///     vec![Rational::from(0), Rational::from_signeds(1, 3)],
+    /// ]);
+    /// assert_eq!(
+    ///     a.solve_dixon(&[Rational::from(1), Rational::from(1)]).to_debug_string(),
+    ///     "Some([-4, 3])"
+    /// );
+    /// ```
+    pub fn solve_dixon(&self, b: &[Rational]) -> Option<Vec<Rational>> {
+        assert!(self.is_square(), "The matrix must be square");
+        let n = self.height;
+        let (xs, _) = clear_row_denominators(&augment(self, b), n, n + 1);
+        let mut a = Vec::with_capacity(n * n);
+        let mut c = Vec::with_capacity(n);
+        for row in xs.chunks_exact(n + 1) {
+            a.extend_from_slice(&row[..n]);
+            c.push(row[n].clone());
+        }
+        solve_dixon_integer(&a, &c, n)
+    }
+}
@@ -0,0 +1,141 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Matrix;
+use malachite_base::num::arithmetic::traits::FloorSqrt;
+use malachite_base::num::exhaustive::primitive_int_increasing_inclusive_range;
+use malachite_base::test_util::generators::common::It;
+use malachite_base::tuples::exhaustive::exhaustive_pairs;
+use malachite_base::vecs::exhaustive::exhaustive_vecs;
+use malachite_nz::integer::exhaustive::exhaustive_integers;
+use malachite_nz::integer::Integer;
+use malachite_q::exhaustive::exhaustive_rationals;
+use malachite_q::Rational;
+
+pub(crate) const MAX_WIDTH: usize = 4;
+
+// Uses as many of `xs` as possible as the entries of a `Matrix` with the given width.
+pub(crate) fn matrix_from_vec<T>((mut xs, width): (Vec<T>, usize)) -> Matrix<T> {
+    let height = xs.len() / width;
+    xs.truncate(height * width);
+    Matrix::from_entries(height, width, xs)
+}
+
+// Uses as many of `xs` as possible as the entries of a `Matrix` with the given width and with at
+// most as many rows as columns.
+pub(crate) fn wide_matrix_from_vec<T>((mut xs, width): (Vec<T>, usize)) -> Matrix<T> {
+    let height = (xs.len() / width).min(width);
+    xs.truncate(height * width);
+    Matrix::from_entries(height, width, xs)
+}
+
+// Uses as many of `xs` as possible as the entries of a square `Matrix`.
+pub(crate) fn square_matrix_from_vec<T>(mut xs: Vec<T>) -> Matrix<T> {
+    let n = xs.len().floor_sqrt();
+    xs.truncate(n * n);
+    Matrix::from_entries(n, n, xs)
+}
+
+// Uses as many of `xs` as possible as the entries of two square `Matrix`s of the same size.
+pub(crate) fn square_matrix_pair_from_vec<T>(mut xs: Vec<T>) -> (Matrix<T>, Matrix<T>) {
+    let n = (xs.len() >> 1).floor_sqrt();
+    xs.truncate((n * n) << 1);
+    let ys = xs.split_off(n * n);
+    (
+        Matrix::from_entries(n, n, xs),
+        Matrix::from_entries(n, n, ys),
+    )
+}
+
+// Uses as many of `xs` as possible as the entries of a `Matrix` $A$ with the given width and a
+// vector $b$ whose length is the height of $A$.
+pub(crate) fn matrix_vec_pair_from_vec<T>((mut xs, width): (Vec<T>, usize)) -> (Matrix<T>, Vec<T>) {
+    let height = xs.len() / (width + 1);
+    xs.truncate(height * (width + 1));
+    let ys = xs.split_off(height * width);
+    (Matrix::from_entries(height, width, xs), ys)
+}
+
+// Uses as many of `xs` as possible as the entries of a square `Matrix` $A$ and a vector $b$ whose
+// length is the height of $A$.
+pub(crate) fn square_matrix_vec_pair_from_vec<T>(mut xs: Vec<T>) -> (Matrix<T>, Vec<T>) {
+    let mut n = xs.len().floor_sqrt();
+    while n * (n + 1) > xs.len() {
+        n -= 1;
+    }
+    xs.truncate(n * (n + 1));
+    matrix_vec_pair_from_vec((xs, n))
+}
+
+fn widths() -> impl Clone + Iterator<Item = usize> {
+    primitive_int_increasing_inclusive_range(1, MAX_WIDTH)
+}
+
+// -- Matrix<Integer> --
+
+pub fn exhaustive_matrix_integer_gen() -> It<Matrix<Integer>> {
+    Box::new(
+        exhaustive_pairs(exhaustive_vecs(exhaustive_integers()), widths()).map(matrix_from_vec),
+    )
+}
+
+pub fn exhaustive_matrix_integer_gen_var_1() -> It<Matrix<Integer>> {
+    Box::new(exhaustive_vecs(exhaustive_integers()).map(square_matrix_from_vec))
+}
+
+pub fn exhaustive_matrix_integer_gen_var_2() -> It<Matrix<Integer>> {
+    Box::new(
+        exhaustive_pairs(exhaustive_vecs(exhaustive_integers()), widths())
+            .map(wide_matrix_from_vec)
+            .filter(|m| m.rank() == m.height()),
+    )
+}
+
+pub fn exhaustive_matrix_integer_pair_gen_var_1() -> It<(Matrix<Integer>, Matrix<Integer>)> {
+    Box::new(exhaustive_vecs(exhaustive_integers()).map(square_matrix_pair_from_vec))
+}
+
+pub fn exhaustive_matrix_integer_integer_vec_pair_gen() -> It<(Matrix<Integer>, Vec<Integer>)> {
+    Box::new(
+        exhaustive_pairs(exhaustive_vecs(exhaustive_integers()), widths())
+            .map(matrix_vec_pair_from_vec),
+    )
+}
+
+pub fn exhaustive_matrix_integer_integer_vec_pair_gen_var_1() -> It<(Matrix<Integer>, Vec<Integer>)>
+{
+    Box::new(exhaustive_vecs(exhaustive_integers()).map(square_matrix_vec_pair_from_vec))
+}
+
+// -- Matrix<Rational> --
+
+pub fn exhaustive_matrix_rational_gen() -> It<Matrix<Rational>> {
+    Box::new(
+        exhaustive_pairs(exhaustive_vecs(exhaustive_rationals()), widths()).map(matrix_from_vec),
+    )
+}
+
+pub fn exhaustive_matrix_rational_gen_var_1() -> It<Matrix<Rational>> {
+    Box::new(exhaustive_vecs(exhaustive_rationals()).map(square_matrix_from_vec))
+}
+
+pub fn exhaustive_matrix_rational_pair_gen_var_1() -> It<(Matrix<Rational>, Matrix<Rational>)> {
+    Box::new(exhaustive_vecs(exhaustive_rationals()).map(square_matrix_pair_from_vec))
+}
+
+pub fn exhaustive_matrix_rational_rational_vec_pair_gen() -> It<(Matrix<Rational>, Vec<Rational>)> {
+    Box::new(
+        exhaustive_pairs(exhaustive_vecs(exhaustive_rationals()), widths())
+            .map(matrix_vec_pair_from_vec),
+    )
+}
+
+pub fn exhaustive_matrix_rational_rational_vec_pair_gen_var_1(
+) -> It<(Matrix<Rational>, Vec<Rational>)> {
+    Box::new(exhaustive_vecs(exhaustive_rationals()).map(square_matrix_vec_pair_from_vec))
+}
@@ -0,0 +1,104 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::test_util::generators::exhaustive::*;
+use crate::test_util::generators::random::*;
+use crate::Matrix;
+use malachite_base::test_util::generators::common::Generator;
+use malachite_nz::integer::Integer;
+use malachite_q::Rational;
+
+// -- Matrix<Integer> --
+
+pub fn matrix_integer_gen() -> Generator<Matrix<Integer>> {
+    Generator::new_no_special(&exhaustive_matrix_integer_gen, &random_matrix_integer_gen)
+}
+
+// All square `Matrix<Integer>`s.
+pub fn matrix_integer_gen_var_1() -> Generator<Matrix<Integer>> {
+    Generator::new_no_special(
+        &exhaustive_matrix_integer_gen_var_1,
+        &random_matrix_integer_gen_var_1,
+    )
+}
+
+// All `Matrix<Integer>`s whose rows are linearly independent.
+pub fn matrix_integer_gen_var_2() -> Generator<Matrix<Integer>> {
+    Generator::new_no_special(
+        &exhaustive_matrix_integer_gen_var_2,
+        &random_matrix_integer_gen_var_2,
+    )
+}
+
+// All pairs of square `Matrix<Integer>`s with the same size.
+pub fn matrix_integer_pair_gen_var_1() -> Generator<(Matrix<Integer>, Matrix<Integer>)> {
+    Generator::new_no_special(
+        &exhaustive_matrix_integer_pair_gen_var_1,
+        &random_matrix_integer_pair_gen_var_1,
+    )
+}
+
+// All pairs of a `Matrix<Integer>` $A$ and a `Vec<Integer>` whose length is the height of $A$.
+pub fn matrix_integer_integer_vec_pair_gen() -> Generator<(Matrix<Integer>, Vec<Integer>)> {
+    Generator::new_no_special(
+        &exhaustive_matrix_integer_integer_vec_pair_gen,
+        &random_matrix_integer_integer_vec_pair_gen,
+    )
+}
+
+// All pairs of a square `Matrix<Integer>` $A$ and a `Vec<Integer>` whose length is the height of
+// $A$.
+pub fn matrix_integer_integer_vec_pair_gen_var_1() -> Generator<(Matrix<Integer>, Vec<Integer>)> {
+    Generator::new_no_special(
+        &exhaustive_matrix_integer_integer_vec_pair_gen_var_1,
+        &random_matrix_integer_integer_vec_pair_gen_var_1,
+    )
+}
+
+// -- Matrix<Rational> --
+
+pub fn matrix_rational_gen() -> Generator<Matrix<Rational>> {
+    Generator::new_no_special(&exhaustive_matrix_rational_gen, &random_matrix_rational_gen)
+}
+
+// All square `Matrix<Rational>`s.
+pub fn matrix_rational_gen_var_1() -> Generator<Matrix<Rational>> {
+    Generator::new_no_special(
+        &exhaustive_matrix_rational_gen_var_1,
+        &random_matrix_rational_gen_var_1,
+    )
+}
+
+// All pairs of square `Matrix<Rational>`s with the same size.
+pub fn matrix_rational_pair_gen_var_1() -> Generator<(Matrix<Rational>, Matrix<Rational>)> {
+    Generator::new_no_special(
+        &exhaustive_matrix_rational_pair_gen_var_1,
+        &random_matrix_rational_pair_gen_var_1,
+    )
+}
+
+// All pairs of a `Matrix<Rational>` $A$ and a `Vec<Rational>` whose length is the height of $A$.
+pub fn matrix_rational_rational_vec_pair_gen() -> Generator<(Matrix<Rational>, Vec<Rational>)> {
+    Generator::new_no_special(
+        &exhaustive_matrix_rational_rational_vec_pair_gen,
+        &random_matrix_rational_rational_vec_pair_gen,
+    )
+}
+
+// All pairs of a square `Matrix<Rational>` $A$ and a `Vec<Rational>` whose length is the height of
+// $A$.
+pub fn matrix_rational_rational_vec_pair_gen_var_1() -> Generator<(Matrix<Rational>, Vec<Rational>)>
+{
+    Generator::new_no_special(
+        &exhaustive_matrix_rational_rational_vec_pair_gen_var_1,
+        &random_matrix_rational_rational_vec_pair_gen_var_1,
+    )
+}
+
+pub mod exhaustive;
+pub mod random;
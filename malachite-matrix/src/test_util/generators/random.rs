@@ -0,0 +1,136 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::test_util::generators::exhaustive::{
+    matrix_from_vec, matrix_vec_pair_from_vec, square_matrix_from_vec, square_matrix_pair_from_vec,
+    square_matrix_vec_pair_from_vec, wide_matrix_from_vec, MAX_WIDTH,
+};
+use crate::Matrix;
+use malachite_base::num::random::random_unsigned_inclusive_range;
+use malachite_base::random::{Seed, EXAMPLE_SEED};
+use malachite_base::test_util::generators::common::{GenConfig, It};
+use malachite_base::tuples::random::random_pairs;
+use malachite_base::vecs::random::random_vecs;
+use malachite_nz::integer::random::random_integers;
+use malachite_nz::integer::Integer;
+use malachite_q::random::random_rationals;
+use malachite_q::Rational;
+
+fn random_integer_vecs(seed: Seed, config: &GenConfig) -> impl Iterator<Item = Vec<Integer>> {
+    random_vecs(
+        seed,
+        &|seed_2| {
+            random_integers(
+                seed_2,
+                config.get_or("mean_bits_n", 64),
+                config.get_or("mean_bits_d", 1),
+            )
+        },
+        config.get_or("mean_length_n", 16),
+        config.get_or("mean_length_d", 1),
+    )
+}
+
+fn random_rational_vecs(seed: Seed, config: &GenConfig) -> impl Iterator<Item = Vec<Rational>> {
+    random_vecs(
+        seed,
+        &|seed_2| {
+            random_rationals(
+                seed_2,
+                config.get_or("mean_bits_n", 64),
+                config.get_or("mean_bits_d", 1),
+            )
+        },
+        config.get_or("mean_length_n", 16),
+        config.get_or("mean_length_d", 1),
+    )
+}
+
+fn random_integer_vec_width_pairs(
+    config: &GenConfig,
+) -> impl Iterator<Item = (Vec<Integer>, usize)> {
+    random_pairs(
+        EXAMPLE_SEED,
+        &|seed| random_integer_vecs(seed, config),
+        &|seed| random_unsigned_inclusive_range(seed, 1, MAX_WIDTH),
+    )
+}
+
+fn random_rational_vec_width_pairs(
+    config: &GenConfig,
+) -> impl Iterator<Item = (Vec<Rational>, usize)> {
+    random_pairs(
+        EXAMPLE_SEED,
+        &|seed| random_rational_vecs(seed, config),
+        &|seed| random_unsigned_inclusive_range(seed, 1, MAX_WIDTH),
+    )
+}
+
+// -- Matrix<Integer> --
+
+pub fn random_matrix_integer_gen(config: &GenConfig) -> It<Matrix<Integer>> {
+    Box::new(random_integer_vec_width_pairs(config).map(matrix_from_vec))
+}
+
+pub fn random_matrix_integer_gen_var_1(config: &GenConfig) -> It<Matrix<Integer>> {
+    Box::new(random_integer_vecs(EXAMPLE_SEED, config).map(square_matrix_from_vec))
+}
+
+pub fn random_matrix_integer_gen_var_2(config: &GenConfig) -> It<Matrix<Integer>> {
+    Box::new(
+        random_integer_vec_width_pairs(config)
+            .map(wide_matrix_from_vec)
+            .filter(|m| m.rank() == m.height()),
+    )
+}
+
+pub fn random_matrix_integer_pair_gen_var_1(
+    config: &GenConfig,
+) -> It<(Matrix<Integer>, Matrix<Integer>)> {
+    Box::new(random_integer_vecs(EXAMPLE_SEED, config).map(square_matrix_pair_from_vec))
+}
+
+pub fn random_matrix_integer_integer_vec_pair_gen(
+    config: &GenConfig,
+) -> It<(Matrix<Integer>, Vec<Integer>)> {
+    Box::new(random_integer_vec_width_pairs(config).map(matrix_vec_pair_from_vec))
+}
+
+pub fn random_matrix_integer_integer_vec_pair_gen_var_1(
+    config: &GenConfig,
+) -> It<(Matrix<Integer>, Vec<Integer>)> {
+    Box::new(random_integer_vecs(EXAMPLE_SEED, config).map(square_matrix_vec_pair_from_vec))
+}
+
+// -- Matrix<Rational> --
+
+pub fn random_matrix_rational_gen(config: &GenConfig) -> It<Matrix<Rational>> {
+    Box::new(random_rational_vec_width_pairs(config).map(matrix_from_vec))
+}
+
+pub fn random_matrix_rational_gen_var_1(config: &GenConfig) -> It<Matrix<Rational>> {
+    Box::new(random_rational_vecs(EXAMPLE_SEED, config).map(square_matrix_from_vec))
+}
+
+pub fn random_matrix_rational_pair_gen_var_1(
+    config: &GenConfig,
+) -> It<(Matrix<Rational>, Matrix<Rational>)> {
+    Box::new(random_rational_vecs(EXAMPLE_SEED, config).map(square_matrix_pair_from_vec))
+}
+
+pub fn random_matrix_rational_rational_vec_pair_gen(
+    config: &GenConfig,
+) -> It<(Matrix<Rational>, Vec<Rational>)> {
+    Box::new(random_rational_vec_width_pairs(config).map(matrix_vec_pair_from_vec))
+}
+
+pub fn random_matrix_rational_rational_vec_pair_gen_var_1(
+    config: &GenConfig,
+) -> It<(Matrix<Rational>, Vec<Rational>)> {
+    Box::new(random_rational_vecs(EXAMPLE_SEED, config).map(square_matrix_vec_pair_from_vec))
+}
@@ -0,0 +1,50 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Matrix;
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_nz::integer::Integer;
+use malachite_q::Rational;
+
+// Computes a determinant using Gaussian elimination over $\Q$.
+pub fn matrix_rational_determinant_naive(a: &Matrix<Rational>) -> Rational {
+    assert!(a.is_square());
+    let n = a.height();
+    let mut xs = a.entries().to_vec();
+    let mut determinant = Rational::ONE;
+    for c in 0..n {
+        let Some(p) = (c..n).find(|&i| xs[i * n + c] != 0u32) else {
+            return Rational::ZERO;
+        };
+        if p != c {
+            for j in 0..n {
+                xs.swap(p * n + j, c * n + j);
+            }
+            determinant = -determinant;
+        }
+        let pivot = xs[c * n + c].clone();
+        determinant *= &pivot;
+        for i in c + 1..n {
+            let factor = &xs[i * n + c] / &pivot;
+            for j in c..n {
+                let y = &factor * &xs[c * n + j];
+                xs[i * n + j] -= y;
+            }
+        }
+    }
+    determinant
+}
+
+pub fn matrix_integer_determinant_naive(a: &Matrix<Integer>) -> Integer {
+    Integer::exact_from(matrix_rational_determinant_naive(&Matrix::from_entries(
+        a.height(),
+        a.width(),
+        a.entries().iter().map(Rational::from).collect(),
+    )))
+}
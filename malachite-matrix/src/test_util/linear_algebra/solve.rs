@@ -0,0 +1,69 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Matrix;
+use malachite_base::num::basic::traits::Zero;
+use malachite_nz::integer::Integer;
+use malachite_q::Rational;
+
+// Solves $Ax = b$ by bringing $[A | b]$ into reduced row echelon form over $\Q$, setting the free
+// variables to zero.
+pub fn matrix_rational_solve_naive(a: &Matrix<Rational>, b: &[Rational]) -> Option<Vec<Rational>> {
+    let height = a.height();
+    let width = a.width();
+    assert_eq!(b.len(), height);
+    let mut rows: Vec<Vec<Rational>> = (0..height)
+        .map(|i| {
+            let mut row = a.row(i).to_vec();
+            row.push(b[i].clone());
+            row
+        })
+        .collect();
+    let mut pivots = Vec::new();
+    let mut r = 0;
+    for c in 0..width {
+        let Some(p) = (r..height).find(|&i| rows[i][c] != 0u32) else {
+            continue;
+        };
+        rows.swap(p, r);
+        let pivot = rows[r][c].clone();
+        for x in &mut rows[r] {
+            *x /= &pivot;
+        }
+        let pivot_row = rows[r].clone();
+        for (i, row) in rows.iter_mut().enumerate() {
+            if i != r && row[c] != 0u32 {
+                let factor = row[c].clone();
+                for (x, y) in row.iter_mut().zip(&pivot_row) {
+                    *x -= &factor * y;
+                }
+            }
+        }
+        pivots.push(c);
+        r += 1;
+    }
+    if rows[r..].iter().any(|row| row[width] != 0u32) {
+        return None;
+    }
+    let mut solution = vec![Rational::ZERO; width];
+    for (row, &c) in rows.iter().zip(&pivots) {
+        solution[c] = row[width].clone();
+    }
+    Some(solution)
+}
+
+pub fn matrix_integer_solve_naive(a: &Matrix<Integer>, b: &[Integer]) -> Option<Vec<Rational>> {
+    matrix_rational_solve_naive(
+        &Matrix::from_entries(
+            a.height(),
+            a.width(),
+            a.entries().iter().map(Rational::from).collect(),
+        ),
+        &b.iter().map(Rational::from).collect::<Vec<_>>(),
+    )
+}
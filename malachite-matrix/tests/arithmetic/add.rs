@@ -0,0 +1,173 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::vecs::vec_from_str;
+use malachite_matrix::test_util::generators::{
+    matrix_integer_gen, matrix_integer_pair_gen_var_1, matrix_rational_gen,
+    matrix_rational_pair_gen_var_1,
+};
+use malachite_matrix::Matrix;
+use malachite_nz::integer::Integer;
+use malachite_q::Rational;
+
+#[test]
+fn test_add() {
+    let test = |height, width, s, t, out| {
+        let u = Matrix::from_entries(height, width, vec_from_str::<Integer>(s).unwrap());
+        let v = Matrix::from_entries(height, width, vec_from_str::<Integer>(t).unwrap());
+
+        let mut n = u.clone();
+        n += v.clone();
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let mut n = u.clone();
+        n += &v;
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = u.clone() + v.clone();
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = &u + v.clone();
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = u.clone() + &v;
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = &u + &v;
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+    };
+    test(0, 0, "[]", "[]", "[]");
+    test(1, 0, "[]", "[]", "[[]]");
+    test(1, 1, "[3]", "[-3]", "[[0]]");
+    test(2, 2, "[1, 2, 3, 4]", "[4, -2, 0, 1]", "[[5, 0], [3, 5]]");
+    test(
+        1,
+        3,
+        "[18446744073709551615, -1, 0]",
+        "[1, 18446744073709551616, 123456789012345678901234567890]",
+        "[[18446744073709551616, 18446744073709551615, 123456789012345678901234567890]]",
+    );
+
+    let test = |height, width, s, t, out| {
+        let u = Matrix::from_entries(height, width, vec_from_str::<Rational>(s).unwrap());
+        let v = Matrix::from_entries(height, width, vec_from_str::<Rational>(t).unwrap());
+
+        let mut n = u.clone();
+        n += v.clone();
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let mut n = u.clone();
+        n += &v;
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = u.clone() + v.clone();
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = &u + v.clone();
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = u.clone() + &v;
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = &u + &v;
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+    };
+    test(2, 1, "[1/2, 1/3]", "[1/2, -1/3]", "[[1], [0]]");
+    test(1, 2, "[1/3, -5/7]", "[1/6, 2]", "[[1/2, 9/7]]");
+}
+
+#[test]
+#[should_panic]
+fn add_fail_1() {
+    let _ = Matrix::<Integer>::zero(2, 3) + Matrix::zero(3, 2);
+}
+
+#[test]
+#[should_panic]
+fn add_fail_2() {
+    let mut m = Matrix::<Integer>::zero(2, 2);
+    m += &Matrix::zero(2, 1);
+}
+
+#[test]
+fn add_properties() {
+    matrix_integer_gen().test_properties(|x| {
+        let sum_val_val = x.clone() + x.clone();
+        let sum_val_ref = x.clone() + &x;
+        let sum_ref_val = &x + x.clone();
+        let sum = &x + &x;
+        assert!(sum_val_val.is_valid());
+        assert!(sum_val_ref.is_valid());
+        assert!(sum_ref_val.is_valid());
+        assert!(sum.is_valid());
+        assert_eq!(sum_val_val, sum);
+        assert_eq!(sum_val_ref, sum);
+        assert_eq!(sum_ref_val, sum);
+
+        let mut mut_x = x.clone();
+        mut_x += x.clone();
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x, sum);
+        let mut mut_x = x.clone();
+        mut_x += &x;
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x, sum);
+
+        let zero = Matrix::zero(x.height(), x.width());
+        assert_eq!(&x + &zero, x);
+        assert_eq!(&zero + &x, x);
+        assert_eq!(&x + -&x, zero);
+        assert!(sum
+            .entries()
+            .iter()
+            .zip(x.entries())
+            .all(|(s, e)| *s == e << 1u32));
+        assert_eq!(sum.transpose(), x.transpose() + x.transpose());
+    });
+
+    matrix_rational_gen().test_properties(|x| {
+        let sum = &x + &x;
+        assert!(sum.is_valid());
+        assert_eq!(x.clone() + x.clone(), sum);
+        let mut mut_x = x.clone();
+        mut_x += &x;
+        assert_eq!(mut_x, sum);
+        assert_eq!(&x + -&x, Matrix::zero(x.height(), x.width()));
+        assert!(sum
+            .entries()
+            .iter()
+            .zip(x.entries())
+            .all(|(s, e)| *s == e << 1u32));
+    });
+
+    matrix_integer_pair_gen_var_1().test_properties(|(x, y)| {
+        let sum = &x + &y;
+        assert_eq!(&y + &x, sum);
+        assert_eq!(&sum - &y, x);
+        assert_eq!(sum.transpose(), x.transpose() + y.transpose());
+    });
+
+    matrix_rational_pair_gen_var_1().test_properties(|(x, y)| {
+        let sum = &x + &y;
+        assert!(sum.is_valid());
+        assert_eq!(&y + &x, sum);
+        assert_eq!(&sum - &y, x);
+    });
+}
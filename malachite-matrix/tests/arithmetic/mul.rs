@@ -0,0 +1,214 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::vecs::vec_from_str;
+use malachite_matrix::test_util::generators::{
+    matrix_integer_gen, matrix_integer_gen_var_1, matrix_integer_pair_gen_var_1,
+    matrix_rational_gen, matrix_rational_pair_gen_var_1,
+};
+use malachite_matrix::Matrix;
+use malachite_nz::integer::Integer;
+use malachite_q::Rational;
+
+#[test]
+fn test_mul() {
+    let test = |height, inner, width, s, t, out| {
+        let u = Matrix::from_entries(height, inner, vec_from_str::<Integer>(s).unwrap());
+        let v = Matrix::from_entries(inner, width, vec_from_str::<Integer>(t).unwrap());
+
+        let mut n = u.clone();
+        n *= v.clone();
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let mut n = u.clone();
+        n *= &v;
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = u.clone() * v.clone();
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = &u * v.clone();
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = u.clone() * &v;
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = &u * &v;
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+    };
+    test(0, 0, 0, "[]", "[]", "[]");
+    test(2, 0, 2, "[]", "[]", "[[0, 0], [0, 0]]");
+    test(1, 2, 0, "[1, 2]", "[]", "[[]]");
+    test(1, 3, 1, "[1, 2, 3]", "[4, 5, 6]", "[[32]]");
+    test(
+        3,
+        1,
+        3,
+        "[1, 2, 3]",
+        "[4, 5, 6]",
+        "[[4, 5, 6], [8, 10, 12], [12, 15, 18]]",
+    );
+    test(
+        2,
+        2,
+        2,
+        "[1, 2, 3, 4]",
+        "[5, 6, 7, 8]",
+        "[[19, 22], [43, 50]]",
+    );
+    test(
+        2,
+        2,
+        2,
+        "[5, 6, 7, 8]",
+        "[1, 2, 3, 4]",
+        "[[23, 34], [31, 46]]",
+    );
+    test(
+        2,
+        3,
+        2,
+        "[1, 0, -1, 2, 1, 0]",
+        "[3, 1, 2, 1, 1, 0]",
+        "[[2, 1], [8, 3]]",
+    );
+    test(
+        1,
+        2,
+        1,
+        "[18446744073709551616, -1]",
+        "[18446744073709551616, 18446744073709551615]",
+        "[[340282366920938463444927863358058659841]]",
+    );
+
+    let test = |height, inner, width, s, t, out| {
+        let u = Matrix::from_entries(height, inner, vec_from_str::<Rational>(s).unwrap());
+        let v = Matrix::from_entries(inner, width, vec_from_str::<Rational>(t).unwrap());
+
+        let mut n = u.clone();
+        n *= v.clone();
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let mut n = u.clone();
+        n *= &v;
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = u.clone() * v.clone();
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = &u * v.clone();
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = u.clone() * &v;
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = &u * &v;
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+    };
+    test(1, 2, 1, "[1/2, 1/3]", "[1/2, -3/4]", "[[0]]");
+    test(
+        2,
+        2,
+        2,
+        "[1/2, 0, 0, 2]",
+        "[2, 1/3, 1/5, 1/2]",
+        "[[1, 1/6], [2/5, 1]]",
+    );
+}
+
+#[test]
+#[should_panic]
+fn mul_fail_1() {
+    let _ = Matrix::<Integer>::zero(2, 3) * Matrix::zero(2, 3);
+}
+
+#[test]
+#[should_panic]
+fn mul_fail_2() {
+    let mut m = Matrix::<Integer>::zero(2, 2);
+    m *= &Matrix::zero(1, 2);
+}
+
+#[test]
+fn mul_properties() {
+    matrix_integer_pair_gen_var_1().test_properties(|(x, y)| {
+        let product_val_val = x.clone() * y.clone();
+        let product_val_ref = x.clone() * &y;
+        let product_ref_val = &x * y.clone();
+        let product = &x * &y;
+        assert!(product_val_val.is_valid());
+        assert!(product_val_ref.is_valid());
+        assert!(product_ref_val.is_valid());
+        assert!(product.is_valid());
+        assert_eq!(product_val_val, product);
+        assert_eq!(product_val_ref, product);
+        assert_eq!(product_ref_val, product);
+
+        let mut mut_x = x.clone();
+        mut_x *= y.clone();
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x, product);
+        let mut mut_x = x.clone();
+        mut_x *= &y;
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x, product);
+
+        assert_eq!(-&x * &y, -&product);
+        assert_eq!(&x * (&y + &x), &product + &x * &x);
+        assert_eq!((&x * &y) * &x, &x * (&y * &x));
+        assert_eq!(product.transpose(), y.transpose() * x.transpose());
+    });
+
+    matrix_integer_gen_var_1().test_properties(|x| {
+        let n = x.height();
+        assert_eq!(&x * Matrix::identity(n), x);
+        assert_eq!(Matrix::<Integer>::identity(n) * &x, x);
+        assert_eq!(&x * Matrix::zero(n, n), Matrix::zero(n, n));
+    });
+
+    matrix_integer_gen().test_properties(|x| {
+        let gram = &x * x.transpose();
+        assert!(gram.is_valid());
+        assert_eq!(gram.height(), x.height());
+        assert_eq!(gram.width(), x.height());
+        assert_eq!(gram.transpose(), gram);
+        for i in 0..x.height() {
+            let row = x.row(i);
+            assert_eq!(*gram.get(i, i), row.iter().map(|e| e * e).sum::<Integer>());
+        }
+    });
+
+    matrix_rational_pair_gen_var_1().test_properties(|(x, y)| {
+        let product = &x * &y;
+        assert!(product.is_valid());
+        assert_eq!(x.clone() * y.clone(), product);
+        let mut mut_x = x.clone();
+        mut_x *= &y;
+        assert_eq!(mut_x, product);
+        assert_eq!(product.transpose(), y.transpose() * x.transpose());
+        assert_eq!(&x * Matrix::identity(x.height()), x);
+    });
+
+    matrix_rational_gen().test_properties(|x| {
+        let gram = x.transpose() * &x;
+        assert!(gram.is_valid());
+        assert_eq!(gram.transpose(), gram);
+    });
+}
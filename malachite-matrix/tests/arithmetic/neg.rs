@@ -0,0 +1,94 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::NegAssign;
+use malachite_base::vecs::vec_from_str;
+use malachite_matrix::test_util::generators::{matrix_integer_gen, matrix_rational_gen};
+use malachite_matrix::Matrix;
+use malachite_nz::integer::Integer;
+use malachite_q::Rational;
+
+#[test]
+fn test_neg() {
+    let test = |height, width, s, out| {
+        let u = Matrix::from_entries(height, width, vec_from_str::<Integer>(s).unwrap());
+
+        let n = -u.clone();
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = -&u;
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let mut n = u.clone();
+        n.neg_assign();
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+    };
+    test(0, 0, "[]", "[]");
+    test(2, 0, "[]", "[[], []]");
+    test(1, 1, "[0]", "[[0]]");
+    test(2, 2, "[1, -2, 0, 4]", "[[-1, 2], [0, -4]]");
+    test(
+        1,
+        2,
+        "[18446744073709551616, -123456789012345678901234567890]",
+        "[[-18446744073709551616, 123456789012345678901234567890]]",
+    );
+
+    let test = |height, width, s, out| {
+        let u = Matrix::from_entries(height, width, vec_from_str::<Rational>(s).unwrap());
+
+        let n = -u.clone();
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = -&u;
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let mut n = u.clone();
+        n.neg_assign();
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+    };
+    test(2, 1, "[1/2, -5/3]", "[[-1/2], [5/3]]");
+}
+
+#[test]
+fn neg_properties() {
+    matrix_integer_gen().test_properties(|x| {
+        let neg = -x.clone();
+        assert!(neg.is_valid());
+        let neg_alt = -&x;
+        assert!(neg_alt.is_valid());
+        assert_eq!(neg_alt, neg);
+        let mut mut_x = x.clone();
+        mut_x.neg_assign();
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x, neg);
+
+        assert_eq!(-&neg, x);
+        assert_eq!(neg.height(), x.height());
+        assert_eq!(neg.width(), x.width());
+        assert_eq!(&neg + &x, Matrix::zero(x.height(), x.width()));
+        assert_eq!(neg == x, x == Matrix::zero(x.height(), x.width()));
+    });
+
+    matrix_rational_gen().test_properties(|x| {
+        let neg = -x.clone();
+        assert!(neg.is_valid());
+        assert_eq!(-&x, neg);
+        let mut mut_x = x.clone();
+        mut_x.neg_assign();
+        assert_eq!(mut_x, neg);
+        assert_eq!(-&neg, x);
+        assert_eq!(&neg + &x, Matrix::zero(x.height(), x.width()));
+    });
+}
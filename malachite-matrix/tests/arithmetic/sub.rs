@@ -0,0 +1,160 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::vecs::vec_from_str;
+use malachite_matrix::test_util::generators::{
+    matrix_integer_gen, matrix_integer_pair_gen_var_1, matrix_rational_gen,
+    matrix_rational_pair_gen_var_1,
+};
+use malachite_matrix::Matrix;
+use malachite_nz::integer::Integer;
+use malachite_q::Rational;
+
+#[test]
+fn test_sub() {
+    let test = |height, width, s, t, out| {
+        let u = Matrix::from_entries(height, width, vec_from_str::<Integer>(s).unwrap());
+        let v = Matrix::from_entries(height, width, vec_from_str::<Integer>(t).unwrap());
+
+        let mut n = u.clone();
+        n -= v.clone();
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let mut n = u.clone();
+        n -= &v;
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = u.clone() - v.clone();
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = &u - v.clone();
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = u.clone() - &v;
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = &u - &v;
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+    };
+    test(0, 0, "[]", "[]", "[]");
+    test(1, 0, "[]", "[]", "[[]]");
+    test(1, 1, "[3]", "[3]", "[[0]]");
+    test(2, 2, "[1, 2, 3, 4]", "[4, -2, 0, 1]", "[[-3, 4], [3, 3]]");
+    test(
+        1,
+        3,
+        "[18446744073709551616, -1, 0]",
+        "[1, 18446744073709551615, 123456789012345678901234567890]",
+        "[[18446744073709551615, -18446744073709551616, -123456789012345678901234567890]]",
+    );
+
+    let test = |height, width, s, t, out| {
+        let u = Matrix::from_entries(height, width, vec_from_str::<Rational>(s).unwrap());
+        let v = Matrix::from_entries(height, width, vec_from_str::<Rational>(t).unwrap());
+
+        let mut n = u.clone();
+        n -= v.clone();
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let mut n = u.clone();
+        n -= &v;
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = u.clone() - v.clone();
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = &u - v.clone();
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = u.clone() - &v;
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = &u - &v;
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+    };
+    test(2, 1, "[1/2, 1/3]", "[1/2, -1/3]", "[[0], [2/3]]");
+    test(1, 2, "[1/3, -5/7]", "[1/6, 2]", "[[1/6, -19/7]]");
+}
+
+#[test]
+#[should_panic]
+fn sub_fail_1() {
+    let _ = Matrix::<Integer>::zero(2, 3) - Matrix::zero(3, 2);
+}
+
+#[test]
+#[should_panic]
+fn sub_fail_2() {
+    let mut m = Matrix::<Integer>::zero(2, 2);
+    m -= &Matrix::zero(2, 1);
+}
+
+#[test]
+fn sub_properties() {
+    matrix_integer_pair_gen_var_1().test_properties(|(x, y)| {
+        let diff_val_val = x.clone() - y.clone();
+        let diff_val_ref = x.clone() - &y;
+        let diff_ref_val = &x - y.clone();
+        let diff = &x - &y;
+        assert!(diff_val_val.is_valid());
+        assert!(diff_val_ref.is_valid());
+        assert!(diff_ref_val.is_valid());
+        assert!(diff.is_valid());
+        assert_eq!(diff_val_val, diff);
+        assert_eq!(diff_val_ref, diff);
+        assert_eq!(diff_ref_val, diff);
+
+        let mut mut_x = x.clone();
+        mut_x -= y.clone();
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x, diff);
+        let mut mut_x = x.clone();
+        mut_x -= &y;
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x, diff);
+
+        assert_eq!(&y - &x, -&diff);
+        assert_eq!(&x + -&y, diff);
+        assert_eq!(&diff + &y, x);
+    });
+
+    matrix_integer_gen().test_properties(|x| {
+        let zero = Matrix::zero(x.height(), x.width());
+        assert_eq!(&x - &zero, x);
+        assert_eq!(&zero - &x, -&x);
+        assert_eq!(&x - &x, zero);
+    });
+
+    matrix_rational_pair_gen_var_1().test_properties(|(x, y)| {
+        let diff = &x - &y;
+        assert!(diff.is_valid());
+        assert_eq!(x.clone() - y.clone(), diff);
+        assert_eq!(&x - y.clone(), diff);
+        let mut mut_x = x.clone();
+        mut_x -= &y;
+        assert_eq!(mut_x, diff);
+        assert_eq!(&y - &x, -&diff);
+        assert_eq!(&diff + &y, x);
+    });
+
+    matrix_rational_gen().test_properties(|x| {
+        assert_eq!(&x - &x, Matrix::zero(x.height(), x.width()));
+    });
+}
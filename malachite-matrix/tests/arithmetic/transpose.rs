@@ -0,0 +1,75 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::vecs::vec_from_str;
+use malachite_matrix::test_util::generators::{
+    matrix_integer_gen, matrix_integer_pair_gen_var_1, matrix_rational_gen,
+};
+use malachite_matrix::Matrix;
+use malachite_nz::integer::Integer;
+use malachite_q::Rational;
+
+#[test]
+fn test_transpose() {
+    let test = |height, width, s, out| {
+        let u = Matrix::from_entries(height, width, vec_from_str::<Integer>(s).unwrap());
+        let t = u.transpose();
+        assert!(t.is_valid());
+        assert_eq!(t.to_string(), out);
+    };
+    test(0, 0, "[]", "[]");
+    test(0, 2, "[]", "[[], []]");
+    test(2, 0, "[]", "[]");
+    test(1, 1, "[5]", "[[5]]");
+    test(1, 3, "[1, 2, 3]", "[[1], [2], [3]]");
+    test(2, 3, "[1, 2, 3, 4, 5, 6]", "[[1, 4], [2, 5], [3, 6]]");
+
+    let test = |height, width, s, out| {
+        let u = Matrix::from_entries(height, width, vec_from_str::<Rational>(s).unwrap());
+        let t = u.transpose();
+        assert!(t.is_valid());
+        assert_eq!(t.to_string(), out);
+    };
+    test(2, 2, "[1/2, 1/3, -1/4, 0]", "[[1/2, -1/4], [1/3, 0]]");
+}
+
+#[test]
+fn transpose_properties() {
+    matrix_integer_gen().test_properties(|x| {
+        let t = x.transpose();
+        assert!(t.is_valid());
+        assert_eq!(t.height(), x.width());
+        assert_eq!(t.width(), x.height());
+        assert_eq!(t.transpose(), x);
+        for i in 0..x.height() {
+            for j in 0..x.width() {
+                assert_eq!(t.get(j, i), x.get(i, j));
+            }
+        }
+        assert_eq!((-&x).transpose(), -t);
+    });
+
+    matrix_integer_pair_gen_var_1().test_properties(|(x, y)| {
+        assert_eq!((&x * &y).transpose(), y.transpose() * x.transpose());
+        assert_eq!((&x + &y).transpose(), x.transpose() + y.transpose());
+    });
+
+    matrix_rational_gen().test_properties(|x| {
+        let t = x.transpose();
+        assert!(t.is_valid());
+        assert_eq!(t.transpose(), x);
+        assert_eq!(
+            t,
+            Matrix::from_rows(
+                (0..x.width())
+                    .map(|j| (0..x.height()).map(|i| x.get(i, j).clone()).collect())
+                    .collect()
+            )
+        );
+    });
+}
@@ -0,0 +1,134 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::vecs::vec_from_str;
+use malachite_matrix::test_util::generators::{matrix_integer_gen, matrix_rational_gen};
+use malachite_matrix::Matrix;
+use malachite_nz::integer::Integer;
+use malachite_q::Rational;
+
+#[test]
+fn test_from_entries() {
+    let test = |height, width, s, out| {
+        let m = Matrix::from_entries(height, width, vec_from_str::<Integer>(s).unwrap());
+        assert!(m.is_valid());
+        assert_eq!(m.height(), height);
+        assert_eq!(m.width(), width);
+        assert_eq!(m.to_string(), out);
+    };
+    test(0, 0, "[]", "[]");
+    test(0, 5, "[]", "[]");
+    test(3, 0, "[]", "[[], [], []]");
+    test(1, 1, "[-7]", "[[-7]]");
+    test(2, 3, "[1, 2, 3, 4, 5, 6]", "[[1, 2, 3], [4, 5, 6]]");
+    test(3, 2, "[1, 2, 3, 4, 5, 6]", "[[1, 2], [3, 4], [5, 6]]");
+
+    let test = |height, width, s, out| {
+        let m = Matrix::from_entries(height, width, vec_from_str::<Rational>(s).unwrap());
+        assert!(m.is_valid());
+        assert_eq!(m.to_string(), out);
+    };
+    test(2, 2, "[1/2, 0, -3/4, 5]", "[[1/2, 0], [-3/4, 5]]");
+}
+
+#[test]
+#[should_panic]
+fn from_entries_fail_1() {
+    Matrix::from_entries(2, 2, vec![Integer::ZERO; 3]);
+}
+
+#[test]
+#[should_panic]
+fn from_entries_fail_2() {
+    Matrix::<Integer>::from_entries(usize::MAX, 2, Vec::new());
+}
+
+#[test]
+fn test_from_rows() {
+    let test = |rows: Vec<Vec<i32>>, out| {
+        let m = Matrix::from_rows(
+            rows.into_iter()
+                .map(|row| row.into_iter().map(Integer::from).collect())
+                .collect(),
+        );
+        assert!(m.is_valid());
+        assert_eq!(m.to_string(), out);
+    };
+    test(vec![], "[]");
+    test(vec![vec![]], "[[]]");
+    test(vec![vec![], vec![]], "[[], []]");
+    test(vec![vec![1, 2, 3]], "[[1, 2, 3]]");
+    test(vec![vec![1], vec![2], vec![3]], "[[1], [2], [3]]");
+    test(vec![vec![1, -2], vec![-3, 4]], "[[1, -2], [-3, 4]]");
+}
+
+#[test]
+#[should_panic]
+fn from_rows_fail() {
+    Matrix::from_rows(vec![vec![Integer::ZERO], vec![Integer::ZERO, Integer::ONE]]);
+}
+
+#[test]
+fn test_zero() {
+    let test = |height, width, out| {
+        let m = Matrix::<Integer>::zero(height, width);
+        assert!(m.is_valid());
+        assert_eq!(m.to_string(), out);
+        let m = Matrix::<Rational>::zero(height, width);
+        assert!(m.is_valid());
+        assert_eq!(m.to_string(), out);
+    };
+    test(0, 0, "[]");
+    test(2, 0, "[[], []]");
+    test(1, 1, "[[0]]");
+    test(2, 3, "[[0, 0, 0], [0, 0, 0]]");
+}
+
+#[test]
+fn test_identity() {
+    let test = |n, out| {
+        let m = Matrix::<Integer>::identity(n);
+        assert!(m.is_valid());
+        assert_eq!(m.to_string(), out);
+        let m = Matrix::<Rational>::identity(n);
+        assert!(m.is_valid());
+        assert_eq!(m.to_string(), out);
+    };
+    test(0, "[]");
+    test(1, "[[1]]");
+    test(2, "[[1, 0], [0, 1]]");
+    test(3, "[[1, 0, 0], [0, 1, 0], [0, 0, 1]]");
+}
+
+#[test]
+fn from_rows_properties() {
+    matrix_integer_gen().test_properties(|x| {
+        let rows = x.to_rows();
+        let y = Matrix::from_rows(rows);
+        assert!(y.is_valid());
+        if x.height() == 0 {
+            assert_eq!(y.height(), 0);
+            assert_eq!(y.width(), 0);
+        } else {
+            assert_eq!(y, x);
+        }
+        let z = Matrix::from_entries(x.height(), x.width(), x.entries().to_vec());
+        assert!(z.is_valid());
+        assert_eq!(z, x);
+    });
+
+    matrix_rational_gen().test_properties(|x| {
+        let z = Matrix::from_entries(x.height(), x.width(), x.clone().into_entries());
+        assert!(z.is_valid());
+        assert_eq!(z, x);
+        if x.height() != 0 {
+            assert_eq!(Matrix::from_rows(x.to_rows()), x);
+        }
+    });
+}
@@ -0,0 +1,84 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::strings::ToDebugString;
+use malachite_base::vecs::vec_from_str;
+use malachite_matrix::test_util::generators::{matrix_integer_gen, matrix_rational_gen};
+use malachite_matrix::Matrix;
+use malachite_nz::integer::Integer;
+use malachite_q::Rational;
+
+#[test]
+fn test_accessors() {
+    let test = |height, width, s, is_square, rows| {
+        let m = Matrix::from_entries(height, width, vec_from_str::<Integer>(s).unwrap());
+        assert_eq!(m.height(), height);
+        assert_eq!(m.width(), width);
+        assert_eq!(m.is_square(), is_square);
+        assert_eq!(m.entries().to_debug_string(), s);
+        assert_eq!(m.to_rows().to_debug_string(), rows);
+        for i in 0..height {
+            assert_eq!(m.row(i), &m.to_rows()[i][..]);
+            for j in 0..width {
+                assert_eq!(*m.get(i, j), m.entries()[i * width + j]);
+            }
+        }
+        assert_eq!(m.into_entries().to_debug_string(), s);
+    };
+    test(0, 0, "[]", true, "[]");
+    test(0, 2, "[]", false, "[]");
+    test(2, 0, "[]", false, "[[], []]");
+    test(1, 1, "[3]", true, "[[3]]");
+    test(1, 3, "[1, 2, 3]", false, "[[1, 2, 3]]");
+    test(2, 2, "[1, -2, 3, -4]", true, "[[1, -2], [3, -4]]");
+
+    let m = Matrix::from_entries(2, 1, vec_from_str::<Rational>("[1/2, -1/3]").unwrap());
+    assert_eq!(m.get(1, 0).to_string(), "-1/3");
+    assert_eq!(m.row(0).to_debug_string(), "[1/2]");
+    assert_eq!(m.to_rows().to_debug_string(), "[[1/2], [-1/3]]");
+}
+
+#[test]
+#[should_panic]
+fn get_fail_1() {
+    Matrix::<Integer>::zero(2, 3).get(2, 0);
+}
+
+#[test]
+#[should_panic]
+fn get_fail_2() {
+    Matrix::<Integer>::zero(2, 3).get(0, 3);
+}
+
+#[test]
+#[should_panic]
+fn row_fail() {
+    Matrix::<Integer>::zero(2, 0).row(2);
+}
+
+#[test]
+fn to_rows_properties() {
+    matrix_integer_gen().test_properties(|x| {
+        let rows = x.to_rows();
+        assert_eq!(rows.len(), x.height());
+        assert!(rows.iter().all(|row| row.len() == x.width()));
+        assert_eq!(rows.concat(), x.entries());
+        assert_eq!(x.is_square(), x.height() == x.width());
+        assert_eq!(x.entries().len(), x.height() * x.width());
+        assert_eq!(x.clone().into_entries(), x.entries());
+    });
+
+    matrix_rational_gen().test_properties(|x| {
+        let rows = x.to_rows();
+        assert_eq!(rows.len(), x.height());
+        for (i, row) in rows.iter().enumerate() {
+            assert_eq!(row.as_slice(), x.row(i));
+        }
+        assert_eq!(rows.concat(), x.entries());
+    });
+}
@@ -0,0 +1,58 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::strings::{string_is_subset, ToDebugString};
+use malachite_base::vecs::vec_from_str;
+use malachite_matrix::test_util::generators::{matrix_integer_gen, matrix_rational_gen};
+use malachite_matrix::Matrix;
+use malachite_nz::integer::Integer;
+use malachite_q::Rational;
+
+#[test]
+fn test_to_string() {
+    let test = |height, width, s, out| {
+        let m = Matrix::from_entries(height, width, vec_from_str::<Integer>(s).unwrap());
+        assert_eq!(m.to_string(), out);
+        assert_eq!(m.to_debug_string(), out);
+    };
+    test(0, 0, "[]", "[]");
+    test(0, 3, "[]", "[]");
+    test(1, 0, "[]", "[[]]");
+    test(1, 1, "[0]", "[[0]]");
+    test(2, 2, "[1, -2, 3, 4]", "[[1, -2], [3, 4]]");
+    test(
+        1,
+        2,
+        "[123456789012345678901234567890, -18446744073709551616]",
+        "[[123456789012345678901234567890, -18446744073709551616]]",
+    );
+
+    let test = |height, width, s, out| {
+        let m = Matrix::from_entries(height, width, vec_from_str::<Rational>(s).unwrap());
+        assert_eq!(m.to_string(), out);
+        assert_eq!(m.to_debug_string(), out);
+    };
+    test(2, 2, "[1/2, 3, -4/5, 0]", "[[1/2, 3], [-4/5, 0]]");
+}
+
+#[test]
+fn to_string_properties() {
+    matrix_integer_gen().test_properties(|x| {
+        let s = x.to_string();
+        assert_eq!(x.to_debug_string(), s);
+        assert!(string_is_subset(&s, "-0123456789 ,[]"));
+        assert_eq!(s, x.to_rows().to_debug_string());
+    });
+
+    matrix_rational_gen().test_properties(|x| {
+        let s = x.to_string();
+        assert_eq!(x.to_debug_string(), s);
+        assert!(string_is_subset(&s, "-/0123456789 ,[]"));
+        assert_eq!(s, x.to_rows().to_debug_string());
+    });
+}
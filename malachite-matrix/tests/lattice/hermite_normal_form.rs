@@ -0,0 +1,123 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::UnsignedAbs;
+use malachite_base::vecs::vec_from_str;
+use malachite_matrix::test_util::generators::matrix_integer_gen;
+use malachite_matrix::Matrix;
+use malachite_nz::integer::Integer;
+
+fn is_hermite_normal_form(h: &Matrix<Integer>) -> bool {
+    let mut last_pivot = None;
+    for i in 0..h.height() {
+        let row = h.row(i);
+        let Some(c) = row.iter().position(|x| *x != 0u32) else {
+            // Every row below a zero row must be zero.
+            return (i..h.height()).all(|k| h.row(k).iter().all(|x| *x == 0u32));
+        };
+        if last_pivot.is_some_and(|p| c <= p) || row[c] <= 0u32 {
+            return false;
+        }
+        if (0..i).any(|k| *h.get(k, c) < 0u32 || *h.get(k, c) >= row[c]) {
+            return false;
+        }
+        last_pivot = Some(c);
+    }
+    true
+}
+
+#[test]
+fn test_hermite_normal_form() {
+    let test = |height, width, s, out, out_u| {
+        let a = Matrix::from_entries(height, width, vec_from_str::<Integer>(s).unwrap());
+        let h = a.hermite_normal_form();
+        assert!(h.is_valid());
+        assert_eq!(h.to_string(), out);
+        assert!(is_hermite_normal_form(&h));
+
+        let (h_alt, u) = a.hermite_normal_form_with_transform();
+        assert!(h_alt.is_valid());
+        assert!(u.is_valid());
+        assert_eq!(h_alt, h);
+        assert_eq!(u.to_string(), out_u);
+        assert_eq!(&u * &a, h);
+    };
+    test(0, 0, "[]", "[]", "[]");
+    test(0, 3, "[]", "[]", "[]");
+    test(2, 0, "[]", "[[], []]", "[[1, 0], [0, 1]]");
+    test(1, 1, "[-5]", "[[5]]", "[[-1]]");
+    test(2, 2, "[0, 0, 0, 0]", "[[0, 0], [0, 0]]", "[[1, 0], [0, 1]]");
+    test(
+        2,
+        2,
+        "[4, 6, 2, 5]",
+        "[[2, 1], [0, 4]]",
+        "[[1, -1], [-1, 2]]",
+    );
+    test(
+        2,
+        3,
+        "[2, 4, 4, -6, 6, 12]",
+        "[[2, 4, 4], [0, 18, 24]]",
+        "[[1, 0], [3, 1]]",
+    );
+    test(
+        3,
+        2,
+        "[3, 1, 4, 1, 5, 9]",
+        "[[1, 0], [0, 1], [0, 0]]",
+        "[[-1, 1, 0], [4, -3, 0], [-31, 22, 1]]",
+    );
+    test(
+        2,
+        3,
+        "[0, 3, 5, 0, 2, 7]",
+        "[[0, 1, 9], [0, 0, 11]]",
+        "[[-1, 2], [-2, 3]]",
+    );
+    test(
+        3,
+        3,
+        "[2, 3, 6, 2, -3, 5, -1, 4, 2]",
+        "[[1, 0, 15], [0, 1, 41], [0, 0, 49]]",
+        "[[-1, 3, 3], [-4, 9, 10], [-5, 11, 12]]",
+    );
+}
+
+#[test]
+fn hermite_normal_form_properties() {
+    matrix_integer_gen().test_properties(|a| {
+        let h = a.hermite_normal_form();
+        assert!(h.is_valid());
+        assert!(is_hermite_normal_form(&h));
+        assert_eq!(h.height(), a.height());
+        assert_eq!(h.width(), a.width());
+        assert_eq!(h.rank(), a.rank());
+        assert_eq!(h.hermite_normal_form(), h);
+
+        let (h_alt, u) = a.hermite_normal_form_with_transform();
+        assert_eq!(h_alt, h);
+        assert!(u.is_square());
+        assert_eq!(u.height(), a.height());
+        assert_eq!(u.determinant().unsigned_abs(), 1u32);
+        assert_eq!(&u * &a, h);
+
+        // The Hermite normal form only depends on the lattice spanned by the rows.
+        let reversed = Matrix::from_entries(
+            a.height(),
+            a.width(),
+            (0..a.height())
+                .rev()
+                .flat_map(|i| a.row(i).to_vec())
+                .collect(),
+        );
+        assert_eq!(reversed.hermite_normal_form(), h);
+        assert_eq!((-&a).hermite_normal_form(), h);
+        assert_eq!(h.hermite_normal_form_with_transform().0, h);
+    });
+}
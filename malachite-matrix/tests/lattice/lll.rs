@@ -0,0 +1,208 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::basic::traits::{One, OneHalf};
+use malachite_base::test_util::generators::common::GenConfig;
+use malachite_base::vecs::vec_from_str;
+use malachite_matrix::test_util::generators::matrix_integer_gen_var_2;
+use malachite_matrix::Matrix;
+use malachite_nz::integer::Integer;
+use malachite_q::Rational;
+use std::str::FromStr;
+
+fn gram_determinant(a: &Matrix<Integer>) -> Integer {
+    (a * a.transpose()).determinant()
+}
+
+// Two bases span the same lattice iff their Hermite normal forms are equal.
+fn same_lattice(a: &Matrix<Integer>, b: &Matrix<Integer>) -> bool {
+    a.hermite_normal_form() == b.hermite_normal_form()
+}
+
+#[test]
+fn test_is_lll_reduced() {
+    let test = |height, width, s, delta: &str, eta: &str, out| {
+        let a = Matrix::from_entries(height, width, vec_from_str::<Integer>(s).unwrap());
+        let delta = Rational::from_str(delta).unwrap();
+        let eta = Rational::from_str(eta).unwrap();
+        assert_eq!(a.is_lll_reduced(&delta, &eta), out);
+    };
+    test(0, 0, "[]", "3/4", "1/2", true);
+    test(1, 2, "[5, -7]", "3/4", "1/2", true);
+    test(1, 2, "[0, 0]", "3/4", "1/2", false);
+    test(2, 2, "[1, 0, 0, 1]", "3/4", "1/2", true);
+    test(2, 2, "[1, 0, 0, 1]", "1", "1/2", true);
+    test(2, 2, "[1, 0, 5, 1]", "3/4", "1/2", false);
+    test(2, 2, "[2, 0, 1, 1]", "3/4", "1/2", false);
+    test(2, 2, "[1, 2, 2, 4]", "3/4", "1/2", false);
+    test(2, 2, "[3, 0, 2, 1]", "3/4", "1/2", false);
+    test(2, 2, "[3, 0, 2, 1]", "3/4", "2/3", false);
+    test(2, 2, "[4, 0, 2, 3]", "3/4", "1/2", true);
+    test(2, 2, "[4, 0, 2, 3]", "1", "1/2", false);
+    test(3, 3, "[0, 1, 0, 1, 0, 1, -1, 0, 2]", "3/4", "1/2", true);
+    test(3, 3, "[1, 1, 1, -1, 0, 2, 3, 5, 6]", "3/4", "1/2", false);
+}
+
+#[test]
+fn test_lll() {
+    let test = |height, width, s, delta: &str, out| {
+        let a = Matrix::from_entries(height, width, vec_from_str::<Integer>(s).unwrap());
+        let delta = Rational::from_str(delta).unwrap();
+        let b = a.lll(&delta);
+        assert!(b.is_valid());
+        assert_eq!(b.to_string(), out);
+        assert!(b.is_lll_reduced(&delta, &Rational::ONE_HALF));
+        assert!(same_lattice(&a, &b));
+    };
+    test(0, 0, "[]", "3/4", "[]");
+    test(0, 3, "[]", "3/4", "[]");
+    test(1, 2, "[-6, 8]", "3/4", "[[-6, 8]]");
+    test(2, 2, "[1, 0, 0, 1]", "3/4", "[[1, 0], [0, 1]]");
+    test(2, 2, "[1, 0, 5, 1]", "3/4", "[[1, 0], [0, 1]]");
+    test(2, 2, "[201, 37, 1648, 297]", "3/4", "[[1, 32], [40, 1]]");
+    test(2, 2, "[201, 37, 1648, 297]", "1", "[[1, 32], [40, 1]]");
+    test(
+        3,
+        3,
+        "[1, 1, 1, -1, 0, 2, 3, 5, 6]",
+        "3/4",
+        "[[0, 1, 0], [1, 0, 1], [-1, 0, 2]]",
+    );
+    test(
+        3,
+        4,
+        "[1, 0, 0, 1000, 0, 1, 0, 1234, 0, 0, 1, 2468]",
+        "3/4",
+        "[[0, -2, 1, 0], [-21, 3, 7, -22], [-37, 6, 12, 20]]",
+    );
+    test(
+        2,
+        3,
+        "[1, 0, 18446744073709551615, 0, 1, 18446744073709551616]",
+        "99/100",
+        "[[-1, 1, 1], [6148914691236517206, -6148914691236517205, 12297829382473034410]]",
+    );
+}
+
+#[test]
+#[should_panic]
+fn lll_fail_1() {
+    Matrix::<Integer>::identity(2).lll(&Rational::from_unsigneds(1u32, 4));
+}
+
+#[test]
+#[should_panic]
+fn lll_fail_2() {
+    Matrix::<Integer>::identity(2).lll(&Rational::from_unsigneds(5u32, 4));
+}
+
+#[test]
+#[should_panic]
+fn lll_fail_3() {
+    Matrix::from_entries(2, 2, [1, 2, 2, 4].map(Integer::from).to_vec())
+        .lll(&Rational::from_unsigneds(3u32, 4));
+}
+
+#[test]
+#[should_panic]
+fn lll_fail_4() {
+    Matrix::<Integer>::zero(1, 3).lll(&Rational::from_unsigneds(3u32, 4));
+}
+
+#[test]
+fn test_lll_l2() {
+    let test = |height, width, s, out| {
+        let a = Matrix::from_entries(height, width, vec_from_str::<Integer>(s).unwrap());
+        let delta = Rational::from_unsigneds(99u32, 100);
+        let eta = Rational::from_unsigneds(51u32, 100);
+        let b = a.lll_l2(&delta, &eta);
+        assert!(b.is_valid());
+        assert_eq!(b.to_string(), out);
+        assert!(b.is_lll_reduced(&delta, &eta));
+        assert!(same_lattice(&a, &b));
+    };
+    test(0, 0, "[]", "[]");
+    test(1, 2, "[-6, 8]", "[[-6, 8]]");
+    test(2, 2, "[1, 0, 5, 1]", "[[1, 0], [0, 1]]");
+    test(2, 2, "[201, 37, 1648, 297]", "[[1, 32], [40, 1]]");
+    test(
+        3,
+        3,
+        "[1, 1, 1, -1, 0, 2, 3, 5, 6]",
+        "[[0, 1, 0], [1, 0, 1], [-1, 0, 2]]",
+    );
+    test(
+        2,
+        3,
+        "[1, 0, 18446744073709551615, 0, 1, 18446744073709551616]",
+        "[[-1, 1, 1], [6148914691236517206, -6148914691236517205, 12297829382473034410]]",
+    );
+}
+
+#[test]
+#[should_panic]
+fn lll_l2_fail_1() {
+    Matrix::<Integer>::identity(2).lll_l2(&Rational::ONE, &Rational::from_unsigneds(51u32, 100));
+}
+
+#[test]
+#[should_panic]
+fn lll_l2_fail_2() {
+    Matrix::<Integer>::identity(2)
+        .lll_l2(&Rational::from_unsigneds(99u32, 100), &Rational::ONE_HALF);
+}
+
+#[test]
+#[should_panic]
+fn lll_l2_fail_3() {
+    Matrix::<Integer>::identity(2).lll_l2(
+        &Rational::from_unsigneds(3u32, 4),
+        &Rational::from_unsigneds(9u32, 10),
+    );
+}
+
+#[test]
+#[should_panic]
+fn lll_l2_fail_4() {
+    Matrix::from_entries(2, 2, [1, 2, 2, 4].map(Integer::from).to_vec()).lll_l2(
+        &Rational::from_unsigneds(99u32, 100),
+        &Rational::from_unsigneds(51u32, 100),
+    );
+}
+
+#[test]
+fn lll_properties() {
+    let mut config = GenConfig::new();
+    config.insert("mean_bits_n", 32);
+    let three_quarters = Rational::from_unsigneds(3u32, 4);
+    let delta = Rational::from_unsigneds(99u32, 100);
+    let eta = Rational::from_unsigneds(51u32, 100);
+    matrix_integer_gen_var_2().test_properties_with_config(&config, |a| {
+        let b = a.lll(&three_quarters);
+        assert!(b.is_valid());
+        assert_eq!(b.height(), a.height());
+        assert_eq!(b.width(), a.width());
+        assert!(b.is_lll_reduced(&three_quarters, &Rational::ONE_HALF));
+        assert!(same_lattice(&a, &b));
+        assert_eq!(gram_determinant(&b), gram_determinant(&a));
+        assert_eq!(b.lll(&three_quarters), b);
+        if a.is_lll_reduced(&three_quarters, &Rational::ONE_HALF) {
+            assert_eq!(b, a);
+        }
+
+        let b = a.lll(&delta);
+        assert!(b.is_lll_reduced(&delta, &Rational::ONE_HALF));
+        assert!(same_lattice(&a, &b));
+
+        let c = a.lll_l2(&delta, &eta);
+        assert!(c.is_valid());
+        assert!(c.is_lll_reduced(&delta, &eta));
+        assert!(same_lattice(&a, &c));
+        assert_eq!(gram_determinant(&c), gram_determinant(&a));
+    });
+}
@@ -0,0 +1,107 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{DivisibleBy, UnsignedAbs};
+use malachite_base::vecs::vec_from_str;
+use malachite_matrix::test_util::generators::{matrix_integer_gen, matrix_integer_gen_var_1};
+use malachite_matrix::Matrix;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+
+fn is_smith_normal_form(s: &Matrix<Integer>) -> bool {
+    for i in 0..s.height() {
+        for j in 0..s.width() {
+            if i != j && *s.get(i, j) != 0u32 {
+                return false;
+            }
+        }
+    }
+    let diagonal: Vec<&Integer> = (0..s.height().min(s.width()))
+        .map(|i| s.get(i, i))
+        .collect();
+    diagonal.iter().all(|x| **x >= 0u32) && diagonal.windows(2).all(|w| w[1].divisible_by(w[0]))
+}
+
+#[test]
+fn test_smith_normal_form() {
+    let test = |height, width, s, out| {
+        let a = Matrix::from_entries(height, width, vec_from_str::<Integer>(s).unwrap());
+        let sm = a.smith_normal_form();
+        assert!(sm.is_valid());
+        assert_eq!(sm.to_string(), out);
+        assert!(is_smith_normal_form(&sm));
+
+        let (sm_alt, u, v) = a.smith_normal_form_with_transforms();
+        assert!(sm_alt.is_valid());
+        assert!(u.is_valid());
+        assert!(v.is_valid());
+        assert_eq!(sm_alt, sm);
+        assert_eq!(&u * &a * &v, sm);
+    };
+    test(0, 0, "[]", "[]");
+    test(0, 2, "[]", "[]");
+    test(2, 0, "[]", "[[], []]");
+    test(1, 1, "[-5]", "[[5]]");
+    test(1, 3, "[6, 10, 15]", "[[1, 0, 0]]");
+    test(2, 2, "[0, 0, 0, 0]", "[[0, 0], [0, 0]]");
+    test(2, 2, "[4, 6, 2, 5]", "[[1, 0], [0, 8]]");
+    test(2, 2, "[2, 0, 0, 3]", "[[1, 0], [0, 6]]");
+    test(2, 2, "[6, 0, 0, 4]", "[[2, 0], [0, 12]]");
+    test(2, 3, "[1, 2, 3, 4, 5, 6]", "[[1, 0, 0], [0, 3, 0]]");
+    test(3, 2, "[2, 4, 6, 8, 10, 12]", "[[2, 0], [0, 4], [0, 0]]");
+    test(
+        3,
+        3,
+        "[2, 4, 4, -6, 6, 12, 10, -4, -16]",
+        "[[2, 0, 0], [0, 6, 0], [0, 0, 12]]",
+    );
+    test(
+        3,
+        3,
+        "[1, 2, 3, 4, 5, 6, 7, 8, 9]",
+        "[[1, 0, 0], [0, 3, 0], [0, 0, 0]]",
+    );
+}
+
+#[test]
+fn smith_normal_form_properties() {
+    matrix_integer_gen().test_properties(|a| {
+        let s = a.smith_normal_form();
+        assert!(s.is_valid());
+        assert!(is_smith_normal_form(&s));
+        assert_eq!(s.height(), a.height());
+        assert_eq!(s.width(), a.width());
+        assert_eq!(s.smith_normal_form(), s);
+        assert_eq!(
+            (0..s.height().min(s.width()))
+                .filter(|&i| *s.get(i, i) != 0u32)
+                .count(),
+            a.rank()
+        );
+
+        let (s_alt, u, v) = a.smith_normal_form_with_transforms();
+        assert_eq!(s_alt, s);
+        assert_eq!(u.height(), a.height());
+        assert_eq!(v.height(), a.width());
+        assert_eq!(u.determinant().unsigned_abs(), 1u32);
+        assert_eq!(v.determinant().unsigned_abs(), 1u32);
+        assert_eq!(&u * &a * &v, s);
+
+        assert_eq!(a.transpose().smith_normal_form(), s.transpose());
+        assert_eq!((-&a).smith_normal_form(), s);
+        assert_eq!(a.hermite_normal_form().smith_normal_form(), s);
+    });
+
+    matrix_integer_gen_var_1().test_properties(|a| {
+        let s = a.smith_normal_form();
+        let product: Natural = (0..s.height())
+            .map(|i| s.get(i, i).unsigned_abs_ref().clone())
+            .product();
+        assert_eq!(product, a.determinant().unsigned_abs());
+    });
+}
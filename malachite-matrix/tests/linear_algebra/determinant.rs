@@ -0,0 +1,122 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::Parity;
+use malachite_base::num::basic::traits::One;
+use malachite_base::test_util::generators::common::GenConfig;
+use malachite_base::vecs::vec_from_str;
+use malachite_matrix::test_util::generators::{
+    matrix_integer_gen_var_1, matrix_integer_pair_gen_var_1, matrix_rational_gen_var_1,
+    matrix_rational_pair_gen_var_1,
+};
+use malachite_matrix::test_util::linear_algebra::determinant::{
+    matrix_integer_determinant_naive, matrix_rational_determinant_naive,
+};
+use malachite_matrix::Matrix;
+use malachite_nz::integer::Integer;
+use malachite_q::Rational;
+
+#[test]
+fn test_determinant() {
+    let test = |n, s, out| {
+        let a = Matrix::from_entries(n, n, vec_from_str::<Integer>(s).unwrap());
+        let det = a.determinant();
+        assert!(det.is_valid());
+        assert_eq!(det.to_string(), out);
+        assert_eq!(matrix_integer_determinant_naive(&a), det);
+    };
+    test(0, "[]", "1");
+    test(1, "[-5]", "-5");
+    test(2, "[1, 2, 3, 4]", "-2");
+    test(2, "[0, 1, 1, 0]", "-1");
+    test(2, "[1, 2, 2, 4]", "0");
+    test(3, "[2, 3, 1, 4, 1, -2, 6, 4, 1]", "-20");
+    test(3, "[6, 1, 1, 4, -2, 5, 2, 8, 7]", "-306");
+    test(3, "[0, 0, 1, 0, 1, 0, 1, 0, 0]", "-1");
+    test(3, "[0, 2, 0, 0, 0, 3, 5, 0, 0]", "30");
+    test(3, "[1, 2, 3, 4, 5, 6, 7, 8, 9]", "0");
+    test(
+        4,
+        "[1, 1, 1, 1, 1, 2, 4, 8, 1, 3, 9, 27, 1, 4, 16, 64]",
+        "12",
+    );
+    test(
+        2,
+        "[18446744073709551616, 1, 1, 18446744073709551616]",
+        "340282366920938463463374607431768211455",
+    );
+
+    let test = |n, s, out| {
+        let a = Matrix::from_entries(n, n, vec_from_str::<Rational>(s).unwrap());
+        let det = a.determinant();
+        assert!(det.is_valid());
+        assert_eq!(det.to_string(), out);
+        assert_eq!(matrix_rational_determinant_naive(&a), det);
+    };
+    test(0, "[]", "1");
+    test(2, "[1/2, 1/3, 1/4, 1/5]", "1/60");
+    test(2, "[1/2, 0, 0, -2/3]", "-1/3");
+    test(2, "[1/2, 1/3, 3/2, 1]", "0");
+    test(3, "[1, 1/2, 1/3, 1/2, 1/3, 1/4, 1/3, 1/4, 1/5]", "1/2160");
+}
+
+#[test]
+#[should_panic]
+fn determinant_fail_1() {
+    Matrix::<Integer>::zero(2, 3).determinant();
+}
+
+#[test]
+#[should_panic]
+fn determinant_fail_2() {
+    Matrix::<Rational>::zero(1, 0).determinant();
+}
+
+#[test]
+fn determinant_properties() {
+    let mut config = GenConfig::new();
+    config.insert("mean_bits_n", 16);
+    matrix_integer_gen_var_1().test_properties(|a| {
+        let det = a.determinant();
+        assert!(det.is_valid());
+        assert_eq!(matrix_integer_determinant_naive(&a), det);
+        assert_eq!(a.transpose().determinant(), det);
+        let neg_det = (-&a).determinant();
+        if a.height().even() {
+            assert_eq!(neg_det, det);
+        } else {
+            assert_eq!(neg_det, -&det);
+        }
+        let a_q = Matrix::from_entries(
+            a.height(),
+            a.width(),
+            a.entries().iter().map(Rational::from).collect(),
+        );
+        assert_eq!(a_q.determinant(), det);
+    });
+
+    matrix_integer_pair_gen_var_1().test_properties(|(a, b)| {
+        assert_eq!((&a * &b).determinant(), a.determinant() * b.determinant());
+    });
+
+    matrix_rational_gen_var_1().test_properties_with_config(&config, |a| {
+        let det = a.determinant();
+        assert!(det.is_valid());
+        assert_eq!(matrix_rational_determinant_naive(&a), det);
+        assert_eq!(a.transpose().determinant(), det);
+    });
+
+    matrix_rational_pair_gen_var_1().test_properties_with_config(&config, |(a, b)| {
+        assert_eq!((&a * &b).determinant(), a.determinant() * b.determinant());
+    });
+
+    for n in 0..10 {
+        assert_eq!(Matrix::<Integer>::identity(n).determinant(), Integer::ONE);
+        assert_eq!(Matrix::<Rational>::identity(n).determinant(), Rational::ONE);
+    }
+}
@@ -0,0 +1,80 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::UnsignedAbs;
+use malachite_base::num::basic::traits::Zero;
+use malachite_base::vecs::vec_from_str;
+use malachite_matrix::test_util::generators::{matrix_integer_gen, matrix_integer_gen_var_1};
+use malachite_matrix::Matrix;
+use malachite_nz::integer::Integer;
+
+#[test]
+fn test_fraction_free_echelon_form() {
+    let test = |height, width, s, out| {
+        let a = Matrix::from_entries(height, width, vec_from_str::<Integer>(s).unwrap());
+        let e = a.fraction_free_echelon_form();
+        assert!(e.is_valid());
+        assert_eq!(e.to_string(), out);
+    };
+    test(0, 0, "[]", "[]");
+    test(2, 0, "[]", "[[], []]");
+    test(1, 3, "[0, 5, -1]", "[[0, 5, -1]]");
+    test(2, 2, "[0, 0, 0, 0]", "[[0, 0], [0, 0]]");
+    test(2, 2, "[0, 1, 1, 0]", "[[1, 0], [0, 1]]");
+    test(2, 2, "[1, 2, 3, 4]", "[[1, 2], [0, -2]]");
+    test(
+        3,
+        3,
+        "[2, 3, 1, 4, 1, -2, 6, 4, 1]",
+        "[[2, 3, 1], [0, -10, -8], [0, 0, -20]]",
+    );
+    test(2, 3, "[0, 1, 2, 0, 2, 4]", "[[0, 1, 2], [0, 0, 0]]");
+    test(3, 2, "[1, 2, 2, 4, 3, 7]", "[[1, 2], [0, 1], [0, 0]]");
+    test(
+        3,
+        3,
+        "[1, 2, 3, 4, 5, 6, 7, 8, 9]",
+        "[[1, 2, 3], [0, -3, -6], [0, 0, 0]]",
+    );
+}
+
+fn leading_column(row: &[Integer]) -> Option<usize> {
+    row.iter().position(|x| *x != 0u32)
+}
+
+#[test]
+fn fraction_free_echelon_form_properties() {
+    matrix_integer_gen().test_properties(|a| {
+        let e = a.fraction_free_echelon_form();
+        assert!(e.is_valid());
+        assert_eq!(e.height(), a.height());
+        assert_eq!(e.width(), a.width());
+
+        let leads: Vec<_> = (0..e.height()).map(|i| leading_column(e.row(i))).collect();
+        let rank = leads.iter().take_while(|c| c.is_some()).count();
+        assert!(leads[rank..].iter().all(Option::is_none));
+        assert!(leads[..rank].windows(2).all(|w| w[0] < w[1]));
+        assert_eq!(rank, a.rank());
+        assert_eq!(a == Matrix::zero(a.height(), a.width()), rank == 0);
+    });
+
+    matrix_integer_gen_var_1().test_properties(|a| {
+        let n = a.height();
+        let e = a.fraction_free_echelon_form();
+        let det = a.determinant();
+        if n == 0 {
+            return;
+        }
+        let last = e.get(n - 1, n - 1);
+        if det == 0u32 {
+            assert_eq!(*last, Integer::ZERO);
+        } else {
+            assert_eq!(last.unsigned_abs(), det.unsigned_abs());
+        }
+    });
+}
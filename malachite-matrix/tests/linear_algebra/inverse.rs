@@ -0,0 +1,109 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::basic::traits::Zero;
+use malachite_base::strings::ToDebugString;
+use malachite_base::test_util::generators::common::GenConfig;
+use malachite_base::vecs::vec_from_str;
+use malachite_matrix::test_util::generators::{
+    matrix_integer_gen_var_1, matrix_rational_gen_var_1, matrix_rational_pair_gen_var_1,
+};
+use malachite_matrix::Matrix;
+use malachite_nz::integer::Integer;
+use malachite_q::Rational;
+
+#[test]
+fn test_inverse() {
+    let test = |n, s, out| {
+        let a = Matrix::from_entries(n, n, vec_from_str::<Integer>(s).unwrap());
+        let inverse = a.inverse();
+        assert!(inverse.as_ref().is_none_or(Matrix::<Rational>::is_valid));
+        assert_eq!(inverse.to_debug_string(), out);
+    };
+    test(0, "[]", "Some([])");
+    test(1, "[0]", "None");
+    test(1, "[-4]", "Some([[-1/4]])");
+    test(2, "[2, 1, 5, 3]", "Some([[3, -1], [-5, 2]])");
+    test(2, "[1, 2, 3, 4]", "Some([[-2, 1], [3/2, -1/2]])");
+    test(2, "[1, 2, 2, 4]", "None");
+    test(2, "[0, 1, 1, 0]", "Some([[0, 1], [1, 0]])");
+    test(3, "[1, 2, 3, 4, 5, 6, 7, 8, 9]", "None");
+    test(
+        3,
+        "[2, 3, 1, 4, 1, -2, 6, 4, 1]",
+        "Some([[-9/20, -1/20, 7/20], [4/5, 1/5, -2/5], [-1/2, -1/2, 1/2]])",
+    );
+
+    let test = |n, s, out| {
+        let a = Matrix::from_entries(n, n, vec_from_str::<Rational>(s).unwrap());
+        let inverse = a.inverse();
+        assert!(inverse.as_ref().is_none_or(Matrix::<Rational>::is_valid));
+        assert_eq!(inverse.to_debug_string(), out);
+    };
+    test(2, "[1/2, 1/3, 1/4, 1/5]", "Some([[12, -20], [-15, 30]])");
+    test(2, "[1/2, 1/3, 3/2, 1]", "None");
+    test(
+        3,
+        "[1, 1/2, 1/3, 1/2, 1/3, 1/4, 1/3, 1/4, 1/5]",
+        "Some([[9, -36, 30], [-36, 192, -180], [30, -180, 180]])",
+    );
+}
+
+#[test]
+#[should_panic]
+fn inverse_fail_1() {
+    Matrix::<Integer>::zero(2, 1).inverse();
+}
+
+#[test]
+#[should_panic]
+fn inverse_fail_2() {
+    Matrix::<Rational>::zero(0, 1).inverse();
+}
+
+#[test]
+fn inverse_properties() {
+    let mut config = GenConfig::new();
+    config.insert("mean_bits_n", 16);
+    config.insert("mean_length_n", 8);
+    matrix_integer_gen_var_1().test_properties_with_config(&config, |a| {
+        let n = a.height();
+        let inverse = a.inverse();
+        assert_eq!(inverse.is_some(), a.determinant() != 0u32);
+        let a_q = Matrix::from_entries(n, n, a.entries().iter().map(Rational::from).collect());
+        assert_eq!(a_q.inverse(), inverse);
+        if let Some(inverse) = inverse {
+            assert!(inverse.is_valid());
+            assert_eq!(&a_q * &inverse, Matrix::identity(n));
+            assert_eq!(&inverse * &a_q, Matrix::identity(n));
+            assert_eq!(
+                inverse.determinant() * Rational::from(a.determinant()),
+                1u32
+            );
+        }
+    });
+
+    matrix_rational_gen_var_1().test_properties_with_config(&config, |a| {
+        let n = a.height();
+        let inverse = a.inverse();
+        assert_eq!(inverse.is_some(), a.determinant() != Rational::ZERO);
+        if let Some(inverse) = inverse {
+            assert!(inverse.is_valid());
+            assert_eq!(&a * &inverse, Matrix::identity(n));
+            assert_eq!(&inverse * &a, Matrix::identity(n));
+            assert_eq!(inverse.inverse().as_ref(), Some(&a));
+            assert_eq!(a.transpose().inverse(), Some(inverse.transpose()));
+        }
+    });
+
+    matrix_rational_pair_gen_var_1().test_properties_with_config(&config, |(a, b)| {
+        if let (Some(a_inverse), Some(b_inverse)) = (a.inverse(), b.inverse()) {
+            assert_eq!((&a * &b).inverse(), Some(b_inverse * a_inverse));
+        }
+    });
+}
@@ -0,0 +1,95 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::Gcd;
+use malachite_base::num::basic::traits::Zero;
+use malachite_base::strings::ToDebugString;
+use malachite_base::vecs::vec_from_str;
+use malachite_matrix::test_util::generators::{matrix_integer_gen, matrix_rational_gen};
+use malachite_matrix::Matrix;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_q::Rational;
+
+fn column(xs: Vec<Integer>) -> Matrix<Integer> {
+    Matrix::from_entries(xs.len(), 1, xs)
+}
+
+fn rational_column(xs: Vec<Rational>) -> Matrix<Rational> {
+    Matrix::from_entries(xs.len(), 1, xs)
+}
+
+#[test]
+fn test_nullspace() {
+    let test = |height, width, s, out| {
+        let a = Matrix::from_entries(height, width, vec_from_str::<Integer>(s).unwrap());
+        let basis = a.nullspace();
+        assert_eq!(basis.to_debug_string(), out);
+        for v in basis {
+            assert_eq!(&a * column(v), Matrix::zero(height, 1));
+        }
+    };
+    test(0, 0, "[]", "[]");
+    test(0, 2, "[]", "[[1, 0], [0, 1]]");
+    test(2, 2, "[1, 0, 0, 1]", "[]");
+    test(1, 2, "[0, 0]", "[[1, 0], [0, 1]]");
+    test(2, 3, "[1, 2, 3, 4, 5, 6]", "[[1, -2, 1]]");
+    test(1, 3, "[2, 4, 3]", "[[-2, 1, 0], [-3, 0, 2]]");
+    test(2, 3, "[0, 1, 2, 0, 2, 4]", "[[1, 0, 0], [0, -2, 1]]");
+    test(3, 3, "[1, 2, 3, 4, 5, 6, 7, 8, 9]", "[[1, -2, 1]]");
+    test(2, 2, "[6, 4, 9, 6]", "[[-2, 3]]");
+
+    let test = |height, width, s, out| {
+        let a = Matrix::from_entries(height, width, vec_from_str::<Rational>(s).unwrap());
+        let basis = a.nullspace();
+        assert_eq!(basis.to_debug_string(), out);
+        for v in basis {
+            assert_eq!(&a * rational_column(v), Matrix::zero(height, 1));
+        }
+    };
+    test(1, 3, "[1/2, 1, 1/8]", "[[-2, 1, 0], [-1/4, 0, 1]]");
+    test(2, 2, "[1/2, 1/3, 3/2, 1]", "[[-2/3, 1]]");
+    test(2, 2, "[1/2, 1/3, 1/4, 1/5]", "[]");
+}
+
+#[test]
+fn nullspace_properties() {
+    matrix_integer_gen().test_properties(|a| {
+        let basis = a.nullspace();
+        assert_eq!(basis.len() + a.rank(), a.width());
+        for v in &basis {
+            assert_eq!(v.len(), a.width());
+            assert!(v.iter().all(Integer::is_valid));
+            assert!(v.iter().any(|x| *x != 0u32));
+            assert_eq!(
+                v.iter()
+                    .fold(Natural::ZERO, |g, x| g.gcd(x.unsigned_abs_ref())),
+                1u32
+            );
+            assert_eq!(&a * column(v.clone()), Matrix::zero(a.height(), 1));
+        }
+        if !basis.is_empty() {
+            let b = Matrix::from_rows(basis);
+            assert_eq!(b.rank(), b.height());
+        }
+    });
+
+    matrix_rational_gen().test_properties(|a| {
+        let basis = a.nullspace();
+        assert_eq!(basis.len() + a.rank(), a.width());
+        for v in &basis {
+            assert_eq!(v.len(), a.width());
+            assert!(v.iter().all(Rational::is_valid));
+            assert_eq!(&a * rational_column(v.clone()), Matrix::zero(a.height(), 1));
+        }
+        if !basis.is_empty() {
+            let b = Matrix::from_rows(basis);
+            assert_eq!(b.rank(), b.height());
+        }
+    });
+}
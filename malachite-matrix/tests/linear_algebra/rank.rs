@@ -0,0 +1,79 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::vecs::vec_from_str;
+use malachite_matrix::test_util::generators::{
+    matrix_integer_gen, matrix_integer_gen_var_1, matrix_rational_gen,
+};
+use malachite_matrix::Matrix;
+use malachite_nz::integer::Integer;
+use malachite_q::Rational;
+
+#[test]
+fn test_rank() {
+    let test = |height, width, s, out| {
+        let a = Matrix::from_entries(height, width, vec_from_str::<Integer>(s).unwrap());
+        assert_eq!(a.rank(), out);
+        assert_eq!(a.transpose().rank(), out);
+    };
+    test(0, 0, "[]", 0);
+    test(3, 0, "[]", 0);
+    test(1, 1, "[0]", 0);
+    test(1, 1, "[-3]", 1);
+    test(2, 3, "[0, 0, 0, 0, 0, 0]", 0);
+    test(2, 3, "[1, 2, 3, 2, 4, 6]", 1);
+    test(2, 3, "[1, 2, 3, 4, 5, 6]", 2);
+    test(3, 3, "[1, 2, 3, 4, 5, 6, 7, 8, 9]", 2);
+    test(3, 3, "[2, 3, 1, 4, 1, -2, 6, 4, 1]", 3);
+    test(
+        2,
+        2,
+        "[18446744073709551616, 18446744073709551615, 18446744073709551617, 18446744073709551616]",
+        2,
+    );
+
+    let test = |height, width, s, out| {
+        let a = Matrix::from_entries(height, width, vec_from_str::<Rational>(s).unwrap());
+        assert_eq!(a.rank(), out);
+        assert_eq!(a.transpose().rank(), out);
+    };
+    test(2, 2, "[1/2, 1/3, 3/2, 1]", 1);
+    test(2, 2, "[1/2, 1/3, 1/4, 1/5]", 2);
+    test(3, 1, "[0, 0, 1/7]", 1);
+}
+
+#[test]
+fn rank_properties() {
+    matrix_integer_gen().test_properties(|a| {
+        let rank = a.rank();
+        assert!(rank <= a.height());
+        assert!(rank <= a.width());
+        assert_eq!(a.transpose().rank(), rank);
+        assert_eq!((-&a).rank(), rank);
+        assert_eq!(rank + a.nullspace().len(), a.width());
+        assert_eq!(rank == 0, a == Matrix::zero(a.height(), a.width()));
+        let a_q = Matrix::from_entries(
+            a.height(),
+            a.width(),
+            a.entries().iter().map(Rational::from).collect(),
+        );
+        assert_eq!(a_q.rank(), rank);
+    });
+
+    matrix_integer_gen_var_1().test_properties(|a| {
+        assert_eq!(a.rank() == a.height(), a.determinant() != 0u32);
+    });
+
+    matrix_rational_gen().test_properties(|a| {
+        let rank = a.rank();
+        assert!(rank <= a.height());
+        assert!(rank <= a.width());
+        assert_eq!(a.transpose().rank(), rank);
+        assert_eq!(rank + a.nullspace().len(), a.width());
+    });
+}
@@ -0,0 +1,122 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::strings::ToDebugString;
+use malachite_base::vecs::vec_from_str;
+use malachite_matrix::test_util::generators::{
+    matrix_integer_integer_vec_pair_gen, matrix_integer_integer_vec_pair_gen_var_1,
+    matrix_rational_rational_vec_pair_gen,
+};
+use malachite_matrix::test_util::linear_algebra::solve::{
+    matrix_integer_solve_naive, matrix_rational_solve_naive,
+};
+use malachite_matrix::Matrix;
+use malachite_nz::integer::Integer;
+use malachite_q::Rational;
+
+fn column(xs: Vec<Rational>) -> Matrix<Rational> {
+    Matrix::from_entries(xs.len(), 1, xs)
+}
+
+fn to_rational_matrix(a: &Matrix<Integer>) -> Matrix<Rational> {
+    Matrix::from_entries(
+        a.height(),
+        a.width(),
+        a.entries().iter().map(Rational::from).collect(),
+    )
+}
+
+#[test]
+fn test_solve() {
+    let test = |height, width, s, t, out| {
+        let a = Matrix::from_entries(height, width, vec_from_str::<Integer>(s).unwrap());
+        let b = vec_from_str::<Integer>(t).unwrap();
+        let x = a.solve(&b);
+        assert_eq!(x.to_debug_string(), out);
+        assert_eq!(matrix_integer_solve_naive(&a, &b), x);
+    };
+    test(0, 0, "[]", "[]", "Some([])");
+    test(0, 2, "[]", "[]", "Some([0, 0])");
+    test(1, 1, "[0]", "[0]", "Some([0])");
+    test(1, 1, "[0]", "[1]", "None");
+    test(1, 1, "[3]", "[-2]", "Some([-2/3])");
+    test(2, 2, "[2, 1, 1, 3]", "[3, 5]", "Some([4/5, 7/5])");
+    test(2, 2, "[1, 2, 2, 4]", "[1, 2]", "Some([1, 0])");
+    test(2, 2, "[1, 2, 2, 4]", "[1, 3]", "None");
+    test(1, 2, "[1, 1]", "[2]", "Some([2, 0])");
+    test(2, 1, "[1, 1]", "[1, 2]", "None");
+    test(2, 1, "[2, 4]", "[1, 2]", "Some([1/2])");
+    test(2, 3, "[0, 1, 2, 0, 2, 5]", "[3, 7]", "Some([0, 1, 1])");
+    test(
+        3,
+        3,
+        "[2, 3, 1, 4, 1, -2, 6, 4, 1]",
+        "[1, 0, -1]",
+        "Some([-4/5, 6/5, -1])",
+    );
+
+    let test = |height, width, s, t, out| {
+        let a = Matrix::from_entries(height, width, vec_from_str::<Rational>(s).unwrap());
+        let b = vec_from_str::<Rational>(t).unwrap();
+        let x = a.solve(&b);
+        assert_eq!(x.to_debug_string(), out);
+        assert_eq!(matrix_rational_solve_naive(&a, &b), x);
+    };
+    test(2, 2, "[1/2, 1, 0, 1/3]", "[1, 1]", "Some([-4, 3])");
+    test(2, 2, "[1/2, 1/3, 3/2, 1]", "[1, 3]", "Some([2, 0])");
+    test(2, 2, "[1/2, 1/3, 3/2, 1]", "[1, 2]", "None");
+}
+
+#[test]
+#[should_panic]
+fn solve_fail_1() {
+    Matrix::<Integer>::zero(2, 2).solve(&[Integer::from(1)]);
+}
+
+#[test]
+#[should_panic]
+fn solve_fail_2() {
+    Matrix::<Rational>::zero(1, 2).solve(&[]);
+}
+
+#[test]
+fn solve_properties() {
+    matrix_integer_integer_vec_pair_gen().test_properties(|(a, b)| {
+        let x = a.solve(&b);
+        assert_eq!(matrix_integer_solve_naive(&a, &b), x);
+        let a_q = to_rational_matrix(&a);
+        let b_q: Vec<Rational> = b.iter().map(Rational::from).collect();
+        assert_eq!(a_q.solve(&b_q), x);
+        if let Some(x) = x {
+            assert_eq!(x.len(), a.width());
+            assert!(x.iter().all(Rational::is_valid));
+            assert_eq!(&a_q * column(x), column(b_q));
+        } else {
+            assert!(a.rank() < a.height());
+        }
+    });
+
+    matrix_integer_integer_vec_pair_gen_var_1().test_properties(|(a, b)| {
+        let x = a.solve(&b);
+        if a.determinant() != 0u32 {
+            let x = x.unwrap();
+            let inverse = a.inverse().unwrap();
+            let b_q: Vec<Rational> = b.iter().map(Rational::from).collect();
+            assert_eq!(column(x), inverse * column(b_q));
+        }
+    });
+
+    matrix_rational_rational_vec_pair_gen().test_properties(|(a, b)| {
+        let x = a.solve(&b);
+        assert_eq!(matrix_rational_solve_naive(&a, &b), x);
+        if let Some(x) = x {
+            assert!(x.iter().all(Rational::is_valid));
+            assert_eq!(&a * column(x), column(b));
+        }
+    });
+}
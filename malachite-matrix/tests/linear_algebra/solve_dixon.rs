@@ -0,0 +1,115 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::strings::ToDebugString;
+use malachite_base::test_util::generators::common::GenConfig;
+use malachite_base::vecs::vec_from_str;
+use malachite_matrix::test_util::generators::{
+    matrix_integer_integer_vec_pair_gen_var_1, matrix_rational_rational_vec_pair_gen_var_1,
+};
+use malachite_matrix::Matrix;
+use malachite_nz::integer::Integer;
+use malachite_q::Rational;
+
+#[test]
+fn test_solve_dixon() {
+    let test = |n, s, t, out| {
+        let a = Matrix::from_entries(n, n, vec_from_str::<Integer>(s).unwrap());
+        let b = vec_from_str::<Integer>(t).unwrap();
+        let x = a.solve_dixon(&b);
+        assert_eq!(x.to_debug_string(), out);
+        if x.is_some() {
+            assert_eq!(a.solve(&b), x);
+        }
+    };
+    test(0, "[]", "[]", "Some([])");
+    test(1, "[0]", "[0]", "None");
+    test(1, "[3]", "[-2]", "Some([-2/3])");
+    test(2, "[2, 1, 1, 3]", "[3, 5]", "Some([4/5, 7/5])");
+    test(2, "[1, 2, 2, 4]", "[1, 2]", "None");
+    test(2, "[1, 2, 2, 4]", "[1, 3]", "None");
+    test(
+        3,
+        "[2, 3, 1, 4, 1, -2, 6, 4, 1]",
+        "[1, 0, -1]",
+        "Some([-4/5, 6/5, -1])",
+    );
+    // The determinant is divisible by the first prime tried, 2^32 - 5.
+    test(1, "[4294967291]", "[1]", "Some([1/4294967291])");
+    test(
+        2,
+        "[4294967291, 0, 0, 8589934582]",
+        "[4294967291, 1]",
+        "Some([1, 1/8589934582])",
+    );
+    test(
+        2,
+        "[18446744073709551616, 1, 1, 18446744073709551616]",
+        "[1, 123456789012345678901234567890]",
+        "Some([-123456788993898934827525016274/340282366920938463463374607431768211455, \
+        2277375791072698140248390838022561708011411210239/\
+        340282366920938463463374607431768211455])",
+    );
+
+    let test = |n, s, t, out| {
+        let a = Matrix::from_entries(n, n, vec_from_str::<Rational>(s).unwrap());
+        let b = vec_from_str::<Rational>(t).unwrap();
+        let x = a.solve_dixon(&b);
+        assert_eq!(x.to_debug_string(), out);
+        if x.is_some() {
+            assert_eq!(a.solve(&b), x);
+        }
+    };
+    test(2, "[1/2, 1, 0, 1/3]", "[1, 1]", "Some([-4, 3])");
+    test(2, "[1/2, 1/3, 3/2, 1]", "[1, 3]", "None");
+    test(
+        2,
+        "[1/2, 1/3, 1/4, 1/5]",
+        "[1/7, -1/11]",
+        "Some([272/77, -375/77])",
+    );
+}
+
+#[test]
+#[should_panic]
+fn solve_dixon_fail_1() {
+    Matrix::<Integer>::zero(1, 2).solve_dixon(&[Integer::from(1)]);
+}
+
+#[test]
+#[should_panic]
+fn solve_dixon_fail_2() {
+    Matrix::<Integer>::zero(2, 2).solve_dixon(&[Integer::from(1)]);
+}
+
+#[test]
+#[should_panic]
+fn solve_dixon_fail_3() {
+    Matrix::<Rational>::zero(2, 2).solve_dixon(&[Rational::from(1)]);
+}
+
+#[test]
+fn solve_dixon_properties() {
+    let mut config = GenConfig::new();
+    config.insert("mean_bits_n", 16);
+    matrix_integer_integer_vec_pair_gen_var_1().test_properties_with_config(&config, |(a, b)| {
+        let x = a.solve_dixon(&b);
+        assert_eq!(x.is_some(), a.determinant() != 0u32);
+        if x.is_some() {
+            assert_eq!(a.solve(&b), x);
+        }
+    });
+
+    matrix_rational_rational_vec_pair_gen_var_1().test_properties_with_config(&config, |(a, b)| {
+        let x = a.solve_dixon(&b);
+        assert_eq!(x.is_some(), a.determinant() != 0u32);
+        if x.is_some() {
+            assert_eq!(a.solve(&b), x);
+        }
+    });
+}
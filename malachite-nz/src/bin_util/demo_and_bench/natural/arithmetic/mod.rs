@@ -40,6 +40,7 @@ pub(crate) fn register(runner: &mut Runner) {
     mod_neg::register(runner);
     mod_op::register(runner);
     mod_pow::register(runner);
+    mod_pow_table::register(runner);
     mod_power_of_2::register(runner);
     mod_power_of_2_add::register(runner);
     mod_power_of_2_inverse::register(runner);
@@ -55,6 +56,7 @@ pub(crate) fn register(runner: &mut Runner) {
     mod_shr::register(runner);
     mod_square::register(runner);
     mod_sub::register(runner);
+    montgomery::register(runner);
     mul::register(runner);
     neg::register(runner);
     next_power_of_2::register(runner);
@@ -109,6 +111,7 @@ mod mod_mul;
 mod mod_neg;
 mod mod_op;
 mod mod_pow;
+mod mod_pow_table;
 mod mod_power_of_2;
 mod mod_power_of_2_add;
 mod mod_power_of_2_inverse;
@@ -124,6 +127,7 @@ mod mod_shl;
 mod mod_shr;
 mod mod_square;
 mod mod_sub;
+mod montgomery;
 mod mul;
 mod neg;
 mod next_power_of_2;
@@ -0,0 +1,121 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{ModMul, ModPow};
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::natural::arithmetic::mod_pow_table::ModPowTable;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::bench::bucketers::triple_1_3_prod_natural_bits_bucketer;
+use malachite_nz::test_util::generators::natural_triple_gen_var_5;
+
+// The number of exponents that each table is used for, and the number of pairs passed to
+// `mod_multi_pow`, in the benchmarks.
+const COUNT: u32 = 16;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_mod_pow_table_pow);
+    register_demo!(runner, demo_natural_mod_multi_pow);
+
+    register_bench!(runner, benchmark_mod_pow_table_pow_algorithms);
+    register_bench!(runner, benchmark_natural_mod_multi_pow_algorithms);
+}
+
+// Returns `COUNT` pairs derived from a base, an exponent, and a modulus.
+fn pairs_from_triple(x: &Natural, exp: &Natural, m: &Natural) -> Vec<(Natural, Natural)> {
+    (0..COUNT)
+        .map(|i| ((x + Natural::from(i)) % m, exp + Natural::from(i)))
+        .collect()
+}
+
+fn demo_mod_pow_table_pow(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, exp, m) in natural_triple_gen_var_5().get(gm, config).take(limit) {
+        let table = ModPowTable::new(x.clone(), m.clone(), exp.significant_bits());
+        println!("{}.pow({}) ≡ {} mod {}", x, exp, table.pow(&exp), m);
+    }
+}
+
+fn demo_natural_mod_multi_pow(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, exp, m) in natural_triple_gen_var_5().get(gm, config).take(limit) {
+        let pairs = pairs_from_triple(&x, &exp, &m);
+        println!(
+            "Natural::mod_multi_pow({:?}, {}) = {}",
+            pairs,
+            m,
+            Natural::mod_multi_pow(&pairs, &m)
+        );
+    }
+}
+
+fn benchmark_mod_pow_table_pow_algorithms(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "ModPowTable.pow(&Natural)",
+        BenchmarkType::Algorithms,
+        natural_triple_gen_var_5().get(gm, config),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_1_3_prod_natural_bits_bucketer("exp", "m"),
+        &mut [
+            ("ModPowTable.pow(&Natural)", &mut |(x, exp, m)| {
+                let last = &exp + Natural::from(COUNT - 1);
+                let table = ModPowTable::new(x, m, last.significant_bits());
+                for i in 0..COUNT {
+                    no_out!(table.pow(&(&exp + Natural::from(i))));
+                }
+            }),
+            (
+                "repeated Natural.mod_pow(&Natural, &Natural)",
+                &mut |(x, exp, m)| {
+                    for i in 0..COUNT {
+                        no_out!((&x).mod_pow(&(&exp + Natural::from(i)), &m));
+                    }
+                },
+            ),
+        ],
+    );
+}
+
+fn benchmark_natural_mod_multi_pow_algorithms(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "Natural::mod_multi_pow(&[(Natural, Natural)], &Natural)",
+        BenchmarkType::Algorithms,
+        natural_triple_gen_var_5()
+            .get(gm, config)
+            .map(|(x, exp, m)| (exp.clone(), pairs_from_triple(&x, &exp, &m), m)),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_1_3_prod_natural_bits_bucketer("exp", "m"),
+        &mut [
+            ("Natural::mod_multi_pow", &mut |(_, pairs, m)| {
+                no_out!(Natural::mod_multi_pow(&pairs, &m))
+            }),
+            ("product of Natural.mod_pow", &mut |(_, pairs, m)| {
+                let mut product = Natural::ONE % &m;
+                for (x, exp) in &pairs {
+                    product = product.mod_mul(x.mod_pow(exp, &m), &m);
+                }
+                no_out!(product)
+            }),
+        ],
+    );
+}
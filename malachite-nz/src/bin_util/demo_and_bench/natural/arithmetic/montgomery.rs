@@ -0,0 +1,64 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{ModPow, Pow};
+use malachite_base::test_util::bench::{run_benchmark, BenchmarkType};
+use malachite_base::test_util::generators::common::{GenConfig, GenMode};
+use malachite_base::test_util::runner::Runner;
+use malachite_nz::natural::arithmetic::montgomery::ModularRing;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::bench::bucketers::triple_1_3_prod_natural_bits_bucketer;
+use malachite_nz::test_util::generators::natural_triple_gen_var_5;
+
+pub(crate) fn register(runner: &mut Runner) {
+    register_demo!(runner, demo_montgomery_natural_pow);
+
+    register_bench!(runner, benchmark_montgomery_natural_pow_algorithms);
+}
+
+fn demo_montgomery_natural_pow(gm: GenMode, config: &GenConfig, limit: usize) {
+    for (x, exp, m) in natural_triple_gen_var_5().get(gm, config).take(limit) {
+        let m = m | Natural::from(1u32);
+        let ring = ModularRing::new(m.clone());
+        println!(
+            "{}.pow({}) ≡ {} mod {}",
+            x,
+            exp,
+            ring.element(&x).pow(&exp),
+            m
+        );
+    }
+}
+
+fn benchmark_montgomery_natural_pow_algorithms(
+    gm: GenMode,
+    config: &GenConfig,
+    limit: usize,
+    file_name: &str,
+) {
+    run_benchmark(
+        "MontgomeryNatural.pow(&Natural)",
+        BenchmarkType::Algorithms,
+        natural_triple_gen_var_5()
+            .get(gm, config)
+            .map(|(x, exp, m)| (x, exp, m | Natural::from(1u32))),
+        gm.name(),
+        limit,
+        file_name,
+        &triple_1_3_prod_natural_bits_bucketer("exp", "m"),
+        &mut [
+            ("MontgomeryNatural.pow(&Natural)", &mut |(x, exp, m)| {
+                let ring = ModularRing::new(m);
+                no_out!(ring.element(&x).pow(&exp))
+            }),
+            ("Natural.mod_pow(&Natural, &Natural)", &mut |(x, exp, m)| {
+                no_out!(x.mod_pow(&exp, &m))
+            }),
+        ],
+    );
+}
@@ -0,0 +1,67 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::integer::Integer;
+use crate::natural::arithmetic::crt::crt_helper;
+use crate::natural::Natural;
+
+impl Integer {
+    /// Solves a system of simultaneous congruences using the Chinese remainder theorem.
+    ///
+    /// Given pairs $(a_i, m_i)$, returns `Some((x, m))`, where $m = \operatorname{lcm}(m_1, m_2,
+    /// \ldots)$ and $x$ is the unique [`Integer`] such that $0 \leq x < m$ and $x \equiv a_i \mod
+    /// m_i$ for all $i$. The moduli do not need to be pairwise coprime; if the congruences have no
+    /// common solution, `None` is returned. The residues may be negative, and do not need to be
+    /// reduced. If there are no congruences, the result is `Some((0, 1))`.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, k) = O(kn (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the sum of the significant bits of the
+    /// residues and moduli, and $k$ is `congruences.len()`.
+    ///
+    /// # Panics
+    /// Panics if any modulus is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Integer::crt(&[
+    ///         (Integer::from(-1), Natural::from(3u32)),
+    ///         (Integer::from(-1), Natural::from(5u32))
+    ///     ])
+    ///     .to_debug_string(),
+    ///     "Some((14, 15))"
+    /// );
+    /// assert_eq!(
+    ///     Integer::crt(&[
+    ///         (Integer::from(-2), Natural::from(4u32)),
+    ///         (Integer::from(4), Natural::from(6u32))
+    ///     ])
+    ///     .to_debug_string(),
+    ///     "Some((10, 12))"
+    /// );
+    /// assert_eq!(
+    ///     Integer::crt(&[
+    ///         (Integer::from(-1), Natural::from(4u32)),
+    ///         (Integer::from(2), Natural::from(6u32))
+    ///     ]),
+    ///     None
+    /// );
+    /// ```
+    pub fn crt(congruences: &[(Integer, Natural)]) -> Option<(Integer, Natural)> {
+        crt_helper(congruences.iter().map(|(a, n)| (a.mod_natural_ref(n), n)))
+            .map(|(x, m)| (Integer::from(x), m))
+    }
+}
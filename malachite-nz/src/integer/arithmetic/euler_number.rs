@@ -0,0 +1,108 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::integer::Integer;
+use crate::natural::arithmetic::approximate_real::{
+    inverse_euler_product, zigzag_numbers, ApproximateReal,
+};
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{EulerNumber, Factorial};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+
+// Below this, the boustrophedon triangle is faster than the Dirichlet beta formula.
+const BETA_THRESHOLD: u64 = 32;
+
+// $\log_2 \pi$
+const LOG_2_PI: f64 = 1.651_496_129_472_318_7;
+
+// Computes $|E_n|$ for even $n$ using
+// $$
+// |E_n| = \frac{2^{n+2} n!}{\pi^{n+1}} \beta(n+1),
+// $$
+// where $\beta(s) = \sum_{k=0}^\infty (-1)^k (2k+1)^{-s} = \prod_{p > 2} (1 - (-1)^{(p-1)/2}
+// p^{-s})^{-1}$ is the Dirichlet beta function. Since $\beta(n + 1)$ is very close to 1 for large
+// $n$, only a few primes are needed. The error of the computation is tracked, and if the precision
+// turns out to be insufficient to round the result correctly, the computation is repeated with
+// more.
+fn euler_number_abs_beta(n: u64) -> Natural {
+    let numerator = Natural::factorial(n) << (n + 2);
+    // Estimate the number of bits of the result.
+    #[allow(clippy::cast_precision_loss)]
+    let pi_bits = (n + 1) as f64 * LOG_2_PI;
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let bits = numerator.significant_bits().saturating_sub(pi_bits as u64);
+    let numerator = ApproximateReal::from(numerator);
+    let mut guard_bits = (n.significant_bits() << 1) + 32;
+    loop {
+        let prec = bits + guard_bits;
+        let pi_power = ApproximateReal::pi(prec).pow(n + 1, prec);
+        let beta_inverse = inverse_euler_product(n + 1, prec, |p| match p & 3 {
+            1 => 1,
+            3 => -1,
+            _ => 0,
+        });
+        if let Some(result) = numerator
+            .div(&pi_power.mul(&beta_inverse, prec), prec)
+            .round()
+        {
+            return Natural::exact_from(result);
+        }
+        guard_bits <<= 1;
+    }
+}
+
+impl EulerNumber for Integer {
+    /// Computes the $n$th Euler number.
+    ///
+    /// $f(n) = E_n$, where $\operatorname{sech} x = \sum_{n=0}^\infty E_n x^n/n!$. The Euler
+    /// numbers of odd index are zero, and those of even index alternate in sign: $E_0 = 1$, $E_2 =
+    /// -1$, $E_4 = 5$, $E_6 = -61$, and so on.
+    ///
+    /// For small $n$, Seidel's boustrophedon triangle is used. For larger $n$, $|E_n|$ is
+    /// computed numerically from the Dirichlet beta function, $|E_n| = 2^{n+2} n! \beta(n+1) /
+    /// \pi^{n+1}$. The numerical error is tracked rigorously, and the precision is increased until
+    /// rounding is guaranteed to give the exact result.
+    ///
+    /// $|E_n| = O((2n/(\pi e))^n n^{1/2})$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 (\log n)^2)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::EulerNumber;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::euler_number(0), 1);
+    /// assert_eq!(Integer::euler_number(1), 0);
+    /// assert_eq!(Integer::euler_number(2), -1);
+    /// assert_eq!(Integer::euler_number(4), 5);
+    /// assert_eq!(Integer::euler_number(10), -50521);
+    /// assert_eq!(
+    ///     Integer::euler_number(50).to_string(),
+    ///     "-6053285248188621896314383785111649088103498225146815121"
+    /// );
+    /// ```
+    fn euler_number(n: u64) -> Integer {
+        if n & 1 == 1 {
+            return Integer::ZERO;
+        } else if n == 0 {
+            return Integer::ONE;
+        }
+        let abs = if n < BETA_THRESHOLD {
+            zigzag_numbers(n).pop().unwrap()
+        } else {
+            euler_number_abs_beta(n)
+        };
+        Integer::from_sign_and_abs(n & 3 == 0, abs)
+    }
+}
@@ -0,0 +1,112 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::integer::Integer;
+use malachite_base::num::arithmetic::traits::{ExpressAsPower, IsPerfectPower, Pow};
+use malachite_base::num::basic::traits::NegativeOne;
+use malachite_base::num::logic::traits::TrailingZeros;
+
+impl ExpressAsPower for Integer {
+    /// Expresses an [`Integer`] as a perfect power $y^k$, with the exponent $k \geq 2$ as large as
+    /// possible. Returns `None` if the [`Integer`] is not a perfect power.
+    ///
+    /// A negative [`Integer`] can only be a perfect power with an odd exponent. 0 and 1 are
+    /// expressed as $0^2$ and $1^2$, and $-1$ is expressed as $(-1)^3$.
+    ///
+    /// $f(x) = (y, k)$, where $y^k = x$ and $k \geq 2$ is maximal, if such $y$ and $k$ exist, and
+    /// `None` otherwise.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::{ExpressAsPower, Pow};
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(
+    ///     Integer::from(-1).express_as_power().to_debug_string(),
+    ///     "Some((-1, 3))"
+    /// );
+    /// assert_eq!(
+    ///     Integer::from(64).express_as_power().to_debug_string(),
+    ///     "Some((2, 6))"
+    /// );
+    /// assert_eq!(
+    ///     Integer::from(-64).express_as_power().to_debug_string(),
+    ///     "Some((-4, 3))"
+    /// );
+    /// assert_eq!(
+    ///     Integer::from(-16).express_as_power().to_debug_string(),
+    ///     "None"
+    /// );
+    /// assert_eq!(
+    ///     Integer::from(-10).pow(15).express_as_power().to_debug_string(),
+    ///     "Some((-10, 15))"
+    /// );
+    /// ```
+    fn express_as_power(&self) -> Option<(Integer, u64)> {
+        if self.sign {
+            return self
+                .abs
+                .express_as_power()
+                .map(|(base, k)| (Integer::from(base), k));
+        }
+        if self.abs == 1u32 {
+            return Some((Integer::NEGATIVE_ONE, 3));
+        }
+        // A negative number is a perfect power only if its absolute value is a perfect power with
+        // an odd exponent.
+        let (base, k) = self.abs.express_as_power()?;
+        let odd = k >> TrailingZeros::trailing_zeros(k);
+        if odd == 1 {
+            None
+        } else {
+            Some((-Integer::from(base.pow(k / odd)), odd))
+        }
+    }
+}
+
+impl IsPerfectPower for Integer {
+    /// Determines whether an [`Integer`] is a perfect power; that is, whether it is equal to $y^k$
+    /// for some $y$ and some $k \geq 2$.
+    ///
+    /// 0, 1, and $-1$ are perfect powers. A negative [`Integer`] can only be a perfect power with
+    /// an odd exponent.
+    ///
+    /// $f(x) = (\exists y \in \Z, k \in \N : k \geq 2 \land y^k = x)$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::IsPerfectPower;
+    /// use malachite_base::num::basic::traits::{NegativeOne, Zero};
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::ZERO.is_perfect_power(), true);
+    /// assert_eq!(Integer::NEGATIVE_ONE.is_perfect_power(), true);
+    /// assert_eq!(Integer::from(-8).is_perfect_power(), true);
+    /// assert_eq!(Integer::from(-4).is_perfect_power(), false);
+    /// assert_eq!(Integer::from(36).is_perfect_power(), true);
+    /// ```
+    #[inline]
+    fn is_perfect_power(&self) -> bool {
+        self.express_as_power().is_some()
+    }
+}
@@ -0,0 +1,41 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::integer::Integer;
+use malachite_base::num::arithmetic::traits::IsSquare;
+
+impl IsSquare for Integer {
+    /// Determines whether an [`Integer`] is a perfect square. Negative [`Integer`]s are never
+    /// squares.
+    ///
+    /// $f(x) = (\exists y \in \Z : y^2 = x)$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::{IsSquare, Pow};
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_nz::integer::Integer;
+    ///
+    /// assert_eq!(Integer::ZERO.is_square(), true);
+    /// assert_eq!(Integer::from(100).is_square(), true);
+    /// assert_eq!(Integer::from(-100).is_square(), false);
+    /// assert_eq!(Integer::from(10).pow(100).is_square(), true);
+    /// assert_eq!(Integer::from(10).pow(101).is_square(), false);
+    /// ```
+    #[inline]
+    fn is_square(&self) -> bool {
+        self.sign && self.abs.is_square()
+    }
+}
@@ -23,6 +23,8 @@ pub mod add_mul;
 /// [`BinomialCoefficient`](malachite_base::num::arithmetic::traits::BinomialCoefficient), a trait
 /// for computing the binomial coefficient of two numbers.
 pub mod binomial_coefficient;
+/// The Chinese remainder theorem for [`Integer`](super::Integer)s.
+pub mod crt;
 /// Division of [`Integer`](super::Integer)s.
 pub mod div;
 /// Implementations of [`DivExact`](malachite_base::num::arithmetic::traits::DivExact) and
@@ -58,14 +60,27 @@ pub mod eq_mod;
 /// Implementations of [`EqModPowerOf2`](malachite_base::num::arithmetic::traits::EqModPowerOf2), a
 /// trait for determining whether one number is equal to another modulo $2^k$.
 pub mod eq_mod_power_of_2;
+/// Implementations of [`EulerNumber`](malachite_base::num::arithmetic::traits::EulerNumber), a
+/// trait for computing Euler numbers.
+pub mod euler_number;
 /// Implementations of [`ExtendedGcd`](malachite_base::num::arithmetic::traits::ExtendedGcd), a
 /// trait for computing the extended GCD of two numbers.
 pub mod extended_gcd;
+/// Implementations of [`IsPerfectPower`](malachite_base::num::arithmetic::traits::IsPerfectPower)
+/// and [`ExpressAsPower`](malachite_base::num::arithmetic::traits::ExpressAsPower), traits for
+/// determining whether a number is a perfect power and for expressing it as one.
+pub mod is_perfect_power;
+/// Implementations of [`IsSquare`](malachite_base::num::arithmetic::traits::IsSquare), a trait for
+/// determining whether a number is a perfect square.
+pub mod is_square;
 /// Implementations of [`LegendreSymbol`](malachite_base::num::arithmetic::traits::LegendreSymbol),
 /// [`JacobiSymbol`](malachite_base::num::arithmetic::traits::JacobiSymbol), and
 /// [`KroneckerSymbol`](malachite_base::num::arithmetic::traits::KroneckerSymbol), traits for
 /// computing the Legendre, Jacobi, and Kronecker symbols of two numbers.
 pub mod kronecker_symbol;
+/// Implementations of [`ModInverse`](malachite_base::num::arithmetic::traits::ModInverse), a trait
+/// for finding the multiplicative inverse of a number modulo another number.
+pub mod mod_inverse;
 /// Implementations of traits for finding the remainder of two numbers, subject to various rounding
 /// rules.
 ///
@@ -78,6 +93,9 @@ pub mod kronecker_symbol;
 ///
 /// The [`Rem`](core::ops::Rem) trait in the standard library rounds towards 0.
 pub mod mod_op;
+/// Implementations of [`ModPow`](malachite_base::num::arithmetic::traits::ModPow), a trait for
+/// raising a number to a power modulo another number.
+pub mod mod_pow;
 /// Implementations of traits for finding the remainder of a number divided by $2^k$, subject to
 /// various rounding rules.
 ///
@@ -89,6 +107,10 @@ pub mod mod_op;
 /// | towards 0 | [`RemPowerOf2`](malachite_base::num::arithmetic::traits::RemPowerOf2) | [`RemPowerOf2Assign`](malachite_base::num::arithmetic::traits::RemPowerOf2Assign)       |
 /// | towards $\infty$  | [`CeilingModPowerOf2`](malachite_base::num::arithmetic::traits::CeilingModPowerOf2) | [`CeilingModPowerOf2Assign`](malachite_base::num::arithmetic::traits::CeilingModPowerOf2Assign) |
 pub mod mod_power_of_2;
+/// Implementations of [`ModSqrt`](malachite_base::num::arithmetic::traits::ModSqrt) and
+/// [`ModPrimePowerSqrt`](malachite_base::num::arithmetic::traits::ModPrimePowerSqrt), traits for
+/// finding square roots modulo a prime or a prime power.
+pub mod mod_sqrt;
 /// Multiplication of [`Integer`](super::Integer)s.
 pub mod mul;
 /// Negation of an [`Integer`](super::Integer).
@@ -0,0 +1,184 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::integer::Integer;
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::ModInverse;
+use malachite_base::num::basic::traits::Zero;
+
+// Computes the inverse of a `Natural` that is already reduced modulo `m`. Unlike the `Natural`
+// implementation, this accepts 0, and treats every number as invertible modulo 1.
+pub(crate) fn mod_inverse_reduced(x: Natural, m: &Natural) -> Option<Natural> {
+    if *m == 1u32 {
+        Some(Natural::ZERO)
+    } else if x == 0u32 {
+        None
+    } else {
+        x.mod_inverse(m)
+    }
+}
+
+impl ModInverse<Natural> for Integer {
+    type Output = Natural;
+
+    /// Computes the multiplicative inverse of an [`Integer`] modulo a [`Natural`] $m$. The
+    /// [`Integer`] does not need to be reduced modulo $m$. Both the [`Integer`] and the [`Natural`]
+    /// are taken by value.
+    ///
+    /// Returns `None` if $x$ and $m$ are not coprime.
+    ///
+    /// $f(x, m) = y$, where $0 \leq y < m$ and $xy \equiv 1 \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModInverse;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Integer::from(-3).mod_inverse(Natural::from(10u32)),
+    ///     Some(Natural::from(3u32))
+    /// );
+    /// assert_eq!(Integer::from(-4).mod_inverse(Natural::from(10u32)), None);
+    /// ```
+    #[inline]
+    fn mod_inverse(self, m: Natural) -> Option<Natural> {
+        mod_inverse_reduced(self.mod_natural_ref(&m), &m)
+    }
+}
+
+impl ModInverse<&Natural> for Integer {
+    type Output = Natural;
+
+    /// Computes the multiplicative inverse of an [`Integer`] modulo a [`Natural`] $m$. The
+    /// [`Integer`] does not need to be reduced modulo $m$. The [`Integer`] is taken by value and
+    /// the [`Natural`] by reference.
+    ///
+    /// Returns `None` if $x$ and $m$ are not coprime.
+    ///
+    /// $f(x, m) = y$, where $0 \leq y < m$ and $xy \equiv 1 \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModInverse;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Integer::from(-3).mod_inverse(&Natural::from(10u32)),
+    ///     Some(Natural::from(3u32))
+    /// );
+    /// assert_eq!(Integer::from(-4).mod_inverse(&Natural::from(10u32)), None);
+    /// ```
+    #[inline]
+    fn mod_inverse(self, m: &Natural) -> Option<Natural> {
+        mod_inverse_reduced(self.mod_natural_ref(m), m)
+    }
+}
+
+impl ModInverse<Natural> for &Integer {
+    type Output = Natural;
+
+    /// Computes the multiplicative inverse of an [`Integer`] modulo a [`Natural`] $m$. The
+    /// [`Integer`] does not need to be reduced modulo $m$. The [`Integer`] is taken by reference
+    /// and the [`Natural`] by value.
+    ///
+    /// Returns `None` if $x$ and $m$ are not coprime.
+    ///
+    /// $f(x, m) = y$, where $0 \leq y < m$ and $xy \equiv 1 \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModInverse;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     (&Integer::from(-3)).mod_inverse(Natural::from(10u32)),
+    ///     Some(Natural::from(3u32))
+    /// );
+    /// assert_eq!((&Integer::from(-4)).mod_inverse(Natural::from(10u32)), None);
+    /// ```
+    #[inline]
+    fn mod_inverse(self, m: Natural) -> Option<Natural> {
+        mod_inverse_reduced(self.mod_natural_ref(&m), &m)
+    }
+}
+
+impl ModInverse<&Natural> for &Integer {
+    type Output = Natural;
+
+    /// Computes the multiplicative inverse of an [`Integer`] modulo a [`Natural`] $m$. The
+    /// [`Integer`] does not need to be reduced modulo $m$. Both the [`Integer`] and the [`Natural`]
+    /// are taken by reference.
+    ///
+    /// Returns `None` if $x$ and $m$ are not coprime.
+    ///
+    /// $f(x, m) = y$, where $0 \leq y < m$ and $xy \equiv 1 \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// m.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModInverse;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     (&Integer::from(-3)).mod_inverse(&Natural::from(10u32)),
+    ///     Some(Natural::from(3u32))
+    /// );
+    /// assert_eq!((&Integer::from(-4)).mod_inverse(&Natural::from(10u32)), None);
+    /// ```
+    #[inline]
+    fn mod_inverse(self, m: &Natural) -> Option<Natural> {
+        mod_inverse_reduced(self.mod_natural_ref(m), m)
+    }
+}
@@ -7,6 +7,7 @@
 // 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
 
 use crate::integer::Integer;
+use crate::natural::Natural;
 use core::ops::{Rem, RemAssign};
 use malachite_base::num::arithmetic::traits::{
     CeilingMod, CeilingModAssign, Mod, ModAssign, NegMod, NegModAssign,
@@ -943,3 +944,15 @@ impl CeilingModAssign<&Integer> for Integer {
         self.sign = !other.sign || self.abs == 0;
     }
 }
+
+impl Integer {
+    // Returns the non-negative remainder of an `Integer` divided by a `Natural`, which must be
+    // nonzero.
+    pub(crate) fn mod_natural_ref(&self, m: &Natural) -> Natural {
+        if self.sign {
+            &self.abs % m
+        } else {
+            (&self.abs).neg_mod(m)
+        }
+    }
+}
@@ -0,0 +1,389 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::integer::arithmetic::mod_inverse::mod_inverse_reduced;
+use crate::integer::Integer;
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::ModPow;
+
+// `x` must be already reduced modulo `m`.
+fn mod_pow_helper(x: Natural, exp_sign: bool, exp_abs: &Natural, m: &Natural) -> Natural {
+    if exp_sign {
+        x.mod_pow(exp_abs, m)
+    } else {
+        mod_inverse_reduced(x, m)
+            .expect("Cannot raise a non-invertible number to a negative power")
+            .mod_pow(exp_abs, m)
+    }
+}
+
+impl ModPow<Integer, Natural> for Integer {
+    type Output = Natural;
+
+    /// Raises an [`Integer`] to an [`Integer`] power modulo a [`Natural`] $m$. The base does not
+    /// need to be reduced modulo $m$. If the exponent $n$ is negative, the result is the $|n|$th
+    /// power of the inverse of the base modulo $m$. All three arguments are taken by value.
+    ///
+    /// $f(x, n, m) = y$, where $0 \leq y < m$ and $x^n \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `max(self.significant_bits(),
+    /// m.significant_bits())`, and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero, or if `exp` is negative and `self` is not coprime to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPow;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Integer::from(-4).mod_pow(Integer::from(13), Natural::from(497u32)),
+    ///     52
+    /// );
+    /// assert_eq!(
+    ///     Integer::from(3).mod_pow(Integer::from(-1), Natural::from(10u32)),
+    ///     7
+    /// );
+    /// assert_eq!(
+    ///     Integer::from(-3).mod_pow(Integer::from(-3), Natural::from(10u32)),
+    ///     7
+    /// );
+    /// ```
+    #[inline]
+    fn mod_pow(self, exp: Integer, m: Natural) -> Natural {
+        mod_pow_helper(self.mod_natural_ref(&m), exp.sign, &exp.abs, &m)
+    }
+}
+
+impl ModPow<Integer, &Natural> for Integer {
+    type Output = Natural;
+
+    /// Raises an [`Integer`] to an [`Integer`] power modulo a [`Natural`] $m$. The base does not
+    /// need to be reduced modulo $m$. If the exponent $n$ is negative, the result is the $|n|$th
+    /// power of the inverse of the base modulo $m$. The first two arguments are taken by value and
+    /// the third by reference.
+    ///
+    /// $f(x, n, m) = y$, where $0 \leq y < m$ and $x^n \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `max(self.significant_bits(),
+    /// m.significant_bits())`, and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero, or if `exp` is negative and `self` is not coprime to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPow;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Integer::from(-4).mod_pow(Integer::from(13), &Natural::from(497u32)),
+    ///     52
+    /// );
+    /// assert_eq!(
+    ///     Integer::from(3).mod_pow(Integer::from(-1), &Natural::from(10u32)),
+    ///     7
+    /// );
+    /// assert_eq!(
+    ///     Integer::from(-3).mod_pow(Integer::from(-3), &Natural::from(10u32)),
+    ///     7
+    /// );
+    /// ```
+    #[inline]
+    fn mod_pow(self, exp: Integer, m: &Natural) -> Natural {
+        mod_pow_helper(self.mod_natural_ref(m), exp.sign, &exp.abs, m)
+    }
+}
+
+impl ModPow<&Integer, Natural> for Integer {
+    type Output = Natural;
+
+    /// Raises an [`Integer`] to an [`Integer`] power modulo a [`Natural`] $m$. The base does not
+    /// need to be reduced modulo $m$. If the exponent $n$ is negative, the result is the $|n|$th
+    /// power of the inverse of the base modulo $m$. The first and third arguments are taken by
+    /// value and the second by reference.
+    ///
+    /// $f(x, n, m) = y$, where $0 \leq y < m$ and $x^n \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `max(self.significant_bits(),
+    /// m.significant_bits())`, and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero, or if `exp` is negative and `self` is not coprime to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPow;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Integer::from(-4).mod_pow(&Integer::from(13), Natural::from(497u32)),
+    ///     52
+    /// );
+    /// assert_eq!(
+    ///     Integer::from(3).mod_pow(&Integer::from(-1), Natural::from(10u32)),
+    ///     7
+    /// );
+    /// assert_eq!(
+    ///     Integer::from(-3).mod_pow(&Integer::from(-3), Natural::from(10u32)),
+    ///     7
+    /// );
+    /// ```
+    #[inline]
+    fn mod_pow(self, exp: &Integer, m: Natural) -> Natural {
+        mod_pow_helper(self.mod_natural_ref(&m), exp.sign, &exp.abs, &m)
+    }
+}
+
+impl ModPow<&Integer, &Natural> for Integer {
+    type Output = Natural;
+
+    /// Raises an [`Integer`] to an [`Integer`] power modulo a [`Natural`] $m$. The base does not
+    /// need to be reduced modulo $m$. If the exponent $n$ is negative, the result is the $|n|$th
+    /// power of the inverse of the base modulo $m$. The first argument is taken by value and the
+    /// second and third by reference.
+    ///
+    /// $f(x, n, m) = y$, where $0 \leq y < m$ and $x^n \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `max(self.significant_bits(),
+    /// m.significant_bits())`, and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero, or if `exp` is negative and `self` is not coprime to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPow;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Integer::from(-4).mod_pow(&Integer::from(13), &Natural::from(497u32)),
+    ///     52
+    /// );
+    /// assert_eq!(
+    ///     Integer::from(3).mod_pow(&Integer::from(-1), &Natural::from(10u32)),
+    ///     7
+    /// );
+    /// assert_eq!(
+    ///     Integer::from(-3).mod_pow(&Integer::from(-3), &Natural::from(10u32)),
+    ///     7
+    /// );
+    /// ```
+    #[inline]
+    fn mod_pow(self, exp: &Integer, m: &Natural) -> Natural {
+        mod_pow_helper(self.mod_natural_ref(m), exp.sign, &exp.abs, m)
+    }
+}
+
+impl ModPow<Integer, Natural> for &Integer {
+    type Output = Natural;
+
+    /// Raises an [`Integer`] to an [`Integer`] power modulo a [`Natural`] $m$. The base does not
+    /// need to be reduced modulo $m$. If the exponent $n$ is negative, the result is the $|n|$th
+    /// power of the inverse of the base modulo $m$. The first argument is taken by reference and
+    /// the second and third by value.
+    ///
+    /// $f(x, n, m) = y$, where $0 \leq y < m$ and $x^n \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `max(self.significant_bits(),
+    /// m.significant_bits())`, and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero, or if `exp` is negative and `self` is not coprime to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPow;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     (&Integer::from(-4)).mod_pow(Integer::from(13), Natural::from(497u32)),
+    ///     52
+    /// );
+    /// assert_eq!(
+    ///     (&Integer::from(3)).mod_pow(Integer::from(-1), Natural::from(10u32)),
+    ///     7
+    /// );
+    /// assert_eq!(
+    ///     (&Integer::from(-3)).mod_pow(Integer::from(-3), Natural::from(10u32)),
+    ///     7
+    /// );
+    /// ```
+    #[inline]
+    fn mod_pow(self, exp: Integer, m: Natural) -> Natural {
+        mod_pow_helper(self.mod_natural_ref(&m), exp.sign, &exp.abs, &m)
+    }
+}
+
+impl ModPow<Integer, &Natural> for &Integer {
+    type Output = Natural;
+
+    /// Raises an [`Integer`] to an [`Integer`] power modulo a [`Natural`] $m$. The base does not
+    /// need to be reduced modulo $m$. If the exponent $n$ is negative, the result is the $|n|$th
+    /// power of the inverse of the base modulo $m$. The first and third arguments are taken by
+    /// reference and the second by value.
+    ///
+    /// $f(x, n, m) = y$, where $0 \leq y < m$ and $x^n \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `max(self.significant_bits(),
+    /// m.significant_bits())`, and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero, or if `exp` is negative and `self` is not coprime to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPow;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     (&Integer::from(-4)).mod_pow(Integer::from(13), &Natural::from(497u32)),
+    ///     52
+    /// );
+    /// assert_eq!(
+    ///     (&Integer::from(3)).mod_pow(Integer::from(-1), &Natural::from(10u32)),
+    ///     7
+    /// );
+    /// assert_eq!(
+    ///     (&Integer::from(-3)).mod_pow(Integer::from(-3), &Natural::from(10u32)),
+    ///     7
+    /// );
+    /// ```
+    #[inline]
+    fn mod_pow(self, exp: Integer, m: &Natural) -> Natural {
+        mod_pow_helper(self.mod_natural_ref(m), exp.sign, &exp.abs, m)
+    }
+}
+
+impl ModPow<&Integer, Natural> for &Integer {
+    type Output = Natural;
+
+    /// Raises an [`Integer`] to an [`Integer`] power modulo a [`Natural`] $m$. The base does not
+    /// need to be reduced modulo $m$. If the exponent $n$ is negative, the result is the $|n|$th
+    /// power of the inverse of the base modulo $m$. The first two arguments are taken by reference
+    /// and the third by value.
+    ///
+    /// $f(x, n, m) = y$, where $0 \leq y < m$ and $x^n \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `max(self.significant_bits(),
+    /// m.significant_bits())`, and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero, or if `exp` is negative and `self` is not coprime to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPow;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     (&Integer::from(-4)).mod_pow(&Integer::from(13), Natural::from(497u32)),
+    ///     52
+    /// );
+    /// assert_eq!(
+    ///     (&Integer::from(3)).mod_pow(&Integer::from(-1), Natural::from(10u32)),
+    ///     7
+    /// );
+    /// assert_eq!(
+    ///     (&Integer::from(-3)).mod_pow(&Integer::from(-3), Natural::from(10u32)),
+    ///     7
+    /// );
+    /// ```
+    #[inline]
+    fn mod_pow(self, exp: &Integer, m: Natural) -> Natural {
+        mod_pow_helper(self.mod_natural_ref(&m), exp.sign, &exp.abs, &m)
+    }
+}
+
+impl ModPow<&Integer, &Natural> for &Integer {
+    type Output = Natural;
+
+    /// Raises an [`Integer`] to an [`Integer`] power modulo a [`Natural`] $m$. The base does not
+    /// need to be reduced modulo $m$. If the exponent $n$ is negative, the result is the $|n|$th
+    /// power of the inverse of the base modulo $m$. All three arguments are taken by reference.
+    ///
+    /// $f(x, n, m) = y$, where $0 \leq y < m$ and $x^n \equiv y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `max(self.significant_bits(),
+    /// m.significant_bits())`, and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero, or if `exp` is negative and `self` is not coprime to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPow;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     (&Integer::from(-4)).mod_pow(&Integer::from(13), &Natural::from(497u32)),
+    ///     52
+    /// );
+    /// assert_eq!(
+    ///     (&Integer::from(3)).mod_pow(&Integer::from(-1), &Natural::from(10u32)),
+    ///     7
+    /// );
+    /// assert_eq!(
+    ///     (&Integer::from(-3)).mod_pow(&Integer::from(-3), &Natural::from(10u32)),
+    ///     7
+    /// );
+    /// ```
+    #[inline]
+    fn mod_pow(self, exp: &Integer, m: &Natural) -> Natural {
+        mod_pow_helper(self.mod_natural_ref(m), exp.sign, &exp.abs, m)
+    }
+}
@@ -0,0 +1,355 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::integer::Integer;
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{ModPrimePowerSqrt, ModSqrt, Pow};
+
+impl ModSqrt<Natural> for Integer {
+    type Output = Natural;
+
+    /// Finds the smallest square root of an [`Integer`] modulo a prime [`Natural`] $p$. The
+    /// [`Integer`] does not need to be reduced modulo $p$. Both the [`Integer`] and the [`Natural`]
+    /// are taken by value.
+    ///
+    /// Returns `None` if $x$ is not a square modulo $p$. The modulus is assumed to be prime; if it
+    /// isn't, the result is either `None` or a square root of $x$.
+    ///
+    /// $f(x, p) = \min \\{y : 0 \leq y < p, y^2 \equiv x \mod p\\}$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// p.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `p` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModSqrt;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Integer::from(-3).mod_sqrt(Natural::from(13u32)),
+    ///     Some(Natural::from(6u32))
+    /// );
+    /// assert_eq!(Integer::from(-2).mod_sqrt(Natural::from(13u32)), None);
+    /// ```
+    #[inline]
+    fn mod_sqrt(self, p: Natural) -> Option<Natural> {
+        self.mod_natural_ref(&p).mod_sqrt(p)
+    }
+}
+
+impl ModSqrt<&Natural> for Integer {
+    type Output = Natural;
+
+    /// Finds the smallest square root of an [`Integer`] modulo a prime [`Natural`] $p$. The
+    /// [`Integer`] does not need to be reduced modulo $p$. The [`Integer`] is taken by value and
+    /// the [`Natural`] by reference.
+    ///
+    /// Returns `None` if $x$ is not a square modulo $p$. The modulus is assumed to be prime; if it
+    /// isn't, the result is either `None` or a square root of $x$.
+    ///
+    /// $f(x, p) = \min \\{y : 0 \leq y < p, y^2 \equiv x \mod p\\}$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// p.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `p` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModSqrt;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Integer::from(-3).mod_sqrt(&Natural::from(13u32)),
+    ///     Some(Natural::from(6u32))
+    /// );
+    /// assert_eq!(Integer::from(-2).mod_sqrt(&Natural::from(13u32)), None);
+    /// ```
+    #[inline]
+    fn mod_sqrt(self, p: &Natural) -> Option<Natural> {
+        self.mod_natural_ref(p).mod_sqrt(p)
+    }
+}
+
+impl ModSqrt<Natural> for &Integer {
+    type Output = Natural;
+
+    /// Finds the smallest square root of an [`Integer`] modulo a prime [`Natural`] $p$. The
+    /// [`Integer`] does not need to be reduced modulo $p$. The [`Integer`] is taken by reference
+    /// and the [`Natural`] by value.
+    ///
+    /// Returns `None` if $x$ is not a square modulo $p$. The modulus is assumed to be prime; if it
+    /// isn't, the result is either `None` or a square root of $x$.
+    ///
+    /// $f(x, p) = \min \\{y : 0 \leq y < p, y^2 \equiv x \mod p\\}$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// p.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `p` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModSqrt;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     (&Integer::from(-3)).mod_sqrt(Natural::from(13u32)),
+    ///     Some(Natural::from(6u32))
+    /// );
+    /// assert_eq!((&Integer::from(-2)).mod_sqrt(Natural::from(13u32)), None);
+    /// ```
+    #[inline]
+    fn mod_sqrt(self, p: Natural) -> Option<Natural> {
+        self.mod_natural_ref(&p).mod_sqrt(p)
+    }
+}
+
+impl ModSqrt<&Natural> for &Integer {
+    type Output = Natural;
+
+    /// Finds the smallest square root of an [`Integer`] modulo a prime [`Natural`] $p$. The
+    /// [`Integer`] does not need to be reduced modulo $p$. Both the [`Integer`] and the [`Natural`]
+    /// are taken by reference.
+    ///
+    /// Returns `None` if $x$ is not a square modulo $p$. The modulus is assumed to be prime; if it
+    /// isn't, the result is either `None` or a square root of $x$.
+    ///
+    /// $f(x, p) = \min \\{y : 0 \leq y < p, y^2 \equiv x \mod p\\}$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// p.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `p` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModSqrt;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     (&Integer::from(-3)).mod_sqrt(&Natural::from(13u32)),
+    ///     Some(Natural::from(6u32))
+    /// );
+    /// assert_eq!((&Integer::from(-2)).mod_sqrt(&Natural::from(13u32)), None);
+    /// ```
+    #[inline]
+    fn mod_sqrt(self, p: &Natural) -> Option<Natural> {
+        self.mod_natural_ref(p).mod_sqrt(p)
+    }
+}
+
+impl ModPrimePowerSqrt<Natural> for Integer {
+    type Output = Natural;
+
+    /// Finds the smallest square root of an [`Integer`] modulo $p^k$, where $p$ is a prime
+    /// [`Natural`]. The [`Integer`] does not need to be reduced modulo $p^k$. Both the [`Integer`]
+    /// and the [`Natural`] are taken by value.
+    ///
+    /// Returns `None` if $x$ is not a square modulo $p^k$. The modulus is assumed to be a prime
+    /// power; if it isn't, the result is unspecified.
+    ///
+    /// $f(x, p, k) = \min \\{y : 0 \leq y < p^k, y^2 \equiv x \mod p^k\\}$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(), pow *
+    /// p.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `p` is zero and `pow` is nonzero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPrimePowerSqrt;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Integer::from(-7).mod_prime_power_sqrt(Natural::from(2u32), 10),
+    ///     Some(Natural::from(181u32))
+    /// );
+    /// assert_eq!(
+    ///     Integer::from(-2).mod_prime_power_sqrt(Natural::from(5u32), 3),
+    ///     None
+    /// );
+    /// ```
+    #[inline]
+    fn mod_prime_power_sqrt(self, p: Natural, pow: u64) -> Option<Natural> {
+        let pk = (&p).pow(pow);
+        self.mod_natural_ref(&pk).mod_prime_power_sqrt(p, pow)
+    }
+}
+
+impl ModPrimePowerSqrt<&Natural> for Integer {
+    type Output = Natural;
+
+    /// Finds the smallest square root of an [`Integer`] modulo $p^k$, where $p$ is a prime
+    /// [`Natural`]. The [`Integer`] does not need to be reduced modulo $p^k$. The [`Integer`] is
+    /// taken by value and the [`Natural`] by reference.
+    ///
+    /// Returns `None` if $x$ is not a square modulo $p^k$. The modulus is assumed to be a prime
+    /// power; if it isn't, the result is unspecified.
+    ///
+    /// $f(x, p, k) = \min \\{y : 0 \leq y < p^k, y^2 \equiv x \mod p^k\\}$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(), pow *
+    /// p.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `p` is zero and `pow` is nonzero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPrimePowerSqrt;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Integer::from(-7).mod_prime_power_sqrt(&Natural::from(2u32), 10),
+    ///     Some(Natural::from(181u32))
+    /// );
+    /// assert_eq!(
+    ///     Integer::from(-2).mod_prime_power_sqrt(&Natural::from(5u32), 3),
+    ///     None
+    /// );
+    /// ```
+    #[inline]
+    fn mod_prime_power_sqrt(self, p: &Natural, pow: u64) -> Option<Natural> {
+        let pk = p.pow(pow);
+        self.mod_natural_ref(&pk).mod_prime_power_sqrt(p, pow)
+    }
+}
+
+impl ModPrimePowerSqrt<Natural> for &Integer {
+    type Output = Natural;
+
+    /// Finds the smallest square root of an [`Integer`] modulo $p^k$, where $p$ is a prime
+    /// [`Natural`]. The [`Integer`] does not need to be reduced modulo $p^k$. The [`Integer`] is
+    /// taken by reference and the [`Natural`] by value.
+    ///
+    /// Returns `None` if $x$ is not a square modulo $p^k$. The modulus is assumed to be a prime
+    /// power; if it isn't, the result is unspecified.
+    ///
+    /// $f(x, p, k) = \min \\{y : 0 \leq y < p^k, y^2 \equiv x \mod p^k\\}$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(), pow *
+    /// p.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `p` is zero and `pow` is nonzero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPrimePowerSqrt;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     (&Integer::from(-7)).mod_prime_power_sqrt(Natural::from(2u32), 10),
+    ///     Some(Natural::from(181u32))
+    /// );
+    /// assert_eq!(
+    ///     (&Integer::from(-2)).mod_prime_power_sqrt(Natural::from(5u32), 3),
+    ///     None
+    /// );
+    /// ```
+    #[inline]
+    fn mod_prime_power_sqrt(self, p: Natural, pow: u64) -> Option<Natural> {
+        let pk = (&p).pow(pow);
+        self.mod_natural_ref(&pk).mod_prime_power_sqrt(p, pow)
+    }
+}
+
+impl ModPrimePowerSqrt<&Natural> for &Integer {
+    type Output = Natural;
+
+    /// Finds the smallest square root of an [`Integer`] modulo $p^k$, where $p$ is a prime
+    /// [`Natural`]. The [`Integer`] does not need to be reduced modulo $p^k$. Both the [`Integer`]
+    /// and the [`Natural`] are taken by reference.
+    ///
+    /// Returns `None` if $x$ is not a square modulo $p^k$. The modulus is assumed to be a prime
+    /// power; if it isn't, the result is unspecified.
+    ///
+    /// $f(x, p, k) = \min \\{y : 0 \leq y < p^k, y^2 \equiv x \mod p^k\\}$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(), pow *
+    /// p.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `p` is zero and `pow` is nonzero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPrimePowerSqrt;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     (&Integer::from(-7)).mod_prime_power_sqrt(&Natural::from(2u32), 10),
+    ///     Some(Natural::from(181u32))
+    /// );
+    /// assert_eq!(
+    ///     (&Integer::from(-2)).mod_prime_power_sqrt(&Natural::from(5u32), 3),
+    ///     None
+    /// );
+    /// ```
+    #[inline]
+    fn mod_prime_power_sqrt(self, p: &Natural, pow: u64) -> Option<Natural> {
+        let pk = p.pow(pow);
+        self.mod_natural_ref(&pk).mod_prime_power_sqrt(p, pow)
+    }
+}
@@ -0,0 +1,483 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::integer::Integer;
+use crate::natural::Natural;
+use alloc::vec::Vec;
+use core::cmp::max;
+use malachite_base::num::arithmetic::traits::{Factorial, FloorSqrt, Parity, Pow};
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::traits::{One, Two, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::factorization::traits::Primes;
+use malachite_base::num::logic::traits::SignificantBits;
+
+// $\log_2 2\pi$
+const LOG_2_TWO_PI: f64 = 2.651_496_129_472_318_7;
+
+// A real number known to lie in the interval $[(m - r)2^e, (m + r)2^e]$, where $m$ is the
+// mantissa, $r \geq 0$ the radius, and $e$ the exponent. Every operation takes a precision $p$,
+// truncates the mantissa of its result to at most $p$ significant bits, and widens the radius
+// enough that the result is guaranteed to contain the exact result of the operation applied to
+// any numbers in the input intervals. Series are truncated with rigorous bounds on the neglected
+// terms, which are also added to the radius.
+//
+// This is a lightweight stand-in for ball arithmetic, used to evaluate the analytic formulas
+// behind Bernoulli numbers, Euler numbers, and partition numbers. Since the radius is tracked,
+// `round` can tell whether the precision was sufficient; callers retry with more precision when
+// it was not.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct ApproximateReal {
+    mantissa: Integer,
+    radius: Natural,
+    exponent: i64,
+}
+
+impl From<Integer> for ApproximateReal {
+    #[inline]
+    fn from(mantissa: Integer) -> ApproximateReal {
+        ApproximateReal {
+            mantissa,
+            radius: Natural::ZERO,
+            exponent: 0,
+        }
+    }
+}
+
+impl From<Natural> for ApproximateReal {
+    #[inline]
+    fn from(n: Natural) -> ApproximateReal {
+        ApproximateReal::from(Integer::from(n))
+    }
+}
+
+impl From<u64> for ApproximateReal {
+    #[inline]
+    fn from(n: u64) -> ApproximateReal {
+        ApproximateReal::from(Integer::from(n))
+    }
+}
+
+impl ApproximateReal {
+    pub(crate) const ZERO: ApproximateReal = ApproximateReal {
+        mantissa: Integer::ZERO,
+        radius: Natural::ZERO,
+        exponent: 0,
+    };
+
+    pub(crate) const ONE: ApproximateReal = ApproximateReal {
+        mantissa: Integer::ONE,
+        radius: Natural::ZERO,
+        exponent: 0,
+    };
+
+    // Returns some $t$ such that $|x| < 2^t$ for every $x$ in the interval.
+    fn magnitude(&self) -> i64 {
+        self.exponent
+            + i64::exact_from((self.mantissa.unsigned_abs_ref() + &self.radius).significant_bits())
+    }
+
+    fn normalize(mut self, prec: u64) -> ApproximateReal {
+        let bits = self.mantissa.significant_bits();
+        if bits > prec {
+            let shift = bits - prec;
+            self.mantissa >>= shift;
+            // One unit for the rounding of the radius, and one for the truncation of the mantissa.
+            self.radius >>= shift;
+            self.radius += Natural::TWO;
+            self.exponent += i64::exact_from(shift);
+        }
+        self
+    }
+
+    // Widens the interval by $2^k$ on either side. If $2^k$ is smaller than a unit in the last
+    // place, the mantissa is extended first.
+    pub(crate) fn add_error(mut self, k: i64) -> ApproximateReal {
+        if k < self.exponent {
+            let shift = u64::exact_from(self.exponent - k);
+            self.mantissa <<= shift;
+            self.radius <<= shift;
+            self.exponent = k;
+        }
+        self.radius += Natural::ONE << u64::exact_from(k - self.exponent);
+        self
+    }
+
+    // Returns whether the interval is exactly zero.
+    #[inline]
+    pub(crate) fn is_zero(&self) -> bool {
+        self.mantissa == 0u32 && self.radius == 0u32
+    }
+
+    #[inline]
+    pub(crate) fn negate(self) -> ApproximateReal {
+        ApproximateReal {
+            mantissa: -self.mantissa,
+            radius: self.radius,
+            exponent: self.exponent,
+        }
+    }
+
+    // Multiplies by $2^k$. This is exact.
+    #[inline]
+    pub(crate) fn mul_power_of_2(self, k: i64) -> ApproximateReal {
+        ApproximateReal {
+            mantissa: self.mantissa,
+            radius: self.radius,
+            exponent: self.exponent + k,
+        }
+    }
+
+    pub(crate) fn add(&self, other: &ApproximateReal, prec: u64) -> ApproximateReal {
+        if self.is_zero() {
+            return other.clone().normalize(prec);
+        } else if other.is_zero() {
+            return self.clone().normalize(prec);
+        }
+        let (hi, lo) = if self.exponent >= other.exponent {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        // If `lo` is far below the precision of the result, it only widens the interval.
+        let hi_magnitude = hi.magnitude();
+        if lo.magnitude() + i64::exact_from(prec) + 2 < hi_magnitude {
+            return hi
+                .clone()
+                .add_error(hi_magnitude - i64::exact_from(prec) - 2)
+                .normalize(prec);
+        }
+        let shift = u64::exact_from(hi.exponent - lo.exponent);
+        ApproximateReal {
+            mantissa: (&hi.mantissa << shift) + &lo.mantissa,
+            radius: (&hi.radius << shift) + &lo.radius,
+            exponent: lo.exponent,
+        }
+        .normalize(prec)
+    }
+
+    #[inline]
+    pub(crate) fn sub(&self, other: &ApproximateReal, prec: u64) -> ApproximateReal {
+        self.add(&other.clone().negate(), prec)
+    }
+
+    pub(crate) fn mul(&self, other: &ApproximateReal, prec: u64) -> ApproximateReal {
+        // $|(m_1 + d_1)(m_2 + d_2) - m_1m_2| \leq |m_1|r_2 + |m_2|r_1 + r_1r_2$
+        let radius = self.mantissa.unsigned_abs_ref() * &other.radius
+            + other.mantissa.unsigned_abs_ref() * &self.radius
+            + &self.radius * &other.radius;
+        ApproximateReal {
+            mantissa: &self.mantissa * &other.mantissa,
+            radius,
+            exponent: self.exponent + other.exponent,
+        }
+        .normalize(prec)
+    }
+
+    // Panics if the interval of `other` contains zero.
+    pub(crate) fn div(&self, other: &ApproximateReal, prec: u64) -> ApproximateReal {
+        let m_1 = self.mantissa.unsigned_abs_ref();
+        let m_2 = other.mantissa.unsigned_abs_ref();
+        assert!(*m_2 > other.radius);
+        let shift = u64::exact_from(max(
+            0,
+            i64::exact_from(prec + m_2.significant_bits())
+                - i64::exact_from(m_1.significant_bits())
+                + 1,
+        ));
+        // $|(m_1 + d_1)/(m_2 + d_2) - m_1/m_2| \leq (|m_2|r_1 + |m_1|r_2)/(|m_2|(|m_2| - r_2))$,
+        // plus one unit for the truncation of the quotient.
+        let radius = if self.radius == 0u32 && other.radius == 0u32 {
+            Natural::ONE
+        } else {
+            ((m_2 * &self.radius + m_1 * &other.radius) << shift) / (m_2 * (m_2 - &other.radius))
+                + Natural::TWO
+        };
+        ApproximateReal {
+            mantissa: (&self.mantissa << shift) / &other.mantissa,
+            radius,
+            exponent: self.exponent - other.exponent - i64::exact_from(shift),
+        }
+        .normalize(prec)
+    }
+
+    pub(crate) fn pow(&self, mut exp: u64, prec: u64) -> ApproximateReal {
+        let working_prec = prec + (exp.significant_bits() << 1) + 2;
+        let mut power = self.clone().normalize(working_prec);
+        let mut result = ApproximateReal::ONE;
+        while exp != 0 {
+            if exp.odd() {
+                result = result.mul(&power, working_prec);
+            }
+            exp >>= 1;
+            if exp != 0 {
+                power = power.mul(&power, working_prec);
+            }
+        }
+        result.normalize(prec)
+    }
+
+    // Panics if the interval contains any nonpositive numbers, unless it is exactly zero.
+    pub(crate) fn sqrt(&self, prec: u64) -> ApproximateReal {
+        if self.is_zero() {
+            return ApproximateReal::ZERO;
+        }
+        assert!(self.mantissa > 0u32);
+        let m = self.mantissa.unsigned_abs_ref();
+        assert!(*m > self.radius);
+        let bits = i64::exact_from(m.significant_bits());
+        let mut shift = max(0, i64::exact_from(prec << 1) + 2 - bits);
+        if (self.exponent - shift).odd() {
+            shift += 1;
+        }
+        let shift_u = u64::exact_from(shift);
+        let m = m << shift_u;
+        // $|\sqrt{m + d} - \sqrt{m}| = |d|/(\sqrt{m + d} + \sqrt{m}) \leq r/\sqrt{m - r}$, plus one
+        // unit for the truncation of the square root.
+        let radius = if self.radius == 0u32 {
+            Natural::ONE
+        } else {
+            let r = &self.radius << shift_u;
+            &r / (&m - &r).floor_sqrt() + Natural::TWO
+        };
+        ApproximateReal {
+            mantissa: Integer::from(m.floor_sqrt()),
+            radius,
+            exponent: (self.exponent - shift) >> 1,
+        }
+        .normalize(prec)
+    }
+
+    // Computes $e^x$, squaring a Taylor series evaluated at $y = x/2^j$ $j$ times. Since $|y| \leq
+    // 2^{-8}$, the neglected tail of the series is at most twice its first term.
+    pub(crate) fn exp(&self, prec: u64) -> ApproximateReal {
+        if self.is_zero() {
+            return ApproximateReal::ONE;
+        }
+        let j = max(0, self.magnitude() + 8);
+        let working_prec = prec + u64::exact_from(j) + (prec.significant_bits() << 1) + 8;
+        let y = self.clone().normalize(working_prec).mul_power_of_2(-j);
+        let mut sum = ApproximateReal::ONE;
+        let mut term = ApproximateReal::ONE;
+        let mut k = 1;
+        let threshold = -i64::exact_from(working_prec);
+        loop {
+            term = term.mul(&y, working_prec).div_u64(k, working_prec);
+            let term_magnitude = term.magnitude();
+            if term_magnitude < threshold {
+                sum = sum.add_error(term_magnitude + 1);
+                break;
+            }
+            sum = sum.add(&term, working_prec);
+            k += 1;
+        }
+        for _ in 0..j {
+            sum = sum.mul(&sum, working_prec);
+        }
+        sum.normalize(prec)
+    }
+
+    // Computes $\cos x$, evaluating a Taylor series at $y = x/2^j$ and applying the double-angle
+    // formula $j$ times. Since $|y| \leq 2^{-8}$, the series alternates with decreasing terms, and
+    // the neglected tail is at most its first term. Meant for small arguments.
+    pub(crate) fn cos(&self, prec: u64) -> ApproximateReal {
+        if self.is_zero() {
+            return ApproximateReal::ONE;
+        }
+        let j = max(0, self.magnitude() + 8);
+        let working_prec = prec + (u64::exact_from(j) << 1) + (prec.significant_bits() << 1) + 8;
+        let y = self.clone().normalize(working_prec).mul_power_of_2(-j);
+        let y_squared = y.mul(&y, working_prec).negate();
+        let mut sum = ApproximateReal::ONE;
+        let mut term = ApproximateReal::ONE;
+        let mut k = 1;
+        let threshold = -i64::exact_from(working_prec);
+        loop {
+            term = term
+                .mul(&y_squared, working_prec)
+                .div_u64(k * (k + 1), working_prec);
+            let term_magnitude = term.magnitude();
+            if term_magnitude < threshold {
+                sum = sum.add_error(term_magnitude);
+                break;
+            }
+            sum = sum.add(&term, working_prec);
+            k += 2;
+        }
+        for _ in 0..j {
+            sum = sum
+                .mul(&sum, working_prec)
+                .mul_power_of_2(1)
+                .sub(&ApproximateReal::ONE, working_prec);
+        }
+        sum.normalize(prec)
+    }
+
+    pub(crate) fn div_u64(&self, d: u64, prec: u64) -> ApproximateReal {
+        self.div(&ApproximateReal::from(d), prec)
+    }
+
+    pub(crate) fn mul_u64(&self, m: u64, prec: u64) -> ApproximateReal {
+        self.mul(&ApproximateReal::from(m), prec)
+    }
+
+    // Computes $\pi$ using Machin's formula, $\pi = 16 \arctan \frac{1}{5} - 4 \arctan
+    // \frac{1}{239}$, in fixed-point arithmetic with $w$ fractional bits.
+    //
+    // In the evaluation of $2^w \arctan \frac{1}{x}$, each power $\lfloor 2^w/x^{2i+1} \rfloor$ is
+    // off by less than $\sum_i x^{-2i} < 2$, so each term is off by less than 3, and once the
+    // powers reach zero, the alternating tail is less than 2.
+    pub(crate) fn pi(prec: u64) -> ApproximateReal {
+        let working_prec = prec + (prec.significant_bits() << 1) + 8;
+        let arctan_inverse = |x: u64| {
+            let x_squared = Natural::from(x * x);
+            let mut power = (Natural::ONE << working_prec) / Natural::from(x);
+            let mut sum = Integer::ZERO;
+            let mut k = 1u64;
+            let mut positive = true;
+            let mut radius = Natural::TWO;
+            while power != 0u32 {
+                let term = Integer::from(&power / Natural::from(k));
+                if positive {
+                    sum += term;
+                } else {
+                    sum -= term;
+                }
+                radius += Natural::from(3u32);
+                power /= &x_squared;
+                k += 2;
+                positive = !positive;
+            }
+            (sum, radius)
+        };
+        let (arctan_5, radius_5) = arctan_inverse(5);
+        let (arctan_239, radius_239) = arctan_inverse(239);
+        ApproximateReal {
+            mantissa: (arctan_5 << 4u32) - (arctan_239 << 2u32),
+            radius: (radius_5 << 4u32) + (radius_239 << 2u32),
+            exponent: -i64::exact_from(working_prec),
+        }
+        .normalize(prec)
+    }
+
+    // Returns the integer nearest to every number in the interval, with ties rounded up, or `None`
+    // if there is no such integer. In particular, if the interval is known to contain an integer,
+    // and this function returns `Some`, the result is that integer.
+    pub(crate) fn round(&self) -> Option<Integer> {
+        if self.exponent >= 0 {
+            if self.radius == 0u32 {
+                Some(&self.mantissa << u64::exact_from(self.exponent))
+            } else {
+                None
+            }
+        } else {
+            let shift = u64::exact_from(-self.exponent);
+            let half = Integer::ONE << (shift - 1);
+            let radius = Integer::from(&self.radius);
+            let lo = (&self.mantissa - &radius + &half) >> shift;
+            let hi = (&self.mantissa + radius + half) >> shift;
+            if lo == hi {
+                Some(lo)
+            } else {
+                None
+            }
+        }
+    }
+}
+
+// Computes $\prod_p (1 - \chi(p)p^{-s})$, the reciprocal of the Euler product of the Dirichlet
+// $L$-function $L(s, \chi)$, where $\chi(p) \in \\{-1, 0, 1\\}$ and $s > 1$. With $\chi = 1$,
+// this is $1/\zeta(s)$.
+//
+// The product is truncated after the primes up to $P = 2^k$, where $k = \lceil p/(s-1) \rceil$.
+// Writing $t = \sum_{n > P} n^{-s} < P^{1-s}/(s-1) \leq 2^{-p}$, the neglected factors have a
+// product within $e^{2t} - 1 \leq 4t$ of 1, and the truncated product is less than $\zeta(2) <
+// 2$, so the truncation changes the result by less than $2^{3-p}$.
+pub(crate) fn inverse_euler_product(s: u64, prec: u64, chi: impl Fn(u64) -> i8) -> ApproximateReal {
+    assert!(s > 1);
+    let k = prec.div_ceil(s - 1);
+    assert!(k < u64::WIDTH);
+    let bound: u64 = 1 << k;
+    let working_prec = prec + (k << 1) + 8;
+    let mut product = ApproximateReal::ONE;
+    for p in u64::primes_less_than_or_equal_to(&bound) {
+        let sign = chi(p);
+        if sign == 0 {
+            continue;
+        }
+        let term = product.div(
+            &ApproximateReal::from(Natural::from(p).pow(s)),
+            working_prec,
+        );
+        product = if sign > 0 {
+            product.sub(&term, working_prec)
+        } else {
+            product.add(&term, working_prec)
+        };
+    }
+    product.add_error(3 - i64::exact_from(prec)).normalize(prec)
+}
+
+// Returns the zigzag numbers $A_0, A_1, \ldots, A_n$, where $A_n$ counts the alternating
+// permutations of $n$ elements, using Seidel's boustrophedon triangle. The zigzag numbers of even
+// index are the absolute values of the Euler numbers, and those of odd index are the tangent
+// numbers, from which Bernoulli numbers follow.
+#[doc(hidden)]
+pub fn zigzag_numbers(n: u64) -> Vec<Natural> {
+    let mut zigzags = Vec::with_capacity(usize::exact_from(n) + 1);
+    zigzags.push(Natural::ONE);
+    let mut previous_row = vec![Natural::ONE];
+    for i in 1..=usize::exact_from(n) {
+        let mut row = Vec::with_capacity(i + 1);
+        row.push(Natural::ZERO);
+        for k in 1..=i {
+            let entry = &row[k - 1] + &previous_row[i - k];
+            row.push(entry);
+        }
+        zigzags.push(row[i].clone());
+        previous_row = row;
+    }
+    zigzags
+}
+
+// Computes $|B_n| D$ for even $n > 0$, where $D$ is the denominator of the Bernoulli number $B_n$,
+// using
+// $$
+// |B_n| = \frac{2 n!}{(2\pi)^n} \zeta(n),
+// $$
+// where $1/\zeta(n) = \prod_p (1 - p^{-n})$. Since $|B_n| D$ is an integer, enough precision to
+// round it correctly suffices; since $\zeta(n)$ is very close to 1 for large $n$, only a few primes
+// are needed. If the precision turns out to be insufficient, the computation is repeated with
+// more.
+//
+// This lives here, rather than in malachite-q, so that `ApproximateReal` can stay private.
+#[doc(hidden)]
+pub fn bernoulli_abs_numerator(n: u64, denominator: &Natural) -> Natural {
+    let numerator = (Natural::factorial(n) * denominator) << 1u32;
+    // Estimate the number of bits of the result.
+    #[allow(clippy::cast_precision_loss)]
+    let two_pi_bits = n as f64 * LOG_2_TWO_PI;
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let bits = numerator
+        .significant_bits()
+        .saturating_sub(two_pi_bits as u64);
+    let numerator = ApproximateReal::from(numerator);
+    let mut guard_bits = (n.significant_bits() << 1) + 32;
+    loop {
+        let prec = bits + guard_bits;
+        let two_pi_power = ApproximateReal::pi(prec).mul_power_of_2(1).pow(n, prec);
+        let zeta_inverse = inverse_euler_product(n, prec, |_| 1);
+        if let Some(result) = numerator
+            .div(&two_pi_power.mul(&zeta_inverse, prec), prec)
+            .round()
+        {
+            return Natural::exact_from(result);
+        }
+        guard_bits <<= 1;
+    }
+}
@@ -0,0 +1,75 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{Bell, DivExact, DivExactAssign, Factorial, Pow};
+use malachite_base::num::basic::traits::{One, Zero};
+
+impl Bell for Natural {
+    /// Computes the $n$th Bell number.
+    ///
+    /// $f(n) = B_n = \sum_{k=0}^n \left\\{{n \atop k}\right\\}$, the number of partitions of a
+    /// set of $n$ elements.
+    ///
+    /// The Bell numbers are computed using a finite form of Dobiński's formula,
+    /// $$
+    /// B_n = \frac{1}{n!} \sum_{j=0}^n \binom{n}{j} D_{n-j} j^n,
+    /// $$
+    /// where $D_m$ is the $m$th subfactorial, so that only $O(n)$ large multiplications are needed.
+    ///
+    /// $B_n = O((n/\log n)^n)$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Bell;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::bell(0), 1);
+    /// assert_eq!(Natural::bell(1), 1);
+    /// assert_eq!(Natural::bell(5), 52);
+    /// assert_eq!(Natural::bell(10), 115975);
+    /// assert_eq!(
+    ///     Natural::bell(50).to_string(),
+    ///     "185724268771078270438257767181908917499221852770"
+    /// );
+    /// ```
+    fn bell(n: u64) -> Natural {
+        if n == 0 {
+            return Natural::ONE;
+        }
+        // Iterate $j$ downwards from $n$, so that $D_{n-j}$ can be computed by the recurrence
+        // $D_m = mD_{m-1} + (-1)^m$ and $\binom{n}{j}$ by $\binom{n}{j-1} = \binom{n}{j}j/(n-j+1)$.
+        // Since $D_1 = 0$, the $j = n - 1$ term vanishes, and the $j = 0$ term vanishes because
+        // $n > 0$.
+        let mut sum = Natural::ZERO;
+        let mut binomial = Natural::ONE;
+        let mut subfactorial = Natural::ONE;
+        for j in (1..=n).rev() {
+            let m = n - j;
+            if m != 0 {
+                subfactorial *= Natural::from(m);
+                if m & 1 == 0 {
+                    subfactorial += Natural::ONE;
+                } else {
+                    subfactorial -= Natural::ONE;
+                }
+            }
+            if subfactorial != 0u32 {
+                sum += &binomial * &subfactorial * Natural::from(j).pow(n);
+            }
+            binomial *= Natural::from(j);
+            binomial.div_exact_assign(Natural::from(m + 1));
+        }
+        sum.div_exact(Natural::factorial(n))
+    }
+}
@@ -0,0 +1,567 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::natural::arithmetic::crt::crt_helper;
+use crate::natural::Natural;
+use crate::platform::Limb;
+use alloc::vec::Vec;
+use core::cmp::min;
+use malachite_base::num::arithmetic::traits::{
+    DivAssignMod, DivMod, DivRound, DivisibleBy, FloorSqrt, ModAdd, ModAddAssign, ModInverse,
+    ModMul, ModMulAssign, ModMulPrecomputed, ModMulPrecomputedAssign, ModNeg, ModNegAssign, ModSub,
+    Parity, Pow, RoundToMultiple,
+};
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::traits::{One, Two, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::*;
+use malachite_base::num::factorization::traits::Factor;
+
+// Up to this modulus, the products of the integers less than $p^e$ that are not divisible by $p$
+// are tabulated; above it, they are computed when needed.
+const P_FREE_PRODUCT_TABLE_LIMIT: u64 = 1 << 20;
+
+// Below this many factors, a product of consecutive integers is computed one factor at a time.
+const RANGE_PRODUCT_FAST_THRESHOLD: u64 = 1 << 10;
+
+// Below this length, polynomials are multiplied using schoolbook multiplication.
+const POLY_MUL_KRONECKER_THRESHOLD: usize = 8;
+
+// Below this many points, a polynomial is evaluated at each point using Horner's rule.
+const MULTIPOINT_EVALUATION_THRESHOLD: usize = 8;
+
+// The polynomials below have coefficients modulo $m$, stored in ascending order of degree. Each
+// coefficient is less than $m$.
+
+fn poly_mul_schoolbook(xs: &[Natural], ys: &[Natural], m: &Natural) -> Vec<Natural> {
+    let mut out = vec![Natural::ZERO; xs.len() + ys.len() - 1];
+    for (i, x) in xs.iter().enumerate() {
+        for (o, y) in out[i..].iter_mut().zip(ys.iter()) {
+            *o += x * y;
+        }
+    }
+    for o in &mut out {
+        *o %= m;
+    }
+    out
+}
+
+// Multiplies two polynomials modulo $m$ using Kronecker substitution: each polynomial is packed
+// into a single `Natural`, with every coefficient given enough limbs that the coefficients of the
+// product cannot overlap, and the product is unpacked.
+fn poly_mul(xs: &[Natural], ys: &[Natural], m: &Natural) -> Vec<Natural> {
+    if xs.is_empty() || ys.is_empty() {
+        return Vec::new();
+    }
+    let min_len = min(xs.len(), ys.len());
+    if min_len < POLY_MUL_KRONECKER_THRESHOLD {
+        return poly_mul_schoolbook(xs, ys, m);
+    }
+    let bits = (m.significant_bits() << 1) + u64::exact_from(min_len).significant_bits();
+    let slot = usize::exact_from(bits.div_round(Limb::WIDTH, Ceiling).0);
+    let pack = |zs: &[Natural]| {
+        let mut limbs = vec![0; zs.len() * slot];
+        for (chunk, z) in limbs.chunks_mut(slot).zip(zs.iter()) {
+            for (l, z) in chunk.iter_mut().zip(z.limbs()) {
+                *l = z;
+            }
+        }
+        Natural::from_owned_limbs_asc(limbs)
+    };
+    let len = xs.len() + ys.len() - 1;
+    let mut out: Vec<Natural> = (pack(xs) * pack(ys))
+        .into_limbs_asc()
+        .chunks(slot)
+        .take(len)
+        .map(|chunk| Natural::from_limbs_asc(chunk) % m)
+        .collect();
+    out.resize(len, Natural::ZERO);
+    out
+}
+
+// Returns $g$ such that $gh \equiv 1 \pmod {x^n}$, where the constant term of $h$ is 1, using
+// Newton iteration.
+fn poly_inverse(hs: &[Natural], n: usize, m: &Natural) -> Vec<Natural> {
+    let mut gs = vec![Natural::ONE];
+    let mut k = 1;
+    while k < n {
+        k = min(k << 1, n);
+        // g <- g(2 - hg)
+        let mut es = poly_mul(&hs[..min(k, hs.len())], &gs, m);
+        es.truncate(k);
+        for e in &mut es {
+            e.mod_neg_assign(m);
+        }
+        es[0].mod_add_assign(Natural::TWO % m, m);
+        gs = poly_mul(&gs, &es, m);
+        gs.truncate(k);
+    }
+    gs
+}
+
+// Returns the remainder of $a$ divided by the monic polynomial $b$.
+fn poly_rem_monic(xs: &[Natural], ys: &[Natural], m: &Natural) -> Vec<Natural> {
+    let d = ys.len() - 1;
+    if xs.len() <= d {
+        return xs.to_vec();
+    }
+    // The reversal of the quotient is the reversal of $a$ divided by the reversal of $b$, modulo
+    // $x^{\deg a - \deg b + 1}$.
+    let q_len = xs.len() - d;
+    let ys_rev: Vec<Natural> = ys.iter().rev().cloned().collect();
+    let xs_rev: Vec<Natural> = xs.iter().rev().take(q_len).cloned().collect();
+    let mut qs = poly_mul(&xs_rev, &poly_inverse(&ys_rev, q_len, m), m);
+    qs.truncate(q_len);
+    qs.reverse();
+    let qys = poly_mul(&qs, ys, m);
+    xs[..d]
+        .iter()
+        .zip(qys.iter())
+        .map(|(x, qy)| x.mod_sub(qy, m))
+        .collect()
+}
+
+fn poly_evaluate(xs: &[Natural], x: &Natural, m: &Natural) -> Natural {
+    let mut out = Natural::ZERO;
+    for c in xs.iter().rev() {
+        out = out.mod_mul(x, m).mod_add(c, m);
+    }
+    out
+}
+
+// Multiplies adjacent pairs of polynomials.
+fn poly_product_level(polys: &[Vec<Natural>], m: &Natural) -> Vec<Vec<Natural>> {
+    polys
+        .chunks(2)
+        .map(|pair| {
+            if pair.len() == 2 {
+                poly_mul(&pair[0], &pair[1], m)
+            } else {
+                pair[0].clone()
+            }
+        })
+        .collect()
+}
+
+// Evaluates a polynomial at many points, using a subproduct tree of the $x - x_i$ and a remainder
+// tree.
+fn poly_multipoint_evaluate(xs: &[Natural], points: &[Natural], m: &Natural) -> Vec<Natural> {
+    if points.len() < MULTIPOINT_EVALUATION_THRESHOLD {
+        return points.iter().map(|x| poly_evaluate(xs, x, m)).collect();
+    }
+    let mut tree: Vec<Vec<Vec<Natural>>> = vec![points
+        .iter()
+        .map(|x| vec![x.mod_neg(m), Natural::ONE])
+        .collect()];
+    while tree.last().unwrap().len() > 1 {
+        let level = poly_product_level(tree.last().unwrap(), m);
+        tree.push(level);
+    }
+    let mut remainders = vec![poly_rem_monic(xs, &tree.last().unwrap()[0], m)];
+    for level in tree.iter().rev().skip(1) {
+        remainders = level
+            .iter()
+            .enumerate()
+            .map(|(i, ys)| poly_rem_monic(&remainders[i >> 1], ys, m))
+            .collect();
+    }
+    remainders
+        .into_iter()
+        .map(|r| r.into_iter().next().unwrap_or(Natural::ZERO))
+        .collect()
+}
+
+// Returns $\prod_{i=1}^l (a + i) \bmod m$, leaving out the factors divisible by $p$ if $p$ is
+// given. In that case, $p$ must divide $a$.
+//
+// For large $l$, a block length $v \approx \sqrt l$ is chosen, rounded up to a multiple of $p$ if
+// $p$ is given, and $f(x) = \prod_{1 \leq i \leq v, p \nmid i} (x + i)$ is evaluated at $a, a + v,
+// a + 2v, \ldots$ using fast multipoint evaluation. Up to logarithmic factors, this takes time
+// proportional to $\max(\sqrt l, p)$ rather than to $l$.
+fn range_product_mod(a: &Natural, l: u64, p: Option<&Natural>, m: &Natural) -> Natural {
+    let p = p.filter(|&p| *p <= l).map(u64::exact_from);
+    let is_factor = |i: u64| p.is_none_or(|p| !i.divisible_by(p));
+    if l < RANGE_PRODUCT_FAST_THRESHOLD {
+        let mut product = Natural::ONE % m;
+        let mut x = a % m;
+        for i in 1..=l {
+            x.mod_add_assign(Natural::ONE % m, m);
+            if is_factor(i) {
+                product.mod_mul_assign(&x, m);
+            }
+        }
+        return product;
+    }
+    let mut v = l.floor_sqrt();
+    if let Some(p) = p {
+        v = v.round_to_multiple(p, Ceiling).0;
+    }
+    let blocks = l / v;
+    let mut polys: Vec<Vec<Natural>> = (1..=v)
+        .filter(|&i| is_factor(i))
+        .map(|i| vec![Natural::from(i) % m, Natural::ONE % m])
+        .collect();
+    while polys.len() > 1 {
+        polys = poly_product_level(&polys, m);
+    }
+    let points: Vec<Natural> = (0..blocks)
+        .map(|j| (a + Natural::from(j * v)) % m)
+        .collect();
+    let mut product = range_product_mod(
+        &(a + Natural::from(blocks * v)),
+        l - blocks * v,
+        p.map(Natural::from).as_ref(),
+        m,
+    );
+    for y in poly_multipoint_evaluate(&polys[0], &points, m) {
+        product.mod_mul_assign(y, m);
+    }
+    product
+}
+
+// Computes $\binom{n}{k} \bmod p$, where $n, k < p$, as $n(n-1)\cdots(n-j+1)/j!$, where $j =
+// \min(k, n - k)$.
+fn binomial_coefficient_mod_prime_digit(n: &Natural, k: &Natural, p: &Natural) -> Natural {
+    if k > n {
+        return Natural::ZERO;
+    }
+    let j = min(k.clone(), n - k);
+    let j_64 = u64::exact_from(&j);
+    let numerator = range_product_mod(&(n - j), j_64, None, p);
+    let denominator = range_product_mod(&Natural::ZERO, j_64, None, p);
+    numerator.mod_mul(denominator.mod_inverse(p).unwrap(), p)
+}
+
+// Computes $\binom{n}{k} \bmod p$ using Lucas's theorem: if $n = \sum_i n_ip^i$ and $k = \sum_i
+// k_ip^i$ in base $p$, then $\binom{n}{k} \equiv \prod_i \binom{n_i}{k_i} \pmod p$.
+fn binomial_coefficient_mod_prime(n: &Natural, k: &Natural, p: &Natural) -> Natural {
+    let mut n = n.clone();
+    let mut k = k.clone();
+    let mut result = Natural::ONE;
+    while k != 0u32 {
+        let n_digit = n.div_assign_mod(p);
+        let k_digit = k.div_assign_mod(p);
+        result = result.mod_mul(
+            binomial_coefficient_mod_prime_digit(&n_digit, &k_digit, p),
+            p,
+        );
+        if result == 0u32 {
+            break;
+        }
+    }
+    result
+}
+
+// Computes factorials modulo $p^e$ with the factors of $p$ removed, using the products $F(r) =
+// \prod_{1 \leq i \leq r, p \nmid i} i \bmod p^e$ for $r < p^e$.
+struct PFreeFactorials<'a> {
+    p: &'a Natural,
+    pe: &'a Natural,
+    // By the generalization of Wilson's theorem, $F(p^e - 1) \equiv -1 \pmod {p^e}$, unless $p = 2$
+    // and $e \geq 3$, in which case $F(p^e - 1) \equiv 1 \pmod {p^e}$.
+    wilson_negative: bool,
+    table: Option<Vec<u64>>,
+}
+
+impl<'a> PFreeFactorials<'a> {
+    fn new(p: &'a Natural, e: u64, pe: &'a Natural) -> PFreeFactorials<'a> {
+        let table = u64::try_from(pe)
+            .ok()
+            .filter(|&pe| pe <= P_FREE_PRODUCT_TABLE_LIMIT)
+            .map(|pe| {
+                let p = u64::exact_from(p);
+                let mut table = Vec::with_capacity(usize::exact_from(pe));
+                let mut product = 1;
+                table.push(product);
+                for i in 1..pe {
+                    if i % p != 0 {
+                        product = product * i % pe;
+                    }
+                    table.push(product);
+                }
+                table
+            });
+        PFreeFactorials {
+            p,
+            pe,
+            wilson_negative: *p != 2u32 || e < 3,
+            table,
+        }
+    }
+
+    // Returns $F(r)$, where $r < p^e$.
+    fn p_free_product(&self, r: &Natural) -> Natural {
+        if let Some(table) = &self.table {
+            Natural::from(table[usize::exact_from(r)])
+        } else {
+            range_product_mod(&Natural::ZERO, u64::exact_from(r), Some(self.p), self.pe)
+        }
+    }
+
+    // Returns $x!/p^v \bmod p^e$ and $v$, where $p^v$ is the largest power of $p$ dividing $x!$.
+    // Removing the multiples of $p$ from $x!$ leaves $F(x)$, and the multiples themselves are $p^{
+    // \lfloor x/p \rfloor} \lfloor x/p \rfloor!$. $F(x) \equiv F(p^e - 1)^{\lfloor x/p^e \rfloor}
+    // F(x \bmod p^e)$, so only $F(r)$ for $r < p^e$ is needed.
+    fn p_free_factorial(&self, x: &Natural) -> (Natural, Natural) {
+        let mut x = x.clone();
+        let mut result = Natural::ONE;
+        let mut v = Natural::ZERO;
+        let mut negate = false;
+        while x != 0u32 {
+            let (q, r) = (&x).div_mod(self.pe);
+            if self.wilson_negative && q.odd() {
+                negate = !negate;
+            }
+            result = result.mod_mul(self.p_free_product(&r), self.pe);
+            x /= self.p;
+            v += &x;
+        }
+        if negate {
+            result = result.mod_neg(self.pe);
+        }
+        (result, v)
+    }
+}
+
+// Computes the multinomial coefficient $\binom{n}{k_1, k_2, \ldots}$ modulo $p^e$, where $n = \sum_i
+// k_i$. When $e = 1$, the multinomial coefficient is written as a product of binomial coefficients
+// and Lucas's theorem is used. Otherwise, Granville's generalization of Lucas's theorem is used, in
+// the form $\binom{n}{k_1, k_2, \ldots} = p^v (n!)_p / \prod_i (k_i!)_p$, where $(x!)_p$ is $x!$
+// with the factors of $p$ removed.
+fn multinomial_mod_prime_power(
+    ks: &[Natural],
+    n: &Natural,
+    p: &Natural,
+    e: u64,
+    pe: &Natural,
+) -> Natural {
+    if e == 1 {
+        let mut result = Natural::ONE;
+        let mut sum = Natural::ZERO;
+        for k in ks {
+            sum += k;
+            result = result.mod_mul(binomial_coefficient_mod_prime(&sum, k, p), p);
+            if result == 0u32 {
+                break;
+            }
+        }
+        return result;
+    }
+    let factorials = PFreeFactorials::new(p, e, pe);
+    let (numerator, mut v) = factorials.p_free_factorial(n);
+    let mut denominator = Natural::ONE;
+    for k in ks {
+        let (f, w) = factorials.p_free_factorial(k);
+        denominator = denominator.mod_mul(f, pe);
+        v -= w;
+    }
+    if v >= e {
+        return Natural::ZERO;
+    }
+    numerator
+        .mod_mul(denominator.mod_inverse(pe).unwrap(), pe)
+        .mod_mul(p.pow(u64::exact_from(&v)), pe)
+}
+
+fn multinomial_mod_helper(ks: &[Natural], m: &Natural) -> Natural {
+    assert_ne!(*m, 0u32, "The modulus cannot be zero");
+    if *m == 1u32 {
+        return Natural::ZERO;
+    }
+    let n: Natural = ks.iter().sum();
+    let factors = m.factor();
+    let prime_powers: Vec<Natural> = factors.iter().map(|(p, e)| p.pow(*e)).collect();
+    crt_helper(
+        factors
+            .iter()
+            .zip(prime_powers.iter())
+            .map(|((p, e), pe)| (multinomial_mod_prime_power(ks, &n, p, *e, pe), pe)),
+    )
+    .unwrap()
+    .0
+}
+
+impl Natural {
+    /// Computes the binomial coefficient $\binom{n}{k}$ modulo a [`Natural`] $m$.
+    ///
+    /// Unlike [`binomial_coefficient`](malachite_base::num::arithmetic::traits::BinomialCoefficient),
+    /// this never computes the binomial coefficient itself, so $n$ and $k$ may be huge.
+    ///
+    /// $f(n, k, m) = \binom{n}{k} \bmod m$.
+    ///
+    /// $m$ is factored, and the result is computed modulo each prime power $p^e$ dividing $m$ and
+    /// combined using the Chinese remainder theorem. Modulo a prime, Lucas's theorem is used;
+    /// modulo a higher prime power, Granville's generalization of it is used. Either way, the
+    /// products of consecutive integers that are needed, of which there may be nearly $q$, are
+    /// computed by evaluating a polynomial of degree about $\sqrt q$ at about $\sqrt q$ points.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m, q) = O(n m \sqrt{q} (\log q)^2 \log\log q)$, plus the time needed to factor $m$
+    ///
+    /// $M(n, m, q) = O(n + m + \sqrt{q} (\log q)^2)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `n.significant_bits()`, $m$ is
+    /// `m.significant_bits()`, and $q$ is the largest prime power dividing $m$.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Natural::binomial_coefficient_mod(
+    ///         &Natural::from(10u32),
+    ///         &Natural::from(3u32),
+    ///         &Natural::from(7u32)
+    ///     ),
+    ///     1
+    /// );
+    /// assert_eq!(
+    ///     Natural::binomial_coefficient_mod(
+    ///         &Natural::from(3u32),
+    ///         &Natural::from(10u32),
+    ///         &Natural::from(7u32)
+    ///     ),
+    ///     0
+    /// );
+    /// assert_eq!(
+    ///     Natural::binomial_coefficient_mod(
+    ///         &Natural::from(10u32).pow(100),
+    ///         &Natural::from(5u32),
+    ///         &Natural::from(1000000007u32)
+    ///     ),
+    ///     452885011
+    /// );
+    /// assert_eq!(
+    ///     Natural::binomial_coefficient_mod(
+    ///         &Natural::from(10u32).pow(100),
+    ///         &Natural::from(100u32),
+    ///         &Natural::from(12192768u32)
+    ///     ),
+    ///     3686400
+    /// );
+    /// ```
+    pub fn binomial_coefficient_mod(n: &Natural, k: &Natural, m: &Natural) -> Natural {
+        if k > n {
+            assert_ne!(*m, 0u32, "The modulus cannot be zero");
+            Natural::ZERO
+        } else {
+            multinomial_mod_helper(&[k.clone(), n - k], m)
+        }
+    }
+
+    /// Computes the multinomial coefficient $\binom{n}{k_1, k_2, \ldots, k_r}$ modulo a
+    /// [`Natural`] $m$, where $n = k_1 + k_2 + \cdots + k_r$.
+    ///
+    /// $$
+    /// f((k_1, \ldots, k_r), m) = \frac{(k_1 + \cdots + k_r)!}{k_1! \cdots k_r!} \bmod m.
+    /// $$
+    ///
+    /// $m$ is factored, and the result is computed modulo each prime power $p^e$ dividing $m$ and
+    /// combined using the Chinese remainder theorem. Modulo a prime, the multinomial coefficient
+    /// is written as a product of binomial coefficients and Lucas's theorem is used; modulo a
+    /// higher prime power, Granville's generalization of Lucas's theorem is used. Either way, the
+    /// products of consecutive integers that are needed, of which there may be nearly $q$, are
+    /// computed by evaluating a polynomial of degree about $\sqrt q$ at about $\sqrt q$ points.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m, q) = O(n m \sqrt{q} (\log q)^2 \log\log q)$, plus the time needed to factor $m$
+    ///
+    /// $M(n, m, q) = O(n + m + \sqrt{q} (\log q)^2)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the total number of significant bits of
+    /// the $k_i$, $m$ is `m.significant_bits()`, and $q$ is the largest prime power dividing $m$.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::multinomial_mod(&[], &Natural::from(10u32)), 1);
+    /// assert_eq!(
+    ///     Natural::multinomial_mod(
+    ///         &[Natural::from(2u32), Natural::from(3u32), Natural::from(4u32)],
+    ///         &Natural::from(1000u32)
+    ///     ),
+    ///     260
+    /// );
+    /// assert_eq!(
+    ///     Natural::multinomial_mod(
+    ///         &[Natural::from(10u32), Natural::from(10u32), Natural::from(10u32)],
+    ///         &Natural::from(1000000007u32)
+    ///     ),
+    ///     996752490
+    /// );
+    /// ```
+    #[inline]
+    pub fn multinomial_mod(ks: &[Natural], m: &Natural) -> Natural {
+        multinomial_mod_helper(ks, m)
+    }
+
+    /// Computes $n!$ modulo a [`Natural`] $m$.
+    ///
+    /// $f(n, m) = n! \bmod m$.
+    ///
+    /// If $n \geq m$, then $m$ divides $n!$ and the result is 0. Otherwise, if $n$ is small, the
+    /// factors are multiplied together in [`u64`]s as far as possible, and the [`u64`]s are
+    /// multiplied modulo $m$. If $n$ is large, $n!$ is split into about $\sqrt n$ blocks of about
+    /// $\sqrt n$ consecutive factors, and the products of the blocks are found by evaluating a
+    /// single polynomial at many points.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(\sqrt{n} (m + \log n) \log n \log (nm) \log\log (nm))$
+    ///
+    /// $M(n, m) = O(\sqrt{n} (m + \log n) \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `n`, and $m$ is `m.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::factorial_mod(0, &Natural::from(10u32)), 1);
+    /// assert_eq!(Natural::factorial_mod(5, &Natural::from(7u32)), 1);
+    /// assert_eq!(Natural::factorial_mod(6, &Natural::from(7u32)), 6);
+    /// assert_eq!(Natural::factorial_mod(10, &Natural::from(7u32)), 0);
+    /// assert_eq!(
+    ///     Natural::factorial_mod(100000, &Natural::from(1000000007u32)),
+    ///     457992974
+    /// );
+    /// ```
+    pub fn factorial_mod(n: u64, m: &Natural) -> Natural {
+        assert_ne!(*m, 0u32, "The modulus cannot be zero");
+        if *m <= n || *m == 1u32 {
+            return Natural::ZERO;
+        }
+        if n >= RANGE_PRODUCT_FAST_THRESHOLD {
+            return range_product_mod(&Natural::ZERO, n, None, m);
+        }
+        let data = ModMulPrecomputed::<Natural>::precompute_mod_mul_data(m);
+        let mut result = Natural::ONE;
+        let mut product: u64 = 1;
+        for i in 2..=n {
+            if let Some(p) = product.checked_mul(i) {
+                product = p;
+            } else {
+                result.mod_mul_precomputed_assign(Natural::from(product) % m, m, &data);
+                product = i;
+            }
+        }
+        result.mod_mul_precomputed_assign(Natural::from(product) % m, m, &data);
+        result
+    }
+}
@@ -0,0 +1,297 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::natural::arithmetic::crt::crt_helper;
+use crate::natural::Natural;
+use alloc::vec;
+use alloc::vec::Vec;
+use malachite_base::num::arithmetic::traits::{
+    CheckedSqrt, Cornacchia, DivMod, Gcd, ModPrimePowerSqrt, Pow, PowerOf2, Square,
+};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::factorization::traits::Factor;
+
+// Given a square root $r$ of $-d$ modulo $m$, runs the Euclidean algorithm on $m$ and $r$ until
+// the remainder $x$ satisfies $x^2 \leq m$, and then checks whether $(m - x^2) / d$ is a perfect
+// square $y^2$. If it is, $(x, y)$ is a primitive solution of $x^2 + dy^2 = m$. Every primitive
+// solution is found from one of the square roots of $-d$.
+pub(crate) fn cornacchia_with_root(
+    d: &Natural,
+    m: &Natural,
+    r: Natural,
+) -> Option<(Natural, Natural)> {
+    let mut a = m.clone();
+    let mut x = r;
+    while (&x).square() > *m {
+        let remainder = &a % &x;
+        a = x;
+        x = remainder;
+    }
+    let (y_squared, remainder) = (m - (&x).square()).div_mod(d);
+    if remainder != 0u32 {
+        return None;
+    }
+    Some((x, y_squared.checked_sqrt()?))
+}
+
+// Returns all square roots of the unit `u` modulo $p^e$, where `pe` is $p^e$.
+fn mod_prime_power_unit_sqrts(u: Natural, p: &Natural, e: u64, pe: &Natural) -> Vec<Natural> {
+    let Some(s) = u.mod_prime_power_sqrt(p, e) else {
+        return Vec::new();
+    };
+    if *p != 2u32 || e == 2 {
+        vec![pe - &s, s]
+    } else if e == 1 {
+        vec![s]
+    } else {
+        // The square roots of an odd square modulo $2^e$ are $\pm s$ and $2^{e - 1} \pm s$.
+        let half = Natural::power_of_2(e - 1);
+        vec![pe - &s, &half - &s, half + &s, s]
+    }
+}
+
+fn cornacchia_helper(d: &Natural, m: &Natural) -> Option<(Natural, Natural)> {
+    assert_ne!(*d, 0u32, "d cannot be zero");
+    if *m == 0u32 {
+        return None;
+    }
+    assert_eq!(d.gcd(m), 1u32, "d and m must be coprime");
+    if *m == 1u32 {
+        return Some((Natural::ONE, Natural::ZERO));
+    }
+    // Find all square roots of $-d$ modulo $m$, by finding them modulo each prime power dividing
+    // $m$ and combining them using the Chinese remainder theorem.
+    let neg_d = m - d % m;
+    let mut roots = vec![Natural::ZERO];
+    let mut modulus = Natural::ONE;
+    for (p, e) in m.factor() {
+        let pe = (&p).pow(e);
+        let component_roots = mod_prime_power_unit_sqrts(&neg_d % &pe, &p, e, &pe);
+        if component_roots.is_empty() {
+            return None;
+        }
+        let mut new_roots = Vec::with_capacity(roots.len() * component_roots.len());
+        for r in &roots {
+            for s in &component_roots {
+                new_roots.push(
+                    crt_helper([(r.clone(), &modulus), (s.clone(), &pe)].into_iter())
+                        .unwrap()
+                        .0,
+                );
+            }
+        }
+        roots = new_roots;
+        modulus *= pe;
+    }
+    roots
+        .into_iter()
+        .filter_map(|r| cornacchia_with_root(d, m, r))
+        .min_by(|(_, y_1), (_, y_2)| y_1.cmp(y_2))
+}
+
+impl Cornacchia<Natural> for Natural {
+    type Output = Natural;
+
+    /// Finds a primitive solution of $x^2 + dy^2 = m$, where $d$ is a positive [`Natural`] and
+    /// $m$ is a [`Natural`]; that is, a pair of [`Natural`]s $(x, y)$ with $\gcd(x, y) = 1$ and
+    /// $x^2 + dy^2 = m$. Both [`Natural`]s are taken by value.
+    ///
+    /// If there are several primitive solutions, the one with the smallest $y$ is returned. If
+    /// there are none, `None` is returned.
+    ///
+    /// $m$ is factored, and each square root $r$ of $-d$ modulo $m$ is tried in turn. Cornacchia's
+    /// algorithm runs the Euclidean algorithm on $m$ and $r$ until the remainder $x$ satisfies $x^2
+    /// \leq m$, and then checks whether $(m - x^2)/d$ is a perfect square.
+    ///
+    /// # Worst-case complexity
+    /// See [`factor`](Factor::factor).
+    ///
+    /// # Panics
+    /// Panics if $d$ is zero, or if $m$ is positive and not coprime to $d$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Cornacchia;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Natural::from(1u32).cornacchia(Natural::from(13u32)).to_debug_string(),
+    ///     "Some((3, 2))"
+    /// );
+    /// assert_eq!(
+    ///     Natural::from(2u32).cornacchia(Natural::from(11u32)).to_debug_string(),
+    ///     "Some((3, 1))"
+    /// );
+    /// assert_eq!(
+    ///     Natural::from(1u32).cornacchia(Natural::from(65u32)).to_debug_string(),
+    ///     "Some((8, 1))"
+    /// );
+    /// assert_eq!(
+    ///     Natural::from(5u32).cornacchia(Natural::from(1009u32)).to_debug_string(),
+    ///     "Some((17, 12))"
+    /// );
+    /// assert_eq!(Natural::from(1u32).cornacchia(Natural::from(21u32)), None);
+    /// ```
+    #[inline]
+    fn cornacchia(self, m: Natural) -> Option<(Natural, Natural)> {
+        cornacchia_helper(&self, &m)
+    }
+}
+
+impl Cornacchia<&Natural> for Natural {
+    type Output = Natural;
+
+    /// Finds a primitive solution of $x^2 + dy^2 = m$, where $d$ is a positive [`Natural`] and
+    /// $m$ is a [`Natural`]; that is, a pair of [`Natural`]s $(x, y)$ with $\gcd(x, y) = 1$ and
+    /// $x^2 + dy^2 = m$. The first [`Natural`] is taken by value and the second by
+    /// reference.
+    ///
+    /// If there are several primitive solutions, the one with the smallest $y$ is returned. If
+    /// there are none, `None` is returned.
+    ///
+    /// $m$ is factored, and each square root $r$ of $-d$ modulo $m$ is tried in turn. Cornacchia's
+    /// algorithm runs the Euclidean algorithm on $m$ and $r$ until the remainder $x$ satisfies $x^2
+    /// \leq m$, and then checks whether $(m - x^2)/d$ is a perfect square.
+    ///
+    /// # Worst-case complexity
+    /// See [`factor`](Factor::factor).
+    ///
+    /// # Panics
+    /// Panics if $d$ is zero, or if $m$ is positive and not coprime to $d$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Cornacchia;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Natural::from(1u32).cornacchia(&Natural::from(13u32)).to_debug_string(),
+    ///     "Some((3, 2))"
+    /// );
+    /// assert_eq!(
+    ///     Natural::from(2u32).cornacchia(&Natural::from(11u32)).to_debug_string(),
+    ///     "Some((3, 1))"
+    /// );
+    /// assert_eq!(
+    ///     Natural::from(1u32).cornacchia(&Natural::from(65u32)).to_debug_string(),
+    ///     "Some((8, 1))"
+    /// );
+    /// assert_eq!(
+    ///     Natural::from(5u32).cornacchia(&Natural::from(1009u32)).to_debug_string(),
+    ///     "Some((17, 12))"
+    /// );
+    /// assert_eq!(Natural::from(1u32).cornacchia(&Natural::from(21u32)), None);
+    /// ```
+    #[inline]
+    fn cornacchia(self, m: &Natural) -> Option<(Natural, Natural)> {
+        cornacchia_helper(&self, m)
+    }
+}
+
+impl Cornacchia<Natural> for &Natural {
+    type Output = Natural;
+
+    /// Finds a primitive solution of $x^2 + dy^2 = m$, where $d$ is a positive [`Natural`] and
+    /// $m$ is a [`Natural`]; that is, a pair of [`Natural`]s $(x, y)$ with $\gcd(x, y) = 1$ and
+    /// $x^2 + dy^2 = m$. The first [`Natural`] is taken by reference and the second by
+    /// value.
+    ///
+    /// If there are several primitive solutions, the one with the smallest $y$ is returned. If
+    /// there are none, `None` is returned.
+    ///
+    /// $m$ is factored, and each square root $r$ of $-d$ modulo $m$ is tried in turn. Cornacchia's
+    /// algorithm runs the Euclidean algorithm on $m$ and $r$ until the remainder $x$ satisfies $x^2
+    /// \leq m$, and then checks whether $(m - x^2)/d$ is a perfect square.
+    ///
+    /// # Worst-case complexity
+    /// See [`factor`](Factor::factor).
+    ///
+    /// # Panics
+    /// Panics if $d$ is zero, or if $m$ is positive and not coprime to $d$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Cornacchia;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     (&Natural::from(1u32)).cornacchia(Natural::from(13u32)).to_debug_string(),
+    ///     "Some((3, 2))"
+    /// );
+    /// assert_eq!(
+    ///     (&Natural::from(2u32)).cornacchia(Natural::from(11u32)).to_debug_string(),
+    ///     "Some((3, 1))"
+    /// );
+    /// assert_eq!(
+    ///     (&Natural::from(1u32)).cornacchia(Natural::from(65u32)).to_debug_string(),
+    ///     "Some((8, 1))"
+    /// );
+    /// assert_eq!(
+    ///     (&Natural::from(5u32)).cornacchia(Natural::from(1009u32)).to_debug_string(),
+    ///     "Some((17, 12))"
+    /// );
+    /// assert_eq!((&Natural::from(1u32)).cornacchia(Natural::from(21u32)), None);
+    /// ```
+    #[inline]
+    fn cornacchia(self, m: Natural) -> Option<(Natural, Natural)> {
+        cornacchia_helper(self, &m)
+    }
+}
+
+impl Cornacchia<&Natural> for &Natural {
+    type Output = Natural;
+
+    /// Finds a primitive solution of $x^2 + dy^2 = m$, where $d$ is a positive [`Natural`] and
+    /// $m$ is a [`Natural`]; that is, a pair of [`Natural`]s $(x, y)$ with $\gcd(x, y) = 1$ and
+    /// $x^2 + dy^2 = m$. Both [`Natural`]s are taken by reference.
+    ///
+    /// If there are several primitive solutions, the one with the smallest $y$ is returned. If
+    /// there are none, `None` is returned.
+    ///
+    /// $m$ is factored, and each square root $r$ of $-d$ modulo $m$ is tried in turn. Cornacchia's
+    /// algorithm runs the Euclidean algorithm on $m$ and $r$ until the remainder $x$ satisfies $x^2
+    /// \leq m$, and then checks whether $(m - x^2)/d$ is a perfect square.
+    ///
+    /// # Worst-case complexity
+    /// See [`factor`](Factor::factor).
+    ///
+    /// # Panics
+    /// Panics if $d$ is zero, or if $m$ is positive and not coprime to $d$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Cornacchia;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     (&Natural::from(1u32)).cornacchia(&Natural::from(13u32)).to_debug_string(),
+    ///     "Some((3, 2))"
+    /// );
+    /// assert_eq!(
+    ///     (&Natural::from(2u32)).cornacchia(&Natural::from(11u32)).to_debug_string(),
+    ///     "Some((3, 1))"
+    /// );
+    /// assert_eq!(
+    ///     (&Natural::from(1u32)).cornacchia(&Natural::from(65u32)).to_debug_string(),
+    ///     "Some((8, 1))"
+    /// );
+    /// assert_eq!(
+    ///     (&Natural::from(5u32)).cornacchia(&Natural::from(1009u32)).to_debug_string(),
+    ///     "Some((17, 12))"
+    /// );
+    /// assert_eq!((&Natural::from(1u32)).cornacchia(&Natural::from(21u32)), None);
+    /// ```
+    #[inline]
+    fn cornacchia(self, m: &Natural) -> Option<(Natural, Natural)> {
+        cornacchia_helper(self, m)
+    }
+}
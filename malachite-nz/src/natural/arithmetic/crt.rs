@@ -0,0 +1,95 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{
+    DivExact, DivisibleBy, Gcd, ModInverse, ModMul, ModSub,
+};
+use malachite_base::num::basic::traits::{One, Zero};
+
+// Combines congruences $x \equiv a_i \mod m_i$, where each residue $a_i$ is already reduced modulo
+// its modulus $m_i$. Each step finds $t$ such that $x + mt \equiv a \mod n$, where $x$ and $m$ are
+// the solution and modulus so far; such a $t$ exists if and only if $\gcd(m, n)$ divides $a - x$.
+pub(crate) fn crt_helper<'a, I: Iterator<Item = (Natural, &'a Natural)>>(
+    congruences: I,
+) -> Option<(Natural, Natural)> {
+    let mut x = Natural::ZERO;
+    let mut m = Natural::ONE;
+    for (a, n) in congruences {
+        let d = a.mod_sub(&x % n, n);
+        let g = (&m).gcd(n);
+        if !(&d).divisible_by(&g) {
+            return None;
+        }
+        let n_g = n.div_exact(&g);
+        if n_g == 1u32 {
+            continue;
+        }
+        let inverse = ((&m).div_exact(&g) % &n_g).mod_inverse(&n_g).unwrap();
+        let t = d.div_exact(&g).mod_mul(inverse, &n_g);
+        x += &m * t;
+        m *= n_g;
+    }
+    Some((x, m))
+}
+
+impl Natural {
+    /// Solves a system of simultaneous congruences using the Chinese remainder theorem.
+    ///
+    /// Given pairs $(a_i, m_i)$, returns `Some((x, m))`, where $m = \operatorname{lcm}(m_1, m_2,
+    /// \ldots)$ and $x$ is the unique [`Natural`] such that $0 \leq x < m$ and $x \equiv a_i \mod
+    /// m_i$ for all $i$. The moduli do not need to be pairwise coprime; if the congruences have no
+    /// common solution, `None` is returned. The residues do not need to be reduced. If there are no
+    /// congruences, the result is `Some((0, 1))`.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, k) = O(kn (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the sum of the significant bits of the
+    /// residues and moduli, and $k$ is `congruences.len()`.
+    ///
+    /// # Panics
+    /// Panics if any modulus is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Natural::crt(&[
+    ///         (Natural::from(2u32), Natural::from(3u32)),
+    ///         (Natural::from(3u32), Natural::from(5u32)),
+    ///         (Natural::from(2u32), Natural::from(7u32))
+    ///     ])
+    ///     .to_debug_string(),
+    ///     "Some((23, 105))"
+    /// );
+    /// assert_eq!(
+    ///     Natural::crt(&[
+    ///         (Natural::from(2u32), Natural::from(4u32)),
+    ///         (Natural::from(10u32), Natural::from(6u32))
+    ///     ])
+    ///     .to_debug_string(),
+    ///     "Some((10, 12))"
+    /// );
+    /// assert_eq!(
+    ///     Natural::crt(&[
+    ///         (Natural::from(1u32), Natural::from(4u32)),
+    ///         (Natural::from(2u32), Natural::from(6u32))
+    ///     ]),
+    ///     None
+    /// );
+    /// assert_eq!(Natural::crt(&[]).to_debug_string(), "Some((0, 1))");
+    /// ```
+    pub fn crt(congruences: &[(Natural, Natural)]) -> Option<(Natural, Natural)> {
+        crt_helper(congruences.iter().map(|(a, n)| (a % n, n)))
+    }
+}
@@ -0,0 +1,130 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::natural::Natural;
+use crate::platform::Limb;
+use malachite_base::num::arithmetic::traits::{CheckedFibonacci, Fibonacci, Lucas, Square};
+use malachite_base::num::basic::traits::{One, Two, Zero};
+use malachite_base::num::logic::traits::{BitAccess, SignificantBits};
+
+fn fibonacci2(n: u64) -> (Natural, Natural) {
+    if let Some((f, f_prev)) = Limb::checked_fibonacci2(n) {
+        return (Natural::from(f), Natural::from(f_prev));
+    }
+    // Maintain $a = F_{j-1}$ and $b = F_j$, where $j$ is the prefix of `n` processed so far,
+    // starting with the highest bit, which is 1.
+    let mut a = Natural::ZERO;
+    let mut b = Natural::ONE;
+    for i in (0..n.significant_bits() - 1).rev() {
+        // $F_{2j-1} = F_j^2 + F_{j-1}^2$ and $F_{2j} = F_j(F_j + 2F_{j-1})$.
+        let f_2j_minus_1 = (&b).square() + (&a).square();
+        let f_2j = (&a << 1u32) + &b;
+        let f_2j = f_2j * b;
+        if n.get_bit(i) {
+            b = &f_2j + f_2j_minus_1;
+            a = f_2j;
+        } else {
+            a = f_2j_minus_1;
+            b = f_2j;
+        }
+    }
+    (b, a)
+}
+
+impl Fibonacci for Natural {
+    /// Computes the $n$th Fibonacci number.
+    ///
+    /// $f(n) = F_n$, where $F_0 = 0$, $F_1 = 1$, and $F_n = F_{n-1} + F_{n-2}$.
+    ///
+    /// $F_n = O(\varphi^n)$, where $\varphi = (1 + \sqrt 5)/2$ is the golden ratio.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Fibonacci;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::fibonacci(0), 0);
+    /// assert_eq!(Natural::fibonacci(1), 1);
+    /// assert_eq!(Natural::fibonacci(2), 1);
+    /// assert_eq!(Natural::fibonacci(10), 55);
+    /// assert_eq!(
+    ///     Natural::fibonacci(100).to_string(),
+    ///     "354224848179261915075"
+    /// );
+    /// ```
+    #[inline]
+    fn fibonacci(n: u64) -> Natural {
+        fibonacci2(n).0
+    }
+
+    /// Computes the $n$th and $(n-1)$th Fibonacci numbers. $F_{-1}$ is 1.
+    ///
+    /// $f(n) = (F_n, F_{n-1})$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Fibonacci;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::fibonacci2(0).to_debug_string(), "(0, 1)");
+    /// assert_eq!(Natural::fibonacci2(1).to_debug_string(), "(1, 0)");
+    /// assert_eq!(Natural::fibonacci2(10).to_debug_string(), "(55, 34)");
+    /// assert_eq!(
+    ///     Natural::fibonacci2(100).to_debug_string(),
+    ///     "(354224848179261915075, 218922995834555169026)"
+    /// );
+    /// ```
+    #[inline]
+    fn fibonacci2(n: u64) -> (Natural, Natural) {
+        fibonacci2(n)
+    }
+}
+
+impl Lucas for Natural {
+    /// Computes the $n$th Lucas number.
+    ///
+    /// $f(n) = L_n$, where $L_0 = 2$, $L_1 = 1$, and $L_n = L_{n-1} + L_{n-2}$.
+    ///
+    /// $L_n = O(\varphi^n)$, where $\varphi = (1 + \sqrt 5)/2$ is the golden ratio.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Lucas;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::lucas(0), 2);
+    /// assert_eq!(Natural::lucas(1), 1);
+    /// assert_eq!(Natural::lucas(2), 3);
+    /// assert_eq!(Natural::lucas(10), 123);
+    /// assert_eq!(Natural::lucas(100).to_string(), "792070839848372253127");
+    /// ```
+    fn lucas(n: u64) -> Natural {
+        if n == 0 {
+            return Natural::TWO;
+        }
+        // $L_n = F_n + 2F_{n-1}$.
+        let (f, f_prev) = fibonacci2(n);
+        (f_prev << 1u32) + f
+    }
+}
@@ -33,6 +33,7 @@ use crate::natural::arithmetic::sub::{
 };
 use crate::natural::arithmetic::sub_mul::limbs_sub_mul_limb_same_length_in_place_left;
 use crate::natural::comparison::cmp::limbs_cmp_same_length;
+use crate::natural::Natural;
 use crate::platform::{DoubleLimb, Limb};
 use core::cmp::{max, min, Ordering::*};
 use core::mem::swap;
@@ -1615,3 +1616,44 @@ pub_crate_test! {limbs_gcd_reduced(out: &mut [Limb], xs: &mut [Limb], ys: &mut [
         2
     }
 }}
+
+// Runs the half-gcd algorithm on $x$ and $y$, where $x > y$, returning $x'$, $y'$, and a matrix $M
+// = (m_{00}, m_{01}; m_{10}, m_{11})$ with nonnegative entries and determinant 1 such that $(x;
+// y) = M (x'; y')$. The pair $(x', y')$ is one that the subtractive Euclidean algorithm passes
+// through, and if $x$ has $n$ limbs, $x'$ and $y'$ both have more than $\lfloor n/2 \rfloor + 1$
+// limbs. Returns `None` if no reduction is possible; this always happens when $n < 3$.
+//
+// # Worst-case complexity
+// $T(n) = O(n (\log n)^2 \log\log n)$
+//
+// $M(n) = O(n \log n)$
+//
+// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
+//
+// This is public so that rational reconstruction in malachite-q can use it.
+#[doc(hidden)]
+pub fn half_gcd_matrix(x: &Natural, y: &Natural) -> Option<(Natural, Natural, [Natural; 4])> {
+    assert!(x > y);
+    let mut xs = x.to_limbs_asc();
+    let n = xs.len();
+    if n < 3 {
+        return None;
+    }
+    let mut ys = y.to_limbs_asc();
+    ys.resize(n, 0);
+    let mut matrix_scratch = vec![0; limbs_half_gcd_matrix_init_scratch_len(n)];
+    let mut scratch = vec![0; limbs_half_gcd_scratch_len(n)];
+    let mut m = HalfGcdMatrix::init(n, &mut matrix_scratch);
+    let new_n = limbs_half_gcd(&mut xs, &mut ys, &mut m, &mut scratch);
+    if new_n == 0 {
+        return None;
+    }
+    xs.truncate(new_n);
+    ys.truncate(new_n);
+    let element = |i, j| Natural::from_limbs_asc(&m.get(i, j)[..m.n]);
+    Some((
+        Natural::from_owned_limbs_asc(xs),
+        Natural::from_owned_limbs_asc(ys),
+        [element(0, 0), element(0, 1), element(1, 0), element(1, 1)],
+    ))
+}
@@ -0,0 +1,141 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::natural::InnerNatural::Small;
+use crate::natural::Natural;
+use crate::platform::Limb;
+use malachite_base::num::arithmetic::is_perfect_power::power_residue_filter;
+use malachite_base::num::arithmetic::traits::{
+    CheckedRoot, ExpressAsPower, FloorSqrt, IsPerfectPower, IsSquare,
+};
+use malachite_base::num::conversion::traits::WrappingFrom;
+use malachite_base::num::factorization::traits::Primes;
+use malachite_base::num::logic::traits::SignificantBits;
+
+impl ExpressAsPower for Natural {
+    /// Expresses a [`Natural`] as a perfect power $y^k$, with the exponent $k \geq 2$ as large as
+    /// possible. Returns `None` if the [`Natural`] is not a perfect power.
+    ///
+    /// 0 and 1 are expressed as $0^2$ and $1^2$. For each prime exponent $p$, most
+    /// non-$p$th-powers are rejected by looking at their residues modulo a few small primes, before
+    /// any root is computed.
+    ///
+    /// $f(x) = (y, k)$, where $y^k = x$ and $k \geq 2$ is maximal, if such $y$ and $k$ exist, and
+    /// `None` otherwise.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::{ExpressAsPower, Pow};
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Natural::ZERO.express_as_power().to_debug_string(),
+    ///     "Some((0, 2))"
+    /// );
+    /// assert_eq!(
+    ///     Natural::from(72u32).express_as_power().to_debug_string(),
+    ///     "None"
+    /// );
+    /// assert_eq!(
+    ///     Natural::from(1024u32).express_as_power().to_debug_string(),
+    ///     "Some((2, 10))"
+    /// );
+    /// assert_eq!(
+    ///     Natural::from(6u32).pow(60).express_as_power().to_debug_string(),
+    ///     "Some((6, 60))"
+    /// );
+    /// assert_eq!(
+    ///     Natural::from(10u32)
+    ///         .pow(100)
+    ///         .express_as_power()
+    ///         .to_debug_string(),
+    ///     "Some((10, 100))"
+    /// );
+    /// ```
+    fn express_as_power(&self) -> Option<(Natural, u64)> {
+        if let Natural(Small(small)) = *self {
+            return small
+                .express_as_power()
+                .map(|(base, k)| (Natural::from(base), k));
+        }
+        // If $x = y^k$, then $k$ divides the multiplicity of 2 in $x$.
+        let zeros = self.trailing_zeros().unwrap();
+        for p in u64::primes_less_than(&self.significant_bits()) {
+            if zeros != 0 && zeros % p != 0 {
+                continue;
+            }
+            let root = if p == 2 {
+                if !self.is_square() {
+                    continue;
+                }
+                self.floor_sqrt()
+            } else if !power_residue_filter(p, |q| {
+                u64::wrapping_from(self.rem_limb_ref(Limb::wrapping_from(q)))
+            }) {
+                continue;
+            } else if let Some(root) = self.checked_root(p) {
+                root
+            } else {
+                continue;
+            };
+            // Since `p` is the smallest prime exponent, the largest exponent of `self` is `p` times
+            // the largest exponent of `root`.
+            return Some(match root.express_as_power() {
+                Some((base, k)) => (base, k * p),
+                None => (root, p),
+            });
+        }
+        None
+    }
+}
+
+impl IsPerfectPower for Natural {
+    /// Determines whether a [`Natural`] is a perfect power; that is, whether it is equal to $y^k$
+    /// for some $y$ and some $k \geq 2$.
+    ///
+    /// 0 and 1 are perfect powers.
+    ///
+    /// $f(x) = (\exists y, k \in \N : k \geq 2 \land y^k = x)$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::{IsPerfectPower, Pow};
+    /// use malachite_base::num::basic::traits::{One, Zero};
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::ZERO.is_perfect_power(), true);
+    /// assert_eq!(Natural::ONE.is_perfect_power(), true);
+    /// assert_eq!(Natural::from(72u32).is_perfect_power(), false);
+    /// assert_eq!(Natural::from(1000u32).is_perfect_power(), true);
+    /// assert_eq!(Natural::from(3u32).pow(101).is_perfect_power(), true);
+    /// assert_eq!(
+    ///     (Natural::from(3u32).pow(101) + Natural::ONE).is_perfect_power(),
+    ///     false
+    /// );
+    /// ```
+    #[inline]
+    fn is_perfect_power(&self) -> bool {
+        self.express_as_power().is_some()
+    }
+}
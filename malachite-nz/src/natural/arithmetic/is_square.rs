@@ -0,0 +1,62 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::natural::arithmetic::mod_op::limbs_mod_limb;
+use crate::natural::InnerNatural::{Large, Small};
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::is_square::square_residue_filter;
+use malachite_base::num::arithmetic::traits::{CheckedSqrt, IsSquare};
+use malachite_base::num::conversion::traits::WrappingFrom;
+
+impl IsSquare for Natural {
+    /// Determines whether a [`Natural`] is a perfect square.
+    ///
+    /// Most non-squares are rejected by looking at their residues modulo a few small numbers,
+    /// without computing a square root.
+    ///
+    /// $f(x) = (\exists y \in \Z : y^2 = x)$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::str::FromStr;
+    /// use malachite_base::num::arithmetic::traits::{IsSquare, Pow};
+    /// use malachite_base::num::basic::traits::{One, Zero};
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::ZERO.is_square(), true);
+    /// assert_eq!(Natural::ONE.is_square(), true);
+    /// assert_eq!(Natural::from(99u32).is_square(), false);
+    /// assert_eq!(Natural::from(100u32).is_square(), true);
+    /// assert_eq!(Natural::from(10u32).pow(101).is_square(), false);
+    /// assert_eq!(Natural::from(10u32).pow(100).is_square(), true);
+    /// assert_eq!(
+    ///     Natural::from_str("1000000000000000000000000000001")
+    ///         .unwrap()
+    ///         .is_square(),
+    ///     false
+    /// );
+    /// ```
+    fn is_square(&self) -> bool {
+        match *self {
+            Natural(Small(small)) => small.is_square(),
+            Natural(Large(ref xs)) => {
+                square_residue_filter(
+                    u64::wrapping_from(xs[0] & 63),
+                    u64::wrapping_from(limbs_mod_limb(xs, 45045)),
+                ) && self.checked_sqrt().is_some()
+            }
+        }
+    }
+}
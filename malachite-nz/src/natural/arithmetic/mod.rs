@@ -16,10 +16,23 @@ pub mod add;
 /// [`AddMulAssign`](malachite_base::num::arithmetic::traits::AddMulAssign), traits for adding a
 /// number and the product of two other numbers.
 pub mod add_mul;
+#[doc(hidden)]
+pub mod approximate_real;
+/// Implementations of [`Bell`](malachite_base::num::arithmetic::traits::Bell), a trait for
+/// computing Bell numbers.
+pub mod bell;
 /// Implementations of
 /// [`BinomialCoefficient`](malachite_base::num::arithmetic::traits::BinomialCoefficient), a trait
 /// for computing the binomial coefficient of two numbers.
 pub mod binomial_coefficient;
+/// [`Natural::binomial_coefficient_mod`](super::Natural::binomial_coefficient_mod),
+/// [`Natural::multinomial_mod`](super::Natural::multinomial_mod), and
+/// [`Natural::factorial_mod`](super::Natural::factorial_mod), for computing binomial coefficients,
+/// multinomial coefficients, and factorials modulo a [`Natural`](super::Natural).
+pub mod binomial_coefficient_mod;
+/// Implementations of [`Catalan`](malachite_base::num::arithmetic::traits::Catalan), a trait for
+/// computing Catalan numbers.
+pub mod catalan;
 /// Implementations of [`CheckedSub`](malachite_base::num::arithmetic::traits::CheckedSub), a trait
 /// for subtracting two numbers and checking whether the result is representable.
 pub mod checked_sub;
@@ -30,6 +43,11 @@ pub mod checked_sub_mul;
 /// Implementations of [`CoprimeWith`](malachite_base::num::arithmetic::traits::CoprimeWith), a
 /// trait for determining whether two numbers are coprime.
 pub mod coprime_with;
+/// Implementations of [`Cornacchia`](malachite_base::num::arithmetic::traits::Cornacchia), a trait
+/// for solving $x^2 + dy^2 = m$ using Cornacchia's algorithm.
+pub mod cornacchia;
+/// The Chinese remainder theorem for [`Natural`](super::Natural)s.
+pub mod crt;
 /// Division of [`Natural`](super::Natural)s.
 pub mod div;
 /// Implementations of [`DivExact`](malachite_base::num::arithmetic::traits::DivExact) and
@@ -74,6 +92,10 @@ pub mod eq_mod_power_of_2;
 /// [`Multifactorial`](malachite_base::num::arithmetic::traits::Multifactorial), and
 /// [`Subfactorial`](malachite_base::num::arithmetic::traits::Subfactorial).
 pub mod factorial;
+/// Implementations of [`Fibonacci`](malachite_base::num::arithmetic::traits::Fibonacci) and
+/// [`Lucas`](malachite_base::num::arithmetic::traits::Lucas), traits for computing Fibonacci and
+/// Lucas numbers.
+pub mod fibonacci;
 #[cfg(feature = "float_helpers")]
 pub mod float_add;
 #[cfg(feature = "float_helpers")]
@@ -92,9 +114,16 @@ pub mod float_sub;
 /// [`GcdAssign`](malachite_base::num::arithmetic::traits::GcdAssign), traits for computing the GCD
 /// (greatest common divisor) of two numbers.
 pub mod gcd;
+/// Implementations of [`IsPerfectPower`](malachite_base::num::arithmetic::traits::IsPerfectPower)
+/// and [`ExpressAsPower`](malachite_base::num::arithmetic::traits::ExpressAsPower), traits for
+/// determining whether a number is a perfect power and for expressing it as one.
+pub mod is_perfect_power;
 /// Implementations of [`IsPowerOf2`](malachite_base::num::arithmetic::traits::IsPowerOf2), a trait
 /// for determining whether a number is an integer power of 2.
 pub mod is_power_of_2;
+/// Implementations of [`IsSquare`](malachite_base::num::arithmetic::traits::IsSquare), a trait for
+/// determining whether a number is a perfect square.
+pub mod is_square;
 /// Implementations of [`LegendreSymbol`](malachite_base::num::arithmetic::traits::LegendreSymbol),
 /// [`JacobiSymbol`](malachite_base::num::arithmetic::traits::JacobiSymbol), and
 /// [`KroneckerSymbol`](malachite_base::num::arithmetic::traits::KroneckerSymbol), traits for
@@ -128,12 +157,62 @@ pub mod log_base_power_of_2;
 /// [`ModAddAssign`](malachite_base::num::arithmetic::traits::ModAddAssign), traits for adding two
 /// numbers modulo another number.
 pub mod mod_add;
+/// Implementations of [`ModDiscreteLog`](malachite_base::num::arithmetic::traits::ModDiscreteLog),
+/// a trait for finding discrete logarithms modulo a number.
+///
+/// # mod_discrete_log
+/// ```
+/// use malachite_base::num::arithmetic::traits::{ModDiscreteLog, Pow};
+/// use malachite_base::num::basic::traits::One;
+/// use malachite_nz::natural::Natural;
+/// use std::str::FromStr;
+///
+/// assert_eq!(
+///     Natural::from(3u32).mod_discrete_log(Natural::from(6u32), Natural::from(7u32)),
+///     Some(Natural::from(3u32))
+/// );
+/// assert_eq!(
+///     Natural::from(2u32).mod_discrete_log(Natural::from(3u32), Natural::from(7u32)),
+///     None
+/// );
+///
+/// let p = Natural::from(10u32).pow(30) + Natural::from(57u32);
+/// let x = Natural::from(10u32).pow(29) + Natural::ONE;
+/// assert_eq!(
+///     (&Natural::from(5u32))
+///         .mod_discrete_log(&x, &p)
+///         .unwrap()
+///         .to_string(),
+///     "536850851924349563545054649225"
+/// );
+///
+/// let p = Natural::from(2u32).pow(89) - Natural::ONE;
+/// assert_eq!(
+///     (&Natural::from(3u32))
+///         .mod_discrete_log(&Natural::from_str("10000000000000000000000000").unwrap(), &p)
+///         .unwrap()
+///         .to_string(),
+///     "299832291162806541314929680"
+/// );
+///
+/// // The base does not need to be coprime to the modulus
+/// let m = Natural::from(3u32) << 100;
+/// assert_eq!(
+///     Natural::from(2u32).mod_discrete_log(Natural::ONE << 100, m),
+///     Some(Natural::from(100u32))
+/// );
+/// ```
+pub mod mod_discrete_log;
 /// Implementations of [`ModInverse`](malachite_base::num::arithmetic::traits::ModInverse), a trait
 /// for finding the multiplicative inverse of a number modulo another number.
 pub mod mod_inverse;
 /// Implementations of [`ModIsReduced`](malachite_base::num::arithmetic::traits::ModIsReduced), a
 /// trait for checking whether a number is reduced modulo another number.
 pub mod mod_is_reduced;
+/// Implementations of
+/// [`ModLucasSequence`](malachite_base::num::arithmetic::traits::ModLucasSequence), a trait for
+/// computing terms of the Lucas sequences $U(P, Q)$ and $V(P, Q)$ modulo a number.
+pub mod mod_lucas_sequence;
 /// Implementations of traits for multiplying two numbers modulo another number.
 ///
 /// The traits are [`ModMul`](malachite_base::num::arithmetic::traits::ModMul),
@@ -172,6 +251,11 @@ pub mod mod_op;
 /// [`ModPowPrecomputed`](malachite_base::num::arithmetic::traits::ModPowPrecomputed) is useful when
 /// having to make several exponentiations modulo the same modulus.
 pub mod mod_pow;
+/// [`ModPowTable`](mod_pow_table::ModPowTable), for raising a fixed base to many different powers
+/// modulo a [`Natural`](super::Natural), and
+/// [`Natural::mod_multi_pow`](super::Natural::mod_multi_pow), for computing a product of several
+/// powers modulo a [`Natural`](super::Natural).
+pub mod mod_pow_table;
 /// Implementations of traits for finding the remainder of a number divided by $2^k$, subject to
 /// various rounding rules.
 ///
@@ -516,6 +600,10 @@ pub mod mod_shl;
 /// assert_eq!(x, 7436663564915145u64);
 /// ```
 pub mod mod_shr;
+/// Implementations of [`ModSqrt`](malachite_base::num::arithmetic::traits::ModSqrt) and
+/// [`ModPrimePowerSqrt`](malachite_base::num::arithmetic::traits::ModPrimePowerSqrt), traits for
+/// finding square roots modulo a prime or a prime power.
+pub mod mod_sqrt;
 /// Implementations of traits for squaring a number modulo another number.
 ///
 /// The traits are [`ModSquare`](malachite_base::num::arithmetic::traits::ModSquare),
@@ -528,8 +616,17 @@ pub mod mod_square;
 /// [`ModSubAssign`](malachite_base::num::arithmetic::traits::ModSubAssign), traits for subtracting
 /// two numbers modulo another number.
 pub mod mod_sub;
+/// Arithmetic modulo a fixed odd [`Natural`](super::Natural) in Montgomery form, using
+/// [`ModularRing`](montgomery::ModularRing) and [`MontgomeryNatural`](montgomery::MontgomeryNatural).
+///
+/// Converting to and from Montgomery form takes about as long as a modular multiplication, but
+/// every multiplication in Montgomery form avoids a division, so this is useful for long chains of
+/// multiplications and exponentiations modulo the same modulus.
+pub mod montgomery;
 /// Multiplication of [`Natural`](super::Natural)s.
 pub mod mul;
+/// [`Natural::multinomial`](super::Natural::multinomial), for computing multinomial coefficients.
+pub mod multinomial;
 /// Negation of a [`Natural`](super::Natural), returning an [`Integer`](crate::integer::Integer).
 pub mod neg;
 /// Implementations of [`NextPowerOf2`](malachite_base::num::arithmetic::traits::NextPowerOf2) and
@@ -539,6 +636,9 @@ pub mod next_power_of_2;
 /// Implementations of [`Parity`](malachite_base::num::arithmetic::traits::Parity), a trait for
 /// determining whether a number is even or odd.
 pub mod parity;
+/// Implementations of [`Partitions`](malachite_base::num::arithmetic::traits::Partitions), a trait
+/// for counting the partitions of a number.
+pub mod partitions;
 /// Implementations of [`Pow`](malachite_base::num::arithmetic::traits::Pow) and
 /// [`PowAssign`](malachite_base::num::arithmetic::traits::PowAssign), traits for raising a number
 /// to a power.
@@ -1237,9 +1337,16 @@ pub mod sqrt;
 /// [`SquareAssign`](malachite_base::num::arithmetic::traits::SquareAssign), traits for squaring a
 /// number.
 pub mod square;
+/// Implementations of [`Stirling`](malachite_base::num::arithmetic::traits::Stirling), a trait for
+/// computing Stirling numbers of the first and second kinds.
+pub mod stirling;
 /// Subtraction of [`Natural`](super::Natural)s.
 pub mod sub;
 /// Implementations of [`SubMul`](malachite_base::num::arithmetic::traits::SubMul) and
 /// [`SubMulAssign`](malachite_base::num::arithmetic::traits::SubMulAssign), traits for subtracting
 /// the product of two numbers from a number.
 pub mod sub_mul;
+/// Implementations of [`TwoSquares`](malachite_base::num::arithmetic::traits::TwoSquares) and
+/// [`FourSquares`](malachite_base::num::arithmetic::traits::FourSquares), traits for writing a
+/// number as a sum of two or four squares.
+pub mod sums_of_squares;
@@ -0,0 +1,482 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::natural::arithmetic::mod_mul::ModMulData;
+use crate::natural::Natural;
+use alloc::vec::Vec;
+use malachite_base::num::arithmetic::traits::{
+    CeilingSqrt, DivExact, DivExactAssign, DivisibleBy, Gcd, ModAdd, ModAddAssign, ModDiscreteLog,
+    ModInverse, ModMul, ModMulPrecomputed, ModMulPrecomputedAssign, ModPow, ModSub, Pow,
+};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::factorization::traits::{CarmichaelLambda, Factor};
+use malachite_base::num::logic::traits::SignificantBits;
+
+// Subgroups whose prime order has at most this many bits are searched using baby-step giant-step;
+// larger ones use Pollard's rho algorithm, which needs no table.
+const BSGS_MAX_BITS: u64 = 32;
+
+// Pollard's rho algorithm restarts from a new starting point when a collision gives no information.
+// If $h$ lies in the subgroup generated by $\gamma$, this happens with probability about $1/q$, so
+// after this many restarts $h$ is taken to lie outside the subgroup.
+const RHO_MAX_ATTEMPTS: u64 = 8;
+
+// Finds $x$ such that $\gamma^x \equiv h \mod m$, where $\gamma$ generates a subgroup of prime
+// order $q$, using the baby-step giant-step algorithm.
+fn subgroup_log_bsgs(
+    gamma: &Natural,
+    h: &Natural,
+    q: &Natural,
+    m: &Natural,
+    data: &ModMulData,
+) -> Option<Natural> {
+    let s = u64::exact_from(&q.ceiling_sqrt());
+    let mut baby_steps = Vec::with_capacity(usize::exact_from(s));
+    let mut x = Natural::ONE;
+    for j in 0..s {
+        let next = (&x).mod_mul_precomputed(gamma, m, data);
+        baby_steps.push((x, j));
+        x = next;
+    }
+    baby_steps.sort_unstable();
+    // `x` is now $\gamma^s$.
+    let giant_step = x.mod_inverse(m).unwrap();
+    let mut y = h.clone();
+    for i in 0..s {
+        if let Ok(k) = baby_steps.binary_search_by(|(z, _)| z.cmp(&y)) {
+            return Some(Natural::from(i * s + baby_steps[k].1));
+        }
+        y.mod_mul_precomputed_assign(&giant_step, m, data);
+    }
+    None
+}
+
+// One step of the walk used by Pollard's rho algorithm. The walk element $y = \gamma^a h^b$ is
+// multiplied by $\gamma$, squared, or multiplied by $h$, depending on its residue modulo 3.
+fn rho_step(
+    (y, a, b): &mut (Natural, Natural, Natural),
+    gamma: &Natural,
+    h: &Natural,
+    q: &Natural,
+    m: &Natural,
+    data: &ModMulData,
+) {
+    match y.rem_limb_ref(3) {
+        0 => {
+            y.mod_mul_precomputed_assign(gamma, m, data);
+            a.mod_add_assign(Natural::ONE, q);
+        }
+        1 => {
+            *y = (&*y).mod_mul_precomputed(&*y, m, data);
+            *a = (&*a).mod_add(&*a, q);
+            *b = (&*b).mod_add(&*b, q);
+        }
+        _ => {
+            y.mod_mul_precomputed_assign(h, m, data);
+            b.mod_add_assign(Natural::ONE, q);
+        }
+    }
+}
+
+// Finds $x$ such that $\gamma^x \equiv h \mod m$, where $\gamma$ generates a subgroup of prime
+// order $q$, using Pollard's rho algorithm. Each walk element is kept as $\gamma^a h^b$, and a
+// collision between the tortoise and the hare yields a linear equation for $x$ modulo $q$. If $h$
+// does not lie in the subgroup, the result is either `None` or wrong, so it must be checked.
+fn subgroup_log_rho(
+    gamma: &Natural,
+    h: &Natural,
+    q: &Natural,
+    m: &Natural,
+    data: &ModMulData,
+) -> Option<Natural> {
+    for a in 1..=RHO_MAX_ATTEMPTS {
+        let a = Natural::from(a);
+        let mut tortoise = (
+            gamma.mod_pow(&a, m).mod_mul_precomputed(h, m, data),
+            a.clone(),
+            Natural::ONE,
+        );
+        rho_step(&mut tortoise, gamma, h, q, m, data);
+        let mut hare = tortoise.clone();
+        rho_step(&mut hare, gamma, h, q, m, data);
+        while tortoise.0 != hare.0 {
+            rho_step(&mut tortoise, gamma, h, q, m, data);
+            rho_step(&mut hare, gamma, h, q, m, data);
+            rho_step(&mut hare, gamma, h, q, m, data);
+        }
+        let (_, a_1, b_1) = tortoise;
+        let (_, a_2, b_2) = hare;
+        if b_1 != b_2 {
+            // $a_1 + b_1 x \equiv a_2 + b_2 x \mod q$.
+            return Some(
+                a_2.mod_sub(a_1, q)
+                    .mod_mul(b_1.mod_sub(b_2, q).mod_inverse(q).unwrap(), q),
+            );
+        }
+    }
+    None
+}
+
+fn subgroup_log(
+    gamma: &Natural,
+    h: &Natural,
+    q: &Natural,
+    m: &Natural,
+    data: &ModMulData,
+) -> Option<Natural> {
+    if q.significant_bits() <= BSGS_MAX_BITS {
+        subgroup_log_bsgs(gamma, h, q, m, data)
+    } else {
+        subgroup_log_rho(gamma, h, q, m, data)
+    }
+}
+
+// The Pohlig–Hellman algorithm. `base` and `target` are coprime to `m`, and `group_order_factors`
+// is the prime factorization of some $N$ with $b^N \equiv 1 \mod m$. The order of `base` is found by
+// removing prime factors from $N$, and the logarithm is then computed modulo each prime power
+// dividing that order, one base-$q$ digit at a time, and combined using the Chinese remainder
+// theorem.
+//
+// When the group of units modulo `m` is not cyclic, `target` may have an order dividing that of
+// `base` without being a power of `base`, and then the subgroup logarithms may be wrong; so the
+// result is checked at the end.
+fn mod_discrete_log_coprime_with_order(
+    base: &Natural,
+    target: &Natural,
+    m: &Natural,
+    group_order_factors: &[(Natural, u64)],
+) -> Option<Natural> {
+    let mut order = Natural::ONE;
+    for (q, e) in group_order_factors {
+        order *= q.pow(*e);
+    }
+    let mut order_factors = Vec::new();
+    for (q, e) in group_order_factors {
+        let mut e = *e;
+        while e != 0 {
+            let reduced = (&order).div_exact(q);
+            if base.mod_pow(&reduced, m) != 1u32 {
+                break;
+            }
+            order = reduced;
+            e -= 1;
+        }
+        if e != 0 {
+            order_factors.push((q.clone(), e));
+        }
+    }
+    if target.mod_pow(&order, m) != 1u32 {
+        return None;
+    }
+    let data = ModMulPrecomputed::<Natural>::precompute_mod_mul_data(m);
+    let mut log = Natural::ZERO;
+    let mut log_modulus = Natural::ONE;
+    for (q, e) in order_factors {
+        let q_e = (&q).pow(e);
+        let cofactor = (&order).div_exact(&q_e);
+        let g = base.mod_pow(&cofactor, m);
+        let h = target.mod_pow(&cofactor, m);
+        let g_inverse = (&g).mod_inverse(m).unwrap();
+        let gamma = g.mod_pow((&q_e).div_exact(&q), m);
+        let mut log_q = Natural::ZERO;
+        let mut q_i = Natural::ONE;
+        for _ in 0..e {
+            let h_i = (&g_inverse)
+                .mod_pow(&log_q, m)
+                .mod_mul_precomputed(&h, m, &data)
+                .mod_pow((&q_e).div_exact(&q_i).div_exact(&q), m);
+            log_q += subgroup_log(&gamma, &h_i, &q, m, &data)? * &q_i;
+            q_i *= &q;
+        }
+        let t = log_q
+            .mod_sub(&log % &q_e, &q_e)
+            .mod_mul((&log_modulus % &q_e).mod_inverse(&q_e).unwrap(), &q_e);
+        log += &log_modulus * t;
+        log_modulus *= q_e;
+    }
+    if base.mod_pow(&log, m) == *target {
+        Some(log)
+    } else {
+        None
+    }
+}
+
+fn mod_discrete_log_coprime(base: &Natural, target: &Natural, m: &Natural) -> Option<Natural> {
+    mod_discrete_log_coprime_with_order(base, target, m, &m.carmichael_lambda().factor())
+}
+
+fn mod_discrete_log(mut base: Natural, mut target: Natural, mut m: Natural) -> Option<Natural> {
+    assert!(base < m, "base must be reduced mod m, but {base} >= {m}");
+    assert!(
+        target < m,
+        "target must be reduced mod m, but {target} >= {m}"
+    );
+    // While `base` shares a factor $g$ with `m`, a solution $k > 0$ of $cb^k \equiv x \mod m$ is a
+    // solution $k - 1$ of $c(b/g)b^{k-1} \equiv x/g \mod m/g$. Each step at least halves `m`, so
+    // there are few steps, and the solutions they skip are checked along the way.
+    let mut offset = 0u64;
+    let mut c = Natural::ONE % &m;
+    loop {
+        if c == target {
+            return Some(Natural::from(offset));
+        }
+        let g = (&base).gcd(&m);
+        if g == 1u32 {
+            break;
+        }
+        if !(&target).divisible_by(&g) {
+            return None;
+        }
+        target.div_exact_assign(&g);
+        m.div_exact_assign(&g);
+        c = (c % &m).mod_mul((&base).div_exact(&g) % &m, &m);
+        base %= &m;
+        offset += 1;
+    }
+    // `c` is now coprime to `m`.
+    let target = target.mod_mul(c.mod_inverse(&m).unwrap(), &m);
+    Some(mod_discrete_log_coprime(&base, &target, &m)? + Natural::from(offset))
+}
+
+impl Natural {
+    /// Finds the discrete logarithm of a [`Natural`] with respect to a base, modulo another
+    /// [`Natural`] $m$, given the prime factorization of a multiple of the order of the base. The
+    /// base and the target must be already reduced modulo $m$, and the base must be coprime to
+    /// $m$. All three [`Natural`]s are taken by reference.
+    ///
+    /// Returns the smallest $k$ such that $b^k \equiv x \mod m$, where $b$ is `self` and $x$ is
+    /// `target`, or `None` if there is no such $k$.
+    ///
+    /// `order_factors` is a list of distinct primes and their exponents, whose product $N$
+    /// satisfies $b^N \equiv 1 \mod m$; for example, the factorization of $p - 1$ when $m$ is a
+    /// prime $p$, or that of the order of a subgroup containing $b$. Unlike
+    /// [`mod_discrete_log`](ModDiscreteLog::mod_discrete_log), neither $m$ nor $\lambda(m)$ is
+    /// factored, so this works for large moduli whenever the group order is known and has no
+    /// large prime factors. The Pohlig–Hellman algorithm is used, with baby-step giant-step or
+    /// Pollard's rho algorithm solving the logarithms in subgroups of prime order.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, q) = O(2^{q/2}n^2 + n^3)$
+    ///
+    /// $M(n, q) = O(2^{q/2}n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `m.significant_bits()`, and $q$ is the
+    /// number of bits of the largest prime in `order_factors`.
+    ///
+    /// # Panics
+    /// Panics if `self` or `target` are greater than or equal to `m`, if `self` is not coprime to
+    /// `m`, or if $b^N \not\equiv 1 \mod m$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_nz::natural::Natural;
+    /// use std::str::FromStr;
+    ///
+    /// // 2 generates the subgroup {1, 2, 4} of order 3
+    /// assert_eq!(
+    ///     Natural::from(2u32).mod_discrete_log_with_order(
+    ///         &Natural::from(4u32),
+    ///         &Natural::from(7u32),
+    ///         &[(Natural::from(3u32), 1)]
+    ///     ),
+    ///     Some(Natural::from(2u32))
+    /// );
+    /// assert_eq!(
+    ///     Natural::from(2u32).mod_discrete_log_with_order(
+    ///         &Natural::from(3u32),
+    ///         &Natural::from(7u32),
+    ///         &[(Natural::from(3u32), 1)]
+    ///     ),
+    ///     None
+    /// );
+    ///
+    /// let p = Natural::from(2u32).pow(89) - Natural::ONE;
+    /// let order_factors: Vec<(Natural, u64)> = [
+    ///     2u64, 3, 5, 17, 23, 89, 353, 397, 683, 2113, 2931542417,
+    /// ]
+    /// .iter()
+    /// .map(|&q| (Natural::from(q), 1))
+    /// .collect();
+    /// assert_eq!(
+    ///     Natural::from(3u32)
+    ///         .mod_discrete_log_with_order(
+    ///             &Natural::from_str("10000000000000000000000000").unwrap(),
+    ///             &p,
+    ///             &order_factors
+    ///         )
+    ///         .unwrap()
+    ///         .to_string(),
+    ///     "299832291162806541314929680"
+    /// );
+    /// ```
+    pub fn mod_discrete_log_with_order(
+        &self,
+        target: &Natural,
+        m: &Natural,
+        order_factors: &[(Natural, u64)],
+    ) -> Option<Natural> {
+        assert!(*self < *m, "base must be reduced mod m, but {self} >= {m}");
+        assert!(
+            *target < *m,
+            "target must be reduced mod m, but {target} >= {m}"
+        );
+        assert_eq!(self.gcd(m), 1u32, "base must be coprime to m");
+        if *m == 1u32 {
+            return Some(Natural::ZERO);
+        }
+        let mut order = Natural::ONE;
+        for (q, e) in order_factors {
+            order *= q.pow(*e);
+        }
+        assert_eq!(
+            self.mod_pow(&order, m),
+            1u32,
+            "the order of the base must divide the product of order_factors"
+        );
+        mod_discrete_log_coprime_with_order(self, target, m, order_factors)
+    }
+}
+
+impl ModDiscreteLog<Natural, Natural> for Natural {
+    type Output = Natural;
+
+    /// Finds the discrete logarithm of a [`Natural`] with respect to a base, modulo another
+    /// [`Natural`] $m$. The base and the target must be already reduced modulo $m$. All three
+    /// [`Natural`]s are taken by value.
+    ///
+    /// Returns the smallest $k$ such that $b^k \equiv x \mod m$, where $b$ is `self` and $x$ is
+    /// `target`, or `None` if there is no such $k$.
+    ///
+    /// The modulus does not need to be prime, and the base does not need to be coprime to it. The
+    /// Pohlig–Hellman algorithm is used, with baby-step giant-step or Pollard's rho algorithm
+    /// solving the logarithms in subgroups of prime order. The modulus and the Carmichael function
+    /// of the modulus are factored, so this is only practical when $\lambda(m)$ has no large prime
+    /// factors; the cost of each subgroup logarithm grows with the square root of its order.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(2^{n/2}n^2)$
+    ///
+    /// $M(n) = O(2^{n/4}n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` or `target` are greater than or equal to `m`.
+    ///
+    /// # Examples
+    /// See [here](super::mod_discrete_log#mod_discrete_log).
+    #[inline]
+    fn mod_discrete_log(self, target: Natural, m: Natural) -> Option<Natural> {
+        mod_discrete_log(self, target, m)
+    }
+}
+
+impl ModDiscreteLog<&Natural, &Natural> for Natural {
+    type Output = Natural;
+
+    /// Finds the discrete logarithm of a [`Natural`] with respect to a base, modulo another
+    /// [`Natural`] $m$. The base and the target must be already reduced modulo $m$. The first
+    /// [`Natural`] is taken by value and the second and third by reference.
+    ///
+    /// Returns the smallest $k$ such that $b^k \equiv x \mod m$, where $b$ is `self` and $x$ is
+    /// `target`, or `None` if there is no such $k$.
+    ///
+    /// The modulus does not need to be prime, and the base does not need to be coprime to it. The
+    /// Pohlig–Hellman algorithm is used, with baby-step giant-step or Pollard's rho algorithm
+    /// solving the logarithms in subgroups of prime order. The modulus and the Carmichael function
+    /// of the modulus are factored, so this is only practical when $\lambda(m)$ has no large prime
+    /// factors; the cost of each subgroup logarithm grows with the square root of its order.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(2^{n/2}n^2)$
+    ///
+    /// $M(n) = O(2^{n/4}n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` or `target` are greater than or equal to `m`.
+    ///
+    /// # Examples
+    /// See [here](super::mod_discrete_log#mod_discrete_log).
+    #[inline]
+    fn mod_discrete_log(self, target: &Natural, m: &Natural) -> Option<Natural> {
+        mod_discrete_log(self, target.clone(), m.clone())
+    }
+}
+
+impl ModDiscreteLog<Natural, Natural> for &Natural {
+    type Output = Natural;
+
+    /// Finds the discrete logarithm of a [`Natural`] with respect to a base, modulo another
+    /// [`Natural`] $m$. The base and the target must be already reduced modulo $m$. The first
+    /// [`Natural`] is taken by reference and the second and third by value.
+    ///
+    /// Returns the smallest $k$ such that $b^k \equiv x \mod m$, where $b$ is `self` and $x$ is
+    /// `target`, or `None` if there is no such $k$.
+    ///
+    /// The modulus does not need to be prime, and the base does not need to be coprime to it. The
+    /// Pohlig–Hellman algorithm is used, with baby-step giant-step or Pollard's rho algorithm
+    /// solving the logarithms in subgroups of prime order. The modulus and the Carmichael function
+    /// of the modulus are factored, so this is only practical when $\lambda(m)$ has no large prime
+    /// factors; the cost of each subgroup logarithm grows with the square root of its order.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(2^{n/2}n^2)$
+    ///
+    /// $M(n) = O(2^{n/4}n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` or `target` are greater than or equal to `m`.
+    ///
+    /// # Examples
+    /// See [here](super::mod_discrete_log#mod_discrete_log).
+    #[inline]
+    fn mod_discrete_log(self, target: Natural, m: Natural) -> Option<Natural> {
+        mod_discrete_log(self.clone(), target, m)
+    }
+}
+
+impl ModDiscreteLog<&Natural, &Natural> for &Natural {
+    type Output = Natural;
+
+    /// Finds the discrete logarithm of a [`Natural`] with respect to a base, modulo another
+    /// [`Natural`] $m$. The base and the target must be already reduced modulo $m$. All three
+    /// [`Natural`]s are taken by reference.
+    ///
+    /// Returns the smallest $k$ such that $b^k \equiv x \mod m$, where $b$ is `self` and $x$ is
+    /// `target`, or `None` if there is no such $k$.
+    ///
+    /// The modulus does not need to be prime, and the base does not need to be coprime to it. The
+    /// Pohlig–Hellman algorithm is used, with baby-step giant-step or Pollard's rho algorithm
+    /// solving the logarithms in subgroups of prime order. The modulus and the Carmichael function
+    /// of the modulus are factored, so this is only practical when $\lambda(m)$ has no large prime
+    /// factors; the cost of each subgroup logarithm grows with the square root of its order.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(2^{n/2}n^2)$
+    ///
+    /// $M(n) = O(2^{n/4}n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` or `target` are greater than or equal to `m`.
+    ///
+    /// # Examples
+    /// See [here](super::mod_discrete_log#mod_discrete_log).
+    #[inline]
+    fn mod_discrete_log(self, target: &Natural, m: &Natural) -> Option<Natural> {
+        mod_discrete_log(self.clone(), target.clone(), m.clone())
+    }
+}
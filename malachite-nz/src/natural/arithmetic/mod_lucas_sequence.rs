@@ -0,0 +1,284 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{ModLucasSequence, ModMul, ModSub};
+use malachite_base::num::basic::traits::{One, Two, Zero};
+use malachite_base::num::logic::traits::{BitAccess, SignificantBits};
+
+fn mod_lucas_sequence(p: &Natural, q: &Natural, k: &Natural, m: &Natural) -> (Natural, Natural) {
+    assert!(p < m, "p must be reduced mod m, but {p} >= {m}");
+    assert!(q < m, "q must be reduced mod m, but {q} >= {m}");
+    if *m == 1u32 {
+        return (Natural::ZERO, Natural::ZERO);
+    }
+    let two = Natural::TWO % m;
+    // Maintain $a = U_j$ and $b = U_{j+1}$, where $j$ is the prefix of `k` processed so far. The
+    // doubling formulas $U_{2j} = U_j(2U_{j+1} - PU_j)$ and $U_{2j+1} = U_{j+1}^2 - QU_j^2$ avoid
+    // any division, so `m` may be even.
+    let mut a = Natural::ZERO;
+    let mut b = Natural::ONE;
+    for i in (0..k.significant_bits()).rev() {
+        let u_2j = (&a).mod_mul((&two).mod_mul(&b, m).mod_sub(p.mod_mul(&a, m), m), m);
+        let u_2j_1 = (&b)
+            .mod_mul(&b, m)
+            .mod_sub(q.mod_mul((&a).mod_mul(&a, m), m), m);
+        if k.get_bit(i) {
+            b = p.mod_mul(&u_2j_1, m).mod_sub(q.mod_mul(u_2j, m), m);
+            a = u_2j_1;
+        } else {
+            a = u_2j;
+            b = u_2j_1;
+        }
+    }
+    // $V_k = 2U_{k+1} - PU_k$.
+    let v = two.mod_mul(b, m).mod_sub(p.mod_mul(&a, m), m);
+    (a, v)
+}
+
+impl ModLucasSequence<Natural, Natural, Natural> for Natural {
+    type Output = Natural;
+
+    /// Computes the $k$th terms of the Lucas sequences $U(P, Q)$ and $V(P, Q)$ modulo $m$. $P$ and
+    /// $Q$ must be already reduced modulo $m$. All four [`Natural`]s are taken by value.
+    ///
+    /// The sequences are defined by $U_0 = 0$, $U_1 = 1$, $V_0 = 2$, $V_1 = P$, and $X_n =
+    /// PX_{n-1} - QX_{n-2}$. The Fibonacci and Lucas numbers are $U(1, -1)$ and $V(1, -1)$.
+    ///
+    /// $f(P, Q, k, m) = (u, v)$, where $u, v < m$, $U_k \equiv u \mod m$, and $V_k \equiv v \mod
+    /// m$.
+    ///
+    /// The modulus does not need to be odd.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `m.significant_bits()`, and $m$ is
+    /// `k.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` or `q` are greater than or equal to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::str::FromStr;
+    /// use malachite_base::num::arithmetic::traits::ModLucasSequence;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// // The Fibonacci and Lucas numbers are $U(1, -1)$ and $V(1, -1)$
+    /// assert_eq!(
+    ///     Natural::from(1u32)
+    ///         .mod_lucas_sequence(
+    ///             Natural::from(999u32),
+    ///             Natural::from(10u32),
+    ///             Natural::from(1000u32)
+    ///         )
+    ///         .to_debug_string(),
+    ///     "(55, 123)"
+    /// );
+    /// assert_eq!(
+    ///     Natural::from(4u32)
+    ///         .mod_lucas_sequence(
+    ///             Natural::from(1u32),
+    ///             Natural::from_str("1000000000000000000000").unwrap(),
+    ///             Natural::from_str("1000000000000000000000000000057").unwrap()
+    ///         )
+    ///         .to_debug_string(),
+    ///     "(709785189097611682225478504000, 254427577138153117228620988197)"
+    /// );
+    /// ```
+    #[inline]
+    fn mod_lucas_sequence(self, q: Natural, k: Natural, m: Natural) -> (Natural, Natural) {
+        mod_lucas_sequence(&self, &q, &k, &m)
+    }
+}
+
+impl ModLucasSequence<&Natural, &Natural, &Natural> for Natural {
+    type Output = Natural;
+
+    /// Computes the $k$th terms of the Lucas sequences $U(P, Q)$ and $V(P, Q)$ modulo $m$. $P$ and
+    /// $Q$ must be already reduced modulo $m$. The first [`Natural`] is taken by value and the
+    /// other three by reference.
+    ///
+    /// The sequences are defined by $U_0 = 0$, $U_1 = 1$, $V_0 = 2$, $V_1 = P$, and $X_n =
+    /// PX_{n-1} - QX_{n-2}$. The Fibonacci and Lucas numbers are $U(1, -1)$ and $V(1, -1)$.
+    ///
+    /// $f(P, Q, k, m) = (u, v)$, where $u, v < m$, $U_k \equiv u \mod m$, and $V_k \equiv v \mod
+    /// m$.
+    ///
+    /// The modulus does not need to be odd.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `m.significant_bits()`, and $m$ is
+    /// `k.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` or `q` are greater than or equal to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::str::FromStr;
+    /// use malachite_base::num::arithmetic::traits::ModLucasSequence;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// // The Fibonacci and Lucas numbers are $U(1, -1)$ and $V(1, -1)$
+    /// assert_eq!(
+    ///     Natural::from(1u32)
+    ///         .mod_lucas_sequence(
+    ///             &Natural::from(999u32),
+    ///             &Natural::from(10u32),
+    ///             &Natural::from(1000u32)
+    ///         )
+    ///         .to_debug_string(),
+    ///     "(55, 123)"
+    /// );
+    /// assert_eq!(
+    ///     Natural::from(4u32)
+    ///         .mod_lucas_sequence(
+    ///             &Natural::from(1u32),
+    ///             &Natural::from_str("1000000000000000000000").unwrap(),
+    ///             &Natural::from_str("1000000000000000000000000000057").unwrap()
+    ///         )
+    ///         .to_debug_string(),
+    ///     "(709785189097611682225478504000, 254427577138153117228620988197)"
+    /// );
+    /// ```
+    #[inline]
+    fn mod_lucas_sequence(self, q: &Natural, k: &Natural, m: &Natural) -> (Natural, Natural) {
+        mod_lucas_sequence(&self, q, k, m)
+    }
+}
+
+impl ModLucasSequence<Natural, Natural, Natural> for &Natural {
+    type Output = Natural;
+
+    /// Computes the $k$th terms of the Lucas sequences $U(P, Q)$ and $V(P, Q)$ modulo $m$. $P$ and
+    /// $Q$ must be already reduced modulo $m$. The first [`Natural`] is taken by reference and the
+    /// other three by value.
+    ///
+    /// The sequences are defined by $U_0 = 0$, $U_1 = 1$, $V_0 = 2$, $V_1 = P$, and $X_n =
+    /// PX_{n-1} - QX_{n-2}$. The Fibonacci and Lucas numbers are $U(1, -1)$ and $V(1, -1)$.
+    ///
+    /// $f(P, Q, k, m) = (u, v)$, where $u, v < m$, $U_k \equiv u \mod m$, and $V_k \equiv v \mod
+    /// m$.
+    ///
+    /// The modulus does not need to be odd.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `m.significant_bits()`, and $m$ is
+    /// `k.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` or `q` are greater than or equal to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::str::FromStr;
+    /// use malachite_base::num::arithmetic::traits::ModLucasSequence;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// // The Fibonacci and Lucas numbers are $U(1, -1)$ and $V(1, -1)$
+    /// assert_eq!(
+    ///     (&Natural::from(1u32))
+    ///         .mod_lucas_sequence(
+    ///             Natural::from(999u32),
+    ///             Natural::from(10u32),
+    ///             Natural::from(1000u32)
+    ///         )
+    ///         .to_debug_string(),
+    ///     "(55, 123)"
+    /// );
+    /// assert_eq!(
+    ///     (&Natural::from(4u32))
+    ///         .mod_lucas_sequence(
+    ///             Natural::from(1u32),
+    ///             Natural::from_str("1000000000000000000000").unwrap(),
+    ///             Natural::from_str("1000000000000000000000000000057").unwrap()
+    ///         )
+    ///         .to_debug_string(),
+    ///     "(709785189097611682225478504000, 254427577138153117228620988197)"
+    /// );
+    /// ```
+    #[inline]
+    fn mod_lucas_sequence(self, q: Natural, k: Natural, m: Natural) -> (Natural, Natural) {
+        mod_lucas_sequence(self, &q, &k, &m)
+    }
+}
+
+impl ModLucasSequence<&Natural, &Natural, &Natural> for &Natural {
+    type Output = Natural;
+
+    /// Computes the $k$th terms of the Lucas sequences $U(P, Q)$ and $V(P, Q)$ modulo $m$. $P$ and
+    /// $Q$ must be already reduced modulo $m$. All four [`Natural`]s are taken by reference.
+    ///
+    /// The sequences are defined by $U_0 = 0$, $U_1 = 1$, $V_0 = 2$, $V_1 = P$, and $X_n =
+    /// PX_{n-1} - QX_{n-2}$. The Fibonacci and Lucas numbers are $U(1, -1)$ and $V(1, -1)$.
+    ///
+    /// $f(P, Q, k, m) = (u, v)$, where $u, v < m$, $U_k \equiv u \mod m$, and $V_k \equiv v \mod
+    /// m$.
+    ///
+    /// The modulus does not need to be odd.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `m.significant_bits()`, and $m$ is
+    /// `k.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` or `q` are greater than or equal to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::str::FromStr;
+    /// use malachite_base::num::arithmetic::traits::ModLucasSequence;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// // $U(3, 2)_k = 2^k - 1$ and $V(3, 2)_k = 2^k + 1$
+    /// assert_eq!(
+    ///     (&Natural::from(3u32))
+    ///         .mod_lucas_sequence(
+    ///             &Natural::from(2u32),
+    ///             &Natural::from(10u32),
+    ///             &Natural::from(1000u32)
+    ///         )
+    ///         .to_debug_string(),
+    ///     "(23, 25)"
+    /// );
+    /// assert_eq!(
+    ///     (&Natural::from(4u32))
+    ///         .mod_lucas_sequence(
+    ///             &Natural::from(1u32),
+    ///             &Natural::from_str("1000000000000000000000").unwrap(),
+    ///             &Natural::from_str("1000000000000000000000000000057").unwrap()
+    ///         )
+    ///         .to_debug_string(),
+    ///     "(709785189097611682225478504000, 254427577138153117228620988197)"
+    /// );
+    /// ```
+    #[inline]
+    fn mod_lucas_sequence(self, q: &Natural, k: &Natural, m: &Natural) -> (Natural, Natural) {
+        mod_lucas_sequence(self, q, k, m)
+    }
+}
@@ -117,7 +117,7 @@ fn limbs_redc_limb_raw(out: &mut [Limb], xs: &mut [Limb], ms: &[Limb], m_inv: Li
 //
 // This is equivalent to `MPN_REDC_1` from `mpn/generic/powm.c`, GMP 6.2.1. Investigate changes from
 // 6.1.2?
-fn limbs_redc_limb(out: &mut [Limb], xs: &mut [Limb], ms: &[Limb], m_inv: Limb) {
+pub(crate) fn limbs_redc_limb(out: &mut [Limb], xs: &mut [Limb], ms: &[Limb], m_inv: Limb) {
     if limbs_redc_limb_raw(out, xs, ms, m_inv) {
         limbs_sub_same_length_in_place_left(&mut out[..ms.len()], ms);
     }
@@ -148,12 +148,38 @@ pub(crate) fn get_window_size(width: u64) -> u64 {
 // where $T$ is time, $M$ is additional memory, and $n$ is `ms.len()`.
 //
 // This is equivalent to `mpn_redc_n` from `mpn/generic/redc_n.c`, GMP 6.2.1.
-fn limbs_redc(out: &mut [Limb], xs: &[Limb], ms: &[Limb], is: &[Limb]) {
+pub(crate) fn limbs_redc(out: &mut [Limb], xs: &[Limb], ms: &[Limb], is: &[Limb]) {
+    let mut scratch = vec![0; limbs_redc_scratch_len(ms.len())];
+    limbs_redc_with_scratch(out, xs, ms, is, &mut scratch);
+}
+
+// # Worst-case complexity
+// Constant time and additional memory.
+pub(crate) fn limbs_redc_scratch_len(ms_len: usize) -> usize {
+    let n = limbs_mul_mod_base_pow_n_minus_1_next_size(ms_len);
+    limbs_mul_mod_base_pow_n_minus_1_scratch_len(n, ms_len, ms_len) + ms_len + n
+}
+
+// Like `limbs_redc`, but uses caller-provided scratch space, which must have length at least
+// `limbs_redc_scratch_len(ms.len())`.
+//
+// # Worst-case complexity
+// $T(n) = O(n \log n \log\log n)$
+//
+// $M(n) = O(1)$
+//
+// where $T$ is time, $M$ is additional memory, and $n$ is `ms.len()`.
+pub(crate) fn limbs_redc_with_scratch(
+    out: &mut [Limb],
+    xs: &[Limb],
+    ms: &[Limb],
+    is: &[Limb],
+    scratch: &mut [Limb],
+) {
     let ms_len = ms.len();
     assert!(ms_len > 8);
     let n = limbs_mul_mod_base_pow_n_minus_1_next_size(ms_len);
-    let mut scratch =
-        vec![0; limbs_mul_mod_base_pow_n_minus_1_scratch_len(n, ms_len, ms_len) + ms_len + n];
+    let scratch = &mut scratch[..limbs_redc_scratch_len(ms_len)];
     let (scratch_0, scratch) = scratch.split_at_mut(ms_len);
     limbs_mul_low_same_length(scratch_0, &xs[..ms_len], &is[..ms_len]);
     let (scratch_1, scratch_2) = scratch.split_at_mut(n);
@@ -197,7 +223,7 @@ fn to_redc(out: &mut [Limb], xs: &[Limb], ms: &[Limb]) {
 }
 
 // TODO tune
-const REDC_1_TO_REDC_N_THRESHOLD: usize = 100;
+pub(crate) const REDC_1_TO_REDC_N_THRESHOLD: usize = 100;
 
 // # Worst-case complexity
 // Constant time and additional memory.
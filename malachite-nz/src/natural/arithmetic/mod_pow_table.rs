@@ -0,0 +1,579 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::natural::arithmetic::mod_pow::{get_bits, get_window_size};
+use crate::natural::arithmetic::mod_power_of_2::limbs_slice_mod_power_of_2_in_place;
+use crate::natural::arithmetic::montgomery::{ModularRing, MontgomeryScratch};
+use crate::natural::arithmetic::mul::{
+    limbs_mul_same_length_to_out, limbs_mul_same_length_to_out_scratch_len,
+};
+use crate::natural::arithmetic::square::{limbs_square_to_out, limbs_square_to_out_scratch_len};
+use crate::natural::Natural;
+use crate::platform::Limb;
+use alloc::vec::Vec;
+use core::cmp::{max, min};
+use core::mem::swap;
+use malachite_base::num::arithmetic::traits::{
+    ModInverse, ModMul, ModPow, ModPowerOf2, ModSub, PowerOf2, ShrRound,
+};
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::*;
+
+// The largest window used by `ModPowTable`. A table for $b$-bit exponents holds about $(2^w -
+// 1)b/w$ residues, so larger windows quickly cost more memory than they save time.
+const MAX_TABLE_WINDOW: u64 = 4;
+
+// Residue arithmetic modulo a fixed modulus $m = 2^sm'$, where $m'$ is odd. A residue is stored as
+// its residue modulo $m'$, in Montgomery form, followed by its residue modulo $2^s$, so every
+// residue has the same number of limbs and can be multiplied in place. The two parts are only
+// combined, using the Chinese remainder theorem, when a residue is converted back to a
+// [`Natural`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct ResidueArithmetic {
+    modulus: Natural,
+    // The ring of integers modulo $m'$.
+    ring: ModularRing,
+    // The number of limbs of the part modulo $m'$.
+    odd_len: usize,
+    // $s$.
+    pow: u64,
+    // $2^{-s} \mod m'$, or 0 if $m' = 1$.
+    inverse: Natural,
+}
+
+// Buffers for multiplying residues of a `ResidueArithmetic`.
+struct ResidueScratch {
+    montgomery: MontgomeryScratch,
+    // Holds the product of two parts modulo $2^s$ before it is truncated.
+    product: Vec<Limb>,
+    // Scratch space for multiplying and squaring the parts modulo $2^s$.
+    scratch: Vec<Limb>,
+}
+
+impl ResidueArithmetic {
+    fn new(m: Natural) -> ResidueArithmetic {
+        let pow = m.trailing_zeros().unwrap();
+        let odd = &m >> pow;
+        let inverse = if odd == 1u32 {
+            Natural::ZERO
+        } else {
+            (Natural::power_of_2(pow) % &odd).mod_inverse(&odd).unwrap()
+        };
+        let ring = ModularRing::new(odd);
+        ResidueArithmetic {
+            modulus: m,
+            odd_len: ring.one_limbs().len(),
+            ring,
+            pow,
+            inverse,
+        }
+    }
+
+    // The number of limbs of the part modulo $2^s$.
+    fn even_len(&self) -> usize {
+        usize::exact_from(self.pow.shr_round(Limb::LOG_WIDTH, Ceiling).0)
+    }
+
+    // The number of limbs of a residue.
+    fn len(&self) -> usize {
+        self.odd_len + self.even_len()
+    }
+
+    fn new_scratch(&self) -> ResidueScratch {
+        let n = self.even_len();
+        let (product, scratch) = if n == 0 {
+            (Vec::new(), Vec::new())
+        } else {
+            let scratch_len = max(
+                limbs_mul_same_length_to_out_scratch_len(n),
+                limbs_square_to_out_scratch_len(n),
+            );
+            (vec![0; n << 1], vec![0; scratch_len])
+        };
+        ResidueScratch {
+            montgomery: self.ring.new_scratch(),
+            product,
+            scratch,
+        }
+    }
+
+    fn to_residue(&self, x: &Natural) -> Vec<Limb> {
+        let mut xs = self.ring.to_montgomery_limbs(x);
+        xs.extend_from_slice(&x.mod_power_of_2(self.pow).into_limbs_asc());
+        xs.resize(self.len(), 0);
+        xs
+    }
+
+    fn residue_to_natural(&self, xs: &[Limb]) -> Natural {
+        let (odd_xs, even_xs) = xs.split_at(self.odd_len);
+        let a = self.ring.montgomery_limbs_to_natural(odd_xs);
+        if self.pow == 0 {
+            return a;
+        }
+        let odd = self.ring.modulus();
+        let b = Natural::from_limbs_asc(even_xs);
+        // $x = b + 2^s((a - b)2^{-s} \mod m')$
+        (a.mod_sub(&b % odd, odd).mod_mul(&self.inverse, odd) << self.pow) + b
+    }
+
+    fn one(&self) -> Vec<Limb> {
+        let mut xs = self.ring.one_limbs().to_vec();
+        xs.resize(self.len(), 0);
+        if self.pow != 0 {
+            xs[self.odd_len] = 1;
+        }
+        xs
+    }
+
+    fn mul_assign(&self, xs: &mut [Limb], ys: &[Limb], scratch: &mut ResidueScratch) {
+        let (odd_xs, even_xs) = xs.split_at_mut(self.odd_len);
+        let (odd_ys, even_ys) = ys.split_at(self.odd_len);
+        self.ring
+            .mul_limbs_assign(odd_xs, odd_ys, &mut scratch.montgomery);
+        if self.pow != 0 {
+            limbs_mul_same_length_to_out(
+                &mut scratch.product,
+                even_xs,
+                even_ys,
+                &mut scratch.scratch,
+            );
+            even_xs.copy_from_slice(&scratch.product[..even_xs.len()]);
+            limbs_slice_mod_power_of_2_in_place(even_xs, self.pow);
+        }
+    }
+
+    fn square_assign(&self, xs: &mut [Limb], scratch: &mut ResidueScratch) {
+        let (odd_xs, even_xs) = xs.split_at_mut(self.odd_len);
+        self.ring
+            .square_limbs_assign(odd_xs, &mut scratch.montgomery);
+        if self.pow != 0 {
+            limbs_square_to_out(&mut scratch.product, even_xs, &mut scratch.scratch);
+            even_xs.copy_from_slice(&scratch.product[..even_xs.len()]);
+            limbs_slice_mod_power_of_2_in_place(even_xs, self.pow);
+        }
+    }
+}
+
+// Splits `exp` into `count` digits of `width` bits each, least significant first.
+fn exponent_digits(exp: &Natural, width: u64, count: u64) -> Vec<usize> {
+    let mut es = exp.to_limbs_asc();
+    es.resize(usize::exact_from((width * count) >> Limb::LOG_WIDTH) + 2, 0);
+    (1..=count)
+        .map(|i| get_bits(&es, i * width, width))
+        .collect()
+}
+
+/// A table of powers of a fixed base modulo a fixed [`Natural`] $m$, which makes repeatedly
+/// raising the base to different powers faster than calling
+/// [`mod_pow`](malachite_base::num::arithmetic::traits::ModPow::mod_pow) each time.
+///
+/// The table uses fixed-base windowing. With a window of $w$ bits, it stores $g^{d2^{wi}}$ for
+/// every $w$-bit digit $d$ and every window position $i$, so that $g^e$ is the product of one
+/// table entry per nonzero $w$-bit digit of $e$. No squarings are needed, and a $b$-bit exponent
+/// costs about $b/w$ modular multiplications, compared to about $b$ squarings and $b/w$
+/// multiplications for ordinary windowed exponentiation. The table holds about $(2^w - 1)b/w$
+/// residues, where $b$ is the largest exponent size that the table was built for.
+///
+/// Residues modulo the odd part of the modulus are kept in Montgomery form, as
+/// [`ModularRing`](crate::natural::arithmetic::montgomery::ModularRing) does, and residues modulo
+/// the largest power of 2 dividing the modulus are kept separately. All residues have the same
+/// size, so computing a power multiplies in place and doesn't allocate per multiplication.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ModPowTable {
+    base: Natural,
+    arithmetic: ResidueArithmetic,
+    window: u64,
+    max_exp_bits: u64,
+    // The residues, each `arithmetic.len()` limbs long. The entry at index $(2^w - 1)i + d - 1$ is
+    // $g^{d2^{wi}}$.
+    powers: Vec<Limb>,
+}
+
+impl ModPowTable {
+    /// Creates a table for raising `base` to powers modulo `m`, for exponents with at most
+    /// `max_exp_bits` significant bits. Larger exponents are still accepted by
+    /// [`ModPowTable::pow`], but they do not benefit from the table.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, b) = O(bn \log n \log\log n)$
+    ///
+    /// $M(n, b) = O(bn)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `m.significant_bits()`, and $b$ is
+    /// `max_exp_bits`.
+    ///
+    /// # Panics
+    /// Panics if `base` is greater than or equal to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::mod_pow_table::ModPowTable;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let table = ModPowTable::new(Natural::from(4u32), Natural::from(497u32), 64);
+    /// assert_eq!(table.base(), &4u32);
+    /// assert_eq!(table.modulus(), &497u32);
+    /// assert_eq!(table.max_exp_bits(), 64);
+    /// ```
+    pub fn new(base: Natural, m: Natural, max_exp_bits: u64) -> ModPowTable {
+        assert!(base < m, "base must be reduced mod m, but {base} >= {m}");
+        let window = min(get_window_size(max_exp_bits), MAX_TABLE_WINDOW);
+        let digits_per_window = usize::exact_from(u64::power_of_2(window) - 1);
+        let windows = max_exp_bits.div_ceil(window);
+        let arithmetic = ResidueArithmetic::new(m);
+        let len = arithmetic.len();
+        let mut scratch = arithmetic.new_scratch();
+        let mut powers = vec![0; usize::exact_from(windows) * digits_per_window * len];
+        let mut g = arithmetic.to_residue(&base);
+        let mut next_g = vec![0; len];
+        for window_powers in powers.chunks_mut(digits_per_window * len) {
+            window_powers[..len].copy_from_slice(&g);
+            for i in 1..digits_per_window {
+                let (previous, next) = window_powers.split_at_mut(i * len);
+                next[..len].copy_from_slice(&previous[(i - 1) * len..]);
+                arithmetic.mul_assign(&mut next[..len], &g, &mut scratch);
+            }
+            // $g^{2^w} = g^{2^w - 1}g$
+            next_g.copy_from_slice(&window_powers[(digits_per_window - 1) * len..]);
+            arithmetic.mul_assign(&mut next_g, &g, &mut scratch);
+            swap(&mut g, &mut next_g);
+        }
+        ModPowTable {
+            base,
+            arithmetic,
+            window,
+            max_exp_bits,
+            powers,
+        }
+    }
+
+    /// Returns the base of a [`ModPowTable`].
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::mod_pow_table::ModPowTable;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let table = ModPowTable::new(Natural::from(4u32), Natural::from(497u32), 64);
+    /// assert_eq!(table.base(), &4u32);
+    /// ```
+    #[inline]
+    pub const fn base(&self) -> &Natural {
+        &self.base
+    }
+
+    /// Returns the modulus of a [`ModPowTable`].
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::mod_pow_table::ModPowTable;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let table = ModPowTable::new(Natural::from(4u32), Natural::from(497u32), 64);
+    /// assert_eq!(table.modulus(), &497u32);
+    /// ```
+    #[inline]
+    pub const fn modulus(&self) -> &Natural {
+        &self.arithmetic.modulus
+    }
+
+    /// Returns the largest number of significant bits of an exponent that a [`ModPowTable`] was
+    /// built for.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::mod_pow_table::ModPowTable;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let table = ModPowTable::new(Natural::from(4u32), Natural::from(497u32), 64);
+    /// assert_eq!(table.max_exp_bits(), 64);
+    /// ```
+    #[inline]
+    pub const fn max_exp_bits(&self) -> u64 {
+        self.max_exp_bits
+    }
+
+    /// Raises the base of a [`ModPowTable`] to a [`Natural`] power modulo the table's modulus.
+    ///
+    /// $f(e) = y$, where $y < m$ and $g^e \equiv y \mod m$.
+    ///
+    /// If `exp` has more significant bits than the table was built for, ordinary modular
+    /// exponentiation is used instead.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, b) = O(bn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `self.modulus().significant_bits()`,
+    /// and $b$ is `exp.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_nz::natural::arithmetic::mod_pow_table::ModPowTable;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let table = ModPowTable::new(Natural::from(4u32), Natural::from(497u32), 64);
+    /// assert_eq!(table.pow(&Natural::from(13u32)), 445);
+    /// assert_eq!(table.pow(&Natural::ZERO), 1);
+    /// // Exponents larger than the table was built for are still supported.
+    /// assert_eq!(table.pow(&Natural::from(10u32).pow(100)), 179);
+    ///
+    /// let table = ModPowTable::new(Natural::from(10u32), Natural::from(30u32), 16);
+    /// assert_eq!(table.pow(&Natural::from(1000u32)), 10);
+    /// ```
+    pub fn pow(&self, exp: &Natural) -> Natural {
+        let bits = exp.significant_bits();
+        if bits > self.max_exp_bits {
+            return (&self.base).mod_pow(exp, self.modulus());
+        }
+        let digits_per_window = usize::exact_from(u64::power_of_2(self.window) - 1);
+        let windows = bits.div_ceil(self.window);
+        let len = self.arithmetic.len();
+        let mut scratch = self.arithmetic.new_scratch();
+        let mut result = self.arithmetic.one();
+        let mut started = false;
+        for (i, d) in exponent_digits(exp, self.window, windows)
+            .into_iter()
+            .enumerate()
+        {
+            if d == 0 {
+                continue;
+            }
+            let start = (i * digits_per_window + d - 1) * len;
+            let power = &self.powers[start..start + len];
+            if started {
+                self.arithmetic.mul_assign(&mut result, power, &mut scratch);
+            } else {
+                result.copy_from_slice(power);
+                started = true;
+            }
+        }
+        self.arithmetic.residue_to_natural(&result)
+    }
+}
+
+// The number of modular multiplications needed by Straus's method with a window of $w$ bits, not
+// counting the squarings that every method needs.
+fn straus_cost(k: u64, bits: u64, w: u64) -> u64 {
+    k * (u64::power_of_2(w) - 2) + k * bits.div_ceil(w)
+}
+
+// The number of modular multiplications needed by Pippenger's method with a window of $c$ bits,
+// not counting the squarings that every method needs.
+fn pippenger_cost(k: u64, bits: u64, c: u64) -> u64 {
+    bits.div_ceil(c) * (k + u64::power_of_2(c + 1))
+}
+
+// Straus's method: all bases share a single chain of squarings, and each base contributes one
+// multiplication per nonzero $w$-bit digit of its exponent, using a table of its first $2^w - 1$
+// powers.
+fn mod_multi_pow_straus(
+    arithmetic: &ResidueArithmetic,
+    pairs: &[(Natural, Natural)],
+    bits: u64,
+    w: u64,
+) -> Vec<Limb> {
+    let windows = bits.div_ceil(w);
+    let digits_per_window = usize::exact_from(u64::power_of_2(w) - 1);
+    let len = arithmetic.len();
+    let table_len = digits_per_window * len;
+    let mut scratch = arithmetic.new_scratch();
+    // The table of each base holds its first $2^w - 1$ powers, and the tables are stored one after
+    // another.
+    let mut tables = vec![0; pairs.len() * table_len];
+    let mut digits = Vec::with_capacity(pairs.len());
+    for ((base, exp), table) in pairs.iter().zip(tables.chunks_mut(table_len)) {
+        let g = arithmetic.to_residue(base);
+        table[..len].copy_from_slice(&g);
+        for i in 1..digits_per_window {
+            let (previous, next) = table.split_at_mut(i * len);
+            next[..len].copy_from_slice(&previous[(i - 1) * len..]);
+            arithmetic.mul_assign(&mut next[..len], &g, &mut scratch);
+        }
+        digits.push(exponent_digits(exp, w, windows));
+    }
+    let mut result = arithmetic.one();
+    for i in (0..usize::exact_from(windows)).rev() {
+        for _ in 0..w {
+            arithmetic.square_assign(&mut result, &mut scratch);
+        }
+        for (table, digits) in tables.chunks(table_len).zip(digits.iter()) {
+            let d = digits[i];
+            if d != 0 {
+                let start = (d - 1) * len;
+                arithmetic.mul_assign(&mut result, &table[start..start + len], &mut scratch);
+            }
+        }
+    }
+    result
+}
+
+// Pippenger's method: for each window of $c$ bits, the bases are sorted into buckets by their
+// digits, and $\prod_d B_d^d$ is computed with about $2^{c+1}$ multiplications, using running
+// products.
+fn mod_multi_pow_pippenger(
+    arithmetic: &ResidueArithmetic,
+    pairs: &[(Natural, Natural)],
+    bits: u64,
+    c: u64,
+) -> Vec<Limb> {
+    let windows = bits.div_ceil(c);
+    let bucket_count = usize::exact_from(u64::power_of_2(c) - 1);
+    let len = arithmetic.len();
+    let mut scratch = arithmetic.new_scratch();
+    let residues: Vec<Vec<Limb>> = pairs
+        .iter()
+        .map(|(base, _)| arithmetic.to_residue(base))
+        .collect();
+    let digits: Vec<Vec<usize>> = pairs
+        .iter()
+        .map(|(_, exp)| exponent_digits(exp, c, windows))
+        .collect();
+    // These buffers are reused for every window; `filled` records which buckets hold a value in
+    // the current window.
+    let mut buckets = vec![0; bucket_count * len];
+    let mut filled = vec![false; bucket_count];
+    let mut running = vec![0; len];
+    let mut window_product = vec![0; len];
+    let mut result = arithmetic.one();
+    for i in (0..usize::exact_from(windows)).rev() {
+        for _ in 0..c {
+            arithmetic.square_assign(&mut result, &mut scratch);
+        }
+        filled.fill(false);
+        for (x, digits) in residues.iter().zip(digits.iter()) {
+            let d = digits[i];
+            if d != 0 {
+                let bucket = &mut buckets[(d - 1) * len..d * len];
+                if filled[d - 1] {
+                    arithmetic.mul_assign(bucket, x, &mut scratch);
+                } else {
+                    bucket.copy_from_slice(x);
+                    filled[d - 1] = true;
+                }
+            }
+        }
+        // $\prod_d B_d^d = \prod_{j} \prod_{d \geq j} B_d$
+        let mut running_started = false;
+        let mut product_started = false;
+        for (bucket, &filled) in buckets.chunks(len).zip(filled.iter()).rev() {
+            if filled {
+                if running_started {
+                    arithmetic.mul_assign(&mut running, bucket, &mut scratch);
+                } else {
+                    running.copy_from_slice(bucket);
+                    running_started = true;
+                }
+            }
+            if running_started {
+                if product_started {
+                    arithmetic.mul_assign(&mut window_product, &running, &mut scratch);
+                } else {
+                    window_product.copy_from_slice(&running);
+                    product_started = true;
+                }
+            }
+        }
+        if product_started {
+            arithmetic.mul_assign(&mut result, &window_product, &mut scratch);
+        }
+    }
+    result
+}
+
+impl Natural {
+    /// Computes the product of several [`Natural`]s, each raised to a [`Natural`] power, modulo a
+    /// [`Natural`] $m$. The bases must be already reduced modulo $m$.
+    ///
+    /// $f(((x_1, e_1), \ldots, (x_k, e_k)), m) = y$, where $y < m$ and $\prod_{i=1}^k x_i^{e_i}
+    /// \equiv y \mod m$.
+    ///
+    /// All the exponentiations share a single chain of squarings. For a few bases, Straus's
+    /// method is used: each base gets a small table of its powers. For many bases, Pippenger's
+    /// bucket method is used instead, which needs no per-base tables. The method and the window
+    /// size are chosen by estimating the number of multiplications each would need. Either way,
+    /// this is much faster than computing each power separately, which makes it useful for
+    /// verifying many signatures at once.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, k, b) = O((b + kb/\log k) n \log n \log\log n)$
+    ///
+    /// $M(n, k, b) = O(kn + kb)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is `m.significant_bits()`, $k$ is
+    /// `pairs.len()`, and $b$ is the largest number of significant bits of any exponent.
+    ///
+    /// # Panics
+    /// Panics if any base is greater than or equal to `m`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Natural::mod_multi_pow(
+    ///         &[
+    ///             (Natural::from(4u32), Natural::from(13u32)),
+    ///             (Natural::from(10u32), Natural::from(5u32))
+    ///         ],
+    ///         &Natural::from(497u32)
+    ///     ),
+    ///     111
+    /// );
+    /// assert_eq!(Natural::mod_multi_pow(&[], &Natural::from(497u32)), 1);
+    /// ```
+    pub fn mod_multi_pow(pairs: &[(Natural, Natural)], m: &Natural) -> Natural {
+        for (base, _) in pairs {
+            assert!(base < m, "base must be reduced mod m, but {base} >= {m}");
+        }
+        let bits = pairs
+            .iter()
+            .map(|(_, exp)| exp.significant_bits())
+            .max()
+            .unwrap_or(0);
+        if bits == 0 {
+            return if *m == 1u32 {
+                Natural::ZERO
+            } else {
+                Natural::ONE
+            };
+        }
+        let k = u64::exact_from(pairs.len());
+        let max_window = min(bits, 16);
+        let (straus_window, straus) = (1..=min(max_window, 8))
+            .map(|w| (w, straus_cost(k, bits, w)))
+            .min_by_key(|&(_, cost)| cost)
+            .unwrap();
+        let (pippenger_window, pippenger) = (1..=max_window)
+            .map(|c| (c, pippenger_cost(k, bits, c)))
+            .min_by_key(|&(_, cost)| cost)
+            .unwrap();
+        let arithmetic = ResidueArithmetic::new(m.clone());
+        let result = if straus <= pippenger {
+            mod_multi_pow_straus(&arithmetic, pairs, bits, straus_window)
+        } else {
+            mod_multi_pow_pippenger(&arithmetic, pairs, bits, pippenger_window)
+        };
+        arithmetic.residue_to_natural(&result)
+    }
+}
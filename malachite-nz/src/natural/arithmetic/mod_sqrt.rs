@@ -0,0 +1,566 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::natural::Natural;
+use core::cmp::min;
+use malachite_base::num::arithmetic::traits::{
+    DivExact, DivExactAssign, DivisibleBy, LegendreSymbol, ModAdd, ModInverse, ModMul, ModPow,
+    ModPowerOf2, ModPowerOf2Inverse, ModPowerOf2Mul, ModPrimePowerSqrt, ModSqrt, ModSquare, ModSub,
+    ModSubAssign, Parity, Pow, PowerOf2, Square,
+};
+use malachite_base::num::basic::traits::{One, Two, Zero};
+use malachite_base::num::logic::traits::{BitAccess, SignificantBits};
+
+// Returns the smaller of `r` and `m - r`.
+fn min_with_negative(r: Natural, m: &Natural) -> Natural {
+    let neg = m - &r;
+    min(r, neg)
+}
+
+// Returns the smallest `z` in [2, p) such that the Legendre symbol (z / p) is -1, or `None` if
+// there is no such `z` (which can only happen if `p` is not prime).
+fn find_non_residue(p: &Natural) -> Option<Natural> {
+    let mut z = Natural::TWO;
+    while z < *p {
+        if (&z).legendre_symbol(p) == -1 {
+            return Some(z);
+        }
+        z += Natural::ONE;
+    }
+    None
+}
+
+// The Tonelli–Shanks algorithm. `p` is an odd prime, $p - 1 = 2^s q$ with $q$ odd, and `x` is a
+// nonzero quadratic residue.
+fn mod_sqrt_tonelli_shanks(x: &Natural, p: &Natural, s: u64, q: &Natural) -> Option<Natural> {
+    let z = find_non_residue(p)?;
+    let mut c = z.mod_pow(q, p);
+    let mut r = x.mod_pow((q >> 1u32) + Natural::ONE, p);
+    let mut t = x.mod_pow(q, p);
+    let mut m = s;
+    while t != 1u32 {
+        // Find the least i such that t^(2^i) = 1.
+        let mut i = 0;
+        let mut u = t.clone();
+        while u != 1u32 {
+            u = u.mod_square(p);
+            i += 1;
+            if i == m {
+                return None;
+            }
+        }
+        let mut b = c;
+        for _ in 0..m - i - 1 {
+            b = b.mod_square(p);
+        }
+        r = r.mod_mul(&b, p);
+        c = b.mod_square(p);
+        t = t.mod_mul(&c, p);
+        m = i;
+    }
+    Some(r)
+}
+
+// Cipolla's algorithm. `p` is an odd prime and `x` is a nonzero quadratic residue. Finds `a` such
+// that $a^2 - x$ is a non-residue $w$, and computes $(a + \sqrt w)^{(p + 1) / 2}$ in
+// $\mathbb{F}_p(\sqrt w)$.
+fn mod_sqrt_cipolla(x: &Natural, p: &Natural) -> Option<Natural> {
+    let mut a = Natural::ONE;
+    let w = loop {
+        if a == *p {
+            return None;
+        }
+        let w = (&a).mod_square(p).mod_sub(x, p);
+        if (&w).legendre_symbol(p) == -1 {
+            break w;
+        }
+        a += Natural::ONE;
+    };
+    let mul = |(a0, a1): &(Natural, Natural), (b0, b1): &(Natural, Natural)| {
+        (
+            a0.mod_mul(b0, p)
+                .mod_add(a1.mod_mul(b1, p).mod_mul(&w, p), p),
+            a0.mod_mul(b1, p).mod_add(a1.mod_mul(b0, p), p),
+        )
+    };
+    let mut result = (Natural::ONE, Natural::ZERO);
+    let mut base = (a, Natural::ONE);
+    let e = (p >> 1u32) + Natural::ONE;
+    for i in 0..e.significant_bits() {
+        if e.get_bit(i) {
+            result = mul(&result, &base);
+        }
+        base = mul(&base, &base);
+    }
+    Some(result.0)
+}
+
+fn mod_sqrt_helper(x: &Natural, p: &Natural) -> Option<Natural> {
+    assert!(x < p, "x must be reduced mod p, but {x} >= {p}");
+    if let (Ok(x), Ok(p)) = (u64::try_from(x), u64::try_from(p)) {
+        return x.mod_sqrt(p).map(Natural::from);
+    }
+    if *x == 0u32 {
+        return Some(Natural::ZERO);
+    }
+    if p.even() || x.legendre_symbol(p) != 1 {
+        return None;
+    }
+    let s = (p - Natural::ONE).trailing_zeros().unwrap();
+    let r = if s == 1 {
+        x.mod_pow((p >> 2u32) + Natural::ONE, p)
+    } else if s * s > p.significant_bits() << 3 {
+        // When $p - 1$ is divisible by a large power of 2, Tonelli–Shanks takes $O(s^2)$
+        // multiplications, but Cipolla's algorithm still takes $O(\log p)$.
+        mod_sqrt_cipolla(x, p)?
+    } else {
+        mod_sqrt_tonelli_shanks(x, p, s, &((p - Natural::ONE) >> s))?
+    };
+    // If p is not prime, r may not be a square root.
+    if (&r).mod_square(p) == *x {
+        Some(min_with_negative(r, p))
+    } else {
+        None
+    }
+}
+
+// Returns the smallest square root of `u` modulo $2^k$, where `u` is odd and reduced modulo $2^k$.
+fn mod_power_of_2_sqrt_unit(u: &Natural, k: u64) -> Option<Natural> {
+    match k {
+        1 => return Some(Natural::ONE),
+        2 => return (u.mod_power_of_2(2) == 1u32).then_some(Natural::ONE),
+        _ => {}
+    }
+    if u.mod_power_of_2(3) != 1u32 {
+        return None;
+    }
+    // If s^2 = u mod 2^j, with j >= 3, then (s + u / s) / 2 is a square root of u modulo
+    // 2^(2j - 2).
+    let mut s = Natural::ONE;
+    let mut j = 3;
+    while j < k {
+        j = min((j << 1) - 2, k);
+        let inverse = (&s).mod_power_of_2_inverse(j + 1).unwrap();
+        let quotient = u.mod_power_of_2(j + 1).mod_power_of_2_mul(inverse, j + 1);
+        s = (s + quotient).mod_power_of_2(j + 1) >> 1u32;
+    }
+    // The square roots are ±s and ±s + 2^(k - 1), so the smallest is the smaller of s and -s
+    // modulo 2^(k - 1).
+    Some(min_with_negative(
+        s.mod_power_of_2(k - 1),
+        &Natural::power_of_2(k - 1),
+    ))
+}
+
+// Returns the smallest square root of `u` modulo $p^k$, where `u` is not divisible by $p$ and `pk`
+// is $p^k$.
+fn mod_prime_power_sqrt_unit(u: &Natural, p: &Natural, k: u64, pk: &Natural) -> Option<Natural> {
+    if *p == 2u32 {
+        return mod_power_of_2_sqrt_unit(u, k);
+    }
+    let mut s = mod_sqrt_helper(&(u % p), p)?;
+    // Newton's method doubles the precision at each step: if s^2 = u mod p^j, then s - (s^2 - u) /
+    // (2s) is a square root of u modulo p^(2j).
+    let mut j = 1;
+    let mut pj = p.clone();
+    while j < k {
+        let new_j = min(j << 1, k);
+        pj = if new_j == k {
+            pk.clone()
+        } else {
+            (&pj).square()
+        };
+        j = new_j;
+        let f = (&s).mod_square(&pj).mod_sub(u % &pj, &pj);
+        let d = (&s).mod_add(&s, &pj).mod_inverse(&pj)?;
+        s.mod_sub_assign(f.mod_mul(d, &pj), &pj);
+    }
+    Some(min_with_negative(s, pk))
+}
+
+fn mod_prime_power_sqrt_helper(x: &Natural, p: &Natural, pow: u64) -> Option<Natural> {
+    let pk = p.pow(pow);
+    assert!(*x < pk, "x must be reduced mod p^pow, but {x} >= {pk}");
+    if let (Ok(x), Ok(p), Ok(_)) = (u64::try_from(x), u64::try_from(p), u64::try_from(&pk)) {
+        return x.mod_prime_power_sqrt(p, pow).map(Natural::from);
+    }
+    if *x == 0u32 {
+        return Some(Natural::ZERO);
+    }
+    // Write x as p^v * u, with u not divisible by p. Since x < p^pow, v < pow.
+    let mut u = x.clone();
+    let mut v = 0;
+    while (&u).divisible_by(p) {
+        u.div_exact_assign(p);
+        v += 1;
+    }
+    if v.odd() {
+        return None;
+    }
+    // If s is a square root of u modulo p^(pow - v), then p^(v / 2) * s is a square root of x
+    // modulo p^pow, and every square root of x has this form.
+    let s = mod_prime_power_sqrt_unit(&u, p, pow - v, &pk.div_exact(p.pow(v)))?;
+    Some(p.pow(v >> 1) * s)
+}
+
+impl ModSqrt<Natural> for Natural {
+    type Output = Natural;
+
+    /// Finds the smallest square root of a [`Natural`] modulo a prime [`Natural`] $p$. The input
+    /// must be already reduced modulo $p$. Both [`Natural`]s are taken by value.
+    ///
+    /// Returns `None` if $x$ is not a square modulo $p$. The modulus is assumed to be prime; if it
+    /// isn't, the result is either `None` or a square root of $x$.
+    ///
+    /// $f(x, p) = \min \\{y : 0 \leq y < p, y^2 \equiv x \mod p\\}$.
+    ///
+    /// The Tonelli–Shanks algorithm is used, unless $p - 1$ is divisible by a large power of 2, in
+    /// which case Cipolla's algorithm is used.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `p.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is greater than or equal to `p`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModSqrt;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Natural::from(10u32).mod_sqrt(Natural::from(13u32)),
+    ///     Some(Natural::from(6u32))
+    /// );
+    /// assert_eq!(Natural::from(11u32).mod_sqrt(Natural::from(13u32)), None);
+    /// ```
+    #[inline]
+    fn mod_sqrt(self, p: Natural) -> Option<Natural> {
+        mod_sqrt_helper(&self, &p)
+    }
+}
+
+impl ModSqrt<&Natural> for Natural {
+    type Output = Natural;
+
+    /// Finds the smallest square root of a [`Natural`] modulo a prime [`Natural`] $p$. The input
+    /// must be already reduced modulo $p$. The first [`Natural`] is taken by value and the second
+    /// by reference.
+    ///
+    /// Returns `None` if $x$ is not a square modulo $p$. The modulus is assumed to be prime; if it
+    /// isn't, the result is either `None` or a square root of $x$.
+    ///
+    /// $f(x, p) = \min \\{y : 0 \leq y < p, y^2 \equiv x \mod p\\}$.
+    ///
+    /// The Tonelli–Shanks algorithm is used, unless $p - 1$ is divisible by a large power of 2, in
+    /// which case Cipolla's algorithm is used.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `p.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is greater than or equal to `p`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModSqrt;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Natural::from(10u32).mod_sqrt(&Natural::from(13u32)),
+    ///     Some(Natural::from(6u32))
+    /// );
+    /// assert_eq!(Natural::from(11u32).mod_sqrt(&Natural::from(13u32)), None);
+    /// ```
+    #[inline]
+    fn mod_sqrt(self, p: &Natural) -> Option<Natural> {
+        mod_sqrt_helper(&self, p)
+    }
+}
+
+impl ModSqrt<Natural> for &Natural {
+    type Output = Natural;
+
+    /// Finds the smallest square root of a [`Natural`] modulo a prime [`Natural`] $p$. The input
+    /// must be already reduced modulo $p$. The first [`Natural`] is taken by reference and the
+    /// second by value.
+    ///
+    /// Returns `None` if $x$ is not a square modulo $p$. The modulus is assumed to be prime; if it
+    /// isn't, the result is either `None` or a square root of $x$.
+    ///
+    /// $f(x, p) = \min \\{y : 0 \leq y < p, y^2 \equiv x \mod p\\}$.
+    ///
+    /// The Tonelli–Shanks algorithm is used, unless $p - 1$ is divisible by a large power of 2, in
+    /// which case Cipolla's algorithm is used.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `p.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is greater than or equal to `p`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModSqrt;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     (&Natural::from(10u32)).mod_sqrt(Natural::from(13u32)),
+    ///     Some(Natural::from(6u32))
+    /// );
+    /// assert_eq!((&Natural::from(11u32)).mod_sqrt(Natural::from(13u32)), None);
+    /// ```
+    #[inline]
+    fn mod_sqrt(self, p: Natural) -> Option<Natural> {
+        mod_sqrt_helper(self, &p)
+    }
+}
+
+impl ModSqrt<&Natural> for &Natural {
+    type Output = Natural;
+
+    /// Finds the smallest square root of a [`Natural`] modulo a prime [`Natural`] $p$. The input
+    /// must be already reduced modulo $p$. Both [`Natural`]s are taken by reference.
+    ///
+    /// Returns `None` if $x$ is not a square modulo $p$. The modulus is assumed to be prime; if it
+    /// isn't, the result is either `None` or a square root of $x$.
+    ///
+    /// $f(x, p) = \min \\{y : 0 \leq y < p, y^2 \equiv x \mod p\\}$.
+    ///
+    /// The Tonelli–Shanks algorithm is used, unless $p - 1$ is divisible by a large power of 2, in
+    /// which case Cipolla's algorithm is used.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `p.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is greater than or equal to `p`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModSqrt;
+    /// use malachite_nz::natural::Natural;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!(
+    ///     (&Natural::from(10u32)).mod_sqrt(&Natural::from(13u32)),
+    ///     Some(Natural::from(6u32))
+    /// );
+    /// // 2^127 - 1
+    /// let p = Natural::from_str("170141183460469231731687303715884105727").unwrap();
+    /// assert_eq!(
+    ///     (&Natural::from(11u32)).mod_sqrt(&p).unwrap().to_string(),
+    ///     "18756936442469208154408315811770624301"
+    /// );
+    /// assert_eq!((&Natural::from(14u32)).mod_sqrt(&p), None);
+    /// ```
+    #[inline]
+    fn mod_sqrt(self, p: &Natural) -> Option<Natural> {
+        mod_sqrt_helper(self, p)
+    }
+}
+
+impl ModPrimePowerSqrt<Natural> for Natural {
+    type Output = Natural;
+
+    /// Finds the smallest square root of a [`Natural`] modulo $p^k$, where $p$ is a prime
+    /// [`Natural`]. The input must be already reduced modulo $p^k$. Both [`Natural`]s are taken by
+    /// value.
+    ///
+    /// Returns `None` if $x$ is not a square modulo $p^k$. The modulus is assumed to be a prime
+    /// power; if it isn't, the result is unspecified.
+    ///
+    /// $f(x, p, k) = \min \\{y : 0 \leq y < p^k, y^2 \equiv x \mod p^k\\}$.
+    ///
+    /// A square root modulo $p$ is found using [`ModSqrt`] and then lifted to a root modulo $p^k$
+    /// using Hensel's lemma.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `pow * p.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is greater than or equal to $p^k$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPrimePowerSqrt;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Natural::from(2u32).mod_prime_power_sqrt(Natural::from(7u32), 3),
+    ///     Some(Natural::from(108u32))
+    /// );
+    /// assert_eq!(
+    ///     Natural::from(3u32).mod_prime_power_sqrt(Natural::from(3u32), 3),
+    ///     None
+    /// );
+    /// ```
+    #[inline]
+    fn mod_prime_power_sqrt(self, p: Natural, pow: u64) -> Option<Natural> {
+        mod_prime_power_sqrt_helper(&self, &p, pow)
+    }
+}
+
+impl ModPrimePowerSqrt<&Natural> for Natural {
+    type Output = Natural;
+
+    /// Finds the smallest square root of a [`Natural`] modulo $p^k$, where $p$ is a prime
+    /// [`Natural`]. The input must be already reduced modulo $p^k$. The first [`Natural`] is taken
+    /// by value and the second by reference.
+    ///
+    /// Returns `None` if $x$ is not a square modulo $p^k$. The modulus is assumed to be a prime
+    /// power; if it isn't, the result is unspecified.
+    ///
+    /// $f(x, p, k) = \min \\{y : 0 \leq y < p^k, y^2 \equiv x \mod p^k\\}$.
+    ///
+    /// A square root modulo $p$ is found using [`ModSqrt`] and then lifted to a root modulo $p^k$
+    /// using Hensel's lemma.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `pow * p.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is greater than or equal to $p^k$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPrimePowerSqrt;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Natural::from(2u32).mod_prime_power_sqrt(&Natural::from(7u32), 3),
+    ///     Some(Natural::from(108u32))
+    /// );
+    /// assert_eq!(
+    ///     Natural::from(3u32).mod_prime_power_sqrt(&Natural::from(3u32), 3),
+    ///     None
+    /// );
+    /// ```
+    #[inline]
+    fn mod_prime_power_sqrt(self, p: &Natural, pow: u64) -> Option<Natural> {
+        mod_prime_power_sqrt_helper(&self, p, pow)
+    }
+}
+
+impl ModPrimePowerSqrt<Natural> for &Natural {
+    type Output = Natural;
+
+    /// Finds the smallest square root of a [`Natural`] modulo $p^k$, where $p$ is a prime
+    /// [`Natural`]. The input must be already reduced modulo $p^k$. The first [`Natural`] is taken
+    /// by reference and the second by value.
+    ///
+    /// Returns `None` if $x$ is not a square modulo $p^k$. The modulus is assumed to be a prime
+    /// power; if it isn't, the result is unspecified.
+    ///
+    /// $f(x, p, k) = \min \\{y : 0 \leq y < p^k, y^2 \equiv x \mod p^k\\}$.
+    ///
+    /// A square root modulo $p$ is found using [`ModSqrt`] and then lifted to a root modulo $p^k$
+    /// using Hensel's lemma.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `pow * p.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is greater than or equal to $p^k$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPrimePowerSqrt;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     (&Natural::from(2u32)).mod_prime_power_sqrt(Natural::from(7u32), 3),
+    ///     Some(Natural::from(108u32))
+    /// );
+    /// assert_eq!(
+    ///     (&Natural::from(3u32)).mod_prime_power_sqrt(Natural::from(3u32), 3),
+    ///     None
+    /// );
+    /// ```
+    #[inline]
+    fn mod_prime_power_sqrt(self, p: Natural, pow: u64) -> Option<Natural> {
+        mod_prime_power_sqrt_helper(self, &p, pow)
+    }
+}
+
+impl ModPrimePowerSqrt<&Natural> for &Natural {
+    type Output = Natural;
+
+    /// Finds the smallest square root of a [`Natural`] modulo $p^k$, where $p$ is a prime
+    /// [`Natural`]. The input must be already reduced modulo $p^k$. Both [`Natural`]s are taken by
+    /// reference.
+    ///
+    /// Returns `None` if $x$ is not a square modulo $p^k$. The modulus is assumed to be a prime
+    /// power; if it isn't, the result is unspecified.
+    ///
+    /// $f(x, p, k) = \min \\{y : 0 \leq y < p^k, y^2 \equiv x \mod p^k\\}$.
+    ///
+    /// A square root modulo $p$ is found using [`ModSqrt`] and then lifted to a root modulo $p^k$
+    /// using Hensel's lemma.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `pow * p.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is greater than or equal to $p^k$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ModPrimePowerSqrt;
+    /// use malachite_base::num::basic::traits::Two;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     (&Natural::from(2u32)).mod_prime_power_sqrt(&Natural::from(7u32), 3),
+    ///     Some(Natural::from(108u32))
+    /// );
+    /// assert_eq!(
+    ///     (&Natural::from(41u32))
+    ///         .mod_prime_power_sqrt(&Natural::TWO, 100)
+    ///         .unwrap()
+    ///         .to_string(),
+    ///     "268257941340519377144514844979"
+    /// );
+    /// ```
+    #[inline]
+    fn mod_prime_power_sqrt(self, p: &Natural, pow: u64) -> Option<Natural> {
+        mod_prime_power_sqrt_helper(self, p, pow)
+    }
+}
@@ -0,0 +1,1514 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::natural::arithmetic::add::limbs_slice_add_same_length_in_place_left;
+use crate::natural::arithmetic::div_exact::{
+    limbs_modular_invert, limbs_modular_invert_limb, limbs_modular_invert_scratch_len,
+};
+use crate::natural::arithmetic::mod_pow::{
+    get_bits, get_window_size, limbs_redc_limb, limbs_redc_scratch_len, limbs_redc_with_scratch,
+    REDC_1_TO_REDC_N_THRESHOLD,
+};
+use crate::natural::arithmetic::mul::{
+    limbs_mul_same_length_to_out, limbs_mul_same_length_to_out_scratch_len,
+};
+use crate::natural::arithmetic::square::{limbs_square_to_out, limbs_square_to_out_scratch_len};
+use crate::natural::arithmetic::sub::{
+    limbs_sub_same_length_in_place_left, limbs_sub_same_length_to_out,
+};
+use crate::natural::comparison::cmp::limbs_cmp_same_length;
+use crate::natural::logic::bit_access::limbs_get_bit;
+use crate::natural::logic::significant_bits::limbs_significant_bits;
+use crate::natural::Natural;
+use crate::platform::Limb;
+use alloc::vec::Vec;
+use core::cmp::Ordering::*;
+use core::cmp::{max, min};
+use core::fmt::{self, Debug, Display, Formatter};
+use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use core::ptr;
+use malachite_base::num::arithmetic::traits::{ModInverse, Parity, Pow, PowerOf2, Reciprocal};
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::TrailingZeros;
+use malachite_base::slices::{slice_set_zero, slice_test_zero};
+
+/// The ring of integers modulo a fixed odd [`Natural`] $m$, with precomputed data for arithmetic
+/// in Montgomery form.
+///
+/// If $m$ has $n$ limbs, let $R = 2^{Wn}$, where $W$ is the width of a [`Limb`]. A residue $x$ is
+/// represented by $xR \mod m$. In this form, a product can be reduced using a multiplication and a
+/// few additions instead of a division, which makes repeated multiplication and exponentiation
+/// modulo $m$ much faster than reducing each result with `%`.
+///
+/// Residues are created with [`ModularRing::element`] and are represented by
+/// [`MontgomeryNatural`]s, which borrow the ring that created them.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ModularRing {
+    modulus: Natural,
+    // The limbs of the modulus.
+    ms: Vec<Limb>,
+    // $-m^{-1} \mod 2^W$.
+    m_inv: Limb,
+    // $m^{-1} \mod R$, only computed when `ms.len()` is large enough to use `limbs_redc`.
+    is: Vec<Limb>,
+    // $R \mod m$, which represents 1.
+    r: Vec<Limb>,
+    // $R^2 \mod m$, which is used to convert into Montgomery form.
+    r_squared: Vec<Limb>,
+}
+
+/// A residue modulo the modulus of a [`ModularRing`], stored in Montgomery form.
+///
+/// Arithmetic on [`MontgomeryNatural`]s is done with the usual operators. Combining residues from
+/// rings with different moduli causes a panic.
+#[derive(Clone)]
+pub struct MontgomeryNatural<'a> {
+    ring: &'a ModularRing,
+    // $xR \mod m$, with exactly as many limbs as $m$.
+    xs: Vec<Limb>,
+}
+
+impl ModularRing {
+    /// Creates the ring of integers modulo an odd [`Natural`] $m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `m.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `m` is even.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::montgomery::ModularRing;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let ring = ModularRing::new(Natural::from(101u32));
+    /// assert_eq!(ring.modulus(), &101u32);
+    /// ```
+    pub fn new(m: Natural) -> ModularRing {
+        assert!(m.odd(), "The modulus must be odd, but it is {m}");
+        let ms = m.to_limbs_asc();
+        let n = ms.len();
+        let m_inv = limbs_modular_invert_limb(ms[0]).wrapping_neg();
+        let is = if n < REDC_1_TO_REDC_N_THRESHOLD {
+            Vec::new()
+        } else {
+            let mut is = vec![0; n];
+            let mut scratch = vec![0; limbs_modular_invert_scratch_len(n)];
+            limbs_modular_invert(&mut is, &ms, &mut scratch);
+            is
+        };
+        let width = u64::exact_from(n) << Limb::LOG_WIDTH;
+        let r = padded_limbs(&(Natural::power_of_2(width) % &m), n);
+        let r_squared = padded_limbs(&(Natural::power_of_2(width << 1) % &m), n);
+        ModularRing {
+            modulus: m,
+            ms,
+            m_inv,
+            is,
+            r,
+            r_squared,
+        }
+    }
+
+    /// Returns the modulus of a [`ModularRing`].
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::montgomery::ModularRing;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(ModularRing::new(Natural::from(101u32)).modulus(), &101u32);
+    /// ```
+    #[inline]
+    pub const fn modulus(&self) -> &Natural {
+        &self.modulus
+    }
+
+    /// Converts a [`Natural`] to a residue of a [`ModularRing`]. The [`Natural`] does not need to
+    /// be reduced modulo the ring's modulus.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(x.significant_bits(),
+    /// self.modulus().significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::montgomery::ModularRing;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let ring = ModularRing::new(Natural::from(101u32));
+    /// assert_eq!(ring.element(&Natural::from(5u32)).to_natural(), 5);
+    /// assert_eq!(ring.element(&Natural::from(1000u32)).to_natural(), 91);
+    /// ```
+    pub fn element(&self, x: &Natural) -> MontgomeryNatural<'_> {
+        MontgomeryNatural {
+            ring: self,
+            xs: self.to_montgomery_limbs(x),
+        }
+    }
+
+    /// Returns the residue 0 of a [`ModularRing`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.modulus().significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::montgomery::ModularRing;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let ring = ModularRing::new(Natural::from(101u32));
+    /// assert_eq!(ring.zero().to_natural(), 0);
+    /// ```
+    pub fn zero(&self) -> MontgomeryNatural<'_> {
+        MontgomeryNatural {
+            ring: self,
+            xs: vec![0; self.ms.len()],
+        }
+    }
+
+    /// Returns the residue 1 of a [`ModularRing`]. If the modulus is 1, this is the same as 0.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.modulus().significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::montgomery::ModularRing;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let ring = ModularRing::new(Natural::from(101u32));
+    /// assert_eq!(ring.one().to_natural(), 1);
+    /// ```
+    pub fn one(&self) -> MontgomeryNatural<'_> {
+        MontgomeryNatural {
+            ring: self,
+            xs: self.r.clone(),
+        }
+    }
+
+    // Allocates the buffers needed to multiply and square residues.
+    pub(crate) fn new_scratch(&self) -> MontgomeryScratch {
+        let n = self.ms.len();
+        let mut scratch_len = max(
+            limbs_mul_same_length_to_out_scratch_len(n),
+            limbs_square_to_out_scratch_len(n),
+        );
+        if !self.is.is_empty() {
+            scratch_len = max(scratch_len, limbs_redc_scratch_len(n));
+        }
+        MontgomeryScratch {
+            product: vec![0; n << 1],
+            scratch: vec![0; scratch_len],
+        }
+    }
+
+    // Given $x$ with `2 * ms.len()` limbs, with $x < mR$, writes $xR^{-1} \mod m$ to `out`.
+    fn redc(&self, out: &mut [Limb], xs: &mut [Limb], scratch: &mut [Limb]) {
+        if self.is.is_empty() {
+            limbs_redc_limb(out, xs, &self.ms, self.m_inv);
+        } else {
+            limbs_redc_with_scratch(out, xs, &self.ms, &self.is, scratch);
+        }
+        if limbs_cmp_same_length(out, &self.ms) != Less {
+            limbs_sub_same_length_in_place_left(out, &self.ms);
+        }
+    }
+
+    // Converts a [`Natural`], which does not need to be reduced, to Montgomery form.
+    pub(crate) fn to_montgomery_limbs(&self, x: &Natural) -> Vec<Limb> {
+        let mut xs = if *x < self.modulus {
+            padded_limbs(x, self.ms.len())
+        } else {
+            padded_limbs(&(x % &self.modulus), self.ms.len())
+        };
+        self.mul_limbs_assign(&mut xs, &self.r_squared, &mut self.new_scratch());
+        xs
+    }
+
+    // Converts a residue in Montgomery form back to a [`Natural`].
+    pub(crate) fn montgomery_limbs_to_natural(&self, xs: &[Limb]) -> Natural {
+        let n = xs.len();
+        let mut scratch = self.new_scratch();
+        scratch.product[..n].copy_from_slice(xs);
+        slice_set_zero(&mut scratch.product[n..]);
+        let mut out = vec![0; n];
+        self.redc(&mut out, &mut scratch.product, &mut scratch.scratch);
+        Natural::from_owned_limbs_asc(out)
+    }
+
+    // Returns 1 in Montgomery form.
+    #[inline]
+    pub(crate) fn one_limbs(&self) -> &[Limb] {
+        &self.r
+    }
+
+    // Replaces a residue in Montgomery form by its product with another.
+    pub(crate) fn mul_limbs_assign(
+        &self,
+        xs: &mut [Limb],
+        ys: &[Limb],
+        scratch: &mut MontgomeryScratch,
+    ) {
+        limbs_mul_same_length_to_out(&mut scratch.product, xs, ys, &mut scratch.scratch);
+        self.redc(xs, &mut scratch.product, &mut scratch.scratch);
+    }
+
+    // Replaces a residue in Montgomery form by its square.
+    pub(crate) fn square_limbs_assign(&self, xs: &mut [Limb], scratch: &mut MontgomeryScratch) {
+        limbs_square_to_out(&mut scratch.product, xs, &mut scratch.scratch);
+        self.redc(xs, &mut scratch.product, &mut scratch.scratch);
+    }
+
+    // Raises a residue in Montgomery form to the power whose limbs are `es`, using left-to-right
+    // sliding-window exponentiation. `es` may be empty, but may not have trailing zeros.
+    //
+    // This follows `limbs_mod_pow_odd`, except that the inputs and output are in Montgomery form.
+    pub(crate) fn pow_limbs(&self, xs: &[Limb], es: &[Limb]) -> Vec<Limb> {
+        let n = self.ms.len();
+        if es.is_empty() {
+            return self.r.clone();
+        }
+        let width = limbs_significant_bits(es);
+        let window_size = get_window_size(width);
+        let mut scratch = self.new_scratch();
+        // Precompute the odd powers $x, x^3, \ldots, x^{2^k - 1}$, where $k$ is the window size.
+        let mut powers = vec![0; n << (window_size - 1)];
+        let mut powers: Vec<&mut [Limb]> = powers.chunks_mut(n).collect();
+        powers[0].copy_from_slice(xs);
+        let mut x_squared = xs.to_vec();
+        self.square_limbs_assign(&mut x_squared, &mut scratch);
+        for i in 1..powers.len() {
+            let (powers_lo, powers_hi) = powers.split_at_mut(i);
+            powers_hi[0].copy_from_slice(powers_lo[i - 1]);
+            self.mul_limbs_assign(powers_hi[0], &x_squared, &mut scratch);
+        }
+        // Returns the index in `powers` of the largest block of at most `window_size` bits ending
+        // at `bit_index` whose least significant bit is 1, along with the number of bits that
+        // precede the block.
+        let next_window = |bit_index: u64| {
+            let this_window_size = min(window_size, bit_index);
+            let exp_bits = get_bits(es, bit_index, this_window_size);
+            let trailing_zeros = TrailingZeros::trailing_zeros(Limb::exact_from(exp_bits));
+            (
+                exp_bits >> trailing_zeros >> 1,
+                bit_index - this_window_size + trailing_zeros,
+            )
+        };
+        let (i, mut bit_index) = next_window(width);
+        let mut out = powers[i].to_vec();
+        while bit_index != 0 {
+            if limbs_get_bit(es, bit_index - 1) {
+                let (i, new_bit_index) = next_window(bit_index);
+                for _ in new_bit_index..bit_index {
+                    self.square_limbs_assign(&mut out, &mut scratch);
+                }
+                self.mul_limbs_assign(&mut out, powers[i], &mut scratch);
+                bit_index = new_bit_index;
+            } else {
+                self.square_limbs_assign(&mut out, &mut scratch);
+                bit_index -= 1;
+            }
+        }
+        out
+    }
+}
+
+// Buffers for multiplying residues of a [`ModularRing`], so that a sequence of multiplications
+// doesn't need to allocate.
+pub(crate) struct MontgomeryScratch {
+    // Holds a product before it is reduced.
+    product: Vec<Limb>,
+    // Scratch space for multiplying, squaring, and reducing.
+    scratch: Vec<Limb>,
+}
+
+// Returns the limbs of `x`, padded with zeros to length `n`. `x` must have at most `n` limbs.
+fn padded_limbs(x: &Natural, n: usize) -> Vec<Limb> {
+    let mut xs = x.to_limbs_asc();
+    xs.resize(n, 0);
+    xs
+}
+
+impl<'a> MontgomeryNatural<'a> {
+    /// Returns the [`ModularRing`] that a [`MontgomeryNatural`] belongs to.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::montgomery::ModularRing;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let ring = ModularRing::new(Natural::from(101u32));
+    /// assert_eq!(ring.one().ring().modulus(), &101u32);
+    /// ```
+    #[inline]
+    pub const fn ring(&self) -> &'a ModularRing {
+        self.ring
+    }
+
+    /// Converts a [`MontgomeryNatural`] back to a [`Natural`], which is less than the modulus.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.ring().modulus().significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::montgomery::ModularRing;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let ring = ModularRing::new(Natural::from(101u32));
+    /// assert_eq!(ring.element(&Natural::from(123u32)).to_natural(), 22);
+    /// ```
+    #[inline]
+    pub fn to_natural(&self) -> Natural {
+        self.ring.montgomery_limbs_to_natural(&self.xs)
+    }
+
+    /// Determines whether a [`MontgomeryNatural`] is zero.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.ring().modulus().significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::montgomery::ModularRing;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let ring = ModularRing::new(Natural::from(101u32));
+    /// assert!(ring.element(&Natural::from(202u32)).is_zero());
+    /// assert!(!ring.one().is_zero());
+    /// ```
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        slice_test_zero(&self.xs)
+    }
+
+    /// Computes the multiplicative inverse of a [`MontgomeryNatural`], returning `None` if it is
+    /// not coprime to the modulus.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.ring().modulus().significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::montgomery::ModularRing;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let ring = ModularRing::new(Natural::from(15u32));
+    /// assert_eq!(
+    ///     ring.element(&Natural::from(7u32))
+    ///         .checked_reciprocal()
+    ///         .unwrap()
+    ///         .to_natural(),
+    ///     13
+    /// );
+    /// assert!(ring.element(&Natural::from(6u32)).checked_reciprocal().is_none());
+    /// ```
+    pub fn checked_reciprocal(&self) -> Option<MontgomeryNatural<'a>> {
+        if self.ring.modulus == 1u32 {
+            return Some(self.clone());
+        }
+        let x = self.to_natural();
+        if x == 0u32 {
+            return None;
+        }
+        Some(self.ring.element(&x.mod_inverse(&self.ring.modulus)?))
+    }
+
+    fn assert_same_ring(&self, other: &MontgomeryNatural) {
+        assert!(
+            ptr::eq(self.ring, other.ring) || self.ring.modulus == other.ring.modulus,
+            "Residues must belong to the same ring, but their moduli are {} and {}",
+            self.ring.modulus,
+            other.ring.modulus
+        );
+    }
+
+    fn add_assign_ref(&mut self, other: &MontgomeryNatural) {
+        self.assert_same_ring(other);
+        let ms = &self.ring.ms;
+        if limbs_slice_add_same_length_in_place_left(&mut self.xs, &other.xs)
+            || limbs_cmp_same_length(&self.xs, ms) != Less
+        {
+            limbs_sub_same_length_in_place_left(&mut self.xs, ms);
+        }
+    }
+
+    fn sub_assign_ref(&mut self, other: &MontgomeryNatural) {
+        self.assert_same_ring(other);
+        if limbs_sub_same_length_in_place_left(&mut self.xs, &other.xs) {
+            limbs_slice_add_same_length_in_place_left(&mut self.xs, &self.ring.ms);
+        }
+    }
+
+    fn neg_assign(&mut self) {
+        if !self.is_zero() {
+            let mut out = vec![0; self.xs.len()];
+            limbs_sub_same_length_to_out(&mut out, &self.ring.ms, &self.xs);
+            self.xs = out;
+        }
+    }
+
+    fn mul_assign_ref(&mut self, other: &MontgomeryNatural) {
+        self.assert_same_ring(other);
+        self.ring
+            .mul_limbs_assign(&mut self.xs, &other.xs, &mut self.ring.new_scratch());
+    }
+}
+
+impl PartialEq for MontgomeryNatural<'_> {
+    /// Determines whether two [`MontgomeryNatural`]s are equal. Residues are equal if their moduli
+    /// and values are equal.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.ring().modulus().significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::montgomery::ModularRing;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let ring = ModularRing::new(Natural::from(101u32));
+    /// assert_eq!(
+    ///     ring.element(&Natural::from(3u32)),
+    ///     ring.element(&Natural::from(104u32))
+    /// );
+    /// assert_ne!(ring.element(&Natural::from(3u32)), ring.one());
+    /// ```
+    fn eq(&self, other: &MontgomeryNatural) -> bool {
+        (ptr::eq(self.ring, other.ring) || self.ring.modulus == other.ring.modulus)
+            && self.xs == other.xs
+    }
+}
+
+impl Eq for MontgomeryNatural<'_> {}
+
+impl Display for MontgomeryNatural<'_> {
+    /// Converts a [`MontgomeryNatural`] to a [`String`](alloc::string::String), by writing the
+    /// least non-negative [`Natural`] that it represents.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.ring().modulus().significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::montgomery::ModularRing;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let ring = ModularRing::new(Natural::from(101u32));
+    /// assert_eq!(ring.element(&Natural::from(1000u32)).to_string(), "91");
+    /// ```
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(&self.to_natural(), f)
+    }
+}
+
+impl Debug for MontgomeryNatural<'_> {
+    /// Converts a [`MontgomeryNatural`] to a [`String`](alloc::string::String).
+    ///
+    /// This is the same as the [`Display::fmt`] implementation.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.ring().modulus().significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::arithmetic::montgomery::ModularRing;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let ring = ModularRing::new(Natural::from(101u32));
+    /// assert_eq!(ring.element(&Natural::from(1000u32)).to_debug_string(), "91");
+    /// ```
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<'a> Add<MontgomeryNatural<'a>> for MontgomeryNatural<'a> {
+    type Output = MontgomeryNatural<'a>;
+
+    /// Adds two [`MontgomeryNatural`]s. Both [`MontgomeryNatural`]s are taken by value.
+    ///
+    /// $f(x, y) = x + y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.ring().modulus().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` belong to rings with different moduli.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::montgomery::ModularRing;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let ring = ModularRing::new(Natural::from(101u32));
+    /// let x = ring.element(&Natural::from(50u32));
+    /// let y = ring.element(&Natural::from(60u32));
+    /// assert_eq!((x + y).to_natural(), 9);
+    /// ```
+    #[inline]
+    fn add(mut self, other: MontgomeryNatural<'a>) -> MontgomeryNatural<'a> {
+        self.add_assign_ref(&other);
+        self
+    }
+}
+
+impl<'a> Add<&MontgomeryNatural<'a>> for MontgomeryNatural<'a> {
+    type Output = MontgomeryNatural<'a>;
+
+    /// Adds two [`MontgomeryNatural`]s. The first [`MontgomeryNatural`] is taken by value and the
+    /// second by reference.
+    ///
+    /// $f(x, y) = x + y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.ring().modulus().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` belong to rings with different moduli.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::montgomery::ModularRing;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let ring = ModularRing::new(Natural::from(101u32));
+    /// let x = ring.element(&Natural::from(50u32));
+    /// let y = ring.element(&Natural::from(60u32));
+    /// assert_eq!((x + &y).to_natural(), 9);
+    /// ```
+    #[inline]
+    fn add(mut self, other: &MontgomeryNatural<'a>) -> MontgomeryNatural<'a> {
+        self.add_assign_ref(other);
+        self
+    }
+}
+
+impl<'a> Add<MontgomeryNatural<'a>> for &MontgomeryNatural<'a> {
+    type Output = MontgomeryNatural<'a>;
+
+    /// Adds two [`MontgomeryNatural`]s. The first [`MontgomeryNatural`] is taken by reference and
+    /// the second by value.
+    ///
+    /// $f(x, y) = x + y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.ring().modulus().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` belong to rings with different moduli.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::montgomery::ModularRing;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let ring = ModularRing::new(Natural::from(101u32));
+    /// let x = ring.element(&Natural::from(50u32));
+    /// let y = ring.element(&Natural::from(60u32));
+    /// assert_eq!((&x + y).to_natural(), 9);
+    /// ```
+    #[inline]
+    fn add(self, mut other: MontgomeryNatural<'a>) -> MontgomeryNatural<'a> {
+        other.add_assign_ref(self);
+        other
+    }
+}
+
+impl<'a> Add<&MontgomeryNatural<'a>> for &MontgomeryNatural<'a> {
+    type Output = MontgomeryNatural<'a>;
+
+    /// Adds two [`MontgomeryNatural`]s. Both [`MontgomeryNatural`]s are taken by reference.
+    ///
+    /// $f(x, y) = x + y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.ring().modulus().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` belong to rings with different moduli.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::montgomery::ModularRing;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let ring = ModularRing::new(Natural::from(101u32));
+    /// let x = ring.element(&Natural::from(50u32));
+    /// let y = ring.element(&Natural::from(60u32));
+    /// assert_eq!((&x + &y).to_natural(), 9);
+    /// ```
+    fn add(self, other: &MontgomeryNatural<'a>) -> MontgomeryNatural<'a> {
+        let mut out = self.clone();
+        out.add_assign_ref(other);
+        out
+    }
+}
+
+impl<'a> AddAssign<MontgomeryNatural<'a>> for MontgomeryNatural<'a> {
+    /// Adds a [`MontgomeryNatural`] to a [`MontgomeryNatural`] in place. The [`MontgomeryNatural`]
+    /// on the right-hand side is taken by value.
+    ///
+    /// $x \gets x + y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.ring().modulus().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` belong to rings with different moduli.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::montgomery::ModularRing;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let ring = ModularRing::new(Natural::from(101u32));
+    /// let mut x = ring.element(&Natural::from(50u32));
+    /// let y = ring.element(&Natural::from(60u32));
+    /// x += y;
+    /// assert_eq!(x.to_natural(), 9);
+    /// ```
+    #[inline]
+    fn add_assign(&mut self, other: MontgomeryNatural<'a>) {
+        self.add_assign_ref(&other);
+    }
+}
+
+impl<'a> AddAssign<&MontgomeryNatural<'a>> for MontgomeryNatural<'a> {
+    /// Adds a [`MontgomeryNatural`] to a [`MontgomeryNatural`] in place. The [`MontgomeryNatural`]
+    /// on the right-hand side is taken by reference.
+    ///
+    /// $x \gets x + y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.ring().modulus().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` belong to rings with different moduli.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::montgomery::ModularRing;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let ring = ModularRing::new(Natural::from(101u32));
+    /// let mut x = ring.element(&Natural::from(50u32));
+    /// let y = ring.element(&Natural::from(60u32));
+    /// x += &y;
+    /// assert_eq!(x.to_natural(), 9);
+    /// ```
+    #[inline]
+    fn add_assign(&mut self, other: &MontgomeryNatural<'a>) {
+        self.add_assign_ref(other);
+    }
+}
+
+impl<'a> Sub<MontgomeryNatural<'a>> for MontgomeryNatural<'a> {
+    type Output = MontgomeryNatural<'a>;
+
+    /// Subtracts a [`MontgomeryNatural`] by another [`MontgomeryNatural`]. Both
+    /// [`MontgomeryNatural`]s are taken by value.
+    ///
+    /// $f(x, y) = x - y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.ring().modulus().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` belong to rings with different moduli.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::montgomery::ModularRing;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let ring = ModularRing::new(Natural::from(101u32));
+    /// let x = ring.element(&Natural::from(50u32));
+    /// let y = ring.element(&Natural::from(60u32));
+    /// assert_eq!((x - y).to_natural(), 91);
+    /// ```
+    #[inline]
+    fn sub(mut self, other: MontgomeryNatural<'a>) -> MontgomeryNatural<'a> {
+        self.sub_assign_ref(&other);
+        self
+    }
+}
+
+impl<'a> Sub<&MontgomeryNatural<'a>> for MontgomeryNatural<'a> {
+    type Output = MontgomeryNatural<'a>;
+
+    /// Subtracts a [`MontgomeryNatural`] by another [`MontgomeryNatural`]. The first
+    /// [`MontgomeryNatural`] is taken by value and the second by reference.
+    ///
+    /// $f(x, y) = x - y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.ring().modulus().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` belong to rings with different moduli.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::montgomery::ModularRing;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let ring = ModularRing::new(Natural::from(101u32));
+    /// let x = ring.element(&Natural::from(50u32));
+    /// let y = ring.element(&Natural::from(60u32));
+    /// assert_eq!((x - &y).to_natural(), 91);
+    /// ```
+    #[inline]
+    fn sub(mut self, other: &MontgomeryNatural<'a>) -> MontgomeryNatural<'a> {
+        self.sub_assign_ref(other);
+        self
+    }
+}
+
+impl<'a> Sub<MontgomeryNatural<'a>> for &MontgomeryNatural<'a> {
+    type Output = MontgomeryNatural<'a>;
+
+    /// Subtracts a [`MontgomeryNatural`] by another [`MontgomeryNatural`]. The first
+    /// [`MontgomeryNatural`] is taken by reference and the second by value.
+    ///
+    /// $f(x, y) = x - y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.ring().modulus().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` belong to rings with different moduli.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::montgomery::ModularRing;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let ring = ModularRing::new(Natural::from(101u32));
+    /// let x = ring.element(&Natural::from(50u32));
+    /// let y = ring.element(&Natural::from(60u32));
+    /// assert_eq!((&x - y).to_natural(), 91);
+    /// ```
+    #[inline]
+    fn sub(self, mut other: MontgomeryNatural<'a>) -> MontgomeryNatural<'a> {
+        other.neg_assign();
+        other.add_assign_ref(self);
+        other
+    }
+}
+
+impl<'a> Sub<&MontgomeryNatural<'a>> for &MontgomeryNatural<'a> {
+    type Output = MontgomeryNatural<'a>;
+
+    /// Subtracts a [`MontgomeryNatural`] by another [`MontgomeryNatural`]. Both
+    /// [`MontgomeryNatural`]s are taken by reference.
+    ///
+    /// $f(x, y) = x - y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.ring().modulus().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` belong to rings with different moduli.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::montgomery::ModularRing;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let ring = ModularRing::new(Natural::from(101u32));
+    /// let x = ring.element(&Natural::from(50u32));
+    /// let y = ring.element(&Natural::from(60u32));
+    /// assert_eq!((&x - &y).to_natural(), 91);
+    /// ```
+    fn sub(self, other: &MontgomeryNatural<'a>) -> MontgomeryNatural<'a> {
+        let mut out = self.clone();
+        out.sub_assign_ref(other);
+        out
+    }
+}
+
+impl<'a> SubAssign<MontgomeryNatural<'a>> for MontgomeryNatural<'a> {
+    /// Subtracts a [`MontgomeryNatural`] by another [`MontgomeryNatural`] in place. The
+    /// [`MontgomeryNatural`] on the right-hand side is taken by value.
+    ///
+    /// $x \gets x - y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.ring().modulus().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` belong to rings with different moduli.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::montgomery::ModularRing;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let ring = ModularRing::new(Natural::from(101u32));
+    /// let mut x = ring.element(&Natural::from(50u32));
+    /// let y = ring.element(&Natural::from(60u32));
+    /// x -= y;
+    /// assert_eq!(x.to_natural(), 91);
+    /// ```
+    #[inline]
+    fn sub_assign(&mut self, other: MontgomeryNatural<'a>) {
+        self.sub_assign_ref(&other);
+    }
+}
+
+impl<'a> SubAssign<&MontgomeryNatural<'a>> for MontgomeryNatural<'a> {
+    /// Subtracts a [`MontgomeryNatural`] by another [`MontgomeryNatural`] in place. The
+    /// [`MontgomeryNatural`] on the right-hand side is taken by reference.
+    ///
+    /// $x \gets x - y \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.ring().modulus().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` belong to rings with different moduli.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::montgomery::ModularRing;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let ring = ModularRing::new(Natural::from(101u32));
+    /// let mut x = ring.element(&Natural::from(50u32));
+    /// let y = ring.element(&Natural::from(60u32));
+    /// x -= &y;
+    /// assert_eq!(x.to_natural(), 91);
+    /// ```
+    #[inline]
+    fn sub_assign(&mut self, other: &MontgomeryNatural<'a>) {
+        self.sub_assign_ref(other);
+    }
+}
+
+impl<'a> Mul<MontgomeryNatural<'a>> for MontgomeryNatural<'a> {
+    type Output = MontgomeryNatural<'a>;
+
+    /// Multiplies two [`MontgomeryNatural`]s. Both [`MontgomeryNatural`]s are taken by value.
+    ///
+    /// $f(x, y) = xy \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.ring().modulus().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` belong to rings with different moduli.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::montgomery::ModularRing;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let ring = ModularRing::new(Natural::from(101u32));
+    /// let x = ring.element(&Natural::from(50u32));
+    /// let y = ring.element(&Natural::from(60u32));
+    /// assert_eq!((x * y).to_natural(), 71);
+    /// ```
+    #[inline]
+    fn mul(mut self, other: MontgomeryNatural<'a>) -> MontgomeryNatural<'a> {
+        self.mul_assign_ref(&other);
+        self
+    }
+}
+
+impl<'a> Mul<&MontgomeryNatural<'a>> for MontgomeryNatural<'a> {
+    type Output = MontgomeryNatural<'a>;
+
+    /// Multiplies two [`MontgomeryNatural`]s. The first [`MontgomeryNatural`] is taken by value and
+    /// the second by reference.
+    ///
+    /// $f(x, y) = xy \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.ring().modulus().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` belong to rings with different moduli.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::montgomery::ModularRing;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let ring = ModularRing::new(Natural::from(101u32));
+    /// let x = ring.element(&Natural::from(50u32));
+    /// let y = ring.element(&Natural::from(60u32));
+    /// assert_eq!((x * &y).to_natural(), 71);
+    /// ```
+    #[inline]
+    fn mul(mut self, other: &MontgomeryNatural<'a>) -> MontgomeryNatural<'a> {
+        self.mul_assign_ref(other);
+        self
+    }
+}
+
+impl<'a> Mul<MontgomeryNatural<'a>> for &MontgomeryNatural<'a> {
+    type Output = MontgomeryNatural<'a>;
+
+    /// Multiplies two [`MontgomeryNatural`]s. The first [`MontgomeryNatural`] is taken by reference
+    /// and the second by value.
+    ///
+    /// $f(x, y) = xy \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.ring().modulus().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` belong to rings with different moduli.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::montgomery::ModularRing;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let ring = ModularRing::new(Natural::from(101u32));
+    /// let x = ring.element(&Natural::from(50u32));
+    /// let y = ring.element(&Natural::from(60u32));
+    /// assert_eq!((&x * y).to_natural(), 71);
+    /// ```
+    #[inline]
+    fn mul(self, mut other: MontgomeryNatural<'a>) -> MontgomeryNatural<'a> {
+        other.mul_assign_ref(self);
+        other
+    }
+}
+
+impl<'a> Mul<&MontgomeryNatural<'a>> for &MontgomeryNatural<'a> {
+    type Output = MontgomeryNatural<'a>;
+
+    /// Multiplies two [`MontgomeryNatural`]s. Both [`MontgomeryNatural`]s are taken by reference.
+    ///
+    /// $f(x, y) = xy \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.ring().modulus().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` belong to rings with different moduli.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::montgomery::ModularRing;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let ring = ModularRing::new(Natural::from(101u32));
+    /// let x = ring.element(&Natural::from(50u32));
+    /// let y = ring.element(&Natural::from(60u32));
+    /// assert_eq!((&x * &y).to_natural(), 71);
+    /// ```
+    fn mul(self, other: &MontgomeryNatural<'a>) -> MontgomeryNatural<'a> {
+        let mut out = self.clone();
+        out.mul_assign_ref(other);
+        out
+    }
+}
+
+impl<'a> MulAssign<MontgomeryNatural<'a>> for MontgomeryNatural<'a> {
+    /// Multiplies a [`MontgomeryNatural`] by a [`MontgomeryNatural`] in place. The
+    /// [`MontgomeryNatural`] on the right-hand side is taken by value.
+    ///
+    /// $x \gets xy \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.ring().modulus().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` belong to rings with different moduli.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::montgomery::ModularRing;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let ring = ModularRing::new(Natural::from(101u32));
+    /// let mut x = ring.element(&Natural::from(50u32));
+    /// let y = ring.element(&Natural::from(60u32));
+    /// x *= y;
+    /// assert_eq!(x.to_natural(), 71);
+    /// ```
+    #[inline]
+    fn mul_assign(&mut self, other: MontgomeryNatural<'a>) {
+        self.mul_assign_ref(&other);
+    }
+}
+
+impl<'a> MulAssign<&MontgomeryNatural<'a>> for MontgomeryNatural<'a> {
+    /// Multiplies a [`MontgomeryNatural`] by a [`MontgomeryNatural`] in place. The
+    /// [`MontgomeryNatural`] on the right-hand side is taken by reference.
+    ///
+    /// $x \gets xy \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.ring().modulus().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` belong to rings with different moduli.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::montgomery::ModularRing;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let ring = ModularRing::new(Natural::from(101u32));
+    /// let mut x = ring.element(&Natural::from(50u32));
+    /// let y = ring.element(&Natural::from(60u32));
+    /// x *= &y;
+    /// assert_eq!(x.to_natural(), 71);
+    /// ```
+    #[inline]
+    fn mul_assign(&mut self, other: &MontgomeryNatural<'a>) {
+        self.mul_assign_ref(other);
+    }
+}
+
+impl<'a> Neg for MontgomeryNatural<'a> {
+    type Output = MontgomeryNatural<'a>;
+
+    /// Negates a [`MontgomeryNatural`], taken by value.
+    ///
+    /// $f(x) = -x \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.ring().modulus().significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::montgomery::ModularRing;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let ring = ModularRing::new(Natural::from(101u32));
+    /// assert_eq!((-ring.element(&Natural::from(3u32))).to_natural(), 98);
+    /// assert_eq!((-ring.zero()).to_natural(), 0);
+    /// let x = ring.element(&Natural::from(3u32));
+    /// assert_eq!((-x).to_natural(), 98);
+    /// ```
+    #[inline]
+    fn neg(mut self) -> MontgomeryNatural<'a> {
+        self.neg_assign();
+        self
+    }
+}
+
+impl<'a> Neg for &MontgomeryNatural<'a> {
+    type Output = MontgomeryNatural<'a>;
+
+    /// Negates a [`MontgomeryNatural`], taken by reference.
+    ///
+    /// $f(x) = -x \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.ring().modulus().significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::arithmetic::montgomery::ModularRing;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let ring = ModularRing::new(Natural::from(101u32));
+    /// assert_eq!((-ring.element(&Natural::from(3u32))).to_natural(), 98);
+    /// assert_eq!((-ring.zero()).to_natural(), 0);
+    /// let x = ring.element(&Natural::from(3u32));
+    /// assert_eq!((-&x).to_natural(), 98);
+    /// ```
+    fn neg(self) -> MontgomeryNatural<'a> {
+        let mut out = self.clone();
+        out.neg_assign();
+        out
+    }
+}
+
+impl<'a> Pow<u64> for MontgomeryNatural<'a> {
+    type Output = MontgomeryNatural<'a>;
+
+    /// Raises a [`MontgomeryNatural`] to a [`u64`] power. The [`MontgomeryNatural`] is taken by
+    /// value.
+    ///
+    /// $f(x, n) = x^n \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is
+    /// `self.ring().modulus().significant_bits()`, and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_nz::natural::arithmetic::montgomery::ModularRing;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let ring = ModularRing::new(Natural::from(101u32));
+    /// assert_eq!(ring.element(&Natural::from(3u32)).pow(7).to_natural(), 66);
+    /// assert_eq!(ring.element(&Natural::from(3u32)).pow(100).to_natural(), 1);
+    /// assert_eq!(ring.element(&Natural::from(3u32)).pow(0).to_natural(), 1);
+    /// ```
+    #[inline]
+    fn pow(self, exp: u64) -> MontgomeryNatural<'a> {
+        MontgomeryNatural {
+            ring: self.ring,
+            xs: self
+                .ring
+                .pow_limbs(&self.xs, &Natural::from(exp).into_limbs_asc()),
+        }
+    }
+}
+
+impl<'a> Pow<u64> for &MontgomeryNatural<'a> {
+    type Output = MontgomeryNatural<'a>;
+
+    /// Raises a [`MontgomeryNatural`] to a [`u64`] power. The [`MontgomeryNatural`] is taken by
+    /// reference.
+    ///
+    /// $f(x, n) = x^n \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is
+    /// `self.ring().modulus().significant_bits()`, and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_nz::natural::arithmetic::montgomery::ModularRing;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let ring = ModularRing::new(Natural::from(101u32));
+    /// let x = ring.element(&Natural::from(3u32));
+    /// assert_eq!((&x).pow(7).to_natural(), 66);
+    /// assert_eq!((&x).pow(100).to_natural(), 1);
+    /// assert_eq!((&x).pow(0).to_natural(), 1);
+    /// ```
+    #[inline]
+    fn pow(self, exp: u64) -> MontgomeryNatural<'a> {
+        MontgomeryNatural {
+            ring: self.ring,
+            xs: self
+                .ring
+                .pow_limbs(&self.xs, &Natural::from(exp).into_limbs_asc()),
+        }
+    }
+}
+
+impl<'a> Pow<&Natural> for MontgomeryNatural<'a> {
+    type Output = MontgomeryNatural<'a>;
+
+    /// Raises a [`MontgomeryNatural`] to a [`Natural`] power. The [`MontgomeryNatural`] is taken by
+    /// value.
+    ///
+    /// $f(x, n) = x^n \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is
+    /// `self.ring().modulus().significant_bits()`, and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_nz::natural::arithmetic::montgomery::ModularRing;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let ring = ModularRing::new(Natural::from(101u32));
+    /// assert_eq!(
+    ///     ring.element(&Natural::from(3u32)).pow(&Natural::from(10u32).pow(20))
+    ///         .to_natural(),
+    ///     1
+    /// );
+    /// assert_eq!(ring.element(&Natural::from(3u32)).pow(&Natural::from(100u32)).to_natural(), 1);
+    /// assert_eq!(ring.element(&Natural::from(3u32)).pow(&Natural::from(0u32)).to_natural(), 1);
+    /// ```
+    #[inline]
+    fn pow(self, exp: &Natural) -> MontgomeryNatural<'a> {
+        MontgomeryNatural {
+            ring: self.ring,
+            xs: self.ring.pow_limbs(&self.xs, &exp.to_limbs_asc()),
+        }
+    }
+}
+
+impl<'a> Pow<&Natural> for &MontgomeryNatural<'a> {
+    type Output = MontgomeryNatural<'a>;
+
+    /// Raises a [`MontgomeryNatural`] to a [`Natural`] power. The [`MontgomeryNatural`] is taken by
+    /// reference.
+    ///
+    /// $f(x, n) = x^n \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, m) = O(mn \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is
+    /// `self.ring().modulus().significant_bits()`, and $m$ is `exp.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_nz::natural::arithmetic::montgomery::ModularRing;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let ring = ModularRing::new(Natural::from(101u32));
+    /// let x = ring.element(&Natural::from(3u32));
+    /// assert_eq!((&x).pow(&Natural::from(10u32).pow(20)).to_natural(), 1);
+    /// assert_eq!((&x).pow(&Natural::from(100u32)).to_natural(), 1);
+    /// assert_eq!((&x).pow(&Natural::from(0u32)).to_natural(), 1);
+    /// ```
+    #[inline]
+    fn pow(self, exp: &Natural) -> MontgomeryNatural<'a> {
+        MontgomeryNatural {
+            ring: self.ring,
+            xs: self.ring.pow_limbs(&self.xs, &exp.to_limbs_asc()),
+        }
+    }
+}
+
+impl<'a> Reciprocal for MontgomeryNatural<'a> {
+    type Output = MontgomeryNatural<'a>;
+
+    /// Computes the multiplicative inverse of a [`MontgomeryNatural`], taken by value.
+    ///
+    /// $f(x) = x^{-1} \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.ring().modulus().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is not coprime to the modulus. To get `None` instead, use
+    /// [`MontgomeryNatural::checked_reciprocal`].
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Reciprocal;
+    /// use malachite_nz::natural::arithmetic::montgomery::ModularRing;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let ring = ModularRing::new(Natural::from(101u32));
+    /// let x = ring.element(&Natural::from(3u32));
+    /// assert_eq!(x.reciprocal().to_natural(), 34);
+    /// ```
+    #[inline]
+    fn reciprocal(self) -> MontgomeryNatural<'a> {
+        self.checked_reciprocal()
+            .expect("Cannot invert a residue that is not coprime to the modulus")
+    }
+}
+
+impl<'a> Reciprocal for &MontgomeryNatural<'a> {
+    type Output = MontgomeryNatural<'a>;
+
+    /// Computes the multiplicative inverse of a [`MontgomeryNatural`], taken by reference.
+    ///
+    /// $f(x) = x^{-1} \mod m$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.ring().modulus().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is not coprime to the modulus. To get `None` instead, use
+    /// [`MontgomeryNatural::checked_reciprocal`].
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Reciprocal;
+    /// use malachite_nz::natural::arithmetic::montgomery::ModularRing;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// let ring = ModularRing::new(Natural::from(101u32));
+    /// let x = ring.element(&Natural::from(3u32));
+    /// assert_eq!((&x).reciprocal().to_natural(), 34);
+    /// ```
+    #[inline]
+    fn reciprocal(self) -> MontgomeryNatural<'a> {
+        self.checked_reciprocal()
+            .expect("Cannot invert a residue that is not coprime to the modulus")
+    }
+}
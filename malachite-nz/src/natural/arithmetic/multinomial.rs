@@ -0,0 +1,178 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::natural::arithmetic::binomial_coefficient::BIN_GOETGHELUCK_THRESHOLD;
+use crate::natural::arithmetic::mul::product_of_limbs::limbs_product;
+use crate::natural::Natural;
+use crate::platform::Limb;
+use alloc::vec::Vec;
+use malachite_base::num::arithmetic::traits::BinomialCoefficient;
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::conversion::traits::{ConvertibleFrom, ExactFrom, WrappingFrom};
+#[cfg(feature = "32_bit_limbs")]
+use malachite_base::num::factorization::prime_sieve::limbs_prime_sieve_u32;
+#[cfg(not(feature = "32_bit_limbs"))]
+use malachite_base::num::factorization::prime_sieve::limbs_prime_sieve_u64;
+use malachite_base::num::factorization::prime_sieve::{id_to_n, limbs_prime_sieve_size, n_to_bit};
+use malachite_base::num::logic::traits::CountOnes;
+
+const fn digit_sum(mut x: u64, p: u64) -> u64 {
+    let mut sum = 0;
+    while x != 0 {
+        sum += x % p;
+        x /= p;
+    }
+    sum
+}
+
+// Returns the exponent of the odd prime $p$ in the multinomial coefficient. By Legendre's formula,
+// the exponent of $p$ in $x!$ is $(x - s_p(x))/(p - 1)$, where $s_p(x)$ is the sum of the digits of
+// $x$ in base $p$. If $p > k_i$, then $s_p(k_i) = k_i$.
+fn prime_exponent(n: u64, ks: &[u64], max_k: u64, p: u64) -> u64 {
+    let ks_digit_sum = if p > max_k {
+        n
+    } else {
+        ks.iter().map(|&k| digit_sum(k, p)).sum()
+    };
+    (ks_digit_sum - digit_sum(n, p)) / (p - 1)
+}
+
+// Multiplies `prod` by $p^e$, storing it in `factors` whenever it gets too large.
+fn push_prime_power(factors: &mut Vec<Limb>, prod: &mut Limb, max_prod: Limb, p: Limb, e: u64) {
+    for _ in 0..e {
+        if *prod > max_prod {
+            factors.push(*prod);
+            *prod = 1;
+        }
+        *prod *= p;
+    }
+}
+
+// Computes the odd part of the multinomial coefficient with $n = \sum_i k_i$ and all $k_i$
+// nonzero, by finding the exponent of every odd prime up to $n$, in the same way as
+// `limbs_binomial_coefficient_limb_limb_goetgheluck` does for binomial coefficients.
+#[allow(clippy::useless_conversion)]
+fn limbs_multinomial_odd_goetgheluck(n: Limb, ks: &[u64]) -> Vec<Limb> {
+    assert!(n >= BIN_GOETGHELUCK_THRESHOLD);
+    let n_64 = u64::from(n);
+    let max_k = *ks.iter().max().unwrap();
+    let mut sieve = vec![0; limbs_prime_sieve_size::<Limb>(n_64)];
+    #[cfg(feature = "32_bit_limbs")]
+    limbs_prime_sieve_u32(&mut sieve, n_64);
+    #[cfg(not(feature = "32_bit_limbs"))]
+    limbs_prime_sieve_u64(&mut sieve, n);
+    let max_prod = Limb::MAX / n;
+    let mut factors = Vec::new();
+    let mut prod = 1;
+    // Handle prime = 3 separately
+    push_prime_power(
+        &mut factors,
+        &mut prod,
+        max_prod,
+        3,
+        prime_exponent(n_64, ks, max_k, 3),
+    );
+    let mut index = 0;
+    let mut mask = 1;
+    for i in 1..=n_to_bit(n_64) + 1 {
+        if sieve[index] & mask == 0 {
+            let prime = id_to_n(i);
+            let e = prime_exponent(n_64, ks, max_k, prime);
+            push_prime_power(
+                &mut factors,
+                &mut prod,
+                max_prod,
+                Limb::exact_from(prime),
+                e,
+            );
+        }
+        mask <<= 1;
+        if mask == 0 {
+            mask = 1;
+            index += 1;
+        }
+    }
+    factors.push(prod);
+    if factors.len() == 1 {
+        return factors;
+    }
+    let mut r = vec![0; factors.len()];
+    let size = limbs_product(&mut r, &mut factors);
+    r.truncate(size);
+    r
+}
+
+impl Natural {
+    /// Computes the multinomial coefficient $\binom{n}{k_1, k_2, \ldots, k_r}$, where $n = k_1 +
+    /// k_2 + \cdots + k_r$.
+    ///
+    /// $$
+    /// f(k_1, \ldots, k_r) = \frac{(k_1 + \cdots + k_r)!}{k_1! \cdots k_r!}.
+    /// $$
+    ///
+    /// This is the number of ways of dividing $n$ distinguishable objects into $r$ groups of sizes
+    /// $k_1, \ldots, k_r$. When there are two groups, it is the binomial coefficient
+    /// $\binom{n}{k_1}$.
+    ///
+    /// If $n$ is small, or there are at most two nonzero $k_i$, the multinomial coefficient is
+    /// computed as a product of binomial coefficients. Otherwise, the primes up to $n$ are sieved
+    /// out as in [`Natural::factorial`](malachite_base::num::arithmetic::traits::Factorial), the
+    /// exponent of each prime is found using Legendre's formula, and the prime powers are
+    /// multiplied together.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, r) = O(n (\log n)^2 \log\log n + rn / \log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the sum of `ks`, and $r$ is
+    /// `ks.len()`.
+    ///
+    /// # Panics
+    /// Panics if the sum of `ks` is greater than `Limb::MAX`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::multinomial(&[]), 1);
+    /// assert_eq!(Natural::multinomial(&[5]), 1);
+    /// assert_eq!(Natural::multinomial(&[2, 3]), 10);
+    /// assert_eq!(Natural::multinomial(&[2, 3, 4]), 1260);
+    /// assert_eq!(Natural::multinomial(&[1, 1, 1, 1, 1]), 120);
+    /// assert_eq!(
+    ///     Natural::multinomial(&[10, 20, 30]).to_string(),
+    ///     "3553261127084984957001360"
+    /// );
+    /// ```
+    #[allow(clippy::useless_conversion)]
+    pub fn multinomial(ks: &[u64]) -> Natural {
+        let n = ks
+            .iter()
+            .try_fold(0u64, |sum, &k| sum.checked_add(k))
+            .unwrap();
+        assert!(Limb::convertible_from(n));
+        let ks: Vec<u64> = ks.iter().copied().filter(|&k| k != 0).collect();
+        if ks.len() <= 2 || n < u64::from(BIN_GOETGHELUCK_THRESHOLD) {
+            let mut result = Natural::ONE;
+            let mut sum = 0;
+            for k in ks {
+                sum += k;
+                result *= Natural::binomial_coefficient(Natural::from(sum), Natural::from(k));
+            }
+            return result;
+        }
+        // By Legendre's formula, the exponent of 2 in $x!$ is $x - s_2(x)$.
+        let twos =
+            ks.iter().map(|&k| CountOnes::count_ones(k)).sum::<u64>() - CountOnes::count_ones(n);
+        Natural::from_owned_limbs_asc(limbs_multinomial_odd_goetgheluck(
+            Limb::wrapping_from(n),
+            &ks,
+        )) << twos
+    }
+}
@@ -0,0 +1,214 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::natural::arithmetic::approximate_real::ApproximateReal;
+use crate::natural::Natural;
+use alloc::vec::Vec;
+use core::f64::consts::{LN_2, PI};
+use malachite_base::num::arithmetic::traits::Partitions;
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+
+// Below this, Euler's pentagonal number recurrence is faster than the Hardy–Ramanujan–Rademacher
+// formula.
+const HRR_THRESHOLD: u64 = 400;
+
+// Computes $p(0), p(1), \ldots, p(n)$ using Euler's pentagonal number theorem:
+// $$
+// p(n) = \sum_{k \geq 1} (-1)^{k+1} (p(n - k(3k-1)/2) + p(n - k(3k+1)/2)).
+// $$
+fn partitions_recurrence(n: u64) -> Natural {
+    let n = usize::exact_from(n);
+    let mut ps: Vec<Natural> = Vec::with_capacity(n + 1);
+    ps.push(Natural::ONE);
+    for i in 1..=n {
+        let mut positive = Natural::ZERO;
+        let mut negative = Natural::ZERO;
+        for k in 1.. {
+            let g = k * (3 * k - 1) / 2;
+            if g > i {
+                break;
+            }
+            let sum = if k & 1 == 1 {
+                &mut positive
+            } else {
+                &mut negative
+            };
+            *sum += &ps[i - g];
+            if g + k <= i {
+                *sum += &ps[i - g - k];
+            }
+        }
+        ps.push(positive - negative);
+    }
+    ps.pop().unwrap()
+}
+
+// An upper bound, due to Rademacher, for the error made by truncating the Hardy–Ramanujan–
+// Rademacher series for $p(n)$ after $N$ terms. It is evaluated in floating-point arithmetic, whose
+// relative error is tiny, so it is only compared against thresholds with a wide margin.
+fn rademacher_remainder_bound(n: f64, terms: f64) -> f64 {
+    44.0 * PI * PI / (225.0 * libm::sqrt(3.0) * libm::sqrt(terms))
+        + PI * libm::sqrt(2.0) / 75.0
+            * libm::sqrt(terms / (n - 1.0))
+            * libm::sinh(PI / terms * libm::sqrt(2.0 * n / 3.0))
+}
+
+// Returns $\sum (-1)^l \cos(\pi(6l+1)/(6k))$, taken over all $0 \leq l < 2k$ such that $(3l^2+l)/2
+// \equiv -n \mod k$, or `None` if there are no such $l$. By a formula due to Selberg, $A_k(n)$, the
+// Kloosterman-type sum in the Hardy–Ramanujan–Rademacher formula, is $\sqrt{k/3}$ times this sum.
+fn selberg_sum(n: u64, k: u64, pi: &ApproximateReal, prec: u64) -> Option<ApproximateReal> {
+    let k_128 = u128::from(k);
+    let target = (k_128 - u128::from(n) % k_128) % k_128;
+    let mut sum: Option<ApproximateReal> = None;
+    for l in 0..k << 1 {
+        let l_128 = u128::from(l);
+        if (3 * l_128 * l_128 + l_128) / 2 % k_128 != target {
+            continue;
+        }
+        // Reduce the angle $\pi(6l+1)/(6k)$ to $\pi a/(6k)$, where $0 \leq a \leq 3k$.
+        let twelve_k = 12 * k;
+        let mut a = (6 * l + 1) % twelve_k;
+        if a > 6 * k {
+            a = twelve_k - a;
+        }
+        let mut negative = l & 1 == 1;
+        if a > 3 * k {
+            a = 6 * k - a;
+            negative = !negative;
+        }
+        let mut c = pi.mul_u64(a, prec).div_u64(6 * k, prec).cos(prec);
+        if negative {
+            c = c.negate();
+        }
+        sum = Some(match sum {
+            None => c,
+            Some(sum) => sum.add(&c, prec),
+        });
+    }
+    sum
+}
+
+// Computes $p(n)$ using the Hardy–Ramanujan–Rademacher formula. Writing $m = 24n - 1$ and $\mu_k =
+// \pi\sqrt{m}/(6k)$, the formula becomes
+// $$
+// p(n) = \frac{24}{\pi m^{3/2}} \sum_{k=1}^\infty k S_k (\mu_k \cosh \mu_k - \sinh \mu_k),
+// $$
+// where $S_k$ is the sum computed by `selberg_sum`. The series is truncated once Rademacher's
+// bound on the remainder drops below $1/8$, and $1/4$ is added to the error of the sum to account
+// for the remainder. Each term is computed with just enough precision to make its absolute error
+// small; the errors are tracked, and if rounding the sum is not guaranteed to give $p(n)$, the
+// computation is repeated with more precision.
+fn partitions_hrr(n: u64) -> Natural {
+    #[allow(clippy::cast_precision_loss)]
+    let n_f64 = n as f64;
+    let mut terms = 1;
+    #[allow(clippy::cast_precision_loss)]
+    while rademacher_remainder_bound(n_f64, terms as f64) >= 0.125 {
+        terms += 1;
+    }
+    let m = n.checked_mul(24).unwrap() - 1;
+    #[allow(clippy::cast_precision_loss)]
+    let sqrt_m_f64 = libm::sqrt(m as f64);
+    let mut guard_bits = (n.significant_bits() << 1) + terms.significant_bits() + 32;
+    loop {
+        if let Some(p) = partitions_hrr_with_precision(n, m, sqrt_m_f64, terms, guard_bits) {
+            return p;
+        }
+        guard_bits <<= 1;
+    }
+}
+
+fn partitions_hrr_with_precision(
+    n: u64,
+    m: u64,
+    sqrt_m_f64: f64,
+    terms: u64,
+    guard_bits: u64,
+) -> Option<Natural> {
+    // The number of bits needed for the $k$th term, based on the size of $e^{\mu_k}$.
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_precision_loss,
+        clippy::cast_sign_loss
+    )]
+    let term_prec = |k: u64| (PI * sqrt_m_f64 / (6.0 * k as f64) / LN_2) as u64 + guard_bits;
+    let prec = term_prec(1);
+    let pi = ApproximateReal::pi(prec);
+    let sqrt_m = ApproximateReal::from(m).sqrt(prec);
+    let pi_sqrt_m = pi.mul(&sqrt_m, prec);
+    let mut sum = ApproximateReal::ZERO;
+    for k in 1..=terms {
+        let k_prec = term_prec(k);
+        let Some(s) = selberg_sum(n, k, &pi, k_prec) else {
+            continue;
+        };
+        let mu = pi_sqrt_m.div_u64(6 * k, k_prec);
+        let e = mu.exp(k_prec);
+        let e_inverse = ApproximateReal::ONE.div(&e, k_prec);
+        // $\mu \cosh \mu - \sinh \mu = ((\mu - 1)e^\mu + (\mu + 1)e^{-\mu})/2$
+        let f = mu
+            .sub(&ApproximateReal::ONE, k_prec)
+            .mul(&e, k_prec)
+            .add(
+                &mu.add(&ApproximateReal::ONE, k_prec)
+                    .mul(&e_inverse, k_prec),
+                k_prec,
+            )
+            .mul_power_of_2(-1);
+        sum = sum.add(&f.mul(&s, k_prec).mul_u64(24 * k, k_prec), prec);
+    }
+    let denominator = pi_sqrt_m.mul_u64(m, prec);
+    sum.div(&denominator, prec)
+        .add_error(-2)
+        .round()
+        .map(Natural::exact_from)
+}
+
+impl Partitions for Natural {
+    /// Computes the number of partitions of $n$.
+    ///
+    /// $f(n) = p(n)$, the number of ways of writing $n$ as a sum of positive integers, where the
+    /// order of the summands does not matter.
+    ///
+    /// For small $n$, Euler's pentagonal number recurrence is used. For larger $n$, the
+    /// Hardy–Ramanujan–Rademacher formula is evaluated numerically; this needs only $O(n^{1/2})$
+    /// terms, and the numerical precision decreases from term to term. The numerical error and the
+    /// error from truncating the series are bounded rigorously, and the precision is increased
+    /// until rounding is guaranteed to give $p(n)$ exactly.
+    ///
+    /// $p(n) = O(e^{\pi\sqrt{2n/3}}/n)$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n^{1/2})$
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Partitions;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::partitions(0), 1);
+    /// assert_eq!(Natural::partitions(1), 1);
+    /// assert_eq!(Natural::partitions(5), 7);
+    /// assert_eq!(Natural::partitions(100), 190569292);
+    /// assert_eq!(
+    ///     Natural::partitions(1000).to_string(),
+    ///     "24061467864032622473692149727991"
+    /// );
+    /// ```
+    fn partitions(n: u64) -> Natural {
+        if n < HRR_THRESHOLD {
+            partitions_recurrence(n)
+        } else {
+            partitions_hrr(n)
+        }
+    }
+}
@@ -0,0 +1,179 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::natural::Natural;
+use alloc::vec::Vec;
+use core::cmp::min;
+use malachite_base::num::arithmetic::traits::{
+    BinomialCoefficient, DivExact, DivExactAssign, Factorial, Pow, Stirling,
+};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+
+// Multiplies two polynomials, given as coefficient vectors in ascending order, discarding all
+// coefficients of degree `len` or more.
+fn mul_truncated(xs: &[Natural], ys: &[Natural], len: usize) -> Vec<Natural> {
+    let mut product = vec![Natural::ZERO; min(xs.len() + ys.len() - 1, len)];
+    for (i, x) in xs.iter().enumerate() {
+        if *x == 0u32 {
+            continue;
+        }
+        for (j, y) in ys.iter().take(product.len().saturating_sub(i)).enumerate() {
+            product[i + j] += x * y;
+        }
+    }
+    product
+}
+
+// Returns the product of the polynomials $a_i + b_i x$ for $i$ in `[start, end)`, truncated to
+// `len` coefficients, using a product tree.
+fn linear_product_truncated(
+    start: u64,
+    end: u64,
+    factor: &impl Fn(u64) -> (Natural, Natural),
+    len: usize,
+) -> Vec<Natural> {
+    if end - start == 1 {
+        let (a, b) = factor(start);
+        let mut p = vec![a, b];
+        p.truncate(len);
+        p
+    } else {
+        let mid = start + ((end - start) >> 1);
+        mul_truncated(
+            &linear_product_truncated(start, mid, factor, len),
+            &linear_product_truncated(mid, end, factor, len),
+            len,
+        )
+    }
+}
+
+fn stirling1(n: u64, k: u64) -> Natural {
+    if k > n {
+        return Natural::ZERO;
+    } else if k == n {
+        return Natural::ONE;
+    } else if k == 0 {
+        return Natural::ZERO;
+    } else if k == 1 {
+        return Natural::factorial(n - 1);
+    } else if k == n - 1 {
+        return Natural::binomial_coefficient(Natural::from(n), Natural::from(2u32));
+    }
+    // $\left[{n \atop k}\right]$ is the coefficient of $x^{k-1}$ in $\prod_{i=1}^{n-1} (x + i)$,
+    // or, equivalently, the coefficient of $x^{n-k}$ in $\prod_{i=1}^{n-1} (1 + ix)$. Whichever
+    // degree is smaller determines how far the products are truncated.
+    let low = k - 1;
+    let high = n - k;
+    if low <= high {
+        let len = usize::exact_from(low + 1);
+        linear_product_truncated(1, n, &|i| (Natural::from(i), Natural::ONE), len)
+            .swap_remove(len - 1)
+    } else {
+        let len = usize::exact_from(high + 1);
+        linear_product_truncated(1, n, &|i| (Natural::ONE, Natural::from(i)), len)
+            .swap_remove(len - 1)
+    }
+}
+
+fn stirling2(n: u64, k: u64) -> Natural {
+    if k > n {
+        return Natural::ZERO;
+    } else if k == n {
+        return Natural::ONE;
+    } else if k == 0 {
+        return Natural::ZERO;
+    } else if k == 1 {
+        return Natural::ONE;
+    } else if k == n - 1 {
+        return Natural::binomial_coefficient(Natural::from(n), Natural::from(2u32));
+    }
+    // $\left\\{{n \atop k}\right\\} = \frac{1}{k!} \sum_{j=0}^k (-1)^{k-j} \binom{k}{j} j^n$. The
+    // positive and negative terms are summed separately.
+    let mut positive = Natural::ZERO;
+    let mut negative = Natural::ZERO;
+    let mut binomial = Natural::ONE;
+    for j in 1..=k {
+        binomial *= Natural::from(k - j + 1);
+        binomial.div_exact_assign(Natural::from(j));
+        let term = &binomial * Natural::from(j).pow(n);
+        if (k - j) & 1 == 0 {
+            positive += term;
+        } else {
+            negative += term;
+        }
+    }
+    (positive - negative).div_exact(Natural::factorial(k))
+}
+
+impl Stirling for Natural {
+    /// Computes an unsigned Stirling number of the first kind.
+    ///
+    /// $f(n, k) = \left[{n \atop k}\right]$, the number of permutations of $n$ elements with
+    /// exactly $k$ cycles. Equivalently, $\left[{n \atop k}\right]$ is the coefficient of $x^k$ in
+    /// the rising factorial $x(x+1)\cdots(x+n-1)$.
+    ///
+    /// The coefficient is extracted from a product tree of linear polynomials, truncated to the
+    /// needed degree.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 (\log n)^2)$
+    ///
+    /// $M(n) = O(n^2 \log n)$
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Stirling;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::stirling1(0, 0), 1);
+    /// assert_eq!(Natural::stirling1(4, 2), 11);
+    /// assert_eq!(Natural::stirling1(10, 5), 269325);
+    /// assert_eq!(Natural::stirling1(5, 6), 0);
+    /// assert_eq!(
+    ///     Natural::stirling1(50, 10).to_string(),
+    ///     "101623020926367490059043797119309944043405505380503665627365376"
+    /// );
+    /// ```
+    #[inline]
+    fn stirling1(n: u64, k: u64) -> Natural {
+        stirling1(n, k)
+    }
+
+    /// Computes a Stirling number of the second kind.
+    ///
+    /// $f(n, k) = \left\\{{n \atop k}\right\\}$, the number of partitions of a set of $n$ elements
+    /// into exactly $k$ nonempty subsets.
+    ///
+    /// The explicit formula $\left\\{{n \atop k}\right\\} = \frac{1}{k!} \sum_{j=0}^k (-1)^{k-j}
+    /// \binom{k}{j} j^n$ is used, so only $O(k)$ large multiplications are needed.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Stirling;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::stirling2(0, 0), 1);
+    /// assert_eq!(Natural::stirling2(4, 2), 7);
+    /// assert_eq!(Natural::stirling2(10, 5), 42525);
+    /// assert_eq!(Natural::stirling2(5, 6), 0);
+    /// assert_eq!(
+    ///     Natural::stirling2(50, 10).to_string(),
+    ///     "26154716515862881292012777396577993781727011"
+    /// );
+    /// ```
+    #[inline]
+    fn stirling2(n: u64, k: u64) -> Natural {
+        stirling2(n, k)
+    }
+}
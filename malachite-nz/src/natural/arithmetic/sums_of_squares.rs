@@ -0,0 +1,278 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::natural::arithmetic::cornacchia::cornacchia_with_root;
+use crate::natural::Natural;
+use core::mem::swap;
+use malachite_base::num::arithmetic::traits::{
+    AbsDiff, FloorSqrt, FourSquares, ModPowerOf2, ModSqrt, Parity, Pow, Square, TwoSquares,
+};
+use malachite_base::num::basic::traits::{One, Two, Zero};
+use malachite_base::num::factorization::traits::{Factor, IsPrime};
+
+// Writes a prime $p \equiv 1 \mod 4$ as $a^2 + b^2$. A square root $r$ of $-1$ modulo $p$ is found,
+// and the Euclidean algorithm is run on $p$ and $r$ until the remainder $a$ satisfies $a^2 < p$;
+// then $p - a^2$ is a perfect square $b^2$. This is Cornacchia's algorithm with $d = 1$, also known
+// as the Hermite–Serret algorithm.
+fn prime_two_squares(p: &Natural) -> (Natural, Natural) {
+    (p - Natural::ONE)
+        .mod_sqrt(p)
+        .and_then(|r| cornacchia_with_root(&Natural::ONE, p, r))
+        .unwrap_or_else(|| panic!("{p} is not a prime congruent to 1 mod 4"))
+}
+
+// Multiplies the Gaussian integers $a + bi$ and $c + di$. Since only the norm of the result
+// matters, the real part is replaced by its absolute value.
+fn gaussian_mul(a: &Natural, b: &Natural, c: &Natural, d: &Natural) -> (Natural, Natural) {
+    ((a * c).abs_diff(b * d), a * d + b * c)
+}
+
+fn gaussian_pow(a: Natural, b: Natural, mut e: u64) -> (Natural, Natural) {
+    let mut result = (Natural::ONE, Natural::ZERO);
+    let mut power = (a, b);
+    loop {
+        if e.odd() {
+            result = gaussian_mul(&result.0, &result.1, &power.0, &power.1);
+        }
+        e >>= 1;
+        if e == 0 {
+            return result;
+        }
+        power = gaussian_mul(&power.0, &power.1, &power.0, &power.1);
+    }
+}
+
+// Returns the largest number less than or equal to `x` with the given parity, if there is one.
+fn largest_with_parity(x: Natural, odd: bool) -> Option<Natural> {
+    if x.odd() == odd {
+        Some(x)
+    } else if x == 0u32 {
+        None
+    } else {
+        Some(x - Natural::ONE)
+    }
+}
+
+// Searches for $x$ and $y$ such that $n - x^2 - y^2$ can be written as a sum of two squares by
+// `two_squares`. $n$ must not be divisible by 4. The parities of $x$ and $y$ are chosen so that $n -
+// x^2 - y^2 \equiv 1 \mod 4$. $x$ starts at $\lfloor\sqrt n\rfloor$, so that the remainders are
+// small, and decreases.
+fn four_squares_search<F: Fn(&Natural) -> Option<(Natural, Natural)>>(
+    n: &Natural,
+    two_squares: F,
+) -> Option<[Natural; 4]> {
+    let r = n.mod_power_of_2(2);
+    let mut x = largest_with_parity(n.floor_sqrt(), r != 1)?;
+    loop {
+        let remainder = n - (&x).square();
+        let mut oy = largest_with_parity((&remainder).floor_sqrt(), r == 3);
+        while let Some(y) = oy {
+            if let Some((a, b)) = two_squares(&(&remainder - (&y).square())) {
+                return Some([a, b, y, x]);
+            }
+            oy = (y >= 2u32).then(|| y - Natural::TWO);
+        }
+        if x < 2u32 {
+            return None;
+        }
+        x -= Natural::TWO;
+    }
+}
+
+impl TwoSquares for Natural {
+    /// Writes a [`Natural`] as a sum of two squares, if possible.
+    ///
+    /// Returns `Some((a, b))` with $a \leq b$ and $a^2 + b^2 = n$, or `None` if $n$ is not a sum
+    /// of two squares; that is, if some prime congruent to 3 mod 4 divides $n$ to an odd power.
+    ///
+    /// The [`Natural`] is factored first; if its factorization is already known, use
+    /// [`two_squares_from_factorization`](TwoSquares::two_squares_from_factorization) instead.
+    ///
+    /// # Worst-case complexity
+    /// See [`factor`](Factor::factor).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::{Pow, TwoSquares};
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Natural::from(0u32).two_squares().to_debug_string(),
+    ///     "Some((0, 0))"
+    /// );
+    /// assert_eq!(
+    ///     Natural::from(13u32).two_squares().to_debug_string(),
+    ///     "Some((2, 3))"
+    /// );
+    /// assert_eq!(
+    ///     Natural::from(1000u32).two_squares().to_debug_string(),
+    ///     "Some((18, 26))"
+    /// );
+    /// assert_eq!(Natural::from(21u32).two_squares(), None);
+    /// assert_eq!(
+    ///     (Natural::from(2u32).pow(127) + Natural::from(45u32))
+    ///         .two_squares()
+    ///         .to_debug_string(),
+    ///     "Some((2660186053251381107, 12769674765731352482))"
+    /// );
+    /// ```
+    fn two_squares(&self) -> Option<(Natural, Natural)> {
+        if *self == 0u32 {
+            Some((Natural::ZERO, Natural::ZERO))
+        } else {
+            Natural::two_squares_from_factorization(&self.factor())
+        }
+    }
+
+    /// Writes a [`Natural`] as a sum of two squares, if possible, given its prime factorization
+    /// as a list of distinct primes, each paired with its exponent.
+    ///
+    /// Returns `Some((a, b))` with $a \leq b$ and $a^2 + b^2 = n$, or `None` if $n$ is not a sum
+    /// of two squares. The factorization is not checked for validity.
+    ///
+    /// Each prime congruent to 1 mod 4 is written as a sum of two squares using Cornacchia's
+    /// algorithm, and the representations are combined by multiplying the corresponding Gaussian
+    /// integers.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the number of bits of the
+    /// represented number.
+    ///
+    /// # Panics
+    /// Panics if any exponent is 0. May panic if a factor congruent to 1 mod 4 is not prime.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::TwoSquares;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Natural::two_squares_from_factorization(&[]).to_debug_string(),
+    ///     "Some((0, 1))"
+    /// );
+    /// assert_eq!(
+    ///     Natural::two_squares_from_factorization(&[
+    ///         (Natural::from(2u32), 1),
+    ///         (Natural::from(3u32), 2),
+    ///         (Natural::from(5u32), 1),
+    ///         (Natural::from(13u32), 1)
+    ///     ])
+    ///     .to_debug_string(),
+    ///     "Some((9, 33))"
+    /// );
+    /// assert_eq!(
+    ///     Natural::two_squares_from_factorization(&[
+    ///         (Natural::from(3u32), 1),
+    ///         (Natural::from(5u32), 1)
+    ///     ]),
+    ///     None
+    /// );
+    /// ```
+    fn two_squares_from_factorization(factors: &[(Natural, u64)]) -> Option<(Natural, Natural)> {
+        // $n = (a^2 + b^2) c^2$, where $a + bi$ is a Gaussian integer.
+        let mut a = Natural::ONE;
+        let mut b = Natural::ZERO;
+        let mut c = Natural::ONE;
+        for (p, e) in factors {
+            let e = *e;
+            assert_ne!(e, 0, "Exponents in a factorization must be positive");
+            if *p == 2u32 {
+                // $2 = (1 + i)(1 - i)$ and $(1 + i)^2 = 2i$.
+                c <<= e >> 1;
+                if e.odd() {
+                    (a, b) = (&a + &b, a.abs_diff(&b));
+                }
+            } else if p.mod_power_of_2(2) == 3 {
+                if e.odd() {
+                    return None;
+                }
+                c *= p.pow(e >> 1);
+            } else {
+                let (x, y) = prime_two_squares(p);
+                let (x, y) = gaussian_pow(x, y, e);
+                (a, b) = gaussian_mul(&a, &b, &x, &y);
+            }
+        }
+        if a > b {
+            swap(&mut a, &mut b);
+        }
+        Some((a * &c, b * c))
+    }
+}
+
+impl FourSquares for Natural {
+    /// Writes a [`Natural`] as a sum of four squares.
+    ///
+    /// Returns $(a, b, c, d)$ with $a \leq b \leq c \leq d$ and $a^2 + b^2 + c^2 + d^2 = n$. By
+    /// Lagrange's four-square theorem, such a representation always exists.
+    ///
+    /// This uses a deterministic variant of the Rabin–Shallit method. After removing factors of 4,
+    /// $x$ and $y$ are chosen so that $p = n - x^2 - y^2$ is a prime congruent to 1 mod 4, and $p$
+    /// is written as a sum of two squares using Cornacchia's algorithm. No factoring is needed.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n \log\log n)$, heuristically
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::{FourSquares, Pow};
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(
+    ///     Natural::from(0u32).four_squares().to_debug_string(),
+    ///     "(0, 0, 0, 0)"
+    /// );
+    /// assert_eq!(
+    ///     Natural::from(7u32).four_squares().to_debug_string(),
+    ///     "(1, 1, 1, 2)"
+    /// );
+    /// assert_eq!(
+    ///     Natural::from(96u32).four_squares().to_debug_string(),
+    ///     "(0, 4, 4, 8)"
+    /// );
+    /// assert_eq!(
+    ///     Natural::from(10u32).pow(30).four_squares().to_debug_string(),
+    ///     "(66125824, 88309760, 8094679040, 999999999967232)"
+    /// );
+    /// ```
+    fn four_squares(&self) -> (Natural, Natural, Natural, Natural) {
+        if *self == 0u32 {
+            return (Natural::ZERO, Natural::ZERO, Natural::ZERO, Natural::ZERO);
+        }
+        let k = self.trailing_zeros().unwrap() >> 1;
+        let n = self >> (k << 1);
+        let mut squares = four_squares_search(&n, |p| {
+            if *p == 1u32 {
+                Some((Natural::ZERO, Natural::ONE))
+            } else if p.mod_power_of_2(2) == 1 && p.is_prime() {
+                Some(prime_two_squares(p))
+            } else {
+                None
+            }
+        })
+        // Every number that is not divisible by 4 seems to have a representation of this form,
+        // but this is not known to be true. Lagrange's theorem guarantees that the fallback
+        // succeeds.
+        .or_else(|| four_squares_search(&n, Natural::two_squares))
+        .unwrap();
+        squares.sort_unstable();
+        let [a, b, c, d] = squares;
+        (a << k, b << k, c << k, d << k)
+    }
+}
@@ -0,0 +1,188 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::natural::Natural;
+use alloc::vec;
+use alloc::vec::IntoIter;
+use malachite_base::num::arithmetic::traits::Pow;
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::factorization::traits::{DivisorCount, DivisorSigma, Divisors, Factor};
+
+impl DivisorCount for Natural {
+    /// Counts the positive divisors of a [`Natural`].
+    ///
+    /// The [`Natural`] is factored first; if its factorization is already known, use
+    /// [`divisor_count_from_factorization`](DivisorCount::divisor_count_from_factorization)
+    /// instead.
+    ///
+    /// $f(n) = d(n) = \sigma_0(n) = \sum_{d | n} 1$.
+    ///
+    /// # Worst-case complexity
+    /// See [`factor`](Factor::factor).
+    ///
+    /// # Panics
+    /// Panics if `self` is 0.
+    ///
+    /// # Examples
+    /// See [here](super::divisors#divisor_count).
+    #[inline]
+    fn divisor_count(&self) -> Natural {
+        Natural::divisor_count_from_factorization(&self.factor())
+    }
+
+    /// Counts the positive divisors of a [`Natural`], given its prime factorization as a list of
+    /// distinct primes, each paired with its exponent.
+    ///
+    /// The factorization is not checked for validity.
+    ///
+    /// $$
+    /// f(((p_1, e_1), \ldots, (p_k, e_k))) = \prod_{i=1}^k (e_i + 1).
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `factors.len()`.
+    ///
+    /// # Panics
+    /// Panics if any exponent is 0.
+    ///
+    /// # Examples
+    /// See [here](super::divisors#divisor_count_from_factorization).
+    fn divisor_count_from_factorization(factors: &[(Natural, u64)]) -> Natural {
+        factors.iter().fold(Natural::ONE, |count, &(_, e)| {
+            assert_ne!(e, 0, "Exponents in a factorization must be positive");
+            count * Natural::from(e + 1)
+        })
+    }
+}
+
+impl DivisorSigma for Natural {
+    /// Computes the divisor function $\sigma_k(n)$ of a [`Natural`]: the sum of the $k$th powers of
+    /// its positive divisors.
+    ///
+    /// The [`Natural`] is factored first; if its factorization is already known, use
+    /// [`divisor_sigma_from_factorization`](DivisorSigma::divisor_sigma_from_factorization)
+    /// instead.
+    ///
+    /// $f(n, k) = \sigma_k(n) = \sum_{d | n} d^k$.
+    ///
+    /// # Worst-case complexity
+    /// See [`factor`](Factor::factor).
+    ///
+    /// # Panics
+    /// Panics if `self` is 0.
+    ///
+    /// # Examples
+    /// See [here](super::divisors#divisor_sigma).
+    #[inline]
+    fn divisor_sigma(&self, k: u64) -> Natural {
+        Natural::divisor_sigma_from_factorization(&self.factor(), k)
+    }
+
+    /// Computes the divisor function $\sigma_k(n)$ of a [`Natural`], given its prime factorization
+    /// as a list of distinct primes, each paired with its exponent.
+    ///
+    /// The factorization is not checked for validity.
+    ///
+    /// $$
+    /// f(((p_1, e_1), \ldots, (p_r, e_r)), k) = \prod_{i=1}^r \sum_{j=0}^{e_i} p_i^{jk}.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the number of bits of the output.
+    ///
+    /// # Panics
+    /// Panics if any exponent is 0.
+    ///
+    /// # Examples
+    /// See [here](super::divisors#divisor_sigma_from_factorization).
+    fn divisor_sigma_from_factorization(factors: &[(Natural, u64)], k: u64) -> Natural {
+        let mut sigma = Natural::ONE;
+        for (p, e) in factors {
+            assert_ne!(*e, 0, "Exponents in a factorization must be positive");
+            let p_k = p.pow(k);
+            // Evaluate $1 + p^k + p^{2k} + \cdots + p^{ek}$ using Horner's rule.
+            let mut sum = Natural::ONE;
+            for _ in 0..*e {
+                sum *= &p_k;
+                sum += Natural::ONE;
+            }
+            sigma *= sum;
+        }
+        sigma
+    }
+}
+
+impl Divisors for Natural {
+    type I = IntoIter<Natural>;
+
+    /// Returns an iterator that generates the positive divisors of a [`Natural`], in ascending
+    /// order.
+    ///
+    /// The [`Natural`] is factored first; if its factorization is already known, use
+    /// [`divisors_from_factorization`](Divisors::divisors_from_factorization) instead.
+    ///
+    /// # Worst-case complexity
+    /// See [`factor`](Factor::factor). Additionally, $O(dn \log d)$ time and $O(dn)$ memory are
+    /// needed to generate the divisors, where $n$ is `self.significant_bits()` and $d$ is the
+    /// number of divisors of `self`.
+    ///
+    /// # Panics
+    /// Panics if `self` is 0.
+    ///
+    /// # Examples
+    /// See [here](super::divisors#divisors).
+    #[inline]
+    fn divisors(&self) -> IntoIter<Natural> {
+        Natural::divisors_from_factorization(&self.factor())
+    }
+
+    /// Returns an iterator that generates the positive divisors of a [`Natural`], in ascending
+    /// order, given the [`Natural`]'s prime factorization as a list of distinct primes, each paired
+    /// with its exponent.
+    ///
+    /// The factorization is not checked for validity.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, d) = O(dn \log d)$
+    ///
+    /// $M(n, d) = O(dn)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the number of bits of the represented
+    /// number, and $d$ is the number of divisors.
+    ///
+    /// # Panics
+    /// Panics if any exponent is 0.
+    ///
+    /// # Examples
+    /// See [here](super::divisors#divisors_from_factorization).
+    fn divisors_from_factorization(factors: &[(Natural, u64)]) -> IntoIter<Natural> {
+        let mut divisors = vec![Natural::ONE];
+        for (p, e) in factors {
+            assert_ne!(*e, 0, "Exponents in a factorization must be positive");
+            let len = divisors.len();
+            let mut start = 0;
+            for _ in 0..*e {
+                for j in start..start + len {
+                    let d = &divisors[j] * p;
+                    divisors.push(d);
+                }
+                start += len;
+            }
+        }
+        divisors.sort_unstable();
+        divisors.into_iter()
+    }
+}
@@ -0,0 +1,380 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::natural::arithmetic::mod_mul::ModMulData;
+use crate::natural::Natural;
+use alloc::vec::Vec;
+use malachite_base::num::arithmetic::traits::{
+    FloorSqrt, Gcd, ModAdd, ModInverse, ModMulPrecomputed, ModMulPrecomputedAssign, ModSub,
+};
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::conversion::traits::WrappingFrom;
+use malachite_base::num::factorization::traits::Primes;
+use malachite_base::num::logic::traits::BitIterable;
+
+// A point on a Montgomery curve $By^2 = x^3 + Ax^2 + x$, in projective coordinates $(X : Z)$ with
+// the $y$-coordinate dropped.
+#[derive(Clone, Debug)]
+struct Point {
+    x: Natural,
+    z: Natural,
+}
+
+// The arithmetic of a single Montgomery curve modulo $n$. Only the constant $(A + 2) / 4$ is needed.
+struct Curve<'a> {
+    n: &'a Natural,
+    data: &'a ModMulData,
+    a24: Natural,
+}
+
+impl Curve<'_> {
+    #[inline]
+    fn mul(&self, x: &Natural, y: &Natural) -> Natural {
+        x.mod_mul_precomputed(y, self.n, self.data)
+    }
+
+    // Computes $2P$.
+    fn double(&self, p: &Point) -> Point {
+        let sum = (&p.x).mod_add(&p.z, self.n);
+        let diff = (&p.x).mod_sub(&p.z, self.n);
+        let sum_squared = self.mul(&sum, &sum);
+        let diff_squared = self.mul(&diff, &diff);
+        let t = (&sum_squared).mod_sub(&diff_squared, self.n);
+        let z = self.mul(&t, &self.mul(&self.a24, &t).mod_add(&diff_squared, self.n));
+        Point {
+            x: self.mul(&sum_squared, &diff_squared),
+            z,
+        }
+    }
+
+    // Computes $P + Q$, given $P - Q$.
+    fn add(&self, p: &Point, q: &Point, difference: &Point) -> Point {
+        let u = self.mul(&(&p.x).mod_sub(&p.z, self.n), &(&q.x).mod_add(&q.z, self.n));
+        let v = self.mul(&(&p.x).mod_add(&p.z, self.n), &(&q.x).mod_sub(&q.z, self.n));
+        let sum = (&u).mod_add(&v, self.n);
+        let diff = u.mod_sub(v, self.n);
+        Point {
+            x: self.mul(&difference.z, &self.mul(&sum, &sum)),
+            z: self.mul(&difference.x, &self.mul(&diff, &diff)),
+        }
+    }
+
+    // Computes $kP$ using the Montgomery ladder. `k` must be positive.
+    fn multiply(&self, p: &Point, k: u64) -> Point {
+        if k == 1 {
+            return p.clone();
+        }
+        let mut r_0 = p.clone();
+        let mut r_1 = self.double(p);
+        for bit in k.bits().rev().skip(1) {
+            if bit {
+                r_0 = self.add(&r_1, &r_0, p);
+                r_1 = self.double(&r_1);
+            } else {
+                r_1 = self.add(&r_1, &r_0, p);
+                r_0 = self.double(&r_0);
+            }
+        }
+        r_0
+    }
+}
+
+// The outcome of running ECM on one curve.
+enum CurveResult {
+    // A nontrivial factor of $n$.
+    Factor(Natural),
+    // The curve did not find a factor.
+    Failure,
+    // Every prime factor of $n$ was found simultaneously; the curve should be discarded.
+    AllFactors,
+}
+
+fn gcd_result(x: Natural, n: &Natural) -> CurveResult {
+    let g = x.gcd(n);
+    if g == 1u32 {
+        CurveResult::Failure
+    } else if g == *n {
+        CurveResult::AllFactors
+    } else {
+        CurveResult::Factor(g)
+    }
+}
+
+// Constructs a curve using Suyama's parametrization, which guarantees that the group order is
+// divisible by 12, and returns it along with its starting point. If a modular inverse fails to
+// exist, the gcd that caused the failure is returned instead.
+fn suyama_curve<'a>(
+    n: &'a Natural,
+    data: &'a ModMulData,
+    sigma: u64,
+) -> Result<(Curve<'a>, Point), CurveResult> {
+    let mul = |x: &Natural, y: &Natural| x.mod_mul_precomputed(y, n, data);
+    let sigma = Natural::from(sigma) % n;
+    // u = sigma^2 - 5, v = 4 * sigma
+    let u = mul(&sigma, &sigma).mod_sub(Natural::from(5u32) % n, n);
+    let v = mul(&sigma, &(Natural::from(4u32) % n));
+    let u_cubed = mul(&mul(&u, &u), &u);
+    let v_cubed = mul(&mul(&v, &v), &v);
+    // (A + 2) / 4 = (v - u)^3 (3u + v) / (16 u^3 v)
+    let v_minus_u = (&v).mod_sub(&u, n);
+    let numerator = mul(
+        &mul(&mul(&v_minus_u, &v_minus_u), &v_minus_u),
+        &mul(&(Natural::from(3u32) % n), &u).mod_add(&v, n),
+    );
+    let denominator = mul(&mul(&(Natural::from(16u32) % n), &u_cubed), &v);
+    match (&denominator).mod_inverse(n) {
+        Some(inverse) => Ok((
+            Curve {
+                n,
+                data,
+                a24: mul(&numerator, &inverse),
+            },
+            Point {
+                x: u_cubed,
+                z: v_cubed,
+            },
+        )),
+        None => Err(if denominator == 0u32 {
+            CurveResult::Failure
+        } else {
+            gcd_result(denominator, n)
+        }),
+    }
+}
+
+// Stage 1: multiplies `p` by every maximal prime power that is at most `b1`.
+fn ecm_stage_1(curve: &Curve, p: &Point, b1: u64) -> Point {
+    let mut q = p.clone();
+    for prime in u64::primes_less_than_or_equal_to(&b1) {
+        let mut power = prime;
+        while let Some(next) = power.checked_mul(prime) {
+            if next > b1 {
+                break;
+            }
+            power = next;
+        }
+        q = curve.multiply(&q, power);
+    }
+    q
+}
+
+// An iterator over the primes $p$ with $a < p \leq b$, using a segmented sieve so that the memory
+// used is proportional to $\sqrt{b}$ rather than $b$.
+struct PrimesInRange {
+    sieving_primes: Vec<u64>,
+    segment: Vec<bool>,
+    segment_start: u64,
+    i: usize,
+    limit: u64,
+}
+
+const PRIMES_IN_RANGE_SEGMENT_SIZE: u64 = 1 << 16;
+
+impl PrimesInRange {
+    fn new(a: u64, b: u64) -> PrimesInRange {
+        let sieving_primes = u64::primes_less_than_or_equal_to(&b.floor_sqrt()).collect();
+        let mut primes = PrimesInRange {
+            sieving_primes,
+            segment: Vec::new(),
+            segment_start: a + 1,
+            i: 0,
+            limit: b,
+        };
+        primes.sieve_segment();
+        primes
+    }
+
+    // Sieves the segment starting at `segment_start`.
+    fn sieve_segment(&mut self) {
+        let start = self.segment_start;
+        if start > self.limit {
+            self.segment.clear();
+            return;
+        }
+        let end = self
+            .limit
+            .min(start.saturating_add(PRIMES_IN_RANGE_SEGMENT_SIZE - 1));
+        let len = usize::try_from(end - start + 1).unwrap();
+        self.segment.clear();
+        self.segment.resize(len, true);
+        for &p in &self.sieving_primes {
+            let p_squared = p * p;
+            if p_squared > end {
+                break;
+            }
+            let mut m = if p_squared >= start {
+                p_squared
+            } else {
+                start.div_ceil(p) * p
+            };
+            while m <= end {
+                self.segment[usize::try_from(m - start).unwrap()] = false;
+                m += p;
+            }
+        }
+        if start < 2 {
+            for x in start..2.min(end + 1) {
+                self.segment[usize::try_from(x - start).unwrap()] = false;
+            }
+        }
+        self.i = 0;
+    }
+}
+
+impl Iterator for PrimesInRange {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        loop {
+            if self.segment.is_empty() {
+                return None;
+            }
+            while self.i < self.segment.len() {
+                let i = self.i;
+                self.i += 1;
+                if self.segment[i] {
+                    return Some(self.segment_start + u64::wrapping_from(i));
+                }
+            }
+            self.segment_start += u64::wrapping_from(self.segment.len());
+            self.sieve_segment();
+        }
+    }
+}
+
+// Stage 2, the standard continuation: looks for a single prime $p$ with $b_1 < p \leq b_2$ such that
+// the order of `q` modulo some prime factor of $n$ is $p$. Every such $p$ is written as $mD \pm j$,
+// where $j < D/2$ is coprime to $D$; then $p Q = O$ implies that $mDQ$ and $jQ$ have the same
+// $x$-coordinate, so the products of $X_{mDQ} Z_{jQ} - X_{jQ} Z_{mDQ}$ are accumulated.
+fn ecm_stage_2(curve: &Curve, q: &Point, b1: u64, b2: u64) -> CurveResult {
+    let n = curve.n;
+    let d: u64 = if b1 >= 50000 { 2310 } else { 210 };
+    let half_d = d >> 1;
+    // baby[j] = jQ, for odd j < D/2.
+    let mut baby: Vec<Option<Point>> = alloc::vec![None; usize::try_from(half_d).unwrap()];
+    let q_2 = curve.double(q);
+    let mut previous = q.clone();
+    let mut current = curve.add(&q_2, q, q);
+    baby[1] = Some(q.clone());
+    let mut j = 3;
+    while j < half_d {
+        if j.gcd(d) == 1 {
+            baby[usize::try_from(j).unwrap()] = Some(current.clone());
+        }
+        let next = curve.add(&current, &q_2, &previous);
+        previous = current;
+        current = next;
+        j += 2;
+    }
+    let dq = curve.multiply(q, d);
+    let mut m = (b1 + half_d) / d;
+    if m == 0 {
+        m = 1;
+    }
+    let mut giant = curve.multiply(q, m * d);
+    let mut next_giant = curve.multiply(q, (m + 1) * d);
+    let mut used = alloc::vec![false; usize::try_from(half_d).unwrap()];
+    let mut product = Natural::ONE;
+    let mut count = 0u64;
+    for p in PrimesInRange::new(b1, b2) {
+        let p_m = (p + half_d) / d;
+        while m < p_m {
+            let new_giant = curve.add(&next_giant, &dq, &giant);
+            giant = next_giant;
+            next_giant = new_giant;
+            m += 1;
+            used.iter_mut().for_each(|u| *u = false);
+        }
+        let j = usize::try_from(p.abs_diff(m * d)).unwrap();
+        if used[j] {
+            continue;
+        }
+        used[j] = true;
+        let baby_point = baby[j].as_ref().unwrap();
+        let term = curve
+            .mul(&giant.x, &baby_point.z)
+            .mod_sub(curve.mul(&baby_point.x, &giant.z), n);
+        if term == 0u32 {
+            // All prime factors of n were found at once; including this term would make the
+            // product 0.
+            continue;
+        }
+        product.mod_mul_precomputed_assign(term, n, curve.data);
+        count += 1;
+        if count & 1023 == 0 {
+            if let CurveResult::Factor(f) = gcd_result(product.clone(), n) {
+                return CurveResult::Factor(f);
+            }
+        }
+    }
+    gcd_result(product, n)
+}
+
+// Tries to find a nontrivial factor of `n` using Lenstra's elliptic curve method with stage 1
+// bound `b1`, a stage 2 bound of `100 * b1`, and at most `curves` curves. The curves are chosen
+// deterministically using Suyama's parametrization with $\sigma$ = `first_sigma`,
+// `first_sigma + 1`, and so on.
+//
+// `n` must be odd, composite, and greater than 1, and `first_sigma` must be at least 6. Returns
+// `None` if no factor is found.
+pub_crate_test! {ecm(n: &Natural, b1: u64, curves: u64, first_sigma: u64) -> Option<Natural> {
+    assert!(first_sigma >= 6);
+    let data = ModMulPrecomputed::<Natural>::precompute_mod_mul_data(n);
+    let b2 = b1.saturating_mul(100);
+    for sigma in first_sigma..first_sigma + curves {
+        let (curve, p) = match suyama_curve(n, &data, sigma) {
+            Ok(x) => x,
+            Err(CurveResult::Factor(f)) => return Some(f),
+            Err(_) => continue,
+        };
+        let q = ecm_stage_1(&curve, &p, b1);
+        match gcd_result(q.z.clone(), n) {
+            CurveResult::Factor(f) => return Some(f),
+            CurveResult::AllFactors => continue,
+            CurveResult::Failure => {}
+        }
+        if let CurveResult::Factor(f) = ecm_stage_2(&curve, &q, b1, b2) {
+            return Some(f);
+        }
+    }
+    None
+}}
+
+// The ECM bounds and curve counts suggested by GMP-ECM for finding factors of 15, 20, 25, 30, 35,
+// 40, 45, 50, 55, and 60 digits.
+pub(crate) const ECM_SCHEDULE: [(u64, u64); 10] = [
+    (2000, 25),
+    (11000, 90),
+    (50000, 300),
+    (250000, 700),
+    (1000000, 1800),
+    (3000000, 5100),
+    (11000000, 10600),
+    (43000000, 19300),
+    (110000000, 49000),
+    (260000000, 124000),
+];
+
+// Returns a nontrivial factor of `n` using ECM, working through `ECM_SCHEDULE` and then continuing
+// with the last entry indefinitely. `n` must be odd and composite, and may not be a perfect power.
+pub(crate) fn ecm_until_factor(n: &Natural) -> Natural {
+    let mut sigma = 6;
+    for &(b1, curves) in &ECM_SCHEDULE {
+        if let Some(f) = ecm(n, b1, curves, sigma) {
+            return f;
+        }
+        sigma += curves;
+    }
+    let (b1, curves) = ECM_SCHEDULE[ECM_SCHEDULE.len() - 1];
+    loop {
+        if let Some(f) = ecm(n, b1, curves, sigma) {
+            return f;
+        }
+        sigma += curves;
+    }
+}
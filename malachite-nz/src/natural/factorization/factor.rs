@@ -0,0 +1,248 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::natural::arithmetic::mod_mul::ModMulData;
+use crate::natural::factorization::ecm::{ecm, ecm_until_factor};
+use crate::natural::factorization::siqs::siqs;
+use crate::natural::Natural;
+use crate::platform::Limb;
+use alloc::vec::Vec;
+use malachite_base::num::arithmetic::traits::{
+    CheckedRoot, DivExact, DivExactAssign, DivisibleBy, Gcd, ModAdd, ModMulPrecomputed,
+    ModMulPrecomputedAssign, ModSub, Square,
+};
+use malachite_base::num::basic::traits::{One, Two};
+use malachite_base::num::factorization::traits::{Factor, IsPrime, Primes};
+use malachite_base::num::logic::traits::SignificantBits;
+
+// Trial division is done using all primes less than this limit.
+const TRIAL_DIVISION_LIMIT: Limb = 1 << 16;
+
+// The number of iterations of Pollard's rho algorithm that are tried before switching to ECM or
+// SIQS. This is enough to find most factors of up to about 30 bits.
+const RHO_ITERATIONS: u64 = 1 << 14;
+
+// Composites with a number of bits in this range, which covers roughly 30 to 100 decimal digits,
+// are factored using SIQS. Smaller and larger composites are factored using ECM.
+const SIQS_MIN_BITS: u64 = 100;
+const SIQS_MAX_BITS: u64 = 333;
+
+// Before SIQS is used on a composite, some ECM curves are run, since ECM finds small factors much
+// more quickly. Each entry gives the minimum number of bits of the composite, followed by the stage
+// 1 bound and number of curves to use.
+const SIQS_ECM_PREPASS: [(u64, u64, u64); 2] = [(160, 2000, 25), (230, 11000, 90)];
+
+// Inserts `p ^ e` into a factorization, keeping the primes sorted. `p` must be prime.
+fn insert_factor(factors: &mut Vec<(Natural, u64)>, p: Natural, e: u64) {
+    match factors.binary_search_by(|(q, _)| q.cmp(&p)) {
+        Ok(i) => factors[i].1 += e,
+        Err(i) => factors.insert(i, (p, e)),
+    }
+}
+
+// Inserts the factorization of `n ^ e` into `factors`, where `n` fits in a `u64`.
+fn insert_factors_u64(factors: &mut Vec<(Natural, u64)>, n: u64, e: u64) {
+    for (p, f) in n.factor() {
+        insert_factor(factors, Natural::from(p), u64::from(f) * e);
+    }
+}
+
+// Divides all primes less than `TRIAL_DIVISION_LIMIT` out of `n`, recording them in `factors`. The
+// primes are grouped into products that fit in a limb, so that only one pass over `n` is needed per
+// group. Stops early if `n` becomes 1 or prime, and returns the remaining cofactor.
+fn trial_divide(factors: &mut Vec<(Natural, u64)>, mut n: Natural) -> Natural {
+    let zeros = n.trailing_zeros().unwrap();
+    if zeros != 0 {
+        insert_factor(factors, Natural::TWO, zeros);
+        n >>= zeros;
+    }
+    let primes = Limb::primes_less_than(&TRIAL_DIVISION_LIMIT)
+        .skip(1)
+        .collect::<Vec<_>>();
+    let mut i = 0;
+    while i < primes.len() {
+        let start = i;
+        let mut product: Limb = 1;
+        while let Some(new_product) = primes.get(i).and_then(|&p| product.checked_mul(p)) {
+            product = new_product;
+            i += 1;
+        }
+        let batch = &primes[start..i];
+        let r = n.rem_limb_ref(product);
+        for &p in batch {
+            if r % p == 0 {
+                let p = Natural::from(p);
+                let mut e = 0;
+                while (&n).divisible_by(&p) {
+                    n.div_exact_assign(&p);
+                    e += 1;
+                }
+                insert_factor(factors, p, e);
+            }
+        }
+        if let Ok(small) = u64::try_from(&n) {
+            if small != 1 {
+                insert_factors_u64(factors, small, 1);
+            }
+            return Natural::ONE;
+        }
+        // If n has no prime factors less than or equal to p and is at most p^2, it is prime.
+        let largest = Natural::from(batch[batch.len() - 1]);
+        if n <= (&largest).square() {
+            insert_factor(factors, n, 1);
+            return Natural::ONE;
+        }
+    }
+    n
+}
+
+// Brent's variant of Pollard's rho algorithm, using the polynomial $x^2 + 1$. Gives up after about
+// `RHO_ITERATIONS` iterations, returning `None`.
+//
+// `n` must be odd and composite.
+fn pollard_brent(n: &Natural, data: &ModMulData) -> Option<Natural> {
+    const BATCH_SIZE: u64 = 128;
+    let f = |x: &Natural| x.mod_mul_precomputed(x, n, data).mod_add(Natural::ONE, n);
+    let mut y = Natural::TWO;
+    let mut r = 1;
+    let mut q = Natural::ONE;
+    let mut g = Natural::ONE;
+    let mut x;
+    let mut ys = y.clone();
+    loop {
+        x = y.clone();
+        for _ in 0..r {
+            y = f(&y);
+        }
+        let mut k = 0;
+        while k < r && g == 1u32 {
+            ys = y.clone();
+            for _ in 0..BATCH_SIZE.min(r - k) {
+                y = f(&y);
+                q.mod_mul_precomputed_assign((&x).mod_sub(&y, n), n, data);
+            }
+            g = (&q).gcd(n);
+            k += BATCH_SIZE;
+        }
+        r <<= 1;
+        if g != 1u32 {
+            break;
+        }
+        if r > RHO_ITERATIONS {
+            return None;
+        }
+    }
+    if g == *n {
+        // The batched product hit 0 mod n; redo the last batch one step at a time.
+        loop {
+            ys = f(&ys);
+            g = (&x).mod_sub(&ys, n).gcd(n);
+            if g != 1u32 {
+                break;
+            }
+        }
+    }
+    if g == *n {
+        None
+    } else {
+        Some(g)
+    }
+}
+
+// Returns a nontrivial factor of `n`, which must be odd, composite, greater than $2^{64}$, and not a
+// perfect power.
+fn find_factor(n: &Natural) -> Natural {
+    let data = ModMulPrecomputed::<Natural>::precompute_mod_mul_data(n);
+    if let Some(f) = pollard_brent(n, &data) {
+        return f;
+    }
+    let bits = n.significant_bits();
+    if (SIQS_MIN_BITS..=SIQS_MAX_BITS).contains(&bits) {
+        let mut sigma = 6;
+        for &(min_bits, b1, curves) in &SIQS_ECM_PREPASS {
+            if bits < min_bits {
+                break;
+            }
+            if let Some(f) = ecm(n, b1, curves, sigma) {
+                return f;
+            }
+            sigma += curves;
+        }
+        if let Some(f) = siqs(n) {
+            return f;
+        }
+    }
+    ecm_until_factor(n)
+}
+
+impl Factor for Natural {
+    type FACTORS = Vec<(Natural, u64)>;
+
+    /// Returns the prime factorization of a [`Natural`].
+    ///
+    /// The result is a [`Vec`] of the distinct prime factors in ascending order, each paired with
+    /// its exponent.
+    ///
+    /// Small factors are removed by trial division, and then Brent's variant of Pollard's rho
+    /// algorithm is briefly tried. If a composite remains, it is split using the self-initializing
+    /// quadratic sieve if it has between 30 and 100 decimal digits, and using Lenstra's elliptic
+    /// curve method otherwise. Primality is determined using
+    /// [`is_prime`](malachite_base::num::factorization::traits::IsPrime::is_prime), so for inputs
+    /// larger than $2^{64}$ the factors are only known to be probable primes.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(e^{(1 + o(1)) \sqrt{n \log n}})$, heuristically
+    ///
+    /// $M(n) = O(e^{(1/2 + o(1)) \sqrt{n \log n}})$, heuristically
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is 0.
+    ///
+    /// # Examples
+    /// See [here](super::factor#factor).
+    fn factor(&self) -> Vec<(Natural, u64)> {
+        assert_ne!(*self, 0u32);
+        let mut factors = Vec::new();
+        if let Ok(n) = u64::try_from(self) {
+            insert_factors_u64(&mut factors, n, 1);
+            return factors;
+        }
+        let n = trial_divide(&mut factors, self.clone());
+        if n == 1u32 {
+            return factors;
+        }
+        let mut composites = alloc::vec![(n, 1)];
+        while let Some((n, e)) = composites.pop() {
+            if let Ok(small) = u64::try_from(&n) {
+                insert_factors_u64(&mut factors, small, e);
+                continue;
+            }
+            if n.is_prime() {
+                insert_factor(&mut factors, n, e);
+                continue;
+            }
+            // Every prime factor is at least `TRIAL_DIVISION_LIMIT`, which bounds the exponent of a
+            // perfect power.
+            let max_exponent =
+                n.significant_bits() / u64::from(TRIAL_DIVISION_LIMIT.trailing_zeros());
+            if let Some((root, k)) = u64::primes_less_than_or_equal_to(&max_exponent)
+                .find_map(|k| (&n).checked_root(k).map(|root| (root, k)))
+            {
+                composites.push((root, e * k));
+                continue;
+            }
+            let d = find_factor(&n);
+            let cofactor = (&n).div_exact(&d);
+            composites.push((d, e));
+            composites.push((cofactor, e));
+        }
+        factors
+    }
+}
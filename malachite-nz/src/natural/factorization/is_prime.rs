@@ -0,0 +1,194 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::natural::arithmetic::mod_mul::ModMulData;
+use crate::natural::arithmetic::mod_op::limbs_mod_limb;
+use crate::natural::InnerNatural::{Large, Small};
+use crate::natural::Natural;
+use crate::platform::Limb;
+use malachite_base::num::arithmetic::traits::{
+    CheckedSqrt, JacobiSymbol, ModAdd, ModMulPrecomputed, ModMulPrecomputedAssign, ModPow,
+    ModSquare, ModSub, Parity,
+};
+use malachite_base::num::basic::traits::{One, Two};
+use malachite_base::num::factorization::traits::{IsPrime, Primes};
+use malachite_base::num::logic::traits::{BitAccess, SignificantBits};
+
+// Primes less than this limit are used for trial division before any probable prime tests are
+// done.
+const IS_PRIME_TRIAL_DIVISION_LIMIT: Limb = 1000;
+
+// Returns whether any odd prime less than `IS_PRIME_TRIAL_DIVISION_LIMIT` divides `xs`. The primes
+// are grouped into products that fit in a limb, so that only one pass over `xs` is needed per
+// group.
+fn limbs_has_small_odd_prime_factor(xs: &[Limb]) -> bool {
+    let mut product: Limb = 1;
+    let mut start = 0;
+    let primes = Limb::primes_less_than(&IS_PRIME_TRIAL_DIVISION_LIMIT)
+        .skip(1)
+        .collect::<alloc::vec::Vec<_>>();
+    for (i, &p) in primes.iter().enumerate() {
+        if let Some(new_product) = product.checked_mul(p) {
+            product = new_product;
+        } else {
+            let r = limbs_mod_limb(xs, product);
+            if primes[start..i].iter().any(|&q| r % q == 0) {
+                return true;
+            }
+            product = p;
+            start = i;
+        }
+    }
+    let r = limbs_mod_limb(xs, product);
+    primes[start..].iter().any(|&q| r % q == 0)
+}
+
+// Divides `x` by 2 modulo the odd modulus `n`.
+fn mod_half(x: Natural, n: &Natural) -> Natural {
+    if x.even() {
+        x >> 1
+    } else {
+        (x + n) >> 1
+    }
+}
+
+// Returns whether `n` is a strong probable prime to base 2. `n` must be odd and greater than 2.
+pub(crate) fn is_strong_probable_prime_base_2(n: &Natural, data: &ModMulData) -> bool {
+    let n_minus_1 = n - Natural::ONE;
+    let s = n_minus_1.trailing_zeros().unwrap();
+    let d = &n_minus_1 >> s;
+    let mut x = Natural::TWO.mod_pow(d, n);
+    if x == 1u32 || x == n_minus_1 {
+        return true;
+    }
+    for _ in 1..s {
+        x.mod_mul_precomputed_assign(x.clone(), n, data);
+        if x == n_minus_1 {
+            return true;
+        }
+        if x == 1u32 {
+            return false;
+        }
+    }
+    false
+}
+
+// Returns whether `n` is a strong Lucas probable prime, using Selfridge's method A to choose the
+// parameters: $D$ is the first element of $5, -7, 9, -11, \ldots$ for which the Jacobi symbol
+// $(D/n)$ is $-1$, $P = 1$, and $Q = (1 - D) / 4$.
+//
+// `n` must be odd, greater than 2^64, and not a perfect square.
+fn is_strong_lucas_probable_prime(n: &Natural, data: &ModMulData) -> bool {
+    // Find D, storing it and Q as residues mod n.
+    let mut abs_d = 5u32;
+    let mut negative = false;
+    let (d, q) = loop {
+        let d = if negative {
+            n - Natural::from(abs_d)
+        } else {
+            Natural::from(abs_d)
+        };
+        match (&d).jacobi_symbol(n) {
+            -1 => {
+                // Q = (1 - D) / 4, so Q = (abs_d + 1) / 4 if D is negative and -(abs_d - 1) / 4
+                // otherwise.
+                let q = if negative {
+                    Natural::from((abs_d + 1) >> 2)
+                } else {
+                    n - Natural::from((abs_d - 1) >> 2)
+                };
+                break (d, q);
+            }
+            // Since |D| < n, a Jacobi symbol of 0 means that gcd(|D|, n) is a nontrivial factor.
+            0 => return false,
+            _ => {}
+        }
+        abs_d += 2;
+        negative = !negative;
+    };
+    let n_plus_1 = n + Natural::ONE;
+    let s = n_plus_1.trailing_zeros().unwrap();
+    let k = &n_plus_1 >> s;
+    // Compute U_k, V_k, and Q^k with a left-to-right binary method, starting from U_1 = 1, V_1 = P
+    // = 1.
+    let mut u = Natural::ONE;
+    let mut v = Natural::ONE;
+    let mut q_k = q.clone();
+    for i in (0..k.significant_bits() - 1).rev() {
+        // U_{2j} = U_j V_j, V_{2j} = V_j^2 - 2Q^j
+        u.mod_mul_precomputed_assign(&v, n, data);
+        v = (&v)
+            .mod_mul_precomputed(&v, n, data)
+            .mod_sub((&q_k).mod_add(&q_k, n), n);
+        q_k.mod_mul_precomputed_assign(q_k.clone(), n, data);
+        if k.get_bit(i) {
+            // U_{j+1} = (P U_j + V_j) / 2, V_{j+1} = (D U_j + P V_j) / 2
+            let du = (&d).mod_mul_precomputed(&u, n, data);
+            u = mod_half((&u).mod_add(&v, n), n);
+            v = mod_half(du.mod_add(v, n), n);
+            q_k.mod_mul_precomputed_assign(&q, n, data);
+        }
+    }
+    if u == 0u32 || v == 0u32 {
+        return true;
+    }
+    for _ in 1..s {
+        // V_{2j} = V_j^2 - 2Q^j
+        v = v.mod_square(n).mod_sub((&q_k).mod_add(&q_k, n), n);
+        if v == 0u32 {
+            return true;
+        }
+        q_k.mod_mul_precomputed_assign(q_k.clone(), n, data);
+    }
+    false
+}
+
+impl IsPrime for Natural {
+    /// Tests whether a [`Natural`] is prime.
+    ///
+    /// If the [`Natural`] fits in a `u64`, the primality test for `u64`s is used, which is always
+    /// correct. Otherwise, this implementation does some trial division and then runs the
+    /// Baillie–PSW test: a strong probable prime test to base 2 followed by a strong Lucas probable
+    /// prime test. No composite number is known to pass the Baillie–PSW test, but it has not been
+    /// proven that none exist.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_base::num::factorization::traits::IsPrime;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::from(5u32).is_prime(), true);
+    /// assert_eq!(Natural::from(6u32).is_prime(), false);
+    /// assert_eq!((Natural::from(2u32).pow(89) - Natural::from(1u32)).is_prime(), true);
+    /// assert_eq!((Natural::from(2u32).pow(97) - Natural::from(1u32)).is_prime(), false);
+    /// ```
+    fn is_prime(&self) -> bool {
+        if let Ok(x) = u64::try_from(self) {
+            return x.is_prime();
+        }
+        let xs = match self {
+            Natural(Small(_)) => unreachable!(),
+            Natural(Large(xs)) => xs,
+        };
+        if self.even() || limbs_has_small_odd_prime_factor(xs) {
+            return false;
+        }
+        let data = ModMulPrecomputed::<Natural>::precompute_mod_mul_data(self);
+        is_strong_probable_prime_base_2(self, &data)
+            && self.checked_sqrt().is_none()
+            && is_strong_lucas_probable_prime(self, &data)
+    }
+}
@@ -0,0 +1,66 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::natural::Natural;
+use malachite_base::num::factorization::traits::{Factor, Mobius};
+
+impl Mobius for Natural {
+    /// Computes the Möbius function $\mu(n)$ of a [`Natural`].
+    ///
+    /// The [`Natural`] is factored first; if its factorization is already known, use
+    /// [`mobius_from_factorization`](Mobius::mobius_from_factorization) instead.
+    ///
+    /// $$
+    /// f(n) = \mu(n) = \\begin{cases}
+    ///     0 & \text{if} \\quad n \text{ is divisible by a square greater than 1}, \\\\
+    ///     (-1)^k & \text{otherwise, where } n \text{ has } k \text{ prime factors}.
+    /// \\end{cases}
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// See [`factor`](Factor::factor).
+    ///
+    /// # Panics
+    /// Panics if `self` is 0.
+    ///
+    /// # Examples
+    /// See [here](super::mobius#mobius).
+    #[inline]
+    fn mobius(&self) -> i8 {
+        Natural::mobius_from_factorization(&self.factor())
+    }
+
+    /// Computes the Möbius function $\mu(n)$ of a [`Natural`], given its prime factorization as a
+    /// list of distinct primes, each paired with its exponent.
+    ///
+    /// The factorization is not checked for validity.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `factors.len()`.
+    ///
+    /// # Panics
+    /// Panics if any exponent is 0.
+    ///
+    /// # Examples
+    /// See [here](super::mobius#mobius_from_factorization).
+    fn mobius_from_factorization(factors: &[(Natural, u64)]) -> i8 {
+        let mut mobius = 1;
+        for &(_, e) in factors {
+            match e {
+                0 => panic!("Exponents in a factorization must be positive"),
+                1 => mobius = -mobius,
+                _ => return 0,
+            }
+        }
+        mobius
+    }
+}
@@ -6,6 +6,249 @@
 // Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
 // 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
 
+/// Implementations of [`DivisorCount`](malachite_base::num::factorization::traits::DivisorCount),
+/// [`DivisorSigma`](malachite_base::num::factorization::traits::DivisorSigma), and
+/// [`Divisors`](malachite_base::num::factorization::traits::Divisors), traits for counting,
+/// summing, and generating the divisors of a number.
+///
+/// # divisor_count
+/// ```
+/// use malachite_base::num::arithmetic::traits::Pow;
+/// use malachite_base::num::factorization::traits::DivisorCount;
+/// use malachite_nz::natural::Natural;
+///
+/// assert_eq!(Natural::from(1u32).divisor_count(), 1);
+/// assert_eq!(Natural::from(360u32).divisor_count(), 24);
+/// assert_eq!(
+///     (Natural::from(10u32).pow(20) - Natural::from(1u32)).divisor_count(),
+///     384
+/// );
+/// assert_eq!(Natural::from(10u32).pow(30).divisor_count(), 961);
+/// ```
+///
+/// # divisor_count_from_factorization
+/// ```
+/// use malachite_base::num::factorization::traits::DivisorCount;
+/// use malachite_nz::natural::Natural;
+///
+/// assert_eq!(Natural::divisor_count_from_factorization(&[]), 1);
+/// assert_eq!(
+///     Natural::divisor_count_from_factorization(&[
+///         (Natural::from(2u32), 3),
+///         (Natural::from(3u32), 2),
+///         (Natural::from(5u32), 1)
+///     ]),
+///     24
+/// );
+/// ```
+///
+/// # divisor_sigma
+/// ```
+/// use malachite_base::num::arithmetic::traits::Pow;
+/// use malachite_base::num::factorization::traits::DivisorSigma;
+/// use malachite_nz::natural::Natural;
+///
+/// assert_eq!(Natural::from(1u32).divisor_sigma(1), 1);
+/// assert_eq!(Natural::from(360u32).divisor_sigma(0), 24);
+/// assert_eq!(Natural::from(360u32).divisor_sigma(1), 1170);
+/// assert_eq!(Natural::from(360u32).divisor_sigma(2), 201110);
+/// assert_eq!(
+///     (Natural::from(2u32).pow(64) + Natural::from(1u32))
+///         .divisor_sigma(1)
+///         .to_string(),
+///     "18446811354131136516"
+/// );
+/// ```
+///
+/// # divisor_sigma_from_factorization
+/// ```
+/// use malachite_base::num::factorization::traits::DivisorSigma;
+/// use malachite_nz::natural::Natural;
+///
+/// assert_eq!(Natural::divisor_sigma_from_factorization(&[], 1), 1);
+/// assert_eq!(
+///     Natural::divisor_sigma_from_factorization(
+///         &[
+///             (Natural::from(2u32), 3),
+///             (Natural::from(3u32), 2),
+///             (Natural::from(5u32), 1)
+///         ],
+///         1
+///     ),
+///     1170
+/// );
+/// ```
+///
+/// # divisors
+/// ```
+/// use itertools::Itertools;
+/// use malachite_base::num::arithmetic::traits::Pow;
+/// use malachite_base::num::factorization::traits::Divisors;
+/// use malachite_base::strings::ToDebugString;
+/// use malachite_nz::natural::Natural;
+///
+/// assert_eq!(Natural::from(1u32).divisors().collect_vec().to_debug_string(), "[1]");
+/// assert_eq!(
+///     Natural::from(60u32).divisors().collect_vec().to_debug_string(),
+///     "[1, 2, 3, 4, 5, 6, 10, 12, 15, 20, 30, 60]"
+/// );
+/// assert_eq!(
+///     (Natural::from(2u32).pow(64) + Natural::from(1u32))
+///         .divisors()
+///         .collect_vec()
+///         .to_debug_string(),
+///     "[1, 274177, 67280421310721, 18446744073709551617]"
+/// );
+/// ```
+///
+/// # divisors_from_factorization
+/// ```
+/// use itertools::Itertools;
+/// use malachite_base::num::factorization::traits::Divisors;
+/// use malachite_base::strings::ToDebugString;
+/// use malachite_nz::natural::Natural;
+///
+/// assert_eq!(
+///     Natural::divisors_from_factorization(&[])
+///         .collect_vec()
+///         .to_debug_string(),
+///     "[1]"
+/// );
+/// assert_eq!(
+///     Natural::divisors_from_factorization(&[
+///         (Natural::from(2u32), 2),
+///         (Natural::from(3u32), 1),
+///         (Natural::from(5u32), 1)
+///     ])
+///     .collect_vec()
+///     .to_debug_string(),
+///     "[1, 2, 3, 4, 5, 6, 10, 12, 15, 20, 30, 60]"
+/// );
+/// ```
+pub mod divisors;
+/// Code for Lenstra's elliptic curve method of factorization.
+pub mod ecm;
+/// An implementation of [`Factor`](malachite_base::num::factorization::traits::Factor), a trait
+/// for finding the prime factorization of a number.
+///
+/// # factor
+/// ```
+/// use malachite_base::num::arithmetic::traits::Pow;
+/// use malachite_base::num::factorization::traits::Factor;
+/// use malachite_base::strings::ToDebugString;
+/// use malachite_nz::natural::Natural;
+///
+/// assert_eq!(Natural::from(1u32).factor().to_debug_string(), "[]");
+/// assert_eq!(
+///     Natural::from(360u32).factor().to_debug_string(),
+///     "[(2, 3), (3, 2), (5, 1)]"
+/// );
+/// assert_eq!(
+///     (Natural::from(2u32).pow(64) + Natural::from(1u32))
+///         .factor()
+///         .to_debug_string(),
+///     "[(274177, 1), (67280421310721, 1)]"
+/// );
+/// assert_eq!(
+///     (Natural::from(10u32).pow(20) - Natural::from(1u32))
+///         .factor()
+///         .to_debug_string(),
+///     "[(3, 2), (11, 1), (41, 1), (101, 1), (271, 1), (3541, 1), (9091, 1), (27961, 1)]"
+/// );
+/// ```
+pub mod factor;
+/// An implementation of [`IsPrime`](malachite_base::num::factorization::traits::IsPrime), a trait
+/// for testing whether a number is prime.
+pub mod is_prime;
+/// An implementation of [`Mobius`](malachite_base::num::factorization::traits::Mobius), a trait for
+/// computing the Möbius function of a number.
+///
+/// # mobius
+/// ```
+/// use malachite_base::num::arithmetic::traits::Pow;
+/// use malachite_base::num::factorization::traits::Mobius;
+/// use malachite_nz::natural::Natural;
+///
+/// assert_eq!(Natural::from(1u32).mobius(), 1);
+/// assert_eq!(Natural::from(2u32).mobius(), -1);
+/// assert_eq!(Natural::from(360u32).mobius(), 0);
+/// assert_eq!((Natural::from(2u32).pow(64) + Natural::from(1u32)).mobius(), 1);
+/// assert_eq!(Natural::from(10u32).pow(30).mobius(), 0);
+/// ```
+///
+/// # mobius_from_factorization
+/// ```
+/// use malachite_base::num::factorization::traits::Mobius;
+/// use malachite_nz::natural::Natural;
+///
+/// assert_eq!(Natural::mobius_from_factorization(&[]), 1);
+/// assert_eq!(
+///     Natural::mobius_from_factorization(&[(Natural::from(2u32), 1), (Natural::from(3u32), 1)]),
+///     1
+/// );
+/// assert_eq!(
+///     Natural::mobius_from_factorization(&[(Natural::from(2u32), 2), (Natural::from(3u32), 1)]),
+///     0
+/// );
+/// ```
+pub mod mobius;
+/// Implementations of [`NextPrime`](malachite_base::num::factorization::traits::NextPrime) and
+/// [`PreviousPrime`](malachite_base::num::factorization::traits::PreviousPrime), traits for finding
+/// the nearest primes above and below a number.
+///
+/// # next_prime
+/// ```
+/// use malachite_base::num::arithmetic::traits::Pow;
+/// use malachite_base::num::factorization::traits::NextPrime;
+/// use malachite_nz::natural::Natural;
+///
+/// assert_eq!(Natural::from(0u32).next_prime(), 2u32);
+/// assert_eq!(Natural::from(100u32).next_prime(), 101u32);
+/// assert_eq!(
+///     Natural::from(2u32).pow(64).next_prime().to_string(),
+///     "18446744073709551629"
+/// );
+/// assert_eq!(
+///     Natural::from(10u32).pow(30).next_prime().to_string(),
+///     "1000000000000000000000000000057"
+/// );
+/// ```
+///
+/// # previous_prime
+/// ```
+/// use malachite_base::num::arithmetic::traits::Pow;
+/// use malachite_base::num::factorization::traits::PreviousPrime;
+/// use malachite_nz::natural::Natural;
+///
+/// assert_eq!(Natural::from(2u32).previous_prime(), None);
+/// assert_eq!(Natural::from(100u32).previous_prime().unwrap(), 97u32);
+/// assert_eq!(
+///     Natural::from(2u32).pow(64).previous_prime().unwrap().to_string(),
+///     "18446744073709551557"
+/// );
+/// assert_eq!(
+///     Natural::from(10u32).pow(30).previous_prime().unwrap().to_string(),
+///     "999999999999999999999999999989"
+/// );
+/// ```
+pub mod next_prime;
+/// Code for finding linear dependencies among the rows of a sparse matrix over $\mathbb{F}_2$,
+/// using either Gaussian elimination or the block Lanczos algorithm.
+pub mod nullspace;
+/// An implementation of [`PrimePi`](malachite_base::num::factorization::traits::PrimePi), a trait
+/// for counting primes.
+///
+/// # prime_pi
+/// ```
+/// use malachite_base::num::arithmetic::traits::Pow;
+/// use malachite_base::num::factorization::traits::PrimePi;
+/// use malachite_nz::natural::Natural;
+///
+/// assert_eq!(Natural::from(0u32).prime_pi(), 0u32);
+/// assert_eq!(Natural::from(100u32).prime_pi(), 25u32);
+/// assert_eq!(Natural::from(10u32).pow(10).prime_pi(), 455052511u32);
+/// ```
+pub mod prime_pi;
 /// An implementation of [`Primes`](malachite_base::num::factorization::traits::Primes), a trait for
 /// generating prime numbers.
 ///
@@ -83,4 +326,106 @@
 ///     181, 191, 193, 197, 199, 211, 223, 227, 229, 233, 239, 241, 251]"
 /// );
 /// ```
+///
+/// # primes_in_range
+/// ```
+/// use itertools::Itertools;
+/// use malachite_base::num::arithmetic::traits::Pow;
+/// use malachite_base::strings::ToDebugString;
+/// use malachite_nz::natural::Natural;
+///
+/// assert_eq!(
+///     Natural::primes_in_range(&Natural::from(10u32), &Natural::from(50u32))
+///         .collect_vec()
+///         .to_debug_string(),
+///     "[11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47]"
+/// );
+/// let x = Natural::from(10u32).pow(30);
+/// assert_eq!(
+///     Natural::primes_in_range(&x, &(&x + Natural::from(500u32)))
+///         .collect_vec()
+///         .to_debug_string(),
+///     "[1000000000000000000000000000057, 1000000000000000000000000000099, \
+///     1000000000000000000000000000211, 1000000000000000000000000000231, \
+///     1000000000000000000000000000271, 1000000000000000000000000000469]"
+/// );
+/// ```
 pub mod primes;
+/// Code for the self-initializing quadratic sieve.
+pub mod siqs;
+/// Implementations of [`Totient`](malachite_base::num::factorization::traits::Totient) and
+/// [`CarmichaelLambda`](malachite_base::num::factorization::traits::CarmichaelLambda), traits for
+/// computing Euler's totient function and the Carmichael function of a number.
+///
+/// # totient
+/// ```
+/// use malachite_base::num::arithmetic::traits::Pow;
+/// use malachite_base::num::factorization::traits::Totient;
+/// use malachite_nz::natural::Natural;
+///
+/// assert_eq!(Natural::from(1u32).totient(), 1);
+/// assert_eq!(Natural::from(360u32).totient(), 96);
+/// assert_eq!(
+///     (Natural::from(2u32).pow(64) + Natural::from(1u32))
+///         .totient()
+///         .to_string(),
+///     "18446676793287966720"
+/// );
+/// assert_eq!(
+///     Natural::from(10u32).pow(30).totient().to_string(),
+///     "400000000000000000000000000000"
+/// );
+/// ```
+///
+/// # totient_from_factorization
+/// ```
+/// use malachite_base::num::factorization::traits::Totient;
+/// use malachite_nz::natural::Natural;
+///
+/// assert_eq!(Natural::totient_from_factorization(&[]), 1);
+/// assert_eq!(
+///     Natural::totient_from_factorization(&[
+///         (Natural::from(2u32), 3),
+///         (Natural::from(3u32), 2),
+///         (Natural::from(5u32), 1)
+///     ]),
+///     96
+/// );
+/// ```
+///
+/// # carmichael_lambda
+/// ```
+/// use malachite_base::num::arithmetic::traits::Pow;
+/// use malachite_base::num::factorization::traits::CarmichaelLambda;
+/// use malachite_nz::natural::Natural;
+///
+/// assert_eq!(Natural::from(1u32).carmichael_lambda(), 1);
+/// assert_eq!(Natural::from(360u32).carmichael_lambda(), 12);
+/// assert_eq!(
+///     (Natural::from(2u32).pow(64) + Natural::from(1u32))
+///         .carmichael_lambda()
+///         .to_string(),
+///     "72057331223781120"
+/// );
+/// assert_eq!(
+///     Natural::from(10u32).pow(30).carmichael_lambda().to_string(),
+///     "50000000000000000000000000000"
+/// );
+/// ```
+///
+/// # carmichael_lambda_from_factorization
+/// ```
+/// use malachite_base::num::factorization::traits::CarmichaelLambda;
+/// use malachite_nz::natural::Natural;
+///
+/// assert_eq!(Natural::carmichael_lambda_from_factorization(&[]), 1);
+/// assert_eq!(
+///     Natural::carmichael_lambda_from_factorization(&[
+///         (Natural::from(2u32), 3),
+///         (Natural::from(3u32), 2),
+///         (Natural::from(5u32), 1)
+///     ]),
+///     12
+/// );
+/// ```
+pub mod totient;
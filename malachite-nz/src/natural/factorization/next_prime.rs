@@ -0,0 +1,90 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::natural::Natural;
+use core::cmp::max;
+use malachite_base::num::arithmetic::traits::SaturatingSub;
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::factorization::traits::{NextPrime, PreviousPrime};
+use malachite_base::num::logic::traits::SignificantBits;
+
+// The largest prime less than 2^64.
+const LARGEST_U64_PRIME: u64 = 18446744073709551557;
+
+// The length of the windows searched for primes above 2^64. Prime gaps near n average about
+// log n, so a window of a few times the bit length usually contains a prime.
+fn window_length(n: &Natural) -> Natural {
+    Natural::from(max(n.significant_bits() << 6, 1 << 10))
+}
+
+impl NextPrime for Natural {
+    /// Returns the smallest prime greater than a [`Natural`].
+    ///
+    /// Above $2^{64}$, windows above the [`Natural`] are searched using
+    /// [`Natural::primes_in_range`]. The result is then only known to be a probable prime; see
+    /// [`is_prime`](malachite_base::num::factorization::traits::IsPrime::is_prime).
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// See [here](super::next_prime#next_prime).
+    fn next_prime(&self) -> Natural {
+        if let Ok(x) = u64::try_from(self) {
+            if x < LARGEST_U64_PRIME {
+                return Natural::from(x.next_prime());
+            }
+        }
+        let window = window_length(self);
+        let mut start = self + Natural::ONE;
+        loop {
+            let end = &start + &window;
+            if let Some(p) = Natural::primes_in_range(&start, &end).next() {
+                return p;
+            }
+            start = end;
+        }
+    }
+}
+
+impl PreviousPrime for Natural {
+    /// Returns the largest prime less than a [`Natural`], or `None` if the [`Natural`] is less
+    /// than or equal to 2.
+    ///
+    /// Above $2^{64}$, windows below the [`Natural`] are searched using
+    /// [`Natural::primes_in_range`]. The result is then only known to be a probable prime; see
+    /// [`is_prime`](malachite_base::num::factorization::traits::IsPrime::is_prime).
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^3 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Examples
+    /// See [here](super::next_prime#previous_prime).
+    fn previous_prime(&self) -> Option<Natural> {
+        if let Ok(x) = u64::try_from(self) {
+            return x.previous_prime().map(Natural::from);
+        }
+        let window = window_length(self);
+        let mut end = self.clone();
+        loop {
+            let start = (&end).saturating_sub(&window);
+            if let Some(p) = Natural::primes_in_range(&start, &end).last() {
+                return Some(p);
+            }
+            end = start;
+        }
+    }
+}
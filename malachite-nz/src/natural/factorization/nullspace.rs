@@ -0,0 +1,394 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+//
+// The block Lanczos implementation is derived from the description in Peter L. Montgomery, "A
+// Block Lanczos Algorithm for Finding Dependencies over GF(2)", EUROCRYPT 1995, and from the
+// structure of the implementation in msieve, by Jason Papadopoulos.
+
+use alloc::vec::Vec;
+
+// A sparse matrix over GF(2) is represented by its columns; each column is the list of the indices
+// of its nonzero rows. Every function in this module finds dependencies among the columns: sets of
+// columns that sum to zero. Up to 64 dependencies are returned at once, as a `Vec<u64>` with one
+// entry per column, where bit `i` of an entry is set if the column belongs to the `i`th dependency.
+// Some of the 64 dependencies may be empty.
+
+// When a matrix has at most this many columns, dense Gaussian elimination is used instead of block
+// Lanczos.
+const DENSE_THRESHOLD: usize = 1000;
+
+// A 64 x 64 matrix over GF(2), where `m[i]` holds row `i` and bit `j` of a row holds column `j`.
+type Matrix64 = [u64; 64];
+
+// A small xorshift generator, used to choose the starting vectors for block Lanczos.
+pub(crate) struct XorShift(u64);
+
+impl XorShift {
+    pub(crate) const fn new(seed: u64) -> XorShift {
+        XorShift(seed ^ 0x9e37_79b9_7f4a_7c15)
+    }
+
+    pub(crate) const fn next(&mut self) -> u64 {
+        let mut x = self.0;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.0 = x;
+        x
+    }
+}
+
+// Finds dependencies among the columns using Gaussian elimination on a dense matrix. Each column is
+// stored as a bit vector, along with a bit vector recording which of the original columns have been
+// added to it.
+fn dense_dependencies(columns: &[Vec<u32>], num_rows: usize) -> Vec<u64> {
+    let num_columns = columns.len();
+    let row_words = num_rows.div_ceil(64);
+    let history_words = num_columns.div_ceil(64);
+    let width = row_words + history_words;
+    let mut m = alloc::vec![0u64; num_columns * width];
+    for (i, column) in columns.iter().enumerate() {
+        let row = &mut m[i * width..(i + 1) * width];
+        for &r in column {
+            let r = r as usize;
+            row[r >> 6] ^= 1 << (r & 63);
+        }
+        row[row_words + (i >> 6)] |= 1 << (i & 63);
+    }
+    let mut pivot_row = 0;
+    for bit in 0..num_rows {
+        let word = bit >> 6;
+        let mask = 1 << (bit & 63);
+        let Some(p) = (pivot_row..num_columns).find(|&i| m[i * width + word] & mask != 0) else {
+            continue;
+        };
+        if p != pivot_row {
+            for k in 0..width {
+                m.swap(p * width + k, pivot_row * width + k);
+            }
+        }
+        let (before, after) = m.split_at_mut((pivot_row + 1) * width);
+        let pivot = &before[pivot_row * width..];
+        for row in after.chunks_exact_mut(width) {
+            if row[word] & mask != 0 {
+                for (x, y) in row[word..].iter_mut().zip(pivot[word..].iter()) {
+                    *x ^= y;
+                }
+            }
+        }
+        pivot_row += 1;
+    }
+    // The rows from `pivot_row` onward are zero in their first `num_rows` bits, so their histories
+    // are dependencies.
+    let mut dependencies = alloc::vec![0u64; num_columns];
+    for (d, row) in m.chunks_exact(width).skip(pivot_row).take(64).enumerate() {
+        for (i, dependency) in dependencies.iter_mut().enumerate() {
+            if row[row_words + (i >> 6)] & (1 << (i & 63)) != 0 {
+                *dependency |= 1 << d;
+            }
+        }
+    }
+    dependencies
+}
+
+// Computes `y = B x`, where `x` has one entry per column and `y` has one entry per row.
+fn mul_b(columns: &[Vec<u32>], x: &[u64], y: &mut [u64]) {
+    y.iter_mut().for_each(|y| *y = 0);
+    for (column, &x) in columns.iter().zip(x.iter()) {
+        for &r in column {
+            y[r as usize] ^= x;
+        }
+    }
+}
+
+// Computes `y = B^T x`, where `x` has one entry per row and `y` has one entry per column.
+fn mul_b_transpose(columns: &[Vec<u32>], x: &[u64], y: &mut [u64]) {
+    for (column, y) in columns.iter().zip(y.iter_mut()) {
+        *y = column.iter().fold(0, |acc, &r| acc ^ x[r as usize]);
+    }
+}
+
+// Computes `x^T y`, a 64 x 64 matrix, where `x` and `y` are N x 64 matrices.
+fn mul_transpose_n(x: &[u64], y: &[u64]) -> Matrix64 {
+    let mut out = [0; 64];
+    for (&x, &y) in x.iter().zip(y.iter()) {
+        let mut bits = x;
+        while bits != 0 {
+            out[bits.trailing_zeros() as usize] ^= y;
+            bits &= bits - 1;
+        }
+    }
+    out
+}
+
+// Computes the product of a 1 x 64 vector and a 64 x 64 matrix.
+const fn mul_vector_64(mut x: u64, m: &Matrix64) -> u64 {
+    let mut out = 0;
+    while x != 0 {
+        out ^= m[x.trailing_zeros() as usize];
+        x &= x - 1;
+    }
+    out
+}
+
+// Computes `x m`, where `x` is N x 64 and `m` is 64 x 64, and adds the result to `y`.
+fn mul_n_64_add(x: &[u64], m: &Matrix64, y: &mut [u64]) {
+    for (&x, y) in x.iter().zip(y.iter_mut()) {
+        *y ^= mul_vector_64(x, m);
+    }
+}
+
+fn mul_64_64(a: &Matrix64, b: &Matrix64) -> Matrix64 {
+    let mut out = [0; 64];
+    for (out, &a) in out.iter_mut().zip(a.iter()) {
+        *out = mul_vector_64(a, b);
+    }
+    out
+}
+
+fn add_identity(m: &mut Matrix64) {
+    for (i, row) in m.iter_mut().enumerate() {
+        *row ^= 1 << i;
+    }
+}
+
+// Given the symmetric matrix `t` = $V_i^T A V_i$, chooses a set of column indices $S_i$ such that
+// the corresponding submatrix of `t` is invertible, preferring the columns that were not chosen in
+// the previous iteration. The chosen indices are written to `s` and their number is returned, and
+// the inverse of the submatrix, embedded in a 64 x 64 matrix, is written to `w`. Returns `None` if
+// the iteration cannot continue.
+fn find_nonsingular_sub(
+    t: &Matrix64,
+    s: &mut [usize; 64],
+    last_s: &[usize; 64],
+    last_dim: usize,
+    w: &mut Matrix64,
+) -> Option<usize> {
+    // m = [t | I]
+    let mut m = [[0u64; 2]; 64];
+    for (i, row) in m.iter_mut().enumerate() {
+        *row = [t[i], 1 << i];
+    }
+    // Put the indices that were chosen last time at the end of s, and the others at the front.
+    let mut mask = 0u64;
+    for i in 0..last_dim {
+        mask |= 1 << last_s[i];
+        s[63 - i] = last_s[i];
+    }
+    let mut j = 0;
+    for i in 0..64 {
+        if mask & (1 << i) == 0 {
+            s[j] = i;
+            j += 1;
+        }
+    }
+    let mut dim = 0;
+    for i in 0..64 {
+        let mask = 1u64 << s[i];
+        // Find a pivot row for column s[i] and move it to row s[i].
+        if let Some(j) = (i..64).find(|&j| m[s[j]][0] & mask != 0) {
+            m.swap(s[i], s[j]);
+            let pivot = m[s[i]];
+            for (j, row) in m.iter_mut().enumerate() {
+                if j != s[i] && row[0] & mask != 0 {
+                    row[0] ^= pivot[0];
+                    row[1] ^= pivot[1];
+                }
+            }
+            s[dim] = s[i];
+            dim += 1;
+        } else {
+            // There's no pivot; use the right half of m to remove the column instead.
+            let j = (i..64).find(|&j| m[s[j]][1] & mask != 0)?;
+            m.swap(s[i], s[j]);
+            let pivot = m[s[i]];
+            for (j, row) in m.iter_mut().enumerate() {
+                if j != s[i] && row[1] & mask != 0 {
+                    row[0] ^= pivot[0];
+                    row[1] ^= pivot[1];
+                }
+            }
+            m[s[i]] = [0, 0];
+        }
+    }
+    for (w, row) in w.iter_mut().zip(m.iter()) {
+        *w = row[1];
+    }
+    // Every column must have been chosen in this iteration or the previous one.
+    let mut mask = 0u64;
+    for &i in &s[..dim] {
+        mask |= 1 << i;
+    }
+    for &i in &last_s[..last_dim] {
+        mask |= 1 << i;
+    }
+    if mask == u64::MAX {
+        Some(dim)
+    } else {
+        None
+    }
+}
+
+// Given the N x 64 matrices `x` and `v` produced by block Lanczos, for which $B^T B x$ and $B^T B
+// v$ are zero but $B x$ and $B v$ might not be, finds linear combinations of their 128 columns that
+// lie in the nullspace of $B$.
+fn combine_cofactors(columns: &[Vec<u32>], num_rows: usize, x: &[u64], v: &[u64]) -> Vec<u64> {
+    let mut bx = alloc::vec![0; num_rows];
+    let mut bv = alloc::vec![0; num_rows];
+    mul_b(columns, x, &mut bx);
+    mul_b(columns, v, &mut bv);
+    // basis[i] describes a combination of the 128 columns of [x | v]; start with the standard basis
+    // and remove one vector for every row on which the combinations are not all zero.
+    let mut basis: Vec<u128> = (0..128).map(|i| 1 << i).collect();
+    for (&bx, &bv) in bx.iter().zip(bv.iter()) {
+        let row = u128::from(bx) | (u128::from(bv) << 64);
+        if let Some(p) = basis.iter().position(|&c| (row & c).count_ones() & 1 == 1) {
+            let pivot = basis.swap_remove(p);
+            for c in &mut basis {
+                if (row & *c).count_ones() & 1 == 1 {
+                    *c ^= pivot;
+                }
+            }
+        }
+        if basis.is_empty() {
+            break;
+        }
+    }
+    let mut dependencies = alloc::vec![0u64; columns.len()];
+    let mut d = 0;
+    for c in basis {
+        let mut nonzero = false;
+        for ((&x, &v), dependency) in x.iter().zip(v.iter()).zip(dependencies.iter_mut()) {
+            let combined = u128::from(x) | (u128::from(v) << 64);
+            if (combined & c).count_ones() & 1 == 1 {
+                *dependency |= 1 << d;
+                nonzero = true;
+            }
+        }
+        if nonzero {
+            d += 1;
+            if d == 64 {
+                break;
+            }
+        }
+    }
+    dependencies
+}
+
+// Finds dependencies among the columns using Montgomery's block Lanczos algorithm, applied to the
+// symmetric matrix $A = B^T B$. Returns `None` if the iteration breaks down, in which case a
+// different seed may be tried.
+fn block_lanczos(columns: &[Vec<u32>], num_rows: usize, seed: u64) -> Option<Vec<u64>> {
+    let n = columns.len();
+    let mut rng = XorShift::new(seed);
+    let mut scratch = alloc::vec![0u64; num_rows];
+    let mul_a = |x: &[u64], y: &mut [u64], scratch: &mut [u64]| {
+        mul_b(columns, x, scratch);
+        mul_b_transpose(columns, scratch, y);
+    };
+    // The solution x starts off random, and v_0 is A x.
+    let mut x: Vec<u64> = (0..n).map(|_| rng.next()).collect();
+    let mut v0 = alloc::vec![0u64; n];
+    mul_a(&x, &mut v0, &mut scratch);
+    let mut v = [v0.clone(), alloc::vec![0u64; n], alloc::vec![0u64; n]];
+    let mut v_next = alloc::vec![0u64; n];
+    let mut winv = [[0u64; 64]; 3];
+    let mut vt_a_v = [[0u64; 64]; 2];
+    let mut vt_a2_v = [[0u64; 64]; 2];
+    let mut s = [[0usize; 64]; 2];
+    for (i, x) in s[1].iter_mut().enumerate() {
+        *x = i;
+    }
+    let mut dim_1 = 64;
+    let mut mask_1 = u64::MAX;
+    // The iteration is expected to take about n / 63 steps.
+    let max_iterations = n / 32 + 100;
+    let mut iterations = 0;
+    loop {
+        iterations += 1;
+        if iterations > max_iterations {
+            return None;
+        }
+        mul_a(&v[0], &mut v_next, &mut scratch);
+        vt_a_v[0] = mul_transpose_n(&v[0], &v_next);
+        vt_a2_v[0] = mul_transpose_n(&v_next, &v_next);
+        if vt_a_v[0].iter().all(|&r| r == 0) {
+            break;
+        }
+        let (s_0, s_1) = s.split_at_mut(1);
+        let dim_0 = find_nonsingular_sub(&vt_a_v[0], &mut s_0[0], &s_1[0], dim_1, &mut winv[0])?;
+        if dim_0 == 0 {
+            return None;
+        }
+        let mut mask_0 = 0u64;
+        for &i in &s[0][..dim_0] {
+            mask_0 |= 1 << i;
+        }
+        // d = I - W_inv_0 (v_0^T A^2 v_0 S S^T + v_0^T A v_0)
+        let mut d = [0u64; 64];
+        for i in 0..64 {
+            d[i] = (vt_a2_v[0][i] & mask_0) ^ vt_a_v[0][i];
+        }
+        let mut d = mul_64_64(&winv[0], &d);
+        add_identity(&mut d);
+        // e = -W_inv_1 v_0^T A v_0 S S^T
+        let mut e = mul_64_64(&winv[1], &vt_a_v[0]);
+        for row in &mut e {
+            *row &= mask_0;
+        }
+        // f = -W_inv_2 (I - v_1^T A v_1 W_inv_1) (v_1^T A^2 v_1 S_1 S_1^T + v_1^T A v_1) S S^T
+        let mut f = mul_64_64(&vt_a_v[1], &winv[1]);
+        add_identity(&mut f);
+        let f = mul_64_64(&winv[2], &f);
+        let mut f_2 = [0u64; 64];
+        for i in 0..64 {
+            f_2[i] = ((vt_a2_v[1][i] & mask_1) ^ vt_a_v[1][i]) & mask_0;
+        }
+        let f = mul_64_64(&f, &f_2);
+        // v_next = A v_0 S S^T + v_0 d + v_1 e + v_2 f
+        for x in &mut v_next {
+            *x &= mask_0;
+        }
+        mul_n_64_add(&v[0], &d, &mut v_next);
+        mul_n_64_add(&v[1], &e, &mut v_next);
+        mul_n_64_add(&v[2], &f, &mut v_next);
+        // x += v_0 W_inv_0 v_0^T v0
+        let d = mul_64_64(&winv[0], &mul_transpose_n(&v[0], &v0));
+        mul_n_64_add(&v[0], &d, &mut x);
+        // Rotate everything.
+        v.rotate_right(1);
+        core::mem::swap(&mut v[0], &mut v_next);
+        winv.copy_within(0..2, 1);
+        vt_a_v[1] = vt_a_v[0];
+        vt_a2_v[1] = vt_a2_v[0];
+        s[1] = s[0];
+        mask_1 = mask_0;
+        dim_1 = dim_0;
+    }
+    let dependencies = combine_cofactors(columns, num_rows, &x, &v[0]);
+    if dependencies.iter().all(|&d| d == 0) {
+        None
+    } else {
+        Some(dependencies)
+    }
+}
+
+// Finds up to 64 dependencies among the columns of a sparse matrix over GF(2) with `num_rows` rows.
+// The result has one entry per column, and bit `i` of an entry is set if the column belongs to the
+// `i`th dependency. There should be substantially more columns than rows.
+pub_crate_test! {find_dependencies(columns: &[Vec<u32>], num_rows: usize) -> Vec<u64> {
+    if columns.len() <= DENSE_THRESHOLD {
+        return dense_dependencies(columns, num_rows);
+    }
+    for seed in 0..8 {
+        if let Some(dependencies) = block_lanczos(columns, num_rows, seed) {
+            return dependencies;
+        }
+    }
+    dense_dependencies(columns, num_rows)
+}}
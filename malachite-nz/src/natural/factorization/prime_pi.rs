@@ -0,0 +1,80 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::natural::Natural;
+use malachite_base::num::factorization::traits::PrimePi;
+
+impl Natural {
+    /// Returns the number of primes less than or equal to a [`Natural`], or `None` if the
+    /// [`Natural`] is greater than or equal to $2^{64}$.
+    ///
+    /// $$
+    /// f(n) = \\begin{cases}
+    ///     \operatorname{Some}(\pi(n)) & \text{if} \\quad n < 2^{64}, \\\\
+    ///     \operatorname{None} & \\text{otherwise},
+    /// \\end{cases}
+    /// $$
+    /// where $\pi(n) = |\\{p \leq n : p \text{ is prime}\\}|$.
+    ///
+    /// The Lagarias–Miller–Odlyzko method is used, so that the primes are counted without being
+    /// enumerated. Even so, the running time grows as $n^{2/3}$, so larger [`Natural`]s are not
+    /// supported.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{2/3} \log n)$
+    ///
+    /// $M(n) = O(n^{1/3})$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Pow;
+    /// use malachite_nz::natural::Natural;
+    ///
+    /// assert_eq!(Natural::from(100u32).checked_prime_pi(), Some(Natural::from(25u32)));
+    /// assert_eq!(
+    ///     Natural::from(10u32).pow(10).checked_prime_pi(),
+    ///     Some(Natural::from(455052511u32))
+    /// );
+    /// assert_eq!(Natural::from(2u32).pow(64).checked_prime_pi(), None);
+    /// ```
+    pub fn checked_prime_pi(&self) -> Option<Natural> {
+        u64::try_from(self)
+            .ok()
+            .map(|n| Natural::from(n.prime_pi()))
+    }
+}
+
+impl PrimePi for Natural {
+    /// Returns the number of primes less than or equal to a [`Natural`].
+    ///
+    /// $f(n) = \pi(n) = |\\{p \leq n : p \text{ is prime}\\}|$.
+    ///
+    /// The Lagarias–Miller–Odlyzko method is used, so that the primes are counted without being
+    /// enumerated. Even so, the running time grows as $n^{2/3}$, so only [`Natural`]s less than
+    /// $2^{64}$ are supported; see [`Natural::checked_prime_pi`] for a version that doesn't panic.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^{2/3} \log n)$
+    ///
+    /// $M(n) = O(n^{1/3})$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self`.
+    ///
+    /// # Panics
+    /// Panics if `self` is greater than or equal to $2^{64}$.
+    ///
+    /// # Examples
+    /// See [here](super::prime_pi#prime_pi).
+    #[inline]
+    fn prime_pi(&self) -> Natural {
+        self.checked_prime_pi()
+            .expect("prime_pi is only supported for Naturals less than 2^64")
+    }
+}
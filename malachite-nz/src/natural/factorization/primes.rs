@@ -7,9 +7,24 @@
 // 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
 
 use crate::natural::Natural;
-use malachite_base::num::conversion::traits::SaturatingFrom;
+use crate::platform::Limb;
+use alloc::vec::Vec;
+use core::cmp::{max, min};
+use malachite_base::num::arithmetic::traits::{FloorSqrt, Parity};
+use malachite_base::num::basic::traits::{One, Two, Zero};
+use malachite_base::num::conversion::traits::{ExactFrom, SaturatingFrom, WrappingFrom};
 use malachite_base::num::factorization::primes::{PrimesIterator, PrimesLessThanIterator};
-use malachite_base::num::factorization::traits::Primes;
+use malachite_base::num::factorization::traits::{IsPrime, Primes};
+
+// The number of odd numbers in each segment sieved by `NaturalPrimesInRangeIterator`.
+const SEGMENT_LENGTH: u64 = 1 << 15;
+
+// `NaturalPrimesInRangeIterator` sieves with primes up to approximately the length of the range,
+// but no fewer than `MIN_SIEVE_LIMIT` and no more than `MAX_SIEVE_LIMIT`, unless the square root of
+// the end of the range is smaller. If the sieving primes don't reach the square root, the survivors
+// are checked with a primality test.
+const MIN_SIEVE_LIMIT: u64 = 1 << 10;
+const MAX_SIEVE_LIMIT: u64 = 1 << 20;
 
 /// An iterator over that generates all prime [`Natural`]s less than a given value.
 ///
@@ -104,3 +119,142 @@ impl Primes for Natural {
         NaturalPrimesIterator(u64::primes())
     }
 }
+
+/// An iterator that generates all prime [`Natural`]s in a range.
+///
+/// The range is processed in segments, each of which is sieved with small primes. If the range
+/// lies far above $2^{64}$, so that sieving with all primes up to the square root of its end would
+/// be impractical, the numbers that survive sieving are checked with a primality test.
+///
+/// This `struct` is created by [`Natural::primes_in_range`]; see its documentation for more.
+#[derive(Clone, Debug)]
+pub struct NaturalPrimesInRangeIterator {
+    yield_two: bool,
+    // The odd number corresponding to the first entry of `sieve`.
+    segment_start: Natural,
+    // The number of odd numbers left in the range, starting from `segment_start`.
+    remaining: Natural,
+    // Odd primes used for sieving.
+    sieving_primes: Vec<Limb>,
+    // Whether `sieving_primes` contains all odd primes up to the square root of the range's end.
+    sieve_is_complete: bool,
+    // `sieve[i]` is false if `segment_start + 2 * i` is known to be composite.
+    sieve: Vec<bool>,
+    i: usize,
+}
+
+impl NaturalPrimesInRangeIterator {
+    fn sieve_next_segment(&mut self) -> bool {
+        self.segment_start += Natural::from(u64::wrapping_from(self.sieve.len()) << 1);
+        if self.remaining == 0u32 {
+            return false;
+        }
+        let length = min(SEGMENT_LENGTH, u64::saturating_from(&self.remaining));
+        self.remaining -= Natural::from(length);
+        let length = usize::wrapping_from(length);
+        self.sieve.clear();
+        self.sieve.resize(length, true);
+        self.i = 0;
+        let start = &self.segment_start;
+        for &p in &self.sieving_primes {
+            // Find the index of the first odd multiple of p that is at least the segment start.
+            let r = start.rem_limb_ref(p);
+            let mut j = if r == 0 {
+                0
+            } else if (p - r).even() {
+                (p - r) >> 1
+            } else {
+                (p << 1).wrapping_sub(r) >> 1
+            };
+            // Don't cross out p itself.
+            if *start <= p && Limb::exact_from(start) + (j << 1) == p {
+                j += p;
+            }
+            let mut j = usize::wrapping_from(j);
+            let p = usize::wrapping_from(p);
+            while j < length {
+                self.sieve[j] = false;
+                j += p;
+            }
+        }
+        true
+    }
+}
+
+impl Iterator for NaturalPrimesInRangeIterator {
+    type Item = Natural;
+
+    fn next(&mut self) -> Option<Natural> {
+        if self.yield_two {
+            self.yield_two = false;
+            return Some(Natural::TWO);
+        }
+        loop {
+            while self.i < self.sieve.len() {
+                let i = self.i;
+                self.i += 1;
+                if self.sieve[i] {
+                    let n = &self.segment_start + Natural::from(u64::wrapping_from(i) << 1);
+                    if self.sieve_is_complete || n.is_prime() {
+                        return Some(n);
+                    }
+                }
+            }
+            if !self.sieve_next_segment() {
+                return None;
+            }
+        }
+    }
+}
+
+impl Natural {
+    /// Returns an iterator that generates all primes in the range $[a, b)$, in ascending order.
+    ///
+    /// Unlike [`Natural::primes`], which is backed by an iterator over `u64`s, this function works
+    /// for ranges at any height. The range is sieved segment by segment, using primes up to about
+    /// the length of the range. If that isn't enough to reach the square root of $b$, the numbers
+    /// that survive the sieve are checked with
+    /// [`is_prime`](malachite_base::num::factorization::traits::IsPrime::is_prime).
+    ///
+    /// # Worst-case complexity (amortized)
+    /// $T(i, n) = O(n^3 \log n \log\log n)$
+    ///
+    /// $M(i, n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $i$ is the iteration index, and $n$ is
+    /// `b.significant_bits()`.
+    ///
+    /// # Examples
+    /// See [here](super::primes#primes_in_range).
+    pub fn primes_in_range(a: &Natural, b: &Natural) -> NaturalPrimesInRangeIterator {
+        let yield_two = *a <= 2u32 && *b > 2u32;
+        let mut start = max(a.clone(), Natural::from(3u32));
+        if start.even() {
+            start += Natural::ONE;
+        }
+        let remaining = if *b > start {
+            (b - &start + Natural::ONE) >> 1
+        } else {
+            Natural::ZERO
+        };
+        let sqrt = u64::saturating_from(&(b.floor_sqrt()));
+        let limit = min(
+            sqrt,
+            u64::saturating_from(&remaining).clamp(MIN_SIEVE_LIMIT, MAX_SIEVE_LIMIT),
+        );
+        let sieving_primes = Limb::primes_less_than_or_equal_to(&Limb::wrapping_from(limit))
+            .skip(1)
+            .collect();
+        let mut iterator = NaturalPrimesInRangeIterator {
+            yield_two,
+            segment_start: start,
+            remaining,
+            sieving_primes,
+            sieve_is_complete: limit == sqrt,
+            sieve: Vec::new(),
+            i: 0,
+        };
+        iterator.sieve_next_segment();
+        iterator
+    }
+}
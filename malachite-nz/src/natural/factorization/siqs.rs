@@ -0,0 +1,737 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+//
+// The parameter choices are derived from msieve, by Jason Papadopoulos; the polynomial switching
+// follows Scott Contini, "Factoring Integers with the Self-Initializing Quadratic Sieve", 1997.
+
+use crate::integer::Integer;
+use crate::natural::factorization::nullspace::{find_dependencies, XorShift};
+use crate::natural::Natural;
+use crate::platform::Limb;
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::vec::Vec;
+use malachite_base::num::arithmetic::traits::{
+    DivExact, DivExactAssign, Gcd, LegendreSymbol, ModInverse, ModMul, ModPow, ModSub, Square,
+    UnsignedAbs,
+};
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::conversion::traits::{ExactFrom, WrappingFrom};
+use malachite_base::num::factorization::traits::Primes;
+use malachite_base::num::logic::traits::SignificantBits;
+
+// Odd squarefree multipliers $k$ to consider; the quadratic sieve is applied to $kn$.
+const MULTIPLIERS: [u32; 36] = [
+    1, 3, 5, 7, 11, 13, 15, 17, 19, 21, 23, 29, 31, 33, 35, 37, 39, 41, 43, 47, 51, 53, 55, 57, 59,
+    61, 65, 67, 69, 71, 73, 77, 79, 83, 85, 87,
+];
+
+// The parameters used for an input of a given size: the number of bits of $kn$, the number of
+// primes in the factor base, the multiple of the largest factor base prime below which partial
+// relations are kept, and the length of the sieve interval. Parameters for other sizes are
+// interpolated.
+const SIQS_PARAMETERS: [(u64, usize, u64, usize); 12] = [
+    (64, 100, 40, 1 << 16),
+    (128, 450, 40, 1 << 16),
+    (183, 2000, 40, 1 << 16),
+    (200, 3000, 50, 1 << 16),
+    (212, 5400, 50, 3 << 16),
+    (233, 10000, 100, 3 << 16),
+    (249, 27000, 100, 3 << 16),
+    (266, 50000, 100, 3 << 16),
+    (283, 55000, 80, 3 << 16),
+    (298, 60000, 80, 9 << 16),
+    (315, 80000, 150, 9 << 16),
+    (332, 100000, 150, 9 << 16),
+];
+
+// Primes less than this are not used for sieving; their contribution is accounted for when
+// choosing the sieve threshold.
+const SMALL_PRIME_LIMIT: u32 = 40;
+
+// Primes at least this large are located by resieving rather than by trial division.
+const RESIEVE_LIMIT: u32 = 256;
+
+// How many bits below the estimated size of a sieve value the threshold is set, in addition to
+// the size of the largest allowed large prime. This makes up for the primes that are not sieved
+// and for rounding in the logarithms; it was chosen empirically.
+const THRESHOLD_FUDGE: u64 = 12;
+
+const CANDIDATE_MARK: u8 = u8::MAX;
+
+// Returns `x` mod `p`.
+fn mod_u32(x: &Natural, p: u32) -> u32 {
+    u32::wrapping_from(x.rem_limb_ref(Limb::from(p)))
+}
+
+// Returns `x` mod `p`, where `x` may be negative.
+fn integer_mod_u32(x: &Integer, p: u32) -> u32 {
+    let r = mod_u32(x.unsigned_abs_ref(), p);
+    if r != 0 && *x < 0 {
+        p - r
+    } else {
+        r
+    }
+}
+
+// Returns a square root of `a` modulo the odd prime `p`, using the Tonelli–Shanks algorithm. `a`
+// must be a quadratic residue modulo `p`.
+fn sqrt_mod_prime(a: u64, p: u64) -> u64 {
+    if a == 0 {
+        return 0;
+    }
+    if p & 3 == 3 {
+        return a.mod_pow((p + 1) >> 2, p);
+    }
+    let s = (p - 1).trailing_zeros();
+    let q = (p - 1) >> s;
+    let mut z = 2;
+    while z.legendre_symbol(p) != -1 {
+        z += 1;
+    }
+    let mut m = s;
+    let mut c = z.mod_pow(q, p);
+    let mut t = a.mod_pow(q, p);
+    let mut r = a.mod_pow((q + 1) >> 1, p);
+    while t != 1 {
+        let mut i = 0;
+        let mut t_2 = t;
+        while t_2 != 1 {
+            t_2 = t_2.mod_mul(t_2, p);
+            i += 1;
+        }
+        let b = c.mod_pow(1 << (m - i - 1), p);
+        m = i;
+        c = b.mod_mul(b, p);
+        t = t.mod_mul(c, p);
+        r = r.mod_mul(b, p);
+    }
+    r
+}
+
+// Chooses a multiplier $k$ using the Knuth–Schroeppel function, which estimates how many small
+// primes will divide the sieve values when sieving with $kn$.
+fn choose_multiplier(n: &Natural) -> u32 {
+    let ln_2 = core::f64::consts::LN_2;
+    let mut best = 1;
+    let mut best_score = f64::NEG_INFINITY;
+    let n_mod_8 = mod_u32(n, 8);
+    let odd_primes = u32::primes_less_than(&1000).skip(1).collect::<Vec<_>>();
+    let n_residues = odd_primes
+        .iter()
+        .map(|&p| mod_u32(n, p))
+        .collect::<Vec<_>>();
+    for &k in &MULTIPLIERS {
+        let mut score = -0.5 * libm::log(f64::from(k));
+        score += match (k * n_mod_8) & 7 {
+            1 => 2.0 * ln_2,
+            5 => ln_2,
+            _ => 0.5 * ln_2,
+        };
+        for (&p, &r) in odd_primes.iter().zip(n_residues.iter()) {
+            let ln_p = libm::log(f64::from(p));
+            if k % p == 0 {
+                score += ln_p / f64::from(p);
+            } else if (u64::from(k) * u64::from(r) % u64::from(p)).legendre_symbol(u64::from(p))
+                == 1
+            {
+                score += 2.0 * ln_p / f64::from(p - 1);
+            }
+        }
+        if score > best_score {
+            best_score = score;
+            best = k;
+        }
+    }
+    best
+}
+
+// Looks up the factor base size, large prime multiplier, and sieve length for an input of the given
+// size.
+fn parameters(bits: u64) -> (usize, u64, usize) {
+    let first = SIQS_PARAMETERS[0];
+    if bits <= first.0 {
+        return (first.1, first.2, first.3);
+    }
+    for w in SIQS_PARAMETERS.windows(2) {
+        let (lo, hi) = (w[0], w[1]);
+        if bits <= hi.0 {
+            let numerator = bits - lo.0;
+            let denominator = hi.0 - lo.0;
+            let fb_size =
+                lo.1 + usize::exact_from(u64::exact_from(hi.1 - lo.1) * numerator / denominator);
+            let large_prime_multiplier =
+                (lo.2 * (denominator - numerator) + hi.2 * numerator) / denominator;
+            return (fb_size, large_prime_multiplier, lo.3);
+        }
+    }
+    let last = SIQS_PARAMETERS[SIQS_PARAMETERS.len() - 1];
+    (last.1, last.2, last.3)
+}
+
+// The factor base: $-1$ (at index 0), 2 (at index 1), and the odd primes $p$ for which $kn$ is a
+// quadratic residue mod $p$, or for which $p$ divides $k$.
+struct FactorBase {
+    primes: Vec<u32>,
+    // A square root of $kn$ mod $p$.
+    sqrts: Vec<u32>,
+    // The base-2 logarithm of $p$, rounded.
+    logs: Vec<u8>,
+}
+
+// Builds a factor base with `size` elements. If a factor of `n` is found along the way, it is
+// returned instead.
+fn factor_base(n: &Natural, kn: &Natural, k: u32, size: usize) -> Result<FactorBase, Natural> {
+    let mut fb = FactorBase {
+        primes: alloc::vec![1, 2],
+        sqrts: alloc::vec![0, 1],
+        logs: alloc::vec![0, 1],
+    };
+    for p in u32::primes().skip(1) {
+        if fb.primes.len() >= size {
+            break;
+        }
+        let r = mod_u32(kn, p);
+        let sqrt = if r == 0 {
+            if k % p != 0 {
+                return Err(Natural::from(p));
+            }
+            0
+        } else if u64::from(r).legendre_symbol(u64::from(p)) == 1 {
+            u32::wrapping_from(sqrt_mod_prime(u64::from(r), u64::from(p)))
+        } else {
+            continue;
+        };
+        if *n == p {
+            break;
+        }
+        fb.primes.push(p);
+        fb.sqrts.push(sqrt);
+        fb.logs.push(libm::round(libm::log2(f64::from(p))) as u8);
+    }
+    Ok(fb)
+}
+
+// A relation $y^2 \equiv \prod_i p_i \cdot L^2 \pmod n$, where the $p_i$ are factor base elements
+// (given by their indices, with repetition) and $L$ is 1 or a large prime shared by two partial
+// relations.
+struct Relation {
+    y: Natural,
+    factors: Vec<u32>,
+    large_prime: u64,
+}
+
+// The state of the sieve for one value of $A$.
+struct PolynomialFamily {
+    a: Natural,
+    // The factor base indices of the primes whose product is $A$.
+    a_factors: Vec<usize>,
+    // The $B_l$, with the signs they have in the current $b$.
+    bs: Vec<Natural>,
+    signs: Vec<bool>,
+    b: Integer,
+    // For each factor base prime, $2 B_l A^{-1} \bmod p$, for each $l$.
+    b_a_inv_2: Vec<Vec<u32>>,
+    // The sieve positions that are roots of the current polynomial mod $p$, or `u32::MAX` for
+    // primes that divide $A$.
+    roots_1: Vec<u32>,
+    roots_2: Vec<u32>,
+}
+
+// The self-initializing quadratic sieve.
+struct Siqs<'a> {
+    n: &'a Natural,
+    kn: Natural,
+    fb: FactorBase,
+    m: u32,
+    large_prime_bound: u64,
+    threshold: u8,
+    sieve_start: usize,
+    resieve_start: usize,
+    rng: XorShift,
+    used_as: BTreeSet<Natural>,
+    relations: Vec<Relation>,
+    partials: BTreeMap<u64, (Natural, Vec<u32>)>,
+    sieve: Vec<u8>,
+}
+
+impl Siqs<'_> {
+    // Chooses a new $A$, a product of factor base primes close to $\sqrt{2kn}/M$, and computes the
+    // first polynomial of the family. Returns `None` if no suitable $A$ can be found.
+    fn new_family(&mut self) -> Option<PolynomialFamily> {
+        let primes = &self.fb.primes;
+        let len = primes.len();
+        let target_bits = (libm::log2(2.0) + self.kn.significant_bits() as f64) / 2.0
+            - libm::log2(f64::from(self.m));
+        // Choose the number of factors so that they are around 2^11, if the factor base allows.
+        let max_log = libm::log2(f64::from(primes[len - 1]));
+        let mut s = libm::round(target_bits / 11.0).max(2.0) as usize;
+        while s < 20 && target_bits / (s as f64) > max_log - 1.5 {
+            s += 1;
+        }
+        let factor_bits = target_bits / s as f64;
+        let lo = primes
+            .partition_point(|&p| libm::log2(f64::from(p)) < factor_bits - 1.0)
+            .max(2);
+        let hi = primes
+            .partition_point(|&p| libm::log2(f64::from(p)) < factor_bits + 1.0)
+            .min(len);
+        if hi <= lo + s {
+            return None;
+        }
+        let usable = |i: usize| self.fb.sqrts[i] != 0;
+        let mut attempts = 0;
+        let (a, a_factors) = loop {
+            attempts += 1;
+            if attempts > 1000 {
+                return None;
+            }
+            let mut a_factors = Vec::with_capacity(s);
+            let mut bits = 0.0;
+            while a_factors.len() < s - 1 {
+                let i = lo + usize::exact_from(self.rng.next() % u64::exact_from(hi - lo));
+                if usable(i) && !a_factors.contains(&i) {
+                    a_factors.push(i);
+                    bits += libm::log2(f64::from(primes[i]));
+                }
+            }
+            // Choose the last factor to bring A as close as possible to the target.
+            let remaining = target_bits - bits;
+            let mut i = primes
+                .partition_point(|&p| libm::log2(f64::from(p)) < remaining)
+                .clamp(2, len - 1);
+            while i < len && (!usable(i) || a_factors.contains(&i)) {
+                i += 1;
+            }
+            if i == len {
+                continue;
+            }
+            a_factors.push(i);
+            a_factors.sort_unstable();
+            let a = a_factors
+                .iter()
+                .fold(Natural::ONE, |a, &i| a * Natural::from(primes[i]));
+            if self.used_as.insert(a.clone()) {
+                break (a, a_factors);
+            }
+        };
+        // B_l = (A / q_l) * gamma, where gamma = sqrt(kn) * (A / q_l)^(-1) mod q_l, and gamma <= q_l
+        // / 2.
+        let mut bs = Vec::with_capacity(s);
+        for &i in &a_factors {
+            let q = primes[i];
+            let a_over_q = (&a).div_exact(Natural::from(q));
+            let inverse = u64::from(mod_u32(&a_over_q, q))
+                .mod_inverse(u64::from(q))
+                .unwrap();
+            let mut gamma = u64::from(self.fb.sqrts[i]).mod_mul(inverse, u64::from(q));
+            if gamma > u64::from(q >> 1) {
+                gamma = u64::from(q) - gamma;
+            }
+            bs.push(a_over_q * Natural::from(gamma));
+        }
+        let b = Integer::from(bs.iter().sum::<Natural>());
+        let mut b_a_inv_2 = alloc::vec![alloc::vec![0u32; len]; s];
+        let mut roots_1 = alloc::vec![u32::MAX; len];
+        let mut roots_2 = alloc::vec![u32::MAX; len];
+        for i in 2..len {
+            if a_factors.contains(&i) {
+                continue;
+            }
+            let p = primes[i];
+            let p_64 = u64::from(p);
+            let a_inv = u64::from(mod_u32(&a, p)).mod_inverse(p_64).unwrap();
+            for (l, b_l) in bs.iter().enumerate() {
+                b_a_inv_2[l][i] = u32::wrapping_from(
+                    ((u64::from(mod_u32(b_l, p)) << 1) % p_64).mod_mul(a_inv, p_64),
+                );
+            }
+            let t = u64::from(self.fb.sqrts[i]);
+            let b_mod_p = u64::from(integer_mod_u32(&b, p));
+            let m_mod_p = u64::from(self.m % p);
+            let root = |t: u64| {
+                u32::wrapping_from(((t + p_64 - b_mod_p) % p_64).mod_mul(a_inv, p_64) + m_mod_p) % p
+            };
+            roots_1[i] = root(t);
+            roots_2[i] = root((p_64 - t) % p_64);
+        }
+        Some(PolynomialFamily {
+            a,
+            a_factors,
+            bs,
+            signs: alloc::vec![true; s],
+            b,
+            b_a_inv_2,
+            roots_1,
+            roots_2,
+        })
+    }
+
+    // Switches to the next polynomial in the family, by flipping the sign of $B_v$.
+    fn switch_polynomial(&self, family: &mut PolynomialFamily, v: usize) {
+        let positive = family.signs[v];
+        family.signs[v] = !positive;
+        let twice_b_v = Integer::from(&family.bs[v]) << 1u32;
+        if positive {
+            family.b -= twice_b_v;
+        } else {
+            family.b += twice_b_v;
+        }
+        let deltas = &family.b_a_inv_2[v];
+        for (((&p, &delta), r_1), r_2) in self
+            .fb
+            .primes
+            .iter()
+            .zip(deltas.iter())
+            .zip(family.roots_1.iter_mut())
+            .zip(family.roots_2.iter_mut())
+            .skip(2)
+        {
+            if *r_1 == u32::MAX {
+                continue;
+            }
+            // If B_v was positive, the roots increase by 2 B_v A^(-1); otherwise they decrease.
+            let shift = |r: u32| {
+                if positive {
+                    let r = r + delta;
+                    if r >= p {
+                        r - p
+                    } else {
+                        r
+                    }
+                } else if r >= delta {
+                    r - delta
+                } else {
+                    r + p - delta
+                }
+            };
+            *r_1 = shift(*r_1);
+            *r_2 = shift(*r_2);
+        }
+    }
+
+    // Sieves the current polynomial and returns the positions whose sieve values reach the
+    // threshold.
+    fn sieve_polynomial(&mut self, family: &PolynomialFamily) -> Vec<usize> {
+        let sieve = &mut self.sieve;
+        sieve.iter_mut().for_each(|x| *x = 0);
+        let len = sieve.len();
+        for i in self.sieve_start..self.fb.primes.len() {
+            let r_1 = family.roots_1[i];
+            if r_1 == u32::MAX {
+                continue;
+            }
+            let p = self.fb.primes[i] as usize;
+            let log = self.fb.logs[i];
+            let r_2 = family.roots_2[i];
+            let mut j = r_1 as usize;
+            while j < len {
+                sieve[j] = sieve[j].wrapping_add(log);
+                j += p;
+            }
+            if r_2 != r_1 {
+                let mut j = r_2 as usize;
+                while j < len {
+                    sieve[j] = sieve[j].wrapping_add(log);
+                    j += p;
+                }
+            }
+        }
+        let threshold = self.threshold;
+        let mut candidates = Vec::new();
+        for (j, x) in sieve.iter_mut().enumerate() {
+            if *x >= threshold {
+                *x = CANDIDATE_MARK;
+                candidates.push(j);
+            } else {
+                *x = 0;
+            }
+        }
+        candidates
+    }
+
+    // For each candidate, finds the large factor base primes that divide its sieve value by
+    // walking through the sieve again.
+    fn resieve(&self, family: &PolynomialFamily, candidates: &[usize]) -> Vec<Vec<u32>> {
+        let mut divisors = alloc::vec![Vec::new(); candidates.len()];
+        let len = self.sieve.len();
+        for i in self.resieve_start..self.fb.primes.len() {
+            let r_1 = family.roots_1[i];
+            if r_1 == u32::MAX {
+                continue;
+            }
+            let p = self.fb.primes[i] as usize;
+            let r_2 = family.roots_2[i];
+            for r in [r_1, r_2] {
+                let mut j = r as usize;
+                while j < len {
+                    if self.sieve[j] == CANDIDATE_MARK {
+                        let c = candidates.binary_search(&j).unwrap();
+                        divisors[c].push(u32::wrapping_from(i));
+                    }
+                    j += p;
+                }
+                if r_2 == r_1 {
+                    break;
+                }
+            }
+        }
+        divisors
+    }
+
+    // Factors the sieve value at position `j` over the factor base. If it is smooth, or smooth apart
+    // from a single large prime, the corresponding relation is recorded. If a factor of $n$ turns up,
+    // it is returned.
+    fn process_candidate(
+        &mut self,
+        family: &PolynomialFamily,
+        j: usize,
+        large_divisors: &[u32],
+    ) -> Option<Natural> {
+        let x = i64::exact_from(j) - i64::from(self.m);
+        let y = Integer::from(&family.a) * Integer::from(x) + &family.b;
+        let q = ((&y).square() - Integer::from(&self.kn)).div_exact(Integer::from(&family.a));
+        let mut factors = Vec::new();
+        if q < 0 {
+            factors.push(0);
+        }
+        let mut q = q.unsigned_abs();
+        if q == 0u32 {
+            return None;
+        }
+        let twos = q.trailing_zeros().unwrap();
+        q >>= twos;
+        factors.resize(factors.len() + usize::exact_from(twos), 1);
+        let mut divide_out = |q: &mut Natural, i: u32| {
+            let p = self.fb.primes[i as usize];
+            while mod_u32(q, p) == 0 {
+                q.div_exact_assign(Natural::from(p));
+                factors.push(i);
+            }
+        };
+        for &i in &family.a_factors {
+            divide_out(&mut q, u32::wrapping_from(i));
+        }
+        for i in 2..self.resieve_start {
+            let r_1 = family.roots_1[i];
+            if r_1 == u32::MAX {
+                continue;
+            }
+            let p = self.fb.primes[i] as usize;
+            let r = u32::wrapping_from(j % p);
+            if r == r_1 || r == family.roots_2[i] {
+                divide_out(&mut q, u32::wrapping_from(i));
+            }
+        }
+        for &i in large_divisors {
+            divide_out(&mut q, i);
+        }
+        for &i in &family.a_factors {
+            factors.push(u32::wrapping_from(i));
+        }
+        let y = y.unsigned_abs() % self.n;
+        if q == 1u32 {
+            self.relations.push(Relation {
+                y,
+                factors,
+                large_prime: 1,
+            });
+            return None;
+        }
+        let Ok(large_prime) = u64::try_from(&q) else {
+            return None;
+        };
+        if large_prime > self.large_prime_bound {
+            return None;
+        }
+        if mod_u32(self.n, u32::exact_from(large_prime)) == 0 {
+            return Some(q);
+        }
+        if let Some((other_y, other_factors)) = self.partials.get(&large_prime) {
+            let mut combined = other_factors.clone();
+            combined.extend_from_slice(&factors);
+            self.relations.push(Relation {
+                y: y * other_y % self.n,
+                factors: combined,
+                large_prime,
+            });
+        } else {
+            self.partials.insert(large_prime, (y, factors));
+        }
+        None
+    }
+
+    // Uses the relations found so far to look for a factor of $n$. Returns `None` if every
+    // dependency gives a trivial factor, or if the relations are not yet sufficient.
+    fn linear_algebra(&self) -> Option<Natural> {
+        let num_primes = self.fb.primes.len();
+        // Reduce every relation to the set of factor base elements with odd exponent.
+        let mut columns: Vec<Vec<u32>> = self
+            .relations
+            .iter()
+            .map(|r| {
+                let mut odd = r.factors.clone();
+                odd.sort_unstable();
+                let mut reduced = Vec::with_capacity(odd.len());
+                for x in odd {
+                    if reduced.last() == Some(&x) {
+                        reduced.pop();
+                    } else {
+                        reduced.push(x);
+                    }
+                }
+                reduced
+            })
+            .collect();
+        // Repeatedly remove relations containing a prime that no other relation contains.
+        let mut alive = alloc::vec![true; columns.len()];
+        loop {
+            let mut counts = alloc::vec![0u32; num_primes];
+            for (column, &alive) in columns.iter().zip(alive.iter()) {
+                if alive {
+                    for &r in column {
+                        counts[r as usize] += 1;
+                    }
+                }
+            }
+            let mut changed = false;
+            for (column, alive) in columns.iter().zip(alive.iter_mut()) {
+                if *alive && column.iter().any(|&r| counts[r as usize] == 1) {
+                    *alive = false;
+                    changed = true;
+                }
+            }
+            if !changed {
+                break;
+            }
+        }
+        let indices = (0..columns.len()).filter(|&i| alive[i]).collect::<Vec<_>>();
+        let mut row_index = alloc::vec![u32::MAX; num_primes];
+        let mut num_rows = 0;
+        for &i in &indices {
+            for r in &mut columns[i] {
+                let index = &mut row_index[*r as usize];
+                if *index == u32::MAX {
+                    *index = num_rows;
+                    num_rows += 1;
+                }
+                *r = *index;
+            }
+        }
+        let num_rows = num_rows as usize;
+        if indices.len() < num_rows + 32 {
+            return None;
+        }
+        let columns = indices
+            .iter()
+            .map(|&i| core::mem::take(&mut columns[i]))
+            .collect::<Vec<_>>();
+        let dependencies = find_dependencies(&columns, num_rows);
+        let n = self.n;
+        for d in 0..64 {
+            let mut x = Natural::ONE;
+            let mut z = Natural::ONE;
+            let mut exponents = alloc::vec![0u32; num_primes];
+            let mut empty = true;
+            for (&i, &dependency) in indices.iter().zip(dependencies.iter()) {
+                if dependency & (1 << d) == 0 {
+                    continue;
+                }
+                empty = false;
+                let relation = &self.relations[i];
+                x = x * &relation.y % n;
+                z = z * Natural::from(relation.large_prime) % n;
+                for &f in &relation.factors {
+                    exponents[f as usize] += 1;
+                }
+            }
+            if empty {
+                continue;
+            }
+            for (i, &e) in exponents.iter().enumerate().skip(1) {
+                if e != 0 {
+                    z = z * Natural::from(self.fb.primes[i]).mod_pow(Natural::from(e >> 1), n) % n;
+                }
+            }
+            let g = (x % n).mod_sub(z, n).gcd(n);
+            if g != 1u32 && g != *n {
+                return Some(g);
+            }
+        }
+        None
+    }
+}
+
+// Tries to find a nontrivial factor of `n` using the self-initializing quadratic sieve.
+//
+// `n` must be odd and composite, and may not be a perfect power. Returns `None` if the sieve fails,
+// which should be rare.
+pub_crate_test! {siqs(n: &Natural) -> Option<Natural> {
+    let k = choose_multiplier(n);
+    let kn = n * Natural::from(k);
+    let bits = kn.significant_bits();
+    let (fb_size, large_prime_multiplier, sieve_len) = parameters(bits);
+    let fb = match factor_base(n, &kn, k, fb_size) {
+        Ok(fb) => fb,
+        Err(f) => return Some(f),
+    };
+    let len = fb.primes.len();
+    let largest_prime = u64::from(fb.primes[len - 1]);
+    let large_prime_bound = largest_prime * large_prime_multiplier;
+    let m = u32::exact_from(sieve_len >> 1);
+    // The sieve values are at most about M sqrt(kn / 2). Candidates are kept if their sieve value
+    // is within a large prime and some rounding of that size.
+    let log_max_value = u64::from(m).significant_bits() + (bits >> 1);
+    let threshold = log_max_value
+        .saturating_sub(large_prime_bound.significant_bits() + THRESHOLD_FUDGE)
+        .clamp(1, u64::from(CANDIDATE_MARK - 1));
+    let sieve_start = fb.primes.partition_point(|&p| p < SMALL_PRIME_LIMIT).max(2);
+    let resieve_start = fb.primes.partition_point(|&p| p < RESIEVE_LIMIT).max(2);
+    let mut siqs = Siqs {
+        n,
+        kn,
+        fb,
+        m,
+        large_prime_bound,
+        threshold: u8::exact_from(threshold),
+        sieve_start,
+        resieve_start,
+        rng: XorShift::new(bits),
+        used_as: BTreeSet::new(),
+        relations: Vec::new(),
+        partials: BTreeMap::new(),
+        sieve: alloc::vec![0; sieve_len],
+    };
+    let mut target = len + 64;
+    let mut attempts = 0;
+    loop {
+        let mut family = siqs.new_family()?;
+        let s = family.bs.len();
+        for i in 0..1usize << (s - 1) {
+            if i != 0 {
+                siqs.switch_polynomial(&mut family, usize::exact_from(i.trailing_zeros()));
+            }
+            let candidates = siqs.sieve_polynomial(&family);
+            let divisors = siqs.resieve(&family, &candidates);
+            for (&j, divisors) in candidates.iter().zip(divisors.iter()) {
+                if let Some(f) = siqs.process_candidate(&family, j, divisors) {
+                    return Some(f);
+                }
+            }
+        }
+        if siqs.relations.len() >= target {
+            if let Some(f) = siqs.linear_algebra() {
+                return Some(f);
+            }
+            attempts += 1;
+            if attempts == 10 {
+                return None;
+            }
+            target = siqs.relations.len() + (len >> 4) + 64;
+        }
+    }
+}}
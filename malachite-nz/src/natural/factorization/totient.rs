@@ -0,0 +1,131 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{LcmAssign, Pow, PowerOf2};
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::factorization::traits::{CarmichaelLambda, Factor, Totient};
+
+// Returns $p^{e-1}(p-1)$, which is $\varphi(p^e)$.
+fn prime_power_totient(p: &Natural, e: u64) -> Natural {
+    assert_ne!(e, 0, "Exponents in a factorization must be positive");
+    p.pow(e - 1) * (p - Natural::ONE)
+}
+
+impl Totient for Natural {
+    /// Computes Euler's totient function $\varphi(n)$ of a [`Natural`]: the number of integers in
+    /// $[1, n]$ that are coprime to $n$.
+    ///
+    /// The [`Natural`] is factored first; if its factorization is already known, use
+    /// [`totient_from_factorization`](Totient::totient_from_factorization) instead.
+    ///
+    /// $$
+    /// f(n) = \varphi(n) = n\prod_{p | n} \left(1 - \frac{1}{p}\right).
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// See [`factor`](Factor::factor).
+    ///
+    /// # Panics
+    /// Panics if `self` is 0.
+    ///
+    /// # Examples
+    /// See [here](super::totient#totient).
+    #[inline]
+    fn totient(&self) -> Natural {
+        Natural::totient_from_factorization(&self.factor())
+    }
+
+    /// Computes Euler's totient function $\varphi(n)$ of a [`Natural`], given its prime
+    /// factorization as a list of distinct primes, each paired with its exponent.
+    ///
+    /// The factorization is not checked for validity.
+    ///
+    /// $$
+    /// f(((p_1, e_1), \ldots, (p_k, e_k))) = \prod_{i=1}^k p_i^{e_i-1}(p_i-1).
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the number of bits of the
+    /// represented number.
+    ///
+    /// # Panics
+    /// Panics if any exponent is 0.
+    ///
+    /// # Examples
+    /// See [here](super::totient#totient_from_factorization).
+    fn totient_from_factorization(factors: &[(Natural, u64)]) -> Natural {
+        factors
+            .iter()
+            .fold(Natural::ONE, |acc, (p, e)| acc * prime_power_totient(p, *e))
+    }
+}
+
+impl CarmichaelLambda for Natural {
+    /// Computes the Carmichael function $\lambda(n)$ of a [`Natural`]: the smallest positive $m$
+    /// such that $a^m \equiv 1 \mod n$ for every $a$ coprime to $n$.
+    ///
+    /// The [`Natural`] is factored first; if its factorization is already known, use
+    /// [`carmichael_lambda_from_factorization`](
+    /// CarmichaelLambda::carmichael_lambda_from_factorization) instead.
+    ///
+    /// $\lambda(n)$ always divides $\varphi(n)$.
+    ///
+    /// # Worst-case complexity
+    /// See [`factor`](Factor::factor).
+    ///
+    /// # Panics
+    /// Panics if `self` is 0.
+    ///
+    /// # Examples
+    /// See [here](super::totient#carmichael_lambda).
+    #[inline]
+    fn carmichael_lambda(&self) -> Natural {
+        Natural::carmichael_lambda_from_factorization(&self.factor())
+    }
+
+    /// Computes the Carmichael function $\lambda(n)$ of a [`Natural`], given its prime
+    /// factorization as a list of distinct primes, each paired with its exponent.
+    ///
+    /// The factorization is not checked for validity.
+    ///
+    /// $$
+    /// f(((p_1, e_1), \ldots, (p_k, e_k))) = \operatorname{lcm}(\lambda(p_1^{e_1}), \ldots,
+    /// \lambda(p_k^{e_k})),
+    /// $$
+    /// where $\lambda(p^e) = p^{e-1}(p-1)$, except that $\lambda(2^e) = 2^{e-2}$ when $e \geq 3$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the number of bits of the
+    /// represented number.
+    ///
+    /// # Panics
+    /// Panics if any exponent is 0.
+    ///
+    /// # Examples
+    /// See [here](super::totient#carmichael_lambda_from_factorization).
+    fn carmichael_lambda_from_factorization(factors: &[(Natural, u64)]) -> Natural {
+        let mut lambda = Natural::ONE;
+        for (p, e) in factors {
+            lambda.lcm_assign(if *p == 2u32 && *e >= 3 {
+                Natural::power_of_2(e - 2)
+            } else {
+                prime_power_totient(p, *e)
+            });
+        }
+        lambda
+    }
+}
@@ -139,7 +139,7 @@ pub mod comparison;
 pub mod conversion;
 /// Iterators that generate [`Natural`]s without repetition.
 pub mod exhaustive;
-/// Traits for generating primes, primality testing, and factorization (TODO!)
+/// Traits for generating primes, primality testing, and factorization.
 pub mod factorization;
 /// Traits for logic and bit manipulation.
 pub mod logic;
@@ -0,0 +1,31 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::integer::Integer;
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::BinomialCoefficient;
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+
+// Uses the recurrence $\sum_{k=0}^n \binom{n}{k} E_k = 0$ for even  > 0$.
+pub fn euler_number_naive(n: u64) -> Integer {
+    let mut es = vec![Integer::ONE];
+    for m in 1..=n {
+        let mut e = Integer::ZERO;
+        if m & 1 == 0 {
+            for k in (0..m).step_by(2) {
+                e -= Integer::from(Natural::binomial_coefficient(
+                    Natural::from(m),
+                    Natural::from(k),
+                )) * &es[usize::exact_from(k)];
+            }
+        }
+        es.push(e);
+    }
+    es.pop().unwrap()
+}
@@ -8,5 +8,6 @@
 
 pub mod add;
 pub mod divisible_by;
+pub mod euler_number;
 pub mod mul;
 pub mod sign;
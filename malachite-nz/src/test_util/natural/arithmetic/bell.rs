@@ -0,0 +1,25 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::natural::Natural;
+use malachite_base::num::basic::traits::One;
+
+// Uses the Bell triangle: each row starts with the last entry of the previous row, and each
+// subsequent entry is the sum of the entry to its left and the entry above that one.
+pub fn bell_naive(n: u64) -> Natural {
+    let mut row = vec![Natural::ONE];
+    for _ in 0..n {
+        let mut next_row = vec![row.last().unwrap().clone()];
+        for x in &row {
+            let next = next_row.last().unwrap() + x;
+            next_row.push(next);
+        }
+        row = next_row;
+    }
+    row.swap_remove(0)
+}
@@ -0,0 +1,29 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::natural::Natural;
+use malachite_base::num::arithmetic::traits::{CheckedSqrt, Gcd};
+use malachite_base::num::basic::traits::{One, Zero};
+
+// Tries each $y$ in increasing order, and checks whether $m - dy^2$ is a perfect square $x^2$ with
+// $\gcd(x, y) = 1$.
+pub fn cornacchia_naive(d: &Natural, m: &Natural) -> Option<(Natural, Natural)> {
+    let mut y = Natural::ZERO;
+    loop {
+        let dy_squared = d * &y * &y;
+        if dy_squared > *m {
+            return None;
+        }
+        if let Some(x) = (m - dy_squared).checked_sqrt() {
+            if (&x).gcd(&y) == 1u32 {
+                return Some((x, y));
+            }
+        }
+        y += Natural::ONE;
+    }
+}
@@ -7,8 +7,10 @@
 // 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
 
 pub mod add;
+pub mod bell;
 pub mod binomial_coefficient;
 pub mod checked_sub;
+pub mod cornacchia;
 pub mod div;
 pub mod div_exact;
 pub mod div_mod;
@@ -28,9 +30,11 @@ pub mod mod_power_of_2_pow;
 pub mod mod_power_of_2_square;
 pub mod mul;
 pub mod neg;
+pub mod partitions;
 pub mod pow;
 pub mod primorial;
 pub mod root;
 pub mod sqrt;
 pub mod square;
+pub mod stirling;
 pub mod sub;
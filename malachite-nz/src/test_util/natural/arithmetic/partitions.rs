@@ -0,0 +1,25 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::natural::Natural;
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+
+// Counts the partitions of `n` by adding the allowed parts one at a time.
+pub fn partitions_naive(n: u64) -> Natural {
+    let n = usize::exact_from(n);
+    let mut counts = vec![Natural::ZERO; n + 1];
+    counts[0] = Natural::ONE;
+    for part in 1..=n {
+        for i in part..=n {
+            let previous = counts[i - part].clone();
+            counts[i] += previous;
+        }
+    }
+    counts.swap_remove(n)
+}
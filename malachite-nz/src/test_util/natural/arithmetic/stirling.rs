@@ -0,0 +1,37 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::natural::Natural;
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+
+fn stirling_naive_helper(n: u64, k: u64, first_kind: bool) -> Natural {
+    if k > n {
+        return Natural::ZERO;
+    }
+    let k = usize::exact_from(k);
+    let mut row = vec![Natural::ONE];
+    for m in 0..n {
+        let mut next_row = vec![Natural::ZERO; row.len() + 1];
+        for (j, x) in row.iter().enumerate() {
+            next_row[j + 1] += x;
+            let factor = if first_kind { m } else { u64::exact_from(j) };
+            next_row[j] += x * Natural::from(factor);
+        }
+        row = next_row;
+    }
+    row.swap_remove(k)
+}
+
+pub fn stirling1_naive(n: u64, k: u64) -> Natural {
+    stirling_naive_helper(n, k, true)
+}
+
+pub fn stirling2_naive(n: u64, k: u64) -> Natural {
+    stirling_naive_helper(n, k, false)
+}
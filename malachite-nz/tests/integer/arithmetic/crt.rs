@@ -0,0 +1,75 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{Lcm, Mod};
+use malachite_base::num::basic::traits::{NegativeOne, One, Zero};
+use malachite_base::strings::ToDebugString;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::{integer_natural_natural_triple_gen, natural_triple_gen};
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_crt() {
+    let test = |congruences: &[(&str, &str)], out| {
+        let congruences = congruences
+            .iter()
+            .map(|&(a, m)| (Integer::from_str(a).unwrap(), Natural::from_str(m).unwrap()))
+            .collect::<Vec<_>>();
+        let result = Integer::crt(&congruences);
+        assert_eq!(result.to_debug_string(), out);
+        if let Some((x, m)) = result {
+            assert!(x.is_valid());
+            assert!(m.is_valid());
+        }
+    };
+    test(&[], "Some((0, 1))");
+    test(&[("-5", "1")], "Some((0, 1))");
+    test(&[("-5", "7")], "Some((2, 7))");
+    test(&[("-1", "3"), ("-1", "5")], "Some((14, 15))");
+    test(&[("-2", "4"), ("4", "6")], "Some((10, 12))");
+    test(&[("-1", "4"), ("2", "6")], "None");
+    test(
+        &[("-12345678987654321", "1000000007"), ("-1", "18446744073709551616")],
+        "Some((17890940317461261410671525887, 18446744202836760131966861312))",
+    );
+}
+
+#[test]
+fn crt_fail() {
+    assert_panic!(Integer::crt(&[(Integer::NEGATIVE_ONE, Natural::ZERO)]));
+}
+
+#[test]
+fn crt_properties() {
+    integer_natural_natural_triple_gen().test_properties(|(x, a, b)| {
+        let m = a + Natural::ONE;
+        let n = b + Natural::ONE;
+        let lcm = (&m).lcm(&n);
+        let result = Integer::crt(&[(x.clone(), m.clone()), (x.clone(), n)]);
+        assert_eq!(result, Some(((&x).mod_op(Integer::from(&lcm)), lcm)));
+        if let Some((y, lcm)) = result {
+            assert!(y.is_valid());
+            assert!(lcm.is_valid());
+        }
+        assert_eq!(
+            Integer::crt(&[(-x.clone(), m.clone())]),
+            Some(((-x).mod_op(Integer::from(&m)), m))
+        );
+    });
+
+    natural_triple_gen().test_properties(|(x, a, b)| {
+        let m = a + Natural::ONE;
+        let n = b + Natural::ONE;
+        assert_eq!(
+            Integer::crt(&[(Integer::from(&x), m.clone()), (Integer::from(&x), n.clone())]),
+            Natural::crt(&[(x.clone(), m), (x, n)]).map(|(x, m)| (Integer::from(x), m))
+        );
+    });
+}
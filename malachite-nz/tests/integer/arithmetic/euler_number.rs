@@ -0,0 +1,61 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{EulerNumber, Parity, Sign};
+use malachite_base::test_util::generators::unsigned_gen_var_5;
+use malachite_nz::integer::Integer;
+use malachite_nz::test_util::integer::arithmetic::euler_number::euler_number_naive;
+use std::cmp::Ordering::*;
+
+#[test]
+fn test_euler_number() {
+    fn test(n: u64, out: &str) {
+        let e = Integer::euler_number(n);
+        assert!(e.is_valid());
+        assert_eq!(e.to_string(), out);
+        assert_eq!(euler_number_naive(n).to_string(), out);
+    }
+    test(0, "1");
+    test(1, "0");
+    test(2, "-1");
+    test(4, "5");
+    test(6, "-61");
+    test(10, "-50521");
+    test(30, "-441543893249023104553682821");
+    test(31, "0");
+    test(32, "177519391579539289436664789665");
+    test(34, "-80723299235887898062168247453281");
+    test(
+        50,
+        "-6053285248188621896314383785111649088103498225146815121",
+    );
+    test(
+        100,
+        "2903528346661097497054603834764435875077553006646158945080492319146997643370625023889353\
+        447129967354174648294748510553528692457632980625125",
+    );
+}
+
+#[test]
+fn euler_number_properties() {
+    unsigned_gen_var_5().test_properties_with_limit(100, |n| {
+        let e = Integer::euler_number(n);
+        assert!(e.is_valid());
+        assert_eq!(euler_number_naive(n), e);
+        assert_eq!(
+            e.sign(),
+            if n.odd() {
+                Equal
+            } else if n % 4 == 0 {
+                Greater
+            } else {
+                Less
+            }
+        );
+    });
+}
@@ -0,0 +1,95 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{ExpressAsPower, IsPerfectPower, Parity, Pow};
+use malachite_base::strings::ToDebugString;
+use malachite_base::test_util::generators::signed_gen;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::platform::SignedLimb;
+use malachite_nz::test_util::generators::{integer_gen, natural_gen};
+use std::str::FromStr;
+
+#[test]
+fn test_express_as_power() {
+    let test = |s, out| {
+        let x = Integer::from_str(s).unwrap();
+        let result = x.express_as_power();
+        assert_eq!(result.to_debug_string(), out);
+        assert_eq!(x.is_perfect_power(), result.is_some());
+    };
+    test("0", "Some((0, 2))");
+    test("1", "Some((1, 2))");
+    test("-1", "Some((-1, 3))");
+    test("-2", "None");
+    test("4", "Some((2, 2))");
+    test("-4", "None");
+    test("-8", "Some((-2, 3))");
+    test("64", "Some((2, 6))");
+    test("-64", "Some((-4, 3))");
+    test("-1000", "Some((-10, 3))");
+    test("18446744073709551616", "Some((2, 64))");
+    test("-18446744073709551616", "None");
+    test("-340282366920938463463374607431768211456", "None");
+    test("-1000000000000000000000000000000", "Some((-100, 15))");
+    test("-1000000000000000000000000000001", "None");
+    test("-1853020188851841", "None");
+    test(
+        "-515377520732011331036461129765621272702107522001",
+        "Some((-81, 25))",
+    );
+
+    assert_eq!(
+        Integer::from(-6)
+            .pow(63)
+            .express_as_power()
+            .to_debug_string(),
+        "Some((-6, 63))"
+    );
+    assert_eq!(
+        Integer::from(-6)
+            .pow(64)
+            .express_as_power()
+            .to_debug_string(),
+        "Some((6, 64))"
+    );
+}
+
+#[test]
+fn express_as_power_properties() {
+    integer_gen().test_properties(|x| {
+        let result = x.express_as_power();
+        assert_eq!(x.is_perfect_power(), result.is_some());
+        if let Some((base, k)) = result {
+            assert!(k >= 2);
+            assert_eq!((&base).pow(k), x);
+            if x < 0u32 {
+                assert!(k.odd());
+            }
+        }
+        if x > 1u32 || x < -1i32 {
+            let (_, k) = (&x).pow(3).express_as_power().unwrap();
+            assert_eq!(k % 3, 0);
+        }
+    });
+
+    natural_gen().test_properties(|x| {
+        assert_eq!(
+            Integer::from(&x).express_as_power(),
+            x.express_as_power().map(|(b, k)| (Integer::from(b), k))
+        );
+    });
+
+    signed_gen::<SignedLimb>().test_properties(|x| {
+        assert_eq!(
+            Integer::from(x).express_as_power(),
+            x.express_as_power().map(|(b, k)| (Integer::from(b), k))
+        );
+        assert_eq!(Integer::from(x).is_perfect_power(), x.is_perfect_power());
+    });
+}
@@ -0,0 +1,53 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{IsSquare, Square};
+use malachite_base::test_util::generators::signed_gen;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::platform::SignedLimb;
+use malachite_nz::test_util::generators::{integer_gen, natural_gen};
+use std::str::FromStr;
+
+#[test]
+fn test_is_square() {
+    let test = |s, out| {
+        assert_eq!(Integer::from_str(s).unwrap().is_square(), out);
+    };
+    test("0", true);
+    test("1", true);
+    test("-1", false);
+    test("4", true);
+    test("-4", false);
+    test("99", false);
+    test("100", true);
+    test("-100", false);
+    test("1000000000000000000000000000000", true);
+    test("-1000000000000000000000000000000", false);
+    test("1000000000000000000000000000001", false);
+}
+
+#[test]
+fn is_square_properties() {
+    integer_gen().test_properties(|x| {
+        let is_square = x.is_square();
+        assert_eq!(is_square, x >= 0u32 && x.unsigned_abs_ref().is_square());
+        assert!((&x).square().is_square());
+        if x != 0u32 {
+            assert!(!(-(&x).square()).is_square());
+        }
+    });
+
+    natural_gen().test_properties(|x| {
+        assert_eq!(Integer::from(&x).is_square(), x.is_square());
+    });
+
+    signed_gen::<SignedLimb>().test_properties(|x| {
+        assert_eq!(Integer::from(x).is_square(), x.is_square());
+    });
+}
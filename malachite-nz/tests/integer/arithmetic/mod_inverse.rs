@@ -0,0 +1,93 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{Gcd, Mod, ModInverse, ModMul};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::strings::ToDebugString;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::integer_natural_pair_gen;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_mod_inverse() {
+    let test = |s, t, out| {
+        let x = Integer::from_str(s).unwrap();
+        let m = Natural::from_str(t).unwrap();
+
+        let result = x.clone().mod_inverse(m.clone());
+        assert_eq!(result.to_debug_string(), out);
+        assert!(result.map_or(true, |r| r.is_valid()));
+
+        let result = x.clone().mod_inverse(&m);
+        assert_eq!(result.to_debug_string(), out);
+        assert!(result.map_or(true, |r| r.is_valid()));
+
+        let result = (&x).mod_inverse(m.clone());
+        assert_eq!(result.to_debug_string(), out);
+        assert!(result.map_or(true, |r| r.is_valid()));
+
+        let result = (&x).mod_inverse(&m);
+        assert_eq!(result.to_debug_string(), out);
+        assert!(result.map_or(true, |r| r.is_valid()));
+    };
+    test("0", "1", "Some(0)");
+    test("-5", "1", "Some(0)");
+    test("0", "5", "None");
+    test("1", "5", "Some(1)");
+    test("-1", "5", "Some(4)");
+    test("3", "10", "Some(7)");
+    test("-3", "10", "Some(3)");
+    test("4", "10", "None");
+    test("-4", "10", "None");
+    test("13", "10", "Some(7)");
+    test("6", "9", "None");
+    test("-1000000000000", "1000000007", "Some(408142860)");
+    test(
+        "-123456789012345678901234567890",
+        "340282366920938463463374607431768211297",
+        "Some(95204314310575073595854744826687191522)",
+    );
+}
+
+#[test]
+fn mod_inverse_fail() {
+    assert_panic!(Integer::from(-3).mod_inverse(Natural::ZERO));
+    assert_panic!(Integer::from(-3).mod_inverse(&Natural::ZERO));
+    assert_panic!((&Integer::from(-3)).mod_inverse(Natural::ZERO));
+    assert_panic!((&Integer::from(-3)).mod_inverse(&Natural::ZERO));
+}
+
+#[test]
+fn mod_inverse_properties() {
+    integer_natural_pair_gen().test_properties(|(x, m)| {
+        if m == 0u32 {
+            return;
+        }
+        let result_val_val = x.clone().mod_inverse(m.clone());
+        let result_val_ref = x.clone().mod_inverse(&m);
+        let result_ref_val = (&x).mod_inverse(m.clone());
+        let result = (&x).mod_inverse(&m);
+        assert!(result.as_ref().map_or(true, Natural::is_valid));
+        assert_eq!(result_val_val, result);
+        assert_eq!(result_val_ref, result);
+        assert_eq!(result_ref_val, result);
+
+        let reduced = Natural::exact_from((&x).mod_op(Integer::from(&m)));
+        assert_eq!((&x + Integer::from(&m)).mod_inverse(&m), result);
+        if let Some(inverse) = result {
+            assert!(inverse < m);
+            assert_eq!((&reduced).mod_mul(&inverse, &m), Natural::ONE % &m);
+            assert_eq!(Integer::from(inverse).mod_inverse(&m), Some(reduced));
+        } else {
+            assert_ne!(x.unsigned_abs_ref().gcd(&m), 1u32);
+        }
+    });
+}
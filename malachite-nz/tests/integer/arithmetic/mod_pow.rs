@@ -0,0 +1,138 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{Mod, ModInverse, ModMul, ModPow};
+use malachite_base::num::basic::traits::{NegativeOne, One, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::integer_integer_natural_triple_gen;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_mod_pow() {
+    let test = |r, s, t, out| {
+        let x = Integer::from_str(r).unwrap();
+        let exp = Integer::from_str(s).unwrap();
+        let m = Natural::from_str(t).unwrap();
+
+        let result = x.clone().mod_pow(exp.clone(), m.clone());
+        assert!(result.is_valid());
+        assert_eq!(result.to_string(), out);
+
+        let result = x.clone().mod_pow(exp.clone(), &m);
+        assert!(result.is_valid());
+        assert_eq!(result.to_string(), out);
+
+        let result = x.clone().mod_pow(&exp, m.clone());
+        assert!(result.is_valid());
+        assert_eq!(result.to_string(), out);
+
+        let result = x.clone().mod_pow(&exp, &m);
+        assert!(result.is_valid());
+        assert_eq!(result.to_string(), out);
+
+        let result = (&x).mod_pow(exp.clone(), m.clone());
+        assert!(result.is_valid());
+        assert_eq!(result.to_string(), out);
+
+        let result = (&x).mod_pow(exp.clone(), &m);
+        assert!(result.is_valid());
+        assert_eq!(result.to_string(), out);
+
+        let result = (&x).mod_pow(&exp, m.clone());
+        assert!(result.is_valid());
+        assert_eq!(result.to_string(), out);
+
+        let result = (&x).mod_pow(&exp, &m);
+        assert!(result.is_valid());
+        assert_eq!(result.to_string(), out);
+    };
+    test("0", "0", "1", "0");
+    test("5", "-3", "1", "0");
+    test("0", "0", "10", "1");
+    test("0", "5", "10", "0");
+    test("-1", "3", "10", "9");
+    test("-1", "-3", "10", "9");
+    test("2", "-1", "7", "4");
+    test("-2", "-1", "7", "3");
+    test("-4", "13", "497", "52");
+    test("10", "1000", "30", "10");
+    test("-10", "1001", "30", "20");
+    test("3", "-100", "1000000007", "35174754");
+    test(
+        "-123456789",
+        "-987654321",
+        "1000000000000000000000007",
+        "693642181807512189208158",
+    );
+}
+
+#[test]
+fn mod_pow_fail() {
+    assert_panic!(Integer::from(3).mod_pow(Integer::from(2), Natural::ZERO));
+    assert_panic!(Integer::from(2).mod_pow(Integer::from(-1), Natural::from(4u32)));
+    assert_panic!(Integer::from(2).mod_pow(&Integer::from(-1), &Natural::from(4u32)));
+    assert_panic!((&Integer::from(0)).mod_pow(Integer::from(-1), Natural::from(5u32)));
+    assert_panic!((&Integer::from(0)).mod_pow(&Integer::from(-1), &Natural::from(5u32)));
+}
+
+#[test]
+fn mod_pow_properties() {
+    integer_integer_natural_triple_gen().test_properties(|(x, exp, m)| {
+        if m == 0u32 {
+            return;
+        }
+        let inverse = (&x).mod_inverse(&m);
+        if exp < 0u32 && inverse.is_none() {
+            return;
+        }
+        let result_val_val_val = x.clone().mod_pow(exp.clone(), m.clone());
+        let result_val_val_ref = x.clone().mod_pow(exp.clone(), &m);
+        let result_val_ref_val = x.clone().mod_pow(&exp, m.clone());
+        let result_val_ref_ref = x.clone().mod_pow(&exp, &m);
+        let result_ref_val_val = (&x).mod_pow(exp.clone(), m.clone());
+        let result_ref_val_ref = (&x).mod_pow(exp.clone(), &m);
+        let result_ref_ref_val = (&x).mod_pow(&exp, m.clone());
+        let result = (&x).mod_pow(&exp, &m);
+        assert!(result.is_valid());
+        assert_eq!(result_val_val_val, result);
+        assert_eq!(result_val_val_ref, result);
+        assert_eq!(result_val_ref_val, result);
+        assert_eq!(result_val_ref_ref, result);
+        assert_eq!(result_ref_val_val, result);
+        assert_eq!(result_ref_val_ref, result);
+        assert_eq!(result_ref_ref_val, result);
+        assert!(result < m);
+
+        let reduced = Natural::exact_from((&x).mod_op(Integer::from(&m)));
+        if exp >= 0u32 {
+            assert_eq!((&reduced).mod_pow(exp.unsigned_abs_ref(), &m), result);
+        } else {
+            assert_eq!(inverse.unwrap().mod_pow(exp.unsigned_abs_ref(), &m), result);
+            assert_eq!(
+                (&x).mod_pow(-&exp, &m).mod_mul(&result, &m),
+                Natural::ONE % &m
+            );
+        }
+        assert_eq!((&x + Integer::from(&m)).mod_pow(&exp, &m), result);
+    });
+
+    integer_integer_natural_triple_gen().test_properties(|(x, _, m)| {
+        if m == 0u32 {
+            return;
+        }
+        let reduced = Natural::exact_from((&x).mod_op(Integer::from(&m)));
+        assert_eq!((&x).mod_pow(Integer::ZERO, &m), Natural::ONE % &m);
+        assert_eq!((&x).mod_pow(Integer::ONE, &m), reduced);
+        if let Some(inverse) = (&x).mod_inverse(&m) {
+            assert_eq!(x.mod_pow(Integer::NEGATIVE_ONE, &m), inverse);
+        }
+    });
+}
@@ -0,0 +1,133 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{
+    Mod, ModPrimePowerSqrt, ModSqrt, Parity, Pow, Square,
+};
+use malachite_base::num::basic::traits::{Two, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::factorization::traits::NextPrime;
+use malachite_base::strings::ToDebugString;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::integer_natural_pair_gen;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_mod_sqrt() {
+    let test = |s, t, out| {
+        let x = Integer::from_str(s).unwrap();
+        let p = Natural::from_str(t).unwrap();
+
+        let result = x.clone().mod_sqrt(p.clone());
+        assert_eq!(result.to_debug_string(), out);
+        assert!(result.map_or(true, |r| r.is_valid()));
+
+        let result = x.clone().mod_sqrt(&p);
+        assert_eq!(result.to_debug_string(), out);
+        assert!(result.map_or(true, |r| r.is_valid()));
+
+        let result = (&x).mod_sqrt(p.clone());
+        assert_eq!(result.to_debug_string(), out);
+        assert!(result.map_or(true, |r| r.is_valid()));
+
+        let result = (&x).mod_sqrt(&p);
+        assert_eq!(result.to_debug_string(), out);
+        assert!(result.map_or(true, |r| r.is_valid()));
+    };
+    test("0", "7", "Some(0)");
+    test("2", "7", "Some(3)");
+    test("-5", "7", "Some(3)");
+    test("-3", "13", "Some(6)");
+    test("-2", "13", "None");
+    test("100", "13", "Some(3)");
+    test("-1", "5", "Some(2)");
+    test("-1", "7", "None");
+    test("-1000000000000000000000", "1000000007", "Some(210147882)");
+}
+
+#[test]
+fn mod_sqrt_fail() {
+    assert_panic!(Integer::from(-3).mod_sqrt(Natural::ZERO));
+    assert_panic!(Integer::from(-3).mod_sqrt(&Natural::ZERO));
+    assert_panic!((&Integer::from(-3)).mod_sqrt(Natural::ZERO));
+    assert_panic!((&Integer::from(-3)).mod_sqrt(&Natural::ZERO));
+}
+
+#[test]
+fn test_mod_prime_power_sqrt() {
+    let test = |s, t, pow, out| {
+        let x = Integer::from_str(s).unwrap();
+        let p = Natural::from_str(t).unwrap();
+
+        let result = x.clone().mod_prime_power_sqrt(p.clone(), pow);
+        assert_eq!(result.to_debug_string(), out);
+        assert!(result.map_or(true, |r| r.is_valid()));
+
+        let result = x.clone().mod_prime_power_sqrt(&p, pow);
+        assert_eq!(result.to_debug_string(), out);
+        assert!(result.map_or(true, |r| r.is_valid()));
+
+        let result = (&x).mod_prime_power_sqrt(p.clone(), pow);
+        assert_eq!(result.to_debug_string(), out);
+        assert!(result.map_or(true, |r| r.is_valid()));
+
+        let result = (&x).mod_prime_power_sqrt(&p, pow);
+        assert_eq!(result.to_debug_string(), out);
+        assert!(result.map_or(true, |r| r.is_valid()));
+    };
+    test("-7", "2", 10, "Some(181)");
+    test("-2", "5", 3, "None");
+    test("-2", "3", 5, "Some(22)");
+    test("9", "2", 3, "Some(1)");
+    test("-1", "2", 1, "Some(1)");
+    test("-1", "2", 2, "None");
+}
+
+#[test]
+fn mod_prime_power_sqrt_fail() {
+    assert_panic!(Integer::from(-3).mod_prime_power_sqrt(Natural::ZERO, 2));
+    assert_panic!(Integer::from(-3).mod_prime_power_sqrt(&Natural::ZERO, 2));
+    assert_panic!((&Integer::from(-3)).mod_prime_power_sqrt(Natural::ZERO, 2));
+    assert_panic!((&Integer::from(-3)).mod_prime_power_sqrt(&Natural::ZERO, 2));
+}
+
+#[test]
+fn mod_sqrt_properties() {
+    integer_natural_pair_gen().test_properties(|(x, m)| {
+        let p = m.next_prime();
+        let result_val_val = x.clone().mod_sqrt(p.clone());
+        let result_val_ref = x.clone().mod_sqrt(&p);
+        let result_ref_val = (&x).mod_sqrt(p.clone());
+        let result = (&x).mod_sqrt(&p);
+        assert!(result.as_ref().map_or(true, Natural::is_valid));
+        assert_eq!(result_val_val, result);
+        assert_eq!(result_val_ref, result);
+        assert_eq!(result_ref_val, result);
+
+        let reduced = Natural::exact_from((&x).mod_op(Integer::from(&p)));
+        assert_eq!(reduced.mod_sqrt(&p), result);
+        assert_eq!((&x + Integer::from(&p)).mod_sqrt(&p), result);
+        assert!((&x).square().mod_sqrt(&p).is_some());
+    });
+
+    integer_natural_pair_gen().test_properties(|(x, m)| {
+        let p = (m % Natural::from(32u32)).next_prime();
+        let pk = (&p).pow(3);
+        let result = (&x).mod_prime_power_sqrt(&p, 3);
+        let reduced = Natural::exact_from((&x).mod_op(Integer::from(&pk)));
+        assert_eq!(reduced.mod_prime_power_sqrt(&p, 3), result);
+        assert!((&x).square().mod_prime_power_sqrt(&p, 3).is_some());
+        let odd = x.odd();
+        assert_eq!(
+            x.mod_prime_power_sqrt(Natural::TWO, 1),
+            Some(Natural::from(odd))
+        );
+    });
+}
@@ -71,6 +71,7 @@ pub mod integer {
         pub mod add;
         pub mod add_mul;
         pub mod binomial_coefficient;
+        pub mod crt;
         pub mod div;
         pub mod div_exact;
         pub mod div_mod;
@@ -79,10 +80,16 @@ pub mod integer {
         pub mod divisible_by_power_of_2;
         pub mod eq_mod;
         pub mod eq_mod_power_of_2;
+        pub mod euler_number;
         pub mod extended_gcd;
+        pub mod is_perfect_power;
+        pub mod is_square;
         pub mod kronecker_symbol;
+        pub mod mod_inverse;
         pub mod mod_op;
+        pub mod mod_pow;
         pub mod mod_power_of_2;
+        pub mod mod_sqrt;
         pub mod mul;
         pub mod neg;
         pub mod parity;
@@ -223,10 +230,15 @@ pub mod natural {
         pub mod abs_diff;
         pub mod add;
         pub mod add_mul;
+        pub mod bell;
         pub mod binomial_coefficient;
+        pub mod binomial_coefficient_mod;
+        pub mod catalan;
         pub mod checked_sub;
         pub mod checked_sub_mul;
         pub mod coprime_with;
+        pub mod cornacchia;
+        pub mod crt;
         pub mod div;
         pub mod div_exact;
         pub mod div_mod;
@@ -237,20 +249,26 @@ pub mod natural {
         pub mod eq_mod_power_of_2;
         pub mod extended_gcd;
         pub mod factorial;
+        pub mod fibonacci;
         pub mod gcd;
+        pub mod is_perfect_power;
         pub mod is_power_of_2;
+        pub mod is_square;
         pub mod kronecker_symbol;
         pub mod lcm;
         pub mod log_base;
         pub mod log_base_2;
         pub mod log_base_power_of_2;
         pub mod mod_add;
+        pub mod mod_discrete_log;
         pub mod mod_inverse;
         pub mod mod_is_reduced;
+        pub mod mod_lucas_sequence;
         pub mod mod_mul;
         pub mod mod_neg;
         pub mod mod_op;
         pub mod mod_pow;
+        pub mod mod_pow_table;
         pub mod mod_power_of_2;
         pub mod mod_power_of_2_add;
         pub mod mod_power_of_2_inverse;
@@ -264,12 +282,16 @@ pub mod natural {
         pub mod mod_power_of_2_sub;
         pub mod mod_shl;
         pub mod mod_shr;
+        pub mod mod_sqrt;
         pub mod mod_square;
         pub mod mod_sub;
+        pub mod montgomery;
         pub mod mul;
+        pub mod multinomial;
         pub mod neg;
         pub mod next_power_of_2;
         pub mod parity;
+        pub mod partitions;
         pub mod pow;
         pub mod power_of_2;
         pub mod primorial;
@@ -285,8 +307,10 @@ pub mod natural {
         pub mod sign;
         pub mod sqrt;
         pub mod square;
+        pub mod stirling;
         pub mod sub;
         pub mod sub_mul;
+        pub mod sums_of_squares;
     }
     pub mod basic {
         pub mod constants;
@@ -345,7 +369,14 @@ pub mod natural {
         pub mod exhaustive_positive_naturals;
     }
     pub mod factorization {
+        pub mod divisors;
+        pub mod factor;
+        pub mod is_prime;
+        pub mod mobius;
+        pub mod next_prime;
+        pub mod prime_pi;
         pub mod primes;
+        pub mod totient;
     }
     pub mod logic {
         pub mod and;
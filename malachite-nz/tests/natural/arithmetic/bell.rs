@@ -0,0 +1,53 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{Bell, BinomialCoefficient, Stirling};
+use malachite_base::num::basic::traits::Zero;
+use malachite_base::test_util::generators::unsigned_gen_var_5;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::natural::arithmetic::bell::bell_naive;
+
+#[test]
+fn test_bell() {
+    fn test(n: u64, out: &str) {
+        let b = Natural::bell(n);
+        assert!(b.is_valid());
+        assert_eq!(b.to_string(), out);
+        assert_eq!(bell_naive(n).to_string(), out);
+    }
+    test(0, "1");
+    test(1, "1");
+    test(2, "2");
+    test(3, "5");
+    test(4, "15");
+    test(5, "52");
+    test(10, "115975");
+    test(25, "4638590332229999353");
+    test(26, "49631246523618756274");
+    test(50, "185724268771078270438257767181908917499221852770");
+}
+
+#[test]
+fn bell_properties() {
+    unsigned_gen_var_5().test_properties_with_limit(100, |n| {
+        let b = Natural::bell(n);
+        assert!(b.is_valid());
+        assert_eq!(bell_naive(n), b);
+        assert_eq!(
+            (0..=n).fold(Natural::ZERO, |sum, k| sum + Natural::stirling2(n, k)),
+            b
+        );
+        // $B_{n+1} = \sum_{k=0}^n \binom{n}{k} B_k$
+        assert_eq!(
+            (0..=n).fold(Natural::ZERO, |sum, k| sum
+                + Natural::binomial_coefficient(Natural::from(n), Natural::from(k))
+                    * Natural::bell(k)),
+            Natural::bell(n + 1)
+        );
+    });
+}
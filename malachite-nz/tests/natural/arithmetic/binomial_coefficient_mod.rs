@@ -0,0 +1,182 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{BinomialCoefficient, Factorial, ModAdd};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::test_util::generators::{
+    unsigned_pair_gen_var_28, unsigned_triple_gen_var_19, unsigned_triple_gen_var_4,
+    unsigned_vec_gen,
+};
+use malachite_nz::natural::Natural;
+use std::str::FromStr;
+
+#[test]
+fn test_binomial_coefficient_mod() {
+    let test = |n: &str, k: &str, m: &str, out: &str| {
+        let n = Natural::from_str(n).unwrap();
+        let k = Natural::from_str(k).unwrap();
+        let m = Natural::from_str(m).unwrap();
+        let result = Natural::binomial_coefficient_mod(&n, &k, &m);
+        assert!(result.is_valid());
+        assert_eq!(result.to_string(), out);
+    };
+    test("0", "0", "1", "0");
+    test("0", "0", "7", "1");
+    test("5", "2", "7", "3");
+    test("10", "3", "7", "1");
+    test("3", "10", "7", "0");
+    test("100", "50", "1000000007", "538992043");
+    test("100", "50", "18446744073709551616", "1184508333840160104");
+    test("1000", "500", "12192768", "876096");
+    test("12345", "678", "2187", "2180");
+    test("100000000000000000000", "12345", "999999", "270270");
+    test("18446744073709551616", "5", "2305843009213693951", "56");
+    test(
+        "18446744073709551616",
+        "4294967296",
+        "18446744073709551557",
+        "0",
+    );
+    test("3000000", "1234567", "5015339982927", "4005782643834");
+}
+
+#[test]
+#[should_panic]
+fn binomial_coefficient_mod_fail() {
+    Natural::binomial_coefficient_mod(&Natural::from(10u32), &Natural::from(5u32), &Natural::ZERO);
+}
+
+#[test]
+fn test_multinomial_mod() {
+    let test = |ks: &[u32], m: &str, out: &str| {
+        let ks: Vec<Natural> = ks.iter().map(|&k| Natural::from(k)).collect();
+        let m = Natural::from_str(m).unwrap();
+        let result = Natural::multinomial_mod(&ks, &m);
+        assert!(result.is_valid());
+        assert_eq!(result.to_string(), out);
+    };
+    test(&[], "1", "0");
+    test(&[], "10", "1");
+    test(&[5], "10", "1");
+    test(&[3, 4, 5], "1000", "720");
+    test(&[3, 4, 5], "1000000007", "27720");
+    test(
+        &[100, 200, 300, 0, 7],
+        "1000000000000000000000000000000",
+        "313648080353071625890699869120",
+    );
+}
+
+#[test]
+#[should_panic]
+fn multinomial_mod_fail() {
+    Natural::multinomial_mod(&[Natural::ONE], &Natural::ZERO);
+}
+
+#[test]
+fn test_factorial_mod() {
+    let test = |n: u64, m: &str, out: &str| {
+        let m = Natural::from_str(m).unwrap();
+        let result = Natural::factorial_mod(n, &m);
+        assert!(result.is_valid());
+        assert_eq!(result.to_string(), out);
+    };
+    test(0, "1", "0");
+    test(0, "10", "1");
+    test(5, "7", "1");
+    test(6, "7", "6");
+    test(7, "7", "0");
+    test(20, "18446744073709551616", "2432902008176640000");
+    test(30, "2305843009213693951", "458908103098268852");
+    test(
+        100,
+        "1267650600228229401496703205376",
+        "792281625142643375935439503360",
+    );
+    test(1000, "1000000007", "641419708");
+    test(100000, "1000000007", "457992974");
+    test(200000, "2305843009213693951", "188934725827639129");
+}
+
+#[test]
+#[should_panic]
+fn factorial_mod_fail() {
+    Natural::factorial_mod(10, &Natural::ZERO);
+}
+
+#[test]
+fn binomial_coefficient_mod_properties() {
+    unsigned_triple_gen_var_19::<u8>().test_properties(|(n, k, m)| {
+        if m == 0 {
+            return;
+        }
+        let n = Natural::from(n);
+        let k = Natural::from(k);
+        let m = Natural::from(m);
+        let result = Natural::binomial_coefficient_mod(&n, &k, &m);
+        assert!(result.is_valid());
+        assert_eq!(
+            result,
+            Natural::binomial_coefficient(n.clone(), k.clone()) % &m
+        );
+        if k <= n {
+            assert_eq!(
+                Natural::binomial_coefficient_mod(&n, &(&n - &k), &m),
+                result
+            );
+            assert_eq!(Natural::multinomial_mod(&[k.clone(), &n - &k], &m), result);
+        }
+    });
+
+    unsigned_triple_gen_var_4::<u32, u16>().test_properties(|(n, k, m)| {
+        if m == 0 {
+            return;
+        }
+        let n = Natural::from(n);
+        let k = Natural::from(k);
+        let m = Natural::from(m);
+        let result = Natural::binomial_coefficient_mod(&n, &k, &m);
+        assert!(result.is_valid());
+        assert!(result < m);
+        // Pascal's rule
+        assert_eq!(
+            result.mod_add(
+                Natural::binomial_coefficient_mod(&n, &(&k + Natural::ONE), &m),
+                &m
+            ),
+            Natural::binomial_coefficient_mod(&(&n + Natural::ONE), &(k + Natural::ONE), &m)
+        );
+    });
+}
+
+#[test]
+fn multinomial_mod_properties() {
+    unsigned_vec_gen::<u8>().test_properties(|ks| {
+        let exact = Natural::multinomial(&ks.iter().map(|&k| u64::from(k)).collect::<Vec<_>>());
+        let ks: Vec<Natural> = ks.into_iter().map(Natural::from).collect();
+        for m in [1u64, 2, 12, 97, 360, 1024, 12192768, 1000000007, 18446744073709551557] {
+            let m = Natural::from(m);
+            let result = Natural::multinomial_mod(&ks, &m);
+            assert!(result.is_valid());
+            assert_eq!(result, &exact % m);
+        }
+    });
+}
+
+#[test]
+fn factorial_mod_properties() {
+    unsigned_pair_gen_var_28::<u64, u32>().test_properties(|(n, m)| {
+        if m == 0 {
+            return;
+        }
+        let m = Natural::from(m);
+        let result = Natural::factorial_mod(n, &m);
+        assert!(result.is_valid());
+        assert_eq!(result, Natural::factorial(n) % &m);
+    });
+}
@@ -0,0 +1,54 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{BinomialCoefficient, Catalan};
+use malachite_base::test_util::generators::unsigned_gen_var_5;
+use malachite_nz::natural::Natural;
+
+#[test]
+fn test_catalan() {
+    fn test(n: u64, out: &str) {
+        let c = Natural::catalan(n);
+        assert!(c.is_valid());
+        assert_eq!(c.to_string(), out);
+    }
+    test(0, "1");
+    test(1, "1");
+    test(2, "2");
+    test(3, "5");
+    test(5, "42");
+    test(10, "16796");
+    test(35, "3116285494907301262");
+    test(36, "11959798385860453492");
+    test(37, "45950804324621742364");
+    test(
+        100,
+        "896519947090131496687170070074100632420837521538745909320",
+    );
+}
+
+#[test]
+fn catalan_properties() {
+    unsigned_gen_var_5().test_properties(|n| {
+        let c = Natural::catalan(n);
+        assert!(c.is_valid());
+        assert_ne!(c, 0u32);
+        let n_natural = Natural::from(n);
+        let two_n = &n_natural << 1u32;
+        assert_eq!(
+            c,
+            Natural::binomial_coefficient(two_n.clone(), n_natural.clone())
+                - Natural::binomial_coefficient(two_n, n_natural + Natural::from(1u32))
+        );
+        // $(n + 2)C_{n+1} = 2(2n + 1)C_n$
+        assert_eq!(
+            Natural::catalan(n + 1) * Natural::from(n + 2),
+            c * Natural::from(2 * (2 * n + 1))
+        );
+    });
+}
@@ -0,0 +1,96 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{Cornacchia, Gcd, Square, TwoSquares};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::strings::ToDebugString;
+use malachite_base::test_util::generators::unsigned_pair_gen_var_27;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::natural::arithmetic::cornacchia::cornacchia_naive;
+use std::str::FromStr;
+
+#[test]
+fn test_cornacchia() {
+    let test = |d: &str, m: &str, out: &str| {
+        let d = Natural::from_str(d).unwrap();
+        let m = Natural::from_str(m).unwrap();
+        let result = d.clone().cornacchia(m.clone());
+        assert_eq!(result.to_debug_string(), out);
+        assert_eq!(d.clone().cornacchia(&m).to_debug_string(), out);
+        assert_eq!((&d).cornacchia(m.clone()).to_debug_string(), out);
+        assert_eq!((&d).cornacchia(&m).to_debug_string(), out);
+        if let Some((x, y)) = result {
+            assert!(x.is_valid());
+            assert!(y.is_valid());
+        }
+    };
+    test("1", "0", "None");
+    test("1", "1", "Some((1, 0))");
+    test("5", "1", "Some((1, 0))");
+    test("1", "2", "Some((1, 1))");
+    test("1", "13", "Some((3, 2))");
+    test("1", "21", "None");
+    test("1", "65", "Some((8, 1))");
+    test("2", "11", "Some((3, 1))");
+    test("3", "7", "Some((2, 1))");
+    test("5", "1009", "Some((17, 12))");
+    test("7", "29", "Some((1, 2))");
+    test("7", "32", "Some((5, 1))");
+    test("1", "170141183460469231731687303715884105727", "None");
+    test(
+        "1",
+        "170141183460469231731687303715884105773",
+        "Some((12769674765731352482, 2660186053251381107))",
+    );
+    test("3", "1000000000000000000000000000057", "Some((970654109437045, 138841156041688))");
+}
+
+#[test]
+#[should_panic]
+fn cornacchia_fail_1() {
+    Natural::ZERO.cornacchia(Natural::from(5u32));
+}
+
+#[test]
+#[should_panic]
+fn cornacchia_fail_2() {
+    Natural::from(6u32).cornacchia(Natural::from(10u32));
+}
+
+#[test]
+fn cornacchia_properties() {
+    unsigned_pair_gen_var_27::<u32>().test_properties(|(d, m)| {
+        let d = Natural::from(d) + Natural::ONE;
+        let m = Natural::from(m);
+        if m != 0u32 && (&d).gcd(&m) != 1u32 {
+            return;
+        }
+        let result = d.clone().cornacchia(m.clone());
+        assert_eq!(d.clone().cornacchia(&m), result);
+        assert_eq!((&d).cornacchia(m.clone()), result);
+        assert_eq!((&d).cornacchia(&m), result);
+        if let Some((x, y)) = &result {
+            assert!(x.is_valid());
+            assert!(y.is_valid());
+            assert_eq!(x.square() + &d * y.square(), m);
+            assert_eq!(x.gcd(y), 1u32);
+        }
+        if d == 1u32 && result.is_some() {
+            assert!(m.two_squares().is_some());
+        }
+    });
+
+    unsigned_pair_gen_var_27::<u16>().test_properties(|(d, m)| {
+        let d = Natural::from(d) + Natural::ONE;
+        let m = Natural::from(m);
+        if m != 0u32 && (&d).gcd(&m) != 1u32 {
+            return;
+        }
+        assert_eq!((&d).cornacchia(&m), cornacchia_naive(&d, &m));
+    });
+}
@@ -0,0 +1,109 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{EqMod, Lcm};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::strings::ToDebugString;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::{natural_triple_gen, natural_vec_gen};
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_crt() {
+    let test = |congruences: &[(&str, &str)], out| {
+        let congruences = congruences
+            .iter()
+            .map(|&(a, m)| (Natural::from_str(a).unwrap(), Natural::from_str(m).unwrap()))
+            .collect::<Vec<_>>();
+        let result = Natural::crt(&congruences);
+        assert_eq!(result.to_debug_string(), out);
+        if let Some((x, m)) = result {
+            assert!(x.is_valid());
+            assert!(m.is_valid());
+        }
+    };
+    test(&[], "Some((0, 1))");
+    test(&[("5", "1")], "Some((0, 1))");
+    test(&[("5", "7")], "Some((5, 7))");
+    test(&[("12", "7")], "Some((5, 7))");
+    test(&[("2", "3"), ("3", "5"), ("2", "7")], "Some((23, 105))");
+    test(&[("2", "4"), ("10", "6")], "Some((10, 12))");
+    test(&[("1", "4"), ("2", "6")], "None");
+    test(&[("3", "6"), ("3", "6")], "Some((3, 6))");
+    test(&[("3", "6"), ("0", "3")], "Some((3, 6))");
+    test(&[("3", "6"), ("2", "3")], "None");
+    test(&[("3", "6"), ("1", "2")], "Some((3, 6))");
+    test(&[("3", "6"), ("4", "10")], "None");
+    test(&[("3", "6"), ("5", "10")], "Some((15, 30))");
+    test(
+        &[
+            ("12345678987654321", "1000000007"),
+            ("98765432123456789", "1000000009"),
+            ("1", "18446744073709551616"),
+        ],
+        "Some((9676288039517126250583781839615295489, \
+        18446744368857457957497702499701751808))",
+    );
+}
+
+#[test]
+fn crt_fail() {
+    assert_panic!(Natural::crt(&[(Natural::ONE, Natural::ZERO)]));
+    assert_panic!(Natural::crt(&[
+        (Natural::ONE, Natural::from(3u32)),
+        (Natural::ONE, Natural::ZERO)
+    ]));
+}
+
+#[test]
+fn crt_properties() {
+    natural_triple_gen().test_properties(|(x, a, b)| {
+        let m = a + Natural::ONE;
+        let n = b + Natural::ONE;
+        let lcm = (&m).lcm(&n);
+        let result = Natural::crt(&[(x.clone(), m.clone()), (x.clone(), n.clone())]);
+        assert_eq!(result, Some((&x % &lcm, lcm)));
+        assert_eq!(
+            Natural::crt(&[(x.clone(), m.clone())]),
+            Some((&x % &m, m.clone()))
+        );
+        assert_eq!(
+            Natural::crt(&[(x.clone(), m.clone()), (x.clone() + Natural::ONE, m.clone())]),
+            if m == 1u32 {
+                Some((Natural::ZERO, m))
+            } else {
+                None
+            }
+        );
+    });
+
+    natural_vec_gen().test_properties(|xs| {
+        let congruences = xs
+            .iter()
+            .enumerate()
+            .map(|(i, x)| (Natural::from(i), x + Natural::ONE))
+            .collect::<Vec<_>>();
+        let result = Natural::crt(&congruences);
+        let lcm = congruences
+            .iter()
+            .fold(Natural::ONE, |lcm, (_, m)| lcm.lcm(m));
+        if let Some((x, m)) = result {
+            assert!(x.is_valid());
+            assert!(m.is_valid());
+            assert!(x < m);
+            assert_eq!(m, lcm);
+            for (a, n) in &congruences {
+                assert!((&x).eq_mod(a, n));
+            }
+        }
+        let mut reversed = congruences.clone();
+        reversed.reverse();
+        assert_eq!(Natural::crt(&reversed), Natural::crt(&congruences));
+    });
+}
@@ -0,0 +1,136 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{
+    CheckedFibonacci, CheckedLucas, Fibonacci, Lucas, Square,
+};
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::strings::ToDebugString;
+use malachite_base::test_util::generators::unsigned_gen_var_5;
+use malachite_nz::natural::Natural;
+use malachite_nz::platform::Limb;
+use rug::Complete;
+
+#[test]
+fn test_fibonacci() {
+    fn test(n: u64, out: &str, out_prev: &str) {
+        let f = Natural::fibonacci(n);
+        assert!(f.is_valid());
+        assert_eq!(f.to_string(), out);
+        let (f, f_prev) = Natural::fibonacci2(n);
+        assert!(f.is_valid());
+        assert!(f_prev.is_valid());
+        assert_eq!(f.to_string(), out);
+        assert_eq!(f_prev.to_string(), out_prev);
+        assert_eq!(
+            rug::Integer::fibonacci(u32::exact_from(n))
+                .complete()
+                .to_string(),
+            out
+        );
+    }
+    test(0, "0", "1");
+    test(1, "1", "0");
+    test(2, "1", "1");
+    test(3, "2", "1");
+    test(10, "55", "34");
+    test(47, "2971215073", "1836311903");
+    test(48, "4807526976", "2971215073");
+    test(93, "12200160415121876738", "7540113804746346429");
+    test(94, "19740274219868223167", "12200160415121876738");
+    test(100, "354224848179261915075", "218922995834555169026");
+    test(
+        300,
+        "222232244629420445529739893461909967206666939096499764990979600",
+        "137347080577163115432025771710279131845700275212767467264610201",
+    );
+}
+
+#[test]
+fn test_lucas() {
+    fn test(n: u64, out: &str) {
+        let l = Natural::lucas(n);
+        assert!(l.is_valid());
+        assert_eq!(l.to_string(), out);
+        assert_eq!(
+            rug::Integer::lucas(u32::exact_from(n))
+                .complete()
+                .to_string(),
+            out
+        );
+    }
+    test(0, "2");
+    test(1, "1");
+    test(2, "3");
+    test(10, "123");
+    test(46, "4106118243");
+    test(47, "6643838879");
+    test(92, "16860207025497407047");
+    test(93, "27280388024614569596");
+    test(100, "792070839848372253127");
+}
+
+#[test]
+fn fibonacci_properties() {
+    unsigned_gen_var_5().test_properties(|n| {
+        let (f, f_prev) = Natural::fibonacci2(n);
+        assert!(f.is_valid());
+        assert!(f_prev.is_valid());
+        assert_eq!(Natural::fibonacci(n), f);
+        assert_eq!(
+            Natural::exact_from(&rug::Integer::fibonacci(u32::exact_from(n)).complete()),
+            f
+        );
+        let (f_next, f_2) = Natural::fibonacci2(n + 1);
+        assert_eq!(f_2, f);
+        assert_eq!(&f + &f_prev, f_next);
+        // $F_{2n+1} = F_{n+1}^2 + F_n^2$
+        assert_eq!(
+            Natural::fibonacci(2 * n + 1),
+            (&f_next).square() + (&f).square()
+        );
+        // Cassini's identity: $F_{n+1}F_{n-1} - F_n^2 = (-1)^n$
+        if n != 0 {
+            let f_squared = (&f).square();
+            let product = f_next * f_prev;
+            if n % 2 == 0 {
+                assert_eq!(product, f_squared + Natural::ONE);
+            } else {
+                assert_eq!(product + Natural::ONE, f_squared);
+            }
+        }
+    });
+
+    unsigned_gen_var_5().test_properties(|n| {
+        if let Some((f, f_prev)) = Limb::checked_fibonacci2(n) {
+            assert_eq!(
+                Natural::fibonacci2(n).to_debug_string(),
+                (f, f_prev).to_debug_string()
+            );
+        }
+    });
+}
+
+#[test]
+fn lucas_properties() {
+    unsigned_gen_var_5().test_properties(|n| {
+        let l = Natural::lucas(n);
+        assert!(l.is_valid());
+        assert_eq!(
+            Natural::exact_from(&rug::Integer::lucas(u32::exact_from(n)).complete()),
+            l
+        );
+        assert_eq!(Natural::lucas(n + 2), &l + Natural::lucas(n + 1));
+        // $F_{2n} = F_n L_n$
+        assert_eq!(Natural::fibonacci(2 * n), Natural::fibonacci(n) * l);
+        if let Some(l) = Limb::checked_lucas(n) {
+            assert_eq!(Natural::lucas(n), l);
+        }
+    });
+}
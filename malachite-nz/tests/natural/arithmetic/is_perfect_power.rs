@@ -0,0 +1,112 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{
+    CheckedRoot, ExpressAsPower, IsPerfectPower, Pow, Square,
+};
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::factorization::traits::Primes;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::strings::ToDebugString;
+use malachite_base::test_util::generators::unsigned_gen;
+use malachite_nz::natural::Natural;
+use malachite_nz::platform::Limb;
+use malachite_nz::test_util::generators::natural_gen;
+use std::str::FromStr;
+
+fn express_as_power_naive(x: &Natural) -> Option<(Natural, u64)> {
+    if *x <= 1u32 {
+        return Some((x.clone(), 2));
+    }
+    for k in (2..x.significant_bits()).rev() {
+        if let Some(root) = x.checked_root(k) {
+            return Some((root, k));
+        }
+    }
+    None
+}
+
+#[test]
+fn test_express_as_power() {
+    let test = |s, out| {
+        let x = Natural::from_str(s).unwrap();
+        let result = x.express_as_power();
+        assert_eq!(result.to_debug_string(), out);
+        assert_eq!(x.is_perfect_power(), result.is_some());
+        assert_eq!(express_as_power_naive(&x).to_debug_string(), out);
+    };
+    test("0", "Some((0, 2))");
+    test("1", "Some((1, 2))");
+    test("2", "None");
+    test("8", "Some((2, 3))");
+    test("72", "None");
+    test("1000", "Some((10, 3))");
+    test("18446744073709551615", "None");
+    test("18446744073709551616", "Some((2, 64))");
+    test("18446744073709551617", "None");
+    test("340282366920938463463374607431768211456", "Some((2, 128))");
+    test("1000000000000000000000000000000", "Some((10, 30))");
+    test("1000000000000000000000000000001", "None");
+    test("1853020188851841", "Some((3, 32))");
+    test(
+        "515377520732011331036461129765621272702107522001",
+        "Some((3, 100))",
+    );
+    test("515377520732011331036461129765621272702107522002", "None");
+    test("3138428376721", "Some((11, 12))");
+    test("3138428376721000000000000", "Some((110, 12))");
+
+    let test_big = |base: u32, k: u64, out: &str| {
+        let x = Natural::from(base).pow(k);
+        assert_eq!(x.express_as_power().to_debug_string(), out);
+        assert!(x.is_perfect_power());
+        assert!(!(x + Natural::ONE).is_perfect_power());
+    };
+    test_big(6, 60, "Some((6, 60))");
+    test_big(12, 97, "Some((12, 97))");
+    test_big(36, 100, "Some((6, 200))");
+    test_big(1000, 211, "Some((10, 633))");
+    test_big(1000003, 101, "Some((1000003, 101))");
+}
+
+#[test]
+fn express_as_power_properties() {
+    natural_gen().test_properties(|x| {
+        let result = x.express_as_power();
+        assert_eq!(x.is_perfect_power(), result.is_some());
+        if let Some((base, k)) = result {
+            assert!(k >= 2);
+            assert_eq!((&base).pow(k), x);
+            if base > 1u32 {
+                assert!(!base.is_perfect_power());
+            }
+        }
+        if x > 1u32 {
+            let (base, k) = (&x).square().express_as_power().unwrap();
+            assert_eq!(base.pow(k / 2), x);
+            assert_eq!(k % 2, 0);
+        }
+    });
+
+    natural_gen().test_properties(|x| {
+        if x > 1u32 && x.significant_bits() < 100 {
+            for p in u64::primes_less_than(&20) {
+                let (_, k) = (&x).pow(p).express_as_power().unwrap();
+                assert_eq!(k % p, 0);
+            }
+        }
+    });
+
+    unsigned_gen::<Limb>().test_properties(|x| {
+        assert_eq!(
+            Natural::from(x).express_as_power(),
+            x.express_as_power().map(|(b, k)| (Natural::from(b), k))
+        );
+        assert_eq!(Natural::from(x).is_perfect_power(), x.is_perfect_power());
+    });
+}
@@ -0,0 +1,65 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{CheckedSqrt, IsSquare, Pow, Square};
+use malachite_base::num::basic::traits::One;
+use malachite_base::test_util::generators::unsigned_gen;
+use malachite_nz::natural::Natural;
+use malachite_nz::platform::Limb;
+use malachite_nz::test_util::generators::natural_gen;
+use std::str::FromStr;
+
+#[test]
+fn test_is_square() {
+    let test = |s, out| {
+        let x = Natural::from_str(s).unwrap();
+        assert_eq!(x.is_square(), out);
+        assert_eq!(x.checked_sqrt().is_some(), out);
+    };
+    test("0", true);
+    test("1", true);
+    test("2", false);
+    test("4", true);
+    test("99", false);
+    test("100", true);
+    test("18446744073709551615", false);
+    test("18446744073709551616", true);
+    test("340282366920938463426481119284349108225", true);
+    test("340282366920938463426481119284349108226", false);
+    test("340282366920938463463374607431768211456", true);
+    test("1000000000000000000000000000000", true);
+    test("1000000000000000000000000000001", false);
+    test("10000000000000000000000000000000", false);
+    test("152415787532388367501905199875019052100", true);
+    test("152415787532388367501905199875019052101", false);
+    test("152415787532388367501905199875019052099", false);
+
+    assert!(Natural::from(3u32).pow(1000).is_square());
+    assert!(!Natural::from(3u32).pow(1001).is_square());
+    assert!(!(Natural::from(3u32).pow(1000) - Natural::ONE).is_square());
+}
+
+#[test]
+fn is_square_properties() {
+    natural_gen().test_properties(|x| {
+        let is_square = x.is_square();
+        assert_eq!(is_square, (&x).checked_sqrt().is_some());
+        assert!((&x).square().is_square());
+        if x != 0u32 {
+            assert!(!((&x).square() + Natural::ONE).is_square());
+        }
+        assert_eq!((&x << 2u32).is_square(), is_square);
+        if is_square && x != 0u32 {
+            assert!(!(&x << 1u32).is_square());
+        }
+    });
+
+    unsigned_gen::<Limb>().test_properties(|x| {
+        assert_eq!(Natural::from(x).is_square(), x.is_square());
+    });
+}
@@ -0,0 +1,201 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{Gcd, ModDiscreteLog, ModPow};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::factorization::traits::{CarmichaelLambda, Factor, Totient};
+use malachite_base::strings::ToDebugString;
+use malachite_base::test_util::generators::unsigned_triple_gen_var_12;
+use malachite_nz::natural::Natural;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_mod_discrete_log() {
+    let test = |s, t, u, out| {
+        let base = Natural::from_str(s).unwrap();
+        let target = Natural::from_str(t).unwrap();
+        let m = Natural::from_str(u).unwrap();
+
+        let result = base.clone().mod_discrete_log(target.clone(), m.clone());
+        assert_eq!(result.to_debug_string(), out);
+        assert!(result.map_or(true, |k| k.is_valid()));
+
+        let result = base.clone().mod_discrete_log(&target, &m);
+        assert_eq!(result.to_debug_string(), out);
+        assert!(result.map_or(true, |k| k.is_valid()));
+
+        let result = (&base).mod_discrete_log(target.clone(), m.clone());
+        assert_eq!(result.to_debug_string(), out);
+        assert!(result.map_or(true, |k| k.is_valid()));
+
+        let result = (&base).mod_discrete_log(&target, &m);
+        assert_eq!(result.to_debug_string(), out);
+        assert!(result.map_or(true, |k| k.is_valid()));
+    };
+    test("0", "0", "1", "Some(0)");
+    test("0", "0", "2", "Some(1)");
+    test("1", "0", "2", "None");
+    test("3", "1", "7", "Some(0)");
+    test("3", "6", "7", "Some(3)");
+    test("2", "3", "7", "None");
+    test("2", "0", "8", "Some(3)");
+    test("12", "0", "72", "Some(2)");
+    test("4", "2", "6", "None");
+    // 11 has order 2, which divides the order of 2, but it is not a power of 2
+    test("2", "11", "15", "None");
+    test("6", "39361", "65521", "Some(1000)");
+    test("2", "123456789", "4294967291", "Some(3676225943)");
+    test(
+        "5",
+        "12345678901234567",
+        "18446744073709551557",
+        "Some(7356342371669930477)",
+    );
+    test(
+        "5",
+        "100000000000000000000000000001",
+        "1000000000000000000000000000057",
+        "Some(536850851924349563545054649225)",
+    );
+    test(
+        "3",
+        "10000000000000000000000000",
+        "618970019642690137449562111",
+        "Some(299832291162806541314929680)",
+    );
+    test(
+        "2",
+        "1267650600228229401496703205376",
+        "3802951800684688204490109616128",
+        "Some(100)",
+    );
+    test(
+        "2",
+        "1267650600228229401496703205377",
+        "3802951800684688204490109616128",
+        "None",
+    );
+}
+
+#[test]
+fn mod_discrete_log_fail() {
+    assert_panic!(Natural::ZERO.mod_discrete_log(Natural::ZERO, Natural::ZERO));
+    assert_panic!(Natural::from(7u32).mod_discrete_log(Natural::ONE, Natural::from(7u32)));
+    assert_panic!(Natural::from(3u32).mod_discrete_log(Natural::from(7u32), Natural::from(7u32)));
+    assert_panic!(
+        (&Natural::from(3u32)).mod_discrete_log(&Natural::from(7u32), &Natural::from(7u32))
+    );
+}
+
+#[test]
+fn mod_discrete_log_properties() {
+    unsigned_triple_gen_var_12::<u32>().test_properties(|(b, x, n)| {
+        let base = Natural::from(b);
+        let target = Natural::from(x);
+        let m = Natural::from(n);
+        let result = (&base).mod_discrete_log(&target, &m);
+        assert_eq!(
+            base.clone().mod_discrete_log(target.clone(), m.clone()),
+            result
+        );
+        assert_eq!(base.clone().mod_discrete_log(&target, &m), result);
+        assert_eq!((&base).mod_discrete_log(target.clone(), m.clone()), result);
+        assert_eq!(result, b.mod_discrete_log(x, n).map(Natural::from));
+        if let Some(k) = &result {
+            assert!(*k < m);
+            assert_eq!((&base).mod_pow(k, &m), target);
+        }
+        assert_eq!(
+            (&base).mod_discrete_log(&(Natural::ONE % &m), &m),
+            Some(Natural::ZERO)
+        );
+        let k = (&base)
+            .mod_discrete_log(&(&base).mod_pow(&target, &m), &m)
+            .unwrap();
+        assert!(k <= target);
+        if (&base).gcd(&m) == 1u32 {
+            assert!(k < m.carmichael_lambda());
+        }
+    });
+}
+
+#[test]
+fn test_mod_discrete_log_with_order() {
+    let test = |s, t, u, order_factors: &[(&str, u64)], out| {
+        let base = Natural::from_str(s).unwrap();
+        let target = Natural::from_str(t).unwrap();
+        let m = Natural::from_str(u).unwrap();
+        let order_factors: Vec<(Natural, u64)> = order_factors
+            .iter()
+            .map(|&(q, e)| (Natural::from_str(q).unwrap(), e))
+            .collect();
+        let result = base.mod_discrete_log_with_order(&target, &m, &order_factors);
+        assert_eq!(result.to_debug_string(), out);
+        assert!(result.map_or(true, |k| k.is_valid()));
+    };
+    test("0", "0", "1", &[], "Some(0)");
+    test("3", "1", "7", &[("2", 1), ("3", 1)], "Some(0)");
+    test("3", "6", "7", &[("2", 1), ("3", 1)], "Some(3)");
+    test("2", "4", "7", &[("3", 1)], "Some(2)");
+    test("2", "3", "7", &[("3", 1)], "None");
+    test("2", "3", "7", &[("2", 2), ("3", 1)], "None");
+    test("2", "11", "15", &[("2", 2)], "None");
+    test("7", "13", "15", &[("2", 2)], "Some(3)");
+    test(
+        "3",
+        "10000000000000000000000000",
+        "618970019642690137449562111",
+        &[
+            ("2", 1),
+            ("3", 1),
+            ("5", 1),
+            ("17", 1),
+            ("23", 1),
+            ("89", 1),
+            ("353", 1),
+            ("397", 1),
+            ("683", 1),
+            ("2113", 1),
+            ("2931542417", 1),
+        ],
+        "Some(299832291162806541314929680)",
+    );
+}
+
+#[test]
+fn mod_discrete_log_with_order_fail() {
+    let two = Natural::from(2u32);
+    let three = Natural::from(3u32);
+    let seven = Natural::from(7u32);
+    let eight = Natural::from(8u32);
+    assert_panic!(seven.mod_discrete_log_with_order(&Natural::ONE, &seven, &[(three.clone(), 1)]));
+    assert_panic!(two.mod_discrete_log_with_order(&seven, &seven, &[(three.clone(), 1)]));
+    assert_panic!(two.mod_discrete_log_with_order(&Natural::ONE, &eight, &[(two.clone(), 2)]));
+    assert_panic!(three.mod_discrete_log_with_order(&Natural::ONE, &seven, &[(three.clone(), 1)]));
+}
+
+#[test]
+fn mod_discrete_log_with_order_properties() {
+    unsigned_triple_gen_var_12::<u32>().test_properties(|(b, x, n)| {
+        let base = Natural::from(b);
+        let target = Natural::from(x);
+        let m = Natural::from(n);
+        if (&base).gcd(&m) != 1u32 {
+            return;
+        }
+        let lambda = m.carmichael_lambda();
+        let result = base.mod_discrete_log_with_order(&target, &m, &lambda.factor());
+        assert_eq!(result, (&base).mod_discrete_log(&target, &m));
+        let totient = m.totient();
+        assert_eq!(
+            base.mod_discrete_log_with_order(&target, &m, &totient.factor()),
+            result
+        );
+    });
+}
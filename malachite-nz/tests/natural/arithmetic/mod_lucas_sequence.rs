@@ -0,0 +1,159 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{
+    Lucas, ModLucasSequence, ModMul, ModPow, ModSquare, ModSub,
+};
+use malachite_base::num::basic::traits::{One, Two, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::strings::ToDebugString;
+use malachite_base::test_util::generators::unsigned_triple_gen_var_12;
+use malachite_nz::natural::Natural;
+use malachite_nz::platform::Limb;
+use malachite_nz::test_util::generators::natural_quadruple_gen_var_2;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_mod_lucas_sequence() {
+    let test = |p, q, k, m, out| {
+        let p = Natural::from_str(p).unwrap();
+        let q = Natural::from_str(q).unwrap();
+        let k = Natural::from_str(k).unwrap();
+        let m = Natural::from_str(m).unwrap();
+
+        let (u, v) = p
+            .clone()
+            .mod_lucas_sequence(q.clone(), k.clone(), m.clone());
+        assert!(u.is_valid());
+        assert!(v.is_valid());
+        assert_eq!((u, v).to_debug_string(), out);
+
+        let (u, v) = p.clone().mod_lucas_sequence(&q, &k, &m);
+        assert!(u.is_valid());
+        assert!(v.is_valid());
+        assert_eq!((u, v).to_debug_string(), out);
+
+        let (u, v) = (&p).mod_lucas_sequence(q.clone(), k.clone(), m.clone());
+        assert!(u.is_valid());
+        assert!(v.is_valid());
+        assert_eq!((u, v).to_debug_string(), out);
+
+        let (u, v) = (&p).mod_lucas_sequence(&q, &k, &m);
+        assert!(u.is_valid());
+        assert!(v.is_valid());
+        assert_eq!((u, v).to_debug_string(), out);
+    };
+    test("0", "0", "0", "1", "(0, 0)");
+    test("0", "0", "5", "1", "(0, 0)");
+    test("1", "9", "0", "10", "(0, 2)");
+    test("1", "9", "1", "10", "(1, 1)");
+    test("1", "999", "10", "1000", "(55, 123)");
+    test("3", "2", "10", "1000", "(23, 25)");
+    test("5", "3", "17", "64", "(17, 43)");
+    test("4", "1", "100", "1009", "(219, 820)");
+    test(
+        "1",
+        "18446744073709551556",
+        "1000000",
+        "18446744073709551557",
+        "(1531522294932794719, 17782668989762456554)",
+    );
+    test(
+        "4",
+        "1",
+        "1000000000000000000000",
+        "1000000000000000000000000000057",
+        "(709785189097611682225478504000, 254427577138153117228620988197)",
+    );
+}
+
+#[test]
+fn mod_lucas_sequence_fail() {
+    assert_panic!(Natural::ZERO.mod_lucas_sequence(Natural::ZERO, Natural::ONE, Natural::ZERO));
+    assert_panic!(Natural::from(7u32).mod_lucas_sequence(
+        Natural::ONE,
+        Natural::ONE,
+        Natural::from(7u32)
+    ));
+    assert_panic!(Natural::ONE.mod_lucas_sequence(
+        Natural::from(7u32),
+        Natural::ONE,
+        Natural::from(7u32)
+    ));
+
+    assert_panic!(Natural::ZERO.mod_lucas_sequence(&Natural::ZERO, &Natural::ONE, &Natural::ZERO));
+    assert_panic!((&Natural::from(7u32)).mod_lucas_sequence(
+        Natural::ONE,
+        Natural::ONE,
+        Natural::from(7u32)
+    ));
+    assert_panic!((&Natural::ONE).mod_lucas_sequence(
+        &Natural::from(7u32),
+        &Natural::ONE,
+        &Natural::from(7u32)
+    ));
+}
+
+#[test]
+fn mod_lucas_sequence_properties() {
+    natural_quadruple_gen_var_2().test_properties(|(p, q, k, m)| {
+        let (u, v) = (&p).mod_lucas_sequence(&q, &k, &m);
+        assert!(u.is_valid());
+        assert!(v.is_valid());
+        assert!(u < m);
+        assert!(v < m);
+        let (u_alt, v_alt) = p
+            .clone()
+            .mod_lucas_sequence(q.clone(), k.clone(), m.clone());
+        assert_eq!(u_alt, u);
+        assert_eq!(v_alt, v);
+        let (u_alt, v_alt) = p.clone().mod_lucas_sequence(&q, &k, &m);
+        assert_eq!(u_alt, u);
+        assert_eq!(v_alt, v);
+        let (u_alt, v_alt) = (&p).mod_lucas_sequence(q.clone(), k.clone(), m.clone());
+        assert_eq!(u_alt, u);
+        assert_eq!(v_alt, v);
+
+        // $U_{2k} = U_k V_k$ and $V_{2k} = V_k^2 - 2Q^k$
+        let (u_2, v_2) = (&p).mod_lucas_sequence(&q, &(&k << 1u32), &m);
+        assert_eq!((&u).mod_mul(&v, &m), u_2);
+        let two_q_k = (Natural::TWO % &m).mod_mul((&q).mod_pow(&k, &m), &m);
+        assert_eq!(v.mod_square(&m).mod_sub(two_q_k, &m), v_2);
+
+        // $U_{k+2} = PU_{k+1} - QU_k$
+        let (u_1, _) = (&p).mod_lucas_sequence(&q, &(&k + Natural::ONE), &m);
+        let (u_2, _) = (&p).mod_lucas_sequence(&q, &(&k + Natural::TWO), &m);
+        assert_eq!((&p).mod_mul(u_1, &m).mod_sub((&q).mod_mul(u, &m), &m), u_2);
+    });
+
+    unsigned_triple_gen_var_12::<Limb>().test_properties(|(p, q, m)| {
+        for k in [0, 1, 2, 10, 100] {
+            let (u, v) = p.mod_lucas_sequence(q, k, m);
+            assert_eq!(
+                Natural::from(p).mod_lucas_sequence(
+                    Natural::from(q),
+                    Natural::from(k),
+                    Natural::from(m)
+                ),
+                (Natural::from(u), Natural::from(v))
+            );
+        }
+    });
+
+    natural_quadruple_gen_var_2().test_properties(|(_, _, k, m)| {
+        if m > 1u32 && k.significant_bits() <= 10 {
+            // The Lucas numbers are $V(1, -1)$
+            let k = u64::exact_from(&k);
+            let (_, v) =
+                Natural::ONE.mod_lucas_sequence(&m - Natural::ONE, Natural::from(k), m.clone());
+            assert_eq!(v, Natural::lucas(k) % m);
+        }
+    });
+}
@@ -0,0 +1,193 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{ModMul, ModPow};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_nz::natural::arithmetic::mod_pow_table::ModPowTable;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::{
+    natural_quadruple_gen_var_2, natural_quadruple_gen_var_3, natural_triple_gen_var_5,
+    natural_vec_natural_pair_gen_var_2,
+};
+use std::str::FromStr;
+
+#[test]
+fn test_mod_pow_table() {
+    let test = |g, exps: &[&str], m, max_exp_bits, outs: &[&str]| {
+        let table = ModPowTable::new(
+            Natural::from_str(g).unwrap(),
+            Natural::from_str(m).unwrap(),
+            max_exp_bits,
+        );
+        assert_eq!(table.base().to_string(), g);
+        assert_eq!(table.modulus().to_string(), m);
+        assert_eq!(table.max_exp_bits(), max_exp_bits);
+        for (exp, out) in exps.iter().zip(outs.iter()) {
+            let power = table.pow(&Natural::from_str(exp).unwrap());
+            assert!(power.is_valid());
+            assert_eq!(power.to_string(), *out);
+        }
+    };
+    test("0", &["0", "1"], "1", 10, &["0", "0"]);
+    test("0", &["0", "1", "5"], "10", 10, &["1", "0", "0"]);
+    test("4", &["0", "1", "13"], "497", 64, &["1", "4", "445"]);
+    test("10", &["1000"], "30", 16, &["10"]);
+    test("123", &["456"], "1000", 0, &["561"]);
+    test("123", &["456"], "1000", 9, &["561"]);
+    test(
+        "3",
+        &["18446744073709551615"],
+        "18446744073709551616",
+        64,
+        &["12297829382473034411"],
+    );
+    test(
+        "3",
+        &["1000000000000000000000000000000"],
+        "170141183460469231731687303715884105727",
+        100,
+        &["154529045331661267443158746728834222196"],
+    );
+    test(
+        "7",
+        &["1606938044258990275541962092341162602522202993782792835313721"],
+        "10000000000000000000000000000000000000000",
+        201,
+        &["3080937492011374449949214121896032824007"],
+    );
+    test(
+        "3",
+        &["1000000000000000000000000000007"],
+        "1361129467683753853853498429727072845824",
+        100,
+        &["765260188859882572318345311648913295499"],
+    );
+    test(
+        "5",
+        &["12345", "10000000000000000000000000000000000000000"],
+        "1153617588319010271378133306175011326520419737189530113840977835459429144159137562624",
+        140,
+        &[
+            "657526956164978472791930510281389955076182373970215533380251559812640984783915680165",
+            "62324406781153233361159560776345864253807364204527251419498672723901006074223788033",
+        ],
+    );
+}
+
+#[test]
+#[should_panic]
+fn mod_pow_table_fail_1() {
+    ModPowTable::new(Natural::from(30u32), Natural::from(30u32), 10);
+}
+
+#[test]
+#[should_panic]
+fn mod_pow_table_fail_2() {
+    ModPowTable::new(Natural::ZERO, Natural::ZERO, 10);
+}
+
+#[test]
+fn test_mod_multi_pow() {
+    let test = |pairs: &[(&str, &str)], m, out| {
+        let pairs: Vec<(Natural, Natural)> = pairs
+            .iter()
+            .map(|(x, e)| (Natural::from_str(x).unwrap(), Natural::from_str(e).unwrap()))
+            .collect();
+        let m = Natural::from_str(m).unwrap();
+        let result = Natural::mod_multi_pow(&pairs, &m);
+        assert!(result.is_valid());
+        assert_eq!(result.to_string(), out);
+    };
+    test(&[], "1", "0");
+    test(&[], "10", "1");
+    test(&[("0", "0")], "10", "1");
+    test(&[("0", "5"), ("3", "2")], "10", "0");
+    test(&[("4", "13"), ("10", "5")], "497", "111");
+    test(
+        &[("2", "100"), ("3", "200"), ("5", "300")],
+        "1000003",
+        "935116",
+    );
+    test(
+        &[("2", "100"), ("3", "200")],
+        "1000000000000",
+        "379563749376",
+    );
+    test(
+        &[
+            ("5", "10000000000000000000000000000000000000000"),
+            ("7", "1000000000000000000000000000000000000001"),
+            ("11", "1267650600228229401496703205376"),
+        ],
+        "1153617588319010271378133306175011326520419737189530113840977835459429144159137562624",
+        "489537603214688685744144314755084285288875869505519331228777571688749734041898450951",
+    );
+}
+
+#[test]
+#[should_panic]
+fn mod_multi_pow_fail() {
+    Natural::mod_multi_pow(
+        &[(Natural::from(3u32), Natural::ONE), (Natural::from(30u32), Natural::ONE)],
+        &Natural::from(30u32),
+    );
+}
+
+#[test]
+fn mod_pow_table_properties() {
+    natural_triple_gen_var_5().test_properties(|(x, exp, m)| {
+        let power = (&x).mod_pow(&exp, &m);
+        let bits = exp.significant_bits();
+        let table = ModPowTable::new(x.clone(), m.clone(), bits);
+        let table_power = table.pow(&exp);
+        assert!(table_power.is_valid());
+        assert_eq!(table_power, power);
+        // Exponents that are too large for the table fall back to `mod_pow`.
+        let small_table = ModPowTable::new(x, m, bits >> 1);
+        assert_eq!(small_table.pow(&exp), power);
+    });
+
+    natural_quadruple_gen_var_3().test_properties(|(x, e, f, m)| {
+        let table = ModPowTable::new(x, m, (&e + &f).significant_bits());
+        assert_eq!(
+            table.pow(&(&e + &f)),
+            table.pow(&e).mod_mul(table.pow(&f), table.modulus())
+        );
+    });
+}
+
+#[test]
+fn mod_multi_pow_properties() {
+    natural_vec_natural_pair_gen_var_2().test_properties(|(xs, m)| {
+        let pairs: Vec<(Natural, Natural)> =
+            xs.iter().cloned().zip(xs.iter().rev().cloned()).collect();
+        let product = Natural::mod_multi_pow(&pairs, &m);
+        assert!(product.is_valid());
+        assert!(product < m);
+        assert_eq!(
+            pairs.iter().fold(Natural::ONE, |product, (x, e)| product
+                .mod_mul(x.mod_pow(e, &m), &m)),
+            product
+        );
+    });
+
+    natural_triple_gen_var_5().test_properties(|(x, exp, m)| {
+        assert_eq!(
+            Natural::mod_multi_pow(&[(x.clone(), exp.clone())], &m),
+            x.mod_pow(exp, m)
+        );
+    });
+
+    natural_quadruple_gen_var_2().test_properties(|(x, y, exp, m)| {
+        assert_eq!(
+            Natural::mod_multi_pow(&[(x.clone(), exp.clone()), (y.clone(), exp.clone())], &m),
+            x.mod_mul(y, &m).mod_pow(exp, m)
+        );
+    });
+}
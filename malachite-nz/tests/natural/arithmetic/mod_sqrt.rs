@@ -0,0 +1,205 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{
+    LegendreSymbol, ModPrimePowerSqrt, ModSqrt, ModSquare, Pow, Square,
+};
+use malachite_base::num::basic::traits::{Two, Zero};
+use malachite_base::num::factorization::traits::{NextPrime, PreviousPrime};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::strings::ToDebugString;
+use malachite_base::test_util::generators::unsigned_pair_gen_var_16;
+use malachite_nz::natural::Natural;
+use malachite_nz::platform::Limb;
+use malachite_nz::test_util::generators::natural_pair_gen_var_8;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_mod_sqrt() {
+    let test = |s, t, out| {
+        let x = Natural::from_str(s).unwrap();
+        let p = Natural::from_str(t).unwrap();
+
+        let result = x.clone().mod_sqrt(p.clone());
+        assert_eq!(result.to_debug_string(), out);
+        assert!(result.map_or(true, |r| r.is_valid()));
+
+        let result = x.clone().mod_sqrt(&p);
+        assert_eq!(result.to_debug_string(), out);
+        assert!(result.map_or(true, |r| r.is_valid()));
+
+        let result = (&x).mod_sqrt(p.clone());
+        assert_eq!(result.to_debug_string(), out);
+        assert!(result.map_or(true, |r| r.is_valid()));
+
+        let result = (&x).mod_sqrt(&p);
+        assert_eq!(result.to_debug_string(), out);
+        assert!(result.map_or(true, |r| r.is_valid()));
+    };
+    test("0", "2", "Some(0)");
+    test("1", "2", "Some(1)");
+    test("0", "7", "Some(0)");
+    test("2", "7", "Some(3)");
+    test("3", "7", "None");
+    test("10", "13", "Some(6)");
+    test("2", "1000000007", "Some(59713600)");
+    // 2^127 - 1
+    test(
+        "11",
+        "170141183460469231731687303715884105727",
+        "Some(18756936442469208154408315811770624301)",
+    );
+    test("14", "170141183460469231731687303715884105727", "None");
+    // 2^255 - 19
+    test(
+        "3",
+        "57896044618658097711785492504343953926634992332820282019728792003956564819949",
+        "Some(15029839470433391022265175636939773287626296101036845499088079275986334742835)",
+    );
+    test(
+        "10",
+        "57896044618658097711785492504343953926634992332820282019728792003956564819949",
+        "None",
+    );
+    // 165 * 2^100 + 1
+    test(
+        "2",
+        "209162349037657851246956028887041",
+        "Some(2708877323875654048997667164873)",
+    );
+    test(
+        "7",
+        "209162349037657851246956028887041",
+        "Some(59938732704164335071816963885447)",
+    );
+}
+
+#[test]
+fn mod_sqrt_fail() {
+    assert_panic!(Natural::ZERO.mod_sqrt(Natural::ZERO));
+    assert_panic!(Natural::from(7u32).mod_sqrt(Natural::from(7u32)));
+    assert_panic!(Natural::from(7u32).mod_sqrt(&Natural::from(7u32)));
+    assert_panic!((&Natural::from(7u32)).mod_sqrt(Natural::from(7u32)));
+    assert_panic!((&Natural::from(7u32)).mod_sqrt(&Natural::from(7u32)));
+}
+
+#[test]
+fn test_mod_prime_power_sqrt() {
+    let test = |s, t, pow, out| {
+        let x = Natural::from_str(s).unwrap();
+        let p = Natural::from_str(t).unwrap();
+
+        let result = x.clone().mod_prime_power_sqrt(p.clone(), pow);
+        assert_eq!(result.to_debug_string(), out);
+        assert!(result.map_or(true, |r| r.is_valid()));
+
+        let result = x.clone().mod_prime_power_sqrt(&p, pow);
+        assert_eq!(result.to_debug_string(), out);
+        assert!(result.map_or(true, |r| r.is_valid()));
+
+        let result = (&x).mod_prime_power_sqrt(p.clone(), pow);
+        assert_eq!(result.to_debug_string(), out);
+        assert!(result.map_or(true, |r| r.is_valid()));
+
+        let result = (&x).mod_prime_power_sqrt(&p, pow);
+        assert_eq!(result.to_debug_string(), out);
+        assert!(result.map_or(true, |r| r.is_valid()));
+    };
+    test("0", "2", 0, "Some(0)");
+    test("0", "5", 2, "Some(0)");
+    test("3", "2", 2, "None");
+    test("17", "2", 5, "Some(7)");
+    test("9", "3", 3, "Some(3)");
+    test("3", "3", 3, "None");
+    test("2", "7", 3, "Some(108)");
+    test("41", "2", 100, "Some(268257941340519377144514844979)");
+    test("164", "2", 64, "Some(701198164604432998)");
+    test("2", "1000000007", 3, "Some(410877934347786033361197191)");
+    test("3", "1000000007", 3, "Some(310313667467905583152031861)");
+    test("4000000056000000196", "1000000007", 3, "Some(2000000014)");
+    test("1000000007", "1000000007", 3, "None");
+}
+
+#[test]
+fn mod_prime_power_sqrt_fail() {
+    assert_panic!(Natural::from(8u32).mod_prime_power_sqrt(Natural::TWO, 3));
+    assert_panic!(Natural::from(8u32).mod_prime_power_sqrt(&Natural::TWO, 3));
+    assert_panic!((&Natural::from(8u32)).mod_prime_power_sqrt(Natural::TWO, 3));
+    assert_panic!((&Natural::from(8u32)).mod_prime_power_sqrt(&Natural::TWO, 3));
+}
+
+#[test]
+fn mod_sqrt_properties() {
+    natural_pair_gen_var_8().test_properties(|(x, m)| {
+        let p = m.next_prime();
+        let result_val_val = x.clone().mod_sqrt(p.clone());
+        let result_val_ref = x.clone().mod_sqrt(&p);
+        let result_ref_val = (&x).mod_sqrt(p.clone());
+        let result = (&x).mod_sqrt(&p);
+        assert!(result.as_ref().map_or(true, Natural::is_valid));
+        assert_eq!(result_val_val, result);
+        assert_eq!(result_val_ref, result);
+        assert_eq!(result_ref_val, result);
+        if let Some(r) = result {
+            assert!(r < p);
+            assert_eq!((&r).mod_square(&p), x);
+            assert!(r == 0u32 || r <= &p - &r);
+        } else {
+            assert_eq!((&x).legendre_symbol(&p), -1);
+        }
+        let neg = &p - &x;
+        assert_eq!(
+            (&x).mod_square(&p).mod_sqrt(&p),
+            Some(if x <= neg { x } else { neg })
+        );
+    });
+
+    natural_pair_gen_var_8().test_properties(|(x, y)| {
+        let p = (y % Natural::from(32u32)).next_prime();
+        let pow = x.significant_bits() / (p.significant_bits() - 1) + 1;
+        let pk = (&p).pow(pow);
+        let result_val_val = x.clone().mod_prime_power_sqrt(p.clone(), pow);
+        let result_val_ref = x.clone().mod_prime_power_sqrt(&p, pow);
+        let result_ref_val = (&x).mod_prime_power_sqrt(p.clone(), pow);
+        let result = (&x).mod_prime_power_sqrt(&p, pow);
+        assert!(result.as_ref().map_or(true, Natural::is_valid));
+        assert_eq!(result_val_val, result);
+        assert_eq!(result_val_ref, result);
+        assert_eq!(result_ref_val, result);
+        if let Some(r) = result {
+            assert!(r < pk);
+            assert_eq!((&r).mod_square(&pk), x);
+        }
+        let r = (&x).mod_square(&pk).mod_prime_power_sqrt(&p, pow).unwrap();
+        assert!(r <= x);
+        let x = x % &p;
+        assert_eq!((&x).mod_prime_power_sqrt(&p, 1), x.mod_sqrt(p));
+    });
+
+    natural_pair_gen_var_8().test_properties(|(x, m)| {
+        let p = m.next_prime();
+        let pk = (&p).square();
+        let x = x % &pk;
+        if let Some(r) = (&x).mod_prime_power_sqrt(&p, 2) {
+            assert!(r < pk);
+            assert_eq!((&r).mod_square(&pk), x);
+        }
+    });
+
+    unsigned_pair_gen_var_16::<Limb>().test_properties(|(x, m)| {
+        let Some(p) = m.checked_add(1).and_then(|m| m.previous_prime()) else {
+            return;
+        };
+        let x = x % p;
+        assert_eq!(
+            x.mod_sqrt(p).map(Natural::from),
+            Natural::from(x).mod_sqrt(Natural::from(p))
+        );
+    });
+}
@@ -0,0 +1,218 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{
+    ModAdd, ModInverse, ModMul, ModNeg, ModPow, ModSub, Pow, PowerOf2, Reciprocal,
+};
+use malachite_base::num::basic::traits::{One, Two, Zero};
+use malachite_base::strings::ToDebugString;
+use malachite_nz::natural::arithmetic::montgomery::ModularRing;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::natural_triple_gen;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_montgomery() {
+    let test = |s, t, u, sum, difference, product, negative, power| {
+        let m = Natural::from_str(u).unwrap();
+        let ring = ModularRing::new(m);
+        let x_n = Natural::from_str(s).unwrap();
+        let y_n = Natural::from_str(t).unwrap();
+        let x = ring.element(&x_n);
+        let y = ring.element(&y_n);
+        assert_eq!(x.to_natural(), x_n);
+        assert_eq!(y.to_natural(), y_n);
+
+        assert_eq!((x.clone() + y.clone()).to_string(), sum);
+        assert_eq!((x.clone() + &y).to_string(), sum);
+        assert_eq!((&x + y.clone()).to_string(), sum);
+        assert_eq!((&x + &y).to_string(), sum);
+        let mut z = x.clone();
+        z += y.clone();
+        assert_eq!(z.to_string(), sum);
+        let mut z = x.clone();
+        z += &y;
+        assert_eq!(z.to_string(), sum);
+
+        assert_eq!((x.clone() - y.clone()).to_string(), difference);
+        assert_eq!((x.clone() - &y).to_string(), difference);
+        assert_eq!((&x - y.clone()).to_string(), difference);
+        assert_eq!((&x - &y).to_string(), difference);
+        let mut z = x.clone();
+        z -= y.clone();
+        assert_eq!(z.to_string(), difference);
+        let mut z = x.clone();
+        z -= &y;
+        assert_eq!(z.to_string(), difference);
+
+        assert_eq!((x.clone() * y.clone()).to_string(), product);
+        assert_eq!((x.clone() * &y).to_string(), product);
+        assert_eq!((&x * y.clone()).to_string(), product);
+        assert_eq!((&x * &y).to_string(), product);
+        let mut z = x.clone();
+        z *= y.clone();
+        assert_eq!(z.to_string(), product);
+        let mut z = x.clone();
+        z *= &y;
+        assert_eq!(z.to_string(), product);
+
+        assert_eq!((-x.clone()).to_string(), negative);
+        assert_eq!((-&x).to_string(), negative);
+
+        assert_eq!(x.clone().pow(&y_n).to_string(), power);
+        assert_eq!((&x).pow(&y_n).to_string(), power);
+        if let Ok(e) = u64::try_from(&y_n) {
+            assert_eq!(x.clone().pow(e).to_string(), power);
+            assert_eq!((&x).pow(e).to_string(), power);
+        }
+    };
+    test("0", "0", "1", "0", "0", "0", "0", "0");
+    test("5", "3", "7", "1", "2", "1", "2", "6");
+    test("50", "60", "101", "9", "91", "71", "51", "36");
+    test(
+        "123",
+        "456",
+        "1000000007",
+        "579",
+        "999999674",
+        "56088",
+        "999999884",
+        "565291922",
+    );
+    test(
+        "1000000000000000000000",
+        "999999999999999999999",
+        "340282366920938463463374607431768211297",
+        "1999999999999999999999",
+        "1",
+        "250405986282794343605403365464995209414",
+        "340282366920938462463374607431768211297",
+        "184904916628363969605483989342247002505",
+    );
+    test(
+        "340282366920938463463374607431768211296",
+        "2",
+        "340282366920938463463374607431768211297",
+        "1",
+        "340282366920938463463374607431768211294",
+        "340282366920938463463374607431768211295",
+        "1",
+        "1",
+    );
+}
+
+#[test]
+fn test_montgomery_large_modulus() {
+    // A modulus with enough limbs that the reductions use the full-width inverse.
+    let m = Natural::from(3u32).pow(5000);
+    let ring = ModularRing::new(m.clone());
+    let x_n = Natural::from(7u32).pow(4000) % &m;
+    let y_n = Natural::from(11u32).pow(3000) % &m;
+    let x = ring.element(&x_n);
+    let y = ring.element(&y_n);
+    assert_eq!(x.to_natural(), x_n);
+    assert_eq!((&x * &y).to_natural(), (&x_n).mod_mul(&y_n, &m));
+    assert_eq!((&x + &y).to_natural(), (&x_n).mod_add(&y_n, &m));
+    assert_eq!((&x - &y).to_natural(), (&x_n).mod_sub(&y_n, &m));
+    let e = Natural::from(10u32).pow(30);
+    assert_eq!((&x).pow(&e).to_natural(), (&x_n).mod_pow(&e, &m));
+    // Exponents on either side of the thresholds where the window size changes, with dense and
+    // sparse bits
+    for bits in [1, 2, 7, 8, 25, 26, 81, 82, 241, 242, 673, 674, 1793, 1794] {
+        for e in [
+            Natural::power_of_2(bits) - Natural::ONE,
+            Natural::power_of_2(bits) + Natural::ONE,
+            Natural::power_of_2(bits - 1),
+        ] {
+            assert_eq!((&x).pow(&e).to_natural(), (&x_n).mod_pow(&e, &m));
+        }
+    }
+    assert_eq!(
+        (&x).reciprocal().to_natural(),
+        (&x_n).mod_inverse(&m).unwrap()
+    );
+}
+
+#[test]
+fn test_checked_reciprocal() {
+    let test = |s, t, out| {
+        let ring = ModularRing::new(Natural::from_str(t).unwrap());
+        let x = ring.element(&Natural::from_str(s).unwrap());
+        assert_eq!(x.checked_reciprocal().to_debug_string(), out);
+        if let Some(inverse) = x.checked_reciprocal() {
+            assert_eq!(x.clone().reciprocal(), inverse);
+            assert_eq!((&x).reciprocal(), inverse);
+        }
+    };
+    test("0", "1", "Some(0)");
+    test("0", "7", "None");
+    test("3", "7", "Some(5)");
+    test("7", "15", "Some(13)");
+    test("6", "15", "None");
+    test("3", "101", "Some(34)");
+}
+
+#[test]
+fn montgomery_fail() {
+    assert_panic!(ModularRing::new(Natural::ZERO));
+    assert_panic!(ModularRing::new(Natural::from(10u32)));
+    let ring_1 = ModularRing::new(Natural::from(7u32));
+    let ring_2 = ModularRing::new(Natural::from(9u32));
+    assert_panic!(ring_1.one() + ring_2.one());
+    assert_panic!(ring_1.one() - ring_2.one());
+    assert_panic!(ring_1.one() * ring_2.one());
+    assert_panic!(ring_1.zero().reciprocal());
+    assert_panic!((&ring_1.zero()).reciprocal());
+}
+
+#[test]
+fn montgomery_properties() {
+    natural_triple_gen().test_properties(|(x, y, m)| {
+        let m = (m << 1u32) + Natural::ONE;
+        let ring = ModularRing::new(m.clone());
+        let x = x % &m;
+        let y = y % &m;
+        let x_m = ring.element(&x);
+        let y_m = ring.element(&y);
+        assert_eq!(x_m.to_natural(), x);
+        assert_eq!(ring.element(&(&x + &m)), x_m);
+        assert_eq!((&x_m + &y_m).to_natural(), (&x).mod_add(&y, &m));
+        assert_eq!((&x_m - &y_m).to_natural(), (&x).mod_sub(&y, &m));
+        assert_eq!((&x_m * &y_m).to_natural(), (&x).mod_mul(&y, &m));
+        assert_eq!((-&x_m).to_natural(), (&x).mod_neg(&m));
+        assert_eq!(&x_m - &y_m, &x_m + -&y_m);
+        assert_eq!(&x_m + ring.zero(), x_m);
+        assert_eq!(&x_m * ring.one(), x_m);
+        assert_eq!(x_m.is_zero(), x == 0u32);
+        assert_eq!(
+            x_m.checked_reciprocal().map(|r| r.to_natural()),
+            if m == 1u32 {
+                Some(Natural::ZERO)
+            } else if x == 0u32 {
+                None
+            } else {
+                (&x).mod_inverse(&m)
+            }
+        );
+        if let Some(inverse) = x_m.checked_reciprocal() {
+            assert_eq!(&x_m * inverse, ring.one());
+        }
+    });
+
+    natural_triple_gen().test_properties(|(x, e, m)| {
+        let m = (m << 1u32) + Natural::ONE;
+        let ring = ModularRing::new(m.clone());
+        let x = x % &m;
+        let x_m = ring.element(&x);
+        let power = (&x_m).pow(&e);
+        assert_eq!(power.to_natural(), (&x).mod_pow(&e, &m));
+        assert_eq!(&power * &x_m, (&x_m).pow(&(&e + Natural::ONE)));
+        assert_eq!((&x_m).pow(&(&e * Natural::TWO)), &power * &power);
+    });
+}
@@ -0,0 +1,95 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{BinomialCoefficient, DivExact, Factorial};
+use malachite_base::num::basic::traits::One;
+use malachite_base::test_util::generators::{unsigned_pair_gen_var_28, unsigned_vec_gen};
+use malachite_nz::natural::Natural;
+
+#[test]
+fn test_multinomial() {
+    let test = |ks: &[u64], out: &str| {
+        let x = Natural::multinomial(ks);
+        assert!(x.is_valid());
+        assert_eq!(x.to_string(), out);
+    };
+    test(&[], "1");
+    test(&[0], "1");
+    test(&[5], "1");
+    test(&[2, 3], "10");
+    test(&[0, 2, 0, 3, 0], "10");
+    test(&[3, 4, 5], "27720");
+    test(&[2, 3, 4], "1260");
+    test(&[1; 30], "265252859812191058636308480000000");
+    test(&[10, 20, 30], "3553261127084984957001360");
+    // The odd part fits in a single limb
+    test(&[600, 1, 1], "361802");
+    test(&[600, 1, 3], "21962105004");
+    test(&[600, 2, 1], "109083303");
+    test(&[600, 7, 1], "3537650678437764768");
+    test(&[1000, 1, 1], "1003002");
+    test(
+        &[100, 200, 300],
+        "561812797503528108022984154079257534052996731014652713946693054369744723960763677665568262\
+        037666873387723276089814458729537081414663956828422022354533538575959599909246383409683752\
+        681979910264098753913152412447850160155228058413061273197901319560650755916294720",
+    );
+}
+
+#[test]
+#[should_panic]
+fn multinomial_fail() {
+    Natural::multinomial(&[u64::MAX, 1]);
+}
+
+fn multinomial_naive(ks: &[u64]) -> Natural {
+    let mut x = Natural::factorial(ks.iter().sum());
+    for &k in ks {
+        x = x.div_exact(Natural::factorial(k));
+    }
+    x
+}
+
+#[test]
+fn multinomial_properties() {
+    unsigned_vec_gen::<u8>().test_properties(|ks| {
+        let ks: Vec<u64> = ks.into_iter().map(u64::from).collect();
+        let x = Natural::multinomial(&ks);
+        assert!(x.is_valid());
+        assert_eq!(x, multinomial_naive(&ks));
+        assert_ne!(x, 0u32);
+
+        let mut ks_alt = ks.clone();
+        ks_alt.reverse();
+        assert_eq!(Natural::multinomial(&ks_alt), x);
+        ks_alt.push(0);
+        assert_eq!(Natural::multinomial(&ks_alt), x);
+
+        if let Some((&last, init)) = ks.split_last() {
+            let n: u64 = ks.iter().sum();
+            assert_eq!(
+                Natural::multinomial(init)
+                    * Natural::binomial_coefficient(Natural::from(n), Natural::from(last)),
+                x
+            );
+        }
+    });
+
+    unsigned_pair_gen_var_28::<u64, u64>().test_properties(|(n, k)| {
+        assert_eq!(
+            Natural::multinomial(&[n, k]),
+            Natural::binomial_coefficient(Natural::from(n + k), Natural::from(k))
+        );
+    });
+
+    unsigned_pair_gen_var_28::<u64, u64>().test_properties(|(n, k)| {
+        assert_eq!(Natural::multinomial(&[n, k]), Natural::multinomial(&[k, n]));
+        assert_eq!(Natural::multinomial(&[n, 1]), Natural::from(n + 1));
+        assert_eq!(Natural::multinomial(&[n]), Natural::ONE);
+    });
+}
@@ -0,0 +1,52 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::Partitions;
+use malachite_base::test_util::generators::unsigned_gen_var_5;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::natural::arithmetic::partitions::partitions_naive;
+
+#[test]
+fn test_partitions() {
+    fn test(n: u64, out: &str) {
+        let p = Natural::partitions(n);
+        assert!(p.is_valid());
+        assert_eq!(p.to_string(), out);
+        assert_eq!(partitions_naive(n).to_string(), out);
+    }
+    test(0, "1");
+    test(1, "1");
+    test(2, "2");
+    test(3, "3");
+    test(4, "5");
+    test(5, "7");
+    test(10, "42");
+    test(100, "190569292");
+    test(399, "6324621482504294325");
+    test(400, "6727090051741041926");
+    test(401, "7154640222653942321");
+    test(1000, "24061467864032622473692149727991");
+    test(2000, "4720819175619413888601432406799959512200344166");
+    let p = Natural::partitions(10000);
+    assert!(p.is_valid());
+    assert_eq!(
+        p.to_string(),
+        "3616725132563629398882047189095369549501603033931565042208186860588795256875406642059231\
+        0556052906916435144"
+    );
+}
+
+#[test]
+fn partitions_properties() {
+    unsigned_gen_var_5().test_properties_with_limit(100, |n| {
+        let p = Natural::partitions(n);
+        assert!(p.is_valid());
+        assert_eq!(partitions_naive(n), p);
+        assert!(Natural::partitions(n + 1) >= p);
+    });
+}
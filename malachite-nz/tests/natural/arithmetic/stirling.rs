@@ -0,0 +1,110 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{Factorial, Stirling};
+use malachite_base::num::basic::traits::Zero;
+use malachite_base::test_util::generators::common::TINY_LIMIT;
+use malachite_base::test_util::generators::{unsigned_gen_var_5, unsigned_pair_gen_var_28};
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::natural::arithmetic::stirling::{stirling1_naive, stirling2_naive};
+
+#[test]
+fn test_stirling1() {
+    fn test(n: u64, k: u64, out: &str) {
+        let s = Natural::stirling1(n, k);
+        assert!(s.is_valid());
+        assert_eq!(s.to_string(), out);
+        assert_eq!(stirling1_naive(n, k).to_string(), out);
+    }
+    test(0, 0, "1");
+    test(1, 0, "0");
+    test(1, 1, "1");
+    test(3, 4, "0");
+    test(4, 2, "11");
+    test(5, 3, "35");
+    test(10, 5, "269325");
+    test(20, 1, "121645100408832000");
+    test(20, 19, "190");
+    test(30, 15, "8459574446076318147830625");
+    test(
+        50,
+        10,
+        "101623020926367490059043797119309944043405505380503665627365376",
+    );
+}
+
+#[test]
+fn test_stirling2() {
+    fn test(n: u64, k: u64, out: &str) {
+        let s = Natural::stirling2(n, k);
+        assert!(s.is_valid());
+        assert_eq!(s.to_string(), out);
+        assert_eq!(stirling2_naive(n, k).to_string(), out);
+    }
+    test(0, 0, "1");
+    test(1, 0, "0");
+    test(1, 1, "1");
+    test(3, 4, "0");
+    test(4, 2, "7");
+    test(5, 3, "25");
+    test(10, 5, "42525");
+    test(20, 1, "1");
+    test(20, 19, "190");
+    test(30, 15, "12879868072770626040000");
+    test(50, 10, "26154716515862881292012777396577993781727011");
+}
+
+#[test]
+fn stirling1_properties() {
+    unsigned_pair_gen_var_28::<u64, u64>().test_properties_with_limit(TINY_LIMIT, |(n, k)| {
+        let s = Natural::stirling1(n, k);
+        assert!(s.is_valid());
+        assert_eq!(stirling1_naive(n, k), s);
+        assert_eq!(s == 0u32, k > n || k == 0 && n != 0);
+        if k != 0 {
+            // $\left[{n+1 \atop k}\right] = n\left[{n \atop k}\right] + \left[{n \atop k-1}\right]$
+            assert_eq!(
+                Natural::stirling1(n + 1, k),
+                Natural::from(n) * &s + Natural::stirling1(n, k - 1)
+            );
+        }
+        assert!(Natural::stirling2(n, k) <= s);
+    });
+
+    unsigned_gen_var_5().test_properties_with_limit(100, |n| {
+        assert_eq!(
+            (0..=n).fold(Natural::ZERO, |sum, k| sum + Natural::stirling1(n, k)),
+            Natural::factorial(n)
+        );
+    });
+}
+
+#[test]
+fn stirling2_properties() {
+    unsigned_pair_gen_var_28::<u64, u64>().test_properties_with_limit(TINY_LIMIT, |(n, k)| {
+        let s = Natural::stirling2(n, k);
+        assert!(s.is_valid());
+        assert_eq!(stirling2_naive(n, k), s);
+        assert_eq!(s == 0u32, k > n || k == 0 && n != 0);
+        if k != 0 {
+            // $\left\\{{n+1 \atop k}\right\\} = k\left\\{{n \atop k}\right\\} + \left\\{{n \atop
+            // k-1}\right\\}$
+            assert_eq!(
+                Natural::stirling2(n + 1, k),
+                Natural::from(k) * &s + Natural::stirling2(n, k - 1)
+            );
+        }
+    });
+
+    unsigned_gen_var_5().test_properties(|n| {
+        assert_eq!(Natural::stirling2(n, n), 1u32);
+        if n != 0 {
+            assert_eq!(Natural::stirling2(n, 1), 1u32);
+        }
+    });
+}
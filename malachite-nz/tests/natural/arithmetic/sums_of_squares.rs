@@ -0,0 +1,138 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{FourSquares, Square, TwoSquares};
+use malachite_base::num::basic::traits::{Two, Zero};
+use malachite_base::num::factorization::traits::Factor;
+use malachite_base::strings::ToDebugString;
+use malachite_base::test_util::generators::unsigned_pair_gen_var_27;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::{natural_gen, natural_gen_var_9};
+use std::str::FromStr;
+
+#[test]
+fn test_two_squares() {
+    let test = |n: &str, out: &str| {
+        let n = Natural::from_str(n).unwrap();
+        let result = n.two_squares();
+        assert_eq!(result.to_debug_string(), out);
+        if n != 0u32 {
+            assert_eq!(
+                Natural::two_squares_from_factorization(&n.factor()).to_debug_string(),
+                out
+            );
+        }
+        if let Some((a, b)) = result {
+            assert!(a.is_valid());
+            assert!(b.is_valid());
+        }
+    };
+    test("0", "Some((0, 0))");
+    test("1", "Some((0, 1))");
+    test("2", "Some((1, 1))");
+    test("3", "None");
+    test("4", "Some((0, 2))");
+    test("5", "Some((1, 2))");
+    test("9", "Some((0, 3))");
+    test("13", "Some((2, 3))");
+    test("21", "None");
+    test("25", "Some((3, 4))");
+    test("1000", "Some((18, 26))");
+    test("1170", "Some((9, 33))");
+    test("18446744073709551557", "Some((1576450879, 3995190446))");
+    test("170141183460469231731687303715884105727", "None");
+    test(
+        "170141183460469231731687303715884105773",
+        "Some((2660186053251381107, 12769674765731352482))",
+    );
+    test("1000000000000000000000000000000", "Some((489514398515200, 871995214233600))");
+}
+
+#[test]
+fn test_four_squares() {
+    let test = |n: &str, out: &str| {
+        let n = Natural::from_str(n).unwrap();
+        let (a, b, c, d) = n.four_squares();
+        assert!(a.is_valid());
+        assert!(b.is_valid());
+        assert!(c.is_valid());
+        assert!(d.is_valid());
+        assert_eq!((a, b, c, d).to_debug_string(), out);
+    };
+    test("0", "(0, 0, 0, 0)");
+    test("1", "(0, 0, 0, 1)");
+    test("2", "(0, 0, 1, 1)");
+    test("3", "(0, 1, 1, 1)");
+    test("7", "(1, 1, 1, 2)");
+    test("15", "(1, 1, 2, 3)");
+    test("96", "(0, 4, 4, 8)");
+    test("18446744073709551615", "(550, 1789, 92663, 4294967295)");
+    test("170141183460469231731687303715884105727", "(223189, 272014, 5933560267, 13043817825332782211)");
+    test(
+        "1000000000000000000000000000000",
+        "(66125824, 88309760, 8094679040, 999999999967232)",
+    );
+}
+
+#[test]
+#[should_panic]
+fn two_squares_from_factorization_fail() {
+    Natural::two_squares_from_factorization(&[(Natural::TWO, 0)]);
+}
+
+#[test]
+fn two_squares_properties() {
+    natural_gen_var_9().test_properties(|n| {
+        let result = n.two_squares();
+        if let Some((a, b)) = &result {
+            assert!(a.is_valid());
+            assert!(b.is_valid());
+            assert!(a <= b);
+            assert_eq!(a.square() + b.square(), n);
+        }
+        if n != 0u32 {
+            let factors = n.factor();
+            assert_eq!(Natural::two_squares_from_factorization(&factors), result);
+            assert_eq!(
+                result.is_some(),
+                factors
+                    .iter()
+                    .all(|(p, e)| *e % 2 == 0 || (p % Natural::from(4u32)) != 3u32)
+            );
+        }
+    });
+
+    unsigned_pair_gen_var_27::<u32>().test_properties(|(x, y)| {
+        let x = Natural::from(x);
+        let y = Natural::from(y);
+        if x.two_squares().is_some() && y.two_squares().is_some() {
+            assert!((x * y).two_squares().is_some());
+        }
+    });
+}
+
+#[test]
+fn four_squares_properties() {
+    natural_gen().test_properties(|n| {
+        let (a, b, c, d) = n.four_squares();
+        assert!(a.is_valid());
+        assert!(b.is_valid());
+        assert!(c.is_valid());
+        assert!(d.is_valid());
+        assert!(a <= b && b <= c && c <= d);
+        assert_eq!(
+            (&a).square() + (&b).square() + (&c).square() + (&d).square(),
+            n
+        );
+        let (a_2, b_2, c_2, d_2) = (&n << 2u32).four_squares();
+        assert_eq!(
+            (a_2, b_2, c_2, d_2),
+            (a << 1u32, b << 1u32, c << 1u32, d << 1u32)
+        );
+    });
+}
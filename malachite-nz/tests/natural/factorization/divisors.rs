@@ -0,0 +1,158 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use itertools::Itertools;
+use malachite_base::iterators::comparison::is_strictly_ascending;
+use malachite_base::num::arithmetic::traits::{DivisibleBy, Gcd};
+use malachite_base::num::basic::traits::{One, Two, Zero};
+use malachite_base::num::factorization::traits::{
+    DivisorCount, DivisorSigma, Divisors, Factor, IsPrime,
+};
+use malachite_base::strings::ToDebugString;
+use malachite_base::test_util::generators::unsigned_pair_gen_var_27;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::natural_gen_var_9;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_divisor_count() {
+    let test = |n: &str, out: u64| {
+        let n = Natural::from_str(n).unwrap();
+        let count = n.divisor_count();
+        assert!(count.is_valid());
+        assert_eq!(count, out);
+        assert_eq!(Natural::divisor_count_from_factorization(&n.factor()), out);
+    };
+    test("1", 1);
+    test("2", 2);
+    test("360", 24);
+    test("18446744073709551615", 128);
+    test("18446744073709551617", 4);
+    test("99999999999999999999", 384);
+    test("1267650600228229401496703205376", 101);
+    test("1000000000000000000000000000000", 961);
+}
+
+#[test]
+fn test_divisor_sigma() {
+    let test = |n: &str, k: u64, out: &str| {
+        let n = Natural::from_str(n).unwrap();
+        let sigma = n.divisor_sigma(k);
+        assert!(sigma.is_valid());
+        assert_eq!(sigma.to_string(), out);
+        assert_eq!(
+            Natural::divisor_sigma_from_factorization(&n.factor(), k).to_string(),
+            out
+        );
+    };
+    test("1", 0, "1");
+    test("1", 1, "1");
+    test("1", 100, "1");
+    test("360", 0, "24");
+    test("360", 1, "1170");
+    test("360", 2, "201110");
+    test("18446744073709551617", 1, "18446811354131136516");
+    test(
+        "18446744073709551617",
+        2,
+        "340282366925465118592016216137989881860",
+    );
+    test("99999999999999999999", 1, "163688881007046979584");
+    test(
+        "1267650600228229401496703205376",
+        1,
+        "2535301200456458802993406410751",
+    );
+    test(
+        "1000000000000000000000000000000",
+        1,
+        "2499999998835846781730114984557",
+    );
+}
+
+#[test]
+fn test_divisors() {
+    let test = |n: &str, out: &str| {
+        let n = Natural::from_str(n).unwrap();
+        let divisors = n.divisors().collect_vec();
+        assert!(divisors.iter().all(Natural::is_valid));
+        assert_eq!(divisors.to_debug_string(), out);
+        assert_eq!(
+            Natural::divisors_from_factorization(&n.factor())
+                .collect_vec()
+                .to_debug_string(),
+            out
+        );
+    };
+    test("1", "[1]");
+    test("2", "[1, 2]");
+    test("60", "[1, 2, 3, 4, 5, 6, 10, 12, 15, 20, 30, 60]");
+    test(
+        "18446744073709551617",
+        "[1, 274177, 67280421310721, 18446744073709551617]",
+    );
+    test(
+        "1000000000000000006000000000000000009",
+        "[1, 1000000000000000003, 1000000000000000006000000000000000009]",
+    );
+}
+
+#[test]
+fn divisors_fail() {
+    assert_panic!(Natural::ZERO.divisor_count());
+    assert_panic!(Natural::ZERO.divisor_sigma(1));
+    assert_panic!(Natural::ZERO.divisors());
+    assert_panic!(Natural::divisor_count_from_factorization(&[(
+        Natural::TWO,
+        0
+    )]));
+    assert_panic!(Natural::divisor_sigma_from_factorization(
+        &[(Natural::TWO, 0)],
+        1
+    ));
+    assert_panic!(Natural::divisors_from_factorization(&[(Natural::TWO, 0)]));
+}
+
+#[test]
+fn divisors_properties() {
+    natural_gen_var_9().test_properties(|n| {
+        if n == 0u32 {
+            return;
+        }
+        let divisors = n.divisors().collect_vec();
+        assert!(is_strictly_ascending(divisors.iter()));
+        assert_eq!(divisors.first(), Some(&Natural::ONE));
+        assert_eq!(divisors.last(), Some(&n));
+        for d in &divisors {
+            assert!((&n).divisible_by(d));
+        }
+        let count = n.divisor_count();
+        assert_eq!(count, divisors.len());
+        assert_eq!(count == 2u32, n.is_prime());
+        assert_eq!(n.divisor_sigma(0), count);
+        let sigma = n.divisor_sigma(1);
+        assert_eq!(sigma, divisors.iter().sum::<Natural>());
+        let u = u64::try_from(&n).unwrap();
+        assert_eq!(count, u.divisor_count());
+        assert!(divisors.into_iter().eq(u.divisors().map(Natural::from)));
+        if let Ok(sigma) = u64::try_from(&sigma) {
+            assert_eq!(u.divisor_sigma(1), sigma);
+        }
+    });
+
+    unsigned_pair_gen_var_27::<u32>().test_properties(|(x, y)| {
+        let x = Natural::from(x) + Natural::ONE;
+        let y = Natural::from(y) + Natural::ONE;
+        let n = &x * &y;
+        if (&x).gcd(&y) == 1u32 {
+            assert_eq!(n.divisor_count(), x.divisor_count() * y.divisor_count());
+            assert_eq!(n.divisor_sigma(1), x.divisor_sigma(1) * y.divisor_sigma(1));
+        }
+    });
+}
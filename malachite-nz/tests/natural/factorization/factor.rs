@@ -0,0 +1,123 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::iterators::comparison::is_strictly_ascending;
+use malachite_base::num::arithmetic::traits::Pow;
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::factorization::traits::{Factor, IsPrime};
+use malachite_base::strings::ToDebugString;
+use malachite_base::test_util::generators::unsigned_triple_gen_var_19;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::natural_gen_var_9;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+fn factor_check(n: &Natural, factors: &[(Natural, u64)]) {
+    assert!(is_strictly_ascending(factors.iter().map(|(p, _)| p)));
+    let mut product = Natural::ONE;
+    for (p, e) in factors {
+        assert!(p.is_valid());
+        assert!(p.is_prime());
+        assert_ne!(*e, 0);
+        product *= p.pow(*e);
+    }
+    assert_eq!(product, *n);
+}
+
+#[test]
+fn test_factor() {
+    let test = |n: &str, out: &str| {
+        let n = Natural::from_str(n).unwrap();
+        let factors = n.factor();
+        assert_eq!(factors.to_debug_string(), out);
+        factor_check(&n, &factors);
+    };
+    // - fits in a u64
+    test("1", "[]");
+    test("2", "[(2, 1)]");
+    test("360", "[(2, 3), (3, 2), (5, 1)]");
+    test(
+        "18446744073709551615",
+        "[(3, 1), (5, 1), (17, 1), (257, 1), (641, 1), (65537, 1), (6700417, 1)]",
+    );
+    // - only small factors
+    test(
+        "99999999999999999999",
+        "[(3, 2), (11, 1), (41, 1), (101, 1), (271, 1), (3541, 1), (9091, 1), (27961, 1)]",
+    );
+    test(
+        "1606938044258990275541962092341162602522202993782792835301376",
+        "[(2, 200)]",
+    );
+    // - cofactor after trial division fits in a u64
+    test("18446744073709551617", "[(274177, 1), (67280421310721, 1)]");
+    // - cofactor after trial division is prime
+    test(
+        "618970019642690137449562111",
+        "[(618970019642690137449562111, 1)]",
+    );
+    test(
+        "1237940039285380274899124222",
+        "[(2, 1), (618970019642690137449562111, 1)]",
+    );
+    // - perfect power
+    test(
+        "1000000000000000009000000000000000027000000000000000027",
+        "[(1000000000000000003, 3)]",
+    );
+    // - factor found by Pollard's rho algorithm
+    test(
+        "1000003000000000000000000000057000171",
+        "[(1000003, 1), (1000000000000000000000000000057, 1)]",
+    );
+    // - factor found by ECM
+    test(
+        "147573952589676412927",
+        "[(193707721, 1), (761838257287, 1)]",
+    );
+    // - factor found by SIQS
+    test(
+        "340282366920938463463374607431768211457",
+        "[(59649589127497217, 1), (5704689200685129054721, 1)]",
+    );
+    test(
+        "1000000000000000012000000000000000027",
+        "[(1000000000000000003, 1), (1000000000000000009, 1)]",
+    );
+}
+
+#[test]
+fn factor_fail() {
+    assert_panic!(Natural::ZERO.factor());
+}
+
+#[test]
+fn factor_properties() {
+    natural_gen_var_9().test_properties(|n| {
+        if n == 0u32 {
+            return;
+        }
+        let factors = n.factor();
+        factor_check(&n, &factors);
+        let u = u64::try_from(&n).unwrap();
+        assert_eq!(
+            factors,
+            u.factor()
+                .into_iter()
+                .map(|(p, e)| (Natural::from(p), u64::from(e)))
+                .collect::<Vec<_>>()
+        );
+    });
+
+    unsigned_triple_gen_var_19::<u32>().test_properties(|(x, y, z)| {
+        let n = (Natural::from(x) + Natural::ONE)
+            * (Natural::from(y) + Natural::ONE)
+            * (Natural::from(z) + Natural::ONE);
+        factor_check(&n, &n.factor());
+    });
+}
@@ -0,0 +1,56 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::factorization::traits::IsPrime;
+use malachite_base::test_util::generators::unsigned_pair_gen_var_27;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::natural_gen_var_9;
+use std::str::FromStr;
+
+#[test]
+fn test_is_prime() {
+    let test = |n: &str, out| {
+        assert_eq!(Natural::from_str(n).unwrap().is_prime(), out);
+    };
+    test("0", false);
+    test("1", false);
+    test("2", true);
+    test("3", true);
+    test("4", false);
+    test("97", true);
+    test("18446744073709551557", true);
+    test("18446744073709551615", false);
+    // - fits in a u64
+    test("18446744073709551617", false);
+    // - has a small odd prime factor
+    test("18446744073709551621", false);
+    // - 2^67 - 1 = 193707721 * 761838257287
+    test("147573952589676412927", false);
+    // - 2^89 - 1
+    test("618970019642690137449562111", true);
+    // - 2^127 - 1
+    test("170141183460469231731687303715884105727", true);
+    // - (2^61 - 1)^2
+    test("5316911983139663487003542222693990401", false);
+    // - 10^20 + 39
+    test("100000000000000000039", true);
+    // - (10^18 + 3)(10^18 + 9)
+    test("1000000000000000012000000000000000027", false);
+}
+
+#[test]
+fn is_prime_properties() {
+    natural_gen_var_9().test_properties(|n| {
+        assert_eq!(n.is_prime(), u64::try_from(&n).unwrap().is_prime());
+    });
+
+    unsigned_pair_gen_var_27::<u64>().test_properties(|(x, y)| {
+        let n = (Natural::from(x) + Natural::from(2u32)) * (Natural::from(y) + Natural::from(2u32));
+        assert!(!n.is_prime());
+    });
+}
@@ -0,0 +1,71 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{Gcd, Square};
+use malachite_base::num::basic::traits::{One, Two, Zero};
+use malachite_base::num::factorization::traits::{Divisors, Factor, IsPrime, Mobius};
+use malachite_base::test_util::generators::unsigned_pair_gen_var_27;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::natural_gen_var_9;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_mobius() {
+    let test = |n: &str, out: i8| {
+        let n = Natural::from_str(n).unwrap();
+        assert_eq!(n.mobius(), out);
+        assert_eq!(Natural::mobius_from_factorization(&n.factor()), out);
+    };
+    test("1", 1);
+    test("2", -1);
+    test("6", 1);
+    test("360", 0);
+    test("18446744073709551615", -1);
+    test("18446744073709551617", 1);
+    test("99999999999999999999", 0);
+    test("618970019642690137449562111", -1);
+    test("1000000000000000012000000000000000027", 1);
+    test("1000000000000000006000000000000000009", 0);
+}
+
+#[test]
+fn mobius_fail() {
+    assert_panic!(Natural::ZERO.mobius());
+    assert_panic!(Natural::mobius_from_factorization(&[(Natural::TWO, 0)]));
+}
+
+#[test]
+fn mobius_properties() {
+    natural_gen_var_9().test_properties(|n| {
+        if n == 0u32 {
+            return;
+        }
+        let mobius = n.mobius();
+        assert!((-1..=1).contains(&mobius));
+        if n.is_prime() {
+            assert_eq!(mobius, -1);
+        }
+        assert_eq!((&n).square().mobius(), i8::from(n == 1u32));
+        assert_eq!(mobius, u64::try_from(&n).unwrap().mobius());
+        if n <= 1000000u32 {
+            // The sum of $\mu(d)$ over the divisors $d$ of $n$ is 1 if $n = 1$ and 0 otherwise.
+            let sum: i64 = n.divisors().map(|d| i64::from(d.mobius())).sum();
+            assert_eq!(sum, i64::from(n == Natural::ONE));
+        }
+    });
+
+    unsigned_pair_gen_var_27::<u32>().test_properties(|(x, y)| {
+        let x = Natural::from(x) + Natural::ONE;
+        let y = Natural::from(y) + Natural::ONE;
+        let n = &x * &y;
+        if (&x).gcd(&y) == 1u32 {
+            assert_eq!(n.mobius(), x.mobius() * y.mobius());
+        }
+    });
+}
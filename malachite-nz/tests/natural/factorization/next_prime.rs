@@ -0,0 +1,93 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::factorization::traits::{IsPrime, NextPrime, PreviousPrime};
+use malachite_base::test_util::generators::unsigned_gen_var_5;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::natural_gen_var_9;
+use std::str::FromStr;
+
+#[test]
+fn test_next_prime() {
+    let test = |n: &str, next: &str, previous: Option<&str>| {
+        let n = Natural::from_str(n).unwrap();
+        let next_prime = n.next_prime();
+        assert!(next_prime.is_valid());
+        assert_eq!(next_prime.to_string(), next);
+        let previous_prime = n.previous_prime();
+        if let Some(p) = &previous_prime {
+            assert!(p.is_valid());
+        }
+        assert_eq!(previous_prime.map(|p| p.to_string()).as_deref(), previous);
+    };
+    test("0", "2", None);
+    test("1", "2", None);
+    test("2", "3", None);
+    test("3", "5", Some("2"));
+    test("100", "101", Some("97"));
+    test(
+        "18446744073709551556",
+        "18446744073709551557",
+        Some("18446744073709551533"),
+    );
+    // - above the largest u64 prime
+    test(
+        "18446744073709551557",
+        "18446744073709551629",
+        Some("18446744073709551533"),
+    );
+    test(
+        "18446744073709551616",
+        "18446744073709551629",
+        Some("18446744073709551557"),
+    );
+    test(
+        "1000000000000000000000000000000",
+        "1000000000000000000000000000057",
+        Some("999999999999999999999999999989"),
+    );
+    test(
+        "1000000000000000000000000000057",
+        "1000000000000000000000000000099",
+        Some("999999999999999999999999999989"),
+    );
+}
+
+#[test]
+fn next_prime_properties() {
+    natural_gen_var_9().test_properties(|n| {
+        let p = n.next_prime();
+        assert!(p > n);
+        assert!(p.is_prime());
+        assert_eq!(p, u64::try_from(&n).unwrap().next_prime());
+        assert!(p == 2u32 || p.previous_prime().unwrap() <= n);
+        if let Some(q) = n.previous_prime() {
+            assert!(q < n);
+            assert!(q.is_prime());
+            assert!(q.next_prime() >= n);
+        } else {
+            assert!(n <= 2u32);
+        }
+    });
+
+    unsigned_gen_var_5::<u64>().test_properties(|k| {
+        let n = Natural::from(u64::MAX) + Natural::from(k);
+        let p = n.next_prime();
+        assert!(p > n);
+        assert!(p.is_prime());
+        let q = n.previous_prime().unwrap();
+        assert!(q < n);
+        assert!(q.is_prime());
+        let mut m = q + Natural::ONE;
+        while m < p {
+            assert!(m == n || !m.is_prime());
+            m += Natural::ONE;
+        }
+    });
+}
@@ -0,0 +1,63 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::Pow;
+use malachite_base::num::factorization::traits::{IsPrime, PrimePi, Primes};
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::natural_gen_var_9;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_prime_pi() {
+    let test = |n: &str, out: &str| {
+        let pi = Natural::from_str(n).unwrap().prime_pi();
+        assert!(pi.is_valid());
+        assert_eq!(pi.to_string(), out);
+    };
+    test("0", "0");
+    test("1", "0");
+    test("2", "1");
+    test("100", "25");
+    test("65536", "6542");
+    test("1000000", "78498");
+    test("10000000000", "455052511");
+}
+
+#[test]
+fn test_checked_prime_pi() {
+    let test = |n: &str, out: Option<&str>| {
+        let pi = Natural::from_str(n).unwrap().checked_prime_pi();
+        assert!(pi.as_ref().is_none_or(Natural::is_valid));
+        assert_eq!(pi.map(|pi| pi.to_string()).as_deref(), out);
+    };
+    test("0", Some("0"));
+    test("100", Some("25"));
+    test("10000000000", Some("455052511"));
+    test("18446744073709551616", None);
+    test("1000000000000000000000", None);
+}
+
+#[test]
+fn prime_pi_fail() {
+    assert_panic!(Natural::from(2u32).pow(64).prime_pi());
+}
+
+#[test]
+fn prime_pi_properties() {
+    natural_gen_var_9().test_properties(|n| {
+        let pi = n.prime_pi();
+        assert_eq!(n.checked_prime_pi().as_ref(), Some(&pi));
+        assert_eq!(pi, u64::try_from(&n).unwrap().prime_pi());
+        assert_eq!(pi, Natural::primes_less_than_or_equal_to(&n).count());
+        if n != 0u32 {
+            let n_minus_1 = &n - Natural::from(1u32);
+            assert_eq!(pi - n_minus_1.prime_pi(), u32::from(n.is_prime()));
+        }
+    });
+}
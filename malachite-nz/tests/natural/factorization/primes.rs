@@ -10,8 +10,9 @@ use itertools::Itertools;
 use malachite_base::iterators::comparison::is_strictly_ascending;
 use malachite_base::num::arithmetic::traits::SaturatingSub;
 use malachite_base::num::basic::traits::One;
-use malachite_base::num::factorization::traits::Primes;
+use malachite_base::num::factorization::traits::{IsPrime, Primes};
 use malachite_base::strings::ToDebugString;
+use malachite_base::test_util::generators::unsigned_pair_gen_var_27;
 use malachite_nz::natural::Natural;
 use malachite_nz::test_util::generators::natural_gen_var_9;
 use std::str::FromStr;
@@ -161,6 +162,75 @@ fn test_primes() {
     assert_eq!(ps.to_debug_string(), expected);
 }
 
+#[test]
+fn test_primes_in_range() {
+    let test = |a: &str, b: &str, out: &str| {
+        let a = Natural::from_str(a).unwrap();
+        let b = Natural::from_str(b).unwrap();
+        let ps = Natural::primes_in_range(&a, &b).collect_vec();
+        assert!(ps.iter().all(Natural::is_valid));
+        assert_eq!(ps.to_debug_string(), out);
+    };
+    test("0", "0", "[]");
+    test("0", "2", "[]");
+    test("0", "3", "[2]");
+    test("2", "3", "[2]");
+    test("3", "3", "[]");
+    test("10", "5", "[]");
+    test("0", "30", "[2, 3, 5, 7, 11, 13, 17, 19, 23, 29]");
+    test("24", "29", "[]");
+    test("24", "30", "[29]");
+    test("100", "130", "[101, 103, 107, 109, 113, 127]");
+    test(
+        "18446744073709551500",
+        "18446744073709551700",
+        "[18446744073709551521, 18446744073709551533, 18446744073709551557, \
+        18446744073709551629, 18446744073709551653, 18446744073709551667, 18446744073709551697]",
+    );
+    test(
+        "1000000000000000000000000000000",
+        "1000000000000000000000000000300",
+        "[1000000000000000000000000000057, 1000000000000000000000000000099, \
+        1000000000000000000000000000211, 1000000000000000000000000000231, \
+        1000000000000000000000000000271]",
+    );
+}
+
+#[test]
+fn primes_in_range_properties() {
+    unsigned_pair_gen_var_27::<u16>().test_properties(|(a, b)| {
+        let a_natural = Natural::from(a);
+        let b_natural = Natural::from(b);
+        let ps = Natural::primes_in_range(&a_natural, &b_natural).collect_vec();
+        assert!(ps.iter().all(Natural::is_valid));
+        assert!(is_strictly_ascending(ps.iter()));
+        assert_eq!(
+            ps,
+            Natural::primes_less_than(&b_natural)
+                .filter(|p| *p >= a_natural)
+                .collect_vec()
+        );
+    });
+
+    unsigned_pair_gen_var_27::<u8>().test_properties(|(a, b)| {
+        let offset = Natural::from(u64::MAX);
+        let a_natural = &offset + Natural::from(a);
+        let b_natural = &offset + Natural::from(b);
+        let ps = Natural::primes_in_range(&a_natural, &b_natural).collect_vec();
+        assert!(is_strictly_ascending(ps.iter()));
+        let mut n = a_natural;
+        let mut i = 0;
+        while n < b_natural {
+            if n.is_prime() {
+                assert_eq!(ps[i], n);
+                i += 1;
+            }
+            n += Natural::ONE;
+        }
+        assert_eq!(i, ps.len());
+    });
+}
+
 #[test]
 fn primes_less_than_properties() {
     natural_gen_var_9().test_properties(|n| {
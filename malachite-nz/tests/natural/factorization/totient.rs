@@ -0,0 +1,114 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{DivisibleBy, Gcd, Lcm};
+use malachite_base::num::basic::traits::{One, Two, Zero};
+use malachite_base::num::factorization::traits::{CarmichaelLambda, Factor, IsPrime, Totient};
+use malachite_base::test_util::generators::unsigned_pair_gen_var_27;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::natural_gen_var_9;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_totient() {
+    let test = |n: &str, out: &str| {
+        let n = Natural::from_str(n).unwrap();
+        let totient = n.totient();
+        assert!(totient.is_valid());
+        assert_eq!(totient.to_string(), out);
+        assert_eq!(
+            Natural::totient_from_factorization(&n.factor()).to_string(),
+            out
+        );
+    };
+    test("1", "1");
+    test("2", "1");
+    test("360", "96");
+    test("18446744073709551615", "9208981628670443520");
+    test("18446744073709551617", "18446676793287966720");
+    test("99999999999999999999", "58301444908800000000");
+    test(
+        "1267650600228229401496703205376",
+        "633825300114114700748351602688",
+    );
+    test(
+        "1000000000000000000000000000000",
+        "400000000000000000000000000000",
+    );
+}
+
+#[test]
+fn test_carmichael_lambda() {
+    let test = |n: &str, out: &str| {
+        let n = Natural::from_str(n).unwrap();
+        let lambda = n.carmichael_lambda();
+        assert!(lambda.is_valid());
+        assert_eq!(lambda.to_string(), out);
+        assert_eq!(
+            Natural::carmichael_lambda_from_factorization(&n.factor()).to_string(),
+            out
+        );
+    };
+    test("1", "1");
+    test("2", "1");
+    test("360", "12");
+    test("18446744073709551615", "17153064960");
+    test("18446744073709551617", "72057331223781120");
+    test("99999999999999999999", "7497613800");
+    test(
+        "1267650600228229401496703205376",
+        "316912650057057350374175801344",
+    );
+    test(
+        "1000000000000000000000000000000",
+        "50000000000000000000000000000",
+    );
+}
+
+#[test]
+fn totient_fail() {
+    assert_panic!(Natural::ZERO.totient());
+    assert_panic!(Natural::ZERO.carmichael_lambda());
+    assert_panic!(Natural::totient_from_factorization(&[(Natural::TWO, 0)]));
+    assert_panic!(Natural::carmichael_lambda_from_factorization(&[(
+        Natural::TWO,
+        0
+    )]));
+}
+
+#[test]
+fn totient_properties() {
+    natural_gen_var_9().test_properties(|n| {
+        if n == 0u32 {
+            return;
+        }
+        let totient = n.totient();
+        assert!(totient.is_valid());
+        let lambda = n.carmichael_lambda();
+        assert!(lambda.is_valid());
+        assert!((&totient).divisible_by(&lambda));
+        assert_eq!(totient == &n - Natural::ONE, n.is_prime());
+        let u = u64::try_from(&n).unwrap();
+        assert_eq!(totient, u.totient());
+        assert_eq!(lambda, u.carmichael_lambda());
+    });
+
+    unsigned_pair_gen_var_27::<u32>().test_properties(|(x, y)| {
+        let x = Natural::from(x) + Natural::ONE;
+        let y = Natural::from(y) + Natural::ONE;
+        let n = &x * &y;
+        if (&x).gcd(&y) == 1u32 {
+            assert_eq!(n.totient(), x.totient() * y.totient());
+            assert_eq!(
+                n.carmichael_lambda(),
+                x.carmichael_lambda().lcm(y.carmichael_lambda())
+            );
+        }
+    });
+}
@@ -0,0 +1,297 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::PAdic;
+use core::cmp::min;
+use core::ops::{Add, AddAssign};
+use malachite_base::num::arithmetic::traits::{ModAdd, ModSub, Pow};
+use malachite_base::num::basic::traits::Zero;
+use malachite_nz::natural::Natural;
+
+// Computes `x + y`, or `x - y` if `subtract` is true. Both terms are reduced modulo $p^{n-m}$, where
+// $n$ is the smaller absolute precision and $m$ the smaller valuation; digits beyond the absolute
+// precision of either input are unknown, so they are discarded.
+pub(crate) fn add_or_sub(x: &PAdic, y: &PAdic, subtract: bool) -> PAdic {
+    x.assert_same_prime(y);
+    let n = min(x.absolute_precision(), y.absolute_precision());
+    let m = min(x.valuation, y.valuation);
+    if n <= m {
+        return PAdic::from_reduced(x.p.clone(), n, Natural::ZERO, 0);
+    }
+    let precision = n.abs_diff(m);
+    let modulus = (&x.p).pow(precision);
+    let term = |z: &PAdic| {
+        let shift = z.valuation.abs_diff(m);
+        if shift >= precision {
+            Natural::ZERO
+        } else {
+            (&z.unit * (&z.p).pow(shift)) % &modulus
+        }
+    };
+    let a = term(x);
+    let b = term(y);
+    let sum = if subtract {
+        a.mod_sub(b, &modulus)
+    } else {
+        a.mod_add(b, &modulus)
+    };
+    PAdic::from_reduced(x.p.clone(), m, sum, precision)
+}
+
+impl Add<PAdic> for PAdic {
+    type Output = PAdic;
+
+    /// Adds two [`PAdic`]s, taking both by value.
+    ///
+    /// The absolute precision of the sum is the minimum of the absolute precisions of the inputs.
+    ///
+    /// $$
+    /// f(x, y) = x + y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.precision(), other.precision()) * self.p().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` have different primes.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_padic::PAdic;
+    /// use malachite_q::Rational;
+    ///
+    /// let p = Natural::from(5u32);
+    /// let x = PAdic::from_rational(Rational::from(7), p.clone(), 4);
+    /// let y = PAdic::from_rational(Rational::from(-2), p.clone(), 2);
+    /// assert_eq!((x + y).to_string(), "5 + O(5^2)");
+    ///
+    /// let x = PAdic::from_rational(Rational::from_signeds(1, 5), p.clone(), 3);
+    /// let y = PAdic::from_rational(Rational::from(3), p.clone(), 4);
+    /// assert_eq!((x + y).to_string(), "5^-1 + 3 + O(5^2)");
+    /// ```
+    #[inline]
+    fn add(self, other: PAdic) -> PAdic {
+        &self + &other
+    }
+}
+
+impl Add<&PAdic> for PAdic {
+    type Output = PAdic;
+
+    /// Adds two [`PAdic`]s, taking the first by value and the second by reference.
+    ///
+    /// The absolute precision of the sum is the minimum of the absolute precisions of the inputs.
+    ///
+    /// $$
+    /// f(x, y) = x + y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.precision(), other.precision()) * self.p().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` have different primes.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_padic::PAdic;
+    /// use malachite_q::Rational;
+    ///
+    /// let p = Natural::from(5u32);
+    /// let x = PAdic::from_rational(Rational::from(7), p.clone(), 4);
+    /// let y = PAdic::from_rational(Rational::from(-2), p.clone(), 2);
+    /// assert_eq!((x + &y).to_string(), "5 + O(5^2)");
+    ///
+    /// let x = PAdic::from_rational(Rational::from_signeds(1, 5), p.clone(), 3);
+    /// let y = PAdic::from_rational(Rational::from(3), p.clone(), 4);
+    /// assert_eq!((x + &y).to_string(), "5^-1 + 3 + O(5^2)");
+    /// ```
+    #[inline]
+    fn add(self, other: &PAdic) -> PAdic {
+        &self + other
+    }
+}
+
+impl Add<PAdic> for &PAdic {
+    type Output = PAdic;
+
+    /// Adds two [`PAdic`]s, taking the first by reference and the second by value.
+    ///
+    /// The absolute precision of the sum is the minimum of the absolute precisions of the inputs.
+    ///
+    /// $$
+    /// f(x, y) = x + y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.precision(), other.precision()) * self.p().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` have different primes.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_padic::PAdic;
+    /// use malachite_q::Rational;
+    ///
+    /// let p = Natural::from(5u32);
+    /// let x = PAdic::from_rational(Rational::from(7), p.clone(), 4);
+    /// let y = PAdic::from_rational(Rational::from(-2), p.clone(), 2);
+    /// assert_eq!((&x + y).to_string(), "5 + O(5^2)");
+    ///
+    /// let x = PAdic::from_rational(Rational::from_signeds(1, 5), p.clone(), 3);
+    /// let y = PAdic::from_rational(Rational::from(3), p.clone(), 4);
+    /// assert_eq!((&x + y).to_string(), "5^-1 + 3 + O(5^2)");
+    /// ```
+    #[inline]
+    fn add(self, other: PAdic) -> PAdic {
+        self + &other
+    }
+}
+
+impl Add<&PAdic> for &PAdic {
+    type Output = PAdic;
+
+    /// Adds two [`PAdic`]s, taking both by reference.
+    ///
+    /// The absolute precision of the sum is the minimum of the absolute precisions of the inputs.
+    ///
+    /// $$
+    /// f(x, y) = x + y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.precision(), other.precision()) * self.p().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` have different primes.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_padic::PAdic;
+    /// use malachite_q::Rational;
+    ///
+    /// let p = Natural::from(5u32);
+    /// let x = PAdic::from_rational(Rational::from(7), p.clone(), 4);
+    /// let y = PAdic::from_rational(Rational::from(-2), p.clone(), 2);
+    /// assert_eq!((&x + &y).to_string(), "5 + O(5^2)");
+    ///
+    /// let x = PAdic::from_rational(Rational::from_signeds(1, 5), p.clone(), 3);
+    /// let y = PAdic::from_rational(Rational::from(3), p.clone(), 4);
+    /// assert_eq!((&x + &y).to_string(), "5^-1 + 3 + O(5^2)");
+    /// ```
+    fn add(self, other: &PAdic) -> PAdic {
+        add_or_sub(self, other, false)
+    }
+}
+
+impl AddAssign<PAdic> for PAdic {
+    /// Adds a [`PAdic`] to a [`PAdic`] in place, taking the [`PAdic`] on the right-hand side by value.
+    ///
+    /// The absolute precision of the sum is the minimum of the absolute precisions of the inputs.
+    ///
+    /// $$
+    /// x \gets x + y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.precision(), other.precision()) * self.p().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` have different primes.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_padic::PAdic;
+    /// use malachite_q::Rational;
+    ///
+    /// let p = Natural::from(5u32);
+    /// let mut x = PAdic::from_rational(Rational::from(7), p.clone(), 4);
+    /// x += PAdic::from_rational(Rational::from(-2), p.clone(), 2);
+    /// assert_eq!(x.to_string(), "5 + O(5^2)");
+    ///
+    /// let mut x = PAdic::from_rational(Rational::from_signeds(1, 5), p.clone(), 3);
+    /// x += PAdic::from_rational(Rational::from(3), p.clone(), 4);
+    /// assert_eq!(x.to_string(), "5^-1 + 3 + O(5^2)");
+    /// ```
+    #[inline]
+    fn add_assign(&mut self, other: PAdic) {
+        *self = &*self + &other;
+    }
+}
+
+impl AddAssign<&PAdic> for PAdic {
+    /// Adds a [`PAdic`] to a [`PAdic`] in place, taking the [`PAdic`] on the right-hand side by reference.
+    ///
+    /// The absolute precision of the sum is the minimum of the absolute precisions of the inputs.
+    ///
+    /// $$
+    /// x \gets x + y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.precision(), other.precision()) * self.p().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` have different primes.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_padic::PAdic;
+    /// use malachite_q::Rational;
+    ///
+    /// let p = Natural::from(5u32);
+    /// let mut x = PAdic::from_rational(Rational::from(7), p.clone(), 4);
+    /// x += &PAdic::from_rational(Rational::from(-2), p.clone(), 2);
+    /// assert_eq!(x.to_string(), "5 + O(5^2)");
+    ///
+    /// let mut x = PAdic::from_rational(Rational::from_signeds(1, 5), p.clone(), 3);
+    /// x += &PAdic::from_rational(Rational::from(3), p.clone(), 4);
+    /// assert_eq!(x.to_string(), "5^-1 + 3 + O(5^2)");
+    /// ```
+    #[inline]
+    fn add_assign(&mut self, other: &PAdic) {
+        *self = &*self + other;
+    }
+}
@@ -0,0 +1,276 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::PAdic;
+use core::ops::{Div, DivAssign};
+use malachite_base::num::arithmetic::traits::Reciprocal;
+
+impl Div<PAdic> for PAdic {
+    type Output = PAdic;
+
+    /// Divides a [`PAdic`] by another [`PAdic`], taking both by value.
+    ///
+    /// The valuation of the quotient is the difference of the valuations of the inputs, and its
+    /// precision is the minimum of their precisions.
+    ///
+    /// $$
+    /// f(x, y) = x / y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.precision(), other.precision()) * self.p().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` have different primes, if `other` is zero, or if the valuation
+    /// of the quotient is out of range.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_padic::PAdic;
+    /// use malachite_q::Rational;
+    ///
+    /// let p = Natural::from(5u32);
+    /// let x = PAdic::from_rational(Rational::from(7), p.clone(), 4);
+    /// let y = PAdic::from_rational(Rational::from(3), p.clone(), 4);
+    /// assert_eq!((x / y).to_string(), "4 + 3*5 + 5^2 + 3*5^3 + O(5^4)");
+    ///
+    /// let x = PAdic::from_rational(Rational::from(1), p.clone(), 3);
+    /// let y = PAdic::from_rational(Rational::from(25), p.clone(), 3);
+    /// assert_eq!((x / y).to_string(), "5^-2 + O(5)");
+    /// ```
+    #[inline]
+    fn div(self, other: PAdic) -> PAdic {
+        &self / &other
+    }
+}
+
+impl Div<&PAdic> for PAdic {
+    type Output = PAdic;
+
+    /// Divides a [`PAdic`] by another [`PAdic`], taking the first by value and the second by reference.
+    ///
+    /// The valuation of the quotient is the difference of the valuations of the inputs, and its
+    /// precision is the minimum of their precisions.
+    ///
+    /// $$
+    /// f(x, y) = x / y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.precision(), other.precision()) * self.p().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` have different primes, if `other` is zero, or if the valuation
+    /// of the quotient is out of range.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_padic::PAdic;
+    /// use malachite_q::Rational;
+    ///
+    /// let p = Natural::from(5u32);
+    /// let x = PAdic::from_rational(Rational::from(7), p.clone(), 4);
+    /// let y = PAdic::from_rational(Rational::from(3), p.clone(), 4);
+    /// assert_eq!((x / &y).to_string(), "4 + 3*5 + 5^2 + 3*5^3 + O(5^4)");
+    ///
+    /// let x = PAdic::from_rational(Rational::from(1), p.clone(), 3);
+    /// let y = PAdic::from_rational(Rational::from(25), p.clone(), 3);
+    /// assert_eq!((x / &y).to_string(), "5^-2 + O(5)");
+    /// ```
+    #[inline]
+    fn div(self, other: &PAdic) -> PAdic {
+        &self / other
+    }
+}
+
+impl Div<PAdic> for &PAdic {
+    type Output = PAdic;
+
+    /// Divides a [`PAdic`] by another [`PAdic`], taking the first by reference and the second by value.
+    ///
+    /// The valuation of the quotient is the difference of the valuations of the inputs, and its
+    /// precision is the minimum of their precisions.
+    ///
+    /// $$
+    /// f(x, y) = x / y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.precision(), other.precision()) * self.p().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` have different primes, if `other` is zero, or if the valuation
+    /// of the quotient is out of range.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_padic::PAdic;
+    /// use malachite_q::Rational;
+    ///
+    /// let p = Natural::from(5u32);
+    /// let x = PAdic::from_rational(Rational::from(7), p.clone(), 4);
+    /// let y = PAdic::from_rational(Rational::from(3), p.clone(), 4);
+    /// assert_eq!((&x / y).to_string(), "4 + 3*5 + 5^2 + 3*5^3 + O(5^4)");
+    ///
+    /// let x = PAdic::from_rational(Rational::from(1), p.clone(), 3);
+    /// let y = PAdic::from_rational(Rational::from(25), p.clone(), 3);
+    /// assert_eq!((&x / y).to_string(), "5^-2 + O(5)");
+    /// ```
+    #[inline]
+    fn div(self, other: PAdic) -> PAdic {
+        self / &other
+    }
+}
+
+impl Div<&PAdic> for &PAdic {
+    type Output = PAdic;
+
+    /// Divides a [`PAdic`] by another [`PAdic`], taking both by reference.
+    ///
+    /// The valuation of the quotient is the difference of the valuations of the inputs, and its
+    /// precision is the minimum of their precisions.
+    ///
+    /// $$
+    /// f(x, y) = x / y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.precision(), other.precision()) * self.p().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` have different primes, if `other` is zero, or if the valuation
+    /// of the quotient is out of range.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_padic::PAdic;
+    /// use malachite_q::Rational;
+    ///
+    /// let p = Natural::from(5u32);
+    /// let x = PAdic::from_rational(Rational::from(7), p.clone(), 4);
+    /// let y = PAdic::from_rational(Rational::from(3), p.clone(), 4);
+    /// assert_eq!((&x / &y).to_string(), "4 + 3*5 + 5^2 + 3*5^3 + O(5^4)");
+    ///
+    /// let x = PAdic::from_rational(Rational::from(1), p.clone(), 3);
+    /// let y = PAdic::from_rational(Rational::from(25), p.clone(), 3);
+    /// assert_eq!((&x / &y).to_string(), "5^-2 + O(5)");
+    /// ```
+    fn div(self, other: &PAdic) -> PAdic {
+        self * other.reciprocal()
+    }
+}
+
+impl DivAssign<PAdic> for PAdic {
+    /// Divides a [`PAdic`] by a [`PAdic`] in place, taking the [`PAdic`] on the right-hand side by value.
+    ///
+    /// The valuation of the quotient is the difference of the valuations of the inputs, and its
+    /// precision is the minimum of their precisions.
+    ///
+    /// $$
+    /// x \gets x / y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.precision(), other.precision()) * self.p().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` have different primes, if `other` is zero, or if the valuation
+    /// of the quotient is out of range.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_padic::PAdic;
+    /// use malachite_q::Rational;
+    ///
+    /// let p = Natural::from(5u32);
+    /// let mut x = PAdic::from_rational(Rational::from(7), p.clone(), 4);
+    /// x /= PAdic::from_rational(Rational::from(3), p.clone(), 4);
+    /// assert_eq!(x.to_string(), "4 + 3*5 + 5^2 + 3*5^3 + O(5^4)");
+    ///
+    /// let mut x = PAdic::from_rational(Rational::from(1), p.clone(), 3);
+    /// x /= PAdic::from_rational(Rational::from(25), p.clone(), 3);
+    /// assert_eq!(x.to_string(), "5^-2 + O(5)");
+    /// ```
+    #[inline]
+    fn div_assign(&mut self, other: PAdic) {
+        *self = &*self / &other;
+    }
+}
+
+impl DivAssign<&PAdic> for PAdic {
+    /// Divides a [`PAdic`] by a [`PAdic`] in place, taking the [`PAdic`] on the right-hand side by reference.
+    ///
+    /// The valuation of the quotient is the difference of the valuations of the inputs, and its
+    /// precision is the minimum of their precisions.
+    ///
+    /// $$
+    /// x \gets x / y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.precision(), other.precision()) * self.p().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` have different primes, if `other` is zero, or if the valuation
+    /// of the quotient is out of range.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_padic::PAdic;
+    /// use malachite_q::Rational;
+    ///
+    /// let p = Natural::from(5u32);
+    /// let mut x = PAdic::from_rational(Rational::from(7), p.clone(), 4);
+    /// x /= &PAdic::from_rational(Rational::from(3), p.clone(), 4);
+    /// assert_eq!(x.to_string(), "4 + 3*5 + 5^2 + 3*5^3 + O(5^4)");
+    ///
+    /// let mut x = PAdic::from_rational(Rational::from(1), p.clone(), 3);
+    /// x /= &PAdic::from_rational(Rational::from(25), p.clone(), 3);
+    /// assert_eq!(x.to_string(), "5^-2 + O(5)");
+    /// ```
+    #[inline]
+    fn div_assign(&mut self, other: &PAdic) {
+        *self = &*self / other;
+    }
+}
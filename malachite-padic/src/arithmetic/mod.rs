@@ -0,0 +1,25 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+/// Addition of [`PAdic`](crate::PAdic)s.
+pub mod add;
+/// Division of [`PAdic`](crate::PAdic)s.
+pub mod div;
+/// Multiplication of [`PAdic`](crate::PAdic)s.
+pub mod mul;
+/// Negation of [`PAdic`](crate::PAdic)s.
+pub mod neg;
+/// Implementations of [`Reciprocal`](malachite_base::num::arithmetic::traits::Reciprocal) and
+/// [`ReciprocalAssign`](malachite_base::num::arithmetic::traits::ReciprocalAssign), traits for
+/// computing the reciprocal of a number.
+pub mod reciprocal;
+/// An implementation of [`CheckedSqrt`](malachite_base::num::arithmetic::traits::CheckedSqrt), a
+/// trait for computing the square root of a number, using Hensel lifting.
+pub mod sqrt;
+/// Subtraction of [`PAdic`](crate::PAdic)s.
+pub mod sub;
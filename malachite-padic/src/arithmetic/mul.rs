@@ -0,0 +1,295 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::{absolute_precision, PAdic};
+use core::cmp::min;
+use core::ops::{Mul, MulAssign};
+use malachite_base::num::arithmetic::traits::Pow;
+use malachite_base::num::basic::traits::Zero;
+use malachite_nz::natural::Natural;
+
+impl Mul<PAdic> for PAdic {
+    type Output = PAdic;
+
+    /// Multiplies two [`PAdic`]s, taking both by value.
+    ///
+    /// The valuation of the product is the sum of the valuations of the inputs, and its precision is
+    /// the minimum of their precisions.
+    ///
+    /// $$
+    /// f(x, y) = x \cdot y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.precision(), other.precision()) * self.p().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` have different primes, or if the valuation of the product is
+    /// out of range.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_padic::PAdic;
+    /// use malachite_q::Rational;
+    ///
+    /// let p = Natural::from(5u32);
+    /// let x = PAdic::from_rational(Rational::from(7), p.clone(), 4);
+    /// let y = PAdic::from_rational(Rational::from_signeds(1, 5), p.clone(), 2);
+    /// assert_eq!((x * y).to_string(), "2*5^-1 + 1 + O(5)");
+    ///
+    /// let x = PAdic::from_rational(Rational::from(50), p.clone(), 3);
+    /// let y = PAdic::from_rational(Rational::from_signeds(1, 3), p.clone(), 4);
+    /// assert_eq!((x * y).to_string(), "4*5^2 + 5^3 + 3*5^4 + O(5^5)");
+    /// ```
+    #[inline]
+    fn mul(self, other: PAdic) -> PAdic {
+        &self * &other
+    }
+}
+
+impl Mul<&PAdic> for PAdic {
+    type Output = PAdic;
+
+    /// Multiplies two [`PAdic`]s, taking the first by value and the second by reference.
+    ///
+    /// The valuation of the product is the sum of the valuations of the inputs, and its precision is
+    /// the minimum of their precisions.
+    ///
+    /// $$
+    /// f(x, y) = x \cdot y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.precision(), other.precision()) * self.p().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` have different primes, or if the valuation of the product is
+    /// out of range.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_padic::PAdic;
+    /// use malachite_q::Rational;
+    ///
+    /// let p = Natural::from(5u32);
+    /// let x = PAdic::from_rational(Rational::from(7), p.clone(), 4);
+    /// let y = PAdic::from_rational(Rational::from_signeds(1, 5), p.clone(), 2);
+    /// assert_eq!((x * &y).to_string(), "2*5^-1 + 1 + O(5)");
+    ///
+    /// let x = PAdic::from_rational(Rational::from(50), p.clone(), 3);
+    /// let y = PAdic::from_rational(Rational::from_signeds(1, 3), p.clone(), 4);
+    /// assert_eq!((x * &y).to_string(), "4*5^2 + 5^3 + 3*5^4 + O(5^5)");
+    /// ```
+    #[inline]
+    fn mul(self, other: &PAdic) -> PAdic {
+        &self * other
+    }
+}
+
+impl Mul<PAdic> for &PAdic {
+    type Output = PAdic;
+
+    /// Multiplies two [`PAdic`]s, taking the first by reference and the second by value.
+    ///
+    /// The valuation of the product is the sum of the valuations of the inputs, and its precision is
+    /// the minimum of their precisions.
+    ///
+    /// $$
+    /// f(x, y) = x \cdot y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.precision(), other.precision()) * self.p().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` have different primes, or if the valuation of the product is
+    /// out of range.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_padic::PAdic;
+    /// use malachite_q::Rational;
+    ///
+    /// let p = Natural::from(5u32);
+    /// let x = PAdic::from_rational(Rational::from(7), p.clone(), 4);
+    /// let y = PAdic::from_rational(Rational::from_signeds(1, 5), p.clone(), 2);
+    /// assert_eq!((&x * y).to_string(), "2*5^-1 + 1 + O(5)");
+    ///
+    /// let x = PAdic::from_rational(Rational::from(50), p.clone(), 3);
+    /// let y = PAdic::from_rational(Rational::from_signeds(1, 3), p.clone(), 4);
+    /// assert_eq!((&x * y).to_string(), "4*5^2 + 5^3 + 3*5^4 + O(5^5)");
+    /// ```
+    #[inline]
+    fn mul(self, other: PAdic) -> PAdic {
+        self * &other
+    }
+}
+
+impl Mul<&PAdic> for &PAdic {
+    type Output = PAdic;
+
+    /// Multiplies two [`PAdic`]s, taking both by reference.
+    ///
+    /// The valuation of the product is the sum of the valuations of the inputs, and its precision is
+    /// the minimum of their precisions.
+    ///
+    /// $$
+    /// f(x, y) = x \cdot y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.precision(), other.precision()) * self.p().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` have different primes, or if the valuation of the product is
+    /// out of range.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_padic::PAdic;
+    /// use malachite_q::Rational;
+    ///
+    /// let p = Natural::from(5u32);
+    /// let x = PAdic::from_rational(Rational::from(7), p.clone(), 4);
+    /// let y = PAdic::from_rational(Rational::from_signeds(1, 5), p.clone(), 2);
+    /// assert_eq!((&x * &y).to_string(), "2*5^-1 + 1 + O(5)");
+    ///
+    /// let x = PAdic::from_rational(Rational::from(50), p.clone(), 3);
+    /// let y = PAdic::from_rational(Rational::from_signeds(1, 3), p.clone(), 4);
+    /// assert_eq!((&x * &y).to_string(), "4*5^2 + 5^3 + 3*5^4 + O(5^5)");
+    /// ```
+    fn mul(self, other: &PAdic) -> PAdic {
+        self.assert_same_prime(other);
+        let valuation = self
+            .valuation
+            .checked_add(other.valuation)
+            .expect("The valuation of a PAdic product is out of range");
+        let precision = min(self.precision, other.precision);
+        let unit = if precision == 0 {
+            Natural::ZERO
+        } else {
+            (&self.unit * &other.unit) % (&self.p).pow(precision)
+        };
+        absolute_precision(valuation, precision);
+        PAdic {
+            p: self.p.clone(),
+            valuation,
+            unit,
+            precision,
+        }
+    }
+}
+
+impl MulAssign<PAdic> for PAdic {
+    /// Multiplies a [`PAdic`] by a [`PAdic`] in place, taking the [`PAdic`] on the right-hand side by value.
+    ///
+    /// The valuation of the product is the sum of the valuations of the inputs, and its precision is
+    /// the minimum of their precisions.
+    ///
+    /// $$
+    /// x \gets x \cdot y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.precision(), other.precision()) * self.p().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` have different primes, or if the valuation of the product is
+    /// out of range.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_padic::PAdic;
+    /// use malachite_q::Rational;
+    ///
+    /// let p = Natural::from(5u32);
+    /// let mut x = PAdic::from_rational(Rational::from(7), p.clone(), 4);
+    /// x *= PAdic::from_rational(Rational::from_signeds(1, 5), p.clone(), 2);
+    /// assert_eq!(x.to_string(), "2*5^-1 + 1 + O(5)");
+    ///
+    /// let mut x = PAdic::from_rational(Rational::from(50), p.clone(), 3);
+    /// x *= PAdic::from_rational(Rational::from_signeds(1, 3), p.clone(), 4);
+    /// assert_eq!(x.to_string(), "4*5^2 + 5^3 + 3*5^4 + O(5^5)");
+    /// ```
+    #[inline]
+    fn mul_assign(&mut self, other: PAdic) {
+        *self *= &other;
+    }
+}
+
+impl MulAssign<&PAdic> for PAdic {
+    /// Multiplies a [`PAdic`] by a [`PAdic`] in place, taking the [`PAdic`] on the right-hand side by reference.
+    ///
+    /// The valuation of the product is the sum of the valuations of the inputs, and its precision is
+    /// the minimum of their precisions.
+    ///
+    /// $$
+    /// x \gets x \cdot y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.precision(), other.precision()) * self.p().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` have different primes, or if the valuation of the product is
+    /// out of range.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_padic::PAdic;
+    /// use malachite_q::Rational;
+    ///
+    /// let p = Natural::from(5u32);
+    /// let mut x = PAdic::from_rational(Rational::from(7), p.clone(), 4);
+    /// x *= &PAdic::from_rational(Rational::from_signeds(1, 5), p.clone(), 2);
+    /// assert_eq!(x.to_string(), "2*5^-1 + 1 + O(5)");
+    ///
+    /// let mut x = PAdic::from_rational(Rational::from(50), p.clone(), 3);
+    /// x *= &PAdic::from_rational(Rational::from_signeds(1, 3), p.clone(), 4);
+    /// assert_eq!(x.to_string(), "4*5^2 + 5^3 + 3*5^4 + O(5^5)");
+    /// ```
+    fn mul_assign(&mut self, other: &PAdic) {
+        *self = &*self * other;
+    }
+}
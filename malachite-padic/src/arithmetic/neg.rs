@@ -0,0 +1,142 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::PAdic;
+use core::ops::Neg;
+use malachite_base::num::arithmetic::traits::{NegAssign, Pow};
+
+impl Neg for PAdic {
+    type Output = PAdic;
+
+    /// Negates a [`PAdic`], taking it by value.
+    ///
+    /// The valuation and precision of the result are the same as those of the input.
+    ///
+    /// $$
+    /// f(x) = -x.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.precision() * self.p().significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_padic::PAdic;
+    /// use malachite_q::Rational;
+    ///
+    /// let p = Natural::from(5u32);
+    /// assert_eq!((-PAdic::zero(p.clone(), 3)).to_string(), "O(5^3)");
+    /// assert_eq!(
+    ///     (-PAdic::from_rational(Rational::from(1), p.clone(), 3)).to_string(),
+    ///     "4 + 4*5 + 4*5^2 + O(5^3)"
+    /// );
+    /// assert_eq!(
+    ///     (-PAdic::from_rational(Rational::from_signeds(7, 5), p, 3)).to_string(),
+    ///     "3*5^-1 + 3 + 4*5 + O(5^2)"
+    /// );
+    /// ```
+    #[inline]
+    fn neg(mut self) -> PAdic {
+        self.neg_assign();
+        self
+    }
+}
+
+impl Neg for &PAdic {
+    type Output = PAdic;
+
+    /// Negates a [`PAdic`], taking it by reference.
+    ///
+    /// The valuation and precision of the result are the same as those of the input.
+    ///
+    /// $$
+    /// f(x) = -x.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.precision() * self.p().significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_padic::PAdic;
+    /// use malachite_q::Rational;
+    ///
+    /// let p = Natural::from(5u32);
+    /// assert_eq!((-&PAdic::zero(p.clone(), 3)).to_string(), "O(5^3)");
+    /// assert_eq!(
+    ///     (-&PAdic::from_rational(Rational::from(1), p.clone(), 3)).to_string(),
+    ///     "4 + 4*5 + 4*5^2 + O(5^3)"
+    /// );
+    /// assert_eq!(
+    ///     (-&PAdic::from_rational(Rational::from_signeds(7, 5), p, 3)).to_string(),
+    ///     "3*5^-1 + 3 + 4*5 + O(5^2)"
+    /// );
+    /// ```
+    fn neg(self) -> PAdic {
+        let mut x = self.clone();
+        x.neg_assign();
+        x
+    }
+}
+
+impl NegAssign for PAdic {
+    /// Negates a [`PAdic`] in place.
+    ///
+    /// The valuation and precision of the result are the same as those of the input.
+    ///
+    /// $$
+    /// x \gets -x.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.precision() * self.p().significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::NegAssign;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_padic::PAdic;
+    /// use malachite_q::Rational;
+    ///
+    /// let p = Natural::from(5u32);
+    /// let mut x = PAdic::zero(p.clone(), 3);
+    /// x.neg_assign();
+    /// assert_eq!(x.to_string(), "O(5^3)");
+    ///
+    /// let mut x = PAdic::from_rational(Rational::from(1), p.clone(), 3);
+    /// x.neg_assign();
+    /// assert_eq!(x.to_string(), "4 + 4*5 + 4*5^2 + O(5^3)");
+    ///
+    /// let mut x = PAdic::from_rational(Rational::from_signeds(7, 5), p, 3);
+    /// x.neg_assign();
+    /// assert_eq!(x.to_string(), "3*5^-1 + 3 + 4*5 + O(5^2)");
+    /// ```
+    fn neg_assign(&mut self) {
+        if self.precision != 0 {
+            // Since the unit is not divisible by p, neither is its negative.
+            self.unit = (&self.p).pow(self.precision) - &self.unit;
+        }
+    }
+}
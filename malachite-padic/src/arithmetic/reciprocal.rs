@@ -0,0 +1,164 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::{absolute_precision, PAdic};
+use malachite_base::num::arithmetic::traits::{ModInverse, Pow, Reciprocal, ReciprocalAssign};
+
+impl Reciprocal for PAdic {
+    type Output = PAdic;
+
+    /// Reciprocates a [`PAdic`], taking it by value.
+    ///
+    /// The valuation of the result is the negative of the valuation of the input, and its
+    /// precision is the same as the input's.
+    ///
+    /// $$
+    /// f(x) = 1/x.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.precision() * self.p().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is zero, or if the valuation of the result is out of range.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Reciprocal;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_padic::PAdic;
+    /// use malachite_q::Rational;
+    ///
+    /// let p = Natural::from(5u32);
+    /// assert_eq!(
+    ///     PAdic::from_rational(Rational::from(3), p.clone(), 4)
+    ///         .reciprocal()
+    ///         .to_string(),
+    ///     "2 + 3*5 + 5^2 + 3*5^3 + O(5^4)"
+    /// );
+    /// assert_eq!(
+    ///     PAdic::from_rational(Rational::from(50), p, 2)
+    ///         .reciprocal()
+    ///         .to_string(),
+    ///     "3*5^-2 + 2*5^-1 + O(5^0)"
+    /// );
+    /// ```
+    #[inline]
+    fn reciprocal(mut self) -> PAdic {
+        self.reciprocal_assign();
+        self
+    }
+}
+
+impl Reciprocal for &PAdic {
+    type Output = PAdic;
+
+    /// Reciprocates a [`PAdic`], taking it by reference.
+    ///
+    /// The valuation of the result is the negative of the valuation of the input, and its
+    /// precision is the same as the input's.
+    ///
+    /// $$
+    /// f(x) = 1/x.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.precision() * self.p().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is zero, or if the valuation of the result is out of range.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::Reciprocal;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_padic::PAdic;
+    /// use malachite_q::Rational;
+    ///
+    /// let p = Natural::from(5u32);
+    /// assert_eq!(
+    ///     (&PAdic::from_rational(Rational::from(3), p.clone(), 4))
+    ///         .reciprocal()
+    ///         .to_string(),
+    ///     "2 + 3*5 + 5^2 + 3*5^3 + O(5^4)"
+    /// );
+    /// assert_eq!(
+    ///     (&PAdic::from_rational(Rational::from(50), p, 2))
+    ///         .reciprocal()
+    ///         .to_string(),
+    ///     "3*5^-2 + 2*5^-1 + O(5^0)"
+    /// );
+    /// ```
+    fn reciprocal(self) -> PAdic {
+        assert!(!self.is_zero(), "Cannot take reciprocal of zero");
+        let valuation = self
+            .valuation
+            .checked_neg()
+            .expect("The valuation of a PAdic reciprocal is out of range");
+        absolute_precision(valuation, self.precision);
+        let modulus = (&self.p).pow(self.precision);
+        PAdic {
+            p: self.p.clone(),
+            valuation,
+            unit: (&self.unit).mod_inverse(&modulus).unwrap(),
+            precision: self.precision,
+        }
+    }
+}
+
+impl ReciprocalAssign for PAdic {
+    /// Reciprocates a [`PAdic`] in place.
+    ///
+    /// The valuation of the result is the negative of the valuation of the input, and its
+    /// precision is the same as the input's.
+    ///
+    /// $$
+    /// x \gets 1/x.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.precision() * self.p().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is zero, or if the valuation of the result is out of range.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::ReciprocalAssign;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_padic::PAdic;
+    /// use malachite_q::Rational;
+    ///
+    /// let p = Natural::from(5u32);
+    /// let mut x = PAdic::from_rational(Rational::from(3), p.clone(), 4);
+    /// x.reciprocal_assign();
+    /// assert_eq!(x.to_string(), "2 + 3*5 + 5^2 + 3*5^3 + O(5^4)");
+    ///
+    /// let mut x = PAdic::from_rational(Rational::from(50), p, 2);
+    /// x.reciprocal_assign();
+    /// assert_eq!(x.to_string(), "3*5^-2 + 2*5^-1 + O(5^0)");
+    /// ```
+    fn reciprocal_assign(&mut self) {
+        *self = (&*self).reciprocal();
+    }
+}
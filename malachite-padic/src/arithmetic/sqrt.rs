@@ -0,0 +1,158 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::PAdic;
+use malachite_base::num::arithmetic::traits::{
+    CheckedSqrt, ModPowerOf2, ModPrimePowerSqrt, Parity,
+};
+use malachite_base::num::basic::traits::Zero;
+use malachite_nz::natural::Natural;
+
+impl CheckedSqrt for PAdic {
+    type Output = PAdic;
+
+    /// Returns the square root of a [`PAdic`], or `None` if it is not a square. The [`PAdic`] is
+    /// taken by value.
+    ///
+    /// See the documentation of the `&PAdic` implementation for details.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.precision() * self.p().significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::CheckedSqrt;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_padic::PAdic;
+    /// use malachite_q::Rational;
+    ///
+    /// let p = Natural::from(7u32);
+    /// assert_eq!(
+    ///     PAdic::from_rational(Rational::from(2), p.clone(), 3)
+    ///         .checked_sqrt()
+    ///         .unwrap()
+    ///         .to_string(),
+    ///     "3 + 7 + 2*7^2 + O(7^3)"
+    /// );
+    /// assert_eq!(
+    ///     PAdic::from_rational(Rational::from(3), p.clone(), 3).checked_sqrt(),
+    ///     None
+    /// );
+    /// assert_eq!(
+    ///     PAdic::zero(p, 3).checked_sqrt().unwrap().to_string(),
+    ///     "O(7^2)"
+    /// );
+    /// ```
+    #[inline]
+    fn checked_sqrt(self) -> Option<PAdic> {
+        (&self).checked_sqrt()
+    }
+}
+
+impl CheckedSqrt for &PAdic {
+    type Output = PAdic;
+
+    /// Returns the square root of a [`PAdic`], or `None` if it is not a square. The [`PAdic`] is
+    /// taken by reference.
+    ///
+    /// A nonzero $p^v u + O(p^{v+r})$ is a square if and only if $v$ is even and $u$ is a square
+    /// modulo $p^r$ (or, when $p = 2$, modulo $2^{\min(r, 3)}$). The square root has valuation
+    /// $v/2$, and its unit is found by taking a square root of $u$ modulo $p$ and lifting it using
+    /// Hensel's lemma. If $p$ is odd, the precision of the root is $r$; if $p = 2$, only $r - 1$
+    /// digits of the root are determined. Of the two square roots, the one whose unit is smaller
+    /// is returned.
+    ///
+    /// The square root of a zero $O(p^n)$ is $O(p^{\lceil n/2 \rceil})$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.precision() * self.p().significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::arithmetic::traits::CheckedSqrt;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_padic::PAdic;
+    /// use malachite_q::Rational;
+    ///
+    /// let p = Natural::from(5u32);
+    /// assert_eq!(
+    ///     (&PAdic::from_rational(Rational::from(-1), p.clone(), 4))
+    ///         .checked_sqrt()
+    ///         .unwrap()
+    ///         .to_string(),
+    ///     "2 + 5 + 2*5^2 + 5^3 + O(5^4)"
+    /// );
+    /// assert_eq!(
+    ///     (&PAdic::from_rational(Rational::from_signeds(4, 25), p.clone(), 2))
+    ///         .checked_sqrt()
+    ///         .unwrap()
+    ///         .to_string(),
+    ///     "2*5^-1 + O(5)"
+    /// );
+    /// assert_eq!(
+    ///     (&PAdic::from_rational(Rational::from(5), p, 3)).checked_sqrt(),
+    ///     None
+    /// );
+    ///
+    /// let p = Natural::from(2u32);
+    /// assert_eq!(
+    ///     (&PAdic::from_rational(Rational::from(17), p.clone(), 6))
+    ///         .checked_sqrt()
+    ///         .unwrap()
+    ///         .to_string(),
+    ///     "1 + 2^3 + O(2^5)"
+    /// );
+    /// assert_eq!(
+    ///     (&PAdic::from_rational(Rational::from(5), p, 6)).checked_sqrt(),
+    ///     None
+    /// );
+    /// ```
+    fn checked_sqrt(self) -> Option<PAdic> {
+        if self.is_zero() {
+            let n = self.valuation;
+            return Some(PAdic {
+                p: self.p.clone(),
+                valuation: n.div_euclid(2) + n.rem_euclid(2),
+                unit: Natural::ZERO,
+                precision: 0,
+            });
+        }
+        if self.valuation.odd() {
+            return None;
+        }
+        let valuation = self.valuation >> 1;
+        let root = (&self.unit).mod_prime_power_sqrt(&self.p, self.precision)?;
+        Some(if self.p == 2u32 {
+            // If $s^2 \equiv u \mod 2^r$, then $s$ is only determined modulo $2^{r-1}$, up to sign.
+            let precision = self.precision - 1;
+            PAdic::from_reduced(
+                self.p.clone(),
+                valuation,
+                root.mod_power_of_2(precision),
+                precision,
+            )
+        } else {
+            PAdic {
+                p: self.p.clone(),
+                valuation,
+                unit: root,
+                precision: self.precision,
+            }
+        })
+    }
+}
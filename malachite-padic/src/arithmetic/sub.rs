@@ -0,0 +1,270 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::arithmetic::add::add_or_sub;
+use crate::PAdic;
+use core::ops::{Sub, SubAssign};
+
+impl Sub<PAdic> for PAdic {
+    type Output = PAdic;
+
+    /// Subtracts a [`PAdic`] by another [`PAdic`], taking both by value.
+    ///
+    /// The absolute precision of the difference is the minimum of the absolute precisions of the
+    /// inputs.
+    ///
+    /// $$
+    /// f(x, y) = x - y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.precision(), other.precision()) * self.p().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` have different primes.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_padic::PAdic;
+    /// use malachite_q::Rational;
+    ///
+    /// let p = Natural::from(5u32);
+    /// let x = PAdic::from_rational(Rational::from(7), p.clone(), 4);
+    /// let y = PAdic::from_rational(Rational::from(2), p.clone(), 4);
+    /// assert_eq!((x - y).to_string(), "5 + O(5^4)");
+    ///
+    /// let x = PAdic::from_rational(Rational::from(7), p.clone(), 4);
+    /// let y = PAdic::from_rational(Rational::from(7), p.clone(), 2);
+    /// assert_eq!((x - y).to_string(), "O(5^2)");
+    /// ```
+    #[inline]
+    fn sub(self, other: PAdic) -> PAdic {
+        &self - &other
+    }
+}
+
+impl Sub<&PAdic> for PAdic {
+    type Output = PAdic;
+
+    /// Subtracts a [`PAdic`] by another [`PAdic`], taking the first by value and the second by reference.
+    ///
+    /// The absolute precision of the difference is the minimum of the absolute precisions of the
+    /// inputs.
+    ///
+    /// $$
+    /// f(x, y) = x - y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.precision(), other.precision()) * self.p().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` have different primes.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_padic::PAdic;
+    /// use malachite_q::Rational;
+    ///
+    /// let p = Natural::from(5u32);
+    /// let x = PAdic::from_rational(Rational::from(7), p.clone(), 4);
+    /// let y = PAdic::from_rational(Rational::from(2), p.clone(), 4);
+    /// assert_eq!((x - &y).to_string(), "5 + O(5^4)");
+    ///
+    /// let x = PAdic::from_rational(Rational::from(7), p.clone(), 4);
+    /// let y = PAdic::from_rational(Rational::from(7), p.clone(), 2);
+    /// assert_eq!((x - &y).to_string(), "O(5^2)");
+    /// ```
+    #[inline]
+    fn sub(self, other: &PAdic) -> PAdic {
+        &self - other
+    }
+}
+
+impl Sub<PAdic> for &PAdic {
+    type Output = PAdic;
+
+    /// Subtracts a [`PAdic`] by another [`PAdic`], taking the first by reference and the second by value.
+    ///
+    /// The absolute precision of the difference is the minimum of the absolute precisions of the
+    /// inputs.
+    ///
+    /// $$
+    /// f(x, y) = x - y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.precision(), other.precision()) * self.p().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` have different primes.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_padic::PAdic;
+    /// use malachite_q::Rational;
+    ///
+    /// let p = Natural::from(5u32);
+    /// let x = PAdic::from_rational(Rational::from(7), p.clone(), 4);
+    /// let y = PAdic::from_rational(Rational::from(2), p.clone(), 4);
+    /// assert_eq!((&x - y).to_string(), "5 + O(5^4)");
+    ///
+    /// let x = PAdic::from_rational(Rational::from(7), p.clone(), 4);
+    /// let y = PAdic::from_rational(Rational::from(7), p.clone(), 2);
+    /// assert_eq!((&x - y).to_string(), "O(5^2)");
+    /// ```
+    #[inline]
+    fn sub(self, other: PAdic) -> PAdic {
+        self - &other
+    }
+}
+
+impl Sub<&PAdic> for &PAdic {
+    type Output = PAdic;
+
+    /// Subtracts a [`PAdic`] by another [`PAdic`], taking both by reference.
+    ///
+    /// The absolute precision of the difference is the minimum of the absolute precisions of the
+    /// inputs.
+    ///
+    /// $$
+    /// f(x, y) = x - y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.precision(), other.precision()) * self.p().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` have different primes.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_padic::PAdic;
+    /// use malachite_q::Rational;
+    ///
+    /// let p = Natural::from(5u32);
+    /// let x = PAdic::from_rational(Rational::from(7), p.clone(), 4);
+    /// let y = PAdic::from_rational(Rational::from(2), p.clone(), 4);
+    /// assert_eq!((&x - &y).to_string(), "5 + O(5^4)");
+    ///
+    /// let x = PAdic::from_rational(Rational::from(7), p.clone(), 4);
+    /// let y = PAdic::from_rational(Rational::from(7), p.clone(), 2);
+    /// assert_eq!((&x - &y).to_string(), "O(5^2)");
+    /// ```
+    fn sub(self, other: &PAdic) -> PAdic {
+        add_or_sub(self, other, true)
+    }
+}
+
+impl SubAssign<PAdic> for PAdic {
+    /// Subtracts a [`PAdic`] by another [`PAdic`] in place, taking the [`PAdic`] on the right-hand side by value.
+    ///
+    /// The absolute precision of the difference is the minimum of the absolute precisions of the
+    /// inputs.
+    ///
+    /// $$
+    /// x \gets x - y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.precision(), other.precision()) * self.p().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` have different primes.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_padic::PAdic;
+    /// use malachite_q::Rational;
+    ///
+    /// let p = Natural::from(5u32);
+    /// let mut x = PAdic::from_rational(Rational::from(7), p.clone(), 4);
+    /// x -= PAdic::from_rational(Rational::from(2), p.clone(), 4);
+    /// assert_eq!(x.to_string(), "5 + O(5^4)");
+    ///
+    /// let mut x = PAdic::from_rational(Rational::from(7), p.clone(), 4);
+    /// x -= PAdic::from_rational(Rational::from(7), p.clone(), 2);
+    /// assert_eq!(x.to_string(), "O(5^2)");
+    /// ```
+    #[inline]
+    fn sub_assign(&mut self, other: PAdic) {
+        *self = &*self - &other;
+    }
+}
+
+impl SubAssign<&PAdic> for PAdic {
+    /// Subtracts a [`PAdic`] by another [`PAdic`] in place, taking the [`PAdic`] on the right-hand side by reference.
+    ///
+    /// The absolute precision of the difference is the minimum of the absolute precisions of the
+    /// inputs.
+    ///
+    /// $$
+    /// x \gets x - y.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.precision(), other.precision()) * self.p().significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` have different primes.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_padic::PAdic;
+    /// use malachite_q::Rational;
+    ///
+    /// let p = Natural::from(5u32);
+    /// let mut x = PAdic::from_rational(Rational::from(7), p.clone(), 4);
+    /// x -= &PAdic::from_rational(Rational::from(2), p.clone(), 4);
+    /// assert_eq!(x.to_string(), "5 + O(5^4)");
+    ///
+    /// let mut x = PAdic::from_rational(Rational::from(7), p.clone(), 4);
+    /// x -= &PAdic::from_rational(Rational::from(7), p.clone(), 2);
+    /// assert_eq!(x.to_string(), "O(5^2)");
+    /// ```
+    #[inline]
+    fn sub_assign(&mut self, other: &PAdic) {
+        *self = &*self - other;
+    }
+}
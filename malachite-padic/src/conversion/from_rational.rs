@@ -0,0 +1,149 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::{remove_factor, PAdic};
+use malachite_base::num::arithmetic::traits::{ModInverse, ModMul, Pow};
+use malachite_base::num::basic::traits::Zero;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::factorization::traits::IsPrime;
+use malachite_nz::natural::Natural;
+use malachite_q::Rational;
+
+// Converts $\pm n/d$ to a `PAdic`, where `sign` is true iff the sign is nonnegative.
+fn from_sign_and_fraction(
+    sign: bool,
+    mut numerator: Natural,
+    mut denominator: Natural,
+    p: Natural,
+    precision: u64,
+) -> PAdic {
+    assert!(p.is_prime(), "{p} is not prime");
+    if numerator == 0u32 {
+        return PAdic::from_reduced(p, 0, Natural::ZERO, precision);
+    }
+    let valuation = i64::exact_from(remove_factor(&mut numerator, &p))
+        - i64::exact_from(remove_factor(&mut denominator, &p));
+    if precision == 0 {
+        return PAdic::from_reduced(p, valuation, Natural::ZERO, 0);
+    }
+    let modulus = (&p).pow(precision);
+    let mut unit = (numerator % &modulus).mod_mul(
+        (denominator % &modulus).mod_inverse(&modulus).unwrap(),
+        &modulus,
+    );
+    if !sign {
+        unit = modulus - unit;
+    }
+    PAdic::from_reduced(p, valuation, unit, precision)
+}
+
+impl PAdic {
+    /// Converts a [`Rational`] to a [`PAdic`] with a given prime and precision, taking the
+    /// [`Rational`] by value.
+    ///
+    /// If $x = p^v a/b$, where $a$ and $b$ are not divisible by $p$, the result has valuation $v$
+    /// and precision $r$, and its unit is the unique $u$ with $0 < u < p^r$ and $bu \equiv a \mod
+    /// p^r$. If $x$ is zero, the result is $O(p^r)$. If $r$ is zero, the result is $O(p^v)$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(x.significant_bits(), precision * p.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `p` is not prime.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_padic::PAdic;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(
+    ///     PAdic::from_rational(Rational::from(7), Natural::from(5u32), 4).to_string(),
+    ///     "2 + 5 + O(5^4)"
+    /// );
+    /// assert_eq!(
+    ///     PAdic::from_rational(Rational::from(-1), Natural::from(5u32), 3).to_string(),
+    ///     "4 + 4*5 + 4*5^2 + O(5^3)"
+    /// );
+    /// assert_eq!(
+    ///     PAdic::from_rational(Rational::from_signeds(1, 3), Natural::from(5u32), 4).to_string(),
+    ///     "2 + 3*5 + 5^2 + 3*5^3 + O(5^4)"
+    /// );
+    /// assert_eq!(
+    ///     PAdic::from_rational(Rational::from_signeds(3, 20), Natural::from(5u32), 2).to_string(),
+    ///     "2*5^-1 + 1 + O(5)"
+    /// );
+    /// assert_eq!(
+    ///     PAdic::from_rational(Rational::from(0), Natural::from(5u32), 2).to_string(),
+    ///     "O(5^2)"
+    /// );
+    /// ```
+    pub fn from_rational(x: Rational, p: Natural, precision: u64) -> PAdic {
+        let sign = x >= 0u32;
+        let (numerator, denominator) = x.into_numerator_and_denominator();
+        from_sign_and_fraction(sign, numerator, denominator, p, precision)
+    }
+
+    /// Converts a [`Rational`] to a [`PAdic`] with a given prime and precision, taking the
+    /// [`Rational`] by reference.
+    ///
+    /// If $x = p^v a/b$, where $a$ and $b$ are not divisible by $p$, the result has valuation $v$
+    /// and precision $r$, and its unit is the unique $u$ with $0 < u < p^r$ and $bu \equiv a \mod
+    /// p^r$. If $x$ is zero, the result is $O(p^r)$. If $r$ is zero, the result is $O(p^v)$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(x.significant_bits(), precision * p.significant_bits())`.
+    ///
+    /// # Panics
+    /// Panics if `p` is not prime.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_padic::PAdic;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(
+    ///     PAdic::from_rational_ref(&Rational::from(7), Natural::from(5u32), 4).to_string(),
+    ///     "2 + 5 + O(5^4)"
+    /// );
+    /// assert_eq!(
+    ///     PAdic::from_rational_ref(&Rational::from(-1), Natural::from(5u32), 3).to_string(),
+    ///     "4 + 4*5 + 4*5^2 + O(5^3)"
+    /// );
+    /// assert_eq!(
+    ///     PAdic::from_rational_ref(&Rational::from_signeds(1, 3), Natural::from(5u32), 4)
+    ///         .to_string(),
+    ///     "2 + 3*5 + 5^2 + 3*5^3 + O(5^4)"
+    /// );
+    /// assert_eq!(
+    ///     PAdic::from_rational_ref(&Rational::from_signeds(3, 20), Natural::from(5u32), 2)
+    ///         .to_string(),
+    ///     "2*5^-1 + 1 + O(5)"
+    /// );
+    /// assert_eq!(
+    ///     PAdic::from_rational_ref(&Rational::from(0), Natural::from(5u32), 2).to_string(),
+    ///     "O(5^2)"
+    /// );
+    /// ```
+    #[inline]
+    pub fn from_rational_ref(x: &Rational, p: Natural, precision: u64) -> PAdic {
+        let (numerator, denominator) = x.to_numerator_and_denominator();
+        from_sign_and_fraction(*x >= 0u32, numerator, denominator, p, precision)
+    }
+}
@@ -0,0 +1,17 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+/// Functions for converting a [`Rational`](malachite_q::Rational) to a [`PAdic`](crate::PAdic).
+pub mod from_rational;
+/// Implementations of the [`From`] trait for converting a [`PAdic`](crate::PAdic) to a
+/// [`Rational`](malachite_q::Rational).
+pub mod rational_from_padic;
+/// Implementations of traits for converting [`PAdic`](crate::PAdic)s to strings.
+pub mod string;
+/// Functions for getting the components of a [`PAdic`](crate::PAdic).
+pub mod to_parts;
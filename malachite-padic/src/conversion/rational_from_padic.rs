@@ -0,0 +1,96 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::PAdic;
+use malachite_base::num::arithmetic::traits::Pow;
+use malachite_q::Rational;
+
+impl From<PAdic> for Rational {
+    /// Converts a [`PAdic`] to a [`Rational`], taking the [`PAdic`] by value.
+    ///
+    /// The [`PAdic`] $p^v u + O(p^{v+r})$ is converted to $p^v u$, where $0 \leq u < p^r$. This is
+    /// the unique nonnegative [`Rational`] in the [`PAdic`]'s residue class whose numerator is less
+    /// than $p^{v+r}$ and whose denominator is a power of $p$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.precision(), self.valuation().unsigned_abs()) * self.p().significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_padic::PAdic;
+    /// use malachite_q::Rational;
+    ///
+    /// let p = Natural::from(5u32);
+    /// assert_eq!(
+    ///     Rational::from(PAdic::from_rational(Rational::from(7), p.clone(), 4)),
+    ///     7
+    /// );
+    /// assert_eq!(
+    ///     Rational::from(PAdic::from_rational(Rational::from(-1), p.clone(), 3)),
+    ///     124
+    /// );
+    /// assert_eq!(
+    ///     Rational::from(PAdic::from_rational(Rational::from_signeds(3, 20), p.clone(), 2))
+    ///         .to_string(),
+    ///     "7/5"
+    /// );
+    /// assert_eq!(Rational::from(PAdic::zero(p, -2)), 0);
+    /// ```
+    #[inline]
+    fn from(x: PAdic) -> Rational {
+        Rational::from(&x)
+    }
+}
+
+impl From<&PAdic> for Rational {
+    /// Converts a [`PAdic`] to a [`Rational`], taking the [`PAdic`] by reference.
+    ///
+    /// The [`PAdic`] $p^v u + O(p^{v+r})$ is converted to $p^v u$, where $0 \leq u < p^r$. This is
+    /// the unique nonnegative [`Rational`] in the [`PAdic`]'s residue class whose numerator is less
+    /// than $p^{v+r}$ and whose denominator is a power of $p$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `max(self.precision(), self.valuation().unsigned_abs()) * self.p().significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_padic::PAdic;
+    /// use malachite_q::Rational;
+    ///
+    /// let p = Natural::from(5u32);
+    /// assert_eq!(
+    ///     Rational::from(&PAdic::from_rational(Rational::from(7), p.clone(), 4)),
+    ///     7
+    /// );
+    /// assert_eq!(
+    ///     Rational::from(&PAdic::from_rational(Rational::from(-1), p.clone(), 3)),
+    ///     124
+    /// );
+    /// assert_eq!(
+    ///     Rational::from(&PAdic::from_rational(Rational::from_signeds(3, 20), p.clone(), 2))
+    ///         .to_string(),
+    ///     "7/5"
+    /// );
+    /// assert_eq!(Rational::from(&PAdic::zero(p, -2)), 0);
+    /// ```
+    fn from(x: &PAdic) -> Rational {
+        Rational::from(&x.unit) * Rational::from(&x.p).pow(x.valuation)
+    }
+}
@@ -0,0 +1,116 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::PAdic;
+use core::fmt::{Debug, Display, Formatter, Result, Write};
+use malachite_base::num::conversion::traits::Digits;
+use malachite_nz::natural::Natural;
+
+// Writes p^e, omitting the exponent if it is 1.
+fn write_power(f: &mut Formatter, p: &Natural, e: i64) -> Result {
+    if e == 1 {
+        write!(f, "{p}")
+    } else {
+        write!(f, "{p}^{e}")
+    }
+}
+
+impl Display for PAdic {
+    /// Converts a [`PAdic`] to a [`String`](alloc::string::String).
+    ///
+    /// The format is the one used by PARI/GP: the known base-$p$ digits of the [`PAdic`] are
+    /// written as a sum of terms $d \cdot p^e$, from the lowest power of $p$ to the highest,
+    /// followed by a big-O term $O(p^n)$, where $n$ is the absolute precision. Zero digits are
+    /// omitted, as are coefficients equal to 1, exponents equal to 1, and powers of $p$ with
+    /// exponent 0.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.precision() * self.p().significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_padic::PAdic;
+    /// use malachite_q::Rational;
+    ///
+    /// let p = Natural::from(5u32);
+    /// assert_eq!(PAdic::zero(p.clone(), 0).to_string(), "O(5^0)");
+    /// assert_eq!(PAdic::zero(p.clone(), 1).to_string(), "O(5)");
+    /// assert_eq!(
+    ///     PAdic::from_rational(Rational::from(7), p.clone(), 4).to_string(),
+    ///     "2 + 5 + O(5^4)"
+    /// );
+    /// assert_eq!(
+    ///     PAdic::from_rational(Rational::from(50), p.clone(), 2).to_string(),
+    ///     "2*5^2 + O(5^4)"
+    /// );
+    /// assert_eq!(
+    ///     PAdic::from_rational(Rational::from_signeds(1, 5), p, 3).to_string(),
+    ///     "5^-1 + O(5^2)"
+    /// );
+    /// ```
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        for (e, d) in (self.valuation..).zip(self.unit.to_digits_asc(&self.p)) {
+            if d != 0u32 {
+                if d != 1u32 {
+                    write!(f, "{d}")?;
+                    if e != 0 {
+                        f.write_char('*')?;
+                    }
+                } else if e == 0 {
+                    f.write_char('1')?;
+                }
+                if e != 0 {
+                    write_power(f, &self.p, e)?;
+                }
+                f.write_str(" + ")?;
+            }
+        }
+        f.write_str("O(")?;
+        write_power(f, &self.p, self.absolute_precision())?;
+        f.write_char(')')
+    }
+}
+
+impl Debug for PAdic {
+    /// Converts a [`PAdic`] to a [`String`](alloc::string::String).
+    ///
+    /// This is the same implementation as for [`Display`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is
+    /// `self.precision() * self.p().significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_padic::PAdic;
+    /// use malachite_q::Rational;
+    ///
+    /// let p = Natural::from(5u32);
+    /// assert_eq!(PAdic::zero(p.clone(), 1).to_debug_string(), "O(5)");
+    /// assert_eq!(
+    ///     PAdic::from_rational(Rational::from(7), p, 4).to_debug_string(),
+    ///     "2 + 5 + O(5^4)"
+    /// );
+    /// ```
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        Display::fmt(self, f)
+    }
+}
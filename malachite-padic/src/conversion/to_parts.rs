@@ -0,0 +1,173 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::{absolute_precision, PAdic};
+use malachite_nz::natural::Natural;
+
+impl PAdic {
+    /// Returns a reference to the prime $p$ of a [`PAdic`].
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_padic::PAdic;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = PAdic::from_rational(Rational::from(7), Natural::from(5u32), 4);
+    /// assert_eq!(*x.p(), 5);
+    /// ```
+    #[inline]
+    pub const fn p(&self) -> &Natural {
+        &self.p
+    }
+
+    /// Returns the valuation of a [`PAdic`].
+    ///
+    /// If the [`PAdic`] is nonzero, this is the exponent of the largest power of $p$ dividing it.
+    /// If it is a zero $O(p^n)$, this is $n$.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_padic::PAdic;
+    /// use malachite_q::Rational;
+    ///
+    /// let p = Natural::from(5u32);
+    /// assert_eq!(
+    ///     PAdic::from_rational(Rational::from(7), p.clone(), 4).valuation(),
+    ///     0
+    /// );
+    /// assert_eq!(
+    ///     PAdic::from_rational(Rational::from(50), p.clone(), 4).valuation(),
+    ///     2
+    /// );
+    /// assert_eq!(
+    ///     PAdic::from_rational(Rational::from_signeds(3, 20), p.clone(), 4).valuation(),
+    ///     -1
+    /// );
+    /// assert_eq!(PAdic::zero(p, 3).valuation(), 3);
+    /// ```
+    #[inline]
+    pub const fn valuation(&self) -> i64 {
+        self.valuation
+    }
+
+    /// Returns a reference to the unit of a [`PAdic`]; that is, the [`PAdic`] divided by $p^v$ and
+    /// reduced modulo $p^r$. The unit is zero if and only if the [`PAdic`] is zero.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_padic::PAdic;
+    /// use malachite_q::Rational;
+    ///
+    /// let p = Natural::from(5u32);
+    /// assert_eq!(
+    ///     *PAdic::from_rational(Rational::from(50), p.clone(), 4).unit(),
+    ///     2
+    /// );
+    /// assert_eq!(
+    ///     *PAdic::from_rational(Rational::from(-1), p.clone(), 3).unit(),
+    ///     124
+    /// );
+    /// assert_eq!(*PAdic::zero(p, 3).unit(), 0);
+    /// ```
+    #[inline]
+    pub const fn unit(&self) -> &Natural {
+        &self.unit
+    }
+
+    /// Returns the precision of a [`PAdic`]; that is, the number of known $p$-adic digits,
+    /// starting from the lowest nonzero one. The precision is zero if and only if the [`PAdic`] is
+    /// zero.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_padic::PAdic;
+    /// use malachite_q::Rational;
+    ///
+    /// let p = Natural::from(5u32);
+    /// assert_eq!(
+    ///     PAdic::from_rational(Rational::from(50), p.clone(), 4).precision(),
+    ///     4
+    /// );
+    /// assert_eq!(PAdic::zero(p, 3).precision(), 0);
+    /// ```
+    #[inline]
+    pub const fn precision(&self) -> u64 {
+        self.precision
+    }
+
+    /// Returns the absolute precision of a [`PAdic`]; that is, the exponent $n$ such that the
+    /// [`PAdic`] is known modulo $p^n$. This is the sum of the valuation and the precision.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_padic::PAdic;
+    /// use malachite_q::Rational;
+    ///
+    /// let p = Natural::from(5u32);
+    /// assert_eq!(
+    ///     PAdic::from_rational(Rational::from(50), p.clone(), 4).absolute_precision(),
+    ///     6
+    /// );
+    /// assert_eq!(
+    ///     PAdic::from_rational(Rational::from_signeds(3, 20), p.clone(), 2).absolute_precision(),
+    ///     1
+    /// );
+    /// assert_eq!(PAdic::zero(p, 3).absolute_precision(), 3);
+    /// ```
+    #[inline]
+    pub const fn absolute_precision(&self) -> i64 {
+        absolute_precision(self.valuation, self.precision)
+    }
+
+    /// Returns whether a [`PAdic`] is a zero $O(p^n)$.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_padic::PAdic;
+    /// use malachite_q::Rational;
+    ///
+    /// let p = Natural::from(5u32);
+    /// assert_eq!(
+    ///     PAdic::from_rational(Rational::from(50), p.clone(), 4).is_zero(),
+    ///     false
+    /// );
+    /// assert_eq!(
+    ///     PAdic::from_rational(Rational::from(50), p.clone(), 0).is_zero(),
+    ///     true
+    /// );
+    /// assert_eq!(PAdic::zero(p, 3).is_zero(), true);
+    /// ```
+    #[inline]
+    pub const fn is_zero(&self) -> bool {
+        self.precision == 0
+    }
+}
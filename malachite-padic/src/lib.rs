@@ -0,0 +1,229 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+//! This crate defines [`PAdic`]s, $p$-adic numbers with bounded precision. A [`PAdic`] is an
+//! approximation $p^v u + O(p^{v+r})$ to an element of $\mathbb{Q}_p$: the prime $p$, the
+//! _valuation_ $v$, the _unit_ $u$, and the _precision_ $r$ together describe the set of $p$-adic
+//! numbers that agree with $p^v u$ modulo $p^{v+r}$. This is the same model used by computer
+//! algebra systems like PARI/GP, and it is what is needed for Hensel lifting in exact polynomial
+//! factorization.
+//! - Addition, subtraction, negation, and multiplication track precision exactly: the result is
+//!   known to exactly the precision that the inputs justify.
+//! - Nonzero [`PAdic`]s can be inverted and divided, and square roots are computed by finding a
+//!   root modulo $p$ and lifting it using Hensel's lemma.
+//! - [`PAdic`]s can be created from [`Rational`]s whose denominators need not be coprime to $p$,
+//!   and converted back to [`Rational`]s. They are formatted like PARI/GP formats them, as a sum of
+//!   powers of $p$ followed by a big-O term.
+//!
+//! # Features
+//! - `32_bit_limbs`: Sets the type of [`Limb`](malachite_nz#limbs) to [`u32`] instead of the
+//!   default, [`u64`].
+//! - `random`: This feature provides some functions for randomly generating values. It is off by
+//!   default to avoid pulling in some extra dependencies.
+//! - `test_build`: A large proportion of the code in this crate is only used for testing. For a
+//!   typical user, building this code would result in an unnecessarily long compilation time and
+//!   an unnecessarily large binary. My solution is to only build this code when the `test_build`
+//!   feature is enabled. If you want to run unit tests, you must enable `test_build`. However,
+//!   doctests don't require it, since they only test the public interface. Enabling this feature
+//!   also enables `random`.
+//!
+//! [`Rational`]: malachite_q::Rational
+
+#![allow(
+    unstable_name_collisions,
+    clippy::assertions_on_constants,
+    clippy::cognitive_complexity,
+    clippy::many_single_char_names,
+    clippy::range_plus_one,
+    clippy::suspicious_arithmetic_impl,
+    clippy::suspicious_op_assign_impl,
+    clippy::too_many_arguments,
+    clippy::type_complexity,
+    clippy::upper_case_acronyms
+)]
+#![warn(
+    clippy::cast_lossless,
+    clippy::explicit_into_iter_loop,
+    clippy::explicit_iter_loop,
+    clippy::filter_map_next,
+    clippy::large_digit_groups,
+    clippy::manual_filter_map,
+    clippy::manual_find_map,
+    clippy::map_flatten,
+    clippy::map_unwrap_or,
+    clippy::match_same_arms,
+    clippy::missing_const_for_fn,
+    clippy::mut_mut,
+    clippy::needless_borrow,
+    clippy::needless_continue,
+    clippy::needless_pass_by_value,
+    clippy::print_stdout,
+    clippy::redundant_closure_for_method_calls,
+    clippy::single_match_else,
+    clippy::trait_duplication_in_bounds,
+    clippy::type_repetition_in_bounds,
+    clippy::uninlined_format_args,
+    clippy::unused_self,
+    clippy::if_not_else,
+    clippy::manual_assert,
+    clippy::range_plus_one,
+    clippy::redundant_else,
+    clippy::semicolon_if_nothing_returned,
+    clippy::cloned_instead_of_copied,
+    clippy::flat_map_option,
+    clippy::unnecessary_wraps,
+    clippy::unnested_or_patterns,
+    clippy::trivially_copy_pass_by_ref
+)]
+#![cfg_attr(not(any(feature = "test_build", feature = "random")), no_std)]
+
+extern crate alloc;
+
+extern crate malachite_base;
+extern crate malachite_nz;
+extern crate malachite_q;
+
+#[cfg(feature = "test_build")]
+use malachite_base::num::arithmetic::traits::Pow;
+use malachite_base::num::arithmetic::traits::{DivExactAssign, DivisibleBy};
+use malachite_base::num::basic::traits::Zero;
+use malachite_base::num::factorization::traits::IsPrime;
+use malachite_nz::natural::Natural;
+
+/// A $p$-adic number with bounded precision.
+///
+/// A [`PAdic`] with prime $p$, valuation $v$, unit $u$, and precision $r$ represents
+/// $p^v u + O(p^{v+r})$; that is, any $p$-adic number congruent to $p^v u$ modulo $p^{v+r}$. The
+/// number $v + r$ is called the _absolute precision_.
+///
+/// If $r > 0$, then $0 < u < p^r$ and $u$ is not divisible by $p$, so $v$ is the true valuation of
+/// the number. If $r = 0$, then $u = 0$, and the [`PAdic`] is a zero $O(p^v)$: all that is known is
+/// that the valuation is at least $v$.
+///
+/// Equality compares all four fields, so two [`PAdic`]s with the same value but different precisions
+/// are not equal.
+#[derive(Clone, Eq, Hash, PartialEq)]
+pub struct PAdic {
+    pub(crate) p: Natural,
+    pub(crate) valuation: i64,
+    pub(crate) unit: Natural,
+    pub(crate) precision: u64,
+}
+
+impl PAdic {
+    // Returns true iff `self` is valid. To be valid, `p` must be at least 2, `unit` must be less
+    // than `p ^ precision`, and either `precision` and `unit` are both zero, or `unit` is not
+    // divisible by `p`. The primality of `p` is not checked. All `PAdic`s must be valid.
+    #[cfg(feature = "test_build")]
+    pub fn is_valid(&self) -> bool {
+        self.p.is_valid()
+            && self.unit.is_valid()
+            && self.p > 1u32
+            && self
+                .valuation
+                .checked_add_unsigned(self.precision)
+                .is_some()
+            && if self.precision == 0 {
+                self.unit == 0u32
+            } else {
+                self.unit != 0u32
+                    && !(&self.unit).divisible_by(&self.p)
+                    && self.unit < (&self.p).pow(self.precision)
+            }
+    }
+
+    /// Returns $O(p^n)$, a zero [`PAdic`] with absolute precision $n$.
+    ///
+    /// This is the [`PAdic`] representing every $p$-adic number divisible by $p^n$. Its valuation
+    /// is $n$, and its unit and precision are both zero.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `p.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `p` is not prime.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_padic::PAdic;
+    ///
+    /// assert_eq!(PAdic::zero(Natural::from(5u32), 3).to_string(), "O(5^3)");
+    /// assert_eq!(PAdic::zero(Natural::from(7u32), -2).to_string(), "O(7^-2)");
+    /// ```
+    pub fn zero(p: Natural, absolute_precision: i64) -> PAdic {
+        assert!(p.is_prime(), "{p} is not prime");
+        PAdic {
+            p,
+            valuation: absolute_precision,
+            unit: Natural::ZERO,
+            precision: 0,
+        }
+    }
+
+    // Creates a `PAdic` equal to $p^v x + O(p^{v+r})$, where $x < p^r$, by moving the factors of
+    // $p$ in $x$ into the valuation.
+    pub(crate) fn from_reduced(
+        p: Natural,
+        valuation: i64,
+        mut x: Natural,
+        precision: u64,
+    ) -> PAdic {
+        let n = absolute_precision(valuation, precision);
+        if x == 0u32 {
+            return PAdic {
+                p,
+                valuation: n,
+                unit: Natural::ZERO,
+                precision: 0,
+            };
+        }
+        let removed = remove_factor(&mut x, &p);
+        PAdic {
+            p,
+            valuation: valuation.checked_add_unsigned(removed).unwrap(),
+            unit: x,
+            precision: precision - removed,
+        }
+    }
+
+    pub(crate) fn assert_same_prime(&self, other: &PAdic) {
+        assert_eq!(
+            self.p, other.p,
+            "Cannot combine p-adic numbers with different primes"
+        );
+    }
+}
+
+// Divides a nonzero `x` by the largest possible power of `p`, returning the exponent.
+pub(crate) fn remove_factor(x: &mut Natural, p: &Natural) -> u64 {
+    let mut removed = 0;
+    while (&*x).divisible_by(p) {
+        x.div_exact_assign(p);
+        removed += 1;
+    }
+    removed
+}
+
+pub(crate) const fn absolute_precision(valuation: i64, precision: u64) -> i64 {
+    valuation
+        .checked_add_unsigned(precision)
+        .expect("The absolute precision of a PAdic is out of range")
+}
+
+/// Traits for arithmetic.
+pub mod arithmetic;
+/// Functions and traits for converting to and from [`PAdic`]s, including converting to strings.
+pub mod conversion;
+
+#[cfg(feature = "test_build")]
+pub mod test_util;
@@ -0,0 +1,27 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::PAdic;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_nz::natural::Natural;
+use malachite_q::Rational;
+
+// Returns $x + O(p^n)$; that is, the `PAdic` representing every $p$-adic number congruent to $x$
+// modulo $p^n$. Every arithmetic operation on `PAdic`s should agree with the corresponding
+// operation on `Rational`s, reduced in this way to the absolute precision of the result.
+pub fn padic_from_rational_with_absolute_precision(x: &Rational, p: &Natural, n: i64) -> PAdic {
+    if *x == 0u32 {
+        return PAdic::zero(p.clone(), n);
+    }
+    let v = PAdic::from_rational_ref(x, p.clone(), 0).valuation();
+    if n <= v {
+        PAdic::zero(p.clone(), n)
+    } else {
+        PAdic::from_rational_ref(x, p.clone(), u64::exact_from(n.abs_diff(v)))
+    }
+}
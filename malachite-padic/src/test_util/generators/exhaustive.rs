@@ -0,0 +1,93 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::test_util::extra_variadic::exhaustive_triples_from_single;
+use crate::PAdic;
+use malachite_base::num::exhaustive::exhaustive_unsigneds;
+use malachite_base::num::factorization::traits::Primes;
+use malachite_base::test_util::generators::common::It;
+use malachite_base::tuples::exhaustive::{exhaustive_pairs, exhaustive_triples};
+use malachite_nz::natural::Natural;
+use malachite_q::exhaustive::exhaustive_rationals;
+use malachite_q::Rational;
+
+fn exhaustive_primes() -> impl Clone + Iterator<Item = Natural> {
+    u64::primes().map(Natural::from)
+}
+
+// Generates the `Rational`s and precisions from which `PAdic`s are built.
+fn exhaustive_padic_parts() -> impl Clone + Iterator<Item = (Rational, u64)> {
+    exhaustive_pairs(exhaustive_rationals(), exhaustive_unsigneds())
+}
+
+fn exhaustive_padics() -> impl Clone + Iterator<Item = PAdic> {
+    exhaustive_pairs(exhaustive_primes(), exhaustive_padic_parts())
+        .map(|(p, (x, precision))| PAdic::from_rational(x, p, precision))
+}
+
+// -- PAdic --
+
+pub fn exhaustive_padic_gen() -> It<PAdic> {
+    Box::new(exhaustive_padics())
+}
+
+pub fn exhaustive_padic_gen_var_1() -> It<PAdic> {
+    Box::new(exhaustive_padics().filter(|x| !x.is_zero()))
+}
+
+// -- (PAdic, PAdic) --
+
+pub fn exhaustive_padic_pair_gen() -> It<(PAdic, PAdic)> {
+    Box::new(
+        exhaustive_triples(
+            exhaustive_primes(),
+            exhaustive_padic_parts(),
+            exhaustive_padic_parts(),
+        )
+        .map(|(p, (x, x_precision), (y, y_precision))| {
+            (
+                PAdic::from_rational(x, p.clone(), x_precision),
+                PAdic::from_rational(y, p, y_precision),
+            )
+        }),
+    )
+}
+
+pub fn exhaustive_padic_pair_gen_var_1() -> It<(PAdic, PAdic)> {
+    Box::new(exhaustive_padic_pair_gen().filter(|(_, y)| !y.is_zero()))
+}
+
+// -- (PAdic, PAdic, PAdic) --
+
+pub fn exhaustive_padic_triple_gen() -> It<(PAdic, PAdic, PAdic)> {
+    Box::new(
+        exhaustive_pairs(
+            exhaustive_primes(),
+            exhaustive_triples_from_single(exhaustive_padic_parts()),
+        )
+        .map(
+            |(p, ((x, x_precision), (y, y_precision), (z, z_precision)))| {
+                (
+                    PAdic::from_rational(x, p.clone(), x_precision),
+                    PAdic::from_rational(y, p.clone(), y_precision),
+                    PAdic::from_rational(z, p, z_precision),
+                )
+            },
+        ),
+    )
+}
+
+// -- (Rational, Natural, u64) --
+
+pub fn exhaustive_rational_natural_unsigned_triple_gen_var_1() -> It<(Rational, Natural, u64)> {
+    Box::new(exhaustive_triples(
+        exhaustive_rationals(),
+        exhaustive_primes(),
+        exhaustive_unsigneds(),
+    ))
+}
@@ -0,0 +1,60 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::test_util::generators::exhaustive::*;
+use crate::test_util::generators::random::*;
+use crate::PAdic;
+use malachite_base::test_util::generators::common::Generator;
+use malachite_nz::natural::Natural;
+use malachite_q::Rational;
+
+// -- PAdic --
+
+pub fn padic_gen() -> Generator<PAdic> {
+    Generator::new_no_special(&exhaustive_padic_gen, &random_padic_gen)
+}
+
+// All nonzero `PAdic`s.
+pub fn padic_gen_var_1() -> Generator<PAdic> {
+    Generator::new_no_special(&exhaustive_padic_gen_var_1, &random_padic_gen_var_1)
+}
+
+// -- (PAdic, PAdic) --
+
+// All pairs of `PAdic`s with the same prime.
+pub fn padic_pair_gen() -> Generator<(PAdic, PAdic)> {
+    Generator::new_no_special(&exhaustive_padic_pair_gen, &random_padic_pair_gen)
+}
+
+// All pairs of `PAdic`s with the same prime, where the second is nonzero.
+pub fn padic_pair_gen_var_1() -> Generator<(PAdic, PAdic)> {
+    Generator::new_no_special(
+        &exhaustive_padic_pair_gen_var_1,
+        &random_padic_pair_gen_var_1,
+    )
+}
+
+// -- (PAdic, PAdic, PAdic) --
+
+// All triples of `PAdic`s with the same prime.
+pub fn padic_triple_gen() -> Generator<(PAdic, PAdic, PAdic)> {
+    Generator::new_no_special(&exhaustive_padic_triple_gen, &random_padic_triple_gen)
+}
+
+// -- (Rational, Natural, u64) --
+
+// All triples of a `Rational`, a prime `Natural`, and a `u64`.
+pub fn rational_natural_unsigned_triple_gen_var_1() -> Generator<(Rational, Natural, u64)> {
+    Generator::new_no_special(
+        &exhaustive_rational_natural_unsigned_triple_gen_var_1,
+        &random_rational_natural_unsigned_triple_gen_var_1,
+    )
+}
+
+pub mod exhaustive;
+pub mod random;
@@ -0,0 +1,135 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::test_util::extra_variadic::{random_triples, random_triples_from_single};
+use crate::PAdic;
+use malachite_base::num::random::geometric::geometric_random_unsigneds;
+use malachite_base::random::{Seed, EXAMPLE_SEED};
+use malachite_base::test_util::generators::common::{GenConfig, It};
+use malachite_base::tuples::random::random_pairs;
+use malachite_base::vecs::random_values_from_vec;
+use malachite_nz::natural::Natural;
+use malachite_q::random::random_rationals;
+use malachite_q::Rational;
+
+// A mix of small primes, where carries and Hensel lifting are exercised heavily, and primes larger
+// than a limb.
+fn random_primes(seed: Seed) -> impl Iterator<Item = Natural> {
+    random_values_from_vec(
+        seed,
+        [2u64, 3, 5, 7, 11, 101, 65537, (1 << 61) - 1]
+            .into_iter()
+            .map(Natural::from)
+            .chain([(Natural::from(1u32) << 89u32) - Natural::from(1u32)])
+            .collect(),
+    )
+}
+
+fn random_padic_parts(seed: Seed, config: &GenConfig) -> impl Iterator<Item = (Rational, u64)> {
+    random_pairs(
+        seed,
+        &|seed| {
+            random_rationals(
+                seed,
+                config.get_or("mean_bits_n", 64),
+                config.get_or("mean_bits_d", 1),
+            )
+        },
+        &|seed| {
+            geometric_random_unsigneds(
+                seed,
+                config.get_or("mean_precision_n", 8),
+                config.get_or("mean_precision_d", 1),
+            )
+        },
+    )
+}
+
+fn random_padics(seed: Seed, config: &GenConfig) -> impl Iterator<Item = PAdic> {
+    random_pairs(seed, &random_primes, &|seed| {
+        random_padic_parts(seed, config)
+    })
+    .map(|(p, (x, precision))| PAdic::from_rational(x, p, precision))
+}
+
+// -- PAdic --
+
+pub fn random_padic_gen(config: &GenConfig) -> It<PAdic> {
+    Box::new(random_padics(EXAMPLE_SEED, config))
+}
+
+pub fn random_padic_gen_var_1(config: &GenConfig) -> It<PAdic> {
+    Box::new(random_padics(EXAMPLE_SEED, config).filter(|x| !x.is_zero()))
+}
+
+// -- (PAdic, PAdic) --
+
+pub fn random_padic_pair_gen(config: &GenConfig) -> It<(PAdic, PAdic)> {
+    Box::new(
+        random_triples(
+            EXAMPLE_SEED,
+            &random_primes,
+            &|seed| random_padic_parts(seed, config),
+            &|seed| random_padic_parts(seed, config),
+        )
+        .map(|(p, (x, x_precision), (y, y_precision))| {
+            (
+                PAdic::from_rational(x, p.clone(), x_precision),
+                PAdic::from_rational(y, p, y_precision),
+            )
+        }),
+    )
+}
+
+pub fn random_padic_pair_gen_var_1(config: &GenConfig) -> It<(PAdic, PAdic)> {
+    Box::new(random_padic_pair_gen(config).filter(|(_, y)| !y.is_zero()))
+}
+
+// -- (PAdic, PAdic, PAdic) --
+
+pub fn random_padic_triple_gen(config: &GenConfig) -> It<(PAdic, PAdic, PAdic)> {
+    Box::new(
+        random_pairs(EXAMPLE_SEED, &random_primes, &|seed| {
+            random_triples_from_single(random_padic_parts(seed, config))
+        })
+        .map(
+            |(p, ((x, x_precision), (y, y_precision), (z, z_precision)))| {
+                (
+                    PAdic::from_rational(x, p.clone(), x_precision),
+                    PAdic::from_rational(y, p.clone(), y_precision),
+                    PAdic::from_rational(z, p, z_precision),
+                )
+            },
+        ),
+    )
+}
+
+// -- (Rational, Natural, u64) --
+
+pub fn random_rational_natural_unsigned_triple_gen_var_1(
+    config: &GenConfig,
+) -> It<(Rational, Natural, u64)> {
+    Box::new(random_triples(
+        EXAMPLE_SEED,
+        &|seed| {
+            random_rationals(
+                seed,
+                config.get_or("mean_bits_n", 64),
+                config.get_or("mean_bits_d", 1),
+            )
+        },
+        &random_primes,
+        &|seed| {
+            geometric_random_unsigneds(
+                seed,
+                config.get_or("mean_precision_n", 8),
+                config.get_or("mean_precision_d", 1),
+            )
+        },
+    ))
+}
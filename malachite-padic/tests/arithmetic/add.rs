@@ -0,0 +1,130 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_nz::natural::Natural;
+use malachite_padic::test_util::common::padic_from_rational_with_absolute_precision;
+use malachite_padic::test_util::generators::{padic_gen, padic_pair_gen, padic_triple_gen};
+use malachite_padic::PAdic;
+use malachite_q::Rational;
+use std::cmp::min;
+use std::str::FromStr;
+
+#[test]
+fn test_add() {
+    let test = |p: u32, s, s_precision, t, t_precision, out| {
+        let p = Natural::from(p);
+        let u = PAdic::from_rational(Rational::from_str(s).unwrap(), p.clone(), s_precision);
+        let v = PAdic::from_rational(Rational::from_str(t).unwrap(), p, t_precision);
+
+        let mut n = u.clone();
+        n += v.clone();
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let mut n = u.clone();
+        n += &v;
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = u.clone() + v.clone();
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = &u + v.clone();
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = u.clone() + &v;
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = &u + &v;
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+    };
+    test(5, "0", 3, "0", 2, "O(5^2)");
+    test(5, "7", 4, "-2", 2, "5 + O(5^2)");
+    test(5, "1/5", 3, "3", 4, "5^-1 + 3 + O(5^2)");
+    test(5, "1", 4, "-1", 4, "O(5^4)");
+    test(5, "1", 4, "24", 4, "5^2 + O(5^4)");
+    test(5, "1", 4, "0", 0, "O(5^0)");
+    test(2, "1", 10, "1", 10, "2 + O(2^10)");
+    test(2, "-1", 10, "1", 3, "O(2^3)");
+    test(7, "1/49", 2, "1/7", 2, "7^-2 + 7^-1 + O(7^0)");
+    test(
+        1000000007,
+        "1000000006",
+        2,
+        "1",
+        2,
+        "1000000007 + O(1000000007^2)",
+    );
+}
+
+#[allow(clippy::no_effect, unused_must_use)]
+#[test]
+#[should_panic]
+fn add_fail() {
+    PAdic::from_rational(Rational::from(1), Natural::from(5u32), 2)
+        + PAdic::from_rational(Rational::from(1), Natural::from(7u32), 2);
+}
+
+#[test]
+fn add_properties() {
+    padic_pair_gen().test_properties(|(x, y)| {
+        let sum_val_val = x.clone() + y.clone();
+        let sum_val_ref = x.clone() + &y;
+        let sum_ref_val = &x + y.clone();
+        let sum = &x + &y;
+        assert!(sum_val_val.is_valid());
+        assert!(sum_val_ref.is_valid());
+        assert!(sum_ref_val.is_valid());
+        assert!(sum.is_valid());
+        assert_eq!(sum_val_val, sum);
+        assert_eq!(sum_val_ref, sum);
+        assert_eq!(sum_ref_val, sum);
+
+        let mut mut_x = x.clone();
+        mut_x += y.clone();
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x, sum);
+        let mut mut_x = x.clone();
+        mut_x += &y;
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x, sum);
+
+        assert_eq!(
+            sum.absolute_precision(),
+            min(x.absolute_precision(), y.absolute_precision())
+        );
+        assert_eq!(
+            padic_from_rational_with_absolute_precision(
+                &(Rational::from(&x) + Rational::from(&y)),
+                x.p(),
+                sum.absolute_precision()
+            ),
+            sum
+        );
+        assert_eq!(&y + &x, sum);
+        assert_eq!(
+            &sum - &y,
+            &x + PAdic::zero(x.p().clone(), sum.absolute_precision())
+        );
+    });
+
+    padic_gen().test_properties(|x| {
+        let zero = PAdic::zero(x.p().clone(), x.absolute_precision());
+        assert_eq!(&x + &zero, x);
+        assert_eq!(&zero + &x, x);
+        assert_eq!(&x + -&x, zero);
+    });
+
+    padic_triple_gen().test_properties(|(x, y, z)| {
+        assert_eq!((&x + &y) + &z, x + (y + z));
+    });
+}
@@ -0,0 +1,122 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::Reciprocal;
+use malachite_nz::natural::Natural;
+use malachite_padic::test_util::common::padic_from_rational_with_absolute_precision;
+use malachite_padic::test_util::generators::{padic_gen_var_1, padic_pair_gen_var_1};
+use malachite_padic::PAdic;
+use malachite_q::Rational;
+use std::cmp::min;
+use std::str::FromStr;
+
+#[test]
+fn test_div() {
+    let test = |p: u32, s, s_precision, t, t_precision, out| {
+        let p = Natural::from(p);
+        let u = PAdic::from_rational(Rational::from_str(s).unwrap(), p.clone(), s_precision);
+        let v = PAdic::from_rational(Rational::from_str(t).unwrap(), p, t_precision);
+
+        let mut n = u.clone();
+        n /= v.clone();
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let mut n = u.clone();
+        n /= &v;
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = u.clone() / v.clone();
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = &u / v.clone();
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = u.clone() / &v;
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = &u / &v;
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+    };
+    test(5, "0", 3, "1", 2, "O(5^3)");
+    test(5, "7", 4, "3", 4, "4 + 3*5 + 5^2 + 3*5^3 + O(5^4)");
+    test(5, "1", 3, "25", 3, "5^-2 + O(5)");
+    test(5, "10", 3, "-1", 2, "3*5 + 4*5^2 + O(5^3)");
+    test(2, "1", 5, "3", 5, "1 + 2 + 2^3 + O(2^5)");
+    test(7, "1/7", 2, "1/49", 4, "7 + O(7^3)");
+}
+
+#[allow(clippy::no_effect, unused_must_use)]
+#[test]
+#[should_panic]
+fn div_fail_1() {
+    PAdic::from_rational(Rational::from(1), Natural::from(5u32), 2)
+        / PAdic::from_rational(Rational::from(1), Natural::from(7u32), 2);
+}
+
+#[allow(clippy::no_effect, unused_must_use)]
+#[test]
+#[should_panic]
+fn div_fail_2() {
+    PAdic::from_rational(Rational::from(1), Natural::from(5u32), 2)
+        / PAdic::zero(Natural::from(5u32), 3);
+}
+
+#[test]
+fn div_properties() {
+    padic_pair_gen_var_1().test_properties(|(x, y)| {
+        let quotient_val_val = x.clone() / y.clone();
+        let quotient_val_ref = x.clone() / &y;
+        let quotient_ref_val = &x / y.clone();
+        let quotient = &x / &y;
+        assert!(quotient_val_val.is_valid());
+        assert!(quotient_val_ref.is_valid());
+        assert!(quotient_ref_val.is_valid());
+        assert!(quotient.is_valid());
+        assert_eq!(quotient_val_val, quotient);
+        assert_eq!(quotient_val_ref, quotient);
+        assert_eq!(quotient_ref_val, quotient);
+
+        let mut mut_x = x.clone();
+        mut_x /= y.clone();
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x, quotient);
+        let mut mut_x = x.clone();
+        mut_x /= &y;
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x, quotient);
+
+        assert_eq!(quotient.valuation(), x.valuation() - y.valuation());
+        assert_eq!(quotient.precision(), min(x.precision(), y.precision()));
+        assert_eq!(
+            padic_from_rational_with_absolute_precision(
+                &(Rational::from(&x) / Rational::from(&y)),
+                x.p(),
+                quotient.absolute_precision()
+            ),
+            quotient
+        );
+        assert_eq!(&x * (&y).reciprocal(), quotient);
+        assert_eq!(-&x / -&y, quotient);
+        if !x.is_zero() {
+            assert_eq!((&y / &x).reciprocal(), quotient);
+        }
+    });
+
+    padic_gen_var_1().test_properties(|x| {
+        let one = PAdic::from_rational(Rational::from(1), x.p().clone(), x.precision());
+        assert_eq!(&x / &one, x);
+        assert_eq!(&one / &x, (&x).reciprocal());
+        assert_eq!(&x / &x, one);
+    });
+}
@@ -0,0 +1,118 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_nz::natural::Natural;
+use malachite_padic::test_util::common::padic_from_rational_with_absolute_precision;
+use malachite_padic::test_util::generators::{padic_gen, padic_pair_gen, padic_triple_gen};
+use malachite_padic::PAdic;
+use malachite_q::Rational;
+use std::cmp::min;
+use std::str::FromStr;
+
+#[test]
+fn test_mul() {
+    let test = |p: u32, s, s_precision, t, t_precision, out| {
+        let p = Natural::from(p);
+        let u = PAdic::from_rational(Rational::from_str(s).unwrap(), p.clone(), s_precision);
+        let v = PAdic::from_rational(Rational::from_str(t).unwrap(), p, t_precision);
+
+        let mut n = u.clone();
+        n *= v.clone();
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let mut n = u.clone();
+        n *= &v;
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = u.clone() * v.clone();
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = &u * v.clone();
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = u.clone() * &v;
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = &u * &v;
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+    };
+    test(5, "0", 3, "0", 2, "O(5^5)");
+    test(5, "0", 3, "1", 2, "O(5^3)");
+    test(5, "7", 4, "1/5", 2, "2*5^-1 + 1 + O(5)");
+    test(5, "50", 3, "1/3", 4, "4*5^2 + 5^3 + 3*5^4 + O(5^5)");
+    test(5, "-1", 4, "-1", 4, "1 + O(5^4)");
+    test(5, "3", 4, "1/3", 4, "1 + O(5^4)");
+    test(2, "3", 3, "5", 10, "1 + 2 + 2^2 + O(2^3)");
+    test(7, "1/49", 2, "49", 1, "1 + O(7)");
+}
+
+#[allow(clippy::no_effect, unused_must_use)]
+#[test]
+#[should_panic]
+fn mul_fail() {
+    PAdic::from_rational(Rational::from(1), Natural::from(5u32), 2)
+        * PAdic::from_rational(Rational::from(1), Natural::from(7u32), 2);
+}
+
+#[test]
+fn mul_properties() {
+    padic_pair_gen().test_properties(|(x, y)| {
+        let product_val_val = x.clone() * y.clone();
+        let product_val_ref = x.clone() * &y;
+        let product_ref_val = &x * y.clone();
+        let product = &x * &y;
+        assert!(product_val_val.is_valid());
+        assert!(product_val_ref.is_valid());
+        assert!(product_ref_val.is_valid());
+        assert!(product.is_valid());
+        assert_eq!(product_val_val, product);
+        assert_eq!(product_val_ref, product);
+        assert_eq!(product_ref_val, product);
+
+        let mut mut_x = x.clone();
+        mut_x *= y.clone();
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x, product);
+        let mut mut_x = x.clone();
+        mut_x *= &y;
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x, product);
+
+        assert_eq!(product.valuation(), x.valuation() + y.valuation());
+        assert_eq!(product.precision(), min(x.precision(), y.precision()));
+        assert_eq!(
+            padic_from_rational_with_absolute_precision(
+                &(Rational::from(&x) * Rational::from(&y)),
+                x.p(),
+                product.absolute_precision()
+            ),
+            product
+        );
+        assert_eq!(&y * &x, product);
+        assert_eq!(-&x * -&y, product);
+        assert_eq!(-(-&x * &y), product);
+    });
+
+    padic_gen().test_properties(|x| {
+        let one = PAdic::from_rational(Rational::from(1), x.p().clone(), x.precision());
+        assert_eq!(&x * &one, x);
+        assert_eq!(&one * &x, x);
+        let zero = PAdic::zero(x.p().clone(), 0);
+        assert_eq!(&x * &zero, PAdic::zero(x.p().clone(), x.valuation()));
+    });
+
+    padic_triple_gen().test_properties(|(x, y, z)| {
+        assert_eq!((&x * &y) * &z, &x * (&y * &z));
+    });
+}
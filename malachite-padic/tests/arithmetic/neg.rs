@@ -0,0 +1,79 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::NegAssign;
+use malachite_nz::natural::Natural;
+use malachite_padic::test_util::common::padic_from_rational_with_absolute_precision;
+use malachite_padic::test_util::generators::padic_gen;
+use malachite_padic::PAdic;
+use malachite_q::Rational;
+use std::str::FromStr;
+
+#[test]
+fn test_neg() {
+    let test = |p: u32, s, precision, out| {
+        let u = PAdic::from_rational(Rational::from_str(s).unwrap(), Natural::from(p), precision);
+
+        let n = -u.clone();
+        assert!(n.is_valid());
+        assert_eq!(n.to_string(), out);
+
+        let n = -&u;
+        assert!(n.is_valid());
+        assert_eq!(n.to_string(), out);
+
+        let mut n = u.clone();
+        n.neg_assign();
+        assert!(n.is_valid());
+        assert_eq!(n.to_string(), out);
+    };
+    test(5, "0", 3, "O(5^3)");
+    test(5, "1", 3, "4 + 4*5 + 4*5^2 + O(5^3)");
+    test(5, "-1", 3, "1 + O(5^3)");
+    test(5, "7/5", 3, "3*5^-1 + 3 + 4*5 + O(5^2)");
+    test(5, "50", 0, "O(5^2)");
+    test(2, "1", 4, "1 + 2 + 2^2 + 2^3 + O(2^4)");
+    test(3, "-1/2", 4, "2 + 3 + 3^2 + 3^3 + O(3^4)");
+}
+
+#[test]
+fn neg_properties() {
+    padic_gen().test_properties(|x| {
+        let neg = -x.clone();
+        assert!(neg.is_valid());
+
+        let neg_alt = -&x;
+        assert!(neg_alt.is_valid());
+        assert_eq!(neg_alt, neg);
+
+        let mut neg_alt = x.clone();
+        neg_alt.neg_assign();
+        assert!(neg_alt.is_valid());
+        assert_eq!(neg_alt, neg);
+
+        assert_eq!(neg.valuation(), x.valuation());
+        assert_eq!(neg.precision(), x.precision());
+        assert_eq!(
+            padic_from_rational_with_absolute_precision(
+                &-Rational::from(&x),
+                x.p(),
+                x.absolute_precision()
+            ),
+            neg
+        );
+        assert_eq!(-&neg, x);
+        assert_eq!(
+            &x + &neg,
+            PAdic::zero(x.p().clone(), x.absolute_precision())
+        );
+        assert_eq!(
+            neg == x,
+            x.is_zero() || (*x.p() == 2u32 && x.precision() == 1)
+        );
+    });
+}
@@ -0,0 +1,95 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{Reciprocal, ReciprocalAssign};
+use malachite_nz::natural::Natural;
+use malachite_padic::test_util::common::padic_from_rational_with_absolute_precision;
+use malachite_padic::test_util::generators::padic_gen_var_1;
+use malachite_padic::PAdic;
+use malachite_q::Rational;
+use std::str::FromStr;
+
+#[test]
+fn test_reciprocal() {
+    let test = |p: u32, s, precision, out| {
+        let u = PAdic::from_rational(Rational::from_str(s).unwrap(), Natural::from(p), precision);
+
+        let n = u.clone().reciprocal();
+        assert!(n.is_valid());
+        assert_eq!(n.to_string(), out);
+
+        let n = (&u).reciprocal();
+        assert!(n.is_valid());
+        assert_eq!(n.to_string(), out);
+
+        let mut n = u.clone();
+        n.reciprocal_assign();
+        assert!(n.is_valid());
+        assert_eq!(n.to_string(), out);
+    };
+    test(5, "1", 3, "1 + O(5^3)");
+    test(5, "3", 4, "2 + 3*5 + 5^2 + 3*5^3 + O(5^4)");
+    test(5, "50", 2, "3*5^-2 + 2*5^-1 + O(5^0)");
+    test(5, "1/125", 2, "5^3 + O(5^5)");
+    test(5, "-1", 3, "4 + 4*5 + 4*5^2 + O(5^3)");
+    test(2, "3", 4, "1 + 2 + 2^3 + O(2^4)");
+    test(7, "2/7", 3, "4*7 + 3*7^2 + 3*7^3 + O(7^4)");
+}
+
+#[test]
+#[should_panic]
+fn reciprocal_fail() {
+    PAdic::zero(Natural::from(5u32), 3).reciprocal();
+}
+
+#[test]
+#[should_panic]
+fn reciprocal_ref_fail() {
+    (&PAdic::zero(Natural::from(5u32), 3)).reciprocal();
+}
+
+#[test]
+#[should_panic]
+fn reciprocal_assign_fail() {
+    let mut x = PAdic::zero(Natural::from(5u32), 3);
+    x.reciprocal_assign();
+}
+
+#[test]
+fn reciprocal_properties() {
+    padic_gen_var_1().test_properties(|x| {
+        let reciprocal = x.clone().reciprocal();
+        assert!(reciprocal.is_valid());
+
+        let reciprocal_alt = (&x).reciprocal();
+        assert!(reciprocal_alt.is_valid());
+        assert_eq!(reciprocal_alt, reciprocal);
+
+        let mut reciprocal_alt = x.clone();
+        reciprocal_alt.reciprocal_assign();
+        assert!(reciprocal_alt.is_valid());
+        assert_eq!(reciprocal_alt, reciprocal);
+
+        assert_eq!(reciprocal.valuation(), -x.valuation());
+        assert_eq!(reciprocal.precision(), x.precision());
+        assert_eq!(
+            padic_from_rational_with_absolute_precision(
+                &Rational::from(&x).reciprocal(),
+                x.p(),
+                reciprocal.absolute_precision()
+            ),
+            reciprocal
+        );
+        assert_eq!((&reciprocal).reciprocal(), x);
+        assert_eq!((-&x).reciprocal(), -&reciprocal);
+        assert_eq!(
+            &x * &reciprocal,
+            PAdic::from_rational(Rational::from(1), x.p().clone(), x.precision())
+        );
+    });
+}
@@ -0,0 +1,97 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::CheckedSqrt;
+use malachite_nz::natural::Natural;
+use malachite_padic::test_util::common::padic_from_rational_with_absolute_precision;
+use malachite_padic::test_util::generators::padic_gen;
+use malachite_padic::PAdic;
+use malachite_q::Rational;
+use std::str::FromStr;
+
+#[test]
+fn test_checked_sqrt() {
+    let test = |p: u32, s, precision, out: Option<&str>| {
+        let u = PAdic::from_rational(Rational::from_str(s).unwrap(), Natural::from(p), precision);
+
+        let n = u.clone().checked_sqrt();
+        assert!(n.as_ref().is_none_or(PAdic::is_valid));
+        assert_eq!(n.map(|x| x.to_string()).as_deref(), out);
+
+        let n = (&u).checked_sqrt();
+        assert!(n.as_ref().is_none_or(PAdic::is_valid));
+        assert_eq!(n.map(|x| x.to_string()).as_deref(), out);
+    };
+    test(5, "0", 3, Some("O(5^2)"));
+    test(5, "0", 4, Some("O(5^2)"));
+    test(5, "1/125", 0, Some("O(5^-1)"));
+    test(5, "1", 3, Some("1 + O(5^3)"));
+    test(5, "4", 3, Some("2 + O(5^3)"));
+    test(5, "-1", 4, Some("2 + 5 + 2*5^2 + 5^3 + O(5^4)"));
+    test(5, "4/25", 2, Some("2*5^-1 + O(5)"));
+    test(5, "100", 2, Some("2*5 + O(5^3)"));
+    test(5, "2", 3, None);
+    test(5, "5", 3, None);
+    test(5, "1/5", 3, None);
+    test(7, "2", 3, Some("3 + 7 + 2*7^2 + O(7^3)"));
+    test(7, "3", 3, None);
+    test(2, "1", 1, Some("O(2^0)"));
+    test(2, "3", 1, Some("O(2^0)"));
+    test(2, "3", 2, None);
+    test(2, "5", 2, Some("1 + O(2)"));
+    test(2, "5", 3, None);
+    test(2, "17", 6, Some("1 + 2^3 + O(2^5)"));
+    test(2, "4", 6, Some("2 + O(2^6)"));
+    test(2, "2", 6, None);
+}
+
+#[test]
+fn checked_sqrt_properties() {
+    padic_gen().test_properties(|x| {
+        let sqrt = x.clone().checked_sqrt();
+        assert!(sqrt.as_ref().is_none_or(PAdic::is_valid));
+
+        let sqrt_alt = (&x).checked_sqrt();
+        assert!(sqrt_alt.as_ref().is_none_or(PAdic::is_valid));
+        assert_eq!(sqrt_alt, sqrt);
+
+        if x.is_zero() {
+            assert!(sqrt.is_some());
+        }
+        if let Some(sqrt) = sqrt {
+            let square = &sqrt * &sqrt;
+            assert_eq!(
+                padic_from_rational_with_absolute_precision(
+                    &Rational::from(&x),
+                    x.p(),
+                    square.absolute_precision()
+                ),
+                square
+            );
+            if !x.is_zero() {
+                assert_eq!(sqrt.valuation() << 1, x.valuation());
+                if *x.p() == 2u32 {
+                    assert_eq!(sqrt.precision() + 1, x.precision());
+                } else {
+                    assert_eq!(sqrt.precision(), x.precision());
+                    assert_eq!(square, x);
+                }
+            }
+            assert!(sqrt.unit() <= (-&sqrt).unit());
+        }
+
+        let square = &x * &x;
+        let sqrt = square.checked_sqrt().unwrap();
+        let n = sqrt.absolute_precision();
+        assert!(
+            sqrt == padic_from_rational_with_absolute_precision(&Rational::from(&x), x.p(), n)
+                || sqrt
+                    == padic_from_rational_with_absolute_precision(&-Rational::from(&x), x.p(), n)
+        );
+    });
+}
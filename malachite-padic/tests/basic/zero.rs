@@ -0,0 +1,42 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::basic::traits::Zero;
+use malachite_nz::natural::Natural;
+use malachite_padic::PAdic;
+
+#[test]
+fn test_zero() {
+    let test = |p: u32, n, out| {
+        let x = PAdic::zero(Natural::from(p), n);
+        assert!(x.is_valid());
+        assert!(x.is_zero());
+        assert_eq!(x.valuation(), n);
+        assert_eq!(x.precision(), 0);
+        assert_eq!(*x.unit(), 0u32);
+        assert_eq!(x.absolute_precision(), n);
+        assert_eq!(x.to_string(), out);
+    };
+    test(2, 0, "O(2^0)");
+    test(5, 1, "O(5)");
+    test(5, 3, "O(5^3)");
+    test(7, -2, "O(7^-2)");
+    test(1000000007, 10, "O(1000000007^10)");
+}
+
+#[test]
+#[should_panic]
+fn zero_fail_1() {
+    PAdic::zero(Natural::ZERO, 3);
+}
+
+#[test]
+#[should_panic]
+fn zero_fail_2() {
+    PAdic::zero(Natural::from(6u32), 3);
+}
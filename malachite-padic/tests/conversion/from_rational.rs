@@ -0,0 +1,104 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{DivisibleBy, Pow};
+use malachite_nz::natural::Natural;
+use malachite_padic::test_util::common::padic_from_rational_with_absolute_precision;
+use malachite_padic::test_util::generators::{
+    padic_gen, rational_natural_unsigned_triple_gen_var_1,
+};
+use malachite_padic::PAdic;
+use malachite_q::Rational;
+use std::str::FromStr;
+
+#[test]
+fn test_from_rational() {
+    let test = |s, p: u32, precision, out, valuation, unit: u32, out_precision| {
+        let u = Rational::from_str(s).unwrap();
+        let p = Natural::from(p);
+
+        let x = PAdic::from_rational(u.clone(), p.clone(), precision);
+        assert!(x.is_valid());
+        assert_eq!(x.to_string(), out);
+        assert_eq!(x.valuation(), valuation);
+        assert_eq!(*x.unit(), unit);
+        assert_eq!(x.precision(), out_precision);
+
+        let x = PAdic::from_rational_ref(&u, p, precision);
+        assert!(x.is_valid());
+        assert_eq!(x.to_string(), out);
+    };
+    test("0", 5, 0, "O(5^0)", 0, 0, 0);
+    test("0", 5, 2, "O(5^2)", 2, 0, 0);
+    test("1", 5, 0, "O(5^0)", 0, 0, 0);
+    test("50", 5, 0, "O(5^2)", 2, 0, 0);
+    test("7", 5, 4, "2 + 5 + O(5^4)", 0, 7, 4);
+    test("-1", 5, 3, "4 + 4*5 + 4*5^2 + O(5^3)", 0, 124, 3);
+    test("1/3", 5, 4, "2 + 3*5 + 5^2 + 3*5^3 + O(5^4)", 0, 417, 4);
+    test("3/20", 5, 2, "2*5^-1 + 1 + O(5)", -1, 7, 2);
+    test("50", 5, 3, "2*5^2 + O(5^5)", 2, 2, 3);
+    test("-1/3", 2, 5, "1 + 2^2 + 2^4 + O(2^5)", 0, 21, 5);
+    test("1/1024", 2, 1, "2^-10 + O(2^-9)", -10, 1, 1);
+    test("100", 101, 3, "100 + O(101^3)", 0, 100, 3);
+}
+
+#[test]
+#[should_panic]
+fn from_rational_fail_1() {
+    PAdic::from_rational(Rational::from(1), Natural::from(6u32), 3);
+}
+
+#[test]
+#[should_panic]
+fn from_rational_fail_2() {
+    PAdic::from_rational(Rational::from(1), Natural::from(1u32), 3);
+}
+
+#[test]
+#[should_panic]
+fn from_rational_ref_fail() {
+    PAdic::from_rational_ref(&Rational::from(1), Natural::from(6u32), 3);
+}
+
+#[test]
+fn from_rational_properties() {
+    rational_natural_unsigned_triple_gen_var_1().test_properties(|(q, p, precision)| {
+        let x = PAdic::from_rational(q.clone(), p.clone(), precision);
+        assert!(x.is_valid());
+        let x_alt = PAdic::from_rational_ref(&q, p.clone(), precision);
+        assert!(x_alt.is_valid());
+        assert_eq!(x_alt, x);
+
+        assert_eq!(x.p(), &p);
+        if q != 0u32 {
+            assert_eq!(x.precision(), precision);
+            assert_eq!(
+                padic_from_rational_with_absolute_precision(&q, &p, x.absolute_precision()),
+                x
+            );
+            let v = x.valuation();
+            let w = Rational::from(&p).pow(-v) * &q;
+            assert!(!w.numerator_ref().divisible_by(&p));
+            assert!(!w.denominator_ref().divisible_by(&p));
+        } else {
+            assert!(x.is_zero());
+            assert_eq!(x.valuation(), i64::try_from(precision).unwrap());
+        }
+        assert_eq!(PAdic::from_rational(-q, p, precision), -x);
+    });
+
+    padic_gen().test_properties(|x| {
+        let q = Rational::from(&x);
+        if !x.is_zero() {
+            assert_eq!(
+                PAdic::from_rational_ref(&q, x.p().clone(), x.precision()),
+                x
+            );
+        }
+    });
+}
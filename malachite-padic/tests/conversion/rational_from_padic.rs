@@ -0,0 +1,61 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{CheckedLogBase, Pow};
+use malachite_nz::natural::Natural;
+use malachite_padic::test_util::generators::padic_gen;
+use malachite_padic::PAdic;
+use malachite_q::Rational;
+use std::str::FromStr;
+
+#[test]
+fn test_rational_from_padic() {
+    let test = |s, p: u32, precision, out| {
+        let x = PAdic::from_rational(Rational::from_str(s).unwrap(), Natural::from(p), precision);
+
+        let q = Rational::from(x.clone());
+        assert!(q.is_valid());
+        assert_eq!(q.to_string(), out);
+
+        let q = Rational::from(&x);
+        assert!(q.is_valid());
+        assert_eq!(q.to_string(), out);
+    };
+    test("0", 5, 3, "0");
+    test("7", 5, 4, "7");
+    test("7", 5, 1, "2");
+    test("-1", 5, 3, "124");
+    test("1/3", 5, 4, "417");
+    test("3/20", 5, 2, "7/5");
+    test("50", 5, 3, "50");
+    test("-1/3", 2, 5, "21");
+    test("5/1024", 2, 3, "5/1024");
+}
+
+#[test]
+fn rational_from_padic_properties() {
+    padic_gen().test_properties(|x| {
+        let q = Rational::from(x.clone());
+        assert!(q.is_valid());
+        let q_alt = Rational::from(&x);
+        assert!(q_alt.is_valid());
+        assert_eq!(q_alt, q);
+
+        assert!(q >= 0u32);
+        assert!(q.denominator_ref().checked_log_base(x.p()).is_some());
+        assert!(q < Rational::from(x.p()).pow(x.absolute_precision()));
+        if x.is_zero() {
+            assert_eq!(q, 0u32);
+        } else {
+            assert_eq!(
+                PAdic::from_rational_ref(&q, x.p().clone(), x.precision()),
+                x
+            );
+        }
+    });
+}
@@ -0,0 +1,53 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::conversion::traits::Digits;
+use malachite_base::strings::ToDebugString;
+use malachite_nz::natural::Natural;
+use malachite_padic::test_util::generators::padic_gen;
+use malachite_padic::PAdic;
+use malachite_q::Rational;
+use std::str::FromStr;
+
+#[test]
+fn test_to_string() {
+    let test = |s, p: u32, precision, out| {
+        let x = PAdic::from_rational(Rational::from_str(s).unwrap(), Natural::from(p), precision);
+        assert_eq!(x.to_string(), out);
+        assert_eq!(x.to_debug_string(), out);
+    };
+    test("0", 5, 0, "O(5^0)");
+    test("0", 5, 1, "O(5)");
+    test("0", 5, 2, "O(5^2)");
+    test("1", 5, 1, "1 + O(5)");
+    test("7", 5, 4, "2 + 5 + O(5^4)");
+    test("50", 5, 2, "2*5^2 + O(5^4)");
+    test("1/5", 5, 3, "5^-1 + O(5^2)");
+    test("3/20", 5, 2, "2*5^-1 + 1 + O(5)");
+    test("-1", 2, 4, "1 + 2 + 2^2 + 2^3 + O(2^4)");
+    test("1/2", 2, 1, "2^-1 + O(2^0)");
+    test("-1", 11, 2, "10 + 10*11 + O(11^2)");
+    test("1/3", 7, 3, "5 + 4*7 + 4*7^2 + O(7^3)");
+}
+
+#[test]
+fn to_string_properties() {
+    padic_gen().test_properties(|x| {
+        let s = x.to_string();
+        assert_eq!(x.to_debug_string(), s);
+        assert_eq!(x.is_zero(), s.starts_with("O("));
+        assert!(s.ends_with(')'));
+        let nonzero_digits = x
+            .unit()
+            .to_digits_asc(x.p())
+            .into_iter()
+            .filter(|d| *d != 0u32)
+            .count();
+        assert_eq!(s.matches(" + ").count(), nonzero_digits);
+    });
+}
@@ -0,0 +1,49 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{DivisibleBy, Pow};
+use malachite_nz::natural::Natural;
+use malachite_padic::test_util::generators::padic_gen;
+use malachite_padic::PAdic;
+use malachite_q::Rational;
+use std::str::FromStr;
+
+#[test]
+fn test_to_parts() {
+    let test = |s, p: u32, precision, valuation, unit: u32, out_precision, absolute_precision| {
+        let x = PAdic::from_rational(Rational::from_str(s).unwrap(), Natural::from(p), precision);
+        assert_eq!(*x.p(), p);
+        assert_eq!(x.valuation(), valuation);
+        assert_eq!(*x.unit(), unit);
+        assert_eq!(x.precision(), out_precision);
+        assert_eq!(x.absolute_precision(), absolute_precision);
+        assert_eq!(x.is_zero(), out_precision == 0);
+    };
+    test("0", 5, 3, 3, 0, 0, 3);
+    test("7", 5, 4, 0, 7, 4, 4);
+    test("50", 5, 4, 2, 2, 4, 6);
+    test("50", 5, 0, 2, 0, 0, 2);
+    test("3/20", 5, 2, -1, 7, 2, 1);
+    test("-1", 2, 3, 0, 7, 3, 3);
+}
+
+#[test]
+fn to_parts_properties() {
+    padic_gen().test_properties(|x| {
+        assert_eq!(
+            x.absolute_precision(),
+            x.valuation() + i64::try_from(x.precision()).unwrap()
+        );
+        assert_eq!(x.is_zero(), *x.unit() == 0u32);
+        assert_eq!(x.is_zero(), x.precision() == 0);
+        assert!(*x.unit() < x.p().pow(x.precision()));
+        if !x.is_zero() {
+            assert!(!x.unit().divisible_by(x.p()));
+        }
+    });
+}
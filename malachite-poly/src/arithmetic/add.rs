@@ -0,0 +1,188 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::mod_natural::ModNatural;
+use crate::{trim, Coefficient, Polynomial};
+use alloc::vec::Vec;
+use core::mem::swap;
+use core::ops::{Add, AddAssign};
+use malachite_nz::integer::Integer;
+use malachite_q::Rational;
+
+pub(crate) fn add_assign_helper<T: Coefficient>(xs: &mut Vec<T>, ys: &[T]) {
+    let xs_len = xs.len();
+    for (x, y) in xs.iter_mut().zip(ys.iter()) {
+        *x += y;
+    }
+    if ys.len() > xs_len {
+        xs.extend_from_slice(&ys[xs_len..]);
+    }
+    trim(xs);
+}
+
+macro_rules! impl_add {
+    ($t:ident) => {
+        impl Add<Polynomial<$t>> for Polynomial<$t> {
+            type Output = Polynomial<$t>;
+
+            /// Adds two [`Polynomial`]s, taking both by value.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in
+            /// the coefficients of `self` and `other`.
+            ///
+            /// # Panics
+            /// Panics if the coefficients are [`ModNatural`]s with different moduli.
+            ///
+            /// # Examples
+            /// See [here](super::add#add).
+            fn add(mut self, mut other: Polynomial<$t>) -> Polynomial<$t> {
+                if self.coefficients.len() < other.coefficients.len() {
+                    swap(&mut self, &mut other);
+                }
+                self += other;
+                self
+            }
+        }
+
+        impl Add<&Polynomial<$t>> for Polynomial<$t> {
+            type Output = Polynomial<$t>;
+
+            /// Adds two [`Polynomial`]s, taking the first by value and the second by reference.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in
+            /// the coefficients of `self` and `other`.
+            ///
+            /// # Panics
+            /// Panics if the coefficients are [`ModNatural`]s with different moduli.
+            ///
+            /// # Examples
+            /// See [here](super::add#add).
+            #[inline]
+            fn add(mut self, other: &Polynomial<$t>) -> Polynomial<$t> {
+                self += other;
+                self
+            }
+        }
+
+        impl Add<Polynomial<$t>> for &Polynomial<$t> {
+            type Output = Polynomial<$t>;
+
+            /// Adds two [`Polynomial`]s, taking the first by reference and the second by value.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in
+            /// the coefficients of `self` and `other`.
+            ///
+            /// # Panics
+            /// Panics if the coefficients are [`ModNatural`]s with different moduli.
+            ///
+            /// # Examples
+            /// See [here](super::add#add).
+            #[inline]
+            fn add(self, mut other: Polynomial<$t>) -> Polynomial<$t> {
+                other += self;
+                other
+            }
+        }
+
+        impl Add<&Polynomial<$t>> for &Polynomial<$t> {
+            type Output = Polynomial<$t>;
+
+            /// Adds two [`Polynomial`]s, taking both by reference.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in
+            /// the coefficients of `self` and `other`.
+            ///
+            /// # Panics
+            /// Panics if the coefficients are [`ModNatural`]s with different moduli.
+            ///
+            /// # Examples
+            /// See [here](super::add#add).
+            fn add(self, other: &Polynomial<$t>) -> Polynomial<$t> {
+                let (xs, ys) = if self.coefficients.len() >= other.coefficients.len() {
+                    (self, other)
+                } else {
+                    (other, self)
+                };
+                let mut xs = xs.coefficients.clone();
+                add_assign_helper(&mut xs, &ys.coefficients);
+                Polynomial { coefficients: xs }
+            }
+        }
+
+        impl AddAssign<Polynomial<$t>> for Polynomial<$t> {
+            /// Adds a [`Polynomial`] to a [`Polynomial`] in place, taking the [`Polynomial`] on the
+            /// right-hand side by value.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in
+            /// the coefficients of `self` and `other`.
+            ///
+            /// # Panics
+            /// Panics if the coefficients are [`ModNatural`]s with different moduli.
+            ///
+            /// # Examples
+            /// See [here](super::add#add_assign).
+            fn add_assign(&mut self, mut other: Polynomial<$t>) {
+                if self.coefficients.len() < other.coefficients.len() {
+                    swap(self, &mut other);
+                }
+                add_assign_helper(&mut self.coefficients, &other.coefficients);
+            }
+        }
+
+        impl AddAssign<&Polynomial<$t>> for Polynomial<$t> {
+            /// Adds a [`Polynomial`] to a [`Polynomial`] in place, taking the [`Polynomial`] on the
+            /// right-hand side by reference.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in
+            /// the coefficients of `self` and `other`.
+            ///
+            /// # Panics
+            /// Panics if the coefficients are [`ModNatural`]s with different moduli.
+            ///
+            /// # Examples
+            /// See [here](super::add#add_assign).
+            #[inline]
+            fn add_assign(&mut self, other: &Polynomial<$t>) {
+                add_assign_helper(&mut self.coefficients, &other.coefficients);
+            }
+        }
+    };
+}
+impl_add!(Integer);
+impl_add!(Rational);
+impl_add!(ModNatural);
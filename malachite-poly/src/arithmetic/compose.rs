@@ -0,0 +1,62 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::arithmetic::add::add_assign_helper;
+use crate::arithmetic::mul::mul_helper;
+use crate::mod_natural::ModNatural;
+use crate::{Coefficient, Polynomial};
+use alloc::vec::Vec;
+use malachite_nz::integer::Integer;
+use malachite_q::Rational;
+
+// Computes $f(g(x))$ using Horner's method.
+fn compose_helper<T: Coefficient>(xs: &[T], ys: &[T]) -> Vec<T> {
+    let mut xs = xs.iter().rev();
+    let Some(x) = xs.next() else {
+        return Vec::new();
+    };
+    let mut out = vec![x.clone()];
+    for x in xs {
+        out = mul_helper(&out, ys);
+        add_assign_helper(&mut out, core::slice::from_ref(x));
+    }
+    out
+}
+
+macro_rules! impl_compose {
+    ($t:ident) => {
+        impl Polynomial<$t> {
+            /// Composes two [`Polynomial`]s, taking both by reference.
+            ///
+            /// $f(p, q) = p \circ q$, the [`Polynomial`] with $(p \circ q)(x) = p(q(x))$.
+            ///
+            /// # Worst-case complexity
+            /// $T(n, d) = O(d n \log n \log\log n)$
+            ///
+            /// $M(n) = O(n \log n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, $d$ is `self.degree()`, and $n$ is the
+            /// total number of bits in the coefficients of the result.
+            ///
+            /// # Panics
+            /// Panics if the coefficients are [`ModNatural`]s with different moduli.
+            ///
+            /// # Examples
+            /// See [here](super::compose#compose).
+            #[inline]
+            pub fn compose(&self, other: &Polynomial<$t>) -> Polynomial<$t> {
+                Polynomial {
+                    coefficients: compose_helper(&self.coefficients, &other.coefficients),
+                }
+            }
+        }
+    };
+}
+impl_compose!(Integer);
+impl_compose!(Rational);
+impl_compose!(ModNatural);
@@ -0,0 +1,535 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::mod_natural::ModNatural;
+use crate::{trim, Coefficient, Polynomial};
+use alloc::vec::Vec;
+use core::mem::take;
+use core::ops::{Div, DivAssign, Rem, RemAssign};
+use malachite_base::num::arithmetic::traits::{DivMod, DivRem, Reciprocal};
+use malachite_nz::integer::Integer;
+use malachite_q::Rational;
+
+// Long division of `xs` by `ys`, where `ys` is nonempty and has a nonzero last element. For each
+// degree from `xs.len() - 1` down to `ys.len() - 1`, `quotient_coefficient` maps the coefficient of
+// the partial remainder of that degree to the corresponding coefficient of the quotient.
+fn div_rem_helper<T: Coefficient>(
+    mut xs: Vec<T>,
+    ys: &[T],
+    quotient_coefficient: &dyn Fn(&T) -> T,
+) -> (Vec<T>, Vec<T>) {
+    let ys_len = ys.len();
+    if xs.len() < ys_len {
+        return (Vec::new(), xs);
+    }
+    let qs_len = xs.len() - ys_len + 1;
+    let mut qs = vec![ys[0].zero_like(); qs_len];
+    for i in (0..qs_len).rev() {
+        let q = quotient_coefficient(&xs[i + ys_len - 1]);
+        if q.is_zero_coefficient() {
+            continue;
+        }
+        for (x, y) in xs[i..i + ys_len].iter_mut().zip(ys.iter()) {
+            let mut p = y.clone();
+            p *= &q;
+            *x -= p;
+        }
+        qs[i] = q;
+    }
+    trim(&mut qs);
+    trim(&mut xs);
+    (qs, xs)
+}
+
+pub(crate) fn div_rem_integer(xs: Vec<Integer>, ys: &[Integer]) -> (Vec<Integer>, Vec<Integer>) {
+    let leading = ys.last().expect("division by zero");
+    div_rem_helper(xs, ys, &|x| x.div_mod(leading).0)
+}
+
+pub(crate) fn div_rem_rational(
+    xs: Vec<Rational>,
+    ys: &[Rational],
+) -> (Vec<Rational>, Vec<Rational>) {
+    let inverse = ys.last().expect("division by zero").reciprocal();
+    div_rem_helper(xs, ys, &|x| x * &inverse)
+}
+
+pub(crate) fn div_rem_mod_natural(
+    xs: Vec<ModNatural>,
+    ys: &[ModNatural],
+) -> (Vec<ModNatural>, Vec<ModNatural>) {
+    let inverse = ys
+        .last()
+        .expect("division by zero")
+        .inverse()
+        .expect("The leading coefficient of the divisor must be invertible");
+    div_rem_helper(xs, ys, &|x| x * &inverse)
+}
+
+macro_rules! impl_div_rem {
+    ($t:ident, $f:ident) => {
+        impl DivRem<Polynomial<$t>> for Polynomial<$t> {
+            type DivOutput = Polynomial<$t>;
+            type RemOutput = Polynomial<$t>;
+
+            /// Divides a [`Polynomial`] by another [`Polynomial`], taking both by value, and
+            /// returns the quotient and remainder.
+            ///
+            /// The quotient $q$ and remainder $r$ satisfy $f = gq + r$, where $f$ is `self` and $g$
+            /// is `other`. If the coefficients lie in a field, then $\deg r < \deg g$. Over $\Z$,
+            /// each coefficient of the quotient is found by dividing by the leading coefficient of
+            /// $g$ and rounding down; if that leading coefficient is $\pm 1$, or if $g$ divides $f$
+            /// over $\Q$, the result is the same as division over $\Q$. Otherwise the coefficients
+            /// of $r$ of degree at least $\deg g$ have the same sign as the leading coefficient of
+            /// $g$ and are smaller in absolute value.
+            ///
+            /// # Worst-case complexity
+            /// $T(n, d) = O(d^2 n \log n \log\log n)$
+            ///
+            /// $M(n, d) = O(dn)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, $d$ is `self.degree()`, and $n$ is the
+            /// maximum number of significant bits of a coefficient of the inputs and outputs.
+            ///
+            /// # Panics
+            /// Panics if `other` is zero, if the coefficients are [`ModNatural`]s with different
+            /// moduli, or if the leading coefficient of `other` is a [`ModNatural`] that is not
+            /// invertible.
+            ///
+            /// # Examples
+            /// See [here](super::div_rem#div_rem).
+            fn div_rem(self, other: Polynomial<$t>) -> (Polynomial<$t>, Polynomial<$t>) {
+                let (q, r) = $f(self.coefficients, &other.coefficients);
+                (
+                    Polynomial { coefficients: q },
+                    Polynomial { coefficients: r },
+                )
+            }
+        }
+
+        impl DivRem<&Polynomial<$t>> for Polynomial<$t> {
+            type DivOutput = Polynomial<$t>;
+            type RemOutput = Polynomial<$t>;
+
+            /// Divides a [`Polynomial`] by another [`Polynomial`], taking the first by value and
+            /// the second by reference, and returns the quotient and remainder.
+            ///
+            /// # Worst-case complexity
+            /// $T(n, d) = O(d^2 n \log n \log\log n)$
+            ///
+            /// $M(n, d) = O(dn)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, $d$ is `self.degree()`, and $n$ is the
+            /// maximum number of significant bits of a coefficient of the inputs and outputs.
+            ///
+            /// # Panics
+            /// Panics if `other` is zero, if the coefficients are [`ModNatural`]s with different
+            /// moduli, or if the leading coefficient of `other` is a [`ModNatural`] that is not
+            /// invertible.
+            ///
+            /// # Examples
+            /// See [here](super::div_rem#div_rem).
+            fn div_rem(self, other: &Polynomial<$t>) -> (Polynomial<$t>, Polynomial<$t>) {
+                let (q, r) = $f(self.coefficients, &other.coefficients);
+                (
+                    Polynomial { coefficients: q },
+                    Polynomial { coefficients: r },
+                )
+            }
+        }
+
+        impl DivRem<Polynomial<$t>> for &Polynomial<$t> {
+            type DivOutput = Polynomial<$t>;
+            type RemOutput = Polynomial<$t>;
+
+            /// Divides a [`Polynomial`] by another [`Polynomial`], taking the first by reference
+            /// and the second by value, and returns the quotient and remainder.
+            ///
+            /// # Worst-case complexity
+            /// $T(n, d) = O(d^2 n \log n \log\log n)$
+            ///
+            /// $M(n, d) = O(dn)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, $d$ is `self.degree()`, and $n$ is the
+            /// maximum number of significant bits of a coefficient of the inputs and outputs.
+            ///
+            /// # Panics
+            /// Panics if `other` is zero, if the coefficients are [`ModNatural`]s with different
+            /// moduli, or if the leading coefficient of `other` is a [`ModNatural`] that is not
+            /// invertible.
+            ///
+            /// # Examples
+            /// See [here](super::div_rem#div_rem).
+            fn div_rem(self, other: Polynomial<$t>) -> (Polynomial<$t>, Polynomial<$t>) {
+                let (q, r) = $f(self.coefficients.clone(), &other.coefficients);
+                (
+                    Polynomial { coefficients: q },
+                    Polynomial { coefficients: r },
+                )
+            }
+        }
+
+        impl DivRem<&Polynomial<$t>> for &Polynomial<$t> {
+            type DivOutput = Polynomial<$t>;
+            type RemOutput = Polynomial<$t>;
+
+            /// Divides a [`Polynomial`] by another [`Polynomial`], taking both by reference, and
+            /// returns the quotient and remainder.
+            ///
+            /// # Worst-case complexity
+            /// $T(n, d) = O(d^2 n \log n \log\log n)$
+            ///
+            /// $M(n, d) = O(dn)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, $d$ is `self.degree()`, and $n$ is the
+            /// maximum number of significant bits of a coefficient of the inputs and outputs.
+            ///
+            /// # Panics
+            /// Panics if `other` is zero, if the coefficients are [`ModNatural`]s with different
+            /// moduli, or if the leading coefficient of `other` is a [`ModNatural`] that is not
+            /// invertible.
+            ///
+            /// # Examples
+            /// See [here](super::div_rem#div_rem).
+            fn div_rem(self, other: &Polynomial<$t>) -> (Polynomial<$t>, Polynomial<$t>) {
+                let (q, r) = $f(self.coefficients.clone(), &other.coefficients);
+                (
+                    Polynomial { coefficients: q },
+                    Polynomial { coefficients: r },
+                )
+            }
+        }
+
+        impl Div<Polynomial<$t>> for Polynomial<$t> {
+            type Output = Polynomial<$t>;
+
+            /// Divides a [`Polynomial`] by another [`Polynomial`], taking both by value. The
+            /// remainder is discarded; see [`DivRem`] for details.
+            ///
+            /// # Worst-case complexity
+            /// $T(n, d) = O(d^2 n \log n \log\log n)$
+            ///
+            /// $M(n, d) = O(dn)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, $d$ is `self.degree()`, and $n$ is the
+            /// maximum number of significant bits of a coefficient of the inputs and outputs.
+            ///
+            /// # Panics
+            /// Panics if `other` is zero, if the coefficients are [`ModNatural`]s with different
+            /// moduli, or if the leading coefficient of `other` is a [`ModNatural`] that is not
+            /// invertible.
+            ///
+            /// # Examples
+            /// See [here](super::div_rem#div).
+            fn div(self, other: Polynomial<$t>) -> Polynomial<$t> {
+                Polynomial {
+                    coefficients: $f(self.coefficients, &other.coefficients).0,
+                }
+            }
+        }
+
+        impl Div<&Polynomial<$t>> for Polynomial<$t> {
+            type Output = Polynomial<$t>;
+
+            /// Divides a [`Polynomial`] by another [`Polynomial`], taking the first by value and
+            /// the second by reference. The remainder is discarded; see [`DivRem`] for details.
+            ///
+            /// # Worst-case complexity
+            /// $T(n, d) = O(d^2 n \log n \log\log n)$
+            ///
+            /// $M(n, d) = O(dn)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, $d$ is `self.degree()`, and $n$ is the
+            /// maximum number of significant bits of a coefficient of the inputs and outputs.
+            ///
+            /// # Panics
+            /// Panics if `other` is zero, if the coefficients are [`ModNatural`]s with different
+            /// moduli, or if the leading coefficient of `other` is a [`ModNatural`] that is not
+            /// invertible.
+            ///
+            /// # Examples
+            /// See [here](super::div_rem#div).
+            fn div(self, other: &Polynomial<$t>) -> Polynomial<$t> {
+                Polynomial {
+                    coefficients: $f(self.coefficients, &other.coefficients).0,
+                }
+            }
+        }
+
+        impl Div<Polynomial<$t>> for &Polynomial<$t> {
+            type Output = Polynomial<$t>;
+
+            /// Divides a [`Polynomial`] by another [`Polynomial`], taking the first by reference
+            /// and the second by value. The remainder is discarded; see [`DivRem`] for details.
+            ///
+            /// # Worst-case complexity
+            /// $T(n, d) = O(d^2 n \log n \log\log n)$
+            ///
+            /// $M(n, d) = O(dn)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, $d$ is `self.degree()`, and $n$ is the
+            /// maximum number of significant bits of a coefficient of the inputs and outputs.
+            ///
+            /// # Panics
+            /// Panics if `other` is zero, if the coefficients are [`ModNatural`]s with different
+            /// moduli, or if the leading coefficient of `other` is a [`ModNatural`] that is not
+            /// invertible.
+            ///
+            /// # Examples
+            /// See [here](super::div_rem#div).
+            fn div(self, other: Polynomial<$t>) -> Polynomial<$t> {
+                Polynomial {
+                    coefficients: $f(self.coefficients.clone(), &other.coefficients).0,
+                }
+            }
+        }
+
+        impl Div<&Polynomial<$t>> for &Polynomial<$t> {
+            type Output = Polynomial<$t>;
+
+            /// Divides a [`Polynomial`] by another [`Polynomial`], taking both by reference. The
+            /// remainder is discarded; see [`DivRem`] for details.
+            ///
+            /// # Worst-case complexity
+            /// $T(n, d) = O(d^2 n \log n \log\log n)$
+            ///
+            /// $M(n, d) = O(dn)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, $d$ is `self.degree()`, and $n$ is the
+            /// maximum number of significant bits of a coefficient of the inputs and outputs.
+            ///
+            /// # Panics
+            /// Panics if `other` is zero, if the coefficients are [`ModNatural`]s with different
+            /// moduli, or if the leading coefficient of `other` is a [`ModNatural`] that is not
+            /// invertible.
+            ///
+            /// # Examples
+            /// See [here](super::div_rem#div).
+            fn div(self, other: &Polynomial<$t>) -> Polynomial<$t> {
+                Polynomial {
+                    coefficients: $f(self.coefficients.clone(), &other.coefficients).0,
+                }
+            }
+        }
+
+        impl Rem<Polynomial<$t>> for Polynomial<$t> {
+            type Output = Polynomial<$t>;
+
+            /// Finds the remainder when a [`Polynomial`] is divided by another [`Polynomial`],
+            /// taking both by value. See [`DivRem`] for details.
+            ///
+            /// # Worst-case complexity
+            /// $T(n, d) = O(d^2 n \log n \log\log n)$
+            ///
+            /// $M(n, d) = O(dn)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, $d$ is `self.degree()`, and $n$ is the
+            /// maximum number of significant bits of a coefficient of the inputs and outputs.
+            ///
+            /// # Panics
+            /// Panics if `other` is zero, if the coefficients are [`ModNatural`]s with different
+            /// moduli, or if the leading coefficient of `other` is a [`ModNatural`] that is not
+            /// invertible.
+            ///
+            /// # Examples
+            /// See [here](super::div_rem#rem).
+            fn rem(self, other: Polynomial<$t>) -> Polynomial<$t> {
+                Polynomial {
+                    coefficients: $f(self.coefficients, &other.coefficients).1,
+                }
+            }
+        }
+
+        impl Rem<&Polynomial<$t>> for Polynomial<$t> {
+            type Output = Polynomial<$t>;
+
+            /// Finds the remainder when a [`Polynomial`] is divided by another [`Polynomial`],
+            /// taking the first by value and the second by reference. See [`DivRem`] for details.
+            ///
+            /// # Worst-case complexity
+            /// $T(n, d) = O(d^2 n \log n \log\log n)$
+            ///
+            /// $M(n, d) = O(dn)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, $d$ is `self.degree()`, and $n$ is the
+            /// maximum number of significant bits of a coefficient of the inputs and outputs.
+            ///
+            /// # Panics
+            /// Panics if `other` is zero, if the coefficients are [`ModNatural`]s with different
+            /// moduli, or if the leading coefficient of `other` is a [`ModNatural`] that is not
+            /// invertible.
+            ///
+            /// # Examples
+            /// See [here](super::div_rem#rem).
+            fn rem(self, other: &Polynomial<$t>) -> Polynomial<$t> {
+                Polynomial {
+                    coefficients: $f(self.coefficients, &other.coefficients).1,
+                }
+            }
+        }
+
+        impl Rem<Polynomial<$t>> for &Polynomial<$t> {
+            type Output = Polynomial<$t>;
+
+            /// Finds the remainder when a [`Polynomial`] is divided by another [`Polynomial`],
+            /// taking the first by reference and the second by value. See [`DivRem`] for details.
+            ///
+            /// # Worst-case complexity
+            /// $T(n, d) = O(d^2 n \log n \log\log n)$
+            ///
+            /// $M(n, d) = O(dn)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, $d$ is `self.degree()`, and $n$ is the
+            /// maximum number of significant bits of a coefficient of the inputs and outputs.
+            ///
+            /// # Panics
+            /// Panics if `other` is zero, if the coefficients are [`ModNatural`]s with different
+            /// moduli, or if the leading coefficient of `other` is a [`ModNatural`] that is not
+            /// invertible.
+            ///
+            /// # Examples
+            /// See [here](super::div_rem#rem).
+            fn rem(self, other: Polynomial<$t>) -> Polynomial<$t> {
+                Polynomial {
+                    coefficients: $f(self.coefficients.clone(), &other.coefficients).1,
+                }
+            }
+        }
+
+        impl Rem<&Polynomial<$t>> for &Polynomial<$t> {
+            type Output = Polynomial<$t>;
+
+            /// Finds the remainder when a [`Polynomial`] is divided by another [`Polynomial`],
+            /// taking both by reference. See [`DivRem`] for details.
+            ///
+            /// # Worst-case complexity
+            /// $T(n, d) = O(d^2 n \log n \log\log n)$
+            ///
+            /// $M(n, d) = O(dn)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, $d$ is `self.degree()`, and $n$ is the
+            /// maximum number of significant bits of a coefficient of the inputs and outputs.
+            ///
+            /// # Panics
+            /// Panics if `other` is zero, if the coefficients are [`ModNatural`]s with different
+            /// moduli, or if the leading coefficient of `other` is a [`ModNatural`] that is not
+            /// invertible.
+            ///
+            /// # Examples
+            /// See [here](super::div_rem#rem).
+            fn rem(self, other: &Polynomial<$t>) -> Polynomial<$t> {
+                Polynomial {
+                    coefficients: $f(self.coefficients.clone(), &other.coefficients).1,
+                }
+            }
+        }
+
+        impl DivAssign<Polynomial<$t>> for Polynomial<$t> {
+            /// Divides a [`Polynomial`] by another [`Polynomial`] in place, taking the
+            /// [`Polynomial`] on the right-hand side by value. The remainder is discarded; see
+            /// [`DivRem`] for details.
+            ///
+            /// # Worst-case complexity
+            /// $T(n, d) = O(d^2 n \log n \log\log n)$
+            ///
+            /// $M(n, d) = O(dn)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, $d$ is `self.degree()`, and $n$ is the
+            /// maximum number of significant bits of a coefficient of the inputs and outputs.
+            ///
+            /// # Panics
+            /// Panics if `other` is zero, if the coefficients are [`ModNatural`]s with different
+            /// moduli, or if the leading coefficient of `other` is a [`ModNatural`] that is not
+            /// invertible.
+            ///
+            /// # Examples
+            /// See [here](super::div_rem#div_assign).
+            fn div_assign(&mut self, other: Polynomial<$t>) {
+                self.coefficients = $f(take(&mut self.coefficients), &other.coefficients).0;
+            }
+        }
+
+        impl DivAssign<&Polynomial<$t>> for Polynomial<$t> {
+            /// Divides a [`Polynomial`] by another [`Polynomial`] in place, taking the
+            /// [`Polynomial`] on the right-hand side by reference. The remainder is discarded; see
+            /// [`DivRem`] for details.
+            ///
+            /// # Worst-case complexity
+            /// $T(n, d) = O(d^2 n \log n \log\log n)$
+            ///
+            /// $M(n, d) = O(dn)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, $d$ is `self.degree()`, and $n$ is the
+            /// maximum number of significant bits of a coefficient of the inputs and outputs.
+            ///
+            /// # Panics
+            /// Panics if `other` is zero, if the coefficients are [`ModNatural`]s with different
+            /// moduli, or if the leading coefficient of `other` is a [`ModNatural`] that is not
+            /// invertible.
+            ///
+            /// # Examples
+            /// See [here](super::div_rem#div_assign).
+            fn div_assign(&mut self, other: &Polynomial<$t>) {
+                self.coefficients = $f(take(&mut self.coefficients), &other.coefficients).0;
+            }
+        }
+
+        impl RemAssign<Polynomial<$t>> for Polynomial<$t> {
+            /// Replaces a [`Polynomial`] with its remainder when divided by another [`Polynomial`],
+            /// taking the [`Polynomial`] on the right-hand side by value. See [`DivRem`] for
+            /// details.
+            ///
+            /// # Worst-case complexity
+            /// $T(n, d) = O(d^2 n \log n \log\log n)$
+            ///
+            /// $M(n, d) = O(dn)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, $d$ is `self.degree()`, and $n$ is the
+            /// maximum number of significant bits of a coefficient of the inputs and outputs.
+            ///
+            /// # Panics
+            /// Panics if `other` is zero, if the coefficients are [`ModNatural`]s with different
+            /// moduli, or if the leading coefficient of `other` is a [`ModNatural`] that is not
+            /// invertible.
+            ///
+            /// # Examples
+            /// See [here](super::div_rem#rem_assign).
+            fn rem_assign(&mut self, other: Polynomial<$t>) {
+                self.coefficients = $f(take(&mut self.coefficients), &other.coefficients).1;
+            }
+        }
+
+        impl RemAssign<&Polynomial<$t>> for Polynomial<$t> {
+            /// Replaces a [`Polynomial`] with its remainder when divided by another [`Polynomial`],
+            /// taking the [`Polynomial`] on the right-hand side by reference. See [`DivRem`] for
+            /// details.
+            ///
+            /// # Worst-case complexity
+            /// $T(n, d) = O(d^2 n \log n \log\log n)$
+            ///
+            /// $M(n, d) = O(dn)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, $d$ is `self.degree()`, and $n$ is the
+            /// maximum number of significant bits of a coefficient of the inputs and outputs.
+            ///
+            /// # Panics
+            /// Panics if `other` is zero, if the coefficients are [`ModNatural`]s with different
+            /// moduli, or if the leading coefficient of `other` is a [`ModNatural`] that is not
+            /// invertible.
+            ///
+            /// # Examples
+            /// See [here](super::div_rem#rem_assign).
+            fn rem_assign(&mut self, other: &Polynomial<$t>) {
+                self.coefficients = $f(take(&mut self.coefficients), &other.coefficients).1;
+            }
+        }
+    };
+}
+impl_div_rem!(Integer, div_rem_integer);
+impl_div_rem!(Rational, div_rem_rational);
+impl_div_rem!(ModNatural, div_rem_mod_natural);
@@ -0,0 +1,59 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::mod_natural::ModNatural;
+use crate::{Coefficient, Polynomial};
+use malachite_nz::integer::Integer;
+use malachite_q::Rational;
+
+// Evaluates a polynomial at `x` using Horner's method.
+pub(crate) fn evaluate_helper<T: Coefficient>(xs: &[T], x: &T) -> T {
+    let mut ys = xs.iter().rev();
+    let Some(y) = ys.next() else {
+        return x.zero_like();
+    };
+    let mut out = y.clone();
+    for y in ys {
+        out *= x;
+        out += y;
+    }
+    out
+}
+
+macro_rules! impl_evaluate {
+    ($t:ident) => {
+        impl Polynomial<$t> {
+            /// Evaluates a [`Polynomial`] at a point, taking both by reference.
+            ///
+            /// $f(p, x) = p(x)$.
+            ///
+            /// The evaluation uses Horner's method.
+            ///
+            /// # Worst-case complexity
+            /// $T(n, d) = O(d n \log n \log\log n)$
+            ///
+            /// $M(n, d) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, $d$ is `self.degree()`, and $n$ is the
+            /// number of significant bits of the result.
+            ///
+            /// # Panics
+            /// Panics if the coefficients and `x` are [`ModNatural`]s with different moduli.
+            ///
+            /// # Examples
+            /// See [here](super::evaluate#evaluate).
+            #[inline]
+            pub fn evaluate(&self, x: &$t) -> $t {
+                evaluate_helper(&self.coefficients, x)
+            }
+        }
+    };
+}
+impl_evaluate!(Integer);
+impl_evaluate!(Rational);
+impl_evaluate!(ModNatural);
@@ -0,0 +1,316 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::arithmetic::div_rem::div_rem_mod_natural;
+use crate::arithmetic::mul::clear_denominators;
+use crate::arithmetic::pseudo_div_rem::pseudo_div_rem_helper;
+use crate::mod_natural::ModNatural;
+use crate::Polynomial;
+use alloc::vec::Vec;
+use core::mem::swap;
+use malachite_base::num::arithmetic::traits::{
+    DivExact, DivExactAssign, Gcd, GcdAssign, NegAssign, Pow,
+};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_q::Rational;
+
+// Returns the GCD of the coefficients of a polynomial over $\Z$, or 0 if it is zero.
+pub(crate) fn content(xs: &[Integer]) -> Natural {
+    let mut c = Natural::ZERO;
+    for x in xs {
+        c = c.gcd(x.unsigned_abs_ref());
+        if c == 1u32 {
+            break;
+        }
+    }
+    c
+}
+
+// Divides each coefficient of a polynomial over $\Z$ by its content, and makes the leading
+// coefficient positive.
+pub(crate) fn make_primitive(xs: &mut [Integer]) {
+    let c = Integer::from(content(xs));
+    if c > 1u32 {
+        for x in xs.iter_mut() {
+            x.div_exact_assign(&c);
+        }
+    }
+    if xs.last().is_some_and(|x| *x < 0u32) {
+        for x in xs.iter_mut() {
+            x.neg_assign();
+        }
+    }
+}
+
+// Computes the GCD of two polynomials over $\Z$, with positive leading coefficient.
+//
+// This is Algorithm 3.3.1 from "A Course in Computational Algebraic Number Theory" by Henri
+// Cohen.
+pub(crate) fn gcd_integer(xs: &[Integer], ys: &[Integer]) -> Vec<Integer> {
+    let (mut a, mut b) = if xs.len() >= ys.len() {
+        (xs.to_vec(), ys.to_vec())
+    } else {
+        (ys.to_vec(), xs.to_vec())
+    };
+    if b.is_empty() {
+        if a.last().is_some_and(|x| *x < 0u32) {
+            for x in &mut a {
+                x.neg_assign();
+            }
+        }
+        return a;
+    }
+    let d = Integer::from(content(&a).gcd(content(&b)));
+    make_primitive(&mut a);
+    make_primitive(&mut b);
+    let mut g = Integer::ONE;
+    let mut h = Integer::ONE;
+    loop {
+        let delta = u64::exact_from(a.len() - b.len());
+        let mut r = pseudo_div_rem_helper(&a, &b, false).1;
+        if r.is_empty() {
+            break;
+        }
+        if r.len() == 1 {
+            b = vec![Integer::ONE];
+            break;
+        }
+        let divisor = &g * (&h).pow(delta);
+        for x in &mut r {
+            x.div_exact_assign(&divisor);
+        }
+        a = b;
+        b = r;
+        g = a.last().unwrap().clone();
+        if delta != 0 {
+            h = (&g).pow(delta).div_exact(h.pow(delta - 1));
+        }
+    }
+    make_primitive(&mut b);
+    if d != 1u32 {
+        for x in &mut b {
+            *x *= &d;
+        }
+    }
+    b
+}
+
+// Computes the monic GCD of two polynomials over $\Q$.
+fn gcd_rational(xs: &[Rational], ys: &[Rational]) -> Vec<Rational> {
+    let gs = gcd_integer(&clear_denominators(xs).0, &clear_denominators(ys).0);
+    let Some(leading) = gs.last() else {
+        return Vec::new();
+    };
+    let leading = leading.clone();
+    gs.into_iter()
+        .map(|x| Rational::from_integers_ref(&x, &leading))
+        .collect()
+}
+
+// Computes the monic GCD of two polynomials over $\Z/p\Z$, using the Euclidean algorithm.
+fn gcd_mod_natural(xs: &[ModNatural], ys: &[ModNatural]) -> Vec<ModNatural> {
+    let mut a = xs.to_vec();
+    let mut b = ys.to_vec();
+    if let (Some(x), Some(y)) = (a.first(), b.first()) {
+        assert_eq!(x.modulus, y.modulus, "The moduli must be equal");
+    }
+    while !b.is_empty() {
+        let r = div_rem_mod_natural(a, &b).1;
+        a = r;
+        swap(&mut a, &mut b);
+    }
+    if let Some(leading) = a.last() {
+        let inverse = leading
+            .inverse()
+            .expect("The leading coefficient must be invertible");
+        for x in &mut a {
+            *x *= &inverse;
+        }
+    }
+    a
+}
+
+macro_rules! impl_gcd {
+    ($t:ident, $f:ident) => {
+        impl Gcd<Polynomial<$t>> for Polynomial<$t> {
+            type Output = Polynomial<$t>;
+
+            /// Computes the GCD (greatest common divisor) of two [`Polynomial`]s, taking both by
+            /// value.
+            ///
+            /// Over $\Z$, the result is the GCD with positive leading coefficient, computed with
+            /// the subresultant algorithm. Over $\Q$ and $\Z/p\Z$, the result is the monic GCD. The
+            /// GCD of two zero [`Polynomial`]s is zero.
+            ///
+            /// # Worst-case complexity
+            /// $T(n, d) = O(d^3 n \log n \log\log n)$
+            ///
+            /// $M(n, d) = O(dn)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, $d$ is `max(self.degree(),
+            /// other.degree())`, and $n$ is the maximum number of significant bits of a coefficient
+            /// of the inputs.
+            ///
+            /// # Panics
+            /// Panics if the coefficients are [`ModNatural`]s with different moduli, or if a
+            /// non-invertible [`ModNatural`] is encountered as a leading coefficient. The latter
+            /// cannot happen if the modulus is prime.
+            ///
+            /// # Examples
+            /// See [here](super::gcd#gcd).
+            fn gcd(self, other: Polynomial<$t>) -> Polynomial<$t> {
+                Polynomial {
+                    coefficients: $f(&self.coefficients, &other.coefficients),
+                }
+            }
+        }
+
+        impl Gcd<&Polynomial<$t>> for Polynomial<$t> {
+            type Output = Polynomial<$t>;
+
+            /// Computes the GCD (greatest common divisor) of two [`Polynomial`]s, taking the first
+            /// by value and the second by reference.
+            ///
+            /// # Worst-case complexity
+            /// $T(n, d) = O(d^3 n \log n \log\log n)$
+            ///
+            /// $M(n, d) = O(dn)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, $d$ is `max(self.degree(),
+            /// other.degree())`, and $n$ is the maximum number of significant bits of a coefficient
+            /// of the inputs.
+            ///
+            /// # Panics
+            /// Panics if the coefficients are [`ModNatural`]s with different moduli, or if a
+            /// non-invertible [`ModNatural`] is encountered as a leading coefficient. The latter
+            /// cannot happen if the modulus is prime.
+            ///
+            /// # Examples
+            /// See [here](super::gcd#gcd).
+            fn gcd(self, other: &Polynomial<$t>) -> Polynomial<$t> {
+                Polynomial {
+                    coefficients: $f(&self.coefficients, &other.coefficients),
+                }
+            }
+        }
+
+        impl Gcd<Polynomial<$t>> for &Polynomial<$t> {
+            type Output = Polynomial<$t>;
+
+            /// Computes the GCD (greatest common divisor) of two [`Polynomial`]s, taking the first
+            /// by reference and the second by value.
+            ///
+            /// # Worst-case complexity
+            /// $T(n, d) = O(d^3 n \log n \log\log n)$
+            ///
+            /// $M(n, d) = O(dn)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, $d$ is `max(self.degree(),
+            /// other.degree())`, and $n$ is the maximum number of significant bits of a coefficient
+            /// of the inputs.
+            ///
+            /// # Panics
+            /// Panics if the coefficients are [`ModNatural`]s with different moduli, or if a
+            /// non-invertible [`ModNatural`] is encountered as a leading coefficient. The latter
+            /// cannot happen if the modulus is prime.
+            ///
+            /// # Examples
+            /// See [here](super::gcd#gcd).
+            fn gcd(self, other: Polynomial<$t>) -> Polynomial<$t> {
+                Polynomial {
+                    coefficients: $f(&self.coefficients, &other.coefficients),
+                }
+            }
+        }
+
+        impl Gcd<&Polynomial<$t>> for &Polynomial<$t> {
+            type Output = Polynomial<$t>;
+
+            /// Computes the GCD (greatest common divisor) of two [`Polynomial`]s, taking both by
+            /// reference.
+            ///
+            /// # Worst-case complexity
+            /// $T(n, d) = O(d^3 n \log n \log\log n)$
+            ///
+            /// $M(n, d) = O(dn)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, $d$ is `max(self.degree(),
+            /// other.degree())`, and $n$ is the maximum number of significant bits of a coefficient
+            /// of the inputs.
+            ///
+            /// # Panics
+            /// Panics if the coefficients are [`ModNatural`]s with different moduli, or if a
+            /// non-invertible [`ModNatural`] is encountered as a leading coefficient. The latter
+            /// cannot happen if the modulus is prime.
+            ///
+            /// # Examples
+            /// See [here](super::gcd#gcd).
+            fn gcd(self, other: &Polynomial<$t>) -> Polynomial<$t> {
+                Polynomial {
+                    coefficients: $f(&self.coefficients, &other.coefficients),
+                }
+            }
+        }
+
+        impl GcdAssign<Polynomial<$t>> for Polynomial<$t> {
+            /// Replaces a [`Polynomial`] with the GCD (greatest common divisor) of it and another
+            /// [`Polynomial`], taking the [`Polynomial`] on the right-hand side by value.
+            ///
+            /// # Worst-case complexity
+            /// $T(n, d) = O(d^3 n \log n \log\log n)$
+            ///
+            /// $M(n, d) = O(dn)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, $d$ is `max(self.degree(),
+            /// other.degree())`, and $n$ is the maximum number of significant bits of a coefficient
+            /// of the inputs.
+            ///
+            /// # Panics
+            /// Panics if the coefficients are [`ModNatural`]s with different moduli, or if a
+            /// non-invertible [`ModNatural`] is encountered as a leading coefficient. The latter
+            /// cannot happen if the modulus is prime.
+            ///
+            /// # Examples
+            /// See [here](super::gcd#gcd_assign).
+            fn gcd_assign(&mut self, other: Polynomial<$t>) {
+                self.coefficients = $f(&self.coefficients, &other.coefficients);
+            }
+        }
+
+        impl GcdAssign<&Polynomial<$t>> for Polynomial<$t> {
+            /// Replaces a [`Polynomial`] with the GCD (greatest common divisor) of it and another
+            /// [`Polynomial`], taking the [`Polynomial`] on the right-hand side by reference.
+            ///
+            /// # Worst-case complexity
+            /// $T(n, d) = O(d^3 n \log n \log\log n)$
+            ///
+            /// $M(n, d) = O(dn)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, $d$ is `max(self.degree(),
+            /// other.degree())`, and $n$ is the maximum number of significant bits of a coefficient
+            /// of the inputs.
+            ///
+            /// # Panics
+            /// Panics if the coefficients are [`ModNatural`]s with different moduli, or if a
+            /// non-invertible [`ModNatural`] is encountered as a leading coefficient. The latter
+            /// cannot happen if the modulus is prime.
+            ///
+            /// # Examples
+            /// See [here](super::gcd#gcd_assign).
+            fn gcd_assign(&mut self, other: &Polynomial<$t>) {
+                self.coefficients = $f(&self.coefficients, &other.coefficients);
+            }
+        }
+    };
+}
+impl_gcd!(Integer, gcd_integer);
+impl_gcd!(Rational, gcd_rational);
+impl_gcd!(ModNatural, gcd_mod_natural);
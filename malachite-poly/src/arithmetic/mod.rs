@@ -0,0 +1,324 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+/// Addition of [`Polynomial`](crate::Polynomial)s.
+///
+/// # add
+/// ```
+/// use malachite_nz::integer::Integer;
+/// use malachite_nz::natural::Natural;
+/// use malachite_poly::Polynomial;
+///
+/// let p = Polynomial::<Integer>::from_coefficients_asc(&[1, -2, 3].map(Integer::from));
+/// let q = Polynomial::<Integer>::from_coefficients_asc(&[5, 1, -3].map(Integer::from));
+/// assert_eq!((&p + &q).to_string(), "-x + 6");
+/// assert_eq!((p + Polynomial::zero()).to_string(), "3*x^2 - 2*x + 1");
+///
+/// let m = Natural::from(7u32);
+/// let p = Polynomial::from_naturals_asc(&[3u32, 6].map(Natural::from), &m);
+/// let q = Polynomial::from_naturals_asc(&[4u32, 1].map(Natural::from), &m);
+/// assert_eq!((p + q).to_string(), "0");
+/// ```
+///
+/// # add_assign
+/// ```
+/// use malachite_q::Rational;
+/// use malachite_poly::Polynomial;
+///
+/// let mut p = Polynomial::<Rational>::from_coefficients_asc(&[
+///     Rational::from_signeds(1, 3),
+///     Rational::from_signeds(1, 2),
+/// ]);
+/// p += Polynomial::<Rational>::from_coefficients_asc(&[
+///     Rational::from_signeds(2, 3),
+///     Rational::from_signeds(1, 2),
+/// ]);
+/// assert_eq!(p.to_string(), "x + 1");
+/// ```
+pub mod add;
+/// Composition of [`Polynomial`](crate::Polynomial)s.
+///
+/// # compose
+/// ```
+/// use malachite_nz::integer::Integer;
+/// use malachite_poly::Polynomial;
+///
+/// let p = Polynomial::<Integer>::from_coefficients_asc(&[1, 0, 1].map(Integer::from));
+/// let q = Polynomial::<Integer>::from_coefficients_asc(&[-1, 1].map(Integer::from));
+/// assert_eq!(p.compose(&q).to_string(), "x^2 - 2*x + 2");
+/// assert_eq!(q.compose(&p).to_string(), "x^2");
+/// assert_eq!(p.compose(&Polynomial::zero()).to_string(), "1");
+/// ```
+pub mod compose;
+/// Implementations of [`DivRem`](malachite_base::num::arithmetic::traits::DivRem), [`Div`],
+/// [`Rem`](core::ops::Rem), [`DivAssign`](core::ops::DivAssign), and
+/// [`RemAssign`](core::ops::RemAssign) for [`Polynomial`](crate::Polynomial)s.
+///
+/// # div_rem
+/// ```
+/// use malachite_base::num::arithmetic::traits::DivRem;
+/// use malachite_base::strings::ToDebugString;
+/// use malachite_nz::integer::Integer;
+/// use malachite_nz::natural::Natural;
+/// use malachite_poly::Polynomial;
+///
+/// let p = Polynomial::<Integer>::from_coefficients_asc(&[-4, 11, -17, 3].map(Integer::from));
+/// let q = Polynomial::<Integer>::from_coefficients_asc(&[-5, 1].map(Integer::from));
+/// assert_eq!(p.div_rem(q).to_debug_string(), "(3*x^2 - 2*x + 1, 1)");
+///
+/// // The leading coefficient of the divisor is not a unit
+/// let p = Polynomial::<Integer>::from_coefficients_asc(&[1, 0, 3].map(Integer::from));
+/// let q = Polynomial::<Integer>::from_coefficients_asc(&[0, 2].map(Integer::from));
+/// assert_eq!(p.div_rem(q).to_debug_string(), "(x, x^2 + 1)");
+///
+/// let m = Natural::from(7u32);
+/// let p = Polynomial::from_naturals_asc(&[1u32, 0, 1].map(Natural::from), &m);
+/// let q = Polynomial::from_naturals_asc(&[1u32, 2].map(Natural::from), &m);
+/// assert_eq!(p.div_rem(q).to_debug_string(), "(4*x + 5 mod 7, 3 mod 7)");
+/// ```
+///
+/// # div
+/// ```
+/// use malachite_q::Rational;
+/// use malachite_poly::Polynomial;
+///
+/// let p = Polynomial::<Rational>::from_coefficients_asc(&[1, 0, 1].map(Rational::from));
+/// let q = Polynomial::<Rational>::from_coefficients_asc(&[0, 2].map(Rational::from));
+/// assert_eq!((p / q).to_string(), "1/2*x");
+/// ```
+///
+/// # rem
+/// ```
+/// use malachite_q::Rational;
+/// use malachite_poly::Polynomial;
+///
+/// let p = Polynomial::<Rational>::from_coefficients_asc(&[1, 0, 1].map(Rational::from));
+/// let q = Polynomial::<Rational>::from_coefficients_asc(&[0, 2].map(Rational::from));
+/// assert_eq!((p % q).to_string(), "1");
+/// ```
+///
+/// # div_assign
+/// ```
+/// use malachite_nz::integer::Integer;
+/// use malachite_poly::Polynomial;
+///
+/// let mut p = Polynomial::<Integer>::from_coefficients_asc(&[-4, 11, -17, 3].map(Integer::from));
+/// p /= Polynomial::<Integer>::from_coefficients_asc(&[-5, 1].map(Integer::from));
+/// assert_eq!(p.to_string(), "3*x^2 - 2*x + 1");
+/// ```
+///
+/// # rem_assign
+/// ```
+/// use malachite_nz::integer::Integer;
+/// use malachite_poly::Polynomial;
+///
+/// let mut p = Polynomial::<Integer>::from_coefficients_asc(&[-4, 11, -17, 3].map(Integer::from));
+/// p %= Polynomial::<Integer>::from_coefficients_asc(&[-5, 1].map(Integer::from));
+/// assert_eq!(p.to_string(), "1");
+/// ```
+pub mod div_rem;
+/// Evaluation of [`Polynomial`](crate::Polynomial)s.
+///
+/// # evaluate
+/// ```
+/// use malachite_nz::integer::Integer;
+/// use malachite_nz::natural::Natural;
+/// use malachite_poly::mod_natural::ModNatural;
+/// use malachite_poly::Polynomial;
+/// use malachite_q::Rational;
+///
+/// let p = Polynomial::<Integer>::from_coefficients_asc(&[1, -2, 3].map(Integer::from));
+/// assert_eq!(p.evaluate(&Integer::from(2)), 9);
+/// assert_eq!(p.evaluate(&Integer::from(-1)), 6);
+///
+/// let p = Polynomial::<Rational>::from_coefficients_asc(&[
+///     Rational::from_signeds(1, 2),
+///     Rational::from(0),
+///     Rational::from(1),
+/// ]);
+/// assert_eq!(p.evaluate(&Rational::from_signeds(1, 2)).to_string(), "3/4");
+///
+/// let m = Natural::from(7u32);
+/// let p = Polynomial::from_naturals_asc(&[1u32, 5, 3].map(Natural::from), &m);
+/// assert_eq!(
+///     p.evaluate(&ModNatural::new(Natural::from(3u32), m)).to_string(),
+///     "1 mod 7"
+/// );
+/// ```
+pub mod evaluate;
+/// Implementations of [`Gcd`](malachite_base::num::arithmetic::traits::Gcd) and
+/// [`GcdAssign`](malachite_base::num::arithmetic::traits::GcdAssign), traits for computing the GCD
+/// (greatest common divisor) of two [`Polynomial`](crate::Polynomial)s.
+///
+/// # gcd
+/// ```
+/// use malachite_base::num::arithmetic::traits::Gcd;
+/// use malachite_nz::integer::Integer;
+/// use malachite_nz::natural::Natural;
+/// use malachite_poly::Polynomial;
+/// use malachite_q::Rational;
+///
+/// let p = Polynomial::<Integer>::from_coefficients_asc(&[-2, 0, 2].map(Integer::from));
+/// let q = Polynomial::<Integer>::from_coefficients_asc(&[6, 6].map(Integer::from));
+/// assert_eq!(p.gcd(q).to_string(), "2*x + 2");
+///
+/// let p = Polynomial::<Integer>::from_coefficients_asc(&[1, 0, 1].map(Integer::from));
+/// let q = Polynomial::<Integer>::from_coefficients_asc(&[0, 1].map(Integer::from));
+/// assert_eq!(p.gcd(q).to_string(), "1");
+///
+/// let p = Polynomial::<Rational>::from_coefficients_asc(&[-1, 0, 1].map(Rational::from));
+/// let q = Polynomial::<Rational>::from_coefficients_asc(&[
+///     Rational::from_signeds(1, 2),
+///     Rational::from_signeds(1, 2),
+/// ]);
+/// assert_eq!(p.gcd(q).to_string(), "x + 1");
+///
+/// let m = Natural::from(5u32);
+/// let p = Polynomial::from_naturals_asc(&[4u32, 0, 1].map(Natural::from), &m);
+/// let q = Polynomial::from_naturals_asc(&[1u32, 2, 1].map(Natural::from), &m);
+/// assert_eq!(p.gcd(q).to_string(), "x + 1 mod 5");
+/// ```
+///
+/// # gcd_assign
+/// ```
+/// use malachite_base::num::arithmetic::traits::GcdAssign;
+/// use malachite_nz::integer::Integer;
+/// use malachite_poly::Polynomial;
+///
+/// let mut p = Polynomial::<Integer>::from_coefficients_asc(&[-2, 0, 2].map(Integer::from));
+/// p.gcd_assign(Polynomial::<Integer>::from_coefficients_asc(&[6, 6].map(Integer::from)));
+/// assert_eq!(p.to_string(), "2*x + 2");
+/// ```
+pub mod gcd;
+/// Multiplication of [`Polynomial`](crate::Polynomial)s, using Kronecker substitution.
+///
+/// # mul
+/// ```
+/// use malachite_nz::integer::Integer;
+/// use malachite_nz::natural::Natural;
+/// use malachite_poly::Polynomial;
+/// use malachite_q::Rational;
+///
+/// let p = Polynomial::<Integer>::from_coefficients_asc(&[1, -2, 3].map(Integer::from));
+/// let q = Polynomial::<Integer>::from_coefficients_asc(&[-5, 1].map(Integer::from));
+/// assert_eq!((p * q).to_string(), "3*x^3 - 17*x^2 + 11*x - 5");
+///
+/// let p = Polynomial::<Integer>::from_coefficients_asc(&[1, 1].map(Integer::from));
+/// assert_eq!((&p * &p).to_string(), "x^2 + 2*x + 1");
+///
+/// let p = Polynomial::<Rational>::from_coefficients_asc(&[
+///     Rational::from_signeds(1, 3),
+///     Rational::from_signeds(1, 2),
+/// ]);
+/// let q = Polynomial::<Rational>::from_coefficients_asc(&[
+///     Rational::from(-1),
+///     Rational::from_signeds(2, 3),
+/// ]);
+/// assert_eq!((p * q).to_string(), "1/3*x^2 - 5/18*x - 1/3");
+///
+/// // Over Z/6Z, the degree of a product may be less than the sum of the degrees
+/// let m = Natural::from(6u32);
+/// let p = Polynomial::from_naturals_asc(&[3u32, 2].map(Natural::from), &m);
+/// let q = Polynomial::from_naturals_asc(&[2u32, 3].map(Natural::from), &m);
+/// assert_eq!((p * q).to_string(), "x mod 6");
+/// ```
+///
+/// # mul_assign
+/// ```
+/// use malachite_nz::integer::Integer;
+/// use malachite_poly::Polynomial;
+///
+/// let mut p = Polynomial::<Integer>::from_coefficients_asc(&[1, -2, 3].map(Integer::from));
+/// p *= Polynomial::<Integer>::from_coefficients_asc(&[-5, 1].map(Integer::from));
+/// assert_eq!(p.to_string(), "3*x^3 - 17*x^2 + 11*x - 5");
+/// ```
+pub mod mul;
+/// Negation of [`Polynomial`](crate::Polynomial)s.
+///
+/// # neg
+/// ```
+/// use malachite_nz::integer::Integer;
+/// use malachite_poly::Polynomial;
+///
+/// let p = Polynomial::<Integer>::from_coefficients_asc(&[1, -2, 3].map(Integer::from));
+/// assert_eq!((-&p).to_string(), "-3*x^2 + 2*x - 1");
+/// assert_eq!((-Polynomial::<Integer>::zero()).to_string(), "0");
+/// ```
+pub mod neg;
+/// Pseudo-division of [`Polynomial`](crate::Polynomial)s.
+///
+/// # pseudo_div_rem
+/// ```
+/// use malachite_base::strings::ToDebugString;
+/// use malachite_nz::integer::Integer;
+/// use malachite_poly::Polynomial;
+///
+/// // 2^2 * (x^2 + 1) = (2x + 1)(2x - 1) + 5
+/// let p = Polynomial::<Integer>::from_coefficients_asc(&[1, 0, 1].map(Integer::from));
+/// let q = Polynomial::<Integer>::from_coefficients_asc(&[1, 2].map(Integer::from));
+/// assert_eq!(p.pseudo_div_rem(&q).to_debug_string(), "(2*x - 1, 5)");
+/// ```
+pub mod pseudo_div_rem;
+/// Resultants of [`Polynomial`](crate::Polynomial)s.
+///
+/// # resultant
+/// ```
+/// use malachite_nz::integer::Integer;
+/// use malachite_nz::natural::Natural;
+/// use malachite_poly::Polynomial;
+/// use malachite_q::Rational;
+///
+/// let p = Polynomial::<Integer>::from_coefficients_asc(&[1, 0, 1].map(Integer::from));
+/// let q = Polynomial::<Integer>::from_coefficients_asc(&[-2, 1].map(Integer::from));
+/// assert_eq!(p.resultant(&q), 5);
+///
+/// let p = Polynomial::<Integer>::from_coefficients_asc(&[5, 2, 0, 1].map(Integer::from));
+/// let q = Polynomial::<Integer>::from_coefficients_asc(&[-7, 0, 3].map(Integer::from));
+/// assert_eq!(p.resultant(&q), -508);
+///
+/// // x^2 - 1 and x - 1 have a common root
+/// let p = Polynomial::<Integer>::from_coefficients_asc(&[-1, 0, 1].map(Integer::from));
+/// let q = Polynomial::<Integer>::from_coefficients_asc(&[-1, 1].map(Integer::from));
+/// assert_eq!(p.resultant(&q), 0);
+///
+/// let p = Polynomial::<Rational>::from_coefficients_asc(&[
+///     Rational::from(1),
+///     Rational::from_signeds(1, 2),
+/// ]);
+/// let q = Polynomial::<Rational>::from_coefficients_asc(&[1, 0, 1].map(Rational::from));
+/// assert_eq!(p.resultant(&q).to_string(), "5/4");
+///
+/// let m = Natural::from(7u32);
+/// let p = Polynomial::from_naturals_asc(&[1u32, 3, 1].map(Natural::from), &m);
+/// let q = Polynomial::from_naturals_asc(&[5u32, 0, 1].map(Natural::from), &m);
+/// assert_eq!(p.resultant(&q).to_string(), "5 mod 7");
+/// ```
+pub mod resultant;
+/// Subtraction of [`Polynomial`](crate::Polynomial)s.
+///
+/// # sub
+/// ```
+/// use malachite_nz::integer::Integer;
+/// use malachite_poly::Polynomial;
+///
+/// let p = Polynomial::<Integer>::from_coefficients_asc(&[1, -2, 3].map(Integer::from));
+/// let q = Polynomial::<Integer>::from_coefficients_asc(&[5, 1, -3].map(Integer::from));
+/// assert_eq!((&p - &q).to_string(), "6*x^2 - 3*x - 4");
+/// assert_eq!((&p - p.clone()).to_string(), "0");
+/// ```
+///
+/// # sub_assign
+/// ```
+/// use malachite_nz::integer::Integer;
+/// use malachite_poly::Polynomial;
+///
+/// let mut p = Polynomial::<Integer>::from_coefficients_asc(&[1, -2, 3].map(Integer::from));
+/// p -= Polynomial::<Integer>::from_coefficients_asc(&[5, 1, -3].map(Integer::from));
+/// assert_eq!(p.to_string(), "6*x^2 - 3*x - 4");
+/// ```
+pub mod sub;
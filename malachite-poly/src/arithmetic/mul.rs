@@ -0,0 +1,373 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::mod_natural::ModNatural;
+use crate::{trim, Coefficient, Polynomial};
+use alloc::vec::Vec;
+use core::cmp::{max, min};
+use core::ops::{Mul, MulAssign};
+use malachite_base::num::arithmetic::traits::{Lcm, PowerOf2, Square, UnsignedAbs};
+use malachite_base::num::basic::integers::PrimitiveInt;
+use malachite_base::num::basic::traits::One;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::{BitBlockAccess, SignificantBits};
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::platform::Limb;
+use malachite_q::Rational;
+
+// Packs `len` values into a single `Natural`, so that the $i$th value occupies bits $ib$ through
+// $(i+1)b-1$. A `None` value is treated as zero. Each value must be less than $2^b$.
+fn kronecker_pack<'a, I: Iterator<Item = Option<&'a Natural>>>(
+    xs: I,
+    len: usize,
+    b: u64,
+) -> Natural {
+    let total_bits = b * u64::exact_from(len);
+    let mut out = vec![0; usize::exact_from(total_bits >> Limb::LOG_WIDTH) + 2];
+    for (i, x) in xs.enumerate() {
+        let Some(x) = x else {
+            continue;
+        };
+        let offset = b * u64::exact_from(i);
+        let shift = offset & Limb::WIDTH_MASK;
+        for (j, limb) in (usize::exact_from(offset >> Limb::LOG_WIDTH)..).zip(x.limbs()) {
+            out[j] |= limb << shift;
+            if shift != 0 {
+                out[j + 1] |= limb >> (Limb::WIDTH - shift);
+            }
+        }
+    }
+    Natural::from_owned_limbs_asc(out)
+}
+
+// Evaluates a polynomial over $\Z$ at $2^b$. The absolute value of each coefficient must be less
+// than $2^b$.
+fn kronecker_pack_signed(xs: &[Integer], b: u64) -> Integer {
+    let positive = kronecker_pack(
+        xs.iter().map(|x| {
+            if *x > 0u32 {
+                Some(x.unsigned_abs_ref())
+            } else {
+                None
+            }
+        }),
+        xs.len(),
+        b,
+    );
+    let negative = kronecker_pack(
+        xs.iter().map(|x| {
+            if *x < 0u32 {
+                Some(x.unsigned_abs_ref())
+            } else {
+                None
+            }
+        }),
+        xs.len(),
+        b,
+    );
+    Integer::from(positive) - Integer::from(negative)
+}
+
+// Recovers `len` coefficients from the value of a polynomial over $\Z$ at $2^b$. The absolute value
+// of each coefficient must be less than $2^{b-1}$.
+fn kronecker_unpack_signed(x: Integer, len: usize, b: u64) -> Vec<Integer> {
+    let negative = x < 0u32;
+    let x = x.unsigned_abs();
+    let mut out = Vec::with_capacity(len);
+    let mut carry = false;
+    let mut start = 0;
+    for _ in 0..len {
+        let mut digit = x.get_bits(start, start + b);
+        start += b;
+        if carry {
+            digit += Natural::ONE;
+        }
+        // Digits of at least $2^{b-1}$ represent negative coefficients.
+        carry = digit.significant_bits() >= b;
+        let mut c = Integer::from(digit);
+        if carry {
+            c -= Integer::from(Natural::power_of_2(b));
+        }
+        out.push(if negative { -c } else { c });
+    }
+    out
+}
+
+fn max_significant_bits<'a, I: Iterator<Item = &'a Natural>>(xs: I) -> u64 {
+    xs.map(SignificantBits::significant_bits).max().unwrap_or(0)
+}
+
+// Multiplies two nonzero polynomials, one of which is a constant.
+fn mul_by_constant<T: Coefficient>(xs: &[T], ys: &[T]) -> Vec<T> {
+    let (c, xs) = if xs.len() == 1 {
+        (&xs[0], ys)
+    } else {
+        (&ys[0], xs)
+    };
+    let mut out: Vec<T> = xs.iter().map(|x| x.clone() * c.clone()).collect();
+    trim(&mut out);
+    out
+}
+
+// Multiplies two polynomials over $\Z$ using Kronecker substitution: both are evaluated at a
+// sufficiently large power of 2, the values are multiplied, and the product's coefficients are read
+// off from the bits of the result.
+//
+// Both slices must be nonempty and have nonzero last elements.
+pub(crate) fn mul_integer_coefficients(xs: &[Integer], ys: &[Integer]) -> Vec<Integer> {
+    if xs.len() == 1 || ys.len() == 1 {
+        return mul_by_constant(xs, ys);
+    }
+    let xs_bits = max_significant_bits(xs.iter().map(Integer::unsigned_abs_ref));
+    let ys_bits = max_significant_bits(ys.iter().map(Integer::unsigned_abs_ref));
+    // Each coefficient of the product has absolute value less than $2^{b-1}$.
+    let b = xs_bits + ys_bits + u64::exact_from(min(xs.len(), ys.len())).significant_bits() + 1;
+    let x = kronecker_pack_signed(xs, b);
+    let product = if xs == ys {
+        x.square()
+    } else {
+        x * kronecker_pack_signed(ys, b)
+    };
+    kronecker_unpack_signed(product, xs.len() + ys.len() - 1, b)
+}
+
+// Writes a polynomial over $\Q$ as a polynomial over $\Z$ divided by a positive integer $d$, where
+// $d$ is the least common multiple of the denominators of the coefficients.
+pub(crate) fn clear_denominators(xs: &[Rational]) -> (Vec<Integer>, Natural) {
+    let d = xs
+        .iter()
+        .fold(Natural::ONE, |d, x| d.lcm(x.denominator_ref()));
+    let ys = xs
+        .iter()
+        .map(|x| {
+            Integer::from_sign_and_abs(*x >= 0u32, x.numerator_ref() * (&d / x.denominator_ref()))
+        })
+        .collect();
+    (ys, d)
+}
+
+// Multiplies two polynomials over $\Q$ by clearing their denominators and multiplying over $\Z$.
+//
+// Both slices must be nonempty and have nonzero last elements.
+pub(crate) fn mul_rational_coefficients(xs: &[Rational], ys: &[Rational]) -> Vec<Rational> {
+    if xs.len() == 1 || ys.len() == 1 {
+        return mul_by_constant(xs, ys);
+    }
+    let (xs, xs_denominator) = clear_denominators(xs);
+    let (ys, ys_denominator) = clear_denominators(ys);
+    let denominator = Integer::from(xs_denominator * ys_denominator);
+    mul_integer_coefficients(&xs, &ys)
+        .into_iter()
+        .map(|x| Rational::from_integers_ref(&x, &denominator))
+        .collect()
+}
+
+// Multiplies two polynomials over $\Z/m\Z$ using Kronecker substitution. The coefficients are
+// lifted to $[0, m)$, so that the product can be computed without signs and reduced afterwards.
+//
+// Both slices must be nonempty and have nonzero last elements.
+pub(crate) fn mul_mod_natural_coefficients(
+    xs: &[ModNatural],
+    ys: &[ModNatural],
+) -> Vec<ModNatural> {
+    let m = &xs[0].modulus;
+    assert_eq!(*m, ys[0].modulus, "The moduli must be equal");
+    if xs.len() == 1 || ys.len() == 1 {
+        return mul_by_constant(xs, ys);
+    }
+    let bits = max(
+        max_significant_bits(xs.iter().map(ModNatural::value)),
+        max_significant_bits(ys.iter().map(ModNatural::value)),
+    );
+    // Each coefficient of the product over $\Z$ is less than $2^b$.
+    let b = (bits << 1) + u64::exact_from(min(xs.len(), ys.len())).significant_bits();
+    let x = kronecker_pack(xs.iter().map(|x| Some(&x.value)), xs.len(), b);
+    let product = if xs == ys {
+        x.square()
+    } else {
+        x * kronecker_pack(ys.iter().map(|y| Some(&y.value)), ys.len(), b)
+    };
+    let mut out = Vec::with_capacity(xs.len() + ys.len() - 1);
+    let mut start = 0;
+    for _ in 0..xs.len() + ys.len() - 1 {
+        out.push(ModNatural::new(
+            product.get_bits(start, start + b),
+            m.clone(),
+        ));
+        start += b;
+    }
+    trim(&mut out);
+    out
+}
+
+pub(crate) fn mul_helper<T: Coefficient>(xs: &[T], ys: &[T]) -> Vec<T> {
+    if xs.is_empty() || ys.is_empty() {
+        Vec::new()
+    } else {
+        T::mul_coefficients(xs, ys)
+    }
+}
+
+macro_rules! impl_mul {
+    ($t:ident) => {
+        impl Mul<Polynomial<$t>> for Polynomial<$t> {
+            type Output = Polynomial<$t>;
+
+            /// Multiplies two [`Polynomial`]s, taking both by value.
+            ///
+            /// The product is computed using Kronecker substitution: each factor is evaluated at a
+            /// power of 2 large enough that the product's coefficients can be recovered from the
+            /// product of the values, which is computed by a single
+            /// [`Natural`](malachite_nz::natural::Natural) multiplication.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n \log n \log\log n)$
+            ///
+            /// $M(n) = O(n \log n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in
+            /// the coefficients of `self` and `other`.
+            ///
+            /// # Panics
+            /// Panics if the coefficients are [`ModNatural`]s with different moduli.
+            ///
+            /// # Examples
+            /// See [here](super::mul#mul).
+            #[inline]
+            fn mul(self, other: Polynomial<$t>) -> Polynomial<$t> {
+                &self * &other
+            }
+        }
+
+        impl Mul<&Polynomial<$t>> for Polynomial<$t> {
+            type Output = Polynomial<$t>;
+
+            /// Multiplies two [`Polynomial`]s, taking the first by value and the second by
+            /// reference.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n \log n \log\log n)$
+            ///
+            /// $M(n) = O(n \log n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in
+            /// the coefficients of `self` and `other`.
+            ///
+            /// # Panics
+            /// Panics if the coefficients are [`ModNatural`]s with different moduli.
+            ///
+            /// # Examples
+            /// See [here](super::mul#mul).
+            #[inline]
+            fn mul(self, other: &Polynomial<$t>) -> Polynomial<$t> {
+                &self * other
+            }
+        }
+
+        impl Mul<Polynomial<$t>> for &Polynomial<$t> {
+            type Output = Polynomial<$t>;
+
+            /// Multiplies two [`Polynomial`]s, taking the first by reference and the second by
+            /// value.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n \log n \log\log n)$
+            ///
+            /// $M(n) = O(n \log n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in
+            /// the coefficients of `self` and `other`.
+            ///
+            /// # Panics
+            /// Panics if the coefficients are [`ModNatural`]s with different moduli.
+            ///
+            /// # Examples
+            /// See [here](super::mul#mul).
+            #[inline]
+            fn mul(self, other: Polynomial<$t>) -> Polynomial<$t> {
+                self * &other
+            }
+        }
+
+        impl Mul<&Polynomial<$t>> for &Polynomial<$t> {
+            type Output = Polynomial<$t>;
+
+            /// Multiplies two [`Polynomial`]s, taking both by reference.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n \log n \log\log n)$
+            ///
+            /// $M(n) = O(n \log n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in
+            /// the coefficients of `self` and `other`.
+            ///
+            /// # Panics
+            /// Panics if the coefficients are [`ModNatural`]s with different moduli.
+            ///
+            /// # Examples
+            /// See [here](super::mul#mul).
+            #[inline]
+            fn mul(self, other: &Polynomial<$t>) -> Polynomial<$t> {
+                Polynomial {
+                    coefficients: mul_helper(&self.coefficients, &other.coefficients),
+                }
+            }
+        }
+
+        impl MulAssign<Polynomial<$t>> for Polynomial<$t> {
+            /// Multiplies a [`Polynomial`] by a [`Polynomial`] in place, taking the
+            /// [`Polynomial`] on the right-hand side by value.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n \log n \log\log n)$
+            ///
+            /// $M(n) = O(n \log n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in
+            /// the coefficients of `self` and `other`.
+            ///
+            /// # Panics
+            /// Panics if the coefficients are [`ModNatural`]s with different moduli.
+            ///
+            /// # Examples
+            /// See [here](super::mul#mul_assign).
+            #[inline]
+            fn mul_assign(&mut self, other: Polynomial<$t>) {
+                *self *= &other;
+            }
+        }
+
+        impl MulAssign<&Polynomial<$t>> for Polynomial<$t> {
+            /// Multiplies a [`Polynomial`] by a [`Polynomial`] in place, taking the
+            /// [`Polynomial`] on the right-hand side by reference.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n \log n \log\log n)$
+            ///
+            /// $M(n) = O(n \log n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in
+            /// the coefficients of `self` and `other`.
+            ///
+            /// # Panics
+            /// Panics if the coefficients are [`ModNatural`]s with different moduli.
+            ///
+            /// # Examples
+            /// See [here](super::mul#mul_assign).
+            #[inline]
+            fn mul_assign(&mut self, other: &Polynomial<$t>) {
+                self.coefficients = mul_helper(&self.coefficients, &other.coefficients);
+            }
+        }
+    };
+}
+impl_mul!(Integer);
+impl_mul!(Rational);
+impl_mul!(ModNatural);
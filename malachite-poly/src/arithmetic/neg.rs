@@ -0,0 +1,66 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::mod_natural::ModNatural;
+use crate::Polynomial;
+use core::ops::Neg;
+use malachite_nz::integer::Integer;
+use malachite_q::Rational;
+
+macro_rules! impl_neg {
+    ($t:ident) => {
+        impl Neg for Polynomial<$t> {
+            type Output = Polynomial<$t>;
+
+            /// Negates a [`Polynomial`], taking it by value.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in
+            /// the coefficients of `self`.
+            ///
+            /// # Examples
+            /// See [here](super::neg#neg).
+            #[inline]
+            fn neg(self) -> Polynomial<$t> {
+                Polynomial {
+                    coefficients: self.coefficients.into_iter().map(Neg::neg).collect(),
+                }
+            }
+        }
+
+        impl Neg for &Polynomial<$t> {
+            type Output = Polynomial<$t>;
+
+            /// Negates a [`Polynomial`], taking it by reference.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in
+            /// the coefficients of `self`.
+            ///
+            /// # Examples
+            /// See [here](super::neg#neg).
+            #[inline]
+            fn neg(self) -> Polynomial<$t> {
+                Polynomial {
+                    coefficients: self.coefficients.iter().map(|x| -x.clone()).collect(),
+                }
+            }
+        }
+    };
+}
+impl_neg!(Integer);
+impl_neg!(Rational);
+impl_neg!(ModNatural);
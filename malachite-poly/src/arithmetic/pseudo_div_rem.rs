@@ -0,0 +1,116 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::mod_natural::ModNatural;
+use crate::{trim, Coefficient, Polynomial};
+use alloc::vec::Vec;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_nz::integer::Integer;
+use malachite_q::Rational;
+
+// Computes $q$ and $r$ such that $\ell^{m-n+1}x = qy + r$ and $\deg r < \deg y$, where $m$ and
+// $n$ are the degrees of $x$ and $y$ and $\ell$ is the leading coefficient of $y$. If $m < n$, then
+// $q = 0$ and $r = x$. `ys` must be nonempty and have a nonzero last element. If `want_quotient`
+// is false, the returned quotient is empty.
+//
+// This is Algorithm 3.1.2 from "A Course in Computational Algebraic Number Theory" by Henri
+// Cohen.
+pub(crate) fn pseudo_div_rem_helper<T: Coefficient>(
+    xs: &[T],
+    ys: &[T],
+    want_quotient: bool,
+) -> (Vec<T>, Vec<T>) {
+    let ys_len = ys.len();
+    if xs.len() < ys_len {
+        return (Vec::new(), xs.to_vec());
+    }
+    let leading = ys.last().unwrap();
+    let mut e = xs.len() - ys_len + 1;
+    let mut qs = if want_quotient {
+        vec![leading.zero_like(); e]
+    } else {
+        Vec::new()
+    };
+    let mut rs = xs.to_vec();
+    while rs.len() >= ys_len {
+        let k = rs.len() - ys_len;
+        let s = rs.last().unwrap().clone();
+        if want_quotient {
+            for q in &mut qs {
+                *q *= leading;
+            }
+            qs[k] += &s;
+        }
+        for r in &mut rs {
+            *r *= leading;
+        }
+        for (r, y) in rs[k..].iter_mut().zip(ys.iter()) {
+            let mut p = y.clone();
+            p *= &s;
+            *r -= p;
+        }
+        trim(&mut rs);
+        e -= 1;
+    }
+    if e != 0 {
+        let f = leading.clone().pow(u64::exact_from(e));
+        for q in &mut qs {
+            *q *= &f;
+        }
+        for r in &mut rs {
+            *r *= &f;
+        }
+        trim(&mut qs);
+        trim(&mut rs);
+    }
+    (qs, rs)
+}
+
+macro_rules! impl_pseudo_div_rem {
+    ($t:ident) => {
+        impl Polynomial<$t> {
+            /// Computes the pseudo-quotient and pseudo-remainder of two [`Polynomial`]s, taking
+            /// both by reference.
+            ///
+            /// Let $f$ be `self`, $g$ be `other`, $m$ and $n$ their degrees, and $\ell$ the
+            /// leading coefficient of $g$. If $m \geq n$, the pseudo-quotient $q$ and
+            /// pseudo-remainder $r$ satisfy $\ell^{m-n+1}f = gq + r$ and $\deg r < n$. Unlike
+            /// ordinary division, no coefficient ever needs to be divided, so this is useful over
+            /// rings such as $\Z$. If $m < n$, then $q = 0$ and $r = f$.
+            ///
+            /// # Worst-case complexity
+            /// $T(n, d) = O(d^2 n \log n \log\log n)$
+            ///
+            /// $M(n, d) = O(dn)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, $d$ is `self.degree()`, and $n$ is the
+            /// maximum number of significant bits of a coefficient of the inputs and outputs.
+            ///
+            /// # Panics
+            /// Panics if `other` is zero, or if the coefficients are [`ModNatural`]s with different
+            /// moduli.
+            ///
+            /// # Examples
+            /// See [here](super::pseudo_div_rem#pseudo_div_rem).
+            pub fn pseudo_div_rem(
+                &self,
+                other: &Polynomial<$t>,
+            ) -> (Polynomial<$t>, Polynomial<$t>) {
+                assert!(!other.coefficients.is_empty(), "division by zero");
+                let (q, r) = pseudo_div_rem_helper(&self.coefficients, &other.coefficients, true);
+                (
+                    Polynomial { coefficients: q },
+                    Polynomial { coefficients: r },
+                )
+            }
+        }
+    };
+}
+impl_pseudo_div_rem!(Integer);
+impl_pseudo_div_rem!(Rational);
+impl_pseudo_div_rem!(ModNatural);
@@ -0,0 +1,207 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::arithmetic::div_rem::div_rem_mod_natural;
+use crate::arithmetic::gcd::content;
+use crate::arithmetic::mul::clear_denominators;
+use crate::arithmetic::pseudo_div_rem::pseudo_div_rem_helper;
+use crate::mod_natural::ModNatural;
+use crate::{Coefficient, Polynomial};
+use malachite_base::num::arithmetic::traits::{DivExact, DivExactAssign, Parity, Pow};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_nz::integer::Integer;
+use malachite_q::Rational;
+
+// Computes the resultant of two polynomials over $\Z$.
+//
+// This is Algorithm 3.3.7 from "A Course in Computational Algebraic Number Theory" by Henri
+// Cohen.
+pub(crate) fn resultant_integer(xs: &[Integer], ys: &[Integer]) -> Integer {
+    if xs.is_empty() || ys.is_empty() {
+        return Integer::ZERO;
+    }
+    let (mut a, mut b, mut negate) = if xs.len() >= ys.len() {
+        (xs.to_vec(), ys.to_vec(), false)
+    } else {
+        (ys.to_vec(), xs.to_vec(), xs.len().even() && ys.len().even())
+    };
+    let a_content = Integer::from(content(&a));
+    let b_content = Integer::from(content(&b));
+    for x in &mut a {
+        x.div_exact_assign(&a_content);
+    }
+    for x in &mut b {
+        x.div_exact_assign(&b_content);
+    }
+    let a_degree = u64::exact_from(a.len() - 1);
+    let b_degree = u64::exact_from(b.len() - 1);
+    let t = a_content.pow(b_degree) * b_content.pow(a_degree);
+    let mut g = Integer::ONE;
+    let mut h = Integer::ONE;
+    while b.len() > 1 {
+        let delta = u64::exact_from(a.len() - b.len());
+        if a.len().even() && b.len().even() {
+            negate = !negate;
+        }
+        let mut r = pseudo_div_rem_helper(&a, &b, false).1;
+        if r.is_empty() {
+            return Integer::ZERO;
+        }
+        let divisor = &g * (&h).pow(delta);
+        for x in &mut r {
+            x.div_exact_assign(&divisor);
+        }
+        a = b;
+        b = r;
+        g = a.last().unwrap().clone();
+        if delta != 0 {
+            h = (&g).pow(delta).div_exact(h.pow(delta - 1));
+        }
+    }
+    let a_degree = u64::exact_from(a.len() - 1);
+    if a_degree != 0 {
+        h = (&b[0]).pow(a_degree).div_exact(h.pow(a_degree - 1));
+    }
+    let result = t * h;
+    if negate {
+        -result
+    } else {
+        result
+    }
+}
+
+fn resultant_rational(xs: &[Rational], ys: &[Rational]) -> Rational {
+    if xs.is_empty() || ys.is_empty() {
+        return Rational::ZERO;
+    }
+    let (xs_int, xs_denominator) = clear_denominators(xs);
+    let (ys_int, ys_denominator) = clear_denominators(ys);
+    // Res(A / a, B / b) = Res(A, B) / (a^deg(B) * b^deg(A))
+    Rational::from_integers(
+        resultant_integer(&xs_int, &ys_int),
+        Integer::from(
+            xs_denominator.pow(u64::exact_from(ys.len() - 1))
+                * ys_denominator.pow(u64::exact_from(xs.len() - 1)),
+        ),
+    )
+}
+
+// Computes the resultant of two polynomials over $\Z/p\Z$ using the Euclidean algorithm and the
+// identity $\operatorname{Res}(a, b) = (-1)^{\deg a \deg b} \ell^{\deg a - \deg r}
+// \operatorname{Res}(b, r)$, where $\ell$ is the leading coefficient of $b$ and $r$ is the
+// remainder of $a$ divided by $b$.
+fn resultant_mod_natural(xs: &[ModNatural], ys: &[ModNatural]) -> ModNatural {
+    let (a, b) = match (xs.first(), ys.first()) {
+        (Some(x), Some(y)) => {
+            assert_eq!(x.modulus, y.modulus, "The moduli must be equal");
+            (xs.to_vec(), ys.to_vec())
+        }
+        (Some(x), None) => return x.zero_like(),
+        (None, Some(y)) => return y.zero_like(),
+        (None, None) => {
+            panic!("Cannot determine the modulus of the resultant of two zero polynomials")
+        }
+    };
+    let mut result = a[0].one_like();
+    let mut a = a;
+    let mut b = b;
+    loop {
+        let a_degree = u64::exact_from(a.len() - 1);
+        if b.len() == 1 {
+            return result * b[0].clone().pow(a_degree);
+        }
+        let r = div_rem_mod_natural(a, &b).1;
+        if r.is_empty() {
+            return b[0].zero_like();
+        }
+        let b_degree = u64::exact_from(b.len() - 1);
+        result *= b
+            .last()
+            .unwrap()
+            .clone()
+            .pow(a_degree - u64::exact_from(r.len() - 1));
+        if a_degree.odd() && b_degree.odd() {
+            result = -result;
+        }
+        a = b;
+        b = r;
+    }
+}
+
+impl Polynomial<Integer> {
+    /// Computes the resultant of two [`Polynomial`]s over $\Z$, taking both by reference.
+    ///
+    /// The resultant of $f$ and $g$ is the determinant of their Sylvester matrix. It is zero if
+    /// and only if $f$ and $g$ have a common factor of positive degree, or if either is zero. It is
+    /// computed using the subresultant algorithm, so the intermediate coefficients never grow
+    /// larger than the minors of the Sylvester matrix.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, d) = O(d^3 n \log n \log\log n)$
+    ///
+    /// $M(n, d) = O(dn)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $d$ is `max(self.degree(), other.degree())`,
+    /// and $n$ is the number of significant bits of the result.
+    ///
+    /// # Examples
+    /// See [here](super::resultant#resultant).
+    pub fn resultant(&self, other: &Polynomial<Integer>) -> Integer {
+        resultant_integer(&self.coefficients, &other.coefficients)
+    }
+}
+
+impl Polynomial<Rational> {
+    /// Computes the resultant of two [`Polynomial`]s over $\Q$, taking both by reference.
+    ///
+    /// The resultant of $f$ and $g$ is the determinant of their Sylvester matrix. It is zero if
+    /// and only if $f$ and $g$ have a common factor of positive degree, or if either is zero. The
+    /// denominators are cleared, and the resultant is computed over $\Z$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, d) = O(d^3 n \log n \log\log n)$
+    ///
+    /// $M(n, d) = O(dn)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $d$ is `max(self.degree(), other.degree())`,
+    /// and $n$ is the number of significant bits of the result.
+    ///
+    /// # Examples
+    /// See [here](super::resultant#resultant).
+    pub fn resultant(&self, other: &Polynomial<Rational>) -> Rational {
+        resultant_rational(&self.coefficients, &other.coefficients)
+    }
+}
+
+impl Polynomial<ModNatural> {
+    /// Computes the resultant of two [`Polynomial`]s over $\Z/p\Z$, taking both by reference.
+    ///
+    /// The resultant of $f$ and $g$ is the determinant of their Sylvester matrix. Over a field,
+    /// it is zero if and only if $f$ and $g$ have a common factor of positive degree, or if either
+    /// is zero. It is computed using the Euclidean algorithm.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, d) = O(d^2 n \log n \log\log n)$
+    ///
+    /// $M(n, d) = O(dn)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $d$ is `max(self.degree(), other.degree())`,
+    /// and $n$ is the number of significant bits of the modulus.
+    ///
+    /// # Panics
+    /// Panics if both [`Polynomial`]s are zero (since the modulus of the result is then unknown),
+    /// if the moduli are different, or if a non-invertible leading coefficient is encountered. The
+    /// last cannot happen if the modulus is prime.
+    ///
+    /// # Examples
+    /// See [here](super::resultant#resultant).
+    pub fn resultant(&self, other: &Polynomial<ModNatural>) -> ModNatural {
+        resultant_mod_natural(&self.coefficients, &other.coefficients)
+    }
+}
@@ -0,0 +1,179 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::mod_natural::ModNatural;
+use crate::{trim, Coefficient, Polynomial};
+use alloc::vec::Vec;
+use core::ops::{Sub, SubAssign};
+use malachite_nz::integer::Integer;
+use malachite_q::Rational;
+
+pub(crate) fn sub_assign_helper<T: Coefficient>(xs: &mut Vec<T>, ys: &[T]) {
+    let xs_len = xs.len();
+    for (x, y) in xs.iter_mut().zip(ys.iter()) {
+        *x -= y;
+    }
+    if ys.len() > xs_len {
+        xs.extend(ys[xs_len..].iter().map(|y| -y.clone()));
+    }
+    trim(xs);
+}
+
+macro_rules! impl_sub {
+    ($t:ident) => {
+        impl Sub<Polynomial<$t>> for Polynomial<$t> {
+            type Output = Polynomial<$t>;
+
+            /// Subtracts a [`Polynomial`] by another [`Polynomial`], taking both by value.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in
+            /// the coefficients of `self` and `other`.
+            ///
+            /// # Panics
+            /// Panics if the coefficients are [`ModNatural`]s with different moduli.
+            ///
+            /// # Examples
+            /// See [here](super::sub#sub).
+            #[inline]
+            fn sub(mut self, other: Polynomial<$t>) -> Polynomial<$t> {
+                self -= other;
+                self
+            }
+        }
+
+        impl Sub<&Polynomial<$t>> for Polynomial<$t> {
+            type Output = Polynomial<$t>;
+
+            /// Subtracts a [`Polynomial`] by another [`Polynomial`], taking the first by value and
+            /// the second by reference.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in
+            /// the coefficients of `self` and `other`.
+            ///
+            /// # Panics
+            /// Panics if the coefficients are [`ModNatural`]s with different moduli.
+            ///
+            /// # Examples
+            /// See [here](super::sub#sub).
+            #[inline]
+            fn sub(mut self, other: &Polynomial<$t>) -> Polynomial<$t> {
+                self -= other;
+                self
+            }
+        }
+
+        impl Sub<Polynomial<$t>> for &Polynomial<$t> {
+            type Output = Polynomial<$t>;
+
+            /// Subtracts a [`Polynomial`] by another [`Polynomial`], taking the first by reference
+            /// and the second by value.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in
+            /// the coefficients of `self` and `other`.
+            ///
+            /// # Panics
+            /// Panics if the coefficients are [`ModNatural`]s with different moduli.
+            ///
+            /// # Examples
+            /// See [here](super::sub#sub).
+            #[inline]
+            fn sub(self, mut other: Polynomial<$t>) -> Polynomial<$t> {
+                other -= self;
+                -other
+            }
+        }
+
+        impl Sub<&Polynomial<$t>> for &Polynomial<$t> {
+            type Output = Polynomial<$t>;
+
+            /// Subtracts a [`Polynomial`] by another [`Polynomial`], taking both by reference.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in
+            /// the coefficients of `self` and `other`.
+            ///
+            /// # Panics
+            /// Panics if the coefficients are [`ModNatural`]s with different moduli.
+            ///
+            /// # Examples
+            /// See [here](super::sub#sub).
+            #[inline]
+            fn sub(self, other: &Polynomial<$t>) -> Polynomial<$t> {
+                self.clone() - other
+            }
+        }
+
+        impl SubAssign<Polynomial<$t>> for Polynomial<$t> {
+            /// Subtracts a [`Polynomial`] by another [`Polynomial`] in place, taking the
+            /// [`Polynomial`] on the right-hand side by value.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in
+            /// the coefficients of `self` and `other`.
+            ///
+            /// # Panics
+            /// Panics if the coefficients are [`ModNatural`]s with different moduli.
+            ///
+            /// # Examples
+            /// See [here](super::sub#sub_assign).
+            #[inline]
+            fn sub_assign(&mut self, other: Polynomial<$t>) {
+                sub_assign_helper(&mut self.coefficients, &other.coefficients);
+            }
+        }
+
+        impl SubAssign<&Polynomial<$t>> for Polynomial<$t> {
+            /// Subtracts a [`Polynomial`] by another [`Polynomial`] in place, taking the
+            /// [`Polynomial`] on the right-hand side by reference.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in
+            /// the coefficients of `self` and `other`.
+            ///
+            /// # Panics
+            /// Panics if the coefficients are [`ModNatural`]s with different moduli.
+            ///
+            /// # Examples
+            /// See [here](super::sub#sub_assign).
+            #[inline]
+            fn sub_assign(&mut self, other: &Polynomial<$t>) {
+                sub_assign_helper(&mut self.coefficients, &other.coefficients);
+            }
+        }
+    };
+}
+impl_sub!(Integer);
+impl_sub!(Rational);
+impl_sub!(ModNatural);
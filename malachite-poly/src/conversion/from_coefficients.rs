@@ -0,0 +1,126 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::mod_natural::ModNatural;
+use crate::{trim, Coefficient, Polynomial};
+use alloc::vec::Vec;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_q::Rational;
+
+macro_rules! impl_from_coefficients {
+    ($t:ident) => {
+        impl Polynomial<$t> {
+            #[doc = concat!(
+                "Converts a slice of [`", stringify!($t), "`]s to a [`Polynomial`]. The ",
+                "coefficients are given in ascending order of degree, and trailing zeros are ",
+                "removed."
+            )]
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in
+            /// `xs`.
+            ///
+            /// # Panics
+            /// Panics if the coefficients are [`ModNatural`]s with different moduli.
+            ///
+            /// # Examples
+            /// See [here](super::from_coefficients#from_coefficients_asc).
+            pub fn from_coefficients_asc(xs: &[$t]) -> Polynomial<$t> {
+                Polynomial::<$t>::from_owned_coefficients_asc(xs.to_vec())
+            }
+
+            #[doc = concat!(
+                "Converts a [`Vec`] of [`", stringify!($t), "`]s to a [`Polynomial`]. The ",
+                "coefficients are given in ascending order of degree, and trailing zeros are ",
+                "removed."
+            )]
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n)$
+            ///
+            /// $M(n) = O(1)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is `xs.len()`.
+            ///
+            /// # Panics
+            /// Panics if the coefficients are [`ModNatural`]s with different moduli.
+            ///
+            /// # Examples
+            /// See [here](super::from_coefficients#from_owned_coefficients_asc).
+            pub fn from_owned_coefficients_asc(mut xs: Vec<$t>) -> Polynomial<$t> {
+                assert!(
+                    xs.windows(2).all(|w| w[0].has_same_ring(&w[1])),
+                    "The moduli must be equal"
+                );
+                trim(&mut xs);
+                Polynomial { coefficients: xs }
+            }
+        }
+
+        impl From<$t> for Polynomial<$t> {
+            #[doc = concat!(
+                "Converts a [`", stringify!($t), "`] to a constant [`Polynomial`]."
+            )]
+            ///
+            /// # Worst-case complexity
+            /// Constant time and additional memory.
+            ///
+            /// # Examples
+            /// See [here](super::from_coefficients#from).
+            #[inline]
+            fn from(x: $t) -> Polynomial<$t> {
+                Polynomial::<$t>::from_owned_coefficients_asc(vec![x])
+            }
+        }
+    };
+}
+impl_from_coefficients!(Integer);
+impl_from_coefficients!(Rational);
+impl_from_coefficients!(ModNatural);
+
+impl Polynomial<ModNatural> {
+    /// Creates a [`Polynomial`] over $\Z/m\Z$ from a slice of [`Natural`]s. The coefficients are
+    /// given in ascending order of degree; each is reduced modulo $m$, and trailing zeros are
+    /// removed.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, k) = O(kn \log n \log\log n)$
+    ///
+    /// $M(n, k) = O(kn \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $n$ is the maximum number of significant bits
+    /// of `xs`, and $k$ is `xs.len()`.
+    ///
+    /// # Panics
+    /// Panics if `modulus` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_poly::Polynomial;
+    ///
+    /// let p = Polynomial::from_naturals_asc(
+    ///     &[Natural::from(8u32), Natural::from(1u32), Natural::from(14u32)],
+    ///     &Natural::from(7u32),
+    /// );
+    /// assert_eq!(p.to_string(), "x + 1 mod 7");
+    /// ```
+    pub fn from_naturals_asc(xs: &[Natural], modulus: &Natural) -> Polynomial<ModNatural> {
+        assert_ne!(*modulus, 0u32, "The modulus cannot be zero");
+        Polynomial::<ModNatural>::from_owned_coefficients_asc(
+            xs.iter()
+                .map(|x| ModNatural::new(x.clone(), modulus.clone()))
+                .collect(),
+        )
+    }
+}
@@ -0,0 +1,50 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+/// Functions for constructing a [`Polynomial`](crate::Polynomial) from its coefficients.
+///
+/// # from_coefficients_asc
+/// ```
+/// use malachite_nz::integer::Integer;
+/// use malachite_poly::Polynomial;
+///
+/// let p = Polynomial::<Integer>::from_coefficients_asc(&[1, -2, 3].map(Integer::from));
+/// assert_eq!(p.to_string(), "3*x^2 - 2*x + 1");
+///
+/// let p = Polynomial::<Integer>::from_coefficients_asc(&[5, 0].map(Integer::from));
+/// assert_eq!(p.to_string(), "5");
+/// ```
+///
+/// # from_owned_coefficients_asc
+/// ```
+/// use malachite_q::Rational;
+/// use malachite_poly::Polynomial;
+///
+/// let p = Polynomial::<Rational>::from_owned_coefficients_asc(vec![
+///     Rational::from_signeds(1, 2),
+///     Rational::from(0),
+///     Rational::from_signeds(-2, 3),
+/// ]);
+/// assert_eq!(p.to_string(), "-2/3*x^2 + 1/2");
+/// ```
+///
+/// # from
+/// ```
+/// use malachite_nz::integer::Integer;
+/// use malachite_poly::Polynomial;
+///
+/// assert_eq!(Polynomial::from(Integer::from(-3)).to_string(), "-3");
+/// assert_eq!(Polynomial::from(Integer::from(0)).to_string(), "0");
+/// ```
+pub mod from_coefficients;
+/// Functions for getting the coefficients, degree, and leading coefficient of a
+/// [`Polynomial`](crate::Polynomial).
+pub mod to_coefficients;
+/// Implementations of [`Display`](core::fmt::Display) and [`Debug`] for
+/// [`Polynomial`](crate::Polynomial)s.
+pub mod to_string;
@@ -0,0 +1,93 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Polynomial;
+use alloc::vec::Vec;
+use malachite_base::num::conversion::traits::ExactFrom;
+
+impl<T> Polynomial<T> {
+    /// Returns the coefficients of a [`Polynomial`], in ascending order of degree. The last
+    /// coefficient, if any, is nonzero.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_poly::Polynomial;
+    ///
+    /// let p = Polynomial::<Integer>::from_coefficients_asc(&[1, -2].map(Integer::from));
+    /// assert_eq!(p.coefficients(), &[Integer::from(1), Integer::from(-2)]);
+    /// assert!(Polynomial::<Integer>::zero().coefficients().is_empty());
+    /// ```
+    pub fn coefficients(&self) -> &[T] {
+        &self.coefficients
+    }
+
+    /// Returns the coefficients of a [`Polynomial`], in ascending order of degree, taking the
+    /// [`Polynomial`] by value. The last coefficient, if any, is nonzero.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_poly::Polynomial;
+    ///
+    /// let p = Polynomial::<Integer>::from_coefficients_asc(&[1, -2].map(Integer::from));
+    /// assert_eq!(p.into_coefficients(), &[Integer::from(1), Integer::from(-2)]);
+    /// ```
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn into_coefficients(self) -> Vec<T> {
+        self.coefficients
+    }
+
+    /// Returns the degree of a [`Polynomial`], or `None` if it is zero.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_poly::Polynomial;
+    ///
+    /// assert_eq!(Polynomial::<Integer>::zero().degree(), None);
+    /// assert_eq!(Polynomial::from(Integer::from(5)).degree(), Some(0));
+    /// assert_eq!(
+    ///     Polynomial::<Integer>::from_coefficients_asc(&[1, -2].map(Integer::from)).degree(),
+    ///     Some(1)
+    /// );
+    /// ```
+    pub fn degree(&self) -> Option<u64> {
+        self.coefficients.len().checked_sub(1).map(u64::exact_from)
+    }
+
+    /// Returns the leading coefficient of a [`Polynomial`], or `None` if it is zero.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_poly::Polynomial;
+    ///
+    /// assert_eq!(Polynomial::<Integer>::zero().leading_coefficient(), None);
+    /// assert_eq!(
+    ///     Polynomial::<Integer>::from_coefficients_asc(&[Integer::from(1), Integer::from(-2)])
+    ///         .leading_coefficient(),
+    ///     Some(&Integer::from(-2))
+    /// );
+    /// ```
+    pub fn leading_coefficient(&self) -> Option<&T> {
+        self.coefficients.last()
+    }
+}
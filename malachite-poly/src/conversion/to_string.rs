@@ -0,0 +1,200 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::mod_natural::ModNatural;
+use crate::Polynomial;
+use alloc::string::{String, ToString};
+use core::fmt::{Debug, Display, Formatter, Result, Write};
+use malachite_base::num::arithmetic::traits::Abs;
+use malachite_nz::integer::Integer;
+use malachite_q::Rational;
+
+// Writes the terms of a polynomial, from highest degree to lowest, omitting zero terms. For each
+// coefficient, `sign_and_abs` returns whether it is negative, and the string representation of its
+// absolute value, or `None` if it is zero.
+fn write_terms<T>(
+    f: &mut Formatter,
+    xs: &[T],
+    sign_and_abs: &dyn Fn(&T) -> Option<(bool, String)>,
+) -> Result {
+    if xs.is_empty() {
+        return f.write_char('0');
+    }
+    let mut first = true;
+    for (i, x) in xs.iter().enumerate().rev() {
+        let Some((negative, abs)) = sign_and_abs(x) else {
+            continue;
+        };
+        if first {
+            if negative {
+                f.write_char('-')?;
+            }
+            first = false;
+        } else {
+            f.write_str(if negative { " - " } else { " + " })?;
+        }
+        if i == 0 {
+            f.write_str(&abs)?;
+        } else {
+            if abs != "1" {
+                write!(f, "{abs}*")?;
+            }
+            if i == 1 {
+                f.write_char('x')?;
+            } else {
+                write!(f, "x^{i}")?;
+            }
+        }
+    }
+    Ok(())
+}
+
+impl Display for Polynomial<Integer> {
+    /// Converts a [`Polynomial`] over $\Z$ to a [`String`], writing its terms in descending order
+    /// of degree.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in the
+    /// coefficients of `self`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_poly::Polynomial;
+    ///
+    /// assert_eq!(Polynomial::<Integer>::zero().to_string(), "0");
+    /// assert_eq!(
+    ///     Polynomial::<Integer>::from_coefficients_asc(&[
+    ///         Integer::from(-1),
+    ///         Integer::from(0),
+    ///         Integer::from(1),
+    ///         Integer::from(-12)
+    ///     ])
+    ///     .to_string(),
+    ///     "-12*x^3 + x^2 - 1"
+    /// );
+    /// ```
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        write_terms(f, &self.coefficients, &|x| {
+            if *x == 0u32 {
+                None
+            } else {
+                Some((*x < 0u32, x.unsigned_abs_ref().to_string()))
+            }
+        })
+    }
+}
+
+impl Display for Polynomial<Rational> {
+    /// Converts a [`Polynomial`] over $\Q$ to a [`String`], writing its terms in descending order
+    /// of degree.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in the
+    /// coefficients of `self`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::Rational;
+    /// use malachite_poly::Polynomial;
+    ///
+    /// assert_eq!(Polynomial::<Rational>::zero().to_string(), "0");
+    /// assert_eq!(
+    ///     Polynomial::<Rational>::from_coefficients_asc(&[
+    ///         Rational::from_signeds(1, 2),
+    ///         Rational::from(-1),
+    ///         Rational::from_signeds(-22, 7)
+    ///     ])
+    ///     .to_string(),
+    ///     "-22/7*x^2 - x + 1/2"
+    /// );
+    /// ```
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        write_terms(f, &self.coefficients, &|x| {
+            if *x == 0u32 {
+                None
+            } else {
+                Some((*x < 0u32, x.abs().to_string()))
+            }
+        })
+    }
+}
+
+impl Display for Polynomial<ModNatural> {
+    /// Converts a [`Polynomial`] over $\Z/n\Z$ to a [`String`], writing its terms in descending
+    /// order of degree, followed by the modulus. The zero [`Polynomial`] is written as `"0"`.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in the
+    /// coefficients of `self`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_poly::Polynomial;
+    ///
+    /// assert_eq!(
+    ///     Polynomial::from_naturals_asc(
+    ///         &[Natural::from(3u32), Natural::from(0u32), Natural::from(1u32)],
+    ///         &Natural::from(5u32)
+    ///     )
+    ///     .to_string(),
+    ///     "x^2 + 3 mod 5"
+    /// );
+    /// ```
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        write_terms(f, &self.coefficients, &|x| {
+            if x.value == 0u32 {
+                None
+            } else {
+                Some((false, x.value.to_string()))
+            }
+        })?;
+        if let Some(x) = self.coefficients.first() {
+            write!(f, " mod {}", x.modulus)?;
+        }
+        Ok(())
+    }
+}
+
+macro_rules! impl_debug {
+    ($t:ident) => {
+        impl Debug for Polynomial<$t> {
+            /// Converts a [`Polynomial`] to a [`String`].
+            ///
+            /// This is the same as the [`Display::fmt`] implementation.
+            ///
+            /// # Worst-case complexity
+            /// $T(n) = O(n (\log n)^2 \log\log n)$
+            ///
+            /// $M(n) = O(n \log n)$
+            ///
+            /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in
+            /// the coefficients of `self`.
+            #[inline]
+            fn fmt(&self, f: &mut Formatter) -> Result {
+                Display::fmt(self, f)
+            }
+        }
+    };
+}
+impl_debug!(Integer);
+impl_debug!(Rational);
+impl_debug!(ModNatural);
@@ -0,0 +1,400 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+//! This crate defines [`Polynomial`]s, dense univariate polynomials whose coefficients are
+//! [`Integer`]s, [`Rational`]s, or [`ModNatural`]s (elements of $\Z/n\Z$).
+//! - The arithmetic you'd expect is available: addition, subtraction, multiplication, division
+//!   with remainder, and pseudo-division.
+//! - [`Polynomial`]s can be evaluated at a point and composed with each other.
+//! - GCDs and resultants are computed using the subresultant algorithm over $\Z$ and the Euclidean
+//!   algorithm over $\Z/p\Z$. [`Polynomial`]s over $\Q$ are reduced to [`Polynomial`]s over $\Z$.
+//! - Multiplication uses Kronecker substitution: the coefficients of each factor are packed into a
+//!   single [`Natural`](malachite_nz::natural::Natural), so that a polynomial product becomes one
+//!   large integer product. Large integer products are carried out by the Schönhage-Strassen FFT
+//!   multiplication in `malachite-nz`.
+//! - The real roots of a [`Polynomial`] over $\Z$ can be isolated in disjoint intervals with
+//!   [`Rational`] endpoints, using Descartes' rule of signs. The intervals can be refined to any
+//!   width, and the roots can be rounded to [`Float`](malachite_float::Float)s of any precision.
+//! - [`RealAlgebraic`](real_algebraic::RealAlgebraic)s represent real algebraic numbers exactly,
+//!   as a minimal polynomial together with an isolating interval. They support exact arithmetic
+//!   and comparison, and can be rounded to [`Float`](malachite_float::Float)s.
+//! - [`QuadraticIrrational`](quadratic_irrational::QuadraticIrrational)s represent numbers of the
+//!   form $(a + b\sqrt{d})/c$ exactly. Their continued fractions are computed exactly as
+//!   eventually periodic sequences, and they are used to solve Pell's equation.
+//!
+//! A [`Polynomial`] is stored as a [`Vec`](alloc::vec::Vec) of coefficients in ascending order of
+//! degree, with no trailing zeros. In particular, the zero polynomial has no coefficients.
+//!
+//! # Features
+//! - `32_bit_limbs`: Sets the type of [`Limb`](malachite_nz#limbs) to [`u32`] instead of the
+//!   default, [`u64`].
+//! - `test_build`: A large proportion of the code in this crate is only used for testing. For a
+//!   typical user, building this code would result in an unnecessarily long compilation time and
+//!   an unnecessarily large binary. My solution is to only build this code when the `test_build`
+//!   feature is enabled. If you want to run unit tests, you must enable `test_build`. However,
+//!   doctests don't require it, since they only test the public interface.
+//!
+//! [`Integer`]: malachite_nz::integer::Integer
+//! [`Rational`]: malachite_q::Rational
+//! [`ModNatural`]: mod_natural::ModNatural
+
+#![allow(
+    unstable_name_collisions,
+    clippy::assertions_on_constants,
+    clippy::cognitive_complexity,
+    clippy::many_single_char_names,
+    clippy::range_plus_one,
+    clippy::suspicious_arithmetic_impl,
+    clippy::suspicious_op_assign_impl,
+    clippy::too_many_arguments,
+    clippy::type_complexity,
+    clippy::upper_case_acronyms
+)]
+#![warn(
+    clippy::cast_lossless,
+    clippy::explicit_into_iter_loop,
+    clippy::explicit_iter_loop,
+    clippy::filter_map_next,
+    clippy::large_digit_groups,
+    clippy::manual_filter_map,
+    clippy::manual_find_map,
+    clippy::map_flatten,
+    clippy::map_unwrap_or,
+    clippy::match_same_arms,
+    clippy::missing_const_for_fn,
+    clippy::mut_mut,
+    clippy::needless_borrow,
+    clippy::needless_continue,
+    clippy::needless_pass_by_value,
+    clippy::print_stdout,
+    clippy::redundant_closure_for_method_calls,
+    clippy::single_match_else,
+    clippy::trait_duplication_in_bounds,
+    clippy::type_repetition_in_bounds,
+    clippy::uninlined_format_args,
+    clippy::unused_self,
+    clippy::if_not_else,
+    clippy::manual_assert,
+    clippy::range_plus_one,
+    clippy::redundant_else,
+    clippy::semicolon_if_nothing_returned,
+    clippy::cloned_instead_of_copied,
+    clippy::flat_map_option,
+    clippy::unnecessary_wraps,
+    clippy::unnested_or_patterns,
+    clippy::trivially_copy_pass_by_ref
+)]
+#![cfg_attr(not(any(feature = "test_build", feature = "random")), no_std)]
+
+#[macro_use]
+extern crate alloc;
+
+extern crate malachite_base;
+extern crate malachite_float;
+extern crate malachite_nz;
+extern crate malachite_q;
+
+use crate::mod_natural::ModNatural;
+use alloc::vec::Vec;
+use core::ops::{AddAssign, Mul, MulAssign, Neg, SubAssign};
+use malachite_base::num::arithmetic::traits::Pow;
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_nz::integer::Integer;
+use malachite_q::Rational;
+
+/// A dense univariate polynomial.
+///
+/// The coefficients are stored in ascending order of degree, and the highest coefficient is never
+/// zero; the zero polynomial has no coefficients. The coefficient type `T` may be
+/// [`Integer`](malachite_nz::integer::Integer), [`Rational`](malachite_q::Rational), or
+/// [`ModNatural`].
+#[derive(Clone, Hash, Eq, PartialEq)]
+pub struct Polynomial<T> {
+    pub(crate) coefficients: Vec<T>,
+}
+
+// The operations that the generic polynomial algorithms need from a coefficient type.
+pub(crate) trait Coefficient:
+    Clone
+    + Eq
+    + AddAssign
+    + for<'a> AddAssign<&'a Self>
+    + SubAssign
+    + for<'a> SubAssign<&'a Self>
+    + Mul<Output = Self>
+    + MulAssign
+    + for<'a> MulAssign<&'a Self>
+    + Neg<Output = Self>
+    + Pow<u64, Output = Self>
+{
+    fn is_zero_coefficient(&self) -> bool;
+
+    // Returns the zero of the ring that `self` belongs to. This only matters for `ModNatural`,
+    // where it determines the modulus.
+    fn zero_like(&self) -> Self;
+
+    fn one_like(&self) -> Self;
+
+    // Returns whether `self` and `other` belong to the same ring. This only matters for
+    // `ModNatural`, where it compares the moduli.
+    #[inline]
+    fn has_same_ring(&self, _other: &Self) -> bool {
+        true
+    }
+
+    // Multiplies two polynomials, given as nonempty slices of coefficients with nonzero last
+    // coefficients.
+    fn mul_coefficients(xs: &[Self], ys: &[Self]) -> Vec<Self>;
+}
+
+impl Coefficient for Integer {
+    #[inline]
+    fn is_zero_coefficient(&self) -> bool {
+        *self == 0u32
+    }
+
+    #[inline]
+    fn zero_like(&self) -> Integer {
+        Integer::ZERO
+    }
+
+    #[inline]
+    fn one_like(&self) -> Integer {
+        Integer::ONE
+    }
+
+    #[inline]
+    fn mul_coefficients(xs: &[Integer], ys: &[Integer]) -> Vec<Integer> {
+        arithmetic::mul::mul_integer_coefficients(xs, ys)
+    }
+}
+
+impl Coefficient for Rational {
+    #[inline]
+    fn is_zero_coefficient(&self) -> bool {
+        *self == 0u32
+    }
+
+    #[inline]
+    fn zero_like(&self) -> Rational {
+        Rational::ZERO
+    }
+
+    #[inline]
+    fn one_like(&self) -> Rational {
+        Rational::ONE
+    }
+
+    #[inline]
+    fn mul_coefficients(xs: &[Rational], ys: &[Rational]) -> Vec<Rational> {
+        arithmetic::mul::mul_rational_coefficients(xs, ys)
+    }
+}
+
+impl Coefficient for ModNatural {
+    #[inline]
+    fn is_zero_coefficient(&self) -> bool {
+        self.value == 0u32
+    }
+
+    #[inline]
+    fn zero_like(&self) -> ModNatural {
+        ModNatural {
+            value: malachite_nz::natural::Natural::ZERO,
+            modulus: self.modulus.clone(),
+        }
+    }
+
+    #[inline]
+    fn one_like(&self) -> ModNatural {
+        ModNatural::new(malachite_nz::natural::Natural::ONE, self.modulus.clone())
+    }
+
+    #[inline]
+    fn has_same_ring(&self, other: &ModNatural) -> bool {
+        self.modulus == other.modulus
+    }
+
+    #[inline]
+    fn mul_coefficients(xs: &[ModNatural], ys: &[ModNatural]) -> Vec<ModNatural> {
+        arithmetic::mul::mul_mod_natural_coefficients(xs, ys)
+    }
+}
+
+// Removes trailing zero coefficients.
+pub(crate) fn trim<T: Coefficient>(xs: &mut Vec<T>) {
+    while xs.last().is_some_and(T::is_zero_coefficient) {
+        xs.pop();
+    }
+}
+
+impl<T> Polynomial<T> {
+    /// The zero polynomial, which has no coefficients.
+    ///
+    /// This is a function rather than an implementation of
+    /// [`Zero`](malachite_base::num::basic::traits::Zero) so that it is available for every
+    /// coefficient type, including [`ModNatural`], whose zero depends on a modulus.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_poly::Polynomial;
+    ///
+    /// assert_eq!(Polynomial::<Integer>::zero().to_string(), "0");
+    /// ```
+    pub const fn zero() -> Polynomial<T> {
+        Polynomial {
+            coefficients: Vec::new(),
+        }
+    }
+}
+
+impl Polynomial<Integer> {
+    // Returns true iff `self` is valid. To be valid, its last coefficient, if any, must be nonzero.
+    // All `Polynomial`s must be valid.
+    #[cfg(feature = "test_build")]
+    pub fn is_valid(&self) -> bool {
+        self.coefficients.last() != Some(&Integer::ZERO)
+    }
+}
+
+impl Polynomial<Rational> {
+    // Returns true iff `self` is valid. To be valid, its last coefficient, if any, must be nonzero,
+    // and each of its coefficients must be valid. All `Polynomial`s must be valid.
+    #[cfg(feature = "test_build")]
+    pub fn is_valid(&self) -> bool {
+        self.coefficients.last() != Some(&Rational::ZERO)
+            && self.coefficients.iter().all(Rational::is_valid)
+    }
+}
+
+impl Polynomial<ModNatural> {
+    // Returns true iff `self` is valid. To be valid, its last coefficient, if any, must be nonzero,
+    // each of its coefficients must be valid, and all of its coefficients must have the same
+    // modulus. All `Polynomial`s must be valid.
+    #[cfg(feature = "test_build")]
+    pub fn is_valid(&self) -> bool {
+        self.coefficients.last().is_none_or(|c| c.value != 0u32)
+            && self.coefficients.iter().all(ModNatural::is_valid)
+            && self
+                .coefficients
+                .windows(2)
+                .all(|w| w[0].modulus == w[1].modulus)
+    }
+}
+
+/// Traits for arithmetic.
+pub mod arithmetic;
+/// Functions and traits for converting to and from [`Polynomial`]s, including converting to
+/// strings.
+pub mod conversion;
+/// The [`ModNatural`] type, representing an element of $\Z/n\Z$.
+///
+/// # add
+/// ```
+/// use malachite_nz::natural::Natural;
+/// use malachite_poly::mod_natural::ModNatural;
+///
+/// let x = ModNatural::new(Natural::from(5u32), Natural::from(7u32));
+/// let y = ModNatural::new(Natural::from(4u32), Natural::from(7u32));
+/// assert_eq!((&x + &y).to_string(), "2 mod 7");
+/// assert_eq!((x + y).to_string(), "2 mod 7");
+/// ```
+///
+/// # add_assign
+/// ```
+/// use malachite_nz::natural::Natural;
+/// use malachite_poly::mod_natural::ModNatural;
+///
+/// let mut x = ModNatural::new(Natural::from(5u32), Natural::from(7u32));
+/// x += ModNatural::new(Natural::from(4u32), Natural::from(7u32));
+/// assert_eq!(x.to_string(), "2 mod 7");
+/// ```
+///
+/// # sub
+/// ```
+/// use malachite_nz::natural::Natural;
+/// use malachite_poly::mod_natural::ModNatural;
+///
+/// let x = ModNatural::new(Natural::from(4u32), Natural::from(7u32));
+/// let y = ModNatural::new(Natural::from(5u32), Natural::from(7u32));
+/// assert_eq!((&x - &y).to_string(), "6 mod 7");
+/// assert_eq!((y - x).to_string(), "1 mod 7");
+/// ```
+///
+/// # sub_assign
+/// ```
+/// use malachite_nz::natural::Natural;
+/// use malachite_poly::mod_natural::ModNatural;
+///
+/// let mut x = ModNatural::new(Natural::from(4u32), Natural::from(7u32));
+/// x -= ModNatural::new(Natural::from(5u32), Natural::from(7u32));
+/// assert_eq!(x.to_string(), "6 mod 7");
+/// ```
+///
+/// # mul
+/// ```
+/// use malachite_nz::natural::Natural;
+/// use malachite_poly::mod_natural::ModNatural;
+///
+/// let x = ModNatural::new(Natural::from(5u32), Natural::from(7u32));
+/// let y = ModNatural::new(Natural::from(4u32), Natural::from(7u32));
+/// assert_eq!((&x * &y).to_string(), "6 mod 7");
+/// assert_eq!((x * y).to_string(), "6 mod 7");
+/// ```
+///
+/// # mul_assign
+/// ```
+/// use malachite_nz::natural::Natural;
+/// use malachite_poly::mod_natural::ModNatural;
+///
+/// let mut x = ModNatural::new(Natural::from(5u32), Natural::from(7u32));
+/// x *= ModNatural::new(Natural::from(4u32), Natural::from(7u32));
+/// assert_eq!(x.to_string(), "6 mod 7");
+/// ```
+///
+/// # neg
+/// ```
+/// use malachite_nz::natural::Natural;
+/// use malachite_poly::mod_natural::ModNatural;
+///
+/// let x = ModNatural::new(Natural::from(5u32), Natural::from(7u32));
+/// assert_eq!((-&x).to_string(), "2 mod 7");
+/// assert_eq!((-ModNatural::new(Natural::from(0u32), Natural::from(7u32))).to_string(), "0 mod 7");
+/// ```
+///
+/// # pow
+/// ```
+/// use malachite_base::num::arithmetic::traits::Pow;
+/// use malachite_nz::natural::Natural;
+/// use malachite_poly::mod_natural::ModNatural;
+///
+/// let x = ModNatural::new(Natural::from(3u32), Natural::from(7u32));
+/// assert_eq!((&x).pow(4).to_string(), "4 mod 7");
+/// assert_eq!(x.pow(0).to_string(), "1 mod 7");
+/// assert_eq!(
+///     ModNatural::new(Natural::from(3u32), Natural::from(1u32)).pow(0).to_string(),
+///     "0 mod 1"
+/// );
+/// ```
+pub mod mod_natural;
+/// The [`QuadraticIrrational`](quadratic_irrational::QuadraticIrrational) type, representing a
+/// real quadratic number exactly, together with its periodic continued fraction and solutions of
+/// Pell's equation.
+pub mod quadratic_irrational;
+/// The [`RealAlgebraic`](real_algebraic::RealAlgebraic) type, representing a real algebraic number.
+pub mod real_algebraic;
+
+/// Isolation and approximation of the real roots of [`Polynomial`]s over $\Z$.
+pub mod roots;
+
+#[cfg(feature = "test_build")]
+pub mod test_util;
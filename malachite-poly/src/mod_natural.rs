@@ -0,0 +1,441 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::fmt::{Debug, Display, Formatter, Result};
+use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use malachite_base::num::arithmetic::traits::{
+    ModAdd, ModAddAssign, ModInverse, ModMul, ModMulAssign, ModNeg, ModPow, ModSub, ModSubAssign,
+    Pow,
+};
+use malachite_base::num::basic::traits::Zero;
+use malachite_nz::natural::Natural;
+
+/// An element of $\Z/n\Z$, the integers modulo some positive $n$.
+///
+/// A [`ModNatural`] stores its value, which is always less than the modulus, together with the
+/// modulus. Arithmetic on two [`ModNatural`]s panics if their moduli differ.
+#[derive(Clone, Hash, Eq, PartialEq)]
+pub struct ModNatural {
+    pub(crate) value: Natural,
+    pub(crate) modulus: Natural,
+}
+
+impl ModNatural {
+    // Returns true iff `self` is valid. To be valid, its modulus must be positive and its value
+    // must be less than its modulus. All `ModNatural`s must be valid.
+    #[cfg(feature = "test_build")]
+    pub fn is_valid(&self) -> bool {
+        self.value < self.modulus
+    }
+
+    /// Creates a [`ModNatural`] from a value and a modulus. The value is reduced modulo the
+    /// modulus.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `value.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `modulus` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_poly::mod_natural::ModNatural;
+    ///
+    /// assert_eq!(
+    ///     ModNatural::new(Natural::from(3u32), Natural::from(7u32)).to_string(),
+    ///     "3 mod 7"
+    /// );
+    /// assert_eq!(
+    ///     ModNatural::new(Natural::from(10u32), Natural::from(7u32)).to_string(),
+    ///     "3 mod 7"
+    /// );
+    /// ```
+    pub fn new(value: Natural, modulus: Natural) -> ModNatural {
+        assert_ne!(modulus, 0u32, "The modulus cannot be zero");
+        ModNatural {
+            value: if value < modulus {
+                value
+            } else {
+                value % &modulus
+            },
+            modulus,
+        }
+    }
+
+    /// Returns the value of a [`ModNatural`], which is less than its modulus.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_poly::mod_natural::ModNatural;
+    ///
+    /// assert_eq!(
+    ///     *ModNatural::new(Natural::from(10u32), Natural::from(7u32)).value(),
+    ///     3
+    /// );
+    /// ```
+    pub const fn value(&self) -> &Natural {
+        &self.value
+    }
+
+    /// Returns the modulus of a [`ModNatural`].
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_poly::mod_natural::ModNatural;
+    ///
+    /// assert_eq!(
+    ///     *ModNatural::new(Natural::from(10u32), Natural::from(7u32)).modulus(),
+    ///     7
+    /// );
+    /// ```
+    pub const fn modulus(&self) -> &Natural {
+        &self.modulus
+    }
+
+    /// Returns the value of a [`ModNatural`], taking the [`ModNatural`] by value.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_poly::mod_natural::ModNatural;
+    ///
+    /// assert_eq!(
+    ///     ModNatural::new(Natural::from(10u32), Natural::from(7u32)).into_value(),
+    ///     3
+    /// );
+    /// ```
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn into_value(self) -> Natural {
+        self.value
+    }
+
+    /// Returns the multiplicative inverse of a [`ModNatural`], or `None` if it is not invertible.
+    ///
+    /// $f(x) = y$, where $xy \equiv 1 \mod n$, if such a $y$ exists.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.modulus().significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_poly::mod_natural::ModNatural;
+    ///
+    /// let x = ModNatural::new(Natural::from(3u32), Natural::from(7u32));
+    /// assert_eq!(x.inverse().unwrap().to_string(), "5 mod 7");
+    ///
+    /// let x = ModNatural::new(Natural::from(4u32), Natural::from(6u32));
+    /// assert_eq!(x.inverse(), None);
+    /// ```
+    pub fn inverse(&self) -> Option<ModNatural> {
+        if self.modulus == 1u32 {
+            // In the zero ring, 0 is its own inverse
+            Some(self.clone())
+        } else if self.value == 0u32 {
+            None
+        } else {
+            (&self.value)
+                .mod_inverse(&self.modulus)
+                .map(|value| ModNatural {
+                    value,
+                    modulus: self.modulus.clone(),
+                })
+        }
+    }
+}
+
+fn assert_same_modulus(x: &ModNatural, y: &ModNatural) {
+    assert_eq!(x.modulus, y.modulus, "The moduli must be equal");
+}
+
+macro_rules! impl_binary_op {
+    (
+        $trait:ident,
+        $fn:ident,
+        $assign_trait:ident,
+        $assign_fn:ident,
+        $mod_fn:ident,
+        $mod_assign_fn:ident,
+        $name:literal
+    ) => {
+        impl $trait<ModNatural> for ModNatural {
+            type Output = ModNatural;
+
+            #[doc = concat!(
+                "Computes the ", $name, " of two [`ModNatural`]s, taking both by value."
+            )]
+            ///
+            /// # Panics
+            /// Panics if the moduli are not equal.
+            ///
+            /// # Examples
+            #[doc = concat!("See [here](super::mod_natural#", stringify!($fn), ").")]
+            #[inline]
+            fn $fn(mut self, other: ModNatural) -> ModNatural {
+                self.$assign_fn(other);
+                self
+            }
+        }
+
+        impl $trait<&ModNatural> for ModNatural {
+            type Output = ModNatural;
+
+            #[doc = concat!(
+                "Computes the ", $name, " of two [`ModNatural`]s, taking the first by value and ",
+                "the second by reference."
+            )]
+            ///
+            /// # Panics
+            /// Panics if the moduli are not equal.
+            ///
+            /// # Examples
+            #[doc = concat!("See [here](super::mod_natural#", stringify!($fn), ").")]
+            #[inline]
+            fn $fn(mut self, other: &ModNatural) -> ModNatural {
+                self.$assign_fn(other);
+                self
+            }
+        }
+
+        impl $trait<ModNatural> for &ModNatural {
+            type Output = ModNatural;
+
+            #[doc = concat!(
+                "Computes the ", $name, " of two [`ModNatural`]s, taking the first by reference ",
+                "and the second by value."
+            )]
+            ///
+            /// # Panics
+            /// Panics if the moduli are not equal.
+            ///
+            /// # Examples
+            #[doc = concat!("See [here](super::mod_natural#", stringify!($fn), ").")]
+            #[inline]
+            fn $fn(self, other: ModNatural) -> ModNatural {
+                assert_same_modulus(self, &other);
+                ModNatural {
+                    value: (&self.value).$mod_fn(other.value, &self.modulus),
+                    modulus: other.modulus,
+                }
+            }
+        }
+
+        impl $trait<&ModNatural> for &ModNatural {
+            type Output = ModNatural;
+
+            #[doc = concat!(
+                "Computes the ", $name, " of two [`ModNatural`]s, taking both by reference."
+            )]
+            ///
+            /// # Panics
+            /// Panics if the moduli are not equal.
+            ///
+            /// # Examples
+            #[doc = concat!("See [here](super::mod_natural#", stringify!($fn), ").")]
+            #[inline]
+            fn $fn(self, other: &ModNatural) -> ModNatural {
+                assert_same_modulus(self, other);
+                ModNatural {
+                    value: (&self.value).$mod_fn(&other.value, &self.modulus),
+                    modulus: self.modulus.clone(),
+                }
+            }
+        }
+
+        impl $assign_trait<ModNatural> for ModNatural {
+            #[doc = concat!(
+                "Replaces a [`ModNatural`] with its ", $name, " with another [`ModNatural`], ",
+                "taking the [`ModNatural`] on the right-hand side by value."
+            )]
+            ///
+            /// # Panics
+            /// Panics if the moduli are not equal.
+            ///
+            /// # Examples
+            #[doc = concat!("See [here](super::mod_natural#", stringify!($assign_fn), ").")]
+            #[inline]
+            fn $assign_fn(&mut self, other: ModNatural) {
+                assert_same_modulus(self, &other);
+                self.value.$mod_assign_fn(other.value, &self.modulus);
+            }
+        }
+
+        impl $assign_trait<&ModNatural> for ModNatural {
+            #[doc = concat!(
+                "Replaces a [`ModNatural`] with its ", $name, " with another [`ModNatural`], ",
+                "taking the [`ModNatural`] on the right-hand side by reference."
+            )]
+            ///
+            /// # Panics
+            /// Panics if the moduli are not equal.
+            ///
+            /// # Examples
+            #[doc = concat!("See [here](super::mod_natural#", stringify!($assign_fn), ").")]
+            #[inline]
+            fn $assign_fn(&mut self, other: &ModNatural) {
+                assert_same_modulus(self, other);
+                self.value.$mod_assign_fn(&other.value, &self.modulus);
+            }
+        }
+    };
+}
+impl_binary_op!(
+    Add,
+    add,
+    AddAssign,
+    add_assign,
+    mod_add,
+    mod_add_assign,
+    "sum"
+);
+impl_binary_op!(
+    Sub,
+    sub,
+    SubAssign,
+    sub_assign,
+    mod_sub,
+    mod_sub_assign,
+    "difference"
+);
+impl_binary_op!(
+    Mul,
+    mul,
+    MulAssign,
+    mul_assign,
+    mod_mul,
+    mod_mul_assign,
+    "product"
+);
+
+impl Neg for ModNatural {
+    type Output = ModNatural;
+
+    /// Negates a [`ModNatural`], taking it by value.
+    ///
+    /// # Examples
+    /// See [here](super::mod_natural#neg).
+    fn neg(self) -> ModNatural {
+        ModNatural {
+            value: self.value.mod_neg(&self.modulus),
+            modulus: self.modulus,
+        }
+    }
+}
+
+impl Neg for &ModNatural {
+    type Output = ModNatural;
+
+    /// Negates a [`ModNatural`], taking it by reference.
+    ///
+    /// # Examples
+    /// See [here](super::mod_natural#neg).
+    fn neg(self) -> ModNatural {
+        ModNatural {
+            value: (&self.value).mod_neg(&self.modulus),
+            modulus: self.modulus.clone(),
+        }
+    }
+}
+
+impl Pow<u64> for ModNatural {
+    type Output = ModNatural;
+
+    /// Raises a [`ModNatural`] to a power, taking it by value.
+    ///
+    /// $0^0$ is 1, unless the modulus is 1.
+    ///
+    /// # Examples
+    /// See [here](super::mod_natural#pow).
+    #[inline]
+    fn pow(self, exp: u64) -> ModNatural {
+        (&self).pow(exp)
+    }
+}
+
+impl Pow<u64> for &ModNatural {
+    type Output = ModNatural;
+
+    /// Raises a [`ModNatural`] to a power, taking it by reference.
+    ///
+    /// $0^0$ is 1, unless the modulus is 1.
+    ///
+    /// # Examples
+    /// See [here](super::mod_natural#pow).
+    fn pow(self, exp: u64) -> ModNatural {
+        ModNatural {
+            value: if self.modulus == 1u32 {
+                Natural::ZERO
+            } else {
+                (&self.value).mod_pow(Natural::from(exp), &self.modulus)
+            },
+            modulus: self.modulus.clone(),
+        }
+    }
+}
+
+impl Display for ModNatural {
+    /// Converts a [`ModNatural`] to a [`String`](alloc::string::String), in the form `"x mod n"`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_poly::mod_natural::ModNatural;
+    ///
+    /// assert_eq!(
+    ///     ModNatural::new(Natural::from(123u32), Natural::from(1000u32)).to_string(),
+    ///     "123 mod 1000"
+    /// );
+    /// ```
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        write!(f, "{} mod {}", self.value, self.modulus)
+    }
+}
+
+impl Debug for ModNatural {
+    /// Converts a [`ModNatural`] to a [`String`](alloc::string::String).
+    ///
+    /// This is the same as the [`Display::fmt`] implementation.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_poly::mod_natural::ModNatural;
+    ///
+    /// assert_eq!(
+    ///     format!(
+    ///         "{:?}",
+    ///         ModNatural::new(Natural::from(123u32), Natural::from(1000u32))
+    ///     ),
+    ///     "123 mod 1000"
+    /// );
+    /// ```
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        Display::fmt(self, f)
+    }
+}
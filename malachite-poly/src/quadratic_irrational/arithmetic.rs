@@ -0,0 +1,428 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::quadratic_irrational::QuadraticIrrational;
+use core::cmp::Ordering::{self, *};
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use malachite_base::num::arithmetic::traits::{
+    Ceiling, DivRound, Floor, FloorSqrt, NegAssign, Reciprocal, Sign, Square,
+};
+use malachite_base::num::basic::traits::One;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+
+// Returns the sign of $a + b\sqrt{d}$, where $d$ is not a perfect square unless $b = 0$.
+pub(crate) fn sign_of_sum(a: &Integer, b: &Integer, d: &Natural) -> Ordering {
+    let sign_a = a.sign();
+    let sign_b = b.sign();
+    if sign_b == Equal || sign_a == sign_b {
+        sign_a
+    } else if sign_a == Equal {
+        sign_b
+    } else if a.square() > b.square() * Integer::from(d) {
+        sign_a
+    } else {
+        sign_b
+    }
+}
+
+// Returns the radicand shared by `x` and `y`, if one of them is rational or if both have the same
+// radicand.
+fn common_radicand<'a>(x: &'a QuadraticIrrational, y: &'a QuadraticIrrational) -> &'a Natural {
+    if x.is_rational() {
+        &y.d
+    } else if y.is_rational() || x.d == y.d {
+        &x.d
+    } else {
+        panic!(
+            "Cannot combine QuadraticIrrationals with different radicands {} and {}",
+            x.d, y.d
+        );
+    }
+}
+
+fn neg_helper(x: &QuadraticIrrational) -> QuadraticIrrational {
+    QuadraticIrrational {
+        a: -&x.a,
+        b: -&x.b,
+        c: x.c.clone(),
+        d: x.d.clone(),
+    }
+}
+
+fn add_helper(x: &QuadraticIrrational, y: &QuadraticIrrational) -> QuadraticIrrational {
+    let d = common_radicand(x, y).clone();
+    let x_c = Integer::from(&x.c);
+    let y_c = Integer::from(&y.c);
+    QuadraticIrrational::from_squarefree_radicand(
+        &x.a * &y_c + &y.a * &x_c,
+        &x.b * &y_c + &y.b * &x_c,
+        x_c * y_c,
+        d,
+    )
+}
+
+fn sub_helper(x: &QuadraticIrrational, y: &QuadraticIrrational) -> QuadraticIrrational {
+    add_helper(x, &neg_helper(y))
+}
+
+fn mul_helper(x: &QuadraticIrrational, y: &QuadraticIrrational) -> QuadraticIrrational {
+    let d = common_radicand(x, y).clone();
+    QuadraticIrrational::from_squarefree_radicand(
+        &x.a * &y.a + &x.b * &y.b * Integer::from(&d),
+        &x.a * &y.b + &x.b * &y.a,
+        Integer::from(&x.c * &y.c),
+        d,
+    )
+}
+
+// $c/(a + b\sqrt{d}) = c(a - b\sqrt{d})/(a^2 - b^2d)$, and the denominator is nonzero since $d$ is
+// not a perfect square.
+fn reciprocal_helper(x: &QuadraticIrrational) -> QuadraticIrrational {
+    assert!(
+        !(x.is_rational() && x.a == 0u32),
+        "Cannot take reciprocal of zero"
+    );
+    let c = Integer::from(&x.c);
+    QuadraticIrrational::from_squarefree_radicand(
+        &x.a * &c,
+        -&x.b * c,
+        (&x.a).square() - (&x.b).square() * Integer::from(&x.d),
+        x.d.clone(),
+    )
+}
+
+fn div_helper(x: &QuadraticIrrational, y: &QuadraticIrrational) -> QuadraticIrrational {
+    assert!(!(y.is_rational() && y.a == 0u32), "division by zero");
+    common_radicand(x, y);
+    mul_helper(x, &reciprocal_helper(y))
+}
+
+impl Neg for QuadraticIrrational {
+    type Output = QuadraticIrrational;
+
+    /// Negates a [`QuadraticIrrational`], taking it by value.
+    ///
+    /// # Examples
+    /// See [here](super::arithmetic#neg).
+    #[inline]
+    fn neg(mut self) -> QuadraticIrrational {
+        self.a.neg_assign();
+        self.b.neg_assign();
+        self
+    }
+}
+
+impl Neg for &QuadraticIrrational {
+    type Output = QuadraticIrrational;
+
+    /// Negates a [`QuadraticIrrational`], taking it by reference.
+    ///
+    /// # Examples
+    /// See [here](super::arithmetic#neg).
+    #[inline]
+    fn neg(self) -> QuadraticIrrational {
+        neg_helper(self)
+    }
+}
+
+macro_rules! impl_binary_op {
+    (
+        $trait:ident,
+        $fn:ident,
+        $assign_trait:ident,
+        $assign_fn:ident,
+        $helper:ident,
+        $name:literal,
+        $panics:literal
+    ) => {
+        impl $trait<QuadraticIrrational> for QuadraticIrrational {
+            type Output = QuadraticIrrational;
+
+            #[doc = concat!(
+                "Computes the ", $name, " of two [`QuadraticIrrational`]s, taking both by value."
+            )]
+            ///
+            /// # Panics
+            #[doc = $panics]
+            ///
+            /// # Examples
+            #[doc = concat!("See [here](super::arithmetic#", stringify!($fn), ").")]
+            #[inline]
+            fn $fn(self, other: QuadraticIrrational) -> QuadraticIrrational {
+                $helper(&self, &other)
+            }
+        }
+
+        impl $trait<&QuadraticIrrational> for QuadraticIrrational {
+            type Output = QuadraticIrrational;
+
+            #[doc = concat!(
+                "Computes the ", $name, " of two [`QuadraticIrrational`]s, taking the first by ",
+                "value and the second by reference."
+            )]
+            ///
+            /// # Panics
+            #[doc = $panics]
+            ///
+            /// # Examples
+            #[doc = concat!("See [here](super::arithmetic#", stringify!($fn), ").")]
+            #[inline]
+            fn $fn(self, other: &QuadraticIrrational) -> QuadraticIrrational {
+                $helper(&self, other)
+            }
+        }
+
+        impl $trait<QuadraticIrrational> for &QuadraticIrrational {
+            type Output = QuadraticIrrational;
+
+            #[doc = concat!(
+                "Computes the ", $name, " of two [`QuadraticIrrational`]s, taking the first by ",
+                "reference and the second by value."
+            )]
+            ///
+            /// # Panics
+            #[doc = $panics]
+            ///
+            /// # Examples
+            #[doc = concat!("See [here](super::arithmetic#", stringify!($fn), ").")]
+            #[inline]
+            fn $fn(self, other: QuadraticIrrational) -> QuadraticIrrational {
+                $helper(self, &other)
+            }
+        }
+
+        impl $trait<&QuadraticIrrational> for &QuadraticIrrational {
+            type Output = QuadraticIrrational;
+
+            #[doc = concat!(
+                "Computes the ", $name, " of two [`QuadraticIrrational`]s, taking both by ",
+                "reference."
+            )]
+            ///
+            /// # Panics
+            #[doc = $panics]
+            ///
+            /// # Examples
+            #[doc = concat!("See [here](super::arithmetic#", stringify!($fn), ").")]
+            #[inline]
+            fn $fn(self, other: &QuadraticIrrational) -> QuadraticIrrational {
+                $helper(self, other)
+            }
+        }
+
+        impl $assign_trait<QuadraticIrrational> for QuadraticIrrational {
+            #[doc = concat!(
+                "Replaces a [`QuadraticIrrational`] with its ", $name, " with another ",
+                "[`QuadraticIrrational`], taking the [`QuadraticIrrational`] on the right-hand ",
+                "side by value."
+            )]
+            ///
+            /// # Panics
+            #[doc = $panics]
+            ///
+            /// # Examples
+            #[doc = concat!("See [here](super::arithmetic#", stringify!($assign_fn), ").")]
+            #[inline]
+            fn $assign_fn(&mut self, other: QuadraticIrrational) {
+                *self = $helper(self, &other);
+            }
+        }
+
+        impl $assign_trait<&QuadraticIrrational> for QuadraticIrrational {
+            #[doc = concat!(
+                "Replaces a [`QuadraticIrrational`] with its ", $name, " with another ",
+                "[`QuadraticIrrational`], taking the [`QuadraticIrrational`] on the right-hand ",
+                "side by reference."
+            )]
+            ///
+            /// # Panics
+            #[doc = $panics]
+            ///
+            /// # Examples
+            #[doc = concat!("See [here](super::arithmetic#", stringify!($assign_fn), ").")]
+            #[inline]
+            fn $assign_fn(&mut self, other: &QuadraticIrrational) {
+                *self = $helper(self, other);
+            }
+        }
+    };
+}
+impl_binary_op!(
+    Add,
+    add,
+    AddAssign,
+    add_assign,
+    add_helper,
+    "sum",
+    "Panics if both [`QuadraticIrrational`]s are irrational and have different radicands."
+);
+impl_binary_op!(
+    Sub,
+    sub,
+    SubAssign,
+    sub_assign,
+    sub_helper,
+    "difference",
+    "Panics if both [`QuadraticIrrational`]s are irrational and have different radicands."
+);
+impl_binary_op!(
+    Mul,
+    mul,
+    MulAssign,
+    mul_assign,
+    mul_helper,
+    "product",
+    "Panics if both [`QuadraticIrrational`]s are irrational and have different radicands."
+);
+impl_binary_op!(
+    Div,
+    div,
+    DivAssign,
+    div_assign,
+    div_helper,
+    "quotient",
+    "Panics if the second [`QuadraticIrrational`] is zero, or if both [`QuadraticIrrational`]s \
+    are irrational and have different radicands."
+);
+
+impl Reciprocal for QuadraticIrrational {
+    type Output = QuadraticIrrational;
+
+    /// Takes the reciprocal of a [`QuadraticIrrational`], taking it by value.
+    ///
+    /// $f((a + b\sqrt{d})/c) = c(a - b\sqrt{d})/(a^2 - b^2d)$.
+    ///
+    /// # Panics
+    /// Panics if `self` is zero.
+    ///
+    /// # Examples
+    /// See [here](super::arithmetic#reciprocal).
+    #[inline]
+    fn reciprocal(self) -> QuadraticIrrational {
+        reciprocal_helper(&self)
+    }
+}
+
+impl Reciprocal for &QuadraticIrrational {
+    type Output = QuadraticIrrational;
+
+    /// Takes the reciprocal of a [`QuadraticIrrational`], taking it by reference.
+    ///
+    /// $f((a + b\sqrt{d})/c) = c(a - b\sqrt{d})/(a^2 - b^2d)$.
+    ///
+    /// # Panics
+    /// Panics if `self` is zero.
+    ///
+    /// # Examples
+    /// See [here](super::arithmetic#reciprocal).
+    #[inline]
+    fn reciprocal(self) -> QuadraticIrrational {
+        reciprocal_helper(self)
+    }
+}
+
+impl Sign for QuadraticIrrational {
+    /// Compares a [`QuadraticIrrational`] to zero.
+    ///
+    /// Returns `Greater`, `Equal`, or `Less`, depending on whether the [`QuadraticIrrational`] is
+    /// positive, zero, or negative, respectively. If $a$ and $b$ have opposite signs, the sign of
+    /// $a + b\sqrt{d}$ is found by comparing $a^2$ with $b^2d$.
+    ///
+    /// # Examples
+    /// See [here](super::arithmetic#sign).
+    #[inline]
+    fn sign(&self) -> Ordering {
+        sign_of_sum(&self.a, &self.b, &self.d)
+    }
+}
+
+// If $s = \lfloor \sqrt{b^2d} \rfloor$, then $|b|\sqrt{d}$ lies strictly between $s$ and $s + 1$,
+// and since $c$ is a positive integer, $\lfloor (a + b\sqrt{d})/c \rfloor$ is $\lfloor (a + s)/c
+// \rfloor$ if $b > 0$ and $\lfloor (a - s - 1)/c \rfloor$ if $b < 0$.
+fn floor_helper(x: &QuadraticIrrational) -> Integer {
+    let c = Integer::from(&x.c);
+    let numerator = match x.b.sign() {
+        Equal => x.a.clone(),
+        sign => {
+            let s = Integer::from((x.b.unsigned_abs_ref().square() * &x.d).floor_sqrt());
+            if sign == Greater {
+                &x.a + s
+            } else {
+                &x.a - s - Integer::ONE
+            }
+        }
+    };
+    numerator.div_round(c, RoundingMode::Floor).0
+}
+
+impl Floor for QuadraticIrrational {
+    type Output = Integer;
+
+    /// Finds the floor of a [`QuadraticIrrational`], taking it by value.
+    ///
+    /// $f(x) = \lfloor x \rfloor$.
+    ///
+    /// # Examples
+    /// See [here](super::arithmetic#floor).
+    #[inline]
+    fn floor(self) -> Integer {
+        floor_helper(&self)
+    }
+}
+
+impl Floor for &QuadraticIrrational {
+    type Output = Integer;
+
+    /// Finds the floor of a [`QuadraticIrrational`], taking it by reference.
+    ///
+    /// $f(x) = \lfloor x \rfloor$.
+    ///
+    /// # Examples
+    /// See [here](super::arithmetic#floor).
+    #[inline]
+    fn floor(self) -> Integer {
+        floor_helper(self)
+    }
+}
+
+impl Ceiling for QuadraticIrrational {
+    type Output = Integer;
+
+    /// Finds the ceiling of a [`QuadraticIrrational`], taking it by value.
+    ///
+    /// $f(x) = \lceil x \rceil$.
+    ///
+    /// # Examples
+    /// See [here](super::arithmetic#ceiling).
+    #[inline]
+    fn ceiling(self) -> Integer {
+        (&self).ceiling()
+    }
+}
+
+impl Ceiling for &QuadraticIrrational {
+    type Output = Integer;
+
+    /// Finds the ceiling of a [`QuadraticIrrational`], taking it by reference.
+    ///
+    /// $f(x) = \lceil x \rceil$.
+    ///
+    /// # Examples
+    /// See [here](super::arithmetic#ceiling).
+    fn ceiling(self) -> Integer {
+        if self.is_rational() {
+            (&self.a)
+                .div_round(Integer::from(&self.c), RoundingMode::Ceiling)
+                .0
+        } else {
+            floor_helper(self) + Integer::ONE
+        }
+    }
+}
@@ -0,0 +1,153 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::quadratic_irrational::arithmetic::sign_of_sum;
+use crate::quadratic_irrational::QuadraticIrrational;
+use core::cmp::Ordering::{self, *};
+use malachite_base::num::arithmetic::traits::{Sign, Square};
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_q::Rational;
+
+// Returns the sign of $u + v\sqrt{d_1} + w\sqrt{d_2}$, where $v$ and $w$ are nonzero and $d_1$
+// and $d_2$ are distinct squarefree numbers greater than 1.
+//
+// Let $t = v\sqrt{d_1} + w\sqrt{d_2}$. Neither $t$ nor $u + t$ can be zero, since $\sqrt{d_1}$,
+// $\sqrt{d_2}$, and $\sqrt{d_1d_2}$ are linearly independent over $\Q$. If $u$ and $t$ have
+// opposite signs, the larger of $u^2$ and $t^2 = v^2d_1 + w^2d_2 + 2vw\sqrt{d_1d_2}$ decides.
+fn sign_of_two_radical_sum(
+    u: &Integer,
+    v: &Integer,
+    d_1: &Natural,
+    w: &Integer,
+    d_2: &Natural,
+) -> Ordering {
+    let v_squared_d_1 = v.square() * Integer::from(d_1);
+    let w_squared_d_2 = w.square() * Integer::from(d_2);
+    let sign_t = if v.sign() == w.sign() || v_squared_d_1 > w_squared_d_2 {
+        v.sign()
+    } else {
+        w.sign()
+    };
+    let sign_u = u.sign();
+    if sign_u == Equal || sign_u == sign_t {
+        return sign_t;
+    }
+    let e = v_squared_d_1 + w_squared_d_2 - u.square();
+    let f = (v * w) << 1u32;
+    if sign_of_sum(&e, &f, &(d_1 * d_2)) == Greater {
+        sign_t
+    } else {
+        sign_u
+    }
+}
+
+impl Ord for QuadraticIrrational {
+    /// Compares two [`QuadraticIrrational`]s.
+    ///
+    /// If the numbers have the same radicand, or one of them is rational, the sign of their
+    /// difference is computed. Otherwise, the sign of $u + v\sqrt{d_1} + w\sqrt{d_2}$ is found
+    /// by comparing squares.
+    ///
+    /// # Examples
+    /// See [here](super::comparison#cmp).
+    fn cmp(&self, other: &QuadraticIrrational) -> Ordering {
+        if self.is_rational() || other.is_rational() || self.d == other.d {
+            return (self - other).sign();
+        }
+        let self_c = Integer::from(&self.c);
+        let other_c = Integer::from(&other.c);
+        sign_of_two_radical_sum(
+            &(&self.a * &other_c - &other.a * &self_c),
+            &(&self.b * other_c),
+            &self.d,
+            &-(&other.b * self_c),
+            &other.d,
+        )
+    }
+}
+
+impl PartialOrd for QuadraticIrrational {
+    /// Compares two [`QuadraticIrrational`]s.
+    ///
+    /// See the documentation for the [`Ord`] implementation.
+    #[inline]
+    fn partial_cmp(&self, other: &QuadraticIrrational) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq<Rational> for QuadraticIrrational {
+    /// Determines whether a [`QuadraticIrrational`] is equal to a [`Rational`].
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_poly::quadratic_irrational::QuadraticIrrational;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(QuadraticIrrational::sqrt_of(Natural::from(4u32)), Rational::from(2));
+    /// assert_ne!(QuadraticIrrational::sqrt_of(Natural::from(2u32)), Rational::from(2));
+    /// ```
+    fn eq(&self, other: &Rational) -> bool {
+        self.is_rational()
+            && (*other >= 0u32) == (self.a >= 0u32)
+            && self.a.unsigned_abs_ref() == other.numerator_ref()
+            && self.c == *other.denominator_ref()
+    }
+}
+
+impl PartialEq<QuadraticIrrational> for Rational {
+    /// Determines whether a [`Rational`] is equal to a [`QuadraticIrrational`].
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_poly::quadratic_irrational::QuadraticIrrational;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(Rational::from(2), QuadraticIrrational::sqrt_of(Natural::from(4u32)));
+    /// assert_ne!(Rational::from(2), QuadraticIrrational::sqrt_of(Natural::from(2u32)));
+    /// ```
+    #[inline]
+    fn eq(&self, other: &QuadraticIrrational) -> bool {
+        other == self
+    }
+}
+
+impl PartialOrd<Rational> for QuadraticIrrational {
+    /// Compares a [`QuadraticIrrational`] to a [`Rational`].
+    ///
+    /// If the [`Rational`] is $n/m$, the result is the sign of $(am - cn) + bm\sqrt{d}$.
+    ///
+    /// # Examples
+    /// See [here](super::comparison#partial_cmp).
+    fn partial_cmp(&self, other: &Rational) -> Option<Ordering> {
+        let n = Integer::from_sign_and_abs_ref(*other >= 0u32, other.numerator_ref());
+        let m = Integer::from(other.denominator_ref());
+        Some(sign_of_sum(
+            &(&self.a * &m - Integer::from(&self.c) * n),
+            &(&self.b * m),
+            &self.d,
+        ))
+    }
+}
+
+impl PartialOrd<QuadraticIrrational> for Rational {
+    /// Compares a [`Rational`] to a [`QuadraticIrrational`].
+    ///
+    /// See the documentation for the `PartialOrd<Rational>` implementation for
+    /// [`QuadraticIrrational`].
+    ///
+    /// # Examples
+    /// See [here](super::comparison#partial_cmp).
+    #[inline]
+    fn partial_cmp(&self, other: &QuadraticIrrational) -> Option<Ordering> {
+        other.partial_cmp(self).map(Ordering::reverse)
+    }
+}
@@ -0,0 +1,282 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::quadratic_irrational::QuadraticIrrational;
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use malachite_base::num::arithmetic::traits::{
+    DivExact, DivRound, DivisibleBy, FloorSqrt, Square, UnsignedAbs,
+};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::rational_sequences::RationalSequence;
+use malachite_base::rounding_modes::RoundingMode::*;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_q::conversion::traits::ContinuedFraction;
+use malachite_q::Rational;
+
+// Returns the numerators and denominators of the last two convergents of the continued fraction
+// $[x_0; x_1, \ldots, x_{n-1}]$, as $(p_{n-1}, p_{n-2}, q_{n-1}, q_{n-2})$. If `xs` is empty, the
+// result is $(1, 0, 0, 1)$.
+fn last_two_convergents<'a, I: Iterator<Item = &'a Integer>>(
+    xs: I,
+) -> (Integer, Integer, Integer, Integer) {
+    let mut p = Integer::ONE;
+    let mut p_previous = Integer::ZERO;
+    let mut q = Integer::ZERO;
+    let mut q_previous = Integer::ONE;
+    for x in xs {
+        p_previous += x * &p;
+        q_previous += x * &q;
+        core::mem::swap(&mut p, &mut p_previous);
+        core::mem::swap(&mut q, &mut q_previous);
+    }
+    (p, p_previous, q, q_previous)
+}
+
+// Returns $[x_0; x_1, \ldots, x_{k-1}, \overline{r_1, \ldots, r_m}]$, where the $x_i$ are the
+// elements of `prefix` and the $r_i$ are the elements of `repeating`, which must be nonempty.
+fn from_prefix_and_period(prefix: &[Integer], repeating: &[Natural]) -> QuadraticIrrational {
+    let repeating: Vec<Integer> = repeating
+        .iter()
+        .map(|x| {
+            assert_ne!(*x, 0u32);
+            Integer::from(x)
+        })
+        .collect();
+    let (p, p_previous, q, q_previous) = last_two_convergents(repeating.iter());
+    let diff = &p - q_previous;
+    let y = QuadraticIrrational::new(
+        diff.clone(),
+        Integer::ONE,
+        (&q << 1u32).unsigned_abs(),
+        (diff.square() + ((q * p_previous) << 2u32)).unsigned_abs(),
+    );
+    let (p, p_previous, q, q_previous) = last_two_convergents(prefix.iter());
+    (&y * QuadraticIrrational::from(p) + QuadraticIrrational::from(p_previous))
+        / (y * QuadraticIrrational::from(q) + QuadraticIrrational::from(q_previous))
+}
+
+impl QuadraticIrrational {
+    /// Returns the continued fraction of a [`QuadraticIrrational`].
+    ///
+    /// The output has two components. The first is the first value of the continued fraction,
+    /// which may be any [`Integer`] and is equal to the floor of the [`QuadraticIrrational`]. The
+    /// second is a [`RationalSequence`] of the remaining values, which are all positive. Using the
+    /// standard notation for continued fractions, the first value is the number before the
+    /// semicolon, and the second value contains the remaining numbers.
+    ///
+    /// If the number is rational, the sequence is finite and is the same as the one produced by
+    /// [`Rational::continued_fraction`](ContinuedFraction::continued_fraction). Otherwise, by
+    /// Lagrange's theorem, the sequence is eventually periodic.
+    ///
+    /// The number is written as $(P + \sqrt{D})/Q$ with $Q \mid D - P^2$, and the complete
+    /// quotients are generated by the recurrence $a = \lfloor (P + \sqrt{D})/Q \rfloor$, $P' =
+    /// aQ - P$, $Q' = (D - P'^2)/Q$, which stays in integers. The period ends when a pair $(P, Q)$
+    /// repeats.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(2^{n/2} n^2)$
+    ///
+    /// $M(n) = O(2^{n/2} n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in $a$,
+    /// $b$, $c$, and $d$. The length of the period is $O(\sqrt{D} \log D)$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_poly::quadratic_irrational::QuadraticIrrational;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(
+    ///     QuadraticIrrational::sqrt_of(Natural::from(2u32))
+    ///         .continued_fraction()
+    ///         .to_debug_string(),
+    ///     "(1, [[2]])"
+    /// );
+    /// assert_eq!(
+    ///     QuadraticIrrational::sqrt_of(Natural::from(7u32))
+    ///         .continued_fraction()
+    ///         .to_debug_string(),
+    ///     "(2, [[1, 1, 1, 4]])"
+    /// );
+    /// let x = QuadraticIrrational::new(
+    ///     Integer::from(1),
+    ///     Integer::from(-1),
+    ///     Natural::from(2u32),
+    ///     Natural::from(5u32),
+    /// );
+    /// assert_eq!(x.continued_fraction().to_debug_string(), "(-1, [2, [1]])");
+    /// assert_eq!(
+    ///     QuadraticIrrational::from(Rational::from_signeds(22, 7))
+    ///         .continued_fraction()
+    ///         .to_debug_string(),
+    ///     "(3, [7])"
+    /// );
+    /// ```
+    pub fn continued_fraction(&self) -> (Integer, RationalSequence<Natural>) {
+        if self.is_rational() {
+            let (floor, xs) = Rational::try_from(self).unwrap().continued_fraction();
+            return (floor, RationalSequence::from_vec(xs.collect()));
+        }
+        let c = Integer::from(&self.c);
+        let (mut p, mut q) = if self.b > 0u32 {
+            (self.a.clone(), c)
+        } else {
+            (-&self.a, -c)
+        };
+        let mut discriminant = Integer::from(self.b.unsigned_abs_ref().square() * &self.d);
+        if !(&discriminant - (&p).square()).divisible_by(&q) {
+            let q_abs = Integer::from(q.unsigned_abs_ref());
+            p *= &q_abs;
+            discriminant *= (&q).square();
+            q *= q_abs;
+        }
+        let s = Integer::from(discriminant.unsigned_abs_ref().floor_sqrt());
+        let mut terms: Vec<Integer> = Vec::new();
+        let mut indices = BTreeMap::new();
+        let start = loop {
+            if !terms.is_empty() {
+                if let Some(&i) = indices.get(&(p.clone(), q.clone())) {
+                    break i;
+                }
+                indices.insert((p.clone(), q.clone()), terms.len());
+            }
+            // Since $\sqrt{D}$ lies strictly between $s$ and $s + 1$, the floor of $(P +
+            // \sqrt{D})/Q$ is the floor of $(P + s)/Q$ if $Q > 0$ and of $(P + s + 1)/Q$ if $Q <
+            // 0$.
+            let mut numerator = &p + &s;
+            if q < 0u32 {
+                numerator += Integer::ONE;
+            }
+            let term = numerator.div_round(&q, Floor).0;
+            p = &term * &q - p;
+            q = (&discriminant - (&p).square()).div_exact(q);
+            terms.push(term);
+        };
+        let mut terms = terms.into_iter();
+        let floor = terms.next().unwrap();
+        let mut terms: Vec<Natural> = terms.map(UnsignedAbs::unsigned_abs).collect();
+        let repeating = terms.split_off(start - 1);
+        (floor, RationalSequence::from_vecs(terms, repeating))
+    }
+
+    /// Converts an eventually periodic continued fraction to a [`QuadraticIrrational`], taking
+    /// the inputs by value.
+    ///
+    /// The input has two components. The first is the first value of the continued fraction,
+    /// which may be any [`Integer`] and is equal to the floor of the result. The second is a
+    /// [`RationalSequence`] of the remaining values, which must all be positive. If the sequence
+    /// is finite, the result is rational; otherwise, it is irrational.
+    ///
+    /// See [`from_continued_fraction_ref`](QuadraticIrrational::from_continued_fraction_ref) for
+    /// details.
+    ///
+    /// # Panics
+    /// Panics if any [`Natural`] in `xs` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rational_sequences::RationalSequence;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_poly::quadratic_irrational::QuadraticIrrational;
+    ///
+    /// let xs = RationalSequence::from_vecs(vec![], vec![Natural::from(2u32)]);
+    /// assert_eq!(
+    ///     QuadraticIrrational::from_continued_fraction(Integer::from(1), xs).to_string(),
+    ///     "sqrt(2)"
+    /// );
+    ///
+    /// let xs = RationalSequence::from_vecs(vec![Natural::from(7u32)], vec![]);
+    /// assert_eq!(
+    ///     QuadraticIrrational::from_continued_fraction(Integer::from(3), xs).to_string(),
+    ///     "22/7"
+    /// );
+    /// ```
+    pub fn from_continued_fraction(
+        floor: Integer,
+        xs: RationalSequence<Natural>,
+    ) -> QuadraticIrrational {
+        let (non_repeating, repeating) = xs.into_vecs();
+        if repeating.is_empty() {
+            return QuadraticIrrational::from(Rational::from_continued_fraction(
+                floor,
+                non_repeating.into_iter(),
+            ));
+        }
+        let prefix: Vec<Integer> = core::iter::once(floor)
+            .chain(non_repeating.into_iter().map(|x| {
+                assert_ne!(x, 0u32);
+                Integer::from(x)
+            }))
+            .collect();
+        from_prefix_and_period(&prefix, &repeating)
+    }
+
+    /// Converts an eventually periodic continued fraction to a [`QuadraticIrrational`], taking
+    /// the inputs by reference.
+    ///
+    /// The input has two components. The first is the first value of the continued fraction,
+    /// which may be any [`Integer`] and is equal to the floor of the result. The second is a
+    /// [`RationalSequence`] of the remaining values, which must all be positive. If the sequence
+    /// is finite, the result is rational; otherwise, it is irrational.
+    ///
+    /// If the repeating part is $[r_1, \ldots, r_m]$ and the last two convergents of $[r_1;
+    /// \ldots, r_m]$ are $p/q$ and $p'/q'$, then the purely periodic number $y = [\overline{r_1;
+    /// \ldots, r_m}]$ satisfies $y = (py + p')/(qy + q')$, so it is the positive root of $qy^2 +
+    /// (q' - p)y - p'$. The non-repeating part is then applied to $y$ as a Möbius transformation.
+    ///
+    /// # Panics
+    /// Panics if any [`Natural`] in `xs` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rational_sequences::RationalSequence;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_poly::quadratic_irrational::QuadraticIrrational;
+    ///
+    /// let xs = RationalSequence::from_vecs(vec![], vec![Natural::from(1u32)]);
+    /// assert_eq!(
+    ///     QuadraticIrrational::from_continued_fraction_ref(&Integer::from(1), &xs).to_string(),
+    ///     "(1 + sqrt(5))/2"
+    /// );
+    ///
+    /// let xs = RationalSequence::from_vecs(
+    ///     vec![Natural::from(2u32)],
+    ///     vec![Natural::from(1u32), Natural::from(3u32)],
+    /// );
+    /// assert_eq!(
+    ///     QuadraticIrrational::from_continued_fraction_ref(&Integer::from(-1), &xs).to_string(),
+    ///     "(-11 + sqrt(21))/10"
+    /// );
+    /// ```
+    pub fn from_continued_fraction_ref(
+        floor: &Integer,
+        xs: &RationalSequence<Natural>,
+    ) -> QuadraticIrrational {
+        let (non_repeating, repeating) = xs.slices_ref();
+        if repeating.is_empty() {
+            return QuadraticIrrational::from(Rational::from_continued_fraction_ref(
+                floor,
+                non_repeating.iter(),
+            ));
+        }
+        let prefix: Vec<Integer> = core::iter::once(floor.clone())
+            .chain(non_repeating.iter().map(|x| {
+                assert_ne!(*x, 0u32);
+                Integer::from(x)
+            }))
+            .collect();
+        from_prefix_and_period(&prefix, repeating)
+    }
+}
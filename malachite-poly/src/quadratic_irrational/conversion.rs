@@ -0,0 +1,342 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::quadratic_irrational::QuadraticIrrational;
+use crate::real_algebraic::RealAlgebraic;
+use crate::Polynomial;
+use core::fmt::{Debug, Display, Formatter, Result, Write};
+use malachite_base::num::arithmetic::traits::Square;
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_q::Rational;
+
+impl From<Rational> for QuadraticIrrational {
+    /// Converts a [`Rational`] to a [`QuadraticIrrational`], taking the [`Rational`] by value.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_poly::quadratic_irrational::QuadraticIrrational;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = QuadraticIrrational::from(Rational::from_signeds(-22, 7));
+    /// assert_eq!(x.to_string(), "-22/7");
+    /// assert_eq!(*x.a(), -22);
+    /// assert_eq!(*x.c(), 7);
+    /// ```
+    fn from(x: Rational) -> QuadraticIrrational {
+        let sign = x >= 0u32;
+        let (n, d) = x.into_numerator_and_denominator();
+        QuadraticIrrational {
+            a: Integer::from_sign_and_abs(sign, n),
+            b: Integer::ZERO,
+            c: d,
+            d: Natural::ZERO,
+        }
+    }
+}
+
+impl From<&Rational> for QuadraticIrrational {
+    /// Converts a [`Rational`] to a [`QuadraticIrrational`], taking the [`Rational`] by
+    /// reference.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_poly::quadratic_irrational::QuadraticIrrational;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = QuadraticIrrational::from(&Rational::from_signeds(-22, 7));
+    /// assert_eq!(x.to_string(), "-22/7");
+    /// ```
+    #[inline]
+    fn from(x: &Rational) -> QuadraticIrrational {
+        QuadraticIrrational::from(x.clone())
+    }
+}
+
+impl From<Integer> for QuadraticIrrational {
+    /// Converts an [`Integer`] to a [`QuadraticIrrational`], taking the [`Integer`] by value.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_poly::quadratic_irrational::QuadraticIrrational;
+    ///
+    /// assert_eq!(
+    ///     QuadraticIrrational::from(Integer::from(-3)).to_string(),
+    ///     "-3"
+    /// );
+    /// ```
+    #[inline]
+    fn from(x: Integer) -> QuadraticIrrational {
+        QuadraticIrrational {
+            a: x,
+            b: Integer::ZERO,
+            c: Natural::ONE,
+            d: Natural::ZERO,
+        }
+    }
+}
+
+impl From<&Integer> for QuadraticIrrational {
+    /// Converts an [`Integer`] to a [`QuadraticIrrational`], taking the [`Integer`] by reference.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_poly::quadratic_irrational::QuadraticIrrational;
+    ///
+    /// assert_eq!(
+    ///     QuadraticIrrational::from(&Integer::from(-3)).to_string(),
+    ///     "-3"
+    /// );
+    /// ```
+    #[inline]
+    fn from(x: &Integer) -> QuadraticIrrational {
+        QuadraticIrrational::from(x.clone())
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct RationalFromQuadraticIrrationalError;
+
+impl TryFrom<QuadraticIrrational> for Rational {
+    type Error = RationalFromQuadraticIrrationalError;
+
+    /// Converts a [`QuadraticIrrational`] to a [`Rational`], taking the [`QuadraticIrrational`]
+    /// by value. If the [`QuadraticIrrational`] is irrational, an error is returned.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_poly::quadratic_irrational::conversion::RationalFromQuadraticIrrationalError;
+    /// use malachite_poly::quadratic_irrational::QuadraticIrrational;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(
+    ///     Rational::try_from(QuadraticIrrational::from(Rational::from_signeds(22, 7))).unwrap(),
+    ///     Rational::from_signeds(22, 7)
+    /// );
+    /// assert_eq!(
+    ///     Rational::try_from(QuadraticIrrational::sqrt_of(Natural::from(2u32))),
+    ///     Err(RationalFromQuadraticIrrationalError)
+    /// );
+    /// ```
+    fn try_from(x: QuadraticIrrational) -> core::result::Result<Rational, Self::Error> {
+        if x.is_rational() {
+            Ok(Rational::from_integers(x.a, Integer::from(x.c)))
+        } else {
+            Err(RationalFromQuadraticIrrationalError)
+        }
+    }
+}
+
+impl TryFrom<&QuadraticIrrational> for Rational {
+    type Error = RationalFromQuadraticIrrationalError;
+
+    /// Converts a [`QuadraticIrrational`] to a [`Rational`], taking the [`QuadraticIrrational`]
+    /// by reference. If the [`QuadraticIrrational`] is irrational, an error is returned.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in $a$
+    /// and $c$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_poly::quadratic_irrational::conversion::RationalFromQuadraticIrrationalError;
+    /// use malachite_poly::quadratic_irrational::QuadraticIrrational;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(
+    ///     Rational::try_from(&QuadraticIrrational::from(Rational::from_signeds(22, 7))).unwrap(),
+    ///     Rational::from_signeds(22, 7)
+    /// );
+    /// assert_eq!(
+    ///     Rational::try_from(&QuadraticIrrational::sqrt_of(Natural::from(2u32))),
+    ///     Err(RationalFromQuadraticIrrationalError)
+    /// );
+    /// ```
+    fn try_from(x: &QuadraticIrrational) -> core::result::Result<Rational, Self::Error> {
+        if x.is_rational() {
+            Ok(Rational::from_integers_ref(&x.a, &Integer::from(&x.c)))
+        } else {
+            Err(RationalFromQuadraticIrrationalError)
+        }
+    }
+}
+
+impl From<&QuadraticIrrational> for RealAlgebraic {
+    /// Converts a [`QuadraticIrrational`] to a [`RealAlgebraic`], taking the
+    /// [`QuadraticIrrational`] by reference.
+    ///
+    /// The minimal polynomial of an irrational $(a + b\sqrt{d})/c$ is $c^2x^2 - 2acx + a^2 -
+    /// b^2d$, divided by its content. The number is the larger of the polynomial's two roots if
+    /// $b > 0$, and the smaller one if $b < 0$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_poly::quadratic_irrational::QuadraticIrrational;
+    /// use malachite_poly::real_algebraic::RealAlgebraic;
+    ///
+    /// let x = QuadraticIrrational::new(
+    ///     Integer::from(1),
+    ///     Integer::from(-1),
+    ///     Natural::from(2u32),
+    ///     Natural::from(5u32),
+    /// );
+    /// assert_eq!(RealAlgebraic::from(&x).to_string(), "root 0 of x^2 - x - 1");
+    /// ```
+    fn from(x: &QuadraticIrrational) -> RealAlgebraic {
+        if x.is_rational() {
+            return RealAlgebraic::from(Rational::try_from(x).unwrap());
+        }
+        let c = Integer::from(&x.c);
+        let p = Polynomial::<Integer>::from_owned_coefficients_asc(vec![
+            (&x.a).square() - (&x.b).square() * Integer::from(&x.d),
+            -((&x.a * &c) << 1u32),
+            c.square(),
+        ]);
+        let mut roots = p.real_roots();
+        if x.b > 0u32 {
+            roots.pop().unwrap()
+        } else {
+            roots.swap_remove(0)
+        }
+    }
+}
+
+impl From<QuadraticIrrational> for RealAlgebraic {
+    /// Converts a [`QuadraticIrrational`] to a [`RealAlgebraic`], taking the
+    /// [`QuadraticIrrational`] by value.
+    ///
+    /// See the documentation for the `From<&QuadraticIrrational>` implementation for details.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_poly::quadratic_irrational::QuadraticIrrational;
+    /// use malachite_poly::real_algebraic::RealAlgebraic;
+    ///
+    /// let x = QuadraticIrrational::sqrt_of(Natural::from(8u32));
+    /// assert_eq!(RealAlgebraic::from(x).to_string(), "root 1 of x^2 - 8");
+    /// ```
+    #[inline]
+    fn from(x: QuadraticIrrational) -> RealAlgebraic {
+        RealAlgebraic::from(&x)
+    }
+}
+
+impl Display for QuadraticIrrational {
+    /// Converts a [`QuadraticIrrational`] to a [`String`](alloc::string::String).
+    ///
+    /// A rational number is written in the same way as a [`Rational`]. An irrational number is
+    /// written as `"(a + b*sqrt(d))/c"`, where coefficients equal to 1 are omitted, a negative
+    /// $b$ is written with a minus sign, and the parentheses and denominator are omitted when
+    /// they are not needed.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_poly::quadratic_irrational::QuadraticIrrational;
+    /// use malachite_q::Rational;
+    ///
+    /// let new = |a: i32, b: i32, c: u32, d: u32| {
+    ///     QuadraticIrrational::new(
+    ///         Integer::from(a),
+    ///         Integer::from(b),
+    ///         Natural::from(c),
+    ///         Natural::from(d),
+    ///     )
+    /// };
+    /// assert_eq!(new(-22, 0, 7, 0).to_string(), "-22/7");
+    /// assert_eq!(new(0, 1, 1, 2).to_string(), "sqrt(2)");
+    /// assert_eq!(new(0, -1, 1, 2).to_string(), "-sqrt(2)");
+    /// assert_eq!(new(3, -1, 1, 2).to_string(), "3 - sqrt(2)");
+    /// assert_eq!(new(0, 3, 2, 5).to_string(), "3*sqrt(5)/2");
+    /// assert_eq!(new(1, 1, 2, 5).to_string(), "(1 + sqrt(5))/2");
+    /// assert_eq!(new(-1, -2, 3, 7).to_string(), "(-1 - 2*sqrt(7))/3");
+    /// ```
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        if self.is_rational() {
+            return Display::fmt(&Rational::try_from(self).unwrap(), f);
+        }
+        let has_a = self.a != 0u32;
+        let has_c = self.c != 1u32;
+        if has_a && has_c {
+            f.write_char('(')?;
+        }
+        if has_a {
+            Display::fmt(&self.a, f)?;
+            f.write_str(if self.b > 0u32 { " + " } else { " - " })?;
+        } else if self.b < 0u32 {
+            f.write_char('-')?;
+        }
+        let b_abs = self.b.unsigned_abs_ref();
+        if *b_abs != 1u32 {
+            write!(f, "{b_abs}*")?;
+        }
+        write!(f, "sqrt({})", self.d)?;
+        if has_c {
+            if has_a {
+                f.write_char(')')?;
+            }
+            write!(f, "/{}", self.c)?;
+        }
+        Ok(())
+    }
+}
+
+impl Debug for QuadraticIrrational {
+    /// Converts a [`QuadraticIrrational`] to a [`String`](alloc::string::String).
+    ///
+    /// This is the same as the [`Display::fmt`] implementation.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_poly::quadratic_irrational::QuadraticIrrational;
+    ///
+    /// let xs = [2u32, 3, 4].map(|d| QuadraticIrrational::sqrt_of(Natural::from(d)));
+    /// assert_eq!(format!("{:?}", xs), "[sqrt(2), sqrt(3), 2]");
+    /// ```
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        Display::fmt(self, f)
+    }
+}
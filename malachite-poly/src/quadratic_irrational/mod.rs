@@ -0,0 +1,737 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{
+    DivExactAssign, Gcd, NegAssign, Parity, Pow, Square, UnsignedAbs,
+};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::factorization::traits::Factor;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_q::Rational;
+
+/// A real quadratic number $(a + b\sqrt{d})/c$, where $a$ and $b$ are integers, $c$ is a positive
+/// integer, and $d$ is a natural number.
+///
+/// A [`QuadraticIrrational`] is stored in a canonical form: $c > 0$, $\gcd(a, b, c) = 1$, and
+/// either $b = d = 0$, in which case the number is rational, or $b \neq 0$ and $d > 1$ is
+/// squarefree, in which case the number is irrational. Because the form is canonical, equal
+/// numbers have equal representations.
+///
+/// All operations are exact. Two [`QuadraticIrrational`]s can be added, subtracted, multiplied,
+/// or divided if at least one of them is rational or if both have the same radicand $d$; otherwise
+/// the result would not, in general, be a quadratic number.
+#[derive(Clone, Hash, Eq, PartialEq)]
+pub struct QuadraticIrrational {
+    pub(crate) a: Integer,
+    pub(crate) b: Integer,
+    pub(crate) c: Natural,
+    pub(crate) d: Natural,
+}
+
+// Writes $d$ as $s^2 t$, where $t$ is squarefree, and returns $(s, t)$.
+fn squarefree_decomposition(d: &Natural) -> (Natural, Natural) {
+    let mut s = Natural::ONE;
+    let mut t = Natural::ONE;
+    for (p, e) in d.factor() {
+        s *= (&p).pow(e >> 1);
+        if e.odd() {
+            t *= p;
+        }
+    }
+    (s, t)
+}
+
+impl QuadraticIrrational {
+    // Returns true iff `self` is valid. To be valid, `c` must be positive, `a`, `b`, and `c` must
+    // be coprime, and either `b` and `d` must both be zero, or `b` must be nonzero and `d` must be
+    // a squarefree number greater than 1. All `QuadraticIrrational`s must be valid.
+    #[cfg(feature = "test_build")]
+    pub fn is_valid(&self) -> bool {
+        self.a.is_valid()
+            && self.b.is_valid()
+            && self.c.is_valid()
+            && self.d.is_valid()
+            && self.c != 0u32
+            && self
+                .a
+                .unsigned_abs_ref()
+                .gcd(self.b.unsigned_abs_ref())
+                .gcd(&self.c)
+                == 1u32
+            && if self.b == 0u32 {
+                self.d == 0u32
+            } else {
+                self.d > 1u32 && squarefree_decomposition(&self.d).0 == 1u32
+            }
+    }
+
+    // Creates a `QuadraticIrrational` equal to $(a + b\sqrt{d})/c$, where $c \neq 0$ and $d$ is
+    // either 0, 1, or squarefree.
+    pub(crate) fn from_squarefree_radicand(
+        mut a: Integer,
+        mut b: Integer,
+        mut c: Integer,
+        mut d: Natural,
+    ) -> QuadraticIrrational {
+        assert_ne!(c, 0u32);
+        if d == 1u32 {
+            a += &b;
+            b = Integer::ZERO;
+        }
+        if b == 0u32 || d == 0u32 {
+            b = Integer::ZERO;
+            d = Natural::ZERO;
+        }
+        if c < 0u32 {
+            a.neg_assign();
+            b.neg_assign();
+            c.neg_assign();
+        }
+        let g = a
+            .unsigned_abs_ref()
+            .gcd(b.unsigned_abs_ref())
+            .gcd(c.unsigned_abs_ref());
+        if g != 1u32 {
+            let g = Integer::from(g);
+            a.div_exact_assign(&g);
+            b.div_exact_assign(&g);
+            c.div_exact_assign(g);
+        }
+        QuadraticIrrational {
+            a,
+            b,
+            c: c.unsigned_abs(),
+            d,
+        }
+    }
+
+    /// Creates a [`QuadraticIrrational`] equal to $(a + b\sqrt{d})/c$.
+    ///
+    /// The result is put into canonical form: the largest square factor of $d$ is moved into $b$,
+    /// and $a$, $b$, and $c$ are divided by their greatest common divisor. If the result is
+    /// rational, $b$ and $d$ are set to zero.
+    ///
+    /// # Worst-case complexity
+    /// The radicand is factored, which takes subexponential time in `d.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `c` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_poly::quadratic_irrational::QuadraticIrrational;
+    ///
+    /// let x = QuadraticIrrational::new(
+    ///     Integer::from(1),
+    ///     Integer::from(1),
+    ///     Natural::from(2u32),
+    ///     Natural::from(5u32),
+    /// );
+    /// assert_eq!(x.to_string(), "(1 + sqrt(5))/2");
+    ///
+    /// let x = QuadraticIrrational::new(
+    ///     Integer::from(2),
+    ///     Integer::from(2),
+    ///     Natural::from(4u32),
+    ///     Natural::from(12u32),
+    /// );
+    /// assert_eq!(x.to_string(), "(1 + 2*sqrt(3))/2");
+    ///
+    /// let x = QuadraticIrrational::new(
+    ///     Integer::from(1),
+    ///     Integer::from(-3),
+    ///     Natural::from(2u32),
+    ///     Natural::from(9u32),
+    /// );
+    /// assert_eq!(x.to_string(), "-4");
+    /// ```
+    pub fn new(a: Integer, b: Integer, c: Natural, d: Natural) -> QuadraticIrrational {
+        assert_ne!(
+            c, 0u32,
+            "The denominator of a QuadraticIrrational cannot be zero"
+        );
+        let (b, d) = if b == 0u32 || d <= 1u32 {
+            (b, d)
+        } else {
+            let (s, t) = squarefree_decomposition(&d);
+            (b * Integer::from(s), t)
+        };
+        QuadraticIrrational::from_squarefree_radicand(a, b, Integer::from(c), d)
+    }
+
+    /// Creates a [`QuadraticIrrational`] equal to $\sqrt{d}$.
+    ///
+    /// # Worst-case complexity
+    /// The radicand is factored, which takes subexponential time in `d.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_poly::quadratic_irrational::QuadraticIrrational;
+    ///
+    /// assert_eq!(
+    ///     QuadraticIrrational::sqrt_of(Natural::from(8u32)).to_string(),
+    ///     "2*sqrt(2)"
+    /// );
+    /// assert_eq!(
+    ///     QuadraticIrrational::sqrt_of(Natural::from(49u32)).to_string(),
+    ///     "7"
+    /// );
+    /// ```
+    #[inline]
+    pub fn sqrt_of(d: Natural) -> QuadraticIrrational {
+        QuadraticIrrational::new(Integer::ZERO, Integer::ONE, Natural::ONE, d)
+    }
+
+    /// Returns $a$, where a [`QuadraticIrrational`] is $(a + b\sqrt{d})/c$ in canonical form.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_poly::quadratic_irrational::QuadraticIrrational;
+    ///
+    /// let x = QuadraticIrrational::new(
+    ///     Integer::from(3),
+    ///     Integer::from(-1),
+    ///     Natural::from(6u32),
+    ///     Natural::from(7u32),
+    /// );
+    /// assert_eq!(*x.a(), 3);
+    /// ```
+    pub const fn a(&self) -> &Integer {
+        &self.a
+    }
+
+    /// Returns $b$, where a [`QuadraticIrrational`] is $(a + b\sqrt{d})/c$ in canonical form.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_poly::quadratic_irrational::QuadraticIrrational;
+    ///
+    /// let x = QuadraticIrrational::new(
+    ///     Integer::from(3),
+    ///     Integer::from(-1),
+    ///     Natural::from(6u32),
+    ///     Natural::from(7u32),
+    /// );
+    /// assert_eq!(*x.b(), -1);
+    /// ```
+    pub const fn b(&self) -> &Integer {
+        &self.b
+    }
+
+    /// Returns $c$, where a [`QuadraticIrrational`] is $(a + b\sqrt{d})/c$ in canonical form.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_poly::quadratic_irrational::QuadraticIrrational;
+    ///
+    /// let x = QuadraticIrrational::new(
+    ///     Integer::from(3),
+    ///     Integer::from(-1),
+    ///     Natural::from(6u32),
+    ///     Natural::from(7u32),
+    /// );
+    /// assert_eq!(*x.c(), 6);
+    /// ```
+    pub const fn c(&self) -> &Natural {
+        &self.c
+    }
+
+    /// Returns $d$, where a [`QuadraticIrrational`] is $(a + b\sqrt{d})/c$ in canonical form.
+    ///
+    /// The radicand is 0 if the number is rational, and a squarefree number greater than 1
+    /// otherwise.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_poly::quadratic_irrational::QuadraticIrrational;
+    ///
+    /// let x = QuadraticIrrational::new(
+    ///     Integer::from(3),
+    ///     Integer::from(-1),
+    ///     Natural::from(6u32),
+    ///     Natural::from(28u32),
+    /// );
+    /// assert_eq!(*x.d(), 7);
+    ///
+    /// let x = QuadraticIrrational::sqrt_of(Natural::from(4u32));
+    /// assert_eq!(*x.d(), 0);
+    /// ```
+    pub const fn d(&self) -> &Natural {
+        &self.d
+    }
+
+    /// Determines whether a [`QuadraticIrrational`] is rational.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_poly::quadratic_irrational::QuadraticIrrational;
+    /// use malachite_q::Rational;
+    ///
+    /// assert!(QuadraticIrrational::from(Rational::from_signeds(22, 7)).is_rational());
+    /// assert!(QuadraticIrrational::sqrt_of(Natural::from(9u32)).is_rational());
+    /// assert!(!QuadraticIrrational::sqrt_of(Natural::from(2u32)).is_rational());
+    /// ```
+    pub fn is_rational(&self) -> bool {
+        self.b == 0u32
+    }
+
+    /// Returns the conjugate of a [`QuadraticIrrational`]: $(a - b\sqrt{d})/c$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in $a$,
+    /// $b$, $c$, and $d$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_poly::quadratic_irrational::QuadraticIrrational;
+    ///
+    /// let x = QuadraticIrrational::new(
+    ///     Integer::from(1),
+    ///     Integer::from(1),
+    ///     Natural::from(2u32),
+    ///     Natural::from(5u32),
+    /// );
+    /// assert_eq!(x.conjugate().to_string(), "(1 - sqrt(5))/2");
+    /// ```
+    pub fn conjugate(&self) -> QuadraticIrrational {
+        QuadraticIrrational {
+            a: self.a.clone(),
+            b: -&self.b,
+            c: self.c.clone(),
+            d: self.d.clone(),
+        }
+    }
+
+    /// Returns the norm of a [`QuadraticIrrational`]: the product of the number and its
+    /// conjugate.
+    ///
+    /// $f((a + b\sqrt{d})/c) = (a^2 - b^2d)/c^2$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in $a$,
+    /// $b$, $c$, and $d$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_poly::quadratic_irrational::QuadraticIrrational;
+    ///
+    /// let x = QuadraticIrrational::new(
+    ///     Integer::from(1),
+    ///     Integer::from(1),
+    ///     Natural::from(2u32),
+    ///     Natural::from(5u32),
+    /// );
+    /// assert_eq!(x.norm().to_string(), "-1");
+    ///
+    /// let x = QuadraticIrrational::new(
+    ///     Integer::from(3),
+    ///     Integer::from(2),
+    ///     Natural::from(1u32),
+    ///     Natural::from(2u32),
+    /// );
+    /// assert_eq!(x.norm().to_string(), "1");
+    /// ```
+    pub fn norm(&self) -> Rational {
+        Rational::from_integers(
+            (&self.a).square() - (&self.b).square() * Integer::from(&self.d),
+            Integer::from((&self.c).square()),
+        )
+    }
+
+    /// Returns the trace of a [`QuadraticIrrational`]: the sum of the number and its conjugate.
+    ///
+    /// $f((a + b\sqrt{d})/c) = 2a/c$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is the total number of bits in $a$
+    /// and $c$.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_poly::quadratic_irrational::QuadraticIrrational;
+    ///
+    /// let x = QuadraticIrrational::new(
+    ///     Integer::from(1),
+    ///     Integer::from(1),
+    ///     Natural::from(2u32),
+    ///     Natural::from(5u32),
+    /// );
+    /// assert_eq!(x.trace().to_string(), "1");
+    /// ```
+    pub fn trace(&self) -> Rational {
+        Rational::from_integers(&self.a << 1u32, Integer::from(&self.c))
+    }
+}
+
+/// Arithmetic on [`QuadraticIrrational`]s: negation, addition, subtraction, multiplication,
+/// division, reciprocals, signs, floors, and ceilings.
+///
+/// If $x = (a_1 + b_1\sqrt{d})/c_1$ and $y = (a_2 + b_2\sqrt{d})/c_2$, then
+/// $$
+/// x + y = \frac{(a_1c_2 + a_2c_1) + (b_1c_2 + b_2c_1)\sqrt{d}}{c_1c_2}
+/// $$
+/// and
+/// $$
+/// xy = \frac{(a_1a_2 + b_1b_2d) + (a_1b_2 + a_2b_1)\sqrt{d}}{c_1c_2}.
+/// $$
+/// Binary operations panic if both operands are irrational and have different radicands.
+///
+/// # neg
+/// ```
+/// use malachite_nz::integer::Integer;
+/// use malachite_nz::natural::Natural;
+/// use malachite_poly::quadratic_irrational::QuadraticIrrational;
+///
+/// let x = QuadraticIrrational::new(
+///     Integer::from(1),
+///     Integer::from(1),
+///     Natural::from(2u32),
+///     Natural::from(5u32),
+/// );
+/// assert_eq!((-&x).to_string(), "(-1 - sqrt(5))/2");
+/// assert_eq!((-x).to_string(), "(-1 - sqrt(5))/2");
+/// ```
+///
+/// # add
+/// ```
+/// use malachite_nz::integer::Integer;
+/// use malachite_nz::natural::Natural;
+/// use malachite_poly::quadratic_irrational::QuadraticIrrational;
+/// use malachite_q::Rational;
+///
+/// let sqrt_2 = QuadraticIrrational::sqrt_of(Natural::from(2u32));
+/// let x = QuadraticIrrational::new(
+///     Integer::from(1),
+///     Integer::from(-1),
+///     Natural::from(3u32),
+///     Natural::from(2u32),
+/// );
+/// assert_eq!((&sqrt_2 + &x).to_string(), "(1 + 2*sqrt(2))/3");
+/// assert_eq!((&sqrt_2 + -&sqrt_2).to_string(), "0");
+/// assert_eq!(
+///     (sqrt_2 + QuadraticIrrational::from(Rational::from_signeds(1, 2))).to_string(),
+///     "(1 + 2*sqrt(2))/2"
+/// );
+/// ```
+///
+/// # add_assign
+/// ```
+/// use malachite_nz::natural::Natural;
+/// use malachite_poly::quadratic_irrational::QuadraticIrrational;
+///
+/// let sqrt_2 = QuadraticIrrational::sqrt_of(Natural::from(2u32));
+/// let mut x = sqrt_2.clone();
+/// x += &sqrt_2;
+/// assert_eq!(x.to_string(), "2*sqrt(2)");
+/// x += sqrt_2;
+/// assert_eq!(x.to_string(), "3*sqrt(2)");
+/// ```
+///
+/// # sub
+/// ```
+/// use malachite_nz::integer::Integer;
+/// use malachite_nz::natural::Natural;
+/// use malachite_poly::quadratic_irrational::QuadraticIrrational;
+///
+/// let sqrt_2 = QuadraticIrrational::sqrt_of(Natural::from(2u32));
+/// let x = QuadraticIrrational::new(
+///     Integer::from(1),
+///     Integer::from(-1),
+///     Natural::from(3u32),
+///     Natural::from(2u32),
+/// );
+/// assert_eq!((&sqrt_2 - &x).to_string(), "(-1 + 4*sqrt(2))/3");
+/// assert_eq!((&sqrt_2 - &sqrt_2).to_string(), "0");
+/// ```
+///
+/// # sub_assign
+/// ```
+/// use malachite_nz::natural::Natural;
+/// use malachite_poly::quadratic_irrational::QuadraticIrrational;
+///
+/// let sqrt_2 = QuadraticIrrational::sqrt_of(Natural::from(2u32));
+/// let mut x = sqrt_2.clone();
+/// x -= &sqrt_2;
+/// assert_eq!(x.to_string(), "0");
+/// x -= sqrt_2;
+/// assert_eq!(x.to_string(), "-sqrt(2)");
+/// ```
+///
+/// # mul
+/// ```
+/// use malachite_nz::integer::Integer;
+/// use malachite_nz::natural::Natural;
+/// use malachite_poly::quadratic_irrational::QuadraticIrrational;
+///
+/// let phi = QuadraticIrrational::new(
+///     Integer::from(1),
+///     Integer::from(1),
+///     Natural::from(2u32),
+///     Natural::from(5u32),
+/// );
+/// assert_eq!((&phi * &phi).to_string(), "(3 + sqrt(5))/2");
+/// assert_eq!((&phi * phi.conjugate()).to_string(), "-1");
+/// ```
+///
+/// # mul_assign
+/// ```
+/// use malachite_nz::natural::Natural;
+/// use malachite_poly::quadratic_irrational::QuadraticIrrational;
+///
+/// let sqrt_3 = QuadraticIrrational::sqrt_of(Natural::from(3u32));
+/// let mut x = sqrt_3.clone();
+/// x *= &sqrt_3;
+/// assert_eq!(x.to_string(), "3");
+/// x *= sqrt_3;
+/// assert_eq!(x.to_string(), "3*sqrt(3)");
+/// ```
+///
+/// # div
+/// ```
+/// use malachite_nz::integer::Integer;
+/// use malachite_nz::natural::Natural;
+/// use malachite_poly::quadratic_irrational::QuadraticIrrational;
+///
+/// let sqrt_2 = QuadraticIrrational::sqrt_of(Natural::from(2u32));
+/// let x = QuadraticIrrational::new(
+///     Integer::from(1),
+///     Integer::from(1),
+///     Natural::from(1u32),
+///     Natural::from(2u32),
+/// );
+/// assert_eq!((&sqrt_2 / &x).to_string(), "2 - sqrt(2)");
+/// assert_eq!((&sqrt_2 / &sqrt_2).to_string(), "1");
+/// ```
+///
+/// # div_assign
+/// ```
+/// use malachite_nz::integer::Integer;
+/// use malachite_nz::natural::Natural;
+/// use malachite_poly::quadratic_irrational::QuadraticIrrational;
+///
+/// let mut x = QuadraticIrrational::from(Integer::from(1));
+/// x /= QuadraticIrrational::sqrt_of(Natural::from(2u32));
+/// assert_eq!(x.to_string(), "sqrt(2)/2");
+/// ```
+///
+/// # reciprocal
+/// ```
+/// use malachite_base::num::arithmetic::traits::Reciprocal;
+/// use malachite_nz::integer::Integer;
+/// use malachite_nz::natural::Natural;
+/// use malachite_poly::quadratic_irrational::QuadraticIrrational;
+///
+/// let phi = QuadraticIrrational::new(
+///     Integer::from(1),
+///     Integer::from(1),
+///     Natural::from(2u32),
+///     Natural::from(5u32),
+/// );
+/// assert_eq!((&phi).reciprocal().to_string(), "(-1 + sqrt(5))/2");
+/// assert_eq!(phi.reciprocal().to_string(), "(-1 + sqrt(5))/2");
+/// ```
+///
+/// # sign
+/// ```
+/// use malachite_base::num::arithmetic::traits::Sign;
+/// use malachite_nz::integer::Integer;
+/// use malachite_nz::natural::Natural;
+/// use malachite_poly::quadratic_irrational::QuadraticIrrational;
+/// use std::cmp::Ordering::*;
+///
+/// let x = QuadraticIrrational::new(
+///     Integer::from(1),
+///     Integer::from(-1),
+///     Natural::from(1u32),
+///     Natural::from(2u32),
+/// );
+/// assert_eq!(x.sign(), Less);
+/// assert_eq!(x.conjugate().sign(), Greater);
+/// assert_eq!(QuadraticIrrational::from(Integer::from(0)).sign(), Equal);
+/// ```
+///
+/// # floor
+/// ```
+/// use malachite_base::num::arithmetic::traits::Floor;
+/// use malachite_nz::integer::Integer;
+/// use malachite_nz::natural::Natural;
+/// use malachite_poly::quadratic_irrational::QuadraticIrrational;
+///
+/// let x = QuadraticIrrational::new(
+///     Integer::from(1),
+///     Integer::from(1),
+///     Natural::from(2u32),
+///     Natural::from(5u32),
+/// );
+/// assert_eq!((&x).floor(), 1);
+/// assert_eq!(x.conjugate().floor(), -1);
+/// ```
+///
+/// # ceiling
+/// ```
+/// use malachite_base::num::arithmetic::traits::Ceiling;
+/// use malachite_nz::integer::Integer;
+/// use malachite_nz::natural::Natural;
+/// use malachite_poly::quadratic_irrational::QuadraticIrrational;
+///
+/// let x = QuadraticIrrational::new(
+///     Integer::from(1),
+///     Integer::from(1),
+///     Natural::from(2u32),
+///     Natural::from(5u32),
+/// );
+/// assert_eq!((&x).ceiling(), 2);
+/// assert_eq!(x.conjugate().ceiling(), 0);
+/// ```
+pub mod arithmetic;
+/// Comparisons of [`QuadraticIrrational`]s with each other and with [`Rational`]s.
+///
+/// Unlike arithmetic, comparison works for any two [`QuadraticIrrational`]s, even if they have
+/// different radicands. The sign of $u + v\sqrt{d_1} + w\sqrt{d_2}$ is determined exactly by
+/// squaring.
+///
+/// # partial_cmp
+/// ```
+/// use malachite_nz::natural::Natural;
+/// use malachite_poly::quadratic_irrational::QuadraticIrrational;
+/// use malachite_q::Rational;
+///
+/// let sqrt_2 = QuadraticIrrational::sqrt_of(Natural::from(2u32));
+/// assert!(sqrt_2 > Rational::from_signeds(1393, 985));
+/// assert!(sqrt_2 < Rational::from_signeds(577, 408));
+/// assert!(Rational::from_signeds(3, 2) > sqrt_2);
+/// ```
+///
+/// # cmp
+/// ```
+/// use malachite_nz::integer::Integer;
+/// use malachite_nz::natural::Natural;
+/// use malachite_poly::quadratic_irrational::QuadraticIrrational;
+///
+/// let sqrt_2 = QuadraticIrrational::sqrt_of(Natural::from(2u32));
+/// let sqrt_3 = QuadraticIrrational::sqrt_of(Natural::from(3u32));
+/// assert!(sqrt_2 < sqrt_3);
+///
+/// // 1 + sqrt(2) > sqrt(5)
+/// let x = QuadraticIrrational::new(
+///     Integer::from(1),
+///     Integer::from(1),
+///     Natural::from(1u32),
+///     Natural::from(2u32),
+/// );
+/// assert!(x > QuadraticIrrational::sqrt_of(Natural::from(5u32)));
+/// ```
+pub mod comparison;
+/// Periodic continued fractions of [`QuadraticIrrational`]s.
+///
+/// By Lagrange's theorem, the continued fraction of a real number is eventually periodic if and
+/// only if the number is a quadratic irrational, so a [`QuadraticIrrational`]'s continued
+/// fraction can be represented exactly as a
+/// [`RationalSequence`](malachite_base::rational_sequences::RationalSequence).
+pub mod continued_fraction;
+/// Conversions between [`QuadraticIrrational`]s and other numbers, and converting
+/// [`QuadraticIrrational`]s to strings.
+///
+/// # from
+/// ```
+/// use malachite_nz::integer::Integer;
+/// use malachite_poly::quadratic_irrational::QuadraticIrrational;
+/// use malachite_q::Rational;
+///
+/// assert_eq!(
+///     QuadraticIrrational::from(Integer::from(-3)).to_string(),
+///     "-3"
+/// );
+/// assert_eq!(
+///     QuadraticIrrational::from(&Rational::from_signeds(22, 7)).to_string(),
+///     "22/7"
+/// );
+/// ```
+///
+/// # try_from
+/// ```
+/// use malachite_nz::natural::Natural;
+/// use malachite_poly::quadratic_irrational::conversion::RationalFromQuadraticIrrationalError;
+/// use malachite_poly::quadratic_irrational::QuadraticIrrational;
+/// use malachite_q::Rational;
+///
+/// assert_eq!(
+///     Rational::try_from(&QuadraticIrrational::sqrt_of(Natural::from(16u32))).unwrap(),
+///     4
+/// );
+/// assert_eq!(
+///     Rational::try_from(&QuadraticIrrational::sqrt_of(Natural::from(2u32))),
+///     Err(RationalFromQuadraticIrrationalError)
+/// );
+/// ```
+///
+/// # to_string
+/// ```
+/// use malachite_nz::integer::Integer;
+/// use malachite_nz::natural::Natural;
+/// use malachite_poly::quadratic_irrational::QuadraticIrrational;
+///
+/// let x = QuadraticIrrational::new(
+///     Integer::from(0),
+///     Integer::from(-3),
+///     Natural::from(4u32),
+///     Natural::from(2u32),
+/// );
+/// assert_eq!(x.to_string(), "-3*sqrt(2)/4");
+/// assert_eq!(format!("{:?}", x), "-3*sqrt(2)/4");
+/// ```
+pub mod conversion;
+/// Solutions of Pell's equation $x^2 - dy^2 = \pm 1$, found from the continued fraction of
+/// $\sqrt{d}$.
+pub mod pell;
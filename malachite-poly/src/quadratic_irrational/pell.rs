@@ -0,0 +1,201 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{FloorSqrt, Parity, Square};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_nz::natural::Natural;
+
+// If $d$ is not a perfect square, returns $(p, q, r)$, where $r$ is the length of the period of
+// the continued fraction of $\sqrt{d}$ and $p/q$ is the convergent at the end of the first period.
+// Then $p^2 - dq^2 = (-1)^r$. If $d$ is a perfect square, returns `None`.
+//
+// The complete quotients of $\sqrt{d}$ are $(m + \sqrt{d})/k$, and the period ends when $k = 1$.
+fn sqrt_first_period_convergent(d: &Natural) -> Option<(Natural, Natural, u64)> {
+    let floor = d.floor_sqrt();
+    if (&floor).square() == *d {
+        return None;
+    }
+    let mut m = Natural::ZERO;
+    let mut k = Natural::ONE;
+    let mut a = floor.clone();
+    let mut p_previous = Natural::ONE;
+    let mut p = floor.clone();
+    let mut q_previous = Natural::ZERO;
+    let mut q = Natural::ONE;
+    let mut period = 0;
+    loop {
+        m = &k * &a - m;
+        k = (d - (&m).square()) / k;
+        a = (&floor + &m) / &k;
+        period += 1;
+        if k == 1u32 {
+            return Some((p, q, period));
+        }
+        p_previous += &a * &p;
+        q_previous += &a * &q;
+        core::mem::swap(&mut p, &mut p_previous);
+        core::mem::swap(&mut q, &mut q_previous);
+    }
+}
+
+/// Finds the fundamental solution of Pell's equation $x^2 - dy^2 = 1$.
+///
+/// If $d$ is not a perfect square, the equation has infinitely many solutions in positive
+/// integers, and the fundamental solution is the one with the smallest $x$; every other positive
+/// solution is obtained from it by [`pell_solutions`]. If $d$ is a perfect square, there are no
+/// solutions in positive integers, and `None` is returned.
+///
+/// If the continued fraction of $\sqrt{d}$ has period $r$ and $p/q$ is the convergent at the end
+/// of its first period, then $p^2 - dq^2 = (-1)^r$. If $r$ is even, $(p, q)$ is the fundamental
+/// solution; otherwise, it is $(p^2 + dq^2, 2pq)$.
+///
+/// # Worst-case complexity
+/// $T(n) = O(2^{n/2} n^2)$
+///
+/// $M(n) = O(2^{n/2} n)$
+///
+/// where $T$ is time, $M$ is additional memory, and $n$ is `d.significant_bits()`. The solution
+/// itself may have $O(\sqrt{d} \log d)$ bits.
+///
+/// # Examples
+/// ```
+/// use malachite_base::strings::ToDebugString;
+/// use malachite_nz::natural::Natural;
+/// use malachite_poly::quadratic_irrational::pell::pell_fundamental_solution;
+///
+/// assert_eq!(
+///     pell_fundamental_solution(&Natural::from(2u32)).to_debug_string(),
+///     "Some((3, 2))"
+/// );
+/// assert_eq!(
+///     pell_fundamental_solution(&Natural::from(61u32)).to_debug_string(),
+///     "Some((1766319049, 226153980))"
+/// );
+/// assert_eq!(
+///     pell_fundamental_solution(&Natural::from(9u32)).to_debug_string(),
+///     "None"
+/// );
+/// ```
+pub fn pell_fundamental_solution(d: &Natural) -> Option<(Natural, Natural)> {
+    let (p, q, period) = sqrt_first_period_convergent(d)?;
+    Some(if period.even() {
+        (p, q)
+    } else {
+        let y = (&p * &q) << 1u32;
+        ((&p).square() + d * q.square(), y)
+    })
+}
+
+/// Finds the fundamental solution of the negative Pell equation $x^2 - dy^2 = -1$, if it has
+/// any solutions.
+///
+/// The equation is solvable if and only if $d$ is not a perfect square and the continued
+/// fraction of $\sqrt{d}$ has an odd period. In that case, the fundamental solution is the
+/// convergent at the end of the first period. Otherwise, `None` is returned.
+///
+/// # Worst-case complexity
+/// $T(n) = O(2^{n/2} n^2)$
+///
+/// $M(n) = O(2^{n/2} n)$
+///
+/// where $T$ is time, $M$ is additional memory, and $n$ is `d.significant_bits()`.
+///
+/// # Examples
+/// ```
+/// use malachite_base::strings::ToDebugString;
+/// use malachite_nz::natural::Natural;
+/// use malachite_poly::quadratic_irrational::pell::negative_pell_fundamental_solution;
+///
+/// assert_eq!(
+///     negative_pell_fundamental_solution(&Natural::from(2u32)).to_debug_string(),
+///     "Some((1, 1))"
+/// );
+/// assert_eq!(
+///     negative_pell_fundamental_solution(&Natural::from(61u32)).to_debug_string(),
+///     "Some((29718, 3805))"
+/// );
+/// assert_eq!(
+///     negative_pell_fundamental_solution(&Natural::from(3u32)).to_debug_string(),
+///     "None"
+/// );
+/// ```
+pub fn negative_pell_fundamental_solution(d: &Natural) -> Option<(Natural, Natural)> {
+    let (p, q, period) = sqrt_first_period_convergent(d)?;
+    if period.odd() {
+        Some((p, q))
+    } else {
+        None
+    }
+}
+
+/// Generates all solutions of Pell's equation $x^2 - dy^2 = 1$ in positive integers.
+///
+/// This `struct` is created by [`pell_solutions`]; see its documentation for more.
+#[derive(Clone, Debug)]
+pub struct PellSolutions {
+    d: Natural,
+    fundamental: Option<(Natural, Natural)>,
+    current: Option<(Natural, Natural)>,
+}
+
+impl Iterator for PellSolutions {
+    type Item = (Natural, Natural);
+
+    fn next(&mut self) -> Option<(Natural, Natural)> {
+        let (x_1, y_1) = self.fundamental.as_ref()?;
+        let next = match &self.current {
+            None => (x_1.clone(), y_1.clone()),
+            Some((x, y)) => (x * x_1 + &self.d * y * y_1, x * y_1 + y * x_1),
+        };
+        self.current = Some(next.clone());
+        Some(next)
+    }
+}
+
+/// Generates all solutions of Pell's equation $x^2 - dy^2 = 1$ in positive integers, in
+/// increasing order.
+///
+/// If $(x_1, y_1)$ is the fundamental solution, the $k$th solution $(x_k, y_k)$ satisfies $x_k +
+/// y_k\sqrt{d} = (x_1 + y_1\sqrt{d})^k$. If $d$ is a perfect square, there are no solutions and
+/// the iterator is empty; otherwise, it is infinite.
+///
+/// # Worst-case complexity per iteration
+/// $T(n, i) = O(n i \log (n i) \log\log (n i))$
+///
+/// $M(n, i) = O(n i \log (n i))$
+///
+/// where $T$ is time, $M$ is additional memory, $i$ is the iteration number, and $n$ is the
+/// number of bits in the fundamental solution.
+///
+/// # Examples
+/// ```
+/// use malachite_base::strings::ToDebugString;
+/// use malachite_nz::natural::Natural;
+/// use malachite_poly::quadratic_irrational::pell::pell_solutions;
+///
+/// assert_eq!(
+///     pell_solutions(&Natural::from(2u32))
+///         .take(4)
+///         .collect::<Vec<_>>()
+///         .to_debug_string(),
+///     "[(3, 2), (17, 12), (99, 70), (577, 408)]"
+/// );
+/// assert_eq!(
+///     pell_solutions(&Natural::from(4u32))
+///         .collect::<Vec<_>>()
+///         .to_debug_string(),
+///     "[]"
+/// );
+/// ```
+pub fn pell_solutions(d: &Natural) -> PellSolutions {
+    PellSolutions {
+        d: d.clone(),
+        fundamental: pell_fundamental_solution(d),
+        current: None,
+    }
+}
@@ -0,0 +1,585 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::arithmetic::gcd::make_primitive;
+use crate::arithmetic::mul::clear_denominators;
+use crate::arithmetic::resultant::resultant_integer;
+use crate::real_algebraic::RealAlgebraic;
+use crate::roots::real_roots::{
+    bisect_isolating_interval, isolate_real_roots, sign_at, squarefree_part,
+};
+use crate::Polynomial;
+use alloc::vec::Vec;
+use core::cmp::Ordering::{self, *};
+use core::mem::swap;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use malachite_base::num::arithmetic::traits::{
+    Ceiling, DivExactAssign, Floor, NegAssign, Reciprocal, Sign,
+};
+use malachite_base::num::basic::traits::{NegativeOne, One, Zero};
+use malachite_nz::integer::Integer;
+use malachite_q::Rational;
+
+// The operations on two irrational numbers that are computed using resultants.
+#[derive(Clone, Copy)]
+enum Operation {
+    Add,
+    Mul,
+}
+
+// Given polynomials $p$ and $q$ over $\Z$ of degrees $m$ and $n$, returns the polynomial of degree
+// $mn$ whose roots are the sums (or products) of a root of $p$ and a root of $q$. For sums this is
+// $\operatorname{Res}_y(p(z - y), q(y))$, and for products it is $\operatorname{Res}_y(y^m p(z/y),
+// q(y))$, which requires $p(0) \neq 0$.
+//
+// Rather than computing a resultant of polynomials with polynomial coefficients, the resultant is
+// evaluated at $z = 0, 1, \ldots, mn$ and interpolated. Since the polynomials' leading coefficients
+// in $y$ don't depend on $z$, evaluating commutes with taking the resultant.
+fn resultant_polynomial(xs: &[Integer], ys: &[Integer], operation: Operation) -> Vec<Integer> {
+    let d = (xs.len() - 1) * (ys.len() - 1);
+    let p = Polynomial {
+        coefficients: xs.to_vec(),
+    };
+    let mut values: Vec<Integer> = (0..=d)
+        .map(|i| {
+            let z = Integer::from(i);
+            let zs = match operation {
+                Operation::Add => {
+                    p.compose(&Polynomial {
+                        coefficients: vec![z, Integer::NEGATIVE_ONE],
+                    })
+                    .coefficients
+                }
+                Operation::Mul => {
+                    // The coefficient of $y^{m-i}$ in $y^m p(z/y)$ is $p_i z^i$.
+                    let mut z_pow = Integer::ONE;
+                    let mut zs = Vec::with_capacity(xs.len());
+                    for x in xs {
+                        zs.push(x * &z_pow);
+                        z_pow *= &z;
+                    }
+                    zs.reverse();
+                    zs
+                }
+            };
+            resultant_integer(&zs, ys)
+        })
+        .collect();
+    // Newton interpolation: afterwards, `values[k]` is the $k$th forward difference at 0, which is
+    // divisible by $k!$ since the interpolated polynomial has integer coefficients.
+    for k in 1..=d {
+        for i in (k..=d).rev() {
+            let (lo, hi) = values.split_at_mut(i);
+            hi[0] -= &lo[i - 1];
+        }
+    }
+    let mut factorial = Integer::ONE;
+    for (k, v) in values.iter_mut().enumerate().skip(1) {
+        factorial *= Integer::from(k);
+        v.div_exact_assign(&factorial);
+    }
+    // Convert from the basis $z(z-1)\cdots(z-k+1)$ to the monomial basis.
+    let mut out = vec![values[d].clone()];
+    for k in (0..d).rev() {
+        let k_integer = Integer::from(k);
+        out.insert(0, Integer::ZERO);
+        for i in 0..out.len() - 1 {
+            let t = &out[i + 1] * &k_integer;
+            out[i] -= t;
+        }
+        out[0] += &values[k];
+    }
+    out
+}
+
+// Returns a closed interval containing the sum or product of `x` and `y`, computed from their
+// isolating intervals.
+fn enclosure(x: &RealAlgebraic, y: &RealAlgebraic, operation: Operation) -> (Rational, Rational) {
+    match operation {
+        Operation::Add => (&x.lower + &y.lower, &x.upper + &y.upper),
+        Operation::Mul => {
+            let products = [
+                &x.lower * &y.lower,
+                &x.lower * &y.upper,
+                &x.upper * &y.lower,
+                &x.upper * &y.upper,
+            ];
+            let lower = products.iter().min().unwrap().clone();
+            let upper = products.iter().max().unwrap().clone();
+            (lower, upper)
+        }
+    }
+}
+
+// Computes the sum or product of two irrational `RealAlgebraic`s. The roots of the resultant
+// polynomial are isolated, and the isolating intervals of all the numbers involved are narrowed
+// until only one root of the resultant is compatible with the isolating intervals of `x` and `y`.
+fn irrational_operation(
+    x: &RealAlgebraic,
+    y: &RealAlgebraic,
+    operation: Operation,
+) -> RealAlgebraic {
+    let zs = squarefree_part(&resultant_polynomial(
+        &x.minimal_polynomial.coefficients,
+        &y.minimal_polynomial.coefficients,
+        operation,
+    ));
+    let mut candidates = isolate_real_roots(&zs);
+    let mut x = x.clone();
+    let mut y = y.clone();
+    loop {
+        let (lower, upper) = enclosure(&x, &y, operation);
+        candidates.retain(|(a, b)| *a <= upper && *b >= lower);
+        if candidates.len() == 1 {
+            break;
+        }
+        x.bisect();
+        y.bisect();
+        for (a, b) in &mut candidates {
+            if a != b {
+                let sign_a = sign_at(&zs, a);
+                bisect_isolating_interval(&zs, a, b, sign_a);
+            }
+        }
+    }
+    let (lower, upper) = candidates.pop().unwrap();
+    RealAlgebraic::from_isolated_root(&zs, lower, upper)
+}
+
+// Replaces the coefficients of a polynomial over $\Q$ by the primitive polynomial over $\Z$ with
+// positive leading coefficient that has the same roots.
+fn primitive_from_rational_coefficients(xs: &[Rational]) -> Polynomial<Integer> {
+    let mut coefficients = clear_denominators(xs).0;
+    make_primitive(&mut coefficients);
+    Polynomial { coefficients }
+}
+
+// Adds a `Rational` to an irrational `RealAlgebraic`. If $p$ is the minimal polynomial of $x$, then
+// the minimal polynomial of $x + r$ is $p(z - r)$, up to a constant factor.
+fn add_rational(x: &RealAlgebraic, r: &Rational) -> RealAlgebraic {
+    let p = Polynomial::<Rational>::from_owned_coefficients_asc(
+        x.minimal_polynomial
+            .coefficients
+            .iter()
+            .map(Rational::from)
+            .collect(),
+    );
+    let shifted = p.compose(&Polynomial::<Rational>::from_owned_coefficients_asc(vec![
+        -r,
+        Rational::ONE,
+    ]));
+    RealAlgebraic {
+        minimal_polynomial: primitive_from_rational_coefficients(&shifted.coefficients),
+        lower: &x.lower + r,
+        upper: &x.upper + r,
+    }
+}
+
+// Multiplies an irrational `RealAlgebraic` by a `Rational`. If $p$ is the minimal polynomial of $x$
+// and $r \neq 0$, then the minimal polynomial of $rx$ is $p(z/r)$, up to a constant factor.
+fn mul_rational(x: &RealAlgebraic, r: &Rational) -> RealAlgebraic {
+    if *r == 0u32 {
+        return RealAlgebraic::from(Rational::ZERO);
+    }
+    let inverse = r.reciprocal();
+    let mut inverse_pow = Rational::ONE;
+    let mut coefficients = Vec::with_capacity(x.minimal_polynomial.coefficients.len());
+    for c in &x.minimal_polynomial.coefficients {
+        coefficients.push(Rational::from(c) * &inverse_pow);
+        inverse_pow *= &inverse;
+    }
+    let mut lower = &x.lower * r;
+    let mut upper = &x.upper * r;
+    if *r < 0u32 {
+        swap(&mut lower, &mut upper);
+    }
+    RealAlgebraic {
+        minimal_polynomial: primitive_from_rational_coefficients(&coefficients),
+        lower,
+        upper,
+    }
+}
+
+fn neg_helper(x: &RealAlgebraic) -> RealAlgebraic {
+    let mut coefficients = x.minimal_polynomial.coefficients.clone();
+    for c in coefficients.iter_mut().skip(1).step_by(2) {
+        c.neg_assign();
+    }
+    make_primitive(&mut coefficients);
+    RealAlgebraic {
+        minimal_polynomial: Polynomial { coefficients },
+        lower: -&x.upper,
+        upper: -&x.lower,
+    }
+}
+
+fn add_helper(x: &RealAlgebraic, y: &RealAlgebraic) -> RealAlgebraic {
+    match (x.is_rational(), y.is_rational()) {
+        (true, true) => RealAlgebraic::from(&x.lower + &y.lower),
+        (true, false) => add_rational(y, &x.lower),
+        (false, true) => add_rational(x, &y.lower),
+        (false, false) => irrational_operation(x, y, Operation::Add),
+    }
+}
+
+fn sub_helper(x: &RealAlgebraic, y: &RealAlgebraic) -> RealAlgebraic {
+    add_helper(x, &neg_helper(y))
+}
+
+fn mul_helper(x: &RealAlgebraic, y: &RealAlgebraic) -> RealAlgebraic {
+    match (x.is_rational(), y.is_rational()) {
+        (true, true) => RealAlgebraic::from(&x.lower * &y.lower),
+        (true, false) => mul_rational(y, &x.lower),
+        (false, true) => mul_rational(x, &y.lower),
+        (false, false) => irrational_operation(x, y, Operation::Mul),
+    }
+}
+
+// If $p$ is the minimal polynomial of $x \neq 0$, then the minimal polynomial of $1/x$ is $p$ with
+// its coefficients reversed, up to sign. Before taking reciprocals of the endpoints, the isolating
+// interval is narrowed until it excludes 0.
+fn reciprocal_helper(x: &RealAlgebraic) -> RealAlgebraic {
+    if x.is_rational() {
+        assert_ne!(x.lower, 0u32, "Cannot take reciprocal of zero");
+        return RealAlgebraic::from((&x.lower).reciprocal());
+    }
+    let mut x = x.clone();
+    if x.lower < 0u32 && x.upper > 0u32 {
+        // 0 is not a root of an irreducible polynomial of degree greater than 1.
+        if sign_at(&x.minimal_polynomial.coefficients, &Rational::ZERO) == x.sign_at_lower() {
+            x.lower = Rational::ZERO;
+        } else {
+            x.upper = Rational::ZERO;
+        }
+    }
+    while x.lower == 0u32 || x.upper == 0u32 {
+        x.bisect();
+    }
+    let mut coefficients = x.minimal_polynomial.coefficients;
+    coefficients.reverse();
+    make_primitive(&mut coefficients);
+    RealAlgebraic {
+        minimal_polynomial: Polynomial { coefficients },
+        lower: x.upper.reciprocal(),
+        upper: x.lower.reciprocal(),
+    }
+}
+
+fn div_helper(x: &RealAlgebraic, y: &RealAlgebraic) -> RealAlgebraic {
+    assert!(!(y.is_rational() && y.lower == 0u32), "division by zero");
+    mul_helper(x, &reciprocal_helper(y))
+}
+
+impl Neg for RealAlgebraic {
+    type Output = RealAlgebraic;
+
+    /// Negates a [`RealAlgebraic`], taking it by value.
+    ///
+    /// # Examples
+    /// See [here](super::arithmetic#neg).
+    #[inline]
+    fn neg(self) -> RealAlgebraic {
+        neg_helper(&self)
+    }
+}
+
+impl Neg for &RealAlgebraic {
+    type Output = RealAlgebraic;
+
+    /// Negates a [`RealAlgebraic`], taking it by reference.
+    ///
+    /// # Examples
+    /// See [here](super::arithmetic#neg).
+    #[inline]
+    fn neg(self) -> RealAlgebraic {
+        neg_helper(self)
+    }
+}
+
+macro_rules! impl_binary_op {
+    (
+        $trait:ident,
+        $fn:ident,
+        $assign_trait:ident,
+        $assign_fn:ident,
+        $helper:ident,
+        $name:literal
+        $(, $panics:literal)?
+    ) => {
+        impl $trait<RealAlgebraic> for RealAlgebraic {
+            type Output = RealAlgebraic;
+
+            #[doc = concat!(
+                "Computes the ", $name, " of two [`RealAlgebraic`]s, taking both by value."
+            )]
+            $(
+                ///
+                /// # Panics
+                #[doc = $panics]
+            )?
+            ///
+            /// # Examples
+            #[doc = concat!("See [here](super::arithmetic#", stringify!($fn), ").")]
+            #[inline]
+            fn $fn(self, other: RealAlgebraic) -> RealAlgebraic {
+                $helper(&self, &other)
+            }
+        }
+
+        impl $trait<&RealAlgebraic> for RealAlgebraic {
+            type Output = RealAlgebraic;
+
+            #[doc = concat!(
+                "Computes the ", $name, " of two [`RealAlgebraic`]s, taking the first by value ",
+                "and the second by reference."
+            )]
+            $(
+                ///
+                /// # Panics
+                #[doc = $panics]
+            )?
+            ///
+            /// # Examples
+            #[doc = concat!("See [here](super::arithmetic#", stringify!($fn), ").")]
+            #[inline]
+            fn $fn(self, other: &RealAlgebraic) -> RealAlgebraic {
+                $helper(&self, other)
+            }
+        }
+
+        impl $trait<RealAlgebraic> for &RealAlgebraic {
+            type Output = RealAlgebraic;
+
+            #[doc = concat!(
+                "Computes the ", $name, " of two [`RealAlgebraic`]s, taking the first by ",
+                "reference and the second by value."
+            )]
+            $(
+                ///
+                /// # Panics
+                #[doc = $panics]
+            )?
+            ///
+            /// # Examples
+            #[doc = concat!("See [here](super::arithmetic#", stringify!($fn), ").")]
+            #[inline]
+            fn $fn(self, other: RealAlgebraic) -> RealAlgebraic {
+                $helper(self, &other)
+            }
+        }
+
+        impl $trait<&RealAlgebraic> for &RealAlgebraic {
+            type Output = RealAlgebraic;
+
+            #[doc = concat!(
+                "Computes the ", $name, " of two [`RealAlgebraic`]s, taking both by reference."
+            )]
+            $(
+                ///
+                /// # Panics
+                #[doc = $panics]
+            )?
+            ///
+            /// # Examples
+            #[doc = concat!("See [here](super::arithmetic#", stringify!($fn), ").")]
+            #[inline]
+            fn $fn(self, other: &RealAlgebraic) -> RealAlgebraic {
+                $helper(self, other)
+            }
+        }
+
+        impl $assign_trait<RealAlgebraic> for RealAlgebraic {
+            #[doc = concat!(
+                "Replaces a [`RealAlgebraic`] with its ", $name, " with another ",
+                "[`RealAlgebraic`], taking the [`RealAlgebraic`] on the right-hand side by value."
+            )]
+            $(
+                ///
+                /// # Panics
+                #[doc = $panics]
+            )?
+            ///
+            /// # Examples
+            #[doc = concat!("See [here](super::arithmetic#", stringify!($assign_fn), ").")]
+            #[inline]
+            fn $assign_fn(&mut self, other: RealAlgebraic) {
+                *self = $helper(self, &other);
+            }
+        }
+
+        impl $assign_trait<&RealAlgebraic> for RealAlgebraic {
+            #[doc = concat!(
+                "Replaces a [`RealAlgebraic`] with its ", $name, " with another ",
+                "[`RealAlgebraic`], taking the [`RealAlgebraic`] on the right-hand side by ",
+                "reference."
+            )]
+            $(
+                ///
+                /// # Panics
+                #[doc = $panics]
+            )?
+            ///
+            /// # Examples
+            #[doc = concat!("See [here](super::arithmetic#", stringify!($assign_fn), ").")]
+            #[inline]
+            fn $assign_fn(&mut self, other: &RealAlgebraic) {
+                *self = $helper(self, other);
+            }
+        }
+    };
+}
+impl_binary_op!(Add, add, AddAssign, add_assign, add_helper, "sum");
+impl_binary_op!(Sub, sub, SubAssign, sub_assign, sub_helper, "difference");
+impl_binary_op!(Mul, mul, MulAssign, mul_assign, mul_helper, "product");
+impl_binary_op!(
+    Div,
+    div,
+    DivAssign,
+    div_assign,
+    div_helper,
+    "quotient",
+    "Panics if the second [`RealAlgebraic`] is zero."
+);
+
+impl Reciprocal for RealAlgebraic {
+    type Output = RealAlgebraic;
+
+    /// Takes the reciprocal of a [`RealAlgebraic`], taking it by value.
+    ///
+    /// # Panics
+    /// Panics if `self` is zero.
+    ///
+    /// # Examples
+    /// See [here](super::arithmetic#reciprocal).
+    #[inline]
+    fn reciprocal(self) -> RealAlgebraic {
+        reciprocal_helper(&self)
+    }
+}
+
+impl Reciprocal for &RealAlgebraic {
+    type Output = RealAlgebraic;
+
+    /// Takes the reciprocal of a [`RealAlgebraic`], taking it by reference.
+    ///
+    /// # Panics
+    /// Panics if `self` is zero.
+    ///
+    /// # Examples
+    /// See [here](super::arithmetic#reciprocal).
+    #[inline]
+    fn reciprocal(self) -> RealAlgebraic {
+        reciprocal_helper(self)
+    }
+}
+
+impl Sign for RealAlgebraic {
+    /// Compares a [`RealAlgebraic`] to zero.
+    ///
+    /// Returns `Greater`, `Equal`, or `Less`, depending on whether the [`RealAlgebraic`] is
+    /// positive, zero, or negative, respectively.
+    ///
+    /// # Examples
+    /// See [here](super::arithmetic#sign).
+    fn sign(&self) -> Ordering {
+        if self.is_rational() {
+            self.lower.sign()
+        } else if self.lower >= 0u32 {
+            Greater
+        } else if self.upper <= 0u32 {
+            Less
+        } else if sign_at(&self.minimal_polynomial.coefficients, &Rational::ZERO)
+            == self.sign_at_lower()
+        {
+            Greater
+        } else {
+            Less
+        }
+    }
+}
+
+fn floor_helper(x: &RealAlgebraic) -> Integer {
+    if x.is_rational() {
+        return (&x.lower).floor();
+    }
+    let mut x = x.clone();
+    while &x.upper - &x.lower >= 1u32 {
+        x.bisect();
+    }
+    // Now at most one integer lies in the isolating interval, and it can't be the number itself.
+    let floor = (&x.lower).floor();
+    let next = Rational::from(&floor + Integer::ONE);
+    if next < x.upper && sign_at(&x.minimal_polynomial.coefficients, &next) == x.sign_at_lower() {
+        floor + Integer::ONE
+    } else {
+        floor
+    }
+}
+
+impl Floor for RealAlgebraic {
+    type Output = Integer;
+
+    /// Finds the floor of a [`RealAlgebraic`], taking it by value.
+    ///
+    /// $f(x) = \lfloor x \rfloor$.
+    ///
+    /// # Examples
+    /// See [here](super::arithmetic#floor).
+    #[inline]
+    fn floor(self) -> Integer {
+        floor_helper(&self)
+    }
+}
+
+impl Floor for &RealAlgebraic {
+    type Output = Integer;
+
+    /// Finds the floor of a [`RealAlgebraic`], taking it by reference.
+    ///
+    /// $f(x) = \lfloor x \rfloor$.
+    ///
+    /// # Examples
+    /// See [here](super::arithmetic#floor).
+    #[inline]
+    fn floor(self) -> Integer {
+        floor_helper(self)
+    }
+}
+
+impl Ceiling for RealAlgebraic {
+    type Output = Integer;
+
+    /// Finds the ceiling of a [`RealAlgebraic`], taking it by value.
+    ///
+    /// $f(x) = \lceil x \rceil$.
+    ///
+    /// # Examples
+    /// See [here](super::arithmetic#ceiling).
+    #[inline]
+    fn ceiling(self) -> Integer {
+        (&self).ceiling()
+    }
+}
+
+impl Ceiling for &RealAlgebraic {
+    type Output = Integer;
+
+    /// Finds the ceiling of a [`RealAlgebraic`], taking it by reference.
+    ///
+    /// $f(x) = \lceil x \rceil$.
+    ///
+    /// # Examples
+    /// See [here](super::arithmetic#ceiling).
+    fn ceiling(self) -> Integer {
+        if self.is_rational() {
+            (&self.lower).ceiling()
+        } else {
+            floor_helper(self) + Integer::ONE
+        }
+    }
+}
@@ -0,0 +1,142 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::real_algebraic::RealAlgebraic;
+use crate::roots::real_roots::sign_at;
+use core::cmp::Ordering::{self, *};
+use core::hash::{Hash, Hasher};
+use malachite_q::Rational;
+
+impl PartialEq for RealAlgebraic {
+    /// Determines whether two [`RealAlgebraic`]s are equal.
+    ///
+    /// The numbers are equal if and only if they have the same minimal polynomial and the
+    /// intersection of their isolating intervals contains a root of it.
+    ///
+    /// # Examples
+    /// See [here](super::comparison#eq).
+    fn eq(&self, other: &RealAlgebraic) -> bool {
+        if self.minimal_polynomial != other.minimal_polynomial {
+            return false;
+        }
+        if self.is_rational() {
+            return true;
+        }
+        let lower = core::cmp::max(&self.lower, &other.lower);
+        let upper = core::cmp::min(&self.upper, &other.upper);
+        let xs = &self.minimal_polynomial.coefficients;
+        lower < upper && sign_at(xs, lower) != sign_at(xs, upper)
+    }
+}
+
+impl Eq for RealAlgebraic {}
+
+impl Hash for RealAlgebraic {
+    /// Hashes a [`RealAlgebraic`].
+    ///
+    /// Only the minimal polynomial is hashed, since equal numbers may have different isolating
+    /// intervals.
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.minimal_polynomial.hash(state);
+    }
+}
+
+impl Ord for RealAlgebraic {
+    /// Compares two [`RealAlgebraic`]s.
+    ///
+    /// If the numbers are not equal, copies of their isolating intervals are narrowed until they
+    /// are disjoint.
+    ///
+    /// # Examples
+    /// See [here](super::comparison#cmp).
+    fn cmp(&self, other: &RealAlgebraic) -> Ordering {
+        if self == other {
+            return Equal;
+        }
+        let mut x = self.clone();
+        let mut y = other.clone();
+        loop {
+            if x.upper <= y.lower {
+                return Less;
+            } else if y.upper <= x.lower {
+                return Greater;
+            }
+            x.bisect();
+            y.bisect();
+        }
+    }
+}
+
+impl PartialOrd for RealAlgebraic {
+    /// Compares two [`RealAlgebraic`]s.
+    ///
+    /// See the documentation for the [`Ord`] implementation.
+    #[inline]
+    fn partial_cmp(&self, other: &RealAlgebraic) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq<Rational> for RealAlgebraic {
+    /// Determines whether a [`RealAlgebraic`] is equal to a [`Rational`].
+    ///
+    /// # Examples
+    /// See [here](super::comparison#eq).
+    #[inline]
+    fn eq(&self, other: &Rational) -> bool {
+        self.is_rational() && self.lower == *other
+    }
+}
+
+impl PartialEq<RealAlgebraic> for Rational {
+    /// Determines whether a [`Rational`] is equal to a [`RealAlgebraic`].
+    ///
+    /// # Examples
+    /// See [here](super::comparison#eq).
+    #[inline]
+    fn eq(&self, other: &RealAlgebraic) -> bool {
+        other == self
+    }
+}
+
+impl PartialOrd<Rational> for RealAlgebraic {
+    /// Compares a [`RealAlgebraic`] to a [`Rational`].
+    ///
+    /// If the [`Rational`] lies inside the isolating interval, the sign of the minimal polynomial
+    /// at the [`Rational`] determines on which side of it the [`RealAlgebraic`] lies.
+    ///
+    /// # Examples
+    /// See [here](super::comparison#partial_cmp).
+    fn partial_cmp(&self, other: &Rational) -> Option<Ordering> {
+        Some(if self.is_rational() {
+            self.lower.cmp(other)
+        } else if *other <= self.lower {
+            Greater
+        } else if *other >= self.upper {
+            Less
+        } else if sign_at(&self.minimal_polynomial.coefficients, other) == self.sign_at_lower() {
+            Greater
+        } else {
+            Less
+        })
+    }
+}
+
+impl PartialOrd<RealAlgebraic> for Rational {
+    /// Compares a [`Rational`] to a [`RealAlgebraic`].
+    ///
+    /// See the documentation for the `PartialOrd<Rational>` implementation for
+    /// [`RealAlgebraic`].
+    ///
+    /// # Examples
+    /// See [here](super::comparison#partial_cmp).
+    #[inline]
+    fn partial_cmp(&self, other: &RealAlgebraic) -> Option<Ordering> {
+        other.partial_cmp(self).map(Ordering::reverse)
+    }
+}
@@ -0,0 +1,386 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::real_algebraic::RealAlgebraic;
+use crate::roots::real_roots::{isolate_real_roots, round_root};
+use crate::Polynomial;
+use core::cmp::Ordering;
+use core::fmt::{Debug, Display, Formatter, Result};
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_float::Float;
+use malachite_nz::integer::Integer;
+use malachite_q::Rational;
+
+impl From<Rational> for RealAlgebraic {
+    /// Converts a [`Rational`] to a [`RealAlgebraic`], taking the [`Rational`] by value.
+    ///
+    /// The minimal polynomial of $n/d$ is $dx - n$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_poly::real_algebraic::RealAlgebraic;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = RealAlgebraic::from(Rational::from_signeds(-22, 7));
+    /// assert_eq!(x.to_string(), "-22/7");
+    /// assert_eq!(x.minimal_polynomial().to_string(), "7*x + 22");
+    /// ```
+    fn from(x: Rational) -> RealAlgebraic {
+        let (n, d) = x.numerator_and_denominator_ref();
+        let n = Integer::from_sign_and_abs_ref(x < 0u32, n);
+        let d = Integer::from(d);
+        RealAlgebraic {
+            minimal_polynomial: Polynomial {
+                coefficients: vec![n, d],
+            },
+            lower: x.clone(),
+            upper: x,
+        }
+    }
+}
+
+impl From<&Rational> for RealAlgebraic {
+    /// Converts a [`Rational`] to a [`RealAlgebraic`], taking the [`Rational`] by reference.
+    ///
+    /// The minimal polynomial of $n/d$ is $dx - n$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_poly::real_algebraic::RealAlgebraic;
+    /// use malachite_q::Rational;
+    ///
+    /// let x = RealAlgebraic::from(&Rational::from_signeds(-22, 7));
+    /// assert_eq!(x.to_string(), "-22/7");
+    /// assert_eq!(x.minimal_polynomial().to_string(), "7*x + 22");
+    /// ```
+    #[inline]
+    fn from(x: &Rational) -> RealAlgebraic {
+        RealAlgebraic::from(x.clone())
+    }
+}
+
+impl From<Integer> for RealAlgebraic {
+    /// Converts an [`Integer`] to a [`RealAlgebraic`], taking the [`Integer`] by value.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_poly::real_algebraic::RealAlgebraic;
+    ///
+    /// let x = RealAlgebraic::from(Integer::from(-3));
+    /// assert_eq!(x.to_string(), "-3");
+    /// assert_eq!(x.minimal_polynomial().to_string(), "x + 3");
+    /// ```
+    #[inline]
+    fn from(x: Integer) -> RealAlgebraic {
+        RealAlgebraic::from(Rational::from(x))
+    }
+}
+
+impl From<&Integer> for RealAlgebraic {
+    /// Converts an [`Integer`] to a [`RealAlgebraic`], taking the [`Integer`] by reference.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_poly::real_algebraic::RealAlgebraic;
+    ///
+    /// let x = RealAlgebraic::from(&Integer::from(-3));
+    /// assert_eq!(x.to_string(), "-3");
+    /// assert_eq!(x.minimal_polynomial().to_string(), "x + 3");
+    /// ```
+    #[inline]
+    fn from(x: &Integer) -> RealAlgebraic {
+        RealAlgebraic::from(Rational::from(x))
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct RationalFromRealAlgebraicError;
+
+impl TryFrom<RealAlgebraic> for Rational {
+    type Error = RationalFromRealAlgebraicError;
+
+    /// Converts a [`RealAlgebraic`] to a [`Rational`], taking the [`RealAlgebraic`] by value. If
+    /// the [`RealAlgebraic`] is irrational, an error is returned.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_poly::real_algebraic::conversion::RationalFromRealAlgebraicError;
+    /// use malachite_poly::real_algebraic::RealAlgebraic;
+    /// use malachite_poly::Polynomial;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(
+    ///     Rational::try_from(RealAlgebraic::from(Rational::from_signeds(22, 7))).unwrap(),
+    ///     Rational::from_signeds(22, 7)
+    /// );
+    ///
+    /// let sqrt_2 = Polynomial::<Integer>::from_coefficients_asc(&[-2, 0, 1].map(Integer::from))
+    ///     .real_roots()
+    ///     .pop()
+    ///     .unwrap();
+    /// assert_eq!(
+    ///     Rational::try_from(sqrt_2),
+    ///     Err(RationalFromRealAlgebraicError)
+    /// );
+    /// ```
+    #[allow(clippy::missing_const_for_fn)]
+    fn try_from(x: RealAlgebraic) -> core::result::Result<Rational, Self::Error> {
+        if x.is_rational() {
+            Ok(x.lower)
+        } else {
+            Err(RationalFromRealAlgebraicError)
+        }
+    }
+}
+
+impl TryFrom<&RealAlgebraic> for Rational {
+    type Error = RationalFromRealAlgebraicError;
+
+    /// Converts a [`RealAlgebraic`] to a [`Rational`], taking the [`RealAlgebraic`] by
+    /// reference. If the [`RealAlgebraic`] is irrational, an error is returned.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `x.significant_bits()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_poly::real_algebraic::conversion::RationalFromRealAlgebraicError;
+    /// use malachite_poly::real_algebraic::RealAlgebraic;
+    /// use malachite_poly::Polynomial;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(
+    ///     Rational::try_from(&RealAlgebraic::from(Rational::from_signeds(22, 7))).unwrap(),
+    ///     Rational::from_signeds(22, 7)
+    /// );
+    ///
+    /// let sqrt_2 = Polynomial::<Integer>::from_coefficients_asc(&[-2, 0, 1].map(Integer::from))
+    ///     .real_roots()
+    ///     .pop()
+    ///     .unwrap();
+    /// assert_eq!(
+    ///     Rational::try_from(&sqrt_2),
+    ///     Err(RationalFromRealAlgebraicError)
+    /// );
+    /// ```
+    fn try_from(x: &RealAlgebraic) -> core::result::Result<Rational, Self::Error> {
+        if x.is_rational() {
+            Ok(x.lower.clone())
+        } else {
+            Err(RationalFromRealAlgebraicError)
+        }
+    }
+}
+
+impl RealAlgebraic {
+    /// Rounds a [`RealAlgebraic`] to a [`Float`] with a given precision, using the given rounding
+    /// mode.
+    ///
+    /// The [`Ordering`] is `Less` if the [`Float`] is less than the [`RealAlgebraic`], `Greater`
+    /// if it is greater, and `Equal` if they are equal. The rounding is correct: the isolating
+    /// interval is narrowed until every number in it rounds to the same [`Float`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n, d, p) = O(d^3 (n + p)^2 (d + n + p))$
+    ///
+    /// $M(n, d, p) = O(d (n + p))$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $d$ is `self.degree()`, $n$ is the maximum
+    /// number of bits in a coefficient of the minimal polynomial or in an endpoint of the
+    /// isolating interval, and $p$ is `prec`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero, or if `rm` is `Exact` but the [`RealAlgebraic`] cannot be
+    /// represented exactly with the given precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_poly::real_algebraic::RealAlgebraic;
+    /// use malachite_poly::Polynomial;
+    /// use malachite_q::Rational;
+    ///
+    /// let sqrt_2 = Polynomial::<Integer>::from_coefficients_asc(&[-2, 0, 1].map(Integer::from))
+    ///     .real_roots()
+    ///     .pop()
+    ///     .unwrap();
+    /// assert_eq!(
+    ///     sqrt_2.to_float_prec_round(10, Floor).to_debug_string(),
+    ///     "(1.414, Less)"
+    /// );
+    /// assert_eq!(
+    ///     sqrt_2.to_float_prec_round(10, Ceiling).to_debug_string(),
+    ///     "(1.416, Greater)"
+    /// );
+    ///
+    /// let x = RealAlgebraic::from(Rational::from_signeds(3, 8));
+    /// assert_eq!(x.to_float_prec_round(2, Exact).to_debug_string(), "(0.4, Equal)");
+    /// ```
+    pub fn to_float_prec_round(&self, prec: u64, rm: RoundingMode) -> (Float, Ordering) {
+        assert_ne!(prec, 0);
+        if self.is_rational() {
+            Float::from_rational_prec_round_ref(&self.lower, prec, rm)
+        } else {
+            round_root(
+                &self.minimal_polynomial.coefficients,
+                self.lower.clone(),
+                self.upper.clone(),
+                self.sign_at_lower(),
+                prec,
+                rm,
+            )
+        }
+    }
+
+    /// Rounds a [`RealAlgebraic`] to the nearest [`Float`] with a given precision.
+    ///
+    /// See [`to_float_prec_round`](RealAlgebraic::to_float_prec_round) for details. If the
+    /// [`RealAlgebraic`] is equidistant from two [`Float`]s with the given precision, the one
+    /// with the even mantissa is chosen.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, d, p) = O(d^3 (n + p)^2 (d + n + p))$
+    ///
+    /// $M(n, d, p) = O(d (n + p))$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $d$ is `self.degree()`, $n$ is the maximum
+    /// number of bits in a coefficient of the minimal polynomial or in an endpoint of the
+    /// isolating interval, and $p$ is `prec`.
+    ///
+    /// # Panics
+    /// Panics if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_poly::Polynomial;
+    ///
+    /// // x^5 - x - 1
+    /// let x = Polynomial::<Integer>::from_coefficients_asc(
+    ///     &[-1, -1, 0, 0, 0, 1].map(Integer::from),
+    /// )
+    /// .real_roots()
+    /// .pop()
+    /// .unwrap();
+    /// assert_eq!(
+    ///     x.to_float_prec(100).to_debug_string(),
+    ///     "(1.167303978261418684256045899856, Greater)"
+    /// );
+    /// ```
+    #[inline]
+    pub fn to_float_prec(&self, prec: u64) -> (Float, Ordering) {
+        self.to_float_prec_round(prec, Nearest)
+    }
+}
+
+impl Display for RealAlgebraic {
+    /// Converts a [`RealAlgebraic`] to a [`String`](alloc::string::String).
+    ///
+    /// A rational number is written in the same way as a [`Rational`]. An irrational number is
+    /// written as `"root k of p"`, where `p` is its minimal polynomial and the real roots of `p`
+    /// are numbered from 0 in increasing order. The result doesn't depend on the isolating
+    /// interval.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_poly::real_algebraic::RealAlgebraic;
+    /// use malachite_poly::Polynomial;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(
+    ///     RealAlgebraic::from(Rational::from_signeds(-22, 7)).to_string(),
+    ///     "-22/7"
+    /// );
+    ///
+    /// // x^3 - 3x + 1
+    /// let roots = Polynomial::<Integer>::from_coefficients_asc(&[1, -3, 0, 1].map(Integer::from))
+    ///     .real_roots();
+    /// assert_eq!(roots[0].to_string(), "root 0 of x^3 - 3*x + 1");
+    /// assert_eq!(roots[2].to_string(), "root 2 of x^3 - 3*x + 1");
+    /// ```
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        if self.is_rational() {
+            return Display::fmt(&self.lower, f);
+        }
+        let index = isolate_real_roots(&self.minimal_polynomial.coefficients)
+            .into_iter()
+            .position(|(lower, upper)| {
+                *self
+                    == RealAlgebraic {
+                        minimal_polynomial: self.minimal_polynomial.clone(),
+                        lower,
+                        upper,
+                    }
+            })
+            .unwrap();
+        write!(f, "root {} of {}", index, self.minimal_polynomial)
+    }
+}
+
+impl Debug for RealAlgebraic {
+    /// Converts a [`RealAlgebraic`] to a [`String`](alloc::string::String).
+    ///
+    /// This is the same as the [`Display::fmt`] implementation.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_poly::Polynomial;
+    ///
+    /// let roots = Polynomial::<Integer>::from_coefficients_asc(&[-2, 0, 1].map(Integer::from))
+    ///     .real_roots();
+    /// assert_eq!(format!("{:?}", roots), "[root 0 of x^2 - 2, root 1 of x^2 - 2]");
+    /// ```
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        Display::fmt(self, f)
+    }
+}
@@ -0,0 +1,276 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::arithmetic::div_rem::div_rem_integer;
+use crate::arithmetic::gcd::make_primitive;
+use crate::mod_natural::ModNatural;
+use crate::Polynomial;
+use alloc::vec::Vec;
+use malachite_base::num::arithmetic::traits::{Gcd, Mod};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::factorization::traits::IsPrime;
+use malachite_base::num::logic::traits::BitConvertible;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+
+// Reduces a polynomial over $\Z$ modulo $p$.
+fn reduce(xs: &[Integer], p: &Natural) -> Polynomial<ModNatural> {
+    let modulus = Integer::from(p);
+    let ys: Vec<Natural> = xs
+        .iter()
+        .map(|x| Natural::exact_from(x.mod_op(&modulus)))
+        .collect();
+    Polynomial::from_naturals_asc(&ys, p)
+}
+
+// Lifts a polynomial over $\Z/p\Z$ to the polynomial over $\Z$ whose coefficients are the
+// representatives in $(-p/2, p/2]$.
+fn lift(xs: &Polynomial<ModNatural>, p: &Natural) -> Vec<Integer> {
+    let half = p >> 1u32;
+    xs.coefficients
+        .iter()
+        .map(|x| {
+            if x.value > half {
+                Integer::from(&x.value) - Integer::from(p)
+            } else {
+                Integer::from(&x.value)
+            }
+        })
+        .collect()
+}
+
+// Computes $b^e \mod m$, where $m$ is a monic polynomial over $\Z/p\Z$ with positive degree.
+fn pow_mod(
+    b: &Polynomial<ModNatural>,
+    e: &Natural,
+    m: &Polynomial<ModNatural>,
+) -> Polynomial<ModNatural> {
+    let b = b % m;
+    let mut out = Polynomial::from_naturals_asc(&[Natural::ONE], &m.coefficients[0].modulus);
+    for bit in e.to_bits_desc() {
+        out = (&out * &out) % m;
+        if bit {
+            out = (&out * &b) % m;
+        }
+    }
+    out
+}
+
+// Returns $x^{ip} \mod f$ for $0 \leq i < \deg f$, where $f$ is a monic polynomial over $\Z/p\Z$
+// with positive degree. These are the columns of the matrix of the Frobenius map $h \mapsto h^p$
+// on $(\Z/p\Z)[x]/(f)$.
+fn frobenius_table(f: &Polynomial<ModNatural>, p: &Natural) -> Vec<Polynomial<ModNatural>> {
+    let n = usize::exact_from(f.degree().unwrap());
+    let x = Polynomial::from_naturals_asc(&[Natural::ZERO, Natural::ONE], p);
+    let x_p = pow_mod(&x, p, f);
+    let mut table = Vec::with_capacity(n);
+    table.push(Polynomial::from_naturals_asc(&[Natural::ONE], p));
+    for i in 1..n {
+        let next = (&table[i - 1] * &x_p) % f;
+        table.push(next);
+    }
+    table
+}
+
+// Computes $h^p \mod f$ using a table returned by `frobenius_table` for $f$ or for a multiple of
+// $f$. Since $p$th powers are additive modulo $p$ and fix the coefficients, $h^p$ is
+// $\sum_i h_i x^{ip}$, so this only takes a linear combination of the table's entries.
+fn frobenius(
+    h: &Polynomial<ModNatural>,
+    table: &[Polynomial<ModNatural>],
+    f: &Polynomial<ModNatural>,
+    p: &Natural,
+) -> Polynomial<ModNatural> {
+    let mut sums = vec![Natural::ZERO; table.len()];
+    for (c, t) in h.coefficients.iter().zip(table) {
+        for (sum, d) in sums.iter_mut().zip(&t.coefficients) {
+            *sum += &c.value * &d.value;
+        }
+    }
+    Polynomial::from_naturals_asc(&sums, p) % f
+}
+
+fn derivative(xs: &[Integer]) -> Vec<Integer> {
+    xs.iter()
+        .enumerate()
+        .skip(1)
+        .map(|(i, x)| x * Integer::from(i))
+        .collect()
+}
+
+// Returns a prime $p$ such that the squarefree polynomial `xs` remains squarefree modulo $p$, and
+// such that every factor of `xs` over $\Z$, multiplied by a suitable rational so that its leading
+// coefficient equals that of `xs`, has coefficients of absolute value less than $p/2$.
+//
+// If $g$ divides $f$, where $f$ has degree $n$, Mignotte's bound gives $\|g\|_\infty \leq 2^n
+// \|f\|_2 \leq 2^n (n + 1) \|f\|_\infty$. Scaling $g$ so that it has the leading coefficient of $f$
+// multiplies this bound by at most the leading coefficient of $f$.
+fn choose_prime(xs: &[Integer]) -> Natural {
+    let n = xs.len() - 1;
+    let max = xs.iter().map(Integer::unsigned_abs_ref).max().unwrap();
+    let bound = (xs.last().unwrap().unsigned_abs_ref() * max * Natural::from(n + 1)) << n;
+    let mut p = (bound << 1u32) + Natural::ONE;
+    let derivative = derivative(xs);
+    loop {
+        if p.is_prime() && reduce(xs, &p).gcd(reduce(&derivative, &p)).degree() == Some(0) {
+            return p;
+        }
+        p += Natural::from(2u32);
+    }
+}
+
+// Splits a monic squarefree polynomial over $\Z/p\Z$ into pairs $(g, d)$, where $g$ is the product
+// of all of its irreducible factors with degree $d$.
+fn distinct_degree_factorization(
+    mut f: Polynomial<ModNatural>,
+    table: &[Polynomial<ModNatural>],
+    p: &Natural,
+) -> Vec<(Polynomial<ModNatural>, u64)> {
+    let x = Polynomial::from_naturals_asc(&[Natural::ZERO, Natural::ONE], p);
+    let mut h = x.clone();
+    let mut out = Vec::new();
+    let mut d = 1;
+    while d << 1 <= f.degree().unwrap() {
+        // h is $x^{p^d} \mod f$, and $x^{p^d} - x$ is the product of all monic irreducible
+        // polynomials whose degrees divide $d$.
+        h = frobenius(&h, table, &f, p);
+        let g = (&f).gcd(&h - &x);
+        if g.degree() != Some(0) {
+            f /= &g;
+            h %= &f;
+            out.push((g, d));
+        }
+        d += 1;
+    }
+    let degree = f.degree().unwrap();
+    if degree != 0 {
+        out.push((f, degree));
+    }
+    out
+}
+
+// Splits a monic squarefree polynomial over $\Z/p\Z$, all of whose irreducible factors have degree
+// $d$, into those factors, using the Cantor–Zassenhaus algorithm. Instead of random polynomials,
+// the polynomials $x + a$ for $a = 0, 1, 2, \ldots$ are tried in turn; each one splits the
+// polynomial with probability about $1/2$.
+fn equal_degree_factorization(
+    f: Polynomial<ModNatural>,
+    d: u64,
+    table: &[Polynomial<ModNatural>],
+    p: &Natural,
+    out: &mut Vec<Polynomial<ModNatural>>,
+) {
+    let degree = f.degree().unwrap();
+    if degree == d {
+        out.push(f);
+        return;
+    }
+    // $(p^d - 1) / 2 = (1 + p + \cdots + p^{d - 1})(p - 1) / 2$, so $b^{(p^d - 1) / 2}$ is computed
+    // from the Frobenius images $b, b^p, \ldots, b^{p^{d - 1}}$ and a single power.
+    let e = (p - Natural::ONE) >> 1u32;
+    let one = Polynomial::from_naturals_asc(&[Natural::ONE], p);
+    let mut a = Natural::ZERO;
+    loop {
+        let b = Polynomial::from_naturals_asc(&[a.clone(), Natural::ONE], p) % &f;
+        let mut c = b.clone();
+        let mut product = b;
+        for _ in 1..d {
+            c = frobenius(&c, table, &f, p);
+            product = (product * &c) % &f;
+        }
+        let t = pow_mod(&product, &e, &f);
+        let g = (&f).gcd(t - &one);
+        let g_degree = g.degree().unwrap();
+        if g_degree != 0 && g_degree != degree {
+            let h = &f / &g;
+            equal_degree_factorization(g, d, table, p, out);
+            equal_degree_factorization(h, d, table, p, out);
+            return;
+        }
+        a += Natural::ONE;
+    }
+}
+
+// Advances `indices`, a strictly increasing sequence of elements of $\{0, \ldots, n - 1\}$, to the
+// next such sequence in lexicographic order. Returns false if there is none.
+fn next_combination(indices: &mut [usize], n: usize) -> bool {
+    let k = indices.len();
+    let Some(i) = (0..k).rev().find(|&i| indices[i] != i + n - k) else {
+        return false;
+    };
+    indices[i] += 1;
+    for j in i + 1..k {
+        indices[j] = indices[j - 1] + 1;
+    }
+    true
+}
+
+// Finds the factors of `xs` over $\Z$ from its monic irreducible factors modulo $p$, by trying
+// products of subsets of the modular factors in order of increasing size.
+fn recombine(
+    mut xs: Vec<Integer>,
+    mut modular_factors: Vec<Polynomial<ModNatural>>,
+    p: &Natural,
+) -> Vec<Vec<Integer>> {
+    let mut out = Vec::new();
+    let mut size = 1;
+    'outer: while size << 1 <= modular_factors.len() {
+        let mut indices: Vec<usize> = (0..size).collect();
+        loop {
+            let mut g = reduce(&xs[xs.len() - 1..], p);
+            for &i in &indices {
+                g *= &modular_factors[i];
+            }
+            let mut candidate = lift(&g, p);
+            make_primitive(&mut candidate);
+            let (quotient, remainder) = div_rem_integer(xs.clone(), &candidate);
+            if remainder.is_empty() {
+                out.push(candidate);
+                xs = quotient;
+                for &i in indices.iter().rev() {
+                    modular_factors.remove(i);
+                }
+                continue 'outer;
+            }
+            if !next_combination(&mut indices, modular_factors.len()) {
+                break;
+            }
+        }
+        size += 1;
+    }
+    out.push(xs);
+    out
+}
+
+// Factors a squarefree primitive polynomial over $\Z$ with positive leading coefficient into
+// irreducible factors, each of which is primitive with positive leading coefficient.
+//
+// This is the Zassenhaus algorithm, using a single large prime instead of Hensel lifting: the
+// polynomial is factored modulo a prime $p$ that is large enough that each factor over $\Z$ can be
+// read off from the product of the corresponding modular factors. The modular factorization uses
+// distinct-degree factorization followed by Cantor–Zassenhaus equal-degree factorization. In the
+// worst case, the number of subsets of modular factors that are tried is exponential in the
+// degree.
+pub(crate) fn factor_squarefree(xs: &[Integer]) -> Vec<Vec<Integer>> {
+    if xs.len() <= 2 {
+        return vec![xs.to_vec()];
+    }
+    let p = choose_prime(xs);
+    let mut f = reduce(xs, &p);
+    let inverse = f.coefficients.last().unwrap().inverse().unwrap();
+    for c in &mut f.coefficients {
+        *c *= &inverse;
+    }
+    let table = frobenius_table(&f, &p);
+    let mut modular_factors = Vec::new();
+    for (g, d) in distinct_degree_factorization(f, &table, &p) {
+        equal_degree_factorization(g, d, &table, &p, &mut modular_factors);
+    }
+    recombine(xs.to_vec(), modular_factors, &p)
+}
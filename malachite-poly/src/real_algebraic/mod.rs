@@ -0,0 +1,677 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+#[cfg(feature = "test_build")]
+use crate::arithmetic::gcd::content;
+use crate::real_algebraic::factor::factor_squarefree;
+use crate::roots::real_roots::{
+    bisect_isolating_interval, isolate_real_roots, sign_at, squarefree_part,
+};
+use crate::Polynomial;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+#[cfg(feature = "test_build")]
+use core::cmp::Ordering::*;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_nz::integer::Integer;
+use malachite_q::Rational;
+
+/// A real algebraic number: a real root of a nonzero [`Polynomial`] over $\Z$.
+///
+/// A [`RealAlgebraic`] is stored as its minimal polynomial, which is the primitive irreducible
+/// [`Polynomial`] over $\Z$ with positive leading coefficient that has the number as a root,
+/// together with an isolating interval: an interval with [`Rational`] endpoints that contains the
+/// number and no other root of the minimal polynomial. If the number is rational, its minimal
+/// polynomial has degree 1 and the interval contains only the number itself. Otherwise, the
+/// interval is open, and its endpoints, being rational, are not roots.
+///
+/// All operations are exact. Since an operation may narrow the isolating intervals that it works
+/// with, equal [`RealAlgebraic`]s may have different isolating intervals; equality, hashing, and
+/// formatting only depend on the number itself.
+#[derive(Clone)]
+pub struct RealAlgebraic {
+    pub(crate) minimal_polynomial: Polynomial<Integer>,
+    pub(crate) lower: Rational,
+    pub(crate) upper: Rational,
+}
+
+// Returns the number of roots of the squarefree polynomial `xs` in the open interval $(a, b)$.
+#[cfg(feature = "test_build")]
+fn count_roots_in_interval(xs: &[Integer], a: &Rational, b: &Rational) -> usize {
+    let mut count = 0;
+    for (mut c, mut d) in isolate_real_roots(xs) {
+        let sign_c = sign_at(xs, &c);
+        loop {
+            if d <= *a || c >= *b || (c == d && (c == *a || c == *b)) {
+                break;
+            } else if *a <= c && d <= *b {
+                count += 1;
+                break;
+            }
+            bisect_isolating_interval(xs, &mut c, &mut d, sign_c);
+        }
+    }
+    count
+}
+
+impl RealAlgebraic {
+    // Returns true iff `self` is valid. To be valid, its minimal polynomial must be primitive and
+    // irreducible with degree at least 1 and a positive leading coefficient. If the degree is 1,
+    // the interval must consist of the polynomial's root; otherwise, the interval must be open and
+    // contain exactly one root of the polynomial, with neither endpoint a root. All
+    // `RealAlgebraic`s must be valid.
+    #[cfg(feature = "test_build")]
+    pub fn is_valid(&self) -> bool {
+        let xs = &self.minimal_polynomial.coefficients;
+        if !self.minimal_polynomial.is_valid()
+            || xs.len() < 2
+            || *xs.last().unwrap() < 0u32
+            || content(xs) != 1u32
+        {
+            return false;
+        }
+        if xs.len() == 2 {
+            return self.lower == self.upper && sign_at(xs, &self.lower) == Equal;
+        }
+        self.lower < self.upper
+            && sign_at(xs, &self.lower) != Equal
+            && sign_at(xs, &self.upper) != Equal
+            && factor_squarefree(xs).len() == 1
+            && count_roots_in_interval(xs, &self.lower, &self.upper) == 1
+    }
+
+    // Creates a `RealAlgebraic` from a root of the squarefree polynomial `xs`, given an isolating
+    // interval for it, which is either a single point or an open interval whose endpoints are not
+    // roots. The minimal polynomial is the irreducible factor of `xs` that changes sign on the
+    // interval.
+    pub(crate) fn from_isolated_root(
+        xs: &[Integer],
+        lower: Rational,
+        upper: Rational,
+    ) -> RealAlgebraic {
+        if lower == upper {
+            return RealAlgebraic::from(lower);
+        }
+        let factor = factor_squarefree(xs)
+            .into_iter()
+            .find(|f| sign_at(f, &lower) != sign_at(f, &upper))
+            .unwrap();
+        if factor.len() == 2 {
+            let [c, d] = <[Integer; 2]>::try_from(factor).unwrap();
+            RealAlgebraic::from(Rational::from_integers(-c, d))
+        } else {
+            RealAlgebraic {
+                minimal_polynomial: Polynomial {
+                    coefficients: factor,
+                },
+                lower,
+                upper,
+            }
+        }
+    }
+
+    // Returns the sign of the minimal polynomial at the lower endpoint of the isolating interval.
+    pub(crate) fn sign_at_lower(&self) -> Ordering {
+        sign_at(&self.minimal_polynomial.coefficients, &self.lower)
+    }
+
+    // Narrows the isolating interval of an irrational `RealAlgebraic` to at most 3/4 of its width.
+    // Does nothing if `self` is rational.
+    pub(crate) fn bisect(&mut self) {
+        if self.lower != self.upper {
+            let sign_lower = self.sign_at_lower();
+            bisect_isolating_interval(
+                &self.minimal_polynomial.coefficients,
+                &mut self.lower,
+                &mut self.upper,
+                sign_lower,
+            );
+        }
+    }
+
+    /// Returns the minimal polynomial of a [`RealAlgebraic`]: the primitive irreducible
+    /// [`Polynomial`] over $\Z$ with positive leading coefficient that has the number as a root.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_poly::real_algebraic::RealAlgebraic;
+    /// use malachite_poly::Polynomial;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(
+    ///     RealAlgebraic::from(Rational::from_signeds(-2, 3))
+    ///         .minimal_polynomial()
+    ///         .to_string(),
+    ///     "3*x + 2"
+    /// );
+    ///
+    /// let p = Polynomial::<Integer>::from_coefficients_asc(&[-4, 0, 2].map(Integer::from));
+    /// assert_eq!(p.real_roots()[1].minimal_polynomial().to_string(), "x^2 - 2");
+    /// ```
+    pub const fn minimal_polynomial(&self) -> &Polynomial<Integer> {
+        &self.minimal_polynomial
+    }
+
+    /// Returns the current isolating interval of a [`RealAlgebraic`].
+    ///
+    /// If the two endpoints are equal, the number is rational and equal to them. Otherwise, the
+    /// number lies strictly between the endpoints, and no other root of the minimal polynomial
+    /// lies in the closed interval between them.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_poly::real_algebraic::RealAlgebraic;
+    /// use malachite_poly::Polynomial;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(
+    ///     RealAlgebraic::from(Rational::from_signeds(-2, 3))
+    ///         .isolating_interval()
+    ///         .to_debug_string(),
+    ///     "(-2/3, -2/3)"
+    /// );
+    ///
+    /// let p = Polynomial::<Integer>::from_coefficients_asc(&[-2, 0, 1].map(Integer::from));
+    /// assert_eq!(p.real_roots()[1].isolating_interval().to_debug_string(), "(0, 8)");
+    /// ```
+    pub const fn isolating_interval(&self) -> (&Rational, &Rational) {
+        (&self.lower, &self.upper)
+    }
+
+    /// Returns the degree of a [`RealAlgebraic`], which is the degree of its minimal polynomial.
+    ///
+    /// The degree is 1 if and only if the number is rational.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_poly::real_algebraic::RealAlgebraic;
+    /// use malachite_poly::Polynomial;
+    ///
+    /// assert_eq!(RealAlgebraic::from(Integer::from(5)).degree(), 1);
+    ///
+    /// // x^5 - x - 1
+    /// let p =
+    ///     Polynomial::<Integer>::from_coefficients_asc(&[-1, -1, 0, 0, 0, 1].map(Integer::from));
+    /// assert_eq!(p.real_roots()[0].degree(), 5);
+    /// ```
+    pub fn degree(&self) -> u64 {
+        u64::exact_from(self.minimal_polynomial.coefficients.len() - 1)
+    }
+
+    /// Determines whether a [`RealAlgebraic`] is rational.
+    ///
+    /// # Worst-case complexity
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_poly::real_algebraic::RealAlgebraic;
+    /// use malachite_poly::Polynomial;
+    /// use malachite_q::Rational;
+    ///
+    /// assert!(RealAlgebraic::from(Rational::from_signeds(22, 7)).is_rational());
+    ///
+    /// let p = Polynomial::<Integer>::from_coefficients_asc(&[-2, 0, 1].map(Integer::from));
+    /// assert!(!p.real_roots()[0].is_rational());
+    /// ```
+    pub fn is_rational(&self) -> bool {
+        self.lower == self.upper
+    }
+
+    /// Narrows the isolating interval of a [`RealAlgebraic`] until its width is at most `width`.
+    ///
+    /// The interval is repeatedly split at the simplest [`Rational`] in its middle half. This does
+    /// nothing if the number is rational, since its interval already has width 0.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, d, w) = O(d^2 (n + \log w)^2 \log w)$
+    ///
+    /// $M(n, d, w) = O(d (n + \log w))$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $d$ is `self.degree()`, $n$ is the maximum
+    /// number of bits in a coefficient of the minimal polynomial or in an endpoint of the
+    /// interval, and $w$ is the width of the interval divided by `width`.
+    ///
+    /// # Panics
+    /// Panics if `width` is not positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_poly::Polynomial;
+    /// use malachite_q::Rational;
+    ///
+    /// let p = Polynomial::<Integer>::from_coefficients_asc(&[-2, 0, 1].map(Integer::from));
+    /// let mut x = p.real_roots().pop().unwrap();
+    /// x.refine_isolating_interval(&Rational::from_unsigneds(1u32, 100));
+    /// assert_eq!(x.isolating_interval().to_debug_string(), "(24/17, 17/12)");
+    /// ```
+    pub fn refine_isolating_interval(&mut self, width: &Rational) {
+        assert!(*width > 0u32, "width must be positive");
+        while &self.upper - &self.lower > *width {
+            self.bisect();
+        }
+    }
+}
+
+impl Polynomial<Integer> {
+    /// Finds the distinct real roots of a [`Polynomial`] over $\Z$, exactly, as
+    /// [`RealAlgebraic`]s.
+    ///
+    /// The roots are returned in increasing order, and repeated roots appear only once. The
+    /// polynomial is first factored into irreducible factors over $\Z$, which become the minimal
+    /// polynomials of the roots, and the roots of each factor are then isolated using
+    /// [`real_root_isolating_intervals`](Polynomial::real_root_isolating_intervals).
+    ///
+    /// # Worst-case complexity
+    /// The factorization uses the Zassenhaus algorithm, which is polynomial in practice but
+    /// exponential in `self.degree()` in the worst case.
+    ///
+    /// # Panics
+    /// Panics if `self` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_poly::Polynomial;
+    ///
+    /// // (x^2 - 2)(2x + 1)
+    /// let p = Polynomial::<Integer>::from_coefficients_asc(&[-2, -4, 1, 2].map(Integer::from));
+    /// assert_eq!(
+    ///     p.real_roots().to_debug_string(),
+    ///     "[root 0 of x^2 - 2, -1/2, root 1 of x^2 - 2]"
+    /// );
+    ///
+    /// let p = Polynomial::<Integer>::from_coefficients_asc(&[1, 0, 1].map(Integer::from));
+    /// assert_eq!(p.real_roots().to_debug_string(), "[]");
+    /// ```
+    pub fn real_roots(&self) -> Vec<RealAlgebraic> {
+        let mut roots = Vec::new();
+        for factor in factor_squarefree(&squarefree_part(&self.coefficients)) {
+            match factor.len() {
+                1 => {}
+                2 => roots.push(RealAlgebraic::from(Rational::from_integers_ref(
+                    &-&factor[0],
+                    &factor[1],
+                ))),
+                _ => {
+                    for (lower, upper) in isolate_real_roots(&factor) {
+                        roots.push(RealAlgebraic {
+                            minimal_polynomial: Polynomial {
+                                coefficients: factor.clone(),
+                            },
+                            lower,
+                            upper,
+                        });
+                    }
+                }
+            }
+        }
+        roots.sort_unstable();
+        roots
+    }
+}
+
+/// Arithmetic on [`RealAlgebraic`]s: negation, addition, subtraction, multiplication, division,
+/// reciprocals, signs, floors, and ceilings.
+///
+/// Sums and products of two irrational numbers are computed using resultants. If $p$ and $q$ are
+/// the minimal polynomials of $x$ and $y$, then $x + y$ is a root of $\operatorname{Res}_y(p(z -
+/// y), q(y))$ and $xy$ is a root of $\operatorname{Res}_y(y^{\deg p} p(z/y), q(y))$. The root that
+/// is wanted is found by narrowing the isolating intervals of $x$ and $y$ until only one root of
+/// the resultant is compatible with them, and its minimal polynomial is the irreducible factor of
+/// the resultant that vanishes there.
+///
+/// # neg
+/// ```
+/// use malachite_nz::integer::Integer;
+/// use malachite_poly::Polynomial;
+///
+/// // x^3 - 2
+/// let p = Polynomial::<Integer>::from_coefficients_asc(&[-2, 0, 0, 1].map(Integer::from));
+/// let x = p.real_roots().pop().unwrap();
+/// assert_eq!((-&x).to_string(), "root 0 of x^3 + 2");
+/// assert_eq!((-x).minimal_polynomial().to_string(), "x^3 + 2");
+/// ```
+///
+/// # add
+/// ```
+/// use malachite_nz::integer::Integer;
+/// use malachite_poly::real_algebraic::RealAlgebraic;
+/// use malachite_poly::Polynomial;
+/// use malachite_q::Rational;
+///
+/// let sqrt_2 = Polynomial::<Integer>::from_coefficients_asc(&[-2, 0, 1].map(Integer::from))
+///     .real_roots()
+///     .pop()
+///     .unwrap();
+/// let sqrt_3 = Polynomial::<Integer>::from_coefficients_asc(&[-3, 0, 1].map(Integer::from))
+///     .real_roots()
+///     .pop()
+///     .unwrap();
+/// assert_eq!((&sqrt_2 + &sqrt_3).to_string(), "root 3 of x^4 - 10*x^2 + 1");
+/// assert_eq!((&sqrt_2 + -&sqrt_2).to_string(), "0");
+/// assert_eq!(
+///     (sqrt_2 + RealAlgebraic::from(Rational::from(1))).to_string(),
+///     "root 1 of x^2 - 2*x - 1"
+/// );
+/// ```
+///
+/// # add_assign
+/// ```
+/// use malachite_nz::integer::Integer;
+/// use malachite_poly::Polynomial;
+///
+/// let sqrt_2 = Polynomial::<Integer>::from_coefficients_asc(&[-2, 0, 1].map(Integer::from))
+///     .real_roots()
+///     .pop()
+///     .unwrap();
+/// let mut x = sqrt_2.clone();
+/// x += &sqrt_2;
+/// assert_eq!(x.to_string(), "root 1 of x^2 - 8");
+/// ```
+///
+/// # sub
+/// ```
+/// use malachite_nz::integer::Integer;
+/// use malachite_poly::Polynomial;
+///
+/// let sqrt_2 = Polynomial::<Integer>::from_coefficients_asc(&[-2, 0, 1].map(Integer::from))
+///     .real_roots()
+///     .pop()
+///     .unwrap();
+/// let sqrt_3 = Polynomial::<Integer>::from_coefficients_asc(&[-3, 0, 1].map(Integer::from))
+///     .real_roots()
+///     .pop()
+///     .unwrap();
+/// assert_eq!((&sqrt_2 - &sqrt_3).to_string(), "root 1 of x^4 - 10*x^2 + 1");
+/// assert_eq!((&sqrt_2 - &sqrt_2).to_string(), "0");
+/// ```
+///
+/// # sub_assign
+/// ```
+/// use malachite_nz::integer::Integer;
+/// use malachite_poly::Polynomial;
+///
+/// let sqrt_2 = Polynomial::<Integer>::from_coefficients_asc(&[-2, 0, 1].map(Integer::from))
+///     .real_roots()
+///     .pop()
+///     .unwrap();
+/// let mut x = sqrt_2.clone();
+/// x -= sqrt_2;
+/// assert_eq!(x.to_string(), "0");
+/// ```
+///
+/// # mul
+/// ```
+/// use malachite_nz::integer::Integer;
+/// use malachite_poly::Polynomial;
+///
+/// let sqrt_2 = Polynomial::<Integer>::from_coefficients_asc(&[-2, 0, 1].map(Integer::from))
+///     .real_roots()
+///     .pop()
+///     .unwrap();
+/// let sqrt_3 = Polynomial::<Integer>::from_coefficients_asc(&[-3, 0, 1].map(Integer::from))
+///     .real_roots()
+///     .pop()
+///     .unwrap();
+/// assert_eq!((&sqrt_2 * &sqrt_3).to_string(), "root 1 of x^2 - 6");
+/// assert_eq!((&sqrt_2 * &sqrt_2).to_string(), "2");
+/// ```
+///
+/// # mul_assign
+/// ```
+/// use malachite_nz::integer::Integer;
+/// use malachite_poly::Polynomial;
+///
+/// // x^3 - 2
+/// let cbrt_2 = Polynomial::<Integer>::from_coefficients_asc(&[-2, 0, 0, 1].map(Integer::from))
+///     .real_roots()
+///     .pop()
+///     .unwrap();
+/// let mut x = cbrt_2.clone();
+/// x *= &cbrt_2;
+/// assert_eq!(x.to_string(), "root 0 of x^3 - 4");
+/// x *= cbrt_2;
+/// assert_eq!(x.to_string(), "2");
+/// ```
+///
+/// # div
+/// ```
+/// use malachite_nz::integer::Integer;
+/// use malachite_poly::Polynomial;
+///
+/// let sqrt_2 = Polynomial::<Integer>::from_coefficients_asc(&[-2, 0, 1].map(Integer::from))
+///     .real_roots()
+///     .pop()
+///     .unwrap();
+/// let sqrt_3 = Polynomial::<Integer>::from_coefficients_asc(&[-3, 0, 1].map(Integer::from))
+///     .real_roots()
+///     .pop()
+///     .unwrap();
+/// assert_eq!((&sqrt_2 / &sqrt_3).to_string(), "root 1 of 3*x^2 - 2");
+/// assert_eq!((&sqrt_2 / &sqrt_2).to_string(), "1");
+/// ```
+///
+/// # div_assign
+/// ```
+/// use malachite_nz::integer::Integer;
+/// use malachite_poly::real_algebraic::RealAlgebraic;
+/// use malachite_poly::Polynomial;
+///
+/// let sqrt_2 = Polynomial::<Integer>::from_coefficients_asc(&[-2, 0, 1].map(Integer::from))
+///     .real_roots()
+///     .pop()
+///     .unwrap();
+/// let mut x = RealAlgebraic::from(Integer::from(1));
+/// x /= sqrt_2;
+/// assert_eq!(x.to_string(), "root 1 of 2*x^2 - 1");
+/// ```
+///
+/// # reciprocal
+/// ```
+/// use malachite_base::num::arithmetic::traits::Reciprocal;
+/// use malachite_nz::integer::Integer;
+/// use malachite_poly::Polynomial;
+///
+/// // x^2 - x - 1
+/// let phi = Polynomial::<Integer>::from_coefficients_asc(&[-1, -1, 1].map(Integer::from))
+///     .real_roots()
+///     .pop()
+///     .unwrap();
+/// assert_eq!((&phi).reciprocal().to_string(), "root 1 of x^2 + x - 1");
+/// assert_eq!(phi.reciprocal().to_string(), "root 1 of x^2 + x - 1");
+/// ```
+///
+/// # sign
+/// ```
+/// use malachite_base::num::arithmetic::traits::Sign;
+/// use malachite_nz::integer::Integer;
+/// use malachite_poly::Polynomial;
+/// use std::cmp::Ordering::*;
+///
+/// // x^3 - 3x + 1
+/// let roots = Polynomial::<Integer>::from_coefficients_asc(&[1, -3, 0, 1].map(Integer::from))
+///     .real_roots();
+/// assert_eq!(roots[0].sign(), Less);
+/// assert_eq!(roots[1].sign(), Greater);
+/// assert_eq!(roots[2].sign(), Greater);
+/// assert_eq!((&roots[0] - &roots[0]).sign(), Equal);
+/// ```
+///
+/// # floor
+/// ```
+/// use malachite_base::num::arithmetic::traits::Floor;
+/// use malachite_nz::integer::Integer;
+/// use malachite_poly::Polynomial;
+///
+/// // x^3 - 3x + 1
+/// let roots = Polynomial::<Integer>::from_coefficients_asc(&[1, -3, 0, 1].map(Integer::from))
+///     .real_roots();
+/// assert_eq!((&roots[0]).floor(), -2);
+/// assert_eq!((&roots[1]).floor(), 0);
+/// assert_eq!((&roots[2]).floor(), 1);
+/// ```
+///
+/// # ceiling
+/// ```
+/// use malachite_base::num::arithmetic::traits::Ceiling;
+/// use malachite_nz::integer::Integer;
+/// use malachite_poly::Polynomial;
+///
+/// // x^3 - 3x + 1
+/// let roots = Polynomial::<Integer>::from_coefficients_asc(&[1, -3, 0, 1].map(Integer::from))
+///     .real_roots();
+/// assert_eq!((&roots[0]).ceiling(), -1);
+/// assert_eq!((&roots[1]).ceiling(), 1);
+/// assert_eq!((&roots[2]).ceiling(), 2);
+/// ```
+pub mod arithmetic;
+/// Comparisons of [`RealAlgebraic`]s with each other and with [`Rational`]s.
+///
+/// Two [`RealAlgebraic`]s are equal if and only if they have the same minimal polynomial and
+/// their isolating intervals contain the same root. To compare two different numbers, their
+/// isolating intervals are narrowed until they are disjoint.
+///
+/// # eq
+/// ```
+/// use malachite_nz::integer::Integer;
+/// use malachite_poly::real_algebraic::RealAlgebraic;
+/// use malachite_poly::Polynomial;
+/// use malachite_q::Rational;
+///
+/// let roots = Polynomial::<Integer>::from_coefficients_asc(&[-2, 0, 1].map(Integer::from))
+///     .real_roots();
+/// assert_eq!(-&roots[0], roots[1]);
+/// assert_ne!(roots[0], roots[1]);
+/// assert_eq!(&roots[1] * &roots[1], Rational::from(2));
+/// assert_ne!(roots[1], Rational::from_signeds(99, 70));
+/// ```
+///
+/// # partial_cmp
+/// ```
+/// use malachite_nz::integer::Integer;
+/// use malachite_poly::Polynomial;
+/// use malachite_q::Rational;
+///
+/// let sqrt_2 = Polynomial::<Integer>::from_coefficients_asc(&[-2, 0, 1].map(Integer::from))
+///     .real_roots()
+///     .pop()
+///     .unwrap();
+/// assert!(sqrt_2 > Rational::from_signeds(1393, 985));
+/// assert!(sqrt_2 < Rational::from_signeds(577, 408));
+/// assert!(Rational::from_signeds(3, 2) > sqrt_2);
+/// ```
+///
+/// # cmp
+/// ```
+/// use malachite_nz::integer::Integer;
+/// use malachite_poly::Polynomial;
+/// use malachite_q::Rational;
+///
+/// let sqrt_2 = Polynomial::<Integer>::from_coefficients_asc(&[-2, 0, 1].map(Integer::from))
+///     .real_roots()
+///     .pop()
+///     .unwrap();
+/// // x^5 - x - 1
+/// let x = Polynomial::<Integer>::from_coefficients_asc(&[-1, -1, 0, 0, 0, 1].map(Integer::from))
+///     .real_roots()
+///     .pop()
+///     .unwrap();
+/// assert!(x < sqrt_2);
+/// assert!(&x * &x > Rational::from(1));
+/// ```
+pub mod comparison;
+/// Conversions between [`RealAlgebraic`]s and [`Rational`]s, rounding [`RealAlgebraic`]s to
+/// [`Float`](malachite_float::Float)s, and converting [`RealAlgebraic`]s to strings.
+///
+/// # from
+/// ```
+/// use malachite_nz::integer::Integer;
+/// use malachite_poly::real_algebraic::RealAlgebraic;
+/// use malachite_q::Rational;
+///
+/// assert_eq!(RealAlgebraic::from(Integer::from(-3)).to_string(), "-3");
+/// assert_eq!(
+///     RealAlgebraic::from(&Rational::from_signeds(22, 7)).to_string(),
+///     "22/7"
+/// );
+/// ```
+///
+/// # try_from
+/// ```
+/// use malachite_nz::integer::Integer;
+/// use malachite_poly::real_algebraic::conversion::RationalFromRealAlgebraicError;
+/// use malachite_poly::Polynomial;
+/// use malachite_q::Rational;
+///
+/// // (x^2 - 2)(2x + 1)
+/// let roots = Polynomial::<Integer>::from_coefficients_asc(&[-2, -4, 1, 2].map(Integer::from))
+///     .real_roots();
+/// assert_eq!(
+///     Rational::try_from(&roots[1]).unwrap(),
+///     Rational::from_signeds(-1, 2)
+/// );
+/// assert_eq!(
+///     Rational::try_from(&roots[2]),
+///     Err(RationalFromRealAlgebraicError)
+/// );
+/// ```
+///
+/// # to_float_prec_round
+/// ```
+/// use malachite_base::rounding_modes::RoundingMode::*;
+/// use malachite_base::strings::ToDebugString;
+/// use malachite_nz::integer::Integer;
+/// use malachite_poly::Polynomial;
+///
+/// let sqrt_2 = Polynomial::<Integer>::from_coefficients_asc(&[-2, 0, 1].map(Integer::from))
+///     .real_roots()
+///     .pop()
+///     .unwrap();
+/// assert_eq!(
+///     sqrt_2.to_float_prec_round(10, Floor).to_debug_string(),
+///     "(1.414, Less)"
+/// );
+/// assert_eq!(
+///     sqrt_2.to_float_prec(100).to_debug_string(),
+///     "(1.414213562373095048801688724209, Less)"
+/// );
+/// ```
+///
+/// # to_string
+/// ```
+/// use malachite_nz::integer::Integer;
+/// use malachite_poly::Polynomial;
+///
+/// // (x^2 - 2)(2x + 1)
+/// let roots = Polynomial::<Integer>::from_coefficients_asc(&[-2, -4, 1, 2].map(Integer::from))
+///     .real_roots();
+/// assert_eq!(
+///     format!("{:?}", roots),
+///     "[root 0 of x^2 - 2, -1/2, root 1 of x^2 - 2]"
+/// );
+/// ```
+pub mod conversion;
+mod factor;
@@ -0,0 +1,11 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+/// Isolation of the real roots of [`Polynomial`](crate::Polynomial)s over $\Z$, and their
+/// approximation by [`Float`](malachite_float::Float)s.
+pub mod real_roots;
@@ -0,0 +1,483 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::arithmetic::div_rem::div_rem_integer;
+use crate::arithmetic::gcd::{gcd_integer, make_primitive};
+use crate::Polynomial;
+use alloc::vec::Vec;
+use core::cmp::Ordering::{self, *};
+use malachite_base::num::arithmetic::traits::{NegAssign, Sign};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_float::Float;
+use malachite_nz::integer::Integer;
+use malachite_q::arithmetic::traits::SimplestRationalInInterval;
+use malachite_q::Rational;
+
+// Returns the squarefree part of a nonzero polynomial over $\Z$: the primitive polynomial with
+// positive leading coefficient whose roots are the distinct roots of `xs`, each with multiplicity
+// 1.
+pub(crate) fn squarefree_part(xs: &[Integer]) -> Vec<Integer> {
+    assert!(
+        !xs.is_empty(),
+        "Cannot find the roots of the zero polynomial"
+    );
+    if xs.len() == 1 {
+        return vec![Integer::ONE];
+    }
+    let derivative: Vec<Integer> = xs
+        .iter()
+        .enumerate()
+        .skip(1)
+        .map(|(i, x)| x * Integer::from(i))
+        .collect();
+    let g = gcd_integer(xs, &derivative);
+    let mut ys = div_rem_integer(xs.to_vec(), &g).0;
+    make_primitive(&mut ys);
+    ys
+}
+
+// Returns the sign of a polynomial over $\Z$ evaluated at `x`. If $x = n/d$, the sign of
+// $d^k p(n/d)$ is computed instead, where $k$ is the degree, so that only `Integer` arithmetic is
+// needed.
+pub(crate) fn sign_at(xs: &[Integer], x: &Rational) -> Ordering {
+    let mut ys = xs.iter().rev();
+    let Some(y) = ys.next() else {
+        return Equal;
+    };
+    let (n, d) = x.numerator_and_denominator_ref();
+    let n = Integer::from_sign_and_abs_ref(*x >= 0u32, n);
+    let d = Integer::from(d);
+    let mut out = y.clone();
+    let mut d_pow = Integer::ONE;
+    for y in ys {
+        d_pow *= &d;
+        out *= &n;
+        out += y * &d_pow;
+    }
+    out.sign()
+}
+
+// Replaces $p(x)$ by $p(x + 1)$.
+fn taylor_shift_1(xs: &mut [Integer]) {
+    let n = xs.len();
+    for i in 1..n {
+        for j in (i..n).rev() {
+            let (lo, hi) = xs.split_at_mut(j);
+            lo[j - 1] += &hi[0];
+        }
+    }
+}
+
+// Returns the number of sign changes in the coefficients of $(x + 1)^k p(1 / (x + 1))$, where $k$
+// is the degree. By Descartes' rule of signs this is an upper bound for the number of roots of
+// $p$ in $(0, 1)$, and it has the same parity. When it is 0 or 1, it is exact.
+fn descartes_bound(xs: &[Integer]) -> usize {
+    let mut ys: Vec<Integer> = xs.iter().rev().cloned().collect();
+    taylor_shift_1(&mut ys);
+    let mut variations = 0;
+    let mut previous = Equal;
+    for y in &ys {
+        let sign = y.sign();
+        if sign != Equal {
+            if previous != Equal && sign != previous {
+                variations += 1;
+            }
+            previous = sign;
+        }
+    }
+    variations
+}
+
+// Isolates the positive roots of a squarefree polynomial over $\Z$, given that its roots all have
+// absolute value less than $2^k$. Each isolating interval is pushed onto `out`, either as an open
+// interval or as a degenerate interval containing an exact root. If `zero_is_root` is true, the
+// polynomial is assumed to have been divided by $x$, and no open interval will have 0 as an
+// endpoint.
+//
+// This is the Vincent–Collins–Akritas bisection algorithm: the polynomial is transformed so that
+// the interval of interest becomes $(0, 1)$, and Descartes' rule of signs is used to decide
+// whether to stop or to split the interval in half.
+fn isolate_positive_roots(
+    xs: &[Integer],
+    k: u64,
+    zero_is_root: bool,
+    out: &mut Vec<(Rational, Rational)>,
+) {
+    let k = i64::exact_from(k);
+    let degree = xs.len() - 1;
+    // Each entry is $(r, c, j, l, h)$, where the roots of $r$ in $(0, 1)$ correspond to the roots
+    // of `xs` in $(2^{k-j} c, 2^{k-j} (c + 1))$, and $l$ and $h$ indicate whether the lower and
+    // upper endpoints of that interval are roots. An interval with a root as an endpoint is
+    // bisected further even if it contains only one root.
+    let mut stack = vec![(
+        xs.iter()
+            .enumerate()
+            .map(|(i, x)| x << (k * i64::exact_from(i)))
+            .collect::<Vec<Integer>>(),
+        Integer::ZERO,
+        0i64,
+        zero_is_root,
+        false,
+    )];
+    while let Some((r, c, j, low_is_root, high_is_root)) = stack.pop() {
+        match descartes_bound(&r) {
+            0 => {}
+            1 if !low_is_root && !high_is_root => out.push((
+                Rational::from(&c) << (k - j),
+                Rational::from(c + Integer::ONE) << (k - j),
+            )),
+            _ => {
+                // left is $2^d r(x/2)$, whose roots in $(0, 1)$ are the roots of $r$ in
+                // $(0, 1/2)$, and right is $2^d r((x+1)/2)$, whose roots in $(0, 1)$ are the roots
+                // of $r$ in $(1/2, 1)$.
+                let left: Vec<Integer> = r
+                    .iter()
+                    .enumerate()
+                    .map(|(i, x)| x << (degree - i))
+                    .collect();
+                let mut right = left.clone();
+                taylor_shift_1(&mut right);
+                let c = c << 1u32;
+                let mid_is_root = right[0] == 0u32;
+                if mid_is_root {
+                    let mid = Rational::from(&c + Integer::ONE) << (k - j - 1);
+                    out.push((mid.clone(), mid));
+                }
+                stack.push((right, &c + Integer::ONE, j + 1, mid_is_root, high_is_root));
+                stack.push((left, c, j + 1, low_is_root, mid_is_root));
+            }
+        }
+    }
+}
+
+// Splits an isolating interval $(a, b)$ for a root of the squarefree polynomial `xs` at the
+// simplest rational in its middle half, and keeps the half that contains the root, where the sign
+// of `xs` at $a$ is `sign_a`. If the root is found exactly, both endpoints are set to it.
+pub(crate) fn bisect_isolating_interval(
+    xs: &[Integer],
+    a: &mut Rational,
+    b: &mut Rational,
+    sign_a: Ordering,
+) {
+    let quarter = (&*b - &*a) >> 2u32;
+    let m = Rational::simplest_rational_in_open_interval(&(&*a + &quarter), &(&*b - &quarter));
+    match sign_at(xs, &m) {
+        Equal => {
+            a.clone_from(&m);
+            *b = m;
+        }
+        sign if sign == sign_a => *a = m,
+        _ => *b = m,
+    }
+}
+
+// Given the leading coefficient and the other coefficients of a polynomial over $\Z$, returns $k$
+// such that every root has absolute value less than $2^k$. By Cauchy's bound, every root has
+// absolute value less than $1 + \max_i |a_i| / |a_d|$.
+fn cauchy_bound_exponent(leading: &Integer, rest: &[Integer]) -> u64 {
+    let max_bits = rest
+        .iter()
+        .map(SignificantBits::significant_bits)
+        .max()
+        .unwrap_or(0);
+    (max_bits + 2)
+        .saturating_sub(leading.significant_bits())
+        .max(1)
+}
+
+// Isolates the real roots of a squarefree polynomial over $\Z$, returning the isolating intervals
+// in increasing order.
+pub(crate) fn isolate_real_roots(xs: &[Integer]) -> Vec<(Rational, Rational)> {
+    let mut out = Vec::new();
+    let mut xs = xs.to_vec();
+    let zero_is_root = xs[0] == 0u32;
+    if zero_is_root {
+        // Since `xs` is squarefree, 0 is a simple root.
+        out.push((Rational::ZERO, Rational::ZERO));
+        xs.remove(0);
+    }
+    if xs.len() == 1 {
+        return out;
+    }
+    let (leading, rest) = xs.split_last().unwrap();
+    let k = cauchy_bound_exponent(leading, rest);
+    isolate_positive_roots(&xs, k, zero_is_root, &mut out);
+    let mut negative_roots = Vec::new();
+    for x in xs.iter_mut().skip(1).step_by(2) {
+        x.neg_assign();
+    }
+    isolate_positive_roots(&xs, k, zero_is_root, &mut negative_roots);
+    out.extend(negative_roots.into_iter().map(|(a, b)| (-b, -a)));
+    out.sort_unstable();
+    out
+}
+
+// Rounds the unique root of the squarefree polynomial `xs` in the open interval $(a, b)$ to a
+// `Float` with precision `prec`, where the sign of `xs` at $a$ is `sign_a`.
+//
+// Every point at which the result of rounding to precision `prec` changes is a `Float` with
+// precision `prec + 1`. The interval is split at such points until it contains none of them, at
+// which point every element of the interval rounds to the same `Float`.
+pub(crate) fn round_root(
+    xs: &[Integer],
+    mut a: Rational,
+    mut b: Rational,
+    sign_a: Ordering,
+    prec: u64,
+    rm: RoundingMode,
+) -> (Float, Ordering) {
+    loop {
+        let m = (&a + &b) >> 1u32;
+        // If there is a `(prec + 1)`-bit `Float` in $(a, b)$, then the one nearest to the midpoint
+        // is one.
+        let s = Rational::exact_from(Float::from_rational_prec_round_ref(&m, prec + 1, Nearest).0);
+        if s <= a || s >= b {
+            // The root is not a `(prec + 1)`-bit `Float`, so it can't be represented exactly.
+            assert_ne!(rm, Exact, "Inexact conversion to Float");
+            let f = Float::from_rational_prec_round(m, prec, rm).0;
+            // `f` is not in $(a, b)$, so it lies on one side of the root.
+            let o = if Rational::exact_from(&f) <= a {
+                Less
+            } else {
+                Greater
+            };
+            return (f, o);
+        }
+        match sign_at(xs, &s) {
+            Equal => return Float::from_rational_prec_round(s, prec, rm),
+            sign if sign == sign_a => a = s,
+            _ => b = s,
+        }
+    }
+}
+
+impl Polynomial<Integer> {
+    /// Finds isolating intervals for the real roots of a [`Polynomial`] over $\Z$.
+    ///
+    /// The result contains one pair $(a, b)$ for each distinct real root, in increasing order. If
+    /// $a = b$, then $a$ is a root. Otherwise, $a < b$ and the open interval $(a, b)$ contains
+    /// exactly one root, which is irrational or at least not equal to $a$ or $b$. The open
+    /// intervals are disjoint from each other and from the exact roots. Repeated roots appear
+    /// only once.
+    ///
+    /// The roots are isolated using the Vincent–Collins–Akritas algorithm, which repeatedly
+    /// bisects an interval and uses Descartes' rule of signs to bound the number of roots in each
+    /// half. All endpoints are dyadic rationals, scaled by a power of 2 that bounds the absolute
+    /// values of the roots. An interval can be narrowed further with
+    /// [`refine_real_root_interval`](Polynomial::refine_real_root_interval).
+    ///
+    /// # Worst-case complexity
+    /// $T(n, d) = O(d^5 (d + n)^2)$
+    ///
+    /// $M(n, d) = O(d^2 (d + n))$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $d$ is `self.degree()`, and $n$ is the
+    /// maximum number of bits in a coefficient of `self`.
+    ///
+    /// # Panics
+    /// Panics if `self` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_poly::Polynomial;
+    ///
+    /// // x^2 - 2
+    /// let p = Polynomial::<Integer>::from_coefficients_asc(&[-2, 0, 1].map(Integer::from));
+    /// assert_eq!(
+    ///     p.real_root_isolating_intervals().to_debug_string(),
+    ///     "[(-8, 0), (0, 8)]"
+    /// );
+    ///
+    /// // (x - 1)(x - 2)(x - 3)
+    /// let p = Polynomial::<Integer>::from_coefficients_asc(&[-6, 11, -6, 1].map(Integer::from));
+    /// assert_eq!(
+    ///     p.real_root_isolating_intervals().to_debug_string(),
+    ///     "[(1, 1), (2, 2), (3, 3)]"
+    /// );
+    /// ```
+    pub fn real_root_isolating_intervals(&self) -> Vec<(Rational, Rational)> {
+        isolate_real_roots(&squarefree_part(&self.coefficients))
+    }
+
+    /// Narrows an isolating interval for a real root of a [`Polynomial`] over $\Z$ until its
+    /// width is at most `width`.
+    ///
+    /// The interval $(a, b)$ should be one of the intervals returned by
+    /// [`real_root_isolating_intervals`](Polynomial::real_root_isolating_intervals), or any other
+    /// interval with $a < b$ containing exactly one root and no roots at its endpoints. If $a =
+    /// b$, $a$ must be a root, and the interval is returned unchanged. The result is again an
+    /// isolating interval of the same form: either an open interval containing the root, or a
+    /// degenerate interval if the root was found exactly.
+    ///
+    /// Each step splits the interval at the simplest [`Rational`] in its middle half, found using
+    /// [`SimplestRationalInInterval`], which keeps the endpoints small.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, d, w) = O(d^2 (n + \log w)^2 \log w)$
+    ///
+    /// $M(n, d, w) = O(d (n + \log w))$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $d$ is `self.degree()`, $n$ is the maximum
+    /// number of bits in a coefficient of `self` or in $a$ or $b$, and $w$ is $(b - a)$ /
+    /// `width`.
+    ///
+    /// # Panics
+    /// Panics if `self` is zero, if `width` is not positive, if $a = b$ and $a$ is not a root, or
+    /// if $a \neq b$ and `self` doesn't change sign between $a$ and $b$ (after removing repeated
+    /// factors).
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_poly::Polynomial;
+    /// use malachite_q::Rational;
+    ///
+    /// // x^2 - 2
+    /// let p = Polynomial::<Integer>::from_coefficients_asc(&[-2, 0, 1].map(Integer::from));
+    /// assert_eq!(
+    ///     p.refine_real_root_interval(
+    ///         &Rational::from(1),
+    ///         &Rational::from(2),
+    ///         &Rational::from_unsigneds(1u32, 100)
+    ///     )
+    ///     .to_debug_string(),
+    ///     "(24/17, 17/12)"
+    /// );
+    /// ```
+    pub fn refine_real_root_interval(
+        &self,
+        a: &Rational,
+        b: &Rational,
+        width: &Rational,
+    ) -> (Rational, Rational) {
+        assert!(*width > 0u32, "width must be positive");
+        let xs = squarefree_part(&self.coefficients);
+        if a == b {
+            assert_eq!(sign_at(&xs, a), Equal, "The interval must contain a root");
+            return (a.clone(), b.clone());
+        }
+        let sign_a = sign_at(&xs, a);
+        let sign_b = sign_at(&xs, b);
+        assert!(
+            a < b && sign_a != Equal && sign_b != Equal && sign_a != sign_b,
+            "The interval must isolate a root"
+        );
+        let mut a = a.clone();
+        let mut b = b.clone();
+        while &b - &a > *width {
+            bisect_isolating_interval(&xs, &mut a, &mut b, sign_a);
+        }
+        (a, b)
+    }
+
+    /// Approximates the real roots of a [`Polynomial`] over $\Z$ by [`Float`]s with a given
+    /// precision, rounding each root using the given rounding mode.
+    ///
+    /// The result contains one pair for each distinct real root, in increasing order. The
+    /// [`Float`] is the root rounded to `prec` bits, and the [`Ordering`] is `Less` if the
+    /// [`Float`] is less than the root, `Greater` if it is greater, and `Equal` if the root is
+    /// exactly representable. The rounding is correct: the results are the same as if each root
+    /// were known exactly and passed to
+    /// [`Float::from_rational_prec_round`](Float::from_rational_prec_round).
+    ///
+    /// The roots are first isolated using
+    /// [`real_root_isolating_intervals`](Polynomial::real_root_isolating_intervals), and then each
+    /// interval is bisected until all of its elements round to the same [`Float`].
+    ///
+    /// # Worst-case complexity
+    /// $T(n, d, p) = O(d^5 (d + n)^2 + d^3 (n + p)^2 (d + n + p))$
+    ///
+    /// $M(n, d, p) = O(d^2 (d + n) + d (n + p))$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $d$ is `self.degree()`, $n$ is the maximum
+    /// number of bits in a coefficient of `self`, and $p$ is `prec`.
+    ///
+    /// # Panics
+    /// Panics if `self` is zero, if `prec` is zero, or if `rm` is `Exact` but some root cannot be
+    /// represented exactly with the given precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::rounding_modes::RoundingMode::*;
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_poly::Polynomial;
+    ///
+    /// // x^2 - 2
+    /// let p = Polynomial::<Integer>::from_coefficients_asc(&[-2, 0, 1].map(Integer::from));
+    /// assert_eq!(
+    ///     p.real_roots_prec_round(10, Floor).to_debug_string(),
+    ///     "[(-1.416, Less), (1.414, Less)]"
+    /// );
+    /// assert_eq!(
+    ///     p.real_roots_prec_round(10, Ceiling).to_debug_string(),
+    ///     "[(-1.414, Greater), (1.416, Greater)]"
+    /// );
+    ///
+    /// // 4x - 1
+    /// let p = Polynomial::<Integer>::from_coefficients_asc(&[-1, 4].map(Integer::from));
+    /// assert_eq!(p.real_roots_prec_round(1, Exact).to_debug_string(), "[(0.2, Equal)]");
+    /// ```
+    pub fn real_roots_prec_round(&self, prec: u64, rm: RoundingMode) -> Vec<(Float, Ordering)> {
+        assert_ne!(prec, 0);
+        let xs = squarefree_part(&self.coefficients);
+        isolate_real_roots(&xs)
+            .into_iter()
+            .map(|(a, b)| {
+                if a == b {
+                    Float::from_rational_prec_round(a, prec, rm)
+                } else {
+                    let sign_a = sign_at(&xs, &a);
+                    round_root(&xs, a, b, sign_a, prec, rm)
+                }
+            })
+            .collect()
+    }
+
+    /// Approximates the real roots of a [`Polynomial`] over $\Z$ by [`Float`]s with a given
+    /// precision, rounding each root to the nearest [`Float`].
+    ///
+    /// See [`real_roots_prec_round`](Polynomial::real_roots_prec_round) for details. If a root is
+    /// equidistant from two [`Float`]s with the given precision, the one with the even mantissa
+    /// is chosen.
+    ///
+    /// # Worst-case complexity
+    /// $T(n, d, p) = O(d^5 (d + n)^2 + d^3 (n + p)^2 (d + n + p))$
+    ///
+    /// $M(n, d, p) = O(d^2 (d + n) + d (n + p))$
+    ///
+    /// where $T$ is time, $M$ is additional memory, $d$ is `self.degree()`, $n$ is the maximum
+    /// number of bits in a coefficient of `self`, and $p$ is `prec`.
+    ///
+    /// # Panics
+    /// Panics if `self` is zero or if `prec` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::integer::Integer;
+    /// use malachite_poly::Polynomial;
+    ///
+    /// // x^3 - x - 1, whose only real root is the plastic ratio
+    /// let p = Polynomial::<Integer>::from_coefficients_asc(&[-1, -1, 0, 1].map(Integer::from));
+    /// assert_eq!(
+    ///     p.real_roots_prec(100).to_debug_string(),
+    ///     "[(1.324717957244746025960908854479, Greater)]"
+    /// );
+    /// ```
+    #[inline]
+    pub fn real_roots_prec(&self, prec: u64) -> Vec<(Float, Ordering)> {
+        self.real_roots_prec_round(prec, Nearest)
+    }
+}
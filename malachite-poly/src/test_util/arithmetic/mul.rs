@@ -0,0 +1,53 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::mod_natural::ModNatural;
+use crate::{trim, Coefficient, Polynomial};
+use malachite_nz::integer::Integer;
+use malachite_q::Rational;
+
+fn mul_naive_helper<T: Coefficient>(xs: &[T], ys: &[T]) -> Vec<T> {
+    if xs.is_empty() || ys.is_empty() {
+        return Vec::new();
+    }
+    let mut out = vec![xs[0].zero_like(); xs.len() + ys.len() - 1];
+    for (i, x) in xs.iter().enumerate() {
+        for (j, y) in ys.iter().enumerate() {
+            out[i + j] += x.clone() * y.clone();
+        }
+    }
+    trim(&mut out);
+    out
+}
+
+pub fn polynomial_integer_mul_naive(
+    xs: &Polynomial<Integer>,
+    ys: &Polynomial<Integer>,
+) -> Polynomial<Integer> {
+    Polynomial {
+        coefficients: mul_naive_helper(&xs.coefficients, &ys.coefficients),
+    }
+}
+
+pub fn polynomial_rational_mul_naive(
+    xs: &Polynomial<Rational>,
+    ys: &Polynomial<Rational>,
+) -> Polynomial<Rational> {
+    Polynomial {
+        coefficients: mul_naive_helper(&xs.coefficients, &ys.coefficients),
+    }
+}
+
+pub fn polynomial_mod_natural_mul_naive(
+    xs: &Polynomial<ModNatural>,
+    ys: &Polynomial<ModNatural>,
+) -> Polynomial<ModNatural> {
+    Polynomial {
+        coefficients: mul_naive_helper(&xs.coefficients, &ys.coefficients),
+    }
+}
@@ -0,0 +1,111 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::mod_natural::ModNatural;
+use crate::Polynomial;
+use malachite_base::num::arithmetic::traits::Mod;
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_q::Rational;
+
+// Computes the determinant of the Sylvester matrix of two nonzero polynomials, using Gaussian
+// elimination over $\Q$.
+fn sylvester_determinant(xs: &[Rational], ys: &[Rational]) -> Rational {
+    let m = xs.len() - 1;
+    let n = ys.len() - 1;
+    let size = m + n;
+    if size == 0 {
+        return Rational::ONE;
+    }
+    let mut matrix = vec![vec![Rational::ZERO; size]; size];
+    for i in 0..n {
+        for (j, x) in xs.iter().rev().enumerate() {
+            matrix[i][i + j] = x.clone();
+        }
+    }
+    for i in 0..m {
+        for (j, y) in ys.iter().rev().enumerate() {
+            matrix[n + i][i + j] = y.clone();
+        }
+    }
+    let mut determinant = Rational::ONE;
+    for column in 0..size {
+        let Some(pivot) = (column..size).find(|&i| matrix[i][column] != 0u32) else {
+            return Rational::ZERO;
+        };
+        if pivot != column {
+            matrix.swap(pivot, column);
+            determinant = -determinant;
+        }
+        let pivot_row = matrix[column].clone();
+        determinant *= &pivot_row[column];
+        for row in &mut matrix[column + 1..] {
+            if row[column] != 0u32 {
+                let factor = &row[column] / &pivot_row[column];
+                for (x, p) in row[column..].iter_mut().zip(&pivot_row[column..]) {
+                    *x -= &factor * p;
+                }
+            }
+        }
+    }
+    determinant
+}
+
+pub fn polynomial_rational_resultant_naive(
+    xs: &Polynomial<Rational>,
+    ys: &Polynomial<Rational>,
+) -> Rational {
+    if xs.coefficients.is_empty() || ys.coefficients.is_empty() {
+        Rational::ZERO
+    } else {
+        sylvester_determinant(&xs.coefficients, &ys.coefficients)
+    }
+}
+
+pub fn polynomial_integer_resultant_naive(
+    xs: &Polynomial<Integer>,
+    ys: &Polynomial<Integer>,
+) -> Integer {
+    let to_rationals = |p: &Polynomial<Integer>| Polynomial {
+        coefficients: p.coefficients.iter().map(Rational::from).collect(),
+    };
+    Integer::try_from(polynomial_rational_resultant_naive(
+        &to_rationals(xs),
+        &to_rationals(ys),
+    ))
+    .unwrap()
+}
+
+// The moduli of the two polynomials must be equal, and at least one of the polynomials must be
+// nonzero.
+pub fn polynomial_mod_natural_resultant_naive(
+    xs: &Polynomial<ModNatural>,
+    ys: &Polynomial<ModNatural>,
+) -> ModNatural {
+    let modulus = xs
+        .coefficients
+        .first()
+        .or_else(|| ys.coefficients.first())
+        .unwrap()
+        .modulus
+        .clone();
+    let to_integers = |p: &Polynomial<ModNatural>| Polynomial {
+        coefficients: p
+            .coefficients
+            .iter()
+            .map(|x| Integer::from(&x.value))
+            .collect(),
+    };
+    let resultant = polynomial_integer_resultant_naive(&to_integers(xs), &to_integers(ys));
+    ModNatural::new(
+        Natural::exact_from(resultant.mod_op(Integer::from(&modulus))),
+        modulus,
+    )
+}
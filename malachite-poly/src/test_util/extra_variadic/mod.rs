@@ -0,0 +1,41 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::iterators::bit_distributor::{BitDistributor, BitDistributorOutputType};
+use malachite_base::iterators::iterator_cache::IteratorCache;
+use malachite_base::num::arithmetic::traits::CheckedPow;
+use malachite_base::num::conversion::traits::{ExactFrom, WrappingFrom};
+use malachite_base::num::logic::traits::SignificantBits;
+use malachite_base::random::Seed;
+use malachite_base::tuples::random::next_helper;
+use malachite_base::{exhaustive_tuples_1_input, random_tuples};
+use std::cmp::max;
+use std::marker::PhantomData;
+
+exhaustive_tuples_1_input!(
+    (pub(crate)),
+    ExhaustiveTriples1Input,
+    exhaustive_triples_1_input,
+    exhaustive_triples_from_single,
+    (I::Item, I::Item, I::Item),
+    [0, output_type_x],
+    [1, output_type_y],
+    [2, output_type_z]
+);
+
+random_tuples!(
+    (pub(crate)),
+    RandomTriples,
+    RandomTriplesFromSingle,
+    random_triples,
+    random_triples_from_single,
+    (I::Item, I::Item, I::Item),
+    [0, X, I, xs, xs_gen],
+    [1, Y, J, ys, ys_gen],
+    [2, Z, K, zs, zs_gen]
+);
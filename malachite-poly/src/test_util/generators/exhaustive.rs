@@ -0,0 +1,385 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::mod_natural::ModNatural;
+use crate::quadratic_irrational::QuadraticIrrational;
+use crate::real_algebraic::RealAlgebraic;
+use crate::test_util::extra_variadic::exhaustive_triples_from_single;
+use crate::Polynomial;
+use core::cmp::Ordering::Equal;
+use malachite_base::num::arithmetic::traits::{Sign, Square};
+use malachite_base::num::basic::traits::Zero;
+use malachite_base::num::exhaustive::exhaustive_positive_primitive_ints;
+use malachite_base::num::factorization::traits::IsPrime;
+use malachite_base::rounding_modes::exhaustive::exhaustive_rounding_modes;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_base::test_util::generators::common::It;
+use malachite_base::tuples::exhaustive::{
+    exhaustive_pairs, exhaustive_pairs_from_single, exhaustive_triples,
+};
+use malachite_base::vecs::exhaustive::exhaustive_vecs;
+use malachite_nz::integer::exhaustive::exhaustive_integers;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::exhaustive::{exhaustive_naturals, exhaustive_positive_naturals};
+use malachite_nz::natural::Natural;
+use malachite_q::exhaustive::exhaustive_rationals;
+use malachite_q::Rational;
+
+fn exhaustive_integer_polynomials() -> impl Clone + Iterator<Item = Polynomial<Integer>> {
+    exhaustive_vecs(exhaustive_integers())
+        .filter(|xs| xs.last() != Some(&Integer::ZERO))
+        .map(Polynomial::<Integer>::from_owned_coefficients_asc)
+}
+
+fn exhaustive_rational_polynomials() -> impl Clone + Iterator<Item = Polynomial<Rational>> {
+    exhaustive_vecs(exhaustive_rationals())
+        .filter(|xs| xs.last() != Some(&Rational::ZERO))
+        .map(Polynomial::<Rational>::from_owned_coefficients_asc)
+}
+
+// Only roots of polynomials with degree at most 3 are generated, to keep arithmetic fast.
+fn exhaustive_real_algebraics() -> impl Clone + Iterator<Item = RealAlgebraic> {
+    exhaustive_integer_polynomials()
+        .skip(1)
+        .filter(|p| p.coefficients().len() <= 4)
+        .flat_map(|p| p.real_roots())
+}
+
+fn exhaustive_quadratic_irrational_coefficients(
+) -> impl Clone + Iterator<Item = (Integer, Integer, Natural)> {
+    exhaustive_triples(
+        exhaustive_integers(),
+        exhaustive_integers(),
+        exhaustive_positive_naturals(),
+    )
+}
+
+fn exhaustive_quadratic_irrationals() -> impl Clone + Iterator<Item = QuadraticIrrational> {
+    exhaustive_pairs(
+        exhaustive_quadratic_irrational_coefficients(),
+        exhaustive_naturals(),
+    )
+    .map(|((a, b, c), d)| QuadraticIrrational::new(a, b, c, d))
+}
+
+fn exhaustive_prime_naturals() -> impl Clone + Iterator<Item = Natural> {
+    exhaustive_positive_naturals().filter(IsPrime::is_prime)
+}
+
+// -- Polynomial<Integer> --
+
+pub fn exhaustive_polynomial_integer_gen() -> It<Polynomial<Integer>> {
+    Box::new(exhaustive_integer_polynomials())
+}
+
+pub fn exhaustive_polynomial_integer_gen_var_1() -> It<Polynomial<Integer>> {
+    Box::new(exhaustive_integer_polynomials().skip(1))
+}
+
+pub fn exhaustive_polynomial_integer_pair_gen() -> It<(Polynomial<Integer>, Polynomial<Integer>)> {
+    Box::new(exhaustive_pairs_from_single(
+        exhaustive_integer_polynomials(),
+    ))
+}
+
+pub fn exhaustive_polynomial_integer_pair_gen_var_1(
+) -> It<(Polynomial<Integer>, Polynomial<Integer>)> {
+    Box::new(exhaustive_pairs(
+        exhaustive_integer_polynomials(),
+        exhaustive_integer_polynomials().skip(1),
+    ))
+}
+
+pub fn exhaustive_polynomial_integer_triple_gen() -> It<(
+    Polynomial<Integer>,
+    Polynomial<Integer>,
+    Polynomial<Integer>,
+)> {
+    Box::new(exhaustive_triples_from_single(
+        exhaustive_integer_polynomials(),
+    ))
+}
+
+pub fn exhaustive_polynomial_integer_integer_pair_gen() -> It<(Polynomial<Integer>, Integer)> {
+    Box::new(exhaustive_pairs(
+        exhaustive_integer_polynomials(),
+        exhaustive_integers(),
+    ))
+}
+
+// -- (Polynomial<Integer>, u64, RoundingMode) --
+
+pub fn exhaustive_polynomial_integer_unsigned_rounding_mode_triple_gen_var_1(
+) -> It<(Polynomial<Integer>, u64, RoundingMode)> {
+    Box::new(exhaustive_triples(
+        exhaustive_integer_polynomials().skip(1),
+        exhaustive_positive_primitive_ints(),
+        exhaustive_rounding_modes().filter(|&rm| rm != Exact),
+    ))
+}
+
+// -- Polynomial<Rational> --
+
+pub fn exhaustive_polynomial_rational_gen() -> It<Polynomial<Rational>> {
+    Box::new(exhaustive_rational_polynomials())
+}
+
+pub fn exhaustive_polynomial_rational_pair_gen() -> It<(Polynomial<Rational>, Polynomial<Rational>)>
+{
+    Box::new(exhaustive_pairs_from_single(
+        exhaustive_rational_polynomials(),
+    ))
+}
+
+pub fn exhaustive_polynomial_rational_pair_gen_var_1(
+) -> It<(Polynomial<Rational>, Polynomial<Rational>)> {
+    Box::new(exhaustive_pairs(
+        exhaustive_rational_polynomials(),
+        exhaustive_rational_polynomials().skip(1),
+    ))
+}
+
+pub fn exhaustive_polynomial_rational_triple_gen() -> It<(
+    Polynomial<Rational>,
+    Polynomial<Rational>,
+    Polynomial<Rational>,
+)> {
+    Box::new(exhaustive_triples_from_single(
+        exhaustive_rational_polynomials(),
+    ))
+}
+
+pub fn exhaustive_polynomial_rational_rational_pair_gen() -> It<(Polynomial<Rational>, Rational)> {
+    Box::new(exhaustive_pairs(
+        exhaustive_rational_polynomials(),
+        exhaustive_rationals(),
+    ))
+}
+
+// -- Polynomial<ModNatural> --
+
+pub fn exhaustive_polynomial_mod_natural_gen() -> It<Polynomial<ModNatural>> {
+    Box::new(
+        exhaustive_pairs(
+            exhaustive_positive_naturals(),
+            exhaustive_vecs(exhaustive_naturals()),
+        )
+        .map(|(m, xs)| Polynomial::from_naturals_asc(&xs, &m)),
+    )
+}
+
+pub fn exhaustive_polynomial_mod_natural_pair_gen(
+) -> It<(Polynomial<ModNatural>, Polynomial<ModNatural>)> {
+    Box::new(
+        exhaustive_pairs(
+            exhaustive_positive_naturals(),
+            exhaustive_pairs_from_single(exhaustive_vecs(exhaustive_naturals())),
+        )
+        .map(|(m, (xs, ys))| {
+            (
+                Polynomial::from_naturals_asc(&xs, &m),
+                Polynomial::from_naturals_asc(&ys, &m),
+            )
+        }),
+    )
+}
+
+// All pairs of `Polynomial<ModNatural>`s with the same prime modulus, where the second
+// `Polynomial` is nonzero.
+pub fn exhaustive_polynomial_mod_natural_pair_gen_var_1(
+) -> It<(Polynomial<ModNatural>, Polynomial<ModNatural>)> {
+    Box::new(
+        exhaustive_pairs(
+            exhaustive_prime_naturals(),
+            exhaustive_pairs_from_single(exhaustive_vecs(exhaustive_naturals())),
+        )
+        .map(|(m, (xs, ys))| {
+            (
+                Polynomial::from_naturals_asc(&xs, &m),
+                Polynomial::from_naturals_asc(&ys, &m),
+            )
+        })
+        .filter(|(_, q)| q != &Polynomial::zero()),
+    )
+}
+
+pub fn exhaustive_polynomial_mod_natural_triple_gen() -> It<(
+    Polynomial<ModNatural>,
+    Polynomial<ModNatural>,
+    Polynomial<ModNatural>,
+)> {
+    Box::new(
+        exhaustive_pairs(
+            exhaustive_positive_naturals(),
+            exhaustive_triples_from_single(exhaustive_vecs(exhaustive_naturals())),
+        )
+        .map(|(m, (xs, ys, zs))| {
+            (
+                Polynomial::from_naturals_asc(&xs, &m),
+                Polynomial::from_naturals_asc(&ys, &m),
+                Polynomial::from_naturals_asc(&zs, &m),
+            )
+        }),
+    )
+}
+
+pub fn exhaustive_polynomial_mod_natural_mod_natural_pair_gen(
+) -> It<(Polynomial<ModNatural>, ModNatural)> {
+    Box::new(
+        exhaustive_pairs(
+            exhaustive_positive_naturals(),
+            exhaustive_pairs(
+                exhaustive_vecs(exhaustive_naturals()),
+                exhaustive_naturals(),
+            ),
+        )
+        .map(|(m, (xs, x))| {
+            (
+                Polynomial::from_naturals_asc(&xs, &m),
+                ModNatural::new(x, m),
+            )
+        }),
+    )
+}
+
+// -- RealAlgebraic --
+
+pub fn exhaustive_real_algebraic_gen() -> It<RealAlgebraic> {
+    Box::new(exhaustive_real_algebraics())
+}
+
+pub fn exhaustive_real_algebraic_gen_var_1() -> It<RealAlgebraic> {
+    Box::new(exhaustive_real_algebraics().filter(|x| x.sign() != Equal))
+}
+
+pub fn exhaustive_real_algebraic_pair_gen() -> It<(RealAlgebraic, RealAlgebraic)> {
+    Box::new(exhaustive_pairs_from_single(exhaustive_real_algebraics()))
+}
+
+pub fn exhaustive_real_algebraic_pair_gen_var_1() -> It<(RealAlgebraic, RealAlgebraic)> {
+    Box::new(exhaustive_pairs(
+        exhaustive_real_algebraics(),
+        exhaustive_real_algebraics().filter(|x| x.sign() != Equal),
+    ))
+}
+
+pub fn exhaustive_real_algebraic_triple_gen() -> It<(RealAlgebraic, RealAlgebraic, RealAlgebraic)> {
+    Box::new(exhaustive_triples_from_single(exhaustive_real_algebraics()))
+}
+
+pub fn exhaustive_real_algebraic_rational_pair_gen() -> It<(RealAlgebraic, Rational)> {
+    Box::new(exhaustive_pairs(
+        exhaustive_real_algebraics(),
+        exhaustive_rationals(),
+    ))
+}
+
+pub fn exhaustive_real_algebraic_unsigned_rounding_mode_triple_gen_var_1(
+) -> It<(RealAlgebraic, u64, RoundingMode)> {
+    Box::new(exhaustive_triples(
+        exhaustive_real_algebraics(),
+        exhaustive_positive_primitive_ints(),
+        exhaustive_rounding_modes().filter(|&rm| rm != Exact),
+    ))
+}
+
+// -- QuadraticIrrational --
+
+pub fn exhaustive_quadratic_irrational_gen() -> It<QuadraticIrrational> {
+    Box::new(exhaustive_quadratic_irrationals())
+}
+
+pub fn exhaustive_quadratic_irrational_gen_var_1() -> It<QuadraticIrrational> {
+    Box::new(exhaustive_quadratic_irrationals().filter(|x| x.sign() != Equal))
+}
+
+pub fn exhaustive_quadratic_irrational_gen_var_2() -> It<QuadraticIrrational> {
+    Box::new(
+        exhaustive_quadratic_irrationals()
+            .filter(|x| (x.b().unsigned_abs_ref() * x.c()).square() * x.d() < 1u32 << 12),
+    )
+}
+
+pub fn exhaustive_quadratic_irrational_pair_gen() -> It<(QuadraticIrrational, QuadraticIrrational)>
+{
+    Box::new(exhaustive_pairs_from_single(
+        exhaustive_quadratic_irrationals(),
+    ))
+}
+
+pub fn exhaustive_quadratic_irrational_pair_gen_var_1(
+) -> It<(QuadraticIrrational, QuadraticIrrational)> {
+    Box::new(
+        exhaustive_pairs(
+            exhaustive_naturals(),
+            exhaustive_pairs_from_single(exhaustive_quadratic_irrational_coefficients()),
+        )
+        .map(|(d, ((a_1, b_1, c_1), (a_2, b_2, c_2)))| {
+            (
+                QuadraticIrrational::new(a_1, b_1, c_1, d.clone()),
+                QuadraticIrrational::new(a_2, b_2, c_2, d),
+            )
+        }),
+    )
+}
+
+pub fn exhaustive_quadratic_irrational_pair_gen_var_2(
+) -> It<(QuadraticIrrational, QuadraticIrrational)> {
+    Box::new(exhaustive_quadratic_irrational_pair_gen_var_1().filter(|(_, y)| y.sign() != Equal))
+}
+
+pub fn exhaustive_quadratic_irrational_triple_gen_var_1() -> It<(
+    QuadraticIrrational,
+    QuadraticIrrational,
+    QuadraticIrrational,
+)> {
+    Box::new(
+        exhaustive_pairs(
+            exhaustive_naturals(),
+            exhaustive_triples_from_single(exhaustive_quadratic_irrational_coefficients()),
+        )
+        .map(|(d, ((a_1, b_1, c_1), (a_2, b_2, c_2), (a_3, b_3, c_3)))| {
+            (
+                QuadraticIrrational::new(a_1, b_1, c_1, d.clone()),
+                QuadraticIrrational::new(a_2, b_2, c_2, d.clone()),
+                QuadraticIrrational::new(a_3, b_3, c_3, d),
+            )
+        }),
+    )
+}
+
+pub fn exhaustive_quadratic_irrational_rational_pair_gen() -> It<(QuadraticIrrational, Rational)> {
+    Box::new(exhaustive_pairs(
+        exhaustive_quadratic_irrationals(),
+        exhaustive_rationals(),
+    ))
+}
+
+pub fn exhaustive_natural_gen_var_1() -> It<Natural> {
+    Box::new(exhaustive_naturals().take_while(|d| *d < 1u32 << 16))
+}
+
+// -- ModNatural --
+
+pub fn exhaustive_mod_natural_pair_gen() -> It<(ModNatural, ModNatural)> {
+    Box::new(
+        exhaustive_pairs(
+            exhaustive_positive_naturals(),
+            exhaustive_pairs_from_single(exhaustive_naturals()),
+        )
+        .map(|(m, (x, y))| (ModNatural::new(x, m.clone()), ModNatural::new(y, m))),
+    )
+}
+
+// All `ModNatural`s with a prime modulus.
+pub fn exhaustive_mod_natural_gen_var_1() -> It<ModNatural> {
+    Box::new(
+        exhaustive_pairs(exhaustive_prime_naturals(), exhaustive_naturals())
+            .map(|(m, x)| ModNatural::new(x, m)),
+    )
+}
@@ -0,0 +1,334 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::mod_natural::ModNatural;
+use crate::quadratic_irrational::QuadraticIrrational;
+use crate::real_algebraic::RealAlgebraic;
+use crate::test_util::generators::exhaustive::*;
+use crate::test_util::generators::random::*;
+use crate::Polynomial;
+use malachite_base::rounding_modes::RoundingMode;
+use malachite_base::test_util::generators::common::Generator;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_q::Rational;
+
+// -- Polynomial<Integer> --
+
+pub fn polynomial_integer_gen() -> Generator<Polynomial<Integer>> {
+    Generator::new_no_special(
+        &exhaustive_polynomial_integer_gen,
+        &random_polynomial_integer_gen,
+    )
+}
+
+// All nonzero `Polynomial<Integer>`s.
+pub fn polynomial_integer_gen_var_1() -> Generator<Polynomial<Integer>> {
+    Generator::new_no_special(
+        &exhaustive_polynomial_integer_gen_var_1,
+        &random_polynomial_integer_gen_var_1,
+    )
+}
+
+pub fn polynomial_integer_pair_gen() -> Generator<(Polynomial<Integer>, Polynomial<Integer>)> {
+    Generator::new_no_special(
+        &exhaustive_polynomial_integer_pair_gen,
+        &random_polynomial_integer_pair_gen,
+    )
+}
+
+// All pairs of `Polynomial<Integer>`s where the second `Polynomial` is nonzero.
+pub fn polynomial_integer_pair_gen_var_1() -> Generator<(Polynomial<Integer>, Polynomial<Integer>)>
+{
+    Generator::new_no_special(
+        &exhaustive_polynomial_integer_pair_gen_var_1,
+        &random_polynomial_integer_pair_gen_var_1,
+    )
+}
+
+pub fn polynomial_integer_triple_gen() -> Generator<(
+    Polynomial<Integer>,
+    Polynomial<Integer>,
+    Polynomial<Integer>,
+)> {
+    Generator::new_no_special(
+        &exhaustive_polynomial_integer_triple_gen,
+        &random_polynomial_integer_triple_gen,
+    )
+}
+
+// -- Polynomial<Integer> and Integer --
+
+pub fn polynomial_integer_integer_pair_gen() -> Generator<(Polynomial<Integer>, Integer)> {
+    Generator::new_no_special(
+        &exhaustive_polynomial_integer_integer_pair_gen,
+        &random_polynomial_integer_integer_pair_gen,
+    )
+}
+
+// -- (Polynomial<Integer>, u64, RoundingMode) --
+
+// All triples of `Polynomial<Integer>`, `u64`, and `RoundingMode` where the `Polynomial` is
+// nonzero, the `u64` is positive, and the `RoundingMode` is not `Exact`.
+pub fn polynomial_integer_unsigned_rounding_mode_triple_gen_var_1(
+) -> Generator<(Polynomial<Integer>, u64, RoundingMode)> {
+    Generator::new_no_special(
+        &exhaustive_polynomial_integer_unsigned_rounding_mode_triple_gen_var_1,
+        &random_polynomial_integer_unsigned_rounding_mode_triple_gen_var_1,
+    )
+}
+
+// -- Polynomial<Rational> --
+
+pub fn polynomial_rational_gen() -> Generator<Polynomial<Rational>> {
+    Generator::new_no_special(
+        &exhaustive_polynomial_rational_gen,
+        &random_polynomial_rational_gen,
+    )
+}
+
+pub fn polynomial_rational_pair_gen() -> Generator<(Polynomial<Rational>, Polynomial<Rational>)> {
+    Generator::new_no_special(
+        &exhaustive_polynomial_rational_pair_gen,
+        &random_polynomial_rational_pair_gen,
+    )
+}
+
+// All pairs of `Polynomial<Rational>`s where the second `Polynomial` is nonzero.
+pub fn polynomial_rational_pair_gen_var_1(
+) -> Generator<(Polynomial<Rational>, Polynomial<Rational>)> {
+    Generator::new_no_special(
+        &exhaustive_polynomial_rational_pair_gen_var_1,
+        &random_polynomial_rational_pair_gen_var_1,
+    )
+}
+
+pub fn polynomial_rational_triple_gen() -> Generator<(
+    Polynomial<Rational>,
+    Polynomial<Rational>,
+    Polynomial<Rational>,
+)> {
+    Generator::new_no_special(
+        &exhaustive_polynomial_rational_triple_gen,
+        &random_polynomial_rational_triple_gen,
+    )
+}
+
+// -- Polynomial<Rational> and Rational --
+
+pub fn polynomial_rational_rational_pair_gen() -> Generator<(Polynomial<Rational>, Rational)> {
+    Generator::new_no_special(
+        &exhaustive_polynomial_rational_rational_pair_gen,
+        &random_polynomial_rational_rational_pair_gen,
+    )
+}
+
+// -- Polynomial<ModNatural> --
+
+pub fn polynomial_mod_natural_gen() -> Generator<Polynomial<ModNatural>> {
+    Generator::new_no_special(
+        &exhaustive_polynomial_mod_natural_gen,
+        &random_polynomial_mod_natural_gen,
+    )
+}
+
+// All pairs of `Polynomial<ModNatural>`s with the same modulus.
+pub fn polynomial_mod_natural_pair_gen(
+) -> Generator<(Polynomial<ModNatural>, Polynomial<ModNatural>)> {
+    Generator::new_no_special(
+        &exhaustive_polynomial_mod_natural_pair_gen,
+        &random_polynomial_mod_natural_pair_gen,
+    )
+}
+
+// All pairs of `Polynomial<ModNatural>`s with the same prime modulus, where the second
+// `Polynomial` is nonzero.
+pub fn polynomial_mod_natural_pair_gen_var_1(
+) -> Generator<(Polynomial<ModNatural>, Polynomial<ModNatural>)> {
+    Generator::new_no_special(
+        &exhaustive_polynomial_mod_natural_pair_gen_var_1,
+        &random_polynomial_mod_natural_pair_gen_var_1,
+    )
+}
+
+// All triples of `Polynomial<ModNatural>`s with the same modulus.
+pub fn polynomial_mod_natural_triple_gen() -> Generator<(
+    Polynomial<ModNatural>,
+    Polynomial<ModNatural>,
+    Polynomial<ModNatural>,
+)> {
+    Generator::new_no_special(
+        &exhaustive_polynomial_mod_natural_triple_gen,
+        &random_polynomial_mod_natural_triple_gen,
+    )
+}
+
+// -- Polynomial<ModNatural> and ModNatural --
+
+// All pairs of a `Polynomial<ModNatural>` and a `ModNatural` with the same modulus.
+pub fn polynomial_mod_natural_mod_natural_pair_gen(
+) -> Generator<(Polynomial<ModNatural>, ModNatural)> {
+    Generator::new_no_special(
+        &exhaustive_polynomial_mod_natural_mod_natural_pair_gen,
+        &random_polynomial_mod_natural_mod_natural_pair_gen,
+    )
+}
+
+// -- RealAlgebraic --
+
+pub fn real_algebraic_gen() -> Generator<RealAlgebraic> {
+    Generator::new_no_special(&exhaustive_real_algebraic_gen, &random_real_algebraic_gen)
+}
+
+// All nonzero `RealAlgebraic`s.
+pub fn real_algebraic_gen_var_1() -> Generator<RealAlgebraic> {
+    Generator::new_no_special(
+        &exhaustive_real_algebraic_gen_var_1,
+        &random_real_algebraic_gen_var_1,
+    )
+}
+
+pub fn real_algebraic_pair_gen() -> Generator<(RealAlgebraic, RealAlgebraic)> {
+    Generator::new_no_special(
+        &exhaustive_real_algebraic_pair_gen,
+        &random_real_algebraic_pair_gen,
+    )
+}
+
+// All pairs of `RealAlgebraic`s where the second `RealAlgebraic` is nonzero.
+pub fn real_algebraic_pair_gen_var_1() -> Generator<(RealAlgebraic, RealAlgebraic)> {
+    Generator::new_no_special(
+        &exhaustive_real_algebraic_pair_gen_var_1,
+        &random_real_algebraic_pair_gen_var_1,
+    )
+}
+
+pub fn real_algebraic_triple_gen() -> Generator<(RealAlgebraic, RealAlgebraic, RealAlgebraic)> {
+    Generator::new_no_special(
+        &exhaustive_real_algebraic_triple_gen,
+        &random_real_algebraic_triple_gen,
+    )
+}
+
+pub fn real_algebraic_rational_pair_gen() -> Generator<(RealAlgebraic, Rational)> {
+    Generator::new_no_special(
+        &exhaustive_real_algebraic_rational_pair_gen,
+        &random_real_algebraic_rational_pair_gen,
+    )
+}
+
+// All triples of `RealAlgebraic`, `u64`, and `RoundingMode` where the `u64` is positive and the
+// `RoundingMode` is not `Exact`.
+pub fn real_algebraic_unsigned_rounding_mode_triple_gen_var_1(
+) -> Generator<(RealAlgebraic, u64, RoundingMode)> {
+    Generator::new_no_special(
+        &exhaustive_real_algebraic_unsigned_rounding_mode_triple_gen_var_1,
+        &random_real_algebraic_unsigned_rounding_mode_triple_gen_var_1,
+    )
+}
+
+// -- QuadraticIrrational --
+
+pub fn quadratic_irrational_gen() -> Generator<QuadraticIrrational> {
+    Generator::new_no_special(
+        &exhaustive_quadratic_irrational_gen,
+        &random_quadratic_irrational_gen,
+    )
+}
+
+// All nonzero `QuadraticIrrational`s.
+pub fn quadratic_irrational_gen_var_1() -> Generator<QuadraticIrrational> {
+    Generator::new_no_special(
+        &exhaustive_quadratic_irrational_gen_var_1,
+        &random_quadratic_irrational_gen_var_1,
+    )
+}
+
+// All `QuadraticIrrational`s $(a + b\sqrt{d})/c$ with $b^2c^2d < 2^{12}$. These have continued
+// fractions with short periods.
+pub fn quadratic_irrational_gen_var_2() -> Generator<QuadraticIrrational> {
+    Generator::new_no_special(
+        &exhaustive_quadratic_irrational_gen_var_2,
+        &random_quadratic_irrational_gen_var_2,
+    )
+}
+
+pub fn quadratic_irrational_pair_gen() -> Generator<(QuadraticIrrational, QuadraticIrrational)> {
+    Generator::new_no_special(
+        &exhaustive_quadratic_irrational_pair_gen,
+        &random_quadratic_irrational_pair_gen,
+    )
+}
+
+// All pairs of `QuadraticIrrational`s with the same radicand, or where at least one
+// `QuadraticIrrational` is rational.
+pub fn quadratic_irrational_pair_gen_var_1() -> Generator<(QuadraticIrrational, QuadraticIrrational)>
+{
+    Generator::new_no_special(
+        &exhaustive_quadratic_irrational_pair_gen_var_1,
+        &random_quadratic_irrational_pair_gen_var_1,
+    )
+}
+
+// All pairs of `QuadraticIrrational`s with the same radicand, or where at least one
+// `QuadraticIrrational` is rational, and where the second `QuadraticIrrational` is nonzero.
+pub fn quadratic_irrational_pair_gen_var_2() -> Generator<(QuadraticIrrational, QuadraticIrrational)>
+{
+    Generator::new_no_special(
+        &exhaustive_quadratic_irrational_pair_gen_var_2,
+        &random_quadratic_irrational_pair_gen_var_2,
+    )
+}
+
+// All triples of `QuadraticIrrational`s where each irrational `QuadraticIrrational` has the same
+// radicand.
+pub fn quadratic_irrational_triple_gen_var_1() -> Generator<(
+    QuadraticIrrational,
+    QuadraticIrrational,
+    QuadraticIrrational,
+)> {
+    Generator::new_no_special(
+        &exhaustive_quadratic_irrational_triple_gen_var_1,
+        &random_quadratic_irrational_triple_gen_var_1,
+    )
+}
+
+pub fn quadratic_irrational_rational_pair_gen() -> Generator<(QuadraticIrrational, Rational)> {
+    Generator::new_no_special(
+        &exhaustive_quadratic_irrational_rational_pair_gen,
+        &random_quadratic_irrational_rational_pair_gen,
+    )
+}
+
+// -- Natural --
+
+// All `Natural`s less than $2^{16}$.
+pub fn natural_gen_var_1() -> Generator<Natural> {
+    Generator::new_no_special(&exhaustive_natural_gen_var_1, &random_natural_gen_var_1)
+}
+
+// -- ModNatural --
+
+// All pairs of `ModNatural`s with the same modulus.
+pub fn mod_natural_pair_gen() -> Generator<(ModNatural, ModNatural)> {
+    Generator::new_no_special(
+        &exhaustive_mod_natural_pair_gen,
+        &random_mod_natural_pair_gen,
+    )
+}
+
+// All `ModNatural`s with a prime modulus.
+pub fn mod_natural_gen_var_1() -> Generator<ModNatural> {
+    Generator::new_no_special(
+        &exhaustive_mod_natural_gen_var_1,
+        &random_mod_natural_gen_var_1,
+    )
+}
+
+pub mod exhaustive;
+pub mod random;
@@ -0,0 +1,587 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::mod_natural::ModNatural;
+use crate::quadratic_irrational::QuadraticIrrational;
+use crate::real_algebraic::RealAlgebraic;
+use crate::test_util::extra_variadic::{random_triples, random_triples_from_single};
+use crate::Polynomial;
+use core::cmp::Ordering::Equal;
+use malachite_base::num::arithmetic::traits::{Sign, Square};
+use malachite_base::num::factorization::traits::IsPrime;
+use malachite_base::num::random::geometric::geometric_random_positive_unsigneds;
+use malachite_base::random::{Seed, EXAMPLE_SEED};
+use malachite_base::rounding_modes::random::random_rounding_modes;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_base::test_util::generators::common::{GenConfig, It};
+use malachite_base::tuples::random::{random_pairs, random_pairs_from_single};
+use malachite_base::vecs::random::random_vecs;
+use malachite_nz::integer::random::random_integers;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::random::{random_naturals, random_positive_naturals};
+use malachite_nz::natural::Natural;
+use malachite_q::random::random_rationals;
+use malachite_q::Rational;
+
+fn random_integer_polynomials(
+    seed: Seed,
+    config: &GenConfig,
+) -> impl Iterator<Item = Polynomial<Integer>> {
+    random_vecs(
+        seed,
+        &|seed_2| {
+            random_integers(
+                seed_2,
+                config.get_or("mean_bits_n", 64),
+                config.get_or("mean_bits_d", 1),
+            )
+        },
+        config.get_or("mean_length_n", 4),
+        config.get_or("mean_length_d", 1),
+    )
+    .map(Polynomial::<Integer>::from_owned_coefficients_asc)
+}
+
+fn random_rational_polynomials(
+    seed: Seed,
+    config: &GenConfig,
+) -> impl Iterator<Item = Polynomial<Rational>> {
+    random_vecs(
+        seed,
+        &|seed_2| {
+            random_rationals(
+                seed_2,
+                config.get_or("mean_bits_n", 64),
+                config.get_or("mean_bits_d", 1),
+            )
+        },
+        config.get_or("mean_length_n", 4),
+        config.get_or("mean_length_d", 1),
+    )
+    .map(Polynomial::<Rational>::from_owned_coefficients_asc)
+}
+
+// Arithmetic on `RealAlgebraic`s quickly becomes expensive as the degrees of their minimal
+// polynomials grow, so the `RealAlgebraic`s generated here are roots of polynomials with degree at
+// most 3 and small coefficients.
+fn random_real_algebraics(seed: Seed, config: &GenConfig) -> impl Iterator<Item = RealAlgebraic> {
+    random_vecs(
+        seed,
+        &|seed_2| {
+            random_integers(
+                seed_2,
+                config.get_or("mean_algebraic_bits_n", 4),
+                config.get_or("mean_algebraic_bits_d", 1),
+            )
+        },
+        config.get_or("mean_algebraic_length_n", 2),
+        config.get_or("mean_algebraic_length_d", 1),
+    )
+    .filter(|xs| xs.len() <= 4)
+    .map(Polynomial::<Integer>::from_owned_coefficients_asc)
+    .filter(|p| p != &Polynomial::zero())
+    .flat_map(|p| p.real_roots())
+}
+
+// Computing the continued fraction of a `QuadraticIrrational` takes time proportional to the
+// square root of its discriminant, so the coefficients and radicands generated here are small.
+fn random_quadratic_irrational_coefficients(
+    seed: Seed,
+    config: &GenConfig,
+) -> impl Iterator<Item = (Integer, Integer, Natural)> {
+    random_triples(
+        seed,
+        &|seed_2| {
+            random_integers(
+                seed_2,
+                config.get_or("mean_quadratic_bits_n", 4),
+                config.get_or("mean_quadratic_bits_d", 1),
+            )
+        },
+        &|seed_2| {
+            random_integers(
+                seed_2,
+                config.get_or("mean_quadratic_bits_n", 4),
+                config.get_or("mean_quadratic_bits_d", 1),
+            )
+        },
+        &|seed_2| {
+            random_positive_naturals(
+                seed_2,
+                config.get_or("mean_quadratic_bits_n", 4),
+                config.get_or("mean_quadratic_bits_d", 1),
+            )
+        },
+    )
+}
+
+fn random_radicands(seed: Seed, config: &GenConfig) -> impl Iterator<Item = Natural> {
+    random_naturals(
+        seed,
+        config.get_or("mean_radicand_bits_n", 4),
+        config.get_or("mean_radicand_bits_d", 1),
+    )
+}
+
+fn random_quadratic_irrationals(
+    seed: Seed,
+    config: &GenConfig,
+) -> impl Iterator<Item = QuadraticIrrational> {
+    random_pairs(
+        seed,
+        &|seed_2| random_quadratic_irrational_coefficients(seed_2, config),
+        &|seed_2| random_radicands(seed_2, config),
+    )
+    .map(|((a, b, c), d)| QuadraticIrrational::new(a, b, c, d))
+}
+
+fn random_natural_vecs(seed: Seed, config: &GenConfig) -> impl Iterator<Item = Vec<Natural>> {
+    random_vecs(
+        seed,
+        &|seed_2| {
+            random_naturals(
+                seed_2,
+                config.get_or("mean_bits_n", 64),
+                config.get_or("mean_bits_d", 1),
+            )
+        },
+        config.get_or("mean_length_n", 4),
+        config.get_or("mean_length_d", 1),
+    )
+}
+
+fn random_moduli(seed: Seed, config: &GenConfig) -> impl Iterator<Item = Natural> {
+    random_positive_naturals(
+        seed,
+        config.get_or("mean_modulus_bits_n", 32),
+        config.get_or("mean_modulus_bits_d", 1),
+    )
+}
+
+fn random_prime_moduli(seed: Seed, config: &GenConfig) -> impl Iterator<Item = Natural> {
+    random_moduli(seed, config).filter(IsPrime::is_prime)
+}
+
+// -- Polynomial<Integer> --
+
+pub fn random_polynomial_integer_gen(config: &GenConfig) -> It<Polynomial<Integer>> {
+    Box::new(random_integer_polynomials(EXAMPLE_SEED, config))
+}
+
+pub fn random_polynomial_integer_gen_var_1(config: &GenConfig) -> It<Polynomial<Integer>> {
+    Box::new(random_integer_polynomials(EXAMPLE_SEED, config).filter(|p| p != &Polynomial::zero()))
+}
+
+pub fn random_polynomial_integer_pair_gen(
+    config: &GenConfig,
+) -> It<(Polynomial<Integer>, Polynomial<Integer>)> {
+    Box::new(random_pairs_from_single(random_integer_polynomials(
+        EXAMPLE_SEED,
+        config,
+    )))
+}
+
+pub fn random_polynomial_integer_pair_gen_var_1(
+    config: &GenConfig,
+) -> It<(Polynomial<Integer>, Polynomial<Integer>)> {
+    Box::new(
+        random_pairs_from_single(random_integer_polynomials(EXAMPLE_SEED, config))
+            .filter(|(_, q)| q != &Polynomial::zero()),
+    )
+}
+
+pub fn random_polynomial_integer_triple_gen(
+    config: &GenConfig,
+) -> It<(
+    Polynomial<Integer>,
+    Polynomial<Integer>,
+    Polynomial<Integer>,
+)> {
+    Box::new(random_triples_from_single(random_integer_polynomials(
+        EXAMPLE_SEED,
+        config,
+    )))
+}
+
+pub fn random_polynomial_integer_integer_pair_gen(
+    config: &GenConfig,
+) -> It<(Polynomial<Integer>, Integer)> {
+    Box::new(random_pairs(
+        EXAMPLE_SEED,
+        &|seed| random_integer_polynomials(seed, config),
+        &|seed| {
+            random_integers(
+                seed,
+                config.get_or("mean_bits_n", 64),
+                config.get_or("mean_bits_d", 1),
+            )
+        },
+    ))
+}
+
+// -- (Polynomial<Integer>, u64, RoundingMode) --
+
+pub fn random_polynomial_integer_unsigned_rounding_mode_triple_gen_var_1(
+    config: &GenConfig,
+) -> It<(Polynomial<Integer>, u64, RoundingMode)> {
+    Box::new(random_triples(
+        EXAMPLE_SEED,
+        &|seed| random_integer_polynomials(seed, config).filter(|p| p != &Polynomial::zero()),
+        &|seed| {
+            geometric_random_positive_unsigneds(
+                seed,
+                config.get_or("mean_precision_n", 64),
+                config.get_or("mean_precision_d", 1),
+            )
+        },
+        &|seed| random_rounding_modes(seed).filter(|&rm| rm != Exact),
+    ))
+}
+
+// -- Polynomial<Rational> --
+
+pub fn random_polynomial_rational_gen(config: &GenConfig) -> It<Polynomial<Rational>> {
+    Box::new(random_rational_polynomials(EXAMPLE_SEED, config))
+}
+
+pub fn random_polynomial_rational_pair_gen(
+    config: &GenConfig,
+) -> It<(Polynomial<Rational>, Polynomial<Rational>)> {
+    Box::new(random_pairs_from_single(random_rational_polynomials(
+        EXAMPLE_SEED,
+        config,
+    )))
+}
+
+pub fn random_polynomial_rational_pair_gen_var_1(
+    config: &GenConfig,
+) -> It<(Polynomial<Rational>, Polynomial<Rational>)> {
+    Box::new(
+        random_pairs_from_single(random_rational_polynomials(EXAMPLE_SEED, config))
+            .filter(|(_, q)| q != &Polynomial::zero()),
+    )
+}
+
+pub fn random_polynomial_rational_triple_gen(
+    config: &GenConfig,
+) -> It<(
+    Polynomial<Rational>,
+    Polynomial<Rational>,
+    Polynomial<Rational>,
+)> {
+    Box::new(random_triples_from_single(random_rational_polynomials(
+        EXAMPLE_SEED,
+        config,
+    )))
+}
+
+pub fn random_polynomial_rational_rational_pair_gen(
+    config: &GenConfig,
+) -> It<(Polynomial<Rational>, Rational)> {
+    Box::new(random_pairs(
+        EXAMPLE_SEED,
+        &|seed| random_rational_polynomials(seed, config),
+        &|seed| {
+            random_rationals(
+                seed,
+                config.get_or("mean_bits_n", 64),
+                config.get_or("mean_bits_d", 1),
+            )
+        },
+    ))
+}
+
+// -- Polynomial<ModNatural> --
+
+pub fn random_polynomial_mod_natural_gen(config: &GenConfig) -> It<Polynomial<ModNatural>> {
+    Box::new(
+        random_pairs(EXAMPLE_SEED, &|seed| random_moduli(seed, config), &|seed| {
+            random_natural_vecs(seed, config)
+        })
+        .map(|(m, xs)| Polynomial::from_naturals_asc(&xs, &m)),
+    )
+}
+
+pub fn random_polynomial_mod_natural_pair_gen(
+    config: &GenConfig,
+) -> It<(Polynomial<ModNatural>, Polynomial<ModNatural>)> {
+    Box::new(
+        random_pairs(EXAMPLE_SEED, &|seed| random_moduli(seed, config), &|seed| {
+            random_pairs_from_single(random_natural_vecs(seed, config))
+        })
+        .map(|(m, (xs, ys))| {
+            (
+                Polynomial::from_naturals_asc(&xs, &m),
+                Polynomial::from_naturals_asc(&ys, &m),
+            )
+        }),
+    )
+}
+
+pub fn random_polynomial_mod_natural_pair_gen_var_1(
+    config: &GenConfig,
+) -> It<(Polynomial<ModNatural>, Polynomial<ModNatural>)> {
+    Box::new(
+        random_pairs(
+            EXAMPLE_SEED,
+            &|seed| random_prime_moduli(seed, config),
+            &|seed| random_pairs_from_single(random_natural_vecs(seed, config)),
+        )
+        .map(|(m, (xs, ys))| {
+            (
+                Polynomial::from_naturals_asc(&xs, &m),
+                Polynomial::from_naturals_asc(&ys, &m),
+            )
+        })
+        .filter(|(_, q)| q != &Polynomial::zero()),
+    )
+}
+
+pub fn random_polynomial_mod_natural_triple_gen(
+    config: &GenConfig,
+) -> It<(
+    Polynomial<ModNatural>,
+    Polynomial<ModNatural>,
+    Polynomial<ModNatural>,
+)> {
+    Box::new(
+        random_pairs(EXAMPLE_SEED, &|seed| random_moduli(seed, config), &|seed| {
+            random_triples_from_single(random_natural_vecs(seed, config))
+        })
+        .map(|(m, (xs, ys, zs))| {
+            (
+                Polynomial::from_naturals_asc(&xs, &m),
+                Polynomial::from_naturals_asc(&ys, &m),
+                Polynomial::from_naturals_asc(&zs, &m),
+            )
+        }),
+    )
+}
+
+pub fn random_polynomial_mod_natural_mod_natural_pair_gen(
+    config: &GenConfig,
+) -> It<(Polynomial<ModNatural>, ModNatural)> {
+    Box::new(
+        random_pairs(EXAMPLE_SEED, &|seed| random_moduli(seed, config), &|seed| {
+            random_pairs(
+                seed,
+                &|seed_2| random_natural_vecs(seed_2, config),
+                &|seed_2| {
+                    random_naturals(
+                        seed_2,
+                        config.get_or("mean_bits_n", 64),
+                        config.get_or("mean_bits_d", 1),
+                    )
+                },
+            )
+        })
+        .map(|(m, (xs, x))| {
+            (
+                Polynomial::from_naturals_asc(&xs, &m),
+                ModNatural::new(x, m),
+            )
+        }),
+    )
+}
+
+// -- RealAlgebraic --
+
+pub fn random_real_algebraic_gen(config: &GenConfig) -> It<RealAlgebraic> {
+    Box::new(random_real_algebraics(EXAMPLE_SEED, config))
+}
+
+pub fn random_real_algebraic_gen_var_1(config: &GenConfig) -> It<RealAlgebraic> {
+    Box::new(random_real_algebraics(EXAMPLE_SEED, config).filter(|x| x.sign() != Equal))
+}
+
+pub fn random_real_algebraic_pair_gen(config: &GenConfig) -> It<(RealAlgebraic, RealAlgebraic)> {
+    Box::new(random_pairs_from_single(random_real_algebraics(
+        EXAMPLE_SEED,
+        config,
+    )))
+}
+
+pub fn random_real_algebraic_pair_gen_var_1(
+    config: &GenConfig,
+) -> It<(RealAlgebraic, RealAlgebraic)> {
+    Box::new(
+        random_pairs_from_single(random_real_algebraics(EXAMPLE_SEED, config))
+            .filter(|(_, y)| y.sign() != Equal),
+    )
+}
+
+pub fn random_real_algebraic_triple_gen(
+    config: &GenConfig,
+) -> It<(RealAlgebraic, RealAlgebraic, RealAlgebraic)> {
+    Box::new(random_triples_from_single(random_real_algebraics(
+        EXAMPLE_SEED,
+        config,
+    )))
+}
+
+pub fn random_real_algebraic_rational_pair_gen(
+    config: &GenConfig,
+) -> It<(RealAlgebraic, Rational)> {
+    Box::new(random_pairs(
+        EXAMPLE_SEED,
+        &|seed| random_real_algebraics(seed, config),
+        &|seed| {
+            random_rationals(
+                seed,
+                config.get_or("mean_bits_n", 64),
+                config.get_or("mean_bits_d", 1),
+            )
+        },
+    ))
+}
+
+pub fn random_real_algebraic_unsigned_rounding_mode_triple_gen_var_1(
+    config: &GenConfig,
+) -> It<(RealAlgebraic, u64, RoundingMode)> {
+    Box::new(random_triples(
+        EXAMPLE_SEED,
+        &|seed| random_real_algebraics(seed, config),
+        &|seed| {
+            geometric_random_positive_unsigneds(
+                seed,
+                config.get_or("mean_precision_n", 64),
+                config.get_or("mean_precision_d", 1),
+            )
+        },
+        &|seed| random_rounding_modes(seed).filter(|&rm| rm != Exact),
+    ))
+}
+
+// -- QuadraticIrrational --
+
+pub fn random_quadratic_irrational_gen(config: &GenConfig) -> It<QuadraticIrrational> {
+    Box::new(random_quadratic_irrationals(EXAMPLE_SEED, config))
+}
+
+pub fn random_quadratic_irrational_gen_var_1(config: &GenConfig) -> It<QuadraticIrrational> {
+    Box::new(random_quadratic_irrationals(EXAMPLE_SEED, config).filter(|x| x.sign() != Equal))
+}
+
+pub fn random_quadratic_irrational_gen_var_2(config: &GenConfig) -> It<QuadraticIrrational> {
+    Box::new(
+        random_quadratic_irrationals(EXAMPLE_SEED, config)
+            .filter(|x| (x.b().unsigned_abs_ref() * x.c()).square() * x.d() < 1u32 << 12),
+    )
+}
+
+pub fn random_quadratic_irrational_pair_gen(
+    config: &GenConfig,
+) -> It<(QuadraticIrrational, QuadraticIrrational)> {
+    Box::new(random_pairs_from_single(random_quadratic_irrationals(
+        EXAMPLE_SEED,
+        config,
+    )))
+}
+
+pub fn random_quadratic_irrational_pair_gen_var_1(
+    config: &GenConfig,
+) -> It<(QuadraticIrrational, QuadraticIrrational)> {
+    Box::new(
+        random_pairs(
+            EXAMPLE_SEED,
+            &|seed| random_radicands(seed, config),
+            &|seed| {
+                random_pairs_from_single(random_quadratic_irrational_coefficients(seed, config))
+            },
+        )
+        .map(|(d, ((a_1, b_1, c_1), (a_2, b_2, c_2)))| {
+            (
+                QuadraticIrrational::new(a_1, b_1, c_1, d.clone()),
+                QuadraticIrrational::new(a_2, b_2, c_2, d),
+            )
+        }),
+    )
+}
+
+pub fn random_quadratic_irrational_pair_gen_var_2(
+    config: &GenConfig,
+) -> It<(QuadraticIrrational, QuadraticIrrational)> {
+    Box::new(random_quadratic_irrational_pair_gen_var_1(config).filter(|(_, y)| y.sign() != Equal))
+}
+
+pub fn random_quadratic_irrational_triple_gen_var_1(
+    config: &GenConfig,
+) -> It<(
+    QuadraticIrrational,
+    QuadraticIrrational,
+    QuadraticIrrational,
+)> {
+    Box::new(
+        random_pairs(
+            EXAMPLE_SEED,
+            &|seed| random_radicands(seed, config),
+            &|seed| {
+                random_triples_from_single(random_quadratic_irrational_coefficients(seed, config))
+            },
+        )
+        .map(|(d, ((a_1, b_1, c_1), (a_2, b_2, c_2), (a_3, b_3, c_3)))| {
+            (
+                QuadraticIrrational::new(a_1, b_1, c_1, d.clone()),
+                QuadraticIrrational::new(a_2, b_2, c_2, d.clone()),
+                QuadraticIrrational::new(a_3, b_3, c_3, d),
+            )
+        }),
+    )
+}
+
+pub fn random_quadratic_irrational_rational_pair_gen(
+    config: &GenConfig,
+) -> It<(QuadraticIrrational, Rational)> {
+    Box::new(random_pairs(
+        EXAMPLE_SEED,
+        &|seed| random_quadratic_irrationals(seed, config),
+        &|seed| {
+            random_rationals(
+                seed,
+                config.get_or("mean_bits_n", 64),
+                config.get_or("mean_bits_d", 1),
+            )
+        },
+    ))
+}
+
+pub fn random_natural_gen_var_1(config: &GenConfig) -> It<Natural> {
+    Box::new(random_radicands(EXAMPLE_SEED, config).filter(|d| *d < 1u32 << 16))
+}
+
+// -- ModNatural --
+
+pub fn random_mod_natural_pair_gen(config: &GenConfig) -> It<(ModNatural, ModNatural)> {
+    Box::new(
+        random_pairs(EXAMPLE_SEED, &|seed| random_moduli(seed, config), &|seed| {
+            random_pairs_from_single(random_naturals(
+                seed,
+                config.get_or("mean_bits_n", 64),
+                config.get_or("mean_bits_d", 1),
+            ))
+        })
+        .map(|(m, (x, y))| (ModNatural::new(x, m.clone()), ModNatural::new(y, m))),
+    )
+}
+
+pub fn random_mod_natural_gen_var_1(config: &GenConfig) -> It<ModNatural> {
+    Box::new(
+        random_pairs(
+            EXAMPLE_SEED,
+            &|seed| random_prime_moduli(seed, config),
+            &|seed| {
+                random_naturals(
+                    seed,
+                    config.get_or("mean_bits_n", 64),
+                    config.get_or("mean_bits_d", 1),
+                )
+            },
+        )
+        .map(|(m, x)| ModNatural::new(x, m)),
+    )
+}
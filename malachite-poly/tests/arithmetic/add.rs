@@ -0,0 +1,222 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::vecs::vec_from_str;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_poly::test_util::generators::{
+    polynomial_integer_gen, polynomial_integer_pair_gen, polynomial_integer_triple_gen,
+    polynomial_mod_natural_gen, polynomial_mod_natural_pair_gen, polynomial_mod_natural_triple_gen,
+    polynomial_rational_gen, polynomial_rational_pair_gen, polynomial_rational_triple_gen,
+};
+use malachite_poly::Polynomial;
+use malachite_q::Rational;
+
+#[test]
+fn test_add() {
+    let test = |s, t, out| {
+        let u = Polynomial::<Integer>::from_owned_coefficients_asc(vec_from_str(s).unwrap());
+        let v = Polynomial::<Integer>::from_owned_coefficients_asc(vec_from_str(t).unwrap());
+
+        let mut n = u.clone();
+        n += v.clone();
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let mut n = u.clone();
+        n += &v;
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = u.clone() + v.clone();
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = &u + v.clone();
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = u.clone() + &v;
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = &u + &v;
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+    };
+    test("[]", "[]", "0");
+    test("[]", "[1, -2, 3]", "3*x^2 - 2*x + 1");
+    test("[1, -2, 3]", "[]", "3*x^2 - 2*x + 1");
+    test("[1, -2, 3]", "[5, 1, -3]", "-x + 6");
+    test("[1, 2, 3]", "[-1, -2, -3]", "0");
+    test("[-5, 0, 0, 7]", "[5, 1]", "7*x^3 + x");
+    test(
+        "[123456789012345678901234567890]",
+        "[1, 1]",
+        "x + 123456789012345678901234567891",
+    );
+
+    let test = |s, t, out| {
+        let u = Polynomial::<Rational>::from_owned_coefficients_asc(vec_from_str(s).unwrap());
+        let v = Polynomial::<Rational>::from_owned_coefficients_asc(vec_from_str(t).unwrap());
+
+        let mut n = u.clone();
+        n += v.clone();
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let mut n = u.clone();
+        n += &v;
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = u.clone() + v.clone();
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = &u + v.clone();
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = u.clone() + &v;
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = &u + &v;
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+    };
+    test("[1/2]", "[1/3]", "5/6");
+    test("[1/3, 1/2]", "[2/3, 1/2]", "x + 1");
+    test("[0, -1/2, 5/3]", "[1/4]", "5/3*x^2 - 1/2*x + 1/4");
+    test("[1/2, 1/3]", "[-1/2, -1/3]", "0");
+
+    let test = |s, t, m: u32, out| {
+        let m = Natural::from(m);
+        let u = Polynomial::from_naturals_asc(&vec_from_str::<Natural>(s).unwrap(), &m);
+        let v = Polynomial::from_naturals_asc(&vec_from_str::<Natural>(t).unwrap(), &m);
+
+        let mut n = u.clone();
+        n += v.clone();
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let mut n = u.clone();
+        n += &v;
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = u.clone() + v.clone();
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = &u + v.clone();
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = u.clone() + &v;
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = &u + &v;
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+    };
+    test("[]", "[3, 6]", 7, "6*x + 3 mod 7");
+    test("[3, 6]", "[4, 1]", 7, "0");
+    test("[1, 2, 3]", "[6]", 7, "3*x^2 + 2*x mod 7");
+    test("[5, 4]", "[3, 2]", 6, "2 mod 6");
+    test("[1]", "[0]", 1, "0");
+}
+
+#[test]
+#[should_panic]
+fn add_fail() {
+    let _ = Polynomial::from_naturals_asc(&[Natural::from(1u32)], &Natural::from(5u32))
+        + Polynomial::from_naturals_asc(&[Natural::from(1u32)], &Natural::from(7u32));
+}
+
+#[test]
+fn add_properties() {
+    polynomial_integer_pair_gen().test_properties(|(x, y)| {
+        let sum_val_val = x.clone() + y.clone();
+        let sum_val_ref = x.clone() + &y;
+        let sum_ref_val = &x + y.clone();
+        let sum = &x + &y;
+        assert!(sum_val_val.is_valid());
+        assert!(sum_val_ref.is_valid());
+        assert!(sum_ref_val.is_valid());
+        assert!(sum.is_valid());
+        assert_eq!(sum_val_val, sum);
+        assert_eq!(sum_val_ref, sum);
+        assert_eq!(sum_ref_val, sum);
+
+        let mut mut_x = x.clone();
+        mut_x += y.clone();
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x, sum);
+        let mut mut_x = x.clone();
+        mut_x += &y;
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x, sum);
+
+        assert_eq!(&y + &x, sum);
+        assert_eq!(&sum - &x, y);
+        assert_eq!(sum - y, x);
+    });
+
+    polynomial_integer_gen().test_properties(|x| {
+        assert_eq!(&x + Polynomial::zero(), x);
+        assert_eq!(Polynomial::zero() + &x, x);
+        assert_eq!(&x + &x, &x * Polynomial::from(Integer::from(2)));
+    });
+
+    polynomial_integer_triple_gen().test_properties(|(x, y, z)| {
+        assert_eq!((&x + &y) + &z, x + (y + z));
+    });
+
+    polynomial_rational_pair_gen().test_properties(|(x, y)| {
+        let sum = &x + &y;
+        assert!(sum.is_valid());
+        assert_eq!(x.clone() + y.clone(), sum);
+        let mut mut_x = x.clone();
+        mut_x += &y;
+        assert_eq!(mut_x, sum);
+        assert_eq!(&y + &x, sum);
+        assert_eq!(sum - y, x);
+    });
+
+    polynomial_rational_gen().test_properties(|x| {
+        assert_eq!(&x + Polynomial::zero(), x);
+        assert_eq!(Polynomial::zero() + &x, x);
+    });
+
+    polynomial_rational_triple_gen().test_properties(|(x, y, z)| {
+        assert_eq!((&x + &y) + &z, x + (y + z));
+    });
+
+    polynomial_mod_natural_pair_gen().test_properties(|(x, y)| {
+        let sum = &x + &y;
+        assert!(sum.is_valid());
+        assert_eq!(x.clone() + y.clone(), sum);
+        let mut mut_x = x.clone();
+        mut_x += &y;
+        assert_eq!(mut_x, sum);
+        assert_eq!(&y + &x, sum);
+        assert_eq!(sum - y, x);
+    });
+
+    polynomial_mod_natural_gen().test_properties(|x| {
+        assert_eq!(&x + Polynomial::zero(), x);
+        assert_eq!(Polynomial::zero() + &x, x);
+    });
+
+    polynomial_mod_natural_triple_gen().test_properties(|(x, y, z)| {
+        assert_eq!((&x + &y) + &z, x + (y + z));
+    });
+}
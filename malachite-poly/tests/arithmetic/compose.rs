@@ -0,0 +1,109 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::test_util::generators::common::GenConfig;
+use malachite_base::vecs::vec_from_str;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_poly::test_util::generators::{
+    polynomial_integer_gen, polynomial_integer_integer_pair_gen, polynomial_integer_pair_gen,
+    polynomial_integer_triple_gen, polynomial_mod_natural_triple_gen,
+    polynomial_rational_triple_gen,
+};
+use malachite_poly::Polynomial;
+use malachite_q::Rational;
+
+#[test]
+fn test_compose() {
+    let test = |s, t, out| {
+        let u = Polynomial::<Integer>::from_owned_coefficients_asc(vec_from_str(s).unwrap());
+        let v = Polynomial::<Integer>::from_owned_coefficients_asc(vec_from_str(t).unwrap());
+        let n = u.compose(&v);
+        assert!(n.is_valid());
+        assert_eq!(n.to_string(), out);
+    };
+    test("[]", "[1, 2]", "0");
+    test("[1, 2]", "[]", "1");
+    test("[1, 0, 1]", "[-1, 1]", "x^2 - 2*x + 2");
+    test("[-1, 1]", "[1, 0, 1]", "x^2");
+    test("[1, 0, 1]", "[]", "1");
+    test("[5]", "[1, 2, 3]", "5");
+    test("[0, 1]", "[4, -3, 2]", "2*x^2 - 3*x + 4");
+    test("[1, 2, 3]", "[0, 0, 1]", "3*x^4 + 2*x^2 + 1");
+    test("[1, -1, 1]", "[2, 3, 1]", "x^4 + 6*x^3 + 12*x^2 + 9*x + 3");
+
+    let test = |s, t, out| {
+        let u = Polynomial::<Rational>::from_owned_coefficients_asc(vec_from_str(s).unwrap());
+        let v = Polynomial::<Rational>::from_owned_coefficients_asc(vec_from_str(t).unwrap());
+        let n = u.compose(&v);
+        assert!(n.is_valid());
+        assert_eq!(n.to_string(), out);
+    };
+    test("[1/2, 0, 1]", "[1/3, 2]", "4*x^2 + 4/3*x + 11/18");
+    test("[0, 1/2]", "[1/2, 1/2]", "1/4*x + 1/4");
+
+    let test = |s, t, m: u32, out| {
+        let m = Natural::from(m);
+        let u = Polynomial::from_naturals_asc(&vec_from_str::<Natural>(s).unwrap(), &m);
+        let v = Polynomial::from_naturals_asc(&vec_from_str::<Natural>(t).unwrap(), &m);
+        let n = u.compose(&v);
+        assert!(n.is_valid());
+        assert_eq!(n.to_string(), out);
+    };
+    test("[1, 0, 1]", "[3, 2]", 7, "4*x^2 + 5*x + 3 mod 7");
+    test("[1, 0, 3]", "[0, 2]", 12, "1 mod 12");
+    test("[1, 1]", "[]", 5, "1 mod 5");
+}
+
+#[test]
+fn compose_properties() {
+    let mut config = GenConfig::new();
+    config.insert("mean_bits_n", 16);
+    config.insert("mean_length_n", 2);
+    polynomial_integer_pair_gen().test_properties_with_config(&config, |(p, q)| {
+        let composition = p.compose(&q);
+        assert!(composition.is_valid());
+        if let (Some(m), Some(n)) = (p.degree(), q.degree()) {
+            if n != 0 {
+                assert_eq!(composition.degree(), Some(m * n));
+            }
+        }
+        let x = Integer::from(-2);
+        assert_eq!(composition.evaluate(&x), p.evaluate(&q.evaluate(&x)));
+    });
+
+    polynomial_integer_gen().test_properties_with_config(&config, |p| {
+        let identity =
+            Polynomial::<Integer>::from_coefficients_asc(&[Integer::from(0), Integer::from(1)]);
+        assert_eq!(p.compose(&identity), p);
+        assert_eq!(identity.compose(&p), p);
+    });
+
+    polynomial_integer_integer_pair_gen().test_properties_with_config(&config, |(p, x)| {
+        assert_eq!(
+            p.compose(&Polynomial::from(x.clone())),
+            Polynomial::from(p.evaluate(&x))
+        );
+    });
+
+    polynomial_integer_triple_gen().test_properties_with_config(&config, |(p, q, r)| {
+        assert_eq!(p.compose(&q).compose(&r), p.compose(&q.compose(&r)));
+        assert_eq!((&p + &q).compose(&r), p.compose(&r) + q.compose(&r));
+        assert_eq!((&p * &q).compose(&r), p.compose(&r) * q.compose(&r));
+    });
+
+    polynomial_rational_triple_gen().test_properties_with_config(&config, |(p, q, r)| {
+        assert_eq!(p.compose(&q).compose(&r), p.compose(&q.compose(&r)));
+        assert_eq!((&p * &q).compose(&r), p.compose(&r) * q.compose(&r));
+    });
+
+    polynomial_mod_natural_triple_gen().test_properties_with_config(&config, |(p, q, r)| {
+        assert_eq!(p.compose(&q).compose(&r), p.compose(&q.compose(&r)));
+        assert_eq!((&p * &q).compose(&r), p.compose(&r) * q.compose(&r));
+    });
+}
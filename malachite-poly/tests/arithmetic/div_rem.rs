@@ -0,0 +1,240 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::DivRem;
+use malachite_base::strings::ToDebugString;
+use malachite_base::vecs::vec_from_str;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_poly::test_util::generators::{
+    polynomial_integer_gen, polynomial_integer_pair_gen_var_1,
+    polynomial_mod_natural_pair_gen_var_1, polynomial_rational_gen,
+    polynomial_rational_pair_gen_var_1,
+};
+use malachite_poly::Polynomial;
+use malachite_q::Rational;
+
+#[test]
+fn test_div_rem() {
+    let test = |s, t, q_out, r_out| {
+        let u = Polynomial::<Integer>::from_owned_coefficients_asc(vec_from_str(s).unwrap());
+        let v = Polynomial::<Integer>::from_owned_coefficients_asc(vec_from_str(t).unwrap());
+        let out = format!("({q_out}, {r_out})");
+
+        let (q, r) = u.clone().div_rem(v.clone());
+        assert!(q.is_valid());
+        assert!(r.is_valid());
+        assert_eq!((&q, &r).to_debug_string(), out);
+
+        let (q, r) = u.clone().div_rem(&v);
+        assert!(q.is_valid());
+        assert!(r.is_valid());
+        assert_eq!((&q, &r).to_debug_string(), out);
+
+        let (q, r) = (&u).div_rem(v.clone());
+        assert!(q.is_valid());
+        assert!(r.is_valid());
+        assert_eq!((&q, &r).to_debug_string(), out);
+
+        let (q, r) = (&u).div_rem(&v);
+        assert!(q.is_valid());
+        assert!(r.is_valid());
+        assert_eq!((&q, &r).to_debug_string(), out);
+
+        assert_eq!((&u / &v).to_string(), q_out);
+        assert_eq!((&u % &v).to_string(), r_out);
+
+        let mut x = u.clone();
+        x /= &v;
+        assert!(x.is_valid());
+        assert_eq!(x.to_string(), q_out);
+
+        let mut x = u;
+        x %= v;
+        assert!(x.is_valid());
+        assert_eq!(x.to_string(), r_out);
+    };
+    test("[]", "[1, 2]", "0", "0");
+    test("[-4, 11, -17, 3]", "[-5, 1]", "3*x^2 - 2*x + 1", "1");
+    // The leading coefficient of the divisor is not a unit
+    test("[1, 0, 3]", "[0, 2]", "x", "x^2 + 1");
+    test("[1, 2, 3]", "[1, 2, 3]", "1", "0");
+    test("[5, 1]", "[1, 0, 2]", "0", "x + 5");
+    test(
+        "[7, -3, 0, 5]",
+        "[2, -3]",
+        "-2*x^2 - 2*x - 1",
+        "-x^3 - 2*x^2 - 2*x + 9",
+    );
+    test(
+        "[-7, 3, 0, -5]",
+        "[2, 3]",
+        "-2*x^2 + x",
+        "x^3 + x^2 + x - 7",
+    );
+    test("[10, 20, 30]", "[5]", "6*x^2 + 4*x + 2", "0");
+
+    let test = |s, t, q_out, r_out| {
+        let u = Polynomial::<Rational>::from_owned_coefficients_asc(vec_from_str(s).unwrap());
+        let v = Polynomial::<Rational>::from_owned_coefficients_asc(vec_from_str(t).unwrap());
+        let out = format!("({q_out}, {r_out})");
+
+        let (q, r) = u.clone().div_rem(v.clone());
+        assert!(q.is_valid());
+        assert!(r.is_valid());
+        assert_eq!((&q, &r).to_debug_string(), out);
+
+        let (q, r) = (&u).div_rem(&v);
+        assert!(q.is_valid());
+        assert!(r.is_valid());
+        assert_eq!((&q, &r).to_debug_string(), out);
+
+        assert_eq!((u.clone() / v.clone()).to_string(), q_out);
+        assert_eq!((u % v).to_string(), r_out);
+    };
+    test("[1, 0, 3]", "[0, 2]", "3/2*x", "1");
+    test("[1/2, 2/3, 1]", "[1/3, 1/2]", "2*x", "1/2");
+    test("[]", "[3/4]", "0", "0");
+    test("[1, 2, 3]", "[5/2]", "6/5*x^2 + 4/5*x + 2/5", "0");
+
+    let test = |s, t, m: u32, q_out, r_out| {
+        let m = Natural::from(m);
+        let u = Polynomial::from_naturals_asc(&vec_from_str::<Natural>(s).unwrap(), &m);
+        let v = Polynomial::from_naturals_asc(&vec_from_str::<Natural>(t).unwrap(), &m);
+        let out = format!("({q_out}, {r_out})");
+
+        let (q, r) = u.clone().div_rem(v.clone());
+        assert!(q.is_valid());
+        assert!(r.is_valid());
+        assert_eq!((&q, &r).to_debug_string(), out);
+
+        let (q, r) = (&u).div_rem(&v);
+        assert!(q.is_valid());
+        assert!(r.is_valid());
+        assert_eq!((&q, &r).to_debug_string(), out);
+
+        assert_eq!((u.clone() / v.clone()).to_string(), q_out);
+        assert_eq!((u % v).to_string(), r_out);
+    };
+    test("[1, 0, 1]", "[1, 2]", 7, "4*x + 5 mod 7", "3 mod 7");
+    test("[]", "[3]", 7, "0", "0");
+    test(
+        "[3, 5, 1, 4]",
+        "[1, 1]",
+        7,
+        "4*x^2 + 4*x + 1 mod 7",
+        "2 mod 7",
+    );
+    // The modulus is composite, but the leading coefficient of the divisor is invertible
+    test(
+        "[1, 2, 3, 4]",
+        "[5, 1]",
+        6,
+        "4*x^2 + x + 3 mod 6",
+        "4 mod 6",
+    );
+    test(
+        "[2, 4, 6, 8, 10]",
+        "[1, 0, 1]",
+        11,
+        "10*x^2 + 8*x + 7 mod 11",
+        "7*x + 6 mod 11",
+    );
+}
+
+#[test]
+#[should_panic]
+fn div_rem_fail_1() {
+    Polynomial::<Integer>::from_coefficients_asc(&[Integer::from(1)]).div_rem(Polynomial::zero());
+}
+
+#[test]
+#[should_panic]
+fn div_rem_fail_2() {
+    Polynomial::<Rational>::from_coefficients_asc(&[Rational::from(1)]).div_rem(Polynomial::zero());
+}
+
+#[test]
+#[should_panic]
+fn div_rem_fail_3() {
+    let m = Natural::from(6u32);
+    Polynomial::from_naturals_asc(&[Natural::from(1u32), Natural::from(1u32)], &m).div_rem(
+        Polynomial::from_naturals_asc(&[Natural::from(1u32), Natural::from(2u32)], &m),
+    );
+}
+
+#[test]
+fn div_rem_properties() {
+    polynomial_integer_pair_gen_var_1().test_properties(|(x, y)| {
+        let (q, r) = x.clone().div_rem(y.clone());
+        assert!(q.is_valid());
+        assert!(r.is_valid());
+        assert_eq!(x.clone().div_rem(&y), (q.clone(), r.clone()));
+        assert_eq!((&x).div_rem(y.clone()), (q.clone(), r.clone()));
+        assert_eq!((&x).div_rem(&y), (q.clone(), r.clone()));
+        assert_eq!(&x / &y, q);
+        assert_eq!(&x % &y, r);
+
+        let mut mut_x = x.clone();
+        mut_x /= &y;
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x, q);
+        let mut mut_x = x.clone();
+        mut_x %= &y;
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x, r);
+
+        assert_eq!(&q * &y + &r, x);
+        let leading = y.leading_coefficient().unwrap();
+        if *leading == 1 || *leading == -1 {
+            assert!(r.degree() < y.degree());
+        }
+        assert_eq!((&x * &y).div_rem(&y), (x.clone(), Polynomial::zero()));
+    });
+
+    polynomial_integer_gen().test_properties(|x| {
+        let one = Polynomial::from(Integer::from(1));
+        assert_eq!((&x).div_rem(&one), (x.clone(), Polynomial::zero()));
+        if x != Polynomial::zero() {
+            assert_eq!((&x).div_rem(&x), (one, Polynomial::zero()));
+        }
+    });
+
+    polynomial_rational_pair_gen_var_1().test_properties(|(x, y)| {
+        let (q, r) = x.clone().div_rem(y.clone());
+        assert!(q.is_valid());
+        assert!(r.is_valid());
+        assert_eq!((&x).div_rem(&y), (q.clone(), r.clone()));
+        assert_eq!(&x / &y, q);
+        assert_eq!(&x % &y, r);
+        assert_eq!(&q * &y + &r, x);
+        assert!(r.degree() < y.degree());
+        assert_eq!((&x * &y).div_rem(&y), (x, Polynomial::zero()));
+    });
+
+    polynomial_rational_gen().test_properties(|x| {
+        if x != Polynomial::zero() {
+            assert_eq!(
+                (&x).div_rem(&x),
+                (Polynomial::from(Rational::from(1)), Polynomial::zero())
+            );
+        }
+    });
+
+    polynomial_mod_natural_pair_gen_var_1().test_properties(|(x, y)| {
+        let (q, r) = x.clone().div_rem(y.clone());
+        assert!(q.is_valid());
+        assert!(r.is_valid());
+        assert_eq!((&x).div_rem(&y), (q.clone(), r.clone()));
+        assert_eq!(&x / &y, q);
+        assert_eq!(&x % &y, r);
+        assert_eq!(&q * &y + &r, x);
+        assert!(r.degree() < y.degree());
+        assert_eq!((&x * &y).div_rem(&y), (x, Polynomial::zero()));
+    });
+}
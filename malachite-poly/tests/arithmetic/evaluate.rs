@@ -0,0 +1,94 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::vecs::vec_from_str;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_poly::mod_natural::ModNatural;
+use malachite_poly::test_util::generators::{
+    polynomial_integer_integer_pair_gen, polynomial_integer_pair_gen,
+    polynomial_mod_natural_mod_natural_pair_gen, polynomial_rational_rational_pair_gen,
+};
+use malachite_poly::Polynomial;
+use malachite_q::Rational;
+use std::str::FromStr;
+
+#[test]
+fn test_evaluate() {
+    let test = |s, x, out| {
+        let p = Polynomial::<Integer>::from_owned_coefficients_asc(vec_from_str(s).unwrap());
+        assert_eq!(p.evaluate(&Integer::from_str(x).unwrap()).to_string(), out);
+    };
+    test("[]", "5", "0");
+    test("[1, -2, 3]", "0", "1");
+    test("[1, -2, 3]", "2", "9");
+    test("[1, -2, 3]", "-3", "34");
+    test("[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1]", "10", "10000000000");
+    test("[7]", "123", "7");
+
+    let test = |s, x, out| {
+        let p = Polynomial::<Rational>::from_owned_coefficients_asc(vec_from_str(s).unwrap());
+        let y = p.evaluate(&Rational::from_str(x).unwrap());
+        assert!(y.is_valid());
+        assert_eq!(y.to_string(), out);
+    };
+    test("[1/2, 1/3]", "3/4", "3/4");
+    test("[1, 0, 1]", "1/2", "5/4");
+    test("[-1/4, 0, 1]", "1/2", "0");
+
+    let test = |s, x: u32, m: u32, out| {
+        let m = Natural::from(m);
+        let p = Polynomial::from_naturals_asc(&vec_from_str::<Natural>(s).unwrap(), &m);
+        let y = p.evaluate(&ModNatural::new(Natural::from(x), m));
+        assert!(y.is_valid());
+        assert_eq!(y.to_string(), out);
+    };
+    test("[1, 0, 1]", 3, 7, "3 mod 7");
+    test("[]", 3, 7, "0 mod 7");
+    test("[1, 2, 3]", 5, 6, "2 mod 6");
+}
+
+#[test]
+fn evaluate_properties() {
+    polynomial_integer_integer_pair_gen().test_properties(|(p, x)| {
+        let y = p.evaluate(&x);
+        assert!(y.is_valid());
+        assert_eq!((-&p).evaluate(&x), -&y);
+        assert_eq!(
+            p.compose(&Polynomial::from(x.clone())),
+            Polynomial::from(y.clone())
+        );
+        let q = Polynomial::<Integer>::from_coefficients_asc(&[-&x, Integer::from(1)]);
+        assert_eq!(&p % &q, Polynomial::from(y));
+    });
+
+    polynomial_integer_pair_gen().test_properties(|(p, q)| {
+        let x = Integer::from(3);
+        assert_eq!((&p + &q).evaluate(&x), p.evaluate(&x) + q.evaluate(&x));
+        assert_eq!((&p * &q).evaluate(&x), p.evaluate(&x) * q.evaluate(&x));
+    });
+
+    polynomial_rational_rational_pair_gen().test_properties(|(p, x)| {
+        let y = p.evaluate(&x);
+        assert!(y.is_valid());
+        assert_eq!((-&p).evaluate(&x), -&y);
+        let q = Polynomial::<Rational>::from_coefficients_asc(&[-&x, Rational::from(1)]);
+        assert_eq!(&p % &q, Polynomial::from(y));
+    });
+
+    polynomial_mod_natural_mod_natural_pair_gen().test_properties(|(p, x)| {
+        let y = p.evaluate(&x);
+        assert!(y.is_valid());
+        assert_eq!((-&p).evaluate(&x), -&y);
+        if *x.modulus() != 1u32 {
+            let one = ModNatural::new(Natural::from(1u32), x.modulus().clone());
+            let q = Polynomial::<ModNatural>::from_coefficients_asc(&[-&x, one]);
+            assert_eq!(&p % &q, Polynomial::from(y));
+        }
+    });
+}
@@ -0,0 +1,198 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{DivRem, Gcd, GcdAssign};
+use malachite_base::vecs::vec_from_str;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_poly::test_util::generators::{
+    polynomial_integer_gen, polynomial_integer_pair_gen, polynomial_integer_triple_gen,
+    polynomial_mod_natural_pair_gen_var_1, polynomial_rational_pair_gen,
+};
+use malachite_poly::Polynomial;
+use malachite_q::Rational;
+
+#[test]
+fn test_gcd() {
+    let test = |s, t, out| {
+        let u = Polynomial::<Integer>::from_owned_coefficients_asc(vec_from_str(s).unwrap());
+        let v = Polynomial::<Integer>::from_owned_coefficients_asc(vec_from_str(t).unwrap());
+
+        let mut n = u.clone();
+        n.gcd_assign(v.clone());
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let mut n = u.clone();
+        n.gcd_assign(&v);
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = u.clone().gcd(v.clone());
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = (&u).gcd(v.clone());
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = u.clone().gcd(&v);
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = (&u).gcd(&v);
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+    };
+    test("[]", "[]", "0");
+    test("[]", "[-2, -4]", "4*x + 2");
+    test("[2, 4]", "[]", "4*x + 2");
+    test("[-2, 0, 2]", "[2, 2]", "2*x + 2");
+    test("[2, 4, 2]", "[6, 6]", "2*x + 2");
+    test("[1, 0, 1]", "[-2, 1]", "1");
+    test("[6]", "[4, 2]", "2");
+    test("[-6, 11, -6, 1]", "[2, -3, 1]", "x^2 - 3*x + 2");
+    test("[12, 0, -12]", "[-18, 18]", "6*x - 6");
+
+    let test = |s, t, out| {
+        let u = Polynomial::<Rational>::from_owned_coefficients_asc(vec_from_str(s).unwrap());
+        let v = Polynomial::<Rational>::from_owned_coefficients_asc(vec_from_str(t).unwrap());
+
+        let mut n = u.clone();
+        n.gcd_assign(&v);
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = (&u).gcd(&v);
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = u.gcd(v);
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+    };
+    test("[]", "[]", "0");
+    test("[1/2, 1/2]", "[]", "x + 1");
+    test("[-1, 0, 1]", "[1/2, 1/2]", "x + 1");
+    test("[1/3, 0, 1/3]", "[2/5]", "1");
+    test("[-6, 11, -6, 1]", "[2/3, -1, 1/3]", "x^2 - 3*x + 2");
+
+    let test = |s, t, m: u32, out| {
+        let m = Natural::from(m);
+        let u = Polynomial::from_naturals_asc(&vec_from_str::<Natural>(s).unwrap(), &m);
+        let v = Polynomial::from_naturals_asc(&vec_from_str::<Natural>(t).unwrap(), &m);
+
+        let mut n = u.clone();
+        n.gcd_assign(&v);
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = (&u).gcd(&v);
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = u.gcd(v);
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+    };
+    test("[]", "[]", 5, "0");
+    test("[1, 1]", "[]", 5, "x + 1 mod 5");
+    test("[4, 0, 1]", "[1, 1]", 5, "x + 1 mod 5");
+    test("[1, 0, 1]", "[1, 1]", 7, "1 mod 7");
+    test("[2, 1, 3, 4]", "[3, 0, 2]", 11, "1 mod 11");
+    test("[6, 5, 1]", "[10, 7, 1]", 11, "x + 2 mod 11");
+}
+
+#[test]
+fn gcd_properties() {
+    polynomial_integer_pair_gen().test_properties(|(x, y)| {
+        let gcd_val_val = x.clone().gcd(y.clone());
+        let gcd_val_ref = x.clone().gcd(&y);
+        let gcd_ref_val = (&x).gcd(y.clone());
+        let gcd = (&x).gcd(&y);
+        assert!(gcd_val_val.is_valid());
+        assert!(gcd_val_ref.is_valid());
+        assert!(gcd_ref_val.is_valid());
+        assert!(gcd.is_valid());
+        assert_eq!(gcd_val_val, gcd);
+        assert_eq!(gcd_val_ref, gcd);
+        assert_eq!(gcd_ref_val, gcd);
+
+        let mut mut_x = x.clone();
+        mut_x.gcd_assign(y.clone());
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x, gcd);
+        let mut mut_x = x.clone();
+        mut_x.gcd_assign(&y);
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x, gcd);
+
+        assert_eq!((&y).gcd(&x), gcd);
+        assert_eq!((-&x).gcd(&y), gcd);
+        if gcd == Polynomial::zero() {
+            assert_eq!(x, Polynomial::zero());
+            assert_eq!(y, Polynomial::zero());
+        } else {
+            assert!(*gcd.leading_coefficient().unwrap() > 0);
+            assert_eq!((&x).div_rem(&gcd).1, Polynomial::zero());
+            assert_eq!((&y).div_rem(&gcd).1, Polynomial::zero());
+            let x_cofactor = &x / &gcd;
+            let y_cofactor = &y / &gcd;
+            assert_eq!(
+                x_cofactor.gcd(y_cofactor),
+                Polynomial::from(Integer::from(1))
+            );
+        }
+    });
+
+    polynomial_integer_gen().test_properties(|x| {
+        let gcd = (&x).gcd(&x);
+        assert_eq!((&x).gcd(Polynomial::zero()), gcd);
+        if x.leading_coefficient().is_some_and(|c| *c > 0) {
+            assert_eq!(gcd, x);
+        }
+    });
+
+    polynomial_integer_triple_gen().test_properties(|(x, y, z)| {
+        assert_eq!((&x).gcd(&y).gcd(&z), x.gcd(y.gcd(z)));
+    });
+
+    polynomial_rational_pair_gen().test_properties(|(x, y)| {
+        let gcd = (&x).gcd(&y);
+        assert!(gcd.is_valid());
+        assert_eq!(x.clone().gcd(y.clone()), gcd);
+        let mut mut_x = x.clone();
+        mut_x.gcd_assign(&y);
+        assert_eq!(mut_x, gcd);
+        assert_eq!((&y).gcd(&x), gcd);
+        if let Some(leading) = gcd.leading_coefficient() {
+            assert_eq!(*leading, 1);
+            assert_eq!(&x % &gcd, Polynomial::zero());
+            assert_eq!(&y % &gcd, Polynomial::zero());
+            assert_eq!(
+                (&x / &gcd).gcd(&y / &gcd),
+                Polynomial::from(Rational::from(1))
+            );
+        }
+    });
+
+    polynomial_mod_natural_pair_gen_var_1().test_properties(|(x, y)| {
+        let gcd = (&x).gcd(&y);
+        assert!(gcd.is_valid());
+        assert_eq!(x.clone().gcd(y.clone()), gcd);
+        let mut mut_x = x.clone();
+        mut_x.gcd_assign(&y);
+        assert_eq!(mut_x, gcd);
+        assert_eq!((&y).gcd(&x), gcd);
+        let leading = gcd.leading_coefficient().unwrap();
+        assert_eq!(*leading.value(), 1u32);
+        assert_eq!(&x % &gcd, Polynomial::zero());
+        assert_eq!(&y % &gcd, Polynomial::zero());
+        assert_eq!((&x / &gcd).gcd(&y / &gcd).degree(), Some(0));
+    });
+}
@@ -0,0 +1,282 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::vecs::vec_from_str;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_poly::test_util::arithmetic::mul::{
+    polynomial_integer_mul_naive, polynomial_mod_natural_mul_naive, polynomial_rational_mul_naive,
+};
+use malachite_poly::test_util::generators::{
+    polynomial_integer_gen, polynomial_integer_integer_pair_gen, polynomial_integer_pair_gen,
+    polynomial_integer_triple_gen, polynomial_mod_natural_pair_gen,
+    polynomial_mod_natural_triple_gen, polynomial_rational_pair_gen,
+    polynomial_rational_triple_gen,
+};
+use malachite_poly::Polynomial;
+use malachite_q::Rational;
+use std::str::FromStr;
+
+#[test]
+fn test_mul() {
+    let test = |s, t, out| {
+        let u = Polynomial::<Integer>::from_owned_coefficients_asc(vec_from_str(s).unwrap());
+        let v = Polynomial::<Integer>::from_owned_coefficients_asc(vec_from_str(t).unwrap());
+
+        let mut n = u.clone();
+        n *= v.clone();
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let mut n = u.clone();
+        n *= &v;
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = u.clone() * v.clone();
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = &u * v.clone();
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = u.clone() * &v;
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = &u * &v;
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = polynomial_integer_mul_naive(&u, &v);
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+    };
+    test("[]", "[]", "0");
+    test("[]", "[1, -2, 3]", "0");
+    test("[1, -2, 3]", "[]", "0");
+    test(
+        "[1, -2, 3]",
+        "[5, 1, -3]",
+        "-9*x^4 + 9*x^3 + 10*x^2 - 9*x + 5",
+    );
+    test(
+        "[1, 2, 3]",
+        "[-1, -2, -3]",
+        "-9*x^4 - 12*x^3 - 10*x^2 - 4*x - 1",
+    );
+    test("[-5, 0, 0, 7]", "[5, 1]", "7*x^4 + 35*x^3 - 5*x - 25");
+    test(
+        "[123456789012345678901234567890]",
+        "[1, 1]",
+        "123456789012345678901234567890*x + 123456789012345678901234567890",
+    );
+    test(
+        "[-123456789012345678901234567890, 987654321098765432109876543210, 0, 1]",
+        "[3, -18446744073709551616, 18446744073709551615]",
+        "18446744073709551615*x^5 - 18446744073709551616*x^4 + \
+        18219006494602281800987506595080531653015272784153*x^3 - \
+        20496382285674979942100094965189513114235325969710*x^2 + \
+        2277375791072698143211353801318858004341040839870*x - 370370367037037036703703703670",
+    );
+    test(
+        "[-123456789012345678901234567890, 987654321098765432109876543210, 0, 1]",
+        "[-123456789012345678901234567890, 987654321098765432109876543210, 0, 1]",
+        "x^6 + 1975308642197530864219753086420*x^4 - 246913578024691357802469135780*x^3 + \
+        975461057985063252587258039935650053345677488187778997104100*x^2 - \
+        243865262274043590452370065467245846664474927602222527053800*x + \
+        15241578753238836750495351562536198787501905199875019052100",
+    );
+
+    let test = |s, t, out| {
+        let u = Polynomial::<Rational>::from_owned_coefficients_asc(vec_from_str(s).unwrap());
+        let v = Polynomial::<Rational>::from_owned_coefficients_asc(vec_from_str(t).unwrap());
+
+        let mut n = u.clone();
+        n *= v.clone();
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let mut n = u.clone();
+        n *= &v;
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = u.clone() * v.clone();
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = &u * v.clone();
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = u.clone() * &v;
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = &u * &v;
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = polynomial_rational_mul_naive(&u, &v);
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+    };
+    test("[1/2]", "[1/3]", "1/6");
+    test("[1/3, 1/2]", "[2/3, 1/2]", "1/4*x^2 + 1/2*x + 2/9");
+    test("[0, -1/2, 5/3]", "[1/4]", "5/12*x^2 - 1/8*x");
+    test("[1/2, 1/3]", "[-1/2, -1/3]", "-1/9*x^2 - 1/3*x - 1/4");
+    test(
+        "[-1/3, 5/7, 2/9]",
+        "[3/2, -7/5]",
+        "-14/45*x^3 - 2/3*x^2 + 323/210*x - 1/2",
+    );
+
+    let test = |s, t, m: &str, out| {
+        let m = Natural::from_str(m).unwrap();
+        let u = Polynomial::from_naturals_asc(&vec_from_str::<Natural>(s).unwrap(), &m);
+        let v = Polynomial::from_naturals_asc(&vec_from_str::<Natural>(t).unwrap(), &m);
+
+        let mut n = u.clone();
+        n *= v.clone();
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let mut n = u.clone();
+        n *= &v;
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = u.clone() * v.clone();
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = &u * v.clone();
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = u.clone() * &v;
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = &u * &v;
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = polynomial_mod_natural_mul_naive(&u, &v);
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+    };
+    test("[]", "[3, 6]", "7", "0");
+    test("[3, 6]", "[4, 1]", "7", "6*x^2 + 6*x + 5 mod 7");
+    test("[1, 2, 3]", "[6]", "7", "4*x^2 + 5*x + 6 mod 7");
+    // The product of the leading coefficients is zero
+    test("[5, 4]", "[3, 2]", "6", "2*x^2 + 4*x + 3 mod 6");
+    test("[0, 2]", "[0, 3]", "6", "0");
+    test("[1]", "[0]", "1", "0");
+    test(
+        "[18446744073709551628, 18446744073709551627, 5]",
+        "[18446744073709551628, 3, 18446744073709551625]",
+        "18446744073709551629",
+        "18446744073709551609*x^4 + 23*x^3 + 18446744073709551622*x^2 + 18446744073709551628*x + \
+        1 mod 18446744073709551629",
+    );
+}
+
+#[test]
+#[should_panic]
+fn mul_fail() {
+    let _ = Polynomial::from_naturals_asc(&[Natural::from(1u32)], &Natural::from(5u32))
+        * Polynomial::from_naturals_asc(&[Natural::from(1u32)], &Natural::from(7u32));
+}
+
+#[test]
+fn mul_properties() {
+    polynomial_integer_pair_gen().test_properties(|(x, y)| {
+        let product_val_val = x.clone() * y.clone();
+        let product_val_ref = x.clone() * &y;
+        let product_ref_val = &x * y.clone();
+        let product = &x * &y;
+        assert!(product_val_val.is_valid());
+        assert!(product_val_ref.is_valid());
+        assert!(product_ref_val.is_valid());
+        assert!(product.is_valid());
+        assert_eq!(product_val_val, product);
+        assert_eq!(product_val_ref, product);
+        assert_eq!(product_ref_val, product);
+
+        let mut mut_x = x.clone();
+        mut_x *= y.clone();
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x, product);
+        let mut mut_x = x.clone();
+        mut_x *= &y;
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x, product);
+
+        assert_eq!(polynomial_integer_mul_naive(&x, &y), product);
+        assert_eq!(&y * &x, product);
+        assert_eq!(-&x * &y, -&product);
+        if let (Some(d), Some(e)) = (x.degree(), y.degree()) {
+            assert_eq!(product.degree(), Some(d + e));
+        } else {
+            assert_eq!(product, Polynomial::zero());
+        }
+    });
+
+    polynomial_integer_gen().test_properties(|x| {
+        assert_eq!(&x * &x, polynomial_integer_mul_naive(&x, &x));
+        assert_eq!(&x * Polynomial::from(Integer::from(1)), x);
+        assert_eq!(&x * Polynomial::zero(), Polynomial::zero());
+    });
+
+    polynomial_integer_triple_gen().test_properties(|(x, y, z)| {
+        assert_eq!((&x * &y) * &z, &x * (&y * &z));
+        assert_eq!(&x * (&y + &z), &x * &y + &x * &z);
+    });
+
+    polynomial_integer_integer_pair_gen().test_properties(|(x, a)| {
+        let y = Polynomial::<Integer>::from_coefficients_asc(&[a.clone(), Integer::from(1)]);
+        assert_eq!((&x * &y).evaluate(&-&a), Integer::from(0));
+    });
+
+    polynomial_rational_pair_gen().test_properties(|(x, y)| {
+        let product = &x * &y;
+        assert!(product.is_valid());
+        assert_eq!(x.clone() * y.clone(), product);
+        let mut mut_x = x.clone();
+        mut_x *= &y;
+        assert_eq!(mut_x, product);
+        assert_eq!(polynomial_rational_mul_naive(&x, &y), product);
+        assert_eq!(&y * &x, product);
+        assert_eq!(&x * &x, polynomial_rational_mul_naive(&x, &x));
+    });
+
+    polynomial_rational_triple_gen().test_properties(|(x, y, z)| {
+        assert_eq!((&x * &y) * &z, &x * (&y * &z));
+        assert_eq!(&x * (&y + &z), &x * &y + &x * &z);
+    });
+
+    polynomial_mod_natural_pair_gen().test_properties(|(x, y)| {
+        let product = &x * &y;
+        assert!(product.is_valid());
+        assert_eq!(x.clone() * y.clone(), product);
+        let mut mut_x = x.clone();
+        mut_x *= &y;
+        assert_eq!(mut_x, product);
+        assert_eq!(polynomial_mod_natural_mul_naive(&x, &y), product);
+        assert_eq!(&y * &x, product);
+        assert_eq!(&x * &x, polynomial_mod_natural_mul_naive(&x, &x));
+    });
+
+    polynomial_mod_natural_triple_gen().test_properties(|(x, y, z)| {
+        assert_eq!((&x * &y) * &z, &x * (&y * &z));
+        assert_eq!(&x * (&y + &z), &x * &y + &x * &z);
+    });
+}
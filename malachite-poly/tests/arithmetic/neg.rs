@@ -0,0 +1,95 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::vecs::vec_from_str;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_poly::test_util::generators::{
+    polynomial_integer_gen, polynomial_mod_natural_gen, polynomial_rational_gen,
+};
+use malachite_poly::Polynomial;
+use malachite_q::Rational;
+
+#[test]
+fn test_neg() {
+    let test = |s, out| {
+        let u = Polynomial::<Integer>::from_owned_coefficients_asc(vec_from_str(s).unwrap());
+
+        let n = -u.clone();
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = -&u;
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+    };
+    test("[]", "0");
+    test("[1, -2, 3]", "-3*x^2 + 2*x - 1");
+    test("[-5, 0, 0, 7]", "-7*x^3 + 5");
+
+    let test = |s, out| {
+        let u = Polynomial::<Rational>::from_owned_coefficients_asc(vec_from_str(s).unwrap());
+
+        let n = -u.clone();
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = -&u;
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+    };
+    test("[]", "0");
+    test("[1/2, -1/3]", "1/3*x - 1/2");
+
+    let test = |s, m: u32, out| {
+        let u =
+            Polynomial::from_naturals_asc(&vec_from_str::<Natural>(s).unwrap(), &Natural::from(m));
+
+        let n = -u.clone();
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = -&u;
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+    };
+    test("[]", 7, "0");
+    test("[3, 0, 1]", 7, "6*x^2 + 4 mod 7");
+    test("[0, 5]", 10, "5*x mod 10");
+}
+
+#[test]
+fn neg_properties() {
+    polynomial_integer_gen().test_properties(|x| {
+        let neg = -x.clone();
+        assert!(neg.is_valid());
+        assert_eq!(-&x, neg);
+        assert_eq!(-&neg, x);
+        assert_eq!(&x + &neg, Polynomial::zero());
+        assert_eq!(neg.degree(), x.degree());
+        assert_eq!(x == neg, x == Polynomial::zero());
+    });
+
+    polynomial_rational_gen().test_properties(|x| {
+        let neg = -x.clone();
+        assert!(neg.is_valid());
+        assert_eq!(-&x, neg);
+        assert_eq!(-&neg, x);
+        assert_eq!(&x + &neg, Polynomial::zero());
+        assert_eq!(neg.degree(), x.degree());
+    });
+
+    polynomial_mod_natural_gen().test_properties(|x| {
+        let neg = -x.clone();
+        assert!(neg.is_valid());
+        assert_eq!(-&x, neg);
+        assert_eq!(-&neg, x);
+        assert_eq!(&x + &neg, Polynomial::zero());
+        assert_eq!(neg.degree(), x.degree());
+    });
+}
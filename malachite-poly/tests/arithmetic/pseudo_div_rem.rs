@@ -0,0 +1,129 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::Pow;
+use malachite_base::strings::ToDebugString;
+use malachite_base::vecs::vec_from_str;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_poly::test_util::generators::{
+    polynomial_integer_pair_gen_var_1, polynomial_mod_natural_pair_gen_var_1,
+    polynomial_rational_pair_gen_var_1,
+};
+use malachite_poly::Polynomial;
+use malachite_q::Rational;
+
+#[test]
+fn test_pseudo_div_rem() {
+    let test = |s, t, q_out, r_out| {
+        let u = Polynomial::<Integer>::from_owned_coefficients_asc(vec_from_str(s).unwrap());
+        let v = Polynomial::<Integer>::from_owned_coefficients_asc(vec_from_str(t).unwrap());
+        let (q, r) = u.pseudo_div_rem(&v);
+        assert!(q.is_valid());
+        assert!(r.is_valid());
+        assert_eq!((q, r).to_debug_string(), format!("({q_out}, {r_out})"));
+    };
+    test("[1, 0, 1]", "[1, 2]", "2*x - 1", "5");
+    test("[-4, 11, -17, 3]", "[-5, 1]", "3*x^2 - 2*x + 1", "1");
+    test("[1, 0, 3]", "[0, 2]", "6*x", "4");
+    test("[5, 1]", "[1, 0, 2]", "0", "x + 5");
+    test("[7, -3, 0, 5]", "[2, -3]", "45*x^2 + 30*x - 7", "-175");
+    test("[1, 2, 3]", "[4]", "48*x^2 + 32*x + 16", "0");
+    test("[]", "[2]", "0", "0");
+
+    let test = |s, t, q_out, r_out| {
+        let u = Polynomial::<Rational>::from_owned_coefficients_asc(vec_from_str(s).unwrap());
+        let v = Polynomial::<Rational>::from_owned_coefficients_asc(vec_from_str(t).unwrap());
+        let (q, r) = u.pseudo_div_rem(&v);
+        assert!(q.is_valid());
+        assert!(r.is_valid());
+        assert_eq!((q, r).to_debug_string(), format!("({q_out}, {r_out})"));
+    };
+    test("[1/2, 2/3, 1]", "[1/3, 1/2]", "1/2*x", "1/8");
+    test("[1, 0, 0, 1/2]", "[2/3, 0, 3]", "3/2*x", "-x + 9");
+
+    let test = |s, t, m: u32, q_out, r_out| {
+        let m = Natural::from(m);
+        let u = Polynomial::from_naturals_asc(&vec_from_str::<Natural>(s).unwrap(), &m);
+        let v = Polynomial::from_naturals_asc(&vec_from_str::<Natural>(t).unwrap(), &m);
+        let (q, r) = u.pseudo_div_rem(&v);
+        assert!(q.is_valid());
+        assert!(r.is_valid());
+        assert_eq!((q, r).to_debug_string(), format!("({q_out}, {r_out})"));
+    };
+    test("[1, 0, 1]", "[1, 2]", 7, "2*x + 6 mod 7", "5 mod 7");
+    // The leading coefficient of the divisor is not invertible
+    test("[1, 2, 3, 4]", "[5, 2]", 6, "4*x^2 + 2*x mod 6", "2 mod 6");
+    test("[3, 1, 4]", "[1, 5, 9]", 13, "4 mod 13", "2*x + 10 mod 13");
+}
+
+#[test]
+#[should_panic]
+fn pseudo_div_rem_fail() {
+    Polynomial::<Integer>::from_coefficients_asc(&[Integer::from(1)])
+        .pseudo_div_rem(&Polynomial::zero());
+}
+
+#[test]
+fn pseudo_div_rem_properties() {
+    polynomial_integer_pair_gen_var_1().test_properties(|(x, y)| {
+        let (q, r) = x.pseudo_div_rem(&y);
+        assert!(q.is_valid());
+        assert!(r.is_valid());
+        match (x.degree(), y.degree()) {
+            (Some(m), Some(n)) if m >= n => {
+                let multiplier = y.leading_coefficient().unwrap().pow(m - n + 1);
+                assert_eq!(&q * &y + &r, &x * Polynomial::from(multiplier));
+                assert!(r.degree() < y.degree());
+                let x = Polynomial::<Rational>::from_coefficients_asc(
+                    &x.coefficients()
+                        .iter()
+                        .map(Rational::from)
+                        .collect::<Vec<_>>(),
+                );
+                let y = Polynomial::<Rational>::from_coefficients_asc(
+                    &y.coefficients()
+                        .iter()
+                        .map(Rational::from)
+                        .collect::<Vec<_>>(),
+                );
+                assert_eq!(x % y == Polynomial::zero(), r == Polynomial::zero());
+            }
+            _ => {
+                assert_eq!(q, Polynomial::zero());
+                assert_eq!(r, x);
+            }
+        }
+    });
+
+    polynomial_rational_pair_gen_var_1().test_properties(|(x, y)| {
+        let (q, r) = x.pseudo_div_rem(&y);
+        assert!(q.is_valid());
+        assert!(r.is_valid());
+        if let (Some(m), Some(n)) = (x.degree(), y.degree()) {
+            if m >= n {
+                let multiplier = y.leading_coefficient().unwrap().pow(m - n + 1);
+                assert_eq!(&q * &y + &r, &x * Polynomial::from(multiplier));
+                assert!(r.degree() < y.degree());
+            }
+        }
+    });
+
+    polynomial_mod_natural_pair_gen_var_1().test_properties(|(x, y)| {
+        let (q, r) = x.pseudo_div_rem(&y);
+        assert!(q.is_valid());
+        assert!(r.is_valid());
+        if let (Some(m), Some(n)) = (x.degree(), y.degree()) {
+            if m >= n {
+                let multiplier = y.leading_coefficient().unwrap().pow(m - n + 1);
+                assert_eq!(&q * &y + &r, &x * Polynomial::from(multiplier));
+                assert!(r.degree() < y.degree());
+            }
+        }
+    });
+}
@@ -0,0 +1,130 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{Gcd, Parity, Pow};
+use malachite_base::test_util::generators::common::GenConfig;
+use malachite_base::vecs::vec_from_str;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_poly::test_util::arithmetic::resultant::{
+    polynomial_integer_resultant_naive, polynomial_mod_natural_resultant_naive,
+    polynomial_rational_resultant_naive,
+};
+use malachite_poly::test_util::generators::{
+    polynomial_integer_pair_gen, polynomial_integer_triple_gen,
+    polynomial_mod_natural_pair_gen_var_1, polynomial_rational_pair_gen,
+};
+use malachite_poly::Polynomial;
+use malachite_q::Rational;
+
+#[test]
+fn test_resultant() {
+    let test = |s, t, out| {
+        let u = Polynomial::<Integer>::from_owned_coefficients_asc(vec_from_str(s).unwrap());
+        let v = Polynomial::<Integer>::from_owned_coefficients_asc(vec_from_str(t).unwrap());
+        assert_eq!(u.resultant(&v).to_string(), out);
+        assert_eq!(polynomial_integer_resultant_naive(&u, &v).to_string(), out);
+    };
+    test("[]", "[1, 2]", "0");
+    test("[3]", "[5]", "1");
+    test("[3]", "[1, 2, 1]", "9");
+    test("[1, 0, 1]", "[-2, 1]", "5");
+    test("[5, 2, 0, 1]", "[-7, 0, 3]", "-508");
+    test("[-2, 1]", "[1, 0, 1]", "5");
+    test("[2, 3, 1]", "[1, 1]", "0");
+    test("[1, 2, 3, 4]", "[5, 6, 7]", "832");
+    test("[0, 1]", "[0, 0, 1]", "0");
+
+    let test = |s, t, out| {
+        let u = Polynomial::<Rational>::from_owned_coefficients_asc(vec_from_str(s).unwrap());
+        let v = Polynomial::<Rational>::from_owned_coefficients_asc(vec_from_str(t).unwrap());
+        let r = u.resultant(&v);
+        assert!(r.is_valid());
+        assert_eq!(r.to_string(), out);
+        assert_eq!(polynomial_rational_resultant_naive(&u, &v).to_string(), out);
+    };
+    test("[1, 0, 1]", "[-1/2, 1]", "5/4");
+    test("[1/2, 1/3]", "[1/5, 0, 2/7]", "59/630");
+    test("[2/3]", "[1, 1, 1]", "4/9");
+
+    let test = |s, t, m: u32, out| {
+        let m = Natural::from(m);
+        let u = Polynomial::from_naturals_asc(&vec_from_str::<Natural>(s).unwrap(), &m);
+        let v = Polynomial::from_naturals_asc(&vec_from_str::<Natural>(t).unwrap(), &m);
+        let r = u.resultant(&v);
+        assert!(r.is_valid());
+        assert_eq!(r.to_string(), out);
+        assert_eq!(
+            polynomial_mod_natural_resultant_naive(&u, &v).to_string(),
+            out
+        );
+    };
+    test("[1, 0, 1]", "[5, 1]", 7, "5 mod 7");
+    test("[3]", "[1, 2, 1]", 7, "2 mod 7");
+    test("[6, 5, 1]", "[10, 7, 1]", 11, "0 mod 11");
+    test("[1, 2, 3, 4]", "[5, 6, 7]", 13, "0 mod 13");
+    test("[]", "[1, 1]", 3, "0 mod 3");
+}
+
+#[test]
+#[should_panic]
+fn resultant_fail() {
+    Polynomial::<malachite_poly::mod_natural::ModNatural>::zero().resultant(&Polynomial::zero());
+}
+
+#[test]
+fn resultant_properties() {
+    let mut config = GenConfig::new();
+    config.insert("mean_bits_n", 16);
+    config.insert("mean_length_n", 2);
+    polynomial_integer_pair_gen().test_properties_with_config(&config, |(x, y)| {
+        let resultant = x.resultant(&y);
+        assert_eq!(polynomial_integer_resultant_naive(&x, &y), resultant);
+        let (m, n) = (x.degree(), y.degree());
+        let swapped = y.resultant(&x);
+        if m.unwrap_or(0).odd() && n.unwrap_or(0).odd() {
+            assert_eq!(swapped, -&resultant);
+        } else {
+            assert_eq!(swapped, resultant);
+        }
+        assert_eq!(
+            resultant == 0u32,
+            x == Polynomial::zero() || y == Polynomial::zero() || (&x).gcd(&y).degree() > Some(0)
+        );
+        if let Some(n) = n {
+            assert_eq!(
+                (-&x).resultant(&y),
+                resultant.clone() * Integer::from(-1).pow(n)
+            );
+        }
+    });
+
+    polynomial_integer_triple_gen().test_properties_with_config(&config, |(x, y, z)| {
+        assert_eq!((&x * &y).resultant(&z), x.resultant(&z) * y.resultant(&z));
+    });
+
+    polynomial_rational_pair_gen().test_properties_with_config(&config, |(x, y)| {
+        let resultant = x.resultant(&y);
+        assert!(resultant.is_valid());
+        assert_eq!(polynomial_rational_resultant_naive(&x, &y), resultant);
+        assert_eq!(
+            resultant == 0u32,
+            x == Polynomial::zero() || y == Polynomial::zero() || (&x).gcd(&y).degree() > Some(0)
+        );
+    });
+
+    polynomial_mod_natural_pair_gen_var_1().test_properties_with_config(&config, |(x, y)| {
+        let resultant = x.resultant(&y);
+        assert!(resultant.is_valid());
+        assert_eq!(polynomial_mod_natural_resultant_naive(&x, &y), resultant);
+        assert_eq!(
+            *resultant.value() == 0u32,
+            x == Polynomial::zero() || y == Polynomial::zero() || (&x).gcd(&y).degree() > Some(0)
+        );
+    });
+}
@@ -0,0 +1,209 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::vecs::vec_from_str;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_poly::test_util::generators::{
+    polynomial_integer_gen, polynomial_integer_pair_gen, polynomial_mod_natural_gen,
+    polynomial_mod_natural_pair_gen, polynomial_rational_gen, polynomial_rational_pair_gen,
+};
+use malachite_poly::Polynomial;
+use malachite_q::Rational;
+
+#[test]
+fn test_sub() {
+    let test = |s, t, out| {
+        let u = Polynomial::<Integer>::from_owned_coefficients_asc(vec_from_str(s).unwrap());
+        let v = Polynomial::<Integer>::from_owned_coefficients_asc(vec_from_str(t).unwrap());
+
+        let mut n = u.clone();
+        n -= v.clone();
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let mut n = u.clone();
+        n -= &v;
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = u.clone() - v.clone();
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = &u - v.clone();
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = u.clone() - &v;
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = &u - &v;
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+    };
+    test("[]", "[]", "0");
+    test("[]", "[1, -2, 3]", "-3*x^2 + 2*x - 1");
+    test("[1, -2, 3]", "[]", "3*x^2 - 2*x + 1");
+    test("[1, -2, 3]", "[5, 1, -3]", "6*x^2 - 3*x - 4");
+    test("[1, 2, 3]", "[-1, -2, -3]", "6*x^2 + 4*x + 2");
+    test("[-5, 0, 0, 7]", "[5, 1]", "7*x^3 - x - 10");
+    test(
+        "[123456789012345678901234567890]",
+        "[1, 1]",
+        "-x + 123456789012345678901234567889",
+    );
+
+    let test = |s, t, out| {
+        let u = Polynomial::<Rational>::from_owned_coefficients_asc(vec_from_str(s).unwrap());
+        let v = Polynomial::<Rational>::from_owned_coefficients_asc(vec_from_str(t).unwrap());
+
+        let mut n = u.clone();
+        n -= v.clone();
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let mut n = u.clone();
+        n -= &v;
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = u.clone() - v.clone();
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = &u - v.clone();
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = u.clone() - &v;
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = &u - &v;
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+    };
+    test("[1/2]", "[1/3]", "1/6");
+    test("[1/3, 1/2]", "[2/3, 1/2]", "-1/3");
+    test("[0, -1/2, 5/3]", "[1/4]", "5/3*x^2 - 1/2*x - 1/4");
+    test("[1/2, 1/3]", "[-1/2, -1/3]", "2/3*x + 1");
+
+    let test = |s, t, m: u32, out| {
+        let m = Natural::from(m);
+        let u = Polynomial::from_naturals_asc(&vec_from_str::<Natural>(s).unwrap(), &m);
+        let v = Polynomial::from_naturals_asc(&vec_from_str::<Natural>(t).unwrap(), &m);
+
+        let mut n = u.clone();
+        n -= v.clone();
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let mut n = u.clone();
+        n -= &v;
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = u.clone() - v.clone();
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = &u - v.clone();
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = u.clone() - &v;
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = &u - &v;
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+    };
+    test("[]", "[3, 6]", 7, "x + 4 mod 7");
+    test("[3, 6]", "[4, 1]", 7, "5*x + 6 mod 7");
+    test("[1, 2, 3]", "[6]", 7, "3*x^2 + 2*x + 2 mod 7");
+    test("[5, 4]", "[3, 2]", 6, "2*x + 2 mod 6");
+    test("[1]", "[0]", 1, "0");
+}
+
+#[test]
+#[should_panic]
+fn sub_fail() {
+    let _ = Polynomial::from_naturals_asc(&[Natural::from(1u32)], &Natural::from(5u32))
+        - Polynomial::from_naturals_asc(&[Natural::from(1u32)], &Natural::from(7u32));
+}
+
+#[test]
+fn sub_properties() {
+    polynomial_integer_pair_gen().test_properties(|(x, y)| {
+        let diff_val_val = x.clone() - y.clone();
+        let diff_val_ref = x.clone() - &y;
+        let diff_ref_val = &x - y.clone();
+        let diff = &x - &y;
+        assert!(diff_val_val.is_valid());
+        assert!(diff_val_ref.is_valid());
+        assert!(diff_ref_val.is_valid());
+        assert!(diff.is_valid());
+        assert_eq!(diff_val_val, diff);
+        assert_eq!(diff_val_ref, diff);
+        assert_eq!(diff_ref_val, diff);
+
+        let mut mut_x = x.clone();
+        mut_x -= y.clone();
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x, diff);
+        let mut mut_x = x.clone();
+        mut_x -= &y;
+        assert!(mut_x.is_valid());
+        assert_eq!(mut_x, diff);
+
+        assert_eq!(&y - &x, -&diff);
+        assert_eq!(&x + -&y, diff);
+        assert_eq!(diff + y, x);
+    });
+
+    polynomial_integer_gen().test_properties(|x| {
+        assert_eq!(&x - Polynomial::zero(), x);
+        assert_eq!(Polynomial::zero() - &x, -&x);
+        assert_eq!(&x - &x, Polynomial::zero());
+    });
+
+    polynomial_rational_pair_gen().test_properties(|(x, y)| {
+        let diff = &x - &y;
+        assert!(diff.is_valid());
+        assert_eq!(x.clone() - y.clone(), diff);
+        let mut mut_x = x.clone();
+        mut_x -= &y;
+        assert_eq!(mut_x, diff);
+        assert_eq!(&y - &x, -&diff);
+        assert_eq!(diff + y, x);
+    });
+
+    polynomial_rational_gen().test_properties(|x| {
+        assert_eq!(&x - Polynomial::zero(), x);
+        assert_eq!(&x - &x, Polynomial::zero());
+    });
+
+    polynomial_mod_natural_pair_gen().test_properties(|(x, y)| {
+        let diff = &x - &y;
+        assert!(diff.is_valid());
+        assert_eq!(x.clone() - y.clone(), diff);
+        let mut mut_x = x.clone();
+        mut_x -= &y;
+        assert_eq!(mut_x, diff);
+        assert_eq!(&y - &x, -&diff);
+        assert_eq!(diff + y, x);
+    });
+
+    polynomial_mod_natural_gen().test_properties(|x| {
+        assert_eq!(&x - Polynomial::zero(), x);
+        assert_eq!(&x - &x, Polynomial::zero());
+    });
+}
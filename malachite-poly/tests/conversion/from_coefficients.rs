@@ -0,0 +1,150 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::vecs::vec_from_str;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_poly::mod_natural::ModNatural;
+use malachite_poly::test_util::generators::{
+    polynomial_integer_gen, polynomial_mod_natural_gen, polynomial_rational_gen,
+};
+use malachite_poly::Polynomial;
+use malachite_q::Rational;
+
+#[test]
+fn test_from_coefficients_asc() {
+    let test = |s, out| {
+        let xs: Vec<Integer> = vec_from_str(s).unwrap();
+        let p = Polynomial::<Integer>::from_coefficients_asc(&xs);
+        assert!(p.is_valid());
+        assert_eq!(p.to_string(), out);
+        let p_alt = Polynomial::<Integer>::from_owned_coefficients_asc(xs);
+        assert!(p_alt.is_valid());
+        assert_eq!(p_alt, p);
+    };
+    test("[]", "0");
+    test("[0]", "0");
+    test("[0, 0, 0]", "0");
+    test("[5]", "5");
+    test("[5, 0]", "5");
+    test("[1, -2, 3]", "3*x^2 - 2*x + 1");
+    test("[0, 1, 0, -1, 0]", "-x^3 + x");
+
+    let test = |s, out| {
+        let xs: Vec<Rational> = vec_from_str(s).unwrap();
+        let p = Polynomial::<Rational>::from_coefficients_asc(&xs);
+        assert!(p.is_valid());
+        assert_eq!(p.to_string(), out);
+        let p_alt = Polynomial::<Rational>::from_owned_coefficients_asc(xs);
+        assert!(p_alt.is_valid());
+        assert_eq!(p_alt, p);
+    };
+    test("[0, 0]", "0");
+    test("[1/2, 0, -2/3, 0]", "-2/3*x^2 + 1/2");
+
+    let test = |s, m: u32, out| {
+        let m = Natural::from(m);
+        let ns: Vec<Natural> = vec_from_str(s).unwrap();
+        let p = Polynomial::from_naturals_asc(&ns, &m);
+        assert!(p.is_valid());
+        assert_eq!(p.to_string(), out);
+        let xs: Vec<ModNatural> = ns
+            .into_iter()
+            .map(|n| ModNatural::new(n, m.clone()))
+            .collect();
+        let p_alt = Polynomial::<ModNatural>::from_coefficients_asc(&xs);
+        assert!(p_alt.is_valid());
+        assert_eq!(p_alt, p);
+        let p_alt = Polynomial::<ModNatural>::from_owned_coefficients_asc(xs);
+        assert!(p_alt.is_valid());
+        assert_eq!(p_alt, p);
+    };
+    test("[]", 7, "0");
+    test("[7, 14]", 7, "0");
+    test("[8, 1, 14]", 7, "x + 1 mod 7");
+    test("[3, 0, 5]", 1, "0");
+}
+
+#[test]
+#[should_panic]
+fn from_coefficients_asc_fail() {
+    Polynomial::<ModNatural>::from_coefficients_asc(&[
+        ModNatural::new(Natural::from(1u32), Natural::from(5u32)),
+        ModNatural::new(Natural::from(1u32), Natural::from(7u32)),
+    ]);
+}
+
+#[test]
+#[should_panic]
+fn from_naturals_asc_fail() {
+    Polynomial::from_naturals_asc(&[Natural::from(1u32)], &Natural::from(0u32));
+}
+
+#[test]
+fn test_from() {
+    let test = |x: Integer, out| {
+        let p = Polynomial::from(x);
+        assert!(p.is_valid());
+        assert_eq!(p.to_string(), out);
+    };
+    test(Integer::from(0), "0");
+    test(Integer::from(-3), "-3");
+
+    let test = |x: Rational, out| {
+        let p = Polynomial::from(x);
+        assert!(p.is_valid());
+        assert_eq!(p.to_string(), out);
+    };
+    test(Rational::from(0), "0");
+    test(Rational::from_signeds(22, 7), "22/7");
+
+    let test = |x: ModNatural, out| {
+        let p = Polynomial::from(x);
+        assert!(p.is_valid());
+        assert_eq!(p.to_string(), out);
+    };
+    test(
+        ModNatural::new(Natural::from(7u32), Natural::from(7u32)),
+        "0",
+    );
+    test(
+        ModNatural::new(Natural::from(3u32), Natural::from(7u32)),
+        "3 mod 7",
+    );
+}
+
+#[test]
+fn from_coefficients_asc_properties() {
+    polynomial_integer_gen().test_properties(|p| {
+        let xs = p.coefficients();
+        assert_eq!(Polynomial::<Integer>::from_coefficients_asc(xs), p);
+        let mut ys = xs.to_vec();
+        ys.push(Integer::from(0));
+        assert_eq!(Polynomial::<Integer>::from_owned_coefficients_asc(ys), p);
+    });
+
+    polynomial_rational_gen().test_properties(|p| {
+        let xs = p.coefficients();
+        assert_eq!(Polynomial::<Rational>::from_coefficients_asc(xs), p);
+        let mut ys = xs.to_vec();
+        ys.push(Rational::from(0));
+        assert_eq!(Polynomial::<Rational>::from_owned_coefficients_asc(ys), p);
+    });
+
+    polynomial_mod_natural_gen().test_properties(|p| {
+        assert_eq!(
+            Polynomial::<ModNatural>::from_coefficients_asc(p.coefficients()),
+            p
+        );
+        if let Some(x) = p.leading_coefficient() {
+            let naturals: Vec<Natural> =
+                p.coefficients().iter().map(|x| x.value().clone()).collect();
+            assert_eq!(Polynomial::from_naturals_asc(&naturals, x.modulus()), p);
+        }
+    });
+}
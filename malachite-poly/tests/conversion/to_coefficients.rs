@@ -0,0 +1,95 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::strings::ToDebugString;
+use malachite_base::vecs::vec_from_str;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_poly::test_util::generators::{
+    polynomial_integer_gen, polynomial_mod_natural_gen, polynomial_rational_gen,
+};
+use malachite_poly::Polynomial;
+use malachite_q::Rational;
+
+#[test]
+fn test_to_coefficients() {
+    let test = |s, degree, leading: Option<&str>| {
+        let p = Polynomial::<Integer>::from_owned_coefficients_asc(vec_from_str(s).unwrap());
+        assert_eq!(p.coefficients().to_debug_string(), s);
+        assert_eq!(p.degree(), degree);
+        assert_eq!(
+            p.leading_coefficient().map(ToString::to_string).as_deref(),
+            leading
+        );
+        assert_eq!(p.into_coefficients().to_debug_string(), s);
+    };
+    test("[]", None, None);
+    test("[5]", Some(0), Some("5"));
+    test("[1, -2, 3]", Some(2), Some("3"));
+    test("[0, 0, 0, -1]", Some(3), Some("-1"));
+
+    let test = |s, degree, leading: Option<&str>| {
+        let p = Polynomial::<Rational>::from_owned_coefficients_asc(vec_from_str(s).unwrap());
+        assert_eq!(p.coefficients().to_debug_string(), s);
+        assert_eq!(p.degree(), degree);
+        assert_eq!(
+            p.leading_coefficient().map(ToString::to_string).as_deref(),
+            leading
+        );
+        assert_eq!(p.into_coefficients().to_debug_string(), s);
+    };
+    test("[]", None, None);
+    test("[1/2, 0, -2/3]", Some(2), Some("-2/3"));
+
+    let test = |s, m: u32, degree, out, leading: Option<&str>| {
+        let p =
+            Polynomial::from_naturals_asc(&vec_from_str::<Natural>(s).unwrap(), &Natural::from(m));
+        assert_eq!(p.coefficients().to_debug_string(), out);
+        assert_eq!(p.degree(), degree);
+        assert_eq!(
+            p.leading_coefficient().map(ToString::to_string).as_deref(),
+            leading
+        );
+        assert_eq!(p.into_coefficients().to_debug_string(), out);
+    };
+    test("[]", 7, None, "[]", None);
+    test(
+        "[8, 1, 14]",
+        7,
+        Some(1),
+        "[1 mod 7, 1 mod 7]",
+        Some("1 mod 7"),
+    );
+}
+
+#[test]
+fn to_coefficients_properties() {
+    polynomial_integer_gen().test_properties(|p| {
+        let xs = p.coefficients();
+        assert_eq!(p.degree(), xs.len().checked_sub(1).map(|d| d as u64));
+        assert_eq!(p.leading_coefficient(), xs.last());
+        assert_ne!(p.leading_coefficient(), Some(&Integer::from(0)));
+        assert_eq!(p.clone().into_coefficients(), xs);
+    });
+
+    polynomial_rational_gen().test_properties(|p| {
+        let xs = p.coefficients();
+        assert_eq!(p.degree(), xs.len().checked_sub(1).map(|d| d as u64));
+        assert_eq!(p.leading_coefficient(), xs.last());
+        assert_ne!(p.leading_coefficient(), Some(&Rational::from(0)));
+        assert_eq!(p.clone().into_coefficients(), xs);
+    });
+
+    polynomial_mod_natural_gen().test_properties(|p| {
+        let xs = p.coefficients();
+        assert_eq!(p.degree(), xs.len().checked_sub(1).map(|d| d as u64));
+        assert_eq!(p.leading_coefficient(), xs.last());
+        assert!(p.leading_coefficient().is_none_or(|x| *x.value() != 0u32));
+        assert_eq!(p.clone().into_coefficients(), xs);
+    });
+}
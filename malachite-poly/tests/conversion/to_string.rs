@@ -0,0 +1,91 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::strings::ToDebugString;
+use malachite_base::vecs::vec_from_str;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_poly::test_util::generators::{
+    polynomial_integer_gen, polynomial_mod_natural_gen, polynomial_rational_gen,
+};
+use malachite_poly::Polynomial;
+use malachite_q::Rational;
+
+#[test]
+fn test_to_string() {
+    let test = |s, out| {
+        let p = Polynomial::<Integer>::from_owned_coefficients_asc(vec_from_str(s).unwrap());
+        assert_eq!(p.to_string(), out);
+        assert_eq!(p.to_debug_string(), out);
+    };
+    test("[]", "0");
+    test("[0]", "0");
+    test("[1]", "1");
+    test("[-1]", "-1");
+    test("[0, 1]", "x");
+    test("[0, -1]", "-x");
+    test("[0, 0, 1]", "x^2");
+    test("[0, 0, -1]", "-x^2");
+    test("[1, -2, 3]", "3*x^2 - 2*x + 1");
+    test("[-1, 0, 0, -5]", "-5*x^3 - 1");
+    test("[0, 1, 0, -1, 0, 1]", "x^5 - x^3 + x");
+    test("[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1]", "x^12");
+    test(
+        "[123456789012345678901234567890, -98765432109876543210]",
+        "-98765432109876543210*x + 123456789012345678901234567890",
+    );
+
+    let test = |s, out| {
+        let p = Polynomial::<Rational>::from_owned_coefficients_asc(vec_from_str(s).unwrap());
+        assert_eq!(p.to_string(), out);
+        assert_eq!(p.to_debug_string(), out);
+    };
+    test("[]", "0");
+    test("[1/2]", "1/2");
+    test("[-1/2]", "-1/2");
+    test("[0, 1/2]", "1/2*x");
+    test("[0, 1/2, -1]", "-x^2 + 1/2*x");
+    test("[-1/3, 0, -22/7]", "-22/7*x^2 - 1/3");
+
+    let test = |s, m: u32, out| {
+        let p =
+            Polynomial::from_naturals_asc(&vec_from_str::<Natural>(s).unwrap(), &Natural::from(m));
+        assert_eq!(p.to_string(), out);
+        assert_eq!(p.to_debug_string(), out);
+    };
+    test("[]", 7, "0");
+    test("[3]", 7, "3 mod 7");
+    test("[0, 1]", 7, "x mod 7");
+    test("[1, 0, 6]", 7, "6*x^2 + 1 mod 7");
+    test("[5, 1, 0, 1]", 10, "x^3 + x + 5 mod 10");
+}
+
+#[test]
+fn to_string_properties() {
+    polynomial_integer_gen().test_properties(|p| {
+        let s = p.to_string();
+        assert_eq!(p.to_debug_string(), s);
+        assert_eq!(s == "0", p == Polynomial::zero());
+    });
+
+    polynomial_rational_gen().test_properties(|p| {
+        let s = p.to_string();
+        assert_eq!(p.to_debug_string(), s);
+        assert_eq!(s == "0", p == Polynomial::zero());
+    });
+
+    polynomial_mod_natural_gen().test_properties(|p| {
+        let s = p.to_string();
+        assert_eq!(p.to_debug_string(), s);
+        assert_eq!(s == "0", p == Polynomial::zero());
+        assert!(!s.starts_with('-'));
+        if let Some(x) = p.leading_coefficient() {
+            assert!(s.ends_with(&format!(" mod {}", x.modulus())));
+        }
+    });
+}
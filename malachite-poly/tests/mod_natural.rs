@@ -0,0 +1,186 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::Pow;
+use malachite_nz::natural::Natural;
+use malachite_poly::mod_natural::ModNatural;
+use malachite_poly::test_util::generators::{mod_natural_gen_var_1, mod_natural_pair_gen};
+
+fn mn(value: u32, modulus: u32) -> ModNatural {
+    ModNatural::new(Natural::from(value), Natural::from(modulus))
+}
+
+#[test]
+fn test_new() {
+    let test = |value: u32, modulus: u32, out| {
+        let x = mn(value, modulus);
+        assert!(x.is_valid());
+        assert_eq!(x.to_string(), out);
+        assert_eq!(format!("{x:?}"), out);
+        assert_eq!(*x.modulus(), modulus);
+        assert_eq!(*x.value(), x.clone().into_value());
+    };
+    test(0, 1, "0 mod 1");
+    test(5, 1, "0 mod 1");
+    test(3, 7, "3 mod 7");
+    test(7, 7, "0 mod 7");
+    test(10, 7, "3 mod 7");
+    test(1000, 999, "1 mod 999");
+}
+
+#[test]
+#[should_panic]
+fn new_fail() {
+    mn(1, 0);
+}
+
+#[test]
+fn test_inverse() {
+    let test = |value: u32, modulus: u32, out: Option<&str>| {
+        let x = mn(value, modulus);
+        let inv = x.inverse();
+        assert!(inv.as_ref().is_none_or(ModNatural::is_valid));
+        assert_eq!(inv.map(|y| y.to_string()).as_deref(), out);
+    };
+    test(0, 1, Some("0 mod 1"));
+    test(0, 7, None);
+    test(1, 7, Some("1 mod 7"));
+    test(3, 7, Some("5 mod 7"));
+    test(6, 7, Some("6 mod 7"));
+    test(4, 6, None);
+    test(5, 6, Some("5 mod 6"));
+    test(7, 100, Some("43 mod 100"));
+}
+
+#[test]
+fn test_arithmetic() {
+    let test = |x: u32, y: u32, modulus: u32, sum, difference, product| {
+        let x = mn(x, modulus);
+        let y = mn(y, modulus);
+
+        let mut s = x.clone();
+        s += y.clone();
+        assert_eq!(s.to_string(), sum);
+        let mut s = x.clone();
+        s += &y;
+        assert_eq!(s.to_string(), sum);
+        assert_eq!((x.clone() + y.clone()).to_string(), sum);
+        assert_eq!((x.clone() + &y).to_string(), sum);
+        assert_eq!((&x + y.clone()).to_string(), sum);
+        assert_eq!((&x + &y).to_string(), sum);
+
+        let mut d = x.clone();
+        d -= y.clone();
+        assert_eq!(d.to_string(), difference);
+        let mut d = x.clone();
+        d -= &y;
+        assert_eq!(d.to_string(), difference);
+        assert_eq!((x.clone() - y.clone()).to_string(), difference);
+        assert_eq!((x.clone() - &y).to_string(), difference);
+        assert_eq!((&x - y.clone()).to_string(), difference);
+        assert_eq!((&x - &y).to_string(), difference);
+
+        let mut p = x.clone();
+        p *= y.clone();
+        assert_eq!(p.to_string(), product);
+        let mut p = x.clone();
+        p *= &y;
+        assert_eq!(p.to_string(), product);
+        assert_eq!((x.clone() * y.clone()).to_string(), product);
+        assert_eq!((x.clone() * &y).to_string(), product);
+        assert_eq!((&x * y.clone()).to_string(), product);
+        assert_eq!((&x * &y).to_string(), product);
+    };
+    test(0, 0, 1, "0 mod 1", "0 mod 1", "0 mod 1");
+    test(0, 0, 7, "0 mod 7", "0 mod 7", "0 mod 7");
+    test(3, 5, 7, "1 mod 7", "5 mod 7", "1 mod 7");
+    test(6, 6, 7, "5 mod 7", "0 mod 7", "1 mod 7");
+    test(2, 3, 6, "5 mod 6", "5 mod 6", "0 mod 6");
+    test(99, 98, 100, "97 mod 100", "1 mod 100", "2 mod 100");
+}
+
+#[test]
+#[should_panic]
+fn add_fail() {
+    let _ = mn(1, 5) + mn(1, 7);
+}
+
+#[test]
+#[should_panic]
+fn sub_fail() {
+    let _ = mn(1, 5) - mn(1, 7);
+}
+
+#[test]
+#[should_panic]
+fn mul_fail() {
+    let _ = mn(1, 5) * mn(1, 7);
+}
+
+#[test]
+fn test_neg() {
+    let test = |x: u32, modulus: u32, out| {
+        let x = mn(x, modulus);
+        let neg = -x.clone();
+        assert!(neg.is_valid());
+        assert_eq!(neg.to_string(), out);
+        assert_eq!((-&x).to_string(), out);
+    };
+    test(0, 1, "0 mod 1");
+    test(0, 7, "0 mod 7");
+    test(1, 7, "6 mod 7");
+    test(3, 7, "4 mod 7");
+}
+
+#[test]
+fn test_pow() {
+    let test = |x: u32, modulus: u32, exp: u64, out| {
+        let x = mn(x, modulus);
+        let p = x.clone().pow(exp);
+        assert!(p.is_valid());
+        assert_eq!(p.to_string(), out);
+        assert_eq!((&x).pow(exp).to_string(), out);
+    };
+    test(0, 1, 0, "0 mod 1");
+    test(0, 7, 0, "1 mod 7");
+    test(0, 7, 5, "0 mod 7");
+    test(3, 7, 6, "1 mod 7");
+    test(2, 7, 10, "2 mod 7");
+    test(2, 1000, 100, "376 mod 1000");
+}
+
+#[test]
+fn mod_natural_properties() {
+    mod_natural_pair_gen().test_properties(|(x, y)| {
+        let sum = &x + &y;
+        assert!(sum.is_valid());
+        assert_eq!(&y + &x, sum);
+        assert_eq!(&sum - &y, x);
+        assert_eq!(&x - &y, &x + -&y);
+        let product = &x * &y;
+        assert!(product.is_valid());
+        assert_eq!(&y * &x, product);
+        assert_eq!(product.value(), &((x.value() * y.value()) % x.modulus()));
+        assert_eq!((&x).pow(2), &x * &x);
+        assert_eq!(-(-&x), x);
+        if let Some(inv) = x.inverse() {
+            assert!(inv.is_valid());
+            assert_eq!(*(&x * &inv).value(), Natural::from(1u32) % x.modulus());
+        }
+    });
+
+    mod_natural_gen_var_1().test_properties(|x| {
+        // The modulus is prime, so every nonzero element is invertible
+        let inv = x.inverse();
+        assert_eq!(inv.is_some(), *x.value() != 0u32);
+        if let Some(inv) = inv {
+            assert_eq!(*(&x * &inv).value(), 1u32);
+            assert_eq!(inv.inverse().unwrap(), x);
+        }
+    });
+}
@@ -0,0 +1,367 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{Ceiling, Floor, Reciprocal, Sign};
+use malachite_base::num::basic::traits::{NegativeOne, One, Zero};
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_poly::quadratic_irrational::QuadraticIrrational;
+use malachite_poly::real_algebraic::RealAlgebraic;
+use malachite_poly::test_util::generators::{
+    quadratic_irrational_gen, quadratic_irrational_gen_var_1, quadratic_irrational_pair_gen_var_1,
+    quadratic_irrational_pair_gen_var_2, quadratic_irrational_triple_gen_var_1,
+};
+use malachite_q::Rational;
+use std::cmp::Ordering::*;
+
+fn new(a: i64, b: i64, c: u64, d: u64) -> QuadraticIrrational {
+    QuadraticIrrational::new(
+        Integer::from(a),
+        Integer::from(b),
+        Natural::from(c),
+        Natural::from(d),
+    )
+}
+
+#[test]
+fn test_neg() {
+    let test = |a, b, c, d, out| {
+        let x = new(a, b, c, d);
+        let y = -x.clone();
+        assert!(y.is_valid());
+        assert_eq!(y.to_string(), out);
+        let y = -&x;
+        assert!(y.is_valid());
+        assert_eq!(y.to_string(), out);
+    };
+    test(0, 0, 1, 0, "0");
+    test(2, 0, 3, 0, "-2/3");
+    test(0, 1, 1, 2, "-sqrt(2)");
+    test(1, 1, 2, 5, "(-1 - sqrt(5))/2");
+    test(3, -1, 1, 7, "-3 + sqrt(7)");
+}
+
+#[test]
+fn test_add_sub_mul_div() {
+    let test =
+        |x: QuadraticIrrational, y: QuadraticIrrational, sum, difference, product, quotient| {
+            let s = x.clone() + y.clone();
+            assert!(s.is_valid());
+            assert_eq!(s.to_string(), sum);
+            assert_eq!((&x + y.clone()).to_string(), sum);
+            assert_eq!((x.clone() + &y).to_string(), sum);
+            assert_eq!((&x + &y).to_string(), sum);
+            let mut s = x.clone();
+            s += y.clone();
+            assert_eq!(s.to_string(), sum);
+            let mut s = x.clone();
+            s += &y;
+            assert_eq!(s.to_string(), sum);
+
+            let d = x.clone() - y.clone();
+            assert!(d.is_valid());
+            assert_eq!(d.to_string(), difference);
+            assert_eq!((&x - y.clone()).to_string(), difference);
+            assert_eq!((x.clone() - &y).to_string(), difference);
+            assert_eq!((&x - &y).to_string(), difference);
+            let mut d = x.clone();
+            d -= y.clone();
+            assert_eq!(d.to_string(), difference);
+            let mut d = x.clone();
+            d -= &y;
+            assert_eq!(d.to_string(), difference);
+
+            let p = x.clone() * y.clone();
+            assert!(p.is_valid());
+            assert_eq!(p.to_string(), product);
+            assert_eq!((&x * y.clone()).to_string(), product);
+            assert_eq!((x.clone() * &y).to_string(), product);
+            assert_eq!((&x * &y).to_string(), product);
+            let mut p = x.clone();
+            p *= y.clone();
+            assert_eq!(p.to_string(), product);
+            let mut p = x.clone();
+            p *= &y;
+            assert_eq!(p.to_string(), product);
+
+            let q = x.clone() / y.clone();
+            assert!(q.is_valid());
+            assert_eq!(q.to_string(), quotient);
+            assert_eq!((&x / y.clone()).to_string(), quotient);
+            assert_eq!((x.clone() / &y).to_string(), quotient);
+            assert_eq!((&x / &y).to_string(), quotient);
+            let mut q = x.clone();
+            q /= y.clone();
+            assert_eq!(q.to_string(), quotient);
+            let mut q = x;
+            q /= &y;
+            assert_eq!(q.to_string(), quotient);
+        };
+    let phi = new(1, 1, 2, 5);
+    test(
+        phi.clone(),
+        phi.clone(),
+        "1 + sqrt(5)",
+        "0",
+        "(3 + sqrt(5))/2",
+        "1",
+    );
+    test(
+        phi.clone(),
+        phi.conjugate(),
+        "1",
+        "sqrt(5)",
+        "-1",
+        "(-3 - sqrt(5))/2",
+    );
+    test(
+        new(0, 1, 1, 2),
+        new(1, 0, 2, 0),
+        "(1 + 2*sqrt(2))/2",
+        "(-1 + 2*sqrt(2))/2",
+        "sqrt(2)/2",
+        "2*sqrt(2)",
+    );
+    test(
+        new(1, 0, 3, 0),
+        new(0, 1, 1, 2),
+        "(1 + 3*sqrt(2))/3",
+        "(1 - 3*sqrt(2))/3",
+        "sqrt(2)/3",
+        "sqrt(2)/6",
+    );
+    test(
+        new(3, 2, 1, 7),
+        new(-1, 1, 2, 7),
+        "(5 + 5*sqrt(7))/2",
+        "(7 + 3*sqrt(7))/2",
+        "(11 + sqrt(7))/2",
+        "(17 + 5*sqrt(7))/3",
+    );
+    test(new(0, 1, 1, 3), new(0, 1, 1, 3), "2*sqrt(3)", "0", "3", "1");
+}
+
+#[test]
+#[should_panic]
+fn add_fail() {
+    let _ = new(0, 1, 1, 2) + new(0, 1, 1, 3);
+}
+
+#[test]
+#[should_panic]
+fn sub_fail() {
+    let _ = new(0, 1, 1, 2) - new(0, 1, 1, 3);
+}
+
+#[test]
+#[should_panic]
+fn mul_fail() {
+    let _ = new(0, 1, 1, 2) * new(0, 1, 1, 3);
+}
+
+#[test]
+#[should_panic]
+fn div_fail_1() {
+    let _ = new(0, 1, 1, 2) / new(0, 1, 1, 3);
+}
+
+#[test]
+#[should_panic]
+fn div_fail_2() {
+    let _ = new(0, 1, 1, 2) / QuadraticIrrational::from(Integer::ZERO);
+}
+
+#[test]
+fn test_reciprocal() {
+    let test = |a, b, c, d, out| {
+        let x = new(a, b, c, d);
+        let y = x.clone().reciprocal();
+        assert!(y.is_valid());
+        assert_eq!(y.to_string(), out);
+        let y = (&x).reciprocal();
+        assert!(y.is_valid());
+        assert_eq!(y.to_string(), out);
+    };
+    test(1, 0, 1, 0, "1");
+    test(-2, 0, 3, 0, "-3/2");
+    test(0, 1, 1, 2, "sqrt(2)/2");
+    test(1, 1, 2, 5, "(-1 + sqrt(5))/2");
+    test(1, 1, 1, 2, "-1 + sqrt(2)");
+    test(2, 1, 1, 3, "2 - sqrt(3)");
+    test(3, -1, 1, 7, "(3 + sqrt(7))/2");
+}
+
+#[test]
+#[should_panic]
+fn reciprocal_fail() {
+    QuadraticIrrational::from(Integer::ZERO).reciprocal();
+}
+
+#[test]
+fn test_sign_floor_ceiling() {
+    let test = |a, b, c, d, sign, floor: i64, ceiling: i64| {
+        let x = new(a, b, c, d);
+        assert_eq!(x.sign(), sign);
+        assert_eq!((&x).floor(), floor);
+        assert_eq!(x.clone().floor(), floor);
+        assert_eq!((&x).ceiling(), ceiling);
+        assert_eq!(x.ceiling(), ceiling);
+    };
+    test(0, 0, 1, 0, Equal, 0, 0);
+    test(7, 0, 2, 0, Greater, 3, 4);
+    test(-7, 0, 2, 0, Less, -4, -3);
+    test(0, 1, 1, 2, Greater, 1, 2);
+    test(0, -1, 1, 2, Less, -2, -1);
+    test(1, 1, 2, 5, Greater, 1, 2);
+    test(1, -1, 2, 5, Less, -1, 0);
+    test(3, -1, 1, 7, Greater, 0, 1);
+    test(-3, 1, 1, 7, Less, -1, 0);
+    test(-3, 1, 1, 8, Less, -1, 0);
+    test(-3, 1, 1, 10, Greater, 0, 1);
+    test(-99, 70, 1, 2, Less, -1, 0);
+    test(99, -70, 1, 2, Greater, 0, 1);
+    test(1000, 1, 7, 1000001, Greater, 285, 286);
+}
+
+#[test]
+fn neg_properties() {
+    quadratic_irrational_gen().test_properties(|x| {
+        let y = -&x;
+        assert!(y.is_valid());
+        assert_eq!(-x.clone(), y);
+        assert_eq!(-&y, x);
+        assert_eq!(&x + &y, Rational::ZERO);
+        assert_eq!(y.sign(), x.sign().reverse());
+        assert_eq!(y == x, x == Rational::ZERO);
+        assert_eq!(-RealAlgebraic::from(&x), RealAlgebraic::from(y));
+    });
+}
+
+#[test]
+fn add_sub_properties() {
+    quadratic_irrational_pair_gen_var_1().test_properties(|(x, y)| {
+        let sum = &x + &y;
+        assert!(sum.is_valid());
+        assert_eq!(x.clone() + y.clone(), sum);
+        assert_eq!(&y + &x, sum);
+        let mut mut_x = x.clone();
+        mut_x += &y;
+        assert_eq!(mut_x, sum);
+        assert_eq!(&sum - &y, x);
+
+        let difference = &x - &y;
+        assert!(difference.is_valid());
+        assert_eq!(x.clone() - y.clone(), difference);
+        assert_eq!(-(&y - &x), difference);
+        let mut mut_x = x.clone();
+        mut_x -= &y;
+        assert_eq!(mut_x, difference);
+        assert_eq!(&difference + &y, x);
+        assert_eq!(&x + -&y, difference);
+
+        assert_eq!(sum.conjugate(), x.conjugate() + y.conjugate());
+    });
+
+    quadratic_irrational_gen().test_properties(|x| {
+        assert_eq!(&x + QuadraticIrrational::from(Integer::ZERO), x);
+        assert_eq!(&x - &x, Rational::ZERO);
+    });
+
+    quadratic_irrational_triple_gen_var_1().test_properties(|(x, y, z)| {
+        assert_eq!((&x + &y) + &z, x + (y + z));
+    });
+}
+
+#[test]
+fn mul_properties() {
+    quadratic_irrational_pair_gen_var_1().test_properties(|(x, y)| {
+        let product = &x * &y;
+        assert!(product.is_valid());
+        assert_eq!(x.clone() * y.clone(), product);
+        assert_eq!(&y * &x, product);
+        let mut mut_x = x.clone();
+        mut_x *= &y;
+        assert_eq!(mut_x, product);
+        let sign = if x.sign() == Equal || y.sign() == Equal {
+            Equal
+        } else if x.sign() == y.sign() {
+            Greater
+        } else {
+            Less
+        };
+        assert_eq!(product.sign(), sign);
+        assert_eq!(product.conjugate(), x.conjugate() * y.conjugate());
+        assert_eq!(product.norm(), x.norm() * y.norm());
+    });
+
+    quadratic_irrational_gen().test_properties(|x| {
+        assert_eq!(&x * QuadraticIrrational::from(Integer::ONE), x);
+        assert_eq!(
+            &x * QuadraticIrrational::from(Integer::ZERO),
+            Rational::ZERO
+        );
+        assert_eq!(&x * QuadraticIrrational::from(Integer::NEGATIVE_ONE), -&x);
+    });
+
+    quadratic_irrational_triple_gen_var_1().test_properties(|(x, y, z)| {
+        assert_eq!((&x * &y) * &z, &x * (&y * &z));
+        assert_eq!(&x * (&y + &z), &x * &y + &x * &z);
+    });
+}
+
+#[test]
+fn div_properties() {
+    quadratic_irrational_pair_gen_var_2().test_properties(|(x, y)| {
+        let quotient = &x / &y;
+        assert!(quotient.is_valid());
+        assert_eq!(x.clone() / y.clone(), quotient);
+        let mut mut_x = x.clone();
+        mut_x /= &y;
+        assert_eq!(mut_x, quotient);
+        assert_eq!(&quotient * &y, x);
+        assert_eq!(&x * (&y).reciprocal(), quotient);
+    });
+}
+
+#[test]
+fn reciprocal_properties() {
+    quadratic_irrational_gen_var_1().test_properties(|x| {
+        let y = (&x).reciprocal();
+        assert!(y.is_valid());
+        assert_eq!(x.clone().reciprocal(), y);
+        assert_eq!((&y).reciprocal(), x);
+        assert_eq!(&x * &y, Rational::ONE);
+        assert_eq!(y.sign(), x.sign());
+    });
+}
+
+#[test]
+fn sign_floor_ceiling_properties() {
+    quadratic_irrational_gen().test_properties(|x| {
+        let sign = x.sign();
+        assert_eq!(sign, RealAlgebraic::from(&x).sign());
+        assert_eq!(x.partial_cmp(&Rational::ZERO), Some(sign));
+
+        let floor = (&x).floor();
+        assert_eq!(x.clone().floor(), floor);
+        assert!(QuadraticIrrational::from(&floor) <= x);
+        assert!(QuadraticIrrational::from(&floor + Integer::ONE) > x);
+        assert_eq!(floor, RealAlgebraic::from(&x).floor());
+
+        let ceiling = (&x).ceiling();
+        assert_eq!(x.clone().ceiling(), ceiling);
+        assert!(QuadraticIrrational::from(&ceiling) >= x);
+        assert!(QuadraticIrrational::from(&ceiling - Integer::ONE) < x);
+        assert_eq!(ceiling, -(-&x).floor());
+        if x.is_rational() {
+            assert!(ceiling - floor <= 1u32);
+        } else {
+            assert_eq!(ceiling - floor, 1u32);
+        }
+    });
+}
@@ -0,0 +1,132 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::Square;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_poly::quadratic_irrational::QuadraticIrrational;
+use malachite_poly::test_util::generators::quadratic_irrational_gen;
+use malachite_q::Rational;
+
+fn new(a: i64, b: i64, c: u64, d: u64) -> QuadraticIrrational {
+    QuadraticIrrational::new(
+        Integer::from(a),
+        Integer::from(b),
+        Natural::from(c),
+        Natural::from(d),
+    )
+}
+
+#[test]
+fn test_new() {
+    let test = |a, b, c, d, out_a, out_b, out_c, out_d, out| {
+        let x = new(a, b, c, d);
+        assert!(x.is_valid());
+        assert_eq!(*x.a(), out_a);
+        assert_eq!(*x.b(), out_b);
+        assert_eq!(*x.c(), out_c);
+        assert_eq!(*x.d(), out_d);
+        assert_eq!(x.to_string(), out);
+    };
+    test(0, 0, 1, 0, 0, 0, 1, 0, "0");
+    test(3, 5, 6, 0, 1, 0, 2, 0, "1/2");
+    test(3, 5, 6, 1, 4, 0, 3, 0, "4/3");
+    test(1, 1, 2, 5, 1, 1, 2, 5, "(1 + sqrt(5))/2");
+    test(2, 2, 4, 12, 1, 2, 2, 3, "(1 + 2*sqrt(3))/2");
+    test(1, -3, 2, 9, -4, 0, 1, 0, "-4");
+    test(0, 3, 6, 8, 0, 1, 1, 2, "sqrt(2)");
+    test(4, 6, 2, 18, 2, 9, 1, 2, "2 + 9*sqrt(2)");
+    test(-6, 0, 4, 7, -3, 0, 2, 0, "-3/2");
+}
+
+#[allow(clippy::no_effect, unused_must_use)]
+#[test]
+#[should_panic]
+fn new_fail() {
+    new(1, 1, 0, 2);
+}
+
+#[test]
+fn test_sqrt_of() {
+    let test = |d: u64, out| {
+        let x = QuadraticIrrational::sqrt_of(Natural::from(d));
+        assert!(x.is_valid());
+        assert_eq!(x.to_string(), out);
+    };
+    test(0, "0");
+    test(1, "1");
+    test(2, "sqrt(2)");
+    test(4, "2");
+    test(8, "2*sqrt(2)");
+    test(12, "2*sqrt(3)");
+    test(30, "sqrt(30)");
+    test(1000000, "1000");
+}
+
+#[test]
+fn test_conjugate_norm_and_trace() {
+    let test = |a, b, c, d, conjugate, norm, trace| {
+        let x = new(a, b, c, d);
+        let y = x.conjugate();
+        assert!(y.is_valid());
+        assert_eq!(y.to_string(), conjugate);
+        assert_eq!(x.norm().to_string(), norm);
+        assert_eq!(x.trace().to_string(), trace);
+    };
+    test(0, 0, 1, 0, "0", "0", "0");
+    test(-3, 0, 2, 0, "-3/2", "9/4", "-3");
+    test(0, 1, 1, 2, "-sqrt(2)", "-2", "0");
+    test(1, 1, 2, 5, "(1 - sqrt(5))/2", "-1", "1");
+    test(3, -1, 1, 7, "3 + sqrt(7)", "2", "6");
+    test(1, 2, 3, 3, "(1 - 2*sqrt(3))/3", "-11/9", "2/3");
+}
+
+#[test]
+fn new_properties() {
+    quadratic_irrational_gen().test_properties(|x| {
+        assert!(x.is_valid());
+        let y =
+            QuadraticIrrational::new(x.a().clone(), x.b().clone(), x.c().clone(), x.d().clone());
+        assert!(y.is_valid());
+        assert_eq!(y, x);
+
+        // Scaling all coefficients, or moving a square factor out of the radicand, doesn't change
+        // the value.
+        let y = QuadraticIrrational::new(
+            x.a() * Integer::from(6),
+            x.b() * Integer::from(3),
+            x.c() * Natural::from(6u32),
+            x.d() * Natural::from(4u32),
+        );
+        assert!(y.is_valid());
+        assert_eq!(y, x);
+        assert_eq!(x.is_rational(), *x.b() == 0u32);
+    });
+}
+
+#[test]
+fn conjugate_norm_and_trace_properties() {
+    quadratic_irrational_gen().test_properties(|x| {
+        let y = x.conjugate();
+        assert!(y.is_valid());
+        assert_eq!(y.conjugate(), x);
+        assert_eq!(y.is_rational(), x.is_rational());
+        if x.is_rational() {
+            assert_eq!(y, x);
+        }
+        assert_eq!(&x * &y, x.norm());
+        assert_eq!(&x + &y, x.trace());
+        assert_eq!(y.norm(), x.norm());
+        assert_eq!(y.trace(), x.trace());
+        assert_eq!(
+            x.trace().square() - (x.norm() << 2u32),
+            Rational::from(x.b().square() * Integer::from(x.d())) / Rational::from(x.c().square())
+                * Rational::from(4)
+        );
+    });
+}
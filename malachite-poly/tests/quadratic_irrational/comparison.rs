@@ -0,0 +1,104 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::Sign;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_poly::quadratic_irrational::QuadraticIrrational;
+use malachite_poly::real_algebraic::RealAlgebraic;
+use malachite_poly::test_util::generators::{
+    quadratic_irrational_pair_gen, quadratic_irrational_pair_gen_var_1,
+    quadratic_irrational_rational_pair_gen,
+};
+use malachite_q::Rational;
+use std::cmp::Ordering::{self, *};
+use std::str::FromStr;
+
+fn new(a: i64, b: i64, c: u64, d: u64) -> QuadraticIrrational {
+    QuadraticIrrational::new(
+        Integer::from(a),
+        Integer::from(b),
+        Natural::from(c),
+        Natural::from(d),
+    )
+}
+
+#[test]
+fn test_cmp() {
+    let test = |x: QuadraticIrrational, y: QuadraticIrrational, out: Ordering| {
+        assert_eq!(x.cmp(&y), out);
+        assert_eq!(y.cmp(&x), out.reverse());
+        assert_eq!(x.partial_cmp(&y), Some(out));
+        assert_eq!(x == y, out == Equal);
+    };
+    test(new(0, 0, 1, 0), new(0, 0, 1, 0), Equal);
+    test(new(1, 0, 2, 0), new(2, 0, 3, 0), Less);
+    test(new(0, 1, 1, 2), new(3, 0, 2, 0), Less);
+    test(new(0, 1, 1, 2), new(7, 0, 5, 0), Greater);
+    test(new(1, 1, 2, 5), new(0, 1, 1, 2), Greater);
+    test(new(0, 1, 1, 2), new(0, 1, 1, 3), Less);
+    test(new(0, 1, 1, 2) + new(0, 1, 1, 2), new(0, 1, 1, 8), Equal);
+    test(new(0, 1, 1, 5), new(0, 1, 1, 6), Less);
+    test(new(1, 1, 1, 2), new(0, 1, 1, 6), Less);
+    test(new(3, 1, 1, 2), new(2, 1, 1, 6), Less);
+    test(new(-1, 1, 1, 2), new(-1, 1, 1, 3), Less);
+    test(new(99, 0, 70, 0), new(0, 1, 1, 2), Greater);
+    test(new(140, 0, 99, 0), new(0, 1, 1, 2), Less);
+    test(new(0, -1, 1, 2), new(0, -1, 1, 3), Greater);
+    test(new(5, -1, 1, 2), new(5, -1, 1, 3), Greater);
+}
+
+#[test]
+fn test_partial_cmp_rational() {
+    let test = |x: QuadraticIrrational, y: &str, out: Ordering| {
+        let y = Rational::from_str(y).unwrap();
+        assert_eq!(x.partial_cmp(&y), Some(out));
+        assert_eq!(y.partial_cmp(&x), Some(out.reverse()));
+        assert_eq!(x == y, out == Equal);
+        assert_eq!(y == x, out == Equal);
+    };
+    test(new(0, 0, 1, 0), "0", Equal);
+    test(new(-1, 0, 3, 0), "-1/3", Equal);
+    test(new(-1, 0, 3, 0), "1/3", Less);
+    test(new(0, 1, 1, 2), "3/2", Less);
+    test(new(0, 1, 1, 2), "7/5", Greater);
+    test(new(0, 1, 1, 2), "-2", Greater);
+    test(new(1, 1, 2, 5), "1618/1000", Greater);
+    test(new(1, 1, 2, 5), "1619/1000", Less);
+    test(new(1, -1, 2, 5), "-618/1000", Less);
+    test(new(1, -1, 2, 5), "-617/1000", Less);
+    test(new(1, -1, 2, 5), "-619/1000", Greater);
+}
+
+#[test]
+fn cmp_properties() {
+    quadratic_irrational_pair_gen().test_properties(|(x, y)| {
+        let ord = x.cmp(&y);
+        assert_eq!(y.cmp(&x), ord.reverse());
+        assert_eq!(x.partial_cmp(&y), Some(ord));
+        assert_eq!(x == y, ord == Equal);
+        assert_eq!((-&y).cmp(&-&x), ord);
+        assert_eq!(RealAlgebraic::from(&x).cmp(&RealAlgebraic::from(&y)), ord);
+    });
+
+    quadratic_irrational_pair_gen_var_1().test_properties(|(x, y)| {
+        assert_eq!(x.cmp(&y), (&x - &y).sign());
+    });
+}
+
+#[test]
+fn partial_cmp_rational_properties() {
+    quadratic_irrational_rational_pair_gen().test_properties(|(x, y)| {
+        let ord = x.partial_cmp(&y);
+        assert_eq!(y.partial_cmp(&x), ord.map(Ordering::reverse));
+        assert_eq!(x == y, ord == Some(Equal));
+        assert_eq!(y == x, ord == Some(Equal));
+        assert_eq!(Some(x.cmp(&QuadraticIrrational::from(&y))), ord);
+        assert_eq!(RealAlgebraic::from(&x).partial_cmp(&y), ord);
+    });
+}
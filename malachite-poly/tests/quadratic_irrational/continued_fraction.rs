@@ -0,0 +1,113 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::Floor;
+use malachite_base::rational_sequences::RationalSequence;
+use malachite_base::strings::ToDebugString;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_poly::quadratic_irrational::QuadraticIrrational;
+use malachite_poly::test_util::generators::quadratic_irrational_gen_var_2;
+use malachite_q::conversion::traits::ContinuedFraction;
+use malachite_q::Rational;
+
+fn new(a: i64, b: i64, c: u64, d: u64) -> QuadraticIrrational {
+    QuadraticIrrational::new(
+        Integer::from(a),
+        Integer::from(b),
+        Natural::from(c),
+        Natural::from(d),
+    )
+}
+
+fn sequence(non_repeating: &[u64], repeating: &[u64]) -> RationalSequence<Natural> {
+    RationalSequence::from_vecs(
+        non_repeating.iter().copied().map(Natural::from).collect(),
+        repeating.iter().copied().map(Natural::from).collect(),
+    )
+}
+
+#[test]
+fn test_continued_fraction() {
+    let test = |x: QuadraticIrrational, out| {
+        assert_eq!(x.continued_fraction().to_debug_string(), out);
+    };
+    test(new(0, 0, 1, 0), "(0, [])");
+    test(new(22, 0, 7, 0), "(3, [7])");
+    test(new(-22, 0, 7, 0), "(-4, [1, 6])");
+    test(new(0, 1, 1, 2), "(1, [[2]])");
+    test(new(0, 1, 1, 3), "(1, [[1, 2]])");
+    test(new(0, 1, 1, 7), "(2, [[1, 1, 1, 4]])");
+    test(new(0, 1, 1, 13), "(3, [[1, 1, 1, 1, 6]])");
+    test(new(0, 1, 1, 31), "(5, [[1, 1, 3, 5, 3, 1, 1, 10]])");
+    test(new(0, -1, 1, 2), "(-2, [1, 1, [2]])");
+    test(new(1, 1, 2, 5), "(1, [[1]])");
+    test(new(1, -1, 2, 5), "(-1, [2, [1]])");
+    test(new(1, 1, 3, 2), "(0, [1, [4, 8]])");
+    test(new(0, 1, 2, 3), "(0, [1, [6, 2]])");
+    test(new(-11, 1, 10, 21), "(-1, [2, [1, 3]])");
+}
+
+#[test]
+fn test_from_continued_fraction() {
+    let test = |floor: i64, non_repeating: &[u64], repeating: &[u64], out| {
+        let floor = Integer::from(floor);
+        let xs = sequence(non_repeating, repeating);
+        let x = QuadraticIrrational::from_continued_fraction_ref(&floor, &xs);
+        assert!(x.is_valid());
+        assert_eq!(x.to_string(), out);
+        let x = QuadraticIrrational::from_continued_fraction(floor, xs);
+        assert!(x.is_valid());
+        assert_eq!(x.to_string(), out);
+    };
+    test(0, &[], &[], "0");
+    test(3, &[7], &[], "22/7");
+    test(-4, &[1, 6], &[], "-22/7");
+    test(1, &[], &[2], "sqrt(2)");
+    test(1, &[], &[1], "(1 + sqrt(5))/2");
+    test(2, &[], &[1, 1, 1, 4], "sqrt(7)");
+    test(-2, &[1, 1], &[2], "-sqrt(2)");
+    test(0, &[1], &[4, 8], "(1 + sqrt(2))/3");
+    test(-1, &[2], &[1, 3], "(-11 + sqrt(21))/10");
+    // A non-minimal period gives the same result
+    test(1, &[], &[2, 2, 2], "sqrt(2)");
+    test(1, &[2, 2], &[2, 2], "sqrt(2)");
+}
+
+#[test]
+#[should_panic]
+fn from_continued_fraction_fail_1() {
+    QuadraticIrrational::from_continued_fraction(Integer::from(1), sequence(&[0], &[2]));
+}
+
+#[test]
+#[should_panic]
+fn from_continued_fraction_fail_2() {
+    QuadraticIrrational::from_continued_fraction(Integer::from(1), sequence(&[], &[2, 0]));
+}
+
+#[test]
+fn continued_fraction_properties() {
+    quadratic_irrational_gen_var_2().test_properties(|x| {
+        let (floor, xs) = x.continued_fraction();
+        assert_eq!(floor, (&x).floor());
+        let (non_repeating, repeating) = xs.slices_ref();
+        assert!(non_repeating.iter().chain(repeating).all(|x| *x != 0u32));
+        assert_eq!(repeating.is_empty(), x.is_rational());
+        if let Ok(q) = Rational::try_from(&x) {
+            let (q_floor, q_xs) = q.continued_fraction();
+            assert_eq!(q_floor, floor);
+            assert_eq!(q_xs.collect::<Vec<_>>(), non_repeating);
+        }
+        assert_eq!(
+            QuadraticIrrational::from_continued_fraction_ref(&floor, &xs),
+            x
+        );
+        assert_eq!(QuadraticIrrational::from_continued_fraction(floor, xs), x);
+    });
+}
@@ -0,0 +1,159 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::basic::traits::Zero;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_poly::quadratic_irrational::QuadraticIrrational;
+use malachite_poly::real_algebraic::RealAlgebraic;
+use malachite_poly::test_util::generators::{
+    quadratic_irrational_gen, quadratic_irrational_rational_pair_gen,
+};
+use malachite_q::Rational;
+use std::str::FromStr;
+
+fn new(a: i64, b: i64, c: u64, d: u64) -> QuadraticIrrational {
+    QuadraticIrrational::new(
+        Integer::from(a),
+        Integer::from(b),
+        Natural::from(c),
+        Natural::from(d),
+    )
+}
+
+#[test]
+fn test_from_rational() {
+    let test = |s, out| {
+        let x = Rational::from_str(s).unwrap();
+        let y = QuadraticIrrational::from(x.clone());
+        assert!(y.is_valid());
+        assert!(y.is_rational());
+        assert_eq!(y.to_string(), out);
+        let y = QuadraticIrrational::from(&x);
+        assert!(y.is_valid());
+        assert_eq!(y.to_string(), out);
+    };
+    test("0", "0");
+    test("5", "5");
+    test("-22/7", "-22/7");
+}
+
+#[test]
+fn test_from_integer() {
+    let test = |n: i64| {
+        let x = Integer::from(n);
+        let y = QuadraticIrrational::from(x.clone());
+        assert!(y.is_valid());
+        assert_eq!(y.to_string(), n.to_string());
+        assert_eq!(QuadraticIrrational::from(&x), y);
+    };
+    test(0);
+    test(1);
+    test(-100);
+}
+
+#[test]
+fn test_try_from_quadratic_irrational() {
+    let test = |x: QuadraticIrrational, out| {
+        let y = Rational::try_from(&x);
+        assert_eq!(y.as_ref().map(ToString::to_string).ok(), out);
+        assert_eq!(Rational::try_from(x), y);
+    };
+    test(new(0, 0, 1, 0), Some("0".to_string()));
+    test(new(-3, 0, 4, 0), Some("-3/4".to_string()));
+    test(new(1, 1, 2, 9), Some("2".to_string()));
+    test(new(0, 1, 1, 2), None);
+    test(new(1, 1, 2, 5), None);
+}
+
+#[test]
+fn test_to_real_algebraic() {
+    let test = |x: QuadraticIrrational, out| {
+        let y = RealAlgebraic::from(&x);
+        assert!(y.is_valid());
+        assert_eq!(y.to_string(), out);
+        assert_eq!(RealAlgebraic::from(x), y);
+    };
+    test(new(0, 0, 1, 0), "0");
+    test(new(-3, 0, 4, 0), "-3/4");
+    test(new(0, 1, 1, 2), "root 1 of x^2 - 2");
+    test(new(0, -1, 1, 2), "root 0 of x^2 - 2");
+    test(new(1, 1, 2, 5), "root 1 of x^2 - x - 1");
+    test(new(1, -1, 2, 5), "root 0 of x^2 - x - 1");
+    test(new(3, -1, 1, 7), "root 0 of x^2 - 6*x + 2");
+    test(new(1, 2, 3, 3), "root 1 of 9*x^2 - 6*x - 11");
+}
+
+#[test]
+fn test_to_string() {
+    let test = |x: QuadraticIrrational, out| {
+        assert_eq!(x.to_string(), out);
+        assert_eq!(format!("{x:?}"), out);
+    };
+    test(new(0, 0, 1, 0), "0");
+    test(new(-22, 0, 7, 0), "-22/7");
+    test(new(0, 1, 1, 2), "sqrt(2)");
+    test(new(0, -1, 1, 2), "-sqrt(2)");
+    test(new(3, -1, 1, 2), "3 - sqrt(2)");
+    test(new(-3, 1, 1, 2), "-3 + sqrt(2)");
+    test(new(0, 3, 2, 5), "3*sqrt(5)/2");
+    test(new(0, -3, 2, 5), "-3*sqrt(5)/2");
+    test(new(1, 1, 2, 5), "(1 + sqrt(5))/2");
+    test(new(-1, -2, 3, 7), "(-1 - 2*sqrt(7))/3");
+}
+
+#[test]
+fn from_rational_properties() {
+    quadratic_irrational_rational_pair_gen().test_properties(|(_, x)| {
+        let y = QuadraticIrrational::from(&x);
+        assert!(y.is_valid());
+        assert_eq!(QuadraticIrrational::from(x.clone()), y);
+        assert_eq!(y, x);
+        assert_eq!(y.to_string(), x.to_string());
+        assert_eq!(Rational::try_from(y), Ok(x));
+    });
+}
+
+#[test]
+fn try_from_quadratic_irrational_properties() {
+    quadratic_irrational_gen().test_properties(|x| {
+        let y = Rational::try_from(&x);
+        assert_eq!(Rational::try_from(x.clone()), y);
+        assert_eq!(y.is_ok(), x.is_rational());
+        if let Ok(y) = y {
+            assert_eq!(QuadraticIrrational::from(y), x);
+        }
+    });
+}
+
+#[test]
+fn to_real_algebraic_properties() {
+    quadratic_irrational_gen().test_properties(|x| {
+        let y = RealAlgebraic::from(&x);
+        assert!(y.is_valid());
+        assert_eq!(RealAlgebraic::from(x.clone()), y);
+        assert_eq!(y.is_rational(), x.is_rational());
+        assert_eq!(y.degree(), if x.is_rational() { 1 } else { 2 });
+        if !x.is_rational() {
+            assert_eq!(
+                RealAlgebraic::from(x.conjugate()).minimal_polynomial(),
+                y.minimal_polynomial()
+            );
+        }
+        assert_eq!(y == Rational::ZERO, x == Rational::ZERO);
+    });
+}
+
+#[test]
+fn to_string_properties() {
+    quadratic_irrational_gen().test_properties(|x| {
+        let s = x.to_string();
+        assert_eq!(format!("{x:?}"), s);
+        assert_eq!(s.contains("sqrt"), !x.is_rational());
+    });
+}
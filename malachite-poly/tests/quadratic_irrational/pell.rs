@@ -0,0 +1,127 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{FloorSqrt, Parity, Square};
+use malachite_base::strings::ToDebugString;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_poly::quadratic_irrational::pell::{
+    negative_pell_fundamental_solution, pell_fundamental_solution, pell_solutions,
+};
+use malachite_poly::quadratic_irrational::QuadraticIrrational;
+use malachite_poly::test_util::generators::natural_gen_var_1;
+
+#[test]
+fn test_pell_fundamental_solution() {
+    let test = |d: u64, out| {
+        assert_eq!(
+            pell_fundamental_solution(&Natural::from(d)).to_debug_string(),
+            out
+        );
+    };
+    test(0, "None");
+    test(1, "None");
+    test(2, "Some((3, 2))");
+    test(3, "Some((2, 1))");
+    test(4, "None");
+    test(5, "Some((9, 4))");
+    test(7, "Some((8, 3))");
+    test(13, "Some((649, 180))");
+    test(61, "Some((1766319049, 226153980))");
+    test(109, "Some((158070671986249, 15140424455100))");
+}
+
+#[test]
+fn test_negative_pell_fundamental_solution() {
+    let test = |d: u64, out| {
+        assert_eq!(
+            negative_pell_fundamental_solution(&Natural::from(d)).to_debug_string(),
+            out
+        );
+    };
+    test(0, "None");
+    test(1, "None");
+    test(2, "Some((1, 1))");
+    test(3, "None");
+    test(5, "Some((2, 1))");
+    test(13, "Some((18, 5))");
+    test(34, "None");
+    test(61, "Some((29718, 3805))");
+}
+
+#[test]
+fn test_pell_solutions() {
+    let test = |d: u64, out| {
+        assert_eq!(
+            pell_solutions(&Natural::from(d))
+                .take(4)
+                .collect::<Vec<_>>()
+                .to_debug_string(),
+            out
+        );
+    };
+    test(0, "[]");
+    test(9, "[]");
+    test(2, "[(3, 2), (17, 12), (99, 70), (577, 408)]");
+    test(3, "[(2, 1), (7, 4), (26, 15), (97, 56)]");
+    test(5, "[(9, 4), (161, 72), (2889, 1292), (51841, 23184)]");
+}
+
+#[test]
+fn pell_properties() {
+    natural_gen_var_1().test_properties(|d| {
+        let solution = pell_fundamental_solution(&d);
+        let square = (&d).floor_sqrt().square() == d;
+        assert_eq!(solution.is_none(), square);
+        let negative_solution = negative_pell_fundamental_solution(&d);
+        if square {
+            assert!(negative_solution.is_none());
+            assert_eq!(pell_solutions(&d).next(), None);
+            return;
+        }
+        let (x, y) = solution.unwrap();
+        assert_eq!((&x).square(), &d * (&y).square() + Natural::from(1u32));
+
+        // The fundamental solution is the square of the fundamental unit of norm -1, if it exists
+        let (_, cf) = QuadraticIrrational::sqrt_of(d.clone()).continued_fraction();
+        assert_eq!(negative_solution.is_some(), cf.slices_ref().1.len().odd());
+        if let Some((u, v)) = negative_solution {
+            assert_eq!((&u).square() + Natural::from(1u32), &d * (&v).square());
+            assert_eq!(x, (&u).square() + &d * (&v).square());
+            assert_eq!(y, (u * v) << 1u32);
+        }
+
+        let mut previous = Natural::from(1u32);
+        let unit = QuadraticIrrational::new(
+            Integer::from(&x),
+            Integer::from(&y),
+            Natural::from(1u32),
+            d.clone(),
+        );
+        let mut power = unit.clone();
+        for (i, (x_i, y_i)) in pell_solutions(&d).take(5).enumerate() {
+            assert!(x_i > previous);
+            assert_eq!((&x_i).square(), &d * (&y_i).square() + Natural::from(1u32));
+            if i == 0 {
+                assert_eq!((&x_i, &y_i), (&x, &y));
+            } else {
+                power *= &unit;
+            }
+            assert_eq!(
+                power,
+                QuadraticIrrational::new(
+                    Integer::from(&x_i),
+                    Integer::from(&y_i),
+                    Natural::from(1u32),
+                    d.clone()
+                )
+            );
+            previous = x_i;
+        }
+    });
+}
@@ -0,0 +1,345 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{Ceiling, Floor, Reciprocal, Sign};
+use malachite_base::test_util::generators::common::GenConfig;
+use malachite_base::vecs::vec_from_str;
+use malachite_nz::integer::Integer;
+use malachite_poly::real_algebraic::RealAlgebraic;
+use malachite_poly::test_util::generators::{
+    real_algebraic_gen, real_algebraic_gen_var_1, real_algebraic_pair_gen,
+    real_algebraic_pair_gen_var_1, real_algebraic_rational_pair_gen,
+};
+use malachite_poly::Polynomial;
+use malachite_q::Rational;
+use std::cmp::Ordering::*;
+use std::str::FromStr;
+
+fn root(s: &str, k: usize) -> RealAlgebraic {
+    Polynomial::<Integer>::from_owned_coefficients_asc(vec_from_str(s).unwrap())
+        .real_roots()
+        .swap_remove(k)
+}
+
+fn rational(s: &str) -> RealAlgebraic {
+    RealAlgebraic::from(Rational::from_str(s).unwrap())
+}
+
+const SQRT_2: (&str, usize) = ("[-2, 0, 1]", 1);
+const SQRT_3: (&str, usize) = ("[-3, 0, 1]", 1);
+const CBRT_2: (&str, usize) = ("[-2, 0, 0, 1]", 0);
+const GOLDEN_RATIO: (&str, usize) = ("[-1, -1, 1]", 1);
+
+fn named(x: (&str, usize)) -> RealAlgebraic {
+    root(x.0, x.1)
+}
+
+#[test]
+fn test_neg() {
+    let test = |x: RealAlgebraic, out| {
+        let neg = -&x;
+        assert!(neg.is_valid());
+        assert_eq!(neg.to_string(), out);
+        let neg_alt = -x.clone();
+        assert!(neg_alt.is_valid());
+        assert_eq!(neg_alt, neg);
+        assert_eq!(-neg, x);
+    };
+    test(rational("0"), "0");
+    test(rational("-22/7"), "22/7");
+    test(named(SQRT_2), "root 0 of x^2 - 2");
+    test(named(CBRT_2), "root 0 of x^3 + 2");
+    test(named(GOLDEN_RATIO), "root 0 of x^2 + x - 1");
+}
+
+fn test_binary_op(
+    f: &dyn Fn(&RealAlgebraic, &RealAlgebraic) -> RealAlgebraic,
+    g: &dyn Fn(RealAlgebraic, RealAlgebraic) -> RealAlgebraic,
+    h: &dyn Fn(&mut RealAlgebraic, RealAlgebraic),
+    x: &RealAlgebraic,
+    y: &RealAlgebraic,
+    out: &str,
+) {
+    let z = f(x, y);
+    assert!(z.is_valid());
+    assert_eq!(z.to_string(), out);
+    let z_alt = g(x.clone(), y.clone());
+    assert!(z_alt.is_valid());
+    assert_eq!(z_alt, z);
+    let mut z_alt = x.clone();
+    h(&mut z_alt, y.clone());
+    assert!(z_alt.is_valid());
+    assert_eq!(z_alt, z);
+}
+
+#[test]
+fn test_add() {
+    let test = |x: RealAlgebraic, y: RealAlgebraic, out| {
+        test_binary_op(&|x, y| x + y, &|x, y| x + y, &|x, y| *x += y, &x, &y, out);
+    };
+    test(rational("1/2"), rational("1/3"), "5/6");
+    test(named(SQRT_2), rational("0"), "root 1 of x^2 - 2");
+    test(named(SQRT_2), rational("1"), "root 1 of x^2 - 2*x - 1");
+    test(rational("-1/2"), named(SQRT_2), "root 1 of 4*x^2 + 4*x - 7");
+    test(named(SQRT_2), named(SQRT_2), "root 1 of x^2 - 8");
+    test(named(SQRT_2), -named(SQRT_2), "0");
+    test(named(SQRT_2), named(SQRT_3), "root 3 of x^4 - 10*x^2 + 1");
+    test(named(SQRT_2), -named(SQRT_3), "root 1 of x^4 - 10*x^2 + 1");
+    test(named(GOLDEN_RATIO), -named(GOLDEN_RATIO), "0");
+    test(named(GOLDEN_RATIO), root("[-1, -1, 1]", 0), "1");
+    test(
+        named(SQRT_2),
+        named(CBRT_2),
+        "root 1 of x^6 - 6*x^4 - 4*x^3 + 12*x^2 - 24*x - 4",
+    );
+}
+
+#[test]
+fn test_sub() {
+    let test = |x: RealAlgebraic, y: RealAlgebraic, out| {
+        test_binary_op(&|x, y| x - y, &|x, y| x - y, &|x, y| *x -= y, &x, &y, out);
+    };
+    test(rational("1/2"), rational("1/3"), "1/6");
+    test(named(SQRT_2), rational("1"), "root 1 of x^2 + 2*x - 1");
+    test(named(SQRT_2), named(SQRT_2), "0");
+    test(named(SQRT_3), named(SQRT_2), "root 2 of x^4 - 10*x^2 + 1");
+    test(
+        named(GOLDEN_RATIO),
+        root("[-1, -1, 1]", 0),
+        "root 1 of x^2 - 5",
+    );
+}
+
+#[test]
+fn test_mul() {
+    let test = |x: RealAlgebraic, y: RealAlgebraic, out| {
+        test_binary_op(&|x, y| x * y, &|x, y| x * y, &|x, y| *x *= y, &x, &y, out);
+    };
+    test(rational("1/2"), rational("2/3"), "1/3");
+    test(named(SQRT_2), rational("0"), "0");
+    test(named(SQRT_2), rational("-3"), "root 0 of x^2 - 18");
+    test(rational("1/2"), named(SQRT_2), "root 1 of 2*x^2 - 1");
+    test(named(SQRT_2), named(SQRT_2), "2");
+    test(named(SQRT_2), -named(SQRT_2), "-2");
+    test(named(SQRT_2), named(SQRT_3), "root 1 of x^2 - 6");
+    test(named(CBRT_2), named(CBRT_2), "root 0 of x^3 - 4");
+    test(named(GOLDEN_RATIO), root("[-1, -1, 1]", 0), "-1");
+    test(named(SQRT_2), named(CBRT_2), "root 1 of x^6 - 32");
+}
+
+#[test]
+fn test_div() {
+    let test = |x: RealAlgebraic, y: RealAlgebraic, out| {
+        test_binary_op(&|x, y| x / y, &|x, y| x / y, &|x, y| *x /= y, &x, &y, out);
+    };
+    test(rational("1/2"), rational("2/3"), "3/4");
+    test(rational("0"), named(SQRT_2), "0");
+    test(named(SQRT_2), rational("2"), "root 1 of 2*x^2 - 1");
+    test(rational("1"), named(SQRT_2), "root 1 of 2*x^2 - 1");
+    test(named(SQRT_2), named(SQRT_2), "1");
+    test(named(SQRT_2), named(SQRT_3), "root 1 of 3*x^2 - 2");
+    test(named(CBRT_2), named(SQRT_2), "root 1 of 2*x^6 - 1");
+}
+
+#[test]
+#[should_panic]
+fn div_fail_1() {
+    let _ = named(SQRT_2) / rational("0");
+}
+
+#[test]
+#[should_panic]
+fn div_fail_2() {
+    let _ = &named(SQRT_2) / &(named(SQRT_2) - named(SQRT_2));
+}
+
+#[test]
+#[should_panic]
+fn div_assign_fail() {
+    let mut x = named(SQRT_2);
+    x /= rational("0");
+}
+
+#[test]
+fn test_reciprocal() {
+    let test = |x: RealAlgebraic, out| {
+        let y = (&x).reciprocal();
+        assert!(y.is_valid());
+        assert_eq!(y.to_string(), out);
+        let y_alt = x.clone().reciprocal();
+        assert!(y_alt.is_valid());
+        assert_eq!(y_alt, y);
+        assert_eq!(y.reciprocal(), x);
+    };
+    test(rational("-22/7"), "-7/22");
+    test(named(SQRT_2), "root 1 of 2*x^2 - 1");
+    test(-named(SQRT_2), "root 0 of 2*x^2 - 1");
+    test(named(CBRT_2), "root 0 of 2*x^3 - 1");
+    test(named(GOLDEN_RATIO), "root 1 of x^2 + x - 1");
+}
+
+#[test]
+#[should_panic]
+fn reciprocal_fail() {
+    rational("0").reciprocal();
+}
+
+#[test]
+fn test_sign() {
+    let test = |x: RealAlgebraic, out| {
+        assert_eq!(x.sign(), out);
+    };
+    test(rational("0"), Equal);
+    test(rational("-1/2"), Less);
+    test(named(SQRT_2), Greater);
+    test(-named(SQRT_2), Less);
+    test(root("[1, -3, 0, 1]", 1), Greater);
+}
+
+#[test]
+fn test_floor_and_ceiling() {
+    let test = |x: RealAlgebraic, floor, ceiling| {
+        assert_eq!((&x).floor().to_string(), floor);
+        assert_eq!(x.clone().floor().to_string(), floor);
+        assert_eq!((&x).ceiling().to_string(), ceiling);
+        assert_eq!(x.ceiling().to_string(), ceiling);
+    };
+    test(rational("0"), "0", "0");
+    test(rational("-22/7"), "-4", "-3");
+    test(rational("5"), "5", "5");
+    test(named(SQRT_2), "1", "2");
+    test(-named(SQRT_2), "-2", "-1");
+    test(named(SQRT_2) * rational("1000"), "1414", "1415");
+    test(root("[1, -3, 0, 1]", 0), "-2", "-1");
+    test(root("[1, -3, 0, 1]", 1), "0", "1");
+    test(root("[-1000001, 0, 1]", 1), "1000", "1001");
+}
+
+#[test]
+fn neg_properties() {
+    real_algebraic_gen().test_properties(|x| {
+        let neg = -&x;
+        assert!(neg.is_valid());
+        assert_eq!(-x.clone(), neg);
+        assert_eq!(-&neg, x);
+        assert_eq!(neg.sign(), x.sign().reverse());
+        assert_eq!(neg.degree(), x.degree());
+        assert_eq!(&x + &neg, rational("0"));
+    });
+}
+
+#[test]
+fn add_properties() {
+    let mut config = GenConfig::new();
+    config.insert("mean_bits_n", 8);
+    real_algebraic_pair_gen().test_properties_with_limit(100, |(x, y)| {
+        let sum = &x + &y;
+        assert!(sum.is_valid());
+        assert_eq!(&y + &x, sum);
+        assert_eq!(&sum - &y, x);
+        assert_eq!(-&x + -&y, -&sum);
+        assert!(sum.degree() <= x.degree() * y.degree());
+    });
+
+    real_algebraic_rational_pair_gen().test_properties_with_config(&config, |(x, y)| {
+        let sum = &x + RealAlgebraic::from(&y);
+        assert!(sum.is_valid());
+        assert_eq!(sum.degree(), x.degree());
+        assert_eq!(sum - RealAlgebraic::from(y), x);
+    });
+
+    real_algebraic_gen().test_properties(|x| {
+        assert_eq!(&x + rational("0"), x);
+        assert_eq!(rational("0") + &x, x);
+        assert_eq!(&x - &x, rational("0"));
+        assert_eq!(&x + &x, &x * RealAlgebraic::from(Integer::from(2)));
+    });
+}
+
+#[test]
+fn mul_properties() {
+    let mut config = GenConfig::new();
+    config.insert("mean_bits_n", 8);
+    real_algebraic_pair_gen().test_properties_with_limit(100, |(x, y)| {
+        let product = &x * &y;
+        assert!(product.is_valid());
+        assert_eq!(&y * &x, product);
+        assert_eq!(
+            product.sign() == Equal,
+            x.sign() == Equal || y.sign() == Equal
+        );
+        assert_eq!(-&x * &y, -&product);
+        assert!(product.degree() <= x.degree() * y.degree());
+    });
+
+    real_algebraic_rational_pair_gen().test_properties_with_config(&config, |(x, y)| {
+        let product = &x * RealAlgebraic::from(&y);
+        assert!(product.is_valid());
+        if y != 0u32 {
+            assert_eq!(product.degree(), x.degree());
+            assert_eq!(product / RealAlgebraic::from(y), x);
+        }
+    });
+
+    real_algebraic_gen().test_properties(|x| {
+        assert_eq!(&x * rational("1"), x);
+        assert_eq!(rational("1") * &x, x);
+        assert_eq!(&x * rational("0"), rational("0"));
+    });
+}
+
+#[test]
+fn div_properties() {
+    real_algebraic_pair_gen_var_1().test_properties_with_limit(100, |(x, y)| {
+        let quotient = &x / &y;
+        assert!(quotient.is_valid());
+        assert_eq!(&quotient * &y, x);
+        assert_eq!(&x * (&y).reciprocal(), quotient);
+    });
+
+    real_algebraic_gen_var_1().test_properties(|x| {
+        assert_eq!(&x / rational("1"), x);
+        assert_eq!(&x / &x, rational("1"));
+    });
+}
+
+#[test]
+fn reciprocal_properties() {
+    real_algebraic_gen_var_1().test_properties(|x| {
+        let reciprocal = (&x).reciprocal();
+        assert!(reciprocal.is_valid());
+        assert_eq!(x.clone().reciprocal(), reciprocal);
+        assert_eq!((&reciprocal).reciprocal(), x);
+        assert_eq!(reciprocal.sign(), x.sign());
+        assert_eq!(reciprocal.degree(), x.degree());
+        assert_eq!(&x * reciprocal, rational("1"));
+    });
+}
+
+#[test]
+fn floor_and_ceiling_properties() {
+    let mut config = GenConfig::new();
+    config.insert("mean_bits_n", 8);
+    real_algebraic_gen().test_properties(|x| {
+        let floor = (&x).floor();
+        let ceiling = (&x).ceiling();
+        assert_eq!(x.clone().floor(), floor);
+        assert_eq!(x.clone().ceiling(), ceiling);
+        assert!(RealAlgebraic::from(&floor) <= x);
+        assert!(RealAlgebraic::from(&ceiling) >= x);
+        assert!(&ceiling - &floor <= 1u32);
+        assert_eq!(ceiling == floor, x == RealAlgebraic::from(&floor));
+        assert_eq!((-&x).ceiling(), -floor);
+    });
+
+    real_algebraic_rational_pair_gen().test_properties_with_config(&config, |(_, y)| {
+        let x = RealAlgebraic::from(&y);
+        assert_eq!((&x).floor(), (&y).floor());
+        assert_eq!(x.ceiling(), y.ceiling());
+    });
+}
@@ -0,0 +1,166 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::Sign;
+use malachite_base::strings::ToDebugString;
+use malachite_base::test_util::generators::common::GenConfig;
+use malachite_base::vecs::vec_from_str;
+use malachite_nz::integer::Integer;
+use malachite_poly::real_algebraic::RealAlgebraic;
+use malachite_poly::test_util::generators::{polynomial_integer_gen_var_1, real_algebraic_gen};
+use malachite_poly::Polynomial;
+use malachite_q::Rational;
+use std::cmp::Ordering::*;
+use std::str::FromStr;
+
+fn polynomial(s: &str) -> Polynomial<Integer> {
+    Polynomial::<Integer>::from_owned_coefficients_asc(vec_from_str(s).unwrap())
+}
+
+fn rational_polynomial(p: &Polynomial<Integer>) -> Polynomial<Rational> {
+    Polynomial::<Rational>::from_owned_coefficients_asc(
+        p.coefficients().iter().map(Rational::from).collect(),
+    )
+}
+
+#[test]
+fn test_real_roots() {
+    let test = |s, out| {
+        let roots = polynomial(s).real_roots();
+        assert!(roots.iter().all(RealAlgebraic::is_valid));
+        assert_eq!(roots.to_debug_string(), out);
+    };
+    test("[1]", "[]");
+    test("[-5]", "[]");
+    test("[0, 1]", "[0]");
+    test("[0, 0, 0, 7]", "[0]");
+    test("[-1, 2]", "[1/2]");
+    test("[1, 0, 1]", "[]");
+    test("[-2, 0, 1]", "[root 0 of x^2 - 2, root 1 of x^2 - 2]");
+    test("[-4, 0, 2]", "[root 0 of x^2 - 2, root 1 of x^2 - 2]");
+    test("[4, -4, 1]", "[2]");
+    test("[-6, 11, -6, 1]", "[1, 2, 3]");
+    test(
+        "[-2, -4, 1, 2]",
+        "[root 0 of x^2 - 2, -1/2, root 1 of x^2 - 2]",
+    );
+    test(
+        "[1, -3, 0, 1]",
+        "[root 0 of x^3 - 3*x + 1, root 1 of x^3 - 3*x + 1, root 2 of x^3 - 3*x + 1]",
+    );
+    test("[4, 0, -5, 0, 1]", "[-2, -1, 1, 2]");
+    test(
+        "[2, 0, -3, 0, 1]",
+        "[root 0 of x^2 - 2, -1, 1, root 1 of x^2 - 2]",
+    );
+    test(
+        "[1, 0, -10, 0, 1]",
+        "[root 0 of x^4 - 10*x^2 + 1, root 1 of x^4 - 10*x^2 + 1, root 2 of x^4 - 10*x^2 + 1, \
+        root 3 of x^4 - 10*x^2 + 1]",
+    );
+    test("[-1, -1, 0, 0, 0, 1]", "[root 0 of x^5 - x - 1]");
+    test("[4, 0, -4, 0, 1]", "[root 0 of x^2 - 2, root 1 of x^2 - 2]");
+}
+
+#[test]
+#[should_panic]
+fn real_roots_fail() {
+    Polynomial::<Integer>::zero().real_roots();
+}
+
+#[test]
+fn test_minimal_polynomial() {
+    let test = |s, k: usize, out, degree, rational| {
+        let x = &polynomial(s).real_roots()[k];
+        assert_eq!(x.minimal_polynomial().to_string(), out);
+        assert_eq!(x.degree(), degree);
+        assert_eq!(x.is_rational(), rational);
+    };
+    test("[0, 1]", 0, "x", 1, true);
+    test("[-3, 6]", 0, "2*x - 1", 1, true);
+    test("[-2, -4, 1, 2]", 1, "2*x + 1", 1, true);
+    test("[-2, -4, 1, 2]", 2, "x^2 - 2", 2, false);
+    test("[2, 0, -3, 0, 1]", 0, "x^2 - 2", 2, false);
+    test("[1, 0, -10, 0, 1]", 3, "x^4 - 10*x^2 + 1", 4, false);
+    test("[-1, -1, 0, 0, 0, 1]", 0, "x^5 - x - 1", 5, false);
+}
+
+#[test]
+fn test_refine_isolating_interval() {
+    let test = |s, k: usize, width, out| {
+        let mut x = polynomial(s).real_roots()[k].clone();
+        let old_x = x.clone();
+        let width = Rational::from_str(width).unwrap();
+        x.refine_isolating_interval(&width);
+        assert!(x.is_valid());
+        assert_eq!(x, old_x);
+        let (a, b) = x.isolating_interval();
+        assert!(b - a <= width);
+        assert_eq!(x.isolating_interval().to_debug_string(), out);
+    };
+    test("[-2, 0, 1]", 1, "1", "(1, 2)");
+    test("[-2, 0, 1]", 1, "1/100", "(24/17, 17/12)");
+    test("[-2, 0, 1]", 0, "1/1000000", "(-1970/1393, -1393/985)");
+    test("[-1, 2]", 0, "1/100", "(1/2, 1/2)");
+    test("[-1, -1, 0, 1]", 0, "1/1000", "(49/37, 53/40)");
+}
+
+#[test]
+#[should_panic]
+fn refine_isolating_interval_fail() {
+    polynomial("[-2, 0, 1]").real_roots()[0].refine_isolating_interval(&Rational::from(0));
+}
+
+#[test]
+fn real_roots_properties() {
+    let mut config = GenConfig::new();
+    config.insert("mean_bits_n", 8);
+    config.insert("mean_length_n", 3);
+    polynomial_integer_gen_var_1().test_properties_with_config(&config, |p| {
+        let roots = p.real_roots();
+        assert!(roots.iter().all(RealAlgebraic::is_valid));
+        assert_eq!(roots.len(), p.real_root_isolating_intervals().len());
+        for w in roots.windows(2) {
+            assert!(w[0] < w[1]);
+        }
+        let q = rational_polynomial(&p);
+        for x in &roots {
+            let m = rational_polynomial(x.minimal_polynomial());
+            assert_eq!(&q % &m, Polynomial::zero());
+            let (a, b) = x.isolating_interval();
+            if a == b {
+                assert_eq!(q.evaluate(a).sign(), Equal);
+            } else {
+                assert_ne!(m.evaluate(a).sign(), m.evaluate(b).sign());
+            }
+        }
+        assert_eq!((-&p).real_roots(), roots);
+        assert_eq!((&p * &p).real_roots(), roots);
+    });
+}
+
+#[test]
+fn refine_isolating_interval_properties() {
+    let mut config = GenConfig::new();
+    config.insert("mean_bits_n", 8);
+    config.insert("mean_bits_d", 1);
+    real_algebraic_gen().test_properties_with_config(&config, |x| {
+        for width in [Rational::from(1), Rational::from_signeds(1, 1000)] {
+            let mut y = x.clone();
+            y.refine_isolating_interval(&width);
+            assert!(y.is_valid());
+            assert_eq!(y, x);
+            assert_eq!(y.to_string(), x.to_string());
+            assert_eq!(y.minimal_polynomial(), x.minimal_polynomial());
+            let (a, b) = y.isolating_interval();
+            let (c, d) = x.isolating_interval();
+            assert!(b - a <= width);
+            assert!(c <= a && b <= d);
+        }
+    });
+}
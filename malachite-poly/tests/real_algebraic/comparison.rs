@@ -0,0 +1,158 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::test_util::generators::common::GenConfig;
+use malachite_base::vecs::vec_from_str;
+use malachite_nz::integer::Integer;
+use malachite_poly::real_algebraic::RealAlgebraic;
+use malachite_poly::test_util::generators::{
+    real_algebraic_gen, real_algebraic_pair_gen, real_algebraic_rational_pair_gen,
+    real_algebraic_triple_gen,
+};
+use malachite_poly::Polynomial;
+use malachite_q::Rational;
+use std::cmp::Ordering::{self, *};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
+
+fn root(s: &str, k: usize) -> RealAlgebraic {
+    Polynomial::<Integer>::from_owned_coefficients_asc(vec_from_str(s).unwrap())
+        .real_roots()
+        .swap_remove(k)
+}
+
+fn rational(s: &str) -> RealAlgebraic {
+    RealAlgebraic::from(Rational::from_str(s).unwrap())
+}
+
+fn hash<T: Hash>(x: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    x.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[test]
+fn test_cmp() {
+    let test = |x: RealAlgebraic, y: RealAlgebraic, out: Ordering| {
+        assert_eq!(x.cmp(&y), out);
+        assert_eq!(y.cmp(&x), out.reverse());
+        assert_eq!(x.partial_cmp(&y), Some(out));
+        assert_eq!(x == y, out == Equal);
+        assert_eq!(y == x, out == Equal);
+    };
+    test(rational("0"), rational("0"), Equal);
+    test(rational("1/2"), rational("1/3"), Greater);
+    test(root("[-2, 0, 1]", 1), root("[-2, 0, 1]", 1), Equal);
+    test(root("[-2, 0, 1]", 0), root("[-2, 0, 1]", 1), Less);
+    test(root("[-2, 0, 1]", 1), root("[4, 0, -4, 0, 1]", 1), Equal);
+    test(root("[-2, 0, 1]", 1), root("[-3, 0, 1]", 1), Less);
+    test(root("[-2, 0, 1]", 1), rational("7/5"), Greater);
+    test(root("[-2, 0, 1]", 1), rational("3/2"), Less);
+    test(
+        root("[-2, 0, 1]", 1),
+        root("[-1, -1, 0, 0, 0, 1]", 0),
+        Greater,
+    );
+    // sqrt(1000001) and 1000 + 1/2000 differ by less than 10^-9
+    test(root("[-1000001, 0, 1]", 1), rational("2000001/2000"), Less);
+    test(
+        root("[-1000001, 0, 1]", 1),
+        root("[-4000004, 0, 4]", 1),
+        Equal,
+    );
+    test(
+        root("[-1000001, 0, 1]", 1),
+        root("[-1000002, 0, 1]", 1),
+        Less,
+    );
+}
+
+#[test]
+fn test_partial_cmp_rational() {
+    let test = |x: RealAlgebraic, y: &str, out: Ordering| {
+        let y = Rational::from_str(y).unwrap();
+        assert_eq!(x.partial_cmp(&y), Some(out));
+        assert_eq!(y.partial_cmp(&x), Some(out.reverse()));
+        assert_eq!(x == y, out == Equal);
+        assert_eq!(y == x, out == Equal);
+    };
+    test(rational("0"), "0", Equal);
+    test(rational("-1/2"), "-1/3", Less);
+    test(root("[-2, 0, 1]", 1), "7/5", Greater);
+    test(root("[-2, 0, 1]", 1), "3/2", Less);
+    test(root("[-2, 0, 1]", 0), "-1", Less);
+    test(root("[-2, 0, 1]", 0), "0", Less);
+    test(root("[-2, 0, 1]", 1), "1393/985", Greater);
+    test(root("[-2, 0, 1]", 1), "1970/1393", Less);
+    test(root("[-1000001, 0, 1]", 1), "2000001/2000", Less);
+}
+
+#[test]
+fn test_hash() {
+    assert_eq!(
+        hash(&root("[-2, 0, 1]", 1)),
+        hash(&root("[4, 0, -4, 0, 1]", 1))
+    );
+    assert_eq!(
+        hash(&rational("-1/2")),
+        hash(&(root("[-2, 0, 1]", 0) * rational("0") - rational("1/2")))
+    );
+}
+
+#[test]
+fn cmp_properties() {
+    real_algebraic_pair_gen().test_properties(|(x, y)| {
+        let ord = x.cmp(&y);
+        assert_eq!(y.cmp(&x), ord.reverse());
+        assert_eq!(x.partial_cmp(&y), Some(ord));
+        assert_eq!(x == y, ord == Equal);
+        assert_eq!((-&y).cmp(&-&x), ord);
+        if x == y {
+            assert_eq!(hash(&x), hash(&y));
+        }
+        let x_f = x.to_float_prec(53).0;
+        let y_f = y.to_float_prec(53).0;
+        if x_f < y_f {
+            assert_eq!(ord, Less);
+        } else if x_f > y_f {
+            assert_eq!(ord, Greater);
+        }
+    });
+
+    real_algebraic_gen().test_properties(|x| {
+        assert_eq!(x.cmp(&x), Equal);
+        assert_eq!(x, x.clone());
+        let mut y = x.clone();
+        y.refine_isolating_interval(&Rational::from_signeds(1, 1000));
+        assert_eq!(x.cmp(&y), Equal);
+        assert_eq!(hash(&x), hash(&y));
+    });
+
+    real_algebraic_triple_gen().test_properties(|(x, y, z)| {
+        if x < y && y < z {
+            assert!(x < z);
+        } else if x > y && y > z {
+            assert!(x > z);
+        }
+    });
+}
+
+#[test]
+fn partial_cmp_rational_properties() {
+    let mut config = GenConfig::new();
+    config.insert("mean_bits_n", 8);
+    real_algebraic_rational_pair_gen().test_properties_with_config(&config, |(x, y)| {
+        let ord = x.partial_cmp(&y);
+        assert!(ord.is_some());
+        assert_eq!(y.partial_cmp(&x), ord.map(Ordering::reverse));
+        assert_eq!(x == y, ord == Some(Equal));
+        assert_eq!(y == x, ord == Some(Equal));
+        assert_eq!(ord, x.partial_cmp(&RealAlgebraic::from(&y)));
+    });
+}
@@ -0,0 +1,303 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::Sign;
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_base::strings::ToDebugString;
+use malachite_base::test_util::generators::common::GenConfig;
+use malachite_base::vecs::vec_from_str;
+use malachite_float::Float;
+use malachite_nz::integer::Integer;
+use malachite_poly::real_algebraic::conversion::RationalFromRealAlgebraicError;
+use malachite_poly::real_algebraic::RealAlgebraic;
+use malachite_poly::test_util::generators::{
+    polynomial_integer_gen_var_1, polynomial_integer_integer_pair_gen, real_algebraic_gen,
+    real_algebraic_rational_pair_gen, real_algebraic_unsigned_rounding_mode_triple_gen_var_1,
+};
+use malachite_poly::Polynomial;
+use malachite_q::Rational;
+use std::cmp::Ordering::{self, *};
+use std::str::FromStr;
+
+fn polynomial(s: &str) -> Polynomial<Integer> {
+    Polynomial::<Integer>::from_owned_coefficients_asc(vec_from_str(s).unwrap())
+}
+
+fn root(s: &str, k: usize) -> RealAlgebraic {
+    polynomial(s).real_roots().swap_remove(k)
+}
+
+#[test]
+fn test_from_rational() {
+    let test = |s, out, out_poly| {
+        let x = Rational::from_str(s).unwrap();
+        let y = RealAlgebraic::from(&x);
+        assert!(y.is_valid());
+        assert_eq!(y.to_string(), out);
+        assert_eq!(y.minimal_polynomial().to_string(), out_poly);
+        assert!(y.is_rational());
+        assert_eq!(y.isolating_interval(), (&x, &x));
+        let y_alt = RealAlgebraic::from(x);
+        assert!(y_alt.is_valid());
+        assert_eq!(y_alt, y);
+    };
+    test("0", "0", "x");
+    test("1", "1", "x - 1");
+    test("-5", "-5", "x + 5");
+    test("22/7", "22/7", "7*x - 22");
+    test("-1/1000", "-1/1000", "1000*x + 1");
+}
+
+#[test]
+fn test_from_integer() {
+    let test = |s, out| {
+        let x = Integer::from_str(s).unwrap();
+        let y = RealAlgebraic::from(&x);
+        assert!(y.is_valid());
+        assert_eq!(y.to_string(), out);
+        let y_alt = RealAlgebraic::from(x);
+        assert!(y_alt.is_valid());
+        assert_eq!(y_alt, y);
+    };
+    test("0", "0");
+    test("123", "123");
+    test("-1000000000000", "-1000000000000");
+}
+
+#[test]
+fn test_try_from_real_algebraic() {
+    let test = |x: RealAlgebraic, out| {
+        let q = Rational::try_from(&x);
+        assert_eq!(q.to_debug_string(), out);
+        assert_eq!(Rational::try_from(x), q);
+    };
+    test(
+        RealAlgebraic::from(Rational::from_signeds(22, 7)),
+        "Ok(22/7)",
+    );
+    test(root("[-2, -4, 1, 2]", 1), "Ok(-1/2)");
+    test(root("[-2, 0, 1]", 1), "Err(RationalFromRealAlgebraicError)");
+    test(root("[-2, 0, 1]", 1) * root("[-2, 0, 1]", 1), "Ok(2)");
+    test(
+        root("[-2, 0, 1]", 1) + root("[-3, 0, 1]", 1),
+        "Err(RationalFromRealAlgebraicError)",
+    );
+}
+
+#[test]
+fn test_to_float_prec_round() {
+    let test = |x: RealAlgebraic, prec, rm, out| {
+        let (f, o) = x.to_float_prec_round(prec, rm);
+        assert!(f.is_valid());
+        assert_eq!((f, o).to_debug_string(), out);
+    };
+    let sqrt_2 = root("[-2, 0, 1]", 1);
+    test(sqrt_2.clone(), 1, Floor, "(1.0, Less)");
+    test(sqrt_2.clone(), 1, Ceiling, "(2.0, Greater)");
+    test(sqrt_2.clone(), 10, Down, "(1.414, Less)");
+    test(sqrt_2.clone(), 10, Up, "(1.416, Greater)");
+    test(-&sqrt_2, 10, Down, "(-1.414, Greater)");
+    test(-&sqrt_2, 10, Floor, "(-1.416, Less)");
+    test(sqrt_2.clone(), 53, Nearest, "(1.4142135623730951, Greater)");
+    test(
+        sqrt_2,
+        100,
+        Nearest,
+        "(1.414213562373095048801688724209, Less)",
+    );
+    test(
+        root("[1, -3, 0, 1]", 0),
+        20,
+        Nearest,
+        "(-1.879385, Greater)",
+    );
+    test(root("[1, -3, 0, 1]", 1), 20, Nearest, "(0.3472962, Less)");
+    test(
+        RealAlgebraic::from(Rational::from_signeds(1, 3)),
+        10,
+        Nearest,
+        "(0.3335, Greater)",
+    );
+    test(
+        RealAlgebraic::from(Rational::from_signeds(3, 8)),
+        2,
+        Exact,
+        "(0.4, Equal)",
+    );
+    test(
+        RealAlgebraic::from(Rational::from(0)),
+        10,
+        Exact,
+        "(0.0, Equal)",
+    );
+}
+
+#[test]
+#[should_panic]
+fn to_float_prec_round_fail_1() {
+    root("[-2, 0, 1]", 1).to_float_prec_round(0, Nearest);
+}
+
+#[test]
+#[should_panic]
+fn to_float_prec_round_fail_2() {
+    root("[-2, 0, 1]", 1).to_float_prec_round(100, Exact);
+}
+
+#[test]
+#[should_panic]
+fn to_float_prec_round_fail_3() {
+    RealAlgebraic::from(Rational::from_signeds(1, 3)).to_float_prec_round(100, Exact);
+}
+
+#[test]
+fn test_to_float_prec() {
+    let test = |x: RealAlgebraic, prec, out| {
+        let (f, o) = x.to_float_prec(prec);
+        assert!(f.is_valid());
+        assert_eq!((f.clone(), o).to_debug_string(), out);
+        assert_eq!(x.to_float_prec_round(prec, Nearest), (f, o));
+    };
+    test(root("[-2, 0, 1]", 0), 20, "(-1.414213, Greater)");
+    test(root("[-5, 0, 0, 1]", 0), 30, "(1.709975947, Greater)");
+    test(
+        root("[-1, -1, 0, 0, 0, 1]", 0),
+        100,
+        "(1.167303978261418684256045899856, Greater)",
+    );
+    test(root("[-1, 2]", 0), 1, "(0.5, Equal)");
+}
+
+#[test]
+#[should_panic]
+fn to_float_prec_fail() {
+    root("[-2, 0, 1]", 1).to_float_prec(0);
+}
+
+#[test]
+fn test_to_string() {
+    let test = |x: RealAlgebraic, out| {
+        assert_eq!(x.to_string(), out);
+        assert_eq!(x.to_debug_string(), out);
+    };
+    test(RealAlgebraic::from(Rational::from_signeds(-22, 7)), "-22/7");
+    test(root("[-2, 0, 1]", 0), "root 0 of x^2 - 2");
+    test(root("[-2, 0, 1]", 1), "root 1 of x^2 - 2");
+    test(root("[-2, -4, 1, 2]", 2), "root 1 of x^2 - 2");
+    test(root("[1, -3, 0, 1]", 1), "root 1 of x^3 - 3*x + 1");
+    test(root("[-6, 0, 3]", 0), "root 0 of x^2 - 2");
+    test(root("[-1, -1, 0, 0, 0, 1]", 0), "root 0 of x^5 - x - 1");
+}
+
+fn verify_rounded(x: &RealAlgebraic, f: &Float, o: Ordering, rm: RoundingMode) {
+    let q = Rational::exact_from(f);
+    assert_eq!(q.partial_cmp(x), Some(o));
+    match (rm, x.sign()) {
+        (Floor, _) | (Down, Greater) | (Up, Less) => assert_ne!(o, Greater),
+        (Ceiling, _) | (Up, Greater) | (Down, Less) => assert_ne!(o, Less),
+        _ => {}
+    }
+}
+
+#[test]
+fn to_float_prec_round_properties() {
+    let mut config = GenConfig::new();
+    config.insert("mean_precision_n", 16);
+    real_algebraic_unsigned_rounding_mode_triple_gen_var_1().test_properties_with_config(
+        &config,
+        |(x, prec, rm)| {
+            let (f, o) = x.to_float_prec_round(prec, rm);
+            assert!(f.is_valid());
+            verify_rounded(&x, &f, o, rm);
+            if o == Equal {
+                assert_eq!(x.to_float_prec_round(prec, Exact), (f.clone(), Equal));
+            }
+
+            let (floor, o_floor) = x.to_float_prec_round(prec, Floor);
+            let (ceiling, o_ceiling) = x.to_float_prec_round(prec, Ceiling);
+            assert!(floor <= ceiling);
+            assert_eq!(o_floor == Equal, o_ceiling == Equal);
+            assert!(f == floor || f == ceiling);
+
+            let (neg_f, neg_o) = (-&x).to_float_prec_round(prec, -rm);
+            assert_eq!(neg_f, -f);
+            assert_eq!(neg_o, o.reverse());
+        },
+    );
+}
+
+#[test]
+fn to_float_prec_properties() {
+    let mut config = GenConfig::new();
+    config.insert("mean_bits_n", 8);
+    config.insert("mean_length_n", 3);
+    polynomial_integer_gen_var_1().test_properties_with_config(&config, |p| {
+        let roots = p.real_roots();
+        let floats = p.real_roots_prec(53);
+        assert_eq!(roots.len(), floats.len());
+        for (x, f) in roots.iter().zip(floats) {
+            assert_eq!(x.to_float_prec(53), f);
+        }
+    });
+}
+
+#[test]
+fn from_rational_properties() {
+    real_algebraic_rational_pair_gen().test_properties(|(_, x)| {
+        let y = RealAlgebraic::from(&x);
+        assert!(y.is_valid());
+        assert!(y.is_rational());
+        assert_eq!(y.degree(), 1);
+        assert_eq!(y.to_string(), x.to_string());
+        assert_eq!(y.sign(), x.sign());
+        assert_eq!(RealAlgebraic::from(x.clone()), y);
+        assert_eq!(Rational::try_from(&y), Ok(x.clone()));
+        assert_eq!(Rational::try_from(y), Ok(x));
+    });
+
+    polynomial_integer_integer_pair_gen().test_properties(|(_, x)| {
+        let y = RealAlgebraic::from(&x);
+        assert!(y.is_valid());
+        assert_eq!(RealAlgebraic::from(x.clone()), y);
+        assert_eq!(y, RealAlgebraic::from(Rational::from(x)));
+    });
+}
+
+#[test]
+fn try_from_real_algebraic_properties() {
+    real_algebraic_gen().test_properties(|x| {
+        let q = Rational::try_from(&x);
+        assert_eq!(Rational::try_from(x.clone()), q);
+        assert_eq!(q.is_ok(), x.is_rational());
+        match q {
+            Ok(q) => {
+                assert_eq!(RealAlgebraic::from(q), x);
+            }
+            Err(e) => {
+                assert_eq!(e, RationalFromRealAlgebraicError);
+                assert!(x.degree() > 1);
+            }
+        }
+    });
+}
+
+#[test]
+fn to_string_properties() {
+    real_algebraic_gen().test_properties(|x| {
+        let s = x.to_string();
+        assert_eq!(x.to_debug_string(), s);
+        let mut y = x.clone();
+        y.refine_isolating_interval(&Rational::from_signeds(1, 1000));
+        assert_eq!(y.to_string(), s);
+        if !x.is_rational() {
+            assert!(s.starts_with("root "));
+            assert!(s.ends_with(&x.minimal_polynomial().to_string()));
+        }
+    });
+}
@@ -0,0 +1,428 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{Abs, Gcd, Sign};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::rounding_modes::RoundingMode::{self, *};
+use malachite_base::strings::ToDebugString;
+use malachite_base::test_util::generators::common::GenConfig;
+use malachite_base::vecs::vec_from_str;
+use malachite_float::Float;
+use malachite_nz::integer::Integer;
+use malachite_poly::test_util::generators::{
+    polynomial_integer_gen_var_1, polynomial_integer_integer_pair_gen,
+    polynomial_integer_unsigned_rounding_mode_triple_gen_var_1,
+};
+use malachite_poly::Polynomial;
+use malachite_q::Rational;
+use std::cmp::Ordering::{self, *};
+use std::str::FromStr;
+
+fn squarefree_part(p: &Polynomial<Integer>) -> Polynomial<Rational> {
+    let derivative = Polynomial::<Integer>::from_owned_coefficients_asc(
+        p.coefficients()
+            .iter()
+            .enumerate()
+            .skip(1)
+            .map(|(i, x)| x * Integer::from(i))
+            .collect(),
+    );
+    let q = p / p.gcd(&derivative);
+    Polynomial::<Rational>::from_owned_coefficients_asc(
+        q.coefficients().iter().map(Rational::from).collect(),
+    )
+}
+
+fn sign_at(q: &Polynomial<Rational>, x: &Rational) -> Ordering {
+    q.evaluate(x).sign()
+}
+
+fn verify_isolating_intervals(p: &Polynomial<Integer>, intervals: &[(Rational, Rational)]) {
+    let q = squarefree_part(p);
+    for (a, b) in intervals {
+        assert!(a.is_valid());
+        assert!(b.is_valid());
+        if a == b {
+            assert_eq!(sign_at(&q, a), Equal);
+        } else {
+            assert!(a < b);
+            let sign_a = sign_at(&q, a);
+            let sign_b = sign_at(&q, b);
+            assert_ne!(sign_a, Equal);
+            assert_ne!(sign_b, Equal);
+            assert_ne!(sign_a, sign_b);
+        }
+    }
+    for w in intervals.windows(2) {
+        assert!(w[0].0 < w[1].0);
+        assert!(w[0].1 <= w[1].0);
+    }
+}
+
+fn polynomial(s: &str) -> Polynomial<Integer> {
+    Polynomial::<Integer>::from_owned_coefficients_asc(vec_from_str(s).unwrap())
+}
+
+#[test]
+fn test_real_root_isolating_intervals() {
+    let test = |s, out| {
+        let p = polynomial(s);
+        let intervals = p.real_root_isolating_intervals();
+        verify_isolating_intervals(&p, &intervals);
+        assert_eq!(intervals.to_debug_string(), out);
+    };
+    test("[1]", "[]");
+    test("[-5]", "[]");
+    test("[0, 1]", "[(0, 0)]");
+    test("[0, 0, 0, 7]", "[(0, 0)]");
+    test("[-1, 2]", "[(0, 2)]");
+    test("[3, 2]", "[(-4, 0)]");
+    test("[1, 0, 1]", "[]");
+    test("[-2, 0, 1]", "[(-8, 0), (0, 8)]");
+    test("[4, -4, 1]", "[(0, 8)]");
+    test("[-6, 11, -6, 1]", "[(1, 1), (2, 2), (3, 3)]");
+    test("[-1, -1, 0, 1]", "[(0, 4)]");
+    test("[-1, 2, -1, 2]", "[(0, 2)]");
+    test("[0, -2, 0, 1]", "[(-2, -1), (0, 0), (1, 2)]");
+    test("[4, 0, -4, 0, 1]", "[(-8, 0), (0, 8)]");
+    test("[-1, 0, 0, 0, 0, 1000000]", "[(0, 2)]");
+    test("[1, -1000001, 1000000]", "[(0, 1/2), (1, 1)]");
+    test("[2, 0, -200, 0, 0, 1]", "[(-512, 0), (0, 4), (4, 8)]");
+}
+
+#[test]
+#[should_panic]
+fn real_root_isolating_intervals_fail() {
+    Polynomial::<Integer>::zero().real_root_isolating_intervals();
+}
+
+#[test]
+fn test_refine_real_root_interval() {
+    let test = |s, a, b, width, out| {
+        let p = polynomial(s);
+        let a = Rational::from_str(a).unwrap();
+        let b = Rational::from_str(b).unwrap();
+        let width = Rational::from_str(width).unwrap();
+        let (c, d) = p.refine_real_root_interval(&a, &b, &width);
+        verify_isolating_intervals(&p, &[(c.clone(), d.clone())]);
+        assert!(a <= c && d <= b);
+        assert!(&d - &c <= width);
+        assert_eq!((c, d).to_debug_string(), out);
+    };
+    test("[-2, 0, 1]", "1", "2", "1", "(1, 2)");
+    test("[-2, 0, 1]", "1", "2", "1/100", "(24/17, 17/12)");
+    test("[-2, 0, 1]", "0", "8", "1/1000000", "(1393/985, 1970/1393)");
+    test(
+        "[-2, 0, 1]",
+        "-8",
+        "0",
+        "1/1000000",
+        "(-1970/1393, -1393/985)",
+    );
+    test("[-1, 2]", "1/2", "1/2", "1/100", "(1/2, 1/2)");
+    test("[-1, 2]", "0", "8", "1/100", "(1/2, 1/2)");
+    test("[-1, -1, 0, 1]", "1", "2", "1/1000", "(49/37, 53/40)");
+    test("[1, -2, 1]", "0", "3", "1/100", "(1, 1)");
+}
+
+#[test]
+#[should_panic]
+fn refine_real_root_interval_fail_1() {
+    Polynomial::<Integer>::zero().refine_real_root_interval(
+        &Rational::ZERO,
+        &Rational::ONE,
+        &Rational::ONE,
+    );
+}
+
+#[test]
+#[should_panic]
+fn refine_real_root_interval_fail_2() {
+    polynomial("[-2, 0, 1]").refine_real_root_interval(
+        &Rational::ONE,
+        &Rational::from(2),
+        &Rational::ZERO,
+    );
+}
+
+#[test]
+#[should_panic]
+fn refine_real_root_interval_fail_3() {
+    polynomial("[-2, 0, 1]").refine_real_root_interval(
+        &Rational::ONE,
+        &Rational::ONE,
+        &Rational::ONE,
+    );
+}
+
+#[test]
+#[should_panic]
+fn refine_real_root_interval_fail_4() {
+    polynomial("[-2, 0, 1]").refine_real_root_interval(
+        &Rational::from(-2),
+        &Rational::from(2),
+        &Rational::ONE,
+    );
+}
+
+#[test]
+#[should_panic]
+fn refine_real_root_interval_fail_5() {
+    polynomial("[-2, 0, 1]").refine_real_root_interval(
+        &Rational::from(2),
+        &Rational::ONE,
+        &Rational::ONE,
+    );
+}
+
+#[test]
+fn test_real_roots_prec_round() {
+    let test = |s, prec, rm, out| {
+        let p = polynomial(s);
+        let roots = p.real_roots_prec_round(prec, rm);
+        assert!(roots.iter().all(|(f, _)| f.is_valid()));
+        assert_eq!(roots.to_debug_string(), out);
+    };
+    test("[1, 0, 1]", 10, Nearest, "[]");
+    test("[0, 1]", 10, Nearest, "[(0.0, Equal)]");
+    test("[-1, 4]", 1, Exact, "[(0.2, Equal)]");
+    test("[-1, 3]", 10, Nearest, "[(0.3335, Greater)]");
+    test("[-1, 3]", 10, Floor, "[(0.333, Less)]");
+    test("[-1, 3]", 10, Ceiling, "[(0.3335, Greater)]");
+    test("[-2, 0, 1]", 10, Floor, "[(-1.416, Less), (1.414, Less)]");
+    test(
+        "[-2, 0, 1]",
+        10,
+        Ceiling,
+        "[(-1.414, Greater), (1.416, Greater)]",
+    );
+    test("[-2, 0, 1]", 10, Down, "[(-1.414, Greater), (1.414, Less)]");
+    test("[-2, 0, 1]", 10, Up, "[(-1.416, Less), (1.416, Greater)]");
+    test(
+        "[-2, 0, 1]",
+        10,
+        Nearest,
+        "[(-1.414, Greater), (1.414, Less)]",
+    );
+    test("[-2, 0, 1]", 1, Nearest, "[(-1.0, Greater), (1.0, Less)]");
+    test(
+        "[-2, 0, 1]",
+        100,
+        Nearest,
+        "[(-1.414213562373095048801688724209, Greater), (1.414213562373095048801688724209, Less)]",
+    );
+    test(
+        "[-6, 11, -6, 1]",
+        5,
+        Nearest,
+        "[(1.0, Equal), (2.0, Equal), (3.0, Equal)]",
+    );
+    test(
+        "[-1, -1, 0, 1]",
+        100,
+        Nearest,
+        "[(1.324717957244746025960908854479, Greater)]",
+    );
+    // 3 is a tie between 2 and 4 with 1 bit of precision.
+    test("[-3, 1]", 1, Nearest, "[(4.0, Greater)]");
+    // The root of 2048x - 2049 is a tie with 11 bits of precision, and a tie-breaking point for
+    // 12 bits.
+    test("[-2049, 2048]", 11, Nearest, "[(1.0, Less)]");
+    test("[-2049, 2048]", 11, Floor, "[(1.0, Less)]");
+    test("[-2049, 2048]", 12, Exact, "[(1.0005, Equal)]");
+}
+
+#[test]
+#[should_panic]
+fn real_roots_prec_round_fail_1() {
+    Polynomial::<Integer>::zero().real_roots_prec_round(10, Nearest);
+}
+
+#[test]
+#[should_panic]
+fn real_roots_prec_round_fail_2() {
+    polynomial("[-2, 0, 1]").real_roots_prec_round(0, Nearest);
+}
+
+#[test]
+#[should_panic]
+fn real_roots_prec_round_fail_3() {
+    polynomial("[-2, 0, 1]").real_roots_prec_round(10, Exact);
+}
+
+#[test]
+fn test_real_roots_prec() {
+    let test = |s, prec, out| {
+        let p = polynomial(s);
+        let roots = p.real_roots_prec(prec);
+        assert!(roots.iter().all(|(f, _)| f.is_valid()));
+        assert_eq!(roots.to_debug_string(), out);
+        assert_eq!(roots, p.real_roots_prec_round(prec, Nearest));
+    };
+    test("[1]", 10, "[]");
+    test("[-2, 0, 1]", 20, "[(-1.414213, Greater), (1.414213, Less)]");
+    test("[-5, 0, 0, 1]", 30, "[(1.709975947, Greater)]");
+    test(
+        "[1, -3, 0, 1]",
+        20,
+        "[(-1.879385, Greater), (0.3472962, Less), (1.532089, Greater)]",
+    );
+}
+
+#[test]
+#[should_panic]
+fn real_roots_prec_fail() {
+    Polynomial::<Integer>::zero().real_roots_prec(10);
+}
+
+#[test]
+fn real_root_isolating_intervals_properties() {
+    let mut config = GenConfig::new();
+    config.insert("mean_bits_n", 16);
+    config.insert("mean_length_n", 4);
+    polynomial_integer_gen_var_1().test_properties_with_config(&config, |p| {
+        let intervals = p.real_root_isolating_intervals();
+        verify_isolating_intervals(&p, &intervals);
+        assert!(intervals.len() < p.coefficients().len());
+
+        assert_eq!((-&p).real_root_isolating_intervals(), intervals);
+        assert_eq!((&p * &p).real_root_isolating_intervals(), intervals);
+        let p_neg_x = p.compose(&polynomial("[0, -1]"));
+        let mut neg_intervals: Vec<(Rational, Rational)> =
+            intervals.iter().map(|(a, b)| (-b, -a)).collect();
+        neg_intervals.reverse();
+        assert_eq!(p_neg_x.real_root_isolating_intervals(), neg_intervals);
+    });
+
+    polynomial_integer_integer_pair_gen().test_properties_with_config(&config, |(p, c)| {
+        if p == Polynomial::zero() {
+            return;
+        }
+        // Multiplying by x - c adds c to the roots.
+        let intervals = p.real_root_isolating_intervals();
+        let c_is_root = p.evaluate(&c) == 0u32;
+        let p_c = &p * Polynomial::<Integer>::from_owned_coefficients_asc(vec![-&c, Integer::ONE]);
+        let c_intervals = p_c.real_root_isolating_intervals();
+        verify_isolating_intervals(&p_c, &c_intervals);
+        assert_eq!(
+            c_intervals.len(),
+            if c_is_root {
+                intervals.len()
+            } else {
+                intervals.len() + 1
+            }
+        );
+        let c = Rational::from(c);
+        assert!(c_intervals.iter().any(|(a, b)| *a <= c && c <= *b));
+    });
+}
+
+#[test]
+fn refine_real_root_interval_properties() {
+    let mut config = GenConfig::new();
+    config.insert("mean_bits_n", 16);
+    config.insert("mean_length_n", 4);
+    let width = Rational::from_unsigneds(1u32, 1000);
+    polynomial_integer_gen_var_1().test_properties_with_config(&config, |p| {
+        for (a, b) in p.real_root_isolating_intervals() {
+            let (c, d) = p.refine_real_root_interval(&a, &b, &width);
+            verify_isolating_intervals(&p, &[(c.clone(), d.clone())]);
+            assert!(a <= c && d <= b);
+            assert!(&d - &c <= width);
+            assert_eq!(
+                p.refine_real_root_interval(&c, &d, &width),
+                (c.clone(), d.clone())
+            );
+            let (e, f) = p.refine_real_root_interval(&c, &d, &(&width >> 10u32));
+            assert!(c <= e && f <= d);
+        }
+    });
+}
+
+fn verify_rounded_root(
+    p: &Polynomial<Integer>,
+    (a, b): &(Rational, Rational),
+    f: &Float,
+    o: Ordering,
+    prec: u64,
+    rm: RoundingMode,
+) {
+    assert!(f.is_valid());
+    if a == b {
+        assert_eq!(
+            (f.clone(), o),
+            Float::from_rational_prec_round_ref(a, prec, rm)
+        );
+        return;
+    }
+    let q = squarefree_part(p);
+    let x = Rational::exact_from(f);
+    // Check that `f` is on the correct side of the root.
+    match o {
+        Less => assert!(x <= *a || (x < *b && sign_at(&q, &x) == sign_at(&q, a))),
+        Greater => assert!(x >= *b || (x > *a && sign_at(&q, &x) == sign_at(&q, b))),
+        Equal => assert!(x > *a && x < *b && sign_at(&q, &x) == Equal),
+    }
+    // If both endpoints of a narrow interval around the root round to the same `Float`, then so
+    // does the root.
+    let (c, d) = p.refine_real_root_interval(a, b, &((a.abs() + Rational::ONE) >> (prec + 8)));
+    let (g, _) = Float::from_rational_prec_round_ref(&c, prec, rm);
+    let (h, _) = Float::from_rational_prec_round_ref(&d, prec, rm);
+    if g == h {
+        assert_eq!(*f, g);
+    }
+}
+
+#[test]
+fn real_roots_prec_round_properties() {
+    let mut config = GenConfig::new();
+    config.insert("mean_bits_n", 16);
+    config.insert("mean_length_n", 4);
+    config.insert("mean_precision_n", 16);
+    polynomial_integer_unsigned_rounding_mode_triple_gen_var_1().test_properties_with_config(
+        &config,
+        |(p, prec, rm)| {
+            let roots = p.real_roots_prec_round(prec, rm);
+            let intervals = p.real_root_isolating_intervals();
+            assert_eq!(roots.len(), intervals.len());
+            for ((f, o), interval) in roots.iter().zip(intervals.iter()) {
+                verify_rounded_root(&p, interval, f, *o, prec, rm);
+                match rm {
+                    Floor => assert_ne!(*o, Greater),
+                    Ceiling => assert_ne!(*o, Less),
+                    _ => {}
+                }
+            }
+            for w in roots.windows(2) {
+                assert!(w[0].0 <= w[1].0);
+            }
+
+            let floors = p.real_roots_prec_round(prec, Floor);
+            let ceilings = p.real_roots_prec_round(prec, Ceiling);
+            for ((f, (g, _)), (h, _)) in roots.iter().map(|(f, _)| f).zip(floors).zip(ceilings) {
+                assert!(g <= h);
+                assert!(*f == g || *f == h);
+            }
+        },
+    );
+}
+
+#[test]
+fn real_roots_prec_properties() {
+    let mut config = GenConfig::new();
+    config.insert("mean_bits_n", 16);
+    config.insert("mean_length_n", 4);
+    polynomial_integer_gen_var_1().test_properties_with_config(&config, |p| {
+        let roots = p.real_roots_prec(53);
+        assert_eq!(roots, p.real_roots_prec_round(53, Nearest));
+        for ((f, o), interval) in roots.iter().zip(p.real_root_isolating_intervals().iter()) {
+            verify_rounded_root(&p, interval, f, *o, 53, Nearest);
+        }
+    });
+}
@@ -0,0 +1,100 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::arithmetic::traits::Bernoulli;
+use crate::Rational;
+use malachite_base::num::basic::traits::{One, OneHalf, Zero};
+use malachite_base::num::factorization::traits::{Divisors, IsPrime};
+use malachite_nz::natural::arithmetic::approximate_real::{
+    bernoulli_abs_numerator, zigzag_numbers,
+};
+use malachite_nz::natural::Natural;
+
+// Below this, the zigzag numbers are faster than the zeta function formula.
+const ZETA_THRESHOLD: u64 = 32;
+
+// Returns the denominator of $B_n$, for even $n > 0$. By the von Staudt–Clausen theorem, this is
+// the product of all primes $p$ such that $p - 1$ divides $n$.
+fn bernoulli_denominator(n: u64) -> Natural {
+    let mut d = Natural::ONE;
+    for k in n.divisors() {
+        if (k + 1).is_prime() {
+            d *= Natural::from(k + 1);
+        }
+    }
+    d
+}
+
+// Computes $|B_n|$ for even $n$ using the zigzag numbers: $|B_n| = nA_{n-1}/(2^n(2^n-1))$.
+fn bernoulli_abs_zigzag(n: u64) -> Rational {
+    let zigzag = zigzag_numbers(n - 1).pop().unwrap();
+    let power = Natural::from(1u64 << n);
+    Rational::from_naturals(zigzag * Natural::from(n), &power * (&power - Natural::ONE))
+}
+
+// Computes $|B_n|$ for even $n$ from $2 n! \zeta(n) / (2\pi)^n$. Since the denominator of $B_n$
+// is known, only the numerator needs to be found numerically.
+fn bernoulli_abs_zeta(n: u64) -> Rational {
+    let denominator = bernoulli_denominator(n);
+    Rational::from_naturals(bernoulli_abs_numerator(n, &denominator), denominator)
+}
+
+impl Bernoulli for Rational {
+    /// Computes the $n$th Bernoulli number.
+    ///
+    /// $f(n) = B_n$, where $x/(e^x - 1) = \sum_{n=0}^\infty B_n x^n/n!$. With this convention,
+    /// $B_1 = -1/2$. The Bernoulli numbers of odd index greater than 1 are zero, and those of even
+    /// index alternate in sign.
+    ///
+    /// For small $n$, the Bernoulli numbers are computed from the zigzag numbers. For larger $n$,
+    /// the denominator is found using the von Staudt–Clausen theorem, and the numerator is
+    /// computed numerically from $|B_n| = 2 n! \zeta(n) / (2\pi)^n$. The numerical error is
+    /// tracked rigorously, and the precision is increased until rounding is guaranteed to give the
+    /// exact result.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2 (\log n)^2)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::arithmetic::traits::Bernoulli;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(Rational::bernoulli(0), 1);
+    /// assert_eq!(Rational::bernoulli(1).to_string(), "-1/2");
+    /// assert_eq!(Rational::bernoulli(2).to_string(), "1/6");
+    /// assert_eq!(Rational::bernoulli(3), 0);
+    /// assert_eq!(Rational::bernoulli(4).to_string(), "-1/30");
+    /// assert_eq!(Rational::bernoulli(20).to_string(), "-174611/330");
+    /// assert_eq!(
+    ///     Rational::bernoulli(50).to_string(),
+    ///     "495057205241079648212477525/66"
+    /// );
+    /// ```
+    fn bernoulli(n: u64) -> Rational {
+        if n == 0 {
+            return Rational::ONE;
+        } else if n == 1 {
+            return -Rational::ONE_HALF;
+        } else if n & 1 == 1 {
+            return Rational::ZERO;
+        }
+        let abs = if n < ZETA_THRESHOLD {
+            bernoulli_abs_zigzag(n)
+        } else {
+            bernoulli_abs_zeta(n)
+        };
+        if n & 3 == 2 {
+            abs
+        } else {
+            -abs
+        }
+    }
+}
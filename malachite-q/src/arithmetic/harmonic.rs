@@ -0,0 +1,61 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::arithmetic::traits::Harmonic;
+use crate::Rational;
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_nz::natural::Natural;
+
+// Returns $(P, Q)$ such that $P/Q = \sum_{k=a}^{b-1} 1/k$ and $Q = \prod_{k=a}^{b-1} k$. The
+// fraction is not reduced.
+fn harmonic_binary_split(a: u64, b: u64) -> (Natural, Natural) {
+    if b - a == 1 {
+        (Natural::ONE, Natural::from(a))
+    } else {
+        let mid = a + ((b - a) >> 1);
+        let (p_1, q_1) = harmonic_binary_split(a, mid);
+        let (p_2, q_2) = harmonic_binary_split(mid, b);
+        (p_1 * &q_2 + p_2 * &q_1, q_1 * q_2)
+    }
+}
+
+impl Harmonic for Rational {
+    /// Computes the $n$th harmonic number.
+    ///
+    /// $f(n) = H_n = \sum_{k=1}^n \frac{1}{k}$.
+    ///
+    /// The sum is computed by binary splitting, so that the numbers being added always have
+    /// roughly the same size, and the fraction is reduced only once, at the end.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::arithmetic::traits::Harmonic;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(Rational::harmonic(0), 0);
+    /// assert_eq!(Rational::harmonic(1), 1);
+    /// assert_eq!(Rational::harmonic(2).to_string(), "3/2");
+    /// assert_eq!(Rational::harmonic(10).to_string(), "7381/2520");
+    /// assert_eq!(
+    ///     Rational::harmonic(100).to_string(),
+    ///     "14466636279520351160221518043104131447711/2788815009188499086581352357412492142272"
+    /// );
+    /// ```
+    fn harmonic(n: u64) -> Rational {
+        if n == 0 {
+            return Rational::ZERO;
+        }
+        let (p, q) = harmonic_binary_split(1, n + 1);
+        Rational::from_naturals(p, q)
+    }
+}
@@ -0,0 +1,208 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::arithmetic::traits::Mediant;
+use crate::Rational;
+use malachite_nz::integer::Integer;
+
+impl Mediant<Rational> for Rational {
+    type Output = Rational;
+
+    /// Computes the mediant of two [`Rational`]s, taking both by value.
+    ///
+    /// If $x = p/q$ and $y = r/s$ are in lowest terms with positive denominators, their mediant is
+    /// $$
+    /// f(x, y) = \frac{p + r}{q + s}.
+    /// $$
+    /// The result is reduced, so its denominator may be smaller than $q + s$. If $x \leq y$, then
+    /// $x \leq f(x, y) \leq y$, with equality only when $x = y$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::arithmetic::traits::Mediant;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(
+    ///     Rational::from_signeds(1, 2)
+    ///         .mediant(Rational::from_signeds(2, 3))
+    ///         .to_string(),
+    ///     "3/5"
+    /// );
+    /// assert_eq!(
+    ///     Rational::from(1).mediant(Rational::from(3)).to_string(),
+    ///     "2"
+    /// );
+    /// assert_eq!(
+    ///     Rational::from_signeds(-1, 2)
+    ///         .mediant(Rational::from_signeds(1, 3))
+    ///         .to_string(),
+    ///     "0"
+    /// );
+    /// ```
+    #[inline]
+    fn mediant(self, other: Rational) -> Rational {
+        (&self).mediant(&other)
+    }
+}
+
+impl Mediant<&Rational> for Rational {
+    type Output = Rational;
+
+    /// Computes the mediant of two [`Rational`]s, taking the first by value and the second by
+    /// reference.
+    ///
+    /// If $x = p/q$ and $y = r/s$ are in lowest terms with positive denominators, their mediant is
+    /// $$
+    /// f(x, y) = \frac{p + r}{q + s}.
+    /// $$
+    /// The result is reduced, so its denominator may be smaller than $q + s$. If $x \leq y$, then
+    /// $x \leq f(x, y) \leq y$, with equality only when $x = y$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::arithmetic::traits::Mediant;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(
+    ///     Rational::from_signeds(1, 2)
+    ///         .mediant(&Rational::from_signeds(2, 3))
+    ///         .to_string(),
+    ///     "3/5"
+    /// );
+    /// assert_eq!(
+    ///     Rational::from(1).mediant(&Rational::from(3)).to_string(),
+    ///     "2"
+    /// );
+    /// assert_eq!(
+    ///     Rational::from_signeds(-1, 2)
+    ///         .mediant(&Rational::from_signeds(1, 3))
+    ///         .to_string(),
+    ///     "0"
+    /// );
+    /// ```
+    #[inline]
+    fn mediant(self, other: &Rational) -> Rational {
+        (&self).mediant(other)
+    }
+}
+
+impl Mediant<Rational> for &Rational {
+    type Output = Rational;
+
+    /// Computes the mediant of two [`Rational`]s, taking the first by reference and the second by
+    /// value.
+    ///
+    /// If $x = p/q$ and $y = r/s$ are in lowest terms with positive denominators, their mediant is
+    /// $$
+    /// f(x, y) = \frac{p + r}{q + s}.
+    /// $$
+    /// The result is reduced, so its denominator may be smaller than $q + s$. If $x \leq y$, then
+    /// $x \leq f(x, y) \leq y$, with equality only when $x = y$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::arithmetic::traits::Mediant;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(1, 2))
+    ///         .mediant(Rational::from_signeds(2, 3))
+    ///         .to_string(),
+    ///     "3/5"
+    /// );
+    /// assert_eq!(
+    ///     (&Rational::from(1)).mediant(Rational::from(3)).to_string(),
+    ///     "2"
+    /// );
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(-1, 2))
+    ///         .mediant(Rational::from_signeds(1, 3))
+    ///         .to_string(),
+    ///     "0"
+    /// );
+    /// ```
+    #[inline]
+    fn mediant(self, other: Rational) -> Rational {
+        self.mediant(&other)
+    }
+}
+
+impl Mediant<&Rational> for &Rational {
+    type Output = Rational;
+
+    /// Computes the mediant of two [`Rational`]s, taking both by reference.
+    ///
+    /// If $x = p/q$ and $y = r/s$ are in lowest terms with positive denominators, their mediant is
+    /// $$
+    /// f(x, y) = \frac{p + r}{q + s}.
+    /// $$
+    /// The result is reduced, so its denominator may be smaller than $q + s$. If $x \leq y$, then
+    /// $x \leq f(x, y) \leq y$, with equality only when $x = y$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `max(self.significant_bits(),
+    /// other.significant_bits())`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::arithmetic::traits::Mediant;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(1, 2))
+    ///         .mediant(&Rational::from_signeds(2, 3))
+    ///         .to_string(),
+    ///     "3/5"
+    /// );
+    /// assert_eq!(
+    ///     (&Rational::from(1)).mediant(&Rational::from(3)).to_string(),
+    ///     "2"
+    /// );
+    /// assert_eq!(
+    ///     (&Rational::from_signeds(-1, 2))
+    ///         .mediant(&Rational::from_signeds(1, 3))
+    ///         .to_string(),
+    ///     "0"
+    /// );
+    /// ```
+    fn mediant(self, other: &Rational) -> Rational {
+        Rational::from_integers(
+            Integer::from_sign_and_abs_ref(self.sign, &self.numerator)
+                + Integer::from_sign_and_abs_ref(other.sign, &other.numerator),
+            Integer::from(&self.denominator + &other.denominator),
+        )
+    }
+}
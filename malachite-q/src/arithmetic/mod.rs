@@ -18,6 +18,8 @@ pub mod add;
 /// [`ApproximateAssign`](traits::ApproximateAssign), traits for approximating a
 /// [`Rational`](super::Rational) by a [`Rational`](super::Rational) with a bounded denominator.
 pub mod approximate;
+/// An implementation of [`Bernoulli`](traits::Bernoulli), a trait for computing Bernoulli numbers.
+pub mod bernoulli;
 /// Implementations of [`Ceiling`](malachite_base::num::arithmetic::traits::Ceiling) and
 /// [`CeilingAssign`](malachite_base::num::arithmetic::traits::CeilingAssign), traits for taking the
 /// ceiling of a number.
@@ -31,6 +33,8 @@ pub mod div;
 /// [`FloorAssign`](malachite_base::num::arithmetic::traits::FloorAssign), traits for taking the
 /// floor of a number.
 pub mod floor;
+/// An implementation of [`Harmonic`](traits::Harmonic), a trait for computing harmonic numbers.
+pub mod harmonic;
 /// An implementation of [`IsPowerOf2`](malachite_base::num::arithmetic::traits::IsPowerOf2), a
 /// trait for determining whether a number is an integer power of 2.
 pub mod is_power_of_2;
@@ -53,6 +57,9 @@ pub mod log_base_2;
 /// [`CeilingLogBasePowerOf2`](malachite_base::num::arithmetic::traits::CeilingLogBasePowerOf2), and
 /// [`CheckedLogBasePowerOf2`](malachite_base::num::arithmetic::traits::CheckedLogBasePowerOf2).
 pub mod log_base_power_of_2;
+/// Implementations of [`Mediant`](traits::Mediant), a trait for computing the mediant of two
+/// [`Rational`](super::Rational)s.
+pub mod mediant;
 /// Multiplication of [`Rational`](super::Rational)s.
 pub mod mul;
 /// Negation of [`Rational`](super::Rational)s.
@@ -254,6 +261,9 @@ pub mod sqrt;
 /// [`SquareAssign`](malachite_base::num::arithmetic::traits::SquareAssign), traits for squaring a
 /// number.
 pub mod square;
+/// Enumerations of [`Rational`](super::Rational)s related to the Stern–Brocot tree: Farey
+/// sequences, paths in the Stern–Brocot tree, and the Calkin–Wilf sequence.
+pub mod stern_brocot;
 /// Subtraction of [`Rational`](super::Rational)s.
 pub mod sub;
 /// Various traits for performing arithmetic operations on numbers.
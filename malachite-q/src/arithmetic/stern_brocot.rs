@@ -0,0 +1,289 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Rational;
+use core::cmp::Ordering::*;
+use malachite_base::num::arithmetic::traits::DivRem;
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_nz::natural::Natural;
+
+/// Generates the Farey sequence of a given order: all [`Rational`]s in $[0, 1]$ whose denominators
+/// do not exceed the order, in ascending order.
+///
+/// This `struct` is created by [`Rational::farey_sequence`]; see its documentation for more.
+#[derive(Clone, Debug)]
+pub struct FareySequence {
+    n: u64,
+    // The last two elements generated are a/b and c/d, or, before the first iteration, a/b is
+    // 0 and c/d is its successor.
+    a: u64,
+    b: u64,
+    c: u64,
+    d: u64,
+    done: bool,
+}
+
+impl Iterator for FareySequence {
+    type Item = Rational;
+
+    fn next(&mut self) -> Option<Rational> {
+        if self.done {
+            return None;
+        }
+        let x = Rational {
+            sign: true,
+            numerator: Natural::from(self.a),
+            denominator: Natural::from(self.b),
+        };
+        if self.a == self.b {
+            self.done = true;
+        } else {
+            // Consecutive elements a/b, c/d, e/f satisfy e = kc - a and f = kd - b, where k =
+            // floor((n + b) / d).
+            let k = (u128::from(self.n) + u128::from(self.b)) / u128::from(self.d);
+            let e = u64::exact_from(k * u128::from(self.c) - u128::from(self.a));
+            let f = u64::exact_from(k * u128::from(self.d) - u128::from(self.b));
+            self.a = self.c;
+            self.b = self.d;
+            self.c = e;
+            self.d = f;
+        }
+        Some(x)
+    }
+}
+
+/// Generates the path from the root of the Stern–Brocot tree to a positive [`Rational`].
+///
+/// This `struct` is created by [`Rational::stern_brocot_path`]; see its documentation for more.
+#[derive(Clone, Debug)]
+pub struct SternBrocotPath {
+    numerator: Natural,
+    denominator: Natural,
+}
+
+impl Iterator for SternBrocotPath {
+    type Item = bool;
+
+    fn next(&mut self) -> Option<bool> {
+        match self.numerator.cmp(&self.denominator) {
+            Equal => None,
+            Greater => {
+                self.numerator -= &self.denominator;
+                Some(true)
+            }
+            Less => {
+                self.denominator -= &self.numerator;
+                Some(false)
+            }
+        }
+    }
+}
+
+impl Rational {
+    /// Generates the Farey sequence of order $n$: all [`Rational`]s $x$ with $0 \leq x \leq 1$
+    /// whose denominators are at most $n$, in ascending order.
+    ///
+    /// Any two consecutive elements $a/b < c/d$ satisfy $bc - ad = 1$, and the first [`Rational`]
+    /// to appear between them in a sequence of higher order is their
+    /// [mediant](crate::arithmetic::traits::Mediant). Each element is computed from the previous
+    /// two, so no elements are stored.
+    ///
+    /// The output length is $1 + \sum_{k=1}^n \varphi(k) = 3n^2/\pi^2 + O(n \log n)$ if $n > 0$,
+    /// and 0 if $n = 0$.
+    ///
+    /// # Worst-case complexity per iteration
+    /// Constant time and additional memory.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::iterators::prefix_to_string;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(prefix_to_string(Rational::farey_sequence(0), 20), "[]");
+    /// assert_eq!(prefix_to_string(Rational::farey_sequence(1), 20), "[0, 1]");
+    /// assert_eq!(
+    ///     prefix_to_string(Rational::farey_sequence(5), 20),
+    ///     "[0, 1/5, 1/4, 1/3, 2/5, 1/2, 3/5, 2/3, 3/4, 4/5, 1]"
+    /// );
+    /// ```
+    pub const fn farey_sequence(n: u64) -> FareySequence {
+        FareySequence {
+            n,
+            a: 0,
+            b: 1,
+            c: 1,
+            d: n,
+            done: n == 0,
+        }
+    }
+
+    /// Returns the path from the root of the Stern–Brocot tree to a positive [`Rational`].
+    ///
+    /// The root of the tree is 1, and the path is a sequence of steps, where `false` means a step
+    /// to the left child (towards smaller values) and `true` a step to the right child (towards
+    /// larger values). If the continued fraction of $x$ is $[a_0; a_1, \ldots, a_k]$, with $a_k >
+    /// 1$ or $k = 0$, then the path consists of $a_0$ right steps, $a_1$ left steps, and so on,
+    /// with only $a_k - 1$ steps at the end. The path of 1 is empty.
+    ///
+    /// The path's length is the sum of the terms of the continued fraction minus 1, which may be
+    /// as large as $x$ or $1/x$, so the path is generated lazily. It may be turned back into $x$
+    /// using [`Rational::from_stern_brocot_path`].
+    ///
+    /// # Worst-case complexity per iteration
+    /// $T(n) = O(n)$
+    ///
+    /// $M(n) = O(1)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is not positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use itertools::Itertools;
+    /// use malachite_base::num::basic::traits::One;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(Rational::ONE.stern_brocot_path().collect_vec(), &[]);
+    /// assert_eq!(
+    ///     Rational::from(3).stern_brocot_path().collect_vec(),
+    ///     &[true, true]
+    /// );
+    /// assert_eq!(
+    ///     Rational::from_signeds(3, 7)
+    ///         .stern_brocot_path()
+    ///         .collect_vec(),
+    ///     &[false, false, true, true]
+    /// );
+    /// assert_eq!(
+    ///     Rational::from_signeds(22, 7)
+    ///         .stern_brocot_path()
+    ///         .collect_vec(),
+    ///     &[true, true, true, false, false, false, false, false, false]
+    /// );
+    /// ```
+    pub fn stern_brocot_path(&self) -> SternBrocotPath {
+        assert!(*self > 0u32, "Cannot find the Stern–Brocot path of {self}");
+        SternBrocotPath {
+            numerator: self.numerator.clone(),
+            denominator: self.denominator.clone(),
+        }
+    }
+
+    /// Finds the positive [`Rational`] at the end of a path in the Stern–Brocot tree.
+    ///
+    /// The path starts at the root, 1, and each step is `false` for a step to the left child or
+    /// `true` for a step to the right child. Every sequence of steps is a valid path, and this
+    /// function is the inverse of [`Rational::stern_brocot_path`].
+    ///
+    /// The node reached is the mediant of the nearest ancestors to its left and right, which
+    /// start out as $0/1$ and $1/0$.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n^2)$
+    ///
+    /// $M(n) = O(n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `path.count()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(Rational::from_stern_brocot_path([]), 1);
+    /// assert_eq!(Rational::from_stern_brocot_path([true, true]), 3);
+    /// assert_eq!(
+    ///     Rational::from_stern_brocot_path([false, false, true, true]).to_string(),
+    ///     "3/7"
+    /// );
+    /// assert_eq!(
+    ///     Rational::from_stern_brocot_path([true, false, true, false, true, false]).to_string(),
+    ///     "21/13"
+    /// );
+    /// ```
+    pub fn from_stern_brocot_path<I: IntoIterator<Item = bool>>(path: I) -> Rational {
+        // The current node is (a + c) / (b + d), between a/b and c/d.
+        let mut a = Natural::ZERO;
+        let mut b = Natural::ONE;
+        let mut c = Natural::ONE;
+        let mut d = Natural::ZERO;
+        for step in path {
+            if step {
+                a += &c;
+                b += &d;
+            } else {
+                c += &a;
+                d += &b;
+            }
+        }
+        Rational {
+            sign: true,
+            numerator: a + c,
+            denominator: b + d,
+        }
+    }
+
+    /// Returns the [`Rational`] that follows a non-negative [`Rational`] in the Calkin–Wilf
+    /// sequence.
+    ///
+    /// The Calkin–Wilf sequence is a breadth-first traversal of the Calkin–Wilf tree, and contains
+    /// every positive [`Rational`] exactly once. Starting from 0, repeatedly applying this function
+    /// gives $0, 1, 1/2, 2, 1/3, 3/2, 2/3, 3, \ldots$, the same order used by
+    /// [`exhaustive_non_negative_rationals`](crate::exhaustive::exhaustive_non_negative_rationals).
+    ///
+    /// $$
+    /// f(x) = \frac{1}{2\lfloor x \rfloor - x + 1}.
+    /// $$
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n \log n \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `self.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `self` is negative.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::num::basic::traits::Zero;
+    /// use malachite_q::Rational;
+    ///
+    /// assert_eq!(Rational::ZERO.calkin_wilf_successor(), 1);
+    /// assert_eq!(Rational::from(3).calkin_wilf_successor().to_string(), "1/4");
+    /// assert_eq!(
+    ///     Rational::from_signeds(3, 5)
+    ///         .calkin_wilf_successor()
+    ///         .to_string(),
+    ///     "5/2"
+    /// );
+    /// assert_eq!(
+    ///     Rational::from_signeds(22, 7)
+    ///         .calkin_wilf_successor()
+    ///         .to_string(),
+    ///     "7/27"
+    /// );
+    /// ```
+    pub fn calkin_wilf_successor(&self) -> Rational {
+        assert!(
+            *self >= 0u32,
+            "Cannot find the Calkin–Wilf successor of {self}"
+        );
+        // If x = p/q with p = kq + r and 0 <= r < q, then f(x) = q/((k + 1)q - r). The numerator
+        // and denominator are coprime because gcd(q, r) = gcd(q, p) = 1.
+        let (k, r) = (&self.numerator).div_rem(&self.denominator);
+        Rational {
+            sign: true,
+            numerator: self.denominator.clone(),
+            denominator: (k + Natural::ONE) * &self.denominator - r,
+        }
+    }
+}
@@ -39,6 +39,14 @@ pub trait SimplestRationalInInterval {
     fn simplest_rational_in_closed_interval(x: &Self, y: &Self) -> Rational;
 }
 
+/// Computes the mediant of two [`Rational`]s: the [`Rational`] whose numerator and denominator are
+/// the sums of theirs.
+pub trait Mediant<RHS = Self> {
+    type Output;
+
+    fn mediant(self, other: RHS) -> Self::Output;
+}
+
 // Returns an iterator of all denominators that appear in the [`Rational`]s contained in a closed
 // interval.
 pub trait DenominatorsInClosedInterval {
@@ -46,3 +54,14 @@ pub trait DenominatorsInClosedInterval {
 
     fn denominators_in_closed_interval(a: Rational, b: Rational) -> Self::Denominators;
 }
+
+/// Computes the $n$th Bernoulli number $B_n$, the coefficient of $x^n/n!$ in the Taylor series of
+/// $x/(e^x - 1)$.
+pub trait Bernoulli {
+    fn bernoulli(n: u64) -> Self;
+}
+
+/// Computes the $n$th harmonic number, $H_n = \sum_{k=1}^n \frac{1}{k}$.
+pub trait Harmonic {
+    fn harmonic(n: u64) -> Self;
+}
@@ -0,0 +1,739 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use core::mem::swap;
+use malachite_base::num::arithmetic::traits::{DivRound, UnsignedAbs};
+use malachite_base::num::basic::traits::{NegativeOne, One, Zero};
+use malachite_base::rounding_modes::RoundingMode::*;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+
+// Given the values of a function at the corners of the region that its inputs may occupy, each
+// value given as a numerator and denominator, returns the common floor of the values, if all the
+// denominators are nonzero and have the same sign and all the floors are equal. Otherwise, returns
+// `None`.
+//
+// The functions used here are ratios of (bi)linear forms, so if the denominator doesn't vanish at
+// any corner, it doesn't vanish anywhere in the region, and the function's range is spanned by the
+// corner values.
+fn common_floor(corners: &[(Integer, Integer)]) -> Option<Integer> {
+    let positive = corners[0].1 > 0u32;
+    let mut floor: Option<Integer> = None;
+    for (n, d) in corners {
+        if *d == 0u32 || (*d > 0u32) != positive {
+            return None;
+        }
+        let q = n.div_round(d, Floor).0;
+        if let Some(floor) = &floor {
+            if *floor != q {
+                return None;
+            }
+        } else {
+            floor = Some(q);
+        }
+    }
+    floor
+}
+
+// Replaces $(p, q)$ by $(pt + q, p)$. This is the effect on the coefficients of $px + q$ of
+// replacing $x$ by $t + 1/x$ and multiplying by $x$.
+fn shift_in(p: &mut Integer, q: &mut Integer, t: &Integer) {
+    *q += &*p * t;
+    swap(p, q);
+}
+
+/// An iterator that produces the continued fraction of $(ax + b)/(cx + d)$, where $x$ is given by
+/// its continued fraction.
+///
+/// This `struct` is created by [`homographic_continued_fraction`]; see its documentation for more.
+#[derive(Clone, Debug)]
+pub struct HomographicContinuedFraction<I: Iterator<Item = Natural>> {
+    // `None` once the input is exhausted
+    xs: Option<I>,
+    // The remaining output is $(ax + b)/(cx + d)$, where $x \geq 1$ is the value of the remaining
+    // input, or infinity if the input is exhausted.
+    a: Integer,
+    b: Integer,
+    c: Integer,
+    d: Integer,
+}
+
+impl<I: Iterator<Item = Natural>> HomographicContinuedFraction<I> {
+    // Replaces $x$ by $t + 1/x$.
+    fn ingest(&mut self, t: &Integer) {
+        shift_in(&mut self.a, &mut self.b, t);
+        shift_in(&mut self.c, &mut self.d, t);
+    }
+
+    // Replaces $x$ by infinity, so that the remaining output is $a/c$.
+    fn exhaust(&mut self) {
+        self.xs = None;
+        self.b = Integer::ZERO;
+        self.d = Integer::ZERO;
+    }
+
+    // If $ad = bc$, the output doesn't depend on $x$, so the input is discarded; this keeps
+    // Gosper's algorithm from reading an infinite input forever.
+    fn discard_input_if_constant(&mut self) {
+        if self.xs.is_some() && &self.a * &self.d == &self.b * &self.c {
+            if self.c == 0u32 {
+                swap(&mut self.a, &mut self.b);
+                swap(&mut self.c, &mut self.d);
+            }
+            self.exhaust();
+        }
+    }
+
+    // Replaces the output $z$ by $1/(z - q)$.
+    fn egest(&mut self, q: &Integer) {
+        self.a -= q * &self.c;
+        self.b -= q * &self.d;
+        swap(&mut self.a, &mut self.c);
+        swap(&mut self.b, &mut self.d);
+    }
+
+    fn next_term(&mut self) -> Option<Integer> {
+        loop {
+            if self.c == 0u32 && self.d == 0u32 {
+                // The remaining output is infinite, so the previous term was exact
+                return None;
+            }
+            if let Some(q) = common_floor(&[
+                (self.a.clone(), self.c.clone()),
+                (&self.a + &self.b, &self.c + &self.d),
+            ]) {
+                self.egest(&q);
+                return Some(q);
+            }
+            if let Some(t) = self.xs.as_mut().and_then(Iterator::next) {
+                assert_ne!(t, 0u32);
+                self.ingest(&Integer::from(t));
+            } else {
+                self.exhaust();
+            }
+        }
+    }
+}
+
+impl<I: Iterator<Item = Natural>> Iterator for HomographicContinuedFraction<I> {
+    type Item = Natural;
+
+    fn next(&mut self) -> Option<Natural> {
+        self.next_term().map(UnsignedAbs::unsigned_abs)
+    }
+}
+
+/// Computes the continued fraction of $(ax + b)/(cx + d)$, where $x$ is given by its continued
+/// fraction, using Gosper's algorithm.
+///
+/// The input $x$ and the output each have two components, in the same format as the output of
+/// [`continued_fraction`](crate::conversion::traits::ContinuedFraction::continued_fraction). The
+/// first is the first value of the continued fraction, which may be any [`Integer`] and is equal
+/// to the floor of the number. The second is an iterator of the remaining values, which are all
+/// positive. The input iterator may be infinite, in which case $x$ is irrational, and it is only
+/// read as far as necessary to produce each output value. This makes it possible to compute
+/// Möbius transformations of numbers like $e$ or $\sqrt{2}$ term by term, without fixing a
+/// precision in advance.
+///
+/// The output iterator is finite if and only if the result is rational. If $ad = bc$, the result
+/// is constant and the input is not read at all.
+///
+/// $f((a, b, c, d), x) = (ax + b)/(cx + d)$.
+///
+/// # Worst-case complexity per iteration
+/// Unbounded; the number of input values that must be read to produce one output value depends on
+/// how close the output is to a rational number with a small denominator.
+///
+/// # Panics
+/// Panics if $cx + d = 0$, or if any [`Natural`] in the input is zero.
+///
+/// # Examples
+/// ```
+/// use itertools::Itertools;
+/// use malachite_base::strings::ToDebugString;
+/// use malachite_nz::integer::Integer;
+/// use malachite_nz::natural::Natural;
+/// use malachite_q::conversion::continued_fraction::gosper::homographic_continued_fraction;
+/// use malachite_q::conversion::traits::ContinuedFraction;
+/// use malachite_q::Rational;
+/// use std::iter::repeat;
+///
+/// let coefficients = |a: i32, b: i32, c: i32, d: i32| {
+///     [a, b, c, d].map(Integer::from)
+/// };
+///
+/// // (2/3 + 1) / 2 = 5/6
+/// let (floor, xs) = homographic_continued_fraction(
+///     coefficients(1, 1, 0, 2),
+///     Rational::from_signeds(2, 3).continued_fraction(),
+/// );
+/// assert_eq!(floor, 0);
+/// assert_eq!(xs.collect_vec().to_debug_string(), "[1, 5]");
+///
+/// // (2 * sqrt(2) + 1) / (sqrt(2) + 3)
+/// let sqrt_2 = (Integer::from(1), repeat(Natural::from(2u32)));
+/// let (floor, xs) = homographic_continued_fraction(coefficients(2, 1, 1, 3), sqrt_2);
+/// assert_eq!(floor, 0);
+/// assert_eq!(
+///     xs.take(9).collect_vec().to_debug_string(),
+///     "[1, 6, 1, 1, 6, 1, 1, 6, 1]"
+/// );
+///
+/// // (e - 1) / (e + 1)
+/// let e = (
+///     Integer::from(2),
+///     (1u32..).flat_map(|k| [1, 2 * k, 1]).map(Natural::from),
+/// );
+/// let (floor, xs) = homographic_continued_fraction(coefficients(1, -1, 1, 1), e);
+/// assert_eq!(floor, 0);
+/// assert_eq!(
+///     xs.take(8).collect_vec().to_debug_string(),
+///     "[2, 6, 10, 14, 18, 22, 26, 30]"
+/// );
+/// ```
+pub fn homographic_continued_fraction<I: Iterator<Item = Natural>>(
+    coefficients: [Integer; 4],
+    x: (Integer, I),
+) -> (Integer, HomographicContinuedFraction<I>) {
+    let [a, b, c, d] = coefficients;
+    let (floor, xs) = x;
+    let mut cf = HomographicContinuedFraction {
+        xs: Some(xs),
+        a,
+        b,
+        c,
+        d,
+    };
+    cf.ingest(&floor);
+    cf.discard_input_if_constant();
+    let Some(floor) = cf.next_term() else {
+        panic!("division by zero");
+    };
+    (floor, cf)
+}
+
+/// An iterator that produces the continued fraction of $(axy + bx + cy + d)/(exy + fx + gy + h)$,
+/// where $x$ and $y$ are given by their continued fractions.
+///
+/// This `struct` is created by [`bihomographic_continued_fraction`]; see its documentation for
+/// more.
+#[derive(Clone, Debug)]
+pub struct BihomographicContinuedFraction<I: Iterator<Item = Natural>, J: Iterator<Item = Natural>>
+{
+    // `None` once the corresponding input is exhausted
+    xs: Option<I>,
+    ys: Option<J>,
+    // Whether the next value should be read from `xs`, if both inputs remain
+    read_x: bool,
+    // The remaining output is $(axy + bx + cy + d)/(exy + fx + gy + h)$, where $x, y \geq 1$ are
+    // the values of the remaining inputs, or infinity if the inputs are exhausted.
+    a: Integer,
+    b: Integer,
+    c: Integer,
+    d: Integer,
+    e: Integer,
+    f: Integer,
+    g: Integer,
+    h: Integer,
+}
+
+impl<I: Iterator<Item = Natural>, J: Iterator<Item = Natural>>
+    BihomographicContinuedFraction<I, J>
+{
+    // Replaces $x$ by $t + 1/x$.
+    fn ingest_x(&mut self, t: &Integer) {
+        shift_in(&mut self.a, &mut self.c, t);
+        shift_in(&mut self.b, &mut self.d, t);
+        shift_in(&mut self.e, &mut self.g, t);
+        shift_in(&mut self.f, &mut self.h, t);
+    }
+
+    // Replaces $y$ by $t + 1/y$.
+    fn ingest_y(&mut self, t: &Integer) {
+        shift_in(&mut self.a, &mut self.b, t);
+        shift_in(&mut self.c, &mut self.d, t);
+        shift_in(&mut self.e, &mut self.f, t);
+        shift_in(&mut self.g, &mut self.h, t);
+    }
+
+    // Replaces $x$ by infinity, so that the remaining output is $(ay + b)/(ey + f)$. If that
+    // doesn't depend on $y$, $y$ is discarded as well.
+    fn exhaust_x(&mut self) {
+        self.xs = None;
+        self.c = Integer::ZERO;
+        self.d = Integer::ZERO;
+        self.g = Integer::ZERO;
+        self.h = Integer::ZERO;
+        if self.ys.is_some() && &self.a * &self.f == &self.b * &self.e {
+            if self.e == 0u32 {
+                swap(&mut self.a, &mut self.b);
+                swap(&mut self.e, &mut self.f);
+            }
+            self.exhaust_y();
+        }
+    }
+
+    // Replaces $y$ by infinity, so that the remaining output is $(ax + c)/(ex + g)$. If that
+    // doesn't depend on $x$, $x$ is discarded as well.
+    fn exhaust_y(&mut self) {
+        self.ys = None;
+        self.b = Integer::ZERO;
+        self.d = Integer::ZERO;
+        self.f = Integer::ZERO;
+        self.h = Integer::ZERO;
+        if self.xs.is_some() && &self.a * &self.g == &self.c * &self.e {
+            if self.e == 0u32 {
+                swap(&mut self.a, &mut self.c);
+                swap(&mut self.e, &mut self.g);
+            }
+            self.exhaust_x();
+        }
+    }
+
+    // Discards an input that the output doesn't depend on; this keeps Gosper's algorithm from
+    // reading an infinite input forever.
+    //
+    // The output is $(y(ax + c) + (bx + d))/(y(ex + g) + (fx + h))$, which doesn't depend on $y$
+    // iff $(ax + c)(fx + h) = (bx + d)(ex + g)$ identically. In that case it equals $(ax + c)/(ex
+    // + g)$, unless both are zero, in which case it equals $(bx + d)/(fx + h)$. The case of $x$
+    // is symmetric.
+    fn discard_inputs_if_constant(&mut self) {
+        let (a, b, c, d) = (&self.a, &self.b, &self.c, &self.d);
+        let (e, f, g, h) = (&self.e, &self.f, &self.g, &self.h);
+        if a * f == b * e && a * h + c * f == b * g + d * e && c * h == d * g {
+            if self.a == 0u32 && self.c == 0u32 && self.e == 0u32 && self.g == 0u32 {
+                swap(&mut self.a, &mut self.b);
+                swap(&mut self.c, &mut self.d);
+                swap(&mut self.e, &mut self.f);
+                swap(&mut self.g, &mut self.h);
+            }
+            self.exhaust_y();
+        } else if a * g == c * e && a * h + b * g == c * f + d * e && b * h == d * f {
+            if self.a == 0u32 && self.b == 0u32 && self.e == 0u32 && self.f == 0u32 {
+                swap(&mut self.a, &mut self.c);
+                swap(&mut self.b, &mut self.d);
+                swap(&mut self.e, &mut self.g);
+                swap(&mut self.f, &mut self.h);
+            }
+            self.exhaust_x();
+        }
+    }
+
+    // Replaces the output $z$ by $1/(z - q)$.
+    fn egest(&mut self, q: &Integer) {
+        self.a -= q * &self.e;
+        self.b -= q * &self.f;
+        self.c -= q * &self.g;
+        self.d -= q * &self.h;
+        swap(&mut self.a, &mut self.e);
+        swap(&mut self.b, &mut self.f);
+        swap(&mut self.c, &mut self.g);
+        swap(&mut self.d, &mut self.h);
+    }
+
+    fn next_term(&mut self) -> Option<Integer> {
+        loop {
+            if self.e == 0u32 && self.f == 0u32 && self.g == 0u32 && self.h == 0u32 {
+                // The remaining output is infinite, so the previous term was exact
+                return None;
+            }
+            // The corners are $(x, y) = (\infty, \infty)$, $(\infty, 1)$, $(1, \infty)$, and
+            // $(1, 1)$.
+            let a_b = &self.a + &self.b;
+            let e_f = &self.e + &self.f;
+            if let Some(q) = common_floor(&[
+                (self.a.clone(), self.e.clone()),
+                (a_b.clone(), e_f.clone()),
+                (&self.a + &self.c, &self.e + &self.g),
+                (a_b + &self.c + &self.d, e_f + &self.g + &self.h),
+            ]) {
+                self.egest(&q);
+                return Some(q);
+            }
+            // At least one input remains; otherwise, the corners would all be $a/e$
+            let read_x = self.ys.is_none() || self.xs.is_some() && self.read_x;
+            self.read_x = !read_x;
+            if read_x {
+                if let Some(t) = self.xs.as_mut().unwrap().next() {
+                    assert_ne!(t, 0u32);
+                    self.ingest_x(&Integer::from(t));
+                } else {
+                    self.exhaust_x();
+                }
+            } else if let Some(t) = self.ys.as_mut().unwrap().next() {
+                assert_ne!(t, 0u32);
+                self.ingest_y(&Integer::from(t));
+            } else {
+                self.exhaust_y();
+            }
+        }
+    }
+}
+
+impl<I: Iterator<Item = Natural>, J: Iterator<Item = Natural>> Iterator
+    for BihomographicContinuedFraction<I, J>
+{
+    type Item = Natural;
+
+    fn next(&mut self) -> Option<Natural> {
+        self.next_term().map(UnsignedAbs::unsigned_abs)
+    }
+}
+
+/// Computes the continued fraction of $(axy + bx + cy + d)/(exy + fx + gy + h)$, where $x$ and
+/// $y$ are given by their continued fractions, using Gosper's algorithm.
+///
+/// The inputs $x$ and $y$ and the output each have two components, in the same format as the
+/// output of
+/// [`continued_fraction`](crate::conversion::traits::ContinuedFraction::continued_fraction). The
+/// first is the first value of the continued fraction, which may be any [`Integer`] and is equal
+/// to the floor of the number. The second is an iterator of the remaining values, which are all
+/// positive. The input iterators may be infinite, and they are only read as far as necessary to
+/// produce each output value; values are read from the two inputs alternately. Sums, differences,
+/// products, and quotients are special cases; see [`add_continued_fractions`],
+/// [`sub_continued_fractions`], [`mul_continued_fractions`], and [`div_continued_fractions`].
+///
+/// The output iterator is finite if and only if the result is rational. If the result does not
+/// depend on one of the inputs, that input is not read.
+///
+/// If both inputs are irrational and the result is rational, as with $\sqrt{2} \cdot \sqrt{2}$,
+/// Gosper's algorithm can never determine the output value that follows the last one, and the
+/// iterator will not terminate. Similarly, if the denominator vanishes at an irrational point, the
+/// first output value is never found, and this function will not terminate.
+///
+/// $f((a, b, c, d, e, f, g, h), x, y) = (axy + bx + cy + d)/(exy + fx + gy + h)$.
+///
+/// # Worst-case complexity per iteration
+/// Unbounded; the number of input values that must be read to produce one output value depends on
+/// how close the output is to a rational number with a small denominator.
+///
+/// # Panics
+/// Panics if $exy + fx + gy + h = 0$, or if any [`Natural`] in the inputs is zero.
+///
+/// # Examples
+/// ```
+/// use itertools::Itertools;
+/// use malachite_base::strings::ToDebugString;
+/// use malachite_nz::integer::Integer;
+/// use malachite_nz::natural::Natural;
+/// use malachite_q::conversion::continued_fraction::gosper::bihomographic_continued_fraction;
+/// use std::iter::repeat;
+///
+/// let e = || {
+///     (
+///         Integer::from(2),
+///         (1u32..).flat_map(|k| [1, 2 * k, 1]).map(Natural::from),
+///     )
+/// };
+/// let sqrt_2 = || (Integer::from(1), repeat(Natural::from(2u32)));
+///
+/// // (e * sqrt(2) + 1) / (e + sqrt(2))
+/// let (floor, xs) = bihomographic_continued_fraction(
+///     [1, 0, 0, 1, 0, 1, 1, 0].map(Integer::from),
+///     e(),
+///     sqrt_2(),
+/// );
+/// assert_eq!(floor, 1);
+/// assert_eq!(
+///     xs.take(6).collect_vec().to_debug_string(),
+///     "[5, 1, 4, 6, 4, 2]"
+/// );
+///
+/// // e^2
+/// let (floor, xs) =
+///     bihomographic_continued_fraction([1, 0, 0, 0, 0, 0, 0, 1].map(Integer::from), e(), e());
+/// assert_eq!(floor, 7);
+/// assert_eq!(
+///     xs.take(10).collect_vec().to_debug_string(),
+///     "[2, 1, 1, 3, 18, 5, 1, 1, 6, 30]"
+/// );
+/// ```
+pub fn bihomographic_continued_fraction<
+    I: Iterator<Item = Natural>,
+    J: Iterator<Item = Natural>,
+>(
+    coefficients: [Integer; 8],
+    x: (Integer, I),
+    y: (Integer, J),
+) -> (Integer, BihomographicContinuedFraction<I, J>) {
+    let [a, b, c, d, e, f, g, h] = coefficients;
+    let (x_floor, xs) = x;
+    let (y_floor, ys) = y;
+    let mut cf = BihomographicContinuedFraction {
+        xs: Some(xs),
+        ys: Some(ys),
+        read_x: true,
+        a,
+        b,
+        c,
+        d,
+        e,
+        f,
+        g,
+        h,
+    };
+    cf.ingest_x(&x_floor);
+    cf.ingest_y(&y_floor);
+    cf.discard_inputs_if_constant();
+    let Some(floor) = cf.next_term() else {
+        panic!("division by zero");
+    };
+    (floor, cf)
+}
+
+/// Computes the continued fraction of $x + y$, where $x$ and $y$ are given by their continued
+/// fractions.
+///
+/// This is [`bihomographic_continued_fraction`] with coefficients $(0, 1, 1, 0, 0, 0, 0, 1)$; see
+/// its documentation for more.
+///
+/// # Worst-case complexity per iteration
+/// Unbounded.
+///
+/// # Panics
+/// Panics if any [`Natural`] in the inputs is zero.
+///
+/// # Examples
+/// ```
+/// use itertools::Itertools;
+/// use malachite_base::strings::ToDebugString;
+/// use malachite_nz::integer::Integer;
+/// use malachite_nz::natural::Natural;
+/// use malachite_q::conversion::continued_fraction::gosper::add_continued_fractions;
+/// use malachite_q::conversion::traits::ContinuedFraction;
+/// use malachite_q::Rational;
+/// use std::iter::repeat;
+///
+/// let (floor, xs) = add_continued_fractions(
+///     Rational::from_signeds(1, 3).continued_fraction(),
+///     Rational::from_signeds(1, 6).continued_fraction(),
+/// );
+/// assert_eq!(floor, 0);
+/// assert_eq!(xs.collect_vec().to_debug_string(), "[2]");
+///
+/// let e = (
+///     Integer::from(2),
+///     (1u32..).flat_map(|k| [1, 2 * k, 1]).map(Natural::from),
+/// );
+/// let sqrt_2 = (Integer::from(1), repeat(Natural::from(2u32)));
+/// let (floor, xs) = add_continued_fractions(e, sqrt_2);
+/// assert_eq!(floor, 4);
+/// assert_eq!(
+///     xs.take(10).collect_vec().to_debug_string(),
+///     "[7, 1, 1, 4, 1, 3, 2, 1, 3, 2]"
+/// );
+/// ```
+#[inline]
+pub fn add_continued_fractions<I: Iterator<Item = Natural>, J: Iterator<Item = Natural>>(
+    x: (Integer, I),
+    y: (Integer, J),
+) -> (Integer, BihomographicContinuedFraction<I, J>) {
+    bihomographic_continued_fraction(
+        [
+            Integer::ZERO,
+            Integer::ONE,
+            Integer::ONE,
+            Integer::ZERO,
+            Integer::ZERO,
+            Integer::ZERO,
+            Integer::ZERO,
+            Integer::ONE,
+        ],
+        x,
+        y,
+    )
+}
+
+/// Computes the continued fraction of $x - y$, where $x$ and $y$ are given by their continued
+/// fractions.
+///
+/// This is [`bihomographic_continued_fraction`] with coefficients $(0, 1, -1, 0, 0, 0, 0, 1)$;
+/// see its documentation for more.
+///
+/// # Worst-case complexity per iteration
+/// Unbounded.
+///
+/// # Panics
+/// Panics if any [`Natural`] in the inputs is zero.
+///
+/// # Examples
+/// ```
+/// use itertools::Itertools;
+/// use malachite_base::strings::ToDebugString;
+/// use malachite_nz::integer::Integer;
+/// use malachite_nz::natural::Natural;
+/// use malachite_q::conversion::continued_fraction::gosper::sub_continued_fractions;
+/// use malachite_q::conversion::traits::ContinuedFraction;
+/// use malachite_q::Rational;
+/// use std::iter::repeat;
+///
+/// let (floor, xs) = sub_continued_fractions(
+///     Rational::from_signeds(1, 3).continued_fraction(),
+///     Rational::from_signeds(1, 2).continued_fraction(),
+/// );
+/// assert_eq!(floor, -1);
+/// assert_eq!(xs.collect_vec().to_debug_string(), "[1, 5]");
+///
+/// let e = (
+///     Integer::from(2),
+///     (1u32..).flat_map(|k| [1, 2 * k, 1]).map(Natural::from),
+/// );
+/// let sqrt_2 = (Integer::from(1), repeat(Natural::from(2u32)));
+/// let (floor, xs) = sub_continued_fractions(e, sqrt_2);
+/// assert_eq!(floor, 1);
+/// assert_eq!(
+///     xs.take(10).collect_vec().to_debug_string(),
+///     "[3, 3, 2, 6, 3, 17, 1, 1, 3, 3]"
+/// );
+/// ```
+#[inline]
+pub fn sub_continued_fractions<I: Iterator<Item = Natural>, J: Iterator<Item = Natural>>(
+    x: (Integer, I),
+    y: (Integer, J),
+) -> (Integer, BihomographicContinuedFraction<I, J>) {
+    bihomographic_continued_fraction(
+        [
+            Integer::ZERO,
+            Integer::ONE,
+            Integer::NEGATIVE_ONE,
+            Integer::ZERO,
+            Integer::ZERO,
+            Integer::ZERO,
+            Integer::ZERO,
+            Integer::ONE,
+        ],
+        x,
+        y,
+    )
+}
+
+/// Computes the continued fraction of $xy$, where $x$ and $y$ are given by their continued
+/// fractions.
+///
+/// This is [`bihomographic_continued_fraction`] with coefficients $(1, 0, 0, 0, 0, 0, 0, 1)$; see
+/// its documentation for more.
+///
+/// # Worst-case complexity per iteration
+/// Unbounded.
+///
+/// # Panics
+/// Panics if any [`Natural`] in the inputs is zero.
+///
+/// # Examples
+/// ```
+/// use itertools::Itertools;
+/// use malachite_base::strings::ToDebugString;
+/// use malachite_nz::integer::Integer;
+/// use malachite_nz::natural::Natural;
+/// use malachite_q::conversion::continued_fraction::gosper::mul_continued_fractions;
+/// use malachite_q::conversion::traits::ContinuedFraction;
+/// use malachite_q::Rational;
+/// use std::iter::repeat;
+///
+/// let (floor, xs) = mul_continued_fractions(
+///     Rational::from_signeds(2, 3).continued_fraction(),
+///     Rational::from_signeds(-9, 4).continued_fraction(),
+/// );
+/// assert_eq!(floor, -2);
+/// assert_eq!(xs.collect_vec().to_debug_string(), "[2]");
+///
+/// let e = (
+///     Integer::from(2),
+///     (1u32..).flat_map(|k| [1, 2 * k, 1]).map(Natural::from),
+/// );
+/// let sqrt_2 = (Integer::from(1), repeat(Natural::from(2u32)));
+/// let (floor, xs) = mul_continued_fractions(e, sqrt_2);
+/// assert_eq!(floor, 3);
+/// assert_eq!(
+///     xs.take(10).collect_vec().to_debug_string(),
+///     "[1, 5, 2, 2, 1, 1, 1, 1, 1, 1]"
+/// );
+/// ```
+#[inline]
+pub fn mul_continued_fractions<I: Iterator<Item = Natural>, J: Iterator<Item = Natural>>(
+    x: (Integer, I),
+    y: (Integer, J),
+) -> (Integer, BihomographicContinuedFraction<I, J>) {
+    bihomographic_continued_fraction(
+        [
+            Integer::ONE,
+            Integer::ZERO,
+            Integer::ZERO,
+            Integer::ZERO,
+            Integer::ZERO,
+            Integer::ZERO,
+            Integer::ZERO,
+            Integer::ONE,
+        ],
+        x,
+        y,
+    )
+}
+
+/// Computes the continued fraction of $x/y$, where $x$ and $y$ are given by their continued
+/// fractions.
+///
+/// This is [`bihomographic_continued_fraction`] with coefficients $(0, 1, 0, 0, 0, 0, 1, 0)$; see
+/// its documentation for more.
+///
+/// # Worst-case complexity per iteration
+/// Unbounded.
+///
+/// # Panics
+/// Panics if $y$ is zero, or if any [`Natural`] in the inputs is zero.
+///
+/// # Examples
+/// ```
+/// use itertools::Itertools;
+/// use malachite_base::strings::ToDebugString;
+/// use malachite_nz::integer::Integer;
+/// use malachite_nz::natural::Natural;
+/// use malachite_q::conversion::continued_fraction::gosper::div_continued_fractions;
+/// use malachite_q::conversion::traits::ContinuedFraction;
+/// use malachite_q::Rational;
+/// use std::iter::repeat;
+///
+/// let (floor, xs) = div_continued_fractions(
+///     Rational::from_signeds(2, 3).continued_fraction(),
+///     Rational::from_signeds(4, 5).continued_fraction(),
+/// );
+/// assert_eq!(floor, 0);
+/// assert_eq!(xs.collect_vec().to_debug_string(), "[1, 5]");
+///
+/// let e = (
+///     Integer::from(2),
+///     (1u32..).flat_map(|k| [1, 2 * k, 1]).map(Natural::from),
+/// );
+/// let sqrt_2 = (Integer::from(1), repeat(Natural::from(2u32)));
+/// let (floor, xs) = div_continued_fractions(e, sqrt_2);
+/// assert_eq!(floor, 1);
+/// assert_eq!(
+///     xs.take(10).collect_vec().to_debug_string(),
+///     "[1, 11, 1, 5, 4, 3, 6, 1, 4, 1]"
+/// );
+/// ```
+#[inline]
+pub fn div_continued_fractions<I: Iterator<Item = Natural>, J: Iterator<Item = Natural>>(
+    x: (Integer, I),
+    y: (Integer, J),
+) -> (Integer, BihomographicContinuedFraction<I, J>) {
+    bihomographic_continued_fraction(
+        [
+            Integer::ZERO,
+            Integer::ONE,
+            Integer::ZERO,
+            Integer::ZERO,
+            Integer::ZERO,
+            Integer::ZERO,
+            Integer::ONE,
+            Integer::ZERO,
+        ],
+        x,
+        y,
+    )
+}
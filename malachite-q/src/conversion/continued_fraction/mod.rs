@@ -11,6 +11,9 @@
 pub mod convergents;
 /// Functions for constructing a [`Rational`](crate::Rational) from a continued fraction.
 pub mod from_continued_fraction;
+/// Gosper's algorithms for computing the continued fractions of homographic and bihomographic
+/// functions of numbers, such as sums and products, from the numbers' continued fractions.
+pub mod gosper;
 /// Implementations of [`ContinuedFraction`](super::traits::ContinuedFraction), a trait for
 /// generating the continued fraction of a number.
 pub mod to_continued_fraction;
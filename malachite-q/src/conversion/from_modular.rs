@@ -0,0 +1,132 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Rational;
+use core::mem::swap;
+use malachite_base::num::arithmetic::traits::{DivMod, Gcd};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::logic::traits::{NotAssign, SignificantBits};
+use malachite_nz::natural::arithmetic::gcd::half_gcd::half_gcd_matrix;
+use malachite_nz::natural::Natural;
+
+// Uses the half-gcd algorithm to perform the first Euclidean steps on $m$ and $r$, returning $r_0$,
+// $r_1$, $t_0$, $t_1$, and the sign of $r_1$, as described in `from_modular`. The reduced
+// remainders have more than half as many limbs as $m$, so if $N$ has at most half as many bits as
+// $m$, no remainder greater than $N$ has been skipped. The remainders are checked against $N$
+// anyway, and `None` is returned if the half-gcd algorithm went too far or couldn't reduce $m$ and
+// $r$ at all.
+//
+// Since $(m; r) = M (x; y)$, where $M$ has determinant 1, $x \equiv -m_{01} r$ and $y \equiv
+// m_{00} r \mod m$.
+fn half_gcd_start(
+    residue: &Natural,
+    modulus: &Natural,
+    num_bound: &Natural,
+) -> Option<(Natural, Natural, Natural, Natural, bool)> {
+    if residue <= num_bound || num_bound.significant_bits() > modulus.significant_bits() >> 1 {
+        return None;
+    }
+    let (x, y, [m_00, m_01, _, _]) = half_gcd_matrix(modulus, residue)?;
+    if x <= *num_bound || y <= *num_bound {
+        return None;
+    }
+    Some(if x >= y {
+        (x, y, m_01, m_00, false)
+    } else {
+        (y, x, m_00, m_01, true)
+    })
+}
+
+impl Rational {
+    /// Recovers a [`Rational`] from its residue modulo a [`Natural`], given bounds on the
+    /// numerator and denominator. This is known as rational reconstruction.
+    ///
+    /// Given a residue $r$, a modulus $m$, and bounds $N$ and $D$, this function finds a
+    /// [`Rational`] $n/d$ in lowest terms such that $|n| \leq N$, $0 < d \leq D$, $d$ is coprime to
+    /// $m$, and $n \equiv rd \mod m$. If $2ND < m$, there is at most one such [`Rational`], and it
+    /// is found whenever it exists. Otherwise, the returned [`Rational`], if any, still satisfies
+    /// all of these conditions, but it may not be the only one. If no [`Rational`] is found,
+    /// `None` is returned.
+    ///
+    /// Typically $N$ and $D$ are both chosen to be $\lfloor \sqrt{m/2} \rfloor$.
+    ///
+    /// The extended Euclidean algorithm is run on $m$ and $r$, and stopped at the first remainder
+    /// that is no greater than $N$; that remainder and the corresponding cofactor of $r$ give the
+    /// numerator and denominator. When $N$ has at most half as many bits as $m$, as it typically
+    /// does, the half-gcd algorithm is used to skip to remainders that are only slightly larger
+    /// than $\sqrt{m}$, and plain Euclidean steps are only used after that.
+    ///
+    /// # Worst-case complexity
+    /// $T(n) = O(n (\log n)^2 \log\log n)$
+    ///
+    /// $M(n) = O(n \log n)$
+    ///
+    /// where $T$ is time, $M$ is additional memory, and $n$ is `modulus.significant_bits()`.
+    ///
+    /// # Panics
+    /// Panics if `residue` is greater than or equal to `modulus`.
+    ///
+    /// # Examples
+    /// ```
+    /// use malachite_base::strings::ToDebugString;
+    /// use malachite_nz::natural::Natural;
+    /// use malachite_q::Rational;
+    ///
+    /// // 2/3 = 34 mod 100, -3/7 = 71 mod 100
+    /// let m = Natural::from(100u32);
+    /// let bound = Natural::from(7u32);
+    /// assert_eq!(
+    ///     Rational::from_modular(&Natural::from(34u32), &m, &bound, &bound).to_debug_string(),
+    ///     "Some(2/3)"
+    /// );
+    /// assert_eq!(
+    ///     Rational::from_modular(&Natural::from(71u32), &m, &bound, &bound).to_debug_string(),
+    ///     "Some(-3/7)"
+    /// );
+    /// assert_eq!(
+    ///     Rational::from_modular(&Natural::from(50u32), &m, &bound, &bound).to_debug_string(),
+    ///     "None"
+    /// );
+    /// ```
+    pub fn from_modular(
+        residue: &Natural,
+        modulus: &Natural,
+        num_bound: &Natural,
+        den_bound: &Natural,
+    ) -> Option<Rational> {
+        assert!(
+            residue < modulus,
+            "residue must be reduced mod modulus, but {residue} >= {modulus}"
+        );
+        // Each remainder $r_i$ satisfies $r_i \equiv \pm t_i r \mod m$, where the sign alternates
+        // and starts out positive for $r_1 = r$.
+        let (mut r_0, mut r_1, mut t_0, mut t_1, mut negative) =
+            half_gcd_start(residue, modulus, num_bound).unwrap_or_else(|| {
+                (
+                    modulus.clone(),
+                    residue.clone(),
+                    Natural::ZERO,
+                    Natural::ONE,
+                    false,
+                )
+            });
+        while r_1 > *num_bound {
+            let (q, r) = (&r_0).div_mod(&r_1);
+            r_0 = r;
+            swap(&mut r_0, &mut r_1);
+            t_0 += q * &t_1;
+            swap(&mut t_0, &mut t_1);
+            negative.not_assign();
+        }
+        if t_1 > *den_bound || (&r_1).gcd(&t_1) != 1u32 {
+            None
+        } else {
+            Some(Rational::from_sign_and_naturals(!negative, r_1, t_1))
+        }
+    }
+}
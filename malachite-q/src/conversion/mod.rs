@@ -20,6 +20,9 @@ pub mod from_float_simplest;
 /// Implementations of the [`From`] trait for converting an
 /// [`Integer`](malachite_nz::integer::Integer) to a [`Rational`](crate::Rational).
 pub mod from_integer;
+/// A function for recovering a [`Rational`](crate::Rational) from its residue modulo a
+/// [`Natural`](malachite_nz::natural::Natural).
+pub mod from_modular;
 /// Implementations of the [`From`] trait for converting a
 /// [`Integer`](malachite_nz::natural::Natural) to a [`Rational`](crate::Rational).
 pub mod from_natural;
@@ -0,0 +1,28 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Rational;
+use malachite_base::num::conversion::traits::ExactFrom;
+
+// Uses the Akiyama–Tanigawa algorithm, which computes $ with  = 1/2$, so the sign of
+// $ is fixed at the end.
+pub fn bernoulli_naive(n: u64) -> Rational {
+    let mut xs: Vec<Rational> = Vec::with_capacity(usize::exact_from(n) + 1);
+    for m in 0..=n {
+        xs.push(Rational::from_unsigneds(1u64, m + 1));
+        for j in (1..=usize::exact_from(m)).rev() {
+            xs[j - 1] = Rational::from(j) * (&xs[j - 1] - &xs[j]);
+        }
+    }
+    let b = xs.swap_remove(0);
+    if n == 1 {
+        -b
+    } else {
+        b
+    }
+}
@@ -0,0 +1,13 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Rational;
+
+pub fn harmonic_naive(n: u64) -> Rational {
+    (1..=n).map(|k| Rational::from_unsigneds(1u64, k)).sum()
+}
@@ -8,7 +8,9 @@
 
 pub mod add;
 pub mod approximate;
+pub mod bernoulli;
 pub mod div;
+pub mod harmonic;
 pub mod mul;
 pub mod sign;
 pub mod simplest_rational_in_interval;
@@ -0,0 +1,41 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use crate::Rational;
+use malachite_base::num::arithmetic::traits::{DivMod, Gcd};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::logic::traits::NotAssign;
+use malachite_nz::natural::Natural;
+use std::mem::swap;
+
+pub fn from_modular_naive(
+    residue: &Natural,
+    modulus: &Natural,
+    num_bound: &Natural,
+    den_bound: &Natural,
+) -> Option<Rational> {
+    assert!(residue < modulus);
+    let mut r_0 = modulus.clone();
+    let mut r_1 = residue.clone();
+    let mut t_0 = Natural::ZERO;
+    let mut t_1 = Natural::ONE;
+    let mut negative = false;
+    while r_1 > *num_bound {
+        let (q, r) = (&r_0).div_mod(&r_1);
+        r_0 = r;
+        swap(&mut r_0, &mut r_1);
+        t_0 += q * &t_1;
+        swap(&mut t_0, &mut t_1);
+        negative.not_assign();
+    }
+    if t_1 > *den_bound || (&r_1).gcd(&t_1) != 1u32 {
+        None
+    } else {
+        Some(Rational::from_sign_and_naturals(!negative, r_1, t_1))
+    }
+}
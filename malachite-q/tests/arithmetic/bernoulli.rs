@@ -0,0 +1,62 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::Parity;
+use malachite_base::num::conversion::traits::IsInteger;
+use malachite_base::num::factorization::traits::{Divisors, IsPrime};
+use malachite_base::test_util::generators::unsigned_gen_var_5;
+use malachite_q::arithmetic::traits::Bernoulli;
+use malachite_q::test_util::arithmetic::bernoulli::bernoulli_naive;
+use malachite_q::Rational;
+
+#[test]
+fn test_bernoulli() {
+    fn test(n: u64, out: &str) {
+        let b = Rational::bernoulli(n);
+        assert!(b.is_valid());
+        assert_eq!(b.to_string(), out);
+        assert_eq!(bernoulli_naive(n).to_string(), out);
+    }
+    test(0, "1");
+    test(1, "-1/2");
+    test(2, "1/6");
+    test(3, "0");
+    test(4, "-1/30");
+    test(6, "1/42");
+    test(30, "8615841276005/14322");
+    test(31, "0");
+    test(32, "-7709321041217/510");
+    test(34, "2577687858367/6");
+    test(
+        100,
+        "-94598037819122125295227433069493721872702841533066936133385696204311395415197247711/\
+        33330",
+    );
+}
+
+#[test]
+fn bernoulli_properties() {
+    unsigned_gen_var_5().test_properties_with_limit(100, |n| {
+        let b = Rational::bernoulli(n);
+        assert!(b.is_valid());
+        assert_eq!(bernoulli_naive(n), b);
+        if n > 1 && n.odd() {
+            assert_eq!(b, 0u32);
+        }
+        // The von Staudt–Clausen theorem
+        if n != 0 && n.even() {
+            let mut sum = b;
+            for d in n.divisors() {
+                if (d + 1).is_prime() {
+                    sum += Rational::from_unsigneds(1u64, d + 1);
+                }
+            }
+            assert!(sum.is_integer());
+        }
+    });
+}
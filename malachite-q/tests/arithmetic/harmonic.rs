@@ -0,0 +1,52 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::conversion::traits::IsInteger;
+use malachite_base::test_util::generators::unsigned_gen_var_5;
+use malachite_q::arithmetic::traits::Harmonic;
+use malachite_q::test_util::arithmetic::harmonic::harmonic_naive;
+use malachite_q::Rational;
+
+#[test]
+fn test_harmonic() {
+    fn test(n: u64, out: &str) {
+        let h = Rational::harmonic(n);
+        assert!(h.is_valid());
+        assert_eq!(h.to_string(), out);
+        assert_eq!(harmonic_naive(n).to_string(), out);
+    }
+    test(0, "0");
+    test(1, "1");
+    test(2, "3/2");
+    test(3, "11/6");
+    test(10, "7381/2520");
+    test(
+        100,
+        "14466636279520351160221518043104131447711/2788815009188499086581352357412492142272",
+    );
+}
+
+#[test]
+fn harmonic_properties() {
+    unsigned_gen_var_5().test_properties_with_limit(100, |n| {
+        let h = Rational::harmonic(n);
+        assert!(h.is_valid());
+        assert_eq!(harmonic_naive(n), h);
+        assert_eq!(
+            Rational::harmonic(n + 1) - h,
+            Rational::from_unsigneds(1u64, n + 1)
+        );
+    });
+
+    // For $n > 1$, $H_n$ is not an integer.
+    unsigned_gen_var_5().test_properties_with_limit(100, |n| {
+        if n > 1 {
+            assert!(!Rational::harmonic(n).is_integer());
+        }
+    });
+}
@@ -0,0 +1,87 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_nz::test_util::generators::integer_pair_gen;
+use malachite_q::arithmetic::traits::Mediant;
+use malachite_q::test_util::generators::{
+    rational_gen, rational_pair_gen, rational_pair_gen_var_3,
+};
+use malachite_q::Rational;
+use std::str::FromStr;
+
+#[test]
+fn test_mediant() {
+    let test = |s, t, out| {
+        let u = Rational::from_str(s).unwrap();
+        let v = Rational::from_str(t).unwrap();
+
+        let n = u.clone().mediant(v.clone());
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = u.clone().mediant(&v);
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = (&u).mediant(v.clone());
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+
+        let n = (&u).mediant(&v);
+        assert_eq!(n.to_string(), out);
+        assert!(n.is_valid());
+    };
+    test("0", "0", "0");
+    test("0", "1", "1/2");
+    test("1", "3", "2");
+    test("1", "2", "3/2");
+    test("1/2", "2/3", "3/5");
+    test("1/3", "1/2", "2/5");
+    test("1/2", "1/2", "1/2");
+    test("-1/2", "1/3", "0");
+    test("-2/3", "1/4", "-1/7");
+    test("-3/2", "-5/3", "-8/5");
+    test("22/7", "333/106", "355/113");
+}
+
+#[test]
+fn mediant_properties() {
+    rational_pair_gen().test_properties(|(x, y)| {
+        let m = (&x).mediant(&y);
+        assert!(m.is_valid());
+        assert_eq!(x.clone().mediant(y.clone()), m);
+        assert_eq!(x.clone().mediant(&y), m);
+        assert_eq!((&x).mediant(y.clone()), m);
+        assert_eq!((&y).mediant(&x), m);
+        assert_eq!((-&x).mediant(-&y), -&m);
+        assert!(*m.denominator_ref() <= x.denominator_ref() + y.denominator_ref());
+        if x <= y {
+            assert!(x <= m && m <= y);
+        } else {
+            assert!(y <= m && m <= x);
+        }
+    });
+
+    rational_pair_gen_var_3().test_properties(|(x, y)| {
+        let m = (&x).mediant(&y);
+        assert!(x < m);
+        assert!(m < y);
+    });
+
+    rational_gen().test_properties(|x| {
+        assert_eq!((&x).mediant(&x), x);
+        assert_eq!((&x).mediant(-&x), 0u32);
+    });
+
+    integer_pair_gen().test_properties(|(x, y)| {
+        assert_eq!(
+            Rational::from(&x).mediant(Rational::from(&y)),
+            Rational::from_integers(x + y, 2u32.into())
+        );
+    });
+}
@@ -0,0 +1,191 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use itertools::Itertools;
+use malachite_base::iterators::prefix_to_string;
+use malachite_base::num::arithmetic::traits::{Gcd, Reciprocal};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::{ExactFrom, IsInteger};
+use malachite_base::test_util::generators::{bool_vec_gen, unsigned_gen_var_5};
+use malachite_nz::natural::Natural;
+use malachite_q::arithmetic::traits::Mediant;
+use malachite_q::conversion::traits::ContinuedFraction;
+use malachite_q::exhaustive::exhaustive_non_negative_rationals;
+use malachite_q::test_util::generators::{rational_gen_var_3, rational_gen_var_7};
+use malachite_q::Rational;
+use std::iter::successors;
+use std::str::FromStr;
+
+#[test]
+fn test_farey_sequence() {
+    let test = |n, out| {
+        assert_eq!(prefix_to_string(Rational::farey_sequence(n), 30), out);
+    };
+    test(0, "[]");
+    test(1, "[0, 1]");
+    test(2, "[0, 1/2, 1]");
+    test(3, "[0, 1/3, 1/2, 2/3, 1]");
+    test(4, "[0, 1/4, 1/3, 1/2, 2/3, 3/4, 1]");
+    test(5, "[0, 1/5, 1/4, 1/3, 2/5, 1/2, 3/5, 2/3, 3/4, 4/5, 1]");
+    test(
+        8,
+        "[0, 1/8, 1/7, 1/6, 1/5, 1/4, 2/7, 1/3, 3/8, 2/5, 3/7, 1/2, 4/7, 3/5, 5/8, 2/3, 5/7, 3/4, \
+        4/5, 5/6, 6/7, 7/8, 1]",
+    );
+    assert_eq!(Rational::farey_sequence(100).count(), 3045);
+    assert_eq!(
+        prefix_to_string(Rational::farey_sequence(u64::MAX), 4),
+        "[0, 1/18446744073709551615, 1/18446744073709551614, 1/18446744073709551613, ...]"
+    );
+}
+
+#[test]
+fn farey_sequence_properties() {
+    unsigned_gen_var_5::<u64>().test_properties_with_limit(100, |n| {
+        let xs = Rational::farey_sequence(n).collect_vec();
+        if n == 0 {
+            assert!(xs.is_empty());
+            return;
+        }
+        assert!(xs.iter().all(Rational::is_valid));
+        assert_eq!(xs[0], 0u32);
+        assert_eq!(*xs.last().unwrap(), 1u32);
+        assert!(xs.iter().all(|x| *x.denominator_ref() <= n));
+        let count: usize = (1..=n)
+            .map(|q| (0..=q).filter(|&p| p.gcd(q) == 1).count())
+            .sum();
+        assert_eq!(xs.len(), count);
+        for (x, y) in xs.iter().tuple_windows() {
+            assert!(x < y);
+            assert_eq!(
+                x.denominator_ref() * y.numerator_ref() - x.numerator_ref() * y.denominator_ref(),
+                1u32
+            );
+            // The first `Rational` to appear between two neighbors is their mediant.
+            assert!(*x.mediant(y).denominator_ref() > n);
+        }
+        assert_eq!(
+            Rational::farey_sequence(n + 1)
+                .filter(|x| *x.denominator_ref() <= n)
+                .collect_vec(),
+            xs
+        );
+    });
+}
+
+#[test]
+fn test_stern_brocot_path() {
+    let test = |s, out: &[bool]| {
+        let x = Rational::from_str(s).unwrap();
+        assert_eq!(x.stern_brocot_path().collect_vec(), out);
+        let y = Rational::from_stern_brocot_path(out.iter().copied());
+        assert!(y.is_valid());
+        assert_eq!(y, x);
+    };
+    test("1", &[]);
+    test("2", &[true]);
+    test("1/2", &[false]);
+    test("3", &[true, true]);
+    test("3/2", &[true, false]);
+    test("2/3", &[false, true]);
+    test("3/7", &[false, false, true, true]);
+    test(
+        "22/7",
+        &[true, true, true, false, false, false, false, false, false],
+    );
+    test("21/13", &[true, false, true, false, true, false]);
+    test("13/21", &[false, true, false, true, false, true]);
+}
+
+#[test]
+#[should_panic]
+fn stern_brocot_path_fail_1() {
+    Rational::ZERO.stern_brocot_path();
+}
+
+#[test]
+#[should_panic]
+fn stern_brocot_path_fail_2() {
+    Rational::from_signeds(-1, 2).stern_brocot_path();
+}
+
+#[test]
+fn stern_brocot_path_properties() {
+    rational_gen_var_7().test_properties(|x| {
+        if x <= 0u32 {
+            return;
+        }
+        let path = x.stern_brocot_path().collect_vec();
+        assert_eq!(Rational::from_stern_brocot_path(path.iter().copied()), x);
+        // Runs of steps correspond to terms of the continued fraction.
+        let (floor, terms) = (&x).continued_fraction();
+        let term_sum = Natural::exact_from(floor) + terms.sum::<Natural>();
+        assert_eq!(Natural::from(path.len()) + Natural::ONE, term_sum);
+        assert_eq!(
+            x.reciprocal().stern_brocot_path().collect_vec(),
+            path.iter().map(|&b| !b).collect_vec()
+        );
+    });
+
+    bool_vec_gen().test_properties(|path| {
+        let x = Rational::from_stern_brocot_path(path.iter().copied());
+        assert!(x.is_valid());
+        assert!(x > 0u32);
+        assert_eq!(x.stern_brocot_path().collect_vec(), path);
+        // The children of a node are the mediants of the node with its nearest ancestors.
+        let mut left = path.clone();
+        left.push(false);
+        let mut right = path.clone();
+        right.push(true);
+        assert!(Rational::from_stern_brocot_path(left) < x);
+        assert!(Rational::from_stern_brocot_path(right) > x);
+    });
+}
+
+#[test]
+fn test_calkin_wilf_successor() {
+    let test = |s, out| {
+        let x = Rational::from_str(s).unwrap();
+        let y = x.calkin_wilf_successor();
+        assert!(y.is_valid());
+        assert_eq!(y.to_string(), out);
+    };
+    test("0", "1");
+    test("1", "1/2");
+    test("1/2", "2");
+    test("2", "1/3");
+    test("3", "1/4");
+    test("3/5", "5/2");
+    test("5/2", "2/5");
+    test("22/7", "7/27");
+    test("1000000", "1/1000001");
+}
+
+#[test]
+#[should_panic]
+fn calkin_wilf_successor_fail() {
+    Rational::from_signeds(-1, 2).calkin_wilf_successor();
+}
+
+#[test]
+fn calkin_wilf_successor_properties() {
+    rational_gen_var_3().test_properties(|x| {
+        let y = x.calkin_wilf_successor();
+        assert!(y.is_valid());
+        assert!(y > 0u32);
+        if x.is_integer() {
+            assert_eq!(y, (x + Rational::ONE).reciprocal());
+        }
+    });
+
+    assert!(
+        successors(Some(Rational::ZERO), |x| Some(x.calkin_wilf_successor()))
+            .take(10000)
+            .eq(exhaustive_non_negative_rationals().take(10000))
+    );
+}
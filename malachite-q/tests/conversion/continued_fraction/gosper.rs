@@ -0,0 +1,367 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use itertools::Itertools;
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::strings::ToDebugString;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::natural_vec_integer_pair_gen_var_1;
+use malachite_q::conversion::continued_fraction::gosper::*;
+use malachite_q::conversion::traits::ContinuedFraction;
+use malachite_q::test_util::generators::{
+    rational_integer_integer_triple_gen, rational_pair_gen, rational_pair_gen_var_1,
+};
+use malachite_q::Rational;
+use std::iter::{empty, repeat};
+use std::str::FromStr;
+
+fn e() -> (Integer, impl Iterator<Item = Natural>) {
+    (
+        Integer::from(2),
+        (1u32..).flat_map(|k| [1, 2 * k, 1]).map(Natural::from),
+    )
+}
+
+fn sqrt_2() -> (Integer, impl Iterator<Item = Natural>) {
+    (Integer::ONE, repeat(Natural::from(2u32)))
+}
+
+fn sqrt_3() -> (Integer, impl Iterator<Item = Natural>) {
+    (
+        Integer::ONE,
+        [1u32, 2].into_iter().cycle().map(Natural::from),
+    )
+}
+
+fn rational(s: &str) -> (Integer, impl Iterator<Item = Natural>) {
+    Rational::from_str(s).unwrap().continued_fraction()
+}
+
+fn coefficients<const N: usize>(xs: [i32; N]) -> [Integer; N] {
+    xs.map(Integer::from)
+}
+
+fn prefix(cf: (Integer, impl Iterator<Item = Natural>), n: usize) -> String {
+    let (floor, xs) = cf;
+    (floor, xs.take(n).collect_vec()).to_debug_string()
+}
+
+fn test_homographic_helper<I: Iterator<Item = Natural>>(
+    cs: [i32; 4],
+    x: (Integer, I),
+    n: usize,
+    out: &str,
+) {
+    assert_eq!(
+        prefix(homographic_continued_fraction(coefficients(cs), x), n),
+        out
+    );
+}
+
+fn test_bihomographic_helper<I: Iterator<Item = Natural>, J: Iterator<Item = Natural>>(
+    cs: [i32; 8],
+    x: (Integer, I),
+    y: (Integer, J),
+    n: usize,
+    out: &str,
+) {
+    assert_eq!(
+        prefix(bihomographic_continued_fraction(coefficients(cs), x, y), n),
+        out
+    );
+}
+
+#[test]
+fn test_homographic_continued_fraction() {
+    let test_rational = |cs: [i32; 4], x: &str, out: &str| {
+        test_homographic_helper(cs, rational(x), usize::MAX, out);
+    };
+    test_rational([1, 0, 0, 1], "0", "(0, [])");
+    test_rational([1, 0, 0, 1], "-22/7", "(-4, [1, 6])");
+    test_rational([0, 1, 1, 0], "22/7", "(0, [3, 7])");
+    test_rational([1, 1, 0, 2], "2/3", "(0, [1, 5])");
+    test_rational([3, -1, 0, 1], "1/3", "(0, [])");
+    test_rational([-1, 0, 0, 1], "1/2", "(-1, [2])");
+    test_rational([2, 1, 1, 3], "4", "(1, [3, 2])");
+    // constant
+    test_rational([2, 4, 1, 2], "5/8", "(2, [])");
+    test_rational([0, 3, 0, 4], "5/8", "(0, [1, 3])");
+
+    test_homographic_helper(
+        [1, 0, 0, 1],
+        sqrt_2(),
+        10,
+        "(1, [2, 2, 2, 2, 2, 2, 2, 2, 2, 2])",
+    );
+    test_homographic_helper(
+        [-1, 0, 0, 1],
+        sqrt_2(),
+        10,
+        "(-2, [1, 1, 2, 2, 2, 2, 2, 2, 2, 2])",
+    );
+    test_homographic_helper(
+        [1, 0, 0, 2],
+        sqrt_2(),
+        10,
+        "(0, [1, 2, 2, 2, 2, 2, 2, 2, 2, 2])",
+    );
+    test_homographic_helper(
+        [3, 0, 0, 1],
+        sqrt_2(),
+        10,
+        "(4, [4, 8, 4, 8, 4, 8, 4, 8, 4, 8])",
+    );
+    test_homographic_helper(
+        [2, 1, 2, 0],
+        sqrt_2(),
+        10,
+        "(1, [2, 1, 4, 1, 4, 1, 4, 1, 4, 1])",
+    );
+    test_homographic_helper(
+        [2, 1, 1, 3],
+        sqrt_2(),
+        10,
+        "(0, [1, 6, 1, 1, 6, 1, 1, 6, 1, 1])",
+    );
+    test_homographic_helper(
+        [1, -1, 1, 1],
+        e(),
+        10,
+        "(0, [2, 6, 10, 14, 18, 22, 26, 30, 34, 38])",
+    );
+    test_homographic_helper([1, 0, 0, 1], e(), 10, "(2, [1, 2, 1, 1, 4, 1, 1, 6, 1, 1])");
+    // constant, so the infinite input is never read
+    test_homographic_helper([2, 6, 1, 3], sqrt_2(), 10, "(2, [])");
+    test_homographic_helper([0, 1, 0, 3], e(), 10, "(0, [3])");
+}
+
+#[test]
+#[should_panic]
+fn homographic_continued_fraction_fail_1() {
+    homographic_continued_fraction(coefficients([1, 0, 1, -1]), rational("1"));
+}
+
+#[test]
+#[should_panic]
+fn homographic_continued_fraction_fail_2() {
+    homographic_continued_fraction(coefficients([1, 1, 0, 0]), sqrt_2());
+}
+
+#[test]
+#[should_panic]
+fn homographic_continued_fraction_fail_3() {
+    homographic_continued_fraction(
+        coefficients([1, 0, 0, 1]),
+        (Integer::ONE, [Natural::ZERO].into_iter()),
+    );
+}
+
+#[test]
+fn test_bihomographic_continued_fraction() {
+    let test_rational = |cs: [i32; 8], x: &str, y: &str, out: &str| {
+        test_bihomographic_helper(cs, rational(x), rational(y), usize::MAX, out);
+    };
+    test_rational([0, 1, 1, 0, 0, 0, 0, 1], "1/3", "1/6", "(0, [2])");
+    test_rational([0, 1, -1, 0, 0, 0, 0, 1], "1/3", "1/2", "(-1, [1, 5])");
+    test_rational([1, 0, 0, 0, 0, 0, 0, 1], "2/3", "-9/4", "(-2, [2])");
+    test_rational([0, 1, 0, 0, 0, 0, 1, 0], "2/3", "4/5", "(0, [1, 5])");
+    test_rational([1, 0, 0, 0, 0, 0, 0, 1], "0", "22/7", "(0, [])");
+    test_rational([1, 1, 1, 1, 0, 0, 0, 1], "-1", "-1", "(0, [])");
+    test_rational([1, 0, 0, 1, 0, 1, 1, 0], "2", "3", "(1, [2, 2])");
+
+    test_bihomographic_helper(
+        [0, 1, 1, 0, 0, 0, 0, 1],
+        e(),
+        sqrt_2(),
+        10,
+        "(4, [7, 1, 1, 4, 1, 3, 2, 1, 3, 2])",
+    );
+    test_bihomographic_helper(
+        [1, 0, 0, 0, 0, 0, 0, 1],
+        e(),
+        e(),
+        10,
+        "(7, [2, 1, 1, 3, 18, 5, 1, 1, 6, 30])",
+    );
+    test_bihomographic_helper(
+        [1, 0, 0, 1, 0, 1, 1, 0],
+        e(),
+        sqrt_2(),
+        10,
+        "(1, [5, 1, 4, 6, 4, 2, 1, 1, 1, 4])",
+    );
+    test_bihomographic_helper(
+        [0, 1, 1, 0, 0, 0, 0, 1],
+        sqrt_2(),
+        sqrt_3(),
+        10,
+        "(3, [6, 1, 5, 7, 1, 1, 4, 1, 38, 43])",
+    );
+    test_bihomographic_helper(
+        [1, 0, 0, 0, 0, 0, 0, 1],
+        sqrt_2(),
+        sqrt_3(),
+        10,
+        "(2, [2, 4, 2, 4, 2, 4, 2, 4, 2, 4])",
+    );
+    // a rational y
+    test_bihomographic_helper(
+        [0, 1, 1, 0, 0, 0, 0, 1],
+        sqrt_2(),
+        (
+            Integer::ZERO,
+            [Natural::from(2u32)].into_iter().chain(empty()),
+        ),
+        10,
+        "(1, [1, 10, 1, 1, 1, 10, 1, 1, 1, 10])",
+    );
+    // independent of x or y, so the infinite input is never read
+    test_bihomographic_helper(
+        [0, 1, 0, 1, 0, 0, 0, 1],
+        sqrt_2(),
+        e(),
+        10,
+        "(2, [2, 2, 2, 2, 2, 2, 2, 2, 2, 2])",
+    );
+    test_bihomographic_helper(
+        [0, 0, 3, 0, 0, 0, 0, 1],
+        sqrt_2(),
+        e(),
+        10,
+        "(8, [6, 2, 5, 2, 5, 1, 2, 5, 1, 2])",
+    );
+    test_bihomographic_helper([2, 2, 2, 2, 1, 1, 1, 1], sqrt_2(), e(), 10, "(2, [])");
+    test_bihomographic_helper(
+        [0, 1, 0, 0, 0, 0, 0, 1],
+        sqrt_2(),
+        rational("0"),
+        10,
+        "(1, [2, 2, 2, 2, 2, 2, 2, 2, 2, 2])",
+    );
+    test_bihomographic_helper(
+        [1, 0, 0, 0, 0, 0, 0, 1],
+        sqrt_2(),
+        rational("0"),
+        10,
+        "(0, [])",
+    );
+}
+
+#[test]
+#[should_panic]
+fn bihomographic_continued_fraction_fail_1() {
+    div_continued_fractions(sqrt_2(), rational("0"));
+}
+
+#[test]
+#[should_panic]
+fn bihomographic_continued_fraction_fail_2() {
+    bihomographic_continued_fraction(coefficients([1, 0, 0, 0, 0, 0, 0, 0]), e(), sqrt_2());
+}
+
+#[test]
+fn test_arithmetic() {
+    assert_eq!(
+        prefix(add_continued_fractions(e(), sqrt_2()), 10),
+        "(4, [7, 1, 1, 4, 1, 3, 2, 1, 3, 2])"
+    );
+    assert_eq!(
+        prefix(sub_continued_fractions(e(), sqrt_2()), 10),
+        "(1, [3, 3, 2, 6, 3, 17, 1, 1, 3, 3])"
+    );
+    assert_eq!(
+        prefix(mul_continued_fractions(e(), sqrt_2()), 10),
+        "(3, [1, 5, 2, 2, 1, 1, 1, 1, 1, 1])"
+    );
+    assert_eq!(
+        prefix(div_continued_fractions(e(), sqrt_2()), 10),
+        "(1, [1, 11, 1, 5, 4, 3, 6, 1, 4, 1])"
+    );
+}
+
+fn continued_fraction_to_rational(cf: (Integer, impl Iterator<Item = Natural>)) -> Rational {
+    let (floor, xs) = cf;
+    Rational::from_continued_fraction(floor, xs)
+}
+
+#[test]
+fn homographic_continued_fraction_properties() {
+    natural_vec_integer_pair_gen_var_1().test_properties(|(xs, floor)| {
+        // The input need not be in canonical form, but the output is
+        let x = Rational::from_continued_fraction_ref(&floor, xs.iter());
+        let (floor_out, xs_out) =
+            homographic_continued_fraction(coefficients([1, 0, 0, 1]), (floor, xs.into_iter()));
+        let (floor_alt, xs_alt) = x.continued_fraction();
+        assert_eq!(floor_out, floor_alt);
+        assert_eq!(xs_out.collect_vec(), xs_alt.collect_vec());
+    });
+
+    rational_integer_integer_triple_gen().test_properties(|(x, p, q)| {
+        let affine = homographic_continued_fraction(
+            [p.clone(), q.clone(), Integer::ZERO, Integer::ONE],
+            (&x).continued_fraction(),
+        );
+        assert_eq!(
+            continued_fraction_to_rational(affine),
+            &x * Rational::from(&p) + Rational::from(&q)
+        );
+
+        let denominator = &x * Rational::from(&q) + Rational::ONE;
+        if denominator != 0u32 {
+            let (floor, xs) = homographic_continued_fraction(
+                [Integer::ONE, p.clone(), q, Integer::ONE],
+                (&x).continued_fraction(),
+            );
+            let (floor_alt, xs_alt) = ((x + Rational::from(p)) / denominator).continued_fraction();
+            assert_eq!(floor, floor_alt);
+            assert_eq!(xs.collect_vec(), xs_alt.collect_vec());
+        }
+    });
+}
+
+#[test]
+fn bihomographic_continued_fraction_properties() {
+    rational_pair_gen().test_properties(|(x, y)| {
+        let (floor, xs) =
+            add_continued_fractions((&x).continued_fraction(), (&y).continued_fraction());
+        let (floor_alt, xs_alt) = (&x + &y).continued_fraction();
+        assert_eq!(floor, floor_alt);
+        assert_eq!(xs.collect_vec(), xs_alt.collect_vec());
+
+        let (floor, xs) =
+            sub_continued_fractions((&x).continued_fraction(), (&y).continued_fraction());
+        let (floor_alt, xs_alt) = (&x - &y).continued_fraction();
+        assert_eq!(floor, floor_alt);
+        assert_eq!(xs.collect_vec(), xs_alt.collect_vec());
+
+        let (floor, xs) =
+            mul_continued_fractions((&x).continued_fraction(), (&y).continued_fraction());
+        let (floor_alt, xs_alt) = (&x * &y).continued_fraction();
+        assert_eq!(floor, floor_alt);
+        assert_eq!(xs.collect_vec(), xs_alt.collect_vec());
+
+        let z = bihomographic_continued_fraction(
+            coefficients([1, 1, 1, 1, 0, 0, 0, 1]),
+            (&x).continued_fraction(),
+            (&y).continued_fraction(),
+        );
+        assert_eq!(
+            continued_fraction_to_rational(z),
+            (x + Rational::ONE) * (y + Rational::ONE)
+        );
+    });
+
+    rational_pair_gen_var_1().test_properties(|(x, y)| {
+        let (floor, xs) =
+            div_continued_fractions((&x).continued_fraction(), (&y).continued_fraction());
+        let (floor_alt, xs_alt) = (x / y).continued_fraction();
+        assert_eq!(floor, floor_alt);
+        assert_eq!(xs.collect_vec(), xs_alt.collect_vec());
+    });
+}
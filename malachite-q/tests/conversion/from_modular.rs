@@ -0,0 +1,166 @@
+// Copyright © 2025 Mikhail Hogrefe
+//
+// This file is part of Malachite.
+//
+// Malachite is free software: you can redistribute it and/or modify it under the terms of the GNU
+// Lesser General Public License (LGPL) as published by the Free Software Foundation; either version
+// 3 of the License, or (at your option) any later version. See <https://www.gnu.org/licenses/>.
+
+use malachite_base::num::arithmetic::traits::{
+    CoprimeWith, FloorSqrt, Mod, ModInverse, ModMul, Pow, PowerOf2,
+};
+use malachite_base::num::basic::traits::{One, Zero};
+use malachite_base::num::conversion::traits::ExactFrom;
+use malachite_base::strings::ToDebugString;
+use malachite_nz::integer::Integer;
+use malachite_nz::natural::Natural;
+use malachite_nz::test_util::generators::natural_pair_gen_var_8;
+use malachite_q::test_util::conversion::from_modular::from_modular_naive;
+use malachite_q::test_util::generators::rational_gen;
+use malachite_q::Rational;
+use std::panic::catch_unwind;
+use std::str::FromStr;
+
+#[test]
+fn test_from_modular() {
+    let test = |r, m, n, d, out| {
+        let r = Natural::from_str(r).unwrap();
+        let m = Natural::from_str(m).unwrap();
+        let n = Natural::from_str(n).unwrap();
+        let d = Natural::from_str(d).unwrap();
+        let x = Rational::from_modular(&r, &m, &n, &d);
+        assert!(x.as_ref().map_or(true, Rational::is_valid));
+        assert_eq!(x.to_debug_string(), out);
+        assert_eq!(from_modular_naive(&r, &m, &n, &d), x);
+    };
+    test("0", "1", "0", "0", "None");
+    test("0", "1", "0", "1", "Some(0)");
+    test("0", "100", "7", "7", "Some(0)");
+    test("1", "100", "7", "7", "Some(1)");
+    test("99", "100", "7", "7", "Some(-1)");
+    test("34", "100", "7", "7", "Some(2/3)");
+    test("71", "100", "7", "7", "Some(-3/7)");
+    test("50", "100", "7", "7", "None");
+    test("34", "100", "1", "7", "None");
+    test("34", "100", "7", "2", "None");
+    // 10 is a possible result when the numerator bound is large
+    test("10", "100", "99", "1", "Some(10)");
+    test("10", "100", "7", "7", "None");
+    test("333333336", "1000000007", "22360", "22360", "Some(1/3)");
+    test("588235291", "1000000007", "22360", "22360", "Some(-123/17)");
+    // 2^127 - 1 is prime
+    test(
+        "113427455640312821154458202477256070485",
+        "170141183460469231731687303715884105727",
+        "9223372036854775807",
+        "9223372036854775807",
+        "Some(1/3)",
+    );
+    test(
+        "100000000000000000000",
+        "170141183460469231731687303715884105727",
+        "9223372036854775807",
+        "9223372036854775807",
+        "Some(4938088852347682819/5104235503814076952)",
+    );
+    test(
+        "10000000000000000000000000",
+        "170141183460469231731687303715884105727",
+        "9223372036854775807",
+        "9223372036854775807",
+        "None",
+    );
+
+    // Moduli with enough limbs for the half-gcd algorithm to be used. 2^521 - 1 and 2^9689 - 1 are
+    // prime.
+    let test_round_trip = |m: &Natural, x: &str| {
+        let x = Rational::from_str(x).unwrap();
+        let (n, d) = x.numerator_and_denominator_ref();
+        let r = Natural::exact_from(
+            Integer::from_sign_and_abs_ref(x >= 0u32, n).mod_op(Integer::from(m)),
+        )
+        .mod_mul(d.mod_inverse(m).unwrap(), m);
+        let bound = (m >> 1u32).floor_sqrt();
+        let result = Rational::from_modular(&r, m, &bound, &bound);
+        assert_eq!(result.as_ref(), Some(&x));
+        assert_eq!(from_modular_naive(&r, m, &bound, &bound), result);
+        // With a smaller bound, the original rational can't be recovered.
+        let small_bound = &bound >> 10u32;
+        let result = Rational::from_modular(&r, m, &small_bound, &small_bound);
+        assert_eq!(
+            from_modular_naive(&r, m, &small_bound, &small_bound),
+            result
+        );
+    };
+    let m = Natural::power_of_2(521) - Natural::ONE;
+    test_round_trip(&m, "0");
+    test_round_trip(&m, "-1");
+    test_round_trip(&m, "123456789012345678901234567890/98765432109876543210987");
+    test_round_trip(
+        &m,
+        "-1234567890123456789012345678901234567890123456789012345678901234567890123456/\
+        98765432109876543210987654321098765432109876543210987654321098765432109876543",
+    );
+    let m = Natural::power_of_2(9689) - Natural::ONE;
+    test_round_trip(&m, "22/7");
+    test_round_trip(
+        &m,
+        &format!(
+            "-{}/{}",
+            Natural::power_of_2(4000) + Natural::ONE,
+            Natural::from(3u32).pow(3000)
+        ),
+    );
+}
+
+#[test]
+fn from_modular_fail() {
+    assert_panic!(Rational::from_modular(
+        &Natural::ZERO,
+        &Natural::ZERO,
+        &Natural::ONE,
+        &Natural::ONE
+    ));
+    assert_panic!(Rational::from_modular(
+        &Natural::from(10u32),
+        &Natural::from(10u32),
+        &Natural::ONE,
+        &Natural::ONE
+    ));
+}
+
+#[test]
+fn from_modular_properties() {
+    natural_pair_gen_var_8().test_properties(|(r, m)| {
+        let bound = (&m >> 1u32).floor_sqrt();
+        let result = Rational::from_modular(&r, &m, &bound, &bound);
+        assert_eq!(from_modular_naive(&r, &m, &bound, &bound), result);
+        if let Some(x) = result {
+            assert!(x.is_valid());
+            let sign = x >= 0u32;
+            let (n, d) = x.to_numerator_and_denominator();
+            assert!(n <= bound);
+            assert!(d <= bound);
+            assert!((&d).coprime_with(&m));
+            let residue = Integer::from_sign_and_abs(sign, n).mod_op(Integer::from(&m));
+            assert_eq!(Natural::exact_from(residue), r.mod_mul(d, &m));
+        }
+    });
+
+    rational_gen().test_properties(|x| {
+        // Choose a modulus that is large enough for `x` to be recovered uniquely, and coprime to
+        // the denominator.
+        let n = x.numerator_ref();
+        let d = x.denominator_ref();
+        let m = ((n * d) << 1u32) + Natural::ONE;
+        let r = if m == 1u32 {
+            Natural::ZERO
+        } else {
+            Natural::exact_from(
+                Integer::from_sign_and_abs_ref(x >= 0u32, n).mod_op(Integer::from(&m)),
+            )
+            .mod_mul((d % &m).mod_inverse(&m).unwrap(), &m)
+        };
+        assert_eq!(Rational::from_modular(&r, &m, n, d), Some(x));
+    });
+}
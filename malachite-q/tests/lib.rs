@@ -69,14 +69,17 @@ pub mod arithmetic {
     pub mod abs_diff;
     pub mod add;
     pub mod approximate;
+    pub mod bernoulli;
     pub mod ceiling;
     pub mod denominators_in_closed_interval;
     pub mod div;
     pub mod floor;
+    pub mod harmonic;
     pub mod is_power_of_2;
     pub mod log_base;
     pub mod log_base_2;
     pub mod log_base_power_of_2;
+    pub mod mediant;
     pub mod mul;
     pub mod neg;
     pub mod next_power_of_2;
@@ -92,6 +95,7 @@ pub mod arithmetic {
     pub mod simplest_rational_in_interval;
     pub mod sqrt;
     pub mod square;
+    pub mod stern_brocot;
     pub mod sub;
 }
 pub mod basic {
@@ -129,6 +133,7 @@ pub mod conversion {
     pub mod continued_fraction {
         pub mod convergents;
         pub mod from_continued_fraction;
+        pub mod gosper;
         pub mod to_continued_fraction;
     }
     pub mod digits {
@@ -143,6 +148,7 @@ pub mod conversion {
     pub mod from_bool;
     pub mod from_float_simplest;
     pub mod from_integer;
+    pub mod from_modular;
     pub mod from_natural;
     pub mod from_numerator_and_denominator;
     pub mod from_primitive_float;
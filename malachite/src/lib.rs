@@ -28,6 +28,49 @@ pub use malachite_nz::*;
 /// [`Rational`], a type representing rational numbers with arbitrarily large
 /// numerators and denominators.
 pub use malachite_q::Rational;
+#[cfg(feature = "naturals_and_integers")]
+#[cfg(feature = "rationals")]
+#[cfg(feature = "decimals")]
+/// [`Decimal`], a type representing arbitrary-precision decimal numbers with an explicit scale.
+pub use malachite_decimal::Decimal;
+#[cfg(feature = "naturals_and_integers")]
+#[cfg(feature = "rationals")]
+#[cfg(feature = "floats")]
+#[cfg(feature = "fixed")]
+/// [`Fixed`], a type representing binary fixed-point numbers with a compile-time number of
+/// fractional bits.
+pub use malachite_fixed::Fixed;
+#[cfg(feature = "naturals_and_integers")]
+#[cfg(feature = "rationals")]
+#[cfg(feature = "padics")]
+/// [`PAdic`], a type representing $p$-adic numbers with bounded precision.
+pub use malachite_padic::PAdic;
+#[cfg(feature = "naturals_and_integers")]
+#[cfg(feature = "rationals")]
+#[cfg(feature = "polynomials")]
+/// [`ModNatural`], a type representing residues modulo a fixed [`Natural`] modulus.
+pub use malachite_poly::mod_natural::ModNatural;
+#[cfg(feature = "naturals_and_integers")]
+#[cfg(feature = "rationals")]
+#[cfg(feature = "polynomials")]
+/// [`Polynomial`], a type representing dense univariate polynomials.
+pub use malachite_poly::Polynomial;
+#[cfg(feature = "naturals_and_integers")]
+#[cfg(feature = "rationals")]
+#[cfg(feature = "polynomials")]
+/// [`RealAlgebraic`], a type representing real algebraic numbers exactly.
+pub use malachite_poly::real_algebraic::RealAlgebraic;
+#[cfg(feature = "naturals_and_integers")]
+#[cfg(feature = "rationals")]
+#[cfg(feature = "polynomials")]
+/// [`QuadraticIrrational`], a type representing numbers of the form $(a + b\sqrt{d})/c$ exactly.
+pub use malachite_poly::quadratic_irrational::QuadraticIrrational;
+#[cfg(feature = "naturals_and_integers")]
+#[cfg(feature = "rationals")]
+#[cfg(feature = "matrices")]
+/// [`Matrix`], a type representing dense matrices, with exact linear algebra over the integers and
+/// the rationals.
+pub use malachite_matrix::Matrix;
 
 /// Various types and constants dependent on whether Malachite is built using 32-bit limbs or
 /// 64-bit limbs. `Limb` is the type such that `Vec`s of limbs are used to represent the bits of a